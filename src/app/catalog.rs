@@ -263,7 +263,12 @@ fn current_section(origin: HelpOrigin, feature_policy: &FeaturePolicy) -> HelpSe
             let mut rows = vec![
                 &result_active::YANK,
                 &result_active::ROW_YANK,
+                &result_active::RANGE,
+                &result_active::RANGE_YANK,
                 &result_active::ROW_DETAIL,
+                &result_active::MARK,
+                &result_active::NEXT_MARK,
+                &result_active::PREV_MARK,
             ];
             if can_write_preview {
                 rows.push(&result_active::STAGE_DELETE);
@@ -278,13 +283,25 @@ fn current_section(origin: HelpOrigin, feature_policy: &FeaturePolicy) -> HelpSe
             ..
         } => rows_from_binding_refs(&[
             &result_active::ENTER_DEEPEN,
+            &result_active::NEXT_MARK,
+            &result_active::PREV_MARK,
             &footer_nav::PAGE_NAV,
+            &footer_nav::PREVIEW_SORT,
+            &footer_nav::PREVIEW_FILTER,
+            &footer_nav::PREVIEW_EXPLAIN,
             csv_export(keymap_preset),
         ]),
         HelpOrigin::Normal {
             focused_pane: FocusedPane::Inspector,
             ..
-        } => rows_from_binding_refs(&[&global::INSPECTOR_TABS, &inspector_ddl::YANK]),
+        } => rows_from_binding_refs(&[
+            &global::INSPECTOR_TABS,
+            &inspector_columns::PIN,
+            &inspector_stats::COLUMN_NAV,
+            &inspector_rls::ROLE_FILTER,
+            &inspector_rls::TEST_AS_ROLE,
+            &inspector_ddl::YANK,
+        ]),
         HelpOrigin::Normal {
             focused_pane: FocusedPane::Explorer,
             keymap_preset,
@@ -292,6 +309,9 @@ fn current_section(origin: HelpOrigin, feature_policy: &FeaturePolicy) -> HelpSe
         } => rows_from_binding_refs(&[
             table_picker(keymap_preset),
             &global::CONNECTIONS,
+            &explorer::MARK,
+            &explorer::QUICK_ACTIONS,
+            &explorer::TOGGLE_SCHEMA,
             &global::SQL,
         ]),
         HelpOrigin::CommandLine => command_line_rows(feature_policy),
@@ -323,6 +343,14 @@ fn current_section(origin: HelpOrigin, feature_policy: &FeaturePolicy) -> HelpSe
         HelpOrigin::CellDetail { searching: true } => rows_from_bindings(CELL_DETAIL_SEARCH_KEYS),
         HelpOrigin::CellDetail { searching: false } => rows_from_mode_rows(CELL_DETAIL_ROWS),
         HelpOrigin::RowDetail => rows_from_mode_rows(ROW_DETAIL_ROWS),
+        HelpOrigin::ServerSettings => rows_from_mode_rows(SERVER_SETTINGS_ROWS),
+        HelpOrigin::SessionSettings => rows_from_mode_rows(SESSION_SETTINGS_ROWS),
+        HelpOrigin::SqlFileBrowser => rows_from_mode_rows(SQL_FILE_BROWSER_ROWS),
+        HelpOrigin::SnippetPicker => rows_from_mode_rows(SNIPPET_PICKER_ROWS),
+        HelpOrigin::ObjectSearch => rows_from_mode_rows(OBJECT_SEARCH_ROWS),
+        HelpOrigin::ErDiagram => rows_from_mode_rows(ER_DIAGRAM_ROWS),
+        HelpOrigin::ConnectionImport => rows_from_mode_rows(CONNECTION_IMPORT_ROWS),
+        HelpOrigin::QuickActions => rows_from_mode_rows(QUICK_ACTIONS_ROWS),
     };
 
     HelpSection {
@@ -499,6 +527,11 @@ fn sql_current_rows(
             &[
                 &sql_modal_normal::RUN,
                 &sql_modal_normal::YANK,
+                &sql_modal_normal::OPERATOR,
+                &sql_modal_normal::DELETE_CHAR,
+                &sql_modal_normal::PASTE,
+                &sql_modal_normal::VISUAL_LINE,
+                &sql_modal_normal::UNDO_EDIT,
                 &sql_modal_normal::ENTER_INSERT,
                 &sql_modal_normal::APPEND,
                 &sql_modal_normal::MOVE,
@@ -506,6 +539,7 @@ fn sql_current_rows(
                 &sql_modal_normal::VIEWPORT,
                 &sql_modal_normal::CLOSE,
                 &sql_modal_normal::CLEAR,
+                &sql_modal_normal::COMMAND,
                 sql_modal_normal_query_history(keymap_preset),
             ],
             feature_policy,
@@ -520,6 +554,7 @@ fn sql_current_rows(
                     &sql_modal::HOME_END,
                     &sql_modal::TAB,
                     &sql_modal::CLEAR,
+                    &sql_modal::UNDO_EDIT,
                 ],
                 feature_policy,
             ),
@@ -781,7 +816,7 @@ mod tests {
             !document.sections()[0]
                 .rows()
                 .iter()
-                .any(|row| row.description() == "Open Row Detail")
+                .any(|row| row.description() == result_active::ROW_DETAIL.description)
         );
     }
 
@@ -802,7 +837,7 @@ mod tests {
             document.sections()[0]
                 .rows()
                 .iter()
-                .any(|row| row.description() == "Open Row Detail")
+                .any(|row| row.description() == result_active::ROW_DETAIL.description)
         );
     }
 
@@ -830,7 +865,7 @@ mod tests {
         assert!(
             current_rows
                 .iter()
-                .any(|row| row.description() == "Open Row Detail")
+                .any(|row| row.description() == result_active::ROW_DETAIL.description)
         );
     }
 
@@ -851,7 +886,7 @@ mod tests {
         assert!(
             !current_rows
                 .iter()
-                .any(|row| row.description() == "Open Row Detail")
+                .any(|row| row.description() == result_active::ROW_DETAIL.description)
         );
         assert!(
             !current_rows
@@ -887,7 +922,7 @@ mod tests {
         assert!(
             !current_rows
                 .iter()
-                .any(|row| row.description() == "Open Row Detail")
+                .any(|row| row.description() == result_active::ROW_DETAIL.description)
         );
     }
 
@@ -0,0 +1,154 @@
+use std::collections::VecDeque;
+
+use crate::update::action::Action;
+
+/// Dispatch priority of an action arriving through the action channel.
+/// Lower lanes only run once every higher lane is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ActionLane {
+    Interactive,
+    QueryResult,
+    Background,
+}
+
+impl ActionLane {
+    pub fn of(action: &Action) -> Self {
+        match action {
+            Action::QueryCompleted { .. }
+            | Action::QueryFailed { .. }
+            | Action::ExplainCompleted { .. }
+            | Action::ExplainFailed { .. }
+            | Action::PreviewExplainCompleted { .. }
+            | Action::PreviewExplainFailed(_)
+            | Action::TransactionBegun { .. }
+            | Action::TransactionBeginFailed(_)
+            | Action::TransactionEnded(_)
+            | Action::TransactionEndFailed { .. }
+            | Action::ExecuteWriteSucceeded { .. }
+            | Action::ExecuteWriteFailed { .. }
+            | Action::ResultColumnTypesLoaded { .. }
+            | Action::CsvExportRowsCounted { .. }
+            | Action::CsvExportSucceeded { .. }
+            | Action::CsvExportFailed { .. }
+            | Action::MetadataLoaded { .. }
+            | Action::MetadataFailed { .. }
+            | Action::TableDetailLoaded { .. }
+            | Action::TableDetailFailed { .. }
+            | Action::BulkDdlLoaded { .. }
+            | Action::BulkDdlFailed { .. } => Self::QueryResult,
+            Action::MetadataSnapshotLoaded { .. }
+            | Action::PrefetchTableDetail { .. }
+            | Action::TableDetailCached { .. }
+            | Action::TableDetailCacheFailed { .. }
+            | Action::TableDetailAlreadyCached { .. }
+            | Action::FkNeighborsDiscovered { .. }
+            | Action::ProcessPrefetchQueue { .. } => Self::Background,
+            _ => Self::Interactive,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::Interactive => 0,
+            Self::QueryResult => 1,
+            Self::Background => 2,
+        }
+    }
+}
+
+/// Channel actions waiting for dispatch, one FIFO per lane. The main loop
+/// drains the channel into here so a burst of prefetch results cannot hold
+/// back a query result or render queued after it.
+#[derive(Debug, Default)]
+pub struct ActionLanes {
+    lanes: [VecDeque<Action>; 3],
+}
+
+impl ActionLanes {
+    /// Upper bound on buffered actions; past it the channel keeps applying
+    /// backpressure to producers.
+    pub const CAPACITY: usize = 256;
+
+    pub fn push(&mut self, action: Action) {
+        self.lanes[ActionLane::of(&action).index()].push_back(action);
+    }
+
+    /// Takes the oldest action from the highest non-empty lane.
+    pub fn pop(&mut self) -> Option<Action> {
+        self.lanes.iter_mut().find_map(VecDeque::pop_front)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lanes.iter().map(VecDeque::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lanes.iter().all(VecDeque::is_empty)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() >= Self::CAPACITY
+    }
+
+    pub fn queued(&self, lane: ActionLane) -> usize {
+        self.lanes[lane.index()].len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+    use rstest::rstest;
+    use std::sync::Arc;
+
+    fn query_completed() -> Action {
+        Action::QueryCompleted {
+            dsn: "postgres://localhost/db".to_string(),
+            run_id: 1,
+            result: Arc::new(QueryResult::success(
+                "SELECT 1".to_string(),
+                Vec::new(),
+                Vec::new(),
+                1,
+                QuerySource::Adhoc,
+            )),
+            generation: 0,
+            target_page: None,
+        }
+    }
+
+    #[rstest]
+    #[case(Action::Render, ActionLane::Interactive)]
+    #[case(Action::StartPrefetchAll, ActionLane::Interactive)]
+    #[case(query_completed(), ActionLane::QueryResult)]
+    #[case(Action::ProcessPrefetchQueue { run_id: 1 }, ActionLane::Background)]
+    #[case(Action::FkNeighborsDiscovered { tables: Vec::new() }, ActionLane::Background)]
+    fn classifies_actions(#[case] action: Action, #[case] expected: ActionLane) {
+        assert_eq!(ActionLane::of(&action), expected);
+    }
+
+    #[test]
+    fn pops_higher_lanes_first_and_fifo_within_a_lane() {
+        let mut lanes = ActionLanes::default();
+        lanes.push(Action::ProcessPrefetchQueue { run_id: 1 });
+        lanes.push(Action::ProcessPrefetchQueue { run_id: 2 });
+        lanes.push(query_completed());
+        lanes.push(Action::Render);
+
+        assert_eq!(lanes.len(), 4);
+        assert_eq!(lanes.queued(ActionLane::Background), 2);
+        assert!(matches!(lanes.pop(), Some(Action::Render)));
+        assert!(matches!(lanes.pop(), Some(Action::QueryCompleted { .. })));
+        assert!(matches!(
+            lanes.pop(),
+            Some(Action::ProcessPrefetchQueue { run_id: 1 })
+        ));
+        assert!(matches!(
+            lanes.pop(),
+            Some(Action::ProcessPrefetchQueue { run_id: 2 })
+        ));
+        assert!(lanes.pop().is_none());
+        assert!(lanes.is_empty());
+    }
+}
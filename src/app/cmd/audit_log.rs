@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::browse::query::utc_now_iso8601;
+use crate::domain::{AuditEntry, AuditEvent};
+use crate::ports::outbound::AuditLogStore;
+use crate::update::action::Action;
+
+// Tracked with pending writes so the record of a confirmed production write
+// survives quitting right after it.
+pub fn run(
+    project_name: String,
+    event: AuditEvent,
+    action_tx: &mpsc::Sender<Action>,
+    store: &Arc<dyn AuditLogStore>,
+    pending_writes: &BackgroundTasks,
+) {
+    let entry = AuditEntry::new(utc_now_iso8601(), event);
+    let store = Arc::clone(store);
+    let tx = action_tx.clone();
+    pending_writes.spawn(async move {
+        if let Err(error) = store.append(&project_name, &entry).await {
+            tx.send(Action::AuditLogFailed(error)).await.ok();
+        }
+    });
+}
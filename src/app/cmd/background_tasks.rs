@@ -0,0 +1,154 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+use crate::cmd::effect_budget::{EffectBudget, EffectClass};
+
+/// Fire-and-forget tasks that shutdown still has to account for: reads are
+/// aborted, writes are given a bounded chance to finish.
+#[derive(Clone, Default)]
+pub struct BackgroundTasks {
+    handles: Arc<Mutex<Vec<JoinHandle<()>>>>,
+    budget: EffectBudget,
+}
+
+impl BackgroundTasks {
+    pub fn with_budget(budget: EffectBudget) -> Self {
+        Self {
+            handles: Arc::default(),
+            budget,
+        }
+    }
+
+    /// Like `spawn`, but the task waits for a `class` slot before running.
+    pub fn spawn_budgeted<F>(&self, class: EffectClass, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let budget = self.budget.clone();
+        self.spawn(async move {
+            let _slot = budget.acquire(class).await;
+            task.await;
+        });
+    }
+
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = tokio::spawn(task);
+        let mut handles = self.handles.lock().expect("background task lock poisoned");
+        handles.retain(|handle| !handle.is_finished());
+        handles.push(handle);
+    }
+
+    /// Waits until every aborted task has been dropped, so child processes
+    /// spawned with `kill_on_drop` are killed while the runtime can still reap them.
+    pub async fn abort_all(&self) {
+        let handles = self.take();
+        for handle in &handles {
+            handle.abort();
+        }
+        for handle in handles {
+            let _ = handle.await;
+        }
+    }
+
+    /// Returns `false` when `timeout` elapsed before every task finished.
+    pub async fn drain(&self, timeout: Duration) -> bool {
+        let handles = self.take();
+        tokio::time::timeout(timeout, async {
+            for handle in handles {
+                let _ = handle.await;
+            }
+        })
+        .await
+        .is_ok()
+    }
+
+    fn take(&self) -> Vec<JoinHandle<()>> {
+        std::mem::take(&mut *self.handles.lock().expect("background task lock poisoned"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use tokio::sync::oneshot;
+
+    use super::*;
+
+    struct DropSignal(Arc<AtomicBool>);
+
+    impl Drop for DropSignal {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn abort_all_returns_after_tasks_are_dropped() {
+        let tasks = BackgroundTasks::default();
+        let dropped = Arc::new(AtomicBool::new(false));
+        let (started_tx, started_rx) = oneshot::channel();
+        let signal = DropSignal(Arc::clone(&dropped));
+
+        tasks.spawn(async move {
+            let _signal = signal;
+            started_tx.send(()).ok();
+            std::future::pending::<()>().await;
+        });
+        started_rx.await.unwrap();
+
+        tasks.abort_all().await;
+
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn drain_waits_for_pending_writes() {
+        let tasks = BackgroundTasks::default();
+        let written = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&written);
+
+        tasks.spawn(async move {
+            tokio::task::yield_now().await;
+            flag.store(true, Ordering::SeqCst);
+        });
+
+        assert!(tasks.drain(Duration::from_secs(1)).await);
+        assert!(written.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn budgeted_tasks_beyond_the_class_limit_wait_for_a_slot() {
+        let budget = EffectBudget::default();
+        let tasks = BackgroundTasks::with_budget(budget.clone());
+        let (release_tx, release_rx) = tokio::sync::watch::channel(false);
+
+        for _ in 0..EffectClass::Prefetch.limit() + 2 {
+            let mut release = release_rx.clone();
+            tasks.spawn_budgeted(EffectClass::Prefetch, async move {
+                let _ = release.wait_for(|released| *released).await;
+            });
+        }
+        for _ in 0..4 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(budget.queued(EffectClass::Prefetch), 2);
+
+        release_tx.send(true).unwrap();
+        assert!(tasks.drain(Duration::from_secs(1)).await);
+        assert_eq!(budget.queued_total(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn drain_gives_up_after_timeout() {
+        let tasks = BackgroundTasks::default();
+        tasks.spawn(std::future::pending::<()>());
+
+        assert!(!tasks.drain(Duration::from_millis(50)).await);
+    }
+}
@@ -4,12 +4,14 @@ use std::sync::Arc;
 use color_eyre::eyre::Result;
 use tokio::sync::mpsc;
 
+use crate::cmd::background_tasks::BackgroundTasks;
 use crate::cmd::cache::TtlCache;
 use crate::cmd::completion_engine::CompletionEngine;
 use crate::cmd::effect::Effect;
+use crate::cmd::effect_budget::EffectClass;
 use crate::cmd::sqlite_path_validate::validate_sqlite_database_path;
-use crate::domain::DatabaseMetadata;
 use crate::domain::sqlite_path_from_dsn;
+use crate::domain::{DatabaseMetadata, Table};
 use crate::model::app_state::AppState;
 use crate::policy::sqlite_path::to_db_operation_error;
 use crate::ports::outbound::{DbOperationError, MetadataProvider, SqlitePathValidator};
@@ -21,23 +23,25 @@ pub async fn run(
     metadata_provider: &Arc<dyn MetadataProvider>,
     metadata_cache: &TtlCache<String, Arc<DatabaseMetadata>>,
     sqlite_path_validator: &Arc<dyn SqlitePathValidator>,
-    _state: &mut AppState,
+    fetch_tasks: &BackgroundTasks,
+    state: &AppState,
     completion_engine: &RefCell<CompletionEngine>,
 ) -> Result<()> {
     match effect {
         Effect::FetchMetadata { dsn, run_id } => {
             fetch_metadata(
                 action_tx,
+                fetch_tasks,
                 metadata_provider,
                 metadata_cache,
                 sqlite_path_validator,
                 dsn,
                 run_id,
-            )
-            .await
+            );
+            Ok(())
         }
         Effect::FetchEffectiveUser { dsn, run_id } => {
-            fetch_effective_user(action_tx, metadata_provider, dsn, run_id);
+            fetch_effective_user(action_tx, fetch_tasks, metadata_provider, dsn, run_id);
             Ok(())
         }
         Effect::FetchTableDetail {
@@ -47,8 +51,27 @@ pub async fn run(
             generation,
             run_id,
         } => {
+            if state.session.is_offline() {
+                let action = match offline_table_detail(state, &schema, &table) {
+                    Ok(detail) => Action::TableDetailLoaded {
+                        dsn,
+                        run_id,
+                        detail: Box::new(detail),
+                        generation,
+                    },
+                    Err(error) => Action::TableDetailFailed {
+                        dsn,
+                        run_id,
+                        error,
+                        generation,
+                    },
+                };
+                action_tx.send(action).await.ok();
+                return Ok(());
+            }
             fetch_table_detail(
                 action_tx,
+                fetch_tasks,
                 metadata_provider,
                 dsn,
                 schema,
@@ -64,8 +87,29 @@ pub async fn run(
             schema,
             table,
         } => {
+            if state.session.is_offline() {
+                let action = match offline_table_detail(state, &schema, &table) {
+                    Ok(detail) => Action::TableDetailCached {
+                        dsn,
+                        run_id,
+                        schema,
+                        table,
+                        detail: Box::new(detail),
+                    },
+                    Err(error) => Action::TableDetailCacheFailed {
+                        dsn,
+                        run_id,
+                        schema,
+                        table,
+                        error,
+                    },
+                };
+                action_tx.send(action).await.ok();
+                return Ok(());
+            }
             prefetch_table_detail(
                 action_tx,
+                fetch_tasks,
                 metadata_provider,
                 completion_engine,
                 dsn,
@@ -75,6 +119,22 @@ pub async fn run(
             )
             .await
         }
+        Effect::FetchBulkTableDetails { dsn, tables } => {
+            if state.session.is_offline() {
+                let action = match tables
+                    .iter()
+                    .map(|(schema, table)| offline_table_detail(state, schema, table))
+                    .collect()
+                {
+                    Ok(tables) => Action::BulkDdlLoaded { dsn, tables },
+                    Err(error) => Action::BulkDdlFailed { dsn, error },
+                };
+                action_tx.send(action).await.ok();
+                return Ok(());
+            }
+            fetch_bulk_table_details(action_tx, fetch_tasks, metadata_provider, dsn, tables);
+            Ok(())
+        }
         Effect::ProcessPrefetchQueue { run_id } => {
             action_tx
                 .send(Action::ProcessPrefetchQueue { run_id })
@@ -84,7 +144,7 @@ pub async fn run(
         }
         Effect::DelayedProcessPrefetchQueue { run_id, delay_secs } => {
             let tx = action_tx.clone();
-            tokio::spawn(async move {
+            fetch_tasks.spawn(async move {
                 tokio::time::sleep(tokio::time::Duration::from_secs(delay_secs)).await;
                 tx.send(Action::ProcessPrefetchQueue { run_id }).await.ok();
             });
@@ -99,46 +159,65 @@ pub async fn run(
     }
 }
 
-async fn fetch_metadata(
+// Offline sessions answer detail requests from the snapshot; the database is
+// only contacted again by an explicit reload.
+fn offline_table_detail(
+    state: &AppState,
+    schema: &str,
+    table: &str,
+) -> Result<Table, DbOperationError> {
+    state
+        .session
+        .offline_table(schema, table)
+        .cloned()
+        .ok_or_else(|| {
+            DbOperationError::ObjectMissing(format!(
+                "{schema}.{table} is not in the offline snapshot"
+            ))
+        })
+}
+
+// Nothing here is awaited inline: even the SQLite path check touches the
+// filesystem, and startup renders only once the connect effect returns.
+fn fetch_metadata(
     action_tx: &mpsc::Sender<Action>,
+    fetch_tasks: &BackgroundTasks,
     metadata_provider: &Arc<dyn MetadataProvider>,
     metadata_cache: &TtlCache<String, Arc<DatabaseMetadata>>,
     sqlite_path_validator: &Arc<dyn SqlitePathValidator>,
     dsn: String,
     run_id: u64,
-) -> Result<()> {
-    if let Some(path) = sqlite_path_from_dsn(&dsn)
-        && let Err(error) =
-            validate_sqlite_database_path(sqlite_path_validator, path.to_string()).await
-    {
-        action_tx
-            .send(Action::MetadataFailed {
+) {
+    let provider = Arc::clone(metadata_provider);
+    let validator = Arc::clone(sqlite_path_validator);
+    let cache = metadata_cache.clone();
+    let tx = action_tx.clone();
+
+    fetch_tasks.spawn(async move {
+        if let Some(path) = sqlite_path_from_dsn(&dsn)
+            && let Err(error) = validate_sqlite_database_path(&validator, path.to_string()).await
+        {
+            tx.send(Action::MetadataFailed {
                 dsn,
                 run_id,
                 error: to_db_operation_error(&error),
             })
             .await
             .ok();
-        return Ok(());
-    }
+            return;
+        }
 
-    if let Some(cached) = metadata_cache.get(&dsn).await {
-        action_tx
-            .send(Action::MetadataLoaded {
+        if let Some(cached) = cache.get(&dsn).await {
+            tx.send(Action::MetadataLoaded {
                 dsn,
                 run_id,
                 metadata: cached,
             })
             .await
             .ok();
-        return Ok(());
-    }
-
-    let provider = Arc::clone(metadata_provider);
-    let cache = metadata_cache.clone();
-    let tx = action_tx.clone();
+            return;
+        }
 
-    tokio::spawn(async move {
         match provider.fetch_metadata(&dsn).await {
             Ok(metadata) => {
                 let metadata = Arc::new(metadata);
@@ -162,12 +241,11 @@ async fn fetch_metadata(
             }
         }
     });
-
-    Ok(())
 }
 
 fn fetch_effective_user(
     action_tx: &mpsc::Sender<Action>,
+    fetch_tasks: &BackgroundTasks,
     metadata_provider: &Arc<dyn MetadataProvider>,
     dsn: String,
     run_id: u64,
@@ -175,7 +253,7 @@ fn fetch_effective_user(
     let provider = Arc::clone(metadata_provider);
     let tx = action_tx.clone();
 
-    tokio::spawn(async move {
+    fetch_tasks.spawn(async move {
         let effective_user = provider.fetch_effective_user(&dsn).await.ok().flatten();
         tx.send(Action::EffectiveUserLoaded {
             dsn,
@@ -189,6 +267,7 @@ fn fetch_effective_user(
 
 fn fetch_table_detail(
     action_tx: &mpsc::Sender<Action>,
+    fetch_tasks: &BackgroundTasks,
     metadata_provider: &Arc<dyn MetadataProvider>,
     dsn: String,
     schema: String,
@@ -199,7 +278,7 @@ fn fetch_table_detail(
     let provider = Arc::clone(metadata_provider);
     let tx = action_tx.clone();
 
-    tokio::spawn(async move {
+    fetch_tasks.spawn_budgeted(EffectClass::Query, async move {
         match provider.fetch_table_detail(&dsn, &schema, &table).await {
             Ok(detail) => {
                 tx.send(Action::TableDetailLoaded {
@@ -225,8 +304,39 @@ fn fetch_table_detail(
     });
 }
 
+fn fetch_bulk_table_details(
+    action_tx: &mpsc::Sender<Action>,
+    fetch_tasks: &BackgroundTasks,
+    metadata_provider: &Arc<dyn MetadataProvider>,
+    dsn: String,
+    tables: Vec<(String, String)>,
+) {
+    let provider = Arc::clone(metadata_provider);
+    let tx = action_tx.clone();
+
+    fetch_tasks.spawn_budgeted(EffectClass::Query, async move {
+        let mut details = Vec::with_capacity(tables.len());
+        for (schema, table) in &tables {
+            match provider.fetch_table_detail(&dsn, schema, table).await {
+                Ok(detail) => details.push(detail),
+                Err(error) => {
+                    tx.send(Action::BulkDdlFailed { dsn, error }).await.ok();
+                    return;
+                }
+            }
+        }
+        tx.send(Action::BulkDdlLoaded {
+            dsn,
+            tables: details,
+        })
+        .await
+        .ok();
+    });
+}
+
 async fn prefetch_table_detail(
     action_tx: &mpsc::Sender<Action>,
+    fetch_tasks: &BackgroundTasks,
     metadata_provider: &Arc<dyn MetadataProvider>,
     completion_engine: &RefCell<CompletionEngine>,
     dsn: String,
@@ -253,7 +363,7 @@ async fn prefetch_table_detail(
     let provider = Arc::clone(metadata_provider);
     let tx = action_tx.clone();
 
-    tokio::spawn(async move {
+    fetch_tasks.spawn_budgeted(EffectClass::Prefetch, async move {
         let result = tokio::time::timeout(
             tokio::time::Duration::from_secs(10),
             provider.fetch_table_columns_and_fks(&dsn, &schema, &table),
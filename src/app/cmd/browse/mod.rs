@@ -1,2 +1,3 @@
 pub(crate) mod metadata;
 pub(crate) mod query;
+pub(crate) mod snapshot;
@@ -1,20 +1,102 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use async_trait::async_trait;
 use color_eyre::eyre::Result;
 use tokio::sync::mpsc;
 
+use crate::cmd::background_tasks::BackgroundTasks;
 use crate::cmd::effect::Effect;
+use crate::cmd::effect_budget::EffectClass;
 use crate::cmd::query_task::QueryTaskRegistry;
-use crate::domain::ConnectionId;
 use crate::domain::QuerySource;
 use crate::domain::command_tag::CommandTag;
 use crate::domain::query_history::{QueryHistoryEntry, QueryResultStatus};
 use crate::domain::sqlite_explain_query_plan_text_from_result;
+use crate::domain::{ConnectionId, QueryResult, WriteExecutionResult};
 use crate::model::app_state::AppState;
-use crate::ports::outbound::{CachedResultExporter, QueryExecutor, QueryHistoryStore};
+use crate::policy::sql::replica_routing::is_read_only_select;
+use crate::ports::outbound::{
+    AccessMode, CachedResultExporter, DbOperationError, PreviewOptions, QueryExecutor,
+    QueryHistoryStore, TransactionEnd,
+};
 use crate::update::action::Action;
 
+// Offline sessions browse a metadata snapshot; queries fail fast through the
+// usual failure actions instead of waiting on an unreachable server.
+struct OfflineQueryExecutor;
+
+impl OfflineQueryExecutor {
+    fn error() -> DbOperationError {
+        DbOperationError::ConnectionFailed(
+            "Offline mode: queries are disabled until a reload reaches the database".to_string(),
+        )
+    }
+}
+
+#[async_trait]
+impl QueryExecutor for OfflineQueryExecutor {
+    async fn execute_preview(
+        &self,
+        _dsn: &str,
+        _schema: &str,
+        _table: &str,
+        _options: PreviewOptions,
+        _limit: usize,
+        _offset: usize,
+    ) -> Result<QueryResult, DbOperationError> {
+        Err(Self::error())
+    }
+
+    async fn execute_adhoc(
+        &self,
+        _dsn: &str,
+        _query: &str,
+        _access_mode: AccessMode,
+    ) -> Result<QueryResult, DbOperationError> {
+        Err(Self::error())
+    }
+
+    async fn execute_write(
+        &self,
+        _dsn: &str,
+        _query: &str,
+        _access_mode: AccessMode,
+    ) -> Result<WriteExecutionResult, DbOperationError> {
+        Err(Self::error())
+    }
+
+    async fn count_query_rows(&self, _dsn: &str, _query: &str) -> Result<usize, DbOperationError> {
+        Err(Self::error())
+    }
+
+    async fn export_to_csv(
+        &self,
+        _dsn: &str,
+        _query: &str,
+        _file_name: &str,
+    ) -> Result<PathBuf, DbOperationError> {
+        Err(Self::error())
+    }
+
+    async fn begin_transaction(
+        &self,
+        _dsn: &str,
+        _access_mode: AccessMode,
+    ) -> Result<(), DbOperationError> {
+        Err(Self::error())
+    }
+
+    async fn end_transaction(
+        &self,
+        _dsn: &str,
+        _end: TransactionEnd,
+    ) -> Result<(), DbOperationError> {
+        Err(Self::error())
+    }
+}
+
 fn epoch_days_to_ymd(days: i64) -> (i64, u32, u32) {
     // Algorithm from https://howardhinnant.github.io/date_algorithms.html
     let z = days + 719_468;
@@ -30,7 +112,7 @@ fn epoch_days_to_ymd(days: i64) -> (i64, u32, u32) {
     (y, m, d)
 }
 
-fn utc_now_iso8601() -> String {
+pub fn utc_now_iso8601() -> String {
     let now_sys = SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
         .unwrap_or_default();
@@ -44,27 +126,52 @@ fn utc_now_iso8601() -> String {
     format!("{y:04}-{m:02}-{d:02}T{hours:02}:{minutes:02}:{seconds:02}Z")
 }
 
-fn save_query_history(
-    query_history_store: &Arc<dyn QueryHistoryStore>,
-    action_tx: &mpsc::Sender<Action>,
-    project_name: &str,
+/// Runs `attempt` once more when the first try lost its connection; the
+/// executor reconnects for the second one. Callers only allow it for reads,
+/// outside a transaction, so a statement never runs twice with effects. For
+/// ad-hoc SQL that means a read-only session: a SELECT can still write
+/// (`nextval`, volatile functions), which the session rejects.
+async fn retry_after_reconnect<T, Fut>(
+    allowed: bool,
+    attempt: impl Fn() -> Fut,
+) -> (Result<T, DbOperationError>, bool)
+where
+    Fut: Future<Output = Result<T, DbOperationError>>,
+{
+    match attempt().await {
+        Err(error) if allowed && error.is_transient() => (attempt().await, true),
+        outcome => (outcome, false),
+    }
+}
+
+fn history_entry(
     connection_id: &ConnectionId,
     query: &str,
     result_status: QueryResultStatus,
     affected_rows: Option<u64>,
-) {
-    let store = Arc::clone(query_history_store);
-    let tx = action_tx.clone();
-    let entry = QueryHistoryEntry::new(
+) -> QueryHistoryEntry {
+    QueryHistoryEntry::new(
         query.to_string(),
         utc_now_iso8601(),
         connection_id.clone(),
         result_status,
         affected_rows,
-    );
+    )
+}
+
+fn save_query_history(
+    pending_writes: &BackgroundTasks,
+    query_history_store: &Arc<dyn QueryHistoryStore>,
+    action_tx: &mpsc::Sender<Action>,
+    project_name: &str,
+    connection_id: &ConnectionId,
+    entry: QueryHistoryEntry,
+) {
+    let store = Arc::clone(query_history_store);
+    let tx = action_tx.clone();
     let project = project_name.to_string();
     let conn_id = connection_id.clone();
-    tokio::spawn(async move {
+    pending_writes.spawn(async move {
         if let Err(e) = store.append(&project, &conn_id, &entry).await {
             let _ = tx.send(Action::QueryHistoryAppendFailed(e)).await;
         }
@@ -82,8 +189,17 @@ pub async fn run(
     query_history_store: &Arc<dyn QueryHistoryStore>,
     cached_result_exporter: &Arc<dyn CachedResultExporter>,
     query_tasks: &QueryTaskRegistry,
+    pending_writes: &BackgroundTasks,
     state: &AppState,
 ) -> Result<()> {
+    let offline = state.session.is_offline();
+    let offline_executor: Arc<dyn QueryExecutor> = Arc::new(OfflineQueryExecutor);
+    let query_executor = if offline {
+        &offline_executor
+    } else {
+        query_executor
+    };
+
     match effect {
         Effect::CancelActiveQuery => {
             query_tasks.cancel();
@@ -94,6 +210,9 @@ pub async fn run(
             dsn,
             schema,
             table,
+            sort_column,
+            filter,
+            columns,
             generation,
             run_id,
             limit,
@@ -102,12 +221,27 @@ pub async fn run(
         } => {
             let executor = Arc::clone(query_executor);
             let tx = action_tx.clone();
+            let options = PreviewOptions {
+                sort_column,
+                filter,
+                columns,
+            };
+            let retry_allowed = !state.session.in_transaction();
 
-            query_tasks.spawn(async move {
-                match executor
-                    .execute_preview(&dsn, &schema, &table, limit, offset)
+            query_tasks.spawn_budgeted(EffectClass::Preview, async move {
+                let (outcome, retried) = retry_after_reconnect(retry_allowed, || {
+                    executor.execute_preview(&dsn, &schema, &table, options.clone(), limit, offset)
+                })
+                .await;
+                if retried && outcome.is_ok() {
+                    tx.send(Action::QueryReconnectedAndRetried {
+                        dsn: dsn.clone(),
+                        run_id,
+                    })
                     .await
-                {
+                    .ok();
+                }
+                match outcome {
                     Ok(result) => {
                         tx.send(Action::QueryCompleted {
                             dsn,
@@ -146,7 +280,7 @@ pub async fn run(
             let executor = Arc::clone(query_executor);
             let tx = action_tx.clone();
 
-            query_tasks.spawn(async move {
+            query_tasks.spawn_budgeted(EffectClass::Query, async move {
                 match executor.execute_adhoc(&dsn, &query, access_mode).await {
                     Ok(result) => {
                         let plan_text = sqlite_explain_query_plan_text_from_result(&result);
@@ -176,8 +310,63 @@ pub async fn run(
             Ok(())
         }
 
+        Effect::ExplainPreview {
+            dsn,
+            query,
+            preview,
+        } => {
+            let executor = Arc::clone(query_executor);
+            let tx = action_tx.clone();
+
+            query_tasks.spawn_budgeted(EffectClass::Query, async move {
+                let action = match executor
+                    .execute_adhoc(&dsn, &query, AccessMode::ReadOnly)
+                    .await
+                {
+                    Ok(result) => Action::PreviewExplainCompleted {
+                        preview,
+                        query,
+                        plan_text: sqlite_explain_query_plan_text_from_result(&result),
+                        execution_time_ms: result.execution_time_ms,
+                    },
+                    Err(e) => Action::PreviewExplainFailed(e),
+                };
+                tx.send(action).await.ok();
+            });
+            Ok(())
+        }
+
+        Effect::BeginTransaction { dsn, access_mode } => {
+            let executor = Arc::clone(query_executor);
+            let tx = action_tx.clone();
+
+            query_tasks.spawn_budgeted(EffectClass::Query, async move {
+                let action = match executor.begin_transaction(&dsn, access_mode).await {
+                    Ok(()) => Action::TransactionBegun { dsn },
+                    Err(e) => Action::TransactionBeginFailed(e),
+                };
+                tx.send(action).await.ok();
+            });
+            Ok(())
+        }
+
+        Effect::EndTransaction { dsn, end } => {
+            let executor = Arc::clone(query_executor);
+            let tx = action_tx.clone();
+
+            query_tasks.spawn_budgeted(EffectClass::Query, async move {
+                let action = match executor.end_transaction(&dsn, end).await {
+                    Ok(()) => Action::TransactionEnded(end),
+                    Err(error) => Action::TransactionEndFailed { end, error },
+                };
+                tx.send(action).await.ok();
+            });
+            Ok(())
+        }
+
         Effect::ExecuteAdhoc {
             dsn,
+            replica_dsn,
             run_id,
             query,
             access_mode,
@@ -185,13 +374,34 @@ pub async fn run(
             let executor = Arc::clone(query_executor);
             let tx = action_tx.clone();
             let history_store = Arc::clone(query_history_store);
+            let history_writes = pending_writes.clone();
             let history_tx = action_tx.clone();
             let project = state.runtime.project_name().to_string();
-            let conn_id = state.session.active_connection_id().cloned();
+            let conn_id = state
+                .session
+                .active_connection_id()
+                .filter(|_| !offline)
+                .cloned();
             let query_for_history = query.clone();
-
-            query_tasks.spawn(async move {
-                match executor.execute_adhoc(&dsn, &query, access_mode).await {
+            let retry_allowed = !state.session.in_transaction()
+                && access_mode == AccessMode::ReadOnly
+                && is_read_only_select(&query);
+
+            query_tasks.spawn_budgeted(EffectClass::Query, async move {
+                let endpoint_dsn = replica_dsn.as_deref().unwrap_or(&dsn);
+                let (outcome, retried) = retry_after_reconnect(retry_allowed, || {
+                    executor.execute_adhoc(endpoint_dsn, &query, access_mode)
+                })
+                .await;
+                if retried && outcome.is_ok() {
+                    tx.send(Action::QueryReconnectedAndRetried {
+                        dsn: dsn.clone(),
+                        run_id,
+                    })
+                    .await
+                    .ok();
+                }
+                match outcome {
                     Ok(result) => {
                         if let Some(cid) = &conn_id {
                             let rows = result
@@ -199,13 +409,18 @@ pub async fn run(
                                 .as_ref()
                                 .and_then(CommandTag::affected_rows);
                             save_query_history(
+                                &history_writes,
                                 &history_store,
                                 &history_tx,
                                 &project,
                                 cid,
-                                &query_for_history,
-                                QueryResultStatus::Success,
-                                rows,
+                                history_entry(
+                                    cid,
+                                    &query_for_history,
+                                    QueryResultStatus::Success,
+                                    rows,
+                                )
+                                .with_duration_ms(result.execution_time_ms),
                             );
                         }
                         tx.send(Action::QueryCompleted {
@@ -221,13 +436,17 @@ pub async fn run(
                     Err(e) => {
                         if let Some(cid) = &conn_id {
                             save_query_history(
+                                &history_writes,
                                 &history_store,
                                 &history_tx,
                                 &project,
                                 cid,
-                                &query_for_history,
-                                QueryResultStatus::Failed,
-                                None,
+                                history_entry(
+                                    cid,
+                                    &query_for_history,
+                                    QueryResultStatus::Failed,
+                                    None,
+                                ),
                             );
                         }
                         tx.send(Action::QueryFailed {
@@ -254,23 +473,33 @@ pub async fn run(
             let executor = Arc::clone(query_executor);
             let tx = action_tx.clone();
             let history_store = Arc::clone(query_history_store);
+            let history_writes = pending_writes.clone();
             let history_tx = action_tx.clone();
             let project = state.runtime.project_name().to_string();
-            let conn_id = state.session.active_connection_id().cloned();
+            let conn_id = state
+                .session
+                .active_connection_id()
+                .filter(|_| !offline)
+                .cloned();
             let query_for_history = query.clone();
 
-            query_tasks.spawn(async move {
+            query_tasks.spawn_budgeted(EffectClass::Query, async move {
                 match executor.execute_write(&dsn, &query, access_mode).await {
                     Ok(result) => {
                         if let Some(cid) = &conn_id {
                             save_query_history(
+                                &history_writes,
                                 &history_store,
                                 &history_tx,
                                 &project,
                                 cid,
-                                &query_for_history,
-                                QueryResultStatus::Success,
-                                Some(result.affected_rows as u64),
+                                history_entry(
+                                    cid,
+                                    &query_for_history,
+                                    QueryResultStatus::Success,
+                                    Some(result.affected_rows as u64),
+                                )
+                                .with_duration_ms(result.execution_time_ms),
                             );
                         }
                         tx.send(Action::ExecuteWriteSucceeded {
@@ -284,13 +513,17 @@ pub async fn run(
                     Err(e) => {
                         if let Some(cid) = &conn_id {
                             save_query_history(
+                                &history_writes,
                                 &history_store,
                                 &history_tx,
                                 &project,
                                 cid,
-                                &query_for_history,
-                                QueryResultStatus::Failed,
-                                None,
+                                history_entry(
+                                    cid,
+                                    &query_for_history,
+                                    QueryResultStatus::Failed,
+                                    None,
+                                ),
                             );
                         }
                         tx.send(Action::ExecuteWriteFailed {
@@ -316,7 +549,7 @@ pub async fn run(
             let executor = Arc::clone(query_executor);
             let tx = action_tx.clone();
 
-            query_tasks.spawn(async move {
+            query_tasks.spawn_budgeted(EffectClass::Export, async move {
                 let row_count = executor.count_query_rows(&dsn, &count_query).await.ok();
                 tx.send(Action::CsvExportRowsCounted {
                     dsn,
@@ -342,7 +575,7 @@ pub async fn run(
             let tx = action_tx.clone();
             let export_dsn = dsn.clone();
 
-            query_tasks.spawn(async move {
+            query_tasks.spawn_budgeted(EffectClass::Export, async move {
                 let result = executor
                     .export_to_csv(&export_dsn, &query, &file_name)
                     .await;
@@ -382,7 +615,7 @@ pub async fn run(
             let tx = action_tx.clone();
             let exporter = Arc::clone(cached_result_exporter);
 
-            query_tasks.spawn(async move {
+            query_tasks.spawn_budgeted(EffectClass::Export, async move {
                 let result = exporter
                     .export_cached_result_to_csv(file_name, columns, values)
                     .await;
@@ -416,6 +649,7 @@ pub async fn run(
 mod tests {
     use std::cell::RefCell;
     use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::time::Duration;
 
     use tokio::sync::mpsc;
@@ -429,7 +663,9 @@ mod tests {
     use crate::ports::outbound::connection_store::MockConnectionStore;
     use crate::ports::outbound::metadata::MockMetadataProvider;
     use crate::ports::outbound::query_executor::MockQueryExecutor;
-    use crate::ports::outbound::{AccessMode, RenderOutput, RenderResult, Renderer};
+    use crate::ports::outbound::{
+        AccessMode, DbOperationError, RenderOutput, RenderResult, Renderer,
+    };
     use crate::services::AppServices;
     use crate::update::action::Action;
 
@@ -670,7 +906,7 @@ mod tests {
             mock_executor
                 .expect_execute_preview()
                 .once()
-                .returning(|_, _, _, _, _| Ok(test_fixtures::sample_query_result()));
+                .returning(|_, _, _, _, _, _| Ok(test_fixtures::sample_query_result()));
 
             let cache = TtlCache::new(300);
             let (tx, mut rx) = mpsc::channel(8);
@@ -692,6 +928,9 @@ mod tests {
                         dsn: "dsn://test".to_string(),
                         schema: "public".to_string(),
                         table: "users".to_string(),
+                        sort_column: None,
+                        filter: None,
+                        columns: Vec::new(),
                         generation: 1,
                         run_id: 8,
                         limit: 100,
@@ -722,7 +961,7 @@ mod tests {
             mock_executor
                 .expect_execute_preview()
                 .once()
-                .returning(|_, _, _, _, _| {
+                .returning(|_, _, _, _, _, _| {
                     Err(DbOperationError::QueryFailed("syntax error".to_string()))
                 });
 
@@ -746,6 +985,9 @@ mod tests {
                         dsn: "dsn://test".to_string(),
                         schema: "public".to_string(),
                         table: "users".to_string(),
+                        sort_column: None,
+                        filter: None,
+                        columns: Vec::new(),
                         generation: 1,
                         run_id: 8,
                         limit: 100,
@@ -835,6 +1077,7 @@ mod tests {
             let action = run_effect(
                 Effect::ExecuteAdhoc {
                     dsn: "dsn://test".to_string(),
+                    replica_dsn: None,
                     run_id: 1,
                     query: "SELECT 1".to_string(),
                     access_mode: AccessMode::ReadOnly,
@@ -846,6 +1089,114 @@ mod tests {
             assert!(matches!(action, Action::QueryCompleted { run_id: 1, .. }));
         }
 
+        #[tokio::test]
+        async fn lost_connection_retries_a_select_once() {
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let counter = Arc::clone(&attempts);
+            let mut executor = MockQueryExecutor::new();
+            executor
+                .expect_execute_adhoc()
+                .times(2)
+                .returning(move |_, _, _| {
+                    if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                        Err(DbOperationError::ConnectionLost("reset".to_string()))
+                    } else {
+                        Ok(test_fixtures::sample_query_result())
+                    }
+                });
+
+            let action = run_effect(
+                Effect::ExecuteAdhoc {
+                    dsn: "dsn://test".to_string(),
+                    replica_dsn: None,
+                    run_id: 3,
+                    query: "SELECT 1".to_string(),
+                    access_mode: AccessMode::ReadOnly,
+                },
+                executor,
+            )
+            .await;
+
+            assert!(matches!(
+                action,
+                Action::QueryReconnectedAndRetried { run_id: 3, .. }
+            ));
+            assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        }
+
+        #[tokio::test]
+        async fn lost_connection_never_retries_a_write() {
+            let mut executor = MockQueryExecutor::new();
+            executor
+                .expect_execute_adhoc()
+                .once()
+                .returning(|_, _, _| Err(DbOperationError::ConnectionLost("reset".to_string())));
+
+            let action = run_effect(
+                Effect::ExecuteAdhoc {
+                    dsn: "dsn://test".to_string(),
+                    replica_dsn: None,
+                    run_id: 3,
+                    query: "UPDATE users SET name = 'x'".to_string(),
+                    access_mode: AccessMode::ReadWrite,
+                },
+                executor,
+            )
+            .await;
+
+            assert!(matches!(action, Action::QueryFailed { run_id: 3, .. }));
+        }
+
+        #[tokio::test]
+        async fn lost_connection_never_retries_a_select_in_read_write_mode() {
+            let mut executor = MockQueryExecutor::new();
+            executor
+                .expect_execute_adhoc()
+                .once()
+                .returning(|_, _, _| Err(DbOperationError::ConnectionLost("reset".to_string())));
+
+            let action = run_effect(
+                Effect::ExecuteAdhoc {
+                    dsn: "dsn://test".to_string(),
+                    replica_dsn: None,
+                    run_id: 4,
+                    query: "SELECT nextval('orders_id_seq')".to_string(),
+                    access_mode: AccessMode::ReadWrite,
+                },
+                executor,
+            )
+            .await;
+
+            assert!(matches!(action, Action::QueryFailed { run_id: 4, .. }));
+        }
+
+        #[tokio::test]
+        async fn execute_adhoc_runs_on_replica_but_reports_primary_dsn() {
+            let mut executor = MockQueryExecutor::new();
+            executor
+                .expect_execute_adhoc()
+                .once()
+                .withf(|dsn, _, _| dsn == "dsn://replica")
+                .returning(|_, _, _| Ok(test_fixtures::sample_query_result()));
+
+            let action = run_effect(
+                Effect::ExecuteAdhoc {
+                    dsn: "dsn://test".to_string(),
+                    replica_dsn: Some("dsn://replica".to_string()),
+                    run_id: 1,
+                    query: "SELECT 1".to_string(),
+                    access_mode: AccessMode::ReadWrite,
+                },
+                executor,
+            )
+            .await;
+
+            assert!(matches!(
+                action,
+                Action::QueryCompleted { ref dsn, run_id: 1, .. } if dsn == "dsn://test"
+            ));
+        }
+
         #[tokio::test]
         async fn execute_explain_forwards_access_mode() {
             let mut executor = MockQueryExecutor::new();
@@ -906,4 +1257,72 @@ mod tests {
             ));
         }
     }
+
+    mod offline {
+        use super::*;
+        use crate::domain::{DatabaseMetadata, MetadataSnapshot};
+
+        struct NoopRenderer;
+        impl Renderer for NoopRenderer {
+            fn draw(
+                &mut self,
+                _state: &AppState,
+                _services: &AppServices,
+                _now: std::time::Instant,
+            ) -> RenderResult<RenderOutput> {
+                Ok(RenderOutput::default())
+            }
+        }
+
+        #[tokio::test]
+        async fn adhoc_fails_without_reaching_the_executor() {
+            let (tx, mut rx) = mpsc::channel(8);
+            // No expectations: any call into the executor panics the test.
+            let runner = test_fixtures::make_runner(
+                Arc::new(MockMetadataProvider::new()),
+                Arc::new(MockQueryExecutor::new()),
+                Arc::new(MockConnectionStore::new()),
+                TtlCache::new(300),
+                tx,
+            );
+            let mut state = AppState::new("test".to_string());
+            state
+                .session
+                .offer_offline_snapshot(Arc::new(MetadataSnapshot {
+                    metadata: DatabaseMetadata::new("app".to_string()),
+                    tables: Vec::new(),
+                }));
+            assert!(state.session.enter_offline());
+            let ce = RefCell::new(CompletionEngine::new());
+
+            runner
+                .run(
+                    vec![Effect::ExecuteAdhoc {
+                        dsn: "dsn://test".to_string(),
+                        replica_dsn: None,
+                        run_id: 1,
+                        query: "SELECT 1".to_string(),
+                        access_mode: AccessMode::ReadOnly,
+                    }],
+                    &mut NoopRenderer,
+                    &mut state,
+                    &ce,
+                    &AppServices::stub(),
+                )
+                .await
+                .unwrap();
+
+            let action = tokio::time::timeout(Duration::from_millis(500), rx.recv())
+                .await
+                .expect("action timeout")
+                .expect("channel closed");
+            assert!(matches!(
+                action,
+                Action::QueryFailed {
+                    error: DbOperationError::ConnectionFailed(_),
+                    ..
+                }
+            ));
+        }
+    }
 }
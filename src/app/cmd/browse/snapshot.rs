@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::ports::outbound::MetadataSnapshotStore;
+use crate::update::action::Action;
+
+// Snapshots are auxiliary: a failed save or load only means offline mode is
+// not offered, so errors are dropped instead of surfacing as messages.
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    snapshot_store: &Arc<dyn MetadataSnapshotStore>,
+    fetch_tasks: &BackgroundTasks,
+    pending_writes: &BackgroundTasks,
+) {
+    let store = Arc::clone(snapshot_store);
+    match effect {
+        Effect::SaveMetadataSnapshot {
+            project_name,
+            connection_id,
+            metadata,
+        } => {
+            pending_writes.spawn(async move {
+                let _ = store
+                    .save_metadata(&project_name, &connection_id, &metadata)
+                    .await;
+            });
+        }
+        Effect::SaveTableSnapshot {
+            project_name,
+            connection_id,
+            detail,
+            columns_only,
+        } => {
+            pending_writes.spawn(async move {
+                let _ = if columns_only {
+                    store
+                        .save_table_columns(&project_name, &connection_id, &detail)
+                        .await
+                } else {
+                    store
+                        .save_table_detail(&project_name, &connection_id, &detail)
+                        .await
+                };
+            });
+        }
+        Effect::LoadMetadataSnapshot {
+            dsn,
+            project_name,
+            connection_id,
+        } => {
+            let tx = action_tx.clone();
+            fetch_tasks.spawn(async move {
+                if let Ok(Some(snapshot)) = store.load(&project_name, &connection_id).await {
+                    tx.send(Action::MetadataSnapshotLoaded {
+                        dsn,
+                        snapshot: Arc::new(snapshot),
+                    })
+                    .await
+                    .ok();
+                }
+            });
+        }
+        _ => unreachable!("snapshot::run called with non-snapshot effect"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::cmd::test_fixtures::InMemoryMetadataSnapshotStore;
+    use crate::domain::{ConnectionId, DatabaseMetadata};
+
+    #[tokio::test]
+    async fn saved_metadata_is_offered_back_on_load() {
+        let (tx, mut rx) = mpsc::channel(8);
+        let store: Arc<dyn MetadataSnapshotStore> =
+            Arc::new(InMemoryMetadataSnapshotStore::default());
+        let fetch_tasks = BackgroundTasks::default();
+        let pending_writes = BackgroundTasks::default();
+        let connection_id = ConnectionId::from_string("conn");
+
+        run(
+            Effect::SaveMetadataSnapshot {
+                project_name: "test".to_string(),
+                connection_id: connection_id.clone(),
+                metadata: Arc::new(DatabaseMetadata::new("app".to_string())),
+            },
+            &tx,
+            &store,
+            &fetch_tasks,
+            &pending_writes,
+        );
+        assert!(pending_writes.drain(Duration::from_secs(1)).await);
+
+        run(
+            Effect::LoadMetadataSnapshot {
+                dsn: "postgres://localhost/app".to_string(),
+                project_name: "test".to_string(),
+                connection_id,
+            },
+            &tx,
+            &store,
+            &fetch_tasks,
+            &pending_writes,
+        );
+
+        match rx.recv().await {
+            Some(Action::MetadataSnapshotLoaded { dsn, snapshot }) => {
+                assert_eq!(dsn, "postgres://localhost/app");
+                assert_eq!(snapshot.metadata.database_name, "app");
+            }
+            other => panic!("expected MetadataSnapshotLoaded, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn missing_snapshot_sends_nothing() {
+        let (tx, mut rx) = mpsc::channel(8);
+        let store: Arc<dyn MetadataSnapshotStore> =
+            Arc::new(InMemoryMetadataSnapshotStore::default());
+        let fetch_tasks = BackgroundTasks::default();
+
+        run(
+            Effect::LoadMetadataSnapshot {
+                dsn: "postgres://localhost/app".to_string(),
+                project_name: "test".to_string(),
+                connection_id: ConnectionId::from_string("conn"),
+            },
+            &tx,
+            &store,
+            &fetch_tasks,
+            &BackgroundTasks::default(),
+        );
+        fetch_tasks.drain(Duration::from_secs(1)).await;
+
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -2,4 +2,4 @@ pub mod lru_cache;
 pub mod ttl_cache;
 
 pub use lru_cache::BoundedLruCache;
-pub use ttl_cache::TtlCache;
+pub use ttl_cache::{CacheStats, TtlCache};
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
 use tokio::sync::RwLock;
@@ -24,9 +25,22 @@ impl<V> CacheEntry<V> {
     }
 }
 
+#[derive(Debug, Default)]
+struct LookupCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
 pub struct TtlCache<K, V> {
     inner: Arc<RwLock<HashMap<K, CacheEntry<V>>>>,
     ttl: Duration,
+    lookups: Arc<LookupCounters>,
 }
 
 impl<K, V> TtlCache<K, V>
@@ -38,18 +52,34 @@ where
         Self {
             inner: Arc::new(RwLock::new(HashMap::new())),
             ttl: Duration::from_secs(ttl_seconds),
+            lookups: Arc::default(),
         }
     }
 
     pub async fn get(&self, key: &K) -> Option<V> {
         let cache = self.inner.read().await;
-        cache.get(key).and_then(|entry| {
+        let value = cache.get(key).and_then(|entry| {
             if entry.is_expired(self.ttl) {
                 None
             } else {
                 Some(entry.value.clone())
             }
-        })
+        });
+        let counter = if value.is_some() {
+            &self.lookups.hits
+        } else {
+            &self.lookups.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    /// Shared across clones, so any handle reports every lookup.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.lookups.hits.load(Ordering::Relaxed),
+            misses: self.lookups.misses.load(Ordering::Relaxed),
+        }
     }
 
     pub async fn set(&self, key: K, value: V) {
@@ -73,6 +103,7 @@ impl<K, V> Clone for TtlCache<K, V> {
         Self {
             inner: Arc::clone(&self.inner),
             ttl: self.ttl,
+            lookups: Arc::clone(&self.lookups),
         }
     }
 }
@@ -100,6 +131,19 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[tokio::test]
+    async fn stats_count_hits_and_misses_across_clones() {
+        let cache = TtlCache::new(60);
+        let handle = cache.clone();
+        cache.set("key".to_string(), "value".to_string()).await;
+
+        handle.get(&"key".to_string()).await;
+        handle.get(&"missing".to_string()).await;
+        cache.get(&"key".to_string()).await;
+
+        assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 1 });
+    }
+
     #[tokio::test]
     async fn test_cache_miss() {
         let cache: TtlCache<String, String> = TtlCache::new(60);
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::ports::outbound::CacheStore;
+use crate::update::action::Action;
+
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    store: &Arc<dyn CacheStore>,
+    fetch_tasks: &BackgroundTasks,
+) {
+    let tx = action_tx.clone();
+    let store = Arc::clone(store);
+    match effect {
+        Effect::LoadCacheUsage { project_name } => {
+            fetch_tasks.spawn(async move {
+                let usage = tokio::task::spawn_blocking(move || store.usage(&project_name)).await;
+                let action = match usage {
+                    Ok(Ok(usage)) => Action::CacheUsageLoaded(usage),
+                    Ok(Err(error)) => Action::CacheOperationFailed(error),
+                    Err(_) => return,
+                };
+                tx.send(action).await.ok();
+            });
+        }
+        Effect::ClearCache {
+            project_name,
+            category,
+        } => {
+            fetch_tasks.spawn(async move {
+                let cleared =
+                    tokio::task::spawn_blocking(move || store.clear(&project_name, category)).await;
+                let action = match cleared {
+                    Ok(Ok(cleanup)) => Action::CacheCleared { category, cleanup },
+                    Ok(Err(error)) => Action::CacheOperationFailed(error),
+                    Err(_) => return,
+                };
+                tx.send(action).await.ok();
+            });
+        }
+        _ => unreachable!("cache_dir::run called with non-cache effect"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::ports::outbound::{
+        CacheCategory, CacheCategoryUsage, CacheCleanup, CachePrunePolicy, CacheStoreError,
+    };
+
+    #[derive(Default)]
+    struct RecordingCacheStore {
+        cleared: Mutex<Option<(String, Option<CacheCategory>)>>,
+    }
+
+    impl CacheStore for RecordingCacheStore {
+        fn usage(&self, _project_name: &str) -> Result<Vec<CacheCategoryUsage>, CacheStoreError> {
+            Err(CacheStoreError::MissingCacheDir)
+        }
+
+        fn clear(
+            &self,
+            project_name: &str,
+            category: Option<CacheCategory>,
+        ) -> Result<CacheCleanup, CacheStoreError> {
+            *self.cleared.lock().unwrap() = Some((project_name.to_string(), category));
+            Ok(CacheCleanup {
+                files: 3,
+                bytes: 120,
+            })
+        }
+
+        fn prune(
+            &self,
+            _project_name: &str,
+            _policy: &CachePrunePolicy,
+        ) -> Result<CacheCleanup, CacheStoreError> {
+            Ok(CacheCleanup::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn clear_reports_what_was_removed() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let recording = Arc::new(RecordingCacheStore::default());
+        let store: Arc<dyn CacheStore> = recording.clone();
+        let tasks = BackgroundTasks::default();
+
+        run(
+            Effect::ClearCache {
+                project_name: "app".to_string(),
+                category: Some(CacheCategory::Logs),
+            },
+            &tx,
+            &store,
+            &tasks,
+        );
+
+        let Some(Action::CacheCleared { category, cleanup }) = rx.recv().await else {
+            panic!("expected CacheCleared");
+        };
+        assert_eq!(category, Some(CacheCategory::Logs));
+        assert_eq!(cleanup.files, 3);
+        assert_eq!(
+            *recording.cleared.lock().unwrap(),
+            Some(("app".to_string(), Some(CacheCategory::Logs)))
+        );
+    }
+
+    #[tokio::test]
+    async fn usage_failure_is_sent_back() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let store: Arc<dyn CacheStore> = Arc::new(RecordingCacheStore::default());
+        let tasks = BackgroundTasks::default();
+
+        run(
+            Effect::LoadCacheUsage {
+                project_name: "app".to_string(),
+            },
+            &tx,
+            &store,
+            &tasks,
+        );
+
+        assert!(matches!(
+            rx.recv().await,
+            Some(Action::CacheOperationFailed(
+                CacheStoreError::MissingCacheDir
+            ))
+        ));
+    }
+}
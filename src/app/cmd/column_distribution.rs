@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::domain::ColumnDistribution;
+use crate::ports::outbound::{AccessMode, QueryExecutor};
+use crate::update::action::Action;
+
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    executor: &Arc<dyn QueryExecutor>,
+    fetch_tasks: &BackgroundTasks,
+) {
+    let Effect::FetchColumnDistribution { dsn, run_id, query } = effect else {
+        unreachable!("column_distribution::run called with non-column-distribution effect");
+    };
+    let executor = Arc::clone(executor);
+    let tx = action_tx.clone();
+    fetch_tasks.spawn(async move {
+        let action = match executor
+            .execute_adhoc(&dsn, &query, AccessMode::ReadOnly)
+            .await
+        {
+            Ok(result) => Action::ColumnDistributionLoaded {
+                dsn,
+                run_id,
+                distribution: ColumnDistribution::from_result(&result),
+            },
+            Err(error) => Action::ColumnDistributionFailed { dsn, run_id, error },
+        };
+        tx.send(action).await.ok();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+    use crate::ports::outbound::query_executor::MockQueryExecutor;
+
+    #[tokio::test]
+    async fn stat_rows_become_a_distribution() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut executor = MockQueryExecutor::new();
+        executor
+            .expect_execute_adhoc()
+            .withf(|_, _, mode| *mode == AccessMode::ReadOnly)
+            .returning(|_, query, _| {
+                Ok(QueryResult::success(
+                    query.to_string(),
+                    vec![
+                        "stat".to_string(),
+                        "value".to_string(),
+                        "frequency".to_string(),
+                    ],
+                    vec![
+                        vec!["sampled".to_string(), "40".to_string(), String::new()],
+                        vec!["common".to_string(), "paid".to_string(), "0.75".to_string()],
+                    ],
+                    3,
+                    QuerySource::Adhoc,
+                ))
+            });
+        let executor = Arc::new(executor) as Arc<dyn QueryExecutor>;
+
+        run(
+            Effect::FetchColumnDistribution {
+                dsn: "postgres://localhost/app".to_string(),
+                run_id: 5,
+                query: "DISTRIBUTION".to_string(),
+            },
+            &tx,
+            &executor,
+            &BackgroundTasks::default(),
+        );
+
+        let Action::ColumnDistributionLoaded {
+            run_id,
+            distribution,
+            ..
+        } = rx.recv().await.unwrap()
+        else {
+            panic!("expected ColumnDistributionLoaded");
+        };
+        assert_eq!(run_id, 5);
+        assert_eq!(distribution.sampled_rows, 40);
+        assert_eq!(distribution.common_values.len(), 1);
+    }
+}
@@ -3,13 +3,21 @@ use std::collections::HashSet;
 use crate::cmd::cache::BoundedLruCache;
 #[cfg(test)]
 use crate::domain::ColumnAttributes;
-use crate::domain::{DatabaseMetadata, Table, TableSummary};
+use crate::domain::{
+    Column, ColumnStats, DatabaseMetadata, DatabaseType, SchemaObjectKind, Table, TableSummary,
+};
 use crate::model::sql_editor::completion::{CompletionCandidate, CompletionKind};
+use crate::policy::sql::builtin_functions::{BuiltinFunction, FunctionRole, POSTGRES_FUNCTIONS};
 use crate::policy::sql::lexer::{SqlContext, SqlLexer, TableReference, Token, TokenKind};
+use crate::policy::sql::templates::{
+    INSERT_FOR_TABLE_TRIGGER, SQL_TEMPLATES, TAB_STOP, insert_template,
+};
+use crate::ports::outbound::Snippet;
 use crate::update::helpers::char_to_byte_index;
 
 const COMPLETION_MAX_CANDIDATES: usize = 30;
 const TABLE_CACHE_CAPACITY: usize = 500;
+const FUNCTION_MAX_CANDIDATES: usize = 8;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CompletionContext {
@@ -34,6 +42,8 @@ pub struct CompletionEngine {
     keywords: Vec<&'static str>,
     lexer: SqlLexer,
     table_detail_cache: BoundedLruCache<String, Table>,
+    snippets: Vec<Snippet>,
+    builtin_functions: &'static [BuiltinFunction],
 }
 
 impl Default for CompletionEngine {
@@ -112,6 +122,8 @@ impl CompletionEngine {
             ],
             lexer: SqlLexer::new(),
             table_detail_cache: BoundedLruCache::new(TABLE_CACHE_CAPACITY),
+            snippets: Vec::new(),
+            builtin_functions: &[],
         }
     }
 
@@ -144,6 +156,18 @@ impl CompletionEngine {
         self.table_detail_cache.resize(new_capacity);
     }
 
+    pub fn set_snippets(&mut self, snippets: Vec<Snippet>) {
+        self.snippets = snippets;
+    }
+
+    /// Built-in functions follow the connected engine; only PostgreSQL has a list.
+    pub fn set_database_type(&mut self, database_type: Option<DatabaseType>) {
+        self.builtin_functions = match database_type {
+            Some(DatabaseType::PostgreSQL) => POSTGRES_FUNCTIONS,
+            Some(DatabaseType::SQLite | DatabaseType::MySQL) | None => &[],
+        };
+    }
+
     pub fn table_details_iter(&self) -> impl Iterator<Item = (&String, &Table)> {
         self.table_detail_cache.iter()
     }
@@ -279,8 +303,17 @@ impl CompletionEngine {
             cursor_pos,
         );
 
+        let clause = Self::current_clause(&prep.tokens, cursor_pos);
+        let filtering = clause == Some("WHERE");
+
         let mut candidates = match &context {
-            CompletionContext::Keyword => self.keyword_candidates(&current_token),
+            CompletionContext::Keyword => {
+                let mut candidates = self.snippet_candidates(&current_token);
+                candidates.extend(Self::template_candidates(&current_token, table_detail));
+                candidates.extend(self.keyword_candidates(&current_token));
+                candidates.truncate(COMPLETION_MAX_CANDIDATES);
+                candidates
+            }
             CompletionContext::Table => self.table_candidates(metadata, &current_token),
             CompletionContext::Column => {
                 let keywords = self.primary_clause_keywords(&current_token);
@@ -299,8 +332,12 @@ impl CompletionEngine {
                     .as_ref()
                     .map(|t| self.qualified_name_from_ref(t, metadata));
 
-                let mut columns =
-                    self.column_candidates_with_fk(table_detail, &current_token, recent_columns);
+                let mut columns = self.column_candidates_with_fk(
+                    table_detail,
+                    &current_token,
+                    recent_columns,
+                    filtering,
+                );
 
                 // UPDATE/DELETE/INSERT target table columns get priority
                 if let (Some(detail), Some(target)) = (table_detail, &target_qualified)
@@ -332,6 +369,7 @@ impl CompletionEngine {
                         Some(cached_table),
                         &current_token,
                         recent_columns,
+                        filtering,
                     );
                     if target_qualified.as_ref() == Some(qualified_name) {
                         for col in &mut cached_columns {
@@ -365,10 +403,13 @@ impl CompletionEngine {
                     15
                 }
                 .min(keywords.len());
-                let max_columns = (COMPLETION_MAX_CANDIDATES - max_keywords).min(columns.len());
+                let functions = self.function_candidates(metadata, &current_token, clause);
+                let max_columns =
+                    (COMPLETION_MAX_CANDIDATES - max_keywords - functions.len()).min(columns.len());
 
                 let mut mixed: Vec<_> = keywords.into_iter().take(max_keywords).collect();
                 mixed.extend(columns.into_iter().take(max_columns));
+                mixed.extend(functions);
                 mixed.sort_by(|a, b| match b.score.cmp(&a.score) {
                     std::cmp::Ordering::Equal => a.text.cmp(&b.text),
                     other => other,
@@ -378,9 +419,13 @@ impl CompletionEngine {
             CompletionContext::SchemaQualified(schema) => {
                 self.schema_qualified_candidates(metadata, schema, &current_token)
             }
-            CompletionContext::AliasColumn(alias) => {
-                self.alias_column_candidates(alias, &prep.context, metadata, &current_token)
-            }
+            CompletionContext::AliasColumn(alias) => self.alias_column_candidates(
+                alias,
+                &prep.context,
+                metadata,
+                &current_token,
+                filtering,
+            ),
             CompletionContext::CteOrTable => {
                 self.cte_or_table_candidates(&prep.context, metadata, &current_token)
             }
@@ -553,6 +598,96 @@ impl CompletionEngine {
         }
     }
 
+    /// The clause keyword the cursor sits under; AND/OR and operators do not
+    /// leave a clause, and `;` ends it.
+    fn current_clause(tokens: &[Token], cursor_pos: usize) -> Option<&'static str> {
+        const CLAUSE_KEYWORDS: [&str; 12] = [
+            "SELECT",
+            "FROM",
+            "WHERE",
+            "JOIN",
+            "ON",
+            "SET",
+            "GROUP",
+            "HAVING",
+            "ORDER",
+            "LIMIT",
+            "RETURNING",
+            "VALUES",
+        ];
+
+        let mut clause = None;
+        for token in tokens.iter().take_while(|t| t.start < cursor_pos) {
+            match &token.kind {
+                TokenKind::Keyword(kw) => {
+                    let kw_upper = kw.to_uppercase();
+                    if let Some(keyword) = CLAUSE_KEYWORDS.iter().find(|c| **c == kw_upper) {
+                        clause = Some(*keyword);
+                    }
+                }
+                TokenKind::Punctuation(';') => clause = None,
+                _ => {}
+            }
+        }
+        clause
+    }
+
+    /// Built-in and user-defined functions named after the typed word, with
+    /// their signature as detail. Aggregates lead in a select list and
+    /// boolean functions in a WHERE clause.
+    fn function_candidates(
+        &self,
+        metadata: Option<&DatabaseMetadata>,
+        prefix: &str,
+        clause: Option<&str>,
+    ) -> Vec<CompletionCandidate> {
+        if prefix.is_empty() {
+            return vec![];
+        }
+        let prefix_lower = prefix.to_lowercase();
+        let preferred = match clause {
+            Some("SELECT" | "HAVING") => Some(FunctionRole::Aggregate),
+            Some("WHERE" | "ON") => Some(FunctionRole::Predicate),
+            _ => None,
+        };
+
+        let builtins = self
+            .builtin_functions
+            .iter()
+            .map(|f| (f.name, f.arguments, f.result, f.role));
+        let user_defined = metadata
+            .into_iter()
+            .flat_map(|m| &m.objects)
+            .filter(|o| o.kind == SchemaObjectKind::Function)
+            .filter_map(|o| {
+                let result = o.function_result()?;
+                let arguments = o.arguments.as_deref().unwrap_or_default();
+                Some((
+                    o.name.as_str(),
+                    arguments,
+                    result,
+                    FunctionRole::for_result(result),
+                ))
+            });
+
+        let mut candidates: Vec<_> = builtins
+            .chain(user_defined)
+            .filter(|(name, ..)| name.to_lowercase().starts_with(&prefix_lower))
+            .map(|(name, arguments, result, role)| CompletionCandidate {
+                text: name.to_string(),
+                kind: CompletionKind::Function,
+                score: if preferred == Some(role) { 250 } else { 150 },
+                detail: Some(format!("({arguments}) → {result}")),
+                insert_text: Some(format!("{name}(")),
+                preview: vec![format!("{name}({arguments})"), format!("returns {result}")],
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.text.cmp(&b.text)));
+        candidates.dedup_by(|a, b| a.text == b.text);
+        candidates.truncate(FUNCTION_MAX_CANDIDATES);
+        candidates
+    }
+
     fn keyword_candidates(&self, prefix: &str) -> Vec<CompletionCandidate> {
         let prefix_upper = prefix.to_uppercase();
         let mut candidates: Vec<_> = self
@@ -565,6 +700,9 @@ impl CompletionEngine {
                     text: (*kw).to_string(),
                     kind: CompletionKind::Keyword,
                     score: if is_prefix_match { 100 } else { 10 },
+                    detail: None,
+                    insert_text: None,
+                    preview: Vec::new(),
                 }
             })
             .collect();
@@ -581,6 +719,62 @@ impl CompletionEngine {
             .collect()
     }
 
+    /// Saved snippets whose name starts with the typed word; accepting one
+    /// inserts its SQL. An empty prefix offers none so keywords stay first.
+    fn snippet_candidates(&self, prefix: &str) -> Vec<CompletionCandidate> {
+        if prefix.is_empty() {
+            return vec![];
+        }
+        let prefix_lower = prefix.to_lowercase();
+        self.snippets
+            .iter()
+            .filter(|snippet| snippet.name.to_lowercase().starts_with(&prefix_lower))
+            .map(|snippet| CompletionCandidate {
+                text: snippet.name.clone(),
+                kind: CompletionKind::Snippet,
+                score: 150,
+                detail: snippet
+                    .sql
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .map(str::to_string),
+                insert_text: Some(snippet.sql.clone()),
+                preview: snippet.sql.lines().map(str::to_string).collect(),
+            })
+            .collect()
+    }
+
+    /// Built-in templates whose trigger starts with the typed word, once it is
+    /// three chars long; `insfk` is offered only while a table is selected.
+    fn template_candidates(prefix: &str, table_detail: Option<&Table>) -> Vec<CompletionCandidate> {
+        if prefix.chars().count() < 3 {
+            return vec![];
+        }
+        let prefix_lower = prefix.to_lowercase();
+        let insert = table_detail
+            .and_then(insert_template)
+            .map(|body| (INSERT_FOR_TABLE_TRIGGER, body));
+        SQL_TEMPLATES
+            .iter()
+            .map(|template| (template.trigger, template.body.to_string()))
+            .chain(insert)
+            .filter(|(trigger, _)| trigger.starts_with(&prefix_lower))
+            .map(|(trigger, body)| CompletionCandidate {
+                text: trigger.to_string(),
+                kind: CompletionKind::Template,
+                score: 150,
+                detail: Some(body.replace('\n', " ").replace(TAB_STOP, "…")),
+                preview: body
+                    .replace(TAB_STOP, "…")
+                    .lines()
+                    .map(str::to_string)
+                    .collect(),
+                insert_text: Some(body),
+            })
+            .collect()
+    }
+
     fn primary_clause_keywords(&self, prefix: &str) -> Vec<CompletionCandidate> {
         const PRIMARY_KEYWORDS: &[&str] = &[
             "FROM",
@@ -627,10 +821,33 @@ impl CompletionEngine {
                 text: (*kw).to_string(),
                 kind: CompletionKind::Keyword,
                 score: 200, // Higher than column scores (max ~170)
+                detail: None,
+                insert_text: None,
+                preview: Vec::new(),
             })
             .collect()
     }
 
+    /// Row estimate plus the cached column list, if the table was loaded.
+    fn table_preview(&self, summary: &TableSummary) -> Vec<String> {
+        let mut lines = vec![summary.qualified_name()];
+        if let Some(rows) = summary.row_count_estimate.filter(|rows| *rows >= 0) {
+            lines.push(format!("~{rows} rows"));
+        }
+        let Some(table) = self.table_detail_cache.peek(&summary.qualified_name()) else {
+            return lines;
+        };
+        if let Some(comment) = &table.comment {
+            lines.push(comment.clone());
+        }
+        lines.push(String::new());
+        lines.extend(table.columns.iter().map(|column| {
+            let key = if column.is_primary_key() { " PK" } else { "" };
+            format!("{} {}{key}", column.name, column.data_type)
+        }));
+        lines
+    }
+
     fn table_candidates(
         &self,
         metadata: Option<&DatabaseMetadata>,
@@ -665,6 +882,9 @@ impl CompletionEngine {
                     text: t.qualified_name(),
                     kind: CompletionKind::Table,
                     score,
+                    detail: None,
+                    insert_text: None,
+                    preview: self.table_preview(t),
                 }
             })
             .collect();
@@ -681,19 +901,23 @@ impl CompletionEngine {
             .collect()
     }
 
+    #[cfg(test)]
     fn column_candidates(
         &self,
         table_detail: Option<&Table>,
         prefix: &str,
     ) -> Vec<CompletionCandidate> {
-        self.column_candidates_with_fk(table_detail, prefix, &[])
+        self.column_candidates_with_fk(table_detail, prefix, &[], false)
     }
 
+    /// `filtering` ranks columns that make selective predicates higher:
+    /// categorical columns per `pg_stats`, and leading index columns.
     fn column_candidates_with_fk(
         &self,
         table_detail: Option<&Table>,
         prefix: &str,
         recent_columns: &[String],
+        filtering: bool,
     ) -> Vec<CompletionCandidate> {
         let Some(table) = table_detail else {
             return vec![];
@@ -746,10 +970,29 @@ impl CompletionEngine {
                     score += 30;
                 }
 
+                let stats = table.column_stats.iter().find(|s| s.column == c.name);
+                if filtering {
+                    // Boost low-cardinality columns in WHERE (+35)
+                    if stats.is_some_and(ColumnStats::is_low_cardinality) {
+                        score += 35;
+                    }
+                    // Boost leading index columns in WHERE (+25)
+                    if table
+                        .indexes
+                        .iter()
+                        .any(|i| i.columns.first() == Some(&c.name))
+                    {
+                        score += 25;
+                    }
+                }
+
                 CompletionCandidate {
                     text: c.name.clone(),
                     kind: CompletionKind::Column,
                     score,
+                    detail: stats.and_then(ColumnStats::summary),
+                    insert_text: None,
+                    preview: column_preview(table, c, stats),
                 }
             })
             .collect();
@@ -792,6 +1035,9 @@ impl CompletionEngine {
                     text: t.name.clone(),
                     kind: CompletionKind::Table,
                     score: if is_prefix_match { 100 } else { 10 },
+                    detail: None,
+                    insert_text: None,
+                    preview: self.table_preview(t),
                 }
             })
             .collect();
@@ -814,6 +1060,7 @@ impl CompletionEngine {
         sql_context: &SqlContext,
         metadata: Option<&DatabaseMetadata>,
         prefix: &str,
+        filtering: bool,
     ) -> Vec<CompletionCandidate> {
         let alias_lower = alias.to_lowercase();
 
@@ -833,7 +1080,7 @@ impl CompletionEngine {
         let qualified_name = self.qualified_name_from_ref(table_ref, metadata);
 
         if let Some(table) = self.table_detail_cache.peek(&qualified_name) {
-            return self.column_candidates(Some(table), prefix);
+            return self.column_candidates_with_fk(Some(table), prefix, &[], filtering);
         }
 
         // If not in cache, return empty (caller should request table details)
@@ -856,6 +1103,9 @@ impl CompletionEngine {
                     text: cte.name.clone(),
                     kind: CompletionKind::Table,
                     score: 110, // CTEs slightly above prefix-matched tables
+                    detail: None,
+                    insert_text: None,
+                    preview: Vec::new(),
                 });
             }
         }
@@ -872,6 +1122,9 @@ impl CompletionEngine {
                         text: t.qualified_name(),
                         kind: CompletionKind::Table,
                         score: if is_name_prefix { 100 } else { 50 },
+                        detail: None,
+                        insert_text: None,
+                        preview: self.table_preview(t),
                     });
                 }
             }
@@ -909,6 +1162,39 @@ impl CompletionEngine {
     }
 }
 
+fn column_preview(table: &Table, column: &Column, stats: Option<&ColumnStats>) -> Vec<String> {
+    let mut lines = vec![
+        format!("{}.{}", table.qualified_name(), column.name),
+        column.type_display(),
+    ];
+    if column.is_primary_key() {
+        lines.push("primary key".to_string());
+    } else if column.is_unique() {
+        lines.push("unique".to_string());
+    }
+    lines.extend(
+        table
+            .foreign_keys
+            .iter()
+            .filter(|fk| fk.from_columns.contains(&column.name))
+            .map(|fk| {
+                format!(
+                    "→ {}.{}({})",
+                    fk.to_schema,
+                    fk.to_table,
+                    fk.to_columns.join(", ")
+                )
+            }),
+    );
+    if let Some(summary) = stats.and_then(ColumnStats::summary) {
+        lines.push(summary);
+    }
+    if let Some(comment) = &column.comment {
+        lines.push(comment.clone());
+    }
+    lines
+}
+
 #[cfg(test)]
 impl CompletionEngine {
     fn analyze(&self, content: &str, cursor_pos: usize) -> (String, CompletionContext) {
@@ -1066,6 +1352,95 @@ mod tests {
         }
     }
 
+    mod snippet_completion {
+        use super::*;
+
+        fn engine_with_snippets() -> CompletionEngine {
+            let mut e = engine();
+            e.set_snippets(vec![Snippet {
+                name: "stuck_jobs".to_string(),
+                sql: "\nSELECT * FROM jobs WHERE state = 'stuck';".to_string(),
+            }]);
+            e
+        }
+
+        #[test]
+        fn matching_snippet_leads_and_inserts_its_sql() {
+            let e = engine_with_snippets();
+
+            let candidates = e.get_candidates("stu", 3, None, None, &[]);
+
+            assert_eq!(candidates[0].kind, CompletionKind::Snippet);
+            assert_eq!(candidates[0].text, "stuck_jobs");
+            assert_eq!(
+                candidates[0].detail.as_deref(),
+                Some("SELECT * FROM jobs WHERE state = 'stuck';")
+            );
+            assert_eq!(
+                candidates[0].insert_text.as_deref(),
+                Some("\nSELECT * FROM jobs WHERE state = 'stuck';")
+            );
+        }
+
+        #[test]
+        fn empty_prefix_offers_no_snippets() {
+            let e = engine_with_snippets();
+
+            let candidates = e.get_candidates("", 0, None, None, &[]);
+
+            assert!(candidates.iter().all(|c| c.kind != CompletionKind::Snippet));
+        }
+    }
+
+    mod template_completion {
+        use super::*;
+
+        fn templates(candidates: &[CompletionCandidate]) -> Vec<&str> {
+            candidates
+                .iter()
+                .filter(|c| c.kind == CompletionKind::Template)
+                .map(|c| c.text.as_str())
+                .collect()
+        }
+
+        #[test]
+        fn trigger_prefix_offers_templates_before_keywords() {
+            let e = engine();
+
+            let candidates = e.get_candidates("sel", 3, None, None, &[]);
+
+            assert_eq!(templates(&candidates), ["sel", "selw", "selc"]);
+            assert_eq!(candidates[0].detail.as_deref(), Some("SELECT * FROM …"));
+            assert_eq!(candidates[3].text, "SELECT");
+        }
+
+        #[test]
+        fn short_or_full_keyword_prefix_offers_none() {
+            let e = engine();
+
+            assert!(templates(&e.get_candidates("se", 2, None, None, &[])).is_empty());
+            assert!(templates(&e.get_candidates("sele", 4, None, None, &[])).is_empty());
+        }
+
+        #[test]
+        fn insert_for_selected_table_needs_a_table() {
+            let e = engine();
+            let table = create_table("public", "users", &["id", "name"]);
+
+            assert_eq!(
+                templates(&e.get_candidates("ins", 3, None, None, &[])),
+                ["ins"]
+            );
+            let candidates = e.get_candidates("insf", 4, None, Some(&table), &[]);
+
+            assert_eq!(templates(&candidates), ["insfk"]);
+            assert_eq!(
+                candidates[0].insert_text.as_deref(),
+                Some("INSERT INTO public.users (id, name)\nVALUES (${}, ${})")
+            );
+        }
+    }
+
     mod word_boundary {
         use super::*;
 
@@ -1582,7 +1957,8 @@ mod tests {
                 false,
             )];
 
-            let candidates = e.alias_column_candidates("u", &sql_context, Some(&metadata), "");
+            let candidates =
+                e.alias_column_candidates("u", &sql_context, Some(&metadata), "", false);
 
             assert_eq!(candidates.len(), 2);
             assert!(candidates.iter().any(|c| c.text == "id"));
@@ -1604,7 +1980,7 @@ mod tests {
                 target_table: None,
             };
 
-            let candidates = e.alias_column_candidates("u", &sql_context, None, "");
+            let candidates = e.alias_column_candidates("u", &sql_context, None, "", false);
 
             assert!(candidates.is_empty());
         }
@@ -1649,7 +2025,8 @@ mod tests {
                 false,
             )];
 
-            let candidates = e.alias_column_candidates("u", &sql_context, Some(&metadata), "user");
+            let candidates =
+                e.alias_column_candidates("u", &sql_context, Some(&metadata), "user", false);
 
             assert_eq!(candidates.len(), 2);
             assert!(candidates.iter().any(|c| c.text == "user_id"));
@@ -1698,7 +2075,7 @@ mod tests {
             let e = engine();
             let table = create_table_with_fk();
 
-            let candidates = e.column_candidates_with_fk(Some(&table), "", &[]);
+            let candidates = e.column_candidates_with_fk(Some(&table), "", &[], false);
 
             // id: PK(+50) + NOT NULL(+20) = 170
             // user_id: FK(+40) + NOT NULL(+20) = 160
@@ -1724,13 +2101,57 @@ mod tests {
             let e = engine();
             let table = create_table_with_fk();
 
-            let candidates = e.column_candidates_with_fk(Some(&table), "user", &[]);
+            let candidates = e.column_candidates_with_fk(Some(&table), "user", &[], false);
 
             assert_eq!(candidates.len(), 1);
             assert_eq!(candidates[0].text, "user_id");
             // Prefix(+100) + FK(+40) + NOT NULL(+20) = 160
             assert_eq!(candidates[0].score, 160);
         }
+
+        #[test]
+        fn column_preview_shows_type_and_referenced_table() {
+            let e = engine();
+            let table = create_table_with_fk();
+
+            let candidates = e.column_candidates_with_fk(Some(&table), "user", &[], false);
+
+            assert_eq!(
+                candidates[0].preview,
+                vec![
+                    "public.orders.user_id",
+                    "int NOT NULL",
+                    "→ public.users(id)"
+                ]
+            );
+        }
+
+        #[test]
+        fn table_preview_lists_cached_columns() {
+            let mut e = engine();
+            e.cache_table_detail("public.orders".to_string(), create_table_with_fk());
+            let mut metadata = DatabaseMetadata::new("test".to_string());
+            metadata.table_summaries = vec![TableSummary::new(
+                "public".to_string(),
+                "orders".to_string(),
+                Some(1200),
+                false,
+            )];
+
+            let candidates = e.table_candidates(Some(&metadata), "ord");
+
+            assert_eq!(
+                candidates[0].preview,
+                vec![
+                    "public.orders",
+                    "~1200 rows",
+                    "",
+                    "id int PK",
+                    "user_id int",
+                    "status text"
+                ]
+            );
+        }
     }
 
     mod contains_match {
@@ -1750,7 +2171,7 @@ mod tests {
             };
 
             // "id" is contained in "user_id"
-            let candidates = e.column_candidates_with_fk(Some(&table), "id", &[]);
+            let candidates = e.column_candidates_with_fk(Some(&table), "id", &[], false);
 
             assert_eq!(candidates.len(), 1);
             assert_eq!(candidates[0].text, "user_id");
@@ -1769,7 +2190,7 @@ mod tests {
                 ..test_support::table::minimal("", "")
             };
 
-            let candidates = e.column_candidates_with_fk(Some(&table), "id", &[]);
+            let candidates = e.column_candidates_with_fk(Some(&table), "id", &[], false);
 
             // "id" is prefix match (+100), "user_id" is contains match (+10)
             assert_eq!(candidates.len(), 2);
@@ -1796,7 +2217,7 @@ mod tests {
             };
 
             let recent = vec!["email".to_string()];
-            let candidates = e.column_candidates_with_fk(Some(&table), "", &recent);
+            let candidates = e.column_candidates_with_fk(Some(&table), "", &recent, false);
 
             // "email" has recent bonus (+30)
             let email_score = candidates.iter().find(|c| c.text == "email").unwrap().score;
@@ -1807,6 +2228,201 @@ mod tests {
         }
     }
 
+    mod function_completion {
+        use super::*;
+        use crate::domain::SchemaObject;
+        use rstest::rstest;
+
+        fn postgres_engine() -> CompletionEngine {
+            let mut e = engine();
+            e.set_database_type(Some(DatabaseType::PostgreSQL));
+            e
+        }
+
+        fn functions(
+            e: &CompletionEngine,
+            sql: &str,
+            metadata: Option<&DatabaseMetadata>,
+        ) -> Vec<CompletionCandidate> {
+            e.get_candidates(sql, sql.chars().count(), metadata, None, &[])
+                .into_iter()
+                .filter(|c| c.kind == CompletionKind::Function)
+                .collect()
+        }
+
+        #[test]
+        fn builtin_carries_its_signature() {
+            let candidates = functions(&postgres_engine(), "SELECT date_t", None);
+
+            assert_eq!(candidates.len(), 1);
+            assert_eq!(candidates[0].text, "date_trunc");
+            assert_eq!(
+                candidates[0].detail.as_deref(),
+                Some("(field text, source timestamp) → timestamp")
+            );
+            assert_eq!(candidates[0].insert_text.as_deref(), Some("date_trunc("));
+        }
+
+        #[test]
+        fn other_engines_get_no_builtins() {
+            let mut e = engine();
+            e.set_database_type(Some(DatabaseType::SQLite));
+
+            assert!(functions(&e, "SELECT date_t", None).is_empty());
+        }
+
+        #[rstest]
+        #[case("SELECT bool", "bool_and")]
+        #[case("SELECT * FROM t WHERE jsonb_", "jsonb_path_exists")]
+        #[case("SELECT * FROM t WHERE is", "isfinite")]
+        fn clause_decides_which_role_leads(#[case] sql: &str, #[case] expected: &str) {
+            let candidates = functions(&postgres_engine(), sql, None);
+
+            assert_eq!(candidates[0].text, expected);
+        }
+
+        #[test]
+        fn aggregates_are_not_boosted_in_where() {
+            let e = postgres_engine();
+
+            let select = functions(&e, "SELECT cou", None);
+            let filter = functions(&e, "SELECT * FROM t WHERE cou", None);
+
+            assert!(select[0].score > filter[0].score);
+        }
+
+        #[test]
+        fn user_defined_functions_come_from_metadata() {
+            let mut metadata = DatabaseMetadata::new("app".to_string());
+            metadata.objects.push(SchemaObject {
+                schema: "public".to_string(),
+                name: "is_active".to_string(),
+                kind: SchemaObjectKind::Function,
+                arguments: Some("user_id integer".to_string()),
+                definition: Some(
+                    "FUNCTION public.is_active(user_id integer) RETURNS boolean LANGUAGE sql"
+                        .to_string(),
+                ),
+            });
+
+            let candidates = functions(&engine(), "SELECT * FROM t WHERE is_a", Some(&metadata));
+
+            assert_eq!(candidates.len(), 1);
+            assert_eq!(
+                candidates[0].detail.as_deref(),
+                Some("(user_id integer) → boolean")
+            );
+            assert_eq!(candidates[0].score, 250);
+        }
+
+        #[test]
+        fn empty_prefix_offers_no_functions() {
+            assert!(functions(&postgres_engine(), "SELECT ", None).is_empty());
+        }
+    }
+
+    mod column_stats_ranking {
+        use super::*;
+        use crate::domain::{Index, IndexAttributes, IndexType};
+        use rstest::rstest;
+
+        fn stats(column: &str, n_distinct: f64) -> ColumnStats {
+            ColumnStats {
+                column: column.to_string(),
+                null_frac: 0.0,
+                n_distinct,
+            }
+        }
+
+        fn orders_table() -> Table {
+            Table {
+                schema: "public".to_string(),
+                name: "orders".to_string(),
+                columns: vec![
+                    test_support::column::test_nullable_column("amount", "numeric", 1),
+                    test_support::column::test_nullable_column("customer", "int", 2),
+                    test_support::column::test_nullable_column("note", "text", 3),
+                    test_support::column::test_nullable_column("status", "text", 4),
+                ],
+                indexes: vec![Index {
+                    name: "orders_customer_idx".to_string(),
+                    columns: vec!["customer".to_string()],
+                    attributes: IndexAttributes::empty(),
+                    index_type: IndexType::BTree,
+                    definition: None,
+                }],
+                column_stats: vec![
+                    stats("amount", -0.8),
+                    stats("note", 12_000.0),
+                    stats("status", 4.0),
+                ],
+                ..test_support::table::minimal("", "")
+            }
+        }
+
+        fn texts(candidates: &[CompletionCandidate]) -> Vec<&str> {
+            candidates.iter().map(|c| c.text.as_str()).collect()
+        }
+
+        #[test]
+        fn where_clause_prefers_categorical_and_indexed_columns() {
+            let e = engine();
+            let table = orders_table();
+            let sql = "SELECT * FROM orders WHERE ";
+
+            let candidates = e.get_candidates(sql, sql.len(), None, Some(&table), &[]);
+            let columns: Vec<_> = candidates
+                .into_iter()
+                .filter(|c| c.kind == CompletionKind::Column)
+                .collect();
+
+            assert_eq!(texts(&columns), ["status", "customer", "amount", "note"]);
+        }
+
+        #[test]
+        fn select_list_keeps_alphabetical_order() {
+            let e = engine();
+            let table = orders_table();
+
+            let candidates = e.column_candidates_with_fk(Some(&table), "", &[], false);
+
+            assert_eq!(texts(&candidates), ["amount", "customer", "note", "status"]);
+        }
+
+        #[test]
+        fn candidates_are_annotated_with_distinct_estimates() {
+            let e = engine();
+            let table = orders_table();
+
+            let candidates = e.column_candidates_with_fk(Some(&table), "", &[], false);
+            let detail = |name: &str| {
+                candidates
+                    .iter()
+                    .find(|c| c.text == name)
+                    .and_then(|c| c.detail.clone())
+            };
+
+            assert_eq!(detail("status").as_deref(), Some("4 distinct"));
+            assert_eq!(detail("customer"), None);
+            assert_eq!(detail("amount"), None);
+        }
+
+        #[rstest]
+        #[case::where_clause("SELECT * FROM t WHERE ", true)]
+        #[case::after_and("SELECT * FROM t WHERE a = 1 AND ", true)]
+        #[case::select_list("SELECT ", false)]
+        #[case::order_by("SELECT * FROM t WHERE a = 1 ORDER BY ", false)]
+        #[case::next_statement("DELETE FROM t WHERE a = 1; SELECT ", false)]
+        fn detects_filter_clause(#[case] sql: &str, #[case] expected: bool) {
+            let tokens = SqlLexer::new().tokenize(sql, sql.len());
+
+            assert_eq!(
+                CompletionEngine::current_clause(&tokens, sql.len()) == Some("WHERE"),
+                expected
+            );
+        }
+    }
+
     mod regression_tests {
         use super::*;
 
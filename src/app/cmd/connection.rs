@@ -32,9 +32,29 @@ pub(crate) async fn run(
     match effect {
         Effect::SaveAndConnect { id, name, config } => {
             let id = id.unwrap_or_else(ConnectionId::new);
+            // The setup form does not edit the replica, pool mode, pool
+            // settings, session settings or accent, so keep the saved ones.
+            let existing = state
+                .connections()
+                .iter()
+                .find(|existing| existing.id == id);
+            let replica_dsn = existing.and_then(|existing| existing.replica_dsn.clone());
+            let pool_mode = existing.and_then(|existing| existing.pool_mode);
+            let pool = existing.map(|existing| existing.pool).unwrap_or_default();
+            let accent = existing.and_then(|existing| existing.accent);
+            let production = existing.is_some_and(|existing| existing.production);
+            let session_settings = existing
+                .map(|existing| existing.session_settings.clone())
+                .unwrap_or_default();
             let profile = ConnectionProfile::with_id_and_config(id, name, config);
             let profile = match profile {
-                Ok(p) => p,
+                Ok(p) => p
+                    .with_replica_dsn(replica_dsn)
+                    .with_pool_mode(pool_mode)
+                    .with_pool(pool)
+                    .with_session_settings(session_settings)
+                    .with_accent(accent)
+                    .with_production(production),
                 Err(e) => {
                     action_tx
                         .send(Action::ConnectionSaveFailed(e.into()))
@@ -172,6 +192,32 @@ pub(crate) async fn run(
             Ok(())
         }
 
+        Effect::ScanConnectionCandidates { project_root } => {
+            let scanner = Arc::clone(&connection.connection_candidate_scanner);
+            let tx = action_tx.clone();
+
+            tokio::task::spawn_blocking(move || {
+                let candidates = scanner.scan(project_root);
+                tx.blocking_send(Action::ConnectionCandidatesLoaded(candidates))
+                    .ok();
+            });
+            Ok(())
+        }
+
+        Effect::ScanContainerDatabases => {
+            let scanner = Arc::clone(&connection.container_database_scanner);
+            let tx = action_tx.clone();
+
+            tokio::task::spawn_blocking(move || {
+                let action = match scanner.scan() {
+                    Ok(candidates) => Action::ConnectionCandidatesLoaded(candidates),
+                    Err(e) => Action::ConnectionCandidateScanFailed(e),
+                };
+                tx.blocking_send(action).ok();
+            });
+            Ok(())
+        }
+
         Effect::DeleteConnection { id } => {
             let store = Arc::clone(&connection.connection_store);
             let tx = action_tx.clone();
@@ -187,6 +233,28 @@ pub(crate) async fn run(
             Ok(())
         }
 
+        Effect::SaveSessionSettings { profile } => {
+            let store = Arc::clone(&connection.connection_store);
+            let tx = action_tx.clone();
+            let target = ConnectionTarget {
+                id: profile.id.clone(),
+                dsn: connection.dsn_builder.build_dsn(&profile),
+                name: profile.display_name().to_string(),
+                database_type: profile.database_type(),
+            };
+
+            tokio::task::spawn_blocking(move || match store.save(&profile) {
+                Ok(()) => {
+                    tx.blocking_send(Action::SessionSettingsSaved { profile, target })
+                        .ok();
+                }
+                Err(e) => {
+                    tx.blocking_send(Action::SessionSettingsSaveFailed(e)).ok();
+                }
+            });
+            Ok(())
+        }
+
         Effect::SwitchConnection { connection_index } => {
             if let Some(profile) = state.connections().get(connection_index) {
                 let dsn = connection.dsn_builder.build_dsn(profile);
@@ -518,6 +586,67 @@ mod tests {
         }
     }
 
+    mod save_session_settings {
+        use super::*;
+
+        #[tokio::test]
+        async fn success_returns_saved_profile_and_target() {
+            let mut mock_store = MockConnectionStore::new();
+            mock_store.expect_save().once().returning(|_| Ok(()));
+
+            let cache = TtlCache::new(300);
+            let (tx, mut rx) = mpsc::channel(8);
+            let runner = test_fixtures::make_runner(
+                Arc::new(MockMetadataProvider::new()),
+                Arc::new(MockQueryExecutor::new()),
+                Arc::new(mock_store),
+                cache,
+                tx,
+            );
+
+            let profile = ConnectionProfile::new_postgres(
+                "Primary",
+                "localhost",
+                5432,
+                "app",
+                "postgres",
+                "",
+                SslMode::Prefer,
+            )
+            .unwrap();
+            let id = profile.id.clone();
+            let state = &mut AppState::new("test".to_string());
+            let ce = RefCell::new(CompletionEngine::new());
+            let mut renderer = NoopRenderer;
+
+            runner
+                .run(
+                    vec![Effect::SaveSessionSettings {
+                        profile: Box::new(profile),
+                    }],
+                    &mut renderer,
+                    state,
+                    &ce,
+                    &AppServices::stub(),
+                )
+                .await
+                .unwrap();
+
+            let action = tokio::time::timeout(std::time::Duration::from_millis(500), rx.recv())
+                .await
+                .expect("action timeout")
+                .expect("channel closed");
+            assert!(
+                matches!(
+                    &action,
+                    Action::SessionSettingsSaved { profile, target }
+                        if profile.id == id && target.id == id && target.name == "Primary"
+                ),
+                "expected SessionSettingsSaved, got {action:?}"
+            );
+        }
+    }
+
     mod load_connections {
         use super::*;
         use crate::cmd::runner::{
@@ -582,11 +711,22 @@ mod tests {
                     dsn_builder: Arc::new(test_fixtures::NoopDsnBuilder),
                     connection_store: Arc::new(mock_store),
                     pg_service_entry_reader: None,
+                    connection_candidate_scanner: Arc::new(
+                        test_fixtures::NoopConnectionCandidateScanner,
+                    ),
+                    container_database_scanner: Arc::new(
+                        test_fixtures::NoopContainerDatabaseScanner,
+                    ),
                     sqlite_path_validator: Arc::new(test_fixtures::TestFsSqlitePathValidator),
+                    metadata_snapshot_store: Arc::new(
+                        test_fixtures::InMemoryMetadataSnapshotStore::default(),
+                    ),
                 },
                 QueryDeps {
                     query_executor: Arc::new(MockQueryExecutor::new()),
+                    connection_pool: None,
                     query_history_store: Arc::new(test_fixtures::NoopQueryHistoryStore),
+                    audit_log_store: Arc::new(test_fixtures::NoopAuditLogStore),
                     sqlite_diagnostics: Arc::new(test_fixtures::NoopSqliteDiagnosticsProvider),
                     cached_result_exporter: Arc::new(test_fixtures::TestCachedResultExporter),
                 },
@@ -598,6 +738,13 @@ mod tests {
                 UtilityDeps {
                     clipboard: Arc::new(test_fixtures::NoopClipboardWriter),
                     folder_opener: Arc::new(test_fixtures::NoopFolderOpener),
+                    temp_file_writer: Arc::new(test_fixtures::NoopTempFileWriter),
+                    query_hook_runner: Arc::new(test_fixtures::NoopQueryHookRunner),
+                    sql_file_scanner: Arc::new(test_fixtures::EmptySqlFileScanner),
+                    sql_file_store: Arc::new(test_fixtures::NoopSqlFileStore),
+                    snippet_store: Arc::new(test_fixtures::EmptySnippetStore),
+                    cache_store: Arc::new(test_fixtures::NoopCacheStore),
+                    release_checker: Arc::new(test_fixtures::NoopReleaseChecker),
                 },
                 SettingsDeps {
                     settings_store: Arc::new(test_fixtures::NoopSettingsStore),
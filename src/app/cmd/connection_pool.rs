@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::ports::outbound::ConnectionPool;
+use crate::update::action::Action;
+
+// Without a pool (demo and mock adapters) warmup is a no-op and every
+// endpoint reports the CLI driver.
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    pool: Option<&Arc<dyn ConnectionPool>>,
+    fetch_tasks: &BackgroundTasks,
+) {
+    match effect {
+        Effect::WarmUpConnectionPool { dsn, settings } => {
+            let Some(pool) = pool.cloned() else {
+                return;
+            };
+            fetch_tasks.spawn(async move {
+                pool.warm_up(&dsn, settings).await;
+            });
+        }
+        Effect::LoadServerInfo { dsn, replica_dsn } => {
+            let stats = |dsn: &str| pool.and_then(|pool| pool.stats(dsn));
+            let action = Action::ServerInfoLoaded {
+                primary: stats(&dsn),
+                replica: replica_dsn.as_deref().and_then(stats),
+                dsn,
+            };
+            let tx = action_tx.clone();
+            fetch_tasks.spawn(async move {
+                tx.send(action).await.ok();
+            });
+        }
+        _ => unreachable!("connection_pool::run called with non-pool effect"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::domain::PoolSettings;
+    use crate::ports::outbound::PoolStats;
+
+    #[derive(Default)]
+    struct RecordingPool {
+        warmed: Mutex<Vec<(String, PoolSettings)>>,
+    }
+
+    #[async_trait]
+    impl ConnectionPool for RecordingPool {
+        async fn warm_up(&self, dsn: &str, settings: PoolSettings) {
+            self.warmed
+                .lock()
+                .unwrap()
+                .push((dsn.to_string(), settings));
+        }
+
+        fn stats(&self, dsn: &str) -> Option<PoolStats> {
+            (dsn == "postgres://primary").then_some(PoolStats {
+                in_use: 1,
+                idle: 3,
+                size: 4,
+            })
+        }
+
+        async fn drain(&self) {}
+    }
+
+    #[tokio::test]
+    async fn warmup_reaches_the_pool() {
+        let (tx, _rx) = mpsc::channel(1);
+        let recording = Arc::new(RecordingPool::default());
+        let pool: Arc<dyn ConnectionPool> = recording.clone();
+        let tasks = BackgroundTasks::default();
+        let settings = PoolSettings::new(Some(6), Some(2));
+
+        run(
+            Effect::WarmUpConnectionPool {
+                dsn: "postgres://primary".to_string(),
+                settings,
+            },
+            &tx,
+            Some(&pool),
+            &tasks,
+        );
+        tasks.drain(std::time::Duration::from_secs(1)).await;
+
+        assert_eq!(
+            *recording.warmed.lock().unwrap(),
+            vec![("postgres://primary".to_string(), settings)]
+        );
+    }
+
+    #[tokio::test]
+    async fn server_info_reports_each_endpoint() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let pool: Arc<dyn ConnectionPool> = Arc::new(RecordingPool::default());
+        let tasks = BackgroundTasks::default();
+
+        run(
+            Effect::LoadServerInfo {
+                dsn: "postgres://primary".to_string(),
+                replica_dsn: Some("postgres://replica".to_string()),
+            },
+            &tx,
+            Some(&pool),
+            &tasks,
+        );
+
+        let Some(Action::ServerInfoLoaded {
+            primary, replica, ..
+        }) = rx.recv().await
+        else {
+            panic!("expected ServerInfoLoaded");
+        };
+        assert_eq!(primary.map(|stats| stats.idle), Some(3));
+        assert_eq!(replica, None);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::domain::ConnectionId;
+use crate::model::app_state::AppState;
+use crate::model::shared::input_mode::InputMode;
+
+/// Routed to the in-memory demo adapter; never reaches a real database.
+pub const DEMO_DSN: &str = "demo://sample";
+const DEMO_CONNECTION_ID: &str = "demo";
+const DEMO_CONNECTION_NAME: &str = "demo";
+/// Routed to the scripted mock adapter (`--mock`).
+pub const MOCK_DSN: &str = "mock://scripted";
+const MOCK_CONNECTION_ID: &str = "mock";
+const MOCK_CONNECTION_NAME: &str = "mock";
+
+pub fn activate_demo_connection(state: &mut AppState) {
+    activate(state, DEMO_CONNECTION_ID, DEMO_CONNECTION_NAME, DEMO_DSN);
+}
+
+pub fn activate_mock_connection(state: &mut AppState) {
+    activate(state, MOCK_CONNECTION_ID, MOCK_CONNECTION_NAME, MOCK_DSN);
+}
+
+fn activate(state: &mut AppState, id: &str, name: &str, dsn: &str) {
+    state
+        .session
+        .activate_demo_connection(&ConnectionId::from_string(id), name, dsn);
+    state.modal.set_mode(InputMode::Normal);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::DatabaseType;
+
+    #[test]
+    fn activates_ephemeral_postgres_session_in_normal_mode() {
+        let mut state = AppState::new("test".to_string());
+        state.modal.set_mode(InputMode::ConnectionSetup);
+
+        activate_demo_connection(&mut state);
+
+        assert_eq!(state.session.dsn(), Some(DEMO_DSN));
+        assert_eq!(
+            state.session.active_database_type(),
+            Some(DatabaseType::PostgreSQL)
+        );
+        assert!(!state.session.can_reenter_connection_setup());
+        assert_eq!(state.input_mode(), InputMode::Normal);
+    }
+
+    #[test]
+    fn mock_connection_uses_its_own_identity() {
+        let mut state = AppState::new("test".to_string());
+
+        activate_mock_connection(&mut state);
+
+        assert_eq!(state.session.dsn(), Some(MOCK_DSN));
+        assert_eq!(state.session.active_connection_name(), Some("mock"));
+        assert_eq!(state.input_mode(), InputMode::Normal);
+    }
+}
@@ -1,6 +1,13 @@
-use crate::domain::connection::{ConnectionConfig, ConnectionId};
-use crate::domain::{QueryValue, Table};
-use crate::ports::outbound::{AccessMode, AppSettings};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::domain::connection::{ConnectionConfig, ConnectionId, ConnectionProfile};
+use crate::domain::{AuditEvent, DatabaseMetadata, PoolSettings, QueryResult, QueryValue, Table};
+use crate::model::er_state::ErOutput;
+use crate::policy::index_usage_report::IndexUsageSort;
+use crate::ports::outbound::{
+    AccessMode, AppSettings, CacheCategory, HookInvocation, Snippet, TransactionEnd,
+};
 use crate::update::action::Action;
 
 #[derive(Debug, Clone)]
@@ -16,9 +23,16 @@ pub enum Effect {
         id: ConnectionId,
     },
     LoadConnections,
+    ScanConnectionCandidates {
+        project_root: Option<PathBuf>,
+    },
+    ScanContainerDatabases,
     DeleteConnection {
         id: ConnectionId,
     },
+    SaveSessionSettings {
+        profile: Box<ConnectionProfile>,
+    },
 
     CacheInvalidate {
         dsn: String,
@@ -46,6 +60,11 @@ pub enum Effect {
         schema: String,
         table: String,
     },
+    // Fetched one after another; any failure fails the whole batch
+    FetchBulkTableDetails {
+        dsn: String,
+        tables: Vec<(String, String)>,
+    },
     ProcessPrefetchQueue {
         run_id: u64,
     },
@@ -58,14 +77,20 @@ pub enum Effect {
         dsn: String,
         schema: String,
         table: String,
+        sort_column: Option<String>,
+        filter: Option<String>,
+        columns: Vec<String>,
         generation: u64,
         run_id: u64,
         limit: usize,
         offset: usize,
         target_page: usize,
     },
+    // `dsn` identifies the connection for stale checks; the query itself
+    // runs on `replica_dsn` when routing picked the replica.
     ExecuteAdhoc {
         dsn: String,
+        replica_dsn: Option<String>,
         run_id: u64,
         query: String,
         access_mode: AccessMode,
@@ -78,6 +103,11 @@ pub enum Effect {
         is_analyze: bool,
         access_mode: AccessMode,
     },
+    ExplainPreview {
+        dsn: String,
+        query: String,
+        preview: Arc<QueryResult>,
+    },
     ExecuteWrite {
         dsn: String,
         run_id: u64,
@@ -85,6 +115,14 @@ pub enum Effect {
         access_mode: AccessMode,
     },
     CancelActiveQuery,
+    BeginTransaction {
+        dsn: String,
+        access_mode: AccessMode,
+    },
+    EndTransaction {
+        dsn: String,
+        end: TransactionEnd,
+    },
     CountRowsForExport {
         dsn: String,
         run_id: u64,
@@ -119,12 +157,16 @@ pub enum Effect {
     ResizeCompletionCache {
         capacity: usize,
     },
+    SetCompletionSnippets {
+        snippets: Vec<Snippet>,
+    },
     TriggerCompletion,
 
     GenerateErDiagramFromCache {
         total_tables: usize,
         project_name: String,
         target_tables: Vec<String>,
+        output: ErOutput,
     },
     WriteErFailureLog {
         failed_tables: Vec<(String, String)>,
@@ -145,16 +187,49 @@ pub enum Effect {
     OpenFolder {
         path: std::path::PathBuf,
     },
+    // Writes content to a temp file, then copies the file path to the clipboard
+    CopyViaTempFile {
+        file_name: String,
+        content: String,
+        row_count: usize,
+    },
 
     LoadQueryHistory {
         project_name: String,
         connection_id: ConnectionId,
     },
 
+    SaveMetadataSnapshot {
+        project_name: String,
+        connection_id: ConnectionId,
+        metadata: Arc<DatabaseMetadata>,
+    },
+    // `columns_only` marks prefetch results, which must not replace a full detail
+    SaveTableSnapshot {
+        project_name: String,
+        connection_id: ConnectionId,
+        detail: Box<Table>,
+        columns_only: bool,
+    },
+    LoadMetadataSnapshot {
+        dsn: String,
+        project_name: String,
+        connection_id: ConnectionId,
+    },
+
     SaveSettings {
         settings: AppSettings,
     },
 
+    RunQueryHooks {
+        invocations: Vec<HookInvocation>,
+    },
+
+    AppendAuditLog {
+        project_name: String,
+        event: AuditEvent,
+    },
+
     FetchSqliteDiagnosticsCore {
         dsn: String,
         run_id: u64,
@@ -165,6 +240,98 @@ pub enum Effect {
         run_id: u64,
     },
 
+    // Statements run one by one so CONCURRENTLY stays outside a transaction.
+    RunMaintenance {
+        dsn: String,
+        run_id: u64,
+        queries: Vec<String>,
+    },
+    // Waits one poll interval before reading progress; the reducer re-issues
+    // it after each reading until the command finishes.
+    PollMaintenanceProgress {
+        dsn: String,
+        run_id: u64,
+        query: String,
+    },
+
+    FetchServerSettings {
+        dsn: String,
+        run_id: u64,
+        query: String,
+    },
+    FetchColumnDistribution {
+        dsn: String,
+        run_id: u64,
+        query: String,
+    },
+    FetchIndexUsage {
+        dsn: String,
+        run_id: u64,
+        query: String,
+        sort: IndexUsageSort,
+    },
+    // Looks up who held the lock after an ad-hoc query hit a lock timeout
+    // or deadlock.
+    FetchLockBlockers {
+        dsn: String,
+        run_id: u64,
+        query: String,
+    },
+    // Describes the visible ad-hoc result's column types for the header.
+    FetchResultColumnTypes {
+        dsn: String,
+        run_id: u64,
+        query: String,
+    },
+
+    LoadSqlFiles {
+        root: PathBuf,
+    },
+    OpenLinkedSqlFile {
+        path: PathBuf,
+    },
+    // Waits one poll interval before reading; the reducer re-issues it after
+    // each reading while the link is current.
+    PollLinkedSqlFile {
+        path: PathBuf,
+        link_id: u64,
+    },
+    WriteLinkedSqlFile {
+        path: PathBuf,
+        link_id: u64,
+        content: String,
+    },
+    // `:w <path>`; the buffer is linked to `path` once the write lands.
+    WriteSqlFileAs {
+        path: PathBuf,
+        content: String,
+    },
+
+    LoadSnippets {
+        root: PathBuf,
+    },
+    SaveSnippet {
+        root: PathBuf,
+        snippet: Snippet,
+    },
+
+    LoadCacheUsage {
+        project_name: String,
+    },
+    WarmUpConnectionPool {
+        dsn: String,
+        settings: PoolSettings,
+    },
+    LoadServerInfo {
+        dsn: String,
+        replica_dsn: Option<String>,
+    },
+    CheckLatestRelease,
+    ClearCache {
+        project_name: String,
+        category: Option<CacheCategory>,
+    },
+
     // Executes effects in order (each awaits before the next),
     // but spawned async tasks (e.g. FetchMetadata) may complete out of order.
     Sequence(Vec<Self>),
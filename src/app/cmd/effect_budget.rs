@@ -0,0 +1,155 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectClass {
+    Query,
+    Preview,
+    Prefetch,
+    Export,
+}
+
+impl EffectClass {
+    pub const ALL: [Self; 4] = [Self::Query, Self::Preview, Self::Prefetch, Self::Export];
+
+    /// Tasks of this class allowed to hold a database process at once.
+    pub fn limit(self) -> usize {
+        match self {
+            Self::Query => 2,
+            Self::Preview | Self::Export => 1,
+            Self::Prefetch => 4,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::Query => 0,
+            Self::Preview => 1,
+            Self::Prefetch => 2,
+            Self::Export => 3,
+        }
+    }
+}
+
+struct ClassBudget {
+    permits: Arc<Semaphore>,
+    queued: AtomicUsize,
+}
+
+/// Per-class concurrency limits for spawned effect tasks. A task acquires a
+/// slot before it starts its `psql`/`sqlite3` process; over-budget tasks wait
+/// in FIFO order and are counted as queued until then.
+#[derive(Clone)]
+pub struct EffectBudget {
+    classes: Arc<[ClassBudget; 4]>,
+}
+
+impl Default for EffectBudget {
+    fn default() -> Self {
+        Self {
+            classes: Arc::new(EffectClass::ALL.map(|class| ClassBudget {
+                permits: Arc::new(Semaphore::new(class.limit())),
+                queued: AtomicUsize::new(0),
+            })),
+        }
+    }
+}
+
+// Un-counts a waiter even when its task is aborted while still queued.
+struct QueuedGuard<'a>(&'a AtomicUsize);
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl EffectBudget {
+    /// Waits for a slot; hold the permit for as long as the work runs.
+    pub async fn acquire(&self, class: EffectClass) -> OwnedSemaphorePermit {
+        let budget = &self.classes[class.index()];
+        budget.queued.fetch_add(1, Ordering::Relaxed);
+        let _queued = QueuedGuard(&budget.queued);
+        Arc::clone(&budget.permits)
+            .acquire_owned()
+            .await
+            .expect("effect budget semaphore is never closed")
+    }
+
+    pub fn queued(&self, class: EffectClass) -> usize {
+        self.classes[class.index()].queued.load(Ordering::Relaxed)
+    }
+
+    pub fn queued_total(&self) -> usize {
+        EffectClass::ALL
+            .into_iter()
+            .map(|class| self.queued(class))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn acquire_beyond_limit_waits_and_counts_as_queued() {
+        let budget = EffectBudget::default();
+        let first = budget.acquire(EffectClass::Preview).await;
+
+        let waiter = {
+            let budget = budget.clone();
+            tokio::spawn(async move {
+                let _permit = budget.acquire(EffectClass::Preview).await;
+            })
+        };
+        tokio::task::yield_now().await;
+
+        assert_eq!(budget.queued(EffectClass::Preview), 1);
+        assert_eq!(budget.queued_total(), 1);
+
+        drop(first);
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(budget.queued_total(), 0);
+    }
+
+    #[tokio::test]
+    async fn classes_do_not_share_slots() {
+        let budget = EffectBudget::default();
+        let _preview = budget.acquire(EffectClass::Preview).await;
+        let _export = budget.acquire(EffectClass::Export).await;
+
+        let mut prefetches = Vec::new();
+        for _ in 0..EffectClass::Prefetch.limit() {
+            prefetches.push(budget.acquire(EffectClass::Prefetch).await);
+        }
+
+        assert_eq!(budget.queued_total(), 0);
+    }
+
+    #[tokio::test]
+    async fn aborted_waiter_leaves_the_queue() {
+        let budget = EffectBudget::default();
+        let _first = budget.acquire(EffectClass::Export).await;
+        let waiter = {
+            let budget = budget.clone();
+            tokio::spawn(async move {
+                let _permit = budget.acquire(EffectClass::Export).await;
+            })
+        };
+        tokio::task::yield_now().await;
+        assert_eq!(budget.queued(EffectClass::Export), 1);
+
+        waiter.abort();
+        let _ = waiter.await;
+
+        assert_eq!(budget.queued(EffectClass::Export), 0);
+    }
+}
@@ -11,6 +11,7 @@ use crate::cmd::effect::Effect;
 use crate::domain::ErTableInfo;
 use crate::domain::er::{er_output_filename, fk_neighbors_of_seeds, fk_reachable_tables_multi};
 use crate::model::app_state::AppState;
+use crate::model::er_state::ErOutput;
 use crate::ports::outbound::{ConfigWriter, ErDiagramExporter, ErLogWriter, MetadataProvider};
 use crate::update::action::{
     Action, ErDiagramError, ErLogError, SmartErRefreshError, SmartErRefreshResult,
@@ -31,19 +32,30 @@ pub async fn run(
             total_tables,
             project_name,
             target_tables,
-        } => {
-            handle_generate_diagram(
-                action_tx,
-                er_exporter,
-                config_writer,
-                completion_engine,
-                total_tables,
-                project_name,
-                target_tables,
-                state.settings.saved_er_browser().map(str::to_string),
-            )
-            .await
-        }
+            output,
+        } => match output {
+            ErOutput::Export => {
+                handle_generate_diagram(
+                    action_tx,
+                    er_exporter,
+                    config_writer,
+                    completion_engine,
+                    total_tables,
+                    project_name,
+                    target_tables,
+                    state,
+                )
+                .await
+            }
+            ErOutput::Viewer => {
+                let action = match diagram_tables(completion_engine, &target_tables) {
+                    Ok((tables, _)) => Action::ErViewerLoaded(tables),
+                    Err(error) => Action::ErDiagramFailed(error),
+                };
+                action_tx.send(action).await.ok();
+                Ok(())
+            }
+        },
         Effect::ExtractFkNeighbors { seed_tables } => {
             handle_extract_fk_neighbors(action_tx, completion_engine, seed_tables).await
         }
@@ -81,36 +93,16 @@ async fn handle_generate_diagram(
     total_tables: usize,
     project_name: String,
     target_tables: Vec<String>,
-    browser: Option<String>,
+    state: &AppState,
 ) -> Result<()> {
-    let all_tables = collect_cached_er_tables(completion_engine);
-    if all_tables.is_empty() {
-        action_tx
-            .send(Action::ErDiagramFailed(ErDiagramError::NoData(
-                "No table data loaded yet".to_string(),
-            )))
-            .await
-            .ok();
-        return Ok(());
-    }
-
-    let total = all_tables.len();
-    let filename = er_output_filename(&target_tables, total);
-    let tables = if target_tables.is_empty() || target_tables.len() == total {
-        all_tables
-    } else {
-        fk_reachable_tables_multi(&all_tables, &target_tables, 1)
+    let (tables, total) = match diagram_tables(completion_engine, &target_tables) {
+        Ok(selection) => selection,
+        Err(error) => {
+            action_tx.send(Action::ErDiagramFailed(error)).await.ok();
+            return Ok(());
+        }
     };
-
-    if tables.is_empty() {
-        action_tx
-            .send(Action::ErDiagramFailed(ErDiagramError::NoData(
-                "Selected tables not found in cached data".to_string(),
-            )))
-            .await
-            .ok();
-        return Ok(());
-    }
+    let filename = er_output_filename(&target_tables, total);
 
     let cache_dir = config_writer.get_cache_dir(&project_name)?;
     spawn_er_diagram_task(
@@ -120,11 +112,40 @@ async fn handle_generate_diagram(
         cache_dir,
         action_tx.clone(),
         filename,
-        browser,
+        state.settings.saved_er_browser().map(str::to_string),
+        state.er_preparation.options().clone(),
     );
     Ok(())
 }
 
+/// The targets and their FK neighbors from the completion cache, plus the
+/// number of cached tables; every cached table when no targets are set.
+fn diagram_tables(
+    completion_engine: &RefCell<CompletionEngine>,
+    target_tables: &[String],
+) -> Result<(Vec<ErTableInfo>, usize), ErDiagramError> {
+    let all_tables = collect_cached_er_tables(completion_engine);
+    if all_tables.is_empty() {
+        return Err(ErDiagramError::NoData(
+            "No table data loaded yet".to_string(),
+        ));
+    }
+
+    let total = all_tables.len();
+    let tables = if target_tables.is_empty() || target_tables.len() == total {
+        all_tables
+    } else {
+        fk_reachable_tables_multi(&all_tables, target_tables, 1)
+    };
+
+    if tables.is_empty() {
+        return Err(ErDiagramError::NoData(
+            "Selected tables not found in cached data".to_string(),
+        ));
+    }
+    Ok((tables, total))
+}
+
 async fn handle_extract_fk_neighbors(
     action_tx: &mpsc::Sender<Action>,
     completion_engine: &RefCell<CompletionEngine>,
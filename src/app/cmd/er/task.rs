@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use tokio::sync::mpsc;
 
-use crate::domain::ErTableInfo;
+use crate::domain::{ErDiagramOptions, ErTableInfo};
 use crate::ports::outbound::ErDiagramExporter;
 use crate::update::action::{Action, ErDiagramError, ErDiagramInfo};
 
@@ -15,11 +15,18 @@ pub fn spawn_er_diagram_task(
     tx: mpsc::Sender<Action>,
     filename: String,
     browser: Option<String>,
+    options: ErDiagramOptions,
 ) {
     let table_count = tables.len();
     tokio::spawn(async move {
         let result = tokio::task::spawn_blocking(move || {
-            exporter.generate_and_export(&tables, &filename, &cache_dir, browser.as_deref())
+            exporter.generate_and_export(
+                &tables,
+                &options,
+                &filename,
+                &cache_dir,
+                browser.as_deref(),
+            )
         })
         .await;
 
@@ -69,6 +76,7 @@ mod tests {
             fn generate_and_export(
                 &self,
                 _tables: &[ErTableInfo],
+                _options: &ErDiagramOptions,
                 _filename: &str,
                 _cache_dir: &Path,
                 _browser: Option<&str>,
@@ -82,6 +90,7 @@ mod tests {
             fn generate_and_export(
                 &self,
                 _tables: &[ErTableInfo],
+                _options: &ErDiagramOptions,
                 _filename: &str,
                 _cache_dir: &Path,
                 _browser: Option<&str>,
@@ -95,6 +104,7 @@ mod tests {
             fn generate_and_export(
                 &self,
                 _tables: &[ErTableInfo],
+                _options: &ErDiagramOptions,
                 _filename: &str,
                 _cache_dir: &Path,
                 _browser: Option<&str>,
@@ -127,6 +137,7 @@ mod tests {
                 tx,
                 "er_full.dot".to_string(),
                 None,
+                ErDiagramOptions::default(),
             );
 
             let action = receive_action(&mut rx).await;
@@ -158,6 +169,7 @@ mod tests {
                 tx,
                 "er_full.dot".to_string(),
                 None,
+                ErDiagramOptions::default(),
             );
 
             let action = receive_action(&mut rx).await;
@@ -183,6 +195,7 @@ mod tests {
                 tx,
                 "er_full.dot".to_string(),
                 None,
+                ErDiagramOptions::default(),
             );
 
             let action = receive_action(&mut rx).await;
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::domain::IndexUsage;
+use crate::ports::outbound::{AccessMode, QueryExecutor};
+use crate::update::action::Action;
+
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    executor: &Arc<dyn QueryExecutor>,
+    fetch_tasks: &BackgroundTasks,
+) {
+    let Effect::FetchIndexUsage {
+        dsn,
+        run_id,
+        query,
+        sort,
+    } = effect
+    else {
+        unreachable!("index_usage::run called with non-index-usage effect");
+    };
+    let executor = Arc::clone(executor);
+    let tx = action_tx.clone();
+    fetch_tasks.spawn(async move {
+        let action = match executor
+            .execute_adhoc(&dsn, &query, AccessMode::ReadOnly)
+            .await
+        {
+            Ok(result) => Action::IndexUsageLoaded {
+                dsn,
+                run_id,
+                sort,
+                indexes: IndexUsage::list_from_result(&result),
+            },
+            Err(error) => Action::IndexUsageFailed { dsn, run_id, error },
+        };
+        tx.send(action).await.ok();
+    });
+}
@@ -0,0 +1,125 @@
+use crate::ports::inbound::{InputEvent, KeyCombo};
+use crate::update::action::Action;
+
+/// Most repeats of one navigation key applied before the next frame is drawn.
+pub const MAX_REPEATS_PER_FRAME: usize = 8;
+
+/// Caps held-key floods within one frame.
+///
+/// Key repeat sends an event per tick, so once drawing falls behind the same
+/// `j`/`k` piles up in the input channel. Every press still moves the cursor,
+/// so quick taps and pasted keys all count, but a run of the same navigation
+/// key beyond [`MAX_REPEATS_PER_FRAME`] is dropped until the frame is drawn.
+/// That keeps held-key scrolling in step with the frame rate and stops it
+/// soon after the key is released.
+#[derive(Debug, Default)]
+pub struct KeyRepeatFilter {
+    last: Option<KeyCombo>,
+    run: usize,
+}
+
+impl KeyRepeatFilter {
+    /// `false` once `event` repeats the same navigation key more than
+    /// [`MAX_REPEATS_PER_FRAME`] times this frame.
+    pub fn admit(&mut self, event: &InputEvent, action: &Action) -> bool {
+        let combo = match event {
+            InputEvent::Key(combo) if action.is_navigation() => *combo,
+            _ => {
+                self.last = None;
+                return true;
+            }
+        };
+        if self.last == Some(combo) {
+            self.run += 1;
+        } else {
+            self.last = Some(combo);
+            self.run = 1;
+        }
+        self.run <= MAX_REPEATS_PER_FRAME
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+    use crate::model::app_state::AppState;
+    use crate::ports::inbound::Key;
+    use crate::services::AppServices;
+    use crate::update::action::{InputTarget, ScrollAmount, ScrollDirection, ScrollTarget};
+    use crate::update::reducer::reduce;
+
+    fn key(c: char) -> InputEvent {
+        InputEvent::Key(KeyCombo::plain(Key::Char(c)))
+    }
+
+    fn scroll(direction: ScrollDirection) -> Action {
+        Action::Scroll {
+            target: ScrollTarget::Result,
+            direction,
+            amount: ScrollAmount::Line,
+        }
+    }
+
+    #[test]
+    fn repeated_navigation_is_capped_per_frame() {
+        let mut filter = KeyRepeatFilter::default();
+        let down = scroll(ScrollDirection::Down);
+
+        let admitted = (0..MAX_REPEATS_PER_FRAME + 3)
+            .filter(|_| filter.admit(&key('j'), &down))
+            .count();
+
+        assert_eq!(admitted, MAX_REPEATS_PER_FRAME);
+    }
+
+    #[test]
+    fn three_presses_in_one_burst_move_three_rows() {
+        let mut state = AppState::new("test".to_string());
+        state.ui.set_result_pane_height(30);
+        let rows = (0..50).map(|i| vec![i.to_string()]).collect();
+        state
+            .query
+            .set_current_result(Arc::new(QueryResult::success(
+                String::new(),
+                vec!["id".to_string()],
+                rows,
+                1,
+                QuerySource::Preview,
+            )));
+        let mut filter = KeyRepeatFilter::default();
+
+        for _ in 0..3 {
+            let down = scroll(ScrollDirection::Down);
+            if filter.admit(&key('j'), &down) {
+                reduce(&mut state, down, Instant::now(), &AppServices::stub());
+            }
+        }
+
+        assert_eq!(state.result_interaction.scroll_offset(), 3);
+    }
+
+    #[test]
+    fn a_different_key_ends_the_run() {
+        let mut filter = KeyRepeatFilter::default();
+
+        assert!(filter.admit(&key('j'), &scroll(ScrollDirection::Down)));
+        assert!(filter.admit(&key('k'), &scroll(ScrollDirection::Up)));
+        assert!(filter.admit(&key('j'), &scroll(ScrollDirection::Down)));
+    }
+
+    #[test]
+    fn typed_text_is_never_dropped() {
+        let mut filter = KeyRepeatFilter::default();
+        let input = Action::TextInput {
+            target: InputTarget::SqlModal,
+            ch: 'l',
+        };
+
+        assert!(filter.admit(&key('l'), &input));
+        assert!(filter.admit(&key('l'), &input));
+    }
+}
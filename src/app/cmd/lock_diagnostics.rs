@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::domain::LockBlocker;
+use crate::ports::outbound::{AccessMode, QueryExecutor};
+use crate::update::action::Action;
+
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    executor: &Arc<dyn QueryExecutor>,
+    fetch_tasks: &BackgroundTasks,
+) {
+    let Effect::FetchLockBlockers { dsn, run_id, query } = effect else {
+        unreachable!("lock_diagnostics::run called with non-lock-diagnostics effect");
+    };
+    let executor = Arc::clone(executor);
+    let tx = action_tx.clone();
+    fetch_tasks.spawn(async move {
+        // Best effort: the original lock error is already on screen, so a
+        // failed lookup (e.g. no pg_stat_activity access) stays silent.
+        let Ok(result) = executor
+            .execute_adhoc(&dsn, &query, AccessMode::ReadOnly)
+            .await
+        else {
+            return;
+        };
+        tx.send(Action::LockBlockersLoaded {
+            dsn,
+            run_id,
+            blockers: LockBlocker::list_from_result(&result),
+        })
+        .await
+        .ok();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+    use crate::ports::outbound::query_executor::MockQueryExecutor;
+
+    #[tokio::test]
+    async fn blocker_rows_are_sent_back() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut executor = MockQueryExecutor::new();
+        executor
+            .expect_execute_adhoc()
+            .withf(|_, _, mode| *mode == AccessMode::ReadOnly)
+            .returning(|_, query, _| {
+                Ok(QueryResult::success(
+                    query.to_string(),
+                    vec![
+                        "pid".to_string(),
+                        "application_name".to_string(),
+                        "usename".to_string(),
+                        "state".to_string(),
+                        "held_secs".to_string(),
+                        "query".to_string(),
+                    ],
+                    vec![vec![
+                        "4123".to_string(),
+                        "worker".to_string(),
+                        "app".to_string(),
+                        "idle in transaction".to_string(),
+                        "42".to_string(),
+                        "UPDATE accounts SET balance = 0".to_string(),
+                    ]],
+                    2,
+                    QuerySource::Adhoc,
+                ))
+            });
+        let executor = Arc::new(executor) as Arc<dyn QueryExecutor>;
+
+        run(
+            Effect::FetchLockBlockers {
+                dsn: "postgres://localhost/app".to_string(),
+                run_id: 3,
+                query: "SELECT 1".to_string(),
+            },
+            &tx,
+            &executor,
+            &BackgroundTasks::default(),
+        );
+
+        let Action::LockBlockersLoaded {
+            run_id, blockers, ..
+        } = rx.recv().await.unwrap()
+        else {
+            panic!("expected LockBlockersLoaded");
+        };
+        assert_eq!(run_id, 3);
+        assert_eq!(blockers[0].pid, 4123);
+        assert_eq!(blockers[0].held_secs, Some(42));
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::model::browse::maintenance::MaintenanceProgress;
+use crate::ports::outbound::{AccessMode, QueryExecutor};
+use crate::update::action::Action;
+
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// Runs beside the query task slot so browsing and ad-hoc queries stay usable
+// while VACUUM works through a large table.
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    executor: &Arc<dyn QueryExecutor>,
+    fetch_tasks: &BackgroundTasks,
+) {
+    match effect {
+        Effect::RunMaintenance {
+            dsn,
+            run_id,
+            queries,
+        } => {
+            let executor = Arc::clone(executor);
+            let tx = action_tx.clone();
+            fetch_tasks.spawn(async move {
+                let mut execution_time_ms = 0;
+                for query in &queries {
+                    match executor
+                        .execute_adhoc(&dsn, query, AccessMode::ReadWrite)
+                        .await
+                    {
+                        Ok(result) => execution_time_ms += result.execution_time_ms,
+                        Err(error) => {
+                            tx.send(Action::MaintenanceFailed { run_id, error })
+                                .await
+                                .ok();
+                            return;
+                        }
+                    }
+                }
+                tx.send(Action::MaintenanceCompleted {
+                    run_id,
+                    execution_time_ms,
+                })
+                .await
+                .ok();
+            });
+        }
+        Effect::PollMaintenanceProgress { dsn, run_id, query } => {
+            let executor = Arc::clone(executor);
+            let tx = action_tx.clone();
+            fetch_tasks.spawn(async move {
+                tokio::time::sleep(PROGRESS_POLL_INTERVAL).await;
+                // A failed reading only costs one panel update.
+                let progress = executor
+                    .execute_adhoc(&dsn, &query, AccessMode::ReadOnly)
+                    .await
+                    .ok()
+                    .and_then(|result| MaintenanceProgress::from_result(&result));
+                tx.send(Action::MaintenanceProgressLoaded { run_id, progress })
+                    .await
+                    .ok();
+            });
+        }
+        _ => unreachable!("maintenance::run called with non-maintenance effect"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+    use crate::ports::outbound::DbOperationError;
+    use crate::ports::outbound::query_executor::MockQueryExecutor;
+
+    #[tokio::test]
+    async fn completion_reports_execution_time() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut executor = MockQueryExecutor::new();
+        executor
+            .expect_execute_adhoc()
+            .withf(|_, query, mode| query == "ANALYZE t" && *mode == AccessMode::ReadWrite)
+            .returning(|_, query, _| {
+                Ok(QueryResult::success(
+                    query.to_string(),
+                    vec![],
+                    vec![],
+                    42,
+                    QuerySource::Adhoc,
+                ))
+            });
+        let executor = Arc::new(executor) as Arc<dyn QueryExecutor>;
+
+        run(
+            Effect::RunMaintenance {
+                dsn: "postgres://localhost/app".to_string(),
+                run_id: 3,
+                queries: vec!["ANALYZE t".to_string()],
+            },
+            &tx,
+            &executor,
+            &BackgroundTasks::default(),
+        );
+
+        assert!(matches!(
+            rx.recv().await.unwrap(),
+            Action::MaintenanceCompleted {
+                run_id: 3,
+                execution_time_ms: 42
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn failed_statement_stops_the_sequence() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut executor = MockQueryExecutor::new();
+        executor
+            .expect_execute_adhoc()
+            .withf(|_, query, _| query == "CREATE INDEX CONCURRENTLY i_rebuild")
+            .times(1)
+            .returning(|_, _, _| Err(DbOperationError::QueryFailed("duplicate key".to_string())));
+        let executor = Arc::new(executor) as Arc<dyn QueryExecutor>;
+
+        run(
+            Effect::RunMaintenance {
+                dsn: "postgres://localhost/app".to_string(),
+                run_id: 4,
+                queries: vec![
+                    "CREATE INDEX CONCURRENTLY i_rebuild".to_string(),
+                    "DROP INDEX CONCURRENTLY i".to_string(),
+                ],
+            },
+            &tx,
+            &executor,
+            &BackgroundTasks::default(),
+        );
+
+        assert!(matches!(
+            rx.recv().await.unwrap(),
+            Action::MaintenanceFailed { run_id: 4, .. }
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_progress_reading_yields_no_progress() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut executor = MockQueryExecutor::new();
+        executor
+            .expect_execute_adhoc()
+            .returning(|_, _, _| Err(DbOperationError::QueryFailed("relation".to_string())));
+        let executor = Arc::new(executor) as Arc<dyn QueryExecutor>;
+
+        run(
+            Effect::PollMaintenanceProgress {
+                dsn: "postgres://localhost/app".to_string(),
+                run_id: 3,
+                query: "SELECT phase".to_string(),
+            },
+            &tx,
+            &executor,
+            &BackgroundTasks::default(),
+        );
+
+        assert!(matches!(
+            rx.recv().await.unwrap(),
+            Action::MaintenanceProgressLoaded {
+                run_id: 3,
+                progress: None
+            }
+        ));
+    }
+}
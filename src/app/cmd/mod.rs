@@ -1,15 +1,33 @@
+pub mod action_lanes;
+mod audit_log;
+mod background_tasks;
 pub mod browse;
 pub mod cache;
+mod cache_dir;
 pub mod cli_sqlite;
+mod column_distribution;
 pub mod completion_engine;
 pub mod connection;
+mod connection_pool;
+pub mod demo;
 pub mod effect;
+mod effect_budget;
 pub mod er;
+mod index_usage;
+pub mod key_repeat;
+mod lock_diagnostics;
+mod maintenance;
+mod query_hooks;
 mod query_task;
+mod release_check;
 pub mod render_schedule;
+mod result_types;
 pub mod runner;
+mod server_settings;
 pub mod settings;
+mod snippets;
 pub mod sql_editor;
+mod sql_files;
 pub mod sqlite_diagnostics;
 pub mod sqlite_path_validate;
 #[cfg(test)]
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::ports::outbound::{HookInvocation, QueryHookRunner};
+use crate::update::action::Action;
+
+// Tracked with pending writes so a notification for the last query still
+// goes out when the user quits right after it finishes.
+pub fn run(
+    invocations: Vec<HookInvocation>,
+    action_tx: &mpsc::Sender<Action>,
+    runner: &Arc<dyn QueryHookRunner>,
+    pending_writes: &BackgroundTasks,
+) {
+    for invocation in invocations {
+        let runner = Arc::clone(runner);
+        let tx = action_tx.clone();
+        pending_writes.spawn(async move {
+            if let Err(error) = runner.run(&invocation).await {
+                tx.send(Action::QueryHookFailed(error)).await.ok();
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::ports::outbound::{HookTarget, QueryHookError};
+
+    #[derive(Default)]
+    struct RecordingRunner {
+        payloads: Mutex<Vec<String>>,
+        fail: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl QueryHookRunner for RecordingRunner {
+        async fn run(&self, invocation: &HookInvocation) -> Result<(), QueryHookError> {
+            self.payloads
+                .lock()
+                .unwrap()
+                .push(invocation.payload.clone());
+            if self.fail {
+                return Err(QueryHookError::Execution("exit status 1".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    fn invocation(payload: &str) -> HookInvocation {
+        HookInvocation {
+            target: HookTarget::Command("notify".to_string()),
+            payload: payload.to_string(),
+            env: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn runs_every_invocation() {
+        let (tx, mut rx) = mpsc::channel(8);
+        let recorder = Arc::new(RecordingRunner::default());
+        let runner: Arc<dyn QueryHookRunner> = recorder.clone();
+        let pending_writes = BackgroundTasks::default();
+
+        run(
+            vec![invocation("a"), invocation("b")],
+            &tx,
+            &runner,
+            &pending_writes,
+        );
+        assert!(pending_writes.drain(Duration::from_secs(1)).await);
+
+        let mut payloads = recorder.payloads.lock().unwrap().clone();
+        payloads.sort();
+        assert_eq!(payloads, vec!["a", "b"]);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn failure_is_reported() {
+        let (tx, mut rx) = mpsc::channel(8);
+        let runner: Arc<dyn QueryHookRunner> = Arc::new(RecordingRunner {
+            fail: true,
+            ..RecordingRunner::default()
+        });
+        let pending_writes = BackgroundTasks::default();
+
+        run(vec![invocation("a")], &tx, &runner, &pending_writes);
+        assert!(pending_writes.drain(Duration::from_secs(1)).await);
+
+        assert!(matches!(rx.try_recv(), Ok(Action::QueryHookFailed(_))));
+    }
+}
@@ -1,13 +1,35 @@
 use std::sync::Mutex;
 
-use tokio::task::AbortHandle;
+use tokio::task::JoinHandle;
+
+use crate::cmd::effect_budget::{EffectBudget, EffectClass};
 
 #[derive(Default)]
 pub struct QueryTaskRegistry {
-    active: Mutex<Option<AbortHandle>>,
+    active: Mutex<Option<JoinHandle<()>>>,
+    budget: EffectBudget,
 }
 
 impl QueryTaskRegistry {
+    pub fn with_budget(budget: EffectBudget) -> Self {
+        Self {
+            active: Mutex::default(),
+            budget,
+        }
+    }
+
+    /// Like `spawn`, but the task waits for a `class` slot before running.
+    pub fn spawn_budgeted<F>(&self, class: EffectClass, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let budget = self.budget.clone();
+        self.spawn(async move {
+            let _slot = budget.acquire(class).await;
+            task.await;
+        });
+    }
+
     /// Starts a task after cancelling any currently active query task.
     ///
     /// The registry is shared by query effects, so it intentionally permits
@@ -21,19 +43,30 @@ impl QueryTaskRegistry {
         *self
             .active
             .lock()
-            .expect("query task registry lock poisoned") = Some(handle.abort_handle());
+            .expect("query task registry lock poisoned") = Some(handle);
     }
 
     pub fn cancel(&self) {
-        let handle = self
-            .active
-            .lock()
-            .expect("query task registry lock poisoned")
-            .take();
-        if let Some(handle) = handle {
+        if let Some(handle) = self.take() {
             handle.abort();
         }
     }
+
+    /// Like `cancel`, but returns only once the task has been dropped so its
+    /// `kill_on_drop` child process is already killed.
+    pub async fn cancel_and_wait(&self) {
+        if let Some(handle) = self.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+    }
+
+    fn take(&self) -> Option<JoinHandle<()>> {
+        self.active
+            .lock()
+            .expect("query task registry lock poisoned")
+            .take()
+    }
 }
 
 #[cfg(test)]
@@ -77,4 +110,23 @@ mod tests {
         .await
         .expect("cancelled query task should be dropped");
     }
+
+    #[tokio::test]
+    async fn cancel_and_wait_returns_after_task_is_dropped() {
+        let registry = QueryTaskRegistry::default();
+        let dropped = Arc::new(AtomicBool::new(false));
+        let (started_tx, started_rx) = oneshot::channel();
+        let guard = DropSignal(Arc::clone(&dropped));
+
+        registry.spawn(async move {
+            let _guard = guard;
+            started_tx.send(()).ok();
+            std::future::pending::<()>().await;
+        });
+
+        started_rx.await.expect("query task should start");
+        registry.cancel_and_wait().await;
+
+        assert!(dropped.load(Ordering::SeqCst));
+    }
 }
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::ports::outbound::ReleaseChecker;
+use crate::update::action::Action;
+
+// Failures stay silent: an offline machine must not nag on every launch.
+pub fn run(
+    action_tx: &mpsc::Sender<Action>,
+    checker: &Arc<dyn ReleaseChecker>,
+    fetch_tasks: &BackgroundTasks,
+) {
+    let tx = action_tx.clone();
+    let checker = Arc::clone(checker);
+    fetch_tasks.spawn(async move {
+        if let Ok(release) = checker.latest_release().await {
+            tx.send(Action::LatestReleaseLoaded(release)).await.ok();
+        }
+    });
+}
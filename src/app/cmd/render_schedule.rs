@@ -8,7 +8,15 @@ const SPINNER_INTERVAL: Duration = Duration::from_millis(150);
 
 const CURSOR_BLINK_INTERVAL: Duration = Duration::from_millis(500);
 
-pub fn next_animation_deadline(state: &AppState, now: Instant) -> Option<Instant> {
+// Without input for this long the editor is idle and stops ticking until the
+// next event; spinners and timed messages keep their own deadlines.
+const IDLE_AFTER: Duration = Duration::from_secs(10);
+
+pub fn next_animation_deadline(
+    state: &AppState,
+    now: Instant,
+    last_input: Instant,
+) -> Option<Instant> {
     let mut earliest: Option<Instant> = None;
 
     if has_active_spinner(state) {
@@ -33,8 +41,16 @@ pub fn next_animation_deadline(state: &AppState, now: Instant) -> Option<Instant
 
     earliest = min_instant(earliest, state.flash_timers.earliest_deadline());
 
+    earliest = min_instant(
+        earliest,
+        state.result_interaction.cell_peek().reveal_deadline(now),
+    );
+
     // Cursor blink is the slowest; skip if faster timers are active
-    if has_blinking_cursor(state) && earliest.is_none() {
+    if has_blinking_cursor(state)
+        && earliest.is_none()
+        && now.saturating_duration_since(last_input) < IDLE_AFTER
+    {
         earliest = Some(now + CURSOR_BLINK_INTERVAL);
     }
 
@@ -42,7 +58,9 @@ pub fn next_animation_deadline(state: &AppState, now: Instant) -> Option<Instant
 }
 
 fn has_active_spinner(state: &AppState) -> bool {
-    state.query.is_running() || state.er_preparation.status() == ErStatus::Waiting
+    state.query.is_running()
+        || state.maintenance.is_running()
+        || state.er_preparation.status() == ErStatus::Waiting
 }
 
 fn has_blinking_cursor(state: &AppState) -> bool {
@@ -81,7 +99,7 @@ mod tests {
             let state = create_test_state();
             let now = Instant::now();
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert!(deadline.is_none());
         }
@@ -92,7 +110,7 @@ mod tests {
             let now = Instant::now();
             let _ = state.query.begin_running(now);
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert!(deadline.is_some());
             let expected = now + SPINNER_INTERVAL;
@@ -105,7 +123,7 @@ mod tests {
             let _ = state.er_preparation.start_waiting_run();
             let now = Instant::now();
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert!(deadline.is_some());
             let expected = now + SPINNER_INTERVAL;
@@ -122,7 +140,7 @@ mod tests {
                 expires_at.checked_sub(MESSAGE_TIMEOUT).unwrap(),
             );
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert_eq!(deadline, Some(expires_at));
         }
@@ -134,7 +152,7 @@ mod tests {
             let highlight_until = now + Duration::from_millis(500);
             state.query.set_result_highlight(highlight_until);
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert_eq!(deadline, Some(highlight_until));
         }
@@ -145,7 +163,7 @@ mod tests {
             state.modal.set_mode(InputMode::SqlModal);
             let now = Instant::now();
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert!(deadline.is_some());
             let expected = now + CURSOR_BLINK_INTERVAL;
@@ -158,7 +176,7 @@ mod tests {
             state.modal.set_mode(InputMode::TablePicker);
             let now = Instant::now();
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert!(deadline.is_some());
             let expected = now + CURSOR_BLINK_INTERVAL;
@@ -171,13 +189,38 @@ mod tests {
             state.modal.set_mode(InputMode::CommandLine);
             let now = Instant::now();
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert!(deadline.is_some());
             let expected = now + CURSOR_BLINK_INTERVAL;
             assert_eq!(deadline.unwrap(), expected);
         }
 
+        #[test]
+        fn idle_editor_stops_cursor_blink() {
+            let mut state = create_test_state();
+            state.modal.set_mode(InputMode::SqlModal);
+            let last_input = Instant::now();
+            let now = last_input + IDLE_AFTER;
+
+            let deadline = next_animation_deadline(&state, now, last_input);
+
+            assert!(deadline.is_none());
+        }
+
+        #[test]
+        fn idle_editor_keeps_spinner_running() {
+            let mut state = create_test_state();
+            state.modal.set_mode(InputMode::SqlModal);
+            let last_input = Instant::now();
+            let now = last_input + IDLE_AFTER;
+            let _ = state.query.begin_running(now);
+
+            let deadline = next_animation_deadline(&state, now, last_input);
+
+            assert_eq!(deadline, Some(now + SPINNER_INTERVAL));
+        }
+
         #[test]
         fn spinner_takes_priority_over_cursor_blink() {
             let mut state = create_test_state();
@@ -185,7 +228,7 @@ mod tests {
             let now = Instant::now();
             let _ = state.query.begin_running(now);
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             // Spinner interval (150ms) is shorter than cursor blink (500ms)
             let expected = now + SPINNER_INTERVAL;
@@ -204,7 +247,7 @@ mod tests {
                 expires_at.checked_sub(MESSAGE_TIMEOUT).unwrap(),
             );
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert_eq!(deadline, Some(expires_at));
         }
@@ -224,7 +267,7 @@ mod tests {
                 .query
                 .set_result_highlight(now + Duration::from_millis(100));
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             // Result highlight (100ms) < Spinner (150ms) < Message (2000ms)
             assert_eq!(deadline, Some(now + Duration::from_millis(100)));
@@ -237,7 +280,7 @@ mod tests {
             let debounce_until = now + Duration::from_millis(100);
             state.sql_modal.schedule_completion(debounce_until);
 
-            let deadline = next_animation_deadline(&state, now);
+            let deadline = next_animation_deadline(&state, now, now);
 
             assert_eq!(deadline, Some(debounce_until));
         }
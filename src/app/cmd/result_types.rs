@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::ports::outbound::{AccessMode, QueryExecutor};
+use crate::update::action::Action;
+
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    executor: &Arc<dyn QueryExecutor>,
+    fetch_tasks: &BackgroundTasks,
+) {
+    let Effect::FetchResultColumnTypes { dsn, run_id, query } = effect else {
+        unreachable!("result_types::run called with non-result-types effect");
+    };
+    let executor = Arc::clone(executor);
+    let tx = action_tx.clone();
+    fetch_tasks.spawn(async move {
+        // Best effort: servers that cannot describe the statement (older
+        // versions, non-preparable SQL) just leave the type row blank.
+        let Ok(result) = executor
+            .execute_adhoc(&dsn, &query, AccessMode::ReadOnly)
+            .await
+        else {
+            return;
+        };
+        let types = (0..result.data_row_count())
+            .map(|row| result.display_value_at(row, 0).unwrap_or_default())
+            .collect();
+        tx.send(Action::ResultColumnTypesLoaded { dsn, run_id, types })
+            .await
+            .ok();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+    use crate::ports::outbound::query_executor::MockQueryExecutor;
+
+    #[tokio::test]
+    async fn type_rows_are_sent_back_in_order() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut executor = MockQueryExecutor::new();
+        executor
+            .expect_execute_adhoc()
+            .withf(|_, _, mode| *mode == AccessMode::ReadOnly)
+            .returning(|_, query, _| {
+                Ok(QueryResult::success(
+                    query.to_string(),
+                    vec!["data_type".to_string()],
+                    vec![vec!["integer".to_string()], vec!["text".to_string()]],
+                    1,
+                    QuerySource::Adhoc,
+                ))
+            });
+        let executor = Arc::new(executor) as Arc<dyn QueryExecutor>;
+
+        run(
+            Effect::FetchResultColumnTypes {
+                dsn: "postgres://localhost/app".to_string(),
+                run_id: 2,
+                query: "SELECT 1".to_string(),
+            },
+            &tx,
+            &executor,
+            &BackgroundTasks::default(),
+        );
+
+        let Action::ResultColumnTypesLoaded { run_id, types, .. } = rx.recv().await.unwrap() else {
+            panic!("expected ResultColumnTypesLoaded");
+        };
+        assert_eq!(run_id, 2);
+        assert_eq!(types, vec!["integer", "text"]);
+    }
+}
@@ -3,30 +3,47 @@
 
 use std::cell::RefCell;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use color_eyre::eyre::Result;
 use tokio::sync::mpsc;
 
+use crate::cmd::audit_log as cmd_audit_log;
+use crate::cmd::background_tasks::BackgroundTasks;
 use crate::cmd::browse as cmd_browse;
 use crate::cmd::cache::TtlCache;
+use crate::cmd::cache_dir as cmd_cache_dir;
+use crate::cmd::column_distribution as cmd_column_distribution;
 use crate::cmd::completion_engine::CompletionEngine;
 use crate::cmd::connection as cmd_connection;
+use crate::cmd::connection_pool as cmd_connection_pool;
 use crate::cmd::effect::Effect;
+use crate::cmd::effect_budget::EffectBudget;
 use crate::cmd::er::handler as cmd_er;
+use crate::cmd::index_usage as cmd_index_usage;
+use crate::cmd::lock_diagnostics as cmd_lock_diagnostics;
+use crate::cmd::maintenance as cmd_maintenance;
+use crate::cmd::query_hooks as cmd_query_hooks;
 use crate::cmd::query_task::QueryTaskRegistry;
+use crate::cmd::release_check as cmd_release_check;
+use crate::cmd::result_types as cmd_result_types;
+use crate::cmd::server_settings as cmd_server_settings;
 use crate::cmd::settings as cmd_settings;
+use crate::cmd::snippets as cmd_snippets;
 use crate::cmd::sql_editor::completion as cmd_completion;
 use crate::cmd::sql_editor::query_history as cmd_query_history;
+use crate::cmd::sql_files as cmd_sql_files;
 use crate::cmd::sqlite_diagnostics;
 use crate::cmd::utility as cmd_utility;
 use crate::domain::DatabaseMetadata;
 use crate::model::app_state::AppState;
 use crate::ports::outbound::{
-    CachedResultExporter, ClipboardWriter, ConfigWriter, ConnectionStore, DsnBuilder,
-    ErDiagramExporter, ErLogWriter, FolderOpener, MetadataProvider, PgServiceEntryReader,
-    QueryExecutor, QueryHistoryStore, Renderer, SettingsStore, SqliteDiagnosticsProvider,
-    SqlitePathValidator,
+    AuditLogStore, CacheStore, CachedResultExporter, ClipboardWriter, ConfigWriter,
+    ConnectionCandidateScanner, ConnectionPool, ConnectionStore, ContainerDatabaseScanner,
+    DsnBuilder, ErDiagramExporter, ErLogWriter, FolderOpener, MetadataProvider,
+    MetadataSnapshotStore, PgServiceEntryReader, QueryExecutor, QueryHistoryStore, QueryHookRunner,
+    ReleaseChecker, Renderer, SettingsStore, SnippetStore, SqlFileScanner, SqlFileStore,
+    SqliteDiagnosticsProvider, SqlitePathValidator, TempFileWriter,
 };
 use crate::services::AppServices;
 use crate::update::action::Action;
@@ -35,12 +52,18 @@ pub struct ConnectionDeps {
     pub dsn_builder: Arc<dyn DsnBuilder>,
     pub connection_store: Arc<dyn ConnectionStore>,
     pub pg_service_entry_reader: Option<Arc<dyn PgServiceEntryReader>>,
+    pub connection_candidate_scanner: Arc<dyn ConnectionCandidateScanner>,
+    pub container_database_scanner: Arc<dyn ContainerDatabaseScanner>,
     pub sqlite_path_validator: Arc<dyn SqlitePathValidator>,
+    pub metadata_snapshot_store: Arc<dyn MetadataSnapshotStore>,
 }
 
 pub struct QueryDeps {
     pub query_executor: Arc<dyn QueryExecutor>,
+    /// `None` for adapters that open a connection per query.
+    pub connection_pool: Option<Arc<dyn ConnectionPool>>,
     pub query_history_store: Arc<dyn QueryHistoryStore>,
+    pub audit_log_store: Arc<dyn AuditLogStore>,
     pub sqlite_diagnostics: Arc<dyn SqliteDiagnosticsProvider>,
     pub cached_result_exporter: Arc<dyn CachedResultExporter>,
 }
@@ -54,6 +77,13 @@ pub struct ErDeps {
 pub struct UtilityDeps {
     pub clipboard: Arc<dyn ClipboardWriter>,
     pub folder_opener: Arc<dyn FolderOpener>,
+    pub temp_file_writer: Arc<dyn TempFileWriter>,
+    pub query_hook_runner: Arc<dyn QueryHookRunner>,
+    pub sql_file_scanner: Arc<dyn SqlFileScanner>,
+    pub sql_file_store: Arc<dyn SqlFileStore>,
+    pub snippet_store: Arc<dyn SnippetStore>,
+    pub cache_store: Arc<dyn CacheStore>,
+    pub release_checker: Arc<dyn ReleaseChecker>,
 }
 
 pub struct SettingsDeps {
@@ -70,8 +100,17 @@ pub struct EffectRunner {
     metadata_cache: TtlCache<String, Arc<DatabaseMetadata>>,
     action_tx: mpsc::Sender<Action>,
     query_tasks: QueryTaskRegistry,
+    fetch_tasks: BackgroundTasks,
+    pending_writes: BackgroundTasks,
+    budget: EffectBudget,
 }
 
+// Long enough for a history append; a hung disk must not block quitting.
+const WRITE_FLUSH_TIMEOUT: Duration = Duration::from_secs(2);
+// Each close is one Terminate message; an unreachable server must not hold
+// up quitting.
+const POOL_DRAIN_TIMEOUT: Duration = Duration::from_secs(1);
+
 impl EffectRunner {
     pub fn new(
         metadata_provider: Arc<dyn MetadataProvider>,
@@ -83,6 +122,7 @@ impl EffectRunner {
         metadata_cache: TtlCache<String, Arc<DatabaseMetadata>>,
         action_tx: mpsc::Sender<Action>,
     ) -> Self {
+        let budget = EffectBudget::default();
         Self {
             metadata_provider,
             connection,
@@ -92,7 +132,10 @@ impl EffectRunner {
             settings,
             metadata_cache,
             action_tx,
-            query_tasks: QueryTaskRegistry::default(),
+            query_tasks: QueryTaskRegistry::with_budget(budget.clone()),
+            fetch_tasks: BackgroundTasks::with_budget(budget.clone()),
+            pending_writes: BackgroundTasks::default(),
+            budget,
         }
     }
 
@@ -100,6 +143,19 @@ impl EffectRunner {
         &self.action_tx
     }
 
+    /// Cancels in-flight queries and fetches, then waits (bounded) for
+    /// pending history writes and for pooled connections to close. Aborted
+    /// tasks drop their `psql`/`sqlite3` children, which `kill_on_drop` kills
+    /// and the runtime reaps.
+    pub async fn shutdown(&self) {
+        self.query_tasks.cancel_and_wait().await;
+        self.fetch_tasks.abort_all().await;
+        self.pending_writes.drain(WRITE_FLUSH_TIMEOUT).await;
+        if let Some(pool) = &self.query.connection_pool {
+            let _ = tokio::time::timeout(POOL_DRAIN_TIMEOUT, pool.drain()).await;
+        }
+    }
+
     pub async fn run<T: Renderer>(
         &self,
         effects: Vec<Effect>,
@@ -145,6 +201,8 @@ impl EffectRunner {
                     reason = "the effect runner is the runtime boundary that reads the clock for rendering"
                 )]
                 let now = Instant::now();
+                state.runtime.set_queued_effects(self.budget.queued_total());
+                state.sql_modal.refresh_editor_tokens();
                 let output = tui.draw(state, services, now)?;
                 state.apply_render_output(output);
                 Ok(vec![])
@@ -156,12 +214,15 @@ impl EffectRunner {
             }
             Effect::DispatchActions(actions) => Ok(actions),
 
-            e @ (Effect::CopyToClipboard { .. } | Effect::OpenFolder { .. }) => {
+            e @ (Effect::CopyToClipboard { .. }
+            | Effect::OpenFolder { .. }
+            | Effect::CopyViaTempFile { .. }) => {
                 cmd_utility::run(
                     e,
                     &self.action_tx,
                     &self.utility.clipboard,
                     &self.utility.folder_opener,
+                    &self.utility.temp_file_writer,
                 )
                 .await?;
                 Ok(vec![])
@@ -170,7 +231,10 @@ impl EffectRunner {
             e @ (Effect::SaveAndConnect { .. }
             | Effect::LoadConnectionForEdit { .. }
             | Effect::LoadConnections
+            | Effect::ScanConnectionCandidates { .. }
+            | Effect::ScanContainerDatabases
             | Effect::DeleteConnection { .. }
+            | Effect::SaveSessionSettings { .. }
             | Effect::SwitchConnection { .. }
             | Effect::SwitchToService { .. }) => {
                 cmd_connection::run(
@@ -189,6 +253,7 @@ impl EffectRunner {
             | Effect::FetchEffectiveUser { .. }
             | Effect::FetchTableDetail { .. }
             | Effect::PrefetchTableDetail { .. }
+            | Effect::FetchBulkTableDetails { .. }
             | Effect::ProcessPrefetchQueue { .. }
             | Effect::DelayedProcessPrefetchQueue { .. }
             | Effect::CacheInvalidate { .. }) => {
@@ -198,6 +263,7 @@ impl EffectRunner {
                     &self.metadata_provider,
                     &self.metadata_cache,
                     &self.connection.sqlite_path_validator,
+                    &self.fetch_tasks,
                     state,
                     completion_engine,
                 )
@@ -208,8 +274,11 @@ impl EffectRunner {
             e @ (Effect::ExecutePreview { .. }
             | Effect::ExecuteAdhoc { .. }
             | Effect::ExecuteExplain { .. }
+            | Effect::ExplainPreview { .. }
             | Effect::ExecuteWrite { .. }
             | Effect::CancelActiveQuery
+            | Effect::BeginTransaction { .. }
+            | Effect::EndTransaction { .. }
             | Effect::CountRowsForExport { .. }
             | Effect::ExportCsv { .. }
             | Effect::ExportCsvFromCache { .. }) => {
@@ -220,6 +289,7 @@ impl EffectRunner {
                     &self.query.query_history_store,
                     &self.query.cached_result_exporter,
                     &self.query_tasks,
+                    &self.pending_writes,
                     state,
                 )
                 .await?;
@@ -244,11 +314,48 @@ impl EffectRunner {
                 Ok(vec![])
             }
 
+            e @ (Effect::SaveMetadataSnapshot { .. }
+            | Effect::SaveTableSnapshot { .. }
+            | Effect::LoadMetadataSnapshot { .. }) => {
+                cmd_browse::snapshot::run(
+                    e,
+                    &self.action_tx,
+                    &self.connection.metadata_snapshot_store,
+                    &self.fetch_tasks,
+                    &self.pending_writes,
+                );
+                Ok(vec![])
+            }
+
             e @ Effect::LoadQueryHistory { .. } => {
                 cmd_query_history::run(e, &self.action_tx, &self.query.query_history_store);
                 Ok(vec![])
             }
 
+            Effect::RunQueryHooks { invocations } => {
+                cmd_query_hooks::run(
+                    invocations,
+                    &self.action_tx,
+                    &self.utility.query_hook_runner,
+                    &self.pending_writes,
+                );
+                Ok(vec![])
+            }
+
+            Effect::AppendAuditLog {
+                project_name,
+                event,
+            } => {
+                cmd_audit_log::run(
+                    project_name,
+                    event,
+                    &self.action_tx,
+                    &self.query.audit_log_store,
+                    &self.pending_writes,
+                );
+                Ok(vec![])
+            }
+
             e @ Effect::SaveSettings { .. } => {
                 cmd_settings::run(e, &self.action_tx, &self.settings.settings_store).await;
                 Ok(vec![])
@@ -260,10 +367,127 @@ impl EffectRunner {
                 Ok(vec![])
             }
 
+            e @ (Effect::RunMaintenance { .. } | Effect::PollMaintenanceProgress { .. }) => {
+                cmd_maintenance::run(
+                    e,
+                    &self.action_tx,
+                    &self.query.query_executor,
+                    &self.fetch_tasks,
+                );
+                Ok(vec![])
+            }
+
+            e @ Effect::FetchServerSettings { .. } => {
+                cmd_server_settings::run(
+                    e,
+                    &self.action_tx,
+                    &self.query.query_executor,
+                    &self.fetch_tasks,
+                );
+                Ok(vec![])
+            }
+
+            e @ Effect::FetchColumnDistribution { .. } => {
+                cmd_column_distribution::run(
+                    e,
+                    &self.action_tx,
+                    &self.query.query_executor,
+                    &self.fetch_tasks,
+                );
+                Ok(vec![])
+            }
+
+            e @ Effect::FetchIndexUsage { .. } => {
+                cmd_index_usage::run(
+                    e,
+                    &self.action_tx,
+                    &self.query.query_executor,
+                    &self.fetch_tasks,
+                );
+                Ok(vec![])
+            }
+
+            e @ Effect::FetchLockBlockers { .. } => {
+                cmd_lock_diagnostics::run(
+                    e,
+                    &self.action_tx,
+                    &self.query.query_executor,
+                    &self.fetch_tasks,
+                );
+                Ok(vec![])
+            }
+
+            e @ Effect::FetchResultColumnTypes { .. } => {
+                cmd_result_types::run(
+                    e,
+                    &self.action_tx,
+                    &self.query.query_executor,
+                    &self.fetch_tasks,
+                );
+                Ok(vec![])
+            }
+
+            e @ (Effect::LoadSqlFiles { .. }
+            | Effect::OpenLinkedSqlFile { .. }
+            | Effect::PollLinkedSqlFile { .. }
+            | Effect::WriteLinkedSqlFile { .. }
+            | Effect::WriteSqlFileAs { .. }) => {
+                cmd_sql_files::run(
+                    e,
+                    &self.action_tx,
+                    &self.utility.sql_file_scanner,
+                    &self.utility.sql_file_store,
+                    &self.fetch_tasks,
+                    &self.pending_writes,
+                );
+                Ok(vec![])
+            }
+
+            e @ (Effect::LoadSnippets { .. } | Effect::SaveSnippet { .. }) => {
+                cmd_snippets::run(
+                    e,
+                    &self.action_tx,
+                    &self.utility.snippet_store,
+                    &self.fetch_tasks,
+                    &self.pending_writes,
+                );
+                Ok(vec![])
+            }
+
+            Effect::CheckLatestRelease => {
+                cmd_release_check::run(
+                    &self.action_tx,
+                    &self.utility.release_checker,
+                    &self.fetch_tasks,
+                );
+                Ok(vec![])
+            }
+
+            e @ (Effect::WarmUpConnectionPool { .. } | Effect::LoadServerInfo { .. }) => {
+                cmd_connection_pool::run(
+                    e,
+                    &self.action_tx,
+                    self.query.connection_pool.as_ref(),
+                    &self.fetch_tasks,
+                );
+                Ok(vec![])
+            }
+
+            e @ (Effect::LoadCacheUsage { .. } | Effect::ClearCache { .. }) => {
+                cmd_cache_dir::run(
+                    e,
+                    &self.action_tx,
+                    &self.utility.cache_store,
+                    &self.fetch_tasks,
+                );
+                Ok(vec![])
+            }
+
             e @ (Effect::CacheTableInCompletionEngine { .. }
             | Effect::EvictTablesFromCompletionCache { .. }
             | Effect::ClearCompletionEngineCache
             | Effect::ResizeCompletionCache { .. }
+            | Effect::SetCompletionSnippets { .. }
             | Effect::TriggerCompletion) => {
                 cmd_completion::run(e, &self.action_tx, state, completion_engine).await?;
                 Ok(vec![])
@@ -515,13 +739,15 @@ mod tests {
         use std::sync::atomic::{AtomicBool, Ordering};
 
         use tokio::sync::oneshot;
-        use tokio::time::{Duration, timeout};
+        use tokio::time::timeout;
 
         use super::*;
         use crate::domain::connection::{ConnectionId, DatabaseType};
         use crate::domain::{QueryResult, WriteExecutionResult};
         use crate::model::connection::cache::ConnectionCache;
-        use crate::ports::outbound::{AccessMode, DbOperationError};
+        use crate::ports::outbound::{
+            AccessMode, DbOperationError, PreviewOptions, TransactionEnd,
+        };
         use crate::update::action::ConnectionTarget;
         use crate::update::reducer::reduce;
 
@@ -545,6 +771,7 @@ mod tests {
                 _dsn: &str,
                 _schema: &str,
                 _table: &str,
+                _options: PreviewOptions,
                 _limit: usize,
                 _offset: usize,
             ) -> Result<QueryResult, DbOperationError> {
@@ -593,6 +820,22 @@ mod tests {
             ) -> Result<PathBuf, DbOperationError> {
                 unreachable!("test only starts a preview")
             }
+
+            async fn begin_transaction(
+                &self,
+                _dsn: &str,
+                _access_mode: AccessMode,
+            ) -> Result<(), DbOperationError> {
+                unreachable!("test only starts a preview")
+            }
+
+            async fn end_transaction(
+                &self,
+                _dsn: &str,
+                _end: TransactionEnd,
+            ) -> Result<(), DbOperationError> {
+                unreachable!("test only starts a preview")
+            }
         }
 
         #[tokio::test]
@@ -633,6 +876,9 @@ mod tests {
                         dsn: "postgres://localhost/current".to_string(),
                         schema: "public".to_string(),
                         table: "users".to_string(),
+                        sort_column: None,
+                        filter: None,
+                        columns: Vec::new(),
                         generation: 1,
                         run_id,
                         limit: 100,
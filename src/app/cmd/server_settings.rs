@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::domain::ServerSetting;
+use crate::ports::outbound::{AccessMode, QueryExecutor};
+use crate::update::action::Action;
+
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    executor: &Arc<dyn QueryExecutor>,
+    fetch_tasks: &BackgroundTasks,
+) {
+    let Effect::FetchServerSettings { dsn, run_id, query } = effect else {
+        unreachable!("server_settings::run called with non-server-settings effect");
+    };
+    let executor = Arc::clone(executor);
+    let tx = action_tx.clone();
+    fetch_tasks.spawn(async move {
+        let action = match executor
+            .execute_adhoc(&dsn, &query, AccessMode::ReadOnly)
+            .await
+        {
+            Ok(result) => Action::ServerSettingsLoaded {
+                dsn,
+                run_id,
+                settings: ServerSetting::list_from_result(&result),
+            },
+            Err(error) => Action::ServerSettingsFailed { dsn, run_id, error },
+        };
+        tx.send(action).await.ok();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+    use crate::ports::outbound::query_executor::MockQueryExecutor;
+
+    #[tokio::test]
+    async fn loaded_rows_become_server_settings() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut executor = MockQueryExecutor::new();
+        executor
+            .expect_execute_adhoc()
+            .withf(|_, _, mode| *mode == AccessMode::ReadOnly)
+            .returning(|_, query, _| {
+                Ok(QueryResult::success(
+                    query.to_string(),
+                    vec![
+                        "name".to_string(),
+                        "setting".to_string(),
+                        "unit".to_string(),
+                        "reset_val".to_string(),
+                        "boot_val".to_string(),
+                        "source".to_string(),
+                        "category".to_string(),
+                        "short_desc".to_string(),
+                        "pending_restart".to_string(),
+                    ],
+                    vec![vec![
+                        "shared_buffers".to_string(),
+                        "16384".to_string(),
+                        "8kB".to_string(),
+                        "16384".to_string(),
+                        "1024".to_string(),
+                        "configuration file".to_string(),
+                        "Resource Usage / Memory".to_string(),
+                        "Sets the number of shared memory buffers.".to_string(),
+                        "t".to_string(),
+                    ]],
+                    3,
+                    QuerySource::Adhoc,
+                ))
+            });
+        let executor = Arc::new(executor) as Arc<dyn QueryExecutor>;
+
+        run(
+            Effect::FetchServerSettings {
+                dsn: "postgres://localhost/app".to_string(),
+                run_id: 2,
+                query: "SELECT * FROM pg_settings".to_string(),
+            },
+            &tx,
+            &executor,
+            &BackgroundTasks::default(),
+        );
+
+        let Action::ServerSettingsLoaded {
+            run_id, settings, ..
+        } = rx.recv().await.unwrap()
+        else {
+            panic!("expected ServerSettingsLoaded");
+        };
+        assert_eq!(run_id, 2);
+        assert_eq!(settings.len(), 1);
+        assert_eq!(settings[0].unit.as_deref(), Some("8kB"));
+        assert_eq!(settings[0].current_display(), "128MB");
+        assert!(settings[0].pending_restart);
+    }
+}
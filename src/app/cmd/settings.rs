@@ -26,7 +26,7 @@ mod tests {
     use std::sync::{Arc, Mutex};
 
     use super::*;
-    use crate::model::shared::settings::KeymapPreset;
+    use crate::model::shared::settings::{KeymapPreset, PrefetchScope};
     use crate::model::shared::theme_id::ThemeId;
     use crate::ports::outbound::{AppSettings, SettingsStoreError};
 
@@ -72,6 +72,11 @@ mod tests {
                     theme_id: ThemeId::Light,
                     keymap_preset: KeymapPreset::Ide,
                     er_browser: Some("Firefox".to_string()),
+                    keyword_case: None,
+                    locale: None,
+                    usage_stats: false,
+                    update_check: false,
+                    prefetch_scope: PrefetchScope::All,
                 },
             },
             &tx,
@@ -108,6 +113,11 @@ mod tests {
                     theme_id: ThemeId::Light,
                     keymap_preset: KeymapPreset::default(),
                     er_browser: None,
+                    keyword_case: None,
+                    locale: None,
+                    usage_stats: false,
+                    update_check: false,
+                    prefetch_scope: PrefetchScope::All,
                 },
             },
             &tx,
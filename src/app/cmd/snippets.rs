@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::ports::outbound::SnippetStore;
+use crate::update::action::Action;
+
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    store: &Arc<dyn SnippetStore>,
+    fetch_tasks: &BackgroundTasks,
+    pending_writes: &BackgroundTasks,
+) {
+    let tx = action_tx.clone();
+    let store = Arc::clone(store);
+    match effect {
+        Effect::LoadSnippets { root } => {
+            fetch_tasks.spawn(async move {
+                let loaded = tokio::task::spawn_blocking(move || store.load(&root)).await;
+                let action = match loaded {
+                    Ok(Ok(snippets)) => Action::SnippetsLoaded(snippets),
+                    Ok(Err(error)) => Action::SnippetsLoadFailed(error),
+                    Err(_) => return,
+                };
+                tx.send(action).await.ok();
+            });
+        }
+        Effect::SaveSnippet { root, snippet } => {
+            pending_writes.spawn(async move {
+                let name = snippet.name.clone();
+                let saved = tokio::task::spawn_blocking(move || store.save(&root, &snippet)).await;
+                let action = match saved {
+                    Ok(Ok(snippets)) => Action::SnippetSaved { name, snippets },
+                    Ok(Err(error)) => Action::SnippetSaveFailed(error),
+                    Err(_) => return,
+                };
+                tx.send(action).await.ok();
+            });
+        }
+        _ => unreachable!("snippets::run called with non-snippet effect"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::ports::outbound::{Snippet, SnippetError};
+
+    #[derive(Default)]
+    struct MemoryStore {
+        snippets: Mutex<Vec<Snippet>>,
+    }
+
+    impl SnippetStore for MemoryStore {
+        fn load(&self, _root: &Path) -> Result<Vec<Snippet>, SnippetError> {
+            Ok(self.snippets.lock().unwrap().clone())
+        }
+
+        fn save(&self, _root: &Path, snippet: &Snippet) -> Result<Vec<Snippet>, SnippetError> {
+            let mut snippets = self.snippets.lock().unwrap();
+            snippets.push(snippet.clone());
+            Ok(snippets.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn saved_snippet_reports_the_updated_list() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let store: Arc<dyn SnippetStore> = Arc::new(MemoryStore::default());
+        let tasks = BackgroundTasks::default();
+
+        run(
+            Effect::SaveSnippet {
+                root: PathBuf::from("/work/app"),
+                snippet: Snippet {
+                    name: "count".to_string(),
+                    sql: "SELECT count(*) FROM users;".to_string(),
+                },
+            },
+            &tx,
+            &store,
+            &tasks,
+            &tasks,
+        );
+
+        let Some(Action::SnippetSaved { name, snippets }) = rx.recv().await else {
+            panic!("expected SnippetSaved");
+        };
+        assert_eq!(name, "count");
+        assert_eq!(snippets.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn loaded_snippets_are_sent_back() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let store = MemoryStore::default();
+        store.snippets.lock().unwrap().push(Snippet {
+            name: "count".to_string(),
+            sql: "SELECT 1;".to_string(),
+        });
+        let store: Arc<dyn SnippetStore> = Arc::new(store);
+        let tasks = BackgroundTasks::default();
+
+        run(
+            Effect::LoadSnippets {
+                root: PathBuf::from("/work/app"),
+            },
+            &tx,
+            &store,
+            &tasks,
+            &tasks,
+        );
+
+        let Some(Action::SnippetsLoaded(snippets)) = rx.recv().await else {
+            panic!("expected SnippetsLoaded");
+        };
+        assert_eq!(snippets[0].name, "count");
+    }
+}
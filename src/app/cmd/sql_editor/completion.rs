@@ -41,10 +41,18 @@ pub async fn run(
             Ok(())
         }
 
+        Effect::SetCompletionSnippets { snippets } => {
+            completion_engine.borrow_mut().set_snippets(snippets);
+            Ok(())
+        }
+
         Effect::TriggerCompletion => {
             let cursor = state.sql_modal.editor().cursor();
             let content = state.sql_modal.editor().content();
 
+            completion_engine
+                .borrow_mut()
+                .set_database_type(state.session.active_database_type());
             let (prep, missing) = {
                 let engine = completion_engine.borrow();
                 let prep = engine.prepare(content, cursor);
@@ -0,0 +1,252 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+use crate::cmd::background_tasks::BackgroundTasks;
+use crate::cmd::effect::Effect;
+use crate::ports::outbound::{SqlFileScanner, SqlFileStore};
+use crate::update::action::Action;
+
+const LINKED_FILE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+pub fn run(
+    effect: Effect,
+    action_tx: &mpsc::Sender<Action>,
+    scanner: &Arc<dyn SqlFileScanner>,
+    store: &Arc<dyn SqlFileStore>,
+    fetch_tasks: &BackgroundTasks,
+    pending_writes: &BackgroundTasks,
+) {
+    let tx = action_tx.clone();
+    match effect {
+        Effect::LoadSqlFiles { root } => {
+            let scanner = Arc::clone(scanner);
+            fetch_tasks.spawn(async move {
+                let scanned = tokio::task::spawn_blocking(move || scanner.scan(&root)).await;
+                let action = match scanned {
+                    Ok(Ok(files)) => Action::SqlFilesLoaded(files),
+                    Ok(Err(error)) => Action::SqlFilesLoadFailed(error),
+                    Err(_) => return,
+                };
+                tx.send(action).await.ok();
+            });
+        }
+        Effect::OpenLinkedSqlFile { path } => {
+            let store = Arc::clone(store);
+            fetch_tasks.spawn(async move {
+                let read_path = path.clone();
+                let read = tokio::task::spawn_blocking(move || store.read(&read_path)).await;
+                let action = match read {
+                    Ok(Ok(content)) => Action::SqlFileLinked { path, content },
+                    Ok(Err(error)) => Action::LinkedSqlFileFailed(error),
+                    Err(_) => return,
+                };
+                tx.send(action).await.ok();
+            });
+        }
+        Effect::PollLinkedSqlFile { path, link_id } => {
+            let store = Arc::clone(store);
+            fetch_tasks.spawn(async move {
+                tokio::time::sleep(LINKED_FILE_POLL_INTERVAL).await;
+                let Ok(content) = tokio::task::spawn_blocking(move || store.read(&path)).await
+                else {
+                    return;
+                };
+                // A failed read (e.g. mid atomic save) is reported as-is; the
+                // reducer keeps polling either way.
+                tx.send(Action::LinkedSqlFilePolled {
+                    link_id,
+                    content: content.ok(),
+                })
+                .await
+                .ok();
+            });
+        }
+        Effect::WriteLinkedSqlFile {
+            path,
+            link_id,
+            content,
+        } => {
+            let store = Arc::clone(store);
+            pending_writes.spawn(async move {
+                let written = content.clone();
+                let write = tokio::task::spawn_blocking(move || store.write(&path, &written)).await;
+                let action = match write {
+                    Ok(Ok(())) => Action::LinkedSqlFileWritten { link_id, content },
+                    Ok(Err(error)) => Action::LinkedSqlFileFailed(error),
+                    Err(_) => return,
+                };
+                tx.send(action).await.ok();
+            });
+        }
+        Effect::WriteSqlFileAs { path, content } => {
+            let store = Arc::clone(store);
+            pending_writes.spawn(async move {
+                let (write_path, written) = (path.clone(), content.clone());
+                let write =
+                    tokio::task::spawn_blocking(move || store.write(&write_path, &written)).await;
+                let action = match write {
+                    Ok(Ok(())) => Action::SqlFileWrittenAs { path, content },
+                    Ok(Err(error)) => Action::LinkedSqlFileFailed(error),
+                    Err(_) => return,
+                };
+                tx.send(action).await.ok();
+            });
+        }
+        _ => unreachable!("sql_files::run called with non-sql-file effect"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::cmd::test_fixtures::{EmptySqlFileScanner, NoopSqlFileStore};
+    use crate::ports::outbound::{SqlFile, SqlFileError};
+
+    struct FixedScanner;
+
+    impl SqlFileScanner for FixedScanner {
+        fn scan(&self, root: &Path) -> Result<Vec<SqlFile>, SqlFileError> {
+            Ok(vec![SqlFile {
+                path: "seed.sql".to_string(),
+                content: root.display().to_string(),
+            }])
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingStore {
+        written: Mutex<Option<(PathBuf, String)>>,
+    }
+
+    impl SqlFileStore for RecordingStore {
+        fn read(&self, path: &Path) -> Result<String, SqlFileError> {
+            Ok(format!("-- {}", path.display()))
+        }
+
+        fn write(&self, path: &Path, content: &str) -> Result<(), SqlFileError> {
+            *self.written.lock().unwrap() = Some((path.to_path_buf(), content.to_string()));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn scanned_files_are_sent_back() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let scanner: Arc<dyn SqlFileScanner> = Arc::new(FixedScanner);
+        let store: Arc<dyn SqlFileStore> = Arc::new(NoopSqlFileStore);
+        let tasks = BackgroundTasks::default();
+
+        run(
+            Effect::LoadSqlFiles {
+                root: PathBuf::from("/work/app"),
+            },
+            &tx,
+            &scanner,
+            &store,
+            &tasks,
+            &tasks,
+        );
+
+        let Some(Action::SqlFilesLoaded(files)) = rx.recv().await else {
+            panic!("expected SqlFilesLoaded");
+        };
+        assert_eq!(files[0].path, "seed.sql");
+        assert_eq!(files[0].content, "/work/app");
+    }
+
+    #[tokio::test]
+    async fn opened_file_is_linked_with_its_content() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let scanner: Arc<dyn SqlFileScanner> = Arc::new(EmptySqlFileScanner);
+        let store: Arc<dyn SqlFileStore> = Arc::new(RecordingStore::default());
+        let tasks = BackgroundTasks::default();
+
+        run(
+            Effect::OpenLinkedSqlFile {
+                path: PathBuf::from("/work/app/report.sql"),
+            },
+            &tx,
+            &scanner,
+            &store,
+            &tasks,
+            &tasks,
+        );
+
+        let Some(Action::SqlFileLinked { path, content }) = rx.recv().await else {
+            panic!("expected SqlFileLinked");
+        };
+        assert_eq!(path, PathBuf::from("/work/app/report.sql"));
+        assert_eq!(content, "-- /work/app/report.sql");
+    }
+
+    #[tokio::test]
+    async fn written_content_is_reported_for_the_link() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let scanner: Arc<dyn SqlFileScanner> = Arc::new(EmptySqlFileScanner);
+        let recording = Arc::new(RecordingStore::default());
+        let store: Arc<dyn SqlFileStore> = Arc::clone(&recording) as _;
+        let tasks = BackgroundTasks::default();
+
+        run(
+            Effect::WriteLinkedSqlFile {
+                path: PathBuf::from("/work/app/report.sql"),
+                link_id: 3,
+                content: "SELECT 2;".to_string(),
+            },
+            &tx,
+            &scanner,
+            &store,
+            &tasks,
+            &tasks,
+        );
+
+        let Some(Action::LinkedSqlFileWritten { link_id, content }) = rx.recv().await else {
+            panic!("expected LinkedSqlFileWritten");
+        };
+        assert_eq!(link_id, 3);
+        assert_eq!(content, "SELECT 2;");
+        assert_eq!(
+            recording.written.lock().unwrap().clone(),
+            Some((
+                PathBuf::from("/work/app/report.sql"),
+                "SELECT 2;".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn written_as_reports_the_new_path() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let scanner: Arc<dyn SqlFileScanner> = Arc::new(EmptySqlFileScanner);
+        let recording = Arc::new(RecordingStore::default());
+        let store: Arc<dyn SqlFileStore> = Arc::clone(&recording) as _;
+        let tasks = BackgroundTasks::default();
+
+        run(
+            Effect::WriteSqlFileAs {
+                path: PathBuf::from("/work/app/new.sql"),
+                content: "SELECT 3;".to_string(),
+            },
+            &tx,
+            &scanner,
+            &store,
+            &tasks,
+            &tasks,
+        );
+
+        let Some(Action::SqlFileWrittenAs { path, content }) = rx.recv().await else {
+            panic!("expected SqlFileWrittenAs");
+        };
+        assert_eq!(path, PathBuf::from("/work/app/new.sql"));
+        assert_eq!(content, "SELECT 3;");
+        assert_eq!(
+            recording.written.lock().unwrap().clone(),
+            Some((PathBuf::from("/work/app/new.sql"), "SELECT 3;".to_string()))
+        );
+    }
+}
@@ -11,16 +11,23 @@ use crate::domain::SqliteDiagnosticsSnapshot;
 use crate::domain::connection::{ConnectionProfile, ServiceEntry};
 use crate::domain::query_history::QueryHistoryEntry;
 use crate::domain::{
-    ConnectionId, DatabaseMetadata, DiagnosticField, ErTableInfo, QueryResult, QuerySource,
-    QueryValue, SqlitePathError, classify_sqlite_metadata_error, classify_sqlite_read_error,
+    AuditEntry, ConnectionId, DatabaseMetadata, DiagnosticField, ErDiagramOptions, ErTableInfo,
+    MetadataSnapshot, QueryResult, QuerySource, QueryValue, SqlitePathError, Table,
+    classify_sqlite_metadata_error, classify_sqlite_read_error,
 };
 use crate::ports::outbound::DbOperationError;
 use crate::ports::outbound::{
-    AppSettings, CachedResultExporter, ClipboardError, ClipboardWriter, ConfigWriter,
-    ConfigWriterError, ConnectionStore, DsnBuilder, ErDiagramExporter, ErExportResult, ErLogWriter,
-    FolderOpenError, FolderOpener, MetadataProvider, PgServiceEntryReader, QueryExecutor,
-    QueryHistoryError, QueryHistoryStore, ServiceFileError, SettingsStore, SettingsStoreError,
-    SqliteDiagnosticsProvider, SqlitePathValidator,
+    AppSettings, AuditLogError, AuditLogStore, CacheCategory, CacheCategoryUsage, CacheCleanup,
+    CachePrunePolicy, CacheStore, CacheStoreError, CachedResultExporter, ClipboardError,
+    ClipboardWriter, ConfigWriter, ConfigWriterError, ConnectionCandidate,
+    ConnectionCandidateScanner, ConnectionStore, ContainerDatabaseScanner, ContainerScanError,
+    DsnBuilder, ErDiagramExporter, ErExportResult, ErLogWriter, FolderOpenError, FolderOpener,
+    HookInvocation, MetadataProvider, MetadataSnapshotError, MetadataSnapshotStore,
+    PgServiceEntryReader, QueryExecutor, QueryHistoryError, QueryHistoryStore, QueryHookError,
+    QueryHookRunner, ReleaseCheckError, ReleaseChecker, ReleaseInfo, ServiceFileError,
+    SettingsStore, SettingsStoreError, Snippet, SnippetError, SnippetStore, SqlFile, SqlFileError,
+    SqlFileScanner, SqlFileStore, SqliteDiagnosticsProvider, SqlitePathValidator, TempFileError,
+    TempFileWriter,
 };
 use crate::update::action::Action;
 
@@ -97,6 +104,7 @@ impl ErDiagramExporter for NoopErExporter {
     fn generate_and_export(
         &self,
         _tables: &[ErTableInfo],
+        _options: &ErDiagramOptions,
         _filename: &str,
         _cache_dir: &Path,
         _browser: Option<&str>,
@@ -130,6 +138,20 @@ impl PgServiceEntryReader for NoopPgServiceEntryReader {
     }
 }
 
+pub struct NoopConnectionCandidateScanner;
+impl ConnectionCandidateScanner for NoopConnectionCandidateScanner {
+    fn scan(&self, _project_root: Option<PathBuf>) -> Vec<ConnectionCandidate> {
+        vec![]
+    }
+}
+
+pub struct NoopContainerDatabaseScanner;
+impl ContainerDatabaseScanner for NoopContainerDatabaseScanner {
+    fn scan(&self) -> Result<Vec<ConnectionCandidate>, ContainerScanError> {
+        Ok(vec![])
+    }
+}
+
 pub struct NoopClipboardWriter;
 impl ClipboardWriter for NoopClipboardWriter {
     fn copy_text(&self, _content: &str) -> Result<(), ClipboardError> {
@@ -144,6 +166,89 @@ impl FolderOpener for NoopFolderOpener {
     }
 }
 
+pub struct NoopTempFileWriter;
+impl TempFileWriter for NoopTempFileWriter {
+    fn write_temp_file(&self, file_name: &str, _content: &str) -> Result<PathBuf, TempFileError> {
+        Ok(std::env::temp_dir().join(file_name))
+    }
+}
+
+pub struct NoopQueryHookRunner;
+#[async_trait::async_trait]
+impl QueryHookRunner for NoopQueryHookRunner {
+    async fn run(&self, _invocation: &HookInvocation) -> Result<(), QueryHookError> {
+        Ok(())
+    }
+}
+
+pub struct NoopReleaseChecker;
+#[async_trait::async_trait]
+impl ReleaseChecker for NoopReleaseChecker {
+    async fn latest_release(&self) -> Result<ReleaseInfo, ReleaseCheckError> {
+        Err(ReleaseCheckError::Request("offline".to_string()))
+    }
+}
+
+pub struct EmptySqlFileScanner;
+impl SqlFileScanner for EmptySqlFileScanner {
+    fn scan(&self, _root: &Path) -> Result<Vec<SqlFile>, SqlFileError> {
+        Ok(Vec::new())
+    }
+}
+
+pub struct NoopSqlFileStore;
+impl SqlFileStore for NoopSqlFileStore {
+    fn read(&self, _path: &Path) -> Result<String, SqlFileError> {
+        Ok(String::new())
+    }
+
+    fn write(&self, _path: &Path, _content: &str) -> Result<(), SqlFileError> {
+        Ok(())
+    }
+}
+
+pub struct EmptySnippetStore;
+impl SnippetStore for EmptySnippetStore {
+    fn load(&self, _root: &Path) -> Result<Vec<Snippet>, SnippetError> {
+        Ok(Vec::new())
+    }
+
+    fn save(&self, _root: &Path, snippet: &Snippet) -> Result<Vec<Snippet>, SnippetError> {
+        Ok(vec![snippet.clone()])
+    }
+}
+
+pub struct NoopCacheStore;
+impl CacheStore for NoopCacheStore {
+    fn usage(&self, _project_name: &str) -> Result<Vec<CacheCategoryUsage>, CacheStoreError> {
+        Ok(Vec::new())
+    }
+
+    fn clear(
+        &self,
+        _project_name: &str,
+        _category: Option<CacheCategory>,
+    ) -> Result<CacheCleanup, CacheStoreError> {
+        Ok(CacheCleanup::default())
+    }
+
+    fn prune(
+        &self,
+        _project_name: &str,
+        _policy: &CachePrunePolicy,
+    ) -> Result<CacheCleanup, CacheStoreError> {
+        Ok(CacheCleanup::default())
+    }
+}
+
+pub struct NoopAuditLogStore;
+#[async_trait::async_trait]
+impl AuditLogStore for NoopAuditLogStore {
+    async fn append(&self, _project_name: &str, _entry: &AuditEntry) -> Result<(), AuditLogError> {
+        Ok(())
+    }
+}
+
 pub struct NoopQueryHistoryStore;
 #[async_trait::async_trait]
 impl QueryHistoryStore for NoopQueryHistoryStore {
@@ -165,6 +270,71 @@ impl QueryHistoryStore for NoopQueryHistoryStore {
     }
 }
 
+/// Keeps a single snapshot regardless of project or connection.
+#[derive(Default)]
+pub struct InMemoryMetadataSnapshotStore {
+    snapshot: std::sync::Mutex<Option<MetadataSnapshot>>,
+}
+
+#[async_trait::async_trait]
+impl MetadataSnapshotStore for InMemoryMetadataSnapshotStore {
+    async fn save_metadata(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+        metadata: &DatabaseMetadata,
+    ) -> Result<(), MetadataSnapshotError> {
+        let mut snapshot = self.snapshot.lock().unwrap();
+        match snapshot.as_mut() {
+            Some(existing) => existing.metadata = metadata.clone(),
+            None => {
+                *snapshot = Some(MetadataSnapshot {
+                    metadata: metadata.clone(),
+                    tables: Vec::new(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    async fn save_table_detail(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+        table: &Table,
+    ) -> Result<(), MetadataSnapshotError> {
+        if let Some(snapshot) = self.snapshot.lock().unwrap().as_mut() {
+            snapshot
+                .tables
+                .retain(|t| t.schema != table.schema || t.name != table.name);
+            snapshot.tables.push(table.clone());
+        }
+        Ok(())
+    }
+
+    async fn save_table_columns(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+        table: &Table,
+    ) -> Result<(), MetadataSnapshotError> {
+        if let Some(snapshot) = self.snapshot.lock().unwrap().as_mut()
+            && snapshot.table(&table.schema, &table.name).is_none()
+        {
+            snapshot.tables.push(table.clone());
+        }
+        Ok(())
+    }
+
+    async fn load(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+    ) -> Result<Option<MetadataSnapshot>, MetadataSnapshotError> {
+        Ok(self.snapshot.lock().unwrap().clone())
+    }
+}
+
 pub struct NoopSettingsStore;
 impl SettingsStore for NoopSettingsStore {
     fn load(&self) -> Result<AppSettings, SettingsStoreError> {
@@ -261,11 +431,16 @@ fn make_runner_with_dsn_and_cached_result_exporter(
             dsn_builder,
             connection_store,
             pg_service_entry_reader: Some(Arc::new(NoopPgServiceEntryReader)),
+            connection_candidate_scanner: Arc::new(NoopConnectionCandidateScanner),
+            container_database_scanner: Arc::new(NoopContainerDatabaseScanner),
             sqlite_path_validator: Arc::new(TestFsSqlitePathValidator),
+            metadata_snapshot_store: Arc::new(InMemoryMetadataSnapshotStore::default()),
         },
         QueryDeps {
             query_executor,
+            connection_pool: None,
             query_history_store: Arc::new(NoopQueryHistoryStore),
+            audit_log_store: Arc::new(NoopAuditLogStore),
             sqlite_diagnostics: Arc::new(NoopSqliteDiagnosticsProvider),
             cached_result_exporter,
         },
@@ -277,6 +452,13 @@ fn make_runner_with_dsn_and_cached_result_exporter(
         UtilityDeps {
             clipboard: Arc::new(NoopClipboardWriter),
             folder_opener: Arc::new(NoopFolderOpener),
+            temp_file_writer: Arc::new(NoopTempFileWriter),
+            query_hook_runner: Arc::new(NoopQueryHookRunner),
+            sql_file_scanner: Arc::new(EmptySqlFileScanner),
+            sql_file_store: Arc::new(NoopSqlFileStore),
+            snippet_store: Arc::new(EmptySnippetStore),
+            cache_store: Arc::new(NoopCacheStore),
+            release_checker: Arc::new(NoopReleaseChecker),
         },
         SettingsDeps {
             settings_store: Arc::new(NoopSettingsStore),
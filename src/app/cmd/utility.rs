@@ -4,7 +4,7 @@ use color_eyre::eyre::Result;
 use tokio::sync::mpsc;
 
 use crate::cmd::effect::Effect;
-use crate::ports::outbound::{ClipboardWriter, FolderOpener};
+use crate::ports::outbound::{ClipboardWriter, FolderOpener, TempFileWriter};
 use crate::update::action::Action;
 
 pub(crate) async fn run(
@@ -12,6 +12,7 @@ pub(crate) async fn run(
     action_tx: &mpsc::Sender<Action>,
     clipboard: &Arc<dyn ClipboardWriter>,
     folder_opener: &Arc<dyn FolderOpener>,
+    temp_file_writer: &Arc<dyn TempFileWriter>,
 ) -> Result<()> {
     match effect {
         Effect::CopyToClipboard {
@@ -41,6 +42,30 @@ pub(crate) async fn run(
                 action_tx.send(Action::OpenFolderFailed(e)).await.ok();
             }
         }
+        Effect::CopyViaTempFile {
+            file_name,
+            content,
+            row_count,
+        } => {
+            let clipboard = Arc::clone(clipboard);
+            let temp_file_writer = Arc::clone(temp_file_writer);
+            let tx = action_tx.clone();
+            tokio::task::spawn_blocking(move || {
+                let action = match temp_file_writer.write_temp_file(&file_name, &content) {
+                    Ok(path) => {
+                        let path = path.display().to_string();
+                        let path_copied = clipboard.copy_text(&path).is_ok();
+                        Action::ResultCopyAllSpilled {
+                            path,
+                            row_count,
+                            path_copied,
+                        }
+                    }
+                    Err(e) => Action::ResultCopyAllSpillFailed(e),
+                };
+                tx.blocking_send(action).ok();
+            });
+        }
         _ => unreachable!("utility::run called with non-utility effect"),
     }
     Ok(())
@@ -54,6 +79,7 @@ mod tests {
 
     use crate::ports::outbound::clipboard::ClipboardError;
     use crate::ports::outbound::folder_opener::FolderOpenError;
+    use crate::ports::outbound::temp_file_writer::TempFileError;
 
     struct MockClipboard {
         result: Result<(), ClipboardError>,
@@ -95,6 +121,26 @@ mod tests {
         }
     }
 
+    struct MockTempFileWriter {
+        result: Result<PathBuf, TempFileError>,
+    }
+
+    impl TempFileWriter for MockTempFileWriter {
+        fn write_temp_file(
+            &self,
+            _file_name: &str,
+            _content: &str,
+        ) -> Result<PathBuf, TempFileError> {
+            self.result.clone()
+        }
+    }
+
+    fn temp_file_writer() -> Arc<dyn TempFileWriter> {
+        Arc::new(MockTempFileWriter {
+            result: Ok(PathBuf::from("/tmp/sabiql/result.csv")),
+        })
+    }
+
     mod copy_to_clipboard {
         use super::*;
 
@@ -113,6 +159,7 @@ mod tests {
                 &tx,
                 &clipboard,
                 &folder_opener,
+                &temp_file_writer(),
             )
             .await
             .unwrap();
@@ -141,6 +188,7 @@ mod tests {
                 &tx,
                 &clipboard,
                 &folder_opener,
+                &temp_file_writer(),
             )
             .await
             .unwrap();
@@ -169,6 +217,7 @@ mod tests {
                 &tx,
                 &clipboard,
                 &folder_opener,
+                &temp_file_writer(),
             )
             .await
             .unwrap();
@@ -201,6 +250,7 @@ mod tests {
                 &tx,
                 &clipboard,
                 &folder_opener,
+                &temp_file_writer(),
             )
             .await
             .unwrap();
@@ -224,6 +274,7 @@ mod tests {
                 &tx,
                 &clipboard,
                 &folder_opener,
+                &temp_file_writer(),
             )
             .await
             .unwrap();
@@ -240,4 +291,76 @@ mod tests {
             }
         }
     }
+
+    mod copy_via_temp_file {
+        use super::*;
+
+        #[tokio::test]
+        async fn dispatches_spilled_with_written_path() {
+            let (tx, mut rx) = mpsc::channel(8);
+            let clipboard: Arc<dyn ClipboardWriter> = Arc::new(MockClipboard { result: Ok(()) });
+            let folder_opener: Arc<dyn FolderOpener> = Arc::new(MockFolderOpener::new());
+
+            run(
+                Effect::CopyViaTempFile {
+                    file_name: "result.csv".to_string(),
+                    content: "a,b".to_string(),
+                    row_count: 3,
+                },
+                &tx,
+                &clipboard,
+                &folder_opener,
+                &temp_file_writer(),
+            )
+            .await
+            .unwrap();
+
+            let action = tokio::time::timeout(std::time::Duration::from_millis(500), rx.recv())
+                .await
+                .expect("action timeout")
+                .expect("channel closed");
+            match action {
+                Action::ResultCopyAllSpilled {
+                    path,
+                    row_count,
+                    path_copied,
+                } => {
+                    assert_eq!(path, "/tmp/sabiql/result.csv");
+                    assert_eq!(row_count, 3);
+                    assert!(path_copied);
+                }
+                other => panic!("expected ResultCopyAllSpilled, got {other:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn write_failure_dispatches_spill_failed() {
+            let (tx, mut rx) = mpsc::channel(8);
+            let clipboard: Arc<dyn ClipboardWriter> = Arc::new(MockClipboard { result: Ok(()) });
+            let folder_opener: Arc<dyn FolderOpener> = Arc::new(MockFolderOpener::new());
+            let temp_file_writer: Arc<dyn TempFileWriter> = Arc::new(MockTempFileWriter {
+                result: Err(TempFileError::from(std::io::Error::other("disk full"))),
+            });
+
+            run(
+                Effect::CopyViaTempFile {
+                    file_name: "result.csv".to_string(),
+                    content: "a,b".to_string(),
+                    row_count: 3,
+                },
+                &tx,
+                &clipboard,
+                &folder_opener,
+                &temp_file_writer,
+            )
+            .await
+            .unwrap();
+
+            let action = tokio::time::timeout(std::time::Duration::from_millis(500), rx.recv())
+                .await
+                .expect("action timeout")
+                .expect("channel closed");
+            assert!(matches!(action, Action::ResultCopyAllSpillFailed(_)));
+        }
+    }
 }
@@ -0,0 +1,289 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    English,
+    Japanese,
+}
+
+impl Locale {
+    pub fn config_value(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Japanese => "ja",
+        }
+    }
+
+    pub fn from_config_value(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "en" | "english" => Some(Self::English),
+            "ja" | "japanese" => Some(Self::Japanese),
+            _ => None,
+        }
+    }
+
+    /// Parses POSIX locale values such as `ja_JP.UTF-8`; `C`, `POSIX` and
+    /// unsupported languages yield `None`.
+    pub fn from_env_value(value: &str) -> Option<Self> {
+        let language = value.split(['_', '.', '@', '-']).next().unwrap_or_default();
+        match language.to_ascii_lowercase().as_str() {
+            "en" => Some(Self::English),
+            "ja" => Some(Self::Japanese),
+            _ => None,
+        }
+    }
+}
+
+/// Translated UI text.
+///
+/// Covers pane, modal and confirm dialog titles, inspector tabs and
+/// connection status. Footer key hints, status-line messages, confirm
+/// dialog bodies and report contents are still English only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Message {
+    HelpTitle,
+    SettingsTitle,
+    ConnectionErrorTitle,
+    SelectConnectionTitle,
+    CommandPaletteTitle,
+    TablePickerTitle,
+    QueryHistoryTitle,
+    ErDiagramTitle,
+    SqliteDiagnosticsTitle,
+    ServerSettingsTitle,
+    SessionSettingsTitle,
+    SqlFilesTitle,
+    SnippetsTitle,
+    ImportConnectionTitle,
+    ContainerDatabasesTitle,
+    ObjectSearchTitle,
+    ExplorerTitle,
+    InspectorTitle,
+    ResultTitle,
+    ResultQueryTitle,
+    RowDetailTitle,
+    CellDetailTitle,
+    JsonbDetailTitle,
+    JsonbEditTitle,
+    QuickActionsTitle,
+    NewConnectionTitle,
+    EditConnectionTitle,
+    SqlEditorTitle,
+    ConfirmTitle,
+    DisableReadOnlyTitle,
+    ConfirmReindexTitle,
+    ConfirmCsvExportTitle,
+    ConfirmCopyTitle,
+    DeleteConnectionTitle,
+    InfoTab,
+    ColumnsTab,
+    StatsTab,
+    StorageTab,
+    IndexesTab,
+    TriggersTab,
+    StatusConnected,
+    StatusLoading,
+    StatusError,
+    StatusNotLoaded,
+    StatusNoDsn,
+    StatusOffline,
+}
+
+impl Message {
+    pub const ALL: [Self; 46] = [
+        Self::HelpTitle,
+        Self::SettingsTitle,
+        Self::ConnectionErrorTitle,
+        Self::SelectConnectionTitle,
+        Self::CommandPaletteTitle,
+        Self::TablePickerTitle,
+        Self::QueryHistoryTitle,
+        Self::ErDiagramTitle,
+        Self::SqliteDiagnosticsTitle,
+        Self::ServerSettingsTitle,
+        Self::SessionSettingsTitle,
+        Self::SqlFilesTitle,
+        Self::SnippetsTitle,
+        Self::ImportConnectionTitle,
+        Self::ContainerDatabasesTitle,
+        Self::ObjectSearchTitle,
+        Self::ExplorerTitle,
+        Self::InspectorTitle,
+        Self::ResultTitle,
+        Self::ResultQueryTitle,
+        Self::RowDetailTitle,
+        Self::CellDetailTitle,
+        Self::JsonbDetailTitle,
+        Self::JsonbEditTitle,
+        Self::QuickActionsTitle,
+        Self::NewConnectionTitle,
+        Self::EditConnectionTitle,
+        Self::SqlEditorTitle,
+        Self::ConfirmTitle,
+        Self::DisableReadOnlyTitle,
+        Self::ConfirmReindexTitle,
+        Self::ConfirmCsvExportTitle,
+        Self::ConfirmCopyTitle,
+        Self::DeleteConnectionTitle,
+        Self::InfoTab,
+        Self::ColumnsTab,
+        Self::StatsTab,
+        Self::StorageTab,
+        Self::IndexesTab,
+        Self::TriggersTab,
+        Self::StatusConnected,
+        Self::StatusLoading,
+        Self::StatusError,
+        Self::StatusNotLoaded,
+        Self::StatusNoDsn,
+        Self::StatusOffline,
+    ];
+
+    pub fn text(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::English => self.english(),
+            Locale::Japanese => self.japanese(),
+        }
+    }
+
+    fn english(self) -> &'static str {
+        match self {
+            Self::HelpTitle => "Help",
+            Self::SettingsTitle => "Settings",
+            Self::ConnectionErrorTitle => "Connection Error",
+            Self::SelectConnectionTitle => "Select Connection",
+            Self::CommandPaletteTitle => "Command Palette",
+            Self::TablePickerTitle => "Table Picker",
+            Self::QueryHistoryTitle => "Query History",
+            Self::ErDiagramTitle => "ER Diagram",
+            Self::SqliteDiagnosticsTitle => "SQLite Diagnostics",
+            Self::ServerSettingsTitle => "Server Settings",
+            Self::SessionSettingsTitle => "Session Settings",
+            Self::SqlFilesTitle => "SQL Files",
+            Self::SnippetsTitle => "Snippets",
+            Self::ImportConnectionTitle => "Import Connection",
+            Self::ContainerDatabasesTitle => "Database Containers",
+            Self::ObjectSearchTitle => "Search Objects",
+            Self::ExplorerTitle => "Explorer",
+            Self::InspectorTitle => "Inspector",
+            Self::ResultTitle => "Result",
+            Self::ResultQueryTitle => "Result Query",
+            Self::RowDetailTitle => "Row Detail",
+            Self::CellDetailTitle => "Cell Detail",
+            Self::JsonbDetailTitle => "JSONB Detail",
+            Self::JsonbEditTitle => "JSONB Edit",
+            Self::QuickActionsTitle => "Quick actions",
+            Self::NewConnectionTitle => "New Connection",
+            Self::EditConnectionTitle => "Edit Connection",
+            Self::SqlEditorTitle => "SQL Editor",
+            Self::ConfirmTitle => "Confirm",
+            Self::DisableReadOnlyTitle => "Disable Read-Only",
+            Self::ConfirmReindexTitle => "Confirm REINDEX",
+            Self::ConfirmCsvExportTitle => "Confirm CSV Export",
+            Self::ConfirmCopyTitle => "Confirm Copy",
+            Self::DeleteConnectionTitle => "Delete Connection",
+            Self::InfoTab => "Info",
+            Self::ColumnsTab => "Cols",
+            Self::StatsTab => "Stats",
+            Self::StorageTab => "Storage",
+            Self::IndexesTab => "Idx",
+            Self::TriggersTab => "Trig",
+            Self::StatusConnected => "connected",
+            Self::StatusLoading => "loading...",
+            Self::StatusError => "error",
+            Self::StatusNotLoaded => "not loaded",
+            Self::StatusNoDsn => "no dsn",
+            Self::StatusOffline => "offline",
+        }
+    }
+
+    fn japanese(self) -> &'static str {
+        match self {
+            Self::HelpTitle => "ヘルプ",
+            Self::SettingsTitle => "設定",
+            Self::ConnectionErrorTitle => "接続エラー",
+            Self::SelectConnectionTitle => "接続を選択",
+            Self::CommandPaletteTitle => "コマンドパレット",
+            Self::TablePickerTitle => "テーブル選択",
+            Self::QueryHistoryTitle => "クエリ履歴",
+            Self::ErDiagramTitle => "ER図",
+            Self::SqliteDiagnosticsTitle => "SQLite診断",
+            Self::ServerSettingsTitle => "サーバー設定",
+            Self::SessionSettingsTitle => "セッション設定",
+            Self::SqlFilesTitle => "SQLファイル",
+            Self::SnippetsTitle => "スニペット",
+            Self::ImportConnectionTitle => "接続をインポート",
+            Self::ContainerDatabasesTitle => "データベースコンテナ",
+            Self::ObjectSearchTitle => "オブジェクト検索",
+            Self::ExplorerTitle => "エクスプローラ",
+            Self::InspectorTitle => "インスペクタ",
+            Self::ResultTitle => "結果",
+            Self::ResultQueryTitle => "クエリ結果",
+            Self::RowDetailTitle => "行の詳細",
+            Self::CellDetailTitle => "セルの詳細",
+            Self::JsonbDetailTitle => "JSONBの詳細",
+            Self::JsonbEditTitle => "JSONBの編集",
+            Self::QuickActionsTitle => "クイック操作",
+            Self::NewConnectionTitle => "新しい接続",
+            Self::EditConnectionTitle => "接続を編集",
+            Self::SqlEditorTitle => "SQLエディタ",
+            Self::ConfirmTitle => "確認",
+            Self::DisableReadOnlyTitle => "読み取り専用を解除",
+            Self::ConfirmReindexTitle => "REINDEXの確認",
+            Self::ConfirmCsvExportTitle => "CSVエクスポートの確認",
+            Self::ConfirmCopyTitle => "コピーの確認",
+            Self::DeleteConnectionTitle => "接続を削除",
+            Self::InfoTab => "情報",
+            Self::ColumnsTab => "列",
+            Self::StatsTab => "統計",
+            Self::StorageTab => "容量",
+            Self::IndexesTab => "索引",
+            Self::TriggersTab => "トリガ",
+            Self::StatusConnected => "接続済み",
+            Self::StatusLoading => "読み込み中...",
+            Self::StatusError => "エラー",
+            Self::StatusNotLoaded => "未読み込み",
+            Self::StatusNoDsn => "DSN未設定",
+            Self::StatusOffline => "オフライン",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("ja_JP.UTF-8", Some(Locale::Japanese))]
+    #[case("ja", Some(Locale::Japanese))]
+    #[case("en_US.UTF-8", Some(Locale::English))]
+    #[case("en-GB", Some(Locale::English))]
+    #[case("C", None)]
+    #[case("POSIX", None)]
+    #[case("fr_FR.UTF-8", None)]
+    fn parses_env_locale(#[case] value: &str, #[case] expected: Option<Locale>) {
+        assert_eq!(Locale::from_env_value(value), expected);
+    }
+
+    #[rstest]
+    #[case(Locale::English)]
+    #[case(Locale::Japanese)]
+    fn config_value_round_trips(#[case] locale: Locale) {
+        assert_eq!(
+            Locale::from_config_value(locale.config_value()),
+            Some(locale)
+        );
+    }
+
+    #[test]
+    fn every_message_is_translated() {
+        for message in Message::ALL {
+            assert!(!message.text(Locale::Japanese).is_empty());
+            assert_ne!(
+                message.text(Locale::Japanese),
+                message.text(Locale::English),
+                "{message:?} is untranslated"
+            );
+        }
+    }
+}
@@ -8,6 +8,7 @@
 
 pub mod catalog;
 pub mod cmd;
+pub mod i18n;
 pub mod model;
 pub mod policy;
 pub mod update;
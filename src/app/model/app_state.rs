@@ -2,19 +2,29 @@ use std::time::Instant;
 
 use super::explain_context::ExplainContext;
 use super::runtime_state::RuntimeState;
-use crate::domain::connection::{ConnectionProfile, ServiceEntry};
+use crate::domain::connection::{
+    ConnectionProfile, PoolMode, ProfileAccent, ServiceEntry, SessionSettings,
+};
 use crate::domain::{DatabaseType, TableSummary};
 use crate::model::browse::cell_detail::CellDetailState;
-use crate::model::browse::inspector_view_model::InspectorViewModel;
+use crate::model::browse::explorer_tree::ExplorerTreeState;
+use crate::model::browse::inspector_view_model::{InspectorStatsColumn, InspectorViewModel};
 use crate::model::browse::jsonb_detail::JsonbDetailState;
-use crate::model::browse::query_execution::QueryExecution;
+use crate::model::browse::maintenance::MaintenanceState;
+use crate::model::browse::object_search::ObjectSearchState;
+use crate::model::browse::query_execution::{QueryExecution, VisibleResultKind};
+use crate::model::browse::quick_actions::{QuickAction, QuickActionsState};
 use crate::model::browse::result_interaction::ResultInteraction;
 use crate::model::browse::row_detail::RowDetailState;
 use crate::model::browse::session::BrowseSession;
 use crate::model::connection::cache::ConnectionCacheStore;
 use crate::model::connection::error_state::ConnectionErrorState;
+use crate::model::connection::import::ConnectionImportState;
 use crate::model::connection::list::{self, ConnectionListItem};
+use crate::model::connection::session_settings::SessionSettingsFormState;
 use crate::model::connection::setup::ConnectionSetupState;
+use crate::model::postgres::column_distribution::ColumnDistributionState;
+use crate::model::postgres::server_settings::ServerSettingsState;
 use crate::model::shared::confirm_dialog::ConfirmDialogState;
 use crate::model::shared::flash_timer::FlashTimerStore;
 use crate::model::shared::input_mode::InputMode;
@@ -26,15 +36,23 @@ use crate::model::shared::render_output::{
 use crate::model::shared::settings::SettingsState;
 use crate::model::shared::text_input::TextInputState;
 use crate::model::shared::ui_state::{UiState, scroll_max_offset};
+use crate::model::shared::usage::UsageTracker;
+use crate::model::sql_editor::linked_file::LinkedSqlFileState;
 use crate::model::sql_editor::modal::SqlModalContext;
 use crate::model::sql_editor::query_history::QueryHistoryPickerState;
+use crate::model::sql_editor::snippets::SnippetPickerState;
+use crate::model::sql_editor::sql_files::SqlFileBrowserState;
 use crate::model::sqlite::diagnostics::SqliteDiagnosticsState;
+use crate::model::undo_history::UndoHistory;
+use crate::policy::execution_policy::{PolicyViolation, enforce_execution_policy};
+use crate::policy::explorer_tree::{ExplorerRow, explorer_rows, max_explorer_row_label_width};
 use crate::policy::preview_cell_text::CellPresentationPolicy;
+use crate::policy::sql::replica_routing::{QueryEndpoint, route_query};
 use crate::policy::sql::result_query::is_rerunnable_select;
-use crate::policy::table_kind::max_explorer_table_label_width;
 use crate::policy::write::inline_cell_edit::supports_inline_edit;
 use crate::policy::write::write_guardrails::{PreviewWriteability, preview_writeability};
-use crate::ports::outbound::DdlGenerator;
+use crate::policy::{FeaturePolicy, FeatureRequirement};
+use crate::ports::outbound::{DdlGenerator, ExecutionPolicy};
 
 pub struct AppState {
     pub should_quit: bool,
@@ -51,7 +69,10 @@ pub struct AppState {
     pub sql_modal: SqlModalContext,
     pub messages: MessageState,
     pub er_preparation: super::er_state::ErPreparationState,
+    pub er_viewer: super::er_viewer::ErViewerState,
     pub connection_setup: ConnectionSetupState,
+    pub connection_import: ConnectionImportState,
+    pub session_settings_form: SessionSettingsFormState,
     pub connection_error: ConnectionErrorState,
     pub confirm_dialog: ConfirmDialogState,
     pub result_interaction: ResultInteraction,
@@ -60,11 +81,22 @@ pub struct AppState {
     pub row_detail: RowDetailState,
     pub query_history_picker: QueryHistoryPickerState,
     pub settings: SettingsState,
+    pub usage: UsageTracker,
+    pub explorer_tree: ExplorerTreeState,
     pub sqlite_diagnostics: SqliteDiagnosticsState,
+    pub server_settings: ServerSettingsState,
+    pub column_distribution: ColumnDistributionState,
+    pub sql_files: SqlFileBrowserState,
+    pub snippets: SnippetPickerState,
+    pub object_search: ObjectSearchState,
+    pub quick_actions: QuickActionsState,
+    pub linked_sql_file: LinkedSqlFileState,
+    pub maintenance: MaintenanceState,
     pub explain: ExplainContext,
     pub modal: ModalState,
     pub flash_timers: FlashTimerStore,
     pub connection_caches: ConnectionCacheStore,
+    pub undo: UndoHistory,
     connections: Vec<ConnectionProfile>,
     service_entries: Vec<ServiceEntry>,
     connection_list_items: Vec<ConnectionListItem>,
@@ -85,7 +117,10 @@ impl AppState {
             sql_modal: SqlModalContext::default(),
             messages: MessageState::default(),
             er_preparation: super::er_state::ErPreparationState::default(),
+            er_viewer: super::er_viewer::ErViewerState::default(),
             connection_setup: ConnectionSetupState::default(),
+            connection_import: ConnectionImportState::default(),
+            session_settings_form: SessionSettingsFormState::default(),
             connection_error: ConnectionErrorState::default(),
             confirm_dialog: ConfirmDialogState::default(),
             result_interaction: ResultInteraction::default(),
@@ -94,11 +129,22 @@ impl AppState {
             row_detail: RowDetailState::default(),
             query_history_picker: QueryHistoryPickerState::default(),
             settings: SettingsState::default(),
+            usage: UsageTracker::default(),
+            explorer_tree: ExplorerTreeState::default(),
             sqlite_diagnostics: SqliteDiagnosticsState::default(),
+            server_settings: ServerSettingsState::default(),
+            column_distribution: ColumnDistributionState::default(),
+            sql_files: SqlFileBrowserState::default(),
+            snippets: SnippetPickerState::default(),
+            object_search: ObjectSearchState::default(),
+            quick_actions: QuickActionsState::default(),
+            linked_sql_file: LinkedSqlFileState::default(),
+            maintenance: MaintenanceState::default(),
             explain: ExplainContext::default(),
             modal: ModalState::default(),
             flash_timers: FlashTimerStore::default(),
             connection_caches: ConnectionCacheStore::default(),
+            undo: UndoHistory::default(),
             connections: Vec::new(),
             service_entries: Vec::new(),
             connection_list_items: Vec::new(),
@@ -154,11 +200,13 @@ impl AppState {
         self.ui
             .set_result_viewport_plan(layout.result.viewport_plan);
         self.ui.set_result_widths_cache(layout.result.widths_cache);
+        self.ui
+            .set_result_active_cell_truncated(layout.result.active_cell_truncated);
         self.ui
             .set_explorer_pane_height(layout.explorer.pane_height);
         self.ui
             .set_explorer_content_width(layout.explorer.content_width);
-        let max_name_width = max_explorer_table_label_width(self.tables());
+        let max_name_width = max_explorer_row_label_width(&self.explorer_rows());
         let max_offset = scroll_max_offset(max_name_width, self.ui.explorer_content_width());
         self.ui
             .set_explorer_horizontal_offset(self.ui.explorer_horizontal_offset().min(max_offset));
@@ -197,6 +245,26 @@ impl AppState {
             self.query_history_picker
                 .set_filter_visible_width(query_history.filter_visible_width);
         }
+        if let Some(server_settings) = layouts.server_settings {
+            let picker = self.server_settings.picker_mut();
+            picker.set_pane_height(server_settings.pane_height);
+            picker.set_filter_visible_width(server_settings.filter_visible_width);
+        }
+        if let Some(sql_files) = layouts.sql_files {
+            let picker = self.sql_files.picker_mut();
+            picker.set_pane_height(sql_files.pane_height);
+            picker.set_filter_visible_width(sql_files.filter_visible_width);
+        }
+        if let Some(snippets) = layouts.snippets {
+            let picker = self.snippets.picker_mut();
+            picker.set_pane_height(snippets.pane_height);
+            picker.set_filter_visible_width(snippets.filter_visible_width);
+        }
+        if let Some(object_search) = layouts.object_search {
+            let picker = self.object_search.picker_mut();
+            picker.set_pane_height(object_search.pane_height);
+            picker.set_filter_visible_width(object_search.filter_visible_width);
+        }
     }
 
     fn apply_detail_layout(&mut self, layout: DetailLayout) {
@@ -243,16 +311,65 @@ impl AppState {
         self.ui.result_visible_rows()
     }
 
+    /// Type labels for the result header, blank where unknown: table metadata
+    /// for previews (with NOT NULL), the server's description for ad-hoc runs.
+    pub fn result_column_types(&self) -> Vec<String> {
+        match self.query.visible_result_kind() {
+            VisibleResultKind::LivePreview => self
+                .session
+                .table_detail()
+                .filter(|table| self.query.pagination.matches_table(table))
+                .map(|table| {
+                    table
+                        .columns
+                        .iter()
+                        .map(|column| {
+                            if column.is_nullable() {
+                                column.data_type.clone()
+                            } else {
+                                format!("{} not null", column.data_type)
+                            }
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            VisibleResultKind::LiveAdhoc => self.query.column_types().to_vec(),
+            VisibleResultKind::Empty => Vec::new(),
+        }
+    }
+
     pub fn inspector_view_model(&self, ddl_generator: &dyn DdlGenerator) -> InspectorViewModel {
+        if let Some(object) = self.session.inspected_object() {
+            return InspectorViewModel::for_object(object);
+        }
         InspectorViewModel::build(
             self.session.active_engine_feature_profile(),
             self.ui.inspector_tab(),
             self.session.table_detail(),
             self.session.active_database_type_or_default(),
             ddl_generator,
+            self.ui.inspector_pinned_column(),
+            self.ui.inspector_rls_role(),
+            self.inspector_stats_column(),
         )
     }
 
+    /// The Stats tab follows the Columns tab cursor.
+    pub fn inspector_stats_column(&self) -> InspectorStatsColumn<'_> {
+        let index = self.ui.inspector_column_cursor();
+        let distribution = self.session.table_detail().and_then(|table| {
+            let column = table
+                .columns
+                .get(index.min(table.columns.len().checked_sub(1)?))?;
+            self.column_distribution
+                .entry(&table.qualified_name(), &column.name)
+        });
+        InspectorStatsColumn {
+            index,
+            distribution,
+        }
+    }
+
     pub fn jsonb_detail_editor_visible_rows(&self) -> usize {
         self.ui.jsonb_detail_editor_visible_rows()
     }
@@ -269,6 +386,37 @@ impl AppState {
         self.session.tables()
     }
 
+    pub fn explorer_rows(&self) -> Vec<ExplorerRow<'_>> {
+        explorer_rows(
+            &self.tables(),
+            self.session.schema_objects(),
+            self.explorer_tree.layout(),
+            self.explorer_tree.schema_filter(),
+        )
+    }
+
+    pub fn explorer_selected_row(&self) -> Option<ExplorerRow<'_>> {
+        self.explorer_rows()
+            .get(self.ui.explorer_selected())
+            .copied()
+    }
+
+    /// `None` while the cursor is on a schema header.
+    pub fn explorer_selected_table(&self) -> Option<&TableSummary> {
+        self.explorer_selected_row().and_then(ExplorerRow::table)
+    }
+
+    /// Row showing `schema.table`, or its schema header while folded.
+    pub fn explorer_row_index(&self, schema: &str, table: &str) -> Option<usize> {
+        let rows = self.explorer_rows();
+        rows.iter()
+            .position(|row| {
+                row.table()
+                    .is_some_and(|t| t.schema == schema && t.name == table)
+            })
+            .or_else(|| rows.iter().position(|row| row.schema() == Some(schema)))
+    }
+
     pub fn filtered_tables(&self) -> Vec<&TableSummary> {
         let filter_lower = self
             .ui
@@ -336,6 +484,13 @@ impl AppState {
         self.rebuild_connection_list();
     }
 
+    /// Swaps in a re-saved profile without reloading the whole list.
+    pub fn replace_connection(&mut self, profile: ConnectionProfile) {
+        if let Some(existing) = self.connections.iter_mut().find(|p| p.id == profile.id) {
+            *existing = profile;
+        }
+    }
+
     pub fn retain_connections<F: FnMut(&ConnectionProfile) -> bool>(&mut self, f: F) {
         self.connections.retain(f);
         self.rebuild_connection_list();
@@ -363,6 +518,34 @@ impl AppState {
         is_rerunnable_select(&result.query)
     }
 
+    /// Why a quick action can't run on the menu's table right now.
+    pub fn quick_action_unavailable_reason(&self, action: QuickAction) -> Option<&'static str> {
+        match action {
+            QuickAction::CountRows | QuickAction::Analyze if self.session.is_offline() => {
+                Some("needs a live connection")
+            }
+            QuickAction::Analyze if self.session.is_read_only() => Some("read-only mode"),
+            QuickAction::Preview
+            | QuickAction::CountRows
+            | QuickAction::CopyDdl
+            | QuickAction::Analyze
+            | QuickAction::ToggleFavorite => None,
+            QuickAction::ErNeighborhood => {
+                let policy = FeaturePolicy::new(self.session.active_engine_feature_profile());
+                (!policy.is_enabled(FeatureRequirement::ErDiagram))
+                    .then_some("not available for this database")
+            }
+            QuickAction::ExportCsv => {
+                let previewed = self.quick_actions.target().is_some_and(|(schema, table)| {
+                    self.query.can_paginate_visible_result()
+                        && self.query.pagination.schema() == schema
+                        && self.query.pagination.table() == table
+                });
+                (!previewed || !self.can_request_csv_export()).then_some("preview the table first")
+            }
+        }
+    }
+
     pub fn visible_preview_target_read_only_reason(&self) -> Option<&'static str> {
         if !self.query.can_edit_visible_result() {
             return None;
@@ -403,6 +586,13 @@ impl AppState {
         let Some(value) = result.value_at(row_idx, col_idx) else {
             return false;
         };
+        if result
+            .columns
+            .get(col_idx)
+            .is_some_and(|name| self.query.is_column_masked(name))
+        {
+            return false;
+        }
 
         if let Some(table_detail) = self.session.table_detail()
             && let Some(column) = table_detail.columns.get(col_idx)
@@ -436,6 +626,74 @@ impl AppState {
     pub fn is_stale_query_run(&self, dsn: &str, run_id: u64) -> bool {
         !self.session.dsn_matches(dsn) || !self.query.is_current_run(run_id)
     }
+
+    pub fn active_replica_dsn(&self) -> Option<&str> {
+        let id = self.session.active_connection_id()?;
+        self.connections
+            .iter()
+            .find(|profile| &profile.id == id)
+            .and_then(ConnectionProfile::replica_dsn)
+    }
+
+    pub fn active_pool_mode(&self) -> Option<PoolMode> {
+        self.active_profile().and_then(ConnectionProfile::pool_mode)
+    }
+
+    /// `None` for service entries, which have no saved profile to edit.
+    pub fn active_profile(&self) -> Option<&ConnectionProfile> {
+        let id = self.session.active_connection_id()?;
+        self.connections.iter().find(|profile| &profile.id == id)
+    }
+
+    pub fn active_accent(&self) -> Option<ProfileAccent> {
+        self.active_profile().and_then(ConnectionProfile::accent)
+    }
+
+    pub fn active_is_production(&self) -> bool {
+        self.active_profile()
+            .is_some_and(ConnectionProfile::is_production)
+    }
+
+    pub fn active_execution_policy(&self) -> Option<&ExecutionPolicy> {
+        self.session
+            .active_connection_name()
+            .and_then(|name| self.runtime.execution_policies().for_connection(name))
+    }
+
+    /// Returns the query as the active profile's policy lets it run.
+    pub fn apply_execution_policy(&self, sql: &str) -> Result<String, PolicyViolation> {
+        match self.active_execution_policy() {
+            Some(policy) => enforce_execution_policy(
+                policy,
+                self.session.active_database_type_or_default(),
+                sql,
+            ),
+            None => Ok(sql.to_string()),
+        }
+    }
+
+    pub fn active_session_settings(&self) -> Option<&SessionSettings> {
+        self.active_profile()
+            .and_then(ConnectionProfile::session_settings)
+            .filter(|settings| !settings.is_empty())
+    }
+
+    /// Picks the endpoint for an ad-hoc query and records it for the footer.
+    /// Returns the replica DSN when the query should not run on the primary.
+    pub fn route_adhoc_query(&mut self, query: &str) -> Option<String> {
+        let Some(replica_dsn) = self.active_replica_dsn().map(String::from) else {
+            self.session.record_query_endpoint(None);
+            return None;
+        };
+        // The open transaction lives on the primary connection.
+        let endpoint = if self.session.in_transaction() {
+            QueryEndpoint::Primary
+        } else {
+            route_query(query, self.session.query_routing())
+        };
+        self.session.record_query_endpoint(Some(endpoint));
+        (endpoint == QueryEndpoint::Replica).then_some(replica_dsn)
+    }
 }
 
 #[cfg(test)]
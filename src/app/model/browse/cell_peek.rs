@@ -0,0 +1,99 @@
+use std::time::{Duration, Instant};
+
+pub const CELL_PEEK_DELAY: Duration = Duration::from_millis(500);
+
+/// Tracks how long the active result cell has stayed put, so a truncated
+/// value can pop up in a tooltip once the selection settles.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CellPeekState {
+    cell: Option<(usize, usize)>,
+    settled_at: Option<Instant>,
+    pinned: bool,
+}
+
+impl CellPeekState {
+    /// Restarts the dwell timer whenever the active cell changes.
+    pub fn sync(&mut self, cell: Option<(usize, usize)>, now: Instant) {
+        if cell != self.cell {
+            self.cell = cell;
+            self.settled_at = cell.map(|_| now);
+            self.pinned = false;
+        }
+    }
+
+    pub fn pin(&mut self) {
+        if self.cell.is_some() {
+            self.pinned = true;
+        }
+    }
+
+    pub fn is_visible(&self, now: Instant) -> bool {
+        self.pinned
+            || self
+                .settled_at
+                .is_some_and(|at| now >= at + CELL_PEEK_DELAY)
+    }
+
+    /// When the tooltip is still waiting on the dwell delay.
+    pub fn reveal_deadline(&self, now: Instant) -> Option<Instant> {
+        if self.pinned {
+            return None;
+        }
+        self.settled_at
+            .map(|at| at + CELL_PEEK_DELAY)
+            .filter(|deadline| *deadline > now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn becomes_visible_after_dwell_delay() {
+        let now = Instant::now();
+        let mut peek = CellPeekState::default();
+
+        peek.sync(Some((0, 1)), now);
+
+        assert!(!peek.is_visible(now));
+        assert_eq!(peek.reveal_deadline(now), Some(now + CELL_PEEK_DELAY));
+        assert!(peek.is_visible(now + CELL_PEEK_DELAY));
+        assert_eq!(peek.reveal_deadline(now + CELL_PEEK_DELAY), None);
+    }
+
+    #[test]
+    fn moving_restarts_timer_and_drops_pin() {
+        let now = Instant::now();
+        let mut peek = CellPeekState::default();
+        peek.sync(Some((0, 1)), now);
+        peek.pin();
+        assert!(peek.is_visible(now));
+
+        let later = now + Duration::from_millis(200);
+        peek.sync(Some((0, 2)), later);
+
+        assert!(!peek.is_visible(later));
+        assert_eq!(peek.reveal_deadline(later), Some(later + CELL_PEEK_DELAY));
+    }
+
+    #[test]
+    fn same_cell_keeps_timer() {
+        let now = Instant::now();
+        let mut peek = CellPeekState::default();
+        peek.sync(Some((3, 0)), now);
+
+        peek.sync(Some((3, 0)), now + Duration::from_millis(400));
+
+        assert!(peek.is_visible(now + CELL_PEEK_DELAY));
+    }
+
+    #[test]
+    fn pin_without_cell_is_ignored() {
+        let mut peek = CellPeekState::default();
+
+        peek.pin();
+
+        assert!(!peek.is_visible(Instant::now()));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::domain::{ExplorerTreeLayout, SchemaObjectKind};
+
+/// How the Explorer groups tables: the folded schemas, favorites and preview
+/// columns, saved per project, and the `:schema` filter, which lasts for the
+/// session only.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExplorerTreeState {
+    layout: ExplorerTreeLayout,
+    schema_filter: Option<String>,
+    dirty: bool,
+}
+
+impl ExplorerTreeState {
+    pub fn load(&mut self, layout: ExplorerTreeLayout) {
+        self.layout = layout;
+        self.dirty = false;
+    }
+
+    pub fn layout(&self) -> &ExplorerTreeLayout {
+        &self.layout
+    }
+
+    /// Folding, favorites or preview columns changed since load.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn toggle_schema(&mut self, schema: &str) {
+        self.layout.toggle(schema);
+        self.dirty = true;
+    }
+
+    pub fn toggle_section(&mut self, schema: &str, kind: SchemaObjectKind) {
+        self.layout.toggle_section(schema, kind);
+        self.dirty = true;
+    }
+
+    pub fn toggle_favorite(&mut self, qualified_name: &str) -> bool {
+        self.dirty = true;
+        self.layout.toggle_favorite(qualified_name)
+    }
+
+    pub fn set_preview_columns(&mut self, qualified_name: &str, columns: Vec<String>) {
+        self.layout.set_preview_columns(qualified_name, columns);
+        self.dirty = true;
+    }
+
+    pub fn schema_filter(&self) -> Option<&str> {
+        self.schema_filter.as_deref()
+    }
+
+    pub fn set_schema_filter(&mut self, schema: Option<String>) {
+        self.schema_filter = schema;
+    }
+
+    /// Drops a filter hiding `schema` and unfolds it, plus `section` if given.
+    pub fn reveal(&mut self, schema: &str, section: Option<SchemaObjectKind>) {
+        if self.schema_filter().is_some_and(|filter| filter != schema) {
+            self.schema_filter = None;
+        }
+        if self.layout.is_collapsed(schema) {
+            self.toggle_schema(schema);
+        }
+        if let Some(kind) = section
+            && !self.layout.is_section_expanded(schema, kind)
+        {
+            self.toggle_section(schema, kind);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_clears_dirty_flag() {
+        let mut state = ExplorerTreeState::default();
+        state.toggle_schema("audit");
+        assert!(state.is_dirty());
+
+        state.load(ExplorerTreeLayout::default());
+
+        assert!(!state.is_dirty());
+        assert!(!state.layout().is_collapsed("audit"));
+    }
+
+    #[test]
+    fn reveal_unfolds_schema_and_section_and_drops_other_filter() {
+        let mut state = ExplorerTreeState::default();
+        state.toggle_schema("audit");
+        state.set_schema_filter(Some("public".to_string()));
+
+        state.reveal("audit", Some(SchemaObjectKind::View));
+
+        assert_eq!(state.schema_filter(), None);
+        assert!(!state.layout().is_collapsed("audit"));
+        assert!(
+            state
+                .layout()
+                .is_section_expanded("audit", SchemaObjectKind::View)
+        );
+    }
+
+    #[test]
+    fn schema_filter_does_not_mark_layout_dirty() {
+        let mut state = ExplorerTreeState::default();
+
+        state.set_schema_filter(Some("audit".to_string()));
+
+        assert_eq!(state.schema_filter(), Some("audit"));
+        assert!(!state.is_dirty());
+    }
+}
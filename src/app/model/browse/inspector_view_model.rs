@@ -1,6 +1,12 @@
-use crate::domain::{DatabaseType, ForeignKey, Index, IndexType, RlsInfo, Table};
+use crate::domain::table_storage::size_label;
+use crate::domain::{
+    DatabaseType, FkAction, ForeignKey, Hypertable, Index, IndexType, RlsInfo, SchemaObject, Table,
+    TableStorage,
+};
+use crate::model::postgres::column_distribution::ColumnDistributionEntry;
 use crate::model::shared::engine_feature_profile::{EngineFeatureProfile, InspectorInfoField};
 use crate::model::shared::inspector_tab::InspectorTab;
+use crate::policy::sql::policy_expression::format_policy_expression;
 use crate::policy::table_kind::{inspector_flags_label, inspector_kind_label};
 use crate::ports::outbound::DdlGenerator;
 
@@ -10,6 +16,9 @@ pub struct InspectorViewModel {
     section: Option<InspectorSection>,
     empty_state: Option<InspectorEmptyState>,
     unavailable_reason: Option<InspectorUnavailableReason>,
+    /// Single tab shown instead of the table tabs for a view, sequence or
+    /// function.
+    object_tab: Option<&'static str>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -21,6 +30,12 @@ pub enum InspectorSection {
         rows: Vec<InspectorColumnRow>,
         show_read_only: bool,
     },
+    Stats {
+        rows: Vec<InspectorStatsRow>,
+    },
+    Storage {
+        rows: Vec<InspectorStorageRow>,
+    },
     Indexes {
         rows: Vec<InspectorIndexRow>,
         show_type: bool,
@@ -46,6 +61,20 @@ pub enum InspectorInfoRow {
         field: InspectorInfoField,
         value: Option<String>,
     },
+    HypertableSpacer,
+    HypertableHeading,
+    Hypertable {
+        field: HypertableField,
+        value: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HypertableField {
+    TimeColumn,
+    Chunks,
+    Compression,
+    Retention,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -57,6 +86,45 @@ pub struct InspectorColumnRow {
     pub read_only_reason: Option<String>,
     pub default: Option<String>,
     pub comment: Option<String>,
+    pub pinned: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InspectorStatsRow {
+    Column {
+        name: String,
+        data_type: String,
+        position: usize,
+        total: usize,
+    },
+    Note(&'static str),
+    Field {
+        label: &'static str,
+        value: String,
+    },
+    Spacer,
+    CommonValuesHeading,
+    /// `bar_permille` is the value's frequency relative to the most common one.
+    CommonValue {
+        value: String,
+        percent: String,
+        bar_permille: u16,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InspectorStorageRow {
+    Heading(&'static str),
+    Field { label: &'static str, value: String },
+    Spacer,
+}
+
+/// The column the Stats tab describes: an index into the table's columns
+/// and whatever has been fetched for it so far.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InspectorStatsColumn<'a> {
+    pub index: usize,
+    pub distribution: Option<&'a ColumnDistributionEntry>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -67,6 +135,7 @@ pub struct InspectorIndexRow {
     pub unique: bool,
     pub partial: bool,
     pub detail: Option<String>,
+    pub pinned: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -74,6 +143,9 @@ pub struct InspectorForeignKeyRow {
     pub name: String,
     pub columns: String,
     pub references: String,
+    pub on_delete: FkAction,
+    pub on_update: FkAction,
+    pub pinned: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -92,13 +164,26 @@ pub enum InspectorRlsRow {
         force: bool,
     },
     RlsSpacer,
-    RlsPoliciesHeading,
+    RlsPoliciesHeading {
+        role_filter: Option<String>,
+    },
+    RlsRole {
+        role: String,
+    },
     RlsPolicy {
         name: String,
         command: String,
         permissive: bool,
+        pinned: bool,
+    },
+    /// One line of a formatted `USING` / `WITH CHECK` expression; only the
+    /// first line of each clause carries its label.
+    RlsPolicyExpression {
+        clause: &'static str,
+        text: String,
+        continuation: bool,
+        pinned: bool,
     },
-    RlsPolicyQual(String),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -108,6 +193,7 @@ pub enum InspectorEmptyState {
     NoIndexes,
     NoForeignKeys,
     NoTriggers,
+    NoStorage,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -122,6 +208,9 @@ impl InspectorViewModel {
         table: Option<&Table>,
         database_type: DatabaseType,
         ddl_generator: &dyn DdlGenerator,
+        pinned_column: Option<&str>,
+        rls_role: Option<&str>,
+        stats_column: InspectorStatsColumn<'_>,
     ) -> Self {
         let active_tab = profile.normalize_inspector_tab(selected_tab);
         let Some(table) = table else {
@@ -130,25 +219,26 @@ impl InspectorViewModel {
                 section: None,
                 empty_state: Some(InspectorEmptyState::NoTableSelected),
                 unavailable_reason: None,
+                object_tab: None,
             };
         };
 
         let (section, empty_state, unavailable_reason) = match active_tab {
-            InspectorTab::Info => (
-                InspectorSection::Info {
-                    rows: profile
-                        .supported_inspector_info_fields()
-                        .iter()
-                        .copied()
-                        .map(|field| InspectorInfoRow::Field {
-                            field,
-                            value: info_value(field, table),
-                        })
-                        .collect(),
-                },
-                None,
-                None,
-            ),
+            InspectorTab::Info => {
+                let mut rows: Vec<InspectorInfoRow> = profile
+                    .supported_inspector_info_fields()
+                    .iter()
+                    .copied()
+                    .map(|field| InspectorInfoRow::Field {
+                        field,
+                        value: info_value(field, table),
+                    })
+                    .collect();
+                if let Some(hypertable) = &table.hypertable {
+                    rows.extend(hypertable_rows(hypertable));
+                }
+                (InspectorSection::Info { rows }, None, None)
+            }
             InspectorTab::Columns => {
                 let show_read_only = table
                     .columns
@@ -165,6 +255,7 @@ impl InspectorViewModel {
                         read_only_reason: column.read_only_reason().map(ToString::to_string),
                         default: column.default.clone(),
                         comment: column.comment.clone(),
+                        pinned: pinned_column == Some(column.name.as_str()),
                     })
                     .collect();
                 (
@@ -179,6 +270,30 @@ impl InspectorViewModel {
                     None,
                 )
             }
+            InspectorTab::Stats => (
+                InspectorSection::Stats {
+                    rows: stats_rows(table, stats_column),
+                },
+                table
+                    .columns
+                    .is_empty()
+                    .then_some(InspectorEmptyState::NoColumns),
+                None,
+            ),
+            InspectorTab::Storage => (
+                InspectorSection::Storage {
+                    rows: table
+                        .storage
+                        .as_ref()
+                        .map(|storage| storage_rows(storage, table.row_count_estimate))
+                        .unwrap_or_default(),
+                },
+                table
+                    .storage
+                    .is_none()
+                    .then_some(InspectorEmptyState::NoStorage),
+                None,
+            ),
             InspectorTab::Indexes => {
                 let show_type = table
                     .indexes
@@ -195,6 +310,7 @@ impl InspectorViewModel {
                         unique: index.is_unique(),
                         partial: index.is_partial(),
                         detail: index.has_index_detail().then(|| index_detail(index)),
+                        pinned: pinned_column.is_some_and(|column| index_involves(index, column)),
                     })
                     .collect();
                 (
@@ -211,7 +327,11 @@ impl InspectorViewModel {
                 )
             }
             InspectorTab::ForeignKeys => {
-                let rows = table.foreign_keys.iter().map(foreign_key_row).collect();
+                let rows = table
+                    .foreign_keys
+                    .iter()
+                    .map(|fk| foreign_key_row(fk, table, pinned_column))
+                    .collect();
                 (
                     InspectorSection::ForeignKeys { rows },
                     table
@@ -229,7 +349,7 @@ impl InspectorViewModel {
                 ),
                 Some(rls) => (
                     InspectorSection::Rls {
-                        rows: rls_rows(rls),
+                        rows: rls_rows(rls, pinned_column, rls_role),
                     },
                     None,
                     None,
@@ -279,9 +399,29 @@ impl InspectorViewModel {
             section: Some(section),
             empty_state,
             unavailable_reason,
+            object_tab: None,
+        }
+    }
+
+    /// Objects have one read-only text view, scrolled like the DDL tab.
+    pub fn for_object(object: &SchemaObject) -> Self {
+        let rows = object.definition.as_deref().map_or_else(
+            || vec!["(definition unavailable)".to_string()],
+            |definition| definition.lines().map(str::to_string).collect(),
+        );
+        Self {
+            active_tab: InspectorTab::Ddl,
+            section: Some(InspectorSection::Ddl { rows }),
+            empty_state: None,
+            unavailable_reason: None,
+            object_tab: Some(object.kind.inspector_label()),
         }
     }
 
+    pub fn object_tab(&self) -> Option<&'static str> {
+        self.object_tab
+    }
+
     pub fn active_tab(&self) -> InspectorTab {
         self.active_tab
     }
@@ -306,6 +446,8 @@ impl InspectorViewModel {
         match self.section.as_ref() {
             Some(
                 InspectorSection::Info { .. }
+                | InspectorSection::Stats { .. }
+                | InspectorSection::Storage { .. }
                 | InspectorSection::Rls { .. }
                 | InspectorSection::Ddl { .. },
             ) => pane_height.saturating_sub(3) as usize,
@@ -317,6 +459,23 @@ impl InspectorViewModel {
         self.row_count()
             .saturating_sub(self.visible_rows(pane_height))
     }
+
+    /// The Columns tab cursor as drawn: kept inside the rows the current
+    /// scroll offset shows, since other tabs scroll without moving it.
+    pub fn column_cursor(
+        &self,
+        cursor: usize,
+        scroll_offset: usize,
+        pane_height: u16,
+    ) -> Option<usize> {
+        let Some(InspectorSection::Columns { rows, .. }) = self.section.as_ref() else {
+            return None;
+        };
+        let last = rows.len().checked_sub(1)?;
+        let top = scroll_offset.min(self.max_scroll(pane_height));
+        let bottom = top + self.visible_rows(pane_height).max(1) - 1;
+        Some(cursor.clamp(top, bottom).min(last))
+    }
 }
 
 impl InspectorSection {
@@ -324,6 +483,8 @@ impl InspectorSection {
         match self {
             Self::Info { rows } => rows.len(),
             Self::Columns { rows, .. } => rows.len(),
+            Self::Stats { rows } => rows.len(),
+            Self::Storage { rows } => rows.len(),
             Self::Indexes { rows, .. } => rows.len(),
             Self::ForeignKeys { rows } => rows.len(),
             Self::Rls { rows } => rows.len(),
@@ -341,6 +502,7 @@ impl InspectorEmptyState {
             Self::NoIndexes => "No indexes",
             Self::NoForeignKeys => "No foreign keys",
             Self::NoTriggers => "No triggers",
+            Self::NoStorage => "No storage details",
         }
     }
 }
@@ -365,6 +527,143 @@ fn info_value(field: InspectorInfoField, table: &Table) -> Option<String> {
     }
 }
 
+fn stats_rows(table: &Table, stats_column: InspectorStatsColumn<'_>) -> Vec<InspectorStatsRow> {
+    let Some(last) = table.columns.len().checked_sub(1) else {
+        return Vec::new();
+    };
+    let index = stats_column.index.min(last);
+    let column = &table.columns[index];
+    let mut rows = vec![InspectorStatsRow::Column {
+        name: column.name.clone(),
+        data_type: column.data_type.clone(),
+        position: index + 1,
+        total: table.columns.len(),
+    }];
+    let distribution = match stats_column.distribution {
+        None | Some(ColumnDistributionEntry::Loading { .. }) => {
+            rows.push(InspectorStatsRow::Note("Loading stats…"));
+            return rows;
+        }
+        Some(ColumnDistributionEntry::Failed) => {
+            rows.push(InspectorStatsRow::Note("Stats unavailable"));
+            return rows;
+        }
+        Some(ColumnDistributionEntry::Loaded(distribution)) => distribution,
+    };
+
+    rows.push(InspectorStatsRow::Spacer);
+    let fields = [
+        ("Min", distribution.min.clone()),
+        ("Max", distribution.max.clone()),
+        ("Avg", distribution.avg.clone()),
+        ("Nulls", distribution.null_frac.map(percent_label)),
+        ("Distinct", distribution.distinct_label()),
+        (
+            "Sampled",
+            Some(format!("{} rows", distribution.sampled_rows)),
+        ),
+    ];
+    rows.extend(
+        fields.into_iter().filter_map(|(label, value)| {
+            value.map(|value| InspectorStatsRow::Field { label, value })
+        }),
+    );
+
+    rows.push(InspectorStatsRow::Spacer);
+    rows.push(InspectorStatsRow::CommonValuesHeading);
+    if distribution.common_values.is_empty() {
+        rows.push(InspectorStatsRow::Note("No repeated values"));
+        return rows;
+    }
+    let top = distribution
+        .common_values
+        .iter()
+        .map(|common| common.frequency)
+        .fold(0.0, f64::max);
+    rows.extend(distribution.common_values.iter().map(|common| {
+        let bar_permille = if top > 0.0 {
+            (common.frequency / top * 1000.0).round().clamp(0.0, 1000.0) as u16
+        } else {
+            0
+        };
+        InspectorStatsRow::CommonValue {
+            value: common.value.clone(),
+            percent: percent_label(common.frequency),
+            bar_permille,
+        }
+    }));
+    rows
+}
+
+fn storage_rows(
+    storage: &TableStorage,
+    row_count_estimate: Option<i64>,
+) -> Vec<InspectorStorageRow> {
+    let field = |label, value: Option<String>| InspectorStorageRow::Field {
+        label,
+        value: value.unwrap_or_else(|| "-".to_string()),
+    };
+    let last_run =
+        |time: &Option<String>| Some(time.clone().unwrap_or_else(|| "never".to_string()));
+    let dead = storage.dead_tuples.map(|dead| {
+        storage.dead_tuple_ratio().map_or_else(
+            || dead.to_string(),
+            |ratio| format!("{dead} ({})", percent_label(ratio)),
+        )
+    });
+    vec![
+        InspectorStorageRow::Heading("Size"),
+        field("Total", Some(size_label(storage.total_bytes))),
+        field("Table", Some(size_label(storage.table_bytes))),
+        field("Indexes", Some(size_label(storage.index_bytes))),
+        field("TOAST", Some(size_label(storage.toast_bytes))),
+        InspectorStorageRow::Spacer,
+        InspectorStorageRow::Heading("Tuples"),
+        field("Rows", row_count_estimate.map(|count| format!("~{count}"))),
+        field("Live", storage.live_tuples.map(|live| live.to_string())),
+        field("Dead", dead),
+        InspectorStorageRow::Spacer,
+        InspectorStorageRow::Heading("Maintenance"),
+        field("Vacuum", last_run(&storage.last_vacuum)),
+        field("Autovacuum", last_run(&storage.last_autovacuum)),
+        field("Analyze", last_run(&storage.last_analyze)),
+        field("Autoanalyze", last_run(&storage.last_autoanalyze)),
+    ]
+}
+
+fn percent_label(fraction: f64) -> String {
+    format!("{:.1}%", fraction * 100.0)
+}
+
+fn hypertable_rows(hypertable: &Hypertable) -> Vec<InspectorInfoRow> {
+    let mut rows = vec![
+        InspectorInfoRow::HypertableSpacer,
+        InspectorInfoRow::HypertableHeading,
+    ];
+    rows.extend(
+        [
+            HypertableField::TimeColumn,
+            HypertableField::Chunks,
+            HypertableField::Compression,
+            HypertableField::Retention,
+        ]
+        .into_iter()
+        .map(|field| InspectorInfoRow::Hypertable {
+            field,
+            value: match field {
+                HypertableField::TimeColumn => hypertable.time_column.clone(),
+                HypertableField::Chunks => Some(hypertable.chunk_count.to_string()),
+                HypertableField::Compression => Some(hypertable.compression_label()),
+                HypertableField::Retention => hypertable
+                    .retention
+                    .as_ref()
+                    .map(|interval| format!("drop after {interval}")),
+            },
+        }),
+    );
+    rows
+}
+
 fn index_detail(index: &Index) -> String {
     if index.needs_source_definition_detail()
         && let Some(definition) = &index.definition
@@ -395,7 +694,47 @@ fn index_type_label(index: &Index) -> Option<String> {
     }
 }
 
-fn foreign_key_row(fk: &ForeignKey) -> InspectorForeignKeyRow {
+fn index_involves(index: &Index, column: &str) -> bool {
+    let definition_matters =
+        index.has_expression() || index.is_partial() || index.has_auxiliary_columns();
+    index.columns.iter().any(|name| name == column)
+        || (definition_matters
+            && index
+                .definition
+                .as_deref()
+                .is_some_and(|definition| mentions_column(definition, column)))
+}
+
+// Outgoing keys list the column on the referencing side; self-references
+// may name it on either side.
+fn foreign_key_involves(fk: &ForeignKey, table: &Table, column: &str) -> bool {
+    let on_side = |schema: &str, name: &str, columns: &[String]| {
+        schema == table.schema && name == table.name && columns.iter().any(|c| c == column)
+    };
+    on_side(&fk.from_schema, &fk.from_table, &fk.from_columns)
+        || on_side(&fk.to_schema, &fk.to_table, &fk.to_columns)
+}
+
+/// Whole-identifier match, so pinning `id` does not light up `org_id`.
+fn mentions_column(expression: &str, column: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let haystack = expression.to_lowercase();
+    let needle = column.to_lowercase();
+    if needle.is_empty() {
+        return false;
+    }
+    haystack.match_indices(&needle).any(|(start, _)| {
+        let before = haystack[..start].chars().next_back();
+        let after = haystack[start + needle.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+fn foreign_key_row(
+    fk: &ForeignKey,
+    table: &Table,
+    pinned_column: Option<&str>,
+) -> InspectorForeignKeyRow {
     let references = format!(
         "{}.{}({})",
         fk.to_schema,
@@ -410,25 +749,65 @@ fn foreign_key_row(fk: &ForeignKey) -> InspectorForeignKeyRow {
         } else {
             format!("{references} (unresolved)")
         },
+        on_delete: fk.on_delete.clone(),
+        on_update: fk.on_update.clone(),
+        pinned: pinned_column.is_some_and(|column| foreign_key_involves(fk, table, column)),
     }
 }
 
-fn rls_rows(rls: &RlsInfo) -> Vec<InspectorRlsRow> {
+// Policies are listed under each role they apply to, so a policy shared by
+// several roles shows up once per role; `rls_role` keeps a single group.
+fn rls_rows(
+    rls: &RlsInfo,
+    pinned_column: Option<&str>,
+    rls_role: Option<&str>,
+) -> Vec<InspectorRlsRow> {
     let mut rows = vec![InspectorRlsRow::RlsStatus {
         enabled: rls.enabled,
         force: rls.force,
     }];
-    if !rls.policies.is_empty() {
-        rows.push(InspectorRlsRow::RlsSpacer);
-        rows.push(InspectorRlsRow::RlsPoliciesHeading);
-        for policy in &rls.policies {
+    if rls.policies.is_empty() {
+        return rows;
+    }
+    rows.push(InspectorRlsRow::RlsSpacer);
+    rows.push(InspectorRlsRow::RlsPoliciesHeading {
+        role_filter: rls_role.map(String::from),
+    });
+    let roles = rls
+        .roles()
+        .into_iter()
+        .filter(|role| rls_role.is_none_or(|filter| filter == *role));
+    for role in roles {
+        rows.push(InspectorRlsRow::RlsRole {
+            role: role.to_string(),
+        });
+        for policy in rls.policies.iter().filter(|policy| policy.applies_to(role)) {
+            let pinned = pinned_column.is_some_and(|column| {
+                [&policy.qual, &policy.with_check]
+                    .into_iter()
+                    .flatten()
+                    .any(|expression| mentions_column(expression, column))
+            });
             rows.push(InspectorRlsRow::RlsPolicy {
                 name: policy.name.clone(),
                 command: policy.cmd.to_string(),
                 permissive: policy.permissive,
+                pinned,
             });
-            if let Some(qual) = &policy.qual {
-                rows.push(InspectorRlsRow::RlsPolicyQual(qual.clone()));
+            let clauses = [("USING", &policy.qual), ("WITH CHECK", &policy.with_check)];
+            for (clause, expression) in clauses {
+                let Some(expression) = expression else {
+                    continue;
+                };
+                let lines = format_policy_expression(expression);
+                rows.extend(lines.into_iter().enumerate().map(|(index, text)| {
+                    InspectorRlsRow::RlsPolicyExpression {
+                        clause,
+                        text,
+                        continuation: index > 0,
+                        pinned,
+                    }
+                }));
             }
         }
     }
@@ -439,8 +818,8 @@ fn rls_rows(rls: &RlsInfo) -> Vec<InspectorRlsRow> {
 mod tests {
     use super::*;
     use crate::domain::{
-        Column, ColumnAttributes, FkAction, IndexAttributes, RlsCommand, RlsPolicy, TableKindInfo,
-        Trigger, TriggerEvent, TriggerTiming,
+        Column, ColumnAttributes, IndexAttributes, RlsCommand, RlsPolicy, SchemaObjectKind,
+        TableKindInfo, Trigger, TriggerEvent, TriggerTiming,
     };
 
     struct TestDdlGenerator;
@@ -507,6 +886,9 @@ mod tests {
             comment: Some("Users".to_string()),
             source_ddl: None,
             kind_info: TableKindInfo::default(),
+            hypertable: None,
+            column_stats: Vec::new(),
+            storage: None,
         }
     }
 
@@ -518,6 +900,9 @@ mod tests {
             None,
             DatabaseType::PostgreSQL,
             &TestDdlGenerator,
+            None,
+            None,
+            InspectorStatsColumn::default(),
         );
 
         assert_eq!(model.row_count(), 0);
@@ -528,15 +913,37 @@ mod tests {
         assert_eq!(model.unavailable_reason(), None);
     }
 
+    #[test]
+    fn object_shows_its_definition_under_a_single_tab() {
+        let function = SchemaObject {
+            schema: "public".to_string(),
+            name: "add".to_string(),
+            kind: SchemaObjectKind::Function,
+            arguments: Some("a integer, b integer".to_string()),
+            definition: Some(
+                "FUNCTION public.add(a integer, b integer) RETURNS integer LANGUAGE sql"
+                    .to_string(),
+            ),
+        };
+
+        let model = InspectorViewModel::for_object(&function);
+
+        assert_eq!(model.object_tab(), Some("Signature"));
+        assert_eq!(model.active_tab(), InspectorTab::Ddl);
+        assert_eq!(model.row_count(), 1);
+        assert_eq!(model.empty_state(), None);
+    }
+
     #[test]
     fn each_section_row_count_is_the_scroll_item_count() {
         let table = table();
         let cases = [
             (InspectorTab::Info, 5),
             (InspectorTab::Columns, 1),
+            (InspectorTab::Stats, 2),
             (InspectorTab::Indexes, 1),
             (InspectorTab::ForeignKeys, 1),
-            (InspectorTab::Rls, 5),
+            (InspectorTab::Rls, 6),
             (InspectorTab::Triggers, 1),
             (InspectorTab::Ddl, 3),
         ];
@@ -548,6 +955,9 @@ mod tests {
                 Some(&table),
                 DatabaseType::PostgreSQL,
                 &TestDdlGenerator,
+                None,
+                None,
+                InspectorStatsColumn::default(),
             );
 
             assert_eq!(model.active_tab(), tab);
@@ -559,6 +969,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn hypertable_adds_info_section_after_table_fields() {
+        let mut table = table();
+        table.hypertable = Some(Hypertable {
+            time_column: Some("time".to_string()),
+            chunk_count: 12,
+            compression_enabled: false,
+            compressed_chunk_count: 0,
+            retention: Some("90 days".to_string()),
+        });
+
+        let model = InspectorViewModel::build(
+            &EngineFeatureProfile::postgres_like(),
+            InspectorTab::Info,
+            Some(&table),
+            DatabaseType::PostgreSQL,
+            &TestDdlGenerator,
+            None,
+            None,
+            InspectorStatsColumn::default(),
+        );
+
+        let Some(InspectorSection::Info { rows }) = model.section() else {
+            panic!("expected info section");
+        };
+        assert_eq!(rows.len(), 11);
+        assert_eq!(rows[6], InspectorInfoRow::HypertableHeading);
+        assert_eq!(
+            rows[10],
+            InspectorInfoRow::Hypertable {
+                field: HypertableField::Retention,
+                value: Some("drop after 90 days".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn storage_lists_sizes_tuples_and_last_runs() {
+        let mut table = table();
+        let build = |table: &Table| {
+            InspectorViewModel::build(
+                &EngineFeatureProfile::postgres_like(),
+                InspectorTab::Storage,
+                Some(table),
+                DatabaseType::PostgreSQL,
+                &TestDdlGenerator,
+                None,
+                None,
+                InspectorStatsColumn::default(),
+            )
+        };
+        assert_eq!(
+            build(&table).empty_state(),
+            Some(InspectorEmptyState::NoStorage)
+        );
+
+        table.storage = Some(TableStorage {
+            total_bytes: 81920,
+            table_bytes: 49152,
+            index_bytes: 32768,
+            toast_bytes: 0,
+            live_tuples: Some(3),
+            dead_tuples: Some(1),
+            last_vacuum: None,
+            last_autovacuum: Some("2026-01-02 03:04:05".to_string()),
+            last_analyze: None,
+            last_autoanalyze: None,
+        });
+        let model = build(&table);
+
+        let Some(InspectorSection::Storage { rows }) = model.section() else {
+            panic!("expected storage section");
+        };
+        let value = |wanted: &str| {
+            rows.iter().find_map(|row| match row {
+                InspectorStorageRow::Field { label, value } if *label == wanted => {
+                    Some(value.as_str())
+                }
+                _ => None,
+            })
+        };
+        assert_eq!(value("Total"), Some("80 kB"));
+        assert_eq!(value("TOAST"), Some("0 bytes"));
+        assert_eq!(value("Rows"), Some("~3"));
+        assert_eq!(value("Dead"), Some("1 (25.0%)"));
+        assert_eq!(value("Vacuum"), Some("never"));
+        assert_eq!(value("Autovacuum"), Some("2026-01-02 03:04:05"));
+    }
+
     #[test]
     fn empty_and_unavailable_sections_have_no_scrollable_rows() {
         let mut table = table();
@@ -571,6 +1070,9 @@ mod tests {
             Some(&table),
             DatabaseType::PostgreSQL,
             &TestDdlGenerator,
+            None,
+            None,
+            InspectorStatsColumn::default(),
         );
         assert_eq!(empty.row_count(), 0);
         assert_eq!(empty.empty_state(), Some(InspectorEmptyState::NoColumns));
@@ -581,6 +1083,9 @@ mod tests {
             Some(&table),
             DatabaseType::PostgreSQL,
             &TestDdlGenerator,
+            None,
+            None,
+            InspectorStatsColumn::default(),
         );
         assert_eq!(unavailable.row_count(), 0);
         assert_eq!(
@@ -594,7 +1099,7 @@ mod tests {
         let table = table();
         let cases = [
             (InspectorTab::Info, 5_usize),
-            (InspectorTab::Rls, 5_usize),
+            (InspectorTab::Rls, 6_usize),
             (InspectorTab::Ddl, 3_usize),
         ];
 
@@ -605,6 +1110,9 @@ mod tests {
                 Some(&table),
                 DatabaseType::PostgreSQL,
                 &TestDdlGenerator,
+                None,
+                None,
+                InspectorStatsColumn::default(),
             );
 
             assert_eq!(model.visible_rows(8), 5, "tab={tab:?}");
@@ -627,6 +1135,9 @@ mod tests {
             Some(&table),
             DatabaseType::PostgreSQL,
             &TestDdlGenerator,
+            None,
+            None,
+            InspectorStatsColumn::default(),
         );
 
         assert_eq!(model.visible_rows(8), 3);
@@ -661,6 +1172,9 @@ mod tests {
             Some(&table),
             DatabaseType::PostgreSQL,
             &TestDdlGenerator,
+            None,
+            None,
+            InspectorStatsColumn::default(),
         );
 
         match model.section() {
@@ -674,4 +1188,273 @@ mod tests {
             section => panic!("expected index section, got {section:?}"),
         }
     }
+
+    mod stats {
+        use super::*;
+        use crate::domain::{ColumnDistribution, CommonValue};
+
+        fn build(entry: Option<&ColumnDistributionEntry>) -> Vec<InspectorStatsRow> {
+            let model = InspectorViewModel::build(
+                &EngineFeatureProfile::postgres_like(),
+                InspectorTab::Stats,
+                Some(&table()),
+                DatabaseType::PostgreSQL,
+                &TestDdlGenerator,
+                None,
+                None,
+                InspectorStatsColumn {
+                    index: 3,
+                    distribution: entry,
+                },
+            );
+            let Some(InspectorSection::Stats { rows }) = model.section().cloned() else {
+                panic!("expected stats section");
+            };
+            rows
+        }
+
+        #[test]
+        fn unfetched_column_shows_loading_under_its_heading() {
+            assert_eq!(
+                build(None),
+                vec![
+                    InspectorStatsRow::Column {
+                        name: "id".to_string(),
+                        data_type: "integer".to_string(),
+                        position: 1,
+                        total: 1,
+                    },
+                    InspectorStatsRow::Note("Loading stats…"),
+                ]
+            );
+        }
+
+        #[test]
+        fn loaded_distribution_lists_figures_then_scaled_bars() {
+            let entry = ColumnDistributionEntry::Loaded(ColumnDistribution {
+                sampled_rows: 200,
+                min: Some("1".to_string()),
+                max: Some("9".to_string()),
+                null_frac: Some(0.125),
+                n_distinct: Some(-1.0),
+                common_values: vec![
+                    CommonValue {
+                        value: "4".to_string(),
+                        frequency: 0.4,
+                    },
+                    CommonValue {
+                        value: "7".to_string(),
+                        frequency: 0.1,
+                    },
+                ],
+                ..ColumnDistribution::default()
+            });
+
+            let rows = build(Some(&entry));
+
+            assert_eq!(
+                rows[2..],
+                [
+                    InspectorStatsRow::Field {
+                        label: "Min",
+                        value: "1".to_string()
+                    },
+                    InspectorStatsRow::Field {
+                        label: "Max",
+                        value: "9".to_string()
+                    },
+                    InspectorStatsRow::Field {
+                        label: "Nulls",
+                        value: "12.5%".to_string()
+                    },
+                    InspectorStatsRow::Field {
+                        label: "Distinct",
+                        value: "unique".to_string()
+                    },
+                    InspectorStatsRow::Field {
+                        label: "Sampled",
+                        value: "200 rows".to_string()
+                    },
+                    InspectorStatsRow::Spacer,
+                    InspectorStatsRow::CommonValuesHeading,
+                    InspectorStatsRow::CommonValue {
+                        value: "4".to_string(),
+                        percent: "40.0%".to_string(),
+                        bar_permille: 1000,
+                    },
+                    InspectorStatsRow::CommonValue {
+                        value: "7".to_string(),
+                        percent: "10.0%".to_string(),
+                        bar_permille: 250,
+                    },
+                ]
+            );
+        }
+    }
+
+    mod pinned_column {
+        use super::*;
+
+        fn build(tab: InspectorTab, table: &Table, pinned: &str) -> InspectorViewModel {
+            InspectorViewModel::build(
+                &EngineFeatureProfile::postgres_like(),
+                tab,
+                Some(table),
+                DatabaseType::PostgreSQL,
+                &TestDdlGenerator,
+                Some(pinned),
+                None,
+                InspectorStatsColumn::default(),
+            )
+        }
+
+        #[test]
+        fn marks_the_column_and_the_indexes_using_it() {
+            let table = table();
+
+            let Some(InspectorSection::Columns { rows, .. }) =
+                build(InspectorTab::Columns, &table, "id")
+                    .section()
+                    .cloned()
+            else {
+                panic!("expected columns section");
+            };
+            assert!(rows[0].pinned);
+
+            let Some(InspectorSection::Indexes { rows, .. }) =
+                build(InspectorTab::Indexes, &table, "id")
+                    .section()
+                    .cloned()
+            else {
+                panic!("expected indexes section");
+            };
+            assert!(rows[0].pinned);
+        }
+
+        #[test]
+        fn foreign_keys_match_the_referencing_column_only() {
+            let table = table();
+
+            let Some(InspectorSection::ForeignKeys { rows }) =
+                build(InspectorTab::ForeignKeys, &table, "org_id")
+                    .section()
+                    .cloned()
+            else {
+                panic!("expected foreign keys section");
+            };
+            assert!(rows[0].pinned);
+
+            // `id` appears in the referenced column list, but of another table
+            let Some(InspectorSection::ForeignKeys { rows }) =
+                build(InspectorTab::ForeignKeys, &table, "id")
+                    .section()
+                    .cloned()
+            else {
+                panic!("expected foreign keys section");
+            };
+            assert!(!rows[0].pinned);
+        }
+
+        #[test]
+        fn policies_match_whole_identifiers_in_their_expressions() {
+            let mut table = table();
+            table.rls.as_mut().unwrap().policies[0].qual = Some("(org_id = 1)".to_string());
+
+            let pinned_rows = |column: &str| {
+                let Some(InspectorSection::Rls { rows }) =
+                    build(InspectorTab::Rls, &table, column).section().cloned()
+                else {
+                    panic!("expected rls section");
+                };
+                rows.iter()
+                    .filter(|row| {
+                        matches!(
+                            row,
+                            InspectorRlsRow::RlsPolicy { pinned: true, .. }
+                                | InspectorRlsRow::RlsPolicyExpression { pinned: true, .. }
+                        )
+                    })
+                    .count()
+            };
+
+            assert_eq!(pinned_rows("org_id"), 2);
+            assert_eq!(pinned_rows("id"), 0);
+        }
+    }
+
+    mod rls_roles {
+        use super::*;
+
+        fn multi_tenant_table() -> Table {
+            let mut table = table();
+            let policy = |name: &str, roles: &[&str], qual: &str| RlsPolicy {
+                name: name.to_string(),
+                permissive: true,
+                roles: roles.iter().map(ToString::to_string).collect(),
+                cmd: RlsCommand::All,
+                qual: Some(qual.to_string()),
+                with_check: None,
+            };
+            table.rls.as_mut().unwrap().policies = vec![
+                policy(
+                    "tenant_isolation",
+                    &["app_user", "reporting"],
+                    "((tenant_id = 1) AND (deleted_at IS NULL))",
+                ),
+                policy("reporting_all", &["reporting"], "true"),
+            ];
+            table
+        }
+
+        fn rls_rows(table: &Table, role: Option<&str>) -> Vec<InspectorRlsRow> {
+            let model = InspectorViewModel::build(
+                &EngineFeatureProfile::postgres_like(),
+                InspectorTab::Rls,
+                Some(table),
+                DatabaseType::PostgreSQL,
+                &TestDdlGenerator,
+                None,
+                role,
+                InspectorStatsColumn::default(),
+            );
+            let Some(InspectorSection::Rls { rows }) = model.section().cloned() else {
+                panic!("expected rls section");
+            };
+            rows
+        }
+
+        fn role_headings(rows: &[InspectorRlsRow]) -> Vec<&str> {
+            rows.iter()
+                .filter_map(|row| match row {
+                    InspectorRlsRow::RlsRole { role } => Some(role.as_str()),
+                    _ => None,
+                })
+                .collect()
+        }
+
+        #[test]
+        fn policies_are_grouped_under_each_role() {
+            let rows = rls_rows(&multi_tenant_table(), None);
+
+            assert_eq!(role_headings(&rows), vec!["app_user", "reporting"]);
+            assert!(rows.contains(&InspectorRlsRow::RlsPolicyExpression {
+                clause: "USING",
+                text: "AND (deleted_at IS NULL)".to_string(),
+                continuation: true,
+                pinned: false,
+            }));
+        }
+
+        #[test]
+        fn role_filter_keeps_one_group() {
+            let rows = rls_rows(&multi_tenant_table(), Some("app_user"));
+
+            assert_eq!(role_headings(&rows), vec!["app_user"]);
+            let policies = rows
+                .iter()
+                .filter(|row| matches!(row, InspectorRlsRow::RlsPolicy { .. }))
+                .count();
+            assert_eq!(policies, 1);
+        }
+    }
 }
@@ -0,0 +1,163 @@
+use std::time::Instant;
+
+use crate::domain::QueryResult;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaintenanceProgress {
+    pub phase: String,
+    pub done: u64,
+    pub total: u64,
+}
+
+impl MaintenanceProgress {
+    /// Reads the first `phase, done, total` row; `None` once the command has
+    /// left the progress view (finished, or not started yet).
+    pub fn from_result(result: &QueryResult) -> Option<Self> {
+        let phase = result.display_value_at(0, 0)?;
+        let number = |col| {
+            result
+                .display_value_at(0, col)
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+        Some(Self {
+            phase,
+            done: number(1),
+            total: number(2),
+        })
+    }
+
+    pub fn percent(&self) -> Option<u64> {
+        (self.total > 0).then(|| (self.done.min(self.total) * 100) / self.total)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaintenanceJob {
+    pub run_id: u64,
+    pub dsn: String,
+    pub label: &'static str,
+    /// `schema.table` for table commands, `schema.index` for index rebuilds.
+    pub target: String,
+    pub progress_query: Option<String>,
+    pub started_at: Instant,
+    pub progress: Option<MaintenanceProgress>,
+}
+
+/// At most one maintenance command runs at a time; it outlives connection
+/// switches, so completions are matched by run id rather than the active DSN.
+#[derive(Debug, Clone, Default)]
+pub struct MaintenanceState {
+    next_run_id: u64,
+    job: Option<MaintenanceJob>,
+}
+
+impl MaintenanceState {
+    pub fn begin(
+        &mut self,
+        dsn: String,
+        label: &'static str,
+        target: String,
+        progress_query: Option<String>,
+        now: Instant,
+    ) -> u64 {
+        self.next_run_id = self.next_run_id.wrapping_add(1);
+        self.job = Some(MaintenanceJob {
+            run_id: self.next_run_id,
+            dsn,
+            label,
+            target,
+            progress_query,
+            started_at: now,
+            progress: None,
+        });
+        self.next_run_id
+    }
+
+    pub fn job(&self) -> Option<&MaintenanceJob> {
+        self.job.as_ref()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.job.is_some()
+    }
+
+    pub fn current(&self, run_id: u64) -> Option<&MaintenanceJob> {
+        self.job.as_ref().filter(|job| job.run_id == run_id)
+    }
+
+    pub fn set_progress(&mut self, run_id: u64, progress: Option<MaintenanceProgress>) {
+        if let Some(job) = self.job.as_mut().filter(|job| job.run_id == run_id) {
+            job.progress = progress;
+        }
+    }
+
+    pub fn finish(&mut self, run_id: u64) -> Option<MaintenanceJob> {
+        if self.current(run_id).is_some() {
+            self.job.take()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::QuerySource;
+
+    fn progress_result(rows: Vec<Vec<String>>) -> QueryResult {
+        QueryResult::success(
+            "SELECT phase, done, total".to_string(),
+            vec!["phase".to_string(), "done".to_string(), "total".to_string()],
+            rows,
+            1,
+            QuerySource::Adhoc,
+        )
+    }
+
+    #[test]
+    fn progress_reads_first_row() {
+        let result = progress_result(vec![vec![
+            "scanning heap".to_string(),
+            "250".to_string(),
+            "1000".to_string(),
+        ]]);
+
+        let progress = MaintenanceProgress::from_result(&result).unwrap();
+
+        assert_eq!(progress.phase, "scanning heap");
+        assert_eq!(progress.percent(), Some(25));
+    }
+
+    #[test]
+    fn progress_is_absent_without_rows_and_unknown_without_total() {
+        assert_eq!(
+            MaintenanceProgress::from_result(&progress_result(vec![])),
+            None
+        );
+        let initializing = MaintenanceProgress {
+            phase: "initializing".to_string(),
+            done: 0,
+            total: 0,
+        };
+        assert_eq!(initializing.percent(), None);
+    }
+
+    #[test]
+    fn finish_ignores_other_runs() {
+        let mut state = MaintenanceState::default();
+        let run_id = state.begin(
+            "postgres://localhost/app".to_string(),
+            "ANALYZE",
+            "public.users".to_string(),
+            None,
+            Instant::now(),
+        );
+
+        assert!(state.finish(run_id + 1).is_none());
+        assert!(state.is_running());
+        assert_eq!(state.finish(run_id).unwrap().target, "public.users");
+        assert!(!state.is_running());
+    }
+}
@@ -1,8 +1,15 @@
 pub mod cell_detail;
 pub mod cell_edit;
+pub mod cell_peek;
+pub mod explorer_tree;
 pub mod inspector_view_model;
 pub mod jsonb_detail;
+pub mod maintenance;
+pub mod object_search;
 pub mod query_execution;
+pub mod quick_actions;
+pub mod result_column_format;
+pub mod result_diff;
 pub mod result_history;
 pub mod result_interaction;
 pub mod row_detail;
@@ -0,0 +1,360 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use nucleo_matcher::pattern::{CaseMatching, Normalization, Pattern};
+use nucleo_matcher::{Config, Matcher};
+
+use crate::domain::{DatabaseMetadata, SchemaObjectKind, Table};
+use crate::model::shared::picker::PickerState;
+
+const MAX_RESULTS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchEntryKind {
+    Table,
+    Column,
+    Object(SchemaObjectKind),
+}
+
+impl SearchEntryKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Table => "table",
+            Self::Column => "column",
+            Self::Object(SchemaObjectKind::View) => "view",
+            Self::Object(SchemaObjectKind::MaterializedView) => "matview",
+            Self::Object(SchemaObjectKind::Sequence) => "sequence",
+            Self::Object(SchemaObjectKind::Function) => "function",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchTarget {
+    Table {
+        schema: String,
+        table: String,
+    },
+    Column {
+        schema: String,
+        table: String,
+        column: String,
+    },
+    Object {
+        schema: String,
+        kind: SchemaObjectKind,
+        qualified_name: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchEntry {
+    pub kind: SearchEntryKind,
+    /// Qualified name shown in the list and matched first.
+    pub label: String,
+    pub comment: Option<String>,
+    pub target: SearchTarget,
+}
+
+pub struct SearchHit<'a> {
+    pub entry: &'a SearchEntry,
+    /// Match positions within `entry.label`; empty when only the comment matched.
+    pub match_indices: Vec<u32>,
+}
+
+/// Tables and schema objects come from the metadata; columns and comments
+/// join as table details load, so the index grows with prefetch.
+#[derive(Debug, Clone, Default)]
+pub struct ObjectSearchState {
+    indexed: Option<Arc<DatabaseMetadata>>,
+    entries: Vec<SearchEntry>,
+    details: BTreeMap<String, Vec<SearchEntry>>,
+    picker: PickerState,
+    pending_column: Option<SearchTarget>,
+}
+
+impl ObjectSearchState {
+    /// Rebuilds from `metadata` unless it is the snapshot already indexed.
+    pub fn refresh(&mut self, metadata: &Arc<DatabaseMetadata>) {
+        if self
+            .indexed
+            .as_ref()
+            .is_some_and(|indexed| Arc::ptr_eq(indexed, metadata))
+        {
+            return;
+        }
+        self.entries = metadata
+            .table_summaries
+            .iter()
+            .map(|table| SearchEntry {
+                kind: SearchEntryKind::Table,
+                label: table.qualified_name(),
+                comment: None,
+                target: SearchTarget::Table {
+                    schema: table.schema.clone(),
+                    table: table.name.clone(),
+                },
+            })
+            .chain(metadata.objects.iter().map(|object| SearchEntry {
+                kind: SearchEntryKind::Object(object.kind),
+                label: object.qualified_name(),
+                comment: None,
+                target: SearchTarget::Object {
+                    schema: object.schema.clone(),
+                    kind: object.kind,
+                    qualified_name: object.qualified_name(),
+                },
+            }))
+            .collect();
+        self.details.clear();
+        self.indexed = Some(Arc::clone(metadata));
+        self.picker.reset();
+    }
+
+    /// Adds the table comment and its columns; a reload replaces them.
+    pub fn index_table(&mut self, table: &Table) {
+        let qualified_name = table.qualified_name();
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.kind == SearchEntryKind::Table && entry.label == qualified_name)
+        {
+            entry.comment.clone_from(&table.comment);
+        }
+        let columns = table
+            .columns
+            .iter()
+            .map(|column| SearchEntry {
+                kind: SearchEntryKind::Column,
+                label: format!("{qualified_name}.{}", column.name),
+                comment: column.comment.clone(),
+                target: SearchTarget::Column {
+                    schema: table.schema.clone(),
+                    table: table.name.clone(),
+                    column: column.name.clone(),
+                },
+            })
+            .collect();
+        self.details.insert(qualified_name, columns);
+    }
+
+    pub fn entry_count(&self) -> usize {
+        self.entries.len() + self.details.values().map(Vec::len).sum::<usize>()
+    }
+
+    pub fn close(&mut self) {
+        self.picker.clear_filter_and_reset();
+    }
+
+    pub fn picker(&self) -> &PickerState {
+        &self.picker
+    }
+
+    pub fn picker_mut(&mut self) -> &mut PickerState {
+        &mut self.picker
+    }
+
+    fn all_entries(&self) -> impl Iterator<Item = &SearchEntry> {
+        self.entries.iter().chain(self.details.values().flatten())
+    }
+
+    /// Fuzzy-matches the filter against each label, falling back to the
+    /// comment; best scores first.
+    pub fn filtered(&self) -> Vec<SearchHit<'_>> {
+        let filter = self.picker.filter_input().content();
+        if filter.is_empty() {
+            return self
+                .entries
+                .iter()
+                .take(MAX_RESULTS)
+                .map(|entry| SearchHit {
+                    entry,
+                    match_indices: Vec::new(),
+                })
+                .collect();
+        }
+
+        let mut matcher = Matcher::new(Config::DEFAULT);
+        let pattern = Pattern::parse(filter, CaseMatching::Ignore, Normalization::Smart);
+        let mut buf = Vec::new();
+
+        let mut hits: Vec<(u32, SearchHit<'_>)> = self
+            .all_entries()
+            .filter_map(|entry| {
+                let mut indices = Vec::new();
+                let haystack = nucleo_matcher::Utf32Str::new(&entry.label, &mut buf);
+                if let Some(score) = pattern.indices(haystack, &mut matcher, &mut indices) {
+                    return Some((
+                        score,
+                        SearchHit {
+                            entry,
+                            match_indices: indices,
+                        },
+                    ));
+                }
+                let comment = entry.comment.as_deref()?;
+                let haystack = nucleo_matcher::Utf32Str::new(comment, &mut buf);
+                pattern.score(haystack, &mut matcher).map(|score| {
+                    (
+                        score,
+                        SearchHit {
+                            entry,
+                            match_indices: Vec::new(),
+                        },
+                    )
+                })
+            })
+            .collect();
+        hits.sort_by_key(|(score, _)| Reverse(*score));
+        hits.into_iter()
+            .take(MAX_RESULTS)
+            .map(|(_, hit)| hit)
+            .collect()
+    }
+
+    pub fn clamped_selected(&self) -> usize {
+        self.picker
+            .selected()
+            .min(self.filtered().len().saturating_sub(1))
+    }
+
+    pub fn selected_target(&self) -> Option<SearchTarget> {
+        self.filtered()
+            .get(self.clamped_selected())
+            .map(|hit| hit.entry.target.clone())
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.filtered().len();
+        if count > 0 {
+            let next = (self.clamped_selected() + 1).min(count - 1);
+            self.picker.set_selection(next);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        let previous = self.clamped_selected().saturating_sub(1);
+        self.picker.set_selection(previous);
+    }
+
+    pub fn set_pending_column(&mut self, target: Option<SearchTarget>) {
+        self.pending_column = target;
+    }
+
+    /// The column to reveal once `schema.table`'s detail arrives.
+    pub fn take_pending_column(&mut self, schema: &str, table: &str) -> Option<String> {
+        match &self.pending_column {
+            Some(SearchTarget::Column {
+                schema: pending_schema,
+                table: pending_table,
+                ..
+            }) if pending_schema == schema && pending_table == table => {}
+            _ => return None,
+        }
+        match self.pending_column.take() {
+            Some(SearchTarget::Column { column, .. }) => Some(column),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{SchemaObject, TableSummary};
+    use crate::test_support;
+    use crate::test_support::column::test_nullable_column;
+
+    fn metadata() -> Arc<DatabaseMetadata> {
+        let mut metadata = DatabaseMetadata::new("app".to_string());
+        metadata.table_summaries = vec![
+            TableSummary::new("public".to_string(), "users".to_string(), None, false),
+            TableSummary::new("public".to_string(), "orders".to_string(), None, false),
+        ];
+        metadata.objects = vec![SchemaObject {
+            schema: "public".to_string(),
+            name: "active_users".to_string(),
+            kind: SchemaObjectKind::View,
+            arguments: None,
+            definition: None,
+        }];
+        Arc::new(metadata)
+    }
+
+    fn orders_detail() -> Table {
+        let mut placed_at = test_nullable_column("placed_at", "timestamptz", 2);
+        placed_at.comment = Some("checkout timestamp".to_string());
+        Table {
+            columns: vec![test_nullable_column("id", "bigint", 1), placed_at],
+            comment: Some("customer purchases".to_string()),
+            ..test_support::table::minimal("public", "orders")
+        }
+    }
+
+    fn labels(state: &ObjectSearchState) -> Vec<&str> {
+        state
+            .filtered()
+            .into_iter()
+            .map(|hit| hit.entry.label.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn refresh_indexes_tables_and_objects() {
+        let mut state = ObjectSearchState::default();
+
+        state.refresh(&metadata());
+
+        assert_eq!(
+            labels(&state),
+            vec!["public.users", "public.orders", "public.active_users"]
+        );
+    }
+
+    #[test]
+    fn column_names_and_comments_match_after_detail_is_indexed() {
+        let mut state = ObjectSearchState::default();
+        state.refresh(&metadata());
+        state.index_table(&orders_detail());
+
+        state.picker_mut().insert_filter_str("placed");
+        assert_eq!(labels(&state), vec!["public.orders.placed_at"]);
+
+        state.picker_mut().clear_filter_and_reset();
+        state.picker_mut().insert_filter_str("purchases");
+        assert_eq!(labels(&state), vec!["public.orders"]);
+        assert!(state.filtered()[0].match_indices.is_empty());
+    }
+
+    #[test]
+    fn refresh_with_the_same_snapshot_keeps_details() {
+        let metadata = metadata();
+        let mut state = ObjectSearchState::default();
+        state.refresh(&metadata);
+        state.index_table(&orders_detail());
+
+        state.refresh(&metadata);
+        assert_eq!(state.entry_count(), 5);
+
+        state.refresh(&self::metadata());
+        assert_eq!(state.entry_count(), 3);
+    }
+
+    #[test]
+    fn pending_column_is_taken_only_for_its_table() {
+        let mut state = ObjectSearchState::default();
+        state.set_pending_column(Some(SearchTarget::Column {
+            schema: "public".to_string(),
+            table: "orders".to_string(),
+            column: "placed_at".to_string(),
+        }));
+
+        assert_eq!(state.take_pending_column("public", "users"), None);
+        assert_eq!(
+            state.take_pending_column("public", "orders"),
+            Some("placed_at".to_string())
+        );
+        assert_eq!(state.take_pending_column("public", "orders"), None);
+    }
+}
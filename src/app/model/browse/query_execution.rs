@@ -2,6 +2,8 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use crate::domain::{QueryResult, QuerySource, Table};
+use crate::model::browse::result_column_format::{ColumnFormat, ResultColumnFormats};
+use crate::model::browse::result_diff::ResultDiff;
 use crate::model::browse::result_history::ResultHistory;
 use crate::model::shared::async_run::AsyncRun;
 
@@ -28,6 +30,8 @@ pub struct PaginationState {
     reached_end: bool,
     schema: String,
     table: String,
+    sort_column: Option<String>,
+    filter: Option<String>,
 }
 
 impl PaginationState {
@@ -51,6 +55,16 @@ impl PaginationState {
         &self.table
     }
 
+    /// `None` is the adapter's default key order.
+    pub fn sort_column(&self) -> Option<&str> {
+        self.sort_column.as_deref()
+    }
+
+    /// The `:where` predicate narrowing the preview, if any.
+    pub fn filter(&self) -> Option<&str> {
+        self.filter.as_deref()
+    }
+
     pub fn has_table(&self) -> bool {
         !self.table.is_empty()
     }
@@ -92,6 +106,39 @@ impl PaginationState {
         !self.reached_end
     }
 
+    /// Header text for a page holding `loaded_rows`, e.g. `rows 501–1,000 of ~12,345`.
+    /// A lone first page keeps the plain row count.
+    pub fn row_window_display(&self, loaded_rows: usize) -> String {
+        let single_page =
+            self.current_page == 0 && (self.reached_end || loaded_rows < PREVIEW_PAGE_SIZE);
+        if single_page || loaded_rows == 0 {
+            return if loaded_rows == 1 {
+                "1 row".to_string()
+            } else {
+                format!("{loaded_rows} rows")
+            };
+        }
+        let first = self.offset() + 1;
+        let last = self.offset() + loaded_rows;
+        let total = if self.reached_end {
+            format!(" of {}", group_thousands(last))
+        } else if self.filter.is_some() {
+            // The estimate counts the whole table, not the filtered rows.
+            String::new()
+        } else {
+            self.total_rows_estimate
+                .and_then(|estimate| usize::try_from(estimate).ok())
+                .filter(|&estimate| estimate >= last)
+                .map(|estimate| format!(" of ~{}", group_thousands(estimate)))
+                .unwrap_or_default()
+        };
+        format!(
+            "rows {}\u{2013}{}{total}",
+            group_thousands(first),
+            group_thousands(last)
+        )
+    }
+
     pub fn can_prev(&self) -> bool {
         self.current_page > 0
     }
@@ -102,6 +149,8 @@ impl PaginationState {
         self.reached_end = false;
         self.schema.clear();
         self.table.clear();
+        self.sort_column = None;
+        self.filter = None;
     }
 
     pub fn reset_for_table(&mut self, schema: &str, table: &str) {
@@ -120,6 +169,20 @@ impl PaginationState {
         self.total_rows_estimate = estimate;
     }
 
+    /// Changing the order invalidates every page, so this restarts at the first.
+    pub fn set_sort_column(&mut self, column: Option<String>) {
+        self.sort_column = column;
+        self.current_page = 0;
+        self.reached_end = false;
+    }
+
+    /// A new predicate selects different rows, so this restarts at the first page.
+    pub fn set_filter(&mut self, filter: Option<String>) {
+        self.filter = filter;
+        self.current_page = 0;
+        self.reached_end = false;
+    }
+
     pub fn clear_reached_end(&mut self) {
         self.reached_end = false;
     }
@@ -142,6 +205,18 @@ impl PaginationState {
     }
 }
 
+fn group_thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, ch) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    grouped
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PostDeleteRowSelection {
     #[default]
@@ -157,18 +232,31 @@ pub struct DeleteRefreshTarget {
     pub expected_delete_count: usize,
 }
 
+/// The unmasked original behind a masked current result.
+#[derive(Debug, Clone)]
+struct MaskedResult {
+    original: Arc<QueryResult>,
+    columns: Vec<String>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct QueryExecution {
     status: QueryStatus,
     start_time: Option<Instant>,
     current_result: Option<Arc<QueryResult>>,
+    masked: Option<MaskedResult>,
+    result_diff: Option<ResultDiff>,
+    shows_diff_old_values: bool,
     result_history: ResultHistory,
     result_generation: u64,
     result_highlight_until: Option<Instant>,
+    column_formats: ResultColumnFormats,
     pub pagination: PaginationState,
     pending_delete_refresh_target: Option<DeleteRefreshTarget>,
     post_delete_row_selection: PostDeleteRowSelection,
     run: AsyncRun,
+    column_types: Vec<String>,
+    column_types_run: AsyncRun,
 }
 
 impl QueryExecution {
@@ -189,6 +277,7 @@ impl QueryExecution {
 
     pub fn reset_for_context_change(&mut self) {
         self.mark_idle();
+        self.column_formats.clear();
         self.clear_delete_refresh_target();
         self.post_delete_row_selection = PostDeleteRowSelection::Keep;
     }
@@ -213,12 +302,29 @@ impl QueryExecution {
 
     pub fn set_current_result(&mut self, result: Arc<QueryResult>) {
         self.current_result = Some(result);
+        self.masked = None;
+        self.result_diff = None;
         self.result_generation += 1;
+        self.clear_column_types();
+    }
+
+    /// Like [`Self::set_current_result`], but diffs against the result it
+    /// replaces when `result` is a re-run of the same statement.
+    pub fn set_rerun_result(&mut self, result: Arc<QueryResult>) {
+        let diff = self
+            .current_result
+            .as_deref()
+            .and_then(|previous| ResultDiff::between(previous, &result));
+        self.set_current_result(result);
+        self.result_diff = diff;
     }
 
     pub fn clear_current_result(&mut self) {
         self.current_result = None;
+        self.masked = None;
+        self.result_diff = None;
         self.result_generation += 1;
+        self.clear_column_types();
     }
 
     pub fn push_history(&mut self, result: Arc<QueryResult>) {
@@ -243,6 +349,89 @@ impl QueryExecution {
         self.current_result.as_ref()
     }
 
+    // ── Column masking ──────────────────────────────────────────────
+
+    /// Keeps `original` for [`Self::unmask`] while the masked copy is shown.
+    pub fn hold_unmasked(&mut self, original: Arc<QueryResult>, columns: Vec<String>) {
+        self.masked = (!columns.is_empty()).then_some(MaskedResult { original, columns });
+    }
+
+    pub fn masked_columns(&self) -> &[String] {
+        self.masked
+            .as_ref()
+            .map_or(&[], |masked| masked.columns.as_slice())
+    }
+
+    pub fn is_column_masked(&self, column: &str) -> bool {
+        self.masked_columns().iter().any(|name| name == column)
+    }
+
+    /// Shows the original values; returns the columns that were masked.
+    pub fn unmask(&mut self) -> Option<Vec<String>> {
+        let masked = self.masked.take()?;
+        self.current_result = Some(masked.original);
+        self.result_generation += 1;
+        Some(masked.columns)
+    }
+
+    // ── Re-run diff ─────────────────────────────────────────────────
+
+    pub fn result_diff(&self) -> Option<&ResultDiff> {
+        self.result_diff.as_ref()
+    }
+
+    pub fn shows_diff_old_values(&self) -> bool {
+        self.shows_diff_old_values
+    }
+
+    /// Returns the new state. Bumps the result generation so cached column
+    /// widths make room for the old values.
+    pub fn toggle_diff_old_values(&mut self) -> bool {
+        self.result_generation += 1;
+        self.shows_diff_old_values = !self.shows_diff_old_values;
+        self.shows_diff_old_values
+    }
+
+    // ── Column formats ──────────────────────────────────────────────
+
+    pub fn column_formats(&self) -> &ResultColumnFormats {
+        &self.column_formats
+    }
+
+    /// Bumps the result generation so cached column widths pick up the new display.
+    pub fn toggle_column_format(&mut self, column: &str, format: ColumnFormat) -> bool {
+        self.result_generation += 1;
+        self.column_formats.toggle(column, format)
+    }
+
+    // ── Column types ────────────────────────────────────────────────
+
+    /// Server-reported types for the current adhoc result, one per column.
+    pub fn column_types(&self) -> &[String] {
+        &self.column_types
+    }
+
+    #[must_use]
+    pub fn begin_column_types_fetch(&mut self) -> u64 {
+        self.column_types.clear();
+        self.column_types_run.begin()
+    }
+
+    /// Returns `false` when the result has changed since `run_id` started.
+    pub fn set_column_types(&mut self, run_id: u64, types: Vec<String>) -> bool {
+        if !self.column_types_run.is_current(run_id) {
+            return false;
+        }
+        self.column_types_run.clear_active();
+        self.column_types = types;
+        true
+    }
+
+    fn clear_column_types(&mut self) {
+        self.column_types.clear();
+        self.column_types_run.clear_active();
+    }
+
     // ── Result highlight ────────────────────────────────────────────
 
     pub fn set_result_highlight(&mut self, until: Instant) {
@@ -451,6 +640,36 @@ mod tests {
         }
     }
 
+    mod masking {
+        use super::*;
+
+        #[test]
+        fn unmask_restores_original_once() {
+            let original = make_result(QuerySource::Preview);
+            let masked = Arc::new(QueryResult::clone(&original).with_masked_columns(&[0], "***"));
+            let mut qe = QueryExecution::default();
+            qe.set_current_result(masked);
+            qe.hold_unmasked(Arc::clone(&original), vec!["col".to_string()]);
+            let generation = qe.result_generation();
+
+            assert!(qe.is_column_masked("col"));
+            assert_eq!(qe.unmask(), Some(vec!["col".to_string()]));
+            assert!(Arc::ptr_eq(qe.current_result().unwrap(), &original));
+            assert!(qe.result_generation() > generation);
+            assert_eq!(qe.unmask(), None);
+        }
+
+        #[test]
+        fn new_result_drops_held_original() {
+            let mut qe = QueryExecution::default();
+            qe.hold_unmasked(make_result(QuerySource::Preview), vec!["col".to_string()]);
+
+            qe.set_current_result(make_result(QuerySource::Adhoc));
+
+            assert!(qe.masked_columns().is_empty());
+        }
+    }
+
     #[test]
     fn default_creates_idle_state() {
         let execution = QueryExecution::default();
@@ -508,6 +727,68 @@ mod tests {
         }
     }
 
+    mod rerun_diff_tests {
+        use super::*;
+
+        fn job_result(status: &str) -> Arc<QueryResult> {
+            Arc::new(QueryResult::success(
+                "SELECT status FROM jobs WHERE id = 7".to_string(),
+                vec!["status".to_string()],
+                vec![vec![status.to_string()]],
+                3,
+                QuerySource::Adhoc,
+            ))
+        }
+
+        #[test]
+        fn rerun_of_same_statement_is_diffed() {
+            let mut qe = QueryExecution::default();
+            qe.set_rerun_result(job_result("queued"));
+            assert!(qe.result_diff().is_none());
+
+            qe.set_rerun_result(job_result("running"));
+
+            let diff = qe.result_diff().unwrap();
+            assert_eq!(diff.old_value(0, 0), Some("queued"));
+        }
+
+        #[test]
+        fn plain_replacement_drops_the_diff() {
+            let mut qe = QueryExecution::default();
+            qe.set_rerun_result(job_result("queued"));
+            qe.set_rerun_result(job_result("running"));
+
+            qe.set_current_result(make_result(QuerySource::Adhoc));
+
+            assert!(qe.result_diff().is_none());
+        }
+    }
+
+    mod column_types_tests {
+        use super::*;
+
+        #[test]
+        fn types_from_current_fetch_are_kept() {
+            let mut qe = QueryExecution::default();
+            qe.set_current_result(make_result(QuerySource::Adhoc));
+            let run_id = qe.begin_column_types_fetch();
+
+            assert!(qe.set_column_types(run_id, vec!["integer".to_string()]));
+            assert_eq!(qe.column_types(), ["integer"]);
+        }
+
+        #[test]
+        fn new_result_drops_types_and_pending_fetch() {
+            let mut qe = QueryExecution::default();
+            qe.set_current_result(make_result(QuerySource::Adhoc));
+            let run_id = qe.begin_column_types_fetch();
+            qe.set_current_result(make_result(QuerySource::Adhoc));
+
+            assert!(!qe.set_column_types(run_id, vec!["integer".to_string()]));
+            assert!(qe.column_types().is_empty());
+        }
+    }
+
     #[test]
     fn query_status_default_is_idle() {
         assert_eq!(QueryStatus::default(), QueryStatus::Idle);
@@ -533,6 +814,7 @@ mod tests {
 
     mod pagination {
         use super::*;
+        use rstest::rstest;
 
         #[test]
         fn offset_returns_correct_value() {
@@ -544,6 +826,44 @@ mod tests {
             assert_eq!(p.offset(), 3 * PREVIEW_PAGE_SIZE);
         }
 
+        #[rstest]
+        #[case(0, false, Some(12_345), 500, "rows 1\u{2013}500 of ~12,345")]
+        #[case(1, false, Some(12_345), 500, "rows 501\u{2013}1,000 of ~12,345")]
+        #[case(2, true, Some(12_345), 120, "rows 1,001\u{2013}1,120 of 1,120")]
+        #[case(1, false, Some(600), 500, "rows 501\u{2013}1,000")]
+        #[case(1, false, None, 500, "rows 501\u{2013}1,000")]
+        #[case(0, true, Some(3), 3, "3 rows")]
+        #[case(0, true, None, 1, "1 row")]
+        #[case(0, false, Some(40), 40, "40 rows")]
+        fn row_window_display_shows_page_bounds_and_total(
+            #[case] current_page: usize,
+            #[case] reached_end: bool,
+            #[case] total_rows_estimate: Option<i64>,
+            #[case] loaded_rows: usize,
+            #[case] expected: &str,
+        ) {
+            let p = PaginationState {
+                current_page,
+                total_rows_estimate,
+                reached_end,
+                ..Default::default()
+            };
+
+            assert_eq!(p.row_window_display(loaded_rows), expected);
+        }
+
+        #[test]
+        fn filtered_window_drops_the_table_estimate() {
+            let mut p = PaginationState {
+                total_rows_estimate: Some(12_345),
+                ..Default::default()
+            };
+            p.set_filter(Some("status = 'open'".to_string()));
+            p.set_current_page(1);
+
+            assert_eq!(p.row_window_display(500), "rows 501\u{2013}1,000");
+        }
+
         #[test]
         fn total_pages_estimate_rounds_up() {
             let p = PaginationState {
@@ -633,6 +953,8 @@ mod tests {
                 reached_end: true,
                 schema: "public".to_string(),
                 table: "users".to_string(),
+                sort_column: Some("email".to_string()),
+                filter: Some("active".to_string()),
             };
 
             p.reset();
@@ -642,6 +964,21 @@ mod tests {
             assert!(!p.reached_end);
             assert!(p.schema.is_empty());
             assert!(p.table.is_empty());
+            assert_eq!(p.sort_column(), None);
+            assert_eq!(p.filter(), None);
+        }
+
+        #[test]
+        fn set_sort_column_restarts_at_first_page() {
+            let mut p = PaginationState::default();
+            p.reset_for_table("public", "users");
+            p.set_page_result(3, true);
+
+            p.set_sort_column(Some("email".to_string()));
+
+            assert_eq!(p.sort_column(), Some("email"));
+            assert_eq!(p.current_page(), 0);
+            assert!(p.can_next());
         }
 
         #[test]
@@ -652,6 +989,8 @@ mod tests {
                 reached_end: true,
                 schema: "old".to_string(),
                 table: "old".to_string(),
+                sort_column: None,
+                filter: None,
             };
 
             p.reset_for_table_with_estimate("public", "users", Some(1200));
@@ -0,0 +1,100 @@
+/// Table-scoped actions offered by the `.` menu on an Explorer table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickAction {
+    Preview,
+    CountRows,
+    CopyDdl,
+    ErNeighborhood,
+    ExportCsv,
+    Analyze,
+    ToggleFavorite,
+}
+
+impl QuickAction {
+    pub const ALL: [Self; 7] = [
+        Self::Preview,
+        Self::CountRows,
+        Self::CopyDdl,
+        Self::ErNeighborhood,
+        Self::ExportCsv,
+        Self::Analyze,
+        Self::ToggleFavorite,
+    ];
+
+    pub fn label(self, favorite: bool) -> &'static str {
+        match self {
+            Self::Preview => "Preview rows",
+            Self::CountRows => "Count rows",
+            Self::CopyDdl => "Copy DDL",
+            Self::ErNeighborhood => "ER neighborhood",
+            Self::ExportCsv => "Export preview to CSV",
+            Self::Analyze => "ANALYZE",
+            Self::ToggleFavorite if favorite => "Remove from favorites",
+            Self::ToggleFavorite => "Add to favorites",
+        }
+    }
+}
+
+/// The open menu: the table it was opened on and the highlighted entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuickActionsState {
+    target: Option<(String, String)>,
+    selected: usize,
+}
+
+impl QuickActionsState {
+    pub fn open(&mut self, schema: String, table: String) {
+        *self = Self {
+            target: Some((schema, table)),
+            selected: 0,
+        };
+    }
+
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    /// `(schema, table)` the menu acts on.
+    pub fn target(&self) -> Option<(&str, &str)> {
+        self.target
+            .as_ref()
+            .map(|(schema, table)| (schema.as_str(), table.as_str()))
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_action(&self) -> QuickAction {
+        QuickAction::ALL[self.selected.min(QuickAction::ALL.len() - 1)]
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % QuickAction::ALL.len();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self
+            .selected
+            .checked_sub(1)
+            .unwrap_or(QuickAction::ALL.len() - 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection_wraps_around_the_menu() {
+        let mut state = QuickActionsState::default();
+        state.open("public".to_string(), "orders".to_string());
+
+        state.select_previous();
+        assert_eq!(state.selected_action(), QuickAction::ToggleFavorite);
+
+        state.select_next();
+        assert_eq!(state.selected_action(), QuickAction::Preview);
+        assert_eq!(state.target(), Some(("public", "orders")));
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+use crate::policy::duration_format::{DurationSource, format_duration};
+use crate::policy::number_format::{NumericStyle, format_number};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnFormat {
+    Number(NumericStyle),
+    Duration(DurationSource),
+}
+
+impl ColumnFormat {
+    /// Falls back to `None` when the value cannot be formatted, so the raw text is shown.
+    pub fn apply(self, raw: &str) -> Option<String> {
+        match self {
+            Self::Number(style) => format_number(raw, style),
+            Self::Duration(source) => format_duration(raw, source),
+        }
+    }
+}
+
+// Keyed by column name so formatting survives paging and re-running the same query.
+#[derive(Debug, Clone, Default)]
+pub struct ResultColumnFormats {
+    by_column: BTreeMap<String, ColumnFormat>,
+}
+
+impl ResultColumnFormats {
+    pub fn get(&self, column: &str) -> Option<ColumnFormat> {
+        self.by_column.get(column).copied()
+    }
+
+    pub fn for_columns(&self, columns: &[String]) -> Vec<Option<ColumnFormat>> {
+        columns.iter().map(|column| self.get(column)).collect()
+    }
+
+    /// Returns true when the column ends up formatted.
+    pub fn toggle(&mut self, column: &str, format: ColumnFormat) -> bool {
+        if self.by_column.get(column) == Some(&format) {
+            self.by_column.remove(column);
+            false
+        } else {
+            self.by_column.insert(column.to_string(), format);
+            true
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.by_column.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUMBER: ColumnFormat = ColumnFormat::Number(NumericStyle::General);
+
+    #[test]
+    fn toggle_twice_restores_raw() {
+        let mut formats = ResultColumnFormats::default();
+
+        assert!(formats.toggle("amount", NUMBER));
+        assert_eq!(formats.get("amount"), Some(NUMBER));
+        assert!(!formats.toggle("amount", NUMBER));
+        assert_eq!(formats.get("amount"), None);
+    }
+
+    #[test]
+    fn toggle_other_format_replaces_existing() {
+        let duration = ColumnFormat::Duration(DurationSource::Seconds);
+        let mut formats = ResultColumnFormats::default();
+        formats.toggle("elapsed", NUMBER);
+
+        assert!(formats.toggle("elapsed", duration));
+        assert_eq!(formats.get("elapsed"), Some(duration));
+    }
+
+    #[test]
+    fn for_columns_maps_by_name() {
+        let mut formats = ResultColumnFormats::default();
+        formats.toggle("b", NUMBER);
+
+        let resolved = formats.for_columns(&["a".to_string(), "b".to_string()]);
+
+        assert_eq!(resolved, vec![None, Some(NUMBER)]);
+    }
+
+    #[test]
+    fn apply_falls_back_for_non_numeric() {
+        assert_eq!(NUMBER.apply("12345"), Some("12,345".to_string()));
+        assert_eq!(NUMBER.apply("abc"), None);
+    }
+}
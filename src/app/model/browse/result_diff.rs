@@ -0,0 +1,132 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::domain::QueryResult;
+
+/// Cells that changed between two runs of the same query.
+///
+/// Rows are aligned by position, so the diff is meaningful for queries with a
+/// stable order (a watched job row, an ordered preview page). Rows past the
+/// previous run's end count as changed in every column.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResultDiff {
+    old_values: HashMap<(usize, usize), String>,
+    previous_row_count: usize,
+    added_rows: usize,
+}
+
+impl ResultDiff {
+    /// `None` when the two results are not re-runs of one another: a different
+    /// statement, source or column list, or either side failed.
+    pub fn between(previous: &QueryResult, current: &QueryResult) -> Option<Self> {
+        if previous.is_error()
+            || current.is_error()
+            || previous.source != current.source
+            || previous.query != current.query
+            || previous.columns != current.columns
+            || current.columns.is_empty()
+        {
+            return None;
+        }
+
+        let previous_row_count = previous.data_row_count();
+        let mut old_values = HashMap::new();
+        for row in 0..current.data_row_count().min(previous_row_count) {
+            for col in 0..current.columns.len() {
+                let old = previous.display_value_ref_at(row, col);
+                if old != current.display_value_ref_at(row, col) {
+                    old_values.insert((row, col), old.map(Cow::into_owned).unwrap_or_default());
+                }
+            }
+        }
+
+        Some(Self {
+            old_values,
+            previous_row_count,
+            added_rows: current.data_row_count().saturating_sub(previous_row_count),
+        })
+    }
+
+    pub fn is_changed(&self, row: usize, col: usize) -> bool {
+        row >= self.previous_row_count || self.old_values.contains_key(&(row, col))
+    }
+
+    /// The previous run's value; `None` for unchanged cells and added rows.
+    pub fn old_value(&self, row: usize, col: usize) -> Option<&str> {
+        self.old_values.get(&(row, col)).map(String::as_str)
+    }
+
+    pub fn changed_cell_count(&self) -> usize {
+        self.old_values.len()
+    }
+
+    pub fn added_row_count(&self) -> usize {
+        self.added_rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.old_values.is_empty() && self.added_rows == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::QuerySource;
+
+    fn result(query: &str, rows: &[[&str; 2]]) -> QueryResult {
+        QueryResult::success(
+            query.to_string(),
+            vec!["id".to_string(), "status".to_string()],
+            rows.iter()
+                .map(|row| row.iter().map(|v| (*v).to_string()).collect())
+                .collect(),
+            1,
+            QuerySource::Adhoc,
+        )
+    }
+
+    #[test]
+    fn changed_cells_keep_their_old_value() {
+        let previous = result("SELECT * FROM jobs", &[["1", "queued"], ["2", "done"]]);
+        let current = result("SELECT * FROM jobs", &[["1", "running"], ["2", "done"]]);
+
+        let diff = ResultDiff::between(&previous, &current).unwrap();
+
+        assert!(diff.is_changed(0, 1));
+        assert_eq!(diff.old_value(0, 1), Some("queued"));
+        assert!(!diff.is_changed(0, 0));
+        assert!(!diff.is_changed(1, 1));
+        assert_eq!(diff.changed_cell_count(), 1);
+    }
+
+    #[test]
+    fn added_rows_are_changed_without_an_old_value() {
+        let previous = result("SELECT * FROM jobs", &[["1", "queued"]]);
+        let current = result("SELECT * FROM jobs", &[["1", "queued"], ["2", "queued"]]);
+
+        let diff = ResultDiff::between(&previous, &current).unwrap();
+
+        assert!(diff.is_changed(1, 0));
+        assert_eq!(diff.old_value(1, 0), None);
+        assert_eq!(diff.added_row_count(), 1);
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn identical_runs_produce_an_empty_diff() {
+        let previous = result("SELECT * FROM jobs", &[["1", "queued"]]);
+
+        let diff = ResultDiff::between(&previous, &previous.clone()).unwrap();
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn different_statements_are_not_compared() {
+        let previous = result("SELECT * FROM jobs", &[["1", "queued"]]);
+        let current = result("SELECT * FROM jobs WHERE id = 1", &[["1", "running"]]);
+
+        assert_eq!(ResultDiff::between(&previous, &current), None);
+    }
+}
@@ -2,6 +2,7 @@ use std::collections::BTreeSet;
 use std::time::Instant;
 
 use super::cell_edit::CellEditState;
+use super::cell_peek::CellPeekState;
 use crate::model::shared::cursor::CursorMove;
 use crate::model::shared::text_input::TextKillDirection;
 use crate::model::shared::ui_state::{ResultSelection, YankFlash};
@@ -10,6 +11,7 @@ use crate::policy::write::write_guardrails::WritePreview;
 // Invariants:
 // - `reset_view` / `reset_interaction` clear staged deletes too.
 // - `exit_cell_to_scroll()` preserves staged deletes so Esc does not drop a staged batch.
+// - Row marks survive focus changes and are only dropped with the result (`reset_view`).
 // - Callers must restore `input_mode` themselves when leaving `CellEdit`.
 #[derive(Debug, Clone, Default)]
 pub struct ResultInteraction {
@@ -21,7 +23,9 @@ pub struct ResultInteraction {
     yank_op_pending: bool,
     selection: ResultSelection,
     cell_edit: CellEditState,
+    cell_peek: CellPeekState,
     staged_delete_rows: BTreeSet<usize>,
+    marked_rows: BTreeSet<usize>,
     pending_write_preview: Option<WritePreview>,
 }
 
@@ -29,6 +33,7 @@ impl ResultInteraction {
     fn clear_active_cell_state(&mut self) {
         self.selection.reset();
         self.cell_edit.clear();
+        self.cell_peek = CellPeekState::default();
         self.pending_write_preview = None;
     }
 
@@ -40,10 +45,27 @@ impl ResultInteraction {
         &self.cell_edit
     }
 
+    pub fn cell_peek(&self) -> &CellPeekState {
+        &self.cell_peek
+    }
+
+    pub fn sync_cell_peek(&mut self, now: Instant) {
+        let cell = self.selection.row().zip(self.selection.cell());
+        self.cell_peek.sync(cell, now);
+    }
+
+    pub fn pin_cell_peek(&mut self) {
+        self.cell_peek.pin();
+    }
+
     pub fn staged_delete_rows(&self) -> &BTreeSet<usize> {
         &self.staged_delete_rows
     }
 
+    pub fn marked_rows(&self) -> &BTreeSet<usize> {
+        &self.marked_rows
+    }
+
     pub fn pending_write_preview(&self) -> Option<&WritePreview> {
         self.pending_write_preview.as_ref()
     }
@@ -84,6 +106,14 @@ impl ResultInteraction {
         self.selection.move_cell(col);
     }
 
+    pub fn start_range(&mut self) {
+        self.selection.start_range();
+    }
+
+    pub fn clear_range(&mut self) {
+        self.selection.clear_range();
+    }
+
     pub fn clamp_selection(&mut self, max_rows: usize, max_cols: usize) {
         self.selection.clamp(max_rows, max_cols);
     }
@@ -165,10 +195,51 @@ impl ResultInteraction {
         }
     }
 
+    pub fn toggle_row_mark(&mut self, row: usize) {
+        if !self.marked_rows.remove(&row) {
+            self.marked_rows.insert(row);
+        }
+    }
+
+    pub fn clear_row_marks(&mut self) {
+        self.marked_rows.clear();
+    }
+
+    /// The first mark after `row`, wrapping around to the first mark.
+    pub fn next_row_mark(&self, row: usize) -> Option<usize> {
+        self.marked_rows
+            .range(row + 1..)
+            .next()
+            .or_else(|| self.marked_rows.iter().next())
+            .copied()
+    }
+
+    /// The last mark before `row`, wrapping around to the last mark.
+    pub fn prev_row_mark(&self, row: usize) -> Option<usize> {
+        self.marked_rows
+            .range(..row)
+            .next_back()
+            .or_else(|| self.marked_rows.iter().next_back())
+            .copied()
+    }
+
     pub fn clear_staged_deletes(&mut self) {
         self.staged_delete_rows.clear();
     }
 
+    pub fn restore_staged_deletes(&mut self, rows: BTreeSet<usize>) {
+        self.staged_delete_rows = rows;
+    }
+
+    /// Puts a discarded draft back and re-selects its cell.
+    pub fn restore_cell_edit(&mut self, edit: CellEditState) {
+        if let Some((row, col)) = edit.row().zip(edit.col()) {
+            self.selection.enter_cell(row, col);
+        }
+        self.cell_edit = edit;
+        self.pending_write_preview = None;
+    }
+
     pub fn set_write_preview(&mut self, preview: WritePreview) {
         self.pending_write_preview = Some(preview);
     }
@@ -185,6 +256,7 @@ impl ResultInteraction {
     pub fn reset_view(&mut self) {
         self.scroll_offset = 0;
         self.horizontal_offset = 0;
+        self.marked_rows.clear();
         self.reset_interaction();
     }
 
@@ -246,6 +318,7 @@ mod tests {
         ri.activate_cell(3, 2);
         ri.begin_cell_edit(3, 2, "val".to_string());
         ri.stage_row(1);
+        ri.toggle_row_mark(4);
         ri.set_write_preview(test_preview());
 
         ri.reset_view();
@@ -255,9 +328,27 @@ mod tests {
         assert_eq!(ri.selection().mode(), ResultNavMode::Scroll);
         assert!(!ri.cell_edit().is_active());
         assert!(ri.staged_delete_rows().is_empty());
+        assert!(ri.marked_rows().is_empty());
         assert!(ri.pending_write_preview().is_none());
     }
 
+    #[test]
+    fn row_marks_toggle_and_jumps_wrap_around() {
+        let mut ri = ResultInteraction::default();
+        ri.toggle_row_mark(2);
+        ri.toggle_row_mark(7);
+        ri.toggle_row_mark(5);
+        ri.toggle_row_mark(5);
+
+        assert_eq!(ri.next_row_mark(2), Some(7));
+        assert_eq!(ri.next_row_mark(7), Some(2));
+        assert_eq!(ri.prev_row_mark(7), Some(2));
+        assert_eq!(ri.prev_row_mark(1), Some(7));
+
+        ri.reset_interaction();
+        assert_eq!(ri.marked_rows().len(), 2);
+    }
+
     #[test]
     fn reset_interaction_preserves_scroll() {
         let mut ri = ResultInteraction {
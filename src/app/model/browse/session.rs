@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use crate::domain::{
-    ConnectionId, DatabaseMetadata, DatabaseType, MetadataState, QueryResult, Table, TableSummary,
+    ConnectionId, DatabaseMetadata, DatabaseType, Dsn, MetadataSnapshot, MetadataState,
+    QueryResult, SchemaObject, Table, TableSummary,
 };
 use crate::model::browse::query_execution::QueryExecution;
 use crate::model::browse::result_history::ResultHistory;
@@ -11,6 +12,7 @@ use crate::model::connection::state::ConnectionState;
 use crate::model::shared::async_run::AsyncRun;
 use crate::model::shared::engine_feature_profile::EngineFeatureProfile;
 use crate::model::shared::inspector_tab::InspectorTab;
+use crate::policy::sql::replica_routing::{QueryEndpoint, QueryRouting};
 
 #[derive(Debug, Clone)]
 struct ActiveConnection {
@@ -25,9 +27,12 @@ struct ActiveConnection {
 // - `connection_state` and `metadata_state` always transition as a pair
 //   (e.g. `begin_connecting` sets both to Connecting/Loading).
 // - `selected_table_key`, `table_detail`, and `selection_generation` change
-//   together via `select_table` / `clear_table_selection`.
+//   together via `select_table` / `clear_table_selection`; either clears
+//   `inspected_object`, which only `inspect_object` sets.
 // - `database_name` is derived from `metadata` (single source of truth).
 // - Cache restore for a connection exits transient reload/read-only state.
+// - `offline` implies `offline_snapshot` is set; both clear on any real
+//   connect or connection switch.
 //
 // # Transitional raw setters
 //
@@ -45,6 +50,8 @@ pub struct BrowseSession {
     selected_table_key: Option<String>,
     table_detail: Option<Table>,
     selection_generation: u64,
+    // Qualified name of the view/sequence/function shown in the Inspector.
+    inspected_object: Option<String>,
 
     // -- lifecycle-gated --
     metadata: Option<Arc<DatabaseMetadata>>,
@@ -59,6 +66,14 @@ pub struct BrowseSession {
     active_engine_feature_profile: EngineFeatureProfile,
     read_only: bool,
     is_reloading: bool,
+    query_routing: QueryRouting,
+    last_query_endpoint: Option<QueryEndpoint>,
+    // DSN the open `:begin` transaction is held on.
+    transaction_dsn: Option<String>,
+
+    // -- co-dependent: offline browsing --
+    offline_snapshot: Option<Arc<MetadataSnapshot>>,
+    offline: bool,
 }
 
 impl Default for BrowseSession {
@@ -69,6 +84,7 @@ impl Default for BrowseSession {
             selected_table_key: None,
             table_detail: None,
             selection_generation: 0,
+            inspected_object: None,
             metadata: None,
             metadata_run: AsyncRun::default(),
             effective_user: None,
@@ -79,6 +95,11 @@ impl Default for BrowseSession {
             active_engine_feature_profile: EngineFeatureProfile::disconnected(),
             read_only: false,
             is_reloading: false,
+            query_routing: QueryRouting::Auto,
+            last_query_endpoint: None,
+            transaction_dsn: None,
+            offline_snapshot: None,
+            offline: false,
         }
     }
 }
@@ -92,11 +113,27 @@ impl BrowseSession {
         query.clear_current_result();
         self.selected_table_key = Some(format!("{schema}.{table}"));
         self.table_detail = None;
+        self.inspected_object = None;
         self.selection_generation += 1;
         query.pagination.reset_for_table(schema, table);
         self.selection_generation
     }
 
+    /// Objects have no rows to preview, so the table selection is dropped.
+    pub fn inspect_object(&mut self, object: &SchemaObject, query: &mut QueryExecution) {
+        self.clear_table_selection(query);
+        self.inspected_object = Some(object.qualified_name());
+    }
+
+    pub fn inspected_object(&self) -> Option<&SchemaObject> {
+        let key = self.inspected_object.as_deref()?;
+        self.metadata
+            .as_ref()?
+            .objects
+            .iter()
+            .find(|object| object.qualified_name() == key)
+    }
+
     #[must_use]
     pub fn set_table_detail(&mut self, detail: Table, generation: u64) -> bool {
         if generation == self.selection_generation {
@@ -112,6 +149,7 @@ impl BrowseSession {
         query.clear_current_result();
         self.selected_table_key = None;
         self.table_detail = None;
+        self.inspected_object = None;
         self.selection_generation += 1;
         self.table_detail_run.clear_active();
         query.pagination.reset();
@@ -158,19 +196,37 @@ impl BrowseSession {
         self.active_engine_feature_profile = EngineFeatureProfile::for_database_type(database_type);
         self.dsn = Some(dsn.to_string());
         self.read_only = false;
+        self.reset_query_routing();
+        self.clear_offline();
     }
 
     pub fn activate_cli_ephemeral_connection(&mut self, id: &ConnectionId, name: &str, dsn: &str) {
+        self.activate_ephemeral_connection(id, name, DatabaseType::SQLite, dsn);
+    }
+
+    /// The demo session browses a PostgreSQL-shaped sample schema served in memory.
+    pub fn activate_demo_connection(&mut self, id: &ConnectionId, name: &str, dsn: &str) {
+        self.activate_ephemeral_connection(id, name, DatabaseType::PostgreSQL, dsn);
+    }
+
+    fn activate_ephemeral_connection(
+        &mut self,
+        id: &ConnectionId,
+        name: &str,
+        database_type: DatabaseType,
+        dsn: &str,
+    ) {
         self.active_connection = Some(ActiveConnection {
             id: id.clone(),
             name: name.to_string(),
-            database_type: DatabaseType::SQLite,
+            database_type,
             origin: ConnectionOrigin::CliEphemeral,
         });
-        self.active_engine_feature_profile =
-            EngineFeatureProfile::for_database_type(DatabaseType::SQLite);
+        self.active_engine_feature_profile = EngineFeatureProfile::for_database_type(database_type);
         self.dsn = Some(dsn.to_string());
         self.read_only = false;
+        self.reset_query_routing();
+        self.clear_offline();
     }
 
     #[cfg(any(test, feature = "test-support"))]
@@ -209,6 +265,7 @@ impl BrowseSession {
         self.metadata_run.clear_active();
         self.effective_user = None;
         self.effective_user_run.clear_active();
+        self.clear_offline();
     }
 
     // On reload failure (already Connected), keeps Connected to preserve
@@ -238,6 +295,7 @@ impl BrowseSession {
         self.effective_user = None;
         self.effective_user_run.clear_active();
         self.table_detail_run.clear_active();
+        self.clear_offline();
     }
 
     #[must_use]
@@ -258,6 +316,56 @@ impl BrowseSession {
         self.read_only = false;
     }
 
+    pub fn set_query_routing(&mut self, routing: QueryRouting) {
+        self.query_routing = routing;
+    }
+
+    pub fn record_query_endpoint(&mut self, endpoint: Option<QueryEndpoint>) {
+        self.last_query_endpoint = endpoint;
+    }
+
+    fn reset_query_routing(&mut self) {
+        self.query_routing = QueryRouting::Auto;
+        self.last_query_endpoint = None;
+    }
+
+    // ── Transactions ─────────────────────────────────────────────────
+
+    pub fn begin_transaction(&mut self, dsn: &str) {
+        self.transaction_dsn = Some(dsn.to_string());
+    }
+
+    /// Forgets the open transaction, returning the DSN it must be ended on.
+    pub fn take_transaction(&mut self) -> Option<String> {
+        self.transaction_dsn.take()
+    }
+
+    // ── Offline browsing ─────────────────────────────────────────────
+
+    pub fn offer_offline_snapshot(&mut self, snapshot: Arc<MetadataSnapshot>) {
+        self.offline_snapshot = Some(snapshot);
+    }
+
+    /// Browses the offered snapshot as if connected. Queries stay disabled
+    /// until a reload reaches the database and `mark_connected` runs.
+    #[must_use]
+    pub fn enter_offline(&mut self) -> bool {
+        let Some(snapshot) = &self.offline_snapshot else {
+            return false;
+        };
+        self.metadata = Some(Arc::new(snapshot.metadata.clone()));
+        self.connection_state = ConnectionState::Connected;
+        self.metadata_state = MetadataState::Loaded;
+        self.metadata_run.clear_active();
+        self.offline = true;
+        true
+    }
+
+    fn clear_offline(&mut self) {
+        self.offline_snapshot = None;
+        self.offline = false;
+    }
+
     #[must_use]
     fn begin_metadata_run(&mut self) -> u64 {
         self.metadata_run.begin()
@@ -309,6 +417,7 @@ impl BrowseSession {
         self.table_detail.clone_from(&cache.table_detail);
         self.selected_table_key
             .clone_from(&cache.selected_table_key);
+        self.inspected_object = None;
         self.connection_state = ConnectionState::Connected;
         self.metadata_state = MetadataState::Loaded;
         self.selection_generation = 0;
@@ -342,6 +451,7 @@ impl BrowseSession {
         self.metadata = None;
         self.table_detail = None;
         self.selected_table_key = None;
+        self.inspected_object = None;
         self.selection_generation = 0;
         self.connection_state = ConnectionState::default();
         self.metadata_state = MetadataState::default();
@@ -352,6 +462,9 @@ impl BrowseSession {
         self.clear_connection();
         self.read_only = false;
         self.is_reloading = false;
+        self.reset_query_routing();
+        self.transaction_dsn = None;
+        self.clear_offline();
         query.pagination.reset();
         query.clear_current_result();
         query.restore_history(ResultHistory::default());
@@ -431,6 +544,34 @@ impl BrowseSession {
         self.read_only
     }
 
+    pub fn query_routing(&self) -> QueryRouting {
+        self.query_routing
+    }
+
+    /// Endpoint that served the last ad-hoc query; `None` without a replica.
+    pub fn last_query_endpoint(&self) -> Option<QueryEndpoint> {
+        self.last_query_endpoint
+    }
+
+    pub fn in_transaction(&self) -> bool {
+        self.transaction_dsn.is_some()
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    pub fn has_offline_snapshot(&self) -> bool {
+        self.offline_snapshot.is_some()
+    }
+
+    pub fn offline_table(&self, schema: &str, table: &str) -> Option<&Table> {
+        if !self.offline {
+            return None;
+        }
+        self.offline_snapshot.as_ref()?.table(schema, table)
+    }
+
     pub fn is_reloading(&self) -> bool {
         self.is_reloading
     }
@@ -442,8 +583,15 @@ impl BrowseSession {
             .unwrap_or_default()
     }
 
+    pub fn schema_objects(&self) -> &[SchemaObject] {
+        self.metadata.as_ref().map_or(&[], |m| m.objects.as_slice())
+    }
+
     pub fn is_service_connection(&self) -> bool {
-        self.dsn.as_ref().is_some_and(|d| d.starts_with("service="))
+        self.dsn
+            .as_deref()
+            .and_then(|dsn| Dsn::parse(dsn).ok())
+            .is_some_and(|dsn| dsn.param("service").is_some())
     }
 
     pub fn is_ephemeral_connection(&self) -> bool {
@@ -1026,6 +1174,63 @@ mod tests {
         }
     }
 
+    // ── Offline browsing ─────────────────────────────────────────────
+
+    mod offline {
+        use super::*;
+
+        fn make_snapshot() -> Arc<MetadataSnapshot> {
+            Arc::new(MetadataSnapshot {
+                metadata: (*make_metadata("cached_db")).clone(),
+                tables: vec![make_table_detail()],
+            })
+        }
+
+        #[test]
+        fn enter_offline_without_snapshot_is_rejected() {
+            let mut session = BrowseSession::default();
+
+            assert!(!session.enter_offline());
+            assert!(!session.is_offline());
+        }
+
+        #[test]
+        fn enter_offline_loads_snapshot_metadata() {
+            let mut session = BrowseSession::default();
+            let _ = session.begin_connecting("postgres://localhost/test");
+            session.mark_connection_failed("unreachable".to_string());
+            session.offer_offline_snapshot(make_snapshot());
+
+            assert!(session.enter_offline());
+
+            assert!(session.is_offline());
+            assert!(session.connection_state().is_connected());
+            assert_eq!(session.database_name(), Some("cached_db"));
+            assert!(session.offline_table("public", "users").is_some());
+        }
+
+        #[test]
+        fn offline_table_is_hidden_until_offline_mode_is_entered() {
+            let mut session = BrowseSession::default();
+            session.offer_offline_snapshot(make_snapshot());
+
+            assert!(session.offline_table("public", "users").is_none());
+        }
+
+        #[test]
+        fn mark_connected_leaves_offline_mode() {
+            let mut session = BrowseSession::default();
+            session.offer_offline_snapshot(make_snapshot());
+            assert!(session.enter_offline());
+
+            session.mark_connected(make_metadata("live_db"));
+
+            assert!(!session.is_offline());
+            assert!(!session.has_offline_snapshot());
+            assert_eq!(session.database_name(), Some("live_db"));
+        }
+    }
+
     // ── Getters ──────────────────────────────────────────────────────
 
     mod getter_tests {
@@ -0,0 +1,84 @@
+use crate::ports::outbound::ConnectionCandidate;
+
+/// Where the picker's candidates come from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CandidateDiscovery {
+    /// `:connect import` — saved as profiles through the setup form.
+    #[default]
+    ProjectFiles,
+    /// `:connect docker` — connected to directly without saving.
+    Containers,
+}
+
+/// Candidates found by `:connect import` or `:connect docker`; cleared when
+/// the picker closes.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionImportState {
+    discovery: CandidateDiscovery,
+    loading: bool,
+    candidates: Vec<ConnectionCandidate>,
+    selected: usize,
+    error: Option<String>,
+}
+
+impl ConnectionImportState {
+    pub fn begin_scan(&mut self, discovery: CandidateDiscovery) {
+        *self = Self {
+            discovery,
+            loading: true,
+            ..Self::default()
+        };
+    }
+
+    pub fn discovery(&self) -> CandidateDiscovery {
+        self.discovery
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    pub fn set_loaded(&mut self, candidates: Vec<ConnectionCandidate>) {
+        self.loading = false;
+        self.candidates = candidates;
+        self.selected = 0;
+        self.error = None;
+    }
+
+    pub fn set_failed(&mut self, message: String) {
+        self.loading = false;
+        self.candidates.clear();
+        self.selected = 0;
+        self.error = Some(message);
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn candidates(&self) -> &[ConnectionCandidate] {
+        &self.candidates
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_candidate(&self) -> Option<&ConnectionCandidate> {
+        self.candidates.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.candidates.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
@@ -1,7 +1,9 @@
 pub mod cache;
 pub mod error;
 pub mod error_state;
+pub mod import;
 pub mod list;
 pub mod origin;
+pub mod session_settings;
 pub mod setup;
 pub mod state;
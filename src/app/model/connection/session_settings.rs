@@ -0,0 +1,92 @@
+use crate::domain::connection::{ConnectionId, SessionSettingKey, SessionSettings};
+use crate::model::shared::text_input::TextInputState;
+
+pub const SESSION_SETTINGS_INPUT_VISIBLE_WIDTH: usize = 40;
+
+#[derive(Debug, Clone, Default)]
+pub struct SessionSettingsFormState {
+    profile_id: Option<ConnectionId>,
+    inputs: [TextInputState; SessionSettingKey::ALL.len()],
+    focused: usize,
+}
+
+impl SessionSettingsFormState {
+    pub fn open(&mut self, profile_id: ConnectionId, settings: &SessionSettings) {
+        self.profile_id = Some(profile_id);
+        self.focused = 0;
+        for (input, key) in self.inputs.iter_mut().zip(SessionSettingKey::ALL) {
+            let value = settings.get(key).unwrap_or_default();
+            *input = TextInputState::new(value, value.chars().count());
+            input.update_viewport(SESSION_SETTINGS_INPUT_VISIBLE_WIDTH);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn profile_id(&self) -> Option<&ConnectionId> {
+        self.profile_id.as_ref()
+    }
+
+    pub fn focused_key(&self) -> SessionSettingKey {
+        SessionSettingKey::ALL[self.focused]
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focused = (self.focused + 1) % self.inputs.len();
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.focused = (self.focused + self.inputs.len() - 1) % self.inputs.len();
+    }
+
+    pub fn input(&self, key: SessionSettingKey) -> &TextInputState {
+        let index = SessionSettingKey::ALL
+            .iter()
+            .position(|k| *k == key)
+            .expect("every key has an input");
+        &self.inputs[index]
+    }
+
+    pub fn focused_input_mut(&mut self) -> &mut TextInputState {
+        &mut self.inputs[self.focused]
+    }
+
+    pub fn to_settings(&self) -> SessionSettings {
+        let mut settings = SessionSettings::default();
+        for (input, key) in self.inputs.iter().zip(SessionSettingKey::ALL) {
+            settings.set(key, input.content());
+        }
+        settings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_prefills_inputs_and_round_trips() {
+        let mut settings = SessionSettings::default();
+        settings.set(SessionSettingKey::WorkMem, "64MB");
+        settings.set(SessionSettingKey::SearchPath, "app, public");
+        let mut form = SessionSettingsFormState::default();
+
+        form.open(ConnectionId::new(), &settings);
+
+        assert_eq!(form.input(SessionSettingKey::WorkMem).content(), "64MB");
+        assert_eq!(form.to_settings(), settings);
+    }
+
+    #[test]
+    fn focus_wraps_in_both_directions() {
+        let mut form = SessionSettingsFormState::default();
+
+        form.focus_previous();
+        assert_eq!(form.focused_key(), SessionSettingKey::SearchPath);
+
+        form.focus_next();
+        assert_eq!(form.focused_key(), SessionSettingKey::ApplicationName);
+    }
+}
@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 
 use crate::domain::connection::{
-    ConnectionConfig, ConnectionId, ConnectionProfile, DatabaseType, PostgresConnectionConfig,
-    SqliteConnectionConfig, SqliteConnectionConfigError, SqlitePathError, SslMode,
+    ConnectionConfig, ConnectionId, ConnectionProfile, DatabaseType, MySqlConnectionConfig,
+    PostgresConnectionConfig, SqliteConnectionConfig, SqliteConnectionConfigError, SqlitePathError,
+    SslMode,
 };
 use crate::model::shared::text_input::TextInputState;
+use crate::ports::outbound::ConnectionCandidate;
 
 pub const CONNECTION_INPUT_WIDTH: u16 = 30;
 pub const CONNECTION_INPUT_VISIBLE_WIDTH: usize = (CONNECTION_INPUT_WIDTH - 4) as usize;
@@ -36,6 +38,15 @@ impl ConnectionField {
                 Self::SslMode,
             ],
             DatabaseType::SQLite => &[Self::DatabaseType, Self::Name, Self::SqlitePath],
+            DatabaseType::MySQL => &[
+                Self::DatabaseType,
+                Self::Name,
+                Self::Host,
+                Self::Port,
+                Self::Database,
+                Self::User,
+                Self::Password,
+            ],
         }
     }
 
@@ -292,6 +303,14 @@ impl ConnectionSetupState {
     }
 
     pub fn set_database_type(&mut self, database_type: DatabaseType) {
+        // Follow the engine's default port unless the user already typed one.
+        if let (Some(from), Some(to)) = (
+            default_port(self.database_type),
+            default_port(database_type),
+        ) && self.port.content() == from
+        {
+            self.port = TextInputState::new(to, to.len());
+        }
         self.database_type = database_type;
         self.database_type_dropdown.is_open = false;
         self.ssl_dropdown.is_open = false;
@@ -421,10 +440,28 @@ impl ConnectionSetupState {
             DatabaseType::SQLite => ConnectionConfig::SQLite(SqliteConnectionConfig::new(
                 self.sqlite_path.content().to_string(),
             )?),
+            DatabaseType::MySQL => ConnectionConfig::MySQL(MySqlConnectionConfig::new(
+                self.host.content().trim().to_string(),
+                self.port
+                    .content()
+                    .parse()
+                    .expect("port validated before building connection config"),
+                self.database.content().trim().to_string(),
+                self.user.content().trim().to_string(),
+                self.password.content().to_string(),
+            )),
         })
     }
 }
 
+fn default_port(database_type: DatabaseType) -> Option<&'static str> {
+    match database_type {
+        DatabaseType::PostgreSQL => Some("5432"),
+        DatabaseType::MySQL => Some("3306"),
+        DatabaseType::SQLite => None,
+    }
+}
+
 // UI form baseline: inherit ConnectionSetupState::default() and override profile identity only.
 fn base_from_profile(profile: &ConnectionProfile) -> ConnectionSetupState {
     let name = profile.name.as_str();
@@ -452,28 +489,61 @@ fn prev_visible_field(
     idx.checked_sub(1).and_then(|idx| fields.get(idx).copied())
 }
 
-impl From<&ConnectionProfile> for ConnectionSetupState {
-    fn from(profile: &ConnectionProfile) -> Self {
-        let mut state = base_from_profile(profile);
-        match &profile.config {
+impl ConnectionSetupState {
+    /// A new-connection form pre-filled from an imported candidate.
+    pub fn from_candidate(candidate: &ConnectionCandidate) -> Self {
+        let name: String = candidate
+            .name
+            .chars()
+            .take(ConnectionField::Name.max_chars().unwrap_or(usize::MAX))
+            .collect();
+        let mut state = Self {
+            name: TextInputState::new(&name, name.chars().count()),
+            is_first_run: false,
+            ..Self::default()
+        };
+        state.fill_config(&candidate.config);
+        state
+    }
+
+    fn fill_config(&mut self, config: &ConnectionConfig) {
+        match config {
             ConnectionConfig::PostgreSQL(config) => {
                 let port_str = config.port.to_string();
-                state.database_type = DatabaseType::PostgreSQL;
-                state.host = TextInputState::new(&config.host, config.host.chars().count());
-                state.port = TextInputState::new(&port_str, port_str.chars().count());
-                state.database =
+                self.database_type = DatabaseType::PostgreSQL;
+                self.host = TextInputState::new(&config.host, config.host.chars().count());
+                self.port = TextInputState::new(&port_str, port_str.chars().count());
+                self.database =
                     TextInputState::new(&config.database, config.database.chars().count());
-                state.user = TextInputState::new(&config.username, config.username.chars().count());
-                state.password =
+                self.user = TextInputState::new(&config.username, config.username.chars().count());
+                self.password =
                     TextInputState::new(&config.password, config.password.chars().count());
-                state.ssl_mode = config.ssl_mode;
+                self.ssl_mode = config.ssl_mode;
             }
             ConnectionConfig::SQLite(config) => {
-                state.database_type = DatabaseType::SQLite;
-                state.sqlite_path =
+                self.database_type = DatabaseType::SQLite;
+                self.sqlite_path =
                     TextInputState::new(config.path(), config.path().chars().count());
             }
+            ConnectionConfig::MySQL(config) => {
+                let port_str = config.port.to_string();
+                self.database_type = DatabaseType::MySQL;
+                self.host = TextInputState::new(&config.host, config.host.chars().count());
+                self.port = TextInputState::new(&port_str, port_str.chars().count());
+                self.database =
+                    TextInputState::new(&config.database, config.database.chars().count());
+                self.user = TextInputState::new(&config.username, config.username.chars().count());
+                self.password =
+                    TextInputState::new(&config.password, config.password.chars().count());
+            }
         }
+    }
+}
+
+impl From<&ConnectionProfile> for ConnectionSetupState {
+    fn from(profile: &ConnectionProfile) -> Self {
+        let mut state = base_from_profile(profile);
+        state.fill_config(&profile.config);
         state
     }
 }
@@ -579,6 +649,27 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn switching_to_mysql_replaces_default_port() {
+            let mut state = ConnectionSetupState::default();
+
+            state.set_database_type(DatabaseType::MySQL);
+
+            assert_eq!(state.port.content(), "3306");
+        }
+
+        #[test]
+        fn switching_engine_keeps_custom_port() {
+            let mut state = ConnectionSetupState {
+                port: TextInputState::new("6543", 4),
+                ..ConnectionSetupState::default()
+            };
+
+            state.set_database_type(DatabaseType::MySQL);
+
+            assert_eq!(state.port.content(), "6543");
+        }
+
         #[test]
         fn has_errors_returns_false_when_empty() {
             let state = ConnectionSetupState::default();
@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::domain::ErDiagramOptions;
 use crate::model::shared::async_run::AsyncRun;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -10,6 +11,16 @@ pub enum ErStatus {
     Rendering,
 }
 
+/// Where a diagram goes once every target table is cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErOutput {
+    /// Graphviz export opened in the browser.
+    #[default]
+    Export,
+    /// The in-terminal viewer.
+    Viewer,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ErPreparationState {
     pending_tables: HashSet<String>,
@@ -22,6 +33,8 @@ pub struct ErPreparationState {
     fk_expanded: bool,
     last_signatures: HashMap<String, String>,
     run: AsyncRun,
+    options: ErDiagramOptions,
+    output: ErOutput,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -185,6 +198,23 @@ impl ErPreparationState {
         self.run.last_id()
     }
 
+    pub fn options(&self) -> &ErDiagramOptions {
+        &self.options
+    }
+
+    pub fn set_options(&mut self, options: ErDiagramOptions) {
+        self.options = options;
+    }
+
+    pub fn set_output(&mut self, output: ErOutput) {
+        self.output = output;
+    }
+
+    /// The pending run's output; later runs default back to export.
+    pub fn take_output(&mut self) -> ErOutput {
+        std::mem::take(&mut self.output)
+    }
+
     pub fn can_generate_from_cache(&self) -> bool {
         matches!(self.status, ErStatus::Idle | ErStatus::Waiting)
     }
@@ -200,6 +230,8 @@ impl ErPreparationState {
         self.fk_expanded = false;
         self.last_signatures.clear();
         self.run.clear_active();
+        self.output = ErOutput::Export;
+        // Display options are a user preference, not connection state.
     }
 
     pub fn mark_rendering(&mut self) {
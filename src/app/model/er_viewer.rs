@@ -0,0 +1,344 @@
+use std::collections::HashMap;
+
+use crate::domain::ErTableInfo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErViewerZoom {
+    /// Title-only labels packed close together.
+    Compact,
+    /// Boxed tables with schema and foreign key count.
+    #[default]
+    Detailed,
+}
+
+/// Grid cell of a table in the viewer layout; columns run from referenced
+/// tables on the left to referencing tables on the right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErNodePlacement {
+    pub table: usize,
+    pub column: usize,
+    pub row: usize,
+}
+
+/// Foreign key from `child` to `parent`, both indexes into `tables()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErViewerEdge {
+    pub child: usize,
+    pub parent: usize,
+}
+
+/// In-terminal ER diagram: the neighborhood graph plus viewport state. The
+/// viewport stays centered on the selected table; `pan` offsets it in cells.
+#[derive(Debug, Clone, Default)]
+pub struct ErViewerState {
+    tables: Vec<ErTableInfo>,
+    selected: usize,
+    zoom: ErViewerZoom,
+    focus: bool,
+    pan: (i32, i32),
+}
+
+impl ErViewerState {
+    /// Selects the first of `preferred` present in `tables`, else the first table.
+    pub fn open(mut tables: Vec<ErTableInfo>, preferred: &[String]) -> Self {
+        tables.sort_by(|a, b| a.qualified_name.cmp(&b.qualified_name));
+        let selected = preferred
+            .iter()
+            .find_map(|name| tables.iter().position(|t| &t.qualified_name == name))
+            .unwrap_or(0);
+        Self {
+            tables,
+            selected,
+            ..Self::default()
+        }
+    }
+
+    pub fn tables(&self) -> &[ErTableInfo] {
+        &self.tables
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_table(&self) -> Option<&ErTableInfo> {
+        self.tables.get(self.selected)
+    }
+
+    pub fn zoom(&self) -> ErViewerZoom {
+        self.zoom
+    }
+
+    pub fn is_focus_mode(&self) -> bool {
+        self.focus
+    }
+
+    pub fn pan(&self) -> (i32, i32) {
+        self.pan
+    }
+
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.tables.is_empty() {
+            self.selected = (self.selected + 1) % self.tables.len();
+            self.pan = (0, 0);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.tables.is_empty() {
+            self.selected = (self.selected + self.tables.len() - 1) % self.tables.len();
+            self.pan = (0, 0);
+        }
+    }
+
+    pub fn pan_by(&mut self, dx: i32, dy: i32) {
+        self.pan = (self.pan.0 + dx, self.pan.1 + dy);
+    }
+
+    pub fn zoom_in(&mut self) {
+        self.zoom = ErViewerZoom::Detailed;
+    }
+
+    pub fn zoom_out(&mut self) {
+        self.zoom = ErViewerZoom::Compact;
+    }
+
+    pub fn toggle_focus(&mut self) {
+        self.focus = !self.focus;
+        self.pan = (0, 0);
+    }
+
+    /// Foreign keys between visible tables; self-references are left out.
+    pub fn edges(&self) -> Vec<ErViewerEdge> {
+        let index = &self.index_by_name();
+        let visible = self.visible();
+        let mut edges: Vec<ErViewerEdge> = self
+            .tables
+            .iter()
+            .enumerate()
+            .filter(|(child, _)| visible[*child])
+            .flat_map(|(child, table)| {
+                table.foreign_keys.iter().filter_map(move |fk| {
+                    let parent = *index.get(fk.to_qualified.as_str())?;
+                    (parent != child).then_some(ErViewerEdge { child, parent })
+                })
+            })
+            .filter(|edge| visible[edge.parent])
+            .collect();
+        edges.sort_by_key(|edge| (edge.child, edge.parent));
+        edges.dedup();
+        edges
+    }
+
+    /// Tables laid out by foreign key depth. In focus mode only the selected
+    /// table and its direct neighbors are placed: referenced tables, then the
+    /// selected table, then referencing tables.
+    pub fn placements(&self) -> Vec<ErNodePlacement> {
+        let columns = if self.focus {
+            self.focus_columns()
+        } else {
+            self.depth_columns()
+        };
+        let mut next_row: HashMap<usize, usize> = HashMap::new();
+        columns
+            .into_iter()
+            .enumerate()
+            .filter_map(|(table, column)| {
+                let column = column?;
+                let row = next_row.entry(column).or_default();
+                let placement = ErNodePlacement {
+                    table,
+                    column,
+                    row: *row,
+                };
+                *row += 1;
+                Some(placement)
+            })
+            .collect()
+    }
+
+    fn index_by_name(&self) -> HashMap<&str, usize> {
+        self.tables
+            .iter()
+            .enumerate()
+            .map(|(i, table)| (table.qualified_name.as_str(), i))
+            .collect()
+    }
+
+    fn visible(&self) -> Vec<bool> {
+        if self.focus {
+            self.focus_columns().iter().map(Option::is_some).collect()
+        } else {
+            vec![true; self.tables.len()]
+        }
+    }
+
+    fn parents_of(&self, index: &HashMap<&str, usize>, table: usize) -> Vec<usize> {
+        self.tables[table]
+            .foreign_keys
+            .iter()
+            .filter_map(|fk| index.get(fk.to_qualified.as_str()).copied())
+            .filter(|&parent| parent != table)
+            .collect()
+    }
+
+    fn depth_columns(&self) -> Vec<Option<usize>> {
+        let index = self.index_by_name();
+        let parents: Vec<Vec<usize>> = (0..self.tables.len())
+            .map(|table| self.parents_of(&index, table))
+            .collect();
+        let mut depth = vec![None; self.tables.len()];
+        let mut on_path = vec![false; self.tables.len()];
+        for table in 0..self.tables.len() {
+            fk_depth(table, &parents, &mut depth, &mut on_path);
+        }
+        depth
+    }
+
+    fn focus_columns(&self) -> Vec<Option<usize>> {
+        let mut columns = vec![None; self.tables.len()];
+        if self.tables.is_empty() {
+            return columns;
+        }
+        let index = self.index_by_name();
+        for parent in self.parents_of(&index, self.selected) {
+            columns[parent] = Some(0);
+        }
+        columns[self.selected] = Some(1);
+        for (child, column) in columns.iter_mut().enumerate() {
+            if column.is_none() && self.parents_of(&index, child).contains(&self.selected) {
+                *column = Some(2);
+            }
+        }
+        columns
+    }
+}
+
+/// Longest chain of references from `table` to a table without parents.
+/// A reference back onto the current chain closes an FK cycle and is ignored.
+fn fk_depth(
+    table: usize,
+    parents: &[Vec<usize>],
+    depth: &mut [Option<usize>],
+    on_path: &mut [bool],
+) -> usize {
+    if let Some(known) = depth[table] {
+        return known;
+    }
+    on_path[table] = true;
+    let mut deepest = 0;
+    for &parent in &parents[table] {
+        if !on_path[parent] {
+            deepest = deepest.max(fk_depth(parent, parents, depth, on_path) + 1);
+        }
+    }
+    on_path[table] = false;
+    depth[table] = Some(deepest);
+    deepest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::er::ErFkInfo;
+
+    fn table(name: &str, references: &[&str]) -> ErTableInfo {
+        ErTableInfo {
+            qualified_name: format!("public.{name}"),
+            name: name.to_string(),
+            schema: "public".to_string(),
+            foreign_keys: references
+                .iter()
+                .map(|parent| ErFkInfo {
+                    name: format!("{name}_{parent}_fkey"),
+                    from_qualified: format!("public.{name}"),
+                    to_qualified: format!("public.{parent}"),
+                })
+                .collect(),
+        }
+    }
+
+    fn blog() -> ErViewerState {
+        ErViewerState::open(
+            vec![
+                table("comments", &["posts", "users"]),
+                table("posts", &["users"]),
+                table("users", &[]),
+                table("tags", &[]),
+            ],
+            &["public.posts".to_string()],
+        )
+    }
+
+    fn column_of(state: &ErViewerState, name: &str) -> Option<usize> {
+        state
+            .placements()
+            .into_iter()
+            .find(|p| state.tables()[p.table].name == name)
+            .map(|p| p.column)
+    }
+
+    #[test]
+    fn open_selects_the_preferred_table() {
+        let state = blog();
+
+        assert_eq!(state.selected_table().unwrap().name, "posts");
+    }
+
+    #[test]
+    fn columns_follow_foreign_key_depth() {
+        let state = blog();
+
+        assert_eq!(column_of(&state, "users"), Some(0));
+        assert_eq!(column_of(&state, "tags"), Some(0));
+        assert_eq!(column_of(&state, "posts"), Some(1));
+        assert_eq!(column_of(&state, "comments"), Some(2));
+    }
+
+    #[test]
+    fn cycles_do_not_grow_columns_without_bound() {
+        let state = ErViewerState::open(vec![table("a", &["b"]), table("b", &["a"])], &[]);
+
+        assert!(state.placements().iter().all(|p| p.column <= 2));
+    }
+
+    #[test]
+    fn focus_mode_keeps_only_direct_neighbors() {
+        let mut state = blog();
+
+        state.toggle_focus();
+
+        assert_eq!(column_of(&state, "users"), Some(0));
+        assert_eq!(column_of(&state, "posts"), Some(1));
+        assert_eq!(column_of(&state, "comments"), Some(2));
+        assert_eq!(column_of(&state, "tags"), None);
+        assert!(
+            state
+                .edges()
+                .iter()
+                .all(|edge| state.tables()[edge.child].name != "tags")
+        );
+    }
+
+    #[test]
+    fn edges_skip_self_references() {
+        let state = ErViewerState::open(vec![table("nodes", &["nodes"])], &[]);
+
+        assert!(state.edges().is_empty());
+    }
+
+    #[test]
+    fn selecting_another_table_recenters() {
+        let mut state = blog();
+        state.pan_by(8, -2);
+
+        state.select_next();
+
+        assert_eq!(state.selected_table().unwrap().name, "tags");
+        assert_eq!(state.pan(), (0, 0));
+    }
+}
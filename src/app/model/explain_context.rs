@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
-use crate::domain::explain_plan::{self, ExplainPlan};
-use crate::model::sql_editor::modal::sql_modal_visible_rows;
+use crate::domain::explain_plan::{self, ExplainPlan, ResourceUsage};
+use crate::model::sql_editor::modal::{SqlModalSize, sql_modal_visible_rows};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SlotSource {
@@ -35,6 +35,7 @@ pub struct ExplainContext {
     pub(crate) error: Option<String>,
     pub(crate) is_analyze: bool,
     pub(crate) execution_time_ms: u64,
+    pub(crate) resource_usage: Option<ResourceUsage>,
     pub(crate) scroll_offset: usize,
 
     pub(crate) left: Option<CompareSlot>,
@@ -68,6 +69,10 @@ impl ExplainContext {
         self.execution_time_ms
     }
 
+    pub fn resource_usage(&self) -> Option<&ResourceUsage> {
+        self.resource_usage.as_ref()
+    }
+
     pub fn scroll_offset(&self) -> usize {
         self.scroll_offset
     }
@@ -131,6 +136,9 @@ impl ExplainContext {
         self.history.truncate(MAX_EXPLAIN_HISTORY);
         self.right = self.history.front().cloned();
 
+        self.resource_usage = is_analyze
+            .then(|| explain_plan::parse_resource_usage(&text))
+            .flatten();
         self.plan_text = Some(text);
         self.plan_query_snippet = Some(plan_snippet);
         self.error = None;
@@ -143,6 +151,7 @@ impl ExplainContext {
     pub fn set_error(&mut self, error: String) {
         self.error = Some(error);
         self.plan_text = None;
+        self.resource_usage = None;
         self.scroll_offset = 0;
     }
 
@@ -207,14 +216,15 @@ impl ExplainContext {
         }
     }
 
-    pub fn modal_inner_height(terminal_height: u16) -> usize {
-        sql_modal_visible_rows(terminal_height)
+    pub fn modal_inner_height(terminal_height: u16, size: SqlModalSize) -> usize {
+        sql_modal_visible_rows(terminal_height, size)
     }
 
-    pub fn compare_max_scroll(&self, terminal_height: u16) -> usize {
-        let viewport = self
-            .compare_viewport_height
-            .map_or_else(|| Self::modal_inner_height(terminal_height), |h| h as usize);
+    pub fn compare_max_scroll(&self, terminal_height: u16, size: SqlModalSize) -> usize {
+        let viewport = self.compare_viewport_height.map_or_else(
+            || Self::modal_inner_height(terminal_height, size),
+            |h| h as usize,
+        );
         self.compare_line_count().saturating_sub(viewport)
     }
 }
@@ -252,6 +262,18 @@ mod tests {
         assert_eq!(ctx.right().unwrap().source, SlotSource::AutoLatest);
     }
 
+    #[test]
+    fn analyze_plan_keeps_root_resource_usage() {
+        let mut ctx = ExplainContext::default();
+        let text = "Seq Scan  (cost=0.00..100.00 rows=10 width=32)\n  Buffers: shared hit=8 read=2";
+
+        ctx.set_plan(text.to_string(), true, 42, "SELECT * FROM users");
+        assert_eq!(ctx.resource_usage().map(|usage| usage.shared_read), Some(2));
+
+        ctx.set_error("canceled".to_string());
+        assert!(ctx.resource_usage().is_none());
+    }
+
     #[test]
     fn second_explain_auto_advances_right_to_left() {
         let mut ctx = ExplainContext::default();
@@ -425,7 +447,7 @@ mod tests {
 
     #[test]
     fn modal_inner_height_uses_shared_visible_rows_contract() {
-        assert_eq!(ExplainContext::modal_inner_height(0), 8);
-        assert_eq!(ExplainContext::modal_inner_height(1), 1);
+        assert_eq!(ExplainContext::modal_inner_height(0, SqlModalSize::Half), 8);
+        assert_eq!(ExplainContext::modal_inner_height(1, SqlModalSize::Half), 1);
     }
 }
@@ -1,10 +1,13 @@
 pub mod browse;
 pub mod connection;
 pub mod er_state;
+pub mod er_viewer;
 pub mod explain_context;
+pub mod postgres;
 pub mod shared;
 pub mod sql_editor;
 
 pub mod app_state;
 pub mod runtime_state;
 pub mod sqlite;
+pub mod undo_history;
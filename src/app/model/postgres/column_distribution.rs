@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use crate::domain::ColumnDistribution;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnDistributionEntry {
+    Loading { run_id: u64 },
+    Loaded(ColumnDistribution),
+    Failed,
+}
+
+/// Distributions fetched for the Inspector Stats tab, keyed by qualified
+/// table name and column. Kept for the connection so revisiting a column
+/// does not query again.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnDistributionState {
+    next_run_id: u64,
+    entries: HashMap<(String, String), ColumnDistributionEntry>,
+}
+
+impl ColumnDistributionState {
+    pub fn entry(&self, table: &str, column: &str) -> Option<&ColumnDistributionEntry> {
+        self.entries.get(&(table.to_string(), column.to_string()))
+    }
+
+    pub fn begin_fetch(&mut self, table: &str, column: &str) -> u64 {
+        self.next_run_id = self.next_run_id.wrapping_add(1);
+        let run_id = self.next_run_id;
+        self.entries.insert(
+            (table.to_string(), column.to_string()),
+            ColumnDistributionEntry::Loading { run_id },
+        );
+        run_id
+    }
+
+    pub fn set_loaded(&mut self, run_id: u64, distribution: ColumnDistribution) {
+        if let Some(entry) = self.loading_entry_mut(run_id) {
+            *entry = ColumnDistributionEntry::Loaded(distribution);
+        }
+    }
+
+    /// `false` when the run was superseded, so its error is stale.
+    pub fn set_failed(&mut self, run_id: u64) -> bool {
+        let Some(entry) = self.loading_entry_mut(run_id) else {
+            return false;
+        };
+        *entry = ColumnDistributionEntry::Failed;
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn loading_entry_mut(&mut self, run_id: u64) -> Option<&mut ColumnDistributionEntry> {
+        self.entries.values_mut().find(|entry| {
+            matches!(entry, ColumnDistributionEntry::Loading { run_id: current } if *current == run_id)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_latest_run_for_a_column_lands() {
+        let mut state = ColumnDistributionState::default();
+        let stale = state.begin_fetch("public.users", "email");
+        let current = state.begin_fetch("public.users", "email");
+
+        assert!(!state.set_failed(stale));
+        state.set_loaded(
+            current,
+            ColumnDistribution {
+                sampled_rows: 3,
+                ..ColumnDistribution::default()
+            },
+        );
+
+        assert!(matches!(
+            state.entry("public.users", "email"),
+            Some(ColumnDistributionEntry::Loaded(distribution)) if distribution.sampled_rows == 3
+        ));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod column_distribution;
+pub mod server_settings;
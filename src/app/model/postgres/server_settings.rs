@@ -0,0 +1,227 @@
+use nucleo_matcher::pattern::{CaseMatching, Normalization, Pattern};
+use nucleo_matcher::{Config, Matcher};
+
+use crate::domain::ServerSetting;
+use crate::model::shared::picker::PickerState;
+
+pub struct FilteredSetting<'a> {
+    pub setting: &'a ServerSetting,
+    /// Match positions within `setting.name`; empty when the filter only
+    /// matched the description.
+    pub match_indices: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ServerSettingsState {
+    next_run_id: u64,
+    run_id: u64,
+    loading: bool,
+    entries: Vec<ServerSetting>,
+    picker: PickerState,
+}
+
+impl ServerSettingsState {
+    pub fn begin_fetch(&mut self) -> u64 {
+        self.next_run_id = self.next_run_id.wrapping_add(1);
+        self.run_id = self.next_run_id;
+        self.loading = true;
+        self.entries.clear();
+        self.picker.clear_filter_and_reset();
+        self.run_id
+    }
+
+    pub fn is_current_run(&self, run_id: u64) -> bool {
+        self.loading && self.run_id == run_id
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    pub fn set_loaded(&mut self, run_id: u64, entries: Vec<ServerSetting>) {
+        if self.is_current_run(run_id) {
+            self.loading = false;
+            self.entries = entries;
+            self.picker.reset();
+        }
+    }
+
+    pub fn set_failed(&mut self, run_id: u64) {
+        if self.is_current_run(run_id) {
+            self.loading = false;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.loading = false;
+        self.entries.clear();
+        self.picker.clear_filter_and_reset();
+    }
+
+    pub fn entries(&self) -> &[ServerSetting] {
+        &self.entries
+    }
+
+    pub fn pending_restart_count(&self) -> usize {
+        self.entries.iter().filter(|s| s.pending_restart).count()
+    }
+
+    pub fn picker(&self) -> &PickerState {
+        &self.picker
+    }
+
+    pub fn picker_mut(&mut self) -> &mut PickerState {
+        &mut self.picker
+    }
+
+    /// Fuzzy-matches the filter against the name, then the description.
+    pub fn filtered(&self) -> Vec<FilteredSetting<'_>> {
+        let filter = self.picker.filter_input().content();
+        if filter.is_empty() {
+            return self
+                .entries
+                .iter()
+                .map(|setting| FilteredSetting {
+                    setting,
+                    match_indices: Vec::new(),
+                })
+                .collect();
+        }
+
+        let mut matcher = Matcher::new(Config::DEFAULT);
+        let pattern = Pattern::parse(filter, CaseMatching::Ignore, Normalization::Smart);
+        let mut buf = Vec::new();
+
+        self.entries
+            .iter()
+            .filter_map(|setting| {
+                let mut indices = Vec::new();
+                let haystack = nucleo_matcher::Utf32Str::new(&setting.name, &mut buf);
+                if pattern
+                    .indices(haystack, &mut matcher, &mut indices)
+                    .is_some()
+                {
+                    return Some(FilteredSetting {
+                        setting,
+                        match_indices: indices,
+                    });
+                }
+                let haystack = nucleo_matcher::Utf32Str::new(&setting.short_desc, &mut buf);
+                pattern
+                    .score(haystack, &mut matcher)
+                    .map(|_| FilteredSetting {
+                        setting,
+                        match_indices: Vec::new(),
+                    })
+            })
+            .collect()
+    }
+
+    pub fn clamped_selected(&self) -> usize {
+        self.picker
+            .selected()
+            .min(self.filtered().len().saturating_sub(1))
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.filtered().len();
+        if count > 0 {
+            let next = (self.clamped_selected() + 1).min(count - 1);
+            self.picker.set_selection(next);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        let previous = self.clamped_selected().saturating_sub(1);
+        self.picker.set_selection(previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setting(name: &str, short_desc: &str) -> ServerSetting {
+        ServerSetting {
+            name: name.to_string(),
+            setting: "on".to_string(),
+            unit: None,
+            reset_val: "on".to_string(),
+            boot_val: "on".to_string(),
+            source: "default".to_string(),
+            category: "Autovacuum".to_string(),
+            short_desc: short_desc.to_string(),
+            pending_restart: false,
+        }
+    }
+
+    fn loaded_state() -> ServerSettingsState {
+        let mut state = ServerSettingsState::default();
+        let run_id = state.begin_fetch();
+        state.set_loaded(
+            run_id,
+            vec![
+                setting("autovacuum", "Starts the autovacuum subprocess."),
+                setting(
+                    "shared_buffers",
+                    "Sets the number of shared memory buffers.",
+                ),
+                setting("work_mem", "Sets the maximum memory for query workspaces."),
+            ],
+        );
+        state
+    }
+
+    #[test]
+    fn filter_matches_name_with_highlight_indices() {
+        let mut state = loaded_state();
+        state.picker_mut().insert_filter_str("shbuf");
+
+        let filtered = state.filtered();
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].setting.name, "shared_buffers");
+        assert!(!filtered[0].match_indices.is_empty());
+    }
+
+    #[test]
+    fn filter_falls_back_to_description() {
+        let mut state = loaded_state();
+        state.picker_mut().insert_filter_str("workspaces");
+
+        let filtered = state.filtered();
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].setting.name, "work_mem");
+        assert!(filtered[0].match_indices.is_empty());
+    }
+
+    #[test]
+    fn stale_run_does_not_replace_entries() {
+        let mut state = ServerSettingsState::default();
+        let stale = state.begin_fetch();
+        let current = state.begin_fetch();
+
+        state.set_loaded(stale, vec![setting("work_mem", "")]);
+        assert!(state.is_loading());
+        assert!(state.entries().is_empty());
+
+        state.set_loaded(current, vec![setting("work_mem", "")]);
+        assert!(!state.is_loading());
+        assert_eq!(state.entries().len(), 1);
+    }
+
+    #[test]
+    fn selection_stays_within_filtered_rows() {
+        let mut state = loaded_state();
+        state.picker_mut().set_pane_height(10);
+
+        state.select_next();
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.clamped_selected(), 2);
+
+        state.picker_mut().insert_filter_str("work");
+        assert_eq!(state.clamped_selected(), 0);
+    }
+}
@@ -1,9 +1,24 @@
 use std::path::{Path, PathBuf};
 
+use crate::model::shared::startup_profile::StartupProfile;
+use crate::ports::outbound::{
+    ExecutionPolicies, MaskingRules, NamingConventions, QueryHook, ReleaseInfo,
+};
+
 #[derive(Debug, Clone)]
 pub struct RuntimeState {
     pub(crate) project_name: String,
     pub(crate) service_file_path: Option<PathBuf>,
+    project_root: Option<PathBuf>,
+    query_hooks: Vec<QueryHook>,
+    naming_conventions: NamingConventions,
+    execution_policies: ExecutionPolicies,
+    masking_rules: MaskingRules,
+    finishing_up: bool,
+    queued_effects: usize,
+    cache_owner_pid: Option<u32>,
+    startup: StartupProfile,
+    latest_release: Option<ReleaseInfo>,
 }
 
 impl RuntimeState {
@@ -11,6 +26,16 @@ impl RuntimeState {
         Self {
             project_name,
             service_file_path: None,
+            project_root: None,
+            query_hooks: Vec::new(),
+            naming_conventions: NamingConventions::default(),
+            execution_policies: ExecutionPolicies::default(),
+            masking_rules: MaskingRules::default(),
+            finishing_up: false,
+            queued_effects: 0,
+            cache_owner_pid: None,
+            startup: StartupProfile::default(),
+            latest_release: None,
         }
     }
 
@@ -25,6 +50,92 @@ impl RuntimeState {
     pub fn set_service_file_path(&mut self, path: Option<PathBuf>) {
         self.service_file_path = path;
     }
+
+    /// Where `:files` looks for `.sql` files.
+    pub fn project_root(&self) -> Option<&Path> {
+        self.project_root.as_deref()
+    }
+
+    pub fn set_project_root(&mut self, root: PathBuf) {
+        self.project_root = Some(root);
+    }
+
+    pub fn query_hooks(&self) -> &[QueryHook] {
+        &self.query_hooks
+    }
+
+    pub fn set_query_hooks(&mut self, hooks: Vec<QueryHook>) {
+        self.query_hooks = hooks;
+    }
+
+    pub fn naming_conventions(&self) -> &NamingConventions {
+        &self.naming_conventions
+    }
+
+    pub fn set_naming_conventions(&mut self, conventions: NamingConventions) {
+        self.naming_conventions = conventions;
+    }
+
+    pub fn execution_policies(&self) -> &ExecutionPolicies {
+        &self.execution_policies
+    }
+
+    pub fn set_execution_policies(&mut self, policies: ExecutionPolicies) {
+        self.execution_policies = policies;
+    }
+
+    pub fn masking_rules(&self) -> &MaskingRules {
+        &self.masking_rules
+    }
+
+    pub fn set_masking_rules(&mut self, rules: MaskingRules) {
+        self.masking_rules = rules;
+    }
+
+    /// Set once quitting outlasts the notice delay while in-flight work drains.
+    pub fn begin_finishing_up(&mut self) {
+        self.finishing_up = true;
+    }
+
+    pub fn is_finishing_up(&self) -> bool {
+        self.finishing_up
+    }
+
+    /// Effect tasks waiting for a concurrency slot, as of the last render.
+    pub fn queued_effects(&self) -> usize {
+        self.queued_effects
+    }
+
+    pub fn set_queued_effects(&mut self, count: usize) {
+        self.queued_effects = count;
+    }
+
+    /// PID of the other instance that owns this project's caches, when this
+    /// one only reads them.
+    pub fn cache_owner_pid(&self) -> Option<u32> {
+        self.cache_owner_pid
+    }
+
+    pub fn set_cache_owner_pid(&mut self, pid: Option<u32>) {
+        self.cache_owner_pid = pid;
+    }
+
+    pub fn startup(&self) -> &StartupProfile {
+        &self.startup
+    }
+
+    pub fn startup_mut(&mut self) -> &mut StartupProfile {
+        &mut self.startup
+    }
+
+    /// The latest published release, once the opt-in update check ran.
+    pub fn latest_release(&self) -> Option<&ReleaseInfo> {
+        self.latest_release.as_ref()
+    }
+
+    pub fn set_latest_release(&mut self, release: ReleaseInfo) {
+        self.latest_release = Some(release);
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,10 @@
 use crate::domain::{ConnectionId, QueryValue};
+use crate::model::shared::text_input::TextInputState;
+use crate::policy::result_copy::ResultCopyFormat;
 use crate::update::action::ScrollDirection;
 
+pub const CONFIRM_INPUT_VISIBLE_WIDTH: usize = 30;
+
 #[derive(Debug, Clone)]
 pub struct CsvExportCacheSnapshot {
     pub columns: Vec<String>,
@@ -30,6 +34,14 @@ pub enum ConfirmIntent {
         snapshot: CsvExportCacheSnapshot,
     },
     DisableReadOnly,
+    BlockingReindex {
+        index: String,
+    },
+    CopyLargeResult {
+        content: String,
+        format: ResultCopyFormat,
+        row_count: usize,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -40,6 +52,9 @@ pub struct ConfirmDialogState {
     pub(crate) preview_scroll: u16,
     pub(crate) preview_viewport_height: Option<u16>,
     pub(crate) preview_content_height: Option<u16>,
+    /// Text the user must type before Enter confirms; `None` confirms on Enter.
+    pub(crate) required_input: Option<String>,
+    pub(crate) input: TextInputState,
 }
 
 impl ConfirmDialogState {
@@ -55,6 +70,31 @@ impl ConfirmDialogState {
         self.preview_scroll = 0;
         self.preview_viewport_height = None;
         self.preview_content_height = None;
+        self.required_input = None;
+        self.input = TextInputState::default();
+    }
+
+    /// Call after `open()`: Enter stays inert until `expected` is typed.
+    pub fn require_input(&mut self, expected: impl Into<String>) {
+        self.required_input = Some(expected.into());
+    }
+
+    pub fn required_input(&self) -> Option<&str> {
+        self.required_input.as_deref()
+    }
+
+    pub fn input(&self) -> &TextInputState {
+        &self.input
+    }
+
+    pub fn input_mut(&mut self) -> &mut TextInputState {
+        &mut self.input
+    }
+
+    pub fn input_matches(&self) -> bool {
+        self.required_input
+            .as_deref()
+            .is_none_or(|expected| self.input.content() == expected)
     }
 
     pub fn max_scroll(&self) -> u16 {
@@ -119,6 +159,8 @@ impl Default for ConfirmDialogState {
             preview_scroll: 0,
             preview_viewport_height: None,
             preview_content_height: None,
+            required_input: None,
+            input: TextInputState::default(),
         }
     }
 }
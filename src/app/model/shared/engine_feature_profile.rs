@@ -18,6 +18,9 @@ pub enum ConnectionFeature {
     ErDiagram,
     JsonbDetail,
     SqliteDiagnostics,
+    Maintenance,
+    ServerSettings,
+    SessionSettings,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -71,6 +74,8 @@ const POSTGRESQL_INSPECTOR: InspectorProfile = InspectorProfile::new(
     &[
         InspectorTab::Info,
         InspectorTab::Columns,
+        InspectorTab::Stats,
+        InspectorTab::Storage,
         InspectorTab::Indexes,
         InspectorTab::ForeignKeys,
         InspectorTab::Rls,
@@ -103,9 +108,31 @@ const SQLITE_INSPECTOR: InspectorProfile = InspectorProfile::new(
     ],
 );
 
+const MYSQL_INSPECTOR: InspectorProfile = InspectorProfile::new(
+    &[
+        InspectorTab::Info,
+        InspectorTab::Columns,
+        InspectorTab::Indexes,
+        InspectorTab::ForeignKeys,
+        InspectorTab::Triggers,
+        InspectorTab::Ddl,
+    ],
+    &[
+        InspectorInfoField::Comment,
+        InspectorInfoField::RowCount,
+        InspectorInfoField::Schema,
+        InspectorInfoField::TableName,
+    ],
+);
+
 const NO_CONNECTION_FEATURES: &[ConnectionFeature] = &[];
-const POSTGRESQL_FEATURES: &[ConnectionFeature] =
-    &[ConnectionFeature::ErDiagram, ConnectionFeature::JsonbDetail];
+const POSTGRESQL_FEATURES: &[ConnectionFeature] = &[
+    ConnectionFeature::ErDiagram,
+    ConnectionFeature::JsonbDetail,
+    ConnectionFeature::Maintenance,
+    ConnectionFeature::ServerSettings,
+    ConnectionFeature::SessionSettings,
+];
 const SQLITE_FEATURES: &[ConnectionFeature] = &[ConnectionFeature::SqliteDiagnostics];
 
 impl EngineFeatureProfile {
@@ -167,10 +194,19 @@ impl EngineFeatureProfile {
         )
     }
 
+    pub fn mysql_like() -> Self {
+        Self::new(
+            MYSQL_INSPECTOR,
+            ExplainProfile::QueryPlanOnly,
+            NO_CONNECTION_FEATURES,
+        )
+    }
+
     pub fn for_database_type(database_type: DatabaseType) -> Self {
         match database_type {
             DatabaseType::PostgreSQL => Self::postgres_like(),
             DatabaseType::SQLite => Self::sqlite_like(),
+            DatabaseType::MySQL => Self::mysql_like(),
         }
     }
 
@@ -215,6 +251,18 @@ impl EngineFeatureProfile {
         self.supports_connection_feature(ConnectionFeature::SqliteDiagnostics)
     }
 
+    pub fn supports_maintenance(&self) -> bool {
+        self.supports_connection_feature(ConnectionFeature::Maintenance)
+    }
+
+    pub fn supports_server_settings(&self) -> bool {
+        self.supports_connection_feature(ConnectionFeature::ServerSettings)
+    }
+
+    pub fn supports_session_settings(&self) -> bool {
+        self.supports_connection_feature(ConnectionFeature::SessionSettings)
+    }
+
     pub fn supported_inspector_tabs(&self) -> &'static [InspectorTab] {
         self.inspector.tabs()
     }
@@ -344,6 +392,8 @@ mod tests {
             &[
                 InspectorTab::Info,
                 InspectorTab::Columns,
+                InspectorTab::Stats,
+                InspectorTab::Storage,
                 InspectorTab::Indexes,
                 InspectorTab::ForeignKeys,
                 InspectorTab::Rls,
@@ -384,6 +434,8 @@ mod tests {
                 InspectorTab::Ddl
             ]
         );
+        assert!(!profile.supports_inspector_tab(InspectorTab::Stats));
+        assert!(!profile.supports_inspector_tab(InspectorTab::Storage));
         assert_eq!(
             profile.supported_inspector_info_fields(),
             &[
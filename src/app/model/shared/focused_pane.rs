@@ -7,6 +7,14 @@ pub enum FocusedPane {
 }
 
 impl FocusedPane {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Explorer => "Explorer",
+            Self::Inspector => "Inspector",
+            Self::Result => "Result",
+        }
+    }
+
     pub fn from_browse_key(key: char) -> Option<Self> {
         match key {
             '1' => Some(Self::Explorer),
@@ -163,6 +163,14 @@ pub enum HelpOrigin {
         searching: bool,
     },
     RowDetail,
+    ServerSettings,
+    SessionSettings,
+    SqlFileBrowser,
+    SnippetPicker,
+    ObjectSearch,
+    ErDiagram,
+    ConnectionImport,
+    QuickActions,
 }
 
 impl HelpOrigin {
@@ -186,7 +194,15 @@ impl HelpOrigin {
             | Self::JsonbDetail { .. }
             | Self::JsonbEdit
             | Self::CellDetail { .. }
-            | Self::RowDetail => KeymapPreset::Default,
+            | Self::RowDetail
+            | Self::ServerSettings
+            | Self::SessionSettings
+            | Self::SqlFileBrowser
+            | Self::SnippetPicker
+            | Self::ObjectSearch
+            | Self::ErDiagram
+            | Self::ConnectionImport
+            | Self::QuickActions => KeymapPreset::Default,
         }
     }
 
@@ -232,6 +248,14 @@ impl HelpOrigin {
                 searching: state.cell_detail.search().is_active(),
             },
             InputMode::RowDetail => Self::RowDetail,
+            InputMode::ServerSettings => Self::ServerSettings,
+            InputMode::SessionSettings => Self::SessionSettings,
+            InputMode::SqlFileBrowser => Self::SqlFileBrowser,
+            InputMode::SnippetPicker => Self::SnippetPicker,
+            InputMode::ObjectSearch => Self::ObjectSearch,
+            InputMode::ErDiagram => Self::ErDiagram,
+            InputMode::ConnectionImport => Self::ConnectionImport,
+            InputMode::QuickActions => Self::QuickActions,
         }
     }
 
@@ -268,6 +292,14 @@ impl HelpOrigin {
             Self::CellDetail { searching: true } => "Cell Detail Search",
             Self::CellDetail { searching: false } => "Cell Detail",
             Self::RowDetail => "Row Detail",
+            Self::ServerSettings => "Server Settings",
+            Self::SessionSettings => "Session Settings",
+            Self::SqlFileBrowser => "SQL Files",
+            Self::SnippetPicker => "Snippets",
+            Self::ObjectSearch => "Object Search",
+            Self::ErDiagram => "ER Diagram",
+            Self::ConnectionImport => "Import Connection",
+            Self::QuickActions => "Quick Actions",
         }
     }
 }
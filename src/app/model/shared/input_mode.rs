@@ -20,4 +20,12 @@ pub enum InputMode {
     CellDetail,
     SqliteDiagnostics,
     RowDetail,
+    ServerSettings,
+    SessionSettings,
+    SqlFileBrowser,
+    SnippetPicker,
+    ObjectSearch,
+    ErDiagram,
+    ConnectionImport,
+    QuickActions,
 }
@@ -3,6 +3,8 @@ pub enum InspectorTab {
     #[default]
     Info,
     Columns,
+    Stats,
+    Storage,
     Indexes,
     ForeignKeys,
     Rls,
@@ -11,10 +13,24 @@ pub enum InspectorTab {
 }
 
 impl InspectorTab {
+    pub const ALL: [Self; 9] = [
+        Self::Info,
+        Self::Columns,
+        Self::Stats,
+        Self::Storage,
+        Self::Indexes,
+        Self::ForeignKeys,
+        Self::Rls,
+        Self::Triggers,
+        Self::Ddl,
+    ];
+
     pub fn display_name(self) -> &'static str {
         match self {
             Self::Info => "Info",
             Self::Columns => "Cols",
+            Self::Stats => "Stats",
+            Self::Storage => "Storage",
             Self::Indexes => "Idx",
             Self::ForeignKeys => "FK",
             Self::Rls => "RLS",
@@ -15,7 +15,9 @@ pub mod multi_line_input;
 pub mod picker;
 pub mod render_output;
 pub mod settings;
+pub mod startup_profile;
 pub mod text_input;
 pub mod theme_id;
 pub mod ui_state;
+pub mod usage;
 pub mod viewport;
@@ -1,9 +1,9 @@
 use crate::model::shared::cursor::CursorMove;
 
 use super::text_input::{
-    TextInputEditing, TextInputLike, TextInputState, TextKillDirection, next_word_start,
-    previous_word_start, readline_forward_word_end, readline_previous_whitespace_boundary,
-    readline_previous_word_start,
+    TextInputEditing, TextInputLike, TextInputState, TextKillDirection, next_word_end,
+    next_word_start, previous_word_start, readline_forward_word_end,
+    readline_previous_whitespace_boundary, readline_previous_word_start,
 };
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -113,6 +113,25 @@ impl MultiLineInputState {
         )
     }
 
+    /// Replaces a char range in place, keeping the cursor anchored and the scroll position.
+    pub fn replace_range(&mut self, start: usize, end: usize, replacement: &str) {
+        let cursor = self.cursor();
+        let removed = self.inner.remove_range(start, end);
+        self.inner.insert_str(replacement);
+        let inserted = replacement.chars().count();
+        let removed = removed.chars().count();
+        let new_cursor = if cursor >= start + removed {
+            cursor - removed + inserted
+        } else if cursor <= start {
+            cursor
+        } else {
+            start + inserted
+        };
+        self.inner.set_cursor(new_cursor);
+        self.preferred_col = None;
+        self.rebuild_derived();
+    }
+
     pub fn set_content(&mut self, s: String) {
         self.inner.set_content(s);
         self.scroll_row = 0;
@@ -181,6 +200,11 @@ impl MultiLineInputState {
                 self.set_cursor_and_sync(previous);
                 self.preferred_col = None;
             }
+            CursorMove::WordEnd => {
+                let next = next_word_end(self.content(), self.cursor());
+                self.set_cursor_and_sync(next);
+                self.preferred_col = None;
+            }
             CursorMove::ReadlineWordStart => {
                 let previous = readline_previous_word_start(self.content(), self.cursor());
                 self.set_cursor_and_sync(previous);
@@ -255,6 +279,27 @@ impl MultiLineInputState {
         }
     }
 
+    pub fn line_count(&self) -> usize {
+        self.line_spans().len()
+    }
+
+    /// Char range of `first_row..=last_row`, excluding the final newline.
+    pub fn line_range(&self, first_row: usize, last_row: usize) -> (usize, usize) {
+        let spans = self.line_spans();
+        let last = spans[last_row.min(spans.len() - 1)];
+        (
+            spans[first_row.min(spans.len() - 1)].start,
+            last.start + last.len,
+        )
+    }
+
+    /// Removes a char range and returns it, leaving the cursor at `start`.
+    pub fn remove_chars(&mut self, start: usize, end: usize) -> String {
+        let removed = self.remove_range(start, end);
+        self.set_cursor(start);
+        removed
+    }
+
     pub fn char_to_byte_index(&self, char_idx: usize) -> usize {
         char_to_byte_index_impl(self.content(), char_idx)
     }
@@ -773,6 +818,23 @@ mod tests {
                 assert_eq!(s.cursor(), 3);
             }
 
+            #[rstest]
+            #[case("SELECT users", 0, 5)]
+            #[case("SELECT users", 5, 11)]
+            #[case("foo(bar)", 0, 2)]
+            #[case("foo(bar)", 2, 3)]
+            #[case("foo \n  bar", 2, 9)]
+            #[case("foo", 2, 2)]
+            fn end_moves_to_last_char_of_next_word(
+                #[case] content: &str,
+                #[case] cursor: usize,
+                #[case] expected: usize,
+            ) {
+                let mut s = ml(content, cursor);
+                s.move_cursor(CursorMove::WordEnd);
+                assert_eq!(s.cursor(), expected);
+            }
+
             #[test]
             fn backward_at_start_returns_unchanged() {
                 let mut s = ml("foo", 0);
@@ -1,8 +1,8 @@
 use std::time::Instant;
 
 use super::{
-    MultiLineInputState, next_word_start, previous_word_start, readline_forward_word_end,
-    readline_previous_word_start,
+    MultiLineInputState, next_word_end, next_word_start, previous_word_start,
+    readline_forward_word_end, readline_previous_word_start,
 };
 use crate::model::shared::cursor::CursorMove;
 
@@ -67,6 +67,9 @@ impl BaselineTextInputState {
             CursorMove::WordBackward => {
                 self.cursor = previous_word_start(&self.content, self.cursor);
             }
+            CursorMove::WordEnd => {
+                self.cursor = next_word_end(&self.content, self.cursor);
+            }
             CursorMove::ReadlineWordStart => {
                 self.cursor = readline_previous_word_start(&self.content, self.cursor);
             }
@@ -183,6 +186,10 @@ impl BaselineMultiLineInputState {
                 self.set_cursor_raw(previous_word_start(self.content(), self.cursor()));
                 self.preferred_col = None;
             }
+            CursorMove::WordEnd => {
+                self.set_cursor_raw(next_word_end(self.content(), self.cursor()));
+                self.preferred_col = None;
+            }
             CursorMove::ReadlineWordStart => {
                 self.set_cursor_raw(readline_previous_word_start(self.content(), self.cursor()));
                 self.preferred_col = None;
@@ -41,6 +41,8 @@ pub struct ResultLayout {
     pub viewport_plan: ViewportPlan,
     pub widths_cache: ColumnWidthsCache,
     pub pane_height: u16,
+    /// Whether the active cell's value was cut to fit its column.
+    pub active_cell_truncated: bool,
 }
 
 #[derive(Default)]
@@ -54,6 +56,10 @@ pub struct PickerLayouts {
     pub table: Option<PickerLayout>,
     pub er: Option<PickerLayout>,
     pub query_history: Option<PickerLayout>,
+    pub server_settings: Option<PickerLayout>,
+    pub sql_files: Option<PickerLayout>,
+    pub snippets: Option<PickerLayout>,
+    pub object_search: Option<PickerLayout>,
 }
 
 pub struct PickerLayout {
@@ -1,5 +1,6 @@
 use super::text_input::TextInputState;
 use super::theme_id::ThemeId;
+use crate::i18n::Locale;
 use crate::model::shared::cursor::CursorMove;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -86,6 +87,78 @@ impl KeymapPreset {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordCase {
+    Upper,
+    Lower,
+}
+
+impl KeywordCase {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Upper => "UPPER",
+            Self::Lower => "lower",
+        }
+    }
+
+    pub fn config_value(self) -> &'static str {
+        match self {
+            Self::Upper => "upper",
+            Self::Lower => "lower",
+        }
+    }
+
+    pub fn from_config_value(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "upper" => Some(Self::Upper),
+            "lower" => Some(Self::Lower),
+            _ => None,
+        }
+    }
+
+    pub fn apply(self, word: &str) -> String {
+        match self {
+            Self::Upper => word.to_ascii_uppercase(),
+            Self::Lower => word.to_ascii_lowercase(),
+        }
+    }
+}
+
+/// Which tables the SQL modal prefetches for completion when it opens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrefetchScope {
+    /// Every table in the database.
+    #[default]
+    All,
+    /// The selected table's schema plus schemas named in the SQL buffer,
+    /// widened as the buffer names more.
+    Schema,
+}
+
+impl PrefetchScope {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "all tables",
+            Self::Schema => "current schema",
+        }
+    }
+
+    pub fn config_value(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Schema => "schema",
+        }
+    }
+
+    pub fn from_config_value(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "all" => Some(Self::All),
+            "schema" => Some(Self::Schema),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErBrowserChoice {
     SystemDefault,
@@ -184,6 +257,11 @@ pub struct SettingsState {
     custom_er_browser: TextInputState,
     editing_custom_er_browser: bool,
     section: SettingsSection,
+    keyword_case: Option<KeywordCase>,
+    configured_locale: Option<Locale>,
+    locale: Locale,
+    update_check: bool,
+    prefetch_scope: PrefetchScope,
 }
 
 impl Default for SettingsState {
@@ -198,6 +276,11 @@ impl Default for SettingsState {
             custom_er_browser: TextInputState::default(),
             editing_custom_er_browser: false,
             section: SettingsSection::Appearance,
+            keyword_case: None,
+            configured_locale: None,
+            locale: Locale::default(),
+            update_check: false,
+            prefetch_scope: PrefetchScope::All,
         }
     }
 }
@@ -215,6 +298,47 @@ impl SettingsState {
         self.selected_keymap_preset = keymap_preset;
     }
 
+    pub fn load_keyword_case(&mut self, keyword_case: Option<KeywordCase>) {
+        self.keyword_case = keyword_case;
+    }
+
+    /// `None` leaves typed keywords as-is.
+    pub fn keyword_case(&self) -> Option<KeywordCase> {
+        self.keyword_case
+    }
+
+    /// The config file wins over the environment; English is the fallback.
+    pub fn load_locale(&mut self, configured: Option<Locale>, environment: Option<Locale>) {
+        self.configured_locale = configured;
+        self.locale = configured.or(environment).unwrap_or_default();
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Only the configured value is persisted, never the environment fallback.
+    pub fn configured_locale(&self) -> Option<Locale> {
+        self.configured_locale
+    }
+
+    pub fn load_update_check(&mut self, enabled: bool) {
+        self.update_check = enabled;
+    }
+
+    /// Opt-in: sabiql only contacts GitHub for releases when this is set.
+    pub fn update_check(&self) -> bool {
+        self.update_check
+    }
+
+    pub fn load_prefetch_scope(&mut self, scope: PrefetchScope) {
+        self.prefetch_scope = scope;
+    }
+
+    pub fn prefetch_scope(&self) -> PrefetchScope {
+        self.prefetch_scope
+    }
+
     pub fn open(&mut self, current_theme: ThemeId) {
         self.previous_theme = current_theme;
         self.selected_theme = current_theme;
@@ -404,6 +528,7 @@ fn custom_input_for(browser: Option<&str>) -> TextInputState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn open_tracks_previous_and_selected_theme() {
@@ -449,6 +574,23 @@ mod tests {
         assert_eq!(state.selected_keymap_preset(), KeymapPreset::Ide);
     }
 
+    #[rstest]
+    #[case(Some(Locale::English), Some(Locale::Japanese), Locale::English)]
+    #[case(None, Some(Locale::Japanese), Locale::Japanese)]
+    #[case(None, None, Locale::English)]
+    fn configured_locale_wins_over_environment(
+        #[case] configured: Option<Locale>,
+        #[case] environment: Option<Locale>,
+        #[case] expected: Locale,
+    ) {
+        let mut state = SettingsState::default();
+
+        state.load_locale(configured, environment);
+
+        assert_eq!(state.locale(), expected);
+        assert_eq!(state.configured_locale(), configured);
+    }
+
     #[test]
     fn discard_selection_returns_to_previous_theme() {
         let mut state = SettingsState::default();
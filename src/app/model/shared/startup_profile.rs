@@ -0,0 +1,158 @@
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupPhase {
+    ConfigLoad,
+    FirstRender,
+    Connect,
+    MetadataFetch,
+    PrefetchStart,
+}
+
+impl StartupPhase {
+    pub const ALL: [Self; 5] = [
+        Self::ConfigLoad,
+        Self::FirstRender,
+        Self::Connect,
+        Self::MetadataFetch,
+        Self::PrefetchStart,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ConfigLoad => "config load",
+            Self::FirstRender => "first render",
+            Self::Connect => "connect",
+            Self::MetadataFetch => "metadata fetch",
+            Self::PrefetchStart => "prefetch start",
+        }
+    }
+
+    /// Only local phases have a budget; the rest wait on the database.
+    pub fn budget(self) -> Option<Duration> {
+        match self {
+            Self::ConfigLoad => Some(Duration::from_millis(100)),
+            Self::FirstRender => Some(Duration::from_millis(250)),
+            Self::Connect | Self::MetadataFetch | Self::PrefetchStart => None,
+        }
+    }
+}
+
+/// When each startup phase was first reached, measured from launch.
+#[derive(Debug, Clone, Default)]
+pub struct StartupProfile {
+    launched_at: Option<Instant>,
+    marks: Vec<(StartupPhase, Duration)>,
+}
+
+impl StartupProfile {
+    pub fn begin(&mut self, launched_at: Instant) {
+        self.launched_at = Some(launched_at);
+        self.marks.clear();
+    }
+
+    /// Keeps the first time a phase is reached; later reconnects and
+    /// prefetches are not part of startup.
+    pub fn mark(&mut self, phase: StartupPhase, now: Instant) {
+        let Some(launched_at) = self.launched_at else {
+            return;
+        };
+        if self.elapsed(phase).is_none() {
+            self.marks
+                .push((phase, now.saturating_duration_since(launched_at)));
+        }
+    }
+
+    pub fn is_marked(&self, phase: StartupPhase) -> bool {
+        self.elapsed(phase).is_some()
+    }
+
+    pub fn elapsed(&self, phase: StartupPhase) -> Option<Duration> {
+        self.marks
+            .iter()
+            .find(|(marked, _)| *marked == phase)
+            .map(|(_, elapsed)| *elapsed)
+    }
+
+    /// Phases in the order they were reached.
+    pub fn marks(&self) -> &[(StartupPhase, Duration)] {
+        &self.marks
+    }
+
+    pub fn is_over_budget(&self, phase: StartupPhase) -> bool {
+        self.step(phase)
+            .zip(phase.budget())
+            .is_some_and(|(step, budget)| step > budget)
+    }
+
+    /// Time spent since the previously reached phase.
+    pub fn step(&self, phase: StartupPhase) -> Option<Duration> {
+        let index = self.marks.iter().position(|(marked, _)| *marked == phase)?;
+        let previous = index
+            .checked_sub(1)
+            .map_or(Duration::ZERO, |i| self.marks[i].1);
+        Some(self.marks[index].1.saturating_sub(previous))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_keep_the_first_time_each_phase_is_reached() {
+        let launched_at = Instant::now();
+        let mut profile = StartupProfile::default();
+        profile.begin(launched_at);
+
+        profile.mark(
+            StartupPhase::ConfigLoad,
+            launched_at + Duration::from_millis(20),
+        );
+        profile.mark(
+            StartupPhase::ConfigLoad,
+            launched_at + Duration::from_millis(90),
+        );
+        profile.mark(
+            StartupPhase::FirstRender,
+            launched_at + Duration::from_millis(50),
+        );
+
+        assert_eq!(
+            profile.elapsed(StartupPhase::ConfigLoad),
+            Some(Duration::from_millis(20))
+        );
+        assert_eq!(
+            profile.step(StartupPhase::FirstRender),
+            Some(Duration::from_millis(30))
+        );
+    }
+
+    #[test]
+    fn marks_before_launch_is_recorded_are_ignored() {
+        let mut profile = StartupProfile::default();
+
+        profile.mark(StartupPhase::Connect, Instant::now());
+
+        assert!(profile.marks().is_empty());
+    }
+
+    #[test]
+    fn slow_local_phase_is_over_budget() {
+        let launched_at = Instant::now();
+        let mut profile = StartupProfile::default();
+        profile.begin(launched_at);
+
+        profile.mark(
+            StartupPhase::ConfigLoad,
+            launched_at + Duration::from_millis(10),
+        );
+        profile.mark(
+            StartupPhase::FirstRender,
+            launched_at + Duration::from_millis(400),
+        );
+
+        assert!(!profile.is_over_budget(StartupPhase::ConfigLoad));
+        assert!(profile.is_over_budget(StartupPhase::FirstRender));
+    }
+}
@@ -168,6 +168,9 @@ impl TextInputState {
             CursorMove::WordBackward => {
                 self.cursor = previous_word_start(&self.content, self.cursor);
             }
+            CursorMove::WordEnd => {
+                self.cursor = next_word_end(&self.content, self.cursor);
+            }
             CursorMove::ReadlineWordStart => {
                 self.cursor = readline_previous_word_start(&self.content, self.cursor);
             }
@@ -368,6 +371,24 @@ pub(super) fn previous_word_start(content: &str, cursor: usize) -> usize {
     last_non_whitespace_run_start.unwrap_or(0)
 }
 
+/// Last char of the next word, as vim's `e`; stays put when no word follows.
+pub(super) fn next_word_end(content: &str, cursor: usize) -> usize {
+    let chars: Vec<char> = content.chars().collect();
+    let mut idx = cursor + 1;
+    while idx < chars.len() && chars[idx].is_whitespace() {
+        idx += 1;
+    }
+    if idx >= chars.len() {
+        return cursor.min(chars.len());
+    }
+
+    let kind = classify_word_char(chars[idx]);
+    while idx + 1 < chars.len() && classify_word_char(chars[idx + 1]) == kind {
+        idx += 1;
+    }
+    idx
+}
+
 pub(super) fn readline_forward_word_end(content: &str, cursor: usize) -> usize {
     let chars: Vec<char> = content.chars().collect();
     let mut idx = cursor.min(chars.len());
@@ -1,7 +1,7 @@
 use std::time::Instant;
 
 use super::{
-    TextInputState, next_word_start, previous_word_start, readline_forward_word_end,
+    TextInputState, next_word_end, next_word_start, previous_word_start, readline_forward_word_end,
     readline_previous_word_start,
 };
 use crate::model::shared::cursor::CursorMove;
@@ -54,6 +54,9 @@ impl BaselineTextInputState {
             CursorMove::WordBackward => {
                 self.cursor = previous_word_start(&self.content, self.cursor);
             }
+            CursorMove::WordEnd => {
+                self.cursor = next_word_end(&self.content, self.cursor);
+            }
             CursorMove::ReadlineWordStart => {
                 self.cursor = readline_previous_word_start(&self.content, self.cursor);
             }
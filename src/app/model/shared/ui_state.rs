@@ -81,11 +81,34 @@ pub struct YankFlash {
     pub until: Instant,
 }
 
+/// Inclusive row and column bounds of a visual selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResultRange {
+    pub rows: (usize, usize),
+    pub cols: (usize, usize),
+}
+
+impl ResultRange {
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        (self.rows.0..=self.rows.1).contains(&row) && (self.cols.0..=self.cols.1).contains(&col)
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows.1 - self.rows.0 + 1
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.cols.1 - self.cols.0 + 1
+    }
+}
+
 // Invariant: `row` and `cell` are both `Some` for CellActive, or both `None` for Scroll.
+// `anchor` is only set while CellActive; the range spans it and the active cell.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ResultSelection {
     row: Option<usize>,
     cell: Option<usize>,
+    anchor: Option<(usize, usize)>,
 }
 
 impl ResultSelection {
@@ -116,9 +139,28 @@ impl ResultSelection {
     pub fn enter_cell(&mut self, row: usize, col: usize) {
         self.row = Some(row);
         self.cell = Some(col);
+        self.anchor = None;
         debug_assert!(self.is_consistent());
     }
 
+    /// Anchors a visual range at the active cell; no-op in Scroll mode.
+    pub fn start_range(&mut self) {
+        self.anchor = self.row().zip(self.cell());
+    }
+
+    pub fn clear_range(&mut self) {
+        self.anchor = None;
+    }
+
+    pub fn range(&self) -> Option<ResultRange> {
+        let (anchor_row, anchor_col) = self.anchor?;
+        let (row, col) = self.row().zip(self.cell())?;
+        Some(ResultRange {
+            rows: (anchor_row.min(row), anchor_row.max(row)),
+            cols: (anchor_col.min(col), anchor_col.max(col)),
+        })
+    }
+
     pub fn move_row(&mut self, row: usize) {
         debug_assert!(self.is_consistent());
         if self.cell.is_some() {
@@ -138,6 +180,7 @@ impl ResultSelection {
     pub fn reset(&mut self) {
         self.row = None;
         self.cell = None;
+        self.anchor = None;
     }
 
     pub fn clamp(&mut self, max_rows: usize, max_cols: usize) {
@@ -160,6 +203,9 @@ impl ResultSelection {
         {
             self.cell = Some(max_cols - 1);
         }
+        if let Some((r, c)) = self.anchor {
+            self.anchor = Some((r.min(max_rows - 1), c.min(max_cols - 1)));
+        }
     }
 }
 
@@ -174,6 +220,7 @@ pub struct UiState {
     // Default::default() leaves this at 0 until the first render updates it, so
     // scroll_max_offset may temporarily return the full content width.
     explorer_content_width: usize,
+    explorer_marked_tables: BTreeSet<String>,
 
     connection_list_selected: usize,
     connection_list_scroll_offset: usize,
@@ -188,6 +235,9 @@ pub struct UiState {
     inspector_tab: InspectorTab,
     inspector_scroll_offset: usize,
     inspector_horizontal_offset: usize,
+    inspector_column_cursor: usize,
+    inspector_pinned_column: Option<String>,
+    inspector_rls_role: Option<String>,
     inspector_viewport_plan: ViewportPlan,
     inspector_pane_height: u16,
 
@@ -195,7 +245,9 @@ pub struct UiState {
 
     result_viewport_plan: ViewportPlan,
     result_widths_cache: ColumnWidthsCache,
+    result_active_cell_truncated: bool,
     result_pane_height: u16,
+    result_type_row: bool,
     jsonb_detail_editor_visible_rows: usize,
     pub row_detail_content_visible_rows: usize,
     pub row_detail_content_visible_columns: usize,
@@ -341,6 +393,21 @@ impl UiState {
         &mut self.er_picker
     }
 
+    /// Qualified names of the tables marked for a bulk operation.
+    pub fn explorer_marked_tables(&self) -> &BTreeSet<String> {
+        &self.explorer_marked_tables
+    }
+
+    pub fn toggle_explorer_marked_table(&mut self, table: String) {
+        if !self.explorer_marked_tables.remove(&table) {
+            self.explorer_marked_tables.insert(table);
+        }
+    }
+
+    pub fn clear_explorer_marked_tables(&mut self) {
+        self.explorer_marked_tables.clear();
+    }
+
     pub fn er_selected_tables(&self) -> &BTreeSet<String> {
         &self.er_selected_tables
     }
@@ -391,6 +458,40 @@ impl UiState {
         self.inspector_horizontal_offset = offset;
     }
 
+    /// Row of the Columns tab that `p` pins; may sit outside the viewport
+    /// after scrolling another tab, so readers clamp it into view.
+    pub fn inspector_column_cursor(&self) -> usize {
+        self.inspector_column_cursor
+    }
+
+    pub fn set_inspector_column_cursor(&mut self, cursor: usize) {
+        self.inspector_column_cursor = cursor;
+    }
+
+    pub fn inspector_pinned_column(&self) -> Option<&str> {
+        self.inspector_pinned_column.as_deref()
+    }
+
+    pub fn set_inspector_pinned_column(&mut self, column: Option<String>) {
+        self.inspector_pinned_column = column;
+    }
+
+    /// Role the RLS tab is narrowed to; `None` lists every role.
+    pub fn inspector_rls_role(&self) -> Option<&str> {
+        self.inspector_rls_role.as_deref()
+    }
+
+    pub fn set_inspector_rls_role(&mut self, role: Option<String>) {
+        self.inspector_rls_role = role;
+    }
+
+    /// Cursor, pin and RLS role filter belong to one table.
+    pub fn reset_inspector_column_focus(&mut self) {
+        self.inspector_column_cursor = 0;
+        self.inspector_pinned_column = None;
+        self.inspector_rls_role = None;
+    }
+
     pub fn inspector_viewport_plan(&self) -> &ViewportPlan {
         &self.inspector_viewport_plan
     }
@@ -431,6 +532,14 @@ impl UiState {
         self.result_widths_cache = cache;
     }
 
+    pub fn result_active_cell_truncated(&self) -> bool {
+        self.result_active_cell_truncated
+    }
+
+    pub fn set_result_active_cell_truncated(&mut self, truncated: bool) {
+        self.result_active_cell_truncated = truncated;
+    }
+
     pub fn result_pane_height(&self) -> u16 {
         self.result_pane_height
     }
@@ -501,7 +610,23 @@ impl UiState {
     }
 
     pub fn result_visible_rows(&self) -> usize {
-        self.result_pane_height.saturating_sub(RESULT_PANE_OVERHEAD) as usize
+        self.result_pane_height
+            .saturating_sub(RESULT_PANE_OVERHEAD + self.result_header_extra_rows()) as usize
+    }
+
+    pub fn shows_result_types(&self) -> bool {
+        self.result_type_row
+    }
+
+    /// Returns the new state: `true` when the type row is now shown.
+    pub fn toggle_result_types(&mut self) -> bool {
+        self.result_type_row = !self.result_type_row;
+        self.result_type_row
+    }
+
+    /// Header lines beyond the column-name row.
+    pub fn result_header_extra_rows(&self) -> u16 {
+        u16::from(self.result_type_row)
     }
 
     pub fn explorer_visible_items(&self) -> usize {
@@ -760,6 +885,18 @@ mod tests {
             assert_eq!(visible, expected);
         }
 
+        #[test]
+        fn type_row_takes_one_data_row() {
+            let mut state = UiState {
+                result_pane_height: 15,
+                ..Default::default()
+            };
+
+            assert!(state.toggle_result_types());
+
+            assert_eq!(state.result_visible_rows(), 9);
+        }
+
         #[test]
         fn small_result_pane_height_does_not_underflow() {
             let state = UiState {
@@ -1078,6 +1215,47 @@ mod tests {
             assert_eq!(sel.row(), None);
         }
 
+        #[test]
+        fn range_spans_anchor_and_active_cell_in_any_direction() {
+            let mut sel = ResultSelection::default();
+            sel.enter_cell(4, 3);
+            sel.start_range();
+
+            sel.move_row(1);
+            sel.move_cell(5);
+
+            let range = sel.range().unwrap();
+            assert_eq!(range.rows, (1, 4));
+            assert_eq!(range.cols, (3, 5));
+            assert_eq!((range.row_count(), range.col_count()), (4, 3));
+            assert!(range.contains(2, 4));
+            assert!(!range.contains(0, 4));
+        }
+
+        #[test]
+        fn range_is_dropped_on_reset_and_reentry() {
+            let mut sel = ResultSelection::default();
+            sel.enter_cell(0, 0);
+            sel.start_range();
+            assert!(sel.range().is_some());
+
+            sel.enter_cell(2, 2);
+            assert!(sel.range().is_none());
+
+            sel.start_range();
+            sel.reset();
+            assert!(sel.range().is_none());
+        }
+
+        #[test]
+        fn start_range_in_scroll_mode_is_noop() {
+            let mut sel = ResultSelection::default();
+
+            sel.start_range();
+
+            assert!(sel.range().is_none());
+        }
+
         #[test]
         fn clamp_resets_when_zero_rows() {
             let mut sel = ResultSelection::default();
@@ -1134,6 +1312,7 @@ mod tests {
             let sel = ResultSelection {
                 row: Some(1),
                 cell: None,
+                anchor: None,
             };
 
             assert_eq!(sel.row(), None);
@@ -0,0 +1,131 @@
+use super::focused_pane::FocusedPane;
+use super::inspector_tab::InspectorTab;
+use crate::domain::{UsageKind, UsageStats};
+
+/// Opt-in usage counters. Stats load regardless of the setting so turning
+/// tracking back on never drops earlier counts, but nothing is recorded or
+/// used for ordering while it is off.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageTracker {
+    enabled: bool,
+    stats: UsageStats,
+    dirty: bool,
+}
+
+impl UsageTracker {
+    pub fn load(&mut self, enabled: bool, stats: UsageStats) {
+        self.enabled = enabled;
+        self.stats = stats;
+        self.dirty = false;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn stats(&self) -> &UsageStats {
+        &self.stats
+    }
+
+    /// Unsaved counts since load.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn record_pane(&mut self, pane: FocusedPane) {
+        self.record(UsageKind::Pane, pane.label());
+    }
+
+    pub fn record_tab(&mut self, tab: InspectorTab) {
+        self.record(UsageKind::Tab, tab.display_name());
+    }
+
+    pub fn record_command(&mut self, command: &str) {
+        self.record(UsageKind::Command, command);
+    }
+
+    /// Zero while tracking is off, so orderings fall back to the static list.
+    pub fn command_count(&self, command: &str) -> u64 {
+        if self.enabled {
+            self.stats.count(UsageKind::Command, command)
+        } else {
+            0
+        }
+    }
+
+    pub fn favorite_tab(&self) -> Option<InspectorTab> {
+        if !self.enabled {
+            return None;
+        }
+        InspectorTab::ALL
+            .into_iter()
+            .map(|tab| (tab, self.stats.count(UsageKind::Tab, tab.display_name())))
+            .filter(|(_, count)| *count > 0)
+            .fold(
+                None,
+                |best: Option<(InspectorTab, u64)>, candidate| match best {
+                    Some(best) if best.1 >= candidate.1 => Some(best),
+                    _ => Some(candidate),
+                },
+            )
+            .map(|(tab, _)| tab)
+    }
+
+    fn record(&mut self, kind: UsageKind, key: &str) {
+        if self.enabled {
+            self.stats.record(kind, key);
+            self.dirty = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> UsageTracker {
+        let mut tracker = UsageTracker::default();
+        tracker.load(true, UsageStats::default());
+        tracker
+    }
+
+    #[test]
+    fn disabled_tracker_records_nothing() {
+        let mut tracker = UsageTracker::default();
+
+        tracker.record_command(":sql");
+
+        assert!(tracker.stats().is_empty());
+        assert!(!tracker.is_dirty());
+    }
+
+    #[test]
+    fn disabled_tracker_hides_loaded_counts() {
+        let mut stats = UsageStats::default();
+        stats.record(UsageKind::Command, ":sql");
+        stats.record(UsageKind::Tab, "DDL");
+        let mut tracker = UsageTracker::default();
+        tracker.load(false, stats);
+
+        assert_eq!(tracker.command_count(":sql"), 0);
+        assert_eq!(tracker.favorite_tab(), None);
+    }
+
+    #[test]
+    fn favorite_tab_prefers_count_then_tab_order() {
+        let mut tracker = enabled();
+        tracker.record_tab(InspectorTab::Ddl);
+        tracker.record_tab(InspectorTab::Indexes);
+
+        assert_eq!(tracker.favorite_tab(), Some(InspectorTab::Indexes));
+
+        tracker.record_tab(InspectorTab::Ddl);
+
+        assert_eq!(tracker.favorite_tab(), Some(InspectorTab::Ddl));
+        assert!(tracker.is_dirty());
+    }
+}
@@ -5,6 +5,10 @@ pub enum CompletionKind {
     Keyword,
     Table,
     Column,
+    Function,
+    Snippet,
+    /// A built-in skeleton whose tab stops Tab walks through after accepting.
+    Template,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -12,6 +16,13 @@ pub struct CompletionCandidate {
     pub text: String,
     pub kind: CompletionKind,
     pub score: i32,
+    /// Short annotation shown beside the kind label, e.g. column statistics.
+    pub detail: Option<String>,
+    /// Inserted instead of `text` on accept, e.g. a saved snippet's SQL.
+    pub insert_text: Option<String>,
+    /// Lines for the side preview pane: a function's full signature, a
+    /// table's columns, a column's type and constraints.
+    pub preview: Vec<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -21,6 +32,9 @@ pub struct CompletionState {
     pub selected_index: usize,
     pub trigger_position: usize,
     pub recent_columns: VecDeque<String>,
+    /// Whether the popup shows the selected candidate's preview beside it;
+    /// kept across popups for the session.
+    pub preview_visible: bool,
 }
 
 impl CompletionState {
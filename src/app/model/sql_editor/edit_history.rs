@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+
+use crate::model::shared::multi_line_input::MultiLineInputState;
+use crate::model::shared::text_input::TextInputLike;
+
+const MAX_EDITS: usize = 200;
+
+/// How an edit joins the undo group before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlEditKind {
+    /// A typed identifier char; extends the open insert group.
+    InsertWord,
+    /// Whitespace or punctuation; starts an insert group the next word joins.
+    InsertSeparator,
+    /// A single-char backspace or delete; extends the open erase group.
+    Erase,
+    /// Anything else is undone on its own.
+    Replace,
+}
+
+impl SqlEditKind {
+    pub fn for_char(ch: char) -> Self {
+        if ch.is_alphanumeric() || ch == '_' {
+            Self::InsertWord
+        } else {
+            Self::InsertSeparator
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OpenGroup {
+    Insert,
+    Erase,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SqlEditSnapshot {
+    pub content: String,
+    pub cursor: usize,
+}
+
+impl SqlEditSnapshot {
+    fn of(editor: &MultiLineInputState) -> Self {
+        Self {
+            content: editor.content().to_string(),
+            cursor: editor.cursor(),
+        }
+    }
+}
+
+/// Undo/redo stacks for the SQL editor buffer.
+///
+/// Edits are noticed by comparing the editor against the last seen buffer, so
+/// every path that rewrites it is covered. Runs of typed word chars or single
+/// char erases collapse into one step; moving the cursor ends the run.
+#[derive(Debug, Clone, Default)]
+pub struct SqlEditHistory {
+    undo: VecDeque<SqlEditSnapshot>,
+    redo: Vec<SqlEditSnapshot>,
+    seen: SqlEditSnapshot,
+    open: Option<OpenGroup>,
+}
+
+impl SqlEditHistory {
+    /// Records the buffer before `editor` changed; a no-op when it did not.
+    pub fn sync(&mut self, editor: &MultiLineInputState, kind: SqlEditKind) {
+        if editor.content() == self.seen.content {
+            if editor.cursor() != self.seen.cursor {
+                self.seen.cursor = editor.cursor();
+                self.open = None;
+            }
+            return;
+        }
+
+        let before = std::mem::replace(&mut self.seen, SqlEditSnapshot::of(editor));
+        let joins = matches!(
+            (self.open, kind),
+            (Some(OpenGroup::Insert), SqlEditKind::InsertWord)
+                | (Some(OpenGroup::Erase), SqlEditKind::Erase)
+        );
+        self.redo.clear();
+        self.open = match kind {
+            SqlEditKind::InsertWord | SqlEditKind::InsertSeparator => Some(OpenGroup::Insert),
+            SqlEditKind::Erase => Some(OpenGroup::Erase),
+            SqlEditKind::Replace => None,
+        };
+        if joins {
+            return;
+        }
+        if self.undo.len() == MAX_EDITS {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(before);
+    }
+
+    pub fn break_group(&mut self) {
+        self.open = None;
+    }
+
+    /// The buffer to restore, or `None` at the oldest change.
+    pub fn undo(&mut self) -> Option<SqlEditSnapshot> {
+        let snapshot = self.undo.pop_back()?;
+        self.redo
+            .push(std::mem::replace(&mut self.seen, snapshot.clone()));
+        self.open = None;
+        Some(snapshot)
+    }
+
+    /// The buffer to restore, or `None` at the newest change.
+    pub fn redo(&mut self) -> Option<SqlEditSnapshot> {
+        let snapshot = self.redo.pop()?;
+        self.undo
+            .push_back(std::mem::replace(&mut self.seen, snapshot.clone()));
+        self.open = None;
+        Some(snapshot)
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::update::action::CursorMove;
+    use rstest::rstest;
+
+    fn type_text(history: &mut SqlEditHistory, editor: &mut MultiLineInputState, text: &str) {
+        for ch in text.chars() {
+            editor.insert_char(ch);
+            history.sync(editor, SqlEditKind::for_char(ch));
+        }
+    }
+
+    fn undo_all(history: &mut SqlEditHistory) -> Vec<String> {
+        std::iter::from_fn(|| history.undo().map(|s| s.content)).collect()
+    }
+
+    #[test]
+    fn typed_words_undo_one_word_at_a_time() {
+        let mut history = SqlEditHistory::default();
+        let mut editor = MultiLineInputState::default();
+
+        type_text(&mut history, &mut editor, "SELECT id, name");
+
+        assert_eq!(
+            undo_all(&mut history),
+            ["SELECT id,", "SELECT id", "SELECT", ""]
+        );
+    }
+
+    #[test]
+    fn moving_the_cursor_ends_the_group() {
+        let mut history = SqlEditHistory::default();
+        let mut editor = MultiLineInputState::default();
+        type_text(&mut history, &mut editor, "abc");
+
+        editor.move_cursor(CursorMove::Left);
+        history.sync(&editor, SqlEditKind::Replace);
+        type_text(&mut history, &mut editor, "x");
+
+        assert_eq!(undo_all(&mut history), ["abc", ""]);
+    }
+
+    #[rstest]
+    #[case(SqlEditKind::Erase, 2)]
+    #[case(SqlEditKind::Replace, 4)]
+    fn erases_group_unless_replaced(#[case] kind: SqlEditKind, #[case] expected_steps: usize) {
+        let mut history = SqlEditHistory::default();
+        let mut editor = MultiLineInputState::default();
+        type_text(&mut history, &mut editor, "SELECT");
+
+        for _ in 0..3 {
+            editor.backspace();
+            history.sync(&editor, kind);
+        }
+
+        assert_eq!(undo_all(&mut history).len(), expected_steps);
+    }
+
+    #[test]
+    fn redo_replays_undone_edits_until_a_new_edit() {
+        let mut history = SqlEditHistory::default();
+        let mut editor = MultiLineInputState::default();
+        type_text(&mut history, &mut editor, "a b");
+
+        assert_eq!(history.undo().map(|s| s.content).as_deref(), Some("a"));
+        assert_eq!(history.redo().map(|s| s.content).as_deref(), Some("a b"));
+        assert!(!history.can_redo());
+
+        let restored = history.undo().unwrap();
+        editor.set_content_with_cursor(restored.content, restored.cursor);
+        history.sync(&editor, SqlEditKind::Replace);
+        editor.insert_char('c');
+        history.sync(&editor, SqlEditKind::InsertWord);
+
+        assert!(!history.can_redo());
+    }
+}
@@ -0,0 +1,175 @@
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskChange {
+    Unchanged,
+    /// No local edits, so the buffer should take the new content.
+    Reload,
+    /// Both sides changed; the buffer is kept until `:w` or `:e!`.
+    Conflict,
+}
+
+#[derive(Debug, Clone)]
+pub struct LinkedSqlFile {
+    path: PathBuf,
+    /// Content last read from or written to disk.
+    synced: String,
+    conflict: bool,
+}
+
+impl LinkedSqlFile {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn file_name(&self) -> String {
+        self.path.file_name().map_or_else(
+            || self.path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        )
+    }
+
+    pub fn is_dirty(&self, buffer: &str) -> bool {
+        buffer != self.synced
+    }
+
+    pub fn has_conflict(&self) -> bool {
+        self.conflict
+    }
+}
+
+/// The file `:edit` tied the SQL modal buffer to. `link_id` retires polls
+/// and writes issued for a previous link.
+#[derive(Debug, Clone, Default)]
+pub struct LinkedSqlFileState {
+    link_id: u64,
+    file: Option<LinkedSqlFile>,
+}
+
+impl LinkedSqlFileState {
+    pub fn link(&mut self, path: PathBuf, content: String) -> u64 {
+        self.link_id = self.link_id.wrapping_add(1);
+        self.file = Some(LinkedSqlFile {
+            path,
+            synced: content,
+            conflict: false,
+        });
+        self.link_id
+    }
+
+    pub fn file(&self) -> Option<&LinkedSqlFile> {
+        self.file.as_ref()
+    }
+
+    pub fn link_id(&self) -> u64 {
+        self.link_id
+    }
+
+    pub fn is_current(&self, link_id: u64) -> bool {
+        self.file.is_some() && self.link_id == link_id
+    }
+
+    pub fn apply_disk_content(&mut self, disk: &str, buffer: &str) -> DiskChange {
+        let Some(file) = self.file.as_mut() else {
+            return DiskChange::Unchanged;
+        };
+        if disk == file.synced {
+            return DiskChange::Unchanged;
+        }
+        let had_local_edits = buffer != file.synced;
+        file.synced = disk.to_string();
+        if !had_local_edits {
+            file.conflict = false;
+            DiskChange::Reload
+        } else if buffer == disk {
+            file.conflict = false;
+            DiskChange::Unchanged
+        } else {
+            file.conflict = true;
+            DiskChange::Conflict
+        }
+    }
+
+    pub fn mark_written(&mut self, content: String) {
+        if let Some(file) = self.file.as_mut() {
+            file.synced = content;
+            file.conflict = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linked(content: &str) -> LinkedSqlFileState {
+        let mut state = LinkedSqlFileState::default();
+        state.link(PathBuf::from("/work/app/report.sql"), content.to_string());
+        state
+    }
+
+    #[test]
+    fn external_change_reloads_clean_buffer() {
+        let mut state = linked("SELECT 1;");
+
+        let change = state.apply_disk_content("SELECT 2;", "SELECT 1;");
+
+        assert_eq!(change, DiskChange::Reload);
+        assert!(!state.file().unwrap().is_dirty("SELECT 2;"));
+    }
+
+    #[test]
+    fn external_change_with_local_edits_is_a_conflict() {
+        let mut state = linked("SELECT 1;");
+
+        let change = state.apply_disk_content("SELECT 2;", "SELECT 1 + 1;");
+
+        assert_eq!(change, DiskChange::Conflict);
+        let file = state.file().unwrap();
+        assert!(file.has_conflict());
+        assert!(file.is_dirty("SELECT 1 + 1;"));
+    }
+
+    #[test]
+    fn same_edit_on_both_sides_is_not_a_conflict() {
+        let mut state = linked("SELECT 1;");
+
+        let change = state.apply_disk_content("SELECT 2;", "SELECT 2;");
+
+        assert_eq!(change, DiskChange::Unchanged);
+        assert!(!state.file().unwrap().has_conflict());
+    }
+
+    #[test]
+    fn unchanged_disk_keeps_local_edits_dirty() {
+        let mut state = linked("SELECT 1;");
+
+        let change = state.apply_disk_content("SELECT 1;", "SELECT 1 + 1;");
+
+        assert_eq!(change, DiskChange::Unchanged);
+        assert!(state.file().unwrap().is_dirty("SELECT 1 + 1;"));
+    }
+
+    #[test]
+    fn write_clears_conflict() {
+        let mut state = linked("SELECT 1;");
+        state.apply_disk_content("SELECT 2;", "SELECT 3;");
+
+        state.mark_written("SELECT 3;".to_string());
+
+        let file = state.file().unwrap();
+        assert!(!file.has_conflict());
+        assert!(!file.is_dirty("SELECT 3;"));
+    }
+
+    #[test]
+    fn relinking_retires_previous_link_id() {
+        let mut state = linked("SELECT 1;");
+        let previous = state.link_id();
+
+        state.link(PathBuf::from("/work/app/other.sql"), String::new());
+
+        assert!(!state.is_current(previous));
+        assert!(state.is_current(state.link_id()));
+    }
+}
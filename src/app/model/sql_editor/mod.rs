@@ -1,3 +1,8 @@
 pub mod completion;
+pub mod edit_history;
+pub mod linked_file;
 pub mod modal;
 pub mod query_history;
+pub mod snippets;
+pub mod sql_files;
+pub mod vim;
@@ -1,14 +1,24 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::time::Instant;
 
-use crate::domain::CommandTag;
+use crate::domain::{CommandTag, LockBlocker, LockConflictKind};
 use crate::model::shared::async_run::AsyncRun;
 use crate::model::shared::multi_line_input::MultiLineInputState;
+use crate::model::shared::settings::KeywordCase;
 use crate::model::shared::text_input::{TextInputLike, TextInputState};
+use crate::policy::sql::alias_rename::{PendingAliasRename, table_aliases, track_alias_edit};
+use crate::policy::sql::keyword_case::keyword_ending_at;
+use crate::policy::sql::lexer::Token;
+use crate::policy::sql::lint::{LintWarning, relint_sql};
+use crate::policy::sql::templates::expand_tab_stops;
+use crate::policy::sql::token_cache::TokenCache;
+use crate::policy::sql::variables::substitute_variables;
 use crate::policy::write::sql_risk::AcknowledgeReason;
 use crate::policy::write::write_guardrails::AdhocRiskDecision;
 
-use super::completion::{CompletionCandidate, CompletionState};
+use super::completion::{CompletionCandidate, CompletionKind, CompletionState};
+use super::edit_history::{SqlEditHistory, SqlEditKind, SqlEditSnapshot};
+use super::vim::SqlVimState;
 
 // Sized so that prompt + input + checkmark fits within the 80-col modal inner width (~62 cols).
 pub const HIGH_RISK_INPUT_VISIBLE_WIDTH: usize = 30;
@@ -17,16 +27,61 @@ pub const SQL_MODAL_HEIGHT_PERCENT: u16 = 60;
 pub const SQL_MODAL_CHROME_LINES: usize = 4;
 pub const SQL_MODAL_VISIBLE_ROWS_FALLBACK: usize = 8;
 
-pub fn sql_modal_visible_rows(terminal_height: u16) -> usize {
+pub fn sql_modal_visible_rows(terminal_height: u16, size: SqlModalSize) -> usize {
     if terminal_height == 0 {
         return SQL_MODAL_VISIBLE_ROWS_FALLBACK;
     }
 
-    (terminal_height as usize * SQL_MODAL_HEIGHT_PERCENT as usize / 100)
+    (terminal_height as usize * size.height_percent() as usize / 100)
         .saturating_sub(SQL_MODAL_CHROME_LINES)
         .max(1)
 }
 
+/// How much of the screen the SQL modal covers; `Ctrl+F` cycles it and the
+/// choice holds until the app exits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SqlModalSize {
+    /// Docked at the top without dimming, so the result pane stays readable.
+    Compact,
+    #[default]
+    Half,
+    Fullscreen,
+}
+
+impl SqlModalSize {
+    pub fn height_percent(self) -> u16 {
+        match self {
+            Self::Compact => 35,
+            Self::Half => SQL_MODAL_HEIGHT_PERCENT,
+            Self::Fullscreen => 100,
+        }
+    }
+
+    pub fn width_percent(self) -> u16 {
+        match self {
+            Self::Compact | Self::Half => 80,
+            Self::Fullscreen => 100,
+        }
+    }
+
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Self::Compact => Self::Half,
+            Self::Half => Self::Fullscreen,
+            Self::Fullscreen => Self::Compact,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Compact => "compact",
+            Self::Half => "half",
+            Self::Fullscreen => "fullscreen",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SqlModalTab {
     #[default]
@@ -92,7 +147,32 @@ pub struct SqlModalContext {
     failed_prefetch_tables: HashMap<String, FailedPrefetchEntry>,
     pub(crate) prefetch_started: bool,
     pub(crate) prefetch_run: AsyncRun,
+    /// Schemas queued so far when the run covers only some schemas; `None`
+    /// for a run over every table or an ER target list.
+    prefetch_schemas: Option<HashSet<String>>,
     active_tab: SqlModalTab,
+    size: SqlModalSize,
+    variables: BTreeMap<String, String>,
+    lock_diagnostics: AsyncRun,
+    lock_conflict: Option<LockConflictKind>,
+    lock_blockers: Vec<LockBlocker>,
+    token_cache: TokenCache,
+    /// Lint result for `token_cache`, relinted per edited statement.
+    lint_warnings: Vec<LintWarning>,
+    alias_rename: Option<PendingAliasRename>,
+    vim: SqlVimState,
+    edit_history: SqlEditHistory,
+    /// Template tab stops still ahead, next last, as char distances from the
+    /// buffer end so typing at the current stop does not shift them.
+    tab_stops: Vec<usize>,
+}
+
+/// An alias rename the user can accept with one key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasRenameOffer {
+    pub from: String,
+    pub to: String,
+    pub references: usize,
 }
 
 impl SqlModalContext {
@@ -110,10 +190,141 @@ impl SqlModalContext {
         &self.editor
     }
 
+    /// Brings the highlight tokens and lint warnings up to date with the
+    /// editor, re-lexing only the edited statement.
+    pub fn refresh_editor_tokens(&mut self) {
+        if let Some(relexed) = self.token_cache.update(self.editor.content()) {
+            relint_sql(&mut self.lint_warnings, self.token_cache.tokens(), &relexed);
+        }
+    }
+
+    /// `None` when the editor changed since the last refresh.
+    pub fn editor_tokens(&self) -> Option<&[Token]> {
+        (self.token_cache.text() == self.editor.content()).then(|| self.token_cache.tokens())
+    }
+
+    /// `None` when the editor changed since the last refresh.
+    pub fn editor_lint_warnings(&self) -> Option<&[LintWarning]> {
+        (self.token_cache.text() == self.editor.content()).then_some(self.lint_warnings.as_slice())
+    }
+
+    /// Table aliases in the buffer; taken before an edit for `track_alias_edit`.
+    pub fn editor_table_aliases(&mut self) -> Vec<Option<String>> {
+        self.refresh_editor_tokens();
+        table_aliases(self.token_cache.tokens())
+    }
+
+    pub fn track_alias_edit(&mut self, before: &[Option<String>]) {
+        let after = self.editor_table_aliases();
+        self.alias_rename = track_alias_edit(before, &after, self.alias_rename.take());
+    }
+
+    pub fn alias_rename_offer(&self) -> Option<AliasRenameOffer> {
+        let pending = self.alias_rename.as_ref()?;
+        let (to, references) = pending.targets(self.editor_tokens()?)?;
+        Some(AliasRenameOffer {
+            from: pending.from.clone(),
+            to: to.to_string(),
+            references: references.len(),
+        })
+    }
+
+    /// Rewrites the old alias qualifiers; returns how many were renamed.
+    pub fn apply_alias_rename(&mut self) -> usize {
+        self.refresh_editor_tokens();
+        let Some(pending) = self.alias_rename.take() else {
+            return 0;
+        };
+        let Some((to, references)) = pending.targets(self.token_cache.tokens()) else {
+            return 0;
+        };
+        for &(start, end) in references.iter().rev() {
+            self.editor.replace_range(start, end, to);
+        }
+        references.len()
+    }
+
+    pub fn restore_editor(&mut self, editor: MultiLineInputState) {
+        self.editor = editor;
+        self.alias_rename = None;
+        self.reset_completion();
+    }
+
     pub fn editor_mut_for_input(&mut self) -> &mut MultiLineInputState {
         &mut self.editor
     }
 
+    pub fn vim(&self) -> &SqlVimState {
+        &self.vim
+    }
+
+    pub fn vim_mut(&mut self) -> &mut SqlVimState {
+        &mut self.vim
+    }
+
+    /// Records the buffer into the undo history if the last action changed it.
+    pub fn sync_edit_history(&mut self, kind: SqlEditKind) {
+        self.edit_history.sync(&self.editor, kind);
+    }
+
+    /// Steps back one edit; `false` when there is nothing to undo.
+    pub fn undo_edit(&mut self) -> bool {
+        let Some(snapshot) = self.edit_history.undo() else {
+            return false;
+        };
+        self.restore_edit(snapshot);
+        true
+    }
+
+    /// Re-applies the last undone edit; `false` when there is nothing to redo.
+    pub fn redo_edit(&mut self) -> bool {
+        let Some(snapshot) = self.edit_history.redo() else {
+            return false;
+        };
+        self.restore_edit(snapshot);
+        true
+    }
+
+    fn restore_edit(&mut self, snapshot: SqlEditSnapshot) {
+        self.editor
+            .set_content_with_cursor(snapshot.content, snapshot.cursor);
+        self.vim.cancel();
+        self.alias_rename = None;
+        self.reset_completion();
+    }
+
+    /// Recases the keyword just before the cursor once a non-word char has been typed after it.
+    pub fn apply_keyword_case(&mut self, case: KeywordCase) {
+        let Some(boundary) = self.editor.cursor().checked_sub(1) else {
+            return;
+        };
+        let Some(range) = keyword_ending_at(self.editor.content(), boundary) else {
+            return;
+        };
+        let start = self.editor.char_to_byte_index(range.start);
+        let end = self.editor.char_to_byte_index(range.end);
+        let word = &self.editor.content()[start..end];
+        let recased = case.apply(word);
+        if recased != word {
+            self.editor.replace_range(range.start, range.end, &recased);
+        }
+    }
+
+    // ── Query variables ─────────────────────────────────────────────
+
+    pub fn variables(&self) -> &BTreeMap<String, String> {
+        &self.variables
+    }
+
+    pub fn set_variable(&mut self, name: String, value: String) {
+        self.variables.insert(name, value);
+    }
+
+    /// Editor content with `:name` references replaced by bound variables.
+    pub fn submitted_query(&self) -> String {
+        substitute_variables(self.editor.content().trim(), &self.variables)
+    }
+
     // ── Prefetch lifecycle ──────────────────────────────────────────
 
     pub fn reset_prefetch(&mut self) {
@@ -122,6 +333,7 @@ impl SqlModalContext {
         self.prefetching_tables.clear();
         self.failed_prefetch_tables.clear();
         self.prefetch_run.clear_active();
+        self.prefetch_schemas = None;
     }
 
     // Preserves `prefetching_tables` so in-flight requests drain naturally.
@@ -130,13 +342,34 @@ impl SqlModalContext {
         self.prefetch_started = true;
         self.prefetch_queue.clear();
         self.failed_prefetch_tables.clear();
+        self.prefetch_schemas = None;
         self.prefetch_run.begin()
     }
 
+    /// Starts a run that grows schema by schema through [`Self::add_prefetch_schema`].
+    #[must_use]
+    pub fn begin_schema_prefetch(&mut self) -> u64 {
+        let run_id = self.begin_prefetch();
+        self.prefetch_schemas = Some(HashSet::new());
+        run_id
+    }
+
+    pub fn is_schema_prefetch(&self) -> bool {
+        self.prefetch_schemas.is_some()
+    }
+
+    /// `false` when the schema is already covered or the run is not per-schema.
+    pub fn add_prefetch_schema(&mut self, schema: &str) -> bool {
+        self.prefetch_schemas
+            .as_mut()
+            .is_some_and(|schemas| schemas.insert(schema.to_string()))
+    }
+
     pub fn invalidate_prefetch(&mut self) {
         self.prefetch_started = false;
         self.prefetching_tables.clear();
         self.prefetch_run.clear_active();
+        self.prefetch_schemas = None;
     }
 
     pub fn is_prefetch_started(&self) -> bool {
@@ -217,6 +450,7 @@ impl SqlModalContext {
 
     pub fn begin_adhoc_running(&mut self) {
         self.status = SqlModalStatus::Running;
+        self.clear_lock_diagnostics();
         self.dismiss_completion();
     }
 
@@ -224,12 +458,60 @@ impl SqlModalContext {
         self.status = SqlModalStatus::Error;
         self.last_adhoc_error = Some(error);
         self.last_adhoc_success = None;
+        self.clear_lock_diagnostics();
     }
 
     pub fn finish_adhoc_success(&mut self, snapshot: AdhocSuccessSnapshot) {
         self.status = SqlModalStatus::Success;
         self.last_adhoc_success = Some(snapshot);
         self.last_adhoc_error = None;
+        self.clear_lock_diagnostics();
+    }
+
+    // ── Lock diagnostics ────────────────────────────────────────────
+
+    #[must_use]
+    pub fn begin_lock_diagnostics(&mut self, kind: LockConflictKind) -> u64 {
+        self.lock_conflict = Some(kind);
+        self.lock_blockers.clear();
+        self.lock_diagnostics.begin()
+    }
+
+    /// Returns `false` when a newer run or error has superseded `run_id`.
+    pub fn set_lock_blockers(&mut self, run_id: u64, blockers: Vec<LockBlocker>) -> bool {
+        if !self.lock_diagnostics.is_current(run_id) {
+            return false;
+        }
+        self.lock_diagnostics.clear_active();
+        self.lock_blockers = blockers;
+        true
+    }
+
+    pub fn lock_blockers(&self) -> &[LockBlocker] {
+        &self.lock_blockers
+    }
+
+    /// `Deadlock — blocked by pid 4123 · worker · …: UPDATE …` for the
+    /// longest-held blocker, with a count of any others.
+    pub fn lock_blocker_summary(&self) -> Option<String> {
+        let kind = self.lock_conflict?;
+        let (first, rest) = self.lock_blockers.split_first()?;
+        let more = if rest.is_empty() {
+            String::new()
+        } else {
+            format!(" (+{} more)", rest.len())
+        };
+        Some(format!(
+            "{} — blocked by {}{more}",
+            kind.label(),
+            first.summary()
+        ))
+    }
+
+    fn clear_lock_diagnostics(&mut self) {
+        self.lock_diagnostics.clear_active();
+        self.lock_conflict = None;
+        self.lock_blockers.clear();
     }
 
     pub fn begin_confirming_high(&mut self, decision: AdhocRiskDecision, target_name: String) {
@@ -294,6 +576,20 @@ impl SqlModalContext {
         self.active_tab = tab;
     }
 
+    pub fn size(&self) -> SqlModalSize {
+        self.size
+    }
+
+    pub fn cycle_size(&mut self) -> SqlModalSize {
+        self.size = self.size.next();
+        self.size
+    }
+
+    /// Editor rows visible at the current size.
+    pub fn visible_rows(&self, terminal_height: u16) -> usize {
+        sql_modal_visible_rows(terminal_height, self.size)
+    }
+
     pub fn open_sql_tab(&mut self) {
         self.status = SqlModalStatus::Normal;
         self.active_tab = SqlModalTab::Sql;
@@ -306,10 +602,13 @@ impl SqlModalContext {
 
     pub fn enter_editing(&mut self) {
         self.status = SqlModalStatus::Editing;
+        self.vim.cancel();
     }
 
     pub fn enter_normal(&mut self) {
         self.status = SqlModalStatus::Normal;
+        self.vim.cancel();
+        self.edit_history.break_group();
         self.dismiss_completion();
     }
 
@@ -321,6 +620,7 @@ impl SqlModalContext {
     pub fn load_query_for_editing(&mut self, query: String) {
         self.editor.set_content(query);
         self.status = SqlModalStatus::Editing;
+        self.vim.cancel();
         self.active_tab = SqlModalTab::Sql;
         self.reset_completion();
     }
@@ -337,6 +637,12 @@ impl SqlModalContext {
         self.completion_debounce = Some(debounce_until);
     }
 
+    /// Joins a pending refresh instead of postponing it, so a burst of
+    /// background cache updates costs one recompute per window.
+    pub fn coalesce_completion_refresh(&mut self, debounce_until: Instant) {
+        self.completion_debounce.get_or_insert(debounce_until);
+    }
+
     pub fn schedule_completion_after_dismiss(&mut self, debounce_until: Instant) {
         self.completion.visible = false;
         self.schedule_completion(debounce_until);
@@ -351,11 +657,13 @@ impl SqlModalContext {
         self.completion_debounce = None;
     }
 
+    /// Drops the popup along with any template tab stops left from it.
     pub fn reset_completion(&mut self) {
         self.completion.visible = false;
         self.completion.candidates.clear();
         self.completion.selected_index = 0;
         self.completion_debounce = None;
+        self.tab_stops.clear();
     }
 
     pub fn apply_completion_update(
@@ -371,6 +679,10 @@ impl SqlModalContext {
         self.completion.selected_index = 0;
     }
 
+    pub fn toggle_completion_preview(&mut self) {
+        self.completion.preview_visible = !self.completion.preview_visible;
+    }
+
     pub fn completion_next(&mut self) {
         if self.completion.candidates.is_empty() {
             return;
@@ -402,7 +714,10 @@ impl SqlModalContext {
         self.completion
             .candidates
             .get(self.completion.selected_index)
-            .map(|candidate| (self.completion.trigger_position, candidate.text.clone()))
+            .map(|candidate| {
+                let replacement = candidate.insert_text.as_ref().unwrap_or(&candidate.text);
+                (self.completion.trigger_position, replacement.clone())
+            })
     }
 
     pub fn accept_selected_completion(&mut self, visible_rows: usize) {
@@ -414,15 +729,55 @@ impl SqlModalContext {
             return;
         }
 
+        let is_template = self
+            .completion
+            .candidates
+            .get(self.completion.selected_index)
+            .is_some_and(|candidate| candidate.kind == CompletionKind::Template);
+        let (replacement, stops) = if is_template {
+            expand_tab_stops(&replacement)
+        } else {
+            (replacement, Vec::new())
+        };
+
         let start_byte = self.editor.char_to_byte_index(trigger_pos);
         let end_byte = self.editor.char_to_byte_index(self.editor.cursor());
         let mut content = self.editor.content().to_string();
         content.drain(start_byte..end_byte);
         content.insert_str(start_byte, &replacement);
-        let new_cursor = trigger_pos + replacement.chars().count();
+        let char_count = content.chars().count();
+        let new_cursor = trigger_pos
+            + stops
+                .first()
+                .copied()
+                .unwrap_or_else(|| replacement.chars().count());
         self.editor.set_content_with_cursor(content, new_cursor);
         self.editor.update_scroll(visible_rows);
         self.dismiss_completion();
+        self.tab_stops = stops
+            .iter()
+            .skip(1)
+            .rev()
+            .map(|stop| char_count - (trigger_pos + stop))
+            .collect();
+    }
+
+    /// Moves the cursor to the next template tab stop; `false` when none is
+    /// left or edits past it made it stale.
+    pub fn jump_to_next_tab_stop(&mut self) -> bool {
+        let Some(from_end) = self.tab_stops.pop() else {
+            return false;
+        };
+        match self.editor.char_count().checked_sub(from_end) {
+            Some(position) if position >= self.editor.cursor() => {
+                self.editor.set_cursor(position);
+                true
+            }
+            _ => {
+                self.tab_stops.clear();
+                false
+            }
+        }
     }
 
     pub fn confirming_high_input_mut(&mut self) -> Option<&mut TextInputState> {
@@ -461,6 +816,9 @@ mod tests {
             text: text.to_string(),
             kind: CompletionKind::Keyword,
             score: 1,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         }
     }
 
@@ -487,6 +845,9 @@ mod tests {
                 text: "test".to_string(),
                 kind: CompletionKind::Table,
                 score: 100,
+                detail: None,
+                insert_text: None,
+                preview: Vec::new(),
             });
 
             ctx.clear_content();
@@ -498,6 +859,48 @@ mod tests {
         }
     }
 
+    mod lock_diagnostics {
+        use super::*;
+
+        fn blocker(pid: i32) -> LockBlocker {
+            LockBlocker {
+                pid,
+                application_name: "worker".to_string(),
+                user: String::new(),
+                state: "idle in transaction".to_string(),
+                held_secs: Some(5),
+                query: "UPDATE accounts SET balance = 0".to_string(),
+            }
+        }
+
+        #[test]
+        fn summary_names_first_blocker_and_counts_the_rest() {
+            let mut ctx = SqlModalContext::default();
+            ctx.finish_adhoc_error("Deadlock detected".to_string());
+            let run_id = ctx.begin_lock_diagnostics(LockConflictKind::Deadlock);
+
+            assert!(ctx.set_lock_blockers(run_id, vec![blocker(7), blocker(8)]));
+
+            assert_eq!(
+                ctx.lock_blocker_summary().as_deref(),
+                Some(
+                    "Deadlock — blocked by pid 7 · worker · idle in transaction 5s: \
+                     UPDATE accounts SET balance = 0 (+1 more)"
+                )
+            );
+        }
+
+        #[test]
+        fn rerun_discards_pending_lookup() {
+            let mut ctx = SqlModalContext::default();
+            let run_id = ctx.begin_lock_diagnostics(LockConflictKind::LockTimeout);
+            ctx.begin_adhoc_running();
+
+            assert!(!ctx.set_lock_blockers(run_id, vec![blocker(7)]));
+            assert_eq!(ctx.lock_blocker_summary(), None);
+        }
+    }
+
     mod prefetch {
         use super::*;
 
@@ -659,6 +1062,8 @@ mod tests {
     }
 
     mod completion {
+        use std::time::Duration;
+
         use super::*;
 
         #[test]
@@ -673,6 +1078,17 @@ mod tests {
             assert_eq!(ctx.completion_debounce, Some(debounce_until));
         }
 
+        #[test]
+        fn coalesced_refresh_keeps_earlier_deadline() {
+            let mut ctx = SqlModalContext::default();
+            let first = Instant::now();
+
+            ctx.coalesce_completion_refresh(first);
+            ctx.coalesce_completion_refresh(first + Duration::from_millis(50));
+
+            assert_eq!(ctx.completion_debounce, Some(first));
+        }
+
         #[test]
         fn schedule_after_dismiss_hides_popup() {
             let mut ctx = SqlModalContext::default();
@@ -705,6 +1121,9 @@ mod tests {
                     text: "users".to_string(),
                     kind: CompletionKind::Table,
                     score: 1,
+                    detail: None,
+                    insert_text: None,
+                    preview: Vec::new(),
                 }],
                 7,
                 true,
@@ -715,6 +1134,57 @@ mod tests {
                 Some((7, "users".to_string()))
             );
         }
+
+        #[test]
+        fn accepted_template_walks_its_tab_stops() {
+            let mut ctx = SqlModalContext::default();
+            ctx.editor.set_content("-- q\nsel".to_string());
+            ctx.apply_completion_update(
+                &[CompletionCandidate {
+                    text: "selw".to_string(),
+                    kind: CompletionKind::Template,
+                    score: 1,
+                    detail: None,
+                    insert_text: Some("SELECT * FROM ${} WHERE ${};".to_string()),
+                    preview: Vec::new(),
+                }],
+                5,
+                true,
+            );
+
+            ctx.accept_selected_completion(10);
+            assert_eq!(ctx.editor.content(), "-- q\nSELECT * FROM  WHERE ;");
+            assert_eq!(ctx.editor.cursor(), 19);
+
+            ctx.editor.insert_str("users");
+            assert!(ctx.jump_to_next_tab_stop());
+            assert_eq!(ctx.editor.cursor(), 31);
+            assert!(!ctx.jump_to_next_tab_stop());
+        }
+
+        #[test]
+        fn tab_stop_behind_the_cursor_is_dropped() {
+            let mut ctx = SqlModalContext::default();
+            ctx.apply_completion_update(
+                &[CompletionCandidate {
+                    text: "upd".to_string(),
+                    kind: CompletionKind::Template,
+                    score: 1,
+                    detail: None,
+                    insert_text: Some("UPDATE ${} SET ${} WHERE ${}".to_string()),
+                    preview: Vec::new(),
+                }],
+                0,
+                true,
+            );
+            ctx.accept_selected_completion(10);
+
+            ctx.editor.set_cursor(ctx.editor.char_count());
+            ctx.editor.insert_str("id = 1");
+
+            assert!(!ctx.jump_to_next_tab_stop());
+            assert!(!ctx.jump_to_next_tab_stop());
+        }
     }
 
     mod adhoc_status {
@@ -741,16 +1211,95 @@ mod tests {
 
     mod visible_rows {
         use super::*;
+        use rstest::rstest;
 
         #[test]
         fn uses_fallback_when_terminal_height_is_zero() {
-            assert_eq!(sql_modal_visible_rows(0), SQL_MODAL_VISIBLE_ROWS_FALLBACK);
+            assert_eq!(
+                sql_modal_visible_rows(0, SqlModalSize::Half),
+                SQL_MODAL_VISIBLE_ROWS_FALLBACK
+            );
         }
 
         #[test]
         fn clamps_to_one_for_small_terminal() {
-            assert_eq!(sql_modal_visible_rows(1), 1);
-            assert_eq!(sql_modal_visible_rows(8), 1);
+            assert_eq!(sql_modal_visible_rows(1, SqlModalSize::Half), 1);
+            assert_eq!(sql_modal_visible_rows(8, SqlModalSize::Half), 1);
+        }
+
+        #[rstest]
+        #[case(SqlModalSize::Compact, 10)]
+        #[case(SqlModalSize::Half, 20)]
+        #[case(SqlModalSize::Fullscreen, 36)]
+        fn grow_with_the_modal_size(#[case] size: SqlModalSize, #[case] expected: usize) {
+            assert_eq!(sql_modal_visible_rows(40, size), expected);
+        }
+
+        #[test]
+        fn size_cycles_through_every_mode_and_survives_reopening() {
+            let mut ctx = SqlModalContext::default();
+
+            assert_eq!(ctx.cycle_size(), SqlModalSize::Fullscreen);
+            assert_eq!(ctx.cycle_size(), SqlModalSize::Compact);
+            ctx.cleanup_on_close();
+            ctx.open_sql_tab();
+
+            assert_eq!(ctx.size(), SqlModalSize::Compact);
+            assert_eq!(ctx.cycle_size(), SqlModalSize::Half);
+        }
+    }
+
+    mod editor_tokens {
+        use super::*;
+        use crate::policy::sql::lint::LintRule;
+
+        #[test]
+        fn refresh_lints_the_new_tokens() {
+            let mut ctx = SqlModalContext::default();
+            ctx.editor_mut_for_input()
+                .set_content("SELECT id FROM users".to_string());
+            ctx.refresh_editor_tokens();
+            assert_eq!(ctx.editor_lint_warnings(), Some(&[][..]));
+
+            ctx.editor_mut_for_input()
+                .set_content("DELETE FROM users".to_string());
+            assert!(ctx.editor_lint_warnings().is_none());
+            ctx.refresh_editor_tokens();
+
+            let rules: Vec<_> = ctx
+                .editor_lint_warnings()
+                .unwrap()
+                .iter()
+                .map(|w| w.rule)
+                .collect();
+            assert_eq!(rules, vec![LintRule::UnfilteredWrite]);
+        }
+
+        #[test]
+        #[ignore = "local-only dev benchmark, not tied to a CI issue"]
+        #[allow(clippy::print_stderr, reason = "benchmark result output")]
+        fn bench_keystroke_refresh_and_lint_in_large_script() {
+            let text = (0..3_000)
+                .map(|i| format!("SELECT * FROM users WHERE id = {i} AND note <> 'x';"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let middle = text.chars().count() / 2;
+            let mut ctx = SqlModalContext::default();
+            ctx.editor_mut_for_input()
+                .set_content_with_cursor(text, middle);
+            ctx.refresh_editor_tokens();
+            let iterations: u32 = 200;
+
+            let start = Instant::now();
+            for _ in 0..iterations {
+                ctx.editor_mut_for_input().insert_char('x');
+                ctx.refresh_editor_tokens();
+                std::hint::black_box(ctx.editor_lint_warnings());
+            }
+            let per_keystroke = start.elapsed() / iterations;
+
+            eprintln!("Refresh + lint: {per_keystroke:?}/keystroke");
+            assert!(per_keystroke.as_micros() < 1_000);
         }
     }
 }
@@ -0,0 +1,174 @@
+use nucleo_matcher::pattern::{CaseMatching, Normalization, Pattern};
+use nucleo_matcher::{Config, Matcher};
+
+use crate::model::shared::picker::PickerState;
+use crate::ports::outbound::Snippet;
+
+pub struct FilteredSnippet<'a> {
+    pub snippet: &'a Snippet,
+    /// Match positions within `snippet.name`.
+    pub match_indices: Vec<u32>,
+}
+
+/// Saved snippets stay loaded after the picker closes: `:save` replaces the
+/// list and completion mirrors it.
+#[derive(Debug, Clone, Default)]
+pub struct SnippetPickerState {
+    loading: bool,
+    snippets: Vec<Snippet>,
+    picker: PickerState,
+}
+
+impl SnippetPickerState {
+    pub fn begin_load(&mut self) {
+        self.loading = true;
+        self.picker.clear_filter_and_reset();
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    pub fn set_loaded(&mut self, snippets: Vec<Snippet>) {
+        self.loading = false;
+        self.snippets = snippets;
+        self.picker.reset();
+    }
+
+    pub fn set_failed(&mut self) {
+        self.loading = false;
+    }
+
+    pub fn close(&mut self) {
+        self.loading = false;
+        self.picker.clear_filter_and_reset();
+    }
+
+    pub fn snippets(&self) -> &[Snippet] {
+        &self.snippets
+    }
+
+    pub fn picker(&self) -> &PickerState {
+        &self.picker
+    }
+
+    pub fn picker_mut(&mut self) -> &mut PickerState {
+        &mut self.picker
+    }
+
+    /// Fuzzy-matches the filter against the snippet name.
+    pub fn filtered(&self) -> Vec<FilteredSnippet<'_>> {
+        let filter = self.picker.filter_input().content();
+        if filter.is_empty() {
+            return self
+                .snippets
+                .iter()
+                .map(|snippet| FilteredSnippet {
+                    snippet,
+                    match_indices: Vec::new(),
+                })
+                .collect();
+        }
+
+        let mut matcher = Matcher::new(Config::DEFAULT);
+        let pattern = Pattern::parse(filter, CaseMatching::Ignore, Normalization::Smart);
+        let mut buf = Vec::new();
+
+        self.snippets
+            .iter()
+            .filter_map(|snippet| {
+                let mut indices = Vec::new();
+                let haystack = nucleo_matcher::Utf32Str::new(&snippet.name, &mut buf);
+                pattern
+                    .indices(haystack, &mut matcher, &mut indices)
+                    .map(|_| FilteredSnippet {
+                        snippet,
+                        match_indices: indices,
+                    })
+            })
+            .collect()
+    }
+
+    pub fn clamped_selected(&self) -> usize {
+        self.picker
+            .selected()
+            .min(self.filtered().len().saturating_sub(1))
+    }
+
+    pub fn selected_snippet(&self) -> Option<&Snippet> {
+        self.filtered()
+            .get(self.clamped_selected())
+            .map(|filtered| filtered.snippet)
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.filtered().len();
+        if count > 0 {
+            let next = (self.clamped_selected() + 1).min(count - 1);
+            self.picker.set_selection(next);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        let previous = self.clamped_selected().saturating_sub(1);
+        self.picker.set_selection(previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded_state() -> SnippetPickerState {
+        let mut state = SnippetPickerState::default();
+        state.begin_load();
+        state.set_loaded(
+            ["active users", "stuck jobs", "table sizes"]
+                .iter()
+                .map(|name| Snippet {
+                    name: (*name).to_string(),
+                    sql: format!("-- {name}"),
+                })
+                .collect(),
+        );
+        state
+    }
+
+    #[test]
+    fn filter_matches_name_with_highlight_indices() {
+        let mut state = loaded_state();
+        state.picker_mut().insert_filter_str("stuck");
+
+        let filtered = state.filtered();
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].snippet.name, "stuck jobs");
+        assert!(!filtered[0].match_indices.is_empty());
+    }
+
+    #[test]
+    fn selection_follows_filtered_rows_and_stops_at_last() {
+        let mut state = loaded_state();
+        state.picker_mut().set_pane_height(10);
+
+        for _ in 0..5 {
+            state.select_next();
+        }
+
+        assert_eq!(
+            state.selected_snippet().map(|s| s.name.as_str()),
+            Some("table sizes")
+        );
+    }
+
+    #[test]
+    fn close_keeps_snippets_but_drops_filter() {
+        let mut state = loaded_state();
+        state.picker_mut().insert_filter_str("zzz");
+
+        state.close();
+
+        assert_eq!(state.snippets().len(), 3);
+        assert_eq!(state.filtered().len(), 3);
+    }
+}
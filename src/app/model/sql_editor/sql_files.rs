@@ -0,0 +1,192 @@
+use nucleo_matcher::pattern::{CaseMatching, Normalization, Pattern};
+use nucleo_matcher::{Config, Matcher};
+
+use crate::model::shared::picker::PickerState;
+use crate::ports::outbound::SqlFile;
+
+pub struct FilteredSqlFile<'a> {
+    pub file: &'a SqlFile,
+    /// Match positions within `file.path`.
+    pub match_indices: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SqlFileBrowserState {
+    loading: bool,
+    files: Vec<SqlFile>,
+    picker: PickerState,
+}
+
+impl SqlFileBrowserState {
+    pub fn begin_scan(&mut self) {
+        self.loading = true;
+        self.files.clear();
+        self.picker.clear_filter_and_reset();
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    pub fn set_loaded(&mut self, files: Vec<SqlFile>) {
+        self.loading = false;
+        self.files = files;
+        self.picker.reset();
+    }
+
+    pub fn set_failed(&mut self) {
+        self.loading = false;
+    }
+
+    pub fn clear(&mut self) {
+        self.loading = false;
+        self.files.clear();
+        self.picker.clear_filter_and_reset();
+    }
+
+    pub fn files(&self) -> &[SqlFile] {
+        &self.files
+    }
+
+    pub fn picker(&self) -> &PickerState {
+        &self.picker
+    }
+
+    pub fn picker_mut(&mut self) -> &mut PickerState {
+        &mut self.picker
+    }
+
+    /// Fuzzy-matches the filter against the relative path.
+    pub fn filtered(&self) -> Vec<FilteredSqlFile<'_>> {
+        let filter = self.picker.filter_input().content();
+        if filter.is_empty() {
+            return self
+                .files
+                .iter()
+                .map(|file| FilteredSqlFile {
+                    file,
+                    match_indices: Vec::new(),
+                })
+                .collect();
+        }
+
+        let mut matcher = Matcher::new(Config::DEFAULT);
+        let pattern = Pattern::parse(filter, CaseMatching::Ignore, Normalization::Smart);
+        let mut buf = Vec::new();
+
+        self.files
+            .iter()
+            .filter_map(|file| {
+                let mut indices = Vec::new();
+                let haystack = nucleo_matcher::Utf32Str::new(&file.path, &mut buf);
+                pattern
+                    .indices(haystack, &mut matcher, &mut indices)
+                    .map(|_| FilteredSqlFile {
+                        file,
+                        match_indices: indices,
+                    })
+            })
+            .collect()
+    }
+
+    pub fn clamped_selected(&self) -> usize {
+        self.picker
+            .selected()
+            .min(self.filtered().len().saturating_sub(1))
+    }
+
+    pub fn selected_file(&self) -> Option<&SqlFile> {
+        self.filtered()
+            .get(self.clamped_selected())
+            .map(|filtered| filtered.file)
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.filtered().len();
+        if count > 0 {
+            let next = (self.clamped_selected() + 1).min(count - 1);
+            self.picker.set_selection(next);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        let previous = self.clamped_selected().saturating_sub(1);
+        self.picker.set_selection(previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str) -> SqlFile {
+        SqlFile {
+            path: path.to_string(),
+            content: format!("-- {path}"),
+        }
+    }
+
+    fn loaded_state() -> SqlFileBrowserState {
+        let mut state = SqlFileBrowserState::default();
+        state.begin_scan();
+        state.set_loaded(vec![
+            file("db/migrations/001_users.sql"),
+            file("db/migrations/002_orders.sql"),
+            file("reports/monthly_revenue.sql"),
+        ]);
+        state
+    }
+
+    #[test]
+    fn filter_matches_path_with_highlight_indices() {
+        let mut state = loaded_state();
+        state.picker_mut().insert_filter_str("revenue");
+
+        let filtered = state.filtered();
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].file.path, "reports/monthly_revenue.sql");
+        assert!(!filtered[0].match_indices.is_empty());
+    }
+
+    #[test]
+    fn selected_file_follows_filtered_rows() {
+        let mut state = loaded_state();
+        state.picker_mut().set_pane_height(10);
+
+        state.select_next();
+        assert_eq!(
+            state.selected_file().map(|f| f.path.as_str()),
+            Some("db/migrations/002_orders.sql")
+        );
+
+        state.picker_mut().insert_filter_str("monthly");
+        assert_eq!(
+            state.selected_file().map(|f| f.path.as_str()),
+            Some("reports/monthly_revenue.sql")
+        );
+    }
+
+    #[test]
+    fn selection_stops_at_last_row() {
+        let mut state = loaded_state();
+        state.picker_mut().set_pane_height(10);
+
+        for _ in 0..5 {
+            state.select_next();
+        }
+
+        assert_eq!(state.clamped_selected(), 2);
+    }
+
+    #[test]
+    fn begin_scan_drops_previous_files() {
+        let mut state = loaded_state();
+
+        state.begin_scan();
+
+        assert!(state.is_loading());
+        assert!(state.files().is_empty());
+        assert!(state.selected_file().is_none());
+    }
+}
@@ -0,0 +1,103 @@
+/// An operator waiting for its motion, as in `d{motion}` or `y{motion}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlVimOperator {
+    Delete,
+    Yank,
+}
+
+/// Text captured by the last delete or yank in the SQL modal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlVimRegister {
+    pub text: String,
+    /// Whole lines paste above/below the cursor line instead of at the cursor.
+    pub linewise: bool,
+}
+
+/// Vim editing state for the SQL modal's normal mode; insert mode lives in
+/// `SqlModalStatus::Editing`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SqlVimState {
+    pending: Option<SqlVimOperator>,
+    visual_anchor_row: Option<usize>,
+    register: Option<SqlVimRegister>,
+}
+
+impl SqlVimState {
+    pub fn pending(&self) -> Option<SqlVimOperator> {
+        self.pending
+    }
+
+    pub fn set_pending(&mut self, operator: SqlVimOperator) {
+        self.pending = Some(operator);
+    }
+
+    pub fn is_visual_line(&self) -> bool {
+        self.visual_anchor_row.is_some()
+    }
+
+    pub fn start_visual_line(&mut self, row: usize) {
+        self.pending = None;
+        self.visual_anchor_row = Some(row);
+    }
+
+    /// First and last selected rows, clamped to the buffer's `line_count`.
+    pub fn visual_rows(&self, cursor_row: usize, line_count: usize) -> Option<(usize, usize)> {
+        let last_row = line_count.saturating_sub(1);
+        let anchor = self.visual_anchor_row?.min(last_row);
+        let cursor_row = cursor_row.min(last_row);
+        Some((anchor.min(cursor_row), anchor.max(cursor_row)))
+    }
+
+    pub fn register(&self) -> Option<&SqlVimRegister> {
+        self.register.as_ref()
+    }
+
+    pub fn set_register(&mut self, text: String, linewise: bool) {
+        self.register = Some(SqlVimRegister { text, linewise });
+    }
+
+    /// A pending operator or visual selection is waiting on the next key.
+    pub fn is_busy(&self) -> bool {
+        self.pending.is_some() || self.visual_anchor_row.is_some()
+    }
+
+    /// Drops the pending operator and visual selection; the register is kept.
+    pub fn cancel(&mut self) {
+        self.pending = None;
+        self.visual_anchor_row = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 3, 5, (1, 3))]
+    #[case(3, 1, 5, (1, 3))]
+    #[case(7, 0, 3, (0, 2))]
+    fn visual_rows_are_ordered_and_clamped(
+        #[case] anchor: usize,
+        #[case] cursor_row: usize,
+        #[case] line_count: usize,
+        #[case] expected: (usize, usize),
+    ) {
+        let mut vim = SqlVimState::default();
+        vim.start_visual_line(anchor);
+
+        assert_eq!(vim.visual_rows(cursor_row, line_count), Some(expected));
+    }
+
+    #[test]
+    fn cancel_keeps_the_register() {
+        let mut vim = SqlVimState::default();
+        vim.set_register("SELECT 1".to_string(), true);
+        vim.set_pending(SqlVimOperator::Delete);
+
+        vim.cancel();
+
+        assert!(!vim.is_busy());
+        assert_eq!(vim.register().map(|r| r.text.as_str()), Some("SELECT 1"));
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::sync::{Arc, Weak};
+
+use crate::domain::QueryResult;
+use crate::model::browse::cell_edit::CellEditState;
+use crate::model::shared::multi_line_input::MultiLineInputState;
+
+const MAX_ENTRIES: usize = 20;
+
+/// Sub-state captured right before a destructive UI transition.
+/// Result-bound snapshots only apply while the same result is on screen,
+/// since staged rows and cell coordinates index into it.
+#[derive(Debug, Clone)]
+pub enum UndoSnapshot {
+    SqlBuffer(MultiLineInputState),
+    StagedDeletes {
+        result: Weak<QueryResult>,
+        rows: BTreeSet<usize>,
+    },
+    CellEdit {
+        result: Weak<QueryResult>,
+        edit: CellEditState,
+    },
+}
+
+impl UndoSnapshot {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::SqlBuffer(_) => "SQL buffer",
+            Self::StagedDeletes { .. } => "staged deletes",
+            Self::CellEdit { .. } => "cell edit",
+        }
+    }
+
+    /// Whether a result-bound snapshot still refers to `current`.
+    pub fn matches_result(&self, current: Option<&Arc<QueryResult>>) -> bool {
+        match self {
+            Self::SqlBuffer(_) => true,
+            Self::StagedDeletes { result, .. } | Self::CellEdit { result, .. } => result
+                .upgrade()
+                .zip(current)
+                .is_some_and(|(captured, current)| Arc::ptr_eq(&captured, current)),
+        }
+    }
+}
+
+/// Most-recent-first stack of undoable UI mutations, capped so long sessions
+/// do not keep every cleared buffer alive.
+#[derive(Debug, Clone, Default)]
+pub struct UndoHistory {
+    entries: VecDeque<UndoSnapshot>,
+}
+
+impl UndoHistory {
+    pub fn push(&mut self, snapshot: UndoSnapshot) {
+        if self.entries.len() == MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(snapshot);
+    }
+
+    pub fn pop(&mut self) -> Option<UndoSnapshot> {
+        self.entries.pop_back()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::QuerySource;
+
+    fn result() -> Arc<QueryResult> {
+        Arc::new(QueryResult::success(
+            "SELECT 1".to_string(),
+            vec!["id".to_string()],
+            vec![vec!["1".to_string()]],
+            1,
+            QuerySource::Preview,
+        ))
+    }
+
+    #[test]
+    fn drops_oldest_entry_past_capacity() {
+        let mut history = UndoHistory::default();
+        for row in 0..=MAX_ENTRIES {
+            history.push(UndoSnapshot::StagedDeletes {
+                result: Weak::new(),
+                rows: BTreeSet::from([row]),
+            });
+        }
+
+        assert_eq!(history.len(), MAX_ENTRIES);
+        assert!(matches!(
+            history.pop(),
+            Some(UndoSnapshot::StagedDeletes { rows, .. }) if rows.contains(&MAX_ENTRIES)
+        ));
+    }
+
+    #[test]
+    fn result_bound_snapshot_requires_same_result() {
+        let shown = result();
+        let snapshot = UndoSnapshot::StagedDeletes {
+            result: Arc::downgrade(&shown),
+            rows: BTreeSet::from([0]),
+        };
+
+        assert!(snapshot.matches_result(Some(&shown)));
+        assert!(!snapshot.matches_result(Some(&result())));
+        assert!(!snapshot.matches_result(None));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::domain::{QueryResult, QuerySource};
+use crate::policy::result_copy::format_byte_size;
+use crate::ports::outbound::CacheCategoryUsage;
+
+/// Builds the `:cache` table: one row per category plus a total, so empty
+/// categories still show what `:cache clear <category>` accepts.
+pub fn cache_report(usage: &[CacheCategoryUsage]) -> QueryResult {
+    let columns = ["Category", "Files", "Size"].map(str::to_string).to_vec();
+    let row = |name: &str, files: usize, bytes: u64| {
+        vec![
+            name.to_string(),
+            files.to_string(),
+            format_byte_size(bytes as usize),
+        ]
+    };
+    let mut rows: Vec<Vec<String>> = usage
+        .iter()
+        .map(|entry| row(entry.category.name(), entry.files, entry.bytes))
+        .collect();
+    rows.push(row(
+        "total",
+        usage.iter().map(|entry| entry.files).sum(),
+        usage.iter().map(|entry| entry.bytes).sum(),
+    ));
+    QueryResult::success(":cache".to_string(), columns, rows, 0, QuerySource::Adhoc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::outbound::CacheCategory;
+
+    #[test]
+    fn report_lists_each_category_and_a_total() {
+        let usage = [
+            CacheCategoryUsage {
+                category: CacheCategory::ErDiagrams,
+                files: 2,
+                bytes: 2048,
+            },
+            CacheCategoryUsage {
+                category: CacheCategory::History,
+                files: 0,
+                bytes: 0,
+            },
+        ];
+
+        let report = cache_report(&usage);
+
+        assert_eq!(report.columns, vec!["Category", "Files", "Size"]);
+        assert_eq!(report.display_row_at(0).unwrap(), vec!["er", "2", "2.0 KB"]);
+        assert_eq!(
+            report.display_row_at(1).unwrap(),
+            vec!["history", "0", "0 B"]
+        );
+        assert_eq!(
+            report.display_row_at(2).unwrap(),
+            vec!["total", "2", "2.0 KB"]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::ports::outbound::MaskingRules;
+
+pub const MASKED_VALUE: &str = "••••••";
+
+/// Indices of `columns` the rules mask. `table` is the previewed
+/// `schema.table`; ad-hoc results only see the name patterns.
+pub fn masked_column_indices(
+    rules: &MaskingRules,
+    table: Option<&str>,
+    columns: &[String],
+) -> Vec<usize> {
+    let listed = table
+        .and_then(|table| rules.table_columns.get(table))
+        .map_or(&[][..], Vec::as_slice);
+    columns
+        .iter()
+        .enumerate()
+        .filter(|(_, column)| {
+            listed.iter().any(|name| name.eq_ignore_ascii_case(column))
+                || rules
+                    .column_patterns
+                    .iter()
+                    .any(|pattern| glob_matches(pattern, column))
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Case-insensitive match where `*` stands for any run of characters.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let name = name.to_lowercase();
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("*password*", "password", true)]
+    #[case("*password*", "User_Password_Hash", true)]
+    #[case("*ssn", "customer_ssn", true)]
+    #[case("*ssn", "ssn_verified", false)]
+    #[case("api_*_key", "api_live_key", true)]
+    #[case("api_*_key", "api_key", false)]
+    #[case("token", "TOKEN", true)]
+    #[case("token", "tokens", false)]
+    fn glob_matches_case_insensitively(
+        #[case] pattern: &str,
+        #[case] name: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(glob_matches(pattern, name), expected);
+    }
+
+    #[test]
+    fn table_lists_apply_only_to_their_table() {
+        let rules = MaskingRules {
+            column_patterns: vec!["*password*".to_string()],
+            table_columns: HashMap::from([("public.users".to_string(), vec!["email".to_string()])]),
+        };
+        let columns: Vec<String> = ["id", "email", "password_hash"].map(String::from).to_vec();
+
+        assert_eq!(
+            masked_column_indices(&rules, Some("public.users"), &columns),
+            vec![1, 2]
+        );
+        assert_eq!(
+            masked_column_indices(&rules, Some("public.orders"), &columns),
+            vec![2]
+        );
+        assert_eq!(masked_column_indices(&rules, None, &columns), vec![2]);
+    }
+}
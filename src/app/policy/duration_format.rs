@@ -0,0 +1,213 @@
+const SECS_PER_MINUTE: f64 = 60.0;
+const SECS_PER_HOUR: f64 = 60.0 * SECS_PER_MINUTE;
+const SECS_PER_DAY: f64 = 24.0 * SECS_PER_HOUR;
+// Calendar units are approximated; the raw value stays available in the row inspector.
+const DAYS_PER_MONTH: f64 = 30.0;
+const DAYS_PER_YEAR: f64 = 365.0;
+const MAX_PARTS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationSource {
+    /// PostgreSQL interval text such as `1 day 02:03:04`.
+    Interval,
+    Seconds,
+    Millis,
+    Micros,
+}
+
+impl DurationSource {
+    /// Interval types are detected by type; integer columns are treated as
+    /// elapsed time, with the unit guessed from the column name suffix.
+    pub fn detect(column: &str, data_type: Option<&str>, sample: Option<&str>) -> Option<Self> {
+        let data_type = data_type.map(|t| t.trim().to_ascii_lowercase());
+        if data_type
+            .as_deref()
+            .is_some_and(|t| t.starts_with("interval"))
+            || (data_type.is_none() && sample.is_some_and(|s| parse_interval_secs(s).is_some()))
+        {
+            return Some(Self::Interval);
+        }
+
+        let is_integer = match data_type.as_deref() {
+            Some(t) => matches!(
+                t,
+                "bigint" | "int8" | "integer" | "int" | "int4" | "smallint" | "int2"
+            ),
+            None => sample.is_some_and(|s| s.trim().parse::<i64>().is_ok()),
+        };
+        if !is_integer {
+            return None;
+        }
+
+        let column = column.to_ascii_lowercase();
+        let unit = if ["_ms", "_millis", "_msec", "_milliseconds"]
+            .iter()
+            .any(|suffix| column.ends_with(suffix))
+        {
+            Self::Millis
+        } else if ["_us", "_micros", "_usec", "_microseconds"]
+            .iter()
+            .any(|suffix| column.ends_with(suffix))
+        {
+            Self::Micros
+        } else {
+            Self::Seconds
+        };
+        Some(unit)
+    }
+}
+
+/// Returns `None` when `raw` cannot be read as a duration, so callers fall back to the raw text.
+pub fn format_duration(raw: &str, source: DurationSource) -> Option<String> {
+    let secs = match source {
+        DurationSource::Interval => parse_interval_secs(raw)?,
+        DurationSource::Seconds => raw.trim().parse::<i64>().ok()? as f64,
+        DurationSource::Millis => raw.trim().parse::<i64>().ok()? as f64 / 1_000.0,
+        DurationSource::Micros => raw.trim().parse::<i64>().ok()? as f64 / 1_000_000.0,
+    };
+    Some(humanize_secs(secs))
+}
+
+pub fn humanize_secs(secs: f64) -> String {
+    let sign = if secs < 0.0 { "-" } else { "" };
+    let magnitude = secs.abs();
+    if magnitude < 1.0 {
+        let millis = (magnitude * 1_000.0).round() as u64;
+        return if millis == 0 {
+            "0s".to_string()
+        } else {
+            format!("{sign}{millis}ms")
+        };
+    }
+
+    let total = magnitude.round() as u64;
+    let parts = [
+        (total / 86_400, "d"),
+        (total % 86_400 / 3_600, "h"),
+        (total % 3_600 / 60, "m"),
+        (total % 60, "s"),
+    ];
+    let humanized = parts
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .take(MAX_PARTS)
+        .map(|(n, unit)| format!("{n}{unit}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("{sign}{humanized}")
+}
+
+/// Parses PostgreSQL's default interval output, e.g. `1 year 2 mons 3 days -04:05:06.5`.
+fn parse_interval_secs(raw: &str) -> Option<f64> {
+    let mut tokens = raw.split_whitespace();
+    let mut secs = 0.0;
+    let mut matched = false;
+
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            secs += parse_clock_secs(token)?;
+        } else {
+            let amount = token.parse::<f64>().ok()?;
+            let unit = tokens.next()?.to_ascii_lowercase();
+            let unit_secs = match unit.trim_end_matches('s') {
+                "year" => DAYS_PER_YEAR * SECS_PER_DAY,
+                "mon" | "month" => DAYS_PER_MONTH * SECS_PER_DAY,
+                "day" => SECS_PER_DAY,
+                "hour" => SECS_PER_HOUR,
+                "min" | "minute" => SECS_PER_MINUTE,
+                "sec" | "second" => 1.0,
+                _ => return None,
+            };
+            secs = amount.mul_add(unit_secs, secs);
+        }
+        matched = true;
+    }
+
+    matched.then_some(secs)
+}
+
+fn parse_clock_secs(token: &str) -> Option<f64> {
+    let (negative, clock) = token
+        .strip_prefix('-')
+        .map_or((false, token), |rest| (true, rest));
+    let mut fields = clock.split(':');
+    let hours = fields.next()?.parse::<f64>().ok()?;
+    let minutes = fields.next()?.parse::<f64>().ok()?;
+    let seconds = fields.next().map_or(Some(0.0), |s| s.parse::<f64>().ok())?;
+    if fields.next().is_some() {
+        return None;
+    }
+    let secs = hours.mul_add(SECS_PER_HOUR, minutes.mul_add(SECS_PER_MINUTE, seconds));
+    Some(if negative { -secs } else { secs })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0.0, "0s")]
+    #[case(0.25, "250ms")]
+    #[case(45.0, "45s")]
+    #[case(3_600.0, "1h")]
+    #[case(187_980.0, "2d 4h 13m")]
+    #[case(187_985.0, "2d 4h 13m")]
+    #[case(86_405.0, "1d 5s")]
+    #[case(-90.0, "-1m 30s")]
+    fn humanize_keeps_three_largest_units(#[case] secs: f64, #[case] expected: &str) {
+        assert_eq!(humanize_secs(secs), expected);
+    }
+
+    #[rstest]
+    #[case("2 days 04:13:00", "2d 4h 13m")]
+    #[case("00:00:01.5", "2s")]
+    #[case("-00:01:30", "-1m 30s")]
+    #[case("1 mon", "30d")]
+    #[case("1 year 1 day", "366d")]
+    #[case("3 days", "3d")]
+    fn interval_text_is_humanized(#[case] raw: &str, #[case] expected: &str) {
+        assert_eq!(
+            format_duration(raw, DurationSource::Interval).as_deref(),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case("187980", DurationSource::Seconds, "2d 4h 13m")]
+    #[case("90000", DurationSource::Millis, "1m 30s")]
+    #[case("1500000", DurationSource::Micros, "2s")]
+    fn epoch_like_integers_are_humanized(
+        #[case] raw: &str,
+        #[case] source: DurationSource,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(format_duration(raw, source).as_deref(), Some(expected));
+    }
+
+    #[rstest]
+    #[case("NULL", DurationSource::Interval)]
+    #[case("abc", DurationSource::Seconds)]
+    #[case("1.5", DurationSource::Millis)]
+    #[case("2 fortnights", DurationSource::Interval)]
+    fn unreadable_values_return_none(#[case] raw: &str, #[case] source: DurationSource) {
+        assert_eq!(format_duration(raw, source), None);
+    }
+
+    #[rstest]
+    #[case("elapsed", Some("interval"), None, Some(DurationSource::Interval))]
+    #[case("elapsed", None, Some("01:02:03"), Some(DurationSource::Interval))]
+    #[case("duration_ms", Some("bigint"), None, Some(DurationSource::Millis))]
+    #[case("latency_us", None, Some("1200"), Some(DurationSource::Micros))]
+    #[case("ttl", Some("integer"), None, Some(DurationSource::Seconds))]
+    #[case("name", Some("text"), Some("10"), None)]
+    #[case("name", None, Some("alice"), None)]
+    fn detect_prefers_type_then_sample(
+        #[case] column: &str,
+        #[case] data_type: Option<&str>,
+        #[case] sample: Option<&str>,
+        #[case] expected: Option<DurationSource>,
+    ) {
+        assert_eq!(DurationSource::detect(column, data_type, sample), expected);
+    }
+}
@@ -0,0 +1,323 @@
+use std::fmt;
+
+use crate::domain::DatabaseType;
+use crate::policy::sql::statement_classifier::{
+    StatementKind, classify, collect_top_level_tokens, first_keyword, statement_after_leading_ctes,
+};
+use crate::policy::write::sql_risk::{
+    adhoc_label_for_statement, evaluate_sql_risk_for_database, statement_slices_for_database,
+};
+use crate::ports::outbound::ExecutionPolicy;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyViolation {
+    StatementNotAllowed(&'static str),
+    MissingWhere(&'static str),
+    RowLimitExceeded {
+        requested: Option<usize>,
+        max_rows: usize,
+    },
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StatementNotAllowed(label) => write!(
+                f,
+                "Profile policy allows SELECT only: {label} is not permitted"
+            ),
+            Self::MissingWhere(label) => {
+                write!(f, "Profile policy requires a WHERE clause on {label}")
+            }
+            Self::RowLimitExceeded {
+                requested: Some(requested),
+                max_rows,
+            } => write!(
+                f,
+                "Profile policy caps results at {max_rows} rows (LIMIT {requested} requested)"
+            ),
+            Self::RowLimitExceeded {
+                requested: None,
+                max_rows,
+            } => write!(
+                f,
+                "Profile policy caps results at {max_rows} rows; use a numeric LIMIT"
+            ),
+        }
+    }
+}
+
+/// Checks `sql` against the profile's policy and returns the query to run.
+///
+/// SELECTs without a LIMIT get `auto_limit`, or `max_rows` when only the cap
+/// is set. It is spliced into the original text at the end of the statement,
+/// on a new line so a trailing comment cannot swallow it, or just before a
+/// locking clause such as `FOR UPDATE`.
+pub fn enforce_execution_policy(
+    policy: &ExecutionPolicy,
+    database_type: DatabaseType,
+    sql: &str,
+) -> Result<String, PolicyViolation> {
+    let default_limit = policy.auto_limit.or(policy.max_rows);
+    let mut insertions = Vec::new();
+
+    for statement in statement_slices_for_database(database_type, sql) {
+        let kind = classify(statement);
+        if policy.select_only
+            && !evaluate_sql_risk_for_database(database_type, &kind, statement).read_only_allowed
+        {
+            return Err(PolicyViolation::StatementNotAllowed(
+                adhoc_label_for_statement(database_type, statement),
+            ));
+        }
+        if policy.require_where
+            && matches!(
+                kind,
+                StatementKind::Update { has_where: false }
+                    | StatementKind::Delete { has_where: false }
+            )
+        {
+            return Err(PolicyViolation::MissingWhere(adhoc_label_for_statement(
+                database_type,
+                statement,
+            )));
+        }
+        if !is_plain_select(statement) {
+            continue;
+        }
+        match (row_limit(statement), policy.max_rows, default_limit) {
+            (RowLimit::Explicit(requested), Some(max_rows), _) if requested > max_rows => {
+                return Err(PolicyViolation::RowLimitExceeded {
+                    requested: Some(requested),
+                    max_rows,
+                });
+            }
+            (RowLimit::Unknown, Some(max_rows), _) => {
+                return Err(PolicyViolation::RowLimitExceeded {
+                    requested: None,
+                    max_rows,
+                });
+            }
+            (RowLimit::None, _, Some(limit)) => {
+                insertions.push(limit_insertion(sql, statement, limit));
+            }
+            _ => {}
+        }
+    }
+
+    let mut limited = sql.to_string();
+    for (at, clause) in insertions.into_iter().rev() {
+        limited.insert_str(at, &clause);
+    }
+    Ok(limited)
+}
+
+/// Where in `sql` the LIMIT for `statement` goes, and its text.
+fn limit_insertion(sql: &str, statement: &str, limit: usize) -> (usize, String) {
+    // Statements are trimmed slices of `sql`, so their addresses locate them.
+    let offset_in_sql = |part: &str| part.as_ptr().addr() - sql.as_ptr().addr();
+    let main = statement_after_leading_ctes(statement);
+    let chars: Vec<(usize, char)> = main.char_indices().collect();
+    let locking_clause = collect_top_level_tokens(main, &chars)
+        .into_iter()
+        .find(|(_, token)| token.eq_ignore_ascii_case("for"))
+        .map(|(at, _)| at);
+    match locking_clause {
+        Some(at) => (offset_in_sql(main) + at, format!("LIMIT {limit}\n")),
+        None => (
+            offset_in_sql(statement) + statement.len(),
+            format!("\nLIMIT {limit}"),
+        ),
+    }
+}
+
+fn is_plain_select(statement: &str) -> bool {
+    first_keyword(statement_after_leading_ctes(statement)).as_deref() == Some("SELECT")
+}
+
+enum RowLimit {
+    None,
+    Explicit(usize),
+    /// `LIMIT ALL`, a parameter or an expression.
+    Unknown,
+}
+
+fn row_limit(statement: &str) -> RowLimit {
+    let main = statement_after_leading_ctes(statement);
+    let chars: Vec<(usize, char)> = main.char_indices().collect();
+    let tokens: Vec<String> = collect_top_level_tokens(main, &chars)
+        .into_iter()
+        .map(|(_, token)| token.to_lowercase())
+        .collect();
+    let count = if let Some(at) = tokens.iter().position(|token| token == "limit") {
+        tokens.get(at + 1)
+    } else if let Some(at) = tokens.iter().position(|token| token == "fetch") {
+        // FETCH FIRST n ROWS ONLY; a missing count means one row.
+        match tokens.get(at + 2) {
+            Some(token) if token == "row" || token == "rows" => return RowLimit::Explicit(1),
+            other => other,
+        }
+    } else {
+        return RowLimit::None;
+    };
+    count
+        .and_then(|token| token.parse().ok())
+        .map_or(RowLimit::Unknown, RowLimit::Explicit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn enforce(policy: &ExecutionPolicy, sql: &str) -> Result<String, PolicyViolation> {
+        enforce_execution_policy(policy, DatabaseType::PostgreSQL, sql)
+    }
+
+    fn select_only() -> ExecutionPolicy {
+        ExecutionPolicy {
+            select_only: true,
+            ..ExecutionPolicy::default()
+        }
+    }
+
+    #[test]
+    fn select_only_rejects_writes() {
+        let result = enforce(&select_only(), "SELECT 1; DELETE FROM users WHERE id = 1");
+
+        assert_eq!(result, Err(PolicyViolation::StatementNotAllowed("DELETE")));
+    }
+
+    #[test]
+    fn select_only_keeps_reads_untouched() {
+        assert_eq!(
+            enforce(&select_only(), "SELECT * FROM users"),
+            Ok("SELECT * FROM users".to_string())
+        );
+    }
+
+    #[rstest]
+    #[case("UPDATE users SET active = false")]
+    #[case("DELETE FROM users")]
+    fn require_where_rejects_unfiltered_writes(#[case] sql: &str) {
+        let policy = ExecutionPolicy {
+            require_where: true,
+            ..ExecutionPolicy::default()
+        };
+
+        assert!(matches!(
+            enforce(&policy, sql),
+            Err(PolicyViolation::MissingWhere(_))
+        ));
+    }
+
+    #[test]
+    fn auto_limit_is_appended_on_its_own_line() {
+        let policy = ExecutionPolicy {
+            auto_limit: Some(200),
+            ..ExecutionPolicy::default()
+        };
+
+        assert_eq!(
+            enforce(&policy, "SELECT * FROM users -- everyone"),
+            Ok("SELECT * FROM users -- everyone\nLIMIT 200".to_string())
+        );
+    }
+
+    #[test]
+    fn limit_inside_subquery_does_not_count() {
+        let policy = ExecutionPolicy {
+            auto_limit: Some(50),
+            ..ExecutionPolicy::default()
+        };
+
+        assert_eq!(
+            enforce(&policy, "SELECT * FROM (SELECT * FROM users LIMIT 5) u"),
+            Ok("SELECT * FROM (SELECT * FROM users LIMIT 5) u\nLIMIT 50".to_string())
+        );
+    }
+
+    #[rstest]
+    #[case("SELECT * FROM users LIMIT 500", Some(500))]
+    #[case("SELECT * FROM users FETCH FIRST 500 ROWS ONLY", Some(500))]
+    #[case("SELECT * FROM users LIMIT ALL", None)]
+    fn max_rows_rejects_larger_or_unknown_limits(
+        #[case] sql: &str,
+        #[case] requested: Option<usize>,
+    ) {
+        let policy = ExecutionPolicy {
+            max_rows: Some(100),
+            ..ExecutionPolicy::default()
+        };
+
+        assert_eq!(
+            enforce(&policy, sql),
+            Err(PolicyViolation::RowLimitExceeded {
+                requested,
+                max_rows: 100
+            })
+        );
+    }
+
+    #[test]
+    fn max_rows_limits_unbounded_selects() {
+        let policy = ExecutionPolicy {
+            max_rows: Some(100),
+            ..ExecutionPolicy::default()
+        };
+
+        assert_eq!(
+            enforce(&policy, "SELECT * FROM users"),
+            Ok("SELECT * FROM users\nLIMIT 100".to_string())
+        );
+    }
+
+    #[test]
+    fn auto_limit_goes_before_locking_clause() {
+        let policy = ExecutionPolicy {
+            auto_limit: Some(200),
+            ..ExecutionPolicy::default()
+        };
+
+        assert_eq!(
+            enforce(
+                &policy,
+                "SELECT * FROM jobs WHERE state = 'new' FOR UPDATE SKIP LOCKED"
+            ),
+            Ok(
+                "SELECT * FROM jobs WHERE state = 'new' LIMIT 200\nFOR UPDATE SKIP LOCKED"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn batch_keeps_its_text_and_limits_each_select_in_place() {
+        let policy = ExecutionPolicy {
+            auto_limit: Some(10),
+            ..ExecutionPolicy::default()
+        };
+        let sql = "SELECT 1 LIMIT 1;\n-- recent users\nSELECT * FROM users; \
+                   UPDATE users SET seen = now() WHERE id = 1;\nSELECT * FROM orders -- all\n";
+
+        assert_eq!(
+            enforce(&policy, sql),
+            Ok("SELECT 1 LIMIT 1;\n-- recent users\nSELECT * FROM users\nLIMIT 10; \
+                UPDATE users SET seen = now() WHERE id = 1;\nSELECT * FROM orders -- all\nLIMIT 10\n"
+                .to_string())
+        );
+    }
+
+    #[test]
+    fn non_select_reads_are_not_limited() {
+        let policy = ExecutionPolicy {
+            auto_limit: Some(10),
+            ..ExecutionPolicy::default()
+        };
+
+        assert_eq!(
+            enforce(&policy, "EXPLAIN SELECT * FROM users"),
+            Ok("EXPLAIN SELECT * FROM users".to_string())
+        );
+    }
+}
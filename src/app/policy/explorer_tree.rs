@@ -0,0 +1,373 @@
+use crate::domain::{ExplorerTreeLayout, SchemaObject, SchemaObjectKind, TableSummary};
+use crate::model::shared::ui_state::text_display_width;
+use crate::policy::table_kind::{explorer_kind_suffix, explorer_table_label};
+
+const NESTED_TABLE_INDENT: &str = "  ";
+
+/// One Explorer line.
+#[derive(Debug, Clone, Copy)]
+pub enum ExplorerRow<'a> {
+    Schema {
+        name: &'a str,
+        table_count: usize,
+        collapsed: bool,
+    },
+    Table {
+        table: &'a TableSummary,
+        nested: bool,
+    },
+    /// Folding header for one kind of object in a schema.
+    Section {
+        schema: &'a str,
+        kind: SchemaObjectKind,
+        count: usize,
+        expanded: bool,
+        nested: bool,
+    },
+    Object {
+        object: &'a SchemaObject,
+        nested: bool,
+    },
+}
+
+impl<'a> ExplorerRow<'a> {
+    pub fn table(self) -> Option<&'a TableSummary> {
+        match self {
+            Self::Table { table, .. } => Some(table),
+            Self::Schema { .. } | Self::Section { .. } | Self::Object { .. } => None,
+        }
+    }
+
+    pub fn schema(self) -> Option<&'a str> {
+        match self {
+            Self::Schema { name, .. } => Some(name),
+            Self::Table { .. } | Self::Section { .. } | Self::Object { .. } => None,
+        }
+    }
+
+    pub fn object(self) -> Option<&'a SchemaObject> {
+        match self {
+            Self::Object { object, .. } => Some(object),
+            Self::Schema { .. } | Self::Table { .. } | Self::Section { .. } => None,
+        }
+    }
+}
+
+struct SchemaGroup<'a> {
+    schema: &'a str,
+    tables: Vec<&'a TableSummary>,
+    objects: Vec<&'a SchemaObject>,
+}
+
+fn group_for<'g, 'a>(
+    groups: &'g mut Vec<SchemaGroup<'a>>,
+    schema: &'a str,
+) -> &'g mut SchemaGroup<'a> {
+    if let Some(index) = groups.iter().position(|group| group.schema == schema) {
+        return &mut groups[index];
+    }
+    groups.push(SchemaGroup {
+        schema,
+        tables: Vec::new(),
+        objects: Vec::new(),
+    });
+    groups.last_mut().expect("group was just pushed")
+}
+
+/// Tables and other objects narrowed to `schema_filter`, grouped under
+/// collapsible schema headers once they span more than one schema.
+///
+/// A single schema stays a flat list of qualified names, so SQLite, MySQL
+/// and public-only PostgreSQL databases look the same as before. Views,
+/// sequences and functions follow the tables under folded typed sections.
+pub fn explorer_rows<'a>(
+    tables: &[&'a TableSummary],
+    objects: &'a [SchemaObject],
+    layout: &ExplorerTreeLayout,
+    schema_filter: Option<&str>,
+) -> Vec<ExplorerRow<'a>> {
+    let mut groups: Vec<SchemaGroup<'a>> = Vec::new();
+    for &table in tables {
+        if schema_filter.is_none_or(|schema| schema == table.schema) {
+            group_for(&mut groups, &table.schema).tables.push(table);
+        }
+    }
+    for object in objects {
+        if schema_filter.is_none_or(|schema| schema == object.schema) {
+            group_for(&mut groups, &object.schema).objects.push(object);
+        }
+    }
+
+    let nested = groups.len() > 1;
+    let mut rows = Vec::new();
+    for group in groups {
+        if nested {
+            let collapsed = layout.is_collapsed(group.schema);
+            rows.push(ExplorerRow::Schema {
+                name: group.schema,
+                table_count: group.tables.len(),
+                collapsed,
+            });
+            if collapsed {
+                continue;
+            }
+        }
+        rows.extend(
+            group
+                .tables
+                .into_iter()
+                .map(|table| ExplorerRow::Table { table, nested }),
+        );
+        for kind in SchemaObjectKind::ALL {
+            let members: Vec<&SchemaObject> = group
+                .objects
+                .iter()
+                .copied()
+                .filter(|object| object.kind == kind)
+                .collect();
+            if members.is_empty() {
+                continue;
+            }
+            let expanded = layout.is_section_expanded(group.schema, kind);
+            rows.push(ExplorerRow::Section {
+                schema: group.schema,
+                kind,
+                count: members.len(),
+                expanded,
+                nested,
+            });
+            if expanded {
+                rows.extend(
+                    members
+                        .into_iter()
+                        .map(|object| ExplorerRow::Object { object, nested }),
+                );
+            }
+        }
+    }
+    rows
+}
+
+fn folding_marker(open: bool) -> char {
+    if open { '▾' } else { '▸' }
+}
+
+pub fn explorer_row_label(row: ExplorerRow<'_>) -> String {
+    match row {
+        ExplorerRow::Schema {
+            name,
+            table_count,
+            collapsed,
+        } => {
+            let marker = folding_marker(!collapsed);
+            format!("{marker} {name} ({table_count})")
+        }
+        ExplorerRow::Table {
+            table,
+            nested: false,
+        } => explorer_table_label(table),
+        ExplorerRow::Table {
+            table,
+            nested: true,
+        } => {
+            let mut label = format!("{NESTED_TABLE_INDENT}{}", table.name);
+            if let Some(suffix) = explorer_kind_suffix(&table.kind_info) {
+                label.push_str(&suffix);
+            }
+            label
+        }
+        ExplorerRow::Section {
+            kind,
+            count,
+            expanded,
+            nested,
+            ..
+        } => {
+            let indent = if nested { NESTED_TABLE_INDENT } else { "" };
+            let marker = folding_marker(expanded);
+            format!("{indent}{marker} {} ({count})", kind.section_label())
+        }
+        ExplorerRow::Object {
+            object,
+            nested: false,
+        } => format!("{NESTED_TABLE_INDENT}{}", object.qualified_name()),
+        ExplorerRow::Object {
+            object,
+            nested: true,
+        } => format!(
+            "{NESTED_TABLE_INDENT}{NESTED_TABLE_INDENT}{}",
+            object.display_name()
+        ),
+    }
+}
+
+pub fn max_explorer_row_label_width(rows: &[ExplorerRow<'_>]) -> usize {
+    rows.iter()
+        .map(|row| text_display_width(&explorer_row_label(*row)))
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(schema: &str, name: &str) -> TableSummary {
+        TableSummary::new(schema.to_string(), name.to_string(), None, false)
+    }
+
+    fn labels(rows: &[ExplorerRow<'_>]) -> Vec<String> {
+        rows.iter().map(|row| explorer_row_label(*row)).collect()
+    }
+
+    #[test]
+    fn single_schema_stays_flat() {
+        let tables = [table("public", "orders"), table("public", "users")];
+        let refs: Vec<&TableSummary> = tables.iter().collect();
+
+        let rows = explorer_rows(&refs, &[], &ExplorerTreeLayout::default(), None);
+
+        assert_eq!(labels(&rows), vec!["public.orders", "public.users"]);
+    }
+
+    #[test]
+    fn multiple_schemas_group_under_headers() {
+        let tables = [
+            table("audit", "events"),
+            table("public", "orders"),
+            table("public", "users"),
+        ];
+        let refs: Vec<&TableSummary> = tables.iter().collect();
+
+        let rows = explorer_rows(&refs, &[], &ExplorerTreeLayout::default(), None);
+
+        assert_eq!(
+            labels(&rows),
+            vec![
+                "▾ audit (1)",
+                "  events",
+                "▾ public (2)",
+                "  orders",
+                "  users"
+            ]
+        );
+    }
+
+    #[test]
+    fn collapsed_schema_hides_its_tables() {
+        let tables = [table("audit", "events"), table("public", "orders")];
+        let refs: Vec<&TableSummary> = tables.iter().collect();
+        let mut layout = ExplorerTreeLayout::default();
+        layout.toggle("audit");
+
+        let rows = explorer_rows(&refs, &[], &layout, None);
+
+        assert_eq!(
+            labels(&rows),
+            vec!["▸ audit (1)", "▾ public (1)", "  orders"]
+        );
+    }
+
+    #[test]
+    fn schema_filter_keeps_one_flat_schema() {
+        let tables = [table("audit", "events"), table("public", "orders")];
+        let refs: Vec<&TableSummary> = tables.iter().collect();
+
+        let rows = explorer_rows(&refs, &[], &ExplorerTreeLayout::default(), Some("audit"));
+
+        assert_eq!(labels(&rows), vec!["audit.events"]);
+    }
+
+    #[test]
+    fn schema_rows_carry_no_table() {
+        let tables = [table("audit", "events"), table("public", "orders")];
+        let refs: Vec<&TableSummary> = tables.iter().collect();
+
+        let rows = explorer_rows(&refs, &[], &ExplorerTreeLayout::default(), None);
+
+        assert_eq!(rows[0].schema(), Some("audit"));
+        assert!(rows[0].table().is_none());
+        assert_eq!(rows[1].table().map(|t| t.name.as_str()), Some("events"));
+    }
+
+    fn object(schema: &str, name: &str, kind: SchemaObjectKind) -> SchemaObject {
+        SchemaObject {
+            schema: schema.to_string(),
+            name: name.to_string(),
+            kind,
+            arguments: (kind == SchemaObjectKind::Function).then(|| "integer".to_string()),
+            definition: None,
+        }
+    }
+
+    #[test]
+    fn objects_follow_tables_under_folded_sections() {
+        let tables = [table("public", "users")];
+        let refs: Vec<&TableSummary> = tables.iter().collect();
+        let objects = [
+            object("public", "total", SchemaObjectKind::Function),
+            object("public", "active_users", SchemaObjectKind::View),
+        ];
+
+        let rows = explorer_rows(&refs, &objects, &ExplorerTreeLayout::default(), None);
+
+        assert_eq!(
+            labels(&rows),
+            vec!["public.users", "▸ Views (1)", "▸ Functions (1)"]
+        );
+    }
+
+    #[test]
+    fn expanded_section_lists_its_objects() {
+        let tables = [table("audit", "events"), table("public", "users")];
+        let refs: Vec<&TableSummary> = tables.iter().collect();
+        let objects = [
+            object("public", "total", SchemaObjectKind::Function),
+            object("public", "total", SchemaObjectKind::Sequence),
+        ];
+        let mut layout = ExplorerTreeLayout::default();
+        layout.toggle_section("public", SchemaObjectKind::Function);
+
+        let rows = explorer_rows(&refs, &objects, &layout, None);
+
+        assert_eq!(
+            labels(&rows),
+            vec![
+                "▾ audit (1)",
+                "  events",
+                "▾ public (1)",
+                "  users",
+                "  ▸ Sequences (1)",
+                "  ▾ Functions (1)",
+                "    total(integer)",
+            ]
+        );
+        assert_eq!(
+            rows[6].object().map(SchemaObject::qualified_name),
+            Some("public.total(integer)".to_string())
+        );
+    }
+
+    #[test]
+    fn schema_with_only_objects_still_gets_a_header() {
+        let tables = [table("public", "users")];
+        let refs: Vec<&TableSummary> = tables.iter().collect();
+        let objects = [object(
+            "reporting",
+            "monthly",
+            SchemaObjectKind::MaterializedView,
+        )];
+
+        let rows = explorer_rows(&refs, &objects, &ExplorerTreeLayout::default(), None);
+
+        assert_eq!(
+            labels(&rows),
+            vec![
+                "▾ public (1)",
+                "  users",
+                "▾ reporting (0)",
+                "  ▸ Materialized Views (1)",
+            ]
+        );
+    }
+}
@@ -9,6 +9,9 @@ pub enum FeatureRequirement {
     Explain,
     ExplainAnalyze,
     PlanComparison,
+    Maintenance,
+    ServerSettings,
+    SessionSettings,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,6 +39,9 @@ impl FeaturePolicy {
             FeatureRequirement::Explain => self.profile.supports_explain(),
             FeatureRequirement::ExplainAnalyze => self.profile.supports_explain_analyze(),
             FeatureRequirement::PlanComparison => self.profile.supports_plan_comparison(),
+            FeatureRequirement::Maintenance => self.profile.supports_maintenance(),
+            FeatureRequirement::ServerSettings => self.profile.supports_server_settings(),
+            FeatureRequirement::SessionSettings => self.profile.supports_session_settings(),
         };
 
         if supported {
@@ -74,6 +80,18 @@ mod tests {
             policy.availability(FeatureRequirement::ExplainAnalyze),
             FeatureAvailability::Enabled
         );
+        assert_eq!(
+            policy.availability(FeatureRequirement::Maintenance),
+            FeatureAvailability::Enabled
+        );
+        assert_eq!(
+            policy.availability(FeatureRequirement::ServerSettings),
+            FeatureAvailability::Enabled
+        );
+        assert_eq!(
+            policy.availability(FeatureRequirement::SessionSettings),
+            FeatureAvailability::Enabled
+        );
         assert_eq!(
             policy.availability(FeatureRequirement::SqliteDiagnostics),
             FeatureAvailability::Hidden
@@ -100,6 +118,18 @@ mod tests {
             policy.availability(FeatureRequirement::PlanComparison),
             FeatureAvailability::Hidden
         );
+        assert_eq!(
+            policy.availability(FeatureRequirement::Maintenance),
+            FeatureAvailability::Hidden
+        );
+        assert_eq!(
+            policy.availability(FeatureRequirement::ServerSettings),
+            FeatureAvailability::Hidden
+        );
+        assert_eq!(
+            policy.availability(FeatureRequirement::SessionSettings),
+            FeatureAvailability::Hidden
+        );
     }
 
     #[test]
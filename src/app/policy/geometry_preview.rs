@@ -0,0 +1,608 @@
+use std::fmt::Write as _;
+
+pub const PLOT_WIDTH: usize = 40;
+pub const PLOT_HEIGHT: usize = 12;
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+const EWKB_TYPE_MASK: u32 = 0x0FFF_FFFF;
+const MAX_NESTING: usize = 32;
+const BRAILLE_BASE: u32 = 0x2800;
+// Dot bit for (row, column) inside a 2x4 braille cell.
+const BRAILLE_BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coord {
+    pub x: f64,
+    pub y: f64,
+    pub z: Option<f64>,
+    pub m: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(Option<Coord>),
+    LineString(Vec<Coord>),
+    Polygon(Vec<Vec<Coord>>),
+    MultiPoint(Vec<Self>),
+    MultiLineString(Vec<Self>),
+    MultiPolygon(Vec<Self>),
+    Collection(Vec<Self>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeometryValue {
+    pub srid: Option<u32>,
+    pub geometry: Geometry,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+pub fn is_spatial_type(data_type: &str) -> bool {
+    let lower = data_type.trim().to_ascii_lowercase();
+    lower.starts_with("geometry") || lower.starts_with("geography")
+}
+
+/// Parses the hex EWKB text PostgreSQL returns for geometry/geography values.
+pub fn parse_ewkb_hex(hex: &str) -> Option<GeometryValue> {
+    let bytes = decode_hex(hex.trim())?;
+    let mut reader = WkbReader {
+        bytes: &bytes,
+        pos: 0,
+        little_endian: true,
+    };
+    let (geometry, srid) = reader.read_geometry(0)?;
+    (reader.pos == bytes.len()).then_some(GeometryValue { srid, geometry })
+}
+
+impl Geometry {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Point(_) => "POINT",
+            Self::LineString(_) => "LINESTRING",
+            Self::Polygon(_) => "POLYGON",
+            Self::MultiPoint(_) => "MULTIPOINT",
+            Self::MultiLineString(_) => "MULTILINESTRING",
+            Self::MultiPolygon(_) => "MULTIPOLYGON",
+            Self::Collection(_) => "GEOMETRYCOLLECTION",
+        }
+    }
+
+    pub fn to_wkt(&self) -> String {
+        let body = self.wkt_body();
+        let dims = match self.first_coord() {
+            Some(Coord {
+                z: Some(_),
+                m: Some(_),
+                ..
+            }) => " ZM",
+            Some(Coord { z: Some(_), .. }) => " Z",
+            Some(Coord { m: Some(_), .. }) => " M",
+            _ => "",
+        };
+        if body == "EMPTY" {
+            format!("{}{dims} EMPTY", self.type_name())
+        } else if dims.is_empty() {
+            format!("{}{body}", self.type_name())
+        } else {
+            format!("{}{dims} {body}", self.type_name())
+        }
+    }
+
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let mut bbox: Option<BoundingBox> = None;
+        self.for_each_coord(&mut |c| {
+            let b = bbox.get_or_insert(BoundingBox {
+                min_x: c.x,
+                min_y: c.y,
+                max_x: c.x,
+                max_y: c.y,
+            });
+            b.min_x = b.min_x.min(c.x);
+            b.min_y = b.min_y.min(c.y);
+            b.max_x = b.max_x.max(c.x);
+            b.max_y = b.max_y.max(c.y);
+        });
+        bbox
+    }
+
+    pub fn coord_count(&self) -> usize {
+        let mut count = 0;
+        self.for_each_coord(&mut |_| count += 1);
+        count
+    }
+
+    fn wkt_body(&self) -> String {
+        fn join(parts: impl Iterator<Item = String>) -> String {
+            let parts: Vec<String> = parts.filter(|p| p != "EMPTY").collect();
+            if parts.is_empty() {
+                "EMPTY".to_string()
+            } else {
+                format!("({})", parts.join(","))
+            }
+        }
+
+        match self {
+            Self::Point(Some(c)) => format!("({})", coord_wkt(c)),
+            Self::Point(None) => "EMPTY".to_string(),
+            Self::LineString(coords) => coords_wkt(coords),
+            Self::Polygon(rings) => join(rings.iter().map(|ring| coords_wkt(ring))),
+            Self::MultiPoint(children)
+            | Self::MultiLineString(children)
+            | Self::MultiPolygon(children) => join(children.iter().map(Self::wkt_body)),
+            Self::Collection(children) => join(children.iter().map(Self::to_wkt)),
+        }
+    }
+
+    fn first_coord(&self) -> Option<Coord> {
+        let mut first = None;
+        self.for_each_coord(&mut |c| {
+            first.get_or_insert(*c);
+        });
+        first
+    }
+
+    fn for_each_coord(&self, f: &mut impl FnMut(&Coord)) {
+        match self {
+            Self::Point(point) => point.iter().for_each(f),
+            Self::LineString(coords) => coords.iter().for_each(f),
+            Self::Polygon(rings) => rings.iter().flatten().for_each(f),
+            Self::MultiPoint(children)
+            | Self::MultiLineString(children)
+            | Self::MultiPolygon(children)
+            | Self::Collection(children) => {
+                for child in children {
+                    child.for_each_coord(f);
+                }
+            }
+        }
+    }
+
+    fn collect_shapes<'a>(&'a self, points: &mut Vec<Coord>, paths: &mut Vec<&'a [Coord]>) {
+        match self {
+            Self::Point(point) => points.extend(point),
+            Self::LineString(coords) => paths.push(coords),
+            Self::Polygon(rings) => paths.extend(rings.iter().map(Vec::as_slice)),
+            Self::MultiPoint(children)
+            | Self::MultiLineString(children)
+            | Self::MultiPolygon(children)
+            | Self::Collection(children) => {
+                for child in children {
+                    child.collect_shapes(points, paths);
+                }
+            }
+        }
+    }
+}
+
+/// Plots the geometry into `width` x `height` braille cells, keeping its aspect ratio.
+pub fn render_braille(geometry: &Geometry, width: usize, height: usize) -> Vec<String> {
+    let mut canvas = BrailleCanvas::new(width, height);
+    let Some(bbox) = geometry.bounding_box() else {
+        return canvas.rows();
+    };
+
+    let dot_w = (width * 2).saturating_sub(1) as f64;
+    let dot_h = (height * 4).saturating_sub(1) as f64;
+    let span_x = bbox.max_x - bbox.min_x;
+    let span_y = bbox.max_y - bbox.min_y;
+    let scale = match (span_x > 0.0, span_y > 0.0) {
+        (true, true) => (dot_w / span_x).min(dot_h / span_y),
+        (true, false) => dot_w / span_x,
+        (false, true) => dot_h / span_y,
+        (false, false) => 0.0,
+    };
+    let offset_x = span_x.mul_add(-scale, dot_w) / 2.0;
+    let offset_y = span_y.mul_add(-scale, dot_h) / 2.0;
+    let to_dot = |c: &Coord| {
+        let x = (c.x - bbox.min_x).mul_add(scale, offset_x);
+        let y = dot_h - (c.y - bbox.min_y).mul_add(scale, offset_y);
+        (x.round() as i64, y.round() as i64)
+    };
+
+    let mut points = Vec::new();
+    let mut paths = Vec::new();
+    geometry.collect_shapes(&mut points, &mut paths);
+    for point in &points {
+        canvas.set(to_dot(point));
+    }
+    for path in paths {
+        if let [only] = path {
+            canvas.set(to_dot(only));
+        }
+        for pair in path.windows(2) {
+            canvas.line(to_dot(&pair[0]), to_dot(&pair[1]));
+        }
+    }
+    canvas.rows()
+}
+
+/// Popup text: summary, bounding box, braille plot, then the WKT.
+pub fn geometry_preview_text(value: &GeometryValue) -> String {
+    let geometry = &value.geometry;
+    let mut out = String::new();
+    out.push_str(geometry.type_name());
+    if let Some(srid) = value.srid {
+        let _ = write!(out, " · SRID {srid}");
+    }
+    let _ = writeln!(out, " · {} points", geometry.coord_count());
+    match geometry.bounding_box() {
+        Some(b) => {
+            let _ = writeln!(
+                out,
+                "bbox ({}, {}) – ({}, {})",
+                b.min_x, b.min_y, b.max_x, b.max_y
+            );
+        }
+        None => out.push_str("bbox (empty)\n"),
+    }
+    out.push('\n');
+    for row in render_braille(geometry, PLOT_WIDTH, PLOT_HEIGHT) {
+        out.push_str(&row);
+        out.push('\n');
+    }
+    out.push('\n');
+    out.push_str(&geometry.to_wkt());
+    out
+}
+
+fn coord_wkt(c: &Coord) -> String {
+    let mut text = format!("{} {}", c.x, c.y);
+    for extra in [c.z, c.m].into_iter().flatten() {
+        let _ = write!(text, " {extra}");
+    }
+    text
+}
+
+fn coords_wkt(coords: &[Coord]) -> String {
+    if coords.is_empty() {
+        "EMPTY".to_string()
+    } else {
+        let parts: Vec<String> = coords.iter().map(coord_wkt).collect();
+        format!("({})", parts.join(","))
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.is_empty() || !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+struct Dims {
+    z: bool,
+    m: bool,
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    little_endian: bool,
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let slice = self.bytes.get(self.pos..self.pos + N)?;
+        self.pos += N;
+        slice.try_into().ok()
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        let bytes = self.take::<4>()?;
+        Some(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self) -> Option<f64> {
+        let bytes = self.take::<8>()?;
+        Some(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    // Rejects counts the remaining bytes cannot hold before anything is allocated.
+    fn read_count(&mut self, min_item_bytes: usize) -> Option<usize> {
+        let count = self.read_u32()? as usize;
+        let remaining = self.bytes.len() - self.pos;
+        (count.checked_mul(min_item_bytes)? <= remaining).then_some(count)
+    }
+
+    fn read_coord(&mut self, dims: Dims) -> Option<Coord> {
+        let x = self.read_f64()?;
+        let y = self.read_f64()?;
+        let z = if dims.z { Some(self.read_f64()?) } else { None };
+        let m = if dims.m { Some(self.read_f64()?) } else { None };
+        Some(Coord { x, y, z, m })
+    }
+
+    fn read_coords(&mut self, dims: Dims) -> Option<Vec<Coord>> {
+        let count = self.read_count(16)?;
+        (0..count).map(|_| self.read_coord(dims)).collect()
+    }
+
+    fn read_children(&mut self, depth: usize) -> Option<Vec<Geometry>> {
+        let count = self.read_count(5)?;
+        (0..count)
+            .map(|_| self.read_geometry(depth + 1).map(|(geometry, _)| geometry))
+            .collect()
+    }
+
+    fn read_geometry(&mut self, depth: usize) -> Option<(Geometry, Option<u32>)> {
+        if depth > MAX_NESTING {
+            return None;
+        }
+        self.little_endian = match self.take::<1>()? {
+            [0] => false,
+            [1] => true,
+            _ => return None,
+        };
+        let raw_type = self.read_u32()?;
+        let srid = if raw_type & EWKB_SRID == 0 {
+            None
+        } else {
+            Some(self.read_u32()?)
+        };
+        // ISO WKB encodes dimensions as 1000 (Z), 2000 (M), 3000 (ZM) offsets.
+        let iso_dims = (raw_type & EWKB_TYPE_MASK) / 1000;
+        let dims = Dims {
+            z: raw_type & EWKB_Z != 0 || matches!(iso_dims, 1 | 3),
+            m: raw_type & EWKB_M != 0 || matches!(iso_dims, 2 | 3),
+        };
+
+        let geometry = match (raw_type & EWKB_TYPE_MASK) % 1000 {
+            1 => {
+                let coord = self.read_coord(dims)?;
+                Geometry::Point((!coord.x.is_nan()).then_some(coord))
+            }
+            2 => Geometry::LineString(self.read_coords(dims)?),
+            3 => {
+                let count = self.read_count(4)?;
+                Geometry::Polygon(
+                    (0..count)
+                        .map(|_| self.read_coords(dims))
+                        .collect::<Option<_>>()?,
+                )
+            }
+            4 => Geometry::MultiPoint(self.read_children(depth)?),
+            5 => Geometry::MultiLineString(self.read_children(depth)?),
+            6 => Geometry::MultiPolygon(self.read_children(depth)?),
+            7 => Geometry::Collection(self.read_children(depth)?),
+            _ => return None,
+        };
+        Some((geometry, srid))
+    }
+}
+
+struct BrailleCanvas {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+}
+
+impl BrailleCanvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![0; width * height],
+        }
+    }
+
+    fn set(&mut self, (x, y): (i64, i64)) {
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            return;
+        };
+        if x >= self.width * 2 || y >= self.height * 4 {
+            return;
+        }
+        self.cells[(y / 4) * self.width + x / 2] |= BRAILLE_BITS[y % 4][x % 2];
+    }
+
+    fn line(&mut self, from: (i64, i64), to: (i64, i64)) {
+        let steps = (to.0 - from.0).abs().max((to.1 - from.1).abs()).max(1);
+        for step in 0..=steps {
+            let t = step as f64 / steps as f64;
+            let x = ((to.0 - from.0) as f64).mul_add(t, from.0 as f64);
+            let y = ((to.1 - from.1) as f64).mul_add(t, from.1 as f64);
+            self.set((x.round() as i64, y.round() as i64));
+        }
+    }
+
+    fn rows(&self) -> Vec<String> {
+        self.cells
+            .chunks(self.width.max(1))
+            .take(self.height)
+            .map(|row| {
+                row.iter()
+                    .map(|&bits| char::from_u32(BRAILLE_BASE + u32::from(bits)).unwrap_or(' '))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut out, b| {
+            let _ = write!(out, "{b:02X}");
+            out
+        })
+    }
+
+    fn le_header(geometry_type: u32) -> Vec<u8> {
+        let mut bytes = vec![1];
+        bytes.extend(geometry_type.to_le_bytes());
+        bytes
+    }
+
+    fn le_coords(coords: &[(f64, f64)]) -> Vec<u8> {
+        coords
+            .iter()
+            .flat_map(|(x, y)| x.to_le_bytes().into_iter().chain(y.to_le_bytes()))
+            .collect()
+    }
+
+    fn le_count(count: u32) -> Vec<u8> {
+        count.to_le_bytes().to_vec()
+    }
+
+    #[test]
+    fn parses_point_with_srid() {
+        let value = parse_ewkb_hex("0101000020E6100000000000000000F03F0000000000000040").unwrap();
+
+        assert_eq!(value.srid, Some(4326));
+        assert_eq!(value.geometry.to_wkt(), "POINT(1 2)");
+    }
+
+    #[test]
+    fn parses_big_endian_linestring() {
+        let mut bytes = vec![0];
+        bytes.extend(2u32.to_be_bytes());
+        bytes.extend(2u32.to_be_bytes());
+        for v in [0.0f64, 0.0, 10.0, 5.5] {
+            bytes.extend(v.to_be_bytes());
+        }
+
+        let value = parse_ewkb_hex(&hex(&bytes)).unwrap();
+
+        assert_eq!(value.srid, None);
+        assert_eq!(value.geometry.to_wkt(), "LINESTRING(0 0,10 5.5)");
+    }
+
+    #[test]
+    fn parses_polygon_and_reports_bbox() {
+        let mut bytes = le_header(3);
+        bytes.extend(le_count(1));
+        bytes.extend(le_count(4));
+        bytes.extend(le_coords(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 0.0)]));
+
+        let value = parse_ewkb_hex(&hex(&bytes)).unwrap();
+
+        assert_eq!(value.geometry.to_wkt(), "POLYGON((0 0,4 0,4 2,0 0))");
+        assert_eq!(
+            value.geometry.bounding_box(),
+            Some(BoundingBox {
+                min_x: 0.0,
+                min_y: 0.0,
+                max_x: 4.0,
+                max_y: 2.0,
+            })
+        );
+        assert_eq!(value.geometry.coord_count(), 4);
+    }
+
+    #[test]
+    fn parses_multipoint_with_z() {
+        let mut bytes = le_header(4 | EWKB_Z);
+        bytes.extend(le_count(1));
+        bytes.extend(le_header(1 | EWKB_Z));
+        for v in [1.0f64, 2.0, 3.0] {
+            bytes.extend(v.to_le_bytes());
+        }
+
+        let value = parse_ewkb_hex(&hex(&bytes)).unwrap();
+
+        assert_eq!(value.geometry.to_wkt(), "MULTIPOINT Z ((1 2 3))");
+    }
+
+    #[test]
+    fn empty_point_is_reported_as_empty() {
+        let mut bytes = le_header(1);
+        bytes.extend(f64::NAN.to_le_bytes());
+        bytes.extend(f64::NAN.to_le_bytes());
+
+        let value = parse_ewkb_hex(&hex(&bytes)).unwrap();
+
+        assert_eq!(value.geometry.to_wkt(), "POINT EMPTY");
+        assert_eq!(value.geometry.bounding_box(), None);
+    }
+
+    #[test]
+    fn rejects_non_geometry_text() {
+        assert_eq!(parse_ewkb_hex("hello"), None);
+        assert_eq!(parse_ewkb_hex("POINT(1 2)"), None);
+        assert_eq!(parse_ewkb_hex(""), None);
+    }
+
+    #[test]
+    fn rejects_counts_larger_than_payload() {
+        let mut bytes = le_header(2);
+        bytes.extend(le_count(u32::MAX));
+
+        assert_eq!(parse_ewkb_hex(&hex(&bytes)), None);
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let mut bytes = le_header(1);
+        bytes.extend(le_coords(&[(1.0, 2.0)]));
+        bytes.push(0);
+
+        assert_eq!(parse_ewkb_hex(&hex(&bytes)), None);
+    }
+
+    #[test]
+    fn horizontal_line_plots_across_middle_row() {
+        let geometry = Geometry::LineString(vec![
+            Coord {
+                x: 0.0,
+                y: 0.0,
+                z: None,
+                m: None,
+            },
+            Coord {
+                x: 10.0,
+                y: 0.0,
+                z: None,
+                m: None,
+            },
+        ]);
+
+        let rows = render_braille(&geometry, 4, 3);
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], "\u{2800}".repeat(4));
+        assert!(rows[1].chars().all(|c| c != '\u{2800}'));
+        assert_eq!(rows[2], "\u{2800}".repeat(4));
+    }
+
+    #[test]
+    fn preview_text_lists_summary_plot_and_wkt() {
+        let value = parse_ewkb_hex("0101000020E6100000000000000000F03F0000000000000040").unwrap();
+
+        let text = geometry_preview_text(&value);
+
+        assert!(text.starts_with("POINT · SRID 4326 · 1 points\nbbox (1, 2) – (1, 2)\n"));
+        assert!(text.ends_with("\nPOINT(1 2)"));
+        assert_eq!(text.lines().count(), 2 + 1 + PLOT_HEIGHT + 1 + 1);
+    }
+
+    #[rstest::rstest]
+    #[case("geometry", true)]
+    #[case("geometry(Point,4326)", true)]
+    #[case("GEOGRAPHY", true)]
+    #[case("text", false)]
+    fn spatial_types_are_detected(#[case] data_type: &str, #[case] expected: bool) {
+        assert_eq!(is_spatial_type(data_type), expected);
+    }
+}
@@ -0,0 +1,122 @@
+use std::cmp::Reverse;
+
+use crate::domain::table_storage::size_label;
+use crate::domain::{IndexUsage, QueryResult, QuerySource};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndexUsageSort {
+    /// Fewest scans first, larger indexes breaking ties, so the biggest
+    /// never-used ones lead.
+    #[default]
+    Scans,
+    /// Largest first.
+    Size,
+}
+
+impl IndexUsageSort {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "" | "scans" => Some(Self::Scans),
+            "size" => Some(Self::Size),
+            _ => None,
+        }
+    }
+}
+
+/// Builds the `:indexes` table. Indexes backing a unique constraint are
+/// flagged apart, since they stay needed without ever being scanned.
+pub fn index_usage_report(indexes: &[IndexUsage], sort: IndexUsageSort) -> QueryResult {
+    let mut sorted: Vec<&IndexUsage> = indexes.iter().collect();
+    match sort {
+        IndexUsageSort::Scans => {
+            sorted.sort_by_key(|index| (index.scans, Reverse(index.size_bytes)));
+        }
+        IndexUsageSort::Size => sorted.sort_by_key(|index| Reverse(index.size_bytes)),
+    }
+
+    let columns = ["Index", "Table", "Scans", "Size", "Status"]
+        .map(str::to_string)
+        .to_vec();
+    let rows = sorted
+        .into_iter()
+        .map(|index| {
+            let status = if index.is_unused() {
+                "never used"
+            } else if index.scans == 0 {
+                "never used (unique)"
+            } else {
+                ""
+            };
+            vec![
+                index.name.clone(),
+                format!("{}.{}", index.schema, index.table),
+                index.scans.to_string(),
+                size_label(index.size_bytes),
+                status.to_string(),
+            ]
+        })
+        .collect();
+    let query = match sort {
+        IndexUsageSort::Scans => ":indexes",
+        IndexUsageSort::Size => ":indexes size",
+    };
+    QueryResult::success(query.to_string(), columns, rows, 0, QuerySource::Adhoc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn index(name: &str, scans: i64, size_bytes: i64, unique: bool) -> IndexUsage {
+        IndexUsage {
+            schema: "public".to_string(),
+            table: "orders".to_string(),
+            name: name.to_string(),
+            scans,
+            size_bytes,
+            unique,
+        }
+    }
+
+    #[rstest]
+    #[case::scans(IndexUsageSort::Scans, &["orders_pkey", "orders_note_idx", "orders_user_idx"])]
+    #[case::size(IndexUsageSort::Size, &["orders_user_idx", "orders_pkey", "orders_note_idx"])]
+    fn rows_follow_the_chosen_order(#[case] sort: IndexUsageSort, #[case] expected: &[&str]) {
+        let indexes = [
+            index("orders_user_idx", 120, 4 << 20, false),
+            index("orders_note_idx", 0, 16384, false),
+            index("orders_pkey", 0, 1 << 20, true),
+        ];
+
+        let report = index_usage_report(&indexes, sort);
+
+        let names: Vec<String> = (0..report.data_row_count())
+            .map(|row| report.display_value_at(row, 0).unwrap())
+            .collect();
+        assert_eq!(names, expected);
+    }
+
+    #[test]
+    fn never_used_indexes_are_flagged() {
+        let report = index_usage_report(
+            &[
+                index("orders_note_idx", 0, 16384, false),
+                index("orders_pkey", 0, 8192, true),
+            ],
+            IndexUsageSort::Size,
+        );
+
+        assert_eq!(
+            report.display_row_at(0).unwrap(),
+            vec![
+                "orders_note_idx",
+                "public.orders",
+                "0",
+                "16 kB",
+                "never used"
+            ]
+        );
+        assert_eq!(report.display_row_at(1).unwrap()[4], "never used (unique)");
+    }
+}
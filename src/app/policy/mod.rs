@@ -1,10 +1,27 @@
+pub mod cache_report;
+pub(crate) mod column_masking;
+pub mod duration_format;
+pub mod execution_policy;
+pub mod explorer_tree;
 pub mod feature_policy;
+pub mod geometry_preview;
+pub mod index_usage_report;
 pub mod json;
+pub mod number_format;
 pub(crate) mod password_masking;
 pub(crate) mod preview_cell_text;
+pub(crate) mod preview_columns;
+pub mod preview_explain;
+pub(crate) mod preview_sort;
+pub(crate) mod query_hooks;
+pub mod result_copy;
+pub mod server_info_report;
 pub mod sql;
 pub mod sqlite_path;
+pub mod startup_report;
 pub mod table_kind;
+pub mod usage_report;
+pub mod version_report;
 pub mod write;
 
 pub use feature_policy::{FeatureAvailability, FeaturePolicy, FeatureRequirement};
@@ -0,0 +1,213 @@
+const SCIENTIFIC_UPPER: f64 = 1e15;
+const SCIENTIFIC_LOWER: f64 = 1e-4;
+const MAX_FIXED_SCALE: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericStyle {
+    /// Thousand separators on the exact decimal text; only exponent input goes scientific.
+    General,
+    /// Thousand separators, switching to scientific notation for tiny/huge magnitudes.
+    Float,
+    /// Fixed decimals with thousand separators, used for money-like columns.
+    Fixed(u8),
+}
+
+impl NumericStyle {
+    pub fn for_data_type(data_type: Option<&str>) -> Self {
+        let Some(data_type) = data_type else {
+            return Self::General;
+        };
+        let lower = data_type.trim().to_ascii_lowercase();
+        if lower == "money" {
+            return Self::Fixed(2);
+        }
+        if lower == "real" || lower.starts_with("float") || lower.starts_with("double") {
+            return Self::Float;
+        }
+        lower
+            .strip_prefix("numeric")
+            .or_else(|| lower.strip_prefix("decimal"))
+            .and_then(|args| args.trim().strip_prefix('('))
+            .and_then(|args| args.strip_suffix(')'))
+            .and_then(|args| args.split_once(','))
+            .and_then(|(_, scale)| scale.trim().parse::<u8>().ok())
+            .filter(|scale| (1..=MAX_FIXED_SCALE).contains(scale))
+            .map_or(Self::General, Self::Fixed)
+    }
+}
+
+/// Returns `None` when `raw` is not a finite number, so callers fall back to the raw text.
+pub fn format_number(raw: &str, style: NumericStyle) -> Option<String> {
+    let trimmed = raw.trim().trim_start_matches('+');
+    let value = trimmed.parse::<f64>().ok().filter(|v| v.is_finite())?;
+    let is_plain = !trimmed.contains(['e', 'E']);
+
+    match style {
+        NumericStyle::Fixed(scale) => {
+            let plain = if is_plain {
+                trimmed.to_string()
+            } else {
+                value.to_string()
+            };
+            Some(group_thousands(&round_decimal(&plain, usize::from(scale))))
+        }
+        NumericStyle::General if is_plain => Some(group_thousands(trimmed)),
+        NumericStyle::General | NumericStyle::Float => {
+            let magnitude = value.abs();
+            if magnitude >= SCIENTIFIC_UPPER || (magnitude > 0.0 && magnitude < SCIENTIFIC_LOWER) {
+                Some(format!("{value:.3e}"))
+            } else if is_plain {
+                Some(group_thousands(trimmed))
+            } else {
+                Some(group_thousands(&value.to_string()))
+            }
+        }
+    }
+}
+
+/// Rounds a plain decimal string half away from zero without going through `f64`,
+/// so wide `numeric` values keep every digit.
+fn round_decimal(number: &str, scale: usize) -> String {
+    let (negative, unsigned) = number
+        .strip_prefix('-')
+        .map_or((false, number), |rest| (true, rest));
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let integer = if integer.is_empty() { "0" } else { integer };
+
+    let mut digits: Vec<u8> = integer.bytes().collect();
+    let fraction = fraction.as_bytes();
+    digits.extend((0..scale).map(|idx| fraction.get(idx).copied().unwrap_or(b'0')));
+    if fraction.get(scale).is_some_and(|digit| *digit >= b'5') {
+        let mut idx = digits.len();
+        loop {
+            if idx == 0 {
+                digits.insert(0, b'1');
+                break;
+            }
+            idx -= 1;
+            if digits[idx] == b'9' {
+                digits[idx] = b'0';
+            } else {
+                digits[idx] += 1;
+                break;
+            }
+        }
+    }
+
+    let split = digits.len() - scale;
+    let mut rounded = String::with_capacity(digits.len() + 2);
+    if negative && digits.iter().any(|digit| *digit != b'0') {
+        rounded.push('-');
+    }
+    rounded.push_str(&String::from_utf8_lossy(&digits[..split]));
+    if scale > 0 {
+        rounded.push('.');
+        rounded.push_str(&String::from_utf8_lossy(&digits[split..]));
+    }
+    rounded
+}
+
+fn group_thousands(number: &str) -> String {
+    let (sign, unsigned) = number
+        .strip_prefix('-')
+        .map_or(("", number), |rest| ("-", rest));
+    let (integer, fraction) = unsigned
+        .find('.')
+        .map_or((unsigned, ""), |idx| unsigned.split_at(idx));
+
+    let mut grouped = String::with_capacity(number.len() + integer.len() / 3);
+    grouped.push_str(sign);
+    for (idx, ch) in integer.chars().enumerate() {
+        if idx > 0 && (integer.len() - idx).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    grouped.push_str(fraction);
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("0", "0")]
+    #[case("999", "999")]
+    #[case("1000", "1,000")]
+    #[case("-1234567", "-1,234,567")]
+    #[case("+1234", "1,234")]
+    #[case("1234567.891", "1,234,567.891")]
+    #[case("1234567890123456", "1,234,567,890,123,456")]
+    #[case("-98765432109876543210", "-98,765,432,109,876,543,210")]
+    #[case("0.00001234", "0.00001234")]
+    #[case("2.5e3", "2,500")]
+    #[case("1.5e20", "1.500e20")]
+    fn general_groups_exact_decimal_text(#[case] raw: &str, #[case] expected: &str) {
+        assert_eq!(
+            format_number(raw, NumericStyle::General).as_deref(),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case("1234.5", "1,234.5")]
+    #[case("1234567890123456", "1.235e15")]
+    #[case("0.00001234", "1.234e-5")]
+    fn float_switches_to_scientific_for_extreme_magnitudes(
+        #[case] raw: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            format_number(raw, NumericStyle::Float).as_deref(),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case("1234", 2, "1,234.00")]
+    #[case("1234.5", 2, "1,234.50")]
+    #[case("1234.567", 2, "1,234.57")]
+    #[case("-0.1", 3, "-0.100")]
+    #[case("99.995", 2, "100.00")]
+    #[case("-0.001", 2, "0.00")]
+    #[case("12345678901234567.125", 2, "12,345,678,901,234,567.13")]
+    #[case("1.2345e3", 2, "1,234.50")]
+    fn fixed_pads_or_rounds_decimals(#[case] raw: &str, #[case] scale: u8, #[case] expected: &str) {
+        assert_eq!(
+            format_number(raw, NumericStyle::Fixed(scale)).as_deref(),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("NULL")]
+    #[case("abc")]
+    #[case("NaN")]
+    #[case("inf")]
+    #[case("$1,234.00")]
+    fn non_numeric_returns_none(#[case] raw: &str) {
+        assert_eq!(format_number(raw, NumericStyle::General), None);
+    }
+
+    #[rstest]
+    #[case(None, NumericStyle::General)]
+    #[case(Some("integer"), NumericStyle::General)]
+    #[case(Some("bigint"), NumericStyle::General)]
+    #[case(Some("real"), NumericStyle::Float)]
+    #[case(Some("double precision"), NumericStyle::Float)]
+    #[case(Some("FLOAT8"), NumericStyle::Float)]
+    #[case(Some("money"), NumericStyle::Fixed(2))]
+    #[case(Some("numeric(12,2)"), NumericStyle::Fixed(2))]
+    #[case(Some("DECIMAL(10, 4)"), NumericStyle::Fixed(4))]
+    #[case(Some("numeric(20,0)"), NumericStyle::General)]
+    #[case(Some("numeric(30,10)"), NumericStyle::General)]
+    fn style_detects_money_and_float_types(
+        #[case] data_type: Option<&str>,
+        #[case] expected: NumericStyle,
+    ) {
+        assert_eq!(NumericStyle::for_data_type(data_type), expected);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::domain::Table;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PreviewColumnsError {
+    #[error("Unknown column: {0}")]
+    UnknownColumn(String),
+}
+
+/// Resolves a `:columns` list, separated by commas or spaces, against `table`.
+/// Names match case-insensitively and are kept as the table spells them. The
+/// primary key always leads so preview rows stay editable and keep their
+/// order. A blank list or `*` selects every column again.
+pub fn parse_preview_columns(
+    table: &Table,
+    input: &str,
+) -> Result<Vec<String>, PreviewColumnsError> {
+    let names: Vec<&str> = input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+        .collect();
+    if names.is_empty() || names == ["*"] {
+        return Ok(Vec::new());
+    }
+
+    let mut columns: Vec<String> = table.primary_key.clone().unwrap_or_default();
+    for name in names {
+        let column = table
+            .columns
+            .iter()
+            .find(|column| column.name == name)
+            .or_else(|| {
+                table
+                    .columns
+                    .iter()
+                    .find(|column| column.name.eq_ignore_ascii_case(name))
+            })
+            .ok_or_else(|| PreviewColumnsError::UnknownColumn(name.to_string()))?;
+        if !columns.contains(&column.name) {
+            columns.push(column.name.clone());
+        }
+    }
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use crate::test_support::column::test_nullable_column;
+    use rstest::rstest;
+
+    fn orders() -> Table {
+        Table {
+            columns: vec![
+                test_nullable_column("id", "integer", 1),
+                test_nullable_column("status", "text", 2),
+                test_nullable_column("Payload", "jsonb", 3),
+                test_nullable_column("note", "text", 4),
+            ],
+            primary_key: Some(vec!["id".to_string()]),
+            ..test_support::table::minimal("public", "orders")
+        }
+    }
+
+    #[rstest]
+    #[case("status, note", &["id", "status", "note"])]
+    #[case("note status id", &["id", "note", "status"])]
+    #[case("payload,status,status", &["id", "Payload", "status"])]
+    #[case("  ", &[])]
+    #[case("*", &[])]
+    fn resolves_names_with_the_key_first(#[case] input: &str, #[case] expected: &[&str]) {
+        assert_eq!(parse_preview_columns(&orders(), input).unwrap(), expected);
+    }
+
+    #[test]
+    fn unknown_column_is_rejected() {
+        assert_eq!(
+            parse_preview_columns(&orders(), "status, total"),
+            Err(PreviewColumnsError::UnknownColumn("total".to_string()))
+        );
+    }
+}
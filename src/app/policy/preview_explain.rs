@@ -0,0 +1,117 @@
+use crate::domain::explain_plan::parse_explain_text;
+use crate::domain::{QueryResult, QuerySource};
+
+/// Builds the `X` report for a table preview.
+///
+/// Shows the statement the preview actually ran, with the ORDER BY and LIMIT
+/// the executor added, its timing, and the plan one line per row.
+pub fn preview_explain_report(
+    preview: &QueryResult,
+    explain_query: &str,
+    plan_text: &str,
+    plan_time_ms: u64,
+) -> QueryResult {
+    let columns = ["Item", "Detail"].map(str::to_string).to_vec();
+    let row = |item: &str, detail: String| vec![item.to_string(), detail];
+
+    let mut rows = vec![
+        row(
+            "Query",
+            preview
+                .query
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        row("Rows", preview.data_row_count().to_string()),
+        row("Fetch time", format!("{} ms", preview.execution_time_ms)),
+        row("Plan time", format!("{plan_time_ms} ms")),
+    ];
+    if let Some(summary) = plan_summary(plan_text) {
+        rows.push(row("Summary", summary));
+    }
+    for (i, line) in plan_text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+    {
+        let item = if i == 0 { "Plan" } else { "" };
+        rows.push(row(item, line.trim_end().to_string()));
+    }
+
+    QueryResult::success(
+        explain_query.to_string(),
+        columns,
+        rows,
+        plan_time_ms,
+        QuerySource::Adhoc,
+    )
+}
+
+// SQLite's query plan has no costs, so there is nothing to summarise.
+fn plan_summary(plan_text: &str) -> Option<String> {
+    let plan = parse_explain_text(plan_text, false, 0);
+    let node = plan.top_node_type?;
+    let mut parts = vec![node];
+    if let Some(cost) = plan.total_cost {
+        parts.push(format!("cost {cost:.2}"));
+    }
+    if let Some(rows) = plan.estimated_rows {
+        parts.push(format!("~{rows} rows"));
+    }
+    Some(parts.join(" · "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preview() -> QueryResult {
+        QueryResult::success(
+            "SELECT *\nFROM \"public\".\"users\"\nORDER BY \"id\"\nLIMIT 100 OFFSET 0".to_string(),
+            vec!["id".to_string()],
+            vec![vec!["1".to_string()], vec!["2".to_string()]],
+            12,
+            QuerySource::Preview,
+        )
+    }
+
+    #[test]
+    fn report_shows_executed_query_timing_and_plan() {
+        let plan = "Limit  (cost=0.15..4.20 rows=100 width=40)\n  ->  Index Scan using users_pkey on users  (cost=0.15..60.00 rows=1000 width=40)";
+
+        let report = preview_explain_report(&preview(), "EXPLAIN SELECT", plan, 3);
+
+        assert_eq!(report.columns, vec!["Item", "Detail"]);
+        assert_eq!(
+            report.display_row_at(0).unwrap(),
+            vec![
+                "Query",
+                "SELECT * FROM \"public\".\"users\" ORDER BY \"id\" LIMIT 100 OFFSET 0"
+            ]
+        );
+        assert_eq!(report.display_row_at(1).unwrap(), vec!["Rows", "2"]);
+        assert_eq!(
+            report.display_row_at(2).unwrap(),
+            vec!["Fetch time", "12 ms"]
+        );
+        assert_eq!(report.display_row_at(3).unwrap(), vec!["Plan time", "3 ms"]);
+        assert_eq!(
+            report.display_row_at(4).unwrap(),
+            vec!["Summary", "Limit · cost 4.20 · ~100 rows"]
+        );
+        assert_eq!(report.display_row_at(5).unwrap()[0], "Plan");
+        assert_eq!(report.display_row_at(6).unwrap()[0], "");
+    }
+
+    #[test]
+    fn plan_without_costs_has_no_summary() {
+        let report =
+            preview_explain_report(&preview(), "EXPLAIN QUERY PLAN SELECT", "SCAN users", 1);
+
+        assert_eq!(
+            report.display_row_at(4).unwrap(),
+            vec!["Plan", "SCAN users"]
+        );
+    }
+}
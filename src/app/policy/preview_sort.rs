@@ -0,0 +1,109 @@
+use crate::domain::{IndexType, Table};
+
+/// Leading columns of the table's plain B-tree indexes, each listed once.
+/// Ordering a preview by one of them lets the server walk the index instead
+/// of sorting. The primary key's leading column is left out: it is the
+/// default order. SQLite reports no index type, and all of its indexes are
+/// B-trees.
+pub fn sortable_columns(table: &Table) -> Vec<&str> {
+    let default_leader = table
+        .primary_key
+        .as_ref()
+        .and_then(|columns| columns.first())
+        .map(String::as_str);
+    let mut columns: Vec<&str> = Vec::new();
+    for index in &table.indexes {
+        let ordered = matches!(index.index_type, IndexType::BTree | IndexType::Unknown);
+        if !ordered || index.is_partial() || index.has_expression() {
+            continue;
+        }
+        let Some(leader) = index.columns.first().map(String::as_str) else {
+            continue;
+        };
+        if Some(leader) != default_leader && !columns.contains(&leader) {
+            columns.push(leader);
+        }
+    }
+    columns
+}
+
+/// The column after `current` in the `o` cycle; `None` goes back to the
+/// default order after the last one.
+pub fn next_sort_column(table: &Table, current: Option<&str>) -> Option<String> {
+    let columns = sortable_columns(table);
+    let next = match current.and_then(|current| columns.iter().position(|c| *c == current)) {
+        Some(position) => columns.get(position + 1),
+        None => columns.first(),
+    };
+    next.map(|column| (*column).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Index, IndexAttributes};
+    use crate::test_support;
+
+    fn index(name: &str, columns: &[&str], attributes: IndexAttributes) -> Index {
+        Index {
+            name: name.to_string(),
+            columns: columns.iter().map(ToString::to_string).collect(),
+            attributes,
+            index_type: IndexType::BTree,
+            definition: None,
+        }
+    }
+
+    fn users() -> Table {
+        Table {
+            primary_key: Some(vec!["id".to_string()]),
+            indexes: vec![
+                index("users_pkey", &["id"], IndexAttributes::PRIMARY),
+                index("users_email_key", &["email"], IndexAttributes::UNIQUE),
+                index(
+                    "users_created",
+                    &["created_at", "id"],
+                    IndexAttributes::empty(),
+                ),
+                index("users_active", &["status"], IndexAttributes::PARTIAL),
+                index("users_lower", &["email"], IndexAttributes::EXPRESSION),
+                index(
+                    "users_email_name",
+                    &["email", "name"],
+                    IndexAttributes::empty(),
+                ),
+                Index {
+                    index_type: IndexType::Gin,
+                    ..index("users_tags", &["tags"], IndexAttributes::empty())
+                },
+            ],
+            ..test_support::table::minimal("public", "users")
+        }
+    }
+
+    #[test]
+    fn lists_plain_index_leaders_once_without_the_primary_key() {
+        assert_eq!(sortable_columns(&users()), ["email", "created_at"]);
+    }
+
+    #[test]
+    fn cycles_through_columns_and_back_to_default() {
+        let table = users();
+
+        let first = next_sort_column(&table, None);
+        let second = next_sort_column(&table, first.as_deref());
+        let back = next_sort_column(&table, second.as_deref());
+
+        assert_eq!(first.as_deref(), Some("email"));
+        assert_eq!(second.as_deref(), Some("created_at"));
+        assert_eq!(back, None);
+    }
+
+    #[test]
+    fn unknown_current_column_restarts_the_cycle() {
+        assert_eq!(
+            next_sort_column(&users(), Some("dropped")).as_deref(),
+            Some("email")
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use crate::ports::outbound::{HookEvent, HookInvocation, QueryHook};
+
+#[derive(Debug, Clone, Copy)]
+pub struct HookContext<'a> {
+    pub event: HookEvent,
+    pub connection: &'a str,
+    pub query: &'a str,
+    pub duration_ms: Option<u64>,
+    pub rows: Option<usize>,
+    pub error: Option<&'a str>,
+}
+
+impl HookContext<'_> {
+    fn variables(&self) -> [(&'static str, String); 6] {
+        [
+            ("event", self.event.config_value().to_string()),
+            ("connection", self.connection.to_string()),
+            ("query", self.query.to_string()),
+            (
+                "duration_ms",
+                self.duration_ms
+                    .map(|ms| ms.to_string())
+                    .unwrap_or_default(),
+            ),
+            ("rows", self.rows.map(|n| n.to_string()).unwrap_or_default()),
+            ("error", self.error.unwrap_or_default().to_string()),
+        ]
+    }
+}
+
+pub fn invocations_for(hooks: &[QueryHook], context: &HookContext<'_>) -> Vec<HookInvocation> {
+    hooks
+        .iter()
+        .filter(|hook| hook.event == context.event)
+        .filter(|hook| {
+            hook.event != HookEvent::SlowQuery
+                || context
+                    .duration_ms
+                    .is_some_and(|ms| ms >= hook.min_duration_ms)
+        })
+        .map(|hook| HookInvocation {
+            target: hook.target.clone(),
+            payload: render_payload(hook.payload_template.as_deref(), context),
+            env: hook_env(context),
+        })
+        .collect()
+}
+
+/// Values are JSON-escaped so templates can embed them inside JSON strings.
+fn render_payload(template: Option<&str>, context: &HookContext<'_>) -> String {
+    let variables = context.variables();
+    let Some(template) = template else {
+        let object: serde_json::Map<String, serde_json::Value> = variables
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(name, value)| (name.to_string(), serde_json::Value::String(value)))
+            .collect();
+        return serde_json::Value::Object(object).to_string();
+    };
+
+    let mut rendered = template.to_string();
+    for (name, value) in variables {
+        rendered = rendered.replace(&format!("{{{{{name}}}}}"), &json_escape(&value));
+    }
+    rendered
+}
+
+fn json_escape(value: &str) -> String {
+    let quoted = serde_json::Value::String(value.to_string()).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+fn hook_env(context: &HookContext<'_>) -> Vec<(String, String)> {
+    context
+        .variables()
+        .into_iter()
+        .map(|(name, value)| (format!("SABIQL_{}", name.to_ascii_uppercase()), value))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::outbound::HookTarget;
+    use rstest::rstest;
+
+    fn hook(event: HookEvent, min_duration_ms: u64, template: Option<&str>) -> QueryHook {
+        QueryHook {
+            event,
+            min_duration_ms,
+            target: HookTarget::Command("notify".to_string()),
+            payload_template: template.map(str::to_string),
+        }
+    }
+
+    fn slow_context(duration_ms: u64) -> HookContext<'static> {
+        HookContext {
+            event: HookEvent::SlowQuery,
+            connection: "prod",
+            query: "SELECT \"id\"\nFROM users",
+            duration_ms: Some(duration_ms),
+            rows: Some(3),
+            error: None,
+        }
+    }
+
+    #[rstest]
+    #[case(29_999, 0)]
+    #[case(30_000, 1)]
+    fn slow_query_hook_respects_threshold(#[case] duration_ms: u64, #[case] expected: usize) {
+        let hooks = [hook(HookEvent::SlowQuery, 30_000, None)];
+
+        assert_eq!(
+            invocations_for(&hooks, &slow_context(duration_ms)).len(),
+            expected
+        );
+    }
+
+    #[test]
+    fn hooks_for_other_events_are_skipped() {
+        let hooks = [
+            hook(HookEvent::QueryError, 0, None),
+            hook(HookEvent::WriteExecuted, 0, None),
+        ];
+
+        assert!(invocations_for(&hooks, &slow_context(60_000)).is_empty());
+    }
+
+    #[test]
+    fn template_values_are_json_escaped() {
+        let hooks = [hook(
+            HookEvent::SlowQuery,
+            0,
+            Some(r#"{"text": "{{connection}}: {{query}} took {{duration_ms}}ms"}"#),
+        )];
+
+        let invocations = invocations_for(&hooks, &slow_context(1200));
+
+        assert_eq!(
+            invocations[0].payload,
+            r#"{"text": "prod: SELECT \"id\"\nFROM users took 1200ms"}"#
+        );
+    }
+
+    #[test]
+    fn default_payload_is_json_without_empty_fields() {
+        let invocations = invocations_for(&[hook(HookEvent::SlowQuery, 0, None)], &slow_context(5));
+
+        let payload: serde_json::Value = serde_json::from_str(&invocations[0].payload).unwrap();
+        assert_eq!(payload["event"], "slow_query");
+        assert_eq!(payload["rows"], "3");
+        assert!(payload.get("error").is_none());
+    }
+
+    #[test]
+    fn env_exposes_raw_values() {
+        let invocations = invocations_for(&[hook(HookEvent::SlowQuery, 0, None)], &slow_context(5));
+
+        assert!(invocations[0].env.contains(&(
+            "SABIQL_QUERY".to_string(),
+            "SELECT \"id\"\nFROM users".to_string()
+        )));
+    }
+}
@@ -0,0 +1,368 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::domain::{QueryResult, QueryValue};
+
+/// Above this size the user confirms before the clipboard is touched.
+pub const CLIPBOARD_WARN_BYTES: usize = 1024 * 1024;
+/// Above this size the payload is written to a temp file and only its path is copied.
+pub const CLIPBOARD_SAFE_BYTES: usize = 8 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultCopyFormat {
+    Csv,
+    Tsv,
+    Markdown,
+    Org,
+}
+
+impl ResultCopyFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "tsv" => Some(Self::Tsv),
+            "md" | "markdown" => Some(Self::Markdown),
+            "org" => Some(Self::Org),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Tsv => "TSV",
+            Self::Markdown => "Markdown",
+            Self::Org => "Org",
+        }
+    }
+
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+            Self::Markdown => "md",
+            Self::Org => "org",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultCopyPlan {
+    Clipboard,
+    ConfirmLarge,
+    SpillToFile,
+}
+
+pub fn result_copy_plan(byte_len: usize) -> ResultCopyPlan {
+    if byte_len > CLIPBOARD_SAFE_BYTES {
+        ResultCopyPlan::SpillToFile
+    } else if byte_len > CLIPBOARD_WARN_BYTES {
+        ResultCopyPlan::ConfirmLarge
+    } else {
+        ResultCopyPlan::Clipboard
+    }
+}
+
+pub fn format_byte_size(bytes: usize) -> String {
+    const KIB: f64 = 1024.0;
+    const MIB: f64 = KIB * 1024.0;
+    let bytes_f = bytes as f64;
+    if bytes_f >= MIB {
+        format!("{:.1} MB", bytes_f / MIB)
+    } else if bytes_f >= KIB {
+        format!("{:.1} KB", bytes_f / KIB)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+pub fn render_result_copy(result: &QueryResult, format: ResultCopyFormat) -> String {
+    render_result_rows(result, format, 0..result.data_row_count())
+}
+
+/// Renders the header and the given rows in order; indexes past the result
+/// are skipped.
+pub fn render_result_rows(
+    result: &QueryResult,
+    format: ResultCopyFormat,
+    rows: impl IntoIterator<Item = usize>,
+) -> String {
+    let values = result.values();
+    let rows: Vec<&Vec<QueryValue>> = rows.into_iter().filter_map(|i| values.get(i)).collect();
+    let mut out = String::new();
+    match format {
+        ResultCopyFormat::Csv => {
+            push_line(
+                &mut out,
+                result.columns.iter().map(String::as_str).map(csv_field),
+                ",",
+            );
+            for row in rows {
+                push_line(&mut out, row.iter().map(csv_value), ",");
+            }
+        }
+        ResultCopyFormat::Tsv => {
+            push_line(
+                &mut out,
+                result.columns.iter().map(String::as_str).map(tsv_field),
+                "\t",
+            );
+            for row in rows {
+                push_line(
+                    &mut out,
+                    row.iter().map(|v| tsv_field(&v.copy_value())),
+                    "\t",
+                );
+            }
+        }
+        ResultCopyFormat::Markdown | ResultCopyFormat::Org => {
+            let escape = if format == ResultCopyFormat::Org {
+                org_field
+            } else {
+                markdown_field
+            };
+            let header: Vec<String> = result
+                .columns
+                .iter()
+                .map(String::as_str)
+                .map(escape)
+                .collect();
+            let body: Vec<Vec<String>> = rows
+                .iter()
+                .map(|row| row.iter().map(|v| escape(&v.copy_value())).collect())
+                .collect();
+            push_table(&mut out, &header, &body, format);
+        }
+    }
+    out
+}
+
+fn push_line(out: &mut String, fields: impl Iterator<Item = String>, separator: &str) {
+    let line = fields.collect::<Vec<_>>().join(separator);
+    out.push_str(&line);
+    out.push('\n');
+}
+
+fn push_table(out: &mut String, header: &[String], body: &[Vec<String>], format: ResultCopyFormat) {
+    // Markdown separators need at least three dashes to be recognized
+    let min_width = if format == ResultCopyFormat::Markdown {
+        3
+    } else {
+        1
+    };
+    let widths: Vec<usize> = (0..header.len())
+        .map(|col| {
+            std::iter::once(&header[col])
+                .chain(body.iter().filter_map(|row| row.get(col)))
+                .map(String::as_str)
+                .map(UnicodeWidthStr::width)
+                .max()
+                .unwrap_or(0)
+                .max(min_width)
+        })
+        .collect();
+
+    push_table_row(out, header, &widths);
+    let junction = if format == ResultCopyFormat::Org {
+        "+"
+    } else {
+        "|"
+    };
+    let dashes: Vec<String> = widths.iter().map(|w| "-".repeat(w + 2)).collect();
+    out.push('|');
+    out.push_str(&dashes.join(junction));
+    out.push_str("|\n");
+    for row in body {
+        push_table_row(out, row, &widths);
+    }
+}
+
+fn push_table_row(out: &mut String, fields: &[String], widths: &[usize]) {
+    out.push('|');
+    for (field, width) in fields.iter().zip(widths) {
+        out.push(' ');
+        out.push_str(field);
+        out.push_str(&" ".repeat(width.saturating_sub(UnicodeWidthStr::width(field.as_str()))));
+        out.push_str(" |");
+    }
+    out.push('\n');
+}
+
+fn csv_value(value: &QueryValue) -> String {
+    match value {
+        QueryValue::Null => String::new(),
+        other => csv_field(&other.copy_value()),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn tsv_field(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn markdown_field(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+fn org_field(value: &str) -> String {
+    value
+        .replace('|', "\\vert{}")
+        .replace("\r\n", " ")
+        .replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::QuerySource;
+    use rstest::rstest;
+
+    fn sample_result() -> QueryResult {
+        QueryResult::success_with_values(
+            "SELECT 1".to_string(),
+            vec!["id".to_string(), "note".to_string()],
+            vec![
+                vec![QueryValue::text("1"), QueryValue::text("a,\"b\"")],
+                vec![QueryValue::text("2"), QueryValue::Null],
+            ],
+            1,
+            QuerySource::Adhoc,
+        )
+    }
+
+    #[test]
+    fn csv_quotes_special_fields_and_blanks_null() {
+        assert_eq!(
+            render_result_copy(&sample_result(), ResultCopyFormat::Csv),
+            "id,note\n1,\"a,\"\"b\"\"\"\n2,\n"
+        );
+    }
+
+    #[test]
+    fn tsv_keeps_null_literal_and_escapes_control_chars() {
+        let result = QueryResult::success(
+            String::new(),
+            vec!["v".to_string()],
+            vec![vec!["a\tb\nc".to_string()]],
+            1,
+            QuerySource::Preview,
+        );
+
+        assert_eq!(
+            render_result_copy(&result, ResultCopyFormat::Tsv),
+            "v\na\\tb\\nc\n"
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_pipes_and_newlines() {
+        let result = QueryResult::success(
+            String::new(),
+            vec!["v".to_string()],
+            vec![vec!["a|b\nc".to_string()]],
+            1,
+            QuerySource::Preview,
+        );
+
+        assert_eq!(
+            render_result_copy(&result, ResultCopyFormat::Markdown),
+            "| v         |\n|-----------|\n| a\\|b<br>c |\n"
+        );
+    }
+
+    #[test]
+    fn markdown_pads_columns_by_display_width() {
+        let result = QueryResult::success(
+            String::new(),
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                vec!["1".to_string(), "日本".to_string()],
+                vec!["22".to_string(), "a".to_string()],
+            ],
+            1,
+            QuerySource::Preview,
+        );
+
+        assert_eq!(
+            render_result_copy(&result, ResultCopyFormat::Markdown),
+            "| id  | name |\n|-----|------|\n| 1   | 日本 |\n| 22  | a    |\n"
+        );
+    }
+
+    #[test]
+    fn org_uses_plus_junctions_and_escapes_pipes() {
+        let result = QueryResult::success(
+            String::new(),
+            vec!["id".to_string(), "v".to_string()],
+            vec![vec!["1".to_string(), "a|b\nc".to_string()]],
+            1,
+            QuerySource::Preview,
+        );
+
+        assert_eq!(
+            render_result_copy(&result, ResultCopyFormat::Org),
+            "| id | v           |\n|----+-------------|\n| 1  | a\\vert{}b c |\n"
+        );
+    }
+
+    #[test]
+    fn row_range_limits_output_and_clamps_to_result() {
+        assert_eq!(
+            render_result_rows(&sample_result(), ResultCopyFormat::Tsv, 1..10),
+            "id\tnote\n2\tNULL\n"
+        );
+    }
+
+    #[test]
+    fn picked_rows_keep_their_order_and_skip_missing_ones() {
+        assert_eq!(
+            render_result_rows(&sample_result(), ResultCopyFormat::Csv, [1, 5, 0]),
+            "id,note\n2,\n1,\"a,\"\"b\"\"\"\n"
+        );
+    }
+
+    #[rstest]
+    #[case(0, ResultCopyPlan::Clipboard)]
+    #[case(CLIPBOARD_WARN_BYTES, ResultCopyPlan::Clipboard)]
+    #[case(CLIPBOARD_WARN_BYTES + 1, ResultCopyPlan::ConfirmLarge)]
+    #[case(CLIPBOARD_SAFE_BYTES, ResultCopyPlan::ConfirmLarge)]
+    #[case(CLIPBOARD_SAFE_BYTES + 1, ResultCopyPlan::SpillToFile)]
+    fn plan_follows_size_thresholds(#[case] bytes: usize, #[case] expected: ResultCopyPlan) {
+        assert_eq!(result_copy_plan(bytes), expected);
+    }
+
+    #[rstest]
+    #[case("csv", Some(ResultCopyFormat::Csv))]
+    #[case("TSV", Some(ResultCopyFormat::Tsv))]
+    #[case("md", Some(ResultCopyFormat::Markdown))]
+    #[case("markdown", Some(ResultCopyFormat::Markdown))]
+    #[case("org", Some(ResultCopyFormat::Org))]
+    #[case("xml", None)]
+    fn parse_accepts_known_formats(
+        #[case] input: &str,
+        #[case] expected: Option<ResultCopyFormat>,
+    ) {
+        assert_eq!(ResultCopyFormat::parse(input), expected);
+    }
+
+    #[rstest]
+    #[case(512, "512 B")]
+    #[case(2048, "2.0 KB")]
+    #[case(3 * 1024 * 1024, "3.0 MB")]
+    fn byte_size_is_humanized(#[case] bytes: usize, #[case] expected: &str) {
+        assert_eq!(format_byte_size(bytes), expected);
+    }
+}
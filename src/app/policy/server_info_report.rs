@@ -0,0 +1,77 @@
+use crate::domain::{PoolSettings, QueryResult, QuerySource};
+use crate::ports::outbound::PoolStats;
+
+/// Builds the `:server info` table: one row per endpoint of the active
+/// profile, with its pooled connections or the CLI fallback that serves it.
+pub fn server_info_report(
+    pool: Option<PoolSettings>,
+    endpoints: &[(&str, Option<PoolStats>)],
+) -> QueryResult {
+    let columns = [
+        "Endpoint",
+        "Driver",
+        "In use",
+        "Idle",
+        "Pool size",
+        "Warmup",
+    ]
+    .map(str::to_string)
+    .to_vec();
+    let warmup = pool.map_or_else(|| "-".to_string(), |pool| pool.warmup.to_string());
+    let rows = endpoints
+        .iter()
+        .map(|(endpoint, stats)| match stats {
+            Some(stats) => vec![
+                (*endpoint).to_string(),
+                "native".to_string(),
+                stats.in_use.to_string(),
+                stats.idle.to_string(),
+                stats.size.to_string(),
+                warmup.clone(),
+            ],
+            None => vec![
+                (*endpoint).to_string(),
+                "cli".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+            ],
+        })
+        .collect();
+    QueryResult::success(
+        ":server info".to_string(),
+        columns,
+        rows,
+        0,
+        QuerySource::Adhoc,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pooled_endpoints_show_their_counts() {
+        let stats = PoolStats {
+            in_use: 1,
+            idle: 2,
+            size: 8,
+        };
+
+        let report = server_info_report(
+            Some(PoolSettings::new(Some(8), Some(2))),
+            &[("primary", Some(stats)), ("replica", None)],
+        );
+
+        assert_eq!(
+            report.display_row_at(0).unwrap(),
+            vec!["primary", "native", "1", "2", "8", "2"]
+        );
+        assert_eq!(
+            report.display_row_at(1).unwrap(),
+            vec!["replica", "cli", "-", "-", "-", "-"]
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use super::lexer::{SqlLexer, Token, TokenKind};
+
+/// An alias edit in a FROM/JOIN clause whose old `alias.` references can be
+/// rewritten.
+///
+/// `from` stays the alias before the first keystroke, so typing a
+/// longer name one char at a time still renames the original references.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingAliasRename {
+    table_index: usize,
+    pub from: String,
+    pub to: Option<String>,
+}
+
+impl PendingAliasRename {
+    /// The new alias and the old qualifiers to rewrite, while the buffer
+    /// still carries the edited alias and at least one old reference.
+    pub fn targets(&self, tokens: &[Token]) -> Option<(&str, Vec<(usize, usize)>)> {
+        let to = self.to.as_deref()?;
+        let current = table_aliases(tokens);
+        if current.get(self.table_index)?.as_deref() != Some(to) {
+            return None;
+        }
+        let references = alias_references(tokens, &self.from);
+        (!references.is_empty()).then_some((to, references))
+    }
+}
+
+/// Aliases of every table reference in the buffer, in order.
+pub fn table_aliases(tokens: &[Token]) -> Vec<Option<String>> {
+    SqlLexer::new()
+        .extract_table_references(tokens)
+        .into_iter()
+        .map(|t| t.alias)
+        .collect()
+}
+
+/// Follows one buffer edit. Only an edit that changes a single alias and
+/// leaves the table references otherwise intact keeps an offer alive.
+pub fn track_alias_edit(
+    before: &[Option<String>],
+    after: &[Option<String>],
+    pending: Option<PendingAliasRename>,
+) -> Option<PendingAliasRename> {
+    if before.len() != after.len() {
+        return None;
+    }
+    let mut changed = (0..after.len()).filter(|&i| before[i] != after[i]);
+    let Some(index) = changed.next() else {
+        return pending;
+    };
+    if changed.next().is_some() {
+        return None;
+    }
+
+    let from = match pending {
+        Some(p) if p.table_index == index => p.from,
+        _ => before[index].clone()?,
+    };
+    let to = after[index].clone();
+    if to.as_ref().is_some_and(|to| to.eq_ignore_ascii_case(&from)) {
+        return None;
+    }
+    Some(PendingAliasRename {
+        table_index: index,
+        from,
+        to,
+    })
+}
+
+/// Char ranges of identifiers used as `alias.` qualifiers.
+pub fn alias_references(tokens: &[Token], alias: &str) -> Vec<(usize, usize)> {
+    tokens
+        .windows(2)
+        .filter(|pair| {
+            matches!(&pair[0].kind, TokenKind::Identifier(name) if name.eq_ignore_ascii_case(alias))
+                && pair[1].kind == TokenKind::Punctuation('.')
+        })
+        .map(|pair| (pair[0].start, pair[0].end))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn aliases(sql: &str) -> Vec<Option<String>> {
+        table_aliases(&SqlLexer::new().tokenize(sql, sql.len()))
+    }
+
+    fn track(steps: &[&str]) -> Option<PendingAliasRename> {
+        steps.windows(2).fold(None, |pending, pair| {
+            track_alias_edit(&aliases(pair[0]), &aliases(pair[1]), pending)
+        })
+    }
+
+    #[test]
+    fn typing_a_new_alias_keeps_the_original() {
+        let pending = track(&[
+            "SELECT u.id FROM users u",
+            "SELECT u.id FROM users us",
+            "SELECT u.id FROM users usr",
+        ])
+        .unwrap();
+
+        assert_eq!(pending.from, "u");
+        assert_eq!(pending.to.as_deref(), Some("usr"));
+    }
+
+    #[test]
+    fn retyping_via_an_empty_alias_keeps_the_original() {
+        let pending = track(&[
+            "SELECT u.id FROM users u WHERE u.id = 1",
+            "SELECT u.id FROM users  WHERE u.id = 1",
+            "SELECT u.id FROM users p WHERE u.id = 1",
+        ])
+        .unwrap();
+
+        assert_eq!(pending.from, "u");
+        assert_eq!(pending.to.as_deref(), Some("p"));
+    }
+
+    #[rstest]
+    #[case::restored(&["FROM users u", "FROM users us", "FROM users u"])]
+    #[case::first_alias(&["FROM users", "FROM users u"])]
+    #[case::table_added(&["FROM users u", "FROM users u JOIN orders o"])]
+    #[case::unrelated_edit(&["FROM users u", "FROM users u WHERE"])]
+    fn no_offer(#[case] steps: &[&str]) {
+        assert_eq!(track(steps), None);
+    }
+
+    #[test]
+    fn unrelated_edits_keep_a_pending_offer() {
+        let pending = track(&["FROM users u", "FROM users p", "FROM users p WHERE"]);
+
+        assert_eq!(pending.map(|p| p.from), Some("u".to_string()));
+    }
+
+    #[test]
+    fn targets_require_the_edited_alias_and_old_references() {
+        let pending = track(&["SELECT u.id FROM users u", "SELECT u.id FROM users p"]).unwrap();
+        let targets = |sql: &str| {
+            let tokens = SqlLexer::new().tokenize(sql, sql.len());
+            pending
+                .targets(&tokens)
+                .map(|(to, refs)| (to.to_string(), refs))
+        };
+
+        assert_eq!(
+            targets("SELECT u.id FROM users p"),
+            Some(("p".to_string(), vec![(7, 8)]))
+        );
+        assert_eq!(targets("SELECT p.id FROM users p"), None);
+        assert_eq!(targets("SELECT u.id FROM users q"), None);
+    }
+
+    #[test]
+    fn references_are_qualifiers_only() {
+        let sql = "SELECT u.id, U.name, u FROM users u WHERE u.id = menu.id";
+        let tokens = SqlLexer::new().tokenize(sql, sql.len());
+
+        let refs: Vec<_> = alias_references(&tokens, "u")
+            .into_iter()
+            .map(|(start, end)| &sql[start..end])
+            .collect();
+
+        assert_eq!(refs, ["u", "U", "u"]);
+    }
+}
@@ -0,0 +1,233 @@
+use FunctionRole::{Aggregate, Predicate, Scalar};
+
+/// Where a function is most useful, for ranking completion candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FunctionRole {
+    /// Collapses rows; preferred in a select list.
+    Aggregate,
+    /// Returns `boolean`; preferred in a `WHERE` clause.
+    Predicate,
+    Scalar,
+}
+
+impl FunctionRole {
+    /// Role of a function that is not an aggregate, read off its return type.
+    pub fn for_result(result: &str) -> Self {
+        if result.trim().eq_ignore_ascii_case("boolean") {
+            Self::Predicate
+        } else {
+            Self::Scalar
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuiltinFunction {
+    pub name: &'static str,
+    pub arguments: &'static str,
+    pub result: &'static str,
+    pub role: FunctionRole,
+}
+
+const fn function(
+    name: &'static str,
+    arguments: &'static str,
+    result: &'static str,
+    role: FunctionRole,
+) -> BuiltinFunction {
+    BuiltinFunction {
+        name,
+        arguments,
+        result,
+        role,
+    }
+}
+
+/// Commonly used PostgreSQL built-ins. Overloads are folded into one
+/// signature written the way the manual summarizes them.
+pub const POSTGRES_FUNCTIONS: &[BuiltinFunction] = &[
+    // Aggregates
+    function("count", "* | expression", "bigint", Aggregate),
+    function("sum", "expression", "numeric", Aggregate),
+    function("avg", "expression", "numeric", Aggregate),
+    function("min", "expression", "same as input", Aggregate),
+    function("max", "expression", "same as input", Aggregate),
+    function("array_agg", "expression", "array", Aggregate),
+    function(
+        "string_agg",
+        "value text, delimiter text",
+        "text",
+        Aggregate,
+    ),
+    function("json_agg", "expression", "json", Aggregate),
+    function("jsonb_agg", "expression", "jsonb", Aggregate),
+    function("jsonb_object_agg", "key, value", "jsonb", Aggregate),
+    function("bool_and", "boolean", "boolean", Aggregate),
+    function("bool_or", "boolean", "boolean", Aggregate),
+    function(
+        "percentile_cont",
+        "fraction) WITHIN GROUP (ORDER BY expression",
+        "double precision",
+        Aggregate,
+    ),
+    // Conditional
+    function("coalesce", "value, ...", "same as input", Scalar),
+    function("nullif", "value1, value2", "same as input", Scalar),
+    function("greatest", "value, ...", "same as input", Scalar),
+    function("least", "value, ...", "same as input", Scalar),
+    // Strings
+    function("lower", "text", "text", Scalar),
+    function("upper", "text", "text", Scalar),
+    function("length", "text", "integer", Scalar),
+    function("concat", "value, ...", "text", Scalar),
+    function("concat_ws", "separator text, value, ...", "text", Scalar),
+    function("substring", "text FROM start FOR count", "text", Scalar),
+    function("trim", "[BOTH] characters FROM text", "text", Scalar),
+    function("replace", "text, from text, to text", "text", Scalar),
+    function(
+        "split_part",
+        "text, delimiter text, n integer",
+        "text",
+        Scalar,
+    ),
+    function("left", "text, n integer", "text", Scalar),
+    function("right", "text, n integer", "text", Scalar),
+    function("format", "formatstr text, value, ...", "text", Scalar),
+    function(
+        "regexp_replace",
+        "text, pattern text, replacement text [, flags text]",
+        "text",
+        Scalar,
+    ),
+    function("starts_with", "text, prefix text", "boolean", Predicate),
+    // Date and time
+    function("now", "", "timestamp with time zone", Scalar),
+    function(
+        "date_trunc",
+        "field text, source timestamp",
+        "timestamp",
+        Scalar,
+    ),
+    function(
+        "date_part",
+        "field text, source timestamp",
+        "double precision",
+        Scalar,
+    ),
+    function("extract", "field FROM source", "numeric", Scalar),
+    function("age", "timestamp [, timestamp]", "interval", Scalar),
+    function("to_char", "value, format text", "text", Scalar),
+    function(
+        "to_timestamp",
+        "text, format text",
+        "timestamp with time zone",
+        Scalar,
+    ),
+    function(
+        "make_interval",
+        "years, months, weeks, days, ...",
+        "interval",
+        Scalar,
+    ),
+    function("isfinite", "timestamp", "boolean", Predicate),
+    // Numbers
+    function("round", "numeric [, scale integer]", "numeric", Scalar),
+    function("abs", "numeric", "numeric", Scalar),
+    function("ceil", "numeric", "numeric", Scalar),
+    function("floor", "numeric", "numeric", Scalar),
+    function("random", "", "double precision", Scalar),
+    // JSON
+    function("jsonb_build_object", "key, value, ...", "jsonb", Scalar),
+    function("jsonb_build_array", "value, ...", "jsonb", Scalar),
+    function("jsonb_array_length", "jsonb", "integer", Scalar),
+    function("jsonb_array_elements", "jsonb", "setof jsonb", Scalar),
+    function("jsonb_each", "jsonb", "setof record", Scalar),
+    function(
+        "jsonb_extract_path_text",
+        "jsonb, path text, ...",
+        "text",
+        Scalar,
+    ),
+    function(
+        "jsonb_set",
+        "target jsonb, path text[], value jsonb",
+        "jsonb",
+        Scalar,
+    ),
+    function("jsonb_typeof", "jsonb", "text", Scalar),
+    function(
+        "jsonb_path_exists",
+        "target jsonb, path jsonpath",
+        "boolean",
+        Predicate,
+    ),
+    function("jsonb_pretty", "jsonb", "text", Scalar),
+    function("to_jsonb", "value", "jsonb", Scalar),
+    // Arrays
+    function(
+        "array_length",
+        "array, dimension integer",
+        "integer",
+        Scalar,
+    ),
+    function("array_position", "array, element", "integer", Scalar),
+    function("cardinality", "array", "integer", Scalar),
+    function("unnest", "array", "setof element", Scalar),
+    // Window
+    function("row_number", ") OVER (...", "bigint", Scalar),
+    function("rank", ") OVER (...", "bigint", Scalar),
+    function(
+        "lag",
+        "value [, offset [, default]]) OVER (...",
+        "same as input",
+        Scalar,
+    ),
+    function(
+        "lead",
+        "value [, offset [, default]]) OVER (...",
+        "same as input",
+        Scalar,
+    ),
+    // Other
+    function("gen_random_uuid", "", "uuid", Scalar),
+    function("pg_size_pretty", "bigint", "text", Scalar),
+    function("pg_total_relation_size", "regclass", "bigint", Scalar),
+    function(
+        "pg_has_role",
+        "role name, privilege text",
+        "boolean",
+        Predicate,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::collections::HashSet;
+
+    #[test]
+    fn names_are_unique_and_lowercase() {
+        let mut seen = HashSet::new();
+        for f in POSTGRES_FUNCTIONS {
+            assert_eq!(f.name, f.name.to_lowercase());
+            assert!(seen.insert(f.name), "duplicate {}", f.name);
+        }
+    }
+
+    #[test]
+    fn boolean_results_are_predicates() {
+        for f in POSTGRES_FUNCTIONS.iter().filter(|f| f.role != Aggregate) {
+            assert_eq!(f.role, FunctionRole::for_result(f.result), "{}", f.name);
+        }
+    }
+
+    #[rstest]
+    #[case("boolean", Predicate)]
+    #[case(" BOOLEAN", Predicate)]
+    #[case("setof boolean", Scalar)]
+    #[case("integer", Scalar)]
+    fn role_follows_the_result_type(#[case] result: &str, #[case] expected: FunctionRole) {
+        assert_eq!(FunctionRole::for_result(result), expected);
+    }
+}
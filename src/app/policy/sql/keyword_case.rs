@@ -0,0 +1,45 @@
+use std::ops::Range;
+
+use super::lexer::{SqlLexer, TokenKind};
+
+/// Char range of the keyword that ends exactly at `boundary`. Keywords inside
+/// strings, comments or quoted identifiers, and words qualified by `.` or a
+/// `:variable` prefix, are not reported.
+pub fn keyword_ending_at(text: &str, boundary: usize) -> Option<Range<usize>> {
+    let tokens = SqlLexer::new().tokenize(text, boundary);
+    let (last, rest) = tokens.split_last()?;
+    if !matches!(last.kind, TokenKind::Keyword(_)) || last.end != boundary {
+        return None;
+    }
+
+    let qualified = rest.last().is_some_and(|prev| match &prev.kind {
+        TokenKind::Punctuation('.') => true,
+        TokenKind::Operator(op) => op.ends_with(':') && !op.ends_with("::"),
+        _ => false,
+    });
+    (!qualified).then_some(last.start..last.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("select ", 6, Some(0..6))]
+    #[case("SELECT id from ", 14, Some(10..14))]
+    #[case("select users ", 12, None)]
+    #[case("select 'from ", 12, None)]
+    #[case("-- select ", 9, None)]
+    #[case("select u.order ", 14, None)]
+    #[case("select :limit ", 13, None)]
+    #[case("select \"from\" ", 13, None)]
+    #[case("sel", 3, None)]
+    fn finds_keyword_before_boundary(
+        #[case] text: &str,
+        #[case] boundary: usize,
+        #[case] expected: Option<Range<usize>>,
+    ) {
+        assert_eq!(keyword_ending_at(text, boundary), expected);
+    }
+}
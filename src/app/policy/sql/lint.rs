@@ -0,0 +1,267 @@
+use std::ops::Range;
+
+use super::lexer::{Token, TokenKind};
+use super::token_cache::Relexed;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintRule {
+    SelectStar,
+    UnfilteredWrite,
+    ImplicitCrossJoin,
+    NotInSubquery,
+    NullComparison,
+}
+
+impl LintRule {
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::SelectStar => "SELECT * breaks when columns change; list the columns you need",
+            Self::UnfilteredWrite => "UPDATE/DELETE without WHERE touches every row",
+            Self::ImplicitCrossJoin => "Comma join is a cross join; use JOIN ... ON",
+            Self::NotInSubquery => {
+                "NOT IN (SELECT ...) matches nothing if the subquery yields NULL; prefer NOT EXISTS"
+            }
+            Self::NullComparison => "Comparing with NULL is never true; use IS NULL / IS NOT NULL",
+        }
+    }
+}
+
+/// Non-blocking warning; `span` is a char range into the linted text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintWarning {
+    pub rule: LintRule,
+    pub span: Range<usize>,
+}
+
+// Keywords that close a FROM list, so later commas are not join separators.
+const FROM_LIST_TERMINATORS: &[&str] = &[
+    "WHERE",
+    "GROUP",
+    "HAVING",
+    "ORDER",
+    "LIMIT",
+    "OFFSET",
+    "UNION",
+    "INTERSECT",
+    "EXCEPT",
+    "RETURNING",
+    "WINDOW",
+    "SET",
+];
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Frame {
+    in_from_list: bool,
+    in_set_clause: bool,
+}
+
+/// Lints already-lexed SQL, so the editor can reuse its cached tokens.
+pub fn lint_sql(tokens: &[Token]) -> Vec<LintWarning> {
+    let tokens: Vec<&Token> = tokens
+        .iter()
+        .filter(|t| !matches!(t.kind, TokenKind::Whitespace | TokenKind::Comment))
+        .collect();
+
+    let mut warnings = Vec::new();
+    for statement in tokens.split(|t| t.kind == TokenKind::Punctuation(';')) {
+        lint_statement(statement, &mut warnings);
+    }
+    warnings
+}
+
+/// Brings `warnings` for the previous tokens up to date after an update,
+/// linting only the re-lexed statements; `tokens` is the updated list.
+pub fn relint_sql(warnings: &mut Vec<LintWarning>, tokens: &[Token], relexed: &Relexed) {
+    let mut before = Vec::with_capacity(warnings.len());
+    let mut after = Vec::new();
+    for mut warning in warnings.drain(..) {
+        if warning.span.start < relexed.start {
+            before.push(warning);
+        } else if warning.span.start >= relexed.old_end {
+            warning.span = warning.span.start + relexed.new_end - relexed.old_end
+                ..warning.span.end + relexed.new_end - relexed.old_end;
+            after.push(warning);
+        }
+    }
+    before.extend(lint_sql(&tokens[relexed.tokens.clone()]));
+    before.extend(after);
+    *warnings = before;
+}
+
+fn lint_statement(tokens: &[&Token], warnings: &mut Vec<LintWarning>) {
+    let Some(first) = tokens.first() else {
+        return;
+    };
+
+    let is_write = is_word(first, "UPDATE") || is_word(first, "DELETE");
+    let mut has_where = false;
+    let mut frames = vec![Frame::default()];
+
+    for (idx, token) in tokens.iter().enumerate() {
+        match token.kind {
+            TokenKind::Punctuation('(') => {
+                frames.push(Frame::default());
+                continue;
+            }
+            TokenKind::Punctuation(')') => {
+                if frames.len() > 1 {
+                    frames.pop();
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        let prev = idx.checked_sub(1).map(|i| &tokens[i]);
+        let next = tokens.get(idx + 1);
+        let depth = frames.len() - 1;
+        let frame = frames.last_mut().expect("lint frames keep a root frame");
+
+        match &token.kind {
+            TokenKind::Punctuation(',') if frame.in_from_list => {
+                if !next.is_some_and(|t| is_word(t, "LATERAL")) {
+                    warnings.push(warning(LintRule::ImplicitCrossJoin, token));
+                }
+            }
+            TokenKind::Operator(op) if op == "*" && depth == 0 => {
+                if prev.is_some_and(|t| is_word(t, "SELECT") || is_word(t, "DISTINCT")) {
+                    warnings.push(warning(LintRule::SelectStar, token));
+                }
+            }
+            TokenKind::Operator(op) if matches!(op.as_str(), "=" | "<>" | "!=") => {
+                let is_assignment = op == "=" && frame.in_set_clause;
+                let null_side = [prev, next]
+                    .into_iter()
+                    .flatten()
+                    .any(|t| is_word(t, "NULL"));
+                if null_side && !is_assignment {
+                    warnings.push(warning(LintRule::NullComparison, token));
+                }
+            }
+            TokenKind::Keyword(kw) => {
+                if kw == "FROM" {
+                    frame.in_from_list = true;
+                } else if FROM_LIST_TERMINATORS.contains(&kw.as_str()) {
+                    frame.in_from_list = false;
+                }
+                match kw.as_str() {
+                    "SET" => frame.in_set_clause = true,
+                    "WHERE" | "FROM" | "RETURNING" => frame.in_set_clause = false,
+                    _ => {}
+                }
+                if kw == "WHERE" && depth == 0 {
+                    has_where = true;
+                }
+                if kw == "NOT"
+                    && next.is_some_and(|t| is_word(t, "IN"))
+                    && tokens.get(idx + 2).map(|t| &t.kind) == Some(&TokenKind::Punctuation('('))
+                    && tokens.get(idx + 3).is_some_and(|t| is_word(t, "SELECT"))
+                {
+                    let end = tokens[idx + 1].end;
+                    warnings.push(LintWarning {
+                        rule: LintRule::NotInSubquery,
+                        span: token.start..end,
+                    });
+                }
+            }
+            TokenKind::Identifier(_) if is_word(token, "FETCH") || is_word(token, "FOR") => {
+                frame.in_from_list = false;
+            }
+            _ => {}
+        }
+    }
+
+    if is_write && !has_where {
+        warnings.push(warning(LintRule::UnfilteredWrite, first));
+    }
+}
+
+fn is_word(token: &Token, word: &str) -> bool {
+    matches!(&token.kind, TokenKind::Keyword(kw) if kw == word)
+        || matches!(&token.kind, TokenKind::Identifier(ident) if ident.eq_ignore_ascii_case(word))
+}
+
+fn warning(rule: LintRule, token: &Token) -> LintWarning {
+    LintWarning {
+        rule,
+        span: token.start..token.end,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::sql::lexer::SqlLexer;
+    use crate::policy::sql::token_cache::TokenCache;
+    use rstest::rstest;
+
+    fn lint(sql: &str) -> Vec<LintWarning> {
+        lint_sql(&SqlLexer::new().tokenize(sql, sql.chars().count()))
+    }
+
+    fn rules(sql: &str) -> Vec<LintRule> {
+        lint(sql).into_iter().map(|w| w.rule).collect()
+    }
+
+    #[rstest]
+    #[case("SELECT * FROM users", vec![LintRule::SelectStar])]
+    #[case("SELECT DISTINCT * FROM users", vec![LintRule::SelectStar])]
+    #[case("SELECT count(*) FROM users", vec![])]
+    #[case("SELECT u.* FROM users u", vec![])]
+    #[case("SELECT 1 WHERE EXISTS (SELECT * FROM users)", vec![])]
+    #[case("UPDATE users SET name = 'x'", vec![LintRule::UnfilteredWrite])]
+    #[case("DELETE FROM users", vec![LintRule::UnfilteredWrite])]
+    #[case("DELETE FROM users WHERE id = 1", vec![])]
+    #[case("SELECT a.id FROM a, b WHERE a.id = b.id", vec![LintRule::ImplicitCrossJoin])]
+    #[case("SELECT id, name FROM users WHERE id IN (1, 2) ORDER BY id, name", vec![])]
+    #[case("SELECT x FROM a, LATERAL f(a.id) AS x", vec![])]
+    #[case("SELECT id FROM a WHERE id NOT IN (SELECT a_id FROM b)", vec![LintRule::NotInSubquery])]
+    #[case("SELECT id FROM a WHERE id NOT IN (1, 2)", vec![])]
+    #[case("SELECT id FROM a WHERE deleted_at = NULL", vec![LintRule::NullComparison])]
+    #[case("SELECT id FROM a WHERE NULL <> deleted_at", vec![LintRule::NullComparison])]
+    #[case("SELECT id FROM a WHERE deleted_at IS NULL", vec![])]
+    #[case("UPDATE a SET deleted_at = NULL WHERE id = 1", vec![])]
+    #[case("SELECT '*', 'x = NULL' -- SELECT * FROM a, b", vec![])]
+    fn flags_risky_patterns(#[case] sql: &str, #[case] expected: Vec<LintRule>) {
+        assert_eq!(rules(sql), expected);
+    }
+
+    #[test]
+    fn each_statement_is_linted_separately() {
+        let sql = "DELETE FROM a WHERE id = 1;\nDELETE FROM b";
+
+        let warnings = lint(sql);
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].rule, LintRule::UnfilteredWrite);
+        assert_eq!(warnings[0].span, 28..34);
+    }
+
+    #[test]
+    fn relint_after_each_keystroke_matches_full_lint() {
+        let script = "DELETE FROM a;\nSELECT * FROM a, b WHERE x = NULL;\nUPDATE b SET y = 1;";
+        let mut cache = TokenCache::default();
+        let mut warnings = Vec::new();
+        let mut text = String::new();
+        for ch in script.chars() {
+            text.push(ch);
+            let relexed = cache.update(&text).unwrap();
+            relint_sql(&mut warnings, cache.tokens(), &relexed);
+
+            assert_eq!(warnings, lint(&text), "after {text:?}");
+        }
+
+        let edited = script.replace("FROM a;", "FROM a WHERE id = 1;");
+        let relexed = cache.update(&edited).unwrap();
+        relint_sql(&mut warnings, cache.tokens(), &relexed);
+
+        assert_eq!(warnings, lint(&edited));
+    }
+
+    #[test]
+    fn span_covers_offending_token() {
+        let warnings = lint("SELECT * FROM users");
+
+        assert_eq!(warnings[0].span, 7..8);
+    }
+}
@@ -1,7 +1,21 @@
+pub mod alias_rename;
+pub mod builtin_functions;
+pub mod keyword_case;
 pub mod lexer;
+pub mod lint;
+pub mod policy_expression;
+pub mod preview_filter;
+pub mod qualifiers;
+pub mod replica_routing;
+pub mod resource_usage;
 pub mod result_query;
+pub mod session_pooling;
 pub mod sqlite_explain;
 pub mod sqlite_export;
 pub mod sqlite_statement_splitter;
 pub mod sqlite_transaction;
 pub mod statement_classifier;
+pub mod statement_timing;
+pub mod templates;
+pub mod token_cache;
+pub mod variables;
@@ -0,0 +1,104 @@
+use super::lexer::{SqlLexer, TokenKind};
+
+struct Part {
+    kind: TokenKind,
+    text: String,
+    space_before: bool,
+}
+
+/// Lines for an RLS `USING` / `WITH CHECK` expression as PostgreSQL stores it.
+///
+/// Wrapping parentheses are dropped, whitespace is collapsed and every
+/// top-level `AND` / `OR` starts a new line. Nested groups stay on the line of
+/// the condition they belong to.
+pub fn format_policy_expression(expression: &str) -> Vec<String> {
+    let tokens = SqlLexer::new().tokenize(expression, expression.chars().count());
+    let mut parts = Vec::with_capacity(tokens.len());
+    let mut space_before = false;
+    for token in tokens {
+        if token.kind == TokenKind::Whitespace {
+            space_before = true;
+            continue;
+        }
+        parts.push(Part {
+            kind: token.kind,
+            text: token.text,
+            space_before,
+        });
+        space_before = false;
+    }
+    while wrapped_in_parentheses(&parts) {
+        parts.pop();
+        parts.remove(0);
+    }
+
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut depth = 0usize;
+    for part in &parts {
+        match &part.kind {
+            TokenKind::Punctuation('(') => depth += 1,
+            TokenKind::Punctuation(')') => depth = depth.saturating_sub(1),
+            TokenKind::Keyword(keyword)
+                if depth == 0 && matches!(keyword.as_str(), "AND" | "OR") && !line.is_empty() =>
+            {
+                lines.push(std::mem::take(&mut line));
+            }
+            _ => {}
+        }
+        if part.space_before && !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&part.text);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+fn wrapped_in_parentheses(parts: &[Part]) -> bool {
+    if parts.len() < 2 || parts[0].kind != TokenKind::Punctuation('(') {
+        return false;
+    }
+    let mut depth = 0usize;
+    for (index, part) in parts.iter().enumerate() {
+        match part.kind {
+            TokenKind::Punctuation('(') => depth += 1,
+            TokenKind::Punctuation(')') => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return index == parts.len() - 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("true", &["true"])]
+    #[case("(tenant_id = 1)", &["tenant_id = 1"])]
+    #[case(
+        "((tenant_id = (current_setting('app.tenant'::text))::integer) AND (deleted_at IS NULL))",
+        &[
+            "(tenant_id = (current_setting('app.tenant'::text))::integer)",
+            "AND (deleted_at IS NULL)",
+        ]
+    )]
+    #[case(
+        "(owner = CURRENT_USER) OR ((role = 'admin') and   (active))",
+        &["(owner = CURRENT_USER)", "OR ((role = 'admin') and (active))"]
+    )]
+    #[case("(a = 1) AND (b = 'x AND y')", &["(a = 1)", "AND (b = 'x AND y')"])]
+    #[case("", &[])]
+    fn formats_policy_expressions(#[case] expression: &str, #[case] expected: &[&str]) {
+        assert_eq!(format_policy_expression(expression), expected);
+    }
+}
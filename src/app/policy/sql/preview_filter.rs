@@ -0,0 +1,156 @@
+use super::lexer::{SqlLexer, TokenKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum PreviewFilterError {
+    #[error("Filter must be a single expression — drop the ';'")]
+    MultipleStatements,
+    #[error("Filter can't contain comments")]
+    Comment,
+    #[error("Filter has unbalanced parentheses")]
+    UnbalancedParentheses,
+}
+
+/// Normalizes a `:where` predicate: an optional leading `WHERE` is dropped,
+/// and anything that could reach past the preview's `WHERE (…)` is refused.
+/// `None` means the input was blank.
+pub fn parse_preview_filter(input: &str) -> Result<Option<String>, PreviewFilterError> {
+    let trimmed = input.trim();
+    let body = match trimmed.get(..6) {
+        Some(head) if head.eq_ignore_ascii_case("where ") => trimmed[6..].trim_start(),
+        _ if trimmed.eq_ignore_ascii_case("where") => "",
+        _ => trimmed,
+    };
+    if body.is_empty() {
+        return Ok(None);
+    }
+
+    let mut depth = 0_i32;
+    for token in SqlLexer::new().tokenize(body, body.chars().count()) {
+        match token.kind {
+            TokenKind::Punctuation(';') => return Err(PreviewFilterError::MultipleStatements),
+            TokenKind::Comment => return Err(PreviewFilterError::Comment),
+            TokenKind::Punctuation('(') => depth += 1,
+            TokenKind::Punctuation(')') => {
+                depth -= 1;
+                if depth < 0 {
+                    return Err(PreviewFilterError::UnbalancedParentheses);
+                }
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(PreviewFilterError::UnbalancedParentheses);
+    }
+    Ok(Some(body.to_string()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnCompletion<'a> {
+    /// Text to insert at the cursor: the part every candidate shares beyond
+    /// what was typed.
+    pub insert: String,
+    pub candidates: Vec<&'a str>,
+}
+
+/// Completes the column name ending at `cursor` (a char index) against
+/// `columns`, matching the typed prefix case-insensitively.
+pub fn complete_column<'a>(
+    text: &str,
+    cursor: usize,
+    columns: &'a [String],
+) -> Option<ColumnCompletion<'a>> {
+    let before: Vec<char> = text.chars().take(cursor).collect();
+    let start = before
+        .iter()
+        .rposition(|c| !(c.is_alphanumeric() || *c == '_'))
+        .map_or(0, |position| position + 1);
+    let prefix: String = before[start..].iter().collect();
+    let prefix_len = prefix.chars().count();
+
+    let candidates: Vec<&str> = columns
+        .iter()
+        .map(String::as_str)
+        .filter(|column| {
+            column
+                .get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(&prefix))
+        })
+        .collect();
+    let (first, rest) = candidates.split_first()?;
+
+    let mut shared: Vec<char> = first.chars().collect();
+    for candidate in rest {
+        let common = shared
+            .iter()
+            .zip(candidate.chars())
+            .take_while(|(a, b)| **a == *b)
+            .count();
+        shared.truncate(common);
+    }
+    let insert = shared.iter().skip(prefix_len).collect();
+    Some(ColumnCompletion { insert, candidates })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("status = 'open'", Some("status = 'open'"))]
+    #[case("  WHERE id > 10 ", Some("id > 10"))]
+    #[case("where (a = 1 OR b = 2)", Some("(a = 1 OR b = 2)"))]
+    #[case("note = ';'", Some("note = ';'"))]
+    #[case("where", None)]
+    #[case("   ", None)]
+    fn parse_accepts_single_predicates(#[case] input: &str, #[case] expected: Option<&str>) {
+        assert_eq!(parse_preview_filter(input).unwrap().as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case("1 = 1; DROP TABLE users", PreviewFilterError::MultipleStatements)]
+    #[case("id = 1 -- trailing", PreviewFilterError::Comment)]
+    #[case("id = 1) OR (1 = 1", PreviewFilterError::UnbalancedParentheses)]
+    #[case("(id = 1", PreviewFilterError::UnbalancedParentheses)]
+    fn parse_refuses_escaping_predicates(
+        #[case] input: &str,
+        #[case] expected: PreviewFilterError,
+    ) {
+        assert_eq!(parse_preview_filter(input), Err(expected));
+    }
+
+    fn columns() -> Vec<String> {
+        ["id", "status", "state_code", "created_at"]
+            .map(String::from)
+            .to_vec()
+    }
+
+    #[test]
+    fn completion_finishes_a_unique_column() {
+        let columns = columns();
+
+        let completion = complete_column("cre", 3, &columns).unwrap();
+
+        assert_eq!(completion.insert, "ated_at");
+        assert_eq!(completion.candidates, vec!["created_at"]);
+    }
+
+    #[test]
+    fn completion_extends_to_the_shared_prefix() {
+        let columns = columns();
+
+        let completion = complete_column("id > 3 AND ST", 13, &columns).unwrap();
+
+        assert_eq!(completion.insert, "at");
+        assert_eq!(completion.candidates, vec!["status", "state_code"]);
+    }
+
+    #[test]
+    fn completion_uses_the_word_before_the_cursor() {
+        let columns = columns();
+
+        assert!(complete_column("zzz = 1", 3, &columns).is_none());
+        assert_eq!(complete_column("i = 1", 1, &columns).unwrap().insert, "d");
+    }
+}
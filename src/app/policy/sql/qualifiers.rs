@@ -0,0 +1,41 @@
+use super::lexer::{SqlLexer, TokenKind};
+
+/// Names written right before a `.`, unquoted: the schemas of qualified
+/// table names, but also table aliases, so callers match them against the
+/// schemas they know.
+pub fn dotted_qualifiers(sql: &str) -> Vec<String> {
+    let tokens: Vec<_> = SqlLexer::new()
+        .tokenize(sql, sql.chars().count())
+        .into_iter()
+        .filter(|t| !matches!(t.kind, TokenKind::Whitespace | TokenKind::Comment))
+        .collect();
+
+    let mut names: Vec<String> = tokens
+        .windows(2)
+        .filter(|pair| pair[1].kind == TokenKind::Punctuation('.'))
+        .filter_map(|pair| match &pair[0].kind {
+            TokenKind::Identifier(name) | TokenKind::Keyword(name) => {
+                Some(name.trim_matches('"').to_string())
+            }
+            _ => None,
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("SELECT * FROM sales.orders o JOIN crm.users u ON u.id = o.user_id", &["crm", "o", "sales", "u"])]
+    #[case("SELECT * FROM \"Audit\".events", &["Audit"])]
+    #[case("SELECT 'a.b', 1.5 -- x.y", &[])]
+    #[case("SELECT * FROM sales.", &["sales"])]
+    fn collects_names_before_a_dot(#[case] sql: &str, #[case] expected: &[&str]) {
+        assert_eq!(dotted_qualifiers(sql), expected);
+    }
+}
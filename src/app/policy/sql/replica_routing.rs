@@ -0,0 +1,155 @@
+use super::lexer::{SqlLexer, Token, TokenKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryEndpoint {
+    Primary,
+    Replica,
+}
+
+impl QueryEndpoint {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Replica => "replica",
+        }
+    }
+}
+
+/// `Auto` routes read-only SELECTs to the replica; the other variants pin
+/// every ad-hoc query to one endpoint.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QueryRouting {
+    #[default]
+    Auto,
+    Primary,
+    Replica,
+}
+
+impl QueryRouting {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Primary => "primary",
+            Self::Replica => "replica",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "primary" => Some(Self::Primary),
+            "replica" => Some(Self::Replica),
+            _ => None,
+        }
+    }
+}
+
+// Any of these turns a SELECT into a write or a lock, which a hot standby rejects.
+const WRITE_KEYWORDS: &[&str] = &["INSERT", "UPDATE", "DELETE", "MERGE", "INTO"];
+const LOCK_STRENGTHS: &[&str] = &["UPDATE", "SHARE", "NO", "KEY"];
+
+pub fn route_query(sql: &str, routing: QueryRouting) -> QueryEndpoint {
+    match routing {
+        QueryRouting::Replica => QueryEndpoint::Replica,
+        QueryRouting::Auto if is_read_only_select(sql) => QueryEndpoint::Replica,
+        QueryRouting::Primary | QueryRouting::Auto => QueryEndpoint::Primary,
+    }
+}
+
+/// True when every statement is a plain SELECT (optionally behind WITH)
+/// without SELECT INTO, data-modifying CTEs or row locks.
+pub fn is_read_only_select(sql: &str) -> bool {
+    let tokens: Vec<Token> = SqlLexer::new()
+        .tokenize(sql, sql.chars().count())
+        .into_iter()
+        .filter(|t| !matches!(t.kind, TokenKind::Whitespace | TokenKind::Comment))
+        .collect();
+
+    let mut statements = tokens
+        .split(|t| t.kind == TokenKind::Punctuation(';'))
+        .filter(|statement| !statement.is_empty())
+        .peekable();
+    statements.peek().is_some() && statements.all(is_read_only_statement)
+}
+
+fn is_read_only_statement(tokens: &[Token]) -> bool {
+    let starts_read_only = tokens
+        .first()
+        .is_some_and(|t| is_keyword(t, "SELECT") || is_keyword(t, "WITH"));
+    if !starts_read_only {
+        return false;
+    }
+
+    tokens.iter().enumerate().all(|(idx, token)| {
+        let writes = WRITE_KEYWORDS.iter().any(|kw| is_keyword(token, kw));
+        let locks = is_word(token, "FOR")
+            && tokens
+                .get(idx + 1)
+                .is_some_and(|next| LOCK_STRENGTHS.iter().any(|kw| is_word(next, kw)));
+        !writes && !locks
+    })
+}
+
+fn is_keyword(token: &Token, word: &str) -> bool {
+    matches!(&token.kind, TokenKind::Keyword(kw) if kw == word)
+}
+
+// FOR and lock strengths like SHARE are not in the completion keyword list,
+// so the lexer hands them back as identifiers.
+fn is_word(token: &Token, word: &str) -> bool {
+    is_keyword(token, word)
+        || matches!(&token.kind, TokenKind::Identifier(_) if token.text.eq_ignore_ascii_case(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("SELECT * FROM users", true)]
+    #[case("  select id from users where id = 1;", true)]
+    #[case(
+        "WITH recent AS (SELECT * FROM orders) SELECT count(*) FROM recent",
+        true
+    )]
+    #[case("SELECT 1; SELECT 2", true)]
+    #[case("-- note\nSELECT 'insert into t'", true)]
+    #[case("SELECT * INTO backup FROM users", false)]
+    #[case("SELECT * FROM users FOR UPDATE", false)]
+    #[case("SELECT * FROM users FOR NO KEY UPDATE", false)]
+    #[case("SELECT * FROM users FOR SHARE", false)]
+    #[case(
+        "WITH gone AS (DELETE FROM users RETURNING id) SELECT * FROM gone",
+        false
+    )]
+    #[case("SELECT 1; DELETE FROM users", false)]
+    #[case("UPDATE users SET name = 'x' WHERE id = 1", false)]
+    #[case("EXPLAIN SELECT 1", false)]
+    #[case("", false)]
+    #[case("-- only a comment", false)]
+    fn classifies_replica_safe_queries(#[case] sql: &str, #[case] expected: bool) {
+        assert_eq!(is_read_only_select(sql), expected);
+    }
+
+    #[rstest]
+    #[case("SELECT 1", QueryRouting::Auto, QueryEndpoint::Replica)]
+    #[case("DELETE FROM t", QueryRouting::Auto, QueryEndpoint::Primary)]
+    #[case("SELECT 1", QueryRouting::Primary, QueryEndpoint::Primary)]
+    #[case("DELETE FROM t", QueryRouting::Replica, QueryEndpoint::Replica)]
+    fn override_pins_endpoint(
+        #[case] sql: &str,
+        #[case] routing: QueryRouting,
+        #[case] expected: QueryEndpoint,
+    ) {
+        assert_eq!(route_query(sql, routing), expected);
+    }
+
+    #[rstest]
+    #[case("auto", Some(QueryRouting::Auto))]
+    #[case("Replica", Some(QueryRouting::Replica))]
+    #[case("standby", None)]
+    fn parses_routing(#[case] value: &str, #[case] expected: Option<QueryRouting>) {
+        assert_eq!(QueryRouting::parse(value), expected);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::domain::QueryResult;
+use crate::domain::explain_plan::{ResourceUsage, parse_resource_usage};
+use crate::policy::result_copy::format_byte_size;
+
+// PostgreSQL's default BLCKSZ; plans report temp usage in blocks.
+const BLOCK_SIZE: u64 = 8192;
+
+/// Usage from an `EXPLAIN (ANALYZE, BUFFERS)` run typed into the editor,
+/// whose result is the single `QUERY PLAN` column.
+pub fn result_resource_usage(result: &QueryResult) -> Option<ResourceUsage> {
+    if result.is_error()
+        || !matches!(result.columns.as_slice(), [column] if column.eq_ignore_ascii_case("QUERY PLAN"))
+    {
+        return None;
+    }
+    let text = (0..result.data_row_count())
+        .filter_map(|row| result.display_value_at(row, 0))
+        .collect::<Vec<_>>()
+        .join("\n");
+    parse_resource_usage(&text)
+}
+
+/// One-line footer text, e.g. `buffers 120 hit / 30 read · temp 512.0 KB · I/O 3.2ms`.
+pub fn resource_usage_summary(usage: &ResourceUsage) -> String {
+    let mut parts = vec![format!(
+        "buffers {} hit / {} read",
+        usage.shared_hit, usage.shared_read
+    )];
+    let temp_blocks = usage.temp_read.max(usage.temp_written);
+    if temp_blocks > 0 {
+        let bytes = usize::try_from(temp_blocks.saturating_mul(BLOCK_SIZE)).unwrap_or(usize::MAX);
+        parts.push(format!("temp {}", format_byte_size(bytes)));
+    }
+    if let Some(io_ms) = usage.io_time_ms() {
+        parts.push(format!("I/O {io_ms:.1}ms"));
+    }
+    parts.join(" · ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::QuerySource;
+
+    fn plan_result(columns: &[&str], lines: &[&str]) -> QueryResult {
+        QueryResult::success(
+            "EXPLAIN (ANALYZE, BUFFERS) SELECT 1".to_string(),
+            columns.iter().map(ToString::to_string).collect(),
+            lines.iter().map(|line| vec![(*line).to_string()]).collect(),
+            5,
+            QuerySource::Adhoc,
+        )
+    }
+
+    #[test]
+    fn query_plan_result_yields_usage() {
+        let result = plan_result(
+            &["QUERY PLAN"],
+            &[
+                "Seq Scan on users  (cost=0.00..1.00 rows=1 width=4)",
+                "  Buffers: shared hit=12 read=3",
+            ],
+        );
+
+        let usage = result_resource_usage(&result).unwrap();
+
+        assert_eq!((usage.shared_hit, usage.shared_read), (12, 3));
+    }
+
+    #[test]
+    fn other_results_have_no_usage() {
+        let result = plan_result(&["plan"], &["Seq Scan", "  Buffers: shared hit=12"]);
+
+        assert_eq!(result_resource_usage(&result), None);
+    }
+
+    #[test]
+    fn summary_lists_temp_and_io_only_when_present() {
+        let mut usage = ResourceUsage {
+            shared_hit: 120,
+            shared_read: 30,
+            ..ResourceUsage::default()
+        };
+        assert_eq!(resource_usage_summary(&usage), "buffers 120 hit / 30 read");
+
+        usage.temp_written = 64;
+        usage.io_read_ms = Some(3.3);
+        assert_eq!(
+            resource_usage_summary(&usage),
+            "buffers 120 hit / 30 read · temp 512.0 KB · I/O 3.3ms"
+        );
+    }
+}
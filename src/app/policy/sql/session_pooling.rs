@@ -0,0 +1,107 @@
+use super::lexer::{SqlLexer, Token, TokenKind};
+use crate::domain::PoolMode;
+
+/// Statements that depend on a dedicated server session, which transaction
+/// and statement pooling do not provide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionStateConflict {
+    Notifications,
+    SessionSetting,
+}
+
+impl SessionStateConflict {
+    pub fn message(self, mode: PoolMode) -> String {
+        match self {
+            Self::Notifications => format!(
+                "LISTEN/NOTIFY is disabled: pgbouncer in {mode} pooling mode does not keep a session to deliver notifications"
+            ),
+            Self::SessionSetting => format!(
+                "Session-level SET/RESET is disabled: pgbouncer in {mode} pooling mode would leak it to other clients; use SET LOCAL"
+            ),
+        }
+    }
+}
+
+// SET LOCAL, SET TRANSACTION and SET CONSTRAINTS end with the transaction.
+const TRANSACTION_SCOPED_SET: &[&str] = &["LOCAL", "TRANSACTION", "CONSTRAINTS"];
+
+/// Returns the first statement that needs session pooling, or `None` when the
+/// pool keeps sessions or every statement is transaction-scoped.
+pub fn session_state_conflict(sql: &str, mode: PoolMode) -> Option<SessionStateConflict> {
+    if mode.keeps_session() {
+        return None;
+    }
+    let tokens: Vec<Token> = SqlLexer::new()
+        .tokenize(sql, sql.chars().count())
+        .into_iter()
+        .filter(|t| !matches!(t.kind, TokenKind::Whitespace | TokenKind::Comment))
+        .collect();
+
+    tokens
+        .split(|t| t.kind == TokenKind::Punctuation(';'))
+        .find_map(statement_conflict)
+}
+
+fn statement_conflict(tokens: &[Token]) -> Option<SessionStateConflict> {
+    let first = tokens.first()?;
+    if ["LISTEN", "UNLISTEN", "NOTIFY"]
+        .iter()
+        .any(|word| is_word(first, word))
+    {
+        return Some(SessionStateConflict::Notifications);
+    }
+    if is_word(first, "RESET") {
+        return Some(SessionStateConflict::SessionSetting);
+    }
+    if is_word(first, "SET") {
+        let scoped = tokens.get(1).is_some_and(|next| {
+            TRANSACTION_SCOPED_SET
+                .iter()
+                .any(|word| is_word(next, word))
+        });
+        return (!scoped).then_some(SessionStateConflict::SessionSetting);
+    }
+    None
+}
+
+// The lexer only tags a subset of keywords, so compare the raw text.
+fn is_word(token: &Token, word: &str) -> bool {
+    matches!(token.kind, TokenKind::Keyword(_) | TokenKind::Identifier(_))
+        && token.text.eq_ignore_ascii_case(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("LISTEN orders", Some(SessionStateConflict::Notifications))]
+    #[case("SELECT 1; unlisten *", Some(SessionStateConflict::Notifications))]
+    #[case("NOTIFY orders, 'x'", Some(SessionStateConflict::Notifications))]
+    #[case("SET search_path = app", Some(SessionStateConflict::SessionSetting))]
+    #[case(
+        "SET SESSION statement_timeout = 0",
+        Some(SessionStateConflict::SessionSetting)
+    )]
+    #[case("RESET ALL", Some(SessionStateConflict::SessionSetting))]
+    #[case("SET LOCAL search_path = app; SELECT 1", None)]
+    #[case("SET TRANSACTION ISOLATION LEVEL SERIALIZABLE", None)]
+    #[case("SET CONSTRAINTS ALL DEFERRED", None)]
+    #[case("UPDATE users SET name = 'listen'", None)]
+    #[case("SELECT pg_notify('orders', 'x')", None)]
+    fn detects_session_state_in_transaction_pooling(
+        #[case] sql: &str,
+        #[case] expected: Option<SessionStateConflict>,
+    ) {
+        assert_eq!(session_state_conflict(sql, PoolMode::Transaction), expected);
+    }
+
+    #[test]
+    fn session_pooling_allows_everything() {
+        assert_eq!(
+            session_state_conflict("LISTEN orders; SET search_path = app", PoolMode::Session),
+            None
+        );
+    }
+}
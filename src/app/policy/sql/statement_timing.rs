@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use crate::domain::{QueryResult, QuerySource, StatementTiming};
+
+const PREVIEW_MAX_CHARS: usize = 60;
+
+/// Builds the per-statement summary table for a multi-statement run.
+/// Returns `None` for single statements, where the summary adds nothing.
+pub fn timing_summary(result: &QueryResult) -> Option<QueryResult> {
+    let timings = result.statement_timings();
+    if timings.len() < 2 {
+        return None;
+    }
+
+    let columns = ["#", "Statement", "Duration", "Rows", "Status"]
+        .map(str::to_string)
+        .to_vec();
+    let rows = timings
+        .iter()
+        .enumerate()
+        .map(|(i, timing)| summary_row(i + 1, timing))
+        .collect();
+    Some(
+        QueryResult::success(
+            result.query.clone(),
+            columns,
+            rows,
+            result.execution_time_ms,
+            QuerySource::Adhoc,
+        )
+        .with_statement_timings(timings.to_vec()),
+    )
+}
+
+fn summary_row(position: usize, timing: &StatementTiming) -> Vec<String> {
+    vec![
+        position.to_string(),
+        statement_preview(&timing.sql),
+        format_elapsed(timing.elapsed),
+        timing
+            .rows
+            .map_or_else(|| "-".to_string(), |rows| rows.to_string()),
+        timing.status.clone(),
+    ]
+}
+
+fn statement_preview(sql: &str) -> String {
+    let collapsed = sql.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= PREVIEW_MAX_CHARS {
+        return collapsed;
+    }
+    let truncated: String = collapsed.chars().take(PREVIEW_MAX_CHARS - 1).collect();
+    format!("{truncated}…")
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+    let millis = elapsed.as_secs_f64() * 1000.0;
+    if millis < 1000.0 {
+        format!("{millis:.1} ms")
+    } else {
+        format!("{:.2} s", elapsed.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(sql: &str, millis: u64, rows: Option<u64>, status: &str) -> StatementTiming {
+        StatementTiming {
+            sql: sql.to_string(),
+            elapsed: Duration::from_millis(millis),
+            rows,
+            status: status.to_string(),
+        }
+    }
+
+    fn script_result(timings: Vec<StatementTiming>) -> QueryResult {
+        QueryResult::success(
+            "script".to_string(),
+            Vec::new(),
+            Vec::new(),
+            50,
+            QuerySource::Adhoc,
+        )
+        .with_statement_timings(timings)
+    }
+
+    #[test]
+    fn single_statement_has_no_summary() {
+        let result = script_result(vec![timing("SELECT 1", 1, Some(1), "SELECT 1")]);
+
+        assert!(timing_summary(&result).is_none());
+    }
+
+    #[test]
+    fn summary_lists_each_statement_in_order() {
+        let result = script_result(vec![
+            timing("INSERT INTO t\n  VALUES (1)", 3, Some(1), "INSERT 0 1"),
+            timing("CREATE INDEX i ON t (id)", 1240, None, "CREATE INDEX"),
+        ]);
+
+        let summary = timing_summary(&result).unwrap();
+
+        assert_eq!(
+            summary.columns,
+            vec!["#", "Statement", "Duration", "Rows", "Status"]
+        );
+        assert_eq!(
+            summary.display_row_at(0).unwrap(),
+            vec!["1", "INSERT INTO t VALUES (1)", "3.0 ms", "1", "INSERT 0 1"]
+        );
+        assert_eq!(
+            summary.display_row_at(1).unwrap(),
+            vec![
+                "2",
+                "CREATE INDEX i ON t (id)",
+                "1.24 s",
+                "-",
+                "CREATE INDEX"
+            ]
+        );
+        assert_eq!(summary.query, "script");
+        assert_eq!(summary.statement_timings(), result.statement_timings());
+    }
+
+    #[test]
+    fn preview_collapses_whitespace() {
+        assert_eq!(statement_preview("SELECT\n    1"), "SELECT 1");
+    }
+
+    #[test]
+    fn long_preview_is_truncated() {
+        let preview = statement_preview(&"x".repeat(80));
+
+        assert_eq!(preview, format!("{}…", "x".repeat(59)));
+    }
+}
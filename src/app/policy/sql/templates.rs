@@ -0,0 +1,154 @@
+use crate::domain::{Column, Table};
+
+/// Marks a tab stop in a template body; the first one takes the cursor and
+/// Tab moves to the next.
+pub const TAB_STOP: &str = "${}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SqlTemplate {
+    pub trigger: &'static str,
+    pub body: &'static str,
+}
+
+pub const SQL_TEMPLATES: &[SqlTemplate] = &[
+    SqlTemplate {
+        trigger: "sel",
+        body: "SELECT * FROM ${}",
+    },
+    SqlTemplate {
+        trigger: "selw",
+        body: "SELECT * FROM ${} WHERE ${}",
+    },
+    SqlTemplate {
+        trigger: "selc",
+        body: "SELECT count(*) FROM ${}",
+    },
+    SqlTemplate {
+        trigger: "ins",
+        body: "INSERT INTO ${} (${}) VALUES (${})",
+    },
+    SqlTemplate {
+        trigger: "upd",
+        body: "UPDATE ${} SET ${} WHERE ${}",
+    },
+    SqlTemplate {
+        trigger: "del",
+        body: "DELETE FROM ${} WHERE ${}",
+    },
+];
+
+/// Trigger of the INSERT skeleton built from the selected table.
+pub const INSERT_FOR_TABLE_TRIGGER: &str = "insfk";
+
+/// An INSERT into `table` naming the columns a row cannot omit: NOT NULL
+/// without a default. Falls back to every writable column when none are
+/// required, and to `None` when nothing can be written.
+pub fn insert_template(table: &Table) -> Option<String> {
+    let writable: Vec<&Column> = table
+        .columns
+        .iter()
+        .filter(|column| column.read_only_reason().is_none())
+        .collect();
+    let required: Vec<&Column> = writable
+        .iter()
+        .copied()
+        .filter(|column| !column.is_nullable() && column.default.is_none())
+        .collect();
+    let columns = if required.is_empty() {
+        writable
+    } else {
+        required
+    };
+    if columns.is_empty() {
+        return None;
+    }
+
+    let names: Vec<&str> = columns.iter().map(|column| column.name.as_str()).collect();
+    let values = vec![TAB_STOP; columns.len()].join(", ");
+    Some(format!(
+        "INSERT INTO {} ({})\nVALUES ({values})",
+        table.qualified_name(),
+        names.join(", ")
+    ))
+}
+
+/// Strips the tab stop markers from `body`, returning the text and the char
+/// offset of each stop in order.
+pub fn expand_tab_stops(body: &str) -> (String, Vec<usize>) {
+    let mut text = String::with_capacity(body.len());
+    let mut stops = Vec::new();
+    for (i, part) in body.split(TAB_STOP).enumerate() {
+        if i > 0 {
+            stops.push(text.chars().count());
+        }
+        text.push_str(part);
+    }
+    (text, stops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ColumnAttributes;
+    use crate::test_support;
+    use crate::test_support::column::test_nullable_column;
+    use rstest::rstest;
+
+    fn column(name: &str, attributes: ColumnAttributes, default: Option<&str>) -> Column {
+        Column {
+            attributes,
+            default: default.map(str::to_string),
+            ..test_nullable_column(name, "text", 1)
+        }
+    }
+
+    fn users(columns: Vec<Column>) -> Table {
+        Table {
+            columns,
+            ..test_support::table::minimal("public", "users")
+        }
+    }
+
+    #[rstest]
+    #[case("SELECT * FROM ${}", "SELECT * FROM ", &[14])]
+    #[case("a ${}, ${}", "a , ", &[2, 4])]
+    #[case("SELECT 1", "SELECT 1", &[])]
+    fn expands_markers_into_char_offsets(
+        #[case] body: &str,
+        #[case] text: &str,
+        #[case] stops: &[usize],
+    ) {
+        assert_eq!(expand_tab_stops(body), (text.to_string(), stops.to_vec()));
+    }
+
+    #[test]
+    fn insert_names_only_required_columns() {
+        let table = users(vec![
+            column("id", ColumnAttributes::PRIMARY_KEY, Some("nextval('id')")),
+            column("email", ColumnAttributes::empty(), None),
+            column("nickname", ColumnAttributes::NULLABLE, None),
+            column("name", ColumnAttributes::empty(), None),
+            column("slug", ColumnAttributes::GENERATED, None),
+        ]);
+
+        assert_eq!(
+            insert_template(&table).as_deref(),
+            Some("INSERT INTO public.users (email, name)\nVALUES (${}, ${})")
+        );
+    }
+
+    #[test]
+    fn insert_falls_back_to_writable_columns() {
+        let table = users(vec![
+            column("id", ColumnAttributes::empty(), Some("1")),
+            column("note", ColumnAttributes::NULLABLE, None),
+            column("slug", ColumnAttributes::READ_ONLY, None),
+        ]);
+
+        assert_eq!(
+            insert_template(&table).as_deref(),
+            Some("INSERT INTO public.users (id, note)\nVALUES (${}, ${})")
+        );
+        assert_eq!(insert_template(&users(vec![])), None);
+    }
+}
@@ -0,0 +1,228 @@
+use std::ops::Range;
+
+use super::lexer::{SqlLexer, Token, TokenKind};
+
+/// Tokens for an editor buffer, re-lexed incrementally between edits.
+///
+/// A top-level `;` always starts the next token in the normal lexer state,
+/// so tokens up to the last `;` before an edit stay valid, and once the
+/// re-lexed region reaches an unchanged `;` after the edit the old tail can
+/// be reused with shifted offsets. Only the edited statement is lexed again.
+#[derive(Debug, Clone, Default)]
+pub struct TokenCache {
+    text: String,
+    char_len: usize,
+    tokens: Vec<Token>,
+}
+
+impl TokenCache {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    /// Returns what was re-lexed, or `None` when `text` is unchanged.
+    pub fn update(&mut self, text: &str) -> Option<Relexed> {
+        if text == self.text {
+            return None;
+        }
+        let new_len = text.chars().count();
+        let old_len = self.char_len;
+        let shared = self.text.len().min(text.len());
+        let prefix_bytes = common_prefix_bytes(&self.text, text);
+        let suffix_bytes = common_suffix_bytes(&self.text, text, shared - prefix_bytes);
+        let prefix = self.text[..prefix_bytes].chars().count();
+        let suffix = self.text[self.text.len() - suffix_bytes..].chars().count();
+
+        let keep = self.tokens[..self.tokens.partition_point(|t| t.end <= prefix)]
+            .iter()
+            .rposition(is_statement_end)
+            .map_or(0, |i| i + 1);
+        let restart = keep.checked_sub(1).map_or(0, |i| self.tokens[i].end);
+        // Walk back from the prefix end; the restart point is at most one
+        // statement away.
+        let restart_byte = prefix_bytes
+            - text[..prefix_bytes]
+                .chars()
+                .rev()
+                .take(prefix - restart)
+                .map(char::len_utf8)
+                .sum::<usize>();
+
+        // First `;` inside the untouched suffix: a candidate resync point.
+        let suffix_start = old_len - suffix;
+        let resync = self.tokens[keep..]
+            .iter()
+            .position(|t| t.start >= suffix_start && is_statement_end(t))
+            .map(|i| keep + i);
+
+        let relexed = resync.and_then(|j| {
+            let new_end = self.tokens[j].start + new_len - old_len;
+            let suffix_start_byte = text.len() - suffix_bytes;
+            let end_byte = suffix_start_byte
+                + text[suffix_start_byte..]
+                    .chars()
+                    .take(new_end - (new_len - suffix))
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+            let chunk = lex_from(&text[restart_byte..=end_byte], restart);
+            chunk
+                .last()
+                .is_some_and(|t| t.start == new_end && is_statement_end(t))
+                .then_some((chunk, j))
+        });
+
+        let edit = if let Some((chunk, j)) = relexed {
+            let shifted_from = keep + chunk.len();
+            let old_end = self.tokens[j].end;
+            self.tokens.splice(keep..=j, chunk);
+            for token in &mut self.tokens[shifted_from..] {
+                token.start = token.start + new_len - old_len;
+                token.end = token.end + new_len - old_len;
+            }
+            Relexed {
+                tokens: keep..shifted_from,
+                start: restart,
+                old_end,
+                new_end: old_end + new_len - old_len,
+            }
+        } else {
+            self.tokens.truncate(keep);
+            self.tokens.extend(lex_from(&text[restart_byte..], restart));
+            Relexed {
+                tokens: keep..self.tokens.len(),
+                start: restart,
+                old_end: old_len,
+                new_end: new_len,
+            }
+        };
+
+        self.text = text.to_string();
+        self.char_len = new_len;
+        Some(edit)
+    }
+}
+
+/// The whole statements one [`TokenCache::update`] lexed again. Everything
+/// before `start` is untouched; everything from `old_end` in the previous
+/// text moved to `new_end`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relexed {
+    /// Indices of the new tokens.
+    pub tokens: Range<usize>,
+    pub start: usize,
+    pub old_end: usize,
+    pub new_end: usize,
+}
+
+fn is_statement_end(token: &Token) -> bool {
+    token.kind == TokenKind::Punctuation(';')
+}
+
+/// Lexes `slice`, reporting positions relative to the buffer it starts at.
+fn lex_from(slice: &str, char_offset: usize) -> Vec<Token> {
+    let mut tokens = SqlLexer::new().tokenize(slice, slice.chars().count());
+    for token in &mut tokens {
+        token.start += char_offset;
+        token.end += char_offset;
+    }
+    tokens
+}
+
+// Slice comparisons in fixed chunks compile to memcmp, keeping the scan of a
+// large buffer well under the per-keystroke budget.
+const SCAN_CHUNK: usize = 64;
+
+fn common_prefix_bytes(a: &str, b: &str) -> usize {
+    let (x, y) = (a.as_bytes(), b.as_bytes());
+    let max = x.len().min(y.len());
+    let mut len = 0;
+    while len + SCAN_CHUNK <= max && x[len..len + SCAN_CHUNK] == y[len..len + SCAN_CHUNK] {
+        len += SCAN_CHUNK;
+    }
+    len += x[len..max]
+        .iter()
+        .zip(&y[len..max])
+        .take_while(|(p, q)| p == q)
+        .count();
+    while !a.is_char_boundary(len) {
+        len -= 1;
+    }
+    len
+}
+
+/// Common suffix length, capped at `max` so it never overlaps the prefix.
+fn common_suffix_bytes(a: &str, b: &str, max: usize) -> usize {
+    let (x, y) = (a.as_bytes(), b.as_bytes());
+    let (x_end, y_end) = (x.len(), y.len());
+    let mut len = 0;
+    while len + SCAN_CHUNK <= max
+        && x[x_end - len - SCAN_CHUNK..x_end - len] == y[y_end - len - SCAN_CHUNK..y_end - len]
+    {
+        len += SCAN_CHUNK;
+    }
+    len += x[..x_end - len]
+        .iter()
+        .rev()
+        .zip(y[..y_end - len].iter().rev())
+        .take(max - len)
+        .take_while(|(p, q)| p == q)
+        .count();
+    while !a.is_char_boundary(x_end - len) {
+        len -= 1;
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn snapshot(tokens: &[Token]) -> Vec<(TokenKind, String, usize, usize)> {
+        tokens
+            .iter()
+            .map(|t| (t.kind.clone(), t.text.clone(), t.start, t.end))
+            .collect()
+    }
+
+    fn full_lex(text: &str) -> Vec<(TokenKind, String, usize, usize)> {
+        snapshot(&SqlLexer::new().tokenize(text, text.chars().count()))
+    }
+
+    const SCRIPT: &str = "SELECT id FROM users;\nUPDATE users SET name = 'ä';\n\
+                          DELETE FROM orders WHERE id = 1;\nSELECT $$x$$;";
+
+    #[rstest]
+    #[case::append_char(SCRIPT, format!("{SCRIPT} "))]
+    #[case::edit_middle_statement(SCRIPT, SCRIPT.replace("name = 'ä'", "name = 'äb'"))]
+    #[case::open_quote_swallows_rest(SCRIPT, SCRIPT.replace("name = 'ä'", "name = 'ä"))]
+    #[case::open_comment(SCRIPT, SCRIPT.replace("DELETE", "/* DELETE"))]
+    #[case::delete_separator(SCRIPT, SCRIPT.replacen(';', "", 1))]
+    #[case::insert_separator(SCRIPT, SCRIPT.replace("FROM orders", "FROM o; rders"))]
+    #[case::clear("SELECT 1;", String::new())]
+    fn incremental_tokens_match_full_lex(#[case] before: &str, #[case] after: String) {
+        let mut cache = TokenCache::default();
+        cache.update(before);
+
+        cache.update(&after).unwrap();
+
+        assert_eq!(snapshot(cache.tokens()), full_lex(&after));
+        assert_eq!(cache.text(), after);
+    }
+
+    #[test]
+    fn keystrokes_across_a_script_match_full_lex() {
+        let mut cache = TokenCache::default();
+        let mut text = String::new();
+        for ch in SCRIPT.chars() {
+            text.push(ch);
+            cache.update(&text).unwrap();
+
+            assert_eq!(snapshot(cache.tokens()), full_lex(&text), "after {text:?}");
+        }
+    }
+}
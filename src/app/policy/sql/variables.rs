@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+pub fn is_valid_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// psql-style interpolation of result-bound variables.
+///
+/// `:name` inserts the raw value, `:'name'` a quoted literal and `:"name"` a
+/// quoted identifier. Strings, comments, dollar quotes
+/// and `::` casts are left alone, as are references to unknown variables.
+pub fn substitute_variables(sql: &str, variables: &BTreeMap<String, String>) -> String {
+    if variables.is_empty() {
+        return sql.to_string();
+    }
+
+    let chars: Vec<char> = sql.chars().collect();
+    let mut out = String::with_capacity(sql.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let next = chars.get(i + 1).copied();
+        let end = match (chars[i], next) {
+            (quote @ ('\'' | '"'), _) => skip_quoted(&chars, i, quote),
+            ('-', Some('-')) => chars[i..]
+                .iter()
+                .position(|&c| c == '\n')
+                .map_or(chars.len(), |offset| i + offset),
+            ('/', Some('*')) => {
+                find_sequence(&chars, i + 2, &['*', '/']).map_or(chars.len(), |p| p + 2)
+            }
+            ('$', _) => skip_dollar_quoted(&chars, i).unwrap_or(i + 1),
+            (':', Some(':')) => i + 2,
+            (':', _) => {
+                if let Some((replacement, end)) = interpolate(&chars, i, variables) {
+                    out.push_str(&replacement);
+                    i = end;
+                    continue;
+                }
+                i + 1
+            }
+            _ => i + 1,
+        };
+        out.extend(&chars[i..end]);
+        i = end;
+    }
+    out
+}
+
+fn interpolate(
+    chars: &[char],
+    colon: usize,
+    variables: &BTreeMap<String, String>,
+) -> Option<(String, usize)> {
+    let start = colon + 1;
+    match chars.get(start)? {
+        quote @ ('\'' | '"') => {
+            let name_start = start + 1;
+            let name_end = name_start + chars[name_start..].iter().position(|c| c == quote)?;
+            let name: String = chars[name_start..name_end].iter().collect();
+            let value = variables.get(&name)?;
+            let quoted = if *quote == '\'' {
+                format!("'{}'", value.replace('\'', "''"))
+            } else {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            };
+            Some((quoted, name_end + 1))
+        }
+        c if c.is_ascii_alphabetic() || *c == '_' => {
+            let name_end = chars[start..]
+                .iter()
+                .position(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+                .map_or(chars.len(), |offset| start + offset);
+            let name: String = chars[start..name_end].iter().collect();
+            variables.get(&name).map(|value| (value.clone(), name_end))
+        }
+        _ => None,
+    }
+}
+
+fn skip_quoted(chars: &[char], start: usize, quote: char) -> usize {
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == quote {
+            if chars.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    chars.len()
+}
+
+fn skip_dollar_quoted(chars: &[char], start: usize) -> Option<usize> {
+    let tag_len = chars[start + 1..]
+        .iter()
+        .position(|&c| c == '$')
+        .filter(|&len| {
+            chars[start + 1..start + 1 + len]
+                .iter()
+                .enumerate()
+                .all(|(idx, c)| {
+                    c.is_ascii_alphabetic() || *c == '_' || (idx > 0 && c.is_ascii_digit())
+                })
+        })?;
+    let tag = &chars[start..start + tag_len + 2];
+    let body_start = start + tag.len();
+    Some(find_sequence(chars, body_start, tag).map_or(chars.len(), |p| p + tag.len()))
+}
+
+fn find_sequence(chars: &[char], from: usize, needle: &[char]) -> Option<usize> {
+    chars
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|offset| from + offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn vars() -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("id".to_string(), "42".to_string()),
+            ("name".to_string(), "O'Brien".to_string()),
+            ("tbl".to_string(), "Order \"Items\"".to_string()),
+        ])
+    }
+
+    #[rstest]
+    #[case("SELECT * FROM t WHERE id = :id", "SELECT * FROM t WHERE id = 42")]
+    #[case("WHERE name = :'name'", "WHERE name = 'O''Brien'")]
+    #[case("SELECT * FROM :\"tbl\"", "SELECT * FROM \"Order \"\"Items\"\"\"")]
+    #[case("SELECT :id::text", "SELECT 42::text")]
+    #[case("SELECT x::int FROM t", "SELECT x::int FROM t")]
+    #[case("SELECT ':id', \":id\"", "SELECT ':id', \":id\"")]
+    #[case("SELECT 1 -- :id\n, :id", "SELECT 1 -- :id\n, 42")]
+    #[case("SELECT /* :id */ :id", "SELECT /* :id */ 42")]
+    #[case(
+        "SELECT $$ :id $$, $fn$ :id $fn$, :id",
+        "SELECT $$ :id $$, $fn$ :id $fn$, 42"
+    )]
+    #[case("SELECT :unknown, :'missing'", "SELECT :unknown, :'missing'")]
+    #[case("SELECT arr[1:2], :idx", "SELECT arr[1:2], :idx")]
+    #[case("SELECT 'it''s :id', :id", "SELECT 'it''s :id', 42")]
+    fn substitutes_outside_literals(#[case] sql: &str, #[case] expected: &str) {
+        assert_eq!(substitute_variables(sql, &vars()), expected);
+    }
+
+    #[test]
+    fn without_variables_returns_input() {
+        assert_eq!(
+            substitute_variables("SELECT :id", &BTreeMap::new()),
+            "SELECT :id"
+        );
+    }
+
+    #[rstest]
+    #[case("id", true)]
+    #[case("_order_id2", true)]
+    #[case("2id", false)]
+    #[case("order-id", false)]
+    #[case("", false)]
+    fn variable_names_are_identifiers(#[case] name: &str, #[case] expected: bool) {
+        assert_eq!(is_valid_variable_name(name), expected);
+    }
+}
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use crate::domain::{QueryResult, QuerySource};
+use crate::model::shared::startup_profile::{StartupPhase, StartupProfile};
+
+/// Builds the `:startup report` table: every phase in launch order, with
+/// phases not reached yet left blank so a stalled connect is easy to spot.
+pub fn startup_report(profile: &StartupProfile) -> QueryResult {
+    let columns = ["Phase", "Since launch", "Step", "Budget"]
+        .map(str::to_string)
+        .to_vec();
+
+    let reached = profile.marks().iter().map(|(phase, _)| *phase);
+    let pending = StartupPhase::ALL
+        .into_iter()
+        .filter(|phase| !profile.is_marked(*phase));
+
+    let rows = reached
+        .chain(pending)
+        .map(|phase| {
+            let Some(elapsed) = profile.elapsed(phase) else {
+                return vec![
+                    phase.label().to_string(),
+                    "not reached".to_string(),
+                    "-".to_string(),
+                    budget_label(phase, false),
+                ];
+            };
+            vec![
+                phase.label().to_string(),
+                format_ms(elapsed),
+                profile
+                    .step(phase)
+                    .map_or_else(|| "-".to_string(), format_ms),
+                budget_label(phase, profile.is_over_budget(phase)),
+            ]
+        })
+        .collect();
+
+    QueryResult::success(
+        ":startup report".to_string(),
+        columns,
+        rows,
+        0,
+        QuerySource::Adhoc,
+    )
+}
+
+fn budget_label(phase: StartupPhase, over: bool) -> String {
+    match phase.budget() {
+        Some(budget) if over => format!("over {}", format_ms(budget)),
+        Some(budget) => format!("≤ {}", format_ms(budget)),
+        None => "-".to_string(),
+    }
+}
+
+fn format_ms(duration: Duration) -> String {
+    format!("{} ms", duration.as_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    #[test]
+    fn report_lists_reached_phases_then_pending_ones() {
+        let launched_at = Instant::now();
+        let mut profile = StartupProfile::default();
+        profile.begin(launched_at);
+        profile.mark(
+            StartupPhase::ConfigLoad,
+            launched_at + Duration::from_millis(40),
+        );
+        profile.mark(
+            StartupPhase::FirstRender,
+            launched_at + Duration::from_millis(340),
+        );
+
+        let report = startup_report(&profile);
+
+        assert_eq!(
+            report.display_row_at(0).unwrap(),
+            vec!["config load", "40 ms", "40 ms", "≤ 100 ms"]
+        );
+        assert_eq!(
+            report.display_row_at(1).unwrap(),
+            vec!["first render", "340 ms", "300 ms", "over 250 ms"]
+        );
+        assert_eq!(
+            report.display_row_at(2).unwrap(),
+            vec!["connect", "not reached", "-", "-"]
+        );
+        assert_eq!(report.data_row_count(), StartupPhase::ALL.len());
+    }
+}
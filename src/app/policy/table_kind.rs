@@ -24,6 +24,7 @@ pub fn explorer_kind_suffix(kind_info: &TableKindInfo) -> Option<String> {
             }
         }
         TableKind::View => parts.push("view".to_string()),
+        TableKind::Hypertable => parts.push("hypertable".to_string()),
     }
     if kind_info.is_strict {
         parts.push("strict".to_string());
@@ -46,21 +47,12 @@ pub fn explorer_table_label_width(summary: &TableSummary) -> usize {
     text_display_width(&explorer_table_label(summary))
 }
 
-pub fn max_explorer_table_label_width<'a>(
-    summaries: impl IntoIterator<Item = &'a TableSummary>,
-) -> usize {
-    summaries
-        .into_iter()
-        .map(explorer_table_label_width)
-        .max()
-        .unwrap_or(0)
-}
-
 pub fn inspector_kind_label(kind_info: &TableKindInfo) -> String {
     match (&kind_info.kind, &kind_info.virtual_module) {
         (TableKind::Virtual, Some(module)) => format!("Virtual table ({module})"),
         (TableKind::Virtual, None) => "Virtual table".to_string(),
         (TableKind::View, _) => "View".to_string(),
+        (TableKind::Hypertable, _) => "Hypertable".to_string(),
         (TableKind::Table, _) => "Table".to_string(),
     }
 }
@@ -149,4 +141,19 @@ mod tests {
         );
         assert_eq!(inspector_kind_label(&summary.kind_info), "View");
     }
+
+    #[test]
+    fn hypertable_shows_kind_in_explorer_suffix() {
+        let summary = TableSummary::new("public".to_string(), "metrics".to_string(), None, false)
+            .with_kind_info(TableKindInfo {
+                kind: TableKind::Hypertable,
+                ..TableKindInfo::default()
+            });
+
+        assert_eq!(
+            explorer_table_label(&summary),
+            "public.metrics [hypertable]"
+        );
+        assert_eq!(inspector_kind_label(&summary.kind_info), "Hypertable");
+    }
 }
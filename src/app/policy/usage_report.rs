@@ -0,0 +1,59 @@
+use crate::domain::{QueryResult, QuerySource, UsageKind, UsageStats};
+
+/// Builds the `:usage` table: panes, tabs, then commands, each most used
+/// first. Returns `None` before anything has been recorded.
+pub fn usage_report(stats: &UsageStats) -> Option<QueryResult> {
+    if stats.is_empty() {
+        return None;
+    }
+
+    let columns = ["Kind", "Name", "Uses"].map(str::to_string).to_vec();
+    let rows = UsageKind::ALL
+        .into_iter()
+        .flat_map(|kind| {
+            stats.ranked(kind).into_iter().map(move |(name, count)| {
+                vec![
+                    kind.label().to_string(),
+                    name.to_string(),
+                    count.to_string(),
+                ]
+            })
+        })
+        .collect();
+    Some(QueryResult::success(
+        ":usage".to_string(),
+        columns,
+        rows,
+        0,
+        QuerySource::Adhoc,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_stats_have_no_report() {
+        assert!(usage_report(&UsageStats::default()).is_none());
+    }
+
+    #[test]
+    fn report_groups_by_kind_most_used_first() {
+        let mut stats = UsageStats::default();
+        stats.record(UsageKind::Command, ":sql");
+        stats.record(UsageKind::Tab, "Idx");
+        stats.record(UsageKind::Tab, "DDL");
+        stats.record(UsageKind::Tab, "DDL");
+
+        let report = usage_report(&stats).unwrap();
+
+        assert_eq!(report.columns, vec!["Kind", "Name", "Uses"]);
+        assert_eq!(report.display_row_at(0).unwrap(), vec!["Tab", "DDL", "2"]);
+        assert_eq!(report.display_row_at(1).unwrap(), vec!["Tab", "Idx", "1"]);
+        assert_eq!(
+            report.display_row_at(2).unwrap(),
+            vec!["Command", ":sql", "1"]
+        );
+    }
+}
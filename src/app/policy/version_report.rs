@@ -0,0 +1,158 @@
+use crate::domain::{QueryResult, QuerySource};
+use crate::ports::outbound::ReleaseInfo;
+
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Whether `candidate` is a later release than `current`. Versions compare
+/// by their dotted numeric core; a pre-release of the same core is older.
+pub fn is_newer_release(current: &str, candidate: &str) -> bool {
+    let (current_core, current_pre) = split_version(current);
+    let (candidate_core, candidate_pre) = split_version(candidate);
+    match candidate_core.cmp(&current_core) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Equal => current_pre && !candidate_pre,
+    }
+}
+
+fn split_version(version: &str) -> (Vec<u64>, bool) {
+    let version = version.trim().trim_start_matches('v');
+    let version = version
+        .split_once('+')
+        .map_or(version, |(version, _)| version);
+    let (core, pre) = version
+        .split_once('-')
+        .map_or((version, false), |(core, _)| (core, true));
+    let mut parts: Vec<u64> = core
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect();
+    while parts.last() == Some(&0) {
+        parts.pop();
+    }
+    (parts, pre)
+}
+
+/// Builds the `:version` table. The changelog row carries the full notes so
+/// the cell detail popup shows them.
+pub fn version_report(update_check: bool, latest: Option<&ReleaseInfo>) -> QueryResult {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    let mut rows = vec![
+        row("Version", format!("v{CURRENT_VERSION}")),
+        row(
+            "Build",
+            format!(
+                "{profile} · {}/{}",
+                std::env::consts::OS,
+                std::env::consts::ARCH
+            ),
+        ),
+        row(
+            "Update check",
+            if update_check {
+                "on"
+            } else {
+                "off — enable with :updates on"
+            }
+            .to_string(),
+        ),
+    ];
+
+    match latest {
+        Some(release) => {
+            let status = if is_newer_release(CURRENT_VERSION, &release.version) {
+                "update available"
+            } else {
+                "up to date"
+            };
+            rows.push(row(
+                "Latest release",
+                format!("v{} ({status})", release.version),
+            ));
+            rows.push(row("Release page", release.url.clone()));
+            if !release.notes.trim().is_empty() {
+                rows.push(row("Changelog", release.notes.trim().to_string()));
+            }
+        }
+        None if update_check => rows.push(row("Latest release", "not checked yet".to_string())),
+        None => {}
+    }
+
+    QueryResult::success(
+        ":version".to_string(),
+        vec!["Field".to_string(), "Value".to_string()],
+        rows,
+        0,
+        QuerySource::Adhoc,
+    )
+}
+
+fn row(field: &str, value: String) -> Vec<String> {
+    vec![field.to_string(), value]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1.15.1", "1.16.0", true)]
+    #[case("1.15.1", "1.15.10", true)]
+    #[case("1.15.1", "2.0", true)]
+    #[case("1.15.1", "1.15.1", false)]
+    #[case("1.15.1", "1.15.0", false)]
+    #[case("1.15.1", "1.16.0-rc.1", true)]
+    #[case("1.16.0-rc.1", "1.16.0", true)]
+    #[case("1.16.0", "1.16.0-rc.1", false)]
+    #[case("1.16", "1.16.0", false)]
+    fn newer_release_compares_numeric_parts(
+        #[case] current: &str,
+        #[case] candidate: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(is_newer_release(current, candidate), expected);
+    }
+
+    fn value(result: &QueryResult, field: &str) -> Option<String> {
+        (0..result.row_count())
+            .filter_map(|i| result.display_row_at(i))
+            .find(|row| row[0] == field)
+            .map(|row| row[1].clone())
+    }
+
+    #[test]
+    fn report_without_update_check_points_at_the_opt_in() {
+        let report = version_report(false, None);
+
+        assert_eq!(report.query, ":version");
+        let version = format!("v{CURRENT_VERSION}");
+        assert_eq!(value(&report, "Version").as_deref(), Some(version.as_str()));
+        assert!(value(&report, "Update check").is_some_and(|v| v.contains(":updates on")));
+        assert_eq!(value(&report, "Latest release"), None);
+    }
+
+    #[test]
+    fn report_with_newer_release_includes_changelog() {
+        let release = ReleaseInfo {
+            version: "999.0.0".to_string(),
+            url: "https://github.com/riii111/sabiql/releases/tag/v999.0.0".to_string(),
+            notes: "## What's Changed\n- Faster previews\n".to_string(),
+        };
+
+        let report = version_report(true, Some(&release));
+
+        assert_eq!(
+            value(&report, "Latest release").as_deref(),
+            Some("v999.0.0 (update available)")
+        );
+        assert_eq!(
+            value(&report, "Changelog").as_deref(),
+            Some("## What's Changed\n- Faster previews")
+        );
+    }
+}
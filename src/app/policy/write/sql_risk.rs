@@ -134,19 +134,27 @@ pub fn split_statements(sql: &str) -> Vec<String> {
 }
 
 pub fn split_statements_for_database(database_type: DatabaseType, sql: &str) -> Vec<String> {
+    statement_slices_for_database(database_type, sql)
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Trimmed statements borrowed from `sql`, so callers can edit the original
+/// text around them instead of re-joining the pieces.
+pub fn statement_slices_for_database(database_type: DatabaseType, sql: &str) -> Vec<&str> {
     if database_type == DatabaseType::SQLite {
         return split_sqlite_statements(sql)
-            .statements()
-            .iter()
+            .into_statements()
+            .into_iter()
             .filter(|statement| !is_comment_only(statement))
-            .map(|statement| (*statement).to_string())
             .collect();
     }
 
     split_postgres_statements(sql)
 }
 
-fn split_postgres_statements(sql: &str) -> Vec<String> {
+fn split_postgres_statements(sql: &str) -> Vec<&str> {
     let chars: Vec<(usize, char)> = sql.char_indices().collect();
     let mut statements = Vec::new();
     let mut start = 0;
@@ -191,7 +199,7 @@ fn split_postgres_statements(sql: &str) -> Vec<String> {
         if depth == 0 && ch == ';' {
             let fragment = sql[start..byte_pos].trim();
             if !fragment.is_empty() {
-                statements.push(fragment.to_string());
+                statements.push(fragment);
             }
             start = byte_pos + 1;
         }
@@ -202,7 +210,7 @@ fn split_postgres_statements(sql: &str) -> Vec<String> {
     if start < sql.len() {
         let fragment = sql[start..].trim();
         if !fragment.is_empty() {
-            statements.push(fragment.to_string());
+            statements.push(fragment);
         }
     }
 
@@ -454,6 +462,57 @@ pub fn evaluate_multi_statement_for_database(
     }
 }
 
+/// Typed instead of a table name on production profiles when the statement
+/// has no single target to name.
+pub const PRODUCTION_CONFIRMATION_PHRASE: &str = "yes, production";
+
+/// Production profiles raise write risk one level. Anything that ends up HIGH
+/// must be confirmed by typing its target table, or
+/// [`PRODUCTION_CONFIRMATION_PHRASE`] when there is no single target.
+pub fn escalate_for_production(
+    database_type: DatabaseType,
+    sql: &str,
+    decision: SqlRiskDecision,
+) -> SqlRiskDecision {
+    if decision.read_only_allowed {
+        return decision;
+    }
+    let risk_level = match decision.risk_level {
+        RiskLevel::Low => RiskLevel::Medium,
+        RiskLevel::Medium | RiskLevel::High => RiskLevel::High,
+    };
+    let confirmation = match decision.confirmation {
+        typed @ ConfirmationType::TableNameInput { .. } => typed,
+        _ if risk_level == RiskLevel::High => ConfirmationType::TableNameInput {
+            target: single_write_target(database_type, sql)
+                .unwrap_or_else(|| PRODUCTION_CONFIRMATION_PHRASE.to_string()),
+        },
+        other => other,
+    };
+    SqlRiskDecision {
+        risk_level,
+        confirmation,
+        read_only_allowed: false,
+    }
+}
+
+fn single_write_target(database_type: DatabaseType, sql: &str) -> Option<String> {
+    let writes: Vec<(String, StatementKind)> = split_statements_for_database(database_type, sql)
+        .into_iter()
+        .map(|stmt| {
+            let kind = classify(&stmt);
+            (stmt, kind)
+        })
+        .filter(|(stmt, kind)| {
+            !evaluate_sql_risk_for_database(database_type, kind, stmt).read_only_allowed
+        })
+        .collect();
+    match writes.as_slice() {
+        [(stmt, kind)] => extract_target_name(stmt, kind),
+        _ => None,
+    }
+}
+
 fn high_acknowledge_label(label: &str) -> SqlRiskDecision {
     SqlRiskDecision {
         risk_level: RiskLevel::High,
@@ -1822,4 +1881,68 @@ mod tests {
             }
         }
     }
+
+    mod escalate_for_production_tests {
+        use super::*;
+
+        fn escalated(sql: &str) -> SqlRiskDecision {
+            let kind = classify(sql);
+            let decision = evaluate_sql_risk(&kind, sql);
+            escalate_for_production(DatabaseType::PostgreSQL, sql, decision)
+        }
+
+        #[test]
+        fn reads_are_unchanged() {
+            let decision = escalated("SELECT * FROM users");
+
+            assert_eq!(decision.risk_level, RiskLevel::Low);
+            assert_eq!(decision.confirmation, ConfirmationType::Immediate);
+        }
+
+        #[test]
+        fn insert_rises_to_medium_without_typing() {
+            let decision = escalated("INSERT INTO users VALUES (1)");
+
+            assert_eq!(decision.risk_level, RiskLevel::Medium);
+            assert_eq!(decision.confirmation, ConfirmationType::Immediate);
+        }
+
+        #[test]
+        fn filtered_delete_requires_the_table_name() {
+            let decision = escalated("DELETE FROM users WHERE id = 1");
+
+            assert_eq!(decision.risk_level, RiskLevel::High);
+            assert_eq!(
+                decision.confirmation,
+                ConfirmationType::TableNameInput {
+                    target: "users".to_string()
+                }
+            );
+        }
+
+        #[test]
+        fn alter_without_extractable_target_requires_the_phrase() {
+            let decision = escalated("ALTER TABLE users ADD COLUMN note text");
+
+            assert_eq!(
+                decision.confirmation,
+                ConfirmationType::TableNameInput {
+                    target: PRODUCTION_CONFIRMATION_PHRASE.to_string()
+                }
+            );
+        }
+
+        #[test]
+        fn existing_typed_target_is_kept() {
+            let decision = escalated("DROP TABLE orders");
+
+            assert_eq!(decision.risk_level, RiskLevel::High);
+            assert_eq!(
+                decision.confirmation,
+                ConfirmationType::TableNameInput {
+                    target: "orders".to_string()
+                }
+            );
+        }
+    }
 }
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::AuditEntry;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AuditLogError {
+    #[error("cache directory is unavailable")]
+    MissingCacheDir,
+    #[error("IO error: {0}")]
+    Io(#[source] Arc<std::io::Error>),
+    #[error("Serialization error: {0}")]
+    Serialization(#[source] Arc<serde_json::Error>),
+    #[error("Task join error: {0}")]
+    Join(#[source] Arc<tokio::task::JoinError>),
+}
+
+impl From<std::io::Error> for AuditLogError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(Arc::new(e))
+    }
+}
+
+impl From<serde_json::Error> for AuditLogError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serialization(Arc::new(e))
+    }
+}
+
+impl From<tokio::task::JoinError> for AuditLogError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        Self::Join(Arc::new(e))
+    }
+}
+
+#[async_trait]
+pub trait AuditLogStore: Send + Sync {
+    /// Appends without trimming; the log is never pruned with the caches.
+    async fn append(&self, project_name: &str, entry: &AuditEntry) -> Result<(), AuditLogError>;
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+/// What the project cache directory holds, grouped the way `:cache` shows it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheCategory {
+    ErDiagrams,
+    Logs,
+    History,
+    Snapshots,
+    Usage,
+}
+
+impl CacheCategory {
+    pub const ALL: [Self; 5] = [
+        Self::ErDiagrams,
+        Self::Logs,
+        Self::History,
+        Self::Snapshots,
+        Self::Usage,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ErDiagrams => "er",
+            Self::Logs => "logs",
+            Self::History => "history",
+            Self::Snapshots => "snapshots",
+            Self::Usage => "usage",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Regenerated on demand, so pruning may drop them without asking.
+    /// Snapshots are kept: they are the only copy offline browsing has of a
+    /// database that is currently unreachable.
+    pub fn is_disposable(self) -> bool {
+        matches!(self, Self::ErDiagrams | Self::Logs)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheCategoryUsage {
+    pub category: CacheCategory,
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// What a clear or prune removed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheCleanup {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Limits applied to disposable categories at startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachePrunePolicy {
+    pub max_age: Duration,
+    pub max_total_bytes: u64,
+}
+
+impl Default for CachePrunePolicy {
+    fn default() -> Self {
+        Self {
+            max_age: Duration::from_hours(30 * 24),
+            max_total_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CacheStoreError {
+    #[error("cache directory is unavailable")]
+    MissingCacheDir,
+    #[error("IO error: {0}")]
+    Io(#[source] Arc<std::io::Error>),
+}
+
+impl From<std::io::Error> for CacheStoreError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(Arc::new(e))
+    }
+}
+
+pub trait CacheStore: Send + Sync {
+    /// One entry per category, empty ones included.
+    fn usage(&self, project_name: &str) -> Result<Vec<CacheCategoryUsage>, CacheStoreError>;
+    /// `None` clears every category.
+    fn clear(
+        &self,
+        project_name: &str,
+        category: Option<CacheCategory>,
+    ) -> Result<CacheCleanup, CacheStoreError>;
+    /// Drops disposable files older than the policy allows, then the oldest
+    /// of them until the whole directory fits the size cap.
+    fn prune(
+        &self,
+        project_name: &str,
+        policy: &CachePrunePolicy,
+    ) -> Result<CacheCleanup, CacheStoreError>;
+}
@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Columns whose values render masked until the user unmasks a result.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MaskingRules {
+    /// `*`-globs matched case-insensitively against every result column.
+    pub column_patterns: Vec<String>,
+    /// Explicit column lists keyed by `schema.table`, applied to its previews.
+    pub table_columns: HashMap<String, Vec<String>>,
+}
+
+impl MaskingRules {
+    pub fn is_empty(&self) -> bool {
+        self.column_patterns.is_empty() && self.table_columns.values().all(Vec::is_empty)
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MaskingRuleError {
+    #[error("Failed to read {path}: {source}", path = path.display())]
+    ReadAt {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("Invalid masking config: {0}")]
+    Parse(String),
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait MaskingRuleReader: Send + Sync {
+    /// An absent file yields no rules.
+    fn read_rules(&self) -> Result<MaskingRules, MaskingRuleError>;
+}
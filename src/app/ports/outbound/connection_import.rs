@@ -0,0 +1,89 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::domain::connection::ConnectionConfig;
+
+/// Where an import candidate was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    ServiceFile,
+    PgPass,
+    DotEnv,
+    DockerCompose,
+    Container,
+}
+
+impl ImportSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ServiceFile => "pg_service.conf",
+            Self::PgPass => ".pgpass",
+            Self::DotEnv => ".env",
+            Self::DockerCompose => "docker compose",
+            Self::Container => "container",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionCandidate {
+    pub name: String,
+    pub source: ImportSource,
+    pub config: ConnectionConfig,
+}
+
+impl ConnectionCandidate {
+    /// `user@host:port/database`, without the password.
+    pub fn summary(&self) -> String {
+        let (host, port, database, username) = match &self.config {
+            ConnectionConfig::PostgreSQL(config) => (
+                &config.host,
+                config.port,
+                &config.database,
+                &config.username,
+            ),
+            ConnectionConfig::MySQL(config) => (
+                &config.host,
+                config.port,
+                &config.database,
+                &config.username,
+            ),
+            ConnectionConfig::SQLite(config) => return config.path().to_string(),
+        };
+        if username.is_empty() {
+            format!("{host}:{port}/{database}")
+        } else {
+            format!("{username}@{host}:{port}/{database}")
+        }
+    }
+}
+
+/// Finds connection settings the user already has on disk.
+#[cfg_attr(test, mockall::automock)]
+pub trait ConnectionCandidateScanner: Send + Sync {
+    /// Unreadable or malformed sources are skipped; most machines have only
+    /// some of them.
+    fn scan(&self, project_root: Option<PathBuf>) -> Vec<ConnectionCandidate>;
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ContainerScanError {
+    #[error("No Docker or Podman socket found (set DOCKER_HOST to point at one)")]
+    SocketNotFound,
+    #[error("Container engine request failed: {0}")]
+    Io(#[source] Arc<std::io::Error>),
+    #[error("Unexpected container engine response: {0}")]
+    InvalidResponse(String),
+}
+
+impl From<std::io::Error> for ContainerScanError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(Arc::new(e))
+    }
+}
+
+/// Lists running local containers that publish a database port.
+#[cfg_attr(test, mockall::automock)]
+pub trait ContainerDatabaseScanner: Send + Sync {
+    fn scan(&self) -> Result<Vec<ConnectionCandidate>, ContainerScanError>;
+}
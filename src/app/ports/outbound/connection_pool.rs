@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::domain::PoolSettings;
+
+/// Driver connections held for one DSN at the moment of asking.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Checked out by a running query or held by an open transaction.
+    pub in_use: usize,
+    pub idle: usize,
+    /// Idle connections the pool keeps before closing returned ones.
+    pub size: usize,
+}
+
+/// Driver connections an adapter keeps open between queries, keyed by DSN.
+#[async_trait]
+pub trait ConnectionPool: Send + Sync {
+    /// Applies the profile's pool size to `dsn` and opens its warmup
+    /// connections. Failures are left for the first query to report.
+    async fn warm_up(&self, dsn: &str, settings: PoolSettings);
+
+    /// `None` when queries on `dsn` do not go through the pool.
+    fn stats(&self, dsn: &str) -> Option<PoolStats>;
+
+    /// Closes every pooled connection and waits for the server side to be
+    /// told; connections still checked out close when their query ends.
+    async fn drain(&self);
+}
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt;
 use std::sync::Arc;
 
+use crate::domain::{LockConflict, LockConflictKind};
 use crate::policy::password_masking::mask_password;
 
 pub const SQLITE_TABLE_LIST_REQUIRED_MARKER: &str = "SQLITE_TABLE_LIST_REQUIRED";
@@ -11,6 +12,7 @@ pub const SQLITE_SAFE_MODE_REQUIRED_MARKER: &str = "SQLITE_SAFE_MODE_REQUIRED";
 pub enum DatabaseCli {
     Psql,
     Sqlite3,
+    Mysql,
 }
 
 impl DatabaseCli {
@@ -18,6 +20,7 @@ impl DatabaseCli {
         match self {
             Self::Psql => "Database CLI not found",
             Self::Sqlite3 => "sqlite3 not found",
+            Self::Mysql => "mysql client not found",
         }
     }
 
@@ -25,6 +28,7 @@ impl DatabaseCli {
         match self {
             Self::Psql => "Install the database client and add it to PATH",
             Self::Sqlite3 => "Install sqlite3 and add it to PATH",
+            Self::Mysql => "Install the mysql client and add it to PATH",
         }
     }
 }
@@ -44,6 +48,8 @@ pub enum DbOperationError {
     UniqueViolation(String),
     #[error("Operation blocked by lock or timeout")]
     LockTimeout(String),
+    #[error("Deadlock detected")]
+    Deadlock(String),
     #[error("Database object not found")]
     ObjectMissing(String),
     #[error("Query failed")]
@@ -80,6 +86,7 @@ impl DbOperationError {
             Self::ForeignKeyViolation(_) => "Foreign key constraint violation",
             Self::UniqueViolation(_) => "Unique constraint violation",
             Self::LockTimeout(_) => "Operation blocked by lock or timeout",
+            Self::Deadlock(_) => "Deadlock detected",
             Self::ObjectMissing(_) => "Database object not found",
             Self::QueryFailed(_) => "Query failed",
             Self::UnsupportedOperation(_) => "Unsupported operation",
@@ -106,6 +113,7 @@ impl DbOperationError {
             Self::LockTimeout(_) => {
                 "Retry; if it persists, check for blocking transactions or timeout settings"
             }
+            Self::Deadlock(_) => "Retry the transaction; take locks in the same order everywhere",
             Self::ObjectMissing(_) => "Check the table, column, or connected database",
             Self::QueryFailed(_) => "Review the database error details and SQL",
             Self::UnsupportedOperation(_) => "Use a supported operation for this database",
@@ -130,6 +138,7 @@ impl DbOperationError {
             | Self::ForeignKeyViolation(details)
             | Self::UniqueViolation(details)
             | Self::LockTimeout(details)
+            | Self::Deadlock(details)
             | Self::ObjectMissing(details)
             | Self::QueryFailed(details)
             | Self::UnsupportedOperation(details)
@@ -144,6 +153,25 @@ impl DbOperationError {
         }
     }
 
+    /// The connection dropped under the statement, so a fresh one may well
+    /// succeed where this one failed.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::ConnectionLost(_))
+    }
+
+    /// Lock failures carry enough detail to look up the blocking backends.
+    pub fn lock_conflict(&self) -> Option<LockConflict> {
+        let kind = match self {
+            Self::LockTimeout(_) => LockConflictKind::LockTimeout,
+            Self::Deadlock(_) => LockConflictKind::Deadlock,
+            _ => return None,
+        };
+        Some(LockConflict::from_error_details(
+            kind,
+            self.raw_details().as_ref(),
+        ))
+    }
+
     pub fn masked_details(&self) -> String {
         mask_password(self.raw_details().as_ref())
     }
@@ -216,6 +244,7 @@ mod tests {
         #[case(DbOperationError::ForeignKeyViolation("boom".to_string()))]
         #[case(DbOperationError::UniqueViolation("boom".to_string()))]
         #[case(DbOperationError::LockTimeout("boom".to_string()))]
+        #[case(DbOperationError::Deadlock("boom".to_string()))]
         #[case(DbOperationError::ObjectMissing("boom".to_string()))]
         #[case(DbOperationError::QueryFailed("boom".to_string()))]
         #[case(DbOperationError::UnsupportedOperation("boom".to_string()))]
@@ -241,6 +270,40 @@ mod tests {
         }
     }
 
+    mod lock_conflicts {
+        use super::*;
+
+        #[test]
+        fn deadlock_exposes_blocking_pids() {
+            let error = DbOperationError::Deadlock(
+                "ERROR:  40P01: deadlock detected\nDETAIL:  Process 7 waits for ShareLock on transaction 9; blocked by process 8.".to_string(),
+            );
+
+            let conflict = error.lock_conflict().unwrap();
+
+            assert_eq!(conflict.kind, LockConflictKind::Deadlock);
+            assert_eq!(conflict.blocker_pids, vec![8]);
+        }
+
+        #[test]
+        fn other_errors_are_not_lock_conflicts() {
+            let error = DbOperationError::QueryFailed("boom".to_string());
+
+            assert_eq!(error.lock_conflict(), None);
+        }
+    }
+
+    #[rstest]
+    #[case(DbOperationError::ConnectionLost("reset".to_string()), true)]
+    #[case(DbOperationError::ConnectionFailed("refused".to_string()), false)]
+    #[case(DbOperationError::Timeout("slow".to_string()), false)]
+    fn only_lost_connections_are_transient(
+        #[case] error: DbOperationError,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(error.is_transient(), expected);
+    }
+
     mod masking {
         use super::*;
 
@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use crate::domain::ErTableInfo;
+use crate::domain::{ErDiagramOptions, ErTableInfo};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ErExportError {
@@ -16,6 +16,7 @@ pub trait ErDiagramExporter: Send + Sync {
     fn generate_and_export(
         &self,
         tables: &[ErTableInfo],
+        options: &ErDiagramOptions,
         filename: &str,
         cache_dir: &Path,
         browser: Option<&str>,
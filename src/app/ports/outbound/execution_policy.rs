@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Team guardrails for one connection, checked before a query is dispatched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionPolicy {
+    /// Only read-only statements may run.
+    pub select_only: bool,
+    /// Largest explicit LIMIT a SELECT may ask for.
+    pub max_rows: Option<usize>,
+    /// LIMIT appended to SELECTs that have none.
+    pub auto_limit: Option<usize>,
+    /// UPDATE and DELETE must carry a WHERE clause.
+    pub require_where: bool,
+}
+
+impl ExecutionPolicy {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Policies keyed by connection name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionPolicies(HashMap<String, ExecutionPolicy>);
+
+impl ExecutionPolicies {
+    pub fn new(policies: HashMap<String, ExecutionPolicy>) -> Self {
+        Self(policies)
+    }
+
+    pub fn for_connection(&self, name: &str) -> Option<&ExecutionPolicy> {
+        self.0.get(name).filter(|policy| !policy.is_empty())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.values().all(ExecutionPolicy::is_empty)
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ExecutionPolicyError {
+    #[error("Failed to read {path}: {source}", path = path.display())]
+    ReadAt {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("Invalid policy config: {0}")]
+    Parse(String),
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ExecutionPolicyReader: Send + Sync {
+    /// Reads the project's shared policy file; an absent file yields no policies.
+    fn read_policies(&self, project_root: &Path)
+    -> Result<ExecutionPolicies, ExecutionPolicyError>;
+}
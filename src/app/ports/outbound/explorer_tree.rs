@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::domain::ExplorerTreeLayout;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ExplorerTreeError {
+    #[error("cache directory is unavailable")]
+    MissingCacheDir,
+    #[error("IO error: {0}")]
+    Io(#[source] Arc<std::io::Error>),
+    #[error("Serialization error: {0}")]
+    Serialization(#[source] Arc<serde_json::Error>),
+}
+
+impl From<std::io::Error> for ExplorerTreeError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(Arc::new(e))
+    }
+}
+
+impl From<serde_json::Error> for ExplorerTreeError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serialization(Arc::new(e))
+    }
+}
+
+pub trait ExplorerTreeStore: Send + Sync {
+    fn load(&self, project_name: &str) -> Result<ExplorerTreeLayout, ExplorerTreeError>;
+    fn save(
+        &self,
+        project_name: &str,
+        layout: &ExplorerTreeLayout,
+    ) -> Result<(), ExplorerTreeError>;
+}
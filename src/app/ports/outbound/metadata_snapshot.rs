@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::connection::ConnectionId;
+use crate::domain::{DatabaseMetadata, MetadataSnapshot, Table};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MetadataSnapshotError {
+    #[error("cache directory is unavailable")]
+    MissingCacheDir,
+    #[error("IO error: {0}")]
+    Io(#[source] Arc<std::io::Error>),
+    #[error("Serialization error: {0}")]
+    Serialization(#[source] Arc<serde_json::Error>),
+    #[error("Task join error: {0}")]
+    Join(#[source] Arc<tokio::task::JoinError>),
+}
+
+impl From<std::io::Error> for MetadataSnapshotError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(Arc::new(e))
+    }
+}
+
+impl From<serde_json::Error> for MetadataSnapshotError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serialization(Arc::new(e))
+    }
+}
+
+impl From<tokio::task::JoinError> for MetadataSnapshotError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        Self::Join(Arc::new(e))
+    }
+}
+
+#[async_trait]
+pub trait MetadataSnapshotStore: Send + Sync {
+    async fn save_metadata(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+        metadata: &DatabaseMetadata,
+    ) -> Result<(), MetadataSnapshotError>;
+
+    /// Replaces any stored detail for the table.
+    async fn save_table_detail(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+        table: &Table,
+    ) -> Result<(), MetadataSnapshotError>;
+
+    /// Stores prefetched columns and foreign keys unless a full detail is
+    /// already stored, so completion prefetch never downgrades the snapshot.
+    async fn save_table_columns(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+        table: &Table,
+    ) -> Result<(), MetadataSnapshotError>;
+
+    /// `None` when no metadata has been stored for the connection yet.
+    async fn load(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+    ) -> Result<Option<MetadataSnapshot>, MetadataSnapshotError>;
+}
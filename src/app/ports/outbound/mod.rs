@@ -4,30 +4,55 @@
 //! stay free of adapter-specific types.
 
 pub mod access_mode;
+pub mod audit_log;
+pub mod cache_store;
 pub mod cached_result_exporter;
 pub mod clipboard;
+pub mod column_masking;
 pub mod config_writer;
+pub mod connection_import;
+pub mod connection_pool;
 pub mod connection_store;
 pub mod db_operation_error;
 pub mod ddl_generator;
 pub mod dsn_builder;
 pub mod er_exporter;
 pub mod er_log_writer;
+pub mod execution_policy;
+pub mod explorer_tree;
 pub mod folder_opener;
 pub mod metadata;
+pub mod metadata_snapshot;
+pub mod naming_convention;
 pub mod query_executor;
 pub mod query_history;
+pub mod query_hook;
+pub mod release_check;
 pub mod renderer;
 pub mod service_file;
 pub mod settings_store;
+pub mod snippet;
 pub mod sql_dialect;
+pub mod sql_file;
 pub mod sqlite_diagnostics;
 pub mod sqlite_path_validator;
+pub mod temp_file_writer;
+pub mod usage_stats;
 
 pub use access_mode::AccessMode;
+pub use audit_log::{AuditLogError, AuditLogStore};
+pub use cache_store::{
+    CacheCategory, CacheCategoryUsage, CacheCleanup, CachePrunePolicy, CacheStore, CacheStoreError,
+};
 pub use cached_result_exporter::CachedResultExporter;
 pub use clipboard::{ClipboardError, ClipboardWriter};
+pub use column_masking::{MaskingRuleError, MaskingRuleReader, MaskingRules};
 pub use config_writer::{ConfigWriter, ConfigWriterError};
+pub use connection_import::{
+    ConnectionCandidate, ConnectionCandidateScanner, ContainerDatabaseScanner, ContainerScanError,
+    ImportSource,
+};
+pub use connection_pool::{ConnectionPool, PoolStats};
 pub use connection_store::{ConnectionStore, ConnectionStoreError};
 pub use db_operation_error::{
     DatabaseCli, DbOperationError, SQLITE_SAFE_MODE_REQUIRED_MARKER,
@@ -37,13 +62,34 @@ pub use ddl_generator::DdlGenerator;
 pub use dsn_builder::DsnBuilder;
 pub use er_exporter::{ErDiagramExporter, ErExportError, ErExportResult};
 pub use er_log_writer::ErLogWriter;
+pub use execution_policy::{
+    ExecutionPolicies, ExecutionPolicy, ExecutionPolicyError, ExecutionPolicyReader,
+};
+pub use explorer_tree::{ExplorerTreeError, ExplorerTreeStore};
 pub use folder_opener::{FolderOpenError, FolderOpener};
 pub use metadata::MetadataProvider;
-pub use query_executor::QueryExecutor;
+pub use metadata_snapshot::{MetadataSnapshotError, MetadataSnapshotStore};
+pub use naming_convention::{
+    NAME_TEMPLATE_COLUMNS, NAME_TEMPLATE_TABLE, NamingConventionError, NamingConventionReader,
+    NamingConventions,
+};
+pub use query_executor::{
+    PreviewOptions, QueryExecutor, TransactionEnd, preview_order_columns, preview_where_clause,
+    project_preview_columns,
+};
 pub use query_history::{QueryHistoryError, QueryHistoryStore};
+pub use query_hook::{
+    HookEvent, HookInvocation, HookTarget, QueryHook, QueryHookError, QueryHookReader,
+    QueryHookRunner,
+};
+pub use release_check::{ReleaseCheckError, ReleaseChecker, ReleaseInfo};
 pub use renderer::{CellDetailViewport, RenderError, RenderOutput, RenderResult, Renderer};
 pub use service_file::{PgServiceEntryReader, ServiceFileError};
 pub use settings_store::{AppSettings, SettingsStore, SettingsStoreError};
+pub use snippet::{Snippet, SnippetError, SnippetStore};
 pub use sql_dialect::SqlDialect;
+pub use sql_file::{SqlFile, SqlFileError, SqlFileScanner, SqlFileStore};
 pub use sqlite_diagnostics::SqliteDiagnosticsProvider;
 pub use sqlite_path_validator::SqlitePathValidator;
+pub use temp_file_writer::{TempFileError, TempFileWriter};
+pub use usage_stats::{UsageStatsError, UsageStatsStore};
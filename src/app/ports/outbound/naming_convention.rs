@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub const NAME_TEMPLATE_TABLE: &str = "{table}";
+/// Key columns joined by `_`.
+pub const NAME_TEMPLATE_COLUMNS: &str = "{columns}";
+
+/// Rules checked by `:audit naming`; a `None` template skips that rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamingConventions {
+    pub snake_case: bool,
+    pub reserved_words: bool,
+    pub primary_key: Option<String>,
+    pub foreign_key: Option<String>,
+    pub index: Option<String>,
+}
+
+impl Default for NamingConventions {
+    fn default() -> Self {
+        Self {
+            snake_case: true,
+            reserved_words: true,
+            primary_key: Some("{table}_pkey".to_string()),
+            foreign_key: Some("{table}_{columns}_fkey".to_string()),
+            index: Some("{table}_{columns}_idx".to_string()),
+        }
+    }
+}
+
+impl NamingConventions {
+    pub fn has_rules(&self) -> bool {
+        self.snake_case
+            || self.reserved_words
+            || self.primary_key.is_some()
+            || self.foreign_key.is_some()
+            || self.index.is_some()
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum NamingConventionError {
+    #[error("Failed to read {path}: {source}", path = path.display())]
+    ReadAt {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("Invalid naming config: {0}")]
+    Parse(String),
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait NamingConventionReader: Send + Sync {
+    /// An absent file yields the default conventions.
+    fn read_conventions(&self) -> Result<NamingConventions, NamingConventionError>;
+}
@@ -6,6 +6,34 @@ use crate::domain::{QueryResult, WriteExecutionResult};
 
 use super::{AccessMode, DbOperationError};
 
+/// How `:commit` / `:rollback` finish a session transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionEnd {
+    Commit,
+    Rollback,
+}
+
+impl TransactionEnd {
+    pub fn statement(self) -> &'static str {
+        match self {
+            Self::Commit => "COMMIT",
+            Self::Rollback => "ROLLBACK",
+        }
+    }
+}
+
+/// What a table preview orders by, filters on and selects.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PreviewOptions {
+    /// Replaces the default key order; adapters keep the key columns behind
+    /// it so rows never shift between pages.
+    pub sort_column: Option<String>,
+    /// A user-typed predicate applied as the preview's `WHERE` clause.
+    pub filter: Option<String>,
+    /// Columns selected instead of `*`; empty selects every column.
+    pub columns: Vec<String>,
+}
+
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait QueryExecutor: Send + Sync {
@@ -14,6 +42,7 @@ pub trait QueryExecutor: Send + Sync {
         dsn: &str,
         schema: &str,
         table: &str,
+        options: PreviewOptions,
         limit: usize,
         offset: usize,
     ) -> Result<QueryResult, DbOperationError>;
@@ -37,4 +66,103 @@ pub trait QueryExecutor: Send + Sync {
         query: &str,
         file_name: &str,
     ) -> Result<PathBuf, DbOperationError>;
+
+    /// Opens a transaction on a connection held for `dsn`; previews, adhoc
+    /// statements and writes on `dsn` run inside it until it is ended.
+    async fn begin_transaction(
+        &self,
+        dsn: &str,
+        access_mode: AccessMode,
+    ) -> Result<(), DbOperationError>;
+    async fn end_transaction(&self, dsn: &str, end: TransactionEnd)
+    -> Result<(), DbOperationError>;
+}
+
+/// Preview ordering for a chosen sort column, with the table's key columns
+/// behind it as tiebreakers.
+pub fn preview_order_columns(sort_column: Option<&str>, key_columns: &[String]) -> Vec<String> {
+    let Some(sort_column) = sort_column else {
+        return key_columns.to_vec();
+    };
+    std::iter::once(sort_column.to_string())
+        .chain(
+            key_columns
+                .iter()
+                .filter(|column| column.as_str() != sort_column)
+                .cloned(),
+        )
+        .collect()
+}
+
+/// The table's `columns` narrowed to a preview projection, in projection
+/// order. Names the table no longer has are dropped.
+pub fn project_preview_columns(columns: &[String], projection: &[String]) -> Vec<String> {
+    if projection.is_empty() {
+        return columns.to_vec();
+    }
+    projection
+        .iter()
+        .filter(|column| columns.contains(column))
+        .cloned()
+        .collect()
+}
+
+/// ` WHERE (…)` for a preview filter; parenthesised so a top-level `OR`
+/// cannot escape into the clauses that follow.
+pub fn preview_where_clause(filter: Option<&str>) -> String {
+    filter
+        .map(str::trim)
+        .filter(|filter| !filter.is_empty())
+        .map_or_else(String::new, |filter| format!(" WHERE ({filter})"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(columns: &[&str]) -> Vec<String> {
+        columns.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn no_sort_column_keeps_key_order() {
+        assert_eq!(preview_order_columns(None, &keys(&["id"])), keys(&["id"]));
+    }
+
+    #[test]
+    fn sort_column_leads_with_keys_as_tiebreakers() {
+        assert_eq!(
+            preview_order_columns(Some("created_at"), &keys(&["tenant", "id"])),
+            keys(&["created_at", "tenant", "id"])
+        );
+    }
+
+    #[test]
+    fn where_clause_wraps_the_filter() {
+        assert_eq!(preview_where_clause(None), "");
+        assert_eq!(preview_where_clause(Some("  ")), "");
+        assert_eq!(
+            preview_where_clause(Some("a = 1 OR b = 2")),
+            " WHERE (a = 1 OR b = 2)"
+        );
+    }
+
+    #[test]
+    fn projection_keeps_its_order_and_drops_missing_columns() {
+        let columns = keys(&["id", "name", "payload"]);
+
+        assert_eq!(project_preview_columns(&columns, &[]), columns);
+        assert_eq!(
+            project_preview_columns(&columns, &keys(&["name", "gone", "id"])),
+            keys(&["name", "id"])
+        );
+    }
+
+    #[test]
+    fn sort_column_that_is_a_key_is_not_repeated() {
+        assert_eq!(
+            preview_order_columns(Some("id"), &keys(&["tenant", "id"])),
+            keys(&["id", "tenant"])
+        );
+    }
 }
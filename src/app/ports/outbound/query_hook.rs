@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    /// An ad-hoc query that succeeded after running at least the hook's threshold.
+    SlowQuery,
+    QueryError,
+    WriteExecuted,
+}
+
+impl HookEvent {
+    pub fn config_value(self) -> &'static str {
+        match self {
+            Self::SlowQuery => "slow_query",
+            Self::QueryError => "query_error",
+            Self::WriteExecuted => "write_executed",
+        }
+    }
+
+    pub fn from_config_value(value: &str) -> Option<Self> {
+        match value.trim() {
+            "slow_query" => Some(Self::SlowQuery),
+            "query_error" => Some(Self::QueryError),
+            "write_executed" => Some(Self::WriteExecuted),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookTarget {
+    /// Run through the platform shell with the rendered payload on stdin.
+    Command(String),
+    /// POST the rendered payload to the URL.
+    Webhook(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryHook {
+    pub event: HookEvent,
+    pub min_duration_ms: u64,
+    pub target: HookTarget,
+    /// `{{event}}`, `{{connection}}`, `{{query}}`, `{{duration_ms}}`,
+    /// `{{rows}}` and `{{error}}` are substituted; `None` sends a JSON object.
+    pub payload_template: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookInvocation {
+    pub target: HookTarget,
+    pub payload: String,
+    /// Exposed to commands so they never need the values spliced into shell text.
+    pub env: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum QueryHookError {
+    #[error("Failed to read {path}: {source}", path = path.display())]
+    ReadAt {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("Invalid hook config: {0}")]
+    Parse(String),
+    #[error("Hook failed: {0}")]
+    Execution(String),
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait QueryHookReader: Send + Sync {
+    /// An absent hook file yields no hooks rather than an error.
+    fn read_hooks(&self) -> Result<Vec<QueryHook>, QueryHookError>;
+}
+
+#[async_trait]
+pub trait QueryHookRunner: Send + Sync {
+    async fn run(&self, invocation: &HookInvocation) -> Result<(), QueryHookError>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseInfo {
+    /// Tag without the leading `v`, e.g. `1.16.0`.
+    pub version: String,
+    pub url: String,
+    /// Release notes as published; may be empty.
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReleaseCheckError {
+    #[error("Release check failed: {0}")]
+    Request(String),
+    #[error("Unexpected release response: {0}")]
+    Parse(String),
+}
+
+#[async_trait]
+pub trait ReleaseChecker: Send + Sync {
+    async fn latest_release(&self) -> Result<ReleaseInfo, ReleaseCheckError>;
+}
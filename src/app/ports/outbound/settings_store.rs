@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
-use crate::model::shared::settings::KeymapPreset;
+use crate::i18n::Locale;
+use crate::model::shared::settings::{KeymapPreset, KeywordCase, PrefetchScope};
 use crate::model::shared::theme_id::ThemeId;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -8,6 +9,11 @@ pub struct AppSettings {
     pub theme_id: ThemeId,
     pub keymap_preset: KeymapPreset,
     pub er_browser: Option<String>,
+    pub keyword_case: Option<KeywordCase>,
+    pub locale: Option<Locale>,
+    pub usage_stats: bool,
+    pub update_check: bool,
+    pub prefetch_scope: PrefetchScope,
 }
 
 impl Default for AppSettings {
@@ -16,6 +22,11 @@ impl Default for AppSettings {
             theme_id: ThemeId::Default,
             keymap_preset: KeymapPreset::Default,
             er_browser: None,
+            keyword_case: None,
+            locale: None,
+            usage_stats: false,
+            update_check: false,
+            prefetch_scope: PrefetchScope::All,
         }
     }
 }
@@ -0,0 +1,36 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    pub name: String,
+    pub sql: String,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SnippetError {
+    #[error("Failed to read {path}: {source}", path = path.display())]
+    ReadAt {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("Failed to write {path}: {source}", path = path.display())]
+    WriteAt {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("Invalid snippets file {path}: {message}", path = path.display())]
+    Parse { path: PathBuf, message: String },
+}
+
+/// Backs `:save` and the snippet picker: named queries kept with the project.
+pub trait SnippetStore: Send + Sync {
+    /// Snippets saved under `root`, sorted by name; no file means none yet.
+    fn load(&self, root: &Path) -> Result<Vec<Snippet>, SnippetError>;
+
+    /// Adds `snippet`, replacing one with the same name, and returns the
+    /// updated list.
+    fn save(&self, root: &Path, snippet: &Snippet) -> Result<Vec<Snippet>, SnippetError>;
+}
@@ -1,4 +1,9 @@
-use crate::domain::{DatabaseType, QueryValue};
+use crate::domain::{
+    Column, DatabaseType, ForeignKey, Index, IndexMaintenanceKind, LockConflict, MaintenanceKind,
+    QueryValue,
+};
+
+use super::NamingConventions;
 
 pub trait SqlDialect: Send + Sync {
     fn build_explain_sql(&self, database_type: DatabaseType, query: &str) -> Option<String>;
@@ -20,4 +25,119 @@ pub trait SqlDialect: Send + Sync {
         table: &str,
         pk_pairs_per_row: &[Vec<(String, QueryValue)>],
     ) -> String;
+    /// `None` when the engine has no logical replication catalogs.
+    fn build_replication_overview_sql(
+        &self,
+        database_type: DatabaseType,
+        table: Option<&str>,
+    ) -> Option<String>;
+    /// Random `limit` rows of the table; `row_estimate` lets the engine
+    /// sample pages instead of shuffling every row of a large table.
+    fn build_sample_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        limit: usize,
+        row_estimate: Option<i64>,
+    ) -> String;
+    /// Exact `count(*)` of the table.
+    fn build_row_count_sql(&self, database_type: DatabaseType, schema: &str, table: &str)
+    -> String;
+    /// Script that reads the table as `role` so its RLS policies apply; the
+    /// role switch must not outlive the script's transaction.
+    fn build_rls_test_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        role: &str,
+    ) -> Option<String>;
+    fn build_maintenance_sql(
+        &self,
+        database_type: DatabaseType,
+        kind: MaintenanceKind,
+        schema: &str,
+        table: &str,
+    ) -> Option<String>;
+    /// Yields `phase, done, total` while the maintenance command runs.
+    fn build_maintenance_progress_sql(
+        &self,
+        database_type: DatabaseType,
+        kind: MaintenanceKind,
+        schema: &str,
+        table: &str,
+    ) -> Option<String>;
+    /// Statements are executed one at a time, each outside a transaction.
+    fn build_index_maintenance_sql(
+        &self,
+        database_type: DatabaseType,
+        kind: IndexMaintenanceKind,
+        schema: &str,
+        index: &Index,
+    ) -> Option<Vec<String>>;
+    /// Yields `phase, done, total` while an index on `table` is being built.
+    fn build_index_maintenance_progress_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+    ) -> Option<String>;
+    /// Yields `item, value, source, suggestion` rows for the table.
+    fn build_autovacuum_report_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+    ) -> Option<String>;
+    /// Yields one `table_name, total_size, table_size, index_size` row per
+    /// `(schema, table)`, largest first.
+    fn build_table_sizes_sql(
+        &self,
+        database_type: DatabaseType,
+        tables: &[(String, String)],
+    ) -> Option<String>;
+    /// Yields `name, setting, unit, reset_val, boot_val, source, category,
+    /// short_desc, pending_restart` rows ordered by name.
+    fn build_server_settings_sql(&self, database_type: DatabaseType) -> Option<String>;
+    /// Yields `schema, table_name, index_name, scans, size_bytes, is_unique`
+    /// rows for every user index.
+    fn build_index_usage_sql(&self, database_type: DatabaseType) -> Option<String>;
+    /// Yields one `constraint_name, references, orphan_rows, status` row per
+    /// foreign key, counting child rows whose parent key is missing.
+    fn build_fk_orphan_check_sql(
+        &self,
+        database_type: DatabaseType,
+        foreign_keys: &[ForeignKey],
+    ) -> Option<String>;
+    /// Yields `table_name, column_name, references, sampled_rows, orphan_rows,
+    /// statement` rows for `*_id` columns that look like unconstrained FKs.
+    fn build_fk_suggestion_sql(&self, database_type: DatabaseType) -> Option<String>;
+    /// Yields `severity, kind, table_name, name, rule, suggestion` rows,
+    /// errors first.
+    fn build_naming_audit_sql(
+        &self,
+        database_type: DatabaseType,
+        conventions: &NamingConventions,
+    ) -> Option<String>;
+    /// Yields `pid, application_name, usename, state, held_secs, query` rows
+    /// for the sessions most likely behind a lock failure.
+    fn build_lock_blockers_sql(
+        &self,
+        database_type: DatabaseType,
+        conflict: &LockConflict,
+    ) -> Option<String>;
+    /// Yields one `data_type` row per result column of `query`, in order,
+    /// without running it.
+    fn build_result_types_sql(&self, database_type: DatabaseType, query: &str) -> Option<String>;
+    /// Yields `stat, value, frequency` rows describing the values of
+    /// `column`; see [`crate::domain::ColumnDistribution::from_result`].
+    fn build_column_distribution_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        column: &Column,
+        row_estimate: Option<i64>,
+    ) -> Option<String>;
 }
@@ -0,0 +1,43 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlFile {
+    /// Relative to the scanned root, `/`-separated.
+    pub path: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SqlFileError {
+    #[error("Failed to scan {path}: {source}", path = path.display())]
+    ScanAt {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("Failed to read {path}: {source}", path = path.display())]
+    ReadAt {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("Failed to write {path}: {source}", path = path.display())]
+    WriteAt {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+}
+
+pub trait SqlFileScanner: Send + Sync {
+    /// `.sql` files below `root` sorted by path, skipping hidden and build
+    /// directories and files too large to preview.
+    fn scan(&self, root: &Path) -> Result<Vec<SqlFile>, SqlFileError>;
+}
+
+/// Backs `:edit`: the SQL modal buffer mirrors one file on disk.
+pub trait SqlFileStore: Send + Sync {
+    fn read(&self, path: &Path) -> Result<String, SqlFileError>;
+    fn write(&self, path: &Path, content: &str) -> Result<(), SqlFileError>;
+}
@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum TempFileError {
+    #[error("Failed to write temp file: {0}")]
+    Io(#[source] Arc<std::io::Error>),
+    #[error("Spill encryption is on but no key is available")]
+    KeyUnavailable,
+}
+
+impl From<std::io::Error> for TempFileError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(Arc::new(e))
+    }
+}
+
+pub trait TempFileWriter: Send + Sync {
+    fn write_temp_file(&self, file_name: &str, content: &str) -> Result<PathBuf, TempFileError>;
+}
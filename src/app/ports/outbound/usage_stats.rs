@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::domain::UsageStats;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum UsageStatsError {
+    #[error("cache directory is unavailable")]
+    MissingCacheDir,
+    #[error("IO error: {0}")]
+    Io(#[source] Arc<std::io::Error>),
+    #[error("Serialization error: {0}")]
+    Serialization(#[source] Arc<serde_json::Error>),
+}
+
+impl From<std::io::Error> for UsageStatsError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(Arc::new(e))
+    }
+}
+
+impl From<serde_json::Error> for UsageStatsError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serialization(Arc::new(e))
+    }
+}
+
+pub trait UsageStatsStore: Send + Sync {
+    fn load(&self, project_name: &str) -> Result<UsageStats, UsageStatsError>;
+    fn save(&self, project_name: &str, stats: &UsageStats) -> Result<(), UsageStatsError>;
+}
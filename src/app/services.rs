@@ -4,7 +4,10 @@ use std::sync::Arc;
 
 use super::ports::outbound::{DdlGenerator, DsnBuilder, SqlDialect};
 #[cfg(any(test, feature = "test-support"))]
-use crate::domain::{ConnectionProfile, DatabaseType, QueryValue, Table};
+use crate::domain::{
+    Column, ConnectionProfile, DatabaseType, ForeignKey, Index, IndexMaintenanceKind, LockConflict,
+    MaintenanceKind, QueryValue, Table,
+};
 pub struct AppServices {
     pub ddl_generator: Arc<dyn DdlGenerator>,
     pub sql_dialect: Arc<dyn SqlDialect>,
@@ -16,6 +19,7 @@ impl AppServices {
     #[doc(hidden)]
     pub fn stub() -> Self {
         use crate::policy::sql::sqlite_explain::build_sqlite_explain_query_plan_sql;
+        use crate::ports::outbound::NamingConventions;
 
         fn quote_literal(value: &str) -> String {
             format!("'{}'", value.replace('\'', "''"))
@@ -33,7 +37,9 @@ impl AppServices {
                     }
                     match database_type {
                         DatabaseType::PostgreSQL => format!("'\\x{hex}'"),
-                        DatabaseType::SQLite => format!("X'{}'", hex.to_uppercase()),
+                        DatabaseType::SQLite | DatabaseType::MySQL => {
+                            format!("X'{}'", hex.to_uppercase())
+                        }
                     }
                 }
             }
@@ -65,7 +71,9 @@ impl AppServices {
                 query: &str,
             ) -> Option<String> {
                 match database_type {
-                    DatabaseType::PostgreSQL => Some(format!("EXPLAIN {query}")),
+                    DatabaseType::PostgreSQL | DatabaseType::MySQL => {
+                        Some(format!("EXPLAIN {query}"))
+                    }
                     DatabaseType::SQLite => build_sqlite_explain_query_plan_sql(query),
                 }
             }
@@ -77,7 +85,227 @@ impl AppServices {
             ) -> Option<String> {
                 match database_type {
                     DatabaseType::PostgreSQL => Some(format!("EXPLAIN ANALYZE {query}")),
-                    DatabaseType::SQLite => None,
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_replication_overview_sql(
+                &self,
+                database_type: DatabaseType,
+                table: Option<&str>,
+            ) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => {
+                        Some(format!("REPLICATION {}", table.unwrap_or("*")))
+                    }
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_sample_sql(
+                &self,
+                _database_type: DatabaseType,
+                schema: &str,
+                table: &str,
+                limit: usize,
+                _row_estimate: Option<i64>,
+            ) -> String {
+                format!("SAMPLE {schema}.{table} {limit}")
+            }
+
+            fn build_row_count_sql(
+                &self,
+                _database_type: DatabaseType,
+                schema: &str,
+                table: &str,
+            ) -> String {
+                format!("COUNT {schema}.{table}")
+            }
+
+            fn build_rls_test_sql(
+                &self,
+                database_type: DatabaseType,
+                schema: &str,
+                table: &str,
+                role: &str,
+            ) -> Option<String> {
+                (database_type == DatabaseType::PostgreSQL)
+                    .then(|| format!("AS {role} SELECT {schema}.{table}"))
+            }
+
+            fn build_maintenance_sql(
+                &self,
+                database_type: DatabaseType,
+                kind: MaintenanceKind,
+                schema: &str,
+                table: &str,
+            ) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => {
+                        Some(format!("{} \"{schema}\".\"{table}\"", kind.label()))
+                    }
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_maintenance_progress_sql(
+                &self,
+                database_type: DatabaseType,
+                _kind: MaintenanceKind,
+                schema: &str,
+                table: &str,
+            ) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => Some(format!("PROGRESS \"{schema}\".\"{table}\"")),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_index_maintenance_sql(
+                &self,
+                database_type: DatabaseType,
+                kind: IndexMaintenanceKind,
+                schema: &str,
+                index: &Index,
+            ) -> Option<Vec<String>> {
+                match database_type {
+                    DatabaseType::PostgreSQL => Some(vec![format!(
+                        "{} \"{schema}\".\"{}\"",
+                        kind.label(),
+                        index.name
+                    )]),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_index_maintenance_progress_sql(
+                &self,
+                database_type: DatabaseType,
+                schema: &str,
+                table: &str,
+            ) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => {
+                        Some(format!("INDEX PROGRESS \"{schema}\".\"{table}\""))
+                    }
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_autovacuum_report_sql(
+                &self,
+                database_type: DatabaseType,
+                schema: &str,
+                table: &str,
+            ) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => {
+                        Some(format!("AUTOVACUUM \"{schema}\".\"{table}\""))
+                    }
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_table_sizes_sql(
+                &self,
+                database_type: DatabaseType,
+                tables: &[(String, String)],
+            ) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => Some(format!(
+                        "TABLE SIZES {}",
+                        tables
+                            .iter()
+                            .map(|(schema, table)| format!("\"{schema}\".\"{table}\""))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_server_settings_sql(&self, database_type: DatabaseType) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => Some("SERVER SETTINGS".to_string()),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_index_usage_sql(&self, database_type: DatabaseType) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => Some("INDEX USAGE".to_string()),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_fk_orphan_check_sql(
+                &self,
+                _database_type: DatabaseType,
+                foreign_keys: &[ForeignKey],
+            ) -> Option<String> {
+                let names: Vec<&str> = foreign_keys.iter().map(|fk| fk.name.as_str()).collect();
+                Some(format!("ORPHANS {}", names.join(",")))
+            }
+
+            fn build_fk_suggestion_sql(&self, database_type: DatabaseType) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => Some("SUGGEST FKS".to_string()),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_naming_audit_sql(
+                &self,
+                database_type: DatabaseType,
+                _conventions: &NamingConventions,
+            ) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => Some("AUDIT NAMING".to_string()),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_lock_blockers_sql(
+                &self,
+                database_type: DatabaseType,
+                conflict: &LockConflict,
+            ) -> Option<String> {
+                let pids: Vec<String> = conflict
+                    .blocker_pids
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                match database_type {
+                    DatabaseType::PostgreSQL => Some(format!("LOCK BLOCKERS {}", pids.join(","))),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_result_types_sql(
+                &self,
+                database_type: DatabaseType,
+                query: &str,
+            ) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => Some(format!("RESULT TYPES {query}")),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
+                }
+            }
+
+            fn build_column_distribution_sql(
+                &self,
+                database_type: DatabaseType,
+                schema: &str,
+                table: &str,
+                column: &Column,
+                _row_estimate: Option<i64>,
+            ) -> Option<String> {
+                match database_type {
+                    DatabaseType::PostgreSQL => Some(format!(
+                        "DISTRIBUTION \"{schema}\".\"{table}\".\"{}\"",
+                        column.name
+                    )),
+                    DatabaseType::SQLite | DatabaseType::MySQL => None,
                 }
             }
 
@@ -106,6 +334,9 @@ impl AppServices {
                     DatabaseType::SQLite => {
                         format!("UPDATE \"{table}\" SET {set_clause} WHERE {where_clause}")
                     }
+                    DatabaseType::MySQL => {
+                        format!("UPDATE `{schema}`.`{table}` SET {set_clause} WHERE {where_clause}")
+                    }
                 }
             }
             fn build_bulk_delete_sql(
@@ -131,6 +362,9 @@ impl AppServices {
                         format!("DELETE FROM \"{schema}\".\"{table}\" WHERE {where_clause}")
                     }
                     DatabaseType::SQLite => format!("DELETE FROM \"{table}\" WHERE {where_clause}"),
+                    DatabaseType::MySQL => {
+                        format!("DELETE FROM `{schema}`.`{table}` WHERE {where_clause}")
+                    }
                 }
             }
         }
@@ -16,6 +16,9 @@ pub fn minimal(schema: impl Into<String>, name: impl Into<String>) -> Table {
         comment: None,
         source_ddl: None,
         kind_info: TableKindInfo::default(),
+        hypertable: None,
+        column_stats: Vec::new(),
+        storage: None,
     }
 }
 
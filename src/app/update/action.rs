@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::domain::connection::{
@@ -6,24 +7,42 @@ use crate::domain::connection::{
 use crate::domain::query_history::QueryHistoryEntry;
 use crate::model::app_state::AppState;
 use crate::model::browse::jsonb_detail::JsonbDetailMode;
+use crate::model::browse::maintenance::MaintenanceProgress;
 use crate::model::connection::error::ConnectionErrorInfo;
 use crate::model::shared::focused_pane::FocusedPane;
 use crate::model::shared::input_mode::InputMode;
 use crate::model::shared::key_sequence::Prefix;
+use crate::model::shared::settings::{KeywordCase, PrefetchScope};
 use crate::model::sql_editor::completion::CompletionCandidate;
+use crate::model::sql_editor::vim::SqlVimOperator;
 use crate::policy::FeatureRequirement;
+use crate::policy::index_usage_report::IndexUsageSort;
+use crate::policy::result_copy::ResultCopyFormat;
+use crate::policy::sql::replica_routing::QueryRouting;
 use crate::policy::write::write_guardrails::WritePreview;
+use crate::ports::outbound::audit_log::AuditLogError;
+use crate::ports::outbound::cache_store::{
+    CacheCategory, CacheCategoryUsage, CacheCleanup, CacheStoreError,
+};
 use crate::ports::outbound::clipboard::ClipboardError;
+use crate::ports::outbound::connection_import::{ConnectionCandidate, ContainerScanError};
 use crate::ports::outbound::connection_store::ConnectionStoreError;
 use crate::ports::outbound::folder_opener::FolderOpenError;
 use crate::ports::outbound::query_history::QueryHistoryError;
+use crate::ports::outbound::query_hook::QueryHookError;
+use crate::ports::outbound::release_check::ReleaseInfo;
 use crate::ports::outbound::settings_store::SettingsStoreError;
-use crate::ports::outbound::{AppSettings, DbOperationError};
+use crate::ports::outbound::snippet::{Snippet, SnippetError};
+use crate::ports::outbound::sql_file::{SqlFile, SqlFileError};
+use crate::ports::outbound::temp_file_writer::TempFileError;
+use crate::ports::outbound::{AppSettings, DbOperationError, PoolStats, TransactionEnd};
 use std::collections::HashMap;
 
 use crate::domain::SqliteDiagnosticsSnapshot;
 use crate::domain::{
-    ConnectionId, DatabaseMetadata, DiagnosticField, QueryResult, QuerySource, Table,
+    ColumnDistribution, ConnectionId, DatabaseMetadata, DiagnosticField, ErDiagramOptions,
+    ErTableInfo, IndexMaintenanceKind, IndexUsage, LockBlocker, MaintenanceKind, MetadataSnapshot,
+    QueryResult, QuerySource, ServerSetting, Table,
 };
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -75,6 +94,7 @@ pub enum ScrollTarget {
     CellDetail,
     SqliteDiagnostics,
     RowDetail,
+    ErDiagram,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -139,6 +159,7 @@ pub enum InputTarget {
     SqlModal,
     SqlModalHighRisk,
     SqlModalAnalyzeHighRisk,
+    ConfirmDialog,
     ResultCellEdit,
     ConnectionSetup,
     CommandLine,
@@ -146,6 +167,11 @@ pub enum InputTarget {
     ErFilter,
     SettingsErBrowser,
     QueryHistoryFilter,
+    ServerSettingsFilter,
+    SessionSettings,
+    SqlFileFilter,
+    SnippetFilter,
+    ObjectSearchFilter,
     JsonbEdit,
     JsonbSearch,
     CellDetailSearch,
@@ -176,6 +202,13 @@ pub enum ListTarget {
     TablePicker,
     ErTablePicker,
     CommandPalette,
+    ServerSettings,
+    SqlFiles,
+    Snippets,
+    ObjectSearch,
+    ErDiagram,
+    ConnectionImport,
+    QuickActions,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -184,6 +217,15 @@ pub enum ListMotion {
     Previous,
 }
 
+/// What `:bulk` does with the tables marked in the Explorer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkTableOperation {
+    CopyDdl,
+    ErDiagram,
+    Analyze,
+    Sizes,
+}
+
 /// Payload-free modal lifecycle kinds.
 ///
 /// Modal lifecycle actions stay generic only while opening does not need a
@@ -204,6 +246,14 @@ pub enum ModalKind {
     ConnectionSetup,
     ConnectionSelector,
     SqliteDiagnostics,
+    ServerSettings,
+    SessionSettings,
+    SqlFileBrowser,
+    SnippetPicker,
+    ObjectSearch,
+    ErDiagram,
+    ConnectionImport,
+    QuickActions,
 }
 
 #[derive(Debug, Clone)]
@@ -329,6 +379,7 @@ pub enum Action {
     EnterCommandLine,
     ExitCommandLine,
     CommandLineSubmit,
+    CommandLineComplete,
 
     // Connections
     TryConnect,
@@ -359,8 +410,21 @@ pub enum Action {
     RequestDeleteSelectedConnection,
     DeleteConnection(ConnectionId),
     ConnectionDeleted(ConnectionId),
+    ConnectionCandidatesLoaded(Vec<ConnectionCandidate>),
+    ConnectionCandidateScanFailed(ContainerScanError),
+    OpenContainerDiscovery,
+    ConnectionImportConfirm,
+    ConnectionImportEdit,
     ConnectionDeleteFailed(ConnectionStoreError),
     RequestEditSelectedConnection,
+    SessionSettingsNextField,
+    SessionSettingsPrevField,
+    SessionSettingsSave,
+    SessionSettingsSaved {
+        profile: Box<ConnectionProfile>,
+        target: ConnectionTarget,
+    },
+    SessionSettingsSaveFailed(ConnectionStoreError),
 
     // SQLite diagnostics
     RunSqliteDiagnosticsQuickCheck,
@@ -375,6 +439,138 @@ pub enum Action {
         quick_check: DiagnosticField,
     },
 
+    // Table maintenance
+    StartMaintenance(MaintenanceKind),
+    /// `index: None` targets the top row of the Indexes tab.
+    StartIndexMaintenance {
+        kind: IndexMaintenanceKind,
+        index: Option<String>,
+        confirmed: bool,
+    },
+    CopyIndexMaintenanceSql {
+        kind: IndexMaintenanceKind,
+        index: Option<String>,
+    },
+    IndexMaintenanceSqlCopied {
+        kind: IndexMaintenanceKind,
+        index: String,
+    },
+    ShowAutovacuumReport,
+    ShowIndexUsage(IndexUsageSort),
+    IndexUsageLoaded {
+        dsn: String,
+        run_id: u64,
+        sort: IndexUsageSort,
+        indexes: Vec<IndexUsage>,
+    },
+    IndexUsageFailed {
+        dsn: String,
+        run_id: u64,
+        error: DbOperationError,
+    },
+    MaintenanceProgressLoaded {
+        run_id: u64,
+        progress: Option<MaintenanceProgress>,
+    },
+    MaintenanceCompleted {
+        run_id: u64,
+        execution_time_ms: u64,
+    },
+    MaintenanceFailed {
+        run_id: u64,
+        error: DbOperationError,
+    },
+
+    // Explorer schema tree
+    ExplorerToggleSchema,
+    SetExplorerSchemaFilter(Option<String>),
+
+    // Bulk table operations
+    ExplorerToggleMark,
+    ExplorerClearMarks,
+    BulkTables(BulkTableOperation),
+    BulkDdlLoaded {
+        dsn: String,
+        tables: Vec<Table>,
+    },
+    BulkDdlFailed {
+        dsn: String,
+        error: DbOperationError,
+    },
+    BulkDdlCopied(usize),
+
+    // Server settings
+    ServerSettingsLoaded {
+        dsn: String,
+        run_id: u64,
+        settings: Vec<ServerSetting>,
+    },
+    ServerSettingsFailed {
+        dsn: String,
+        run_id: u64,
+        error: DbOperationError,
+    },
+
+    // Inspector Stats tab
+    ColumnDistributionLoaded {
+        dsn: String,
+        run_id: u64,
+        distribution: ColumnDistribution,
+    },
+    ColumnDistributionFailed {
+        dsn: String,
+        run_id: u64,
+        error: DbOperationError,
+    },
+
+    // SQL file browser
+    SqlFilesLoaded(Vec<SqlFile>),
+    SqlFilesLoadFailed(SqlFileError),
+    SqlFileOpen,
+    SqlFileRun,
+
+    // Snippets
+    ReloadSnippets,
+    SnippetsLoaded(Vec<Snippet>),
+    SnippetsLoadFailed(SnippetError),
+    SaveSnippet(String),
+    SnippetSaved {
+        name: String,
+        snippets: Vec<Snippet>,
+    },
+    SnippetSaveFailed(SnippetError),
+    SnippetInsert,
+    SnippetRun,
+
+    // Object search
+    ObjectSearchConfirm,
+
+    // Quick actions menu (`.` on an Explorer table)
+    QuickActionConfirm,
+
+    // Linked SQL file (:edit)
+    LinkSqlFile(String),
+    ReloadLinkedSqlFile,
+    WriteLinkedSqlFile,
+    WriteSqlFileAs(String),
+    SqlFileLinked {
+        path: PathBuf,
+        content: String,
+    },
+    LinkedSqlFilePolled {
+        link_id: u64,
+        content: Option<String>,
+    },
+    LinkedSqlFileWritten {
+        link_id: u64,
+        content: String,
+    },
+    SqlFileWrittenAs {
+        path: PathBuf,
+        content: String,
+    },
+    LinkedSqlFileFailed(SqlFileError),
+
     // Settings
     SettingsSelectNext,
     SettingsSelectPrevious,
@@ -386,6 +582,10 @@ pub enum Action {
     SettingsCancel,
     SettingsSaved(AppSettings),
     SettingsSaveFailed(SettingsStoreError),
+    SetKeywordCase(Option<KeywordCase>),
+    SetPrefetchScope(PrefetchScope),
+    SetUsageTracking(bool),
+    SetUpdateCheck(bool),
 
     // Database structure
     LoadMetadata,
@@ -400,6 +600,11 @@ pub enum Action {
         run_id: u64,
         error: DbOperationError,
     },
+    MetadataSnapshotLoaded {
+        dsn: String,
+        snapshot: Arc<MetadataSnapshot>,
+    },
+    EnterOfflineMode,
     EffectiveUserLoaded {
         dsn: String,
         run_id: u64,
@@ -447,6 +652,9 @@ pub enum Action {
     StartPrefetchScoped {
         tables: Vec<String>,
     },
+    /// Per-schema prefetch: queues the tables of the selected table's schema
+    /// and of schemas named in the SQL buffer that are not queued yet.
+    PrefetchReferencedSchemas,
     ExpandPrefetchWithFkNeighbors,
     FkNeighborsDiscovered {
         tables: Vec<String>,
@@ -456,17 +664,34 @@ pub enum Action {
     },
     InspectorNextTab,
     InspectorPrevTab,
+    InspectorTogglePin,
+    InspectorCycleRlsRole,
+    InspectorTestRlsRole,
+    /// Fetches the Stats tab's column unless it is already cached.
+    InspectorLoadColumnDistribution,
 
     // SQL editing
     SqlModalAppendInsert,
     SqlModalEnterInsert,
     SqlModalEnterNormal,
+    SqlModalCycleSize,
     SqlModalYank,
     SqlModalYankSuccess,
+    SqlModalVimOperator(SqlVimOperator),
+    SqlModalVimDeleteChar,
+    SqlModalVimPaste {
+        before: bool,
+    },
+    SqlModalVimVisualLine,
+    SqlModalVimCancel,
+    SqlModalUndoEdit,
+    SqlModalRedoEdit,
     SqlModalNewLine,
     SqlModalTab,
     SqlModalSubmit,
     SqlModalClear,
+    UndoUiMutation,
+    SqlModalRenameAlias,
     SqlModalCancelConfirm,
     SqlModalConfirmExecute,
     SqlModalNextTab,
@@ -481,6 +706,7 @@ pub enum Action {
     CompletionDismiss,
     CompletionNext,
     CompletionPrev,
+    CompletionTogglePreview,
 
     // Explain plans
     ExplainRequest,
@@ -502,11 +728,58 @@ pub enum Action {
         is_analyze: bool,
     },
     CompareEditQuery,
+    ExplainPreview,
+    PreviewExplainCompleted {
+        preview: Arc<QueryResult>,
+        query: String,
+        plan_text: String,
+        execution_time_ms: u64,
+    },
+    PreviewExplainFailed(DbOperationError),
+
+    // Session transactions
+    BeginTransaction,
+    EndTransaction(TransactionEnd),
+    TransactionBegun {
+        dsn: String,
+    },
+    TransactionBeginFailed(DbOperationError),
+    TransactionEnded(TransactionEnd),
+    TransactionEndFailed {
+        end: TransactionEnd,
+        error: DbOperationError,
+    },
 
     // Query results
     ExecutePreview(TableTarget),
     ExecuteAdhoc(String),
     ExecuteWrite(String),
+    SetQueryRouting(QueryRouting),
+    ShowReplicationOverview(Option<String>),
+    SampleTable(usize),
+    CheckForeignKeyOrphans(Option<String>),
+    SuggestForeignKeys,
+    AuditNaming,
+    ShowStatementTimings,
+    ShowStartupReport,
+    ShowUsageStats,
+    ShowVersion,
+    CheckForUpdate,
+    LatestReleaseLoaded(ReleaseInfo),
+    ShowCacheInfo,
+    ClearCache(Option<CacheCategory>),
+    CacheUsageLoaded(Vec<CacheCategoryUsage>),
+    CacheCleared {
+        category: Option<CacheCategory>,
+        cleanup: CacheCleanup,
+    },
+    CacheOperationFailed(CacheStoreError),
+    ShowServerInfo,
+    ServerInfoLoaded {
+        dsn: String,
+        primary: Option<PoolStats>,
+        replica: Option<PoolStats>,
+    },
     QueryCompleted {
         dsn: String,
         run_id: u64,
@@ -521,6 +794,17 @@ pub enum Action {
         generation: u64,
         source: QuerySource,
     },
+    /// The run lost its connection once and succeeded on a fresh one; sent
+    /// ahead of its `QueryCompleted`.
+    QueryReconnectedAndRetried {
+        dsn: String,
+        run_id: u64,
+    },
+    LockBlockersLoaded {
+        dsn: String,
+        run_id: u64,
+        blockers: Vec<LockBlocker>,
+    },
     ExecuteWriteSucceeded {
         dsn: String,
         run_id: u64,
@@ -533,6 +817,12 @@ pub enum Action {
     },
     ResultNextPage,
     ResultPrevPage,
+    CyclePreviewSort,
+    /// `None` clears the filter.
+    SetPreviewFilter(Option<String>),
+    /// `None` selects every column again.
+    SetPreviewColumns(Option<String>),
+    OpenPreviewFilter,
     ResultActivateCell,
     ResultExitToScroll,
     ResultCellLeft,
@@ -547,21 +837,63 @@ pub enum Action {
     ResultRowYankSuccess {
         row: usize,
     },
+    ResultToggleRange,
+    ResultClearRange,
+    ResultRangeYank,
+    ResultRangeYankSuccess {
+        rows: usize,
+        cols: usize,
+    },
+    ResultCopyAll(ResultCopyFormat),
+    ResultCopyRows(ResultCopyFormat),
+    ResultCopyAllCopied {
+        format: ResultCopyFormat,
+        row_count: usize,
+    },
+    ResultCopyAllSpilled {
+        path: String,
+        row_count: usize,
+        path_copied: bool,
+    },
+    ResultCopyAllSpillFailed(TempFileError),
+    ResultToggleNumberFormat,
+    ResultToggleDurationFormat,
+    ResultToggleColumnTypes,
+    ResultToggleDiffOldValues,
+    /// Reveals the masked columns of the current result; audit-logged.
+    ResultUnmask,
+    ResultColumnTypesLoaded {
+        dsn: String,
+        run_id: u64,
+        types: Vec<String>,
+    },
+    ResultBindVariable {
+        name: String,
+        value: Option<String>,
+    },
     DdlYank,
     DdlYankSuccess,
     ResultDeleteOperatorPending,
     StageRowForDelete,
     UnstageLastStagedRow,
     ClearStagedDeletes,
+    ResultToggleRowMark,
+    ResultNextRowMark,
+    ResultPrevRowMark,
     RequestDeleteActiveRow,
     ResultEnterCellEdit,
     ResultOpenCellDetail,
+    /// Pops the truncated-value tooltip, or opens Row Detail once it is up.
+    ResultPeekCell,
+    ResultOpenGeometryPreview,
     ResultCancelCellEdit,
     ResultDiscardCellEdit,
     SubmitCellEditWrite,
     OpenWritePreviewConfirm(Box<WritePreview>),
     CopyFailed(ClipboardError),
     OpenFolderFailed(FolderOpenError),
+    QueryHookFailed(QueryHookError),
+    AuditLogFailed(AuditLogError),
     ToggleFocus,
     ToggleReadOnly,
 
@@ -629,13 +961,19 @@ pub enum Action {
     ErToggleSelection,
     ErSelectAll,
     ErConfirmSelection,
+    ErViewSelection,
     ErOpenDiagram,
     ErGenerateFromCache,
+    SetErDiagramOptions(ErDiagramOptions),
     SmartErRefreshCompleted(SmartErRefreshResult),
     SmartErRefreshFailed(SmartErRefreshError),
     ErDiagramOpened(ErDiagramInfo),
     ErDiagramFailed(ErDiagramError),
     ErLogWriteFailed(ErLogError),
+    ErViewerLoaded(Vec<ErTableInfo>),
+    ErViewerZoomIn,
+    ErViewerZoomOut,
+    ErViewerToggleFocus,
 }
 
 impl Action {
@@ -647,10 +985,15 @@ impl Action {
         matches!(self, Self::Scroll { .. })
     }
 
+    /// Moves a held key repeats; the input loop batches these per frame.
+    pub fn is_navigation(&self) -> bool {
+        matches!(self, Self::Scroll { .. } | Self::Select(_))
+    }
+
     pub fn feature_requirement(&self) -> FeatureRequirement {
         use FeatureRequirement::{
-            ErDiagram, Explain, ExplainAnalyze, JsonbDetail, None, PlanComparison,
-            SqliteDiagnostics,
+            ErDiagram, Explain, ExplainAnalyze, JsonbDetail, Maintenance, None, PlanComparison,
+            ServerSettings, SessionSettings, SqliteDiagnostics,
         };
 
         match self {
@@ -659,13 +1002,17 @@ impl Action {
             | Self::ErToggleSelection
             | Self::ErSelectAll
             | Self::ErConfirmSelection
+            | Self::ErViewSelection
             | Self::ErOpenDiagram
+            | Self::BulkTables(BulkTableOperation::ErDiagram)
             | Self::ErGenerateFromCache
+            | Self::SetErDiagramOptions(_)
             | Self::SmartErRefreshCompleted(_)
             | Self::SmartErRefreshFailed(_)
             | Self::ErDiagramOpened(_)
             | Self::ErDiagramFailed(_)
             | Self::ErLogWriteFailed(_)
+            | Self::ErViewerLoaded(_)
             | Self::TextInput {
                 target: InputTarget::ErFilter,
                 ..
@@ -700,6 +1047,19 @@ impl Action {
                 target: ScrollTarget::SqliteDiagnostics,
                 ..
             } => SqliteDiagnostics,
+            Self::StartMaintenance(_)
+            | Self::StartIndexMaintenance { .. }
+            | Self::CopyIndexMaintenanceSql { .. }
+            | Self::ShowAutovacuumReport
+            | Self::ShowIndexUsage(_)
+            | Self::BulkTables(BulkTableOperation::Analyze) => Maintenance,
+            Self::OpenModal(ModalKind::ServerSettings)
+            | Self::ToggleModal(ModalKind::ServerSettings)
+            | Self::ServerSettingsLoaded { .. }
+            | Self::ServerSettingsFailed { .. } => ServerSettings,
+            Self::OpenModal(ModalKind::SessionSettings)
+            | Self::ToggleModal(ModalKind::SessionSettings)
+            | Self::SessionSettingsSave => SessionSettings,
             Self::OpenModal(ModalKind::JsonbDetail)
             | Self::ToggleModal(ModalKind::JsonbDetail)
             | Self::JsonbYankAll
@@ -734,6 +1094,7 @@ impl Action {
                 ..
             } => JsonbDetail,
             Self::ExplainRequest
+            | Self::ExplainPreview
             | Self::Scroll {
                 target: ScrollTarget::ExplainPlan,
                 ..
@@ -876,6 +1237,27 @@ mod tests {
             .feature_requirement(),
             FeatureRequirement::SqliteDiagnostics
         );
+        assert_eq!(
+            Action::StartMaintenance(MaintenanceKind::Analyze).feature_requirement(),
+            FeatureRequirement::Maintenance
+        );
+        assert_eq!(
+            Action::StartIndexMaintenance {
+                kind: IndexMaintenanceKind::ReindexConcurrently,
+                index: None,
+                confirmed: false,
+            }
+            .feature_requirement(),
+            FeatureRequirement::Maintenance
+        );
+        assert_eq!(
+            Action::OpenModal(ModalKind::ServerSettings).feature_requirement(),
+            FeatureRequirement::ServerSettings
+        );
+        assert_eq!(
+            Action::OpenModal(ModalKind::SessionSettings).feature_requirement(),
+            FeatureRequirement::SessionSettings
+        );
         assert_eq!(
             Action::ExplainAnalyzeCancel.feature_requirement(),
             FeatureRequirement::ExplainAnalyze
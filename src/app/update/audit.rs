@@ -0,0 +1,30 @@
+use crate::cmd::effect::Effect;
+use crate::domain::AuditEvent;
+use crate::model::app_state::AppState;
+use crate::policy::write::write_guardrails::RiskLevel;
+
+/// Audit record for a write the user just confirmed; only production
+/// profiles keep one.
+pub fn production_audit_effect(
+    state: &AppState,
+    operation: &str,
+    risk: RiskLevel,
+    statement: &str,
+) -> Option<Effect> {
+    if !state.active_is_production() {
+        return None;
+    }
+    Some(Effect::AppendAuditLog {
+        project_name: state.runtime.project_name().to_string(),
+        event: AuditEvent {
+            connection: state
+                .session
+                .active_connection_name()
+                .unwrap_or_default()
+                .to_string(),
+            operation: operation.to_string(),
+            risk: risk.as_str().to_string(),
+            statement: statement.to_string(),
+        },
+    })
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use crate::cmd::effect::Effect;
+use crate::domain::connection::ConnectionProfile;
 use crate::model::app_state::AppState;
 use crate::model::connection::error::ConnectionErrorInfo;
 use crate::model::er_state::ErStatus;
@@ -9,6 +10,7 @@ use crate::model::shared::input_mode::InputMode;
 use crate::update::action::{Action, ModalKind};
 use crate::update::browse::query::preview_effect_for_current_table;
 use crate::update::dispatch_result::DispatchResult;
+use crate::update::helpers::sql_modal_prefetch_action;
 use crate::update::query_context::termination_effects;
 
 pub(super) fn reduce_loading(
@@ -27,13 +29,41 @@ pub(super) fn reduce_loading(
             }
 
             let has_tables = !metadata.table_summaries.is_empty();
+            let first_connect = !state.session.connection_state().is_connected();
             state.session.mark_connected(Arc::clone(metadata));
+            state.object_search.refresh(metadata);
             let effective_user_run_id = state.session.begin_effective_user_fetch();
 
             let mut effects = vec![Effect::FetchEffectiveUser {
                 dsn: dsn.clone(),
                 run_id: effective_user_run_id,
             }];
+            if let Some(connection_id) = state.session.active_connection_id().cloned() {
+                effects.push(Effect::SaveMetadataSnapshot {
+                    project_name: state.runtime.project_name().to_string(),
+                    connection_id,
+                    metadata: Arc::clone(metadata),
+                });
+            }
+            // Reloads keep the pool they already filled; only a fresh
+            // connection opens the configured warm connections.
+            if first_connect
+                && let Some(settings) = state
+                    .active_profile()
+                    .and_then(ConnectionProfile::pool)
+                    .filter(|settings| settings.warmup > 0)
+            {
+                effects.push(Effect::WarmUpConnectionPool {
+                    dsn: dsn.clone(),
+                    settings,
+                });
+                if let Some(replica_dsn) = state.active_replica_dsn() {
+                    effects.push(Effect::WarmUpConnectionPool {
+                        dsn: replica_dsn.to_string(),
+                        settings,
+                    });
+                }
+            }
 
             if state.query.pagination.table().is_empty() {
                 state
@@ -42,12 +72,13 @@ pub(super) fn reduce_loading(
             } else {
                 let prev_schema = state.query.pagination.schema();
                 let prev_table = state.query.pagination.table();
-                let found_index = metadata
+                let still_exists = metadata
                     .table_summaries
                     .iter()
-                    .position(|t| t.schema == prev_schema && t.name == prev_table);
-                if let Some(idx) = found_index {
-                    state.ui.set_explorer_selection(Some(idx));
+                    .any(|t| t.schema == prev_schema && t.name == prev_table);
+                if still_exists {
+                    let idx = state.explorer_row_index(prev_schema, prev_table);
+                    state.ui.set_explorer_selection(Some(idx.unwrap_or(0)));
                     // Refresh preview and detail: DDL or reload may have changed
                     // data/schema even though the table still exists.
                     let page = state.query.pagination.current_page();
@@ -85,7 +116,9 @@ pub(super) fn reduce_loading(
             if state.modal.active_mode() == InputMode::SqlModal
                 && !state.sql_modal.is_prefetch_started()
             {
-                effects.push(Effect::DispatchActions(vec![Action::StartPrefetchAll]));
+                effects.push(Effect::DispatchActions(vec![sql_modal_prefetch_action(
+                    state,
+                )]));
             }
 
             if state.ui.take_pending_er_picker() && state.modal.active_mode() == InputMode::Normal {
@@ -132,11 +165,25 @@ pub(super) fn reduce_loading(
             if state.er_preparation.status() == ErStatus::Waiting {
                 state.er_preparation.mark_idle();
             }
-            DispatchResult::handled_with(if was_connected {
-                vec![]
-            } else {
-                termination_effects(&state.query, vec![])
-            })
+            if was_connected {
+                return DispatchResult::handled();
+            }
+            let mut effects = termination_effects(&state.query, vec![]);
+            if let Some(connection_id) = state.session.active_connection_id().cloned() {
+                effects.push(Effect::LoadMetadataSnapshot {
+                    dsn: dsn.clone(),
+                    project_name: state.runtime.project_name().to_string(),
+                    connection_id,
+                });
+            }
+            DispatchResult::handled_with(effects)
+        }
+        Action::MetadataSnapshotLoaded { dsn, snapshot } => {
+            // Only a connection that is still failing is offered the snapshot.
+            if state.session.dsn_matches(dsn) && state.session.connection_state().is_failed() {
+                state.session.offer_offline_snapshot(Arc::clone(snapshot));
+            }
+            DispatchResult::handled()
         }
         Action::LoadMetadata => {
             if let Some(dsn) = state.session.dsn().map(String::from) {
@@ -6,6 +6,7 @@ mod prefetch;
 mod table_detail;
 
 use crate::cmd::effect::Effect;
+use crate::domain::Table;
 use crate::model::app_state::AppState;
 use crate::model::er_state::ErStatus;
 use crate::update::action::Action;
@@ -41,6 +42,24 @@ pub(super) fn check_er_completion(state: &mut AppState, now: Instant) -> Vec<Eff
     }]
 }
 
+// Full details replace the stored table; prefetched columns only fill gaps.
+pub(super) fn table_snapshot_effect(
+    state: &AppState,
+    detail: &Table,
+    columns_only: bool,
+) -> Option<Effect> {
+    if state.session.is_offline() {
+        return None;
+    }
+    let connection_id = state.session.active_connection_id()?.clone();
+    Some(Effect::SaveTableSnapshot {
+        project_name: state.runtime.project_name().to_string(),
+        connection_id,
+        detail: Box::new(detail.clone()),
+        columns_only,
+    })
+}
+
 pub fn dispatch_metadata(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
     loading::reduce_loading(state, action, now)
         .or_else(|| table_detail::reduce_table_detail(state, action, now))
@@ -54,7 +73,7 @@ mod tests {
 
     use super::*;
     use crate::cmd::effect::Effect;
-    use crate::domain::{ConnectionId, DatabaseType, Table};
+    use crate::domain::{ConnectionId, DatabaseType};
     use crate::model::app_state::AppState;
     use crate::model::sql_editor::modal::FailedPrefetchEntry;
     use crate::update::action::Action;
@@ -157,6 +176,55 @@ mod tests {
         }
     }
 
+    mod object_search {
+        use super::*;
+        use crate::model::browse::object_search::SearchTarget;
+        use crate::model::shared::focused_pane::FocusedPane;
+        use crate::model::shared::inspector_tab::InspectorTab;
+        use crate::test_support::column::test_nullable_column;
+
+        #[test]
+        fn loaded_detail_lands_on_pending_column() {
+            let mut state = state_with_dsn("postgres://localhost/test");
+            let generation = state
+                .session
+                .select_table("public", "orders", &mut state.query);
+            let run_id = state.session.begin_table_detail_run();
+            state
+                .object_search
+                .set_pending_column(Some(SearchTarget::Column {
+                    schema: "public".to_string(),
+                    table: "orders".to_string(),
+                    column: "total".to_string(),
+                }));
+            let mut detail = empty_table("public", "orders");
+            detail.columns = ["id", "placed_at", "total"]
+                .iter()
+                .zip(1..)
+                .map(|(name, position)| test_nullable_column(*name, "text", position))
+                .collect();
+
+            dispatch_metadata(
+                &mut state,
+                &Action::TableDetailLoaded {
+                    dsn: "postgres://localhost/test".to_string(),
+                    run_id,
+                    detail,
+                    generation,
+                },
+                Instant::now(),
+            );
+
+            assert_eq!(state.ui.inspector_tab(), InspectorTab::Columns);
+            assert_eq!(state.ui.focused_pane(), FocusedPane::Inspector);
+            assert_eq!(state.ui.inspector_column_cursor(), 2);
+            assert_eq!(
+                state.object_search.take_pending_column("public", "orders"),
+                None
+            );
+        }
+    }
+
     mod prefetch_table_detail {
         use super::prefetch::MAX_PREFETCH_RETRIES;
         use super::*;
@@ -626,7 +694,8 @@ mod tests {
 
     mod metadata_loaded {
         use super::*;
-        use crate::domain::{DatabaseMetadata, TableSummary};
+        use crate::domain::connection::{ConnectionProfile, SslMode};
+        use crate::domain::{DatabaseMetadata, PoolSettings, TableSummary};
 
         fn make_metadata(tables: Vec<(&str, &str)>) -> Arc<DatabaseMetadata> {
             Arc::new({
@@ -726,6 +795,64 @@ mod tests {
                     .any(|e| matches!(e, Effect::FetchTableDetail { .. }))
             );
         }
+
+        fn state_with_warm_pool() -> AppState {
+            let profile = ConnectionProfile::new_postgres(
+                "app",
+                "localhost",
+                5432,
+                "test",
+                "app",
+                "",
+                SslMode::Prefer,
+            )
+            .unwrap()
+            .with_pool(PoolSettings::new(Some(8), Some(2)));
+            let mut state = AppState::new("test".to_string());
+            state.session.activate_connection_with_dsn(
+                &profile.id,
+                "app",
+                DatabaseType::PostgreSQL,
+                "postgres://localhost/test",
+            );
+            state.set_connections(vec![profile]);
+            state
+        }
+
+        fn warmups(effects: &[Effect]) -> Vec<&str> {
+            effects
+                .iter()
+                .filter_map(|e| match e {
+                    Effect::WarmUpConnectionPool { dsn, settings } => {
+                        assert_eq!(settings.warmup, 2);
+                        Some(dsn.as_str())
+                    }
+                    _ => None,
+                })
+                .collect()
+        }
+
+        #[test]
+        fn first_connect_warms_up_the_pool() {
+            let mut state = state_with_warm_pool();
+
+            let action = metadata_loaded_action(&mut state, make_metadata(vec![]));
+            let effects = dispatch_metadata(&mut state, &action, Instant::now()).unwrap();
+
+            assert_eq!(warmups(&effects), ["postgres://localhost/test"]);
+        }
+
+        #[test]
+        fn reload_does_not_warm_up_again() {
+            let mut state = state_with_warm_pool();
+            let action = metadata_loaded_action(&mut state, make_metadata(vec![]));
+            dispatch_metadata(&mut state, &action, Instant::now());
+
+            let action = metadata_loaded_action(&mut state, make_metadata(vec![]));
+            let effects = dispatch_metadata(&mut state, &action, Instant::now()).unwrap();
+
+            assert!(warmups(&effects).is_empty());
+        }
     }
 
     mod start_prefetch_all {
@@ -786,6 +913,116 @@ mod tests {
         }
     }
 
+    mod prefetch_referenced_schemas {
+        use super::*;
+        use crate::domain::{DatabaseMetadata, TableSummary};
+
+        fn warehouse() -> Arc<DatabaseMetadata> {
+            let mut metadata = DatabaseMetadata::new("test".to_string());
+            metadata.table_summaries = [
+                ("sales", "orders"),
+                ("sales", "invoices"),
+                ("crm", "users"),
+                ("audit", "events"),
+            ]
+            .into_iter()
+            .map(|(schema, name)| {
+                TableSummary::new(schema.to_string(), name.to_string(), None, false)
+            })
+            .collect();
+            Arc::new(metadata)
+        }
+
+        fn queued(state: &AppState) -> Vec<&str> {
+            [
+                "sales.orders",
+                "sales.invoices",
+                "crm.users",
+                "audit.events",
+            ]
+            .into_iter()
+            .filter(|table| state.sql_modal.is_prefetch_queued(table))
+            .collect()
+        }
+
+        #[test]
+        fn queues_only_schemas_named_in_the_buffer() {
+            let mut state = state_with_dsn("postgres://localhost/test");
+            state.session.set_metadata(Some(warehouse()));
+            state
+                .sql_modal
+                .editor
+                .set_content("SELECT * FROM sales.orders o WHERE o.".to_string());
+
+            let effects = dispatch_metadata(
+                &mut state,
+                &Action::PrefetchReferencedSchemas,
+                Instant::now(),
+            )
+            .unwrap();
+
+            assert_eq!(queued(&state), ["sales.orders", "sales.invoices"]);
+            assert!(state.sql_modal.is_schema_prefetch());
+            assert!(
+                effects
+                    .iter()
+                    .any(|e| matches!(e, Effect::ProcessPrefetchQueue { .. }))
+            );
+        }
+
+        #[test]
+        fn later_schemas_join_the_same_run_once() {
+            let mut state = state_with_dsn("postgres://localhost/test");
+            state.session.set_metadata(Some(warehouse()));
+            state
+                .sql_modal
+                .editor
+                .set_content("SELECT * FROM sales.orders".to_string());
+            dispatch_metadata(
+                &mut state,
+                &Action::PrefetchReferencedSchemas,
+                Instant::now(),
+            );
+            let run_id = state.sql_modal.active_prefetch_run_id();
+            while state.sql_modal.take_next_prefetch().is_some() {}
+
+            state
+                .sql_modal
+                .editor
+                .set_content("SELECT * FROM sales.orders JOIN crm.users".to_string());
+            dispatch_metadata(
+                &mut state,
+                &Action::PrefetchReferencedSchemas,
+                Instant::now(),
+            );
+
+            assert_eq!(state.sql_modal.active_prefetch_run_id(), run_id);
+            assert_eq!(queued(&state), ["crm.users"]);
+        }
+
+        #[test]
+        fn leaves_a_whole_database_run_alone() {
+            let mut state = state_with_dsn("postgres://localhost/test");
+            state.session.set_metadata(Some(warehouse()));
+            dispatch_metadata(&mut state, &Action::StartPrefetchAll, Instant::now());
+            while state.sql_modal.take_next_prefetch().is_some() {}
+            state
+                .sql_modal
+                .editor
+                .set_content("SELECT * FROM audit.events".to_string());
+
+            let effects = dispatch_metadata(
+                &mut state,
+                &Action::PrefetchReferencedSchemas,
+                Instant::now(),
+            )
+            .unwrap();
+
+            assert!(effects.is_empty());
+            assert!(queued(&state).is_empty());
+        }
+    }
+
     mod start_prefetch_scoped {
         use super::*;
 
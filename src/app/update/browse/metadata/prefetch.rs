@@ -1,17 +1,22 @@
-use std::time::Instant;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::cmd::effect::Effect;
 use crate::domain::TableSummary;
 use crate::model::app_state::AppState;
+use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::text_input::TextInputLike;
 use crate::model::sql_editor::modal::FailedPrefetchEntry;
+use crate::policy::sql::qualifiers::dotted_qualifiers;
 use crate::update::action::Action;
 use crate::update::dispatch_result::DispatchResult;
 
-use super::check_er_completion;
+use super::{check_er_completion, table_snapshot_effect};
 
 const BASE_BACKOFF_SECS: u64 = 1;
 const MAX_BACKOFF_SECS: u64 = 4;
 pub(super) const MAX_PREFETCH_RETRIES: u32 = 3;
+const COMPLETION_REFRESH_DEBOUNCE: Duration = Duration::from_millis(150);
 
 pub(super) fn backoff_secs_for(retry_count: u32) -> u64 {
     (BASE_BACKOFF_SECS * 2u64.pow(retry_count)).min(MAX_BACKOFF_SECS)
@@ -54,6 +59,55 @@ pub(super) fn reduce_prefetch(
             }
         }
 
+        Action::PrefetchReferencedSchemas => {
+            let Some(metadata) = state.session.metadata() else {
+                return DispatchResult::handled();
+            };
+            if state.sql_modal.is_prefetch_started() && !state.sql_modal.is_schema_prefetch() {
+                return DispatchResult::handled();
+            }
+            let selected_schema = state
+                .session
+                .table_detail()
+                .map(|table| table.schema.clone())
+                .or_else(|| state.explorer_selected_table().map(|t| t.schema.clone()));
+            let mut schemas = dotted_qualifiers(state.sql_modal.editor.content());
+            schemas.extend(selected_schema);
+            let metadata = Arc::clone(metadata);
+
+            let mut effects = Vec::new();
+            let run_id = if let Some(run_id) = state
+                .sql_modal
+                .active_prefetch_run_id()
+                .filter(|_| state.sql_modal.is_prefetch_started())
+            {
+                run_id
+            } else {
+                effects.push(Effect::ResizeCompletionCache {
+                    capacity: metadata.table_summaries.len().clamp(500, 10_000),
+                });
+                state.sql_modal.begin_schema_prefetch()
+            };
+            for schema in &schemas {
+                if !metadata.table_summaries.iter().any(|t| &t.schema == schema)
+                    || !state.sql_modal.add_prefetch_schema(schema)
+                {
+                    continue;
+                }
+                for table in metadata
+                    .table_summaries
+                    .iter()
+                    .filter(|t| &t.schema == schema)
+                {
+                    state.sql_modal.queue_table_prefetch(table.qualified_name());
+                }
+            }
+            if state.sql_modal.has_pending_prefetch() {
+                effects.push(Effect::ProcessPrefetchQueue { run_id });
+            }
+            DispatchResult::handled_with(effects)
+        }
+
         Action::StartPrefetchScoped { tables } => {
             if state.sql_modal.is_prefetch_started() {
                 DispatchResult::handled()
@@ -170,11 +224,21 @@ pub(super) fn reduce_prefetch(
             let qualified_name = format!("{schema}.{table}");
             state.sql_modal.complete_table_prefetch(&qualified_name);
             state.er_preparation.on_table_cached(&qualified_name);
+            state.object_search.index_table(detail);
 
             let mut effects = vec![Effect::CacheTableInCompletionEngine {
                 qualified_name,
                 table: detail.clone(),
             }];
+            effects.extend(table_snapshot_effect(state, detail, true));
+
+            // Open candidates may now be missing columns; refresh them once per
+            // window however many tables land.
+            if state.input_mode() == InputMode::SqlModal && state.sql_modal.completion().visible {
+                state
+                    .sql_modal
+                    .coalesce_completion_refresh(now + COMPLETION_REFRESH_DEBOUNCE);
+            }
 
             if state.sql_modal.has_pending_prefetch() {
                 effects.push(Effect::ProcessPrefetchQueue { run_id: *run_id });
@@ -2,9 +2,13 @@ use std::time::Instant;
 
 use crate::cmd::effect::Effect;
 use crate::model::app_state::AppState;
+use crate::model::shared::focused_pane::FocusedPane;
+use crate::model::shared::inspector_tab::InspectorTab;
 use crate::update::action::{Action, TableTarget};
 use crate::update::dispatch_result::DispatchResult;
 
+use super::table_snapshot_effect;
+
 pub(super) fn reduce_table_detail(
     state: &mut AppState,
     action: &Action,
@@ -23,10 +27,44 @@ pub(super) fn reduce_table_detail(
                 return DispatchResult::handled();
             }
 
+            let mut effects: Vec<Effect> = table_snapshot_effect(state, detail, false)
+                .into_iter()
+                .collect();
+            state.object_search.index_table(detail);
+            let previous = state
+                .session
+                .table_detail()
+                .map(|table| (table.schema.clone(), table.name.clone()));
             if state.session.set_table_detail(*detail.clone(), *generation) {
                 state.ui.set_inspector_scroll_offset(0);
+                // A refresh of the same table keeps the pin while the column exists.
+                let keep_pin = previous == Some((detail.schema.clone(), detail.name.clone()))
+                    && state.ui.inspector_pinned_column().is_some_and(|pinned| {
+                        detail.columns.iter().any(|column| column.name == pinned)
+                    });
+                let pinned = keep_pin
+                    .then(|| state.ui.inspector_pinned_column().map(str::to_string))
+                    .flatten();
+                state.ui.reset_inspector_column_focus();
+                state.ui.set_inspector_pinned_column(pinned);
+                // A column picked in the object search lands on its Columns row.
+                if let Some(column) = state
+                    .object_search
+                    .take_pending_column(&detail.schema, &detail.name)
+                    && let Some(index) = detail.columns.iter().position(|c| c.name == column)
+                {
+                    state.ui.set_inspector_tab(InspectorTab::Columns);
+                    state.ui.set_focused_pane(FocusedPane::Inspector);
+                    state.ui.set_inspector_column_cursor(index);
+                    state.ui.set_inspector_scroll_offset(index);
+                }
+                if state.ui.inspector_tab() == InspectorTab::Stats {
+                    effects.push(Effect::DispatchActions(vec![
+                        Action::InspectorLoadColumnDistribution,
+                    ]));
+                }
             }
-            DispatchResult::handled()
+            DispatchResult::handled_with(effects)
         }
         Action::TableDetailFailed {
             dsn,
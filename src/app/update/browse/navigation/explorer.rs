@@ -1,8 +1,11 @@
+use std::time::Instant;
+
+use crate::domain::TableSummary;
 use crate::model::app_state::AppState;
 use crate::model::shared::focused_pane::FocusedPane;
 use crate::model::shared::key_sequence::KeySequenceState;
 use crate::model::shared::ui_state::scroll_max_offset;
-use crate::policy::table_kind::max_explorer_table_label_width;
+use crate::policy::explorer_tree::{ExplorerRow, max_explorer_row_label_width};
 use crate::update::action::{
     Action, CursorPosition, ScrollAmount, ScrollDirection, ScrollTarget, ScrollToCursorTarget,
     SelectMotion,
@@ -11,11 +14,11 @@ use crate::update::dispatch_result::DispatchResult;
 
 use super::explorer_item_count;
 
-pub fn reduce_explorer(state: &mut AppState, action: &Action) -> DispatchResult {
+pub fn reduce_explorer(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
     match action {
         Action::Select(SelectMotion::Next) => {
             if state.ui.focused_pane() == FocusedPane::Explorer {
-                let len = state.tables().len();
+                let len = explorer_item_count(state);
                 if len > 0 && state.ui.explorer_selected() < len - 1 {
                     state
                         .ui
@@ -25,21 +28,21 @@ pub fn reduce_explorer(state: &mut AppState, action: &Action) -> DispatchResult
             DispatchResult::handled()
         }
         Action::Select(SelectMotion::Previous) => {
-            if state.ui.focused_pane() == FocusedPane::Explorer && !state.tables().is_empty() {
+            if state.ui.focused_pane() == FocusedPane::Explorer && explorer_item_count(state) > 0 {
                 let new_idx = state.ui.explorer_selected().saturating_sub(1);
                 state.ui.set_explorer_selection(Some(new_idx));
             }
             DispatchResult::handled()
         }
         Action::Select(SelectMotion::First) => {
-            if state.ui.focused_pane() == FocusedPane::Explorer && !state.tables().is_empty() {
+            if state.ui.focused_pane() == FocusedPane::Explorer && explorer_item_count(state) > 0 {
                 state.ui.set_explorer_selection(Some(0));
             }
             DispatchResult::handled()
         }
         Action::Select(SelectMotion::Last) => {
             if state.ui.focused_pane() == FocusedPane::Explorer {
-                let len = state.tables().len();
+                let len = explorer_item_count(state);
                 if len > 0 {
                     state.ui.set_explorer_selection(Some(len - 1));
                 }
@@ -155,6 +158,54 @@ pub fn reduce_explorer(state: &mut AppState, action: &Action) -> DispatchResult
             DispatchResult::handled()
         }
 
+        Action::ExplorerToggleMark => {
+            if state.ui.focused_pane() == FocusedPane::Explorer
+                && let Some(name) = state
+                    .explorer_selected_table()
+                    .map(TableSummary::qualified_name)
+            {
+                state.ui.toggle_explorer_marked_table(name);
+            }
+            DispatchResult::handled()
+        }
+        Action::ExplorerClearMarks => {
+            state.ui.clear_explorer_marked_tables();
+            DispatchResult::handled()
+        }
+        Action::ExplorerToggleSchema => {
+            match state.explorer_selected_row() {
+                Some(ExplorerRow::Schema { name, .. }) => {
+                    let schema = name.to_string();
+                    state.explorer_tree.toggle_schema(&schema);
+                }
+                Some(ExplorerRow::Section { schema, kind, .. }) => {
+                    let schema = schema.to_string();
+                    state.explorer_tree.toggle_section(&schema, kind);
+                }
+                _ => {}
+            }
+            DispatchResult::handled()
+        }
+        Action::SetExplorerSchemaFilter(schema) => {
+            if let Some(schema) = schema
+                && !state.tables().iter().any(|table| table.schema == *schema)
+                && !state
+                    .session
+                    .schema_objects()
+                    .iter()
+                    .any(|object| object.schema == *schema)
+            {
+                state
+                    .messages
+                    .set_error_at(format!("Unknown schema: {schema}"), now);
+                return DispatchResult::handled();
+            }
+            state.explorer_tree.set_schema_filter(schema.clone());
+            state.ui.set_explorer_selection(Some(0));
+            state.ui.set_explorer_horizontal_offset(0);
+            DispatchResult::handled()
+        }
+
         Action::Scroll {
             target: ScrollTarget::Explorer,
             direction: ScrollDirection::Left,
@@ -170,7 +221,7 @@ pub fn reduce_explorer(state: &mut AppState, action: &Action) -> DispatchResult
             direction: ScrollDirection::Right,
             amount: ScrollAmount::Line,
         } => {
-            let max_name_width = max_explorer_table_label_width(state.tables());
+            let max_name_width = max_explorer_row_label_width(&state.explorer_rows());
             let max_offset = scroll_max_offset(max_name_width, state.ui.explorer_content_width());
             if state.ui.explorer_horizontal_offset() < max_offset {
                 state
@@ -187,13 +238,12 @@ pub fn reduce_explorer(state: &mut AppState, action: &Action) -> DispatchResult
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{DatabaseMetadata, TableSummary};
+    use crate::domain::{DatabaseMetadata, SchemaObject, SchemaObjectKind, TableSummary};
     use crate::model::shared::key_sequence::Prefix;
     use crate::services::AppServices;
     use crate::update::browse::navigation::dispatch_navigation;
     use rstest::rstest;
     use std::sync::Arc;
-    use std::time::Instant;
 
     fn state_with_tables(count: usize, pane_height: u16) -> AppState {
         let mut state = AppState::new("test".to_string());
@@ -787,4 +837,174 @@ mod tests {
             );
         }
     }
+
+    mod marks {
+        use super::*;
+
+        #[test]
+        fn toggle_marks_and_unmarks_selected_table() {
+            let mut state = state_with_tables(3, 10);
+            state.ui.set_explorer_selection(Some(1));
+
+            reduce_explorer(&mut state, &Action::ExplorerToggleMark, Instant::now());
+            assert!(state.ui.explorer_marked_tables().contains("public.table_1"));
+
+            reduce_explorer(&mut state, &Action::ExplorerToggleMark, Instant::now());
+            assert!(state.ui.explorer_marked_tables().is_empty());
+        }
+
+        #[test]
+        fn toggle_outside_explorer_is_noop() {
+            let mut state = state_with_tables(3, 10);
+            state.ui.set_focused_pane(FocusedPane::Result);
+
+            reduce_explorer(&mut state, &Action::ExplorerToggleMark, Instant::now());
+
+            assert!(state.ui.explorer_marked_tables().is_empty());
+        }
+    }
+
+    mod schema_tree {
+        use super::*;
+
+        fn state_with_schemas() -> AppState {
+            let mut state = state_with_tables(0, 20);
+            let tables = [
+                ("audit", "events"),
+                ("public", "orders"),
+                ("public", "users"),
+            ]
+            .iter()
+            .map(|(schema, name)| {
+                TableSummary::new((*schema).to_string(), (*name).to_string(), None, false)
+            })
+            .collect();
+            state.session.set_metadata(Some(Arc::new({
+                let mut metadata = DatabaseMetadata::new("test".to_string());
+                metadata.table_summaries = tables;
+                metadata
+            })));
+            state
+        }
+
+        #[test]
+        fn toggle_on_schema_header_folds_its_tables() {
+            let mut state = state_with_schemas();
+            assert_eq!(explorer_item_count(&state), 5);
+
+            reduce_explorer(&mut state, &Action::ExplorerToggleSchema, Instant::now());
+
+            assert!(state.explorer_tree.layout().is_collapsed("audit"));
+            assert!(state.explorer_tree.is_dirty());
+            assert_eq!(explorer_item_count(&state), 4);
+            assert_eq!(state.ui.explorer_selected(), 0);
+        }
+
+        #[test]
+        fn toggle_on_section_header_lists_its_objects() {
+            let mut state = state_with_schemas();
+            let mut metadata = (**state.session.metadata().unwrap()).clone();
+            metadata.objects = vec![SchemaObject {
+                schema: "audit".to_string(),
+                name: "recent_events".to_string(),
+                kind: SchemaObjectKind::View,
+                arguments: None,
+                definition: None,
+            }];
+            state.session.set_metadata(Some(Arc::new(metadata)));
+            state.ui.set_explorer_selection(Some(2));
+            assert_eq!(explorer_item_count(&state), 6);
+
+            reduce_explorer(&mut state, &Action::ExplorerToggleSchema, Instant::now());
+
+            assert!(
+                state
+                    .explorer_tree
+                    .layout()
+                    .is_section_expanded("audit", SchemaObjectKind::View)
+            );
+            assert!(state.explorer_tree.is_dirty());
+            assert_eq!(explorer_item_count(&state), 7);
+        }
+
+        #[test]
+        fn toggle_on_table_row_is_noop() {
+            let mut state = state_with_schemas();
+            state.ui.set_explorer_selection(Some(1));
+
+            reduce_explorer(&mut state, &Action::ExplorerToggleSchema, Instant::now());
+
+            assert!(!state.explorer_tree.is_dirty());
+            assert_eq!(
+                state.explorer_selected_table().map(|t| t.name.as_str()),
+                Some("events")
+            );
+        }
+
+        #[test]
+        fn next_walks_headers_and_tables() {
+            let mut state = state_with_schemas();
+
+            for _ in 0..10 {
+                reduce_explorer(
+                    &mut state,
+                    &Action::Select(SelectMotion::Next),
+                    Instant::now(),
+                );
+            }
+
+            assert_eq!(state.ui.explorer_selected(), 4);
+            assert_eq!(
+                state.explorer_selected_table().map(|t| t.name.as_str()),
+                Some("users")
+            );
+        }
+
+        #[test]
+        fn schema_filter_narrows_rows_and_resets_selection() {
+            let mut state = state_with_schemas();
+            state.ui.set_explorer_selection(Some(3));
+
+            reduce_explorer(
+                &mut state,
+                &Action::SetExplorerSchemaFilter(Some("public".to_string())),
+                Instant::now(),
+            );
+
+            assert_eq!(state.explorer_tree.schema_filter(), Some("public"));
+            assert_eq!(explorer_item_count(&state), 2);
+            assert_eq!(state.ui.explorer_selected(), 0);
+        }
+
+        #[test]
+        fn unknown_schema_filter_is_rejected() {
+            let mut state = state_with_schemas();
+
+            reduce_explorer(
+                &mut state,
+                &Action::SetExplorerSchemaFilter(Some("billing".to_string())),
+                Instant::now(),
+            );
+
+            assert_eq!(state.explorer_tree.schema_filter(), None);
+            assert_eq!(state.messages.last_error(), Some("Unknown schema: billing"));
+        }
+
+        #[test]
+        fn clearing_schema_filter_restores_tree() {
+            let mut state = state_with_schemas();
+            state
+                .explorer_tree
+                .set_schema_filter(Some("audit".to_string()));
+
+            reduce_explorer(
+                &mut state,
+                &Action::SetExplorerSchemaFilter(None),
+                Instant::now(),
+            );
+
+            assert_eq!(state.explorer_tree.schema_filter(), None);
+            assert_eq!(explorer_item_count(&state), 5);
+        }
+    }
 }
@@ -1,7 +1,10 @@
+use crate::cmd::effect::Effect;
+use crate::i18n::Message;
 use crate::model::app_state::AppState;
 use crate::model::shared::confirm_dialog::ConfirmIntent;
 use crate::model::shared::focused_pane::FocusedPane;
 use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::inspector_tab::InspectorTab;
 use crate::update::action::Action;
 use crate::update::dispatch_result::DispatchResult;
 
@@ -28,7 +31,7 @@ pub fn reduce_focus(state: &mut AppState, action: &Action) -> DispatchResult {
         Action::ToggleReadOnly => {
             if state.session.is_read_only() {
                 state.confirm_dialog.open(
-                    "Disable Read-Only",
+                    Message::DisableReadOnlyTitle.text(state.settings.locale()),
                     "Switch to read-write mode? Write operations will be allowed.",
                     ConfirmIntent::DisableReadOnly,
                 );
@@ -45,7 +48,7 @@ pub fn reduce_focus(state: &mut AppState, action: &Action) -> DispatchResult {
                     .active_engine_feature_profile()
                     .next_inspector_tab(state.ui.inspector_tab()),
             );
-            DispatchResult::handled()
+            load_stats_on_entry(state)
         }
         Action::InspectorPrevTab => {
             state.ui.set_inspector_tab(
@@ -54,13 +57,23 @@ pub fn reduce_focus(state: &mut AppState, action: &Action) -> DispatchResult {
                     .active_engine_feature_profile()
                     .prev_inspector_tab(state.ui.inspector_tab()),
             );
-            DispatchResult::handled()
+            load_stats_on_entry(state)
         }
 
         _ => DispatchResult::pass(),
     }
 }
 
+fn load_stats_on_entry(state: &AppState) -> DispatchResult {
+    if state.ui.inspector_tab() == InspectorTab::Stats {
+        DispatchResult::handled_with(vec![Effect::DispatchActions(vec![
+            Action::InspectorLoadColumnDistribution,
+        ])])
+    } else {
+        DispatchResult::handled()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,6 +33,22 @@ pub fn reduce_input(state: &mut AppState, action: &Action) -> DispatchResult {
                 state.query_history_picker.insert_filter_str(text);
                 DispatchResult::handled()
             }
+            InputMode::ServerSettings => {
+                state.server_settings.picker_mut().insert_filter_str(text);
+                DispatchResult::handled()
+            }
+            InputMode::SqlFileBrowser => {
+                state.sql_files.picker_mut().insert_filter_str(text);
+                DispatchResult::handled()
+            }
+            InputMode::SnippetPicker => {
+                state.snippets.picker_mut().insert_filter_str(text);
+                DispatchResult::handled()
+            }
+            InputMode::ObjectSearch => {
+                state.object_search.picker_mut().insert_filter_str(text);
+                DispatchResult::handled()
+            }
             _ => DispatchResult::pass(),
         },
 
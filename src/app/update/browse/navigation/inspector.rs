@@ -1,8 +1,14 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::PUBLIC_ROLE;
 use crate::model::app_state::AppState;
-use crate::model::browse::inspector_view_model::InspectorViewModel;
+use crate::model::browse::inspector_view_model::{InspectorSection, InspectorViewModel};
+use crate::model::postgres::column_distribution::ColumnDistributionEntry;
+use crate::model::shared::inspector_tab::InspectorTab;
 use crate::model::shared::viewport::{calculate_next_column_offset, calculate_prev_column_offset};
 use crate::services::AppServices;
-use crate::update::action::{Action, ScrollAmount, ScrollDirection, ScrollTarget};
+use crate::update::action::{Action, ModalKind, ScrollAmount, ScrollDirection, ScrollTarget};
 use crate::update::dispatch_result::DispatchResult;
 
 use super::inspector_max_scroll;
@@ -15,12 +21,137 @@ fn inspector_page_scroll_delta(
     amount.page_delta(view_model.visible_rows(pane_height))
 }
 
+// Line moves in the Columns tab walk the pin cursor and only scroll once it
+// reaches a viewport edge.
+fn move_column_cursor(
+    state: &mut AppState,
+    view_model: &InspectorViewModel,
+    direction: ScrollDirection,
+) {
+    let pane_height = state.ui.inspector_pane_height();
+    let Some(cursor) = view_model.column_cursor(
+        state.ui.inspector_column_cursor(),
+        state.ui.inspector_scroll_offset(),
+        pane_height,
+    ) else {
+        return;
+    };
+    let next = direction.clamp_vertical_offset(cursor, view_model.row_count() - 1, 1);
+    let visible = view_model.visible_rows(pane_height).max(1);
+    let top = state
+        .ui
+        .inspector_scroll_offset()
+        .min(view_model.max_scroll(pane_height));
+    let top = if next < top {
+        next
+    } else if next >= top + visible {
+        next + 1 - visible
+    } else {
+        top
+    };
+    state.ui.set_inspector_column_cursor(next);
+    state.ui.set_inspector_scroll_offset(top);
+}
+
+/// Queries the Stats tab's column when it has no result yet; a failed fetch
+/// is retried on the next visit.
+fn column_distribution_fetch(state: &mut AppState, services: &AppServices) -> Option<Effect> {
+    let profile = state.session.active_engine_feature_profile();
+    if profile.normalize_inspector_tab(state.ui.inspector_tab()) != InspectorTab::Stats
+        || state.session.inspected_object().is_some()
+    {
+        return None;
+    }
+    let dsn = state.session.dsn()?.to_string();
+    let table = state.session.table_detail()?;
+    let column = table.columns.get(
+        state
+            .ui
+            .inspector_column_cursor()
+            .min(table.columns.len().checked_sub(1)?),
+    )?;
+    let qualified = table.qualified_name();
+    if matches!(
+        state.column_distribution.entry(&qualified, &column.name),
+        Some(ColumnDistributionEntry::Loading { .. } | ColumnDistributionEntry::Loaded(_))
+    ) {
+        return None;
+    }
+    let query = services.sql_dialect.build_column_distribution_sql(
+        state.session.active_database_type_or_default(),
+        &table.schema,
+        &table.name,
+        column,
+        table.row_count_estimate,
+    )?;
+    let column = column.name.clone();
+    let run_id = state.column_distribution.begin_fetch(&qualified, &column);
+    Some(Effect::FetchColumnDistribution { dsn, run_id, query })
+}
+
 pub fn reduce_inspector(
     state: &mut AppState,
     action: &Action,
     services: &AppServices,
+    now: Instant,
 ) -> DispatchResult {
     match action {
+        Action::Scroll {
+            target: ScrollTarget::Inspector,
+            direction: direction @ (ScrollDirection::Up | ScrollDirection::Down),
+            amount: ScrollAmount::Line,
+        } if state.ui.inspector_tab() == InspectorTab::Columns => {
+            let view_model = state.inspector_view_model(services.ddl_generator.as_ref());
+            move_column_cursor(state, &view_model, *direction);
+            DispatchResult::handled()
+        }
+        // j/k in the Stats tab step to the neighbouring column.
+        Action::Scroll {
+            target: ScrollTarget::Inspector,
+            direction: direction @ (ScrollDirection::Up | ScrollDirection::Down),
+            amount: ScrollAmount::Line,
+        } if state.ui.inspector_tab() == InspectorTab::Stats => {
+            let Some(last) = state
+                .session
+                .table_detail()
+                .and_then(|table| table.columns.len().checked_sub(1))
+            else {
+                return DispatchResult::handled();
+            };
+            let cursor = state.ui.inspector_column_cursor().min(last);
+            state
+                .ui
+                .set_inspector_column_cursor(direction.clamp_vertical_offset(cursor, last, 1));
+            state.ui.set_inspector_scroll_offset(0);
+            DispatchResult::handled_with(
+                column_distribution_fetch(state, services)
+                    .into_iter()
+                    .collect(),
+            )
+        }
+        Action::InspectorLoadColumnDistribution => DispatchResult::handled_with(
+            column_distribution_fetch(state, services)
+                .into_iter()
+                .collect(),
+        ),
+        Action::ColumnDistributionLoaded {
+            dsn,
+            run_id,
+            distribution,
+        } => {
+            if state.session.dsn_matches(dsn) {
+                state
+                    .column_distribution
+                    .set_loaded(*run_id, distribution.clone());
+            }
+            DispatchResult::handled()
+        }
+        Action::ColumnDistributionFailed { dsn, run_id, error } => {
+            if state.session.dsn_matches(dsn) && state.column_distribution.set_failed(*run_id) {
+                state.messages.set_error_at(error.user_message(), now);
+            }
+            DispatchResult::handled()
+        }
         Action::Scroll {
             target: ScrollTarget::Inspector,
             direction: direction @ (ScrollDirection::Up | ScrollDirection::Down),
@@ -42,6 +173,9 @@ pub fn reduce_inspector(
             amount: ScrollAmount::ToStart,
         } => {
             state.ui.set_inspector_scroll_offset(0);
+            if state.ui.inspector_tab() == InspectorTab::Columns {
+                state.ui.set_inspector_column_cursor(0);
+            }
             DispatchResult::handled()
         }
         Action::Scroll {
@@ -49,9 +183,15 @@ pub fn reduce_inspector(
             direction: ScrollDirection::Down,
             amount: ScrollAmount::ToEnd,
         } => {
-            state
-                .ui
-                .set_inspector_scroll_offset(inspector_max_scroll(state, services));
+            let view_model = state.inspector_view_model(services.ddl_generator.as_ref());
+            state.ui.set_inspector_scroll_offset(
+                view_model.max_scroll(state.ui.inspector_pane_height()),
+            );
+            if view_model.active_tab() == InspectorTab::Columns {
+                state
+                    .ui
+                    .set_inspector_column_cursor(view_model.row_count().saturating_sub(1));
+            }
             DispatchResult::handled()
         }
         Action::Scroll {
@@ -99,6 +239,88 @@ pub fn reduce_inspector(
                 ));
             DispatchResult::handled()
         }
+        Action::InspectorTogglePin => {
+            let view_model = state.inspector_view_model(services.ddl_generator.as_ref());
+            let Some(InspectorSection::Columns { rows, .. }) = view_model.section() else {
+                return DispatchResult::handled();
+            };
+            let Some(cursor) = view_model.column_cursor(
+                state.ui.inspector_column_cursor(),
+                state.ui.inspector_scroll_offset(),
+                state.ui.inspector_pane_height(),
+            ) else {
+                return DispatchResult::handled();
+            };
+            let name = rows[cursor].name.clone();
+            if state.ui.inspector_pinned_column() == Some(name.as_str()) {
+                state.ui.set_inspector_pinned_column(None);
+                state
+                    .messages
+                    .set_success_at(format!("Unpinned column: {name}"), now);
+            } else {
+                state
+                    .messages
+                    .set_success_at(format!("Pinned column: {name}"), now);
+                state.ui.set_inspector_pinned_column(Some(name));
+            }
+            DispatchResult::handled()
+        }
+        // All roles → each role in turn → all roles again.
+        Action::InspectorCycleRlsRole => {
+            let Some(rls) = state
+                .session
+                .table_detail()
+                .and_then(|table| table.rls.as_ref())
+            else {
+                return DispatchResult::handled();
+            };
+            let roles = rls.roles();
+            let next = match state.ui.inspector_rls_role() {
+                None => roles.first(),
+                Some(current) => roles
+                    .iter()
+                    .position(|role| *role == current)
+                    .and_then(|index| roles.get(index + 1)),
+            }
+            .map(ToString::to_string);
+            state.ui.set_inspector_rls_role(next);
+            state.ui.set_inspector_scroll_offset(0);
+            DispatchResult::handled()
+        }
+        Action::InspectorTestRlsRole => {
+            let Some(table) = state.session.table_detail() else {
+                return DispatchResult::handled();
+            };
+            let Some(role) = state.ui.inspector_rls_role() else {
+                state
+                    .messages
+                    .set_error_at("Pick a role with o first".to_string(), now);
+                return DispatchResult::handled();
+            };
+            if role == PUBLIC_ROLE {
+                state.messages.set_error_at(
+                    "public covers every role; pick a specific one to test".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            }
+            let Some(script) = services.sql_dialect.build_rls_test_sql(
+                state.session.active_database_type_or_default(),
+                &table.schema,
+                &table.name,
+                role,
+            ) else {
+                state.messages.set_error_at(
+                    "Testing as a role is only available for PostgreSQL".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            };
+            state.sql_modal.editor.set_content(script);
+            DispatchResult::handled_with(vec![Effect::DispatchActions(vec![Action::OpenModal(
+                ModalKind::SqlModal,
+            )])])
+        }
 
         _ => DispatchResult::pass(),
     }
@@ -110,7 +332,6 @@ mod tests {
     use crate::domain::{Column, ColumnAttributes, ConnectionId, DatabaseType, Table};
     use crate::model::shared::inspector_tab::InspectorTab;
     use crate::update::browse::navigation::dispatch_navigation;
-    use std::time::Instant;
 
     mod inspector_scroll_top_bottom {
         use crate::test_support;
@@ -404,5 +625,308 @@ mod tests {
             assert!(effects.is_handled());
             assert_eq!(state.ui.inspector_scroll_offset(), 0);
         }
+
+        mod rls_roles {
+            use super::*;
+            use crate::domain::{RlsCommand, RlsInfo, RlsPolicy};
+            use crate::model::shared::text_input::TextInputLike;
+
+            fn state_with_policies() -> AppState {
+                let mut state = state_with_table_detail(2);
+                state.ui.set_inspector_tab(InspectorTab::Rls);
+                let mut table = state.session.table_detail().cloned().unwrap();
+                table.rls = Some(RlsInfo {
+                    enabled: true,
+                    force: false,
+                    policies: vec![RlsPolicy {
+                        name: "tenant_isolation".to_string(),
+                        permissive: true,
+                        roles: vec!["reporting".to_string(), "app_user".to_string()],
+                        cmd: RlsCommand::All,
+                        qual: Some("(tenant_id = 1)".to_string()),
+                        with_check: None,
+                    }],
+                });
+                state.session.set_table_detail_raw(Some(table));
+                state
+            }
+
+            fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+                dispatch_navigation(state, action, &AppServices::stub(), Instant::now())
+                    .into_effects()
+                    .unwrap()
+            }
+
+            #[test]
+            fn cycle_walks_each_role_then_clears_the_filter() {
+                let mut state = state_with_policies();
+
+                let mut seen = Vec::new();
+                for _ in 0..3 {
+                    dispatch(&mut state, &Action::InspectorCycleRlsRole);
+                    seen.push(state.ui.inspector_rls_role().map(String::from));
+                }
+
+                assert_eq!(
+                    seen,
+                    vec![
+                        Some("app_user".to_string()),
+                        Some("reporting".to_string()),
+                        None
+                    ]
+                );
+            }
+
+            #[test]
+            fn test_as_role_loads_the_script_into_the_sql_modal() {
+                let mut state = state_with_policies();
+                state
+                    .ui
+                    .set_inspector_rls_role(Some("app_user".to_string()));
+
+                let effects = dispatch(&mut state, &Action::InspectorTestRlsRole);
+
+                assert_eq!(
+                    state.sql_modal.editor.content(),
+                    "AS app_user SELECT public.test_table"
+                );
+                assert!(matches!(
+                    effects.as_slice(),
+                    [Effect::DispatchActions(actions)]
+                        if matches!(actions.as_slice(), [Action::OpenModal(ModalKind::SqlModal)])
+                ));
+            }
+
+            #[test]
+            fn test_as_role_needs_a_specific_role() {
+                let mut state = state_with_policies();
+
+                let effects = dispatch(&mut state, &Action::InspectorTestRlsRole);
+
+                assert!(effects.is_empty());
+                assert_eq!(
+                    state.messages.last_error(),
+                    Some("Pick a role with o first")
+                );
+            }
+        }
+
+        mod column_pin {
+            use super::*;
+
+            fn line(state: &mut AppState, direction: ScrollDirection) {
+                dispatch_navigation(
+                    state,
+                    &Action::Scroll {
+                        target: ScrollTarget::Inspector,
+                        direction,
+                        amount: ScrollAmount::Line,
+                    },
+                    &AppServices::stub(),
+                    Instant::now(),
+                );
+            }
+
+            fn toggle_pin(state: &mut AppState) {
+                dispatch_navigation(
+                    state,
+                    &Action::InspectorTogglePin,
+                    &AppServices::stub(),
+                    Instant::now(),
+                );
+            }
+
+            #[test]
+            fn line_moves_walk_the_cursor_and_scroll_at_the_edge() {
+                let mut state = state_with_table_detail(20);
+
+                for _ in 0..6 {
+                    line(&mut state, ScrollDirection::Down);
+                }
+
+                // 5 visible rows: the 7th row scrolls the viewport by two
+                assert_eq!(state.ui.inspector_column_cursor(), 6);
+                assert_eq!(state.ui.inspector_scroll_offset(), 2);
+
+                line(&mut state, ScrollDirection::Up);
+                assert_eq!(state.ui.inspector_column_cursor(), 5);
+                assert_eq!(state.ui.inspector_scroll_offset(), 2);
+            }
+
+            #[test]
+            fn bottom_reaches_the_last_column() {
+                let mut state = state_with_table_detail(20);
+
+                dispatch_navigation(
+                    &mut state,
+                    &Action::Scroll {
+                        target: ScrollTarget::Inspector,
+                        direction: ScrollDirection::Down,
+                        amount: ScrollAmount::ToEnd,
+                    },
+                    &AppServices::stub(),
+                    Instant::now(),
+                );
+                toggle_pin(&mut state);
+
+                assert_eq!(state.ui.inspector_pinned_column(), Some("col_19"));
+            }
+
+            #[test]
+            fn pin_toggles_the_column_under_the_cursor() {
+                let mut state = state_with_table_detail(20);
+                line(&mut state, ScrollDirection::Down);
+
+                toggle_pin(&mut state);
+                assert_eq!(state.ui.inspector_pinned_column(), Some("col_1"));
+                assert_eq!(
+                    state.messages.last_success.as_deref(),
+                    Some("Pinned column: col_1")
+                );
+
+                toggle_pin(&mut state);
+                assert_eq!(state.ui.inspector_pinned_column(), None);
+            }
+
+            #[test]
+            fn pin_follows_the_viewport_after_another_tab_scrolled_it() {
+                let mut state = state_with_table_detail(20);
+                state.ui.set_inspector_scroll_offset(8);
+
+                toggle_pin(&mut state);
+
+                assert_eq!(state.ui.inspector_pinned_column(), Some("col_8"));
+            }
+
+            #[test]
+            fn pin_is_ignored_outside_the_columns_tab() {
+                let mut state = state_with_table_detail(20);
+                state.ui.set_inspector_tab(InspectorTab::Indexes);
+
+                toggle_pin(&mut state);
+
+                assert_eq!(state.ui.inspector_pinned_column(), None);
+            }
+        }
+
+        mod stats_tab {
+            use super::*;
+            use crate::domain::ColumnDistribution;
+            use crate::ports::outbound::DbOperationError;
+
+            fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+                dispatch_navigation(state, action, &AppServices::stub(), Instant::now())
+                    .into_effects()
+                    .unwrap()
+            }
+
+            fn line(state: &mut AppState, direction: ScrollDirection) -> Vec<Effect> {
+                dispatch(
+                    state,
+                    &Action::Scroll {
+                        target: ScrollTarget::Inspector,
+                        direction,
+                        amount: ScrollAmount::Line,
+                    },
+                )
+            }
+
+            fn fetched(effects: &[Effect]) -> Option<(u64, &str)> {
+                match effects {
+                    [Effect::FetchColumnDistribution { run_id, query, .. }] => {
+                        Some((*run_id, query.as_str()))
+                    }
+                    _ => None,
+                }
+            }
+
+            fn stats_state() -> AppState {
+                let mut state = state_with_table_detail(3);
+                state.ui.set_inspector_tab(InspectorTab::Stats);
+                state
+            }
+
+            #[test]
+            fn entering_the_tab_asks_for_the_cursor_column() {
+                let mut state = state_with_table_detail(3);
+                state.ui.set_inspector_tab(InspectorTab::Columns);
+
+                let effects = dispatch(&mut state, &Action::InspectorNextTab);
+
+                assert_eq!(state.ui.inspector_tab(), InspectorTab::Stats);
+                assert!(matches!(
+                    effects.as_slice(),
+                    [Effect::DispatchActions(actions)]
+                        if matches!(actions.as_slice(), [Action::InspectorLoadColumnDistribution])
+                ));
+                let effects = dispatch(&mut state, &Action::InspectorLoadColumnDistribution);
+                assert_eq!(
+                    fetched(&effects).map(|(_, query)| query),
+                    Some("DISTRIBUTION \"public\".\"test_table\".\"col_0\"")
+                );
+            }
+
+            #[test]
+            fn line_moves_step_columns_and_fetch_each_once() {
+                let mut state = stats_state();
+                state.ui.set_inspector_scroll_offset(4);
+
+                let effects = line(&mut state, ScrollDirection::Down);
+                assert_eq!(state.ui.inspector_column_cursor(), 1);
+                assert_eq!(state.ui.inspector_scroll_offset(), 0);
+                assert_eq!(
+                    fetched(&effects).map(|(_, query)| query),
+                    Some("DISTRIBUTION \"public\".\"test_table\".\"col_1\"")
+                );
+
+                line(&mut state, ScrollDirection::Down);
+                assert_eq!(state.ui.inspector_column_cursor(), 2);
+                let effects = line(&mut state, ScrollDirection::Down);
+                assert_eq!(state.ui.inspector_column_cursor(), 2);
+                assert!(effects.is_empty());
+
+                let effects = line(&mut state, ScrollDirection::Up);
+                assert!(effects.is_empty(), "col_1 is already in flight");
+            }
+
+            #[test]
+            fn results_land_on_their_column_and_failures_are_retried() {
+                let mut state = stats_state();
+                let effects = dispatch(&mut state, &Action::InspectorLoadColumnDistribution);
+                let (run_id, _) = fetched(&effects).unwrap();
+
+                dispatch(
+                    &mut state,
+                    &Action::ColumnDistributionFailed {
+                        dsn: "postgres://test".to_string(),
+                        run_id,
+                        error: DbOperationError::PermissionDenied("pg_stats".to_string()),
+                    },
+                );
+                assert!(state.messages.last_error().is_some());
+
+                let effects = dispatch(&mut state, &Action::InspectorLoadColumnDistribution);
+                let (run_id, _) = fetched(&effects).expect("failed fetch is retried");
+                dispatch(
+                    &mut state,
+                    &Action::ColumnDistributionLoaded {
+                        dsn: "postgres://test".to_string(),
+                        run_id,
+                        distribution: ColumnDistribution {
+                            sampled_rows: 12,
+                            ..ColumnDistribution::default()
+                        },
+                    },
+                );
+
+                assert!(matches!(
+                    state.column_distribution.entry("public.test_table", "col_0"),
+                    Some(ColumnDistributionEntry::Loaded(distribution))
+                        if distribution.sampled_rows == 12
+                ));
+                let effects = dispatch(&mut state, &Action::InspectorLoadColumnDistribution);
+                assert!(effects.is_empty());
+            }
+        }
     }
 }
@@ -18,7 +18,7 @@ pub(super) fn inspector_max_scroll(state: &AppState, services: &AppServices) ->
 }
 
 pub(super) fn explorer_item_count(state: &AppState) -> usize {
-    state.tables().len()
+    state.explorer_rows().len()
 }
 
 pub fn dispatch_navigation(
@@ -29,7 +29,7 @@ pub fn dispatch_navigation(
 ) -> DispatchResult {
     focus::reduce_focus(state, action)
         .or_else(|| input::reduce_input(state, action))
-        .or_else(|| explorer::reduce_explorer(state, action))
-        .or_else(|| inspector::reduce_inspector(state, action, services))
+        .or_else(|| explorer::reduce_explorer(state, action, now))
+        .or_else(|| inspector::reduce_inspector(state, action, services, now))
         .or_else(|| connection_list::reduce_connection_list(state, action, now))
 }
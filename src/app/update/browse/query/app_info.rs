@@ -0,0 +1,221 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::policy::startup_report::startup_report;
+use crate::policy::usage_report::usage_report;
+use crate::policy::version_report::{CURRENT_VERSION, is_newer_release, version_report};
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+use super::execution::reset_view_for_new_result;
+
+pub fn reduce_app_info(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    _services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::ShowStartupReport => {
+            reset_view_for_new_result(state, now);
+            state
+                .query
+                .set_current_result(Arc::new(startup_report(state.runtime.startup())));
+            DispatchResult::handled()
+        }
+
+        Action::ShowUsageStats => {
+            let Some(report) = usage_report(state.usage.stats()) else {
+                let message = if state.usage.is_enabled() {
+                    "No usage recorded yet"
+                } else {
+                    "Usage tracking is off — enable it with :usage on"
+                };
+                state.messages.set_error_at(message.to_string(), now);
+                return DispatchResult::handled();
+            };
+            reset_view_for_new_result(state, now);
+            state.query.set_current_result(Arc::new(report));
+            DispatchResult::handled()
+        }
+
+        Action::ShowVersion => {
+            reset_view_for_new_result(state, now);
+            state.query.set_current_result(Arc::new(version_report(
+                state.settings.update_check(),
+                state.runtime.latest_release(),
+            )));
+            DispatchResult::handled()
+        }
+
+        Action::CheckForUpdate => {
+            if !state.settings.update_check() {
+                return DispatchResult::handled();
+            }
+            DispatchResult::handled_with(vec![Effect::CheckLatestRelease])
+        }
+
+        Action::LatestReleaseLoaded(release) => {
+            if is_newer_release(CURRENT_VERSION, &release.version) {
+                state.messages.set_success_at(
+                    format!(
+                        "sabiql v{} is available — :version for the changelog",
+                        release.version
+                    ),
+                    now,
+                );
+            }
+            state.runtime.set_latest_release(release.clone());
+            DispatchResult::handled()
+        }
+
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::UsageStats;
+    use crate::model::shared::startup_profile::StartupPhase;
+    use crate::ports::outbound::ReleaseInfo;
+    use crate::update::browse::query::dispatch_query;
+    use crate::update::browse::query::tests::create_test_state;
+
+    #[test]
+    fn usage_report_replaces_visible_result() {
+        let mut state = create_test_state();
+        state.usage.load(true, UsageStats::default());
+        state.usage.record_command(":sql");
+
+        dispatch_query(
+            &mut state,
+            &Action::ShowUsageStats,
+            Instant::now(),
+            &AppServices::stub(),
+        );
+
+        let shown = state.query.visible_result().unwrap();
+        assert_eq!(
+            shown.display_row_at(0).unwrap(),
+            vec!["Command", ":sql", "1"]
+        );
+    }
+    #[test]
+    fn usage_report_without_counts_explains_opt_in() {
+        let mut state = create_test_state();
+
+        dispatch_query(
+            &mut state,
+            &Action::ShowUsageStats,
+            Instant::now(),
+            &AppServices::stub(),
+        );
+
+        assert!(state.query.visible_result().is_none());
+        assert!(state.messages.last_error().unwrap().contains(":usage on"));
+    }
+    #[test]
+    fn startup_report_replaces_visible_result() {
+        let mut state = create_test_state();
+        let launched_at = Instant::now();
+        state.runtime.startup_mut().begin(launched_at);
+        state
+            .runtime
+            .startup_mut()
+            .mark(StartupPhase::ConfigLoad, launched_at);
+
+        dispatch_query(
+            &mut state,
+            &Action::ShowStartupReport,
+            Instant::now(),
+            &AppServices::stub(),
+        );
+
+        let shown = state.query.visible_result().unwrap();
+        assert_eq!(shown.query, ":startup report");
+        assert_eq!(shown.display_row_at(0).unwrap()[0], "config load");
+    }
+    #[test]
+    fn update_check_is_skipped_until_opted_in() {
+        let mut state = create_test_state();
+
+        let result = dispatch_query(
+            &mut state,
+            &Action::CheckForUpdate,
+            Instant::now(),
+            &AppServices::stub(),
+        );
+        assert!(result.into_effects().unwrap().is_empty());
+
+        state.settings.load_update_check(true);
+        let result = dispatch_query(
+            &mut state,
+            &Action::CheckForUpdate,
+            Instant::now(),
+            &AppServices::stub(),
+        );
+        assert!(matches!(
+            result.into_effects().unwrap().as_slice(),
+            [Effect::CheckLatestRelease]
+        ));
+    }
+    #[test]
+    fn newer_release_notifies_and_shows_in_version_report() {
+        let mut state = create_test_state();
+        state.settings.load_update_check(true);
+
+        dispatch_query(
+            &mut state,
+            &Action::LatestReleaseLoaded(ReleaseInfo {
+                version: "999.0.0".to_string(),
+                url: "https://github.com/riii111/sabiql/releases/tag/v999.0.0".to_string(),
+                notes: "- Faster previews".to_string(),
+            }),
+            Instant::now(),
+            &AppServices::stub(),
+        );
+        assert!(
+            state
+                .messages
+                .last_success
+                .as_deref()
+                .is_some_and(|message| message.contains("v999.0.0 is available"))
+        );
+
+        dispatch_query(
+            &mut state,
+            &Action::ShowVersion,
+            Instant::now(),
+            &AppServices::stub(),
+        );
+        let shown = state.query.visible_result().unwrap();
+        assert_eq!(shown.query, ":version");
+        assert!(
+            (0..shown.row_count())
+                .filter_map(|i| shown.display_row_at(i))
+                .any(|row| row[1] == "- Faster previews")
+        );
+    }
+    #[test]
+    fn current_release_is_stored_without_notice() {
+        let mut state = create_test_state();
+
+        dispatch_query(
+            &mut state,
+            &Action::LatestReleaseLoaded(ReleaseInfo {
+                version: CURRENT_VERSION.to_string(),
+                url: String::new(),
+                notes: String::new(),
+            }),
+            Instant::now(),
+            &AppServices::stub(),
+        );
+
+        assert!(state.messages.last_success().is_none());
+        assert!(state.runtime.latest_release().is_some());
+    }
+}
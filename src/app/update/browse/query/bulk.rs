@@ -0,0 +1,332 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::MaintenanceKind;
+use crate::model::app_state::AppState;
+use crate::policy::sql::replica_routing::QueryEndpoint;
+use crate::ports::outbound::AccessMode;
+use crate::ports::outbound::clipboard::ClipboardError;
+use crate::services::AppServices;
+use crate::update::action::{Action, BulkTableOperation};
+use crate::update::dispatch_result::DispatchResult;
+
+use super::maintenance::{start_effects, startable_dsn};
+
+pub fn reduce_bulk(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::BulkTables(operation) => {
+            let targets = marked_targets(state);
+            if targets.is_empty() {
+                state.messages.set_error_at(
+                    "Mark tables in the Explorer with Space first".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            }
+            match operation {
+                BulkTableOperation::CopyDdl => copy_ddl(state, targets),
+                BulkTableOperation::ErDiagram => {
+                    state.er_preparation.set_targets(
+                        targets
+                            .iter()
+                            .map(|(schema, table)| format!("{schema}.{table}"))
+                            .collect(),
+                    );
+                    DispatchResult::handled_with(vec![Effect::DispatchActions(vec![
+                        Action::ErOpenDiagram,
+                    ])])
+                }
+                BulkTableOperation::Analyze => analyze(state, services, &targets, now),
+                BulkTableOperation::Sizes => sizes(state, services, &targets, now),
+            }
+        }
+
+        Action::BulkDdlLoaded { dsn, tables } => {
+            if state.session.dsn() != Some(dsn.as_str()) {
+                return DispatchResult::handled();
+            }
+            let database_type = state.session.active_database_type_or_default();
+            let content = tables
+                .iter()
+                .map(|table| services.ddl_generator.generate_ddl(database_type, table))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            DispatchResult::handled_with(vec![Effect::CopyToClipboard {
+                content,
+                on_success: Some(Box::new(Action::BulkDdlCopied(tables.len()))),
+                on_failure: Some(Box::new(Action::CopyFailed(ClipboardError::Unavailable(
+                    "Clipboard unavailable".into(),
+                )))),
+            }])
+        }
+        Action::BulkDdlFailed { dsn, error } => {
+            if state.session.dsn() == Some(dsn.as_str()) {
+                state
+                    .messages
+                    .set_error_at(format!("Bulk DDL failed: {}", error.user_message()), now);
+            }
+            DispatchResult::handled()
+        }
+        Action::BulkDdlCopied(count) => {
+            let noun = if *count == 1 { "table" } else { "tables" };
+            state
+                .messages
+                .set_success_at(format!("Copied DDL for {count} {noun}"), now);
+            DispatchResult::handled()
+        }
+
+        _ => DispatchResult::pass(),
+    }
+}
+
+/// `(schema, table)` for each marked table still in the metadata, in Explorer
+/// order; marks left behind by a reload are skipped.
+fn marked_targets(state: &AppState) -> Vec<(String, String)> {
+    let marked = state.ui.explorer_marked_tables();
+    state
+        .tables()
+        .into_iter()
+        .filter(|table| marked.contains(&table.qualified_name()))
+        .map(|table| (table.schema.clone(), table.name.clone()))
+        .collect()
+}
+
+pub(super) fn copy_ddl(state: &AppState, tables: Vec<(String, String)>) -> DispatchResult {
+    let Some(dsn) = state.session.dsn().map(String::from) else {
+        return DispatchResult::handled();
+    };
+    DispatchResult::handled_with(vec![Effect::FetchBulkTableDetails { dsn, tables }])
+}
+
+pub(super) fn analyze(
+    state: &mut AppState,
+    services: &AppServices,
+    targets: &[(String, String)],
+    now: Instant,
+) -> DispatchResult {
+    let Some(dsn) = startable_dsn(state, now) else {
+        return DispatchResult::handled();
+    };
+    let database_type = state.session.active_database_type_or_default();
+    let queries: Vec<String> = targets
+        .iter()
+        .filter_map(|(schema, table)| {
+            services.sql_dialect.build_maintenance_sql(
+                database_type,
+                MaintenanceKind::Analyze,
+                schema,
+                table,
+            )
+        })
+        .collect();
+    if queries.is_empty() {
+        return DispatchResult::handled();
+    }
+
+    let run_id = state.maintenance.begin(
+        dsn.clone(),
+        MaintenanceKind::Analyze.label(),
+        match targets {
+            [(schema, table)] => format!("{schema}.{table}"),
+            _ => format!("{} tables", targets.len()),
+        },
+        None,
+        now,
+    );
+    DispatchResult::handled_with(start_effects(dsn, run_id, queries, None))
+}
+
+fn sizes(
+    state: &mut AppState,
+    services: &AppServices,
+    targets: &[(String, String)],
+    now: Instant,
+) -> DispatchResult {
+    let Some(dsn) = state.session.dsn().map(String::from) else {
+        return DispatchResult::handled();
+    };
+    let Some(query) = services
+        .sql_dialect
+        .build_table_sizes_sql(state.session.active_database_type_or_default(), targets)
+    else {
+        state.messages.set_error_at(
+            "Table sizes are only available for PostgreSQL".to_string(),
+            now,
+        );
+        return DispatchResult::handled();
+    };
+    let endpoint = state.active_replica_dsn().map(|_| QueryEndpoint::Primary);
+    state.session.record_query_endpoint(endpoint);
+    let run_id = state.query.begin_running(now);
+    DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
+        dsn,
+        replica_dsn: None,
+        run_id,
+        query,
+        access_mode: AccessMode::ReadOnly,
+    }])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::{DatabaseMetadata, TableSummary};
+    use crate::ports::outbound::DbOperationError;
+    use crate::test_support;
+    use crate::update::browse::query::tests::create_test_state;
+
+    const DSN: &str = "postgres://localhost/test";
+
+    fn state_with_marks(marked: &[&str]) -> AppState {
+        let mut state = create_test_state();
+        let mut metadata = DatabaseMetadata::new("test".to_string());
+        metadata.table_summaries = ["users", "orders", "items"]
+            .into_iter()
+            .map(|name| TableSummary::new("public".to_string(), name.to_string(), None, false))
+            .collect();
+        state.session.set_metadata(Some(Arc::new(metadata)));
+        for table in marked {
+            state.ui.toggle_explorer_marked_table((*table).to_string());
+        }
+        state
+    }
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        reduce_bulk(state, action, Instant::now(), &AppServices::stub())
+            .into_effects()
+            .unwrap()
+    }
+
+    #[test]
+    fn without_marks_reports_error() {
+        let mut state = state_with_marks(&[]);
+
+        let effects = dispatch(&mut state, &Action::BulkTables(BulkTableOperation::Sizes));
+
+        assert!(effects.is_empty());
+        assert_eq!(
+            state.messages.last_error(),
+            Some("Mark tables in the Explorer with Space first")
+        );
+    }
+
+    #[test]
+    fn copy_ddl_fetches_marked_tables_in_explorer_order() {
+        let mut state = state_with_marks(&["public.items", "public.users", "public.gone"]);
+
+        let effects = dispatch(&mut state, &Action::BulkTables(BulkTableOperation::CopyDdl));
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::FetchBulkTableDetails { dsn, tables }]
+                if dsn == DSN
+                    && tables == &[
+                        ("public".to_string(), "users".to_string()),
+                        ("public".to_string(), "items".to_string()),
+                    ]
+        ));
+    }
+
+    #[test]
+    fn loaded_details_are_copied_as_one_script() {
+        let mut state = state_with_marks(&["public.users"]);
+
+        let effects = dispatch(
+            &mut state,
+            &Action::BulkDdlLoaded {
+                dsn: DSN.to_string(),
+                tables: vec![
+                    test_support::table::minimal("public", "users"),
+                    test_support::table::minimal("public", "orders"),
+                ],
+            },
+        );
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::CopyToClipboard { on_success: Some(action), .. }]
+                if matches!(**action, Action::BulkDdlCopied(2))
+        ));
+    }
+
+    #[test]
+    fn failed_fetch_for_another_connection_is_ignored() {
+        let mut state = state_with_marks(&["public.users"]);
+
+        dispatch(
+            &mut state,
+            &Action::BulkDdlFailed {
+                dsn: "postgres://localhost/other".to_string(),
+                error: DbOperationError::QueryFailed("boom".to_string()),
+            },
+        );
+
+        assert!(state.messages.last_error().is_none());
+    }
+
+    #[test]
+    fn er_diagram_targets_marked_tables() {
+        let mut state = state_with_marks(&["public.orders", "public.users"]);
+
+        let effects = dispatch(
+            &mut state,
+            &Action::BulkTables(BulkTableOperation::ErDiagram),
+        );
+
+        assert_eq!(
+            state.er_preparation.target_tables(),
+            ["public.users".to_string(), "public.orders".to_string()]
+        );
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchActions(actions)] if matches!(actions.as_slice(), [Action::ErOpenDiagram])
+        ));
+    }
+
+    #[test]
+    fn analyze_runs_one_statement_per_table_as_a_single_job() {
+        let mut state = state_with_marks(&["public.orders", "public.users"]);
+
+        let effects = dispatch(&mut state, &Action::BulkTables(BulkTableOperation::Analyze));
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::RunMaintenance { queries, .. }] if queries.len() == 2
+        ));
+        assert_eq!(
+            state.maintenance.job().map(|job| job.target.as_str()),
+            Some("2 tables")
+        );
+    }
+
+    #[test]
+    fn analyze_is_refused_in_read_only_mode() {
+        let mut state = state_with_marks(&["public.users"]);
+        state.session.enable_read_only();
+
+        let effects = dispatch(&mut state, &Action::BulkTables(BulkTableOperation::Analyze));
+
+        assert!(effects.is_empty());
+        assert!(state.maintenance.job().is_none());
+    }
+
+    #[test]
+    fn sizes_run_a_read_only_catalog_query() {
+        let mut state = state_with_marks(&["public.users"]);
+
+        let effects = dispatch(&mut state, &Action::BulkTables(BulkTableOperation::Sizes));
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ExecuteAdhoc { query, access_mode: AccessMode::ReadOnly, .. }]
+                if query == "TABLE SIZES \"public\".\"users\""
+        ));
+    }
+}
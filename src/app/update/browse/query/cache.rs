@@ -0,0 +1,128 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::UsageStats;
+use crate::model::app_state::AppState;
+use crate::policy::cache_report::cache_report;
+use crate::policy::result_copy::format_byte_size;
+use crate::ports::outbound::CacheCategory;
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+use super::execution::reset_view_for_new_result;
+
+pub fn reduce_cache(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    _services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::ShowCacheInfo => DispatchResult::handled_with(vec![Effect::LoadCacheUsage {
+            project_name: state.runtime.project_name().to_string(),
+        }]),
+
+        Action::CacheUsageLoaded(usage) => {
+            reset_view_for_new_result(state, now);
+            state
+                .query
+                .set_current_result(Arc::new(cache_report(usage)));
+            DispatchResult::handled()
+        }
+
+        Action::ClearCache(category) => {
+            // The owning instance may be mid-write; it cleans up after itself.
+            if let Some(owner_pid) = state.runtime.cache_owner_pid() {
+                state.messages.set_error_at(
+                    format!("Cache is in use by sabiql PID {owner_pid} — clear it from there"),
+                    now,
+                );
+                return DispatchResult::handled();
+            }
+            DispatchResult::handled_with(vec![Effect::ClearCache {
+                project_name: state.runtime.project_name().to_string(),
+                category: *category,
+            }])
+        }
+
+        Action::CacheCleared { category, cleanup } => {
+            // Otherwise the in-memory counts are written straight back on exit.
+            if category.is_none_or(|category| category == CacheCategory::Usage) {
+                let enabled = state.usage.is_enabled();
+                state.usage.load(enabled, UsageStats::default());
+            }
+            let scope = category.map_or_else(
+                || "cache".to_string(),
+                |category| format!("{} cache", category.name()),
+            );
+            state.messages.set_success_at(
+                format!(
+                    "Cleared {scope}: {} files, {}",
+                    cleanup.files,
+                    format_byte_size(cleanup.bytes as usize)
+                ),
+                now,
+            );
+            DispatchResult::handled()
+        }
+
+        Action::CacheOperationFailed(error) => {
+            state.messages.set_error_at(format!("Cache: {error}"), now);
+            DispatchResult::handled()
+        }
+
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::outbound::CacheCleanup;
+    use crate::update::browse::query::dispatch_query;
+    use crate::update::browse::query::tests::create_test_state;
+
+    #[test]
+    fn clear_cache_is_refused_while_another_instance_owns_it() {
+        let mut state = create_test_state();
+        state.runtime.set_cache_owner_pid(Some(4242));
+
+        let result = dispatch_query(
+            &mut state,
+            &Action::ClearCache(None),
+            Instant::now(),
+            &AppServices::stub(),
+        );
+
+        assert!(result.into_effects().unwrap().is_empty());
+        assert!(state.messages.last_error().unwrap().contains("4242"));
+    }
+    #[test]
+    fn clearing_usage_cache_drops_in_memory_counts() {
+        let mut state = create_test_state();
+        state.usage.load(true, UsageStats::default());
+        state.usage.record_command(":sql");
+
+        dispatch_query(
+            &mut state,
+            &Action::CacheCleared {
+                category: Some(CacheCategory::Usage),
+                cleanup: CacheCleanup {
+                    files: 1,
+                    bytes: 2048,
+                },
+            },
+            Instant::now(),
+            &AppServices::stub(),
+        );
+
+        assert!(state.usage.stats().is_empty());
+        assert!(!state.usage.is_dirty());
+        assert_eq!(
+            state.messages.last_success(),
+            Some("Cleared usage cache: 1 files, 2.0 KB")
+        );
+    }
+}
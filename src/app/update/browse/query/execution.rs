@@ -8,12 +8,18 @@ use crate::model::browse::query_execution::{PREVIEW_PAGE_SIZE, PostDeleteRowSele
 use crate::model::shared::help::HelpOrigin;
 use crate::model::shared::input_mode::InputMode;
 use crate::model::sql_editor::modal::AdhocSuccessSnapshot;
-use crate::ports::outbound::AccessMode;
+use crate::policy::column_masking::{MASKED_VALUE, masked_column_indices};
+use crate::policy::sql::replica_routing::QueryRouting;
+use crate::policy::sql::statement_timing::timing_summary;
+use crate::ports::outbound::{AccessMode, DbOperationError};
 use crate::services::AppServices;
 use crate::update::action::{Action, ModalKind, TableTarget};
 use crate::update::browse::query::preview_effect_for_current_table;
+use crate::update::browse::result::column_types_effect;
 use crate::update::dispatch_result::DispatchResult;
-use crate::update::input::command::{command_to_action, parse_command};
+use crate::update::input::command::{
+    Command, command_to_action, parse_command, sql_modal_command_to_action,
+};
 
 fn try_adhoc_refresh(state: &mut AppState, result: &QueryResult, now: Instant) -> Vec<Effect> {
     if result.source != QuerySource::Adhoc || result.is_error() {
@@ -50,18 +56,58 @@ fn try_adhoc_refresh(state: &mut AppState, result: &QueryResult, now: Instant) -
     effects
 }
 
-fn reset_view_for_new_result(state: &mut AppState, now: Instant) {
+/// The result as shown, with rule-matched columns masked, and the names of
+/// those columns. Previews also honour their table's explicit list.
+fn masked_for_display(
+    state: &AppState,
+    result: &Arc<QueryResult>,
+) -> (Arc<QueryResult>, Vec<String>) {
+    let rules = state.runtime.masking_rules();
+    if rules.is_empty() || result.is_error() {
+        return (Arc::clone(result), Vec::new());
+    }
+    let table =
+        (result.source == QuerySource::Preview).then(|| state.query.pagination.qualified_name());
+    let indices = masked_column_indices(rules, table.as_deref(), &result.columns);
+    if indices.is_empty() {
+        return (Arc::clone(result), Vec::new());
+    }
+    let columns = indices.iter().map(|&i| result.columns[i].clone()).collect();
+    let masked = QueryResult::clone(result).with_masked_columns(&indices, MASKED_VALUE);
+    (Arc::new(masked), columns)
+}
+
+pub(super) fn reset_view_for_new_result(state: &mut AppState, now: Instant) {
     state.result_interaction.reset_view();
     state
         .query
         .set_result_highlight(now + Duration::from_millis(500));
 }
 
+/// Asks who held the lock while the blocker is most likely still around.
+fn lock_blockers_effect(
+    state: &mut AppState,
+    dsn: &str,
+    error: &DbOperationError,
+    services: &AppServices,
+) -> Option<Effect> {
+    let conflict = error.lock_conflict()?;
+    let query = services
+        .sql_dialect
+        .build_lock_blockers_sql(state.session.active_database_type_or_default(), &conflict)?;
+    let run_id = state.sql_modal.begin_lock_diagnostics(conflict.kind);
+    Some(Effect::FetchLockBlockers {
+        dsn: dsn.to_string(),
+        run_id,
+        query,
+    })
+}
+
 pub fn reduce_execution(
     state: &mut AppState,
     action: &Action,
     now: Instant,
-    _services: &AppServices,
+    services: &AppServices,
 ) -> DispatchResult {
     match action {
         Action::QueryCompleted {
@@ -98,8 +144,17 @@ pub fn reduce_execution(
                         row_count: result.row_count(),
                         execution_time_ms: result.execution_time_ms,
                     });
-                    state.query.push_history(Arc::clone(result));
-                    state.query.set_current_result(Arc::clone(result));
+                    let (masked, masked_columns) = masked_for_display(state, result);
+                    state.query.push_history(Arc::clone(&masked));
+                    // Scripts with no final result set show the per-statement
+                    // summary; otherwise it stays one :timings away.
+                    let shown = timing_summary(result)
+                        .filter(|_| result.columns.is_empty())
+                        .map_or(masked, Arc::new);
+                    state.query.set_rerun_result(shown);
+                    state
+                        .query
+                        .hold_unmasked(Arc::clone(result), masked_columns);
                 }
                 // Preview errors arrive as error results and are shown in the
                 // Result pane like any other preview.
@@ -114,7 +169,13 @@ pub fn reduce_execution(
                             .pagination
                             .set_page_result(*page, result.data_row_count() < PREVIEW_PAGE_SIZE);
                     }
-                    state.query.set_current_result(Arc::clone(result));
+                    let (masked, masked_columns) = masked_for_display(state, result);
+                    state.query.set_rerun_result(masked);
+                    state
+                        .query
+                        .hold_unmasked(Arc::clone(result), masked_columns);
+                    // Deleted rows shift the indexes the marks point at
+                    state.result_interaction.clear_row_marks();
 
                     match state.query.post_delete_row_selection() {
                         PostDeleteRowSelection::Keep => {}
@@ -148,7 +209,11 @@ pub fn reduce_execution(
                 }
             }
 
-            DispatchResult::handled_with(try_adhoc_refresh(state, result, now))
+            let mut effects = try_adhoc_refresh(state, result, now);
+            if result.source == QuerySource::Adhoc {
+                effects.extend(column_types_effect(state, services));
+            }
+            DispatchResult::handled_with(effects)
         }
         Action::QueryFailed {
             dsn,
@@ -161,6 +226,7 @@ pub fn reduce_execution(
                 return DispatchResult::handled();
             }
 
+            let mut effects = vec![];
             if *generation == 0 || *generation == state.session.selection_generation() {
                 state.query.mark_idle();
                 if *source == QuerySource::Preview {
@@ -180,16 +246,52 @@ pub fn reduce_execution(
                     let user_message = error.user_message();
                     state.messages.set_error_at(user_message.clone(), now);
                     state.sql_modal.finish_adhoc_error(user_message);
+                    effects.extend(lock_blockers_effect(state, dsn, error, services));
                 }
             }
+            DispatchResult::handled_with(effects)
+        }
+
+        Action::QueryReconnectedAndRetried { dsn, run_id } => {
+            if !state.is_stale_query_run(dsn, *run_id) {
+                state.messages.set_success_at(
+                    "Connection was lost: reconnected and retried".to_string(),
+                    now,
+                );
+            }
+            DispatchResult::handled()
+        }
+
+        Action::LockBlockersLoaded {
+            dsn,
+            run_id,
+            blockers,
+        } => {
+            if !state.session.dsn_matches(dsn)
+                || !state.sql_modal.set_lock_blockers(*run_id, blockers.clone())
+            {
+                return DispatchResult::handled();
+            }
+            if let Some(summary) = state.sql_modal.lock_blocker_summary() {
+                state.messages.set_error_at(summary, now);
+            }
             DispatchResult::handled()
         }
 
         Action::CommandLineSubmit => {
-            let cmd = parse_command(state.command_line_input.content());
-            let follow_up = command_to_action(cmd);
+            let input = state.command_line_input.content().trim().to_string();
+            let cmd = parse_command(&input);
+            let unknown = matches!(cmd, Command::Unknown(_));
+            if !unknown && let Some(name) = input.split_whitespace().next() {
+                state.usage.record_command(&format!(":{name}"));
+            }
             state.modal.pop_mode();
             state.command_line_input.clear();
+            let follow_up = if state.input_mode() == InputMode::SqlModal {
+                sql_modal_command_to_action(cmd)
+            } else {
+                command_to_action(cmd)
+            };
 
             DispatchResult::handled_with(match follow_up {
                 Action::Quit => {
@@ -201,31 +303,20 @@ pub fn reduce_execution(
                     state.modal.push_mode(InputMode::Help);
                     vec![]
                 }
-                Action::OpenModal(ModalKind::SqlModal) => {
-                    vec![Effect::DispatchActions(vec![Action::OpenModal(
-                        ModalKind::SqlModal,
-                    )])]
-                }
-                Action::OpenModal(ModalKind::ErTablePicker) => {
-                    // Defer to modal reducer so metadata readiness checks stay in one place.
-                    vec![Effect::DispatchActions(vec![Action::OpenModal(
-                        ModalKind::ErTablePicker,
-                    )])]
-                }
-                Action::OpenModal(ModalKind::Settings) => {
-                    vec![Effect::DispatchActions(vec![Action::OpenModal(
-                        ModalKind::Settings,
-                    )])]
-                }
-                Action::OpenModal(ModalKind::CommandPalette) => {
-                    vec![Effect::DispatchActions(vec![Action::OpenModal(
-                        ModalKind::CommandPalette,
-                    )])]
-                }
-                Action::SubmitCellEditWrite => {
-                    vec![Effect::DispatchActions(vec![Action::SubmitCellEditWrite])]
+                Action::None if input.is_empty() => vec![],
+                Action::None => {
+                    let message = if unknown {
+                        format!("Unknown command :{input}")
+                    } else {
+                        format!(":{input} is not available here")
+                    };
+                    state.messages.set_error_at(message, now);
+                    vec![]
                 }
-                _ => vec![],
+                // Re-dispatched so the reducer owning the action (and its
+                // readiness checks) handles it; no per-command whitelist to
+                // forget a new command in.
+                action => vec![Effect::DispatchActions(vec![action])],
             })
         }
 
@@ -267,11 +358,20 @@ pub fn reduce_execution(
 
         Action::ExecuteAdhoc(query) => {
             if let Some(dsn) = state.session.dsn().map(String::from) {
+                let query = match state.apply_execution_policy(query) {
+                    Ok(query) => query,
+                    Err(violation) => {
+                        state.messages.set_error_at(violation.to_string(), now);
+                        return DispatchResult::handled();
+                    }
+                };
+                let replica_dsn = state.route_adhoc_query(&query);
                 let run_id = state.query.begin_running(now);
                 DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
                     dsn,
+                    replica_dsn,
                     run_id,
-                    query: query.clone(),
+                    query,
                     access_mode: AccessMode::from_read_only(state.session.is_read_only()),
                 }])
             } else {
@@ -279,6 +379,33 @@ pub fn reduce_execution(
             }
         }
 
+        Action::SetQueryRouting(routing) => {
+            if *routing != QueryRouting::Auto && state.active_replica_dsn().is_none() {
+                state
+                    .messages
+                    .set_error_at("No replica configured for this connection".to_string(), now);
+            } else {
+                state.session.set_query_routing(*routing);
+                state
+                    .messages
+                    .set_success_at(format!("Query routing: {}", routing.label()), now);
+            }
+            DispatchResult::handled()
+        }
+
+        Action::ShowStatementTimings => {
+            let Some(summary) = state.query.visible_result().and_then(timing_summary) else {
+                state.messages.set_error_at(
+                    "No per-statement timings — run a multi-statement script first".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            };
+            reset_view_for_new_result(state, now);
+            state.query.set_current_result(Arc::new(summary));
+            DispatchResult::handled()
+        }
+
         _ => DispatchResult::pass(),
     }
 }
@@ -286,7 +413,8 @@ pub fn reduce_execution(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ports::outbound::DbOperationError;
+    use crate::domain::{UsageKind, UsageStats};
+    use crate::policy::result_copy::ResultCopyFormat;
     use crate::update::browse::query::dispatch_query;
     use crate::update::browse::query::tests::*;
 
@@ -327,7 +455,28 @@ mod tests {
         }
 
         #[test]
-        fn submit_unknown_pops_mode_without_side_effects() {
+        fn submit_counts_known_commands_by_name() {
+            let mut state = create_test_state();
+            state.usage.load(true, UsageStats::default());
+            for input in ["copy csv", "copy", "bogus"] {
+                state.modal.push_mode(InputMode::CommandLine);
+                state.command_line_input.set_content(input.to_string());
+
+                dispatch_query(
+                    &mut state,
+                    &Action::CommandLineSubmit,
+                    Instant::now(),
+                    &AppServices::stub(),
+                );
+            }
+
+            let stats = state.usage.stats();
+            assert_eq!(stats.count(UsageKind::Command, ":copy"), 2);
+            assert_eq!(stats.count(UsageKind::Command, ":bogus"), 0);
+        }
+
+        #[test]
+        fn submit_unknown_reports_error_and_pops_mode() {
             let mut state = create_test_state();
             state.modal.set_mode(InputMode::CellEdit);
             state.modal.push_mode(InputMode::CommandLine);
@@ -335,17 +484,62 @@ mod tests {
                 .command_line_input
                 .set_content("unknown_cmd".to_string());
 
-            dispatch_query(
+            let effects = dispatch_query(
                 &mut state,
                 &Action::CommandLineSubmit,
                 Instant::now(),
                 &AppServices::stub(),
-            );
+            )
+            .unwrap();
 
+            assert!(effects.is_empty());
             assert_eq!(state.input_mode(), InputMode::CellEdit);
+            assert_eq!(
+                state.messages.last_error(),
+                Some("Unknown command :unknown_cmd")
+            );
             assert!(!state.should_quit);
         }
 
+        #[test]
+        fn submit_empty_input_just_closes() {
+            let mut state = create_test_state();
+            state.modal.push_mode(InputMode::CommandLine);
+
+            let effects = dispatch_query(
+                &mut state,
+                &Action::CommandLineSubmit,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            assert!(effects.is_empty());
+            assert_eq!(state.input_mode(), InputMode::Normal);
+            assert!(state.messages.last_error().is_none());
+        }
+
+        #[test]
+        fn submit_history_dispatches_open_history_picker() {
+            let mut state = create_test_state();
+            state.modal.push_mode(InputMode::CommandLine);
+            state.command_line_input.set_content("history".to_string());
+
+            let effects = dispatch_query(
+                &mut state,
+                &Action::CommandLineSubmit,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::DispatchActions(actions)]
+                    if matches!(actions[..], [Action::OpenModal(ModalKind::QueryHistoryPicker)])
+            ));
+        }
+
         #[test]
         fn submit_erd_dispatches_open_er_table_picker() {
             let mut state = create_test_state();
@@ -374,6 +568,32 @@ mod tests {
             }
         }
 
+        #[test]
+        fn submit_copy_dispatches_result_copy_all() {
+            let mut state = create_test_state();
+            state.modal.push_mode(InputMode::CommandLine);
+            state.command_line_input.set_content("copy md".to_string());
+
+            let effects = dispatch_query(
+                &mut state,
+                &Action::CommandLineSubmit,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            assert_eq!(state.input_mode(), InputMode::Normal);
+            match &effects[0] {
+                Effect::DispatchActions(actions) => {
+                    assert!(matches!(
+                        actions[0],
+                        Action::ResultCopyAll(ResultCopyFormat::Markdown)
+                    ));
+                }
+                other => panic!("expected DispatchActions, got {other:?}"),
+            }
+        }
+
         #[test]
         fn submit_settings_dispatches_open_settings() {
             let mut state = create_test_state();
@@ -428,6 +648,339 @@ mod tests {
         }
     }
 
+    mod replica_routing {
+        use super::*;
+        use crate::domain::connection::{ConnectionProfile, DatabaseType, SslMode};
+        use crate::policy::sql::replica_routing::QueryEndpoint;
+
+        const PRIMARY: &str = "postgres://primary/app";
+        const REPLICA: &str = "postgres://replica/app";
+
+        fn state_with_replica() -> AppState {
+            let mut state = AppState::new("test_project".to_string());
+            let profile = ConnectionProfile::new_postgres(
+                "app",
+                "primary",
+                5432,
+                "app",
+                "user",
+                "",
+                SslMode::Prefer,
+            )
+            .unwrap()
+            .with_replica_dsn(Some(REPLICA.to_string()));
+            state.session.activate_connection_with_dsn(
+                &profile.id,
+                "app",
+                DatabaseType::PostgreSQL,
+                PRIMARY,
+            );
+            state.set_connections(vec![profile]);
+            state
+        }
+
+        fn execute(state: &mut AppState, query: &str) -> Effect {
+            dispatch_query(
+                state,
+                &Action::ExecuteAdhoc(query.to_string()),
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .into_effects()
+            .and_then(|effects| effects.into_iter().next())
+            .unwrap()
+        }
+
+        #[test]
+        fn select_runs_on_replica_and_keeps_primary_identity() {
+            let mut state = state_with_replica();
+
+            let effect = execute(&mut state, "SELECT * FROM users");
+
+            assert!(matches!(
+                effect,
+                Effect::ExecuteAdhoc { ref dsn, replica_dsn: Some(ref replica), .. }
+                    if dsn == PRIMARY && replica == REPLICA
+            ));
+            assert_eq!(
+                state.session.last_query_endpoint(),
+                Some(QueryEndpoint::Replica)
+            );
+        }
+
+        #[test]
+        fn write_runs_on_primary() {
+            let mut state = state_with_replica();
+
+            let effect = execute(&mut state, "DELETE FROM users WHERE id = 1");
+
+            assert!(matches!(
+                effect,
+                Effect::ExecuteAdhoc {
+                    replica_dsn: None,
+                    ..
+                }
+            ));
+            assert_eq!(
+                state.session.last_query_endpoint(),
+                Some(QueryEndpoint::Primary)
+            );
+        }
+
+        #[test]
+        fn open_transaction_keeps_selects_on_primary() {
+            let mut state = state_with_replica();
+            state.session.begin_transaction(PRIMARY);
+
+            let effect = execute(&mut state, "SELECT * FROM users");
+
+            assert!(matches!(
+                effect,
+                Effect::ExecuteAdhoc {
+                    replica_dsn: None,
+                    ..
+                }
+            ));
+        }
+
+        #[test]
+        fn override_pins_selects_to_primary() {
+            let mut state = state_with_replica();
+            dispatch_query(
+                &mut state,
+                &Action::SetQueryRouting(QueryRouting::Primary),
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            let effect = execute(&mut state, "SELECT 1");
+
+            assert!(matches!(
+                effect,
+                Effect::ExecuteAdhoc {
+                    replica_dsn: None,
+                    ..
+                }
+            ));
+            assert_eq!(
+                state.messages.last_success(),
+                Some("Query routing: primary")
+            );
+        }
+
+        #[test]
+        fn without_replica_hides_endpoint_and_rejects_override() {
+            let mut state = create_test_state();
+
+            dispatch_query(
+                &mut state,
+                &Action::SetQueryRouting(QueryRouting::Replica),
+                Instant::now(),
+                &AppServices::stub(),
+            );
+            let effect = execute(&mut state, "SELECT 1");
+
+            assert_eq!(state.session.query_routing(), QueryRouting::Auto);
+            assert_eq!(
+                state.messages.last_error(),
+                Some("No replica configured for this connection")
+            );
+            assert!(matches!(
+                effect,
+                Effect::ExecuteAdhoc {
+                    replica_dsn: None,
+                    ..
+                }
+            ));
+            assert_eq!(state.session.last_query_endpoint(), None);
+        }
+    }
+
+    mod column_masking {
+        use std::collections::HashMap;
+
+        use super::*;
+        use crate::ports::outbound::MaskingRules;
+
+        fn masking_state() -> AppState {
+            let mut state = state_with_table("public", "users");
+            state.runtime.set_masking_rules(MaskingRules {
+                column_patterns: vec!["*token*".to_string()],
+                table_columns: HashMap::from([(
+                    "public.users".to_string(),
+                    vec!["name".to_string()],
+                )]),
+            });
+            state
+        }
+
+        fn complete(state: &mut AppState, source: QuerySource) {
+            let result = Arc::new(QueryResult::success(
+                "SELECT * FROM users".to_string(),
+                vec![
+                    "id".to_string(),
+                    "name".to_string(),
+                    "api_token".to_string(),
+                ],
+                vec![vec![
+                    "1".to_string(),
+                    "Alice".to_string(),
+                    "t0k".to_string(),
+                ]],
+                1,
+                source,
+            ));
+            let action = query_completed_action(state, result, 0, None);
+            dispatch_query(state, &action, Instant::now(), &AppServices::stub());
+        }
+
+        fn shown_row(state: &AppState) -> Vec<String> {
+            state
+                .query
+                .visible_result()
+                .and_then(|result| result.display_row_at(0))
+                .unwrap()
+        }
+
+        #[test]
+        fn preview_masks_patterns_and_its_table_list() {
+            let mut state = masking_state();
+
+            complete(&mut state, QuerySource::Preview);
+
+            assert_eq!(shown_row(&state), vec!["1", MASKED_VALUE, MASKED_VALUE]);
+            assert_eq!(state.query.masked_columns(), ["name", "api_token"]);
+        }
+
+        #[test]
+        fn adhoc_masks_patterns_only_and_keeps_history_masked() {
+            let mut state = masking_state();
+
+            complete(&mut state, QuerySource::Adhoc);
+
+            assert_eq!(shown_row(&state), vec!["1", "Alice", MASKED_VALUE]);
+            let history = state.query.result_history();
+            let latest = history.get(history.len() - 1).unwrap();
+            assert_eq!(latest.display_value_at(0, 2).as_deref(), Some(MASKED_VALUE));
+        }
+    }
+
+    mod column_types {
+        use super::*;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(true, true)]
+        #[case(false, false)]
+        fn adhoc_success_describes_types_only_when_row_is_shown(
+            #[case] shown: bool,
+            #[case] expect_fetch: bool,
+        ) {
+            let mut state = create_test_state();
+            if shown {
+                state.ui.toggle_result_types();
+            }
+            let action = query_completed_action(&mut state, adhoc_result(), 0, None);
+
+            let effects = dispatch_query(&mut state, &action, Instant::now(), &AppServices::stub())
+                .into_effects()
+                .unwrap();
+
+            let fetches_types = effects.iter().any(|e| {
+                matches!(
+                    e,
+                    Effect::FetchResultColumnTypes { query, .. } if query == "RESULT TYPES SELECT 1"
+                )
+            });
+            assert_eq!(fetches_types, expect_fetch);
+        }
+    }
+
+    mod statement_timings {
+        use super::*;
+        use crate::domain::StatementTiming;
+
+        fn script_result(columns: Vec<String>) -> Arc<QueryResult> {
+            let timing = |sql: &str, status: &str| StatementTiming {
+                sql: sql.to_string(),
+                elapsed: Duration::from_millis(2),
+                rows: Some(1),
+                status: status.to_string(),
+            };
+            Arc::new(
+                QueryResult::success(
+                    "script".to_string(),
+                    columns,
+                    Vec::new(),
+                    4,
+                    QuerySource::Adhoc,
+                )
+                .with_statement_timings(vec![
+                    timing("INSERT INTO t VALUES (1)", "INSERT 0 1"),
+                    timing("UPDATE t SET a = 1", "UPDATE 1"),
+                ]),
+            )
+        }
+
+        #[test]
+        fn script_without_result_set_shows_summary() {
+            let mut state = create_test_state();
+            let action = query_completed_action(&mut state, script_result(vec![]), 0, None);
+
+            dispatch_query(&mut state, &action, Instant::now(), &AppServices::stub());
+
+            let shown = state.query.visible_result().unwrap();
+            assert_eq!(shown.columns[1], "Statement");
+            assert_eq!(shown.row_count(), 2);
+            assert!(
+                state
+                    .query
+                    .result_history()
+                    .get(0)
+                    .unwrap()
+                    .columns
+                    .is_empty()
+            );
+        }
+
+        #[test]
+        fn script_ending_in_select_keeps_result_until_requested() {
+            let mut state = create_test_state();
+            let result = script_result(vec!["id".to_string()]);
+            let action = query_completed_action(&mut state, result, 0, None);
+            dispatch_query(&mut state, &action, Instant::now(), &AppServices::stub());
+            assert_eq!(state.query.visible_result().unwrap().columns, vec!["id"]);
+
+            dispatch_query(
+                &mut state,
+                &Action::ShowStatementTimings,
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            assert_eq!(
+                state.query.visible_result().unwrap().columns[1],
+                "Statement"
+            );
+        }
+
+        #[test]
+        fn single_statement_reports_error() {
+            let mut state = create_test_state();
+            state.query.set_current_result(adhoc_result());
+
+            dispatch_query(
+                &mut state,
+                &Action::ShowStatementTimings,
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            assert_eq!(state.query.visible_result().unwrap().columns, vec!["id"]);
+            assert!(state.messages.last_error().is_some());
+        }
+    }
+
     mod execute_preview {
         use super::*;
 
@@ -529,6 +1082,18 @@ mod tests {
             assert!(state.result_interaction.staged_delete_rows().is_empty());
         }
 
+        #[test]
+        fn adhoc_rerun_diffs_against_previous_result() {
+            let mut state = create_test_state();
+            for _ in 0..2 {
+                let action = query_completed_action(&mut state, adhoc_result(), 0, None);
+                dispatch_query(&mut state, &action, Instant::now(), &AppServices::stub());
+            }
+
+            let diff = state.query.result_diff().expect("re-run should be diffed");
+            assert!(diff.is_empty());
+        }
+
         #[test]
         fn adhoc_error_preserves_current_result_and_view_state() {
             let mut state = create_test_state();
@@ -650,6 +1215,54 @@ mod tests {
             assert!(state.query.current_result().is_none());
             assert!(!state.query.is_running());
         }
+
+        #[test]
+        fn retried_run_keeps_its_reconnect_note_after_completing() {
+            let mut state = create_test_state();
+            let run_id = begin_query_run(&mut state);
+            let dsn = "postgres://localhost/test".to_string();
+
+            for action in [
+                Action::QueryReconnectedAndRetried {
+                    dsn: dsn.clone(),
+                    run_id,
+                },
+                Action::QueryCompleted {
+                    dsn,
+                    run_id,
+                    result: adhoc_result(),
+                    generation: 0,
+                    target_page: None,
+                },
+            ] {
+                dispatch_query(&mut state, &action, Instant::now(), &AppServices::stub());
+            }
+
+            assert_eq!(
+                state.messages.last_success(),
+                Some("Connection was lost: reconnected and retried")
+            );
+            assert!(state.query.current_result().is_some());
+        }
+
+        #[test]
+        fn stale_reconnect_note_is_dropped() {
+            let mut state = create_test_state();
+            let old_run_id = begin_query_run(&mut state);
+            let _ = begin_query_run(&mut state);
+
+            dispatch_query(
+                &mut state,
+                &Action::QueryReconnectedAndRetried {
+                    dsn: "postgres://localhost/test".to_string(),
+                    run_id: old_run_id,
+                },
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            assert!(state.messages.last_success().is_none());
+        }
     }
 
     mod query_failed {
@@ -733,6 +1346,116 @@ mod tests {
         }
     }
 
+    mod lock_diagnostics {
+        use super::*;
+        use crate::domain::LockBlocker;
+
+        const DSN: &str = "postgres://localhost/test";
+
+        fn fail_adhoc(state: &mut AppState, error: DbOperationError) -> Vec<Effect> {
+            let action = query_failed_action(state, error, 0, QuerySource::Adhoc);
+            dispatch_query(state, &action, Instant::now(), &AppServices::stub())
+                .into_effects()
+                .unwrap()
+        }
+
+        fn blocker() -> LockBlocker {
+            LockBlocker {
+                pid: 4123,
+                application_name: "worker".to_string(),
+                user: "app".to_string(),
+                state: "idle in transaction".to_string(),
+                held_secs: Some(90),
+                query: "UPDATE accounts SET balance = 0".to_string(),
+            }
+        }
+
+        #[test]
+        fn deadlock_fetches_named_blockers() {
+            let mut state = create_test_state();
+
+            let effects = fail_adhoc(
+                &mut state,
+                DbOperationError::Deadlock(
+                    "ERROR:  40P01: deadlock detected\nDETAIL:  blocked by process 4123."
+                        .to_string(),
+                ),
+            );
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::FetchLockBlockers { query, .. }] if query == "LOCK BLOCKERS 4123"
+            ));
+        }
+
+        #[test]
+        fn other_failures_do_not_look_up_locks() {
+            let mut state = create_test_state();
+
+            let effects = fail_adhoc(
+                &mut state,
+                DbOperationError::QueryFailed("syntax error".to_string()),
+            );
+
+            assert!(effects.is_empty());
+        }
+
+        #[test]
+        fn loaded_blockers_explain_the_failure() {
+            let mut state = create_test_state();
+            let effects = fail_adhoc(
+                &mut state,
+                DbOperationError::LockTimeout(
+                    "canceling statement due to lock timeout".to_string(),
+                ),
+            );
+            let [Effect::FetchLockBlockers { run_id, .. }] = effects.as_slice() else {
+                panic!("expected FetchLockBlockers, got {effects:?}");
+            };
+
+            dispatch_query(
+                &mut state,
+                &Action::LockBlockersLoaded {
+                    dsn: DSN.to_string(),
+                    run_id: *run_id,
+                    blockers: vec![blocker()],
+                },
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            let summary = state.sql_modal.lock_blocker_summary().unwrap();
+            assert!(summary.starts_with("Lock timeout — blocked by pid 4123 · worker"));
+            assert_eq!(state.messages.last_error(), Some(summary.as_str()));
+        }
+
+        #[test]
+        fn blockers_for_superseded_error_are_ignored() {
+            let mut state = create_test_state();
+            let effects = fail_adhoc(
+                &mut state,
+                DbOperationError::LockTimeout("lock timeout".to_string()),
+            );
+            let [Effect::FetchLockBlockers { run_id, .. }] = effects.as_slice() else {
+                panic!("expected FetchLockBlockers, got {effects:?}");
+            };
+            state.sql_modal.begin_adhoc_running();
+
+            dispatch_query(
+                &mut state,
+                &Action::LockBlockersLoaded {
+                    dsn: DSN.to_string(),
+                    run_id: *run_id,
+                    blockers: vec![blocker()],
+                },
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            assert!(state.sql_modal.lock_blockers().is_empty());
+        }
+    }
+
     mod adhoc_refresh {
         use super::*;
         use crate::domain::CommandTag;
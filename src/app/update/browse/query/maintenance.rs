@@ -0,0 +1,783 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::{Index, IndexMaintenanceKind};
+use crate::i18n::Message;
+use crate::model::app_state::AppState;
+use crate::model::shared::confirm_dialog::ConfirmIntent;
+use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::inspector_tab::InspectorTab;
+use crate::policy::duration_format::humanize_secs;
+use crate::policy::index_usage_report::index_usage_report;
+use crate::policy::sql::replica_routing::QueryEndpoint;
+use crate::ports::outbound::AccessMode;
+use crate::ports::outbound::clipboard::ClipboardError;
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+use super::execution::reset_view_for_new_result;
+
+// Below this estimate a blocking REINDEX finishes quickly enough to skip the
+// confirmation; unknown estimates (never analyzed) still ask.
+const BLOCKING_REINDEX_CONFIRM_ROWS: i64 = 100_000;
+
+pub fn reduce_maintenance(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::StartMaintenance(kind) => {
+            let Some(dsn) = startable_dsn(state, now) else {
+                return DispatchResult::handled();
+            };
+            let Some((schema, table)) = state
+                .session
+                .table_detail()
+                .map(|t| (t.schema.clone(), t.name.clone()))
+            else {
+                state
+                    .messages
+                    .set_error_at("Select a table first".to_string(), now);
+                return DispatchResult::handled();
+            };
+
+            let database_type = state.session.active_database_type_or_default();
+            let Some(query) =
+                services
+                    .sql_dialect
+                    .build_maintenance_sql(database_type, *kind, &schema, &table)
+            else {
+                return DispatchResult::handled();
+            };
+            let progress_query = services.sql_dialect.build_maintenance_progress_sql(
+                database_type,
+                *kind,
+                &schema,
+                &table,
+            );
+
+            let run_id = state.maintenance.begin(
+                dsn.clone(),
+                kind.label(),
+                format!("{schema}.{table}"),
+                progress_query.clone(),
+                now,
+            );
+            DispatchResult::handled_with(start_effects(dsn, run_id, vec![query], progress_query))
+        }
+
+        Action::StartIndexMaintenance {
+            kind,
+            index,
+            confirmed,
+        } => {
+            let Some(dsn) = startable_dsn(state, now) else {
+                return DispatchResult::handled();
+            };
+            let (schema, table, rows, index) = match target_index(state, index.as_deref()) {
+                Ok(target) => target,
+                Err(message) => {
+                    state.messages.set_error_at(message, now);
+                    return DispatchResult::handled();
+                }
+            };
+
+            if !kind.is_concurrent()
+                && !confirmed
+                && rows.is_none_or(|rows| !(0..BLOCKING_REINDEX_CONFIRM_ROWS).contains(&rows))
+            {
+                let size = rows.filter(|rows| *rows >= 0).map_or_else(
+                    || "row count unknown".to_string(),
+                    |rows| format!("~{rows} rows"),
+                );
+                state.confirm_dialog.open(
+                    Message::ConfirmReindexTitle.text(state.settings.locale()),
+                    format!(
+                        "REINDEX {} blocks writes to {schema}.{table} ({size}) until it finishes.\n\
+                         Use :reindex for a concurrent rebuild. Run it anyway?",
+                        index.name
+                    ),
+                    ConfirmIntent::BlockingReindex { index: index.name },
+                );
+                state.modal.push_mode(InputMode::ConfirmDialog);
+                return DispatchResult::handled();
+            }
+
+            let Some(queries) = index_queries(state, services, *kind, &schema, &index, now) else {
+                return DispatchResult::handled();
+            };
+            let database_type = state.session.active_database_type_or_default();
+            let progress_query = services.sql_dialect.build_index_maintenance_progress_sql(
+                database_type,
+                &schema,
+                &table,
+            );
+
+            let run_id = state.maintenance.begin(
+                dsn.clone(),
+                kind.label(),
+                format!("{schema}.{}", index.name),
+                progress_query.clone(),
+                now,
+            );
+            DispatchResult::handled_with(start_effects(dsn, run_id, queries, progress_query))
+        }
+
+        Action::CopyIndexMaintenanceSql { kind, index } => {
+            let (schema, _, _, index) = match target_index(state, index.as_deref()) {
+                Ok(target) => target,
+                Err(message) => {
+                    state.messages.set_error_at(message, now);
+                    return DispatchResult::handled();
+                }
+            };
+            let Some(queries) = index_queries(state, services, *kind, &schema, &index, now) else {
+                return DispatchResult::handled();
+            };
+            DispatchResult::handled_with(vec![Effect::CopyToClipboard {
+                content: format!("{};\n", queries.join(";\n")),
+                on_success: Some(Box::new(Action::IndexMaintenanceSqlCopied {
+                    kind: *kind,
+                    index: index.name,
+                })),
+                on_failure: Some(Box::new(Action::CopyFailed(ClipboardError::Unavailable(
+                    "Clipboard unavailable".into(),
+                )))),
+            }])
+        }
+
+        Action::IndexMaintenanceSqlCopied { kind, index } => {
+            state
+                .messages
+                .set_success_at(format!("Copied {} SQL for {index}", kind.label()), now);
+            DispatchResult::handled()
+        }
+
+        Action::ShowAutovacuumReport => {
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let Some((schema, table)) = state
+                .session
+                .table_detail()
+                .map(|t| (t.schema.clone(), t.name.clone()))
+            else {
+                state
+                    .messages
+                    .set_error_at("Select a table first".to_string(), now);
+                return DispatchResult::handled();
+            };
+            let Some(query) = services.sql_dialect.build_autovacuum_report_sql(
+                state.session.active_database_type_or_default(),
+                &schema,
+                &table,
+            ) else {
+                return DispatchResult::handled();
+            };
+            // Vacuum statistics are per server; the primary's are the ones to tune.
+            let endpoint = state.active_replica_dsn().map(|_| QueryEndpoint::Primary);
+            state.session.record_query_endpoint(endpoint);
+            let run_id = state.query.begin_running(now);
+            DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
+                dsn,
+                replica_dsn: None,
+                run_id,
+                query,
+                access_mode: AccessMode::ReadOnly,
+            }])
+        }
+
+        Action::ShowIndexUsage(sort) => {
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let Some(query) = services
+                .sql_dialect
+                .build_index_usage_sql(state.session.active_database_type_or_default())
+            else {
+                state.messages.set_error_at(
+                    "Index usage is only available for PostgreSQL".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            };
+            // Scan counters are per server; a replica's say nothing about
+            // what the primary's planner uses.
+            let endpoint = state.active_replica_dsn().map(|_| QueryEndpoint::Primary);
+            state.session.record_query_endpoint(endpoint);
+            let run_id = state.query.begin_running(now);
+            DispatchResult::handled_with(vec![Effect::FetchIndexUsage {
+                dsn,
+                run_id,
+                query,
+                sort: *sort,
+            }])
+        }
+
+        Action::IndexUsageLoaded {
+            dsn,
+            run_id,
+            sort,
+            indexes,
+        } => {
+            if state.is_stale_query_run(dsn, *run_id) {
+                return DispatchResult::handled();
+            }
+            state.query.mark_idle();
+            if indexes.is_empty() {
+                state
+                    .messages
+                    .set_error_at("No user indexes found".to_string(), now);
+                return DispatchResult::handled();
+            }
+            reset_view_for_new_result(state, now);
+            state
+                .query
+                .set_current_result(Arc::new(index_usage_report(indexes, *sort)));
+            DispatchResult::handled()
+        }
+
+        Action::IndexUsageFailed { dsn, run_id, error } => {
+            if state.is_stale_query_run(dsn, *run_id) {
+                return DispatchResult::handled();
+            }
+            state.query.mark_idle();
+            state
+                .messages
+                .set_error_at(format!("Index usage failed: {}", error.user_message()), now);
+            DispatchResult::handled()
+        }
+
+        Action::MaintenanceProgressLoaded { run_id, progress } => {
+            state.maintenance.set_progress(*run_id, progress.clone());
+            let next_poll = state.maintenance.current(*run_id).and_then(|job| {
+                job.progress_query
+                    .clone()
+                    .map(|query| Effect::PollMaintenanceProgress {
+                        dsn: job.dsn.clone(),
+                        run_id: *run_id,
+                        query,
+                    })
+            });
+            DispatchResult::handled_with(next_poll.into_iter().collect())
+        }
+
+        Action::MaintenanceCompleted {
+            run_id,
+            execution_time_ms,
+        } => {
+            if let Some(job) = state.maintenance.finish(*run_id) {
+                state.messages.set_success_at(
+                    format!(
+                        "{} {} finished in {}",
+                        job.label,
+                        job.target,
+                        humanize_secs(*execution_time_ms as f64 / 1_000.0)
+                    ),
+                    now,
+                );
+            }
+            DispatchResult::handled()
+        }
+
+        Action::MaintenanceFailed { run_id, error } => {
+            if let Some(job) = state.maintenance.finish(*run_id) {
+                state.messages.set_error_at(
+                    format!(
+                        "{} {} failed: {}",
+                        job.label,
+                        job.target,
+                        error.user_message()
+                    ),
+                    now,
+                );
+            }
+            DispatchResult::handled()
+        }
+
+        _ => DispatchResult::pass(),
+    }
+}
+
+// Shared guards for anything that starts a maintenance job.
+pub(super) fn startable_dsn(state: &mut AppState, now: Instant) -> Option<String> {
+    let dsn = state.session.dsn().map(String::from)?;
+    let error = if state.session.is_offline() {
+        "Offline mode: maintenance needs a live connection".to_string()
+    } else if state.session.is_read_only() {
+        "Read-only mode: maintenance commands are disabled".to_string()
+    } else if let Some(job) = state.maintenance.job() {
+        format!("{} is still running on {}", job.label, job.target)
+    } else {
+        return Some(dsn);
+    };
+    state.messages.set_error_at(error, now);
+    None
+}
+
+/// Resolves `(schema, table, row estimate, index)` from an explicit name or,
+/// without one, the top row of the Indexes tab.
+fn target_index(
+    state: &AppState,
+    name: Option<&str>,
+) -> Result<(String, String, Option<i64>, Index), String> {
+    let Some(table) = state.session.table_detail() else {
+        return Err("Select a table first".to_string());
+    };
+    let index = match name {
+        Some(name) => table
+            .indexes
+            .iter()
+            .find(|index| index.name == name)
+            .ok_or_else(|| format!("No index named {name} on {}", table.qualified_name()))?,
+        None if state.ui.inspector_tab() == InspectorTab::Indexes => table
+            .indexes
+            .get(state.ui.inspector_scroll_offset())
+            .ok_or_else(|| format!("{} has no indexes", table.qualified_name()))?,
+        None => return Err("Open the Indexes tab or pass an index name".to_string()),
+    };
+    Ok((
+        table.schema.clone(),
+        table.name.clone(),
+        table.row_count_estimate,
+        index.clone(),
+    ))
+}
+
+fn index_queries(
+    state: &mut AppState,
+    services: &AppServices,
+    kind: IndexMaintenanceKind,
+    schema: &str,
+    index: &Index,
+    now: Instant,
+) -> Option<Vec<String>> {
+    let database_type = state.session.active_database_type_or_default();
+    let queries =
+        services
+            .sql_dialect
+            .build_index_maintenance_sql(database_type, kind, schema, index);
+    if queries.is_none() {
+        state.messages.set_error_at(
+            format!(
+                "Cannot rebuild {} by swapping; use :reindex instead",
+                index.name
+            ),
+            now,
+        );
+    }
+    queries
+}
+
+pub(super) fn start_effects(
+    dsn: String,
+    run_id: u64,
+    queries: Vec<String>,
+    progress_query: Option<String>,
+) -> Vec<Effect> {
+    let mut effects = vec![Effect::RunMaintenance {
+        dsn: dsn.clone(),
+        run_id,
+        queries,
+    }];
+    effects.extend(progress_query.map(|query| Effect::PollMaintenanceProgress {
+        dsn,
+        run_id,
+        query,
+    }));
+    effects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{IndexAttributes, IndexType, MaintenanceKind};
+    use crate::model::browse::maintenance::MaintenanceProgress;
+    use crate::ports::outbound::DbOperationError;
+    use crate::test_support;
+    use crate::update::browse::query::dispatch_query;
+    use crate::update::test_fixtures;
+    use rstest::rstest;
+
+    const DSN: &str = "postgres://localhost/app";
+
+    fn state_with_table() -> AppState {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, DSN);
+        let generation = state.session.selection_generation();
+        assert!(
+            state
+                .session
+                .set_table_detail(test_support::table::minimal("public", "users"), generation)
+        );
+        state
+    }
+
+    fn state_with_indexes(row_count_estimate: Option<i64>) -> AppState {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, DSN);
+        let mut table = test_support::table::minimal("public", "users");
+        table.row_count_estimate = row_count_estimate;
+        table.indexes = ["users_pkey", "users_email_idx"]
+            .into_iter()
+            .map(|name| Index {
+                name: name.to_string(),
+                columns: vec!["email".to_string()],
+                attributes: IndexAttributes::empty(),
+                index_type: IndexType::BTree,
+                definition: None,
+            })
+            .collect();
+        let generation = state.session.selection_generation();
+        assert!(state.session.set_table_detail(table, generation));
+        state.ui.set_inspector_tab(InspectorTab::Indexes);
+        state
+    }
+
+    fn reindex(kind: IndexMaintenanceKind, index: Option<&str>, confirmed: bool) -> Action {
+        Action::StartIndexMaintenance {
+            kind,
+            index: index.map(str::to_string),
+            confirmed,
+        }
+    }
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        dispatch_query(state, action, Instant::now(), &AppServices::stub())
+            .into_effects()
+            .unwrap()
+    }
+
+    #[test]
+    fn start_runs_command_and_first_poll() {
+        let mut state = state_with_table();
+
+        let effects = dispatch(
+            &mut state,
+            &Action::StartMaintenance(MaintenanceKind::VacuumAnalyze),
+        );
+
+        assert!(matches!(
+            effects.as_slice(),
+            [
+                Effect::RunMaintenance { queries, run_id: 1, .. },
+                Effect::PollMaintenanceProgress { run_id: 1, .. },
+            ] if queries == &["VACUUM (ANALYZE) \"public\".\"users\""]
+        ));
+        assert!(state.maintenance.is_running());
+    }
+
+    #[test]
+    fn start_is_rejected_in_read_only_mode() {
+        let mut state = state_with_table();
+        state.session.enable_read_only();
+
+        let effects = dispatch(
+            &mut state,
+            &Action::StartMaintenance(MaintenanceKind::Analyze),
+        );
+
+        assert!(effects.is_empty());
+        assert_eq!(
+            state.messages.last_error(),
+            Some("Read-only mode: maintenance commands are disabled")
+        );
+    }
+
+    #[test]
+    fn second_start_is_rejected_while_running() {
+        let mut state = state_with_table();
+        dispatch(
+            &mut state,
+            &Action::StartMaintenance(MaintenanceKind::Analyze),
+        );
+
+        let effects = dispatch(
+            &mut state,
+            &Action::StartMaintenance(MaintenanceKind::Analyze),
+        );
+
+        assert!(effects.is_empty());
+        assert_eq!(
+            state.messages.last_error(),
+            Some("ANALYZE is still running on public.users")
+        );
+    }
+
+    #[test]
+    fn progress_reschedules_poll_until_completion() {
+        let mut state = state_with_table();
+        dispatch(
+            &mut state,
+            &Action::StartMaintenance(MaintenanceKind::Analyze),
+        );
+        let progress = MaintenanceProgress {
+            phase: "acquiring sample rows".to_string(),
+            done: 5,
+            total: 10,
+        };
+
+        let effects = dispatch(
+            &mut state,
+            &Action::MaintenanceProgressLoaded {
+                run_id: 1,
+                progress: Some(progress.clone()),
+            },
+        );
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::PollMaintenanceProgress { run_id: 1, .. }]
+        ));
+        assert_eq!(
+            state
+                .maintenance
+                .job()
+                .and_then(|job| job.progress.as_ref()),
+            Some(&progress)
+        );
+
+        dispatch(
+            &mut state,
+            &Action::MaintenanceCompleted {
+                run_id: 1,
+                execution_time_ms: 1500,
+            },
+        );
+        let effects = dispatch(
+            &mut state,
+            &Action::MaintenanceProgressLoaded {
+                run_id: 1,
+                progress: None,
+            },
+        );
+
+        assert!(effects.is_empty());
+        assert_eq!(
+            state.messages.last_success(),
+            Some("ANALYZE public.users finished in 2s")
+        );
+    }
+
+    #[test]
+    fn failure_reports_error_and_clears_job() {
+        let mut state = state_with_table();
+        dispatch(
+            &mut state,
+            &Action::StartMaintenance(MaintenanceKind::Analyze),
+        );
+
+        dispatch(
+            &mut state,
+            &Action::MaintenanceFailed {
+                run_id: 1,
+                error: DbOperationError::QueryFailed("permission denied".to_string()),
+            },
+        );
+
+        assert!(!state.maintenance.is_running());
+        assert!(
+            state
+                .messages
+                .last_error()
+                .is_some_and(|msg| msg.starts_with("ANALYZE public.users failed:"))
+        );
+    }
+
+    #[test]
+    fn autovacuum_report_runs_read_only_for_selected_table() {
+        let mut state = state_with_table();
+
+        let effects = dispatch(&mut state, &Action::ShowAutovacuumReport);
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ExecuteAdhoc {
+                replica_dsn: None,
+                query,
+                access_mode: AccessMode::ReadOnly,
+                ..
+            }] if query == "AUTOVACUUM \"public\".\"users\""
+        ));
+        assert!(state.query.is_running());
+    }
+
+    mod index_usage {
+        use super::*;
+        use crate::domain::IndexUsage;
+        use crate::policy::index_usage_report::IndexUsageSort;
+
+        fn loaded(run_id: u64, indexes: Vec<IndexUsage>) -> Action {
+            Action::IndexUsageLoaded {
+                dsn: DSN.to_string(),
+                run_id,
+                sort: IndexUsageSort::Size,
+                indexes,
+            }
+        }
+
+        #[test]
+        fn report_is_fetched_read_only_with_the_requested_order() {
+            let mut state = state_with_table();
+
+            let effects = dispatch(&mut state, &Action::ShowIndexUsage(IndexUsageSort::Size));
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::FetchIndexUsage { query, sort: IndexUsageSort::Size, .. }]
+                    if query == "INDEX USAGE"
+            ));
+            assert!(state.query.is_running());
+        }
+
+        #[test]
+        fn loaded_indexes_replace_the_visible_result() {
+            let mut state = state_with_table();
+            let Effect::FetchIndexUsage { run_id, .. } =
+                dispatch(&mut state, &Action::ShowIndexUsage(IndexUsageSort::Size)).remove(0)
+            else {
+                panic!("expected FetchIndexUsage");
+            };
+
+            dispatch(
+                &mut state,
+                &loaded(
+                    run_id,
+                    vec![IndexUsage {
+                        schema: "public".to_string(),
+                        table: "users".to_string(),
+                        name: "users_email_idx".to_string(),
+                        scans: 0,
+                        size_bytes: 16384,
+                        unique: false,
+                    }],
+                ),
+            );
+
+            assert!(!state.query.is_running());
+            let shown = state.query.visible_result().unwrap();
+            assert_eq!(shown.query, ":indexes size");
+            assert_eq!(shown.display_row_at(0).unwrap()[4], "never used");
+        }
+
+        #[test]
+        fn superseded_run_is_ignored() {
+            let mut state = state_with_table();
+            dispatch(&mut state, &Action::ShowIndexUsage(IndexUsageSort::Scans));
+
+            dispatch(&mut state, &loaded(99, Vec::new()));
+
+            assert!(state.query.is_running());
+            assert!(state.messages.last_error().is_none());
+        }
+    }
+
+    #[test]
+    fn reindex_targets_top_row_of_indexes_tab() {
+        let mut state = state_with_indexes(Some(10));
+        state.ui.set_inspector_scroll_offset(1);
+
+        let effects = dispatch(
+            &mut state,
+            &reindex(IndexMaintenanceKind::ReindexConcurrently, None, false),
+        );
+
+        assert!(matches!(
+            effects.as_slice(),
+            [
+                Effect::RunMaintenance { queries, run_id: 1, .. },
+                Effect::PollMaintenanceProgress { query, .. },
+            ] if queries == &["REINDEX CONCURRENTLY \"public\".\"users_email_idx\""]
+                && query == "INDEX PROGRESS \"public\".\"users\""
+        ));
+        assert_eq!(
+            state.maintenance.job().map(|job| job.target.as_str()),
+            Some("public.users_email_idx")
+        );
+    }
+
+    #[test]
+    fn reindex_without_name_needs_indexes_tab() {
+        let mut state = state_with_indexes(Some(10));
+        state.ui.set_inspector_tab(InspectorTab::Columns);
+
+        let effects = dispatch(
+            &mut state,
+            &reindex(IndexMaintenanceKind::ReindexConcurrently, None, false),
+        );
+
+        assert!(effects.is_empty());
+        assert_eq!(
+            state.messages.last_error(),
+            Some("Open the Indexes tab or pass an index name")
+        );
+    }
+
+    #[rstest]
+    #[case(Some(5_000_000))]
+    #[case(None)]
+    fn blocking_reindex_on_large_table_asks_first(#[case] rows: Option<i64>) {
+        let mut state = state_with_indexes(rows);
+
+        let effects = dispatch(
+            &mut state,
+            &reindex(IndexMaintenanceKind::Reindex, Some("users_pkey"), false),
+        );
+
+        assert!(effects.is_empty());
+        assert!(!state.maintenance.is_running());
+        assert!(matches!(
+            state.confirm_dialog.intent(),
+            Some(ConfirmIntent::BlockingReindex { index }) if index == "users_pkey"
+        ));
+
+        let effects = dispatch(
+            &mut state,
+            &reindex(IndexMaintenanceKind::Reindex, Some("users_pkey"), true),
+        );
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::RunMaintenance { queries, .. }, ..]
+                if queries == &["REINDEX \"public\".\"users_pkey\""]
+        ));
+    }
+
+    #[test]
+    fn blocking_reindex_on_small_table_runs_directly() {
+        let mut state = state_with_indexes(Some(500));
+
+        let effects = dispatch(
+            &mut state,
+            &reindex(IndexMaintenanceKind::Reindex, Some("users_pkey"), false),
+        );
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::RunMaintenance { .. }, ..]
+        ));
+        assert!(state.confirm_dialog.intent().is_none());
+    }
+
+    #[test]
+    fn copy_sql_puts_statements_on_clipboard() {
+        let mut state = state_with_indexes(Some(10));
+
+        let effects = dispatch(
+            &mut state,
+            &Action::CopyIndexMaintenanceSql {
+                kind: IndexMaintenanceKind::Rebuild,
+                index: Some("users_email_idx".to_string()),
+            },
+        );
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::CopyToClipboard { content, .. }]
+                if content == "Index rebuild \"public\".\"users_email_idx\";\n"
+        ));
+        assert!(!state.maintenance.is_running());
+    }
+}
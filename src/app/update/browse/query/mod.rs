@@ -1,5 +1,17 @@
+mod app_info;
+mod bulk;
+mod cache;
 mod execution;
+mod maintenance;
 mod pagination;
+mod preview_explain;
+mod quick_actions;
+mod replication;
+mod sampling;
+mod schema_checks;
+mod server_info;
+mod server_settings;
+mod transaction;
 mod write;
 
 use std::time::Instant;
@@ -18,8 +30,20 @@ pub fn dispatch_query(
     services: &AppServices,
 ) -> DispatchResult {
     execution::reduce_execution(state, action, now, services)
+        .or_else(|| replication::reduce_replication(state, action, now, services))
+        .or_else(|| sampling::reduce_sampling(state, action, now, services))
+        .or_else(|| schema_checks::reduce_schema_checks(state, action, now, services))
+        .or_else(|| app_info::reduce_app_info(state, action, now, services))
+        .or_else(|| cache::reduce_cache(state, action, now, services))
         .or_else(|| write::reduce_write(state, action, now, services))
         .or_else(|| pagination::reduce_pagination(state, action, now, services))
+        .or_else(|| preview_explain::reduce_preview_explain(state, action, now, services))
+        .or_else(|| maintenance::reduce_maintenance(state, action, now, services))
+        .or_else(|| server_settings::reduce_server_settings(state, action, now, services))
+        .or_else(|| server_info::reduce_server_info(state, action, now, services))
+        .or_else(|| transaction::reduce_transaction(state, action, now, services))
+        .or_else(|| bulk::reduce_bulk(state, action, now, services))
+        .or_else(|| quick_actions::reduce_quick_actions(state, action, now, services))
 }
 
 /// Builds the preview effect for the table currently held in pagination state,
@@ -42,6 +66,13 @@ pub(super) fn preview_effect_for_current_table(
         dsn,
         schema: state.query.pagination.schema().to_string(),
         table: state.query.pagination.table().to_string(),
+        sort_column: state.query.pagination.sort_column().map(String::from),
+        filter: state.query.pagination.filter().map(String::from),
+        columns: state
+            .explorer_tree
+            .layout()
+            .preview_columns(&state.query.pagination.qualified_name())
+            .to_vec(),
         generation,
         run_id,
         limit: PREVIEW_PAGE_SIZE,
@@ -3,9 +3,13 @@ use std::time::Instant;
 
 use crate::cmd::effect::Effect;
 use crate::domain::{DatabaseType, QuerySource, QueryValue};
+use crate::i18n::Message;
 use crate::model::app_state::AppState;
 use crate::model::shared::confirm_dialog::{ConfirmIntent, CsvExportCacheSnapshot};
 use crate::model::shared::input_mode::InputMode;
+use crate::policy::preview_columns::parse_preview_columns;
+use crate::policy::preview_sort::{next_sort_column, sortable_columns};
+use crate::policy::sql::preview_filter::{complete_column, parse_preview_filter};
 use crate::policy::sql::sqlite_export::{SqliteExportPlan, sqlite_export_plan};
 use crate::services::AppServices;
 use crate::update::action::Action;
@@ -49,7 +53,7 @@ fn dispatch_cached_csv_export(
             None => "Export to CSV?".to_string(),
         };
         state.confirm_dialog.open(
-            "Confirm CSV Export",
+            Message::ConfirmCsvExportTitle.text(state.settings.locale()),
             msg,
             ConfirmIntent::CsvExportCached {
                 dsn,
@@ -112,6 +116,24 @@ pub fn reduce_pagination(
             let file_name = csv_export_file_name(state, result.source);
             let row_count = result.row_count();
 
+            // Re-running the query would write the masked columns in clear,
+            // so only the loaded rows are exported.
+            if !state.query.masked_columns().is_empty() {
+                let columns = result.columns.clone();
+                let values = result.values().to_vec();
+                let row_count = values.len();
+                let run_id = state.query.begin_running(now);
+                return dispatch_cached_csv_export(
+                    state,
+                    dsn,
+                    run_id,
+                    file_name,
+                    columns,
+                    values,
+                    Some(row_count),
+                );
+            }
+
             if state.session.active_database_type() == Some(DatabaseType::SQLite) {
                 match sqlite_export_plan(result.source, &export_query, &result.columns, row_count) {
                     SqliteExportPlan::NotExportable { reason } => {
@@ -165,7 +187,7 @@ pub fn reduce_pagination(
                     None => "Row count unknown. Export to CSV?".to_string(),
                 };
                 state.confirm_dialog.open(
-                    "Confirm CSV Export",
+                    Message::ConfirmCsvExportTitle.text(state.settings.locale()),
                     msg,
                     ConfirmIntent::CsvExportRerunnable {
                         dsn: dsn.clone(),
@@ -248,6 +270,22 @@ pub fn reduce_pagination(
             DispatchResult::handled()
         }
 
+        Action::QueryHookFailed(error) => {
+            state
+                .messages
+                .set_error_at(format!("Query hook failed: {error}"), now);
+
+            DispatchResult::handled()
+        }
+
+        Action::AuditLogFailed(error) => {
+            state
+                .messages
+                .set_error_at(format!("Audit log write failed: {error}"), now);
+
+            DispatchResult::handled()
+        }
+
         Action::ResultNextPage => {
             if state.query.is_running() || !state.query.can_paginate_visible_result() {
                 return DispatchResult::handled();
@@ -285,6 +323,196 @@ pub fn reduce_pagination(
             }
         }
 
+        Action::CyclePreviewSort => {
+            if state.query.is_running() || !state.query.can_paginate_visible_result() {
+                return DispatchResult::handled();
+            }
+            let Some(table) = state
+                .session
+                .table_detail()
+                .filter(|table| state.query.pagination.matches_table(table))
+            else {
+                state
+                    .messages
+                    .set_error_at("Table details are still loading".into(), now);
+                return DispatchResult::handled();
+            };
+            if sortable_columns(table).is_empty() {
+                state
+                    .messages
+                    .set_error_at("No indexed columns to sort by".into(), now);
+                return DispatchResult::handled();
+            }
+            let next = next_sort_column(table, state.query.pagination.sort_column());
+            let message = next.as_ref().map_or_else(
+                || "Preview order: key order".to_string(),
+                |column| format!("Preview order: {column}"),
+            );
+            state.query.pagination.set_sort_column(next);
+            let generation = state.session.selection_generation();
+            match preview_effect_for_current_table(state, now, 0, generation) {
+                Some(effect) => {
+                    state.result_interaction.reset_view();
+                    state.messages.set_success_at(message, now);
+                    DispatchResult::handled_with(vec![effect])
+                }
+                None => DispatchResult::handled(),
+            }
+        }
+
+        Action::SetPreviewFilter(input) => {
+            if state.query.is_running() || !state.query.can_paginate_visible_result() {
+                if input.is_some() {
+                    state.messages.set_error_at(
+                        "Filters apply to a table preview — select a table first".into(),
+                        now,
+                    );
+                }
+                return DispatchResult::handled();
+            }
+            let filter = match input.as_deref().map(parse_preview_filter) {
+                None => None,
+                Some(Ok(filter)) => filter,
+                Some(Err(error)) => {
+                    state.messages.set_error_at(error.to_string(), now);
+                    return DispatchResult::handled();
+                }
+            };
+            if filter.as_deref() == state.query.pagination.filter() {
+                return DispatchResult::handled();
+            }
+            let message = filter.as_ref().map_or_else(
+                || "Preview filter cleared".to_string(),
+                |filter| format!("Preview filter: WHERE {filter}"),
+            );
+            state.query.pagination.set_filter(filter);
+            let generation = state.session.selection_generation();
+            match preview_effect_for_current_table(state, now, 0, generation) {
+                Some(effect) => {
+                    state.result_interaction.reset_view();
+                    state.messages.set_success_at(message, now);
+                    DispatchResult::handled_with(vec![effect])
+                }
+                None => DispatchResult::handled(),
+            }
+        }
+
+        Action::SetPreviewColumns(input) => {
+            if state.query.is_running() || !state.query.can_paginate_visible_result() {
+                if input.is_some() {
+                    state.messages.set_error_at(
+                        "Preview columns apply to a table preview — select a table first".into(),
+                        now,
+                    );
+                }
+                return DispatchResult::handled();
+            }
+            let columns = match input {
+                None => Vec::new(),
+                Some(input) => {
+                    let Some(table) = state
+                        .session
+                        .table_detail()
+                        .filter(|table| state.query.pagination.matches_table(table))
+                    else {
+                        state
+                            .messages
+                            .set_error_at("Table details are still loading".into(), now);
+                        return DispatchResult::handled();
+                    };
+                    match parse_preview_columns(table, input) {
+                        Ok(columns) => columns,
+                        Err(error) => {
+                            state.messages.set_error_at(error.to_string(), now);
+                            return DispatchResult::handled();
+                        }
+                    }
+                }
+            };
+            let qualified_name = state.query.pagination.qualified_name();
+            if columns
+                == state
+                    .explorer_tree
+                    .layout()
+                    .preview_columns(&qualified_name)
+            {
+                return DispatchResult::handled();
+            }
+            let message = if columns.is_empty() {
+                "Preview columns: all".to_string()
+            } else {
+                format!("Preview columns: {}", columns.join(", "))
+            };
+            state
+                .explorer_tree
+                .set_preview_columns(&qualified_name, columns);
+            let target_page = state.query.pagination.current_page();
+            let generation = state.session.selection_generation();
+            match preview_effect_for_current_table(state, now, target_page, generation) {
+                Some(effect) => {
+                    state.result_interaction.reset_view();
+                    state.messages.set_success_at(message, now);
+                    DispatchResult::handled_with(vec![effect])
+                }
+                None => DispatchResult::handled(),
+            }
+        }
+
+        Action::OpenPreviewFilter => {
+            if !state.query.can_paginate_visible_result() {
+                state.messages.set_error_at(
+                    "Filters apply to a table preview — select a table first".into(),
+                    now,
+                );
+                return DispatchResult::handled();
+            }
+            let current = state.query.pagination.filter().unwrap_or_default();
+            state.modal.push_mode(InputMode::CommandLine);
+            state
+                .command_line_input
+                .set_content(format!("where {current}"));
+            state
+                .command_line_input
+                .update_viewport(state.command_line_visible_width);
+            DispatchResult::handled()
+        }
+
+        Action::CommandLineComplete => {
+            let command = state
+                .command_line_input
+                .content()
+                .trim_start()
+                .to_ascii_lowercase();
+            if !command.starts_with("where ") && !command.starts_with("columns ") {
+                return DispatchResult::handled();
+            }
+            let Some(table) = state
+                .session
+                .table_detail()
+                .filter(|table| state.query.pagination.matches_table(table))
+            else {
+                return DispatchResult::handled();
+            };
+            let columns: Vec<String> = table.columns.iter().map(|c| c.name.clone()).collect();
+            let Some(completion) = complete_column(
+                state.command_line_input.content(),
+                state.command_line_input.cursor(),
+                &columns,
+            ) else {
+                return DispatchResult::handled();
+            };
+            if completion.candidates.len() > 1 {
+                state
+                    .messages
+                    .set_success_at(completion.candidates.join("  "), now);
+            }
+            state.command_line_input.insert_str(&completion.insert);
+            state
+                .command_line_input
+                .update_viewport(state.command_line_visible_width);
+            DispatchResult::handled()
+        }
+
         _ => DispatchResult::pass(),
     }
 }
@@ -665,6 +893,34 @@ mod tests {
             assert!(!state.query.is_running());
         }
 
+        #[test]
+        fn masked_result_exports_loaded_rows_instead_of_rerunning() {
+            let mut state = create_test_state();
+            let original = editable_preview_result();
+            state.query.set_current_result(Arc::new(
+                QueryResult::clone(&original).with_masked_columns(&[1], "***"),
+            ));
+            state
+                .query
+                .hold_unmasked(original, vec!["name".to_string()]);
+
+            let effects = dispatch_query(
+                &mut state,
+                &Action::RequestCsvExport,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            let [Effect::ExportCsvFromCache { values, .. }] = effects.as_slice() else {
+                panic!("expected cached CSV export effect");
+            };
+            assert_eq!(
+                values,
+                &vec![vec![QueryValue::text("1"), QueryValue::text("***")]]
+            );
+        }
+
         #[test]
         fn request_without_result_is_noop() {
             let mut state = create_test_state();
@@ -934,4 +1190,349 @@ mod tests {
             }
         }
     }
+
+    mod preview_sort {
+        use super::*;
+        use crate::domain::{Index, IndexAttributes, IndexType};
+
+        fn sortable_state() -> AppState {
+            let mut state = create_test_state();
+            state.query.set_current_result(preview_result(10));
+            state.query.pagination.reset_for_table("public", "users");
+            let mut detail = users_table_detail();
+            detail.schema = "public".to_string();
+            detail.indexes.push(Index {
+                name: "users_name_idx".to_string(),
+                columns: vec!["name".to_string()],
+                attributes: IndexAttributes::empty(),
+                index_type: IndexType::BTree,
+                definition: None,
+            });
+            state.session.set_table_detail_raw(Some(detail));
+            state
+        }
+
+        #[test]
+        fn orders_by_next_indexed_column_from_first_page() {
+            let mut state = sortable_state();
+            state.query.pagination.set_page_result(2, false);
+
+            let effects = dispatch_query(
+                &mut state,
+                &Action::CyclePreviewSort,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            assert!(matches!(
+                &effects[0],
+                Effect::ExecutePreview { sort_column: Some(column), offset: 0, target_page: 0, .. }
+                    if column == "name"
+            ));
+            assert_eq!(state.query.pagination.sort_column(), Some("name"));
+            assert_eq!(
+                state.messages.last_success.as_deref(),
+                Some("Preview order: name")
+            );
+        }
+
+        #[test]
+        fn returns_to_key_order_after_the_last_column() {
+            let mut state = sortable_state();
+            state
+                .query
+                .pagination
+                .set_sort_column(Some("name".to_string()));
+
+            let effects = dispatch_query(
+                &mut state,
+                &Action::CyclePreviewSort,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            assert!(matches!(
+                &effects[0],
+                Effect::ExecutePreview {
+                    sort_column: None,
+                    ..
+                }
+            ));
+        }
+
+        #[test]
+        fn table_without_secondary_indexes_reports_it() {
+            let mut state = sortable_state();
+            let mut detail = users_table_detail();
+            detail.schema = "public".to_string();
+            state.session.set_table_detail_raw(Some(detail));
+
+            let effects = dispatch_query(
+                &mut state,
+                &Action::CyclePreviewSort,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            assert!(effects.is_empty());
+            assert_eq!(
+                state.messages.last_error.as_deref(),
+                Some("No indexed columns to sort by")
+            );
+        }
+
+        #[test]
+        fn adhoc_results_are_left_alone() {
+            let mut state = sortable_state();
+            state.query.set_current_result(adhoc_result());
+
+            let effects = dispatch_query(
+                &mut state,
+                &Action::CyclePreviewSort,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            assert!(effects.is_empty());
+            assert_eq!(state.query.pagination.sort_column(), None);
+        }
+    }
+
+    mod preview_filter {
+        use super::*;
+        use crate::domain::Table;
+        use crate::test_support;
+        use crate::test_support::column::test_nullable_column;
+
+        fn previewing_state() -> AppState {
+            let mut state = create_test_state();
+            state.query.set_current_result(preview_result(10));
+            state.query.pagination.reset_for_table("public", "users");
+            state
+        }
+
+        fn set_filter(state: &mut AppState, filter: Option<&str>) -> Vec<Effect> {
+            dispatch_query(
+                state,
+                &Action::SetPreviewFilter(filter.map(String::from)),
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn filter_reruns_the_preview_from_the_first_page() {
+            let mut state = previewing_state();
+            state.query.pagination.set_page_result(3, false);
+
+            let effects = set_filter(&mut state, Some("WHERE status = 'open'"));
+
+            assert!(matches!(
+                &effects[0],
+                Effect::ExecutePreview { filter: Some(filter), offset: 0, target_page: 0, .. }
+                    if filter == "status = 'open'"
+            ));
+            assert_eq!(state.query.pagination.filter(), Some("status = 'open'"));
+            assert_eq!(
+                state.messages.last_success.as_deref(),
+                Some("Preview filter: WHERE status = 'open'")
+            );
+        }
+
+        #[test]
+        fn clearing_reruns_without_the_filter() {
+            let mut state = previewing_state();
+            state
+                .query
+                .pagination
+                .set_filter(Some("status = 'open'".to_string()));
+
+            let effects = set_filter(&mut state, None);
+
+            assert!(matches!(
+                &effects[0],
+                Effect::ExecutePreview { filter: None, .. }
+            ));
+            assert_eq!(state.query.pagination.filter(), None);
+        }
+
+        #[test]
+        fn refused_filter_keeps_the_current_preview() {
+            let mut state = previewing_state();
+
+            let effects = set_filter(&mut state, Some("1 = 1; DROP TABLE users"));
+
+            assert!(effects.is_empty());
+            assert_eq!(state.query.pagination.filter(), None);
+            assert!(
+                state
+                    .messages
+                    .last_error
+                    .as_deref()
+                    .unwrap()
+                    .contains("';'")
+            );
+        }
+
+        #[test]
+        fn adhoc_results_report_that_filters_need_a_preview() {
+            let mut state = previewing_state();
+            state.query.set_current_result(adhoc_result());
+
+            let effects = set_filter(&mut state, Some("id = 1"));
+
+            assert!(effects.is_empty());
+            assert!(state.messages.last_error.is_some());
+        }
+
+        #[test]
+        fn open_prefills_the_command_line_with_the_active_filter() {
+            let mut state = previewing_state();
+            state
+                .query
+                .pagination
+                .set_filter(Some("status = 'open'".to_string()));
+
+            set_filter_action(&mut state, &Action::OpenPreviewFilter);
+
+            assert_eq!(state.input_mode(), InputMode::CommandLine);
+            assert_eq!(state.command_line_input.content(), "where status = 'open'");
+        }
+
+        #[test]
+        fn tab_completes_columns_of_the_previewed_table() {
+            let mut state = previewing_state();
+            state.session.set_table_detail_raw(Some(Table {
+                columns: vec![
+                    test_nullable_column("id", "bigint", 1),
+                    test_nullable_column("created_at", "timestamptz", 2),
+                ],
+                ..test_support::table::minimal("public", "users")
+            }));
+            set_filter_action(&mut state, &Action::OpenPreviewFilter);
+            state.command_line_input.insert_str("cre");
+
+            set_filter_action(&mut state, &Action::CommandLineComplete);
+
+            assert_eq!(state.command_line_input.content(), "where created_at");
+        }
+
+        fn set_filter_action(state: &mut AppState, action: &Action) {
+            dispatch_query(state, action, Instant::now(), &AppServices::stub()).unwrap();
+        }
+    }
+
+    mod preview_columns {
+        use super::*;
+        use crate::domain::Table;
+        use crate::test_support;
+        use crate::test_support::column::test_nullable_column;
+
+        fn previewing_state() -> AppState {
+            let mut state = create_test_state();
+            state.query.set_current_result(preview_result(10));
+            state.query.pagination.reset_for_table("public", "users");
+            state.session.set_table_detail_raw(Some(Table {
+                columns: vec![
+                    test_nullable_column("id", "bigint", 1),
+                    test_nullable_column("name", "text", 2),
+                    test_nullable_column("avatar", "bytea", 3),
+                ],
+                primary_key: Some(vec!["id".to_string()]),
+                ..test_support::table::minimal("public", "users")
+            }));
+            state
+        }
+
+        fn set_columns(state: &mut AppState, columns: Option<&str>) -> Vec<Effect> {
+            dispatch_query(
+                state,
+                &Action::SetPreviewColumns(columns.map(String::from)),
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn columns_are_saved_and_rerun_the_preview() {
+            let mut state = previewing_state();
+
+            let effects = set_columns(&mut state, Some("name"));
+
+            assert!(matches!(
+                &effects[0],
+                Effect::ExecutePreview { columns, .. } if columns == &["id", "name"]
+            ));
+            assert_eq!(
+                state.explorer_tree.layout().preview_columns("public.users"),
+                ["id", "name"]
+            );
+            assert!(state.explorer_tree.is_dirty());
+            assert_eq!(
+                state.messages.last_success.as_deref(),
+                Some("Preview columns: id, name")
+            );
+        }
+
+        #[test]
+        fn bare_command_selects_every_column_again() {
+            let mut state = previewing_state();
+            state
+                .explorer_tree
+                .set_preview_columns("public.users", vec!["id".to_string(), "name".to_string()]);
+
+            let effects = set_columns(&mut state, None);
+
+            assert!(matches!(
+                &effects[0],
+                Effect::ExecutePreview { columns, .. } if columns.is_empty()
+            ));
+            assert!(
+                state
+                    .explorer_tree
+                    .layout()
+                    .preview_columns("public.users")
+                    .is_empty()
+            );
+        }
+
+        #[test]
+        fn unknown_column_keeps_the_current_preview() {
+            let mut state = previewing_state();
+
+            let effects = set_columns(&mut state, Some("name, email"));
+
+            assert!(effects.is_empty());
+            assert_eq!(
+                state.messages.last_error.as_deref(),
+                Some("Unknown column: email")
+            );
+            assert!(!state.explorer_tree.is_dirty());
+        }
+
+        #[test]
+        fn tab_completes_columns_in_the_list() {
+            let mut state = previewing_state();
+            state.modal.push_mode(InputMode::CommandLine);
+            state
+                .command_line_input
+                .set_content("columns name, av".to_string());
+
+            dispatch_query(
+                &mut state,
+                &Action::CommandLineComplete,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            assert_eq!(state.command_line_input.content(), "columns name, avatar");
+        }
+    }
 }
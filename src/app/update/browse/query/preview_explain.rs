@@ -0,0 +1,161 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::model::browse::query_execution::VisibleResultKind;
+use crate::policy::preview_explain::preview_explain_report;
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+use super::execution::reset_view_for_new_result;
+
+pub fn reduce_preview_explain(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::ExplainPreview => {
+            let preview = match state.query.current_result() {
+                Some(result)
+                    if state.query.visible_result_kind() == VisibleResultKind::LivePreview
+                        && !result.is_error() =>
+                {
+                    Arc::clone(result)
+                }
+                _ => {
+                    state.messages.set_error_at(
+                        "Preview explain needs a table preview in the result pane".to_string(),
+                        now,
+                    );
+                    return DispatchResult::handled();
+                }
+            };
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let Some(query) = services.sql_dialect.build_explain_sql(
+                state.session.active_database_type_or_default(),
+                &preview.query,
+            ) else {
+                state.messages.set_error_at(
+                    "EXPLAIN is unavailable for this connection".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            };
+            DispatchResult::handled_with(vec![Effect::ExplainPreview {
+                dsn,
+                query,
+                preview,
+            }])
+        }
+        Action::PreviewExplainCompleted {
+            preview,
+            query,
+            plan_text,
+            execution_time_ms,
+        } => {
+            // A table switch or page change since the request makes the plan stale.
+            if !state
+                .query
+                .current_result()
+                .is_some_and(|current| Arc::ptr_eq(current, preview))
+            {
+                return DispatchResult::handled();
+            }
+            let report = preview_explain_report(preview, query, plan_text, *execution_time_ms);
+            reset_view_for_new_result(state, now);
+            state.query.set_current_result(Arc::new(report));
+            DispatchResult::handled()
+        }
+        Action::PreviewExplainFailed(error) => {
+            state.messages.set_error_at(error.user_message(), now);
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::update::browse::query::tests::{adhoc_result, create_test_state, preview_result};
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        reduce_preview_explain(state, action, Instant::now(), &AppServices::stub())
+            .into_effects()
+            .unwrap()
+    }
+
+    #[test]
+    fn explain_wraps_the_executed_preview_query() {
+        let mut state = create_test_state();
+        state.query.set_current_result(preview_result(2));
+
+        let effects = dispatch(&mut state, &Action::ExplainPreview);
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ExplainPreview { query, preview, .. }]
+                if query == "EXPLAIN SELECT * FROM users" && preview.data_row_count() == 2
+        ));
+    }
+
+    #[test]
+    fn explain_on_adhoc_result_reports_error() {
+        let mut state = create_test_state();
+        state.query.set_current_result(adhoc_result());
+
+        let effects = dispatch(&mut state, &Action::ExplainPreview);
+
+        assert!(effects.is_empty());
+        assert!(state.messages.last_error().is_some());
+    }
+
+    #[test]
+    fn completed_plan_replaces_the_preview_with_a_report() {
+        let mut state = create_test_state();
+        let preview = preview_result(2);
+        state.query.set_current_result(Arc::clone(&preview));
+
+        dispatch(
+            &mut state,
+            &Action::PreviewExplainCompleted {
+                preview,
+                query: "EXPLAIN SELECT * FROM users".to_string(),
+                plan_text: "Seq Scan on users  (cost=0.00..1.02 rows=2 width=4)".to_string(),
+                execution_time_ms: 1,
+            },
+        );
+
+        let visible = state.query.visible_result().unwrap();
+        assert_eq!(visible.columns, vec!["Item", "Detail"]);
+        assert_eq!(
+            visible.display_row_at(0).unwrap(),
+            vec!["Query", "SELECT * FROM users"]
+        );
+    }
+
+    #[test]
+    fn plan_for_a_replaced_preview_is_dropped() {
+        let mut state = create_test_state();
+        let stale = preview_result(2);
+        state.query.set_current_result(preview_result(3));
+
+        dispatch(
+            &mut state,
+            &Action::PreviewExplainCompleted {
+                preview: stale,
+                query: "EXPLAIN SELECT * FROM users".to_string(),
+                plan_text: "Seq Scan on users".to_string(),
+                execution_time_ms: 1,
+            },
+        );
+
+        assert_eq!(state.query.visible_result().unwrap().data_row_count(), 3);
+    }
+}
@@ -0,0 +1,260 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::model::browse::quick_actions::QuickAction;
+use crate::model::shared::input_mode::InputMode;
+use crate::policy::sql::replica_routing::QueryEndpoint;
+use crate::ports::outbound::AccessMode;
+use crate::services::AppServices;
+use crate::update::action::{Action, ListMotion, ListTarget, ModalKind};
+use crate::update::dispatch_result::DispatchResult;
+
+use super::bulk;
+
+pub fn reduce_quick_actions(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::OpenModal(ModalKind::QuickActions) => {
+            if state.input_mode() == InputMode::QuickActions {
+                return DispatchResult::handled();
+            }
+            let Some(table) = state.explorer_selected_table() else {
+                state
+                    .messages
+                    .set_error_at("Select a table in the Explorer first".to_string(), now);
+                return DispatchResult::handled();
+            };
+            let (schema, name) = (table.schema.clone(), table.name.clone());
+            state.quick_actions.open(schema, name);
+            state.modal.push_mode(InputMode::QuickActions);
+            DispatchResult::handled()
+        }
+        Action::CloseModal(ModalKind::QuickActions) => {
+            close(state);
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::QuickActions,
+            motion,
+        } => {
+            match motion {
+                ListMotion::Next => state.quick_actions.select_next(),
+                ListMotion::Previous => state.quick_actions.select_previous(),
+            }
+            DispatchResult::handled()
+        }
+        Action::QuickActionConfirm => {
+            let Some((schema, table)) = state
+                .quick_actions
+                .target()
+                .map(|(schema, table)| (schema.to_string(), table.to_string()))
+            else {
+                return DispatchResult::handled();
+            };
+            let quick_action = state.quick_actions.selected_action();
+            if let Some(reason) = state.quick_action_unavailable_reason(quick_action) {
+                let favorite = state
+                    .explorer_tree
+                    .layout()
+                    .is_favorite(&format!("{schema}.{table}"));
+                state
+                    .messages
+                    .set_error_at(format!("{}: {reason}", quick_action.label(favorite)), now);
+                return DispatchResult::handled();
+            }
+            close(state);
+            run(state, quick_action, schema, table, now, services)
+        }
+
+        _ => DispatchResult::pass(),
+    }
+}
+
+fn close(state: &mut AppState) {
+    if state.input_mode() == InputMode::QuickActions {
+        state.modal.pop_mode();
+    }
+    state.quick_actions.close();
+}
+
+fn run(
+    state: &mut AppState,
+    quick_action: QuickAction,
+    schema: String,
+    table: String,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match quick_action {
+        // The Explorer cursor still sits on the target, so this is the same as Enter.
+        QuickAction::Preview => DispatchResult::handled_with(vec![Effect::DispatchActions(vec![
+            Action::ConfirmSelection,
+        ])]),
+        QuickAction::CountRows => {
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let query = services.sql_dialect.build_row_count_sql(
+                state.session.active_database_type_or_default(),
+                &schema,
+                &table,
+            );
+            let endpoint = state.active_replica_dsn().map(|_| QueryEndpoint::Primary);
+            state.session.record_query_endpoint(endpoint);
+            let run_id = state.query.begin_running(now);
+            DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
+                dsn,
+                replica_dsn: None,
+                run_id,
+                query,
+                access_mode: AccessMode::ReadOnly,
+            }])
+        }
+        QuickAction::CopyDdl => bulk::copy_ddl(state, vec![(schema, table)]),
+        // A single seed table makes the ER preparation pull in its FK neighbors.
+        QuickAction::ErNeighborhood => {
+            state
+                .er_preparation
+                .set_targets(vec![format!("{schema}.{table}")]);
+            DispatchResult::handled_with(vec![Effect::DispatchActions(vec![Action::ErOpenDiagram])])
+        }
+        QuickAction::ExportCsv => {
+            DispatchResult::handled_with(vec![Effect::DispatchActions(vec![
+                Action::RequestCsvExport,
+            ])])
+        }
+        QuickAction::Analyze => bulk::analyze(state, services, &[(schema, table)], now),
+        QuickAction::ToggleFavorite => {
+            let qualified_name = format!("{schema}.{table}");
+            let message = if state.explorer_tree.toggle_favorite(&qualified_name) {
+                format!("Added {qualified_name} to favorites")
+            } else {
+                format!("Removed {qualified_name} from favorites")
+            };
+            state.messages.set_success_at(message, now);
+            DispatchResult::handled()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::{DatabaseMetadata, TableSummary};
+    use crate::update::browse::query::tests::create_test_state;
+
+    fn state_on_orders() -> AppState {
+        let mut state = create_test_state();
+        let mut metadata = DatabaseMetadata::new("test".to_string());
+        metadata.table_summaries = vec![TableSummary::new(
+            "public".to_string(),
+            "orders".to_string(),
+            None,
+            false,
+        )];
+        state.session.set_metadata(Some(Arc::new(metadata)));
+        let row = state
+            .explorer_row_index("public", "orders")
+            .expect("orders row");
+        state.ui.set_explorer_selection(Some(row));
+        state
+    }
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        reduce_quick_actions(state, action, Instant::now(), &AppServices::stub())
+            .into_effects()
+            .unwrap()
+    }
+
+    fn confirm(state: &mut AppState, quick_action: QuickAction) -> Vec<Effect> {
+        dispatch(state, &Action::OpenModal(ModalKind::QuickActions));
+        while state.quick_actions.selected_action() != quick_action {
+            state.quick_actions.select_next();
+        }
+        dispatch(state, &Action::QuickActionConfirm)
+    }
+
+    #[test]
+    fn open_without_a_selected_table_reports_error() {
+        let mut state = create_test_state();
+
+        dispatch(&mut state, &Action::OpenModal(ModalKind::QuickActions));
+
+        assert_eq!(state.input_mode(), InputMode::Normal);
+        assert_eq!(
+            state.messages.last_error(),
+            Some("Select a table in the Explorer first")
+        );
+    }
+
+    #[test]
+    fn open_targets_the_selected_table() {
+        let mut state = state_on_orders();
+
+        dispatch(&mut state, &Action::OpenModal(ModalKind::QuickActions));
+
+        assert_eq!(state.input_mode(), InputMode::QuickActions);
+        assert_eq!(state.quick_actions.target(), Some(("public", "orders")));
+    }
+
+    #[test]
+    fn count_rows_runs_a_read_only_query_and_closes_the_menu() {
+        let mut state = state_on_orders();
+
+        let effects = confirm(&mut state, QuickAction::CountRows);
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ExecuteAdhoc { query, access_mode: AccessMode::ReadOnly, .. }]
+                if query == "COUNT public.orders"
+        ));
+        assert_eq!(state.input_mode(), InputMode::Normal);
+        assert_eq!(state.quick_actions.target(), None);
+    }
+
+    #[test]
+    fn er_neighborhood_seeds_the_diagram_with_the_table() {
+        let mut state = state_on_orders();
+
+        let effects = confirm(&mut state, QuickAction::ErNeighborhood);
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchActions(actions)] if matches!(actions.as_slice(), [Action::ErOpenDiagram])
+        ));
+    }
+
+    #[test]
+    fn unavailable_action_keeps_the_menu_open() {
+        let mut state = state_on_orders();
+        state.session.enable_read_only();
+
+        let effects = confirm(&mut state, QuickAction::Analyze);
+
+        assert!(effects.is_empty());
+        assert_eq!(state.input_mode(), InputMode::QuickActions);
+        assert_eq!(state.messages.last_error(), Some("ANALYZE: read-only mode"));
+    }
+
+    #[test]
+    fn toggle_favorite_flips_the_table() {
+        let mut state = state_on_orders();
+
+        confirm(&mut state, QuickAction::ToggleFavorite);
+        assert!(state.explorer_tree.layout().is_favorite("public.orders"));
+
+        confirm(&mut state, QuickAction::ToggleFavorite);
+        assert!(!state.explorer_tree.layout().is_favorite("public.orders"));
+        assert_eq!(
+            state.messages.last_success(),
+            Some("Removed public.orders from favorites")
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::policy::sql::replica_routing::QueryEndpoint;
+use crate::ports::outbound::AccessMode;
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+pub fn reduce_replication(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match action {
+        // Catalog reads pin to the primary: slots and their lag live there.
+        Action::ShowReplicationOverview(table) => {
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let Some(query) = services.sql_dialect.build_replication_overview_sql(
+                state.session.active_database_type_or_default(),
+                table.as_deref(),
+            ) else {
+                state.messages.set_error_at(
+                    "Replication overview is only available for PostgreSQL".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            };
+            let endpoint = state.active_replica_dsn().map(|_| QueryEndpoint::Primary);
+            state.session.record_query_endpoint(endpoint);
+            let run_id = state.query.begin_running(now);
+            DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
+                dsn,
+                replica_dsn: None,
+                run_id,
+                query,
+                access_mode: AccessMode::ReadOnly,
+            }])
+        }
+
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::update::browse::query::dispatch_query;
+    use crate::update::test_fixtures;
+
+    fn show(state: &mut AppState, table: Option<&str>) -> DispatchResult {
+        dispatch_query(
+            state,
+            &Action::ShowReplicationOverview(table.map(String::from)),
+            Instant::now(),
+            &AppServices::stub(),
+        )
+    }
+
+    #[test]
+    fn runs_read_only_catalog_query_on_primary() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/app");
+
+        let effects = show(&mut state, Some("public.orders"))
+            .into_effects()
+            .unwrap();
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ExecuteAdhoc {
+                replica_dsn: None,
+                query,
+                access_mode: AccessMode::ReadOnly,
+                ..
+            }] if query == "REPLICATION public.orders"
+        ));
+        assert!(state.query.is_running());
+    }
+
+    #[test]
+    fn sqlite_reports_unsupported() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_sqlite_connection(&mut state, "sqlite:///tmp/app.db");
+
+        let effects = show(&mut state, None).into_effects().unwrap_or_default();
+
+        assert!(effects.is_empty());
+        assert_eq!(
+            state.messages.last_error(),
+            Some("Replication overview is only available for PostgreSQL")
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::ports::outbound::AccessMode;
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+pub fn reduce_sampling(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match action {
+        // Generated reads skip the execution policy but still follow routing.
+        Action::SampleTable(limit) => {
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let pagination = &state.query.pagination;
+            if !pagination.has_table() {
+                state
+                    .messages
+                    .set_error_at("Select a table first".to_string(), now);
+                return DispatchResult::handled();
+            }
+            let query = services.sql_dialect.build_sample_sql(
+                state.session.active_database_type_or_default(),
+                pagination.schema(),
+                pagination.table(),
+                *limit,
+                pagination.total_rows_estimate(),
+            );
+            let replica_dsn = state.route_adhoc_query(&query);
+            let run_id = state.query.begin_running(now);
+            DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
+                dsn,
+                replica_dsn,
+                run_id,
+                query,
+                access_mode: AccessMode::ReadOnly,
+            }])
+        }
+
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::update::browse::query::dispatch_query;
+    use crate::update::test_fixtures;
+
+    fn sample(state: &mut AppState, limit: usize) -> Vec<Effect> {
+        dispatch_query(
+            state,
+            &Action::SampleTable(limit),
+            Instant::now(),
+            &AppServices::stub(),
+        )
+        .into_effects()
+        .unwrap_or_default()
+    }
+
+    #[test]
+    fn runs_read_only_sample_of_the_previewed_table() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/app");
+        state
+            .query
+            .pagination
+            .reset_for_table_with_estimate("public", "orders", Some(50_000));
+
+        let effects = sample(&mut state, 25);
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ExecuteAdhoc {
+                query,
+                access_mode: AccessMode::ReadOnly,
+                ..
+            }] if query == "SAMPLE public.orders 25"
+        ));
+        assert!(state.query.is_running());
+    }
+
+    #[test]
+    fn without_a_table_reports_error() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/app");
+
+        let effects = sample(&mut state, 100);
+
+        assert!(effects.is_empty());
+        assert_eq!(state.messages.last_error(), Some("Select a table first"));
+    }
+}
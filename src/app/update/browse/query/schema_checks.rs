@@ -0,0 +1,347 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::ForeignKey;
+use crate::model::app_state::AppState;
+use crate::policy::sql::replica_routing::QueryEndpoint;
+use crate::ports::outbound::AccessMode;
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+pub fn reduce_schema_checks(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match action {
+        // Pinned to the primary too: replica lag would report rows whose
+        // parent was just inserted as orphans.
+        Action::CheckForeignKeyOrphans(name) => {
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let Some(table) = state.session.table_detail() else {
+                state
+                    .messages
+                    .set_error_at("Select a table first".to_string(), now);
+                return DispatchResult::handled();
+            };
+            let table_name = table.qualified_name();
+            let foreign_keys: Vec<ForeignKey> = table
+                .foreign_keys
+                .iter()
+                .filter(|fk| name.as_deref().is_none_or(|name| fk.name == name))
+                .filter(|fk| fk.is_reference_resolved() && !fk.from_columns.is_empty())
+                .cloned()
+                .collect();
+            if foreign_keys.is_empty() {
+                let message = match name {
+                    Some(name) => format!("No checkable foreign key named {name} on {table_name}"),
+                    None => format!("{table_name} has no checkable foreign keys"),
+                };
+                state.messages.set_error_at(message, now);
+                return DispatchResult::handled();
+            }
+            let Some(query) = services.sql_dialect.build_fk_orphan_check_sql(
+                state.session.active_database_type_or_default(),
+                &foreign_keys,
+            ) else {
+                return DispatchResult::handled();
+            };
+            let endpoint = state.active_replica_dsn().map(|_| QueryEndpoint::Primary);
+            state.session.record_query_endpoint(endpoint);
+            let run_id = state.query.begin_running(now);
+            DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
+                dsn,
+                replica_dsn: None,
+                run_id,
+                query,
+                access_mode: AccessMode::ReadOnly,
+            }])
+        }
+
+        Action::SuggestForeignKeys => {
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let Some(query) = services
+                .sql_dialect
+                .build_fk_suggestion_sql(state.session.active_database_type_or_default())
+            else {
+                state.messages.set_error_at(
+                    "Foreign key suggestions are only available for PostgreSQL".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            };
+            let endpoint = state.active_replica_dsn().map(|_| QueryEndpoint::Primary);
+            state.session.record_query_endpoint(endpoint);
+            let run_id = state.query.begin_running(now);
+            DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
+                dsn,
+                replica_dsn: None,
+                run_id,
+                query,
+                access_mode: AccessMode::ReadOnly,
+            }])
+        }
+
+        Action::AuditNaming => {
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let conventions = state.runtime.naming_conventions();
+            if !conventions.has_rules() {
+                state.messages.set_error_at(
+                    "Every naming rule is disabled in naming.toml".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            }
+            let Some(query) = services.sql_dialect.build_naming_audit_sql(
+                state.session.active_database_type_or_default(),
+                conventions,
+            ) else {
+                state.messages.set_error_at(
+                    "Naming audit is only available for PostgreSQL".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            };
+            let endpoint = state.active_replica_dsn().map(|_| QueryEndpoint::Primary);
+            state.session.record_query_endpoint(endpoint);
+            let run_id = state.query.begin_running(now);
+            DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
+                dsn,
+                replica_dsn: None,
+                run_id,
+                query,
+                access_mode: AccessMode::ReadOnly,
+            }])
+        }
+
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::update::browse::query::dispatch_query;
+
+    mod fk_orphan_check {
+        use super::*;
+        use crate::domain::FkAction;
+        use crate::test_support;
+        use crate::update::test_fixtures;
+        use rstest::rstest;
+
+        fn foreign_key(name: &str, resolved: bool) -> ForeignKey {
+            ForeignKey {
+                name: name.to_string(),
+                from_schema: "public".to_string(),
+                from_table: "orders".to_string(),
+                from_columns: vec![format!("{name}_id")],
+                to_schema: "public".to_string(),
+                to_table: name.to_string(),
+                to_columns: vec!["id".to_string()],
+                on_delete: FkAction::Cascade,
+                on_update: FkAction::NoAction,
+                reference_resolved: resolved,
+            }
+        }
+
+        fn state_with_orders(foreign_keys: Vec<ForeignKey>) -> AppState {
+            let mut state = AppState::new("test".to_string());
+            test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/app");
+            let mut table = test_support::table::minimal("public", "orders");
+            table.foreign_keys = foreign_keys;
+            let generation = state.session.selection_generation();
+            assert!(state.session.set_table_detail(table, generation));
+            state
+        }
+
+        fn check(state: &mut AppState, name: Option<&str>) -> Vec<Effect> {
+            dispatch_query(
+                state,
+                &Action::CheckForeignKeyOrphans(name.map(String::from)),
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .into_effects()
+            .unwrap_or_default()
+        }
+
+        #[test]
+        fn checks_every_resolved_fk_read_only() {
+            let mut state = state_with_orders(vec![
+                foreign_key("users", true),
+                foreign_key("legacy", false),
+                foreign_key("products", true),
+            ]);
+
+            let effects = check(&mut state, None);
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::ExecuteAdhoc {
+                    replica_dsn: None,
+                    query,
+                    access_mode: AccessMode::ReadOnly,
+                    ..
+                }] if query == "ORPHANS users,products"
+            ));
+            assert!(state.query.is_running());
+        }
+
+        #[test]
+        fn named_fk_limits_the_check() {
+            let mut state = state_with_orders(vec![
+                foreign_key("users", true),
+                foreign_key("products", true),
+            ]);
+
+            let effects = check(&mut state, Some("products"));
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::ExecuteAdhoc { query, .. }] if query == "ORPHANS products"
+            ));
+        }
+
+        #[rstest]
+        #[case(
+            Some("missing"),
+            "No checkable foreign key named missing on public.orders"
+        )]
+        #[case(None, "public.orders has no checkable foreign keys")]
+        fn reports_when_nothing_to_check(#[case] name: Option<&str>, #[case] expected: &str) {
+            let mut state = state_with_orders(vec![foreign_key("legacy", false)]);
+
+            let effects = check(&mut state, name);
+
+            assert!(effects.is_empty());
+            assert!(!state.query.is_running());
+            assert_eq!(state.messages.last_error(), Some(expected));
+        }
+
+        #[test]
+        fn requires_selected_table() {
+            let mut state = AppState::new("test".to_string());
+            test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/app");
+
+            let effects = check(&mut state, None);
+
+            assert!(effects.is_empty());
+            assert_eq!(state.messages.last_error(), Some("Select a table first"));
+        }
+    }
+
+    mod fk_suggestion {
+        use super::*;
+        use crate::update::test_fixtures;
+
+        fn suggest(state: &mut AppState) -> Vec<Effect> {
+            dispatch_query(
+                state,
+                &Action::SuggestForeignKeys,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .into_effects()
+            .unwrap_or_default()
+        }
+
+        #[test]
+        fn runs_read_only_catalog_query() {
+            let mut state = AppState::new("test".to_string());
+            test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/app");
+
+            let effects = suggest(&mut state);
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::ExecuteAdhoc {
+                    replica_dsn: None,
+                    query,
+                    access_mode: AccessMode::ReadOnly,
+                    ..
+                }] if query == "SUGGEST FKS"
+            ));
+            assert!(state.query.is_running());
+        }
+
+        #[test]
+        fn sqlite_reports_unsupported() {
+            let mut state = AppState::new("test".to_string());
+            test_fixtures::activate_sqlite_connection(&mut state, "sqlite:///tmp/app.db");
+
+            let effects = suggest(&mut state);
+
+            assert!(effects.is_empty());
+            assert_eq!(
+                state.messages.last_error(),
+                Some("Foreign key suggestions are only available for PostgreSQL")
+            );
+        }
+    }
+
+    mod naming_audit {
+        use super::*;
+        use crate::ports::outbound::NamingConventions;
+        use crate::update::test_fixtures;
+
+        fn audit(state: &mut AppState) -> Vec<Effect> {
+            dispatch_query(
+                state,
+                &Action::AuditNaming,
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .into_effects()
+            .unwrap_or_default()
+        }
+
+        #[test]
+        fn runs_read_only_catalog_query() {
+            let mut state = AppState::new("test".to_string());
+            test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/app");
+
+            let effects = audit(&mut state);
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::ExecuteAdhoc {
+                    replica_dsn: None,
+                    query,
+                    access_mode: AccessMode::ReadOnly,
+                    ..
+                }] if query == "AUDIT NAMING"
+            ));
+        }
+
+        #[test]
+        fn all_rules_disabled_reports_error() {
+            let mut state = AppState::new("test".to_string());
+            test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/app");
+            state.runtime.set_naming_conventions(NamingConventions {
+                snake_case: false,
+                reserved_words: false,
+                primary_key: None,
+                foreign_key: None,
+                index: None,
+            });
+
+            let effects = audit(&mut state);
+
+            assert!(effects.is_empty());
+            assert!(!state.query.is_running());
+            assert_eq!(
+                state.messages.last_error(),
+                Some("Every naming rule is disabled in naming.toml")
+            );
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::connection::ConnectionProfile;
+use crate::model::app_state::AppState;
+use crate::policy::server_info_report::server_info_report;
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+use super::execution::reset_view_for_new_result;
+
+pub fn reduce_server_info(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    _services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::ShowServerInfo => {
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                state
+                    .messages
+                    .set_error_at("No active connection".to_string(), now);
+                return DispatchResult::handled();
+            };
+            DispatchResult::handled_with(vec![Effect::LoadServerInfo {
+                dsn,
+                replica_dsn: state.active_replica_dsn().map(String::from),
+            }])
+        }
+
+        Action::ServerInfoLoaded {
+            dsn,
+            primary,
+            replica,
+        } => {
+            if !state.session.dsn_matches(dsn) {
+                return DispatchResult::handled();
+            }
+            let mut endpoints = vec![("primary", *primary)];
+            if state.active_replica_dsn().is_some() {
+                endpoints.push(("replica", *replica));
+            }
+            let report = server_info_report(
+                state.active_profile().and_then(ConnectionProfile::pool),
+                &endpoints,
+            );
+            reset_view_for_new_result(state, now);
+            state.query.set_current_result(Arc::new(report));
+            DispatchResult::handled()
+        }
+
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::outbound::PoolStats;
+    use crate::update::browse::query::dispatch_query;
+    use crate::update::test_fixtures;
+
+    const DSN: &str = "postgres://localhost/app";
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        dispatch_query(state, action, Instant::now(), &AppServices::stub())
+            .into_effects()
+            .unwrap()
+    }
+
+    #[test]
+    fn show_loads_counts_for_the_active_dsn() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, DSN);
+
+        let effects = dispatch(&mut state, &Action::ShowServerInfo);
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LoadServerInfo { dsn, replica_dsn: None }] if dsn == DSN
+        ));
+    }
+
+    #[test]
+    fn show_without_connection_reports_error() {
+        let mut state = AppState::new("test".to_string());
+
+        let effects = dispatch(&mut state, &Action::ShowServerInfo);
+
+        assert!(effects.is_empty());
+        assert_eq!(state.messages.last_error(), Some("No active connection"));
+    }
+
+    #[test]
+    fn loaded_counts_replace_the_visible_result() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, DSN);
+
+        dispatch(
+            &mut state,
+            &Action::ServerInfoLoaded {
+                dsn: DSN.to_string(),
+                primary: Some(PoolStats {
+                    in_use: 1,
+                    idle: 3,
+                    size: 4,
+                }),
+                replica: None,
+            },
+        );
+
+        let shown = state.query.visible_result().unwrap();
+        assert_eq!(shown.query, ":server info");
+        assert_eq!(shown.row_count(), 1);
+        assert_eq!(shown.display_row_at(0).unwrap()[2..4], ["1", "3"]);
+    }
+
+    #[test]
+    fn counts_for_another_connection_are_ignored() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, DSN);
+
+        dispatch(
+            &mut state,
+            &Action::ServerInfoLoaded {
+                dsn: "postgres://localhost/other".to_string(),
+                primary: None,
+                replica: None,
+            },
+        );
+
+        assert!(state.query.visible_result().is_none());
+    }
+}
@@ -0,0 +1,244 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::text_input::{TextInputEditing, TextInputState};
+use crate::services::AppServices;
+use crate::update::action::{Action, InputTarget, ListMotion, ListTarget, ModalKind};
+use crate::update::dispatch_result::DispatchResult;
+
+// Lives beside the query reducers rather than in `update::modal` because
+// opening the overlay needs the SQL dialect to build the pg_settings query.
+pub fn reduce_server_settings(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::OpenModal(ModalKind::ServerSettings) => {
+            if state.modal.active_mode() == InputMode::ServerSettings {
+                return DispatchResult::handled();
+            }
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            let Some(query) = services
+                .sql_dialect
+                .build_server_settings_sql(state.session.active_database_type_or_default())
+            else {
+                return DispatchResult::handled();
+            };
+            let run_id = state.server_settings.begin_fetch();
+            state.modal.push_mode(InputMode::ServerSettings);
+            DispatchResult::handled_with(vec![Effect::FetchServerSettings { dsn, run_id, query }])
+        }
+        Action::CloseModal(ModalKind::ServerSettings) => {
+            state.modal.pop_mode();
+            state.server_settings.clear();
+            DispatchResult::handled()
+        }
+        Action::ServerSettingsLoaded {
+            dsn,
+            run_id,
+            settings,
+        } => {
+            if state.session.dsn_matches(dsn) {
+                state.server_settings.set_loaded(*run_id, settings.clone());
+            }
+            DispatchResult::handled()
+        }
+        Action::ServerSettingsFailed { dsn, run_id, error } => {
+            if !state.session.dsn_matches(dsn) || !state.server_settings.is_current_run(*run_id) {
+                return DispatchResult::handled();
+            }
+            state.server_settings.set_failed(*run_id);
+            state.messages.set_error_at(error.user_message(), now);
+            DispatchResult::handled()
+        }
+        Action::TextInput {
+            target: InputTarget::ServerSettingsFilter,
+            ch,
+        } => {
+            state.server_settings.picker_mut().insert_filter_char(*ch);
+            DispatchResult::handled()
+        }
+        Action::TextBackspace {
+            target: InputTarget::ServerSettingsFilter,
+        } => {
+            state.server_settings.picker_mut().backspace_filter();
+            DispatchResult::handled()
+        }
+        Action::TextDelete {
+            target: InputTarget::ServerSettingsFilter,
+        } => {
+            state
+                .server_settings
+                .picker_mut()
+                .edit_filter(TextInputState::delete);
+            DispatchResult::handled()
+        }
+        Action::TextKill {
+            target: InputTarget::ServerSettingsFilter,
+            direction,
+        } => {
+            let killed = state
+                .server_settings
+                .picker_mut()
+                .edit_filter(|input| input.kill(*direction));
+            state.record_kill(killed);
+            DispatchResult::handled()
+        }
+        Action::TextYank {
+            target: InputTarget::ServerSettingsFilter,
+        } => {
+            if let Some(killed) = state.kill_buffer().map(str::to_owned) {
+                state
+                    .server_settings
+                    .picker_mut()
+                    .edit_filter(|input| input.yank(&killed));
+            }
+            DispatchResult::handled()
+        }
+        Action::TextMoveCursor {
+            target: InputTarget::ServerSettingsFilter,
+            direction,
+        } => {
+            state
+                .server_settings
+                .picker_mut()
+                .move_filter_cursor(*direction);
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::ServerSettings,
+            motion: ListMotion::Next,
+        } => {
+            state.server_settings.select_next();
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::ServerSettings,
+            motion: ListMotion::Previous,
+        } => {
+            state.server_settings.select_previous();
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ServerSetting;
+    use crate::ports::outbound::DbOperationError;
+    use crate::update::browse::query::dispatch_query;
+    use crate::update::test_fixtures;
+
+    const DSN: &str = "postgres://localhost/app";
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        dispatch_query(state, action, Instant::now(), &AppServices::stub())
+            .into_effects()
+            .unwrap()
+    }
+
+    fn open(state: &mut AppState) -> u64 {
+        let effects = dispatch(state, &Action::OpenModal(ModalKind::ServerSettings));
+        let [Effect::FetchServerSettings { run_id, query, .. }] = effects.as_slice() else {
+            panic!("expected FetchServerSettings, got {effects:?}");
+        };
+        assert_eq!(query, "SERVER SETTINGS");
+        *run_id
+    }
+
+    fn setting(name: &str) -> ServerSetting {
+        ServerSetting {
+            name: name.to_string(),
+            setting: "on".to_string(),
+            unit: None,
+            reset_val: "on".to_string(),
+            boot_val: "on".to_string(),
+            source: "default".to_string(),
+            category: "Autovacuum".to_string(),
+            short_desc: String::new(),
+            pending_restart: false,
+        }
+    }
+
+    #[test]
+    fn open_fetches_settings_and_enters_overlay() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, DSN);
+
+        let run_id = open(&mut state);
+        dispatch(
+            &mut state,
+            &Action::ServerSettingsLoaded {
+                dsn: DSN.to_string(),
+                run_id,
+                settings: vec![setting("autovacuum"), setting("work_mem")],
+            },
+        );
+
+        assert_eq!(state.modal.active_mode(), InputMode::ServerSettings);
+        assert!(!state.server_settings.is_loading());
+        assert_eq!(state.server_settings.entries().len(), 2);
+    }
+
+    #[test]
+    fn results_for_another_connection_are_ignored() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, DSN);
+        let run_id = open(&mut state);
+
+        dispatch(
+            &mut state,
+            &Action::ServerSettingsFailed {
+                dsn: "postgres://localhost/other".to_string(),
+                run_id,
+                error: DbOperationError::QueryFailed("boom".to_string()),
+            },
+        );
+
+        assert!(state.server_settings.is_loading());
+        assert!(state.messages.last_error().is_none());
+    }
+
+    #[test]
+    fn filter_input_resets_selection() {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, DSN);
+        let run_id = open(&mut state);
+        dispatch(
+            &mut state,
+            &Action::ServerSettingsLoaded {
+                dsn: DSN.to_string(),
+                run_id,
+                settings: vec![setting("autovacuum"), setting("work_mem")],
+            },
+        );
+        state.server_settings.picker_mut().set_pane_height(10);
+        dispatch(
+            &mut state,
+            &Action::ListSelect {
+                target: ListTarget::ServerSettings,
+                motion: ListMotion::Next,
+            },
+        );
+        assert_eq!(state.server_settings.clamped_selected(), 1);
+
+        dispatch(
+            &mut state,
+            &Action::TextInput {
+                target: InputTarget::ServerSettingsFilter,
+                ch: 'w',
+            },
+        );
+
+        assert_eq!(state.server_settings.picker().filter_input().content(), "w");
+        assert_eq!(state.server_settings.clamped_selected(), 0);
+    }
+}
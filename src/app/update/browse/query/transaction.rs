@@ -0,0 +1,208 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::connection::DatabaseType;
+use crate::model::app_state::AppState;
+use crate::ports::outbound::{AccessMode, TransactionEnd};
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+pub fn reduce_transaction(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    _services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::BeginTransaction => {
+            if state.session.in_transaction() {
+                state.messages.set_error_at(
+                    "A transaction is already open; :commit or :rollback it first".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            }
+            if state.session.active_database_type_or_default() != DatabaseType::PostgreSQL {
+                state.messages.set_error_at(
+                    "Transactions are only available for PostgreSQL".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            }
+            let Some(dsn) = state.session.dsn().map(String::from) else {
+                return DispatchResult::handled();
+            };
+            if state.session.is_offline() {
+                state.messages.set_error_at(
+                    "Transactions need a live connection; reload to reconnect".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            }
+            DispatchResult::handled_with(vec![Effect::BeginTransaction {
+                dsn,
+                access_mode: AccessMode::from_read_only(state.session.is_read_only()),
+            }])
+        }
+        // The adapter releases the held connection even when COMMIT fails,
+        // so the session forgets the transaction up front.
+        Action::EndTransaction(end) => {
+            let Some(dsn) = state.session.take_transaction() else {
+                state
+                    .messages
+                    .set_error_at("No transaction is open".to_string(), now);
+                return DispatchResult::handled();
+            };
+            DispatchResult::handled_with(vec![Effect::EndTransaction { dsn, end: *end }])
+        }
+        Action::TransactionBegun { dsn } => {
+            // The connection changed while BEGIN was in flight.
+            if state.session.dsn() != Some(dsn.as_str()) {
+                return DispatchResult::handled_with(vec![Effect::EndTransaction {
+                    dsn: dsn.clone(),
+                    end: TransactionEnd::Rollback,
+                }]);
+            }
+            state.session.begin_transaction(dsn);
+            state.messages.set_success_at(
+                "Transaction open: :commit or :rollback to finish".to_string(),
+                now,
+            );
+            DispatchResult::handled()
+        }
+        Action::TransactionEnded(end) => {
+            let message = match end {
+                TransactionEnd::Commit => "Transaction committed",
+                TransactionEnd::Rollback => "Transaction rolled back",
+            };
+            state.messages.set_success_at(message.to_string(), now);
+            DispatchResult::handled()
+        }
+        Action::TransactionBeginFailed(error) | Action::TransactionEndFailed { error, .. } => {
+            state.messages.set_error_at(error.user_message(), now);
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::outbound::DbOperationError;
+    use crate::update::browse::query::tests::create_test_state;
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        reduce_transaction(state, action, Instant::now(), &AppServices::stub())
+            .into_effects()
+            .unwrap()
+    }
+
+    #[test]
+    fn begin_opens_a_transaction_on_the_active_connection() {
+        let mut state = create_test_state();
+
+        let effects = dispatch(&mut state, &Action::BeginTransaction);
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::BeginTransaction {
+                dsn,
+                access_mode: AccessMode::ReadWrite,
+            }] if dsn == "postgres://localhost/test"
+        ));
+        assert!(!state.session.in_transaction());
+    }
+
+    #[test]
+    fn begun_transaction_shows_in_session() {
+        let mut state = create_test_state();
+
+        dispatch(
+            &mut state,
+            &Action::TransactionBegun {
+                dsn: "postgres://localhost/test".to_string(),
+            },
+        );
+
+        assert!(state.session.in_transaction());
+    }
+
+    #[test]
+    fn begin_while_open_reports_error() {
+        let mut state = create_test_state();
+        state.session.begin_transaction("postgres://localhost/test");
+
+        let effects = dispatch(&mut state, &Action::BeginTransaction);
+
+        assert!(effects.is_empty());
+        assert!(state.messages.last_error().is_some());
+    }
+
+    #[test]
+    fn transaction_begun_after_a_connection_switch_is_rolled_back() {
+        let mut state = create_test_state();
+
+        let effects = dispatch(
+            &mut state,
+            &Action::TransactionBegun {
+                dsn: "postgres://localhost/other".to_string(),
+            },
+        );
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::EndTransaction {
+                dsn,
+                end: TransactionEnd::Rollback,
+            }] if dsn == "postgres://localhost/other"
+        ));
+        assert!(!state.session.in_transaction());
+    }
+
+    #[test]
+    fn commit_ends_the_open_transaction() {
+        let mut state = create_test_state();
+        state.session.begin_transaction("postgres://localhost/test");
+
+        let effects = dispatch(&mut state, &Action::EndTransaction(TransactionEnd::Commit));
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::EndTransaction {
+                end: TransactionEnd::Commit,
+                ..
+            }]
+        ));
+        assert!(!state.session.in_transaction());
+    }
+
+    #[test]
+    fn rollback_without_transaction_reports_error() {
+        let mut state = create_test_state();
+
+        let effects = dispatch(
+            &mut state,
+            &Action::EndTransaction(TransactionEnd::Rollback),
+        );
+
+        assert!(effects.is_empty());
+        assert_eq!(state.messages.last_error(), Some("No transaction is open"));
+    }
+
+    #[test]
+    fn failed_commit_surfaces_the_error() {
+        let mut state = create_test_state();
+
+        dispatch(
+            &mut state,
+            &Action::TransactionEndFailed {
+                end: TransactionEnd::Commit,
+                error: DbOperationError::QueryFailed("current transaction is aborted".to_string()),
+            },
+        );
+
+        assert!(state.messages.last_error().is_some());
+    }
+}
@@ -214,9 +214,22 @@ pub fn reduce_write(
                 );
                 return DispatchResult::handled();
             }
-            state
-                .result_interaction
-                .set_write_preview((**preview).clone());
+            if let Err(violation) = state.apply_execution_policy(&preview.sql) {
+                state.messages.set_error_at(violation.to_string(), now);
+                return DispatchResult::handled();
+            }
+            let mut preview = (**preview).clone();
+            // Production raises the guardrail; deleting rows there is confirmed
+            // by typing the table name instead of a single Enter.
+            let production = state.active_is_production() && !preview.guardrail.blocked;
+            if production {
+                preview.guardrail.risk_level = match preview.operation {
+                    WriteOperation::Update => preview.guardrail.risk_level.max(RiskLevel::Medium),
+                    WriteOperation::Delete => RiskLevel::High,
+                };
+                preview.guardrail.reason = Some("Production connection".to_string());
+            }
+            state.result_interaction.set_write_preview(preview.clone());
             let operation = preview.operation;
             let title = match operation {
                 WriteOperation::Update => {
@@ -239,12 +252,17 @@ pub fn reduce_write(
 
             state.confirm_dialog.open(
                 title,
-                build_write_preview_fallback_message(preview),
+                build_write_preview_fallback_message(&preview),
                 ConfirmIntent::ExecuteWrite {
                     sql: preview.sql.clone(),
                     blocked: preview.guardrail.blocked,
                 },
             );
+            if production && preview.guardrail.risk_level == RiskLevel::High {
+                state
+                    .confirm_dialog
+                    .require_input(preview.target_summary.table);
+            }
             if matches!(operation, WriteOperation::Delete) {
                 state.modal.set_mode(InputMode::Normal);
             }
@@ -1302,6 +1320,29 @@ mod tests {
             );
         }
 
+        #[test]
+        fn production_delete_requires_typing_the_table_name() {
+            let mut state = create_test_state();
+            test_fixtures::activate_production_connection(&mut state, "postgres://prod");
+
+            dispatch_query(
+                &mut state,
+                &Action::OpenWritePreviewConfirm(Box::new(delete_preview())),
+                Instant::now(),
+                &AppServices::stub(),
+            )
+            .unwrap();
+
+            assert_eq!(state.confirm_dialog.required_input(), Some("users"));
+            assert_eq!(
+                state
+                    .result_interaction
+                    .pending_write_preview()
+                    .map(|preview| preview.guardrail.risk_level),
+                Some(RiskLevel::High)
+            );
+        }
+
         #[test]
         fn open_write_preview_confirm_for_delete_sets_refresh_target_count() {
             let mut state = create_test_state();
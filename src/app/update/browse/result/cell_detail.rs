@@ -6,6 +6,7 @@ use crate::model::browse::cell_detail::CellDetailState;
 use crate::model::shared::detail_view::DetailDisplayMode;
 use crate::model::shared::flash_timer::FlashId;
 use crate::model::shared::input_mode::InputMode;
+use crate::policy::geometry_preview::{geometry_preview_text, is_spatial_type, parse_ewkb_hex};
 use crate::policy::preview_cell_text::{CellPresentationPolicy, format_for_cell_detail};
 use crate::ports::outbound::ClipboardError;
 use crate::update::action::{Action, InputTarget, ModalKind, ScrollDirection, ScrollTarget};
@@ -47,6 +48,45 @@ pub fn reduce_cell_detail(state: &mut AppState, action: &Action, now: Instant) -
             state.modal.push_mode(InputMode::CellDetail);
             DispatchResult::handled()
         }
+        Action::ResultOpenGeometryPreview => {
+            let Some((row_idx, col_idx, column_name, cell_value, data_type)) =
+                selected_cell_value(state)
+            else {
+                state
+                    .messages
+                    .set_error_at("Select a geometry cell to preview".into(), now);
+                return DispatchResult::handled();
+            };
+            if data_type.as_deref().is_some_and(|t| !is_spatial_type(t)) {
+                state
+                    .messages
+                    .set_error_at(format!("{column_name} is not a geometry column"), now);
+                return DispatchResult::handled();
+            }
+            let Some(value) = parse_ewkb_hex(&cell_value) else {
+                state
+                    .messages
+                    .set_error_at("Not a PostGIS geometry value".into(), now);
+                return DispatchResult::handled();
+            };
+
+            let wkt = value.geometry.to_wkt();
+            state.cell_detail = CellDetailState::open(
+                row_idx,
+                col_idx,
+                column_name,
+                wkt.clone(),
+                geometry_preview_text(&value),
+            );
+            state.modal.push_mode(InputMode::CellDetail);
+            DispatchResult::handled_with(vec![Effect::CopyToClipboard {
+                content: wkt,
+                on_success: Some(Box::new(Action::CellDetailYankSuccess)),
+                on_failure: Some(Box::new(Action::CopyFailed(ClipboardError::Unavailable(
+                    "Clipboard unavailable".into(),
+                )))),
+            }])
+        }
         Action::CloseModal(ModalKind::CellDetail) => {
             state.cell_detail.close();
             state.modal.pop_mode();
@@ -173,7 +213,7 @@ fn selected_cell_uses_jsonb_detail_modal(state: &AppState) -> bool {
     policy.uses_jsonb_detail_modal()
 }
 
-fn selected_column_data_type(state: &AppState, col_idx: usize) -> Option<&str> {
+pub(super) fn selected_column_data_type(state: &AppState, col_idx: usize) -> Option<&str> {
     let td = state.session.table_detail()?;
     if !state.query.pagination.matches_table(td) {
         return None;
@@ -234,6 +274,59 @@ mod tests {
         state
     }
 
+    const POINT_EWKB: &str = "0101000020E6100000000000000000F03F0000000000000040";
+
+    #[test]
+    fn geometry_preview_opens_plot_and_copies_wkt() {
+        let mut state = state_with_cell("geometry(Point,4326)", POINT_EWKB);
+
+        let effects = reduce_cell_detail(
+            &mut state,
+            &Action::ResultOpenGeometryPreview,
+            Instant::now(),
+        )
+        .unwrap();
+
+        assert_eq!(state.input_mode(), InputMode::CellDetail);
+        assert_eq!(state.cell_detail.original_content(), "POINT(1 2)");
+        assert!(state.cell_detail.content().starts_with("POINT · SRID 4326"));
+        assert!(matches!(
+            &effects[0],
+            Effect::CopyToClipboard { content, .. } if content == "POINT(1 2)"
+        ));
+    }
+
+    #[test]
+    fn geometry_preview_rejects_non_spatial_column() {
+        let mut state = state_with_cell("text", POINT_EWKB);
+
+        let effects = reduce_cell_detail(
+            &mut state,
+            &Action::ResultOpenGeometryPreview,
+            Instant::now(),
+        )
+        .unwrap();
+
+        assert!(effects.is_empty());
+        assert!(state.messages.last_error.is_some());
+        assert!(!state.cell_detail.is_active());
+    }
+
+    #[test]
+    fn geometry_preview_rejects_unparseable_value() {
+        let mut state = state_with_cell("geometry", "not-ewkb");
+
+        let effects = reduce_cell_detail(
+            &mut state,
+            &Action::ResultOpenGeometryPreview,
+            Instant::now(),
+        )
+        .unwrap();
+
+        assert!(effects.is_empty());
+        assert!(state.messages.last_error.is_some());
+    }
+
     #[test]
     fn long_text_cell_opens_read_only_detail() {
         let mut state = state_with_cell("text", &"a".repeat(60));
@@ -0,0 +1,191 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::QuerySource;
+use crate::model::app_state::AppState;
+use crate::services::AppServices;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+/// Asks the server to describe the visible ad-hoc result while the type row
+/// is on. Previews need no fetch: their types come from table metadata.
+pub(in crate::update::browse) fn column_types_effect(
+    state: &mut AppState,
+    services: &AppServices,
+) -> Option<Effect> {
+    if !state.ui.shows_result_types() {
+        return None;
+    }
+    let result = state.query.visible_result()?;
+    if result.source != QuerySource::Adhoc || result.is_error() || result.columns.is_empty() {
+        return None;
+    }
+    let dsn = state.session.dsn()?.to_string();
+    let query = services.sql_dialect.build_result_types_sql(
+        state.session.active_database_type_or_default(),
+        &result.query,
+    )?;
+    let run_id = state.query.begin_column_types_fetch();
+    Some(Effect::FetchResultColumnTypes { dsn, run_id, query })
+}
+
+pub fn reduce_column_types(
+    state: &mut AppState,
+    action: &Action,
+    services: &AppServices,
+    now: Instant,
+) -> DispatchResult {
+    match action {
+        Action::ResultToggleColumnTypes => {
+            let msg = if state.ui.toggle_result_types() {
+                "Column types shown"
+            } else {
+                "Column types hidden"
+            };
+            state.messages.set_success_at(msg.to_string(), now);
+            if !state.query.column_types().is_empty() {
+                return DispatchResult::handled();
+            }
+            DispatchResult::handled_with(column_types_effect(state, services).into_iter().collect())
+        }
+        Action::ResultColumnTypesLoaded { dsn, run_id, types } => {
+            let column_count = state
+                .query
+                .visible_result()
+                .map_or(0, |result| result.columns.len());
+            // A count mismatch means the described statement is not the one
+            // shown (e.g. `$1` substitutions); blank beats misattributed.
+            if state.session.dsn_matches(dsn) && types.len() == column_count {
+                state.query.set_column_types(*run_id, types.clone());
+            }
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::connection::ConnectionId;
+    use crate::domain::{Column, ColumnAttributes, DatabaseType, QueryResult, Table};
+    use crate::test_support;
+
+    const DSN: &str = "postgres://localhost/test";
+
+    fn state_with_result(source: QuerySource) -> AppState {
+        let mut state = AppState::new("test".to_string());
+        state.session.activate_connection_with_dsn(
+            &ConnectionId::new(),
+            "database",
+            DatabaseType::PostgreSQL,
+            DSN,
+        );
+        state
+            .query
+            .set_current_result(Arc::new(QueryResult::success(
+                "SELECT id, body FROM notes".to_string(),
+                vec!["id".to_string(), "body".to_string()],
+                vec![vec!["42".to_string(), "42".to_string()]],
+                1,
+                source,
+            )));
+        state
+    }
+
+    fn toggle(state: &mut AppState) -> Vec<Effect> {
+        reduce_column_types(
+            state,
+            &Action::ResultToggleColumnTypes,
+            &AppServices::stub(),
+            Instant::now(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn preview_types_come_from_table_metadata() {
+        let mut state = state_with_result(QuerySource::Preview);
+        state.query.pagination.reset_for_table("public", "notes");
+        state.session.set_table_detail_raw(Some(Table {
+            schema: "public".to_string(),
+            name: "notes".to_string(),
+            columns: vec![
+                Column {
+                    attributes: ColumnAttributes::PRIMARY_KEY,
+                    ..test_support::column::test_nullable_column("id", "integer", 1)
+                },
+                test_support::column::test_nullable_column("body", "text", 2),
+            ],
+            ..test_support::table::minimal("", "")
+        }));
+
+        let effects = toggle(&mut state);
+
+        assert!(effects.is_empty());
+        assert!(state.ui.shows_result_types());
+        assert_eq!(
+            state.result_column_types(),
+            vec!["integer not null", "text"]
+        );
+    }
+
+    #[test]
+    fn adhoc_types_are_described_by_the_server() {
+        let mut state = state_with_result(QuerySource::Adhoc);
+
+        let effects = toggle(&mut state);
+        let [Effect::FetchResultColumnTypes { run_id, query, .. }] = effects.as_slice() else {
+            panic!("expected FetchResultColumnTypes, got {effects:?}");
+        };
+        assert_eq!(query, "RESULT TYPES SELECT id, body FROM notes");
+
+        reduce_column_types(
+            &mut state,
+            &Action::ResultColumnTypesLoaded {
+                dsn: DSN.to_string(),
+                run_id: *run_id,
+                types: vec!["integer".to_string(), "text".to_string()],
+            },
+            &AppServices::stub(),
+            Instant::now(),
+        );
+
+        assert_eq!(state.result_column_types(), vec!["integer", "text"]);
+    }
+
+    #[test]
+    fn mismatched_type_count_is_dropped() {
+        let mut state = state_with_result(QuerySource::Adhoc);
+        let effects = toggle(&mut state);
+        let [Effect::FetchResultColumnTypes { run_id, .. }] = effects.as_slice() else {
+            panic!("expected FetchResultColumnTypes, got {effects:?}");
+        };
+
+        reduce_column_types(
+            &mut state,
+            &Action::ResultColumnTypesLoaded {
+                dsn: DSN.to_string(),
+                run_id: *run_id,
+                types: vec!["integer".to_string()],
+            },
+            &AppServices::stub(),
+            Instant::now(),
+        );
+
+        assert!(state.result_column_types().is_empty());
+    }
+
+    #[test]
+    fn toggling_off_hides_without_fetching() {
+        let mut state = state_with_result(QuerySource::Adhoc);
+        toggle(&mut state);
+
+        let effects = toggle(&mut state);
+
+        assert!(effects.is_empty());
+        assert!(!state.ui.shows_result_types());
+    }
+}
@@ -0,0 +1,236 @@
+use std::time::Instant;
+
+use super::cell_detail::selected_column_data_type;
+use crate::domain::QueryValue;
+use crate::model::app_state::AppState;
+use crate::model::browse::result_column_format::ColumnFormat;
+use crate::policy::duration_format::DurationSource;
+use crate::policy::number_format::NumericStyle;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+pub fn reduce_format(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
+    match action {
+        Action::ResultToggleNumberFormat => {
+            let Some((col_idx, column)) = active_column(state, now) else {
+                return DispatchResult::handled();
+            };
+
+            let style = NumericStyle::for_data_type(selected_column_data_type(state, col_idx));
+            let msg = if state
+                .query
+                .toggle_column_format(&column, ColumnFormat::Number(style))
+            {
+                format!("Formatted numbers: {column}")
+            } else {
+                format!("Raw numbers: {column}")
+            };
+            state.messages.set_success_at(msg, now);
+            DispatchResult::handled()
+        }
+        Action::ResultToggleDiffOldValues => {
+            let msg = if state.query.toggle_diff_old_values() {
+                match state.query.result_diff() {
+                    Some(diff) if !diff.is_empty() => {
+                        format!("Old values shown ({} changed)", diff.changed_cell_count())
+                    }
+                    _ => "Old values shown (no changes since the last run)".to_string(),
+                }
+            } else {
+                "Old values hidden".to_string()
+            };
+            state.messages.set_success_at(msg, now);
+            DispatchResult::handled()
+        }
+        Action::ResultToggleDurationFormat => {
+            let Some((col_idx, column)) = active_column(state, now) else {
+                return DispatchResult::handled();
+            };
+
+            let sample = state.query.visible_result().and_then(|result| {
+                result.values().iter().find_map(|row| {
+                    row.get(col_idx)
+                        .filter(|value| !matches!(value, QueryValue::Null))
+                        .map(QueryValue::copy_value)
+                        .filter(|value| !value.is_empty())
+                })
+            });
+            let Some(source) = DurationSource::detect(
+                &column,
+                selected_column_data_type(state, col_idx),
+                sample.as_deref(),
+            ) else {
+                state
+                    .messages
+                    .set_error_at(format!("{column} does not look like a duration"), now);
+                return DispatchResult::handled();
+            };
+
+            let msg = if state
+                .query
+                .toggle_column_format(&column, ColumnFormat::Duration(source))
+            {
+                format!("Humanized durations: {column}")
+            } else {
+                format!("Raw durations: {column}")
+            };
+            state.messages.set_success_at(msg, now);
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+fn active_column(state: &mut AppState, now: Instant) -> Option<(usize, String)> {
+    let Some(col_idx) = state.result_interaction.selection().cell() else {
+        state
+            .messages
+            .set_error_at("Select a cell to format its column".into(), now);
+        return None;
+    };
+    let column = state
+        .query
+        .visible_result()
+        .filter(|result| !result.is_error())
+        .and_then(|result| result.columns.get(col_idx).cloned())?;
+    Some((col_idx, column))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+
+    fn state_with_amounts() -> AppState {
+        let mut state = AppState::new("test".to_string());
+        state
+            .query
+            .set_current_result(Arc::new(QueryResult::success(
+                String::new(),
+                vec!["id".to_string(), "amount".to_string()],
+                vec![vec!["1".to_string(), "1234567".to_string()]],
+                1,
+                QuerySource::Adhoc,
+            )));
+        state
+    }
+
+    #[test]
+    fn toggle_formats_active_column_then_restores_raw() {
+        let mut state = state_with_amounts();
+        state.result_interaction.activate_cell(0, 1);
+        let generation = state.query.result_generation();
+
+        reduce_format(
+            &mut state,
+            &Action::ResultToggleNumberFormat,
+            Instant::now(),
+        );
+
+        assert_eq!(
+            state.query.column_formats().get("amount"),
+            Some(ColumnFormat::Number(NumericStyle::General))
+        );
+        assert!(state.query.result_generation() > generation);
+
+        reduce_format(
+            &mut state,
+            &Action::ResultToggleNumberFormat,
+            Instant::now(),
+        );
+
+        assert_eq!(state.query.column_formats().get("amount"), None);
+    }
+
+    #[test]
+    fn diff_toggle_reports_changed_cell_count() {
+        let mut state = AppState::new("test".to_string());
+        for amount in ["10", "25"] {
+            state.query.set_rerun_result(Arc::new(QueryResult::success(
+                "SELECT id, amount FROM orders".to_string(),
+                vec!["id".to_string(), "amount".to_string()],
+                vec![vec!["1".to_string(), amount.to_string()]],
+                1,
+                QuerySource::Adhoc,
+            )));
+        }
+
+        reduce_format(
+            &mut state,
+            &Action::ResultToggleDiffOldValues,
+            Instant::now(),
+        );
+
+        assert!(state.query.shows_diff_old_values());
+        assert_eq!(
+            state.messages.last_success.as_deref(),
+            Some("Old values shown (1 changed)")
+        );
+    }
+
+    #[test]
+    fn duration_toggle_detects_unit_from_column_name() {
+        let mut state = AppState::new("test".to_string());
+        state
+            .query
+            .set_current_result(Arc::new(QueryResult::success(
+                String::new(),
+                vec!["elapsed_ms".to_string()],
+                vec![vec![String::new()], vec!["90000".to_string()]],
+                1,
+                QuerySource::Adhoc,
+            )));
+        state.result_interaction.activate_cell(0, 0);
+
+        reduce_format(
+            &mut state,
+            &Action::ResultToggleDurationFormat,
+            Instant::now(),
+        );
+
+        assert_eq!(
+            state.query.column_formats().get("elapsed_ms"),
+            Some(ColumnFormat::Duration(DurationSource::Millis))
+        );
+    }
+
+    #[test]
+    fn duration_toggle_rejects_text_column() {
+        let mut state = AppState::new("test".to_string());
+        state
+            .query
+            .set_current_result(Arc::new(QueryResult::success(
+                String::new(),
+                vec!["name".to_string()],
+                vec![vec!["alice".to_string()]],
+                1,
+                QuerySource::Adhoc,
+            )));
+        state.result_interaction.activate_cell(0, 0);
+
+        reduce_format(
+            &mut state,
+            &Action::ResultToggleDurationFormat,
+            Instant::now(),
+        );
+
+        assert!(state.messages.last_error.is_some());
+        assert_eq!(state.query.column_formats().get("name"), None);
+    }
+
+    #[test]
+    fn without_active_cell_sets_error() {
+        let mut state = state_with_amounts();
+
+        reduce_format(
+            &mut state,
+            &Action::ResultToggleNumberFormat,
+            Instant::now(),
+        );
+
+        assert!(state.messages.last_error.is_some());
+        assert_eq!(state.query.column_formats().get("amount"), None);
+    }
+}
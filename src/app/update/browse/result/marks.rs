@@ -0,0 +1,110 @@
+use std::time::Instant;
+
+use crate::model::app_state::AppState;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+use super::scroll::{ensure_row_visible, result_max_scroll};
+
+pub fn reduce_marks(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
+    match action {
+        Action::ResultToggleRowMark => {
+            if let Some(row) = state.result_interaction.selection().row() {
+                state.result_interaction.toggle_row_mark(row);
+            }
+            DispatchResult::handled()
+        }
+        Action::ResultNextRowMark | Action::ResultPrevRowMark => {
+            let selected = state.result_interaction.selection().row();
+            let from = selected.unwrap_or_else(|| state.result_interaction.scroll_offset());
+            let target = if matches!(action, Action::ResultNextRowMark) {
+                state.result_interaction.next_row_mark(from)
+            } else {
+                state.result_interaction.prev_row_mark(from)
+            };
+            let Some(target) = target else {
+                state
+                    .messages
+                    .set_error_at("No marked rows".to_string(), now);
+                return DispatchResult::handled();
+            };
+            if selected.is_some() {
+                state.result_interaction.move_row(target);
+                ensure_row_visible(state);
+            } else {
+                // Scroll mode has no active row, so the mark becomes the top row
+                state
+                    .result_interaction
+                    .set_scroll_offset(target.min(result_max_scroll(state)));
+            }
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+
+    fn state_with_rows(rows: usize, pane_height: u16) -> AppState {
+        let mut state = AppState::new("test".to_string());
+        state.ui.set_result_pane_height(pane_height);
+        state
+            .query
+            .set_current_result(Arc::new(QueryResult::success(
+                String::new(),
+                vec!["id".to_string()],
+                (0..rows).map(|r| vec![r.to_string()]).collect(),
+                1,
+                QuerySource::Adhoc,
+            )));
+        state
+    }
+
+    #[test]
+    fn marks_follow_the_active_row_and_jumps_scroll_it_into_view() {
+        let mut state = state_with_rows(100, 10);
+        for row in [3, 60] {
+            state.result_interaction.activate_cell(row, 0);
+            reduce_marks(&mut state, &Action::ResultToggleRowMark, Instant::now());
+        }
+        state.result_interaction.activate_cell(0, 0);
+
+        reduce_marks(&mut state, &Action::ResultNextRowMark, Instant::now());
+        assert_eq!(state.result_interaction.selection().row(), Some(3));
+
+        reduce_marks(&mut state, &Action::ResultNextRowMark, Instant::now());
+        assert_eq!(state.result_interaction.selection().row(), Some(60));
+        let offset = state.result_interaction.scroll_offset();
+        assert!(offset <= 60 && 60 < offset + state.result_visible_rows());
+
+        reduce_marks(&mut state, &Action::ResultPrevRowMark, Instant::now());
+        assert_eq!(state.result_interaction.selection().row(), Some(3));
+    }
+
+    #[test]
+    fn jump_in_scroll_mode_puts_the_mark_on_top() {
+        let mut state = state_with_rows(100, 10);
+        state.result_interaction.activate_cell(40, 0);
+        reduce_marks(&mut state, &Action::ResultToggleRowMark, Instant::now());
+        state.result_interaction.exit_cell_to_scroll();
+
+        reduce_marks(&mut state, &Action::ResultNextRowMark, Instant::now());
+
+        assert_eq!(state.result_interaction.scroll_offset(), 40);
+        assert!(state.result_interaction.selection().row().is_none());
+    }
+
+    #[test]
+    fn jump_without_marks_reports_it() {
+        let mut state = state_with_rows(5, 10);
+
+        reduce_marks(&mut state, &Action::ResultNextRowMark, Instant::now());
+
+        assert_eq!(state.messages.last_error(), Some("No marked rows"));
+    }
+}
@@ -0,0 +1,108 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::{AuditEvent, QuerySource};
+use crate::model::app_state::AppState;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+pub fn reduce_masking(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
+    match action {
+        // Every unmask is audited, not only on production profiles: the
+        // record is of who looked, not of what changed.
+        Action::ResultUnmask => {
+            let target = match state.query.visible_result() {
+                Some(result) if result.source == QuerySource::Preview => {
+                    state.query.pagination.qualified_name()
+                }
+                Some(result) => result.query.clone(),
+                None => String::new(),
+            };
+            let Some(columns) = state.query.unmask() else {
+                state
+                    .messages
+                    .set_error_at("No masked columns in this result".to_string(), now);
+                return DispatchResult::handled();
+            };
+            let columns = columns.join(", ");
+            state
+                .messages
+                .set_success_at(format!("Unmasked {columns}"), now);
+            DispatchResult::handled_with(vec![Effect::AppendAuditLog {
+                project_name: state.runtime.project_name().to_string(),
+                event: AuditEvent {
+                    connection: state
+                        .session
+                        .active_connection_name()
+                        .unwrap_or_default()
+                        .to_string(),
+                    operation: "UNMASK".to_string(),
+                    risk: "SENSITIVE".to_string(),
+                    statement: format!("{target} ({columns})"),
+                },
+            }])
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::QueryResult;
+
+    fn masked_state() -> AppState {
+        let mut state = AppState::new("test".to_string());
+        let original = Arc::new(QueryResult::success(
+            "SELECT * FROM users".to_string(),
+            vec!["id".to_string(), "password".to_string()],
+            vec![vec!["1".to_string(), "hunter2".to_string()]],
+            1,
+            QuerySource::Preview,
+        ));
+        state.query.pagination.reset_for_table("public", "users");
+        state.query.set_current_result(Arc::new(
+            QueryResult::clone(&original).with_masked_columns(&[1], "***"),
+        ));
+        state
+            .query
+            .hold_unmasked(original, vec!["password".to_string()]);
+        state
+    }
+
+    #[test]
+    fn unmask_reveals_values_and_appends_audit_entry() {
+        let mut state = masked_state();
+
+        let effects = reduce_masking(&mut state, &Action::ResultUnmask, Instant::now())
+            .into_effects()
+            .unwrap();
+
+        assert_eq!(
+            state.query.visible_result().unwrap().display_value_at(0, 1),
+            Some("hunter2".to_string())
+        );
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::AppendAuditLog { event, .. }]
+                if event.operation == "UNMASK" && event.statement == "public.users (password)"
+        ));
+    }
+
+    #[test]
+    fn unmask_without_masked_columns_reports_error() {
+        let mut state = AppState::new("test".to_string());
+
+        let effects = reduce_masking(&mut state, &Action::ResultUnmask, Instant::now())
+            .into_effects()
+            .unwrap();
+
+        assert!(effects.is_empty());
+        assert_eq!(
+            state.messages.last_error(),
+            Some("No masked columns in this result")
+        );
+    }
+}
@@ -1,9 +1,14 @@
 mod cell_detail;
+mod column_types;
 mod edit;
+mod format;
 mod jsonb;
+mod marks;
+mod masking;
 mod row_detail;
 mod scroll;
 mod selection;
+mod variable;
 mod yank;
 
 use std::time::Instant;
@@ -13,6 +18,8 @@ use crate::services::AppServices;
 use crate::update::action::Action;
 use crate::update::dispatch_result::DispatchResult;
 
+pub(super) use column_types::column_types_effect;
+
 pub fn dispatch_result(
     state: &mut AppState,
     action: &Action,
@@ -21,8 +28,13 @@ pub fn dispatch_result(
 ) -> DispatchResult {
     scroll::reduce_scroll(state, action)
         .or_else(|| selection::reduce_selection(state, action, now))
+        .or_else(|| marks::reduce_marks(state, action, now))
         .or_else(|| edit::reduce_edit(state, action, now))
         .or_else(|| yank::reduce_yank(state, action, services, now))
+        .or_else(|| format::reduce_format(state, action, now))
+        .or_else(|| masking::reduce_masking(state, action, now))
+        .or_else(|| column_types::reduce_column_types(state, action, services, now))
+        .or_else(|| variable::reduce_variable(state, action, now))
         .or_else(|| cell_detail::reduce_cell_detail(state, action, now))
         .or_else(|| jsonb::reduce_jsonb(state, action, now))
         .or_else(|| row_detail::reduce_row_detail(state, action, now))
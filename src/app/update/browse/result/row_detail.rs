@@ -11,6 +11,16 @@ use crate::update::dispatch_result::DispatchResult;
 
 pub fn reduce_row_detail(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
     match action {
+        Action::ResultPeekCell => {
+            if state.ui.result_active_cell_truncated()
+                && !state.result_interaction.cell_peek().is_visible(now)
+            {
+                state.result_interaction.pin_cell_peek();
+                return DispatchResult::handled();
+            }
+            reduce_row_detail(state, &Action::OpenModal(ModalKind::RowDetail), now)
+        }
+
         Action::OpenModal(ModalKind::RowDetail) => {
             let result = match state.query.visible_result() {
                 Some(r) if !r.is_error() && r.data_row_count() > 0 => r,
@@ -155,6 +165,8 @@ mod tests {
     use std::sync::Arc;
 
     use crate::domain::{QueryResult, QuerySource, QueryValue};
+    use crate::model::browse::result_column_format::ColumnFormat;
+    use crate::policy::duration_format::DurationSource;
 
     fn state_with_result() -> AppState {
         let mut state = AppState::new("test".to_string());
@@ -181,6 +193,41 @@ mod tests {
         state
     }
 
+    #[test]
+    fn peek_pins_tooltip_on_truncated_cell() {
+        let mut state = state_with_result();
+        let now = Instant::now();
+        state.result_interaction.sync_cell_peek(now);
+        state.ui.set_result_active_cell_truncated(true);
+
+        reduce_row_detail(&mut state, &Action::ResultPeekCell, now);
+
+        assert!(state.result_interaction.cell_peek().is_visible(now));
+        assert_eq!(state.modal.active_mode(), InputMode::Normal);
+    }
+
+    #[test]
+    fn peek_opens_row_detail_once_tooltip_is_up() {
+        let mut state = state_with_result();
+        let now = Instant::now();
+        state.result_interaction.sync_cell_peek(now);
+        state.ui.set_result_active_cell_truncated(true);
+        reduce_row_detail(&mut state, &Action::ResultPeekCell, now);
+
+        reduce_row_detail(&mut state, &Action::ResultPeekCell, now);
+
+        assert_eq!(state.modal.active_mode(), InputMode::RowDetail);
+    }
+
+    #[test]
+    fn peek_on_fitting_cell_opens_row_detail() {
+        let mut state = state_with_result();
+
+        reduce_row_detail(&mut state, &Action::ResultPeekCell, Instant::now());
+
+        assert_eq!(state.modal.active_mode(), InputMode::RowDetail);
+    }
+
     #[test]
     fn open_builds_row_detail() {
         let mut state = state_with_result();
@@ -204,6 +251,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn open_keeps_raw_values_for_formatted_columns() {
+        let mut state = AppState::new("test".to_string());
+        state
+            .query
+            .set_current_result(Arc::new(QueryResult::success(
+                "SELECT elapsed".to_string(),
+                vec!["elapsed".to_string()],
+                vec![vec!["187980".to_string()]],
+                1,
+                QuerySource::Adhoc,
+            )));
+        state
+            .query
+            .toggle_column_format("elapsed", ColumnFormat::Duration(DurationSource::Seconds));
+        state.result_interaction.activate_cell(0, 0);
+
+        reduce_row_detail(
+            &mut state,
+            &Action::OpenModal(ModalKind::RowDetail),
+            Instant::now(),
+        );
+
+        assert!(state.row_detail.content().contains("elapsed\n  187980"));
+    }
+
     #[test]
     fn open_builds_row_detail_from_typed_values() {
         let mut state = AppState::new("test".to_string());
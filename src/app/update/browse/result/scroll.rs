@@ -1,3 +1,4 @@
+use crate::cmd::effect::Effect;
 use crate::domain::QueryResult;
 use crate::model::app_state::AppState;
 use crate::model::shared::key_sequence::KeySequenceState;
@@ -23,7 +24,7 @@ pub(super) fn result_max_scroll(state: &AppState) -> usize {
     result_row_count(state).saturating_sub(visible)
 }
 
-fn ensure_row_visible(state: &mut AppState) {
+pub(super) fn ensure_row_visible(state: &mut AppState) {
     if let Some(row) = state.result_interaction.selection().row() {
         let visible = state.result_visible_rows();
         if visible == 0 {
@@ -77,6 +78,20 @@ fn move_result_row_and_scroll(state: &mut AppState, direction: ScrollDirection,
     scroll_result_by(state, direction, delta);
 }
 
+/// A full-page scroll past the loaded rows turns the preview page instead.
+fn at_page_edge(state: &AppState, direction: ScrollDirection) -> bool {
+    let offset = state.result_interaction.scroll_offset();
+    let row = state.result_interaction.selection().row();
+    match direction {
+        ScrollDirection::Down => {
+            state.query.pagination.can_next()
+                && offset >= result_max_scroll(state)
+                && row.is_none_or(|row| row + 1 >= result_row_count(state))
+        }
+        _ => state.query.pagination.can_prev() && offset == 0 && row.is_none_or(|row| row == 0),
+    }
+}
+
 pub fn reduce_scroll(state: &mut AppState, action: &Action) -> DispatchResult {
     match action {
         Action::Scroll {
@@ -184,6 +199,17 @@ pub fn reduce_scroll(state: &mut AppState, action: &Action) -> DispatchResult {
             }
             DispatchResult::handled()
         }
+        Action::Scroll {
+            target: ScrollTarget::Result,
+            direction: direction @ (ScrollDirection::Down | ScrollDirection::Up),
+            amount: ScrollAmount::FullPage,
+        } if state.query.can_paginate_visible_result() && at_page_edge(state, *direction) => {
+            let turn = match direction {
+                ScrollDirection::Down => Action::ResultNextPage,
+                _ => Action::ResultPrevPage,
+            };
+            DispatchResult::handled_with(vec![Effect::DispatchActions(vec![turn])])
+        }
         Action::Scroll {
             target: ScrollTarget::Result,
             direction: direction @ (ScrollDirection::Down | ScrollDirection::Up),
@@ -689,6 +715,60 @@ mod tests {
         }
     }
 
+    mod page_turn {
+        use super::*;
+
+        fn full_page(state: &mut AppState, direction: ScrollDirection) -> Vec<Effect> {
+            reduce_scroll(
+                state,
+                &Action::Scroll {
+                    target: ScrollTarget::Result,
+                    direction,
+                    amount: ScrollAmount::FullPage,
+                },
+            )
+            .into_effects()
+            .unwrap()
+        }
+
+        #[test]
+        fn page_down_at_the_last_row_fetches_the_next_page() {
+            let mut state = state_with_result_rows(100, 25);
+            state.result_interaction.activate_cell(99, 0);
+            state.result_interaction.set_scroll_offset(80);
+
+            let effects = full_page(&mut state, ScrollDirection::Down);
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::DispatchActions(actions)] if matches!(actions.as_slice(), [Action::ResultNextPage])
+            ));
+        }
+
+        #[test]
+        fn page_up_at_the_top_of_a_later_page_fetches_the_previous_one() {
+            let mut state = state_with_result_rows(100, 25);
+            state.query.pagination.set_current_page(2);
+
+            let effects = full_page(&mut state, ScrollDirection::Up);
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::DispatchActions(actions)] if matches!(actions.as_slice(), [Action::ResultPrevPage])
+            ));
+        }
+
+        #[test]
+        fn page_up_on_the_first_page_stays_put() {
+            let mut state = state_with_result_rows(100, 25);
+
+            let effects = full_page(&mut state, ScrollDirection::Up);
+
+            assert!(effects.is_empty());
+            assert_eq!(state.result_interaction.scroll_offset(), 0);
+        }
+    }
+
     mod result_scroll_to_cursor {
         use super::*;
 
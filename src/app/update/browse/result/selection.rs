@@ -42,6 +42,18 @@ pub fn reduce_selection(state: &mut AppState, action: &Action, now: Instant) ->
             state.result_interaction.exit_cell_to_scroll();
             DispatchResult::handled()
         }
+        Action::ResultToggleRange => {
+            if state.result_interaction.selection().range().is_some() {
+                state.result_interaction.clear_range();
+            } else {
+                state.result_interaction.start_range();
+            }
+            DispatchResult::handled()
+        }
+        Action::ResultClearRange => {
+            state.result_interaction.clear_range();
+            DispatchResult::handled()
+        }
         Action::ResultCellLeft => {
             if let Some(c) = state.result_interaction.selection().cell()
                 && c > 0
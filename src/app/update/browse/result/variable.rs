@@ -0,0 +1,153 @@
+use std::time::Instant;
+
+use crate::domain::QueryValue;
+use crate::model::app_state::AppState;
+use crate::policy::sql::variables::is_valid_variable_name;
+use crate::update::action::Action;
+use crate::update::dispatch_result::DispatchResult;
+
+const MESSAGE_VALUE_MAX_CHARS: usize = 40;
+
+pub fn reduce_variable(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
+    match action {
+        Action::ResultBindVariable { name, value } => {
+            if !is_valid_variable_name(name) {
+                state
+                    .messages
+                    .set_error_at(format!("Invalid variable name: {name}"), now);
+                return DispatchResult::handled();
+            }
+
+            let Some(value) = value.clone().or_else(|| selected_cell_value(state)) else {
+                state
+                    .messages
+                    .set_error_at(format!("Select a cell to bind :{name}"), now);
+                return DispatchResult::handled();
+            };
+
+            let shown = if value.chars().count() > MESSAGE_VALUE_MAX_CHARS {
+                let head: String = value.chars().take(MESSAGE_VALUE_MAX_CHARS).collect();
+                format!("{head}…")
+            } else {
+                value.clone()
+            };
+            state.sql_modal.set_variable(name.clone(), value);
+            state
+                .messages
+                .set_success_at(format!("Set :{name} = {shown}"), now);
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+fn selected_cell_value(state: &AppState) -> Option<String> {
+    let selection = state.result_interaction.selection();
+    let (row_idx, col_idx) = (selection.row()?, selection.cell()?);
+    let result = state.query.visible_result()?;
+    if result.has_typed_values() {
+        result
+            .value_at(row_idx, col_idx)
+            .map(QueryValue::copy_value)
+    } else {
+        result.display_value_at(row_idx, col_idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+
+    fn state_with_orders() -> AppState {
+        let mut state = AppState::new("test".to_string());
+        state
+            .query
+            .set_current_result(Arc::new(QueryResult::success(
+                String::new(),
+                vec!["order_id".to_string(), "status".to_string()],
+                vec![
+                    vec!["41".to_string(), "paid".to_string()],
+                    vec!["42".to_string(), "open".to_string()],
+                ],
+                1,
+                QuerySource::Adhoc,
+            )));
+        state
+    }
+
+    fn bind(name: &str, value: Option<&str>) -> Action {
+        Action::ResultBindVariable {
+            name: name.to_string(),
+            value: value.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn binds_selected_cell_value() {
+        let mut state = state_with_orders();
+        state.result_interaction.activate_cell(1, 0);
+
+        reduce_variable(&mut state, &bind("id", None), Instant::now());
+
+        assert_eq!(
+            state.sql_modal.variables().get("id").map(String::as_str),
+            Some("42")
+        );
+        assert!(state.messages.last_error.is_none());
+    }
+
+    #[test]
+    fn explicit_value_does_not_need_selection() {
+        let mut state = state_with_orders();
+
+        reduce_variable(&mut state, &bind("status", Some("open")), Instant::now());
+
+        assert_eq!(
+            state
+                .sql_modal
+                .variables()
+                .get("status")
+                .map(String::as_str),
+            Some("open")
+        );
+    }
+
+    #[test]
+    fn without_selection_sets_error() {
+        let mut state = state_with_orders();
+
+        reduce_variable(&mut state, &bind("id", None), Instant::now());
+
+        assert!(state.messages.last_error.is_some());
+        assert!(state.sql_modal.variables().is_empty());
+    }
+
+    #[test]
+    fn invalid_name_sets_error() {
+        let mut state = state_with_orders();
+
+        reduce_variable(&mut state, &bind("1id", Some("42")), Instant::now());
+
+        assert!(state.messages.last_error.is_some());
+        assert!(state.sql_modal.variables().is_empty());
+    }
+
+    #[test]
+    fn bound_variable_is_substituted_into_submitted_query() {
+        let mut state = state_with_orders();
+        state.result_interaction.activate_cell(1, 0);
+        reduce_variable(&mut state, &bind("id", None), Instant::now());
+        state
+            .sql_modal
+            .editor_mut_for_input()
+            .set_content("SELECT * FROM items WHERE order_id = :id\n".to_string());
+
+        assert_eq!(
+            state.sql_modal.submitted_query(),
+            "SELECT * FROM items WHERE order_id = 42"
+        );
+    }
+}
@@ -2,10 +2,16 @@ use std::time::{Duration, Instant};
 
 use crate::cmd::effect::Effect;
 use crate::domain::QueryValue;
+use crate::i18n::Message;
 use crate::model::app_state::AppState;
+use crate::model::shared::confirm_dialog::ConfirmIntent;
 use crate::model::shared::flash_timer::FlashId;
+use crate::model::shared::input_mode::InputMode;
 use crate::model::shared::inspector_tab::InspectorTab;
 use crate::model::shared::ui_state::YankFlash;
+use crate::policy::result_copy::{
+    ResultCopyPlan, format_byte_size, render_result_copy, render_result_rows, result_copy_plan,
+};
 use crate::ports::outbound::ClipboardError;
 use crate::services::AppServices;
 use crate::update::action::Action;
@@ -86,11 +92,7 @@ pub fn reduce_yank(
                     })
                     .map(|row| {
                         row.iter()
-                            .map(|v| {
-                                v.replace('\\', "\\\\")
-                                    .replace('\t', "\\t")
-                                    .replace('\n', "\\n")
-                            })
+                            .map(|v| tsv_escape(v))
                             .collect::<Vec<_>>()
                             .join("\t")
                     });
@@ -110,6 +112,50 @@ pub fn reduce_yank(
                 DispatchResult::handled()
             }
         }
+        Action::ResultRangeYank => {
+            let Some(range) = state.result_interaction.selection().range() else {
+                return DispatchResult::handled();
+            };
+            let content = state.query.visible_result().and_then(|result| {
+                (range.rows.0..=range.rows.1)
+                    .map(|row| {
+                        (range.cols.0..=range.cols.1)
+                            .map(|col| {
+                                if result.has_typed_values() {
+                                    result.value_at(row, col).map(QueryValue::copy_value)
+                                } else {
+                                    result.display_value_at(row, col)
+                                }
+                                .map(|v| tsv_escape(&v))
+                            })
+                            .collect::<Option<Vec<_>>>()
+                            .map(|cells| cells.join("\t"))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            });
+            if let Some(lines) = content {
+                DispatchResult::handled_with(vec![Effect::CopyToClipboard {
+                    content: lines.join("\n"),
+                    on_success: Some(Box::new(Action::ResultRangeYankSuccess {
+                        rows: range.row_count(),
+                        cols: range.col_count(),
+                    })),
+                    on_failure: Some(Box::new(clipboard_unavailable())),
+                }])
+            } else {
+                state
+                    .messages
+                    .set_error_at("Selection out of bounds".into(), now);
+                DispatchResult::handled()
+            }
+        }
+        Action::ResultRangeYankSuccess { rows, cols } => {
+            state.result_interaction.clear_range();
+            state
+                .messages
+                .set_success_at(format!("Copied {rows}×{cols} cells (TSV)"), now);
+            DispatchResult::handled()
+        }
         Action::ResultCellYankSuccess { row, col } => {
             state.result_interaction.set_yank_flash(Some(YankFlash {
                 row: *row,
@@ -130,6 +176,115 @@ pub fn reduce_yank(
             state.flash_timers.set(FlashId::Ddl, now);
             DispatchResult::handled()
         }
+        Action::ResultCopyAll(format) => {
+            let format = *format;
+            let Some((content, row_count)) = state
+                .query
+                .visible_result()
+                .filter(|result| !result.is_error() && !result.columns.is_empty())
+                .map(|result| (render_result_copy(result, format), result.data_row_count()))
+            else {
+                state.messages.set_error_at("No result to copy".into(), now);
+                return DispatchResult::handled();
+            };
+
+            match result_copy_plan(content.len()) {
+                ResultCopyPlan::Clipboard => {
+                    DispatchResult::handled_with(vec![Effect::CopyToClipboard {
+                        content,
+                        on_success: Some(Box::new(Action::ResultCopyAllCopied {
+                            format,
+                            row_count,
+                        })),
+                        on_failure: None,
+                    }])
+                }
+                ResultCopyPlan::ConfirmLarge => {
+                    let msg = format!(
+                        "Copy {row_count} rows ({}) as {} to the clipboard?",
+                        format_byte_size(content.len()),
+                        format.label()
+                    );
+                    state.confirm_dialog.open(
+                        Message::ConfirmCopyTitle.text(state.settings.locale()),
+                        msg,
+                        ConfirmIntent::CopyLargeResult {
+                            content,
+                            format,
+                            row_count,
+                        },
+                    );
+                    state.modal.push_mode(InputMode::ConfirmDialog);
+                    DispatchResult::handled()
+                }
+                ResultCopyPlan::SpillToFile => {
+                    DispatchResult::handled_with(vec![Effect::CopyViaTempFile {
+                        file_name: format!("sabiql_result.{}", format.file_extension()),
+                        content,
+                        row_count,
+                    }])
+                }
+            }
+        }
+        Action::ResultCopyRows(format) => {
+            let format = *format;
+            // Marks win over the active row, which wins over the viewport
+            let marked = state.result_interaction.marked_rows();
+            let rows: Vec<usize> = if !marked.is_empty() {
+                marked.iter().copied().collect()
+            } else if let Some(row) = state.result_interaction.selection().row() {
+                vec![row]
+            } else {
+                let start = state.result_interaction.scroll_offset();
+                (start..start + state.result_visible_rows()).collect()
+            };
+            let Some((content, row_count)) = state
+                .query
+                .visible_result()
+                .filter(|result| !result.is_error() && !result.columns.is_empty())
+                .map(|result| {
+                    let row_count = rows
+                        .iter()
+                        .filter(|&&row| row < result.data_row_count())
+                        .count();
+                    (render_result_rows(result, format, rows), row_count)
+                })
+                .filter(|(_, row_count)| *row_count > 0)
+            else {
+                state.messages.set_error_at("No rows to copy".into(), now);
+                return DispatchResult::handled();
+            };
+
+            DispatchResult::handled_with(vec![Effect::CopyToClipboard {
+                content,
+                on_success: Some(Box::new(Action::ResultCopyAllCopied { format, row_count })),
+                on_failure: Some(Box::new(clipboard_unavailable())),
+            }])
+        }
+        Action::ResultCopyAllCopied { format, row_count } => {
+            state.messages.set_success_at(
+                format!("Copied {row_count} rows as {}", format.label()),
+                now,
+            );
+            DispatchResult::handled()
+        }
+        Action::ResultCopyAllSpilled {
+            path,
+            row_count,
+            path_copied,
+        } => {
+            let msg = if *path_copied {
+                format!("Too large for clipboard: wrote {row_count} rows to {path} (path copied)")
+            } else {
+                format!("Too large for clipboard: wrote {row_count} rows to {path}")
+            };
+            state.messages.set_success_at(msg, now);
+            DispatchResult::handled()
+        }
+        Action::ResultCopyAllSpillFailed(e) => {
+            state.messages.set_error_at(e.to_string(), now);
+            DispatchResult::handled()
+        }
         Action::CopyFailed(e) => {
             state.messages.set_error_at(e.to_string(), now);
             DispatchResult::handled()
@@ -138,6 +293,13 @@ pub fn reduce_yank(
     }
 }
 
+fn tsv_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
 fn clipboard_unavailable() -> Action {
     Action::CopyFailed(ClipboardError::Unavailable("Clipboard unavailable".into()))
 }
@@ -494,6 +656,306 @@ mod tests {
         }
     }
 
+    mod range_yank {
+        use super::*;
+
+        fn state_with_grid() -> AppState {
+            let mut state = AppState::new("test".to_string());
+            state
+                .query
+                .set_current_result(Arc::new(QueryResult::success(
+                    String::new(),
+                    vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                    vec![
+                        vec!["r0a".to_string(), "r0b".to_string(), "r0c".to_string()],
+                        vec!["r1a".to_string(), "r1\tb".to_string(), "r1c".to_string()],
+                        vec!["r2a".to_string(), "r2b".to_string(), "r2c".to_string()],
+                    ],
+                    1,
+                    QuerySource::Preview,
+                )));
+            state
+        }
+
+        #[test]
+        fn copies_selected_block_as_tsv() {
+            let mut state = state_with_grid();
+            state.result_interaction.activate_cell(2, 2);
+            state.result_interaction.start_range();
+            state.result_interaction.move_row(1);
+            state.result_interaction.move_cell(1);
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultRangeYank,
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .unwrap();
+
+            match &effects[0] {
+                Effect::CopyToClipboard {
+                    content,
+                    on_success,
+                    ..
+                } => {
+                    assert_eq!(content, "r1\\tb\tr1c\nr2b\tr2c");
+                    assert!(matches!(
+                        on_success.as_deref(),
+                        Some(Action::ResultRangeYankSuccess { rows: 2, cols: 2 })
+                    ));
+                }
+                other => panic!("expected CopyToClipboard, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn without_range_is_noop() {
+            let mut state = state_with_grid();
+            state.result_interaction.activate_cell(0, 0);
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultRangeYank,
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .unwrap();
+
+            assert!(effects.is_empty());
+        }
+
+        #[test]
+        fn success_clears_range_and_reports_size() {
+            let mut state = state_with_grid();
+            state.result_interaction.activate_cell(0, 0);
+            state.result_interaction.start_range();
+
+            reduce_yank(
+                &mut state,
+                &Action::ResultRangeYankSuccess { rows: 3, cols: 2 },
+                &AppServices::stub(),
+                Instant::now(),
+            );
+
+            assert!(state.result_interaction.selection().range().is_none());
+            assert_eq!(
+                state.messages.last_success.as_deref(),
+                Some("Copied 3×2 cells (TSV)")
+            );
+        }
+    }
+
+    mod copy_all {
+        use super::*;
+        use crate::policy::result_copy::{
+            CLIPBOARD_SAFE_BYTES, CLIPBOARD_WARN_BYTES, ResultCopyFormat,
+        };
+
+        fn state_with_cell(value: String) -> AppState {
+            let mut state = AppState::new("test".to_string());
+            state
+                .query
+                .set_current_result(Arc::new(QueryResult::success(
+                    String::new(),
+                    vec!["v".to_string()],
+                    vec![vec![value]],
+                    1,
+                    QuerySource::Adhoc,
+                )));
+            state
+        }
+
+        #[test]
+        fn small_result_copies_directly() {
+            let mut state = state_with_cell("a,b".to_string());
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultCopyAll(ResultCopyFormat::Csv),
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .into_effects()
+            .unwrap();
+
+            match &effects[0] {
+                Effect::CopyToClipboard {
+                    content,
+                    on_success,
+                    ..
+                } => {
+                    assert_eq!(content, "v\n\"a,b\"\n");
+                    assert!(matches!(
+                        on_success.as_deref(),
+                        Some(Action::ResultCopyAllCopied { row_count: 1, .. })
+                    ));
+                }
+                other => panic!("expected CopyToClipboard, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn large_result_asks_for_confirmation() {
+            let mut state = state_with_cell("x".repeat(CLIPBOARD_WARN_BYTES + 1));
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultCopyAll(ResultCopyFormat::Tsv),
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .into_effects()
+            .unwrap();
+
+            assert!(effects.is_empty());
+            assert_eq!(state.input_mode(), InputMode::ConfirmDialog);
+            assert!(matches!(
+                state.confirm_dialog.intent(),
+                Some(ConfirmIntent::CopyLargeResult { row_count: 1, .. })
+            ));
+        }
+
+        #[test]
+        fn oversized_result_spills_to_temp_file() {
+            let mut state = state_with_cell("x".repeat(CLIPBOARD_SAFE_BYTES + 1));
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultCopyAll(ResultCopyFormat::Markdown),
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .into_effects()
+            .unwrap();
+
+            assert!(matches!(
+                &effects[0],
+                Effect::CopyViaTempFile { file_name, row_count: 1, .. } if file_name == "sabiql_result.md"
+            ));
+        }
+
+        #[test]
+        fn without_result_sets_error() {
+            let mut state = AppState::new("test".to_string());
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultCopyAll(ResultCopyFormat::Csv),
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .into_effects()
+            .unwrap();
+
+            assert!(effects.is_empty());
+            assert!(state.messages.last_error.is_some());
+        }
+    }
+
+    mod copy_rows {
+        use super::*;
+        use crate::model::shared::ui_state::RESULT_PANE_OVERHEAD;
+        use crate::policy::result_copy::ResultCopyFormat;
+
+        fn state_with_rows(count: usize) -> AppState {
+            let mut state = AppState::new("test".to_string());
+            state
+                .query
+                .set_current_result(Arc::new(QueryResult::success(
+                    String::new(),
+                    vec!["id".to_string()],
+                    (0..count).map(|i| vec![i.to_string()]).collect(),
+                    1,
+                    QuerySource::Preview,
+                )));
+            state.ui.set_result_pane_height(RESULT_PANE_OVERHEAD + 2);
+            state
+        }
+
+        fn copied_content(effects: &[Effect]) -> &str {
+            match &effects[0] {
+                Effect::CopyToClipboard { content, .. } => content,
+                other => panic!("expected CopyToClipboard, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn without_selection_copies_visible_rows() {
+            let mut state = state_with_rows(5);
+            state.result_interaction.set_scroll_offset(1);
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultCopyRows(ResultCopyFormat::Markdown),
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                copied_content(&effects),
+                "| id  |\n|-----|\n| 1   |\n| 2   |\n"
+            );
+        }
+
+        #[test]
+        fn with_selection_copies_selected_row_only() {
+            let mut state = state_with_rows(5);
+            state.result_interaction.activate_cell(3, 0);
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultCopyRows(ResultCopyFormat::Org),
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .unwrap();
+
+            assert_eq!(copied_content(&effects), "| id |\n|----|\n| 3  |\n");
+            assert!(matches!(
+                &effects[0],
+                Effect::CopyToClipboard { on_success, .. }
+                    if matches!(on_success.as_deref(), Some(Action::ResultCopyAllCopied { row_count: 1, .. }))
+            ));
+        }
+
+        #[test]
+        fn marked_rows_win_over_the_active_row() {
+            let mut state = state_with_rows(5);
+            for row in [4, 1] {
+                state.result_interaction.toggle_row_mark(row);
+            }
+            state.result_interaction.activate_cell(3, 0);
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultCopyRows(ResultCopyFormat::Csv),
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .unwrap();
+
+            assert_eq!(copied_content(&effects), "id\n1\n4\n");
+        }
+
+        #[test]
+        fn empty_result_sets_error() {
+            let mut state = state_with_rows(0);
+
+            let effects = reduce_yank(
+                &mut state,
+                &Action::ResultCopyRows(ResultCopyFormat::Markdown),
+                &AppServices::stub(),
+                Instant::now(),
+            )
+            .unwrap();
+
+            assert!(effects.is_empty());
+            assert!(state.messages.last_error.is_some());
+        }
+    }
+
     mod ddl_yank {
         use crate::test_support;
 
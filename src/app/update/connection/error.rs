@@ -72,6 +72,25 @@ pub(super) fn reduce_connection_error(
             state.modal.replace_mode(InputMode::ConnectionSetup);
             DispatchResult::handled()
         }
+        Action::EnterOfflineMode => {
+            if !state.session.enter_offline() {
+                state
+                    .messages
+                    .set_error_at("No cached metadata for this connection".to_string(), now);
+                return DispatchResult::handled();
+            }
+            state.connection_error.clear();
+            state.modal.set_mode(InputMode::Normal);
+            let has_tables = !state.session.tables().is_empty();
+            state
+                .ui
+                .set_explorer_selection(if has_tables { Some(0) } else { None });
+            state.messages.set_success_at(
+                "Offline: browsing cached metadata, queries disabled".to_string(),
+                now,
+            );
+            DispatchResult::handled()
+        }
         Action::RetryConnection => {
             if let Some(dsn) = state.session.dsn().map(String::from) {
                 state.connection_error.clear();
@@ -175,4 +194,66 @@ mod tests {
             assert_eq!(state.input_mode(), InputMode::ConnectionSetup);
         }
     }
+
+    mod enter_offline_mode {
+        use super::*;
+        use std::sync::Arc;
+
+        use crate::domain::{DatabaseMetadata, MetadataSnapshot, TableSummary};
+        use crate::model::connection::error::ConnectionErrorInfo;
+
+        fn failed_state() -> AppState {
+            let mut state = AppState::new("test".to_string());
+            test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/db");
+            let _ = state.session.begin_connecting("postgres://localhost/db");
+            state
+                .session
+                .mark_connection_failed("unreachable".to_string());
+            state
+                .connection_error
+                .set_error(ConnectionErrorInfo::new("unreachable"));
+            state.modal.set_mode(InputMode::ConnectionError);
+            state
+        }
+
+        #[test]
+        fn without_snapshot_stays_on_error() {
+            let mut state = failed_state();
+            let now = Instant::now();
+
+            reduce_connection_error(&mut state, &Action::EnterOfflineMode, now);
+
+            assert_eq!(state.input_mode(), InputMode::ConnectionError);
+            assert!(!state.session.is_offline());
+            assert_eq!(
+                state.messages.last_error(),
+                Some("No cached metadata for this connection")
+            );
+        }
+
+        #[test]
+        fn with_snapshot_opens_cached_explorer() {
+            let mut state = failed_state();
+            let mut metadata = DatabaseMetadata::new("db".to_string());
+            metadata.table_summaries = vec![TableSummary::new(
+                "public".to_string(),
+                "users".to_string(),
+                None,
+                false,
+            )];
+            state
+                .session
+                .offer_offline_snapshot(Arc::new(MetadataSnapshot {
+                    metadata,
+                    tables: Vec::new(),
+                }));
+
+            reduce_connection_error(&mut state, &Action::EnterOfflineMode, Instant::now());
+
+            assert!(state.session.is_offline());
+            assert_eq!(state.input_mode(), InputMode::Normal);
+            assert!(!state.connection_error.has_error());
+            assert_eq!(state.ui.explorer_selected(), 0);
+        }
+    }
 }
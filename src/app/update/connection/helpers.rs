@@ -3,17 +3,34 @@ use crate::domain::connection::{ConnectionId, DatabaseType};
 use crate::model::app_state::AppState;
 use crate::model::connection::cache::ConnectionCache;
 use crate::model::shared::inspector_tab::InspectorTab;
+use crate::ports::outbound::TransactionEnd;
 use crate::update::action::ConnectionTarget;
 use crate::update::query_context::termination_effects;
 
+/// Rolls back the `:begin` transaction held on the connection being left;
+/// the adapter would otherwise keep its locks until sabiql exits.
+pub(super) fn rollback_open_transaction(state: &mut AppState) -> Option<Effect> {
+    state
+        .session
+        .take_transaction()
+        .map(|dsn| Effect::EndTransaction {
+            dsn,
+            end: TransactionEnd::Rollback,
+        })
+}
+
 fn reset_connection_scoped_state(state: &mut AppState) {
     state.sql_modal.reset_prefetch();
     state.explain.reset_for_connection_change();
     state.er_preparation.reset();
     state.ui.reset_er_picker_request();
+    state.ui.clear_explorer_marked_tables();
+    state.explorer_tree.set_schema_filter(None);
     state.ui.set_inspector_scroll_offset(0);
     state.ui.set_inspector_horizontal_offset(0);
+    state.ui.reset_inspector_column_focus();
     state.sqlite_diagnostics.clear();
+    state.column_distribution.clear();
 }
 
 fn reconcile_connection_state(state: &mut AppState, inspector_tab: InspectorTab) {
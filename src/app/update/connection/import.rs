@@ -0,0 +1,331 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::domain::connection::{ConnectionId, ConnectionProfile};
+use crate::model::app_state::AppState;
+use crate::model::connection::import::CandidateDiscovery;
+use crate::model::connection::setup::ConnectionSetupState;
+use crate::model::shared::input_mode::InputMode;
+use crate::ports::outbound::ConnectionCandidate;
+use crate::services::AppServices;
+use crate::update::action::{Action, ConnectionTarget, ListMotion, ListTarget, ModalKind};
+use crate::update::dispatch_result::DispatchResult;
+
+const CONTAINER_ID_PREFIX: &str = "container:";
+
+pub fn reduce_connection_import(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+    services: &AppServices,
+) -> DispatchResult {
+    match action {
+        Action::OpenModal(ModalKind::ConnectionImport) => {
+            if state.modal.active_mode() == InputMode::ConnectionImport {
+                return DispatchResult::handled();
+            }
+            state
+                .connection_import
+                .begin_scan(CandidateDiscovery::ProjectFiles);
+            state.modal.push_mode(InputMode::ConnectionImport);
+            DispatchResult::handled_with(vec![Effect::ScanConnectionCandidates {
+                project_root: state.runtime.project_root().map(ToOwned::to_owned),
+            }])
+        }
+        Action::OpenContainerDiscovery => {
+            if state.modal.active_mode() == InputMode::ConnectionImport {
+                return DispatchResult::handled();
+            }
+            state
+                .connection_import
+                .begin_scan(CandidateDiscovery::Containers);
+            state.modal.push_mode(InputMode::ConnectionImport);
+            DispatchResult::handled_with(vec![Effect::ScanContainerDatabases])
+        }
+        Action::CloseModal(ModalKind::ConnectionImport) => {
+            state.modal.pop_mode();
+            state.connection_import.clear();
+            DispatchResult::handled()
+        }
+        Action::ConnectionCandidatesLoaded(candidates) => {
+            if state.modal.active_mode() == InputMode::ConnectionImport {
+                state.connection_import.set_loaded(candidates.clone());
+            }
+            DispatchResult::handled()
+        }
+        Action::ConnectionCandidateScanFailed(e) => {
+            if state.modal.active_mode() == InputMode::ConnectionImport {
+                state.connection_import.set_failed(e.to_string());
+            }
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::ConnectionImport,
+            motion,
+        } => {
+            match motion {
+                ListMotion::Next => state.connection_import.select_next(),
+                ListMotion::Previous => state.connection_import.select_previous(),
+            }
+            DispatchResult::handled()
+        }
+        Action::ConnectionImportConfirm
+            if state.connection_import.discovery() == CandidateDiscovery::Containers =>
+        {
+            let Some(candidate) = state.connection_import.selected_candidate() else {
+                return DispatchResult::handled();
+            };
+            match container_target(candidate, services) {
+                Ok(target) => {
+                    state.modal.pop_mode();
+                    state.connection_import.clear();
+                    DispatchResult::handled_with(vec![Effect::DispatchActions(vec![
+                        Action::SwitchConnection(target),
+                    ])])
+                }
+                Err(message) => {
+                    state.messages.set_error_at(message, now);
+                    DispatchResult::handled()
+                }
+            }
+        }
+        // Both go through the setup form so validation and duplicate-name
+        // errors surface where they can be fixed.
+        Action::ConnectionImportConfirm | Action::ConnectionImportEdit => {
+            let Some(candidate) = state.connection_import.selected_candidate() else {
+                return DispatchResult::handled();
+            };
+            state.connection_setup = ConnectionSetupState::from_candidate(candidate);
+            state.connection_import.clear();
+            state.modal.set_mode(InputMode::ConnectionSetup);
+            if matches!(action, Action::ConnectionImportConfirm) {
+                DispatchResult::handled_with(vec![Effect::DispatchActions(vec![
+                    Action::ConnectionSetupSave,
+                ])])
+            } else {
+                DispatchResult::handled()
+            }
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+/// Containers are connected to without saving a profile; the id is derived
+/// from the container name so its caches survive a reconnect.
+fn container_target(
+    candidate: &ConnectionCandidate,
+    services: &AppServices,
+) -> Result<ConnectionTarget, String> {
+    let profile = ConnectionProfile::with_id_and_config(
+        ConnectionId::from_string(format!("{CONTAINER_ID_PREFIX}{}", candidate.name)),
+        candidate.name.clone(),
+        candidate.config.clone(),
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(ConnectionTarget {
+        dsn: services.dsn_builder.build_dsn(&profile),
+        name: profile.display_name().to_string(),
+        database_type: profile.database_type(),
+        id: profile.id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::domain::connection::{ConnectionConfig, PostgresConnectionConfig, SslMode};
+    use crate::model::connection::setup::ConnectionField;
+    use crate::ports::outbound::{ContainerScanError, ImportSource};
+
+    fn reduce(state: &mut AppState, action: &Action) -> DispatchResult {
+        reduce_connection_import(state, action, Instant::now(), &AppServices::stub())
+    }
+
+    fn candidate(name: &str) -> ConnectionCandidate {
+        ConnectionCandidate {
+            name: name.to_string(),
+            source: ImportSource::DockerCompose,
+            config: ConnectionConfig::PostgreSQL(PostgresConnectionConfig::new(
+                "localhost",
+                5433,
+                "app",
+                "app",
+                "secret",
+                SslMode::Prefer,
+            )),
+        }
+    }
+
+    fn open_with(state: &mut AppState, names: &[&str]) -> Vec<Effect> {
+        let effects = reduce(state, &Action::OpenModal(ModalKind::ConnectionImport))
+            .into_effects()
+            .unwrap();
+        reduce(
+            state,
+            &Action::ConnectionCandidatesLoaded(names.iter().map(|name| candidate(name)).collect()),
+        );
+        effects
+    }
+
+    #[test]
+    fn open_scans_from_the_project_root() {
+        let mut state = AppState::new("test".to_string());
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+
+        let effects = open_with(&mut state, &["db"]);
+
+        assert_eq!(state.input_mode(), InputMode::ConnectionImport);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ScanConnectionCandidates { project_root: Some(root) }]
+                if root == &PathBuf::from("/work/app")
+        ));
+        assert_eq!(state.connection_import.candidates().len(), 1);
+    }
+
+    #[test]
+    fn late_results_after_close_are_ignored() {
+        let mut state = AppState::new("test".to_string());
+        reduce(&mut state, &Action::OpenModal(ModalKind::ConnectionImport));
+        reduce(&mut state, &Action::CloseModal(ModalKind::ConnectionImport));
+
+        reduce(
+            &mut state,
+            &Action::ConnectionCandidatesLoaded(vec![candidate("db")]),
+        );
+
+        assert_eq!(state.input_mode(), InputMode::Normal);
+        assert!(state.connection_import.candidates().is_empty());
+    }
+
+    #[test]
+    fn confirm_saves_the_selected_candidate_through_the_setup_form() {
+        let mut state = AppState::new("test".to_string());
+        open_with(&mut state, &["db", "replica"]);
+        reduce(
+            &mut state,
+            &Action::ListSelect {
+                target: ListTarget::ConnectionImport,
+                motion: ListMotion::Next,
+            },
+        );
+
+        let effects = reduce(&mut state, &Action::ConnectionImportConfirm)
+            .into_effects()
+            .unwrap();
+
+        assert_eq!(state.input_mode(), InputMode::ConnectionSetup);
+        assert_eq!(
+            state.connection_setup.field_value(ConnectionField::Name),
+            "replica"
+        );
+        assert_eq!(
+            state.connection_setup.field_value(ConnectionField::Port),
+            "5433"
+        );
+        assert!(state.connection_setup.editing_id().is_none());
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchActions(actions)]
+                if matches!(actions.as_slice(), [Action::ConnectionSetupSave])
+        ));
+    }
+
+    #[test]
+    fn edit_opens_the_form_without_saving() {
+        let mut state = AppState::new("test".to_string());
+        open_with(&mut state, &["db"]);
+
+        let effects = reduce(&mut state, &Action::ConnectionImportEdit)
+            .into_effects()
+            .unwrap();
+
+        assert_eq!(state.input_mode(), InputMode::ConnectionSetup);
+        assert!(effects.is_empty());
+    }
+
+    fn open_containers_with(state: &mut AppState, names: &[&str]) -> Vec<Effect> {
+        let effects = reduce(state, &Action::OpenContainerDiscovery)
+            .into_effects()
+            .unwrap();
+        reduce(
+            state,
+            &Action::ConnectionCandidatesLoaded(names.iter().map(|name| candidate(name)).collect()),
+        );
+        effects
+    }
+
+    #[test]
+    fn container_discovery_scans_the_engine_socket() {
+        let mut state = AppState::new("test".to_string());
+
+        let effects = open_containers_with(&mut state, &["shop-db"]);
+
+        assert_eq!(state.input_mode(), InputMode::ConnectionImport);
+        assert_eq!(
+            state.connection_import.discovery(),
+            CandidateDiscovery::Containers
+        );
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ScanContainerDatabases]
+        ));
+    }
+
+    #[test]
+    fn container_confirm_connects_without_saving() {
+        let mut state = AppState::new("test".to_string());
+        open_containers_with(&mut state, &["shop-db"]);
+
+        let effects = reduce(&mut state, &Action::ConnectionImportConfirm)
+            .into_effects()
+            .unwrap();
+
+        assert_eq!(state.input_mode(), InputMode::Normal);
+        assert!(state.connection_import.candidates().is_empty());
+        let [Effect::DispatchActions(actions)] = effects.as_slice() else {
+            panic!("expected a dispatched switch, got {effects:?}");
+        };
+        let [Action::SwitchConnection(target)] = actions.as_slice() else {
+            panic!("expected SwitchConnection, got {actions:?}");
+        };
+        assert_eq!(target.id, ConnectionId::from_string("container:shop-db"));
+        assert_eq!(target.name, "shop-db");
+        assert_eq!(target.dsn, "stub-dsn");
+    }
+
+    #[test]
+    fn container_with_an_invalid_profile_name_stays_in_the_picker() {
+        let mut state = AppState::new("test".to_string());
+        let long_name = "x".repeat(80);
+        open_containers_with(&mut state, &[long_name.as_str()]);
+
+        let effects = reduce(&mut state, &Action::ConnectionImportConfirm)
+            .into_effects()
+            .unwrap();
+
+        assert!(effects.is_empty());
+        assert_eq!(state.input_mode(), InputMode::ConnectionImport);
+        assert_eq!(state.connection_import.candidates().len(), 1);
+        assert!(state.messages.last_error().is_some());
+    }
+
+    #[test]
+    fn scan_failure_is_shown_in_the_picker() {
+        let mut state = AppState::new("test".to_string());
+        reduce(&mut state, &Action::OpenContainerDiscovery);
+
+        reduce(
+            &mut state,
+            &Action::ConnectionCandidateScanFailed(ContainerScanError::SocketNotFound),
+        );
+
+        assert!(!state.connection_import.is_loading());
+        assert_eq!(
+            state.connection_import.error(),
+            Some("No Docker or Podman socket found (set DOCKER_HOST to point at one)")
+        );
+    }
+}
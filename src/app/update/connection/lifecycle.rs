@@ -7,7 +7,9 @@ use crate::update::query_context::termination_effects;
 
 use crate::update::dispatch_result::DispatchResult;
 
-use super::helpers::{reset_for_new_connection, restore_cache, save_current_cache};
+use super::helpers::{
+    reset_for_new_connection, restore_cache, rollback_open_transaction, save_current_cache,
+};
 
 pub fn reduce_connection_lifecycle(
     state: &mut AppState,
@@ -38,6 +40,7 @@ pub fn reduce_connection_lifecycle(
                 name,
                 database_type,
             } = target;
+            let rollback = rollback_open_transaction(state);
 
             if let Some(current_id) = state.session.active_connection_id().cloned() {
                 let cache = save_current_cache(state);
@@ -46,7 +49,8 @@ pub fn reduce_connection_lifecycle(
 
             if let Some(cached) = state.connection_caches.get(id).cloned() {
                 restore_cache(state, &cached, target);
-                let mut effects = vec![Effect::ClearCompletionEngineCache];
+                let mut effects: Vec<Effect> = rollback.into_iter().collect();
+                effects.push(Effect::ClearCompletionEngineCache);
                 if state.session.effective_user().is_none() {
                     let run_id = state.session.begin_effective_user_fetch();
                     effects.push(Effect::FetchEffectiveUser {
@@ -59,16 +63,17 @@ pub fn reduce_connection_lifecycle(
                 // No cache: reset and fetch metadata
                 reset_for_new_connection(state, id, dsn, name, *database_type);
                 let run_id = state.session.begin_connecting(dsn);
-                DispatchResult::handled_with(termination_effects(
-                    &state.query,
-                    vec![
+                let effects = rollback
+                    .into_iter()
+                    .chain([
                         Effect::ClearCompletionEngineCache,
                         Effect::FetchMetadata {
                             dsn: dsn.clone(),
                             run_id,
                         },
-                    ],
-                ))
+                    ])
+                    .collect();
+                DispatchResult::handled_with(termination_effects(&state.query, effects))
             }
         }
 
@@ -86,6 +91,7 @@ mod tests {
     use crate::model::er_state::ErStatus;
     use crate::model::shared::inspector_tab::InspectorTab;
     use crate::model::shared::ui_state::ResultNavMode;
+    use crate::ports::outbound::TransactionEnd;
     use crate::test_support::connection::{
         assert_explain_state_cleared, assert_sqlite_diagnostics_cleared,
     };
@@ -109,6 +115,35 @@ mod tests {
         })
     }
 
+    #[test]
+    fn switching_rolls_back_the_open_transaction() {
+        let mut state = AppState::new("test".to_string());
+        state.session.activate_connection_with_dsn(
+            &ConnectionId::new(),
+            "current",
+            DatabaseType::PostgreSQL,
+            "postgres://localhost/current",
+        );
+        state
+            .session
+            .begin_transaction("postgres://localhost/current");
+
+        let effects = reduce(
+            &mut state,
+            &create_switch_action(&ConnectionId::new(), "new_db"),
+        )
+        .unwrap();
+
+        assert!(effects.iter().any(|effect| matches!(
+            effect,
+            Effect::EndTransaction {
+                dsn,
+                end: TransactionEnd::Rollback,
+            } if dsn == "postgres://localhost/current"
+        )));
+        assert!(!state.session.in_transaction());
+    }
+
     mod cache_tests {
         use super::*;
 
@@ -206,6 +241,7 @@ mod tests {
             let dsn = match database_type {
                 DatabaseType::PostgreSQL => format!("postgres://localhost/{name}"),
                 DatabaseType::SQLite => format!("sqlite:///tmp/{name}.db"),
+                DatabaseType::MySQL => format!("mysql://localhost/{name}"),
             };
             Action::SwitchConnection(ConnectionTarget {
                 id,
@@ -1,7 +1,9 @@
 mod error;
 mod helpers;
+mod import;
 mod lifecycle;
 mod selector;
+mod session_settings;
 mod setup;
 
 use std::time::Instant;
@@ -21,6 +23,8 @@ pub fn dispatch_connection(
         .or_else(|| setup::reduce_connection_setup(state, action, now))
         .or_else(|| error::reduce_connection_error(state, action, now))
         .or_else(|| selector::reduce_connection_selector(state, action, now))
+        .or_else(|| session_settings::reduce_session_settings(state, action, now))
+        .or_else(|| import::reduce_connection_import(state, action, now, services))
 }
 
 #[cfg(test)]
@@ -1,11 +1,14 @@
 use std::time::Instant;
 
 use crate::cmd::effect::Effect;
+use crate::i18n::Message;
 use crate::model::app_state::AppState;
 use crate::model::shared::confirm_dialog::ConfirmIntent;
 use crate::model::shared::input_mode::InputMode;
 use crate::update::action::{Action, ModalKind};
-use crate::update::connection::helpers::reset_active_connection_state;
+use crate::update::connection::helpers::{
+    reset_active_connection_state, rollback_open_transaction,
+};
 use crate::update::dispatch_result::DispatchResult;
 use crate::update::query_context::termination_effects;
 
@@ -42,7 +45,7 @@ pub(super) fn reduce_connection_selector(
                     format!("Delete \"{name}\"?\n\nThis action cannot be undone.")
                 };
                 state.confirm_dialog.open(
-                    "Delete Connection",
+                    Message::DeleteConnectionTitle.text(state.settings.locale()),
                     message,
                     ConfirmIntent::DeleteConnection(id),
                 );
@@ -55,9 +58,13 @@ pub(super) fn reduce_connection_selector(
         }
         Action::ConnectionDeleted(id) => {
             let was_active = state.session.active_connection_id() == Some(id);
-            if was_active {
+            let rollback = if was_active {
+                let rollback = rollback_open_transaction(state);
                 reset_active_connection_state(state);
-            }
+                rollback
+            } else {
+                None
+            };
 
             let id_clone = id.clone();
             state.retain_connections(move |c| c.id != id_clone);
@@ -78,7 +85,7 @@ pub(super) fn reduce_connection_selector(
                 .messages
                 .set_success_at("Connection deleted".to_string(), now);
             DispatchResult::handled_with(if was_active {
-                termination_effects(&state.query, vec![])
+                termination_effects(&state.query, rollback.into_iter().collect())
             } else {
                 vec![]
             })
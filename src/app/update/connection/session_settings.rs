@@ -0,0 +1,293 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::model::connection::session_settings::SESSION_SETTINGS_INPUT_VISIBLE_WIDTH;
+use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::text_input::{TextInputEditing, TextInputState};
+use crate::update::action::{Action, ConnectionTarget, InputTarget, ModalKind};
+use crate::update::connection::helpers::{
+    connection_save_fetch_effects, reset_for_new_connection, rollback_open_transaction,
+};
+use crate::update::dispatch_result::DispatchResult;
+
+pub fn reduce_session_settings(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+) -> DispatchResult {
+    match action {
+        Action::OpenModal(ModalKind::SessionSettings) => {
+            let Some((id, settings)) = state.active_profile().and_then(|profile| {
+                profile
+                    .session_settings()
+                    .map(|settings| (profile.id.clone(), settings.clone()))
+            }) else {
+                state.messages.set_error_at(
+                    "Session settings need a saved PostgreSQL connection profile".to_string(),
+                    now,
+                );
+                return DispatchResult::handled();
+            };
+            state.session_settings_form.open(id, &settings);
+            state.modal.push_mode(InputMode::SessionSettings);
+            DispatchResult::handled()
+        }
+        Action::CloseModal(ModalKind::SessionSettings) => {
+            state.modal.pop_mode();
+            state.session_settings_form.clear();
+            DispatchResult::handled()
+        }
+        Action::Paste(text) if state.modal.active_mode() == InputMode::SessionSettings => {
+            let clean: String = text.chars().filter(|c| *c != '\n' && *c != '\r').collect();
+            edit_focused(state, |input| input.insert_str(&clean));
+            DispatchResult::handled()
+        }
+        Action::TextInput {
+            target: InputTarget::SessionSettings,
+            ch,
+        } => {
+            edit_focused(state, |input| input.insert_char(*ch));
+            DispatchResult::handled()
+        }
+        Action::TextBackspace {
+            target: InputTarget::SessionSettings,
+        } => {
+            edit_focused(state, TextInputState::backspace);
+            DispatchResult::handled()
+        }
+        Action::TextDelete {
+            target: InputTarget::SessionSettings,
+        } => {
+            edit_focused(state, TextInputState::delete);
+            DispatchResult::handled()
+        }
+        Action::TextKill {
+            target: InputTarget::SessionSettings,
+            direction,
+        } => {
+            let mut killed = String::new();
+            edit_focused(state, |input| killed = input.kill(*direction));
+            state.record_kill(killed);
+            DispatchResult::handled()
+        }
+        Action::TextYank {
+            target: InputTarget::SessionSettings,
+        } => {
+            if let Some(killed) = state.kill_buffer().map(str::to_owned) {
+                edit_focused(state, |input| input.yank(&killed));
+            }
+            DispatchResult::handled()
+        }
+        Action::TextMoveCursor {
+            target: InputTarget::SessionSettings,
+            direction,
+        } => {
+            edit_focused(state, |input| input.move_cursor(*direction));
+            DispatchResult::handled()
+        }
+        Action::SessionSettingsNextField => {
+            state.session_settings_form.focus_next();
+            DispatchResult::handled()
+        }
+        Action::SessionSettingsPrevField => {
+            state.session_settings_form.focus_previous();
+            DispatchResult::handled()
+        }
+        Action::SessionSettingsSave => {
+            let profile = state
+                .session_settings_form
+                .profile_id()
+                .and_then(|id| state.connections().iter().find(|p| &p.id == id))
+                .cloned();
+            let settings = state.session_settings_form.to_settings();
+            state.modal.pop_mode();
+            state.session_settings_form.clear();
+            let Some(profile) = profile else {
+                return DispatchResult::handled();
+            };
+            if profile.session_settings == settings {
+                return DispatchResult::handled();
+            }
+            DispatchResult::handled_with(vec![Effect::SaveSessionSettings {
+                profile: Box::new(profile.with_session_settings(settings)),
+            }])
+        }
+        Action::SessionSettingsSaved { profile, target } => {
+            let ConnectionTarget {
+                id,
+                dsn,
+                name,
+                database_type,
+            } = target;
+            state.replace_connection((**profile).clone());
+            if state.session.active_connection_id() != Some(id) {
+                return DispatchResult::handled();
+            }
+            // The overrides ride on the DSN, so every cached result belongs
+            // to the old session and the connection starts over.
+            state.connection_caches.remove(id);
+            let rollback = rollback_open_transaction(state);
+            reset_for_new_connection(state, id, dsn, name, *database_type);
+            let run_id = state.session.begin_connecting(dsn);
+            let mut effects: Vec<Effect> = rollback.into_iter().collect();
+            effects.extend(connection_save_fetch_effects(
+                state,
+                dsn,
+                run_id,
+                *database_type,
+            ));
+            DispatchResult::handled_with(effects)
+        }
+        Action::SessionSettingsSaveFailed(e) => {
+            state.messages.set_error_at(e.to_string(), now);
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+fn edit_focused(state: &mut AppState, edit: impl FnOnce(&mut TextInputState)) {
+    let input = state.session_settings_form.focused_input_mut();
+    edit(input);
+    input.update_viewport(SESSION_SETTINGS_INPUT_VISIBLE_WIDTH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::connection::{
+        ConnectionProfile, DatabaseType, SessionSettingKey, SessionSettings, SslMode,
+    };
+
+    fn reduce(state: &mut AppState, action: &Action) -> Option<Vec<Effect>> {
+        reduce_session_settings(state, action, Instant::now()).into_effects()
+    }
+
+    fn connected_state() -> (AppState, ConnectionProfile) {
+        let mut state = AppState::new("test".to_string());
+        let mut settings = SessionSettings::default();
+        settings.set(SessionSettingKey::WorkMem, "64MB");
+        let profile = ConnectionProfile::new_postgres(
+            "Primary",
+            "localhost",
+            5432,
+            "app",
+            "postgres",
+            "",
+            SslMode::Prefer,
+        )
+        .unwrap()
+        .with_session_settings(settings);
+        state.set_connections(vec![profile.clone()]);
+        state.session.activate_connection_with_dsn(
+            &profile.id,
+            "Primary",
+            DatabaseType::PostgreSQL,
+            "host='localhost'",
+        );
+        (state, profile)
+    }
+
+    #[test]
+    fn open_prefills_form_from_active_profile() {
+        let (mut state, _) = connected_state();
+
+        reduce(&mut state, &Action::OpenModal(ModalKind::SessionSettings));
+
+        assert_eq!(state.modal.active_mode(), InputMode::SessionSettings);
+        assert_eq!(
+            state
+                .session_settings_form
+                .input(SessionSettingKey::WorkMem)
+                .content(),
+            "64MB"
+        );
+    }
+
+    #[test]
+    fn open_without_profile_reports_error() {
+        let mut state = AppState::new("test".to_string());
+
+        reduce(&mut state, &Action::OpenModal(ModalKind::SessionSettings));
+
+        assert_eq!(state.modal.active_mode(), InputMode::Normal);
+        assert!(state.messages.last_error().is_some());
+    }
+
+    #[test]
+    fn save_emits_updated_profile() {
+        let (mut state, profile) = connected_state();
+        reduce(&mut state, &Action::OpenModal(ModalKind::SessionSettings));
+        for ch in "sabiql".chars() {
+            reduce(
+                &mut state,
+                &Action::TextInput {
+                    target: InputTarget::SessionSettings,
+                    ch,
+                },
+            );
+        }
+
+        let effects = reduce(&mut state, &Action::SessionSettingsSave).unwrap();
+
+        assert_eq!(state.modal.active_mode(), InputMode::Normal);
+        let [Effect::SaveSessionSettings { profile: saved }] = effects.as_slice() else {
+            panic!("expected SaveSessionSettings, got {effects:?}");
+        };
+        assert_eq!(saved.id, profile.id);
+        assert_eq!(
+            saved
+                .session_settings
+                .get(SessionSettingKey::ApplicationName),
+            Some("sabiql")
+        );
+        assert_eq!(
+            saved.session_settings.get(SessionSettingKey::WorkMem),
+            Some("64MB")
+        );
+    }
+
+    #[test]
+    fn unchanged_save_skips_reconnect() {
+        let (mut state, _) = connected_state();
+        reduce(&mut state, &Action::OpenModal(ModalKind::SessionSettings));
+
+        let effects = reduce(&mut state, &Action::SessionSettingsSave).unwrap();
+
+        assert!(effects.is_empty());
+    }
+
+    #[test]
+    fn saved_profile_reconnects_with_new_dsn() {
+        let (mut state, profile) = connected_state();
+        let mut settings = SessionSettings::default();
+        settings.set(SessionSettingKey::StatementTimeout, "30s");
+        let saved = profile.clone().with_session_settings(settings.clone());
+
+        let effects = reduce(
+            &mut state,
+            &Action::SessionSettingsSaved {
+                profile: Box::new(saved),
+                target: ConnectionTarget {
+                    id: profile.id,
+                    dsn: "host='localhost' options='-c statement_timeout=30s'".to_string(),
+                    name: "Primary".to_string(),
+                    database_type: DatabaseType::PostgreSQL,
+                },
+            },
+        )
+        .unwrap();
+
+        assert_eq!(state.active_session_settings(), Some(&settings));
+        assert_eq!(
+            state.session.dsn(),
+            Some("host='localhost' options='-c statement_timeout=30s'")
+        );
+        assert!(
+            effects
+                .iter()
+                .any(|e| matches!(e, Effect::FetchMetadata { .. }))
+        );
+    }
+}
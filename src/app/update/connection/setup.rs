@@ -2,6 +2,7 @@ use std::time::Instant;
 
 use crate::cmd::effect::Effect;
 use crate::domain::connection::ConnectionProfileError;
+use crate::i18n::Message;
 use crate::model::app_state::AppState;
 use crate::model::connection::setup::{
     CONNECTION_INPUT_VISIBLE_WIDTH, ConnectionField, ConnectionSetupState,
@@ -14,7 +15,8 @@ use crate::update::action::{
     Action, ConnectionSaveError, ConnectionTarget, InputTarget, ModalKind,
 };
 use crate::update::connection::helpers::{
-    connection_save_fetch_effects, reset_for_new_connection, save_current_cache,
+    connection_save_fetch_effects, reset_for_new_connection, rollback_open_transaction,
+    save_current_cache,
 };
 use crate::update::dispatch_result::DispatchResult;
 use crate::update::helpers::{validate_all, validate_field};
@@ -218,7 +220,7 @@ pub fn reduce_connection_setup(
         Action::ConnectionSetupCancel => {
             if state.connection_setup.is_first_run() {
                 state.confirm_dialog.open(
-                    "Confirm",
+                    Message::ConfirmTitle.text(state.settings.locale()),
                     "No connection configured.\nAre you sure you want to quit?",
                     ConfirmIntent::QuitNoConnection,
                 );
@@ -241,14 +243,17 @@ pub fn reduce_connection_setup(
             state.modal.set_mode(InputMode::Normal);
             state.connection_caches.remove(id);
 
+            let rollback = rollback_open_transaction(state);
             reset_for_new_connection(state, id, dsn, name, *database_type);
             let run_id = state.session.begin_connecting(dsn);
-            DispatchResult::handled_with(connection_save_fetch_effects(
+            let mut effects: Vec<Effect> = rollback.into_iter().collect();
+            effects.extend(connection_save_fetch_effects(
                 state,
                 dsn,
                 run_id,
                 *database_type,
-            ))
+            ));
+            DispatchResult::handled_with(effects)
         }
         Action::ConnectionSaveFailed(e) => {
             if let ConnectionSaveError::Validation(ConnectionProfileError::SqlitePath(error)) = &e {
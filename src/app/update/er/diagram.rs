@@ -2,7 +2,7 @@ use std::time::Instant;
 
 use crate::cmd::effect::Effect;
 use crate::model::app_state::AppState;
-use crate::update::action::{Action, ErDiagramInfo};
+use crate::update::action::{Action, ErDiagramInfo, ModalKind};
 use crate::update::dispatch_result::DispatchResult;
 use crate::update::helpers::require_er_diagram_enabled;
 
@@ -84,8 +84,18 @@ pub(super) fn reduce_diagram_lifecycle(
                 total_tables,
                 project_name: state.runtime.project_name.clone(),
                 target_tables: state.er_preparation.target_tables().to_vec(),
+                output: state.er_preparation.take_output(),
             }])
         }
+        Action::SetErDiagramOptions(options) => {
+            state.er_preparation.set_options(options.clone());
+            state
+                .messages
+                .set_success_at(format!("ER diagram: {}", options.summary()), now);
+            DispatchResult::handled_with(vec![Effect::DispatchActions(vec![Action::OpenModal(
+                ModalKind::ErTablePicker,
+            )])])
+        }
         _ => DispatchResult::pass(),
     }
 }
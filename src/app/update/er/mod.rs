@@ -1,6 +1,7 @@
 mod diagram;
 mod smart_refresh_completed;
 mod smart_refresh_failed;
+mod viewer;
 
 use std::time::Instant;
 
@@ -12,6 +13,7 @@ pub fn dispatch_er(state: &mut AppState, action: &Action, now: Instant) -> Dispa
     diagram::reduce_diagram_lifecycle(state, action, now)
         .or_else(|| smart_refresh_completed::reduce_smart_refresh_completed(state, action, now))
         .or_else(|| smart_refresh_failed::reduce_smart_refresh_failed(state, action, now))
+        .or_else(|| viewer::reduce_viewer(state, action, now))
 }
 
 #[cfg(test)]
@@ -257,6 +259,139 @@ mod tests {
         }
     }
 
+    mod set_er_diagram_options {
+        use super::*;
+        use crate::domain::ErDiagramOptions;
+        use crate::update::action::ModalKind;
+
+        #[test]
+        fn stores_options_and_opens_table_picker() {
+            let mut state = state_with_dsn("postgres://localhost/test");
+            let options = ErDiagramOptions::parse("hide=users color").unwrap();
+
+            let effects = reduce_er(
+                &mut state,
+                &Action::SetErDiagramOptions(options.clone()),
+                Instant::now(),
+            )
+            .into_effects()
+            .expect("reducer should handle action");
+
+            assert_eq!(state.er_preparation.options(), &options);
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::DispatchActions(actions)]
+                    if matches!(actions.as_slice(), [Action::OpenModal(ModalKind::ErTablePicker)])
+            ));
+        }
+
+        #[test]
+        fn options_survive_connection_reset() {
+            let mut state = state_with_dsn("postgres://localhost/test");
+            state
+                .er_preparation
+                .set_options(ErDiagramOptions::parse("collapse=30").unwrap());
+
+            state.er_preparation.reset();
+
+            assert_eq!(state.er_preparation.options().collapse_above, Some(30));
+        }
+    }
+
+    mod er_viewer {
+        use super::*;
+        use crate::domain::ErTableInfo;
+        use crate::model::shared::input_mode::InputMode;
+        use crate::update::action::{
+            ListMotion, ListTarget, ModalKind, ScrollAmount, ScrollDirection, ScrollTarget,
+        };
+
+        fn table(name: &str) -> ErTableInfo {
+            ErTableInfo {
+                qualified_name: format!("public.{name}"),
+                name: name.to_string(),
+                schema: "public".to_string(),
+                foreign_keys: Vec::new(),
+            }
+        }
+
+        fn loaded_state() -> AppState {
+            let mut state = state_with_dsn("postgres://localhost/test");
+            let _ = state.er_preparation.start_waiting_run();
+            state
+                .er_preparation
+                .set_targets(vec!["public.users".to_string()]);
+            reduce_er(
+                &mut state,
+                &Action::ErViewerLoaded(vec![table("posts"), table("users")]),
+                Instant::now(),
+            );
+            state
+        }
+
+        #[test]
+        fn loaded_tables_open_the_viewer_on_the_target() {
+            let state = loaded_state();
+
+            assert_eq!(state.input_mode(), InputMode::ErDiagram);
+            assert_eq!(state.er_preparation.status(), ErStatus::Idle);
+            assert_eq!(
+                state.er_viewer.selected_table().map(|t| t.name.as_str()),
+                Some("users")
+            );
+        }
+
+        #[test]
+        fn pan_moves_the_viewport() {
+            let mut state = loaded_state();
+
+            reduce_er(
+                &mut state,
+                &Action::Scroll {
+                    target: ScrollTarget::ErDiagram,
+                    direction: ScrollDirection::Right,
+                    amount: ScrollAmount::Line,
+                },
+                Instant::now(),
+            );
+
+            assert_eq!(state.er_viewer.pan(), (4, 0));
+        }
+
+        #[test]
+        fn next_table_wraps_around() {
+            let mut state = loaded_state();
+
+            reduce_er(
+                &mut state,
+                &Action::ListSelect {
+                    target: ListTarget::ErDiagram,
+                    motion: ListMotion::Next,
+                },
+                Instant::now(),
+            );
+
+            assert_eq!(
+                state.er_viewer.selected_table().map(|t| t.name.as_str()),
+                Some("posts")
+            );
+        }
+
+        #[test]
+        fn close_returns_to_normal_mode() {
+            let mut state = loaded_state();
+
+            reduce_er(
+                &mut state,
+                &Action::CloseModal(ModalKind::ErDiagram),
+                Instant::now(),
+            );
+
+            assert_eq!(state.input_mode(), InputMode::Normal);
+            assert!(state.er_viewer.tables().is_empty());
+        }
+    }
+
     mod smart_er_refresh_completed {
         use super::*;
 
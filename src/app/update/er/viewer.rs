@@ -0,0 +1,72 @@
+use std::time::Instant;
+
+use crate::model::app_state::AppState;
+use crate::model::er_viewer::ErViewerState;
+use crate::model::shared::input_mode::InputMode;
+use crate::update::action::{
+    Action, ListMotion, ListTarget, ModalKind, ScrollDirection, ScrollTarget,
+};
+use crate::update::dispatch_result::DispatchResult;
+
+/// Cells moved per pan step; columns are narrower than rows are tall.
+const PAN_COLUMNS: i32 = 4;
+const PAN_ROWS: i32 = 2;
+
+pub(super) fn reduce_viewer(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
+    match action {
+        Action::ErViewerLoaded(tables) => {
+            state.er_preparation.mark_idle();
+            state.sql_modal.invalidate_prefetch();
+            state.er_viewer =
+                ErViewerState::open(tables.clone(), state.er_preparation.target_tables());
+            state.modal.set_mode(InputMode::ErDiagram);
+            state.messages.set_success_at(
+                format!("ER diagram: {} tables", state.er_viewer.tables().len()),
+                now,
+            );
+            DispatchResult::handled()
+        }
+        Action::CloseModal(ModalKind::ErDiagram) => {
+            state.er_viewer.close();
+            state.modal.set_mode(InputMode::Normal);
+            DispatchResult::handled()
+        }
+        Action::Scroll {
+            target: ScrollTarget::ErDiagram,
+            direction,
+            ..
+        } => {
+            let (dx, dy) = match direction {
+                ScrollDirection::Left => (-PAN_COLUMNS, 0),
+                ScrollDirection::Right => (PAN_COLUMNS, 0),
+                ScrollDirection::Up => (0, -PAN_ROWS),
+                ScrollDirection::Down => (0, PAN_ROWS),
+            };
+            state.er_viewer.pan_by(dx, dy);
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::ErDiagram,
+            motion,
+        } => {
+            match motion {
+                ListMotion::Next => state.er_viewer.select_next(),
+                ListMotion::Previous => state.er_viewer.select_previous(),
+            }
+            DispatchResult::handled()
+        }
+        Action::ErViewerZoomIn => {
+            state.er_viewer.zoom_in();
+            DispatchResult::handled()
+        }
+        Action::ErViewerZoomOut => {
+            state.er_viewer.zoom_out();
+            DispatchResult::handled()
+        }
+        Action::ErViewerToggleFocus => {
+            state.er_viewer.toggle_focus();
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
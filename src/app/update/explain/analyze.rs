@@ -2,7 +2,6 @@ use std::time::Instant;
 
 use crate::cmd::effect::Effect;
 use crate::model::app_state::AppState;
-use crate::model::shared::text_input::TextInputLike;
 use crate::model::sql_editor::modal::SqlModalStatus;
 use crate::policy::sql::statement_classifier;
 use crate::policy::write::sql_risk::{ConfirmationType, evaluate_sql_risk_for_database};
@@ -24,7 +23,7 @@ pub(super) fn reduce_analyze(
 ) -> DispatchResult {
     match action {
         Action::ExplainAnalyzeRequest => {
-            let content = state.sql_modal.editor.content().trim().to_string();
+            let content = state.sql_modal.submitted_query();
             if content.is_empty() {
                 return DispatchResult::handled();
             }
@@ -975,7 +975,10 @@ mod tests {
                 .collect::<Vec<_>>()
                 .join("\n");
             state.explain.set_plan(long_plan, false, 0, "Q1");
-            let modal_inner = ExplainContext::modal_inner_height(state.ui.terminal_height());
+            let modal_inner = ExplainContext::modal_inner_height(
+                state.ui.terminal_height(),
+                state.sql_modal.size(),
+            );
             let max = state.explain.line_count().saturating_sub(modal_inner);
             state.explain.scroll_offset = max;
 
@@ -1044,7 +1047,9 @@ mod tests {
             state.explain.set_plan(long_plan.clone(), false, 0, "Q1");
             state.explain.set_plan(long_plan, false, 0, "Q2");
 
-            let max = state.explain.compare_max_scroll(state.ui.terminal_height());
+            let max = state
+                .explain
+                .compare_max_scroll(state.ui.terminal_height(), state.sql_modal.size());
 
             // Scroll to max
             for _ in 0..max + 5 {
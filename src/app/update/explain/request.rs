@@ -2,7 +2,6 @@ use std::time::Instant;
 
 use crate::cmd::effect::Effect;
 use crate::model::app_state::AppState;
-use crate::model::shared::text_input::TextInputLike;
 use crate::model::sql_editor::modal::SqlModalStatus;
 use crate::policy::{FeaturePolicy, FeatureRequirement};
 use crate::ports::outbound::AccessMode;
@@ -23,7 +22,7 @@ pub(super) fn reduce_request(
 ) -> DispatchResult {
     match action {
         Action::ExplainRequest => {
-            let content = state.sql_modal.editor.content().trim().to_string();
+            let content = state.sql_modal.submitted_query();
             if content.is_empty() {
                 return DispatchResult::handled();
             }
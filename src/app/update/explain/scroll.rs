@@ -26,21 +26,27 @@ pub(super) fn reduce_scroll(
                     const CONFIRM_HEADER_LINES: usize = 8;
                     let content_lines =
                         CONFIRM_HEADER_LINES + state.sql_modal.editor.content().lines().count();
-                    let modal_inner =
-                        ExplainContext::modal_inner_height(state.ui.terminal_height());
+                    let modal_inner = ExplainContext::modal_inner_height(
+                        state.ui.terminal_height(),
+                        state.sql_modal.size(),
+                    );
                     (
                         &mut state.explain.confirm_scroll_offset,
                         content_lines.saturating_sub(modal_inner),
                     )
                 }
                 ScrollTarget::ExplainPlan => {
-                    let modal_inner =
-                        ExplainContext::modal_inner_height(state.ui.terminal_height());
+                    let modal_inner = ExplainContext::modal_inner_height(
+                        state.ui.terminal_height(),
+                        state.sql_modal.size(),
+                    );
                     let max = state.explain.line_count().saturating_sub(modal_inner);
                     (&mut state.explain.scroll_offset, max)
                 }
                 ScrollTarget::ExplainCompare => {
-                    let max = state.explain.compare_max_scroll(state.ui.terminal_height());
+                    let max = state
+                        .explain
+                        .compare_max_scroll(state.ui.terminal_height(), state.sql_modal.size());
                     (&mut state.explain.compare_scroll_offset, max)
                 }
                 _ => unreachable!(),
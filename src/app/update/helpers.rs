@@ -8,6 +8,7 @@ use crate::domain::{QueryResult, QueryValue};
 use crate::model::app_state::AppState;
 use crate::model::browse::query_execution::QueryStatus;
 use crate::model::connection::setup::{ConnectionField, ConnectionSetupState};
+use crate::model::shared::settings::PrefetchScope;
 use crate::policy::write::inline_cell_edit::InlineCellEditError;
 use crate::policy::write::write_guardrails::{
     PreviewWriteability, StableRowIdentity, TargetSummary, WriteOperation, WritePreview,
@@ -15,6 +16,7 @@ use crate::policy::write::write_guardrails::{
 };
 use crate::policy::{FeaturePolicy, FeatureRequirement};
 use crate::services::AppServices;
+use crate::update::action::Action;
 use crate::update::dispatch_result::DispatchResult;
 
 pub(crate) fn require_er_diagram_enabled(
@@ -33,6 +35,14 @@ pub(crate) fn require_er_diagram_enabled(
     Some(DispatchResult::handled())
 }
 
+/// What opening the SQL modal prefetches for completion, per `:prefetch`.
+pub(crate) fn sql_modal_prefetch_action(state: &AppState) -> Action {
+    match state.settings.prefetch_scope() {
+        PrefetchScope::All => Action::StartPrefetchAll,
+        PrefetchScope::Schema => Action::PrefetchReferencedSchemas,
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum EditGuardrailError {
     #[error("No result to edit")]
@@ -73,6 +83,10 @@ pub enum EditGuardrailError {
     ColumnIndexOutOfBounds,
     #[error("Primary key columns are read-only")]
     PrimaryKeyColumnsReadOnly,
+    #[error("{0} is masked; :unmask to edit it")]
+    MaskedColumn(String),
+    #[error("Row key columns are masked; :unmask to write")]
+    MaskedKeyColumns,
     #[error("Read-only column cannot be edited: {0}")]
     ReadOnlyColumn(String),
     #[error("No active row")]
@@ -146,6 +160,15 @@ pub fn editable_preview_base(
     }
     let identity = stable_row_identity_for_table(table_detail)
         .ok_or(EditGuardrailError::EditingRequiresPrimaryKey)?;
+    // Masked key values would target rows that do not exist.
+    if state
+        .query
+        .masked_columns()
+        .iter()
+        .any(|column| identity.is_primary_key_column(column))
+    {
+        return Err(EditGuardrailError::MaskedKeyColumns);
+    }
 
     Ok((result, identity))
 }
@@ -158,6 +181,9 @@ pub fn ensure_column_writable(
     if identity.is_primary_key_column(column_name) {
         return Err(EditGuardrailError::PrimaryKeyColumnsReadOnly);
     }
+    if state.query.is_column_masked(column_name) {
+        return Err(EditGuardrailError::MaskedColumn(column_name.to_string()));
+    }
 
     if let Some(column) = state.session.table_detail().and_then(|table| {
         table
@@ -739,6 +765,7 @@ mod tests {
             let dsn = match database_type {
                 DatabaseType::PostgreSQL => "postgres://localhost/test",
                 DatabaseType::SQLite => "sqlite:///tmp/app.db",
+                DatabaseType::MySQL => "mysql://localhost/test",
             };
             state.session.activate_connection_with_dsn(
                 &ConnectionId::from_string("test-connection"),
@@ -1,4 +1,12 @@
-use crate::update::action::{Action, ModalKind};
+use crate::domain::{ErDiagramOptions, IndexMaintenanceKind, MaintenanceKind};
+use crate::model::shared::settings::{KeywordCase, PrefetchScope};
+use crate::policy::index_usage_report::IndexUsageSort;
+use crate::policy::result_copy::ResultCopyFormat;
+use crate::policy::sql::replica_routing::QueryRouting;
+use crate::ports::outbound::{CacheCategory, TransactionEnd};
+use crate::update::action::{Action, BulkTableOperation, ModalKind};
+
+const DEFAULT_SAMPLE_ROWS: usize = 100;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Command {
@@ -6,10 +14,71 @@ pub enum Command {
     Help,
     Sql,
     Erd,
+    ErOptions(ErDiagramOptions),
     Settings,
     Theme,
     Palette,
     Write,
+    Copy(ResultCopyFormat),
+    CopyRows(ResultCopyFormat),
+    NumberFormat,
+    DurationFormat,
+    ColumnTypes,
+    ResultDiff,
+    GeometryPreview,
+    Unmask,
+    SetVariable {
+        name: String,
+        value: Option<String>,
+    },
+    KeywordCase(Option<KeywordCase>),
+    PrefetchScope(PrefetchScope),
+    Usage(Option<bool>),
+    Version,
+    /// `:where <expr>` narrows the preview; a bare `:where` clears it.
+    PreviewFilter(Option<String>),
+    /// `:columns a, b` picks the preview's columns for the table; a bare
+    /// `:columns` selects all of them again.
+    PreviewColumns(Option<String>),
+    UpdateCheck(bool),
+    CacheInfo,
+    /// `None` clears every category.
+    CacheClear(Option<CacheCategory>),
+    Route(QueryRouting),
+    Replication(Option<String>),
+    /// Random rows of the selected table.
+    Sample(usize),
+    ForeignKeyOrphans(Option<String>),
+    SuggestForeignKeys,
+    AuditNaming,
+    StatementTimings,
+    StartupReport,
+    Maintenance(MaintenanceKind),
+    AutovacuumReport,
+    IndexUsage(IndexUsageSort),
+    ServerSettings,
+    ServerInfo,
+    SessionSettings,
+    SqlFiles,
+    Snippets,
+    SaveSnippet(String),
+    BeginTransaction,
+    EndTransaction(TransactionEnd),
+    Bulk(BulkTableOperation),
+    ClearMarks,
+    SchemaFilter(Option<String>),
+    History,
+    Connections,
+    ImportConnections,
+    DiscoverContainers,
+    EditFile(String),
+    WriteFile(String),
+    ReloadFile,
+    IndexMaintenance {
+        kind: IndexMaintenanceKind,
+        index: Option<String>,
+        copy_sql: bool,
+    },
     Unknown(String),
 }
 
@@ -19,13 +88,167 @@ pub fn parse_command(input: &str) -> Command {
         "q" | "quit" => Command::Quit,
         "?" | "help" => Command::Help,
         "sql" => Command::Sql,
-        "erd" => Command::Erd,
+        "er" | "erd" => Command::Erd,
         "settings" => Command::Settings,
         "theme" => Command::Theme,
         "palette" => Command::Palette,
         "w" | "write" => Command::Write,
-        other => Command::Unknown(other.to_string()),
+        "copy" => Command::Copy(ResultCopyFormat::Tsv),
+        "copyrows" => Command::CopyRows(ResultCopyFormat::Markdown),
+        "numfmt" => Command::NumberFormat,
+        "durfmt" => Command::DurationFormat,
+        "types" => Command::ColumnTypes,
+        "diff" => Command::ResultDiff,
+        "geom" => Command::GeometryPreview,
+        "unmask" => Command::Unmask,
+        "replication" => Command::Replication(None),
+        "sample" => Command::Sample(DEFAULT_SAMPLE_ROWS),
+        "orphans" => Command::ForeignKeyOrphans(None),
+        "timings" => Command::StatementTimings,
+        "vacuum" => Command::Maintenance(MaintenanceKind::VacuumAnalyze),
+        "analyze" => Command::Maintenance(MaintenanceKind::Analyze),
+        "autovacuum" => Command::AutovacuumReport,
+        "indexes" => Command::IndexUsage(IndexUsageSort::Scans),
+        "pgsettings" => Command::ServerSettings,
+        "session" => Command::SessionSettings,
+        "files" => Command::SqlFiles,
+        "snippets" => Command::Snippets,
+        "begin" => Command::BeginTransaction,
+        "commit" => Command::EndTransaction(TransactionEnd::Commit),
+        "rollback" => Command::EndTransaction(TransactionEnd::Rollback),
+        "history" => Command::History,
+        "conn" | "connections" => Command::Connections,
+        "usage" => Command::Usage(None),
+        "version" => Command::Version,
+        "where" => Command::PreviewFilter(None),
+        "columns" => Command::PreviewColumns(None),
+        "cache" => Command::CacheInfo,
+        "schema" => Command::SchemaFilter(None),
+        "e!" | "edit!" => Command::ReloadFile,
+        other => {
+            if let Some(command) = parse_index_maintenance(other) {
+                return command;
+            }
+            parse_command_with_args(other).unwrap_or_else(|| Command::Unknown(other.to_string()))
+        }
+    }
+}
+
+fn parse_command_with_args(input: &str) -> Option<Command> {
+    let (name, args) = input.split_once(char::is_whitespace)?;
+    match name {
+        "copy" => ResultCopyFormat::parse(args.trim()).map(Command::Copy),
+        "copyrows" => ResultCopyFormat::parse(args.trim()).map(Command::CopyRows),
+        "set" => parse_set_variable(args.trim()),
+        "kwcase" => match args.trim() {
+            "off" => Some(Command::KeywordCase(None)),
+            value => {
+                KeywordCase::from_config_value(value).map(|case| Command::KeywordCase(Some(case)))
+            }
+        },
+        "prefetch" => PrefetchScope::from_config_value(args).map(Command::PrefetchScope),
+        "indexes" => IndexUsageSort::parse(args.trim()).map(Command::IndexUsage),
+        "usage" => match args.trim() {
+            "on" => Some(Command::Usage(Some(true))),
+            "off" => Some(Command::Usage(Some(false))),
+            _ => None,
+        },
+        "where" => Some(Command::PreviewFilter(Some(args.trim().to_string()))),
+        "columns" => Some(Command::PreviewColumns(Some(args.trim().to_string()))),
+        "updates" => match args.trim() {
+            "on" => Some(Command::UpdateCheck(true)),
+            "off" => Some(Command::UpdateCheck(false)),
+            _ => None,
+        },
+        "cache" => parse_cache(args.trim()),
+        "route" => QueryRouting::parse(args.trim()).map(Command::Route),
+        "replication" => Some(Command::Replication(Some(args.trim().to_string()))),
+        "sample" => args
+            .trim()
+            .parse()
+            .ok()
+            .filter(|limit| *limit > 0)
+            .map(Command::Sample),
+        "orphans" => Some(Command::ForeignKeyOrphans(Some(args.trim().to_string()))),
+        "suggest" => (args.trim() == "fks").then_some(Command::SuggestForeignKeys),
+        "audit" => (args.trim() == "naming").then_some(Command::AuditNaming),
+        "connect" | "conn" => match args.trim() {
+            "import" => Some(Command::ImportConnections),
+            "docker" | "containers" => Some(Command::DiscoverContainers),
+            _ => None,
+        },
+        "startup" => (args.trim() == "report").then_some(Command::StartupReport),
+        "server" => (args.trim() == "info").then_some(Command::ServerInfo),
+        "er" | "erd" => match args.trim() {
+            "reset" => Some(Command::ErOptions(ErDiagramOptions::default())),
+            options => ErDiagramOptions::parse(options).map(Command::ErOptions),
+        },
+        "bulk" => match args.trim() {
+            "ddl" => Some(Command::Bulk(BulkTableOperation::CopyDdl)),
+            "er" | "erd" => Some(Command::Bulk(BulkTableOperation::ErDiagram)),
+            "analyze" => Some(Command::Bulk(BulkTableOperation::Analyze)),
+            "size" | "sizes" => Some(Command::Bulk(BulkTableOperation::Sizes)),
+            "clear" => Some(Command::ClearMarks),
+            _ => None,
+        },
+        "schema" => Some(Command::SchemaFilter(Some(args.trim().to_string()))),
+        "e" | "edit" => Some(Command::EditFile(args.trim().to_string())),
+        "w" | "write" => Some(Command::WriteFile(args.trim().to_string())),
+        "save" => Some(Command::SaveSnippet(args.trim().to_string())),
+        _ => None,
+    }
+}
+
+// `reindex[!]|rebuild [index]`, with an `sql` suffix copying the statements
+// instead of running them.
+fn parse_index_maintenance(input: &str) -> Option<Command> {
+    let (name, index) = input
+        .split_once(char::is_whitespace)
+        .map_or((input, None), |(name, index)| (name, Some(index.trim())));
+    let (kind, copy_sql) = match name {
+        "reindex" => (IndexMaintenanceKind::ReindexConcurrently, false),
+        "reindex!" => (IndexMaintenanceKind::Reindex, false),
+        "rebuild" => (IndexMaintenanceKind::Rebuild, false),
+        "reindexsql" => (IndexMaintenanceKind::ReindexConcurrently, true),
+        "rebuildsql" => (IndexMaintenanceKind::Rebuild, true),
+        _ => return None,
+    };
+    Some(Command::IndexMaintenance {
+        kind,
+        index: index.filter(|index| !index.is_empty()).map(str::to_string),
+        copy_sql,
+    })
+}
+
+// `cache [info]` or `cache clear [category]`.
+fn parse_cache(args: &str) -> Option<Command> {
+    let (verb, category) = args
+        .split_once(char::is_whitespace)
+        .map_or((args, ""), |(verb, category)| (verb, category.trim()));
+    match (verb, category) {
+        ("info", "") => Some(Command::CacheInfo),
+        ("clear", "" | "all") => Some(Command::CacheClear(None)),
+        ("clear", name) => {
+            CacheCategory::parse(name).map(|category| Command::CacheClear(Some(category)))
+        }
+        _ => None,
+    }
+}
+
+// `set <name>` binds the active cell; `set <name> = <value>` binds a literal.
+fn parse_set_variable(args: &str) -> Option<Command> {
+    let (name, rest) = args
+        .split_once(|c: char| c.is_whitespace() || c == '=')
+        .unwrap_or((args, ""));
+    if name.is_empty() {
+        return None;
     }
+    let rest = rest.trim_start();
+    let value = rest.strip_prefix('=').unwrap_or(rest).trim();
+    Some(Command::SetVariable {
+        name: name.to_string(),
+        value: (!value.is_empty()).then(|| value.to_string()),
+    })
 }
 
 pub fn command_to_action(cmd: Command) -> Action {
@@ -34,10 +257,90 @@ pub fn command_to_action(cmd: Command) -> Action {
         Command::Help => Action::ToggleModal(ModalKind::Help),
         Command::Sql => Action::OpenModal(ModalKind::SqlModal),
         Command::Erd => Action::OpenModal(ModalKind::ErTablePicker),
+        Command::ErOptions(options) => Action::SetErDiagramOptions(options),
         Command::Settings | Command::Theme => Action::OpenModal(ModalKind::Settings),
         Command::Palette => Action::OpenModal(ModalKind::CommandPalette),
         Command::Write => Action::SubmitCellEditWrite,
-        Command::Unknown(_) => Action::None,
+        Command::Copy(format) => Action::ResultCopyAll(format),
+        Command::CopyRows(format) => Action::ResultCopyRows(format),
+        Command::NumberFormat => Action::ResultToggleNumberFormat,
+        Command::DurationFormat => Action::ResultToggleDurationFormat,
+        Command::ColumnTypes => Action::ResultToggleColumnTypes,
+        Command::ResultDiff => Action::ResultToggleDiffOldValues,
+        Command::GeometryPreview => Action::ResultOpenGeometryPreview,
+        Command::Unmask => Action::ResultUnmask,
+        Command::SetVariable { name, value } => Action::ResultBindVariable { name, value },
+        Command::KeywordCase(case) => Action::SetKeywordCase(case),
+        Command::PrefetchScope(scope) => Action::SetPrefetchScope(scope),
+        Command::Usage(None) => Action::ShowUsageStats,
+        Command::Usage(Some(enabled)) => Action::SetUsageTracking(enabled),
+        Command::Version => Action::ShowVersion,
+        Command::PreviewFilter(filter) => Action::SetPreviewFilter(filter),
+        Command::PreviewColumns(columns) => Action::SetPreviewColumns(columns),
+        Command::UpdateCheck(enabled) => Action::SetUpdateCheck(enabled),
+        Command::CacheInfo => Action::ShowCacheInfo,
+        Command::CacheClear(category) => Action::ClearCache(category),
+        Command::Route(routing) => Action::SetQueryRouting(routing),
+        Command::Replication(table) => Action::ShowReplicationOverview(table),
+        Command::Sample(limit) => Action::SampleTable(limit),
+        Command::ForeignKeyOrphans(name) => Action::CheckForeignKeyOrphans(name),
+        Command::SuggestForeignKeys => Action::SuggestForeignKeys,
+        Command::AuditNaming => Action::AuditNaming,
+        Command::StatementTimings => Action::ShowStatementTimings,
+        Command::StartupReport => Action::ShowStartupReport,
+        Command::Maintenance(kind) => Action::StartMaintenance(kind),
+        Command::AutovacuumReport => Action::ShowAutovacuumReport,
+        Command::IndexUsage(sort) => Action::ShowIndexUsage(sort),
+        Command::ServerSettings => Action::OpenModal(ModalKind::ServerSettings),
+        Command::ServerInfo => Action::ShowServerInfo,
+        Command::SessionSettings => Action::OpenModal(ModalKind::SessionSettings),
+        Command::SqlFiles => Action::OpenModal(ModalKind::SqlFileBrowser),
+        Command::Snippets => Action::OpenModal(ModalKind::SnippetPicker),
+        Command::SaveSnippet(name) => Action::SaveSnippet(name),
+        Command::BeginTransaction => Action::BeginTransaction,
+        Command::EndTransaction(end) => Action::EndTransaction(end),
+        Command::Bulk(operation) => Action::BulkTables(operation),
+        Command::ClearMarks => Action::ExplorerClearMarks,
+        Command::SchemaFilter(schema) => Action::SetExplorerSchemaFilter(schema),
+        Command::History => Action::OpenModal(ModalKind::QueryHistoryPicker),
+        Command::Connections => Action::OpenModal(ModalKind::ConnectionSelector),
+        Command::ImportConnections => Action::OpenModal(ModalKind::ConnectionImport),
+        Command::DiscoverContainers => Action::OpenContainerDiscovery,
+        Command::EditFile(path) => Action::LinkSqlFile(path),
+        Command::ReloadFile => Action::ReloadLinkedSqlFile,
+        Command::IndexMaintenance {
+            kind,
+            index,
+            copy_sql: true,
+        } => Action::CopyIndexMaintenanceSql { kind, index },
+        Command::IndexMaintenance {
+            kind,
+            index,
+            copy_sql: false,
+        } => Action::StartIndexMaintenance {
+            kind,
+            index,
+            confirmed: false,
+        },
+        // Only the SQL modal has a buffer to save under a new name.
+        Command::WriteFile(_) | Command::Unknown(_) => Action::None,
+    }
+}
+
+/// Commands entered from the SQL modal act on the editor buffer, not the
+/// browse pane underneath it.
+pub fn sql_modal_command_to_action(cmd: Command) -> Action {
+    match cmd {
+        Command::Write => Action::WriteLinkedSqlFile,
+        Command::WriteFile(path) => Action::WriteSqlFileAs(path),
+        Command::Quit => Action::CloseModal(ModalKind::SqlModal),
+        Command::EditFile(_)
+        | Command::ReloadFile
+        | Command::Snippets
+        | Command::SaveSnippet(_)
+        | Command::BeginTransaction
+        | Command::EndTransaction(_) => command_to_action(cmd),
+        _ => Action::None,
     }
 }
 
@@ -89,6 +392,163 @@ mod tests {
             assert_eq!(result, Command::Settings);
         }
 
+        #[test]
+        fn pgsettings_returns_server_settings() {
+            let result = parse_command("pgsettings");
+
+            assert_eq!(result, Command::ServerSettings);
+        }
+
+        #[test]
+        fn session_returns_session_settings() {
+            let result = parse_command("session");
+
+            assert_eq!(result, Command::SessionSettings);
+        }
+
+        #[test]
+        fn timings_returns_statement_timings() {
+            let result = parse_command("timings");
+
+            assert_eq!(result, Command::StatementTimings);
+        }
+
+        #[test]
+        fn startup_report_shows_startup_phases() {
+            let result = parse_command("startup report");
+
+            assert_eq!(result, Command::StartupReport);
+            assert!(matches!(
+                command_to_action(result),
+                Action::ShowStartupReport
+            ));
+        }
+
+        #[test]
+        fn server_info_shows_pool_counts() {
+            let result = parse_command("server info");
+
+            assert_eq!(result, Command::ServerInfo);
+            assert!(matches!(command_to_action(result), Action::ShowServerInfo));
+        }
+
+        #[test]
+        fn files_returns_sql_files() {
+            let result = parse_command("files");
+
+            assert_eq!(result, Command::SqlFiles);
+        }
+
+        #[test]
+        fn history_opens_query_history() {
+            let result = parse_command("history");
+
+            assert_eq!(result, Command::History);
+            assert!(matches!(
+                command_to_action(result),
+                Action::OpenModal(ModalKind::QueryHistoryPicker)
+            ));
+        }
+
+        #[test]
+        fn snippets_opens_snippet_picker() {
+            let result = parse_command("snippets");
+
+            assert_eq!(result, Command::Snippets);
+            assert!(matches!(
+                command_to_action(result),
+                Action::OpenModal(ModalKind::SnippetPicker)
+            ));
+        }
+
+        #[rstest]
+        #[case("begin", Command::BeginTransaction)]
+        #[case("commit", Command::EndTransaction(TransactionEnd::Commit))]
+        #[case("rollback", Command::EndTransaction(TransactionEnd::Rollback))]
+        fn transaction_commands(#[case] input: &str, #[case] expected: Command) {
+            let result = parse_command(input);
+
+            assert_eq!(result, expected);
+            assert!(!matches!(sql_modal_command_to_action(result), Action::None));
+        }
+
+        #[rstest]
+        #[case("bulk ddl", Command::Bulk(BulkTableOperation::CopyDdl))]
+        #[case("bulk er", Command::Bulk(BulkTableOperation::ErDiagram))]
+        #[case("bulk analyze", Command::Bulk(BulkTableOperation::Analyze))]
+        #[case("bulk sizes", Command::Bulk(BulkTableOperation::Sizes))]
+        #[case("bulk clear", Command::ClearMarks)]
+        #[case("bulk vacuum", Command::Unknown("bulk vacuum".to_string()))]
+        fn bulk_acts_on_marked_tables(#[case] input: &str, #[case] expected: Command) {
+            let result = parse_command(input);
+
+            assert_eq!(result, expected);
+        }
+
+        #[rstest]
+        #[case("er", Command::Erd)]
+        #[case(
+            "erd hide=users color",
+            Command::ErOptions(ErDiagramOptions {
+                hidden_hubs: vec!["users".to_string()],
+                color_by_schema: true,
+                collapse_above: None,
+            })
+        )]
+        #[case("er collapse=25", Command::ErOptions(ErDiagramOptions {
+            collapse_above: Some(25),
+            ..ErDiagramOptions::default()
+        }))]
+        #[case("erd reset", Command::ErOptions(ErDiagramOptions::default()))]
+        #[case("erd huge", Command::Unknown("erd huge".to_string()))]
+        fn erd_takes_diagram_options(#[case] input: &str, #[case] expected: Command) {
+            let result = parse_command(input);
+
+            assert_eq!(result, expected);
+        }
+
+        #[rstest]
+        #[case("save active_users", Command::SaveSnippet("active_users".to_string()))]
+        #[case("save", Command::Unknown("save".to_string()))]
+        fn save_names_snippet(#[case] input: &str, #[case] expected: Command) {
+            let result = parse_command(input);
+
+            assert_eq!(result, expected);
+        }
+
+        #[rstest]
+        #[case("conn")]
+        #[case("connections")]
+        fn connections_aliases(#[case] input: &str) {
+            let result = parse_command(input);
+
+            assert_eq!(result, Command::Connections);
+        }
+
+        #[rstest]
+        #[case("connect import", Command::ImportConnections)]
+        #[case("conn  import ", Command::ImportConnections)]
+        #[case("connect docker", Command::DiscoverContainers)]
+        #[case("conn containers", Command::DiscoverContainers)]
+        #[case("connect", Command::Unknown("connect".to_string()))]
+        #[case("connect export", Command::Unknown("connect export".to_string()))]
+        fn connect_import(#[case] input: &str, #[case] expected: Command) {
+            let result = parse_command(input);
+
+            assert_eq!(result, expected);
+        }
+
+        #[rstest]
+        #[case("e queries/report.sql", Command::EditFile("queries/report.sql".to_string()))]
+        #[case("edit  report.sql ", Command::EditFile("report.sql".to_string()))]
+        #[case("e!", Command::ReloadFile)]
+        #[case("edit!", Command::ReloadFile)]
+        fn edit_links_file(#[case] input: &str, #[case] expected: Command) {
+            let result = parse_command(input);
+
+            assert_eq!(result, expected);
+        }
+
         #[test]
         fn theme_returns_theme() {
             let result = parse_command("theme");
@@ -103,14 +563,277 @@ mod tests {
             assert_eq!(result, Command::Palette);
         }
 
+        #[test]
+        fn numfmt_returns_number_format() {
+            let result = parse_command("numfmt");
+
+            assert_eq!(result, Command::NumberFormat);
+        }
+
+        #[test]
+        fn durfmt_returns_duration_format() {
+            let result = parse_command("durfmt");
+
+            assert_eq!(result, Command::DurationFormat);
+        }
+
+        #[test]
+        fn types_returns_column_types() {
+            let result = parse_command("types");
+
+            assert_eq!(result, Command::ColumnTypes);
+        }
+
+        #[test]
+        fn diff_returns_result_diff() {
+            let result = parse_command("diff");
+
+            assert_eq!(result, Command::ResultDiff);
+        }
+
+        #[test]
+        fn geom_returns_geometry_preview() {
+            let result = parse_command("geom");
+
+            assert_eq!(result, Command::GeometryPreview);
+        }
+
+        #[test]
+        fn unmask_returns_unmask() {
+            let result = parse_command("unmask");
+
+            assert_eq!(result, Command::Unmask);
+        }
+
         #[rstest]
         #[case("w", Command::Write)]
         #[case("write", Command::Write)]
+        #[case("w q/new.sql", Command::WriteFile("q/new.sql".to_string()))]
+        #[case("write new.sql", Command::WriteFile("new.sql".to_string()))]
         fn write_aliases(#[case] input: &str, #[case] expected: Command) {
             let result = parse_command(input);
             assert_eq!(result, expected);
         }
 
+        #[rstest]
+        #[case("copy", Command::Copy(ResultCopyFormat::Tsv))]
+        #[case("copy csv", Command::Copy(ResultCopyFormat::Csv))]
+        #[case("copy  md", Command::Copy(ResultCopyFormat::Markdown))]
+        #[case("copy xml", Command::Unknown("copy xml".to_string()))]
+        fn copy_accepts_format_argument(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("copyrows", Command::CopyRows(ResultCopyFormat::Markdown))]
+        #[case("copyrows org", Command::CopyRows(ResultCopyFormat::Org))]
+        #[case("copyrows csv", Command::CopyRows(ResultCopyFormat::Csv))]
+        fn copyrows_defaults_to_markdown(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("set id", "id", None)]
+        #[case("set id = 42", "id", Some("42"))]
+        #[case("set id=42", "id", Some("42"))]
+        #[case("set status open", "status", Some("open"))]
+        fn set_binds_cell_or_literal(
+            #[case] input: &str,
+            #[case] name: &str,
+            #[case] value: Option<&str>,
+        ) {
+            assert_eq!(
+                parse_command(input),
+                Command::SetVariable {
+                    name: name.to_string(),
+                    value: value.map(str::to_string),
+                }
+            );
+        }
+
+        #[rstest]
+        #[case("kwcase upper", Command::KeywordCase(Some(KeywordCase::Upper)))]
+        #[case("kwcase LOWER", Command::KeywordCase(Some(KeywordCase::Lower)))]
+        #[case("kwcase off", Command::KeywordCase(None))]
+        #[case("kwcase title", Command::Unknown("kwcase title".to_string()))]
+        fn kwcase_accepts_style(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("prefetch schema", Command::PrefetchScope(PrefetchScope::Schema))]
+        #[case("prefetch  ALL ", Command::PrefetchScope(PrefetchScope::All))]
+        #[case("prefetch some", Command::Unknown("prefetch some".to_string()))]
+        fn prefetch_picks_scope(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("usage", Command::Usage(None))]
+        #[case("usage on", Command::Usage(Some(true)))]
+        #[case("usage  off ", Command::Usage(Some(false)))]
+        #[case("usage reset", Command::Unknown("usage reset".to_string()))]
+        fn usage_shows_or_toggles_tracking(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("version", Command::Version)]
+        #[case("updates on", Command::UpdateCheck(true))]
+        #[case(" updates  off ", Command::UpdateCheck(false))]
+        #[case("updates", Command::Unknown("updates".to_string()))]
+        fn version_and_update_check(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("where", Command::PreviewFilter(None))]
+        #[case(
+            "where  status = 'open' ",
+            Command::PreviewFilter(Some("status = 'open'".to_string()))
+        )]
+        #[case(
+            "where id in (1, 2)",
+            Command::PreviewFilter(Some("id in (1, 2)".to_string()))
+        )]
+        fn where_sets_or_clears_preview_filter(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("columns", Command::PreviewColumns(None))]
+        #[case(
+            "columns id, status ",
+            Command::PreviewColumns(Some("id, status".to_string()))
+        )]
+        fn columns_sets_or_clears_preview_columns(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("cache", Command::CacheInfo)]
+        #[case("cache info", Command::CacheInfo)]
+        #[case("cache clear", Command::CacheClear(None))]
+        #[case("cache clear all", Command::CacheClear(None))]
+        #[case("cache clear er", Command::CacheClear(Some(CacheCategory::ErDiagrams)))]
+        #[case(
+            " cache  clear  Snapshots ",
+            Command::CacheClear(Some(CacheCategory::Snapshots))
+        )]
+        #[case("cache clear drafts", Command::Unknown("cache clear drafts".to_string()))]
+        #[case("cache prune", Command::Unknown("cache prune".to_string()))]
+        fn cache_shows_or_clears_categories(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("route auto", Command::Route(QueryRouting::Auto))]
+        #[case("route primary", Command::Route(QueryRouting::Primary))]
+        #[case("route replica", Command::Route(QueryRouting::Replica))]
+        #[case("route standby", Command::Unknown("route standby".to_string()))]
+        fn route_accepts_endpoint(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("replication", Command::Replication(None))]
+        #[case(
+            "replication public.orders",
+            Command::Replication(Some("public.orders".to_string()))
+        )]
+        #[case("replication   orders ", Command::Replication(Some("orders".to_string())))]
+        fn replication_accepts_optional_table(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("sample", Command::Sample(100))]
+        #[case("sample 25", Command::Sample(25))]
+        #[case(" sample  500 ", Command::Sample(500))]
+        #[case("sample 0", Command::Unknown("sample 0".to_string()))]
+        #[case("sample many", Command::Unknown("sample many".to_string()))]
+        fn sample_accepts_optional_row_count(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("schema", Command::SchemaFilter(None))]
+        #[case("schema audit", Command::SchemaFilter(Some("audit".to_string())))]
+        #[case(" schema   audit ", Command::SchemaFilter(Some("audit".to_string())))]
+        fn schema_accepts_optional_name(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("orphans", Command::ForeignKeyOrphans(None))]
+        #[case(
+            "orphans orders_user_fk",
+            Command::ForeignKeyOrphans(Some("orders_user_fk".to_string()))
+        )]
+        fn orphans_accepts_optional_fk_name(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("suggest fks", Command::SuggestForeignKeys)]
+        #[case("suggest  fks ", Command::SuggestForeignKeys)]
+        #[case("suggest", Command::Unknown("suggest".to_string()))]
+        #[case("suggest indexes", Command::Unknown("suggest indexes".to_string()))]
+        fn suggest_requires_fks_target(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("audit naming", Command::AuditNaming)]
+        #[case("audit", Command::Unknown("audit".to_string()))]
+        #[case("audit perms", Command::Unknown("audit perms".to_string()))]
+        fn audit_requires_naming_target(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("vacuum", Command::Maintenance(MaintenanceKind::VacuumAnalyze))]
+        #[case("analyze", Command::Maintenance(MaintenanceKind::Analyze))]
+        #[case("autovacuum", Command::AutovacuumReport)]
+        #[case("indexes", Command::IndexUsage(IndexUsageSort::Scans))]
+        #[case("indexes size", Command::IndexUsage(IndexUsageSort::Size))]
+        #[case("indexes rows", Command::Unknown("indexes rows".to_string()))]
+        fn maintenance_commands(#[case] input: &str, #[case] expected: Command) {
+            assert_eq!(parse_command(input), expected);
+        }
+
+        #[rstest]
+        #[case("reindex", IndexMaintenanceKind::ReindexConcurrently, None, false)]
+        #[case(
+            "reindex users_email_idx",
+            IndexMaintenanceKind::ReindexConcurrently,
+            Some("users_email_idx"),
+            false
+        )]
+        #[case(
+            "reindex! users_pkey",
+            IndexMaintenanceKind::Reindex,
+            Some("users_pkey"),
+            false
+        )]
+        #[case("rebuild", IndexMaintenanceKind::Rebuild, None, false)]
+        #[case("rebuildsql  idx ", IndexMaintenanceKind::Rebuild, Some("idx"), true)]
+        fn index_maintenance_commands(
+            #[case] input: &str,
+            #[case] kind: IndexMaintenanceKind,
+            #[case] index: Option<&str>,
+            #[case] copy_sql: bool,
+        ) {
+            assert_eq!(
+                parse_command(input),
+                Command::IndexMaintenance {
+                    kind,
+                    index: index.map(str::to_string),
+                    copy_sql,
+                }
+            );
+        }
+
         #[test]
         fn unknown_command_returns_unknown() {
             let result = parse_command("foo");
@@ -225,4 +948,43 @@ mod tests {
             assert!(matches!(result, Action::None));
         }
     }
+
+    mod sql_modal_command_to_action {
+        use super::*;
+
+        #[test]
+        fn write_saves_linked_file() {
+            let result = sql_modal_command_to_action(Command::Write);
+
+            assert!(matches!(result, Action::WriteLinkedSqlFile));
+        }
+
+        #[test]
+        fn write_with_path_saves_under_new_name() {
+            let result = sql_modal_command_to_action(Command::WriteFile("b.sql".to_string()));
+
+            assert!(matches!(result, Action::WriteSqlFileAs(path) if path == "b.sql"));
+        }
+
+        #[test]
+        fn edit_links_file() {
+            let result = sql_modal_command_to_action(Command::EditFile("a.sql".to_string()));
+
+            assert!(matches!(result, Action::LinkSqlFile(path) if path == "a.sql"));
+        }
+
+        #[test]
+        fn save_stores_snippet() {
+            let result = sql_modal_command_to_action(Command::SaveSnippet("a".to_string()));
+
+            assert!(matches!(result, Action::SaveSnippet(name) if name == "a"));
+        }
+
+        #[test]
+        fn browse_commands_are_ignored() {
+            let result = sql_modal_command_to_action(Command::Copy(ResultCopyFormat::Tsv));
+
+            assert!(matches!(result, Action::None));
+        }
+    }
 }
@@ -95,6 +95,27 @@ pub fn handle_connection_selector_keys(combo: KeyCombo) -> Action {
         .unwrap_or(Action::None)
 }
 
+pub fn handle_connection_import_keys(combo: KeyCombo) -> Action {
+    keybindings::CONNECTION_IMPORT
+        .resolve(&combo)
+        .unwrap_or(Action::None)
+}
+
+pub fn handle_session_settings_keys(combo: KeyCombo) -> Action {
+    if let Some(action) = keybindings::SESSION_SETTINGS.resolve(&combo) {
+        return action;
+    }
+    let ctrl = combo.modifiers.contains(Modifiers::CTRL);
+    let alt = combo.modifiers.contains(Modifiers::ALT);
+    match combo.key {
+        Key::Char(c) if !ctrl || alt => Action::TextInput {
+            target: InputTarget::SessionSettings,
+            ch: c,
+        },
+        _ => Action::None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -413,6 +434,7 @@ mod tests {
             OpenSelector,
             ToggleDetails,
             Copy,
+            Offline,
             ScrollUp,
             ScrollDown,
         }
@@ -423,6 +445,7 @@ mod tests {
         #[case(Key::Char('s'), Expected::OpenSelector)]
         #[case(Key::Char('d'), Expected::ToggleDetails)]
         #[case(Key::Char('y'), Expected::Copy)]
+        #[case(Key::Char('o'), Expected::Offline)]
         fn action_keys(#[case] code: Key, #[case] expected: Expected) {
             let result = handle_connection_error_keys(combo(code));
 
@@ -439,6 +462,7 @@ mod tests {
                     assert!(matches!(result, Action::ToggleConnectionErrorDetails));
                 }
                 Expected::Copy => assert!(matches!(result, Action::CopyConnectionError)),
+                Expected::Offline => assert!(matches!(result, Action::EnterOfflineMode)),
                 _ => unreachable!(),
             }
         }
@@ -538,4 +562,24 @@ mod tests {
             assert!(matches!(result, Action::None));
         }
     }
+
+    mod session_settings_keys {
+        use super::*;
+
+        #[rstest]
+        #[case(combo(Key::Tab), Action::SessionSettingsNextField)]
+        #[case(KeyCombo::shift(Key::BackTab), Action::SessionSettingsPrevField)]
+        #[case(combo(Key::Enter), Action::SessionSettingsSave)]
+        #[case(combo(Key::Esc), Action::CloseModal(ModalKind::SessionSettings))]
+        #[case(
+            combo(Key::Char('j')),
+            Action::TextInput { target: InputTarget::SessionSettings, ch: 'j' }
+        )]
+        #[case(combo_ctrl(Key::Char('x')), Action::None)]
+        fn form_keys(#[case] input: KeyCombo, #[case] expected: Action) {
+            let result = handle_session_settings_keys(input);
+
+            assert_eq!(format!("{result:?}"), format!("{expected:?}"));
+        }
+    }
 }
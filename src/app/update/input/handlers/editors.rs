@@ -202,6 +202,7 @@ mod tests {
             Submit,
             Exit,
             Backspace,
+            Complete,
             Input(char),
             None,
         }
@@ -211,7 +212,8 @@ mod tests {
         #[case(Key::Esc, Expected::Exit)]
         #[case(Key::Backspace, Expected::Backspace)]
         #[case(Key::Char('s'), Expected::Input('s'))]
-        #[case(Key::Tab, Expected::None)]
+        #[case(Key::Tab, Expected::Complete)]
+        #[case(Key::BackTab, Expected::None)]
         fn handles_keys(#[case] code: Key, #[case] expected: Expected) {
             let result = handle_command_line_mode(combo(code));
 
@@ -224,6 +226,7 @@ mod tests {
                         target: InputTarget::CommandLine
                     }
                 )),
+                Expected::Complete => assert!(matches!(result, Action::CommandLineComplete)),
                 Expected::Input(ch) => {
                     assert!(
                         matches!(result, Action::TextInput { target: InputTarget::CommandLine, ch: c } if c == ch)
@@ -23,6 +23,13 @@ pub fn resolve_input_interaction(state: &AppState) -> InputInteraction {
         InputMode::QueryHistoryPicker => {
             InputInteraction::FormEditing(InputTarget::QueryHistoryFilter)
         }
+        InputMode::ServerSettings => {
+            InputInteraction::FormEditing(InputTarget::ServerSettingsFilter)
+        }
+        InputMode::SessionSettings => InputInteraction::FormEditing(InputTarget::SessionSettings),
+        InputMode::SqlFileBrowser => InputInteraction::FormEditing(InputTarget::SqlFileFilter),
+        InputMode::SnippetPicker => InputInteraction::FormEditing(InputTarget::SnippetFilter),
+        InputMode::ObjectSearch => InputInteraction::FormEditing(InputTarget::ObjectSearchFilter),
         InputMode::Settings if state.settings.is_editing_custom_er_browser() => {
             InputInteraction::FormEditing(InputTarget::SettingsErBrowser)
         }
@@ -39,6 +46,9 @@ pub fn resolve_input_interaction(state: &AppState) -> InputInteraction {
             }
             _ => InputInteraction::Viewing,
         },
+        InputMode::ConfirmDialog if state.confirm_dialog.required_input().is_some() => {
+            InputInteraction::FormEditing(InputTarget::ConfirmDialog)
+        }
         InputMode::JsonbDetail => match state.jsonb_detail.mode() {
             JsonbDetailMode::Viewing => InputInteraction::Viewing,
             JsonbDetailMode::Editing => InputInteraction::VimEditing(InputTarget::JsonbEdit),
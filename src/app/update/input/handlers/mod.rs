@@ -41,6 +41,11 @@ fn handle_paste_event(text: String, state: &AppState) -> Action {
         | InputMode::ConnectionSetup
         | InputMode::SqlModal
         | InputMode::QueryHistoryPicker
+        | InputMode::ServerSettings
+        | InputMode::SessionSettings
+        | InputMode::SqlFileBrowser
+        | InputMode::SnippetPicker
+        | InputMode::ObjectSearch
         | InputMode::JsonbEdit
         | InputMode::JsonbDetail
         | InputMode::CellDetail => action,
@@ -77,7 +82,7 @@ fn handle_key_event(combo: KeyCombo, state: &AppState) -> Action {
         InputMode::SqlModal => {
             let completion_visible = state.sql_modal.completion().visible
                 && !state.sql_modal.completion().candidates.is_empty();
-            sql_modal::handle_sql_modal_keys_with_feature_policy(
+            let action = sql_modal::handle_sql_modal_keys_with_feature_policy(
                 combo,
                 completion_visible,
                 state.sql_modal.status(),
@@ -88,11 +93,12 @@ fn handle_key_event(combo: KeyCombo, state: &AppState) -> Action {
                 state.ui.key_sequence().pending_prefix(),
                 state.settings.saved_keymap_preset(),
                 &feature_policy,
-            )
+            );
+            sql_modal::constrain_to_vim_state(combo, action, state.sql_modal.vim())
         }
         InputMode::ConnectionSetup => connections::handle_connection_setup_keys(combo, state),
         InputMode::ConnectionError => connections::handle_connection_error_keys(combo),
-        InputMode::ConfirmDialog => overlays::handle_confirm_dialog_keys(combo),
+        InputMode::ConfirmDialog => overlays::handle_confirm_dialog_keys(combo, interaction),
         InputMode::SqliteDiagnostics => {
             overlays::handle_sqlite_diagnostics_keys_with_policy(combo, &feature_policy)
         }
@@ -111,6 +117,14 @@ fn handle_key_event(combo: KeyCombo, state: &AppState) -> Action {
             cell_detail::handle_cell_detail_keys(combo, is_searching)
         }
         InputMode::RowDetail => row_detail::handle_row_detail_keys(combo),
+        InputMode::ServerSettings => pickers::handle_server_settings_keys(combo),
+        InputMode::SessionSettings => connections::handle_session_settings_keys(combo),
+        InputMode::SqlFileBrowser => pickers::handle_sql_file_browser_keys(combo),
+        InputMode::SnippetPicker => pickers::handle_snippet_picker_keys(combo),
+        InputMode::ObjectSearch => pickers::handle_object_search_keys(combo),
+        InputMode::ErDiagram => overlays::handle_er_diagram_keys(combo),
+        InputMode::ConnectionImport => connections::handle_connection_import_keys(combo),
+        InputMode::QuickActions => overlays::handle_quick_actions_keys(combo),
     }
 }
 
@@ -239,10 +253,16 @@ mod tests {
         TableFilter,
         ErFilter,
         QueryHistoryFilter,
+        ServerSettingsFilter,
+        SessionSettings,
+        SqlFileFilter,
+        SnippetFilter,
+        ObjectSearchFilter,
         SettingsBrowser,
         ConnectionSetup,
         SqlModalHighRisk,
         SqlModalAnalyzeHighRisk,
+        ConfirmDialog,
         JsonbSearch,
         HelpFilter,
     }
@@ -270,6 +290,26 @@ mod tests {
                 state.modal.set_mode(InputMode::QueryHistoryPicker);
                 InputTarget::QueryHistoryFilter
             }
+            FormSurface::ServerSettingsFilter => {
+                state.modal.set_mode(InputMode::ServerSettings);
+                InputTarget::ServerSettingsFilter
+            }
+            FormSurface::SessionSettings => {
+                state.modal.set_mode(InputMode::SessionSettings);
+                InputTarget::SessionSettings
+            }
+            FormSurface::SqlFileFilter => {
+                state.modal.set_mode(InputMode::SqlFileBrowser);
+                InputTarget::SqlFileFilter
+            }
+            FormSurface::SnippetFilter => {
+                state.modal.set_mode(InputMode::SnippetPicker);
+                InputTarget::SnippetFilter
+            }
+            FormSurface::ObjectSearchFilter => {
+                state.modal.set_mode(InputMode::ObjectSearch);
+                InputTarget::ObjectSearchFilter
+            }
             FormSurface::SettingsBrowser => {
                 state.modal.set_mode(InputMode::Settings);
                 state.settings.switch_next_section();
@@ -302,6 +342,11 @@ mod tests {
                 );
                 InputTarget::SqlModalAnalyzeHighRisk
             }
+            FormSurface::ConfirmDialog => {
+                state.modal.set_mode(InputMode::ConfirmDialog);
+                state.confirm_dialog.require_input("users");
+                InputTarget::ConfirmDialog
+            }
             FormSurface::JsonbSearch => {
                 state.modal.set_mode(InputMode::JsonbDetail);
                 state.jsonb_detail.enter_search();
@@ -322,10 +367,16 @@ mod tests {
     #[case(FormSurface::TableFilter)]
     #[case(FormSurface::ErFilter)]
     #[case(FormSurface::QueryHistoryFilter)]
+    #[case(FormSurface::ServerSettingsFilter)]
+    #[case(FormSurface::SessionSettings)]
+    #[case(FormSurface::SqlFileFilter)]
+    #[case(FormSurface::SnippetFilter)]
+    #[case(FormSurface::ObjectSearchFilter)]
     #[case(FormSurface::SettingsBrowser)]
     #[case(FormSurface::ConnectionSetup)]
     #[case(FormSurface::SqlModalHighRisk)]
     #[case(FormSurface::SqlModalAnalyzeHighRisk)]
+    #[case(FormSurface::ConfirmDialog)]
     #[case(FormSurface::JsonbSearch)]
     #[case(FormSurface::HelpFilter)]
     fn form_editing_surfaces_prioritize_readline(#[case] surface: FormSurface) {
@@ -347,10 +398,16 @@ mod tests {
     #[case(InputTarget::Filter)]
     #[case(InputTarget::ErFilter)]
     #[case(InputTarget::QueryHistoryFilter)]
+    #[case(InputTarget::ServerSettingsFilter)]
+    #[case(InputTarget::SessionSettings)]
+    #[case(InputTarget::SqlFileFilter)]
+    #[case(InputTarget::SnippetFilter)]
+    #[case(InputTarget::ObjectSearchFilter)]
     #[case(InputTarget::SettingsErBrowser)]
     #[case(InputTarget::ConnectionSetup)]
     #[case(InputTarget::SqlModalHighRisk)]
     #[case(InputTarget::SqlModalAnalyzeHighRisk)]
+    #[case(InputTarget::ConfirmDialog)]
     #[case(InputTarget::JsonbSearch)]
     #[case(InputTarget::HelpFilter)]
     fn readline_leaves_ctrl_n_and_ctrl_p_for_existing_handlers(#[case] target: InputTarget) {
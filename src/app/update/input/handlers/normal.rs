@@ -1,6 +1,8 @@
 use crate::model::app_state::AppState;
 use crate::model::shared::focused_pane::FocusedPane;
+use crate::model::shared::inspector_tab::InspectorTab;
 use crate::model::shared::key_sequence::Prefix;
+use crate::model::shared::ui_state::ResultNavMode;
 use crate::policy::{FeaturePolicy, FeatureRequirement};
 use crate::update::action::{Action, ModalKind};
 use crate::update::input::keybindings::{self as kb, Key, KeyCombo, Modifiers};
@@ -46,6 +48,12 @@ pub fn handle_normal_mode(combo: KeyCombo, state: &AppState) -> Action {
             Key::Char('p') if kb::table_picker(keymap_preset).combos.contains(&combo) => {
                 return Action::OpenModal(ModalKind::TablePicker);
             }
+            Key::Char('g') if kb::global::OBJECT_SEARCH.combos.contains(&combo) => {
+                return Action::OpenModal(ModalKind::ObjectSearch);
+            }
+            Key::Char('_') if kb::result_active::UNDO.combos.contains(&combo) => {
+                return Action::UndoUiMutation;
+            }
             // Ctrl+N/P navigation disabled on main screen; use j/k or arrows.
             // Modals/pickers handle Ctrl+N/P via their own bindings.
             // NOTE: vim/classify.rs still maps Ctrl+N/P → MoveDown/MoveUp for modal
@@ -75,6 +83,16 @@ pub fn handle_normal_mode(combo: KeyCombo, state: &AppState) -> Action {
         return Action::ConfirmSelection;
     }
 
+    // Esc in Result scroll mode drops an active :where filter before anything else
+    if result_navigation
+        && combo == KeyCombo::plain(Key::Esc)
+        && state.result_interaction.selection().mode() == ResultNavMode::Scroll
+        && state.query.pagination.filter().is_some()
+        && state.query.can_paginate_visible_result()
+    {
+        return Action::SetPreviewFilter(None);
+    }
+
     // Shared vim semantics (navigation, mode, operators)
     if let Some(action) = action_for_key(&combo, VimSurfaceContext::Browse(browse_ctx)) {
         return action;
@@ -96,6 +114,19 @@ pub fn handle_normal_mode(combo: KeyCombo, state: &AppState) -> Action {
     {
         return kb::result_active::YANK.action.clone();
     }
+    if result_navigation
+        && kb::result_active::MARK.combos.contains(&combo)
+        && state.result_interaction.selection().row().is_some()
+    {
+        return kb::result_active::MARK.action.clone();
+    }
+    if result_navigation
+        && !staged_delete_in_progress
+        && kb::result_active::RANGE.combos.contains(&combo)
+        && state.result_interaction.selection().cell().is_some()
+    {
+        return kb::result_active::RANGE.action.clone();
+    }
 
     // Non-navigation context keys
     match combo.key {
@@ -113,6 +144,11 @@ pub fn handle_normal_mode(combo: KeyCombo, state: &AppState) -> Action {
                 Action::None
             }
         }
+        Key::Char('o') if result_navigation => kb::footer_nav::PREVIEW_SORT.action.clone(),
+        Key::Char('F') if result_navigation => kb::footer_nav::PREVIEW_FILTER.action.clone(),
+        Key::Char('X') if result_navigation => kb::footer_nav::PREVIEW_EXPLAIN.action.clone(),
+        Key::Char('\'') if result_navigation => kb::result_active::NEXT_MARK.action.clone(),
+        Key::Char('"') if result_navigation => kb::result_active::PREV_MARK.action.clone(),
 
         // Pane switching: exit focus mode first if active
         Key::Char(c @ '1'..='3') => {
@@ -126,6 +162,17 @@ pub fn handle_normal_mode(combo: KeyCombo, state: &AppState) -> Action {
         // Inspector sub-tab navigation (Tab/Shift+Tab, only when Inspector focused)
         Key::Tab if inspector_navigation => Action::InspectorNextTab,
         Key::BackTab if inspector_navigation => Action::InspectorPrevTab,
+        Key::Char('p')
+            if inspector_navigation && state.ui.inspector_tab() == InspectorTab::Columns =>
+        {
+            kb::inspector_columns::PIN.action.clone()
+        }
+        Key::Char('o') if inspector_navigation && state.ui.inspector_tab() == InspectorTab::Rls => {
+            kb::inspector_rls::ROLE_FILTER.action.clone()
+        }
+        Key::Char('a') if inspector_navigation && state.ui.inspector_tab() == InspectorTab::Rls => {
+            kb::inspector_rls::TEST_AS_ROLE.action.clone()
+        }
 
         Key::Char('u') if result_navigation && staged_delete_in_progress => {
             Action::UnstageLastStagedRow
@@ -137,6 +184,12 @@ pub fn handle_normal_mode(combo: KeyCombo, state: &AppState) -> Action {
         Key::Char('c') if state.ui.focused_pane() == FocusedPane::Explorer => {
             Action::OpenModal(ModalKind::ConnectionSelector)
         }
+        Key::Char(' ') if state.ui.focused_pane() == FocusedPane::Explorer => {
+            kb::explorer::MARK.action.clone()
+        }
+        Key::Char('.') if state.ui.focused_pane() == FocusedPane::Explorer => {
+            kb::explorer::QUICK_ACTIONS.action.clone()
+        }
 
         Key::Char('z') => Action::BeginKeySequence(Prefix::Z),
 
@@ -233,6 +286,15 @@ mod tests {
                 assert!(matches!(result, Action::OpenModal(ModalKind::TablePicker)));
             }
 
+            #[test]
+            fn ctrl_underscore_undoes_last_ui_mutation() {
+                let state = browse_state();
+
+                let result = handle_normal_mode(combo_ctrl(Key::Char('_')), &state);
+
+                assert!(matches!(result, Action::UndoUiMutation));
+            }
+
             #[test]
             fn plain_p_is_noop() {
                 let state = browse_state();
@@ -271,6 +333,7 @@ mod tests {
 
             #[rstest]
             #[case(combo_ctrl(Key::Char('p')), Action::OpenModal(ModalKind::TablePicker))]
+            #[case(combo_ctrl(Key::Char('g')), Action::OpenModal(ModalKind::ObjectSearch))]
             #[case(combo(Key::Char(',')), Action::OpenModal(ModalKind::Settings))]
             #[case(combo(Key::F(1)), Action::OpenModal(ModalKind::CommandPalette))]
             #[case(combo_ctrl(Key::Char('r')), Action::ToggleReadOnly)]
@@ -629,6 +692,21 @@ mod tests {
         mod explorer_navigation {
             use super::*;
 
+            #[test]
+            fn dot_opens_quick_actions_only_in_explorer() {
+                let explorer = browse_state();
+                let result_pane = result_focused_state();
+
+                assert!(matches!(
+                    handle_normal_mode(combo(Key::Char('.')), &explorer),
+                    Action::OpenModal(ModalKind::QuickActions)
+                ));
+                assert!(matches!(
+                    handle_normal_mode(combo(Key::Char('.')), &result_pane),
+                    Action::None
+                ));
+            }
+
             #[test]
             fn h_scrolls_left() {
                 let state = browse_state();
@@ -725,6 +803,34 @@ mod tests {
                 ));
             }
 
+            #[test]
+            fn p_pins_only_in_the_columns_tab() {
+                let mut state = inspector_focused_state();
+                state.ui.set_inspector_tab(InspectorTab::Columns);
+
+                let result = handle_normal_mode(combo(Key::Char('p')), &state);
+                assert!(matches!(result, Action::InspectorTogglePin));
+
+                state.ui.set_inspector_tab(InspectorTab::Indexes);
+                let result = handle_normal_mode(combo(Key::Char('p')), &state);
+                assert!(matches!(result, Action::None));
+            }
+
+            #[test]
+            fn o_and_a_drive_roles_only_in_the_rls_tab() {
+                let mut state = inspector_focused_state();
+                state.ui.set_inspector_tab(InspectorTab::Rls);
+
+                let filter = handle_normal_mode(combo(Key::Char('o')), &state);
+                let test = handle_normal_mode(combo(Key::Char('a')), &state);
+                assert!(matches!(filter, Action::InspectorCycleRlsRole));
+                assert!(matches!(test, Action::InspectorTestRlsRole));
+
+                state.ui.set_inspector_tab(InspectorTab::Columns);
+                let result = handle_normal_mode(combo(Key::Char('o')), &state);
+                assert!(matches!(result, Action::None));
+            }
+
             #[test]
             fn ctrl_p_opens_table_picker_from_inspector() {
                 let state = inspector_focused_state();
@@ -849,13 +955,69 @@ mod tests {
             }
 
             #[test]
-            fn uppercase_k_opens_row_detail_with_active_row() {
+            fn o_cycles_preview_sort() {
+                let state = result_focused_state();
+
+                let result = handle_normal_mode(combo(Key::Char('o')), &state);
+
+                assert!(matches!(result, Action::CyclePreviewSort));
+            }
+
+            #[test]
+            fn uppercase_f_opens_preview_filter() {
+                let state = result_focused_state();
+
+                let result = handle_normal_mode(combo(Key::Char('F')), &state);
+
+                assert!(matches!(result, Action::OpenPreviewFilter));
+            }
+
+            #[test]
+            fn esc_clears_an_active_preview_filter() {
+                use std::sync::Arc;
+
+                use crate::domain::{QueryResult, QuerySource};
+
+                let mut state = result_focused_state();
+                state
+                    .query
+                    .set_current_result(Arc::new(QueryResult::success(
+                        "SELECT 1".to_string(),
+                        vec!["col".to_string()],
+                        vec![vec!["value".to_string()]],
+                        1,
+                        QuerySource::Preview,
+                    )));
+                state.query.pagination.reset_for_table("public", "users");
+                let unfiltered = handle_normal_mode(combo(Key::Esc), &state);
+                state
+                    .query
+                    .pagination
+                    .set_filter(Some("col = 'value'".to_string()));
+
+                let result = handle_normal_mode(combo(Key::Esc), &state);
+
+                assert!(matches!(unfiltered, Action::Escape));
+                assert!(matches!(result, Action::SetPreviewFilter(None)));
+            }
+
+            #[test]
+            fn uppercase_x_explains_preview() {
+                let state = result_focused_state();
+
+                let result = handle_normal_mode(combo(Key::Char('X')), &state);
+
+                assert!(matches!(result, Action::ExplainPreview));
+            }
+
+            #[test]
+            fn uppercase_k_peeks_active_cell() {
                 let mut state = result_focused_state();
                 state.result_interaction.activate_cell(0, 0);
 
                 let result = handle_normal_mode(combo(Key::Char('K')), &state);
 
-                assert!(matches!(result, Action::OpenModal(ModalKind::RowDetail)));
+                assert!(matches!(result, Action::ResultPeekCell));
             }
 
             #[test]
@@ -1312,6 +1474,37 @@ mod tests {
                 assert!(matches!(result, Action::ResultCellYank));
             }
 
+            #[test]
+            fn v_toggles_cell_range() {
+                let state = active_cell_state();
+
+                let result = handle_normal_mode(combo(Key::Char('v')), &state);
+
+                assert!(matches!(result, Action::ResultToggleRange));
+            }
+
+            #[rstest]
+            #[case(Key::Char('m'), Action::ResultToggleRowMark)]
+            #[case(Key::Char('\''), Action::ResultNextRowMark)]
+            #[case(Key::Char('"'), Action::ResultPrevRowMark)]
+            fn mark_keys_map_to_row_marks(#[case] key: Key, #[case] expected: Action) {
+                let state = active_cell_state();
+
+                let result = handle_normal_mode(combo(key), &state);
+
+                assert!(same_payload_free_action(&result, &expected));
+            }
+
+            #[test]
+            fn y_with_range_yanks_range() {
+                let mut state = active_cell_state();
+                state.result_interaction.start_range();
+
+                let result = handle_normal_mode(combo(Key::Char('y')), &state);
+
+                assert!(matches!(result, Action::ResultRangeYank));
+            }
+
             #[test]
             fn esc_with_draft_discards_edit() {
                 let mut state = result_focused_state();
@@ -59,8 +59,42 @@ fn handle_help_editing_keys(combo: KeyCombo, feature_policy: &FeaturePolicy) ->
     }
 }
 
-pub fn handle_confirm_dialog_keys(combo: KeyCombo) -> Action {
-    keymap::resolve(&combo, keybindings::CONFIRM_DIALOG_KEYS).unwrap_or(Action::None)
+pub fn handle_confirm_dialog_keys(combo: KeyCombo, interaction: InputInteraction) -> Action {
+    match interaction {
+        InputInteraction::FormEditing(InputTarget::ConfirmDialog) => {
+            handle_confirm_dialog_typing_keys(combo)
+        }
+        _ => keymap::resolve(&combo, keybindings::CONFIRM_DIALOG_KEYS).unwrap_or(Action::None),
+    }
+}
+
+// Typed confirmations take letters as text, so y/n/j/k lose their shortcuts.
+fn handle_confirm_dialog_typing_keys(combo: KeyCombo) -> Action {
+    let target = InputTarget::ConfirmDialog;
+    match (combo.key, combo.modifiers) {
+        (Key::Enter, Modifiers::NONE) => Action::ConfirmDialogConfirm,
+        (Key::Esc, _) => Action::ConfirmDialogCancel,
+        (Key::Backspace, Modifiers::NONE) => Action::TextBackspace { target },
+        (Key::Delete, Modifiers::NONE) => Action::TextDelete { target },
+        (Key::Left, Modifiers::NONE) => Action::TextMoveCursor {
+            target,
+            direction: CursorMove::Left,
+        },
+        (Key::Right, Modifiers::NONE) => Action::TextMoveCursor {
+            target,
+            direction: CursorMove::Right,
+        },
+        (Key::Home, Modifiers::NONE) => Action::TextMoveCursor {
+            target,
+            direction: CursorMove::Home,
+        },
+        (Key::End, Modifiers::NONE) => Action::TextMoveCursor {
+            target,
+            direction: CursorMove::End,
+        },
+        (Key::Char(ch), Modifiers::NONE | Modifiers::SHIFT) => Action::TextInput { target, ch },
+        _ => Action::None,
+    }
 }
 
 pub fn handle_sqlite_diagnostics_keys_with_policy(
@@ -72,6 +106,18 @@ pub fn handle_sqlite_diagnostics_keys_with_policy(
         .unwrap_or(Action::None)
 }
 
+pub fn handle_er_diagram_keys(combo: KeyCombo) -> Action {
+    keybindings::ER_DIAGRAM
+        .resolve(&combo)
+        .unwrap_or(Action::None)
+}
+
+pub fn handle_quick_actions_keys(combo: KeyCombo) -> Action {
+    keybindings::QUICK_ACTIONS
+        .resolve(&combo)
+        .unwrap_or(Action::None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -385,7 +431,7 @@ mod tests {
         #[case(Key::Enter, Action::ConfirmDialogConfirm)]
         #[case(Key::Esc, Action::ConfirmDialogCancel)]
         fn dialog_keys(#[case] code: Key, #[case] expected: Action) {
-            let result = handle_confirm_dialog_keys(combo(code));
+            let result = handle_confirm_dialog_keys(combo(code), InputInteraction::Viewing);
 
             assert_eq!(
                 std::mem::discriminant(&result),
@@ -402,8 +448,10 @@ mod tests {
         #[case(Key::Char('p'))]
         fn scroll_keys_return_scroll_action(#[case] code: Key) {
             let result = match code {
-                Key::Char('n' | 'p') => handle_confirm_dialog_keys(combo_ctrl(code)),
-                _ => handle_confirm_dialog_keys(combo(code)),
+                Key::Char('n' | 'p') => {
+                    handle_confirm_dialog_keys(combo_ctrl(code), InputInteraction::Viewing)
+                }
+                _ => handle_confirm_dialog_keys(combo(code), InputInteraction::Viewing),
             };
 
             assert!(matches!(result, Action::Scroll { .. }));
@@ -416,10 +464,38 @@ mod tests {
         #[case(Key::Char('N'))]
         #[case(Key::Char('x'))]
         fn non_bound_keys_return_none(#[case] code: Key) {
-            let result = handle_confirm_dialog_keys(combo(code));
+            let result = handle_confirm_dialog_keys(combo(code), InputInteraction::Viewing);
 
             assert!(matches!(result, Action::None));
         }
+
+        #[rstest]
+        #[case(Key::Char('y'))]
+        #[case(Key::Char('j'))]
+        fn typed_confirmation_takes_letters_as_text(#[case] code: Key) {
+            let result = handle_confirm_dialog_keys(
+                combo(code),
+                InputInteraction::FormEditing(InputTarget::ConfirmDialog),
+            );
+
+            assert!(matches!(
+                result,
+                Action::TextInput {
+                    target: InputTarget::ConfirmDialog,
+                    ..
+                }
+            ));
+        }
+
+        #[test]
+        fn typed_confirmation_still_confirms_on_enter() {
+            let result = handle_confirm_dialog_keys(
+                combo(Key::Enter),
+                InputInteraction::FormEditing(InputTarget::ConfirmDialog),
+            );
+
+            assert!(matches!(result, Action::ConfirmDialogConfirm));
+        }
     }
 
     mod sqlite_diagnostics_keys {
@@ -439,4 +515,85 @@ mod tests {
             assert!(matches!(result, Action::RunSqliteDiagnosticsQuickCheck));
         }
     }
+
+    mod er_diagram {
+        use super::*;
+        use crate::update::action::{ListMotion, ListTarget};
+
+        #[rstest]
+        #[case(Key::Char('h'), ScrollDirection::Left)]
+        #[case(Key::Char('j'), ScrollDirection::Down)]
+        #[case(Key::Char('k'), ScrollDirection::Up)]
+        #[case(Key::Char('l'), ScrollDirection::Right)]
+        fn hjkl_pans(#[case] key: Key, #[case] expected: ScrollDirection) {
+            let result = handle_er_diagram_keys(combo(key));
+
+            assert!(matches!(
+                result,
+                Action::Scroll {
+                    target: ScrollTarget::ErDiagram,
+                    direction,
+                    amount: ScrollAmount::Line,
+                } if direction == expected
+            ));
+        }
+
+        #[test]
+        fn tab_selects_next_table() {
+            let result = handle_er_diagram_keys(combo(Key::Tab));
+
+            assert!(matches!(
+                result,
+                Action::ListSelect {
+                    target: ListTarget::ErDiagram,
+                    motion: ListMotion::Next,
+                }
+            ));
+        }
+
+        #[rstest]
+        #[case(Key::Char('f'))]
+        #[case(Key::Char('+'))]
+        #[case(Key::Char('-'))]
+        #[case(Key::Esc)]
+        fn viewer_keys_are_bound(#[case] key: Key) {
+            let result = handle_er_diagram_keys(combo(key));
+
+            assert!(!matches!(result, Action::None));
+        }
+    }
+
+    mod quick_actions {
+        use super::*;
+        use crate::update::action::{ListMotion, ListTarget};
+
+        #[rstest]
+        #[case(Key::Char('j'), ListMotion::Next)]
+        #[case(Key::Down, ListMotion::Next)]
+        #[case(Key::Char('k'), ListMotion::Previous)]
+        #[case(Key::Up, ListMotion::Previous)]
+        fn navigation_keys_move_the_selection(#[case] key: Key, #[case] expected: ListMotion) {
+            let result = handle_quick_actions_keys(combo(key));
+
+            assert!(matches!(
+                result,
+                Action::ListSelect {
+                    target: ListTarget::QuickActions,
+                    motion,
+                } if motion == expected
+            ));
+        }
+
+        #[test]
+        fn enter_runs_and_dot_closes() {
+            assert!(matches!(
+                handle_quick_actions_keys(combo(Key::Enter)),
+                Action::QuickActionConfirm
+            ));
+            assert!(matches!(
+                handle_quick_actions_keys(combo(Key::Char('.'))),
+                Action::CloseModal(ModalKind::QuickActions)
+            ));
+        }
+    }
 }
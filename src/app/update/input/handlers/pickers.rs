@@ -82,6 +82,58 @@ pub fn handle_query_history_picker_keys(combo: KeyCombo) -> Action {
     }
 }
 
+pub fn handle_server_settings_keys(combo: KeyCombo) -> Action {
+    if let Some(action) = keybindings::SERVER_SETTINGS.resolve(&combo) {
+        return action;
+    }
+    match combo.key {
+        Key::Char(c) => Action::TextInput {
+            target: InputTarget::ServerSettingsFilter,
+            ch: c,
+        },
+        _ => Action::None,
+    }
+}
+
+pub fn handle_sql_file_browser_keys(combo: KeyCombo) -> Action {
+    if let Some(action) = keybindings::SQL_FILE_BROWSER.resolve(&combo) {
+        return action;
+    }
+    match combo.key {
+        Key::Char(c) => Action::TextInput {
+            target: InputTarget::SqlFileFilter,
+            ch: c,
+        },
+        _ => Action::None,
+    }
+}
+
+pub fn handle_snippet_picker_keys(combo: KeyCombo) -> Action {
+    if let Some(action) = keybindings::SNIPPET_PICKER.resolve(&combo) {
+        return action;
+    }
+    match combo.key {
+        Key::Char(c) => Action::TextInput {
+            target: InputTarget::SnippetFilter,
+            ch: c,
+        },
+        _ => Action::None,
+    }
+}
+
+pub fn handle_object_search_keys(combo: KeyCombo) -> Action {
+    if let Some(action) = keybindings::OBJECT_SEARCH.resolve(&combo) {
+        return action;
+    }
+    match combo.key {
+        Key::Char(c) => Action::TextInput {
+            target: InputTarget::ObjectSearchFilter,
+            ch: c,
+        },
+        _ => Action::None,
+    }
+}
+
 pub fn handle_er_table_picker_keys(combo: KeyCombo, state: &AppState) -> Action {
     let feature_policy = FeaturePolicy::new(state.session.active_engine_feature_profile());
     if let Some(action) = resolve_mode_with_policy(
@@ -1,13 +1,14 @@
 use crate::model::shared::key_sequence::Prefix;
 use crate::model::shared::settings::KeymapPreset;
 use crate::model::sql_editor::modal::{SqlModalStatus, SqlModalTab};
+use crate::model::sql_editor::vim::SqlVimState;
 use crate::policy::{FeaturePolicy, FeatureRequirement};
 use crate::update::action::{
     Action, InputTarget, ModalKind, ScrollAmount, ScrollDirection, ScrollTarget,
 };
 use crate::update::input::keybindings::{
-    Key, KeyCombo, Modifiers, sql_modal_compare_explain, sql_modal_normal_query_history,
-    sql_modal_plan_explain,
+    Key, KeyCombo, Modifiers, sql_modal, sql_modal_compare_explain, sql_modal_normal,
+    sql_modal_normal_query_history, sql_modal_plan_explain,
 };
 use crate::update::input::vim::{
     SqlModalVimContext, VimSurfaceContext, action_for_input, action_for_key,
@@ -34,6 +35,30 @@ pub fn handle_sql_modal_keys_with_feature_policy(
     )
 }
 
+/// Narrows a resolved key while a vim operator or visual-line selection waits:
+/// Esc cancels it, and a pending operator only accepts motions.
+pub fn constrain_to_vim_state(combo: KeyCombo, action: Action, vim: &SqlVimState) -> Action {
+    if !vim.is_busy() {
+        return action;
+    }
+    if combo.key == Key::Esc {
+        return Action::SqlModalVimCancel;
+    }
+    if vim.pending().is_none() {
+        return action;
+    }
+    match action {
+        Action::TextMoveCursor {
+            target: InputTarget::SqlModal,
+            ..
+        }
+        | Action::SqlModalVimOperator(_)
+        | Action::BeginKeySequence(_)
+        | Action::CancelKeySequence => action,
+        _ => Action::SqlModalVimCancel,
+    }
+}
+
 fn handle_sql_modal_keys_internal(
     combo: KeyCombo,
     completion_visible: bool,
@@ -78,6 +103,10 @@ fn handle_sql_modal_keys_internal(
             };
         }
 
+        if sql_modal_normal::RESIZE.combos.contains(&combo) {
+            return Action::SqlModalCycleSize;
+        }
+
         let explain_binding = match active_tab {
             SqlModalTab::Compare => sql_modal_compare_explain(keymap_preset),
             SqlModalTab::Sql | SqlModalTab::Plan => sql_modal_plan_explain(keymap_preset),
@@ -145,6 +174,12 @@ fn handle_sql_modal_keys_internal(
         if ctrl && combo.key == Key::Char('l') {
             return Action::SqlModalClear;
         }
+        if sql_modal_normal::UNDO.combos.contains(&combo) {
+            return Action::UndoUiMutation;
+        }
+        if ctrl && !alt && combo.key == Key::Char('r') {
+            return Action::SqlModalRedoEdit;
+        }
         if plain && combo.key == Key::Char('g') {
             return Action::BeginKeySequence(Prefix::G);
         }
@@ -160,6 +195,27 @@ fn handle_sql_modal_keys_internal(
                 direction: CursorMove::End,
             };
         }
+        // ':' may arrive with SHIFT depending on the terminal.
+        if !ctrl && !alt && combo.key == Key::Char(':') {
+            return Action::EnterCommandLine;
+        }
+        if !ctrl && !alt {
+            match combo.key {
+                Key::Char('Y') => return Action::SqlModalYank,
+                Key::Char('x') => return Action::SqlModalVimDeleteChar,
+                Key::Char('p') => return Action::SqlModalVimPaste { before: false },
+                Key::Char('P') => return Action::SqlModalVimPaste { before: true },
+                Key::Char('V') => return Action::SqlModalVimVisualLine,
+                Key::Char('u') => return Action::SqlModalUndoEdit,
+                Key::Char('e') => {
+                    return Action::TextMoveCursor {
+                        target: InputTarget::SqlModal,
+                        direction: CursorMove::WordEnd,
+                    };
+                }
+                _ => {}
+            }
+        }
 
         if let Some(action) = action_for_key(
             &combo,
@@ -320,6 +376,26 @@ fn handle_sql_modal_keys_internal(
         return Action::SqlModalClear;
     }
 
+    if sql_modal::UNDO.combos.contains(&combo) {
+        return Action::UndoUiMutation;
+    }
+
+    if sql_modal::RESIZE.combos.contains(&combo) {
+        return Action::SqlModalCycleSize;
+    }
+
+    if ctrl_only && combo.key == Key::Char('z') {
+        return Action::SqlModalUndoEdit;
+    }
+
+    if ctrl_only && combo.key == Key::Char('r') {
+        return Action::SqlModalRedoEdit;
+    }
+
+    if sql_modal::RENAME_ALIAS.combos.contains(&combo) {
+        return Action::SqlModalRenameAlias;
+    }
+
     if alt && combo.key == Key::Char('e') {
         return if supports_explain_analyze {
             Action::ExplainAnalyzeRequest
@@ -335,6 +411,9 @@ fn handle_sql_modal_keys_internal(
             Key::Up => return Action::CompletionPrev,
             Key::Down => return Action::CompletionNext,
             Key::Tab | Key::Enter => return Action::CompletionAccept,
+            _ if sql_modal::COMPLETION_PREVIEW.combos.contains(&combo) => {
+                return Action::CompletionTogglePreview;
+            }
             Key::Esc | Key::Left | Key::Right => return Action::CompletionDismiss,
             _ => {}
         }
@@ -393,6 +472,7 @@ fn handle_sql_modal_keys_internal(
 mod tests {
     use super::*;
     use crate::model::shared::engine_feature_profile::EngineFeatureProfile;
+    use crate::model::sql_editor::vim::SqlVimOperator;
     use crate::update::action::CursorMove;
     use crate::update::input::keybindings::{Key, KeyCombo};
     use rstest::rstest;
@@ -859,6 +939,18 @@ mod tests {
             assert_action(result, expected);
         }
 
+        #[test]
+        fn colon_enters_command_line() {
+            let result = handle_sql_modal_keys(
+                combo(Key::Char(':')),
+                false,
+                &SqlModalStatus::Normal,
+                SqlModalTab::Sql,
+            );
+
+            assert!(matches!(result, Action::EnterCommandLine));
+        }
+
         #[test]
         fn g_begins_key_sequence() {
             let result = handle_sql_modal_keys(
@@ -1010,7 +1102,7 @@ mod tests {
         }
         #[rstest]
         #[case(Key::Char('a'))]
-        #[case(Key::Char('e'))]
+        #[case(Key::Char('q'))]
         fn unsupported_keys_remain_unbound(#[case] code: Key) {
             let result = handle_sql_modal_keys(
                 combo(code),
@@ -1089,9 +1181,9 @@ mod tests {
         }
 
         #[test]
-        fn y_yanks_query() {
+        fn shift_y_yanks_query() {
             let result = handle_sql_modal_keys(
-                combo(Key::Char('y')),
+                combo(Key::Char('Y')),
                 false,
                 &SqlModalStatus::Normal,
                 SqlModalTab::Sql,
@@ -1100,6 +1192,85 @@ mod tests {
             assert_action(result, Expected::SqlModalYank);
         }
 
+        #[rstest]
+        #[case(Key::Char('y'), SqlVimOperator::Yank)]
+        #[case(Key::Char('d'), SqlVimOperator::Delete)]
+        fn operator_keys_start_vim_operators(#[case] key: Key, #[case] expected: SqlVimOperator) {
+            let result =
+                handle_sql_modal_keys(combo(key), false, &SqlModalStatus::Normal, SqlModalTab::Sql);
+
+            assert!(matches!(result, Action::SqlModalVimOperator(op) if op == expected));
+        }
+
+        #[test]
+        fn vim_edit_keys() {
+            let key = |c| {
+                handle_sql_modal_keys(
+                    combo(Key::Char(c)),
+                    false,
+                    &SqlModalStatus::Normal,
+                    SqlModalTab::Sql,
+                )
+            };
+
+            assert!(matches!(key('x'), Action::SqlModalVimDeleteChar));
+            assert!(matches!(
+                key('p'),
+                Action::SqlModalVimPaste { before: false }
+            ));
+            assert!(matches!(
+                key('P'),
+                Action::SqlModalVimPaste { before: true }
+            ));
+            assert!(matches!(key('V'), Action::SqlModalVimVisualLine));
+        }
+
+        #[test]
+        fn e_moves_to_word_end() {
+            let result = handle_sql_modal_keys(
+                combo(Key::Char('e')),
+                false,
+                &SqlModalStatus::Normal,
+                SqlModalTab::Sql,
+            );
+
+            assert_action(result, Expected::SqlModalMoveCursor(CursorMove::WordEnd));
+        }
+
+        #[rstest]
+        #[case(Key::Esc, true)]
+        #[case(Key::Char('w'), false)]
+        #[case(Key::Char('d'), false)]
+        #[case(Key::Char('g'), false)]
+        #[case(Key::Char('i'), true)]
+        #[case(Key::Char('q'), true)]
+        fn pending_operator_only_accepts_motions(#[case] key: Key, #[case] cancels: bool) {
+            let mut vim = SqlVimState::default();
+            vim.set_pending(SqlVimOperator::Delete);
+            let action =
+                handle_sql_modal_keys(combo(key), false, &SqlModalStatus::Normal, SqlModalTab::Sql);
+
+            let result = constrain_to_vim_state(combo(key), action, &vim);
+
+            assert_eq!(matches!(result, Action::SqlModalVimCancel), cancels);
+        }
+
+        #[test]
+        fn esc_leaves_visual_line_instead_of_closing() {
+            let mut vim = SqlVimState::default();
+            vim.start_visual_line(0);
+            let action = handle_sql_modal_keys(
+                combo(Key::Esc),
+                false,
+                &SqlModalStatus::Normal,
+                SqlModalTab::Sql,
+            );
+
+            let result = constrain_to_vim_state(combo(Key::Esc), action, &vim);
+
+            assert!(matches!(result, Action::SqlModalVimCancel));
+        }
+
         #[test]
         fn enter_is_unbound() {
             let result = handle_sql_modal_keys(
@@ -1184,12 +1355,52 @@ mod tests {
             assert_action(result, Expected::SqlModalClear);
         }
 
+        #[rstest]
+        #[case(SqlModalStatus::Normal)]
+        #[case(SqlModalStatus::Editing)]
+        fn ctrl_underscore_undoes_last_clear(#[case] status: SqlModalStatus) {
+            let result =
+                handle_sql_modal_keys(combo_ctrl(Key::Char('_')), false, &status, SqlModalTab::Sql);
+
+            assert!(matches!(result, Action::UndoUiMutation));
+        }
+
+        #[rstest]
+        #[case(SqlModalStatus::Normal, SqlModalTab::Sql)]
+        #[case(SqlModalStatus::Editing, SqlModalTab::Sql)]
+        #[case(SqlModalStatus::Success, SqlModalTab::Plan)]
+        #[case(SqlModalStatus::Normal, SqlModalTab::Compare)]
+        fn ctrl_f_cycles_the_editor_size(#[case] status: SqlModalStatus, #[case] tab: SqlModalTab) {
+            let result = handle_sql_modal_keys(combo_ctrl(Key::Char('f')), false, &status, tab);
+
+            assert!(matches!(result, Action::SqlModalCycleSize));
+        }
+
+        #[rstest]
+        #[case(combo(Key::Char('u')), SqlModalStatus::Normal, false)]
+        #[case(combo_ctrl(Key::Char('r')), SqlModalStatus::Normal, true)]
+        #[case(combo_ctrl(Key::Char('z')), SqlModalStatus::Editing, false)]
+        #[case(combo_ctrl(Key::Char('r')), SqlModalStatus::Editing, true)]
+        fn undo_and_redo_edits(
+            #[case] combo: KeyCombo,
+            #[case] status: SqlModalStatus,
+            #[case] redo: bool,
+        ) {
+            let result = handle_sql_modal_keys(combo, false, &status, SqlModalTab::Sql);
+
+            if redo {
+                assert!(matches!(result, Action::SqlModalRedoEdit));
+            } else {
+                assert!(matches!(result, Action::SqlModalUndoEdit));
+            }
+        }
+
         #[rstest]
         #[case(SqlModalStatus::Success)]
         #[case(SqlModalStatus::Error)]
         fn success_error_share_normal_keybindings(#[case] status: SqlModalStatus) {
             let yank =
-                handle_sql_modal_keys(combo(Key::Char('y')), false, &status, SqlModalTab::Sql);
+                handle_sql_modal_keys(combo(Key::Char('Y')), false, &status, SqlModalTab::Sql);
             let enter = handle_sql_modal_keys(combo(Key::Enter), false, &status, SqlModalTab::Sql);
             let close = handle_sql_modal_keys(combo(Key::Esc), false, &status, SqlModalTab::Sql);
 
@@ -1261,6 +1472,18 @@ mod tests {
 
             assert!(!matches!(result, Action::ExplainRequest));
         }
+
+        #[test]
+        fn editing_mode_alt_r_renames_alias() {
+            let result = handle_sql_modal_keys(
+                combo_alt(Key::Char('r')),
+                false,
+                &SqlModalStatus::Editing,
+                SqlModalTab::Sql,
+            );
+
+            assert!(matches!(result, Action::SqlModalRenameAlias));
+        }
     }
 
     mod read_only_tabs {
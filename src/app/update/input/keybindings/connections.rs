@@ -206,6 +206,17 @@ pub mod connection_error {
             combos: &[KeyCombo::plain(Key::Char('r'))],
         }],
     };
+
+    pub const OFFLINE: ModeRow = ModeRow {
+        key_short: "o",
+        key: "o",
+        desc_short: "Offline",
+        description: "Browse cached metadata offline",
+        bindings: &[ExecBinding {
+            action: Action::EnterOfflineMode,
+            combos: &[KeyCombo::plain(Key::Char('o'))],
+        }],
+    };
 }
 
 pub const CONNECTION_ERROR_ROWS: &[ModeRow] = &[
@@ -216,6 +227,7 @@ pub const CONNECTION_ERROR_ROWS: &[ModeRow] = &[
     connection_error::SCROLL,
     connection_error::ESC_CLOSE,
     connection_error::RETRY,
+    connection_error::OFFLINE,
 ];
 
 // =============================================================================
@@ -321,3 +333,191 @@ pub const CONNECTION_SELECTOR_ROWS: &[ModeRow] = &[
     connection_selector::DELETE,
     connection_selector::CLOSE,
 ];
+
+// =============================================================================
+// Connection Import
+// =============================================================================
+
+pub mod connection_import {
+    use crate::update::action::{Action, ListMotion, ListTarget, ModalKind};
+    use crate::update::input::keybindings::{ExecBinding, Key, KeyCombo, ModeRow};
+
+    pub const IMPORT: ModeRow = ModeRow {
+        key_short: "Enter",
+        key: "Enter",
+        desc_short: "Import",
+        description: "Save as a connection and connect (containers connect without saving)",
+        bindings: &[ExecBinding {
+            action: Action::ConnectionImportConfirm,
+            combos: &[KeyCombo::plain(Key::Enter)],
+        }],
+    };
+
+    pub const SELECT: ModeRow = ModeRow {
+        key_short: "^N/^P/↑↓",
+        key: "Ctrl+N / Ctrl+P / ↑ / ↓ / j / k",
+        desc_short: "Nav",
+        description: "Select candidate",
+        bindings: &[
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::ConnectionImport,
+                    motion: ListMotion::Next,
+                },
+                combos: &[
+                    KeyCombo::plain(Key::Char('j')),
+                    KeyCombo::plain(Key::Down),
+                    KeyCombo::ctrl(Key::Char('n')),
+                ],
+            },
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::ConnectionImport,
+                    motion: ListMotion::Previous,
+                },
+                combos: &[
+                    KeyCombo::plain(Key::Char('k')),
+                    KeyCombo::plain(Key::Up),
+                    KeyCombo::ctrl(Key::Char('p')),
+                ],
+            },
+        ],
+    };
+
+    pub const EDIT: ModeRow = ModeRow {
+        key_short: "e",
+        key: "e",
+        desc_short: "Edit",
+        description: "Review in the connection form before saving",
+        bindings: &[ExecBinding {
+            action: Action::ConnectionImportEdit,
+            combos: &[KeyCombo::plain(Key::Char('e'))],
+        }],
+    };
+
+    pub const CLOSE: ModeRow = ModeRow {
+        key_short: "Esc",
+        key: "Esc",
+        desc_short: "Close",
+        description: "Close import",
+        bindings: &[ExecBinding {
+            action: Action::CloseModal(ModalKind::ConnectionImport),
+            combos: &[KeyCombo::plain(Key::Esc)],
+        }],
+    };
+}
+
+pub const CONNECTION_IMPORT_ROWS: &[ModeRow] = &[
+    connection_import::IMPORT,
+    connection_import::SELECT,
+    connection_import::EDIT,
+    connection_import::CLOSE,
+];
+
+// =============================================================================
+// Session Settings
+// =============================================================================
+
+pub mod session_settings {
+    use crate::update::action::{Action, CursorMove, InputTarget, ModalKind};
+    use crate::update::input::keybindings::{ExecBinding, Key, KeyCombo, ModeRow};
+
+    pub const FIELD: ModeRow = ModeRow {
+        key_short: "Tab/⇧Tab",
+        key: "Tab/⇧Tab",
+        desc_short: "Field",
+        description: "Next/Previous field",
+        bindings: &[
+            ExecBinding {
+                action: Action::SessionSettingsNextField,
+                combos: &[KeyCombo::plain(Key::Tab), KeyCombo::plain(Key::Down)],
+            },
+            ExecBinding {
+                action: Action::SessionSettingsPrevField,
+                combos: &[
+                    KeyCombo::shift(Key::BackTab),
+                    KeyCombo::plain(Key::BackTab),
+                    KeyCombo::plain(Key::Up),
+                ],
+            },
+        ],
+    };
+
+    pub const EDIT: ModeRow = ModeRow {
+        key_short: "type",
+        key: "type",
+        desc_short: "Edit",
+        description: "Edit value (blank uses the server default)",
+        bindings: &[
+            ExecBinding {
+                action: Action::TextBackspace {
+                    target: InputTarget::SessionSettings,
+                },
+                combos: &[KeyCombo::plain(Key::Backspace)],
+            },
+            ExecBinding {
+                action: Action::TextDelete {
+                    target: InputTarget::SessionSettings,
+                },
+                combos: &[KeyCombo::plain(Key::Delete)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SessionSettings,
+                    direction: CursorMove::Left,
+                },
+                combos: &[KeyCombo::plain(Key::Left)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SessionSettings,
+                    direction: CursorMove::Right,
+                },
+                combos: &[KeyCombo::plain(Key::Right)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SessionSettings,
+                    direction: CursorMove::Home,
+                },
+                combos: &[KeyCombo::plain(Key::Home)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SessionSettings,
+                    direction: CursorMove::End,
+                },
+                combos: &[KeyCombo::plain(Key::End)],
+            },
+        ],
+    };
+
+    pub const SAVE: ModeRow = ModeRow {
+        key_short: "Enter",
+        key: "Enter",
+        desc_short: "Apply",
+        description: "Save and reconnect",
+        bindings: &[ExecBinding {
+            action: Action::SessionSettingsSave,
+            combos: &[KeyCombo::plain(Key::Enter)],
+        }],
+    };
+
+    pub const ESC_CLOSE: ModeRow = ModeRow {
+        key_short: "Esc",
+        key: "Esc",
+        desc_short: "Cancel",
+        description: "Discard changes",
+        bindings: &[ExecBinding {
+            action: Action::CloseModal(ModalKind::SessionSettings),
+            combos: &[KeyCombo::plain(Key::Esc)],
+        }],
+    };
+}
+
+pub const SESSION_SETTINGS_ROWS: &[ModeRow] = &[
+    session_settings::FIELD,
+    session_settings::EDIT,
+    session_settings::SAVE,
+    session_settings::ESC_CLOSE,
+];
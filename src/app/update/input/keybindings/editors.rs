@@ -1,7 +1,12 @@
 use super::KeyBinding;
 use super::{Key, KeyCombo};
-use crate::model::shared::settings::KeymapPreset;
-use crate::update::action::{Action, ModalKind};
+use crate::domain::{ErDiagramOptions, IndexMaintenanceKind};
+use crate::model::shared::settings::{KeymapPreset, PrefetchScope};
+use crate::policy::index_usage_report::IndexUsageSort;
+use crate::policy::result_copy::ResultCopyFormat;
+use crate::policy::sql::replica_routing::QueryRouting;
+use crate::ports::outbound::TransactionEnd;
+use crate::update::action::{Action, BulkTableOperation, ModalKind};
 
 // =============================================================================
 // SQL Modal (Normal mode — default when opened)
@@ -21,12 +26,57 @@ pub mod sql_modal_normal {
     };
 
     pub const YANK: KeyBinding = KeyBinding {
-        key_short: "y",
-        key: "y",
+        key_short: "Y",
+        key: "Y",
         desc_short: "Yank",
         description: "Copy query to clipboard",
         action: Action::SqlModalYank,
-        combos: &[KeyCombo::plain(Key::Char('y'))],
+        combos: &[KeyCombo::plain(Key::Char('Y'))],
+    };
+
+    pub const OPERATOR: KeyBinding = KeyBinding {
+        key_short: "d/y",
+        key: "d{motion} / y{motion} / dd / yy",
+        desc_short: "Cut/Copy",
+        description: "Delete or yank text to the register",
+        action: Action::None,
+        combos: &[],
+    };
+
+    pub const DELETE_CHAR: KeyBinding = KeyBinding {
+        key_short: "x",
+        key: "x",
+        desc_short: "Del",
+        description: "Delete char under cursor",
+        action: Action::SqlModalVimDeleteChar,
+        combos: &[KeyCombo::plain(Key::Char('x'))],
+    };
+
+    pub const PASTE: KeyBinding = KeyBinding {
+        key_short: "p/P",
+        key: "p / P",
+        desc_short: "Paste",
+        description: "Paste register after / before",
+        action: Action::SqlModalVimPaste { before: false },
+        combos: &[KeyCombo::plain(Key::Char('p'))],
+    };
+
+    pub const VISUAL_LINE: KeyBinding = KeyBinding {
+        key_short: "V",
+        key: "V",
+        desc_short: "V-Line",
+        description: "Select lines for d / y",
+        action: Action::SqlModalVimVisualLine,
+        combos: &[KeyCombo::plain(Key::Char('V'))],
+    };
+
+    pub const UNDO_EDIT: KeyBinding = KeyBinding {
+        key_short: "u/^R",
+        key: "u / Ctrl+R",
+        desc_short: "Undo/Redo",
+        description: "Undo / redo the last edit",
+        action: Action::SqlModalUndoEdit,
+        combos: &[KeyCombo::plain(Key::Char('u'))],
     };
 
     pub const ENTER_INSERT: KeyBinding = KeyBinding {
@@ -57,8 +107,8 @@ pub mod sql_modal_normal {
     };
 
     pub const HOME_END: KeyBinding = KeyBinding {
-        key_short: "0$wb",
-        key: "0 / $ / w / b / Home / End",
+        key_short: "0$wbe",
+        key: "0 / $ / w / b / e / Home / End",
         desc_short: "Jump",
         description: "Move by word or line boundary",
         action: Action::None,
@@ -92,6 +142,33 @@ pub mod sql_modal_normal {
         combos: &[KeyCombo::ctrl(Key::Char('l'))],
     };
 
+    pub const RESIZE: KeyBinding = KeyBinding {
+        key_short: "^F",
+        key: "Ctrl+F",
+        desc_short: "Resize",
+        description: "Cycle the editor size: compact, half, fullscreen",
+        action: Action::SqlModalCycleSize,
+        combos: &[KeyCombo::ctrl(Key::Char('f'))],
+    };
+
+    pub const UNDO: KeyBinding = KeyBinding {
+        key_short: "^_",
+        key: "Ctrl+_",
+        desc_short: "Undo",
+        description: "Undo the last clear, delete, paste or alias rename",
+        action: Action::UndoUiMutation,
+        combos: &[KeyCombo::ctrl(Key::Char('_'))],
+    };
+
+    pub const COMMAND: KeyBinding = KeyBinding {
+        key_short: ":",
+        key: ":",
+        desc_short: "Cmd",
+        description: "Command line (:edit, :w, :e!, :save)",
+        action: Action::EnterCommandLine,
+        combos: &[KeyCombo::plain(Key::Char(':'))],
+    };
+
     pub const QUERY_HISTORY: KeyBinding = KeyBinding {
         key_short: "^O",
         key: "Ctrl+O",
@@ -114,6 +191,11 @@ pub mod sql_modal_normal {
 pub const SQL_MODAL_NORMAL_KEYS: &[KeyBinding] = &[
     sql_modal_normal::RUN,
     sql_modal_normal::YANK,
+    sql_modal_normal::OPERATOR,
+    sql_modal_normal::DELETE_CHAR,
+    sql_modal_normal::PASTE,
+    sql_modal_normal::VISUAL_LINE,
+    sql_modal_normal::UNDO_EDIT,
     sql_modal_normal::ENTER_INSERT,
     sql_modal_normal::APPEND,
     sql_modal_normal::MOVE,
@@ -121,6 +203,9 @@ pub const SQL_MODAL_NORMAL_KEYS: &[KeyBinding] = &[
     sql_modal_normal::VIEWPORT,
     sql_modal_normal::CLOSE,
     sql_modal_normal::CLEAR,
+    sql_modal_normal::RESIZE,
+    sql_modal_normal::UNDO,
+    sql_modal_normal::COMMAND,
     sql_modal_normal::QUERY_HISTORY,
 ];
 
@@ -324,6 +409,17 @@ pub mod sql_modal {
     use crate::update::action::{Action, ModalKind};
     use crate::update::input::keybindings::{Key, KeyBinding, KeyCombo};
 
+    // Contextual: advertised by the status line only while an alias rename
+    // is on offer, so it is left out of SQL_MODAL_KEYS.
+    pub const RENAME_ALIAS: KeyBinding = KeyBinding {
+        key_short: "⌥R",
+        key: "Alt+R",
+        desc_short: "Rename",
+        description: "Rename references to the edited alias",
+        action: Action::SqlModalRenameAlias,
+        combos: &[KeyCombo::alt(Key::Char('r'))],
+    };
+
     pub const RUN: KeyBinding = KeyBinding {
         key_short: "⌥Enter/F5",
         key: "Alt+Enter / F5",
@@ -364,11 +460,20 @@ pub mod sql_modal {
         key_short: "Tab",
         key: "Tab",
         desc_short: "Tab/Complete",
-        description: "Insert tab / Accept completion",
+        description: "Insert tab / Accept completion / Next template stop",
         action: Action::None,
         combos: &[],
     };
 
+    pub const COMPLETION_PREVIEW: KeyBinding = KeyBinding {
+        key_short: "⌥P",
+        key: "Alt+P",
+        desc_short: "Preview",
+        description: "Show or hide the completion preview pane",
+        action: Action::CompletionTogglePreview,
+        combos: &[KeyCombo::alt(Key::Char('p'))],
+    };
+
     pub const CLEAR: KeyBinding = KeyBinding {
         key_short: "^L",
         key: "Ctrl+L",
@@ -378,6 +483,33 @@ pub mod sql_modal {
         combos: &[KeyCombo::ctrl(Key::Char('l'))],
     };
 
+    pub const UNDO_EDIT: KeyBinding = KeyBinding {
+        key_short: "^Z/^R",
+        key: "Ctrl+Z / Ctrl+R",
+        desc_short: "Undo/Redo",
+        description: "Undo / redo the last edit",
+        action: Action::SqlModalUndoEdit,
+        combos: &[KeyCombo::ctrl(Key::Char('z'))],
+    };
+
+    pub const RESIZE: KeyBinding = KeyBinding {
+        key_short: "^F",
+        key: "Ctrl+F",
+        desc_short: "Resize",
+        description: "Cycle the editor size: compact, half, fullscreen",
+        action: Action::SqlModalCycleSize,
+        combos: &[KeyCombo::ctrl(Key::Char('f'))],
+    };
+
+    pub const UNDO: KeyBinding = KeyBinding {
+        key_short: "^_",
+        key: "Ctrl+_",
+        desc_short: "Undo",
+        description: "Restore the buffer before the last clear or alias rename",
+        action: Action::UndoUiMutation,
+        combos: &[KeyCombo::ctrl(Key::Char('_'))],
+    };
+
     pub const QUERY_HISTORY: KeyBinding = KeyBinding {
         key_short: "^O",
         key: "Ctrl+O",
@@ -394,7 +526,11 @@ pub const SQL_MODAL_KEYS: &[KeyBinding] = &[
     sql_modal::MOVE,
     sql_modal::HOME_END,
     sql_modal::TAB,
+    sql_modal::COMPLETION_PREVIEW,
     sql_modal::CLEAR,
+    sql_modal::UNDO_EDIT,
+    sql_modal::RESIZE,
+    sql_modal::UNDO,
     sql_modal::QUERY_HISTORY,
 ];
 
@@ -488,6 +624,18 @@ pub const COMMAND_LINE_KEYS: &[KeyBinding] = &[
         action: Action::OpenModal(ModalKind::ErTablePicker),
         combos: &[],
     },
+    KeyBinding {
+        key_short: ":erd opts",
+        key: ":erd hide=t1,t2 color collapse=N|reset",
+        desc_short: "ER options",
+        description: "Hide hub table edges, color by schema, or draw title-only boxes past N tables",
+        action: Action::SetErDiagramOptions(ErDiagramOptions {
+            hidden_hubs: Vec::new(),
+            color_by_schema: false,
+            collapse_above: None,
+        }),
+        combos: &[],
+    },
     KeyBinding {
         key_short: ":settings",
         key: ":settings",
@@ -512,6 +660,372 @@ pub const COMMAND_LINE_KEYS: &[KeyBinding] = &[
         action: Action::OpenModal(ModalKind::CommandPalette),
         combos: &[],
     },
+    KeyBinding {
+        key_short: ":copy",
+        key: ":copy [csv|tsv|md|org]",
+        desc_short: "Copy",
+        description: "Copy all result rows (large results spill to a temp file)",
+        action: Action::ResultCopyAll(ResultCopyFormat::Tsv),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":copyrows",
+        key: ":copyrows [md|org|csv|tsv]",
+        desc_short: "Copy rows",
+        description: "Copy the marked rows, else the selected or visible rows, as a table",
+        action: Action::ResultCopyRows(ResultCopyFormat::Markdown),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":numfmt",
+        key: ":numfmt",
+        desc_short: "Num format",
+        description: "Toggle formatted numbers for the active result column",
+        action: Action::ResultToggleNumberFormat,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":durfmt",
+        key: ":durfmt",
+        desc_short: "Duration",
+        description: "Toggle humanized durations for the active interval/epoch column",
+        action: Action::ResultToggleDurationFormat,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":types",
+        key: ":types",
+        desc_short: "Col types",
+        description: "Toggle a header row with each result column's type",
+        action: Action::ResultToggleColumnTypes,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":diff",
+        key: ":diff",
+        desc_short: "Old values",
+        description: "Toggle struck-through previous values next to cells changed since the last run",
+        action: Action::ResultToggleDiffOldValues,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":geom",
+        key: ":geom",
+        desc_short: "Geometry",
+        description: "Plot the active PostGIS geometry cell and copy its WKT",
+        action: Action::ResultOpenGeometryPreview,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":set",
+        key: ":set <name> [= value]",
+        desc_short: "Set var",
+        description: "Bind the active cell (or a value) to :name for SQL modal queries",
+        action: Action::ResultBindVariable {
+            name: String::new(),
+            value: None,
+        },
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":kwcase",
+        key: ":kwcase upper|lower|off",
+        desc_short: "Kw case",
+        description: "Auto-correct SQL keyword case while typing in the SQL modal",
+        action: Action::SetKeywordCase(None),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":prefetch",
+        key: ":prefetch all|schema",
+        desc_short: "Prefetch",
+        description: "Prefetch every table for SQL completion, or only the schemas in use",
+        action: Action::SetPrefetchScope(PrefetchScope::Schema),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":route",
+        key: ":route auto|primary|replica",
+        desc_short: "Route",
+        description: "Choose which endpoint serves ad-hoc queries when a replica is configured",
+        action: Action::SetQueryRouting(QueryRouting::Auto),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":replication",
+        key: ":replication [schema.table]",
+        desc_short: "Replication",
+        description: "List publications, subscriptions and logical slot lag, optionally for one table",
+        action: Action::ShowReplicationOverview(None),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":unmask",
+        key: ":unmask",
+        desc_short: "Unmask",
+        description: "Reveal the columns masked by masking.toml in the current result; audit-logged",
+        action: Action::ResultUnmask,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":sample",
+        key: ":sample [n]",
+        desc_short: "Sample",
+        description: "Show n random rows of the selected table instead of the first ones (default 100)",
+        action: Action::SampleTable(100),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":where",
+        key: ":where <expr>",
+        desc_short: "Filter",
+        description: "Re-run the table preview with a WHERE predicate; bare :where clears it",
+        action: Action::SetPreviewFilter(None),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":columns",
+        key: ":columns <a, b>",
+        desc_short: "Columns",
+        description: "Choose the columns this table's preview selects, saved per project; bare :columns resets",
+        action: Action::SetPreviewColumns(None),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":orphans",
+        key: ":orphans [fk]",
+        desc_short: "FK Orphans",
+        description: "Count rows whose foreign key has no parent row, per FK of the table",
+        action: Action::CheckForeignKeyOrphans(None),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":suggest fks",
+        key: ":suggest fks",
+        desc_short: "Suggest FKs",
+        description: "Find *_id columns without a foreign key and draft ALTER TABLE statements",
+        action: Action::SuggestForeignKeys,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":audit naming",
+        key: ":audit naming",
+        desc_short: "Naming Audit",
+        description: "Check names against naming.toml conventions, grouped by severity",
+        action: Action::AuditNaming,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":timings",
+        key: ":timings",
+        desc_short: "Timings",
+        description: "Show duration, rows and status per statement of the last script run",
+        action: Action::ShowStatementTimings,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":startup",
+        key: ":startup report",
+        desc_short: "Startup",
+        description: "Show how long each startup phase took against its budget",
+        action: Action::ShowStartupReport,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":version",
+        key: ":version",
+        desc_short: "Version",
+        description: "Show build info and, with :updates on, the latest release and its changelog",
+        action: Action::ShowVersion,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":updates",
+        key: ":updates on|off",
+        desc_short: "Updates",
+        description: "Opt in to a background check for newer releases on GitHub at startup",
+        action: Action::SetUpdateCheck(true),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":begin",
+        key: ":begin",
+        desc_short: "Begin",
+        description: "Open a transaction; later queries and writes run inside it",
+        action: Action::BeginTransaction,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":commit",
+        key: ":commit",
+        desc_short: "Commit",
+        description: "Commit the open transaction",
+        action: Action::EndTransaction(TransactionEnd::Commit),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":rollback",
+        key: ":rollback",
+        desc_short: "Rollback",
+        description: "Roll back the open transaction",
+        action: Action::EndTransaction(TransactionEnd::Rollback),
+        combos: &[],
+    },
+    super::global::VACUUM_ANALYZE,
+    super::global::ANALYZE,
+    KeyBinding {
+        key_short: ":autovacuum",
+        key: ":autovacuum",
+        desc_short: "Autovacuum",
+        description: "Report dead tuples, autovacuum settings and last runs, with tuning hints",
+        action: Action::ShowAutovacuumReport,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":indexes",
+        key: ":indexes [size]",
+        desc_short: "Index usage",
+        description: "List index scans and sizes, flagging never-used ones; add size to sort largest first",
+        action: Action::ShowIndexUsage(IndexUsageSort::Scans),
+        combos: &[],
+    },
+    super::global::REINDEX,
+    KeyBinding {
+        key_short: ":reindex!",
+        key: ":reindex! [index]",
+        desc_short: "Reindex (lock)",
+        description: "Run a blocking REINDEX; large tables ask for confirmation first",
+        action: Action::StartIndexMaintenance {
+            kind: IndexMaintenanceKind::Reindex,
+            index: None,
+            confirmed: false,
+        },
+        combos: &[],
+    },
+    super::global::REBUILD_INDEX,
+    KeyBinding {
+        key_short: ":reindexsql",
+        key: ":reindexsql|rebuildsql [index]",
+        desc_short: "Index SQL",
+        description: "Copy the REINDEX CONCURRENTLY or rebuild statements without running them",
+        action: Action::CopyIndexMaintenanceSql {
+            kind: IndexMaintenanceKind::ReindexConcurrently,
+            index: None,
+        },
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":bulk",
+        key: ":bulk ddl|er|analyze|sizes",
+        desc_short: "Bulk tables",
+        description: "Copy DDL, draw an ER diagram, ANALYZE or size the tables marked with Space",
+        action: Action::BulkTables(BulkTableOperation::CopyDdl),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":bulk clear",
+        key: ":bulk clear",
+        desc_short: "Clear marks",
+        description: "Unmark every table in the Explorer",
+        action: Action::ExplorerClearMarks,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":schema",
+        key: ":schema [name]",
+        desc_short: "Schema filter",
+        description: "Show only one schema in the Explorer; without a name, show them all",
+        action: Action::SetExplorerSchemaFilter(None),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":pgsettings",
+        key: ":pgsettings",
+        desc_short: "Server settings",
+        description: "Browse pg_settings with current, reset and boot values",
+        action: Action::OpenModal(ModalKind::ServerSettings),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":session",
+        key: ":session",
+        desc_short: "Session settings",
+        description: "Edit the GUC overrides applied when this profile connects",
+        action: Action::OpenModal(ModalKind::SessionSettings),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":files",
+        key: ":files",
+        desc_short: "SQL files",
+        description: "Browse .sql files in the project to open or run",
+        action: Action::OpenModal(ModalKind::SqlFileBrowser),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":conn",
+        key: ":conn|connections",
+        desc_short: "Connections",
+        description: "Switch to another saved connection without restarting",
+        action: Action::OpenModal(ModalKind::ConnectionSelector),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":connect import",
+        key: ":connect import",
+        desc_short: "Import connection",
+        description: "Create a connection from pg_service.conf, .pgpass, .env or compose files",
+        action: Action::OpenModal(ModalKind::ConnectionImport),
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":connect docker",
+        key: ":connect docker|containers",
+        desc_short: "Container DBs",
+        description: "Connect to a running Docker/Podman database container",
+        action: Action::OpenContainerDiscovery,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":edit",
+        key: ":edit|e <file.sql>",
+        desc_short: "Edit file",
+        description: "Link the SQL editor to a file; external saves reload it and :w writes back",
+        action: Action::None,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":e!",
+        key: ":edit!|e!",
+        desc_short: "Reload file",
+        description: "Discard editor changes and reload the linked file",
+        action: Action::ReloadLinkedSqlFile,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":w <file>",
+        key: ":write|w <file.sql>",
+        desc_short: "Write file",
+        description: "Write the SQL editor to a new file and link the editor to it",
+        action: Action::None,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":save",
+        key: ":save <name>",
+        desc_short: "Save snippet",
+        description: "Save the SQL editor content as a named project snippet",
+        action: Action::None,
+        combos: &[],
+    },
+    KeyBinding {
+        key_short: ":snippets",
+        key: ":snippets",
+        desc_short: "Snippets",
+        description: "Pick a saved snippet to insert or run",
+        action: Action::OpenModal(ModalKind::SnippetPicker),
+        combos: &[],
+    },
     KeyBinding {
         key_short: "←→",
         key: "←→",
@@ -528,6 +1042,14 @@ pub const COMMAND_LINE_KEYS: &[KeyBinding] = &[
         action: Action::None,
         combos: &[],
     },
+    KeyBinding {
+        key_short: "Tab",
+        key: "Tab",
+        desc_short: "Complete",
+        description: "Complete a column name after :where",
+        action: Action::CommandLineComplete,
+        combos: &[KeyCombo::plain(Key::Tab)],
+    },
     KeyBinding {
         key_short: "Enter",
         key: "Enter",
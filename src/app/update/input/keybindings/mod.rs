@@ -121,6 +121,30 @@ pub const SQLITE_DIAGNOSTICS: ModeBindings = ModeBindings {
 pub const ROW_DETAIL: ModeBindings = ModeBindings {
     rows: ROW_DETAIL_ROWS,
 };
+pub const SERVER_SETTINGS: ModeBindings = ModeBindings {
+    rows: SERVER_SETTINGS_ROWS,
+};
+pub const SESSION_SETTINGS: ModeBindings = ModeBindings {
+    rows: SESSION_SETTINGS_ROWS,
+};
+pub const SQL_FILE_BROWSER: ModeBindings = ModeBindings {
+    rows: SQL_FILE_BROWSER_ROWS,
+};
+pub const SNIPPET_PICKER: ModeBindings = ModeBindings {
+    rows: SNIPPET_PICKER_ROWS,
+};
+pub const OBJECT_SEARCH: ModeBindings = ModeBindings {
+    rows: OBJECT_SEARCH_ROWS,
+};
+pub const ER_DIAGRAM: ModeBindings = ModeBindings {
+    rows: ER_DIAGRAM_ROWS,
+};
+pub const CONNECTION_IMPORT: ModeBindings = ModeBindings {
+    rows: CONNECTION_IMPORT_ROWS,
+};
+pub const QUICK_ACTIONS: ModeBindings = ModeBindings {
+    rows: QUICK_ACTIONS_ROWS,
+};
 
 pub const ALL_MODE_BINDINGS: &[(&str, &ModeBindings)] = &[
     ("HELP", &HELP),
@@ -136,6 +160,14 @@ pub const ALL_MODE_BINDINGS: &[(&str, &ModeBindings)] = &[
     ("CELL_DETAIL", &CELL_DETAIL),
     ("SQLITE_DIAGNOSTICS", &SQLITE_DIAGNOSTICS),
     ("ROW_DETAIL", &ROW_DETAIL),
+    ("SERVER_SETTINGS", &SERVER_SETTINGS),
+    ("SESSION_SETTINGS", &SESSION_SETTINGS),
+    ("SQL_FILE_BROWSER", &SQL_FILE_BROWSER),
+    ("SNIPPET_PICKER", &SNIPPET_PICKER),
+    ("OBJECT_SEARCH", &OBJECT_SEARCH),
+    ("ER_DIAGRAM", &ER_DIAGRAM),
+    ("CONNECTION_IMPORT", &CONNECTION_IMPORT),
+    ("QUICK_ACTIONS", &QUICK_ACTIONS),
 ];
 
 pub const HELP_KEY_INDENT_WIDTH: usize = 2;
@@ -592,6 +624,51 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn server_settings_has_no_plain_char_combos() {
+                check_no_plain_char_in_filter_mode_rows(
+                    SERVER_SETTINGS_ROWS,
+                    "SERVER_SETTINGS_ROWS",
+                    &[],
+                );
+            }
+
+            #[test]
+            fn sql_file_browser_has_no_plain_char_combos() {
+                check_no_plain_char_in_filter_mode_rows(
+                    SQL_FILE_BROWSER_ROWS,
+                    "SQL_FILE_BROWSER_ROWS",
+                    &[],
+                );
+            }
+
+            #[test]
+            fn snippet_picker_has_no_plain_char_combos() {
+                check_no_plain_char_in_filter_mode_rows(
+                    SNIPPET_PICKER_ROWS,
+                    "SNIPPET_PICKER_ROWS",
+                    &[],
+                );
+            }
+
+            #[test]
+            fn object_search_has_no_plain_char_combos() {
+                check_no_plain_char_in_filter_mode_rows(
+                    OBJECT_SEARCH_ROWS,
+                    "OBJECT_SEARCH_ROWS",
+                    &[],
+                );
+            }
+
+            #[test]
+            fn session_settings_has_no_plain_char_combos() {
+                check_no_plain_char_in_filter_mode_rows(
+                    SESSION_SETTINGS_ROWS,
+                    "SESSION_SETTINGS_ROWS",
+                    &[],
+                );
+            }
+
             #[test]
             fn command_line_has_no_problematic_plain_char_combos() {
                 check_no_plain_char_in_filter_mode(COMMAND_LINE_KEYS, "COMMAND_LINE_KEYS", &[]);
@@ -608,7 +685,7 @@ mod tests {
 
             #[test]
             fn all_mode_bindings_count() {
-                assert_eq!(ALL_MODE_BINDINGS.len(), 13);
+                assert_eq!(ALL_MODE_BINDINGS.len(), 21);
             }
         }
     }
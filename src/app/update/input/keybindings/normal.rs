@@ -8,6 +8,7 @@ use crate::update::action::Action;
 // =============================================================================
 
 pub mod global {
+    use crate::domain::{IndexMaintenanceKind, MaintenanceKind};
     use crate::update::action::{Action, ModalKind};
     use crate::update::input::keybindings::{Key, KeyBinding, KeyCombo};
 
@@ -38,6 +39,15 @@ pub mod global {
         combos: &[KeyCombo::ctrl(Key::Char('p'))],
     };
 
+    pub const OBJECT_SEARCH: KeyBinding = KeyBinding {
+        key_short: "^G",
+        key: "Ctrl+G",
+        desc_short: "Search",
+        description: "Search tables, columns, views and comments",
+        action: Action::OpenModal(ModalKind::ObjectSearch),
+        combos: &[KeyCombo::ctrl(Key::Char('g'))],
+    };
+
     pub const TABLE_PICKER_IDE: KeyBinding = KeyBinding {
         key_short: "T",
         key: "T",
@@ -253,12 +263,58 @@ pub mod global {
         action: Action::OpenModal(ModalKind::SqliteDiagnostics),
         combos: &[KeyCombo::plain(Key::Char('D'))],
     };
+
+    // Palette and command-line entries only; maintenance has no key combo.
+    pub const VACUUM_ANALYZE: KeyBinding = KeyBinding {
+        key_short: ":vacuum",
+        key: ":vacuum",
+        desc_short: "Vacuum",
+        description: "Run VACUUM (ANALYZE) on the selected table",
+        action: Action::StartMaintenance(MaintenanceKind::VacuumAnalyze),
+        combos: &[],
+    };
+
+    pub const ANALYZE: KeyBinding = KeyBinding {
+        key_short: ":analyze",
+        key: ":analyze",
+        desc_short: "Analyze",
+        description: "Run ANALYZE on the selected table",
+        action: Action::StartMaintenance(MaintenanceKind::Analyze),
+        combos: &[],
+    };
+
+    pub const REINDEX: KeyBinding = KeyBinding {
+        key_short: ":reindex",
+        key: ":reindex [index]",
+        desc_short: "Reindex",
+        description: "REINDEX CONCURRENTLY the named index or the top row of the Indexes tab",
+        action: Action::StartIndexMaintenance {
+            kind: IndexMaintenanceKind::ReindexConcurrently,
+            index: None,
+            confirmed: false,
+        },
+        combos: &[],
+    };
+
+    pub const REBUILD_INDEX: KeyBinding = KeyBinding {
+        key_short: ":rebuild",
+        key: ":rebuild [index]",
+        desc_short: "Rebuild index",
+        description: "Build a concurrent copy of the index, then drop the original and swap names",
+        action: Action::StartIndexMaintenance {
+            kind: IndexMaintenanceKind::Rebuild,
+            index: None,
+            confirmed: false,
+        },
+        combos: &[],
+    };
 }
 
 pub const DEFAULT_GLOBAL_KEYS: &[KeyBinding] = &[
     global::QUIT,
     global::HELP,
     global::TABLE_PICKER,
+    global::OBJECT_SEARCH,
     global::SETTINGS,
     global::COMMAND_LINE,
     global::COMMAND_PALETTE,
@@ -281,6 +337,7 @@ pub const IDE_GLOBAL_KEYS: &[KeyBinding] = &[
     global::QUIT,
     global::HELP,
     global::TABLE_PICKER_IDE,
+    global::OBJECT_SEARCH,
     global::SETTINGS,
     global::COMMAND_LINE,
     global::COMMAND_PALETTE_IDE,
@@ -454,7 +511,7 @@ pub const NAVIGATION_KEYS: &[KeyBinding] = &[
         key_short: "]",
         key: "]",
         desc_short: "Next Page",
-        description: "Next page (Preview)",
+        description: "Next page (Preview; PageDown on the last row)",
         action: Action::ResultNextPage,
         combos: &[KeyCombo::plain(Key::Char(']'))],
     },
@@ -462,7 +519,7 @@ pub const NAVIGATION_KEYS: &[KeyBinding] = &[
         key_short: "[",
         key: "[",
         desc_short: "Prev Page",
-        description: "Previous page (Preview)",
+        description: "Previous page (Preview; PageUp on the first row)",
         action: Action::ResultPrevPage,
         combos: &[KeyCombo::plain(Key::Char('['))],
     },
@@ -470,7 +527,7 @@ pub const NAVIGATION_KEYS: &[KeyBinding] = &[
 
 pub mod footer_nav {
     use crate::update::action::Action;
-    use crate::update::input::keybindings::KeyBinding;
+    use crate::update::input::keybindings::{Key, KeyBinding, KeyCombo};
 
     pub const SCROLL: KeyBinding = KeyBinding {
         key_short: "j/↓",
@@ -516,6 +573,33 @@ pub mod footer_nav {
         action: Action::None,
         combos: &[],
     };
+
+    pub const PREVIEW_SORT: KeyBinding = KeyBinding {
+        key_short: "o",
+        key: "o",
+        desc_short: "Sort",
+        description: "Order the preview by the next indexed column (back to key order after the last)",
+        action: Action::CyclePreviewSort,
+        combos: &[KeyCombo::plain(Key::Char('o'))],
+    };
+
+    pub const PREVIEW_FILTER: KeyBinding = KeyBinding {
+        key_short: "F",
+        key: "F",
+        desc_short: "Filter",
+        description: "Filter the preview with a WHERE predicate (Esc clears it)",
+        action: Action::OpenPreviewFilter,
+        combos: &[KeyCombo::plain(Key::Char('F'))],
+    };
+
+    pub const PREVIEW_EXPLAIN: KeyBinding = KeyBinding {
+        key_short: "X",
+        key: "X",
+        desc_short: "Explain",
+        description: "Show the preview's executed query, timing and EXPLAIN plan",
+        action: Action::ExplainPreview,
+        combos: &[KeyCombo::plain(Key::Char('X'))],
+    };
 }
 
 pub const FOOTER_NAV_KEYS: &[KeyBinding] = &[
@@ -524,10 +608,13 @@ pub const FOOTER_NAV_KEYS: &[KeyBinding] = &[
     footer_nav::TOP_BOTTOM,
     footer_nav::H_SCROLL,
     footer_nav::PAGE_NAV,
+    footer_nav::PREVIEW_SORT,
+    footer_nav::PREVIEW_FILTER,
+    footer_nav::PREVIEW_EXPLAIN,
 ];
 
 pub mod result_active {
-    use crate::update::action::{Action, ModalKind};
+    use crate::update::action::Action;
     use crate::update::input::keybindings::{Key, KeyBinding, KeyCombo};
 
     pub const ENTER_DEEPEN: KeyBinding = KeyBinding {
@@ -557,6 +644,33 @@ pub mod result_active {
         combos: &[KeyCombo::plain(Key::Char('Y'))],
     };
 
+    pub const RANGE: KeyBinding = KeyBinding {
+        key_short: "v",
+        key: "v",
+        desc_short: "Visual",
+        description: "Start or drop a cell range anchored at the active cell",
+        action: Action::ResultToggleRange,
+        combos: &[KeyCombo::plain(Key::Char('v'))],
+    };
+
+    pub const RANGE_YANK: KeyBinding = KeyBinding {
+        key_short: "y",
+        key: "y",
+        desc_short: "Yank Range",
+        description: "Copy the selected cell range to clipboard (TSV)",
+        action: Action::ResultRangeYank,
+        combos: &[], // resolved through the shared yank operator
+    };
+
+    pub const RANGE_CLEAR: KeyBinding = KeyBinding {
+        key_short: "Esc",
+        key: "Esc",
+        desc_short: "Clear",
+        description: "Clear the cell range and keep the active cell",
+        action: Action::ResultClearRange,
+        combos: &[KeyCombo::plain(Key::Esc)],
+    };
+
     pub const STAGE_DELETE: KeyBinding = KeyBinding {
         key_short: "dd",
         key: "d, d",
@@ -575,6 +689,15 @@ pub mod result_active {
         combos: &[KeyCombo::plain(Key::Char('u'))],
     };
 
+    pub const UNDO: KeyBinding = KeyBinding {
+        key_short: "^_",
+        key: "Ctrl+_",
+        desc_short: "Undo",
+        description: "Restore cleared staged deletes or a discarded cell edit",
+        action: Action::UndoUiMutation,
+        combos: &[KeyCombo::ctrl(Key::Char('_'))],
+    };
+
     pub const CELL_NAV: KeyBinding = KeyBinding {
         key_short: "h/l",
         key: "h / l",
@@ -641,19 +764,50 @@ pub mod result_active {
     pub const ROW_DETAIL: KeyBinding = KeyBinding {
         key_short: "K",
         key: "K",
-        desc_short: "Row Detail",
-        description: "Open Row Detail",
-        action: Action::OpenModal(ModalKind::RowDetail),
+        desc_short: "Peek/Detail",
+        description: "Peek at a truncated cell (also shown after a short pause), otherwise open Row Detail",
+        action: Action::ResultPeekCell,
         combos: &[KeyCombo::plain(Key::Char('K'))],
     };
+
+    pub const MARK: KeyBinding = KeyBinding {
+        key_short: "m",
+        key: "m",
+        desc_short: "Mark",
+        description: "Toggle a mark on the active row (:copyrows copies marked rows)",
+        action: Action::ResultToggleRowMark,
+        combos: &[KeyCombo::plain(Key::Char('m'))],
+    };
+
+    pub const NEXT_MARK: KeyBinding = KeyBinding {
+        key_short: "'",
+        key: "'",
+        desc_short: "Next Mark",
+        description: "Jump to the next marked row",
+        action: Action::ResultNextRowMark,
+        combos: &[KeyCombo::plain(Key::Char('\''))],
+    };
+
+    pub const PREV_MARK: KeyBinding = KeyBinding {
+        key_short: "\"",
+        key: "\"",
+        desc_short: "Prev Mark",
+        description: "Jump to the previous marked row",
+        action: Action::ResultPrevRowMark,
+        combos: &[KeyCombo::plain(Key::Char('"'))],
+    };
 }
 
 pub const RESULT_ACTIVE_KEYS: &[KeyBinding] = &[
     result_active::DETAIL,
     result_active::YANK,
+    result_active::RANGE,
+    result_active::RANGE_YANK,
+    result_active::RANGE_CLEAR,
     result_active::ROW_DETAIL,
     result_active::STAGE_DELETE,
     result_active::UNSTAGE_DELETE,
+    result_active::UNDO,
     result_active::CELL_NAV,
     result_active::ROW_NAV,
     result_active::TOP_BOTTOM,
@@ -661,6 +815,9 @@ pub const RESULT_ACTIVE_KEYS: &[KeyBinding] = &[
     result_active::EDIT,
     result_active::DRAFT_DISCARD,
     result_active::ROW_YANK,
+    result_active::MARK,
+    result_active::NEXT_MARK,
+    result_active::PREV_MARK,
 ];
 
 pub mod inspector_ddl {
@@ -678,3 +835,93 @@ pub mod inspector_ddl {
 }
 
 pub const INSPECTOR_DDL_KEYS: &[KeyBinding] = &[inspector_ddl::YANK];
+
+pub mod inspector_columns {
+    use crate::update::action::Action;
+    use crate::update::input::keybindings::{Key, KeyBinding, KeyCombo};
+
+    pub const PIN: KeyBinding = KeyBinding {
+        key_short: "p",
+        key: "p",
+        desc_short: "Pin",
+        description: "Pin column (highlight it in Indexes/FKs/RLS and the result)",
+        action: Action::InspectorTogglePin,
+        combos: &[KeyCombo::plain(Key::Char('p'))],
+    };
+}
+
+pub const INSPECTOR_COLUMNS_KEYS: &[KeyBinding] = &[inspector_columns::PIN];
+
+pub mod inspector_stats {
+    use crate::update::action::Action;
+    use crate::update::input::keybindings::KeyBinding;
+
+    pub const COLUMN_NAV: KeyBinding = KeyBinding {
+        key_short: "j/k",
+        key: "j / k",
+        desc_short: "Column",
+        description: "Show stats for the next/previous column",
+        action: Action::None,
+        combos: &[],
+    };
+}
+
+pub const INSPECTOR_STATS_KEYS: &[KeyBinding] = &[inspector_stats::COLUMN_NAV];
+
+pub mod inspector_rls {
+    use crate::update::action::Action;
+    use crate::update::input::keybindings::{Key, KeyBinding, KeyCombo};
+
+    pub const ROLE_FILTER: KeyBinding = KeyBinding {
+        key_short: "o",
+        key: "o",
+        desc_short: "Role",
+        description: "Show policies for one role at a time (cycles back to all roles)",
+        action: Action::InspectorCycleRlsRole,
+        combos: &[KeyCombo::plain(Key::Char('o'))],
+    };
+
+    pub const TEST_AS_ROLE: KeyBinding = KeyBinding {
+        key_short: "a",
+        key: "a",
+        desc_short: "Test as role",
+        description: "Open a SQL script that reads the table as the filtered role",
+        action: Action::InspectorTestRlsRole,
+        combos: &[KeyCombo::plain(Key::Char('a'))],
+    };
+}
+
+pub const INSPECTOR_RLS_KEYS: &[KeyBinding] =
+    &[inspector_rls::ROLE_FILTER, inspector_rls::TEST_AS_ROLE];
+
+pub mod explorer {
+    use crate::update::action::{Action, ModalKind};
+    use crate::update::input::keybindings::{Key, KeyBinding, KeyCombo};
+
+    pub const MARK: KeyBinding = KeyBinding {
+        key_short: "Space",
+        key: "Space",
+        desc_short: "Mark",
+        description: "Mark table for :bulk ddl/er/analyze/sizes",
+        action: Action::ExplorerToggleMark,
+        combos: &[KeyCombo::plain(Key::Char(' '))],
+    };
+
+    pub const TOGGLE_SCHEMA: KeyBinding = KeyBinding {
+        key_short: "Enter",
+        key: "Enter",
+        desc_short: "Fold",
+        description: "Expand or collapse the schema or object section under the cursor",
+        action: Action::ExplorerToggleSchema,
+        combos: &[KeyCombo::plain(Key::Enter)],
+    };
+
+    pub const QUICK_ACTIONS: KeyBinding = KeyBinding {
+        key_short: ".",
+        key: ".",
+        desc_short: "Actions",
+        description: "Open the quick actions menu for the table",
+        action: Action::OpenModal(ModalKind::QuickActions),
+        combos: &[KeyCombo::plain(Key::Char('.'))],
+    };
+}
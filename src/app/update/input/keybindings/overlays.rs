@@ -545,6 +545,17 @@ pub mod er_picker {
         ],
     };
 
+    pub const VIEW: ModeRow = ModeRow {
+        key_short: "Tab",
+        key: "Tab",
+        desc_short: "View",
+        description: "Draw the ER diagram in the terminal",
+        bindings: &[ExecBinding {
+            action: Action::ErViewSelection,
+            combos: &[KeyCombo::plain(Key::Tab)],
+        }],
+    };
+
     pub const ESC_CLOSE: ModeRow = ModeRow {
         key_short: "Esc",
         key: "Esc",
@@ -559,6 +570,7 @@ pub mod er_picker {
 
 pub const ER_PICKER_ROWS: &[ModeRow] = &[
     er_picker::ENTER_GENERATE,
+    er_picker::VIEW,
     er_picker::SELECT,
     er_picker::SELECT_ALL,
     er_picker::NAVIGATE,
@@ -568,6 +580,7 @@ pub const ER_PICKER_ROWS: &[ModeRow] = &[
 
 pub const ER_PICKER_ROWS_IDE: &[ModeRow] = &[
     er_picker::ENTER_GENERATE,
+    er_picker::VIEW,
     er_picker::SELECT,
     er_picker::SELECT_ALL_IDE,
     er_picker::NAVIGATE,
@@ -695,6 +708,510 @@ pub const QUERY_HISTORY_PICKER_ROWS: &[ModeRow] = &[
     query_history_picker::ESC_CLOSE,
 ];
 
+// =============================================================================
+// Server Settings
+// =============================================================================
+
+pub mod server_settings {
+    use crate::update::action::{
+        Action, CursorMove, InputTarget, ListMotion, ListTarget, ModalKind,
+    };
+    use crate::update::input::keybindings::{ExecBinding, Key, KeyCombo, ModeRow};
+
+    pub const NAVIGATE: ModeRow = ModeRow {
+        key_short: "^N/^P/↑↓",
+        key: "Ctrl+N / Ctrl+P / ↑ / ↓",
+        desc_short: "Navigate",
+        description: "Navigate",
+        bindings: &[
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::ServerSettings,
+                    motion: ListMotion::Next,
+                },
+                combos: &[KeyCombo::plain(Key::Down), KeyCombo::ctrl(Key::Char('n'))],
+            },
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::ServerSettings,
+                    motion: ListMotion::Previous,
+                },
+                combos: &[KeyCombo::plain(Key::Up), KeyCombo::ctrl(Key::Char('p'))],
+            },
+        ],
+    };
+
+    pub const TYPE_FILTER: ModeRow = ModeRow {
+        key_short: "type",
+        key: "type",
+        desc_short: "Filter",
+        description: "Type to filter by name or description",
+        bindings: &[
+            ExecBinding {
+                action: Action::TextBackspace {
+                    target: InputTarget::ServerSettingsFilter,
+                },
+                combos: &[KeyCombo::plain(Key::Backspace)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::ServerSettingsFilter,
+                    direction: CursorMove::Left,
+                },
+                combos: &[KeyCombo::plain(Key::Left)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::ServerSettingsFilter,
+                    direction: CursorMove::Right,
+                },
+                combos: &[KeyCombo::plain(Key::Right)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::ServerSettingsFilter,
+                    direction: CursorMove::Home,
+                },
+                combos: &[KeyCombo::plain(Key::Home)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::ServerSettingsFilter,
+                    direction: CursorMove::End,
+                },
+                combos: &[KeyCombo::plain(Key::End)],
+            },
+        ],
+    };
+
+    pub const ESC_CLOSE: ModeRow = ModeRow {
+        key_short: "Esc",
+        key: "Esc",
+        desc_short: "Close",
+        description: "Close",
+        bindings: &[ExecBinding {
+            action: Action::CloseModal(ModalKind::ServerSettings),
+            combos: &[KeyCombo::plain(Key::Esc)],
+        }],
+    };
+}
+
+pub const SERVER_SETTINGS_ROWS: &[ModeRow] = &[
+    server_settings::NAVIGATE,
+    server_settings::TYPE_FILTER,
+    server_settings::ESC_CLOSE,
+];
+
+// =============================================================================
+// SQL File Browser
+// =============================================================================
+
+pub mod sql_file_browser {
+    use crate::update::action::{
+        Action, CursorMove, InputTarget, ListMotion, ListTarget, ModalKind,
+    };
+    use crate::update::input::keybindings::{ExecBinding, Key, KeyCombo, ModeRow};
+
+    pub const ENTER_OPEN: ModeRow = ModeRow {
+        key_short: "Enter",
+        key: "Enter",
+        desc_short: "Open",
+        description: "Open file in SQL editor",
+        bindings: &[ExecBinding {
+            action: Action::SqlFileOpen,
+            combos: &[KeyCombo::plain(Key::Enter)],
+        }],
+    };
+
+    pub const RUN: ModeRow = ModeRow {
+        key_short: "^R",
+        key: "Ctrl+R",
+        desc_short: "Run",
+        description: "Run file in SQL editor",
+        bindings: &[ExecBinding {
+            action: Action::SqlFileRun,
+            combos: &[KeyCombo::ctrl(Key::Char('r'))],
+        }],
+    };
+
+    pub const NAVIGATE: ModeRow = ModeRow {
+        key_short: "^N/^P/↑↓",
+        key: "Ctrl+N / Ctrl+P / ↑ / ↓",
+        desc_short: "Navigate",
+        description: "Navigate",
+        bindings: &[
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::SqlFiles,
+                    motion: ListMotion::Next,
+                },
+                combos: &[KeyCombo::plain(Key::Down), KeyCombo::ctrl(Key::Char('n'))],
+            },
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::SqlFiles,
+                    motion: ListMotion::Previous,
+                },
+                combos: &[KeyCombo::plain(Key::Up), KeyCombo::ctrl(Key::Char('p'))],
+            },
+        ],
+    };
+
+    pub const TYPE_FILTER: ModeRow = ModeRow {
+        key_short: "type",
+        key: "type",
+        desc_short: "Filter",
+        description: "Type to filter by path",
+        bindings: &[
+            ExecBinding {
+                action: Action::TextBackspace {
+                    target: InputTarget::SqlFileFilter,
+                },
+                combos: &[KeyCombo::plain(Key::Backspace)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SqlFileFilter,
+                    direction: CursorMove::Left,
+                },
+                combos: &[KeyCombo::plain(Key::Left)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SqlFileFilter,
+                    direction: CursorMove::Right,
+                },
+                combos: &[KeyCombo::plain(Key::Right)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SqlFileFilter,
+                    direction: CursorMove::Home,
+                },
+                combos: &[KeyCombo::plain(Key::Home)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SqlFileFilter,
+                    direction: CursorMove::End,
+                },
+                combos: &[KeyCombo::plain(Key::End)],
+            },
+        ],
+    };
+
+    pub const ESC_CLOSE: ModeRow = ModeRow {
+        key_short: "Esc",
+        key: "Esc",
+        desc_short: "Close",
+        description: "Close",
+        bindings: &[ExecBinding {
+            action: Action::CloseModal(ModalKind::SqlFileBrowser),
+            combos: &[KeyCombo::plain(Key::Esc)],
+        }],
+    };
+}
+
+pub const SQL_FILE_BROWSER_ROWS: &[ModeRow] = &[
+    sql_file_browser::ENTER_OPEN,
+    sql_file_browser::RUN,
+    sql_file_browser::NAVIGATE,
+    sql_file_browser::TYPE_FILTER,
+    sql_file_browser::ESC_CLOSE,
+];
+
+// =============================================================================
+// Snippet Picker
+// =============================================================================
+
+pub mod snippet_picker {
+    use crate::update::action::{
+        Action, CursorMove, InputTarget, ListMotion, ListTarget, ModalKind,
+    };
+    use crate::update::input::keybindings::{ExecBinding, Key, KeyCombo, ModeRow};
+
+    pub const ENTER_INSERT: ModeRow = ModeRow {
+        key_short: "Enter",
+        key: "Enter",
+        desc_short: "Insert",
+        description: "Insert snippet at the SQL editor cursor",
+        bindings: &[ExecBinding {
+            action: Action::SnippetInsert,
+            combos: &[KeyCombo::plain(Key::Enter)],
+        }],
+    };
+
+    pub const RUN: ModeRow = ModeRow {
+        key_short: "^R",
+        key: "Ctrl+R",
+        desc_short: "Run",
+        description: "Run snippet in SQL editor",
+        bindings: &[ExecBinding {
+            action: Action::SnippetRun,
+            combos: &[KeyCombo::ctrl(Key::Char('r'))],
+        }],
+    };
+
+    pub const NAVIGATE: ModeRow = ModeRow {
+        key_short: "^N/^P/↑↓",
+        key: "Ctrl+N / Ctrl+P / ↑ / ↓",
+        desc_short: "Navigate",
+        description: "Navigate",
+        bindings: &[
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::Snippets,
+                    motion: ListMotion::Next,
+                },
+                combos: &[KeyCombo::plain(Key::Down), KeyCombo::ctrl(Key::Char('n'))],
+            },
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::Snippets,
+                    motion: ListMotion::Previous,
+                },
+                combos: &[KeyCombo::plain(Key::Up), KeyCombo::ctrl(Key::Char('p'))],
+            },
+        ],
+    };
+
+    pub const TYPE_FILTER: ModeRow = ModeRow {
+        key_short: "type",
+        key: "type",
+        desc_short: "Filter",
+        description: "Type to filter by name",
+        bindings: &[
+            ExecBinding {
+                action: Action::TextBackspace {
+                    target: InputTarget::SnippetFilter,
+                },
+                combos: &[KeyCombo::plain(Key::Backspace)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SnippetFilter,
+                    direction: CursorMove::Left,
+                },
+                combos: &[KeyCombo::plain(Key::Left)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SnippetFilter,
+                    direction: CursorMove::Right,
+                },
+                combos: &[KeyCombo::plain(Key::Right)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SnippetFilter,
+                    direction: CursorMove::Home,
+                },
+                combos: &[KeyCombo::plain(Key::Home)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::SnippetFilter,
+                    direction: CursorMove::End,
+                },
+                combos: &[KeyCombo::plain(Key::End)],
+            },
+        ],
+    };
+
+    pub const ESC_CLOSE: ModeRow = ModeRow {
+        key_short: "Esc",
+        key: "Esc",
+        desc_short: "Close",
+        description: "Close",
+        bindings: &[ExecBinding {
+            action: Action::CloseModal(ModalKind::SnippetPicker),
+            combos: &[KeyCombo::plain(Key::Esc)],
+        }],
+    };
+}
+
+pub const SNIPPET_PICKER_ROWS: &[ModeRow] = &[
+    snippet_picker::ENTER_INSERT,
+    snippet_picker::RUN,
+    snippet_picker::NAVIGATE,
+    snippet_picker::TYPE_FILTER,
+    snippet_picker::ESC_CLOSE,
+];
+
+// =============================================================================
+// Object Search
+// =============================================================================
+
+pub mod object_search {
+    use crate::update::action::{
+        Action, CursorMove, InputTarget, ListMotion, ListTarget, ModalKind,
+    };
+    use crate::update::input::keybindings::{ExecBinding, Key, KeyCombo, ModeRow};
+
+    pub const ENTER_JUMP: ModeRow = ModeRow {
+        key_short: "Enter",
+        key: "Enter",
+        desc_short: "Jump",
+        description: "Open the table, object or column in the Explorer",
+        bindings: &[ExecBinding {
+            action: Action::ObjectSearchConfirm,
+            combos: &[KeyCombo::plain(Key::Enter)],
+        }],
+    };
+
+    pub const NAVIGATE: ModeRow = ModeRow {
+        key_short: "^N/^P/↑↓",
+        key: "Ctrl+N / Ctrl+P / ↑ / ↓",
+        desc_short: "Navigate",
+        description: "Navigate",
+        bindings: &[
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::ObjectSearch,
+                    motion: ListMotion::Next,
+                },
+                combos: &[KeyCombo::plain(Key::Down), KeyCombo::ctrl(Key::Char('n'))],
+            },
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::ObjectSearch,
+                    motion: ListMotion::Previous,
+                },
+                combos: &[KeyCombo::plain(Key::Up), KeyCombo::ctrl(Key::Char('p'))],
+            },
+        ],
+    };
+
+    pub const TYPE_FILTER: ModeRow = ModeRow {
+        key_short: "type",
+        key: "type",
+        desc_short: "Search",
+        description: "Type to search names and comments",
+        bindings: &[
+            ExecBinding {
+                action: Action::TextBackspace {
+                    target: InputTarget::ObjectSearchFilter,
+                },
+                combos: &[KeyCombo::plain(Key::Backspace)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::ObjectSearchFilter,
+                    direction: CursorMove::Left,
+                },
+                combos: &[KeyCombo::plain(Key::Left)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::ObjectSearchFilter,
+                    direction: CursorMove::Right,
+                },
+                combos: &[KeyCombo::plain(Key::Right)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::ObjectSearchFilter,
+                    direction: CursorMove::Home,
+                },
+                combos: &[KeyCombo::plain(Key::Home)],
+            },
+            ExecBinding {
+                action: Action::TextMoveCursor {
+                    target: InputTarget::ObjectSearchFilter,
+                    direction: CursorMove::End,
+                },
+                combos: &[KeyCombo::plain(Key::End)],
+            },
+        ],
+    };
+
+    pub const ESC_CLOSE: ModeRow = ModeRow {
+        key_short: "Esc",
+        key: "Esc",
+        desc_short: "Close",
+        description: "Close",
+        bindings: &[ExecBinding {
+            action: Action::CloseModal(ModalKind::ObjectSearch),
+            combos: &[KeyCombo::plain(Key::Esc)],
+        }],
+    };
+}
+
+pub const OBJECT_SEARCH_ROWS: &[ModeRow] = &[
+    object_search::ENTER_JUMP,
+    object_search::NAVIGATE,
+    object_search::TYPE_FILTER,
+    object_search::ESC_CLOSE,
+];
+
+// =============================================================================
+// Quick Actions
+// =============================================================================
+
+pub mod quick_actions {
+    use crate::update::action::{Action, ListMotion, ListTarget, ModalKind};
+    use crate::update::input::keybindings::{ExecBinding, Key, KeyCombo, ModeRow};
+
+    pub const ENTER_RUN: ModeRow = ModeRow {
+        key_short: "Enter",
+        key: "Enter",
+        desc_short: "Run",
+        description: "Run the highlighted action on the table",
+        bindings: &[ExecBinding {
+            action: Action::QuickActionConfirm,
+            combos: &[KeyCombo::plain(Key::Enter)],
+        }],
+    };
+
+    pub const NAVIGATE: ModeRow = ModeRow {
+        key_short: "j/k/↑↓",
+        key: "j / k / ↑ / ↓ / Ctrl+N / Ctrl+P",
+        desc_short: "Navigate",
+        description: "Navigate",
+        bindings: &[
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::QuickActions,
+                    motion: ListMotion::Next,
+                },
+                combos: &[
+                    KeyCombo::plain(Key::Char('j')),
+                    KeyCombo::plain(Key::Down),
+                    KeyCombo::ctrl(Key::Char('n')),
+                ],
+            },
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::QuickActions,
+                    motion: ListMotion::Previous,
+                },
+                combos: &[
+                    KeyCombo::plain(Key::Char('k')),
+                    KeyCombo::plain(Key::Up),
+                    KeyCombo::ctrl(Key::Char('p')),
+                ],
+            },
+        ],
+    };
+
+    pub const CLOSE: ModeRow = ModeRow {
+        key_short: "Esc",
+        key: "Esc / .",
+        desc_short: "Close",
+        description: "Close",
+        bindings: &[ExecBinding {
+            action: Action::CloseModal(ModalKind::QuickActions),
+            combos: &[KeyCombo::plain(Key::Esc), KeyCombo::plain(Key::Char('.'))],
+        }],
+    };
+}
+
+pub const QUICK_ACTIONS_ROWS: &[ModeRow] = &[
+    quick_actions::ENTER_RUN,
+    quick_actions::NAVIGATE,
+    quick_actions::CLOSE,
+];
+
 // =============================================================================
 // Command Palette
 // =============================================================================
@@ -1697,3 +2214,128 @@ pub const SQLITE_DIAGNOSTICS_ROWS: &[ModeRow] = &[
     sqlite_diagnostics::HELP,
     sqlite_diagnostics::ESC_CLOSE,
 ];
+
+// =============================================================================
+// ER Diagram Viewer
+// =============================================================================
+
+pub mod er_diagram {
+    use crate::update::action::{
+        Action, ListMotion, ListTarget, ModalKind, ScrollAmount, ScrollDirection, ScrollTarget,
+    };
+    use crate::update::input::keybindings::{ExecBinding, Key, KeyCombo, ModeRow};
+
+    pub const PAN: ModeRow = ModeRow {
+        key_short: "hjkl",
+        key: "h / j / k / l / ← / ↓ / ↑ / →",
+        desc_short: "Pan",
+        description: "Pan the diagram",
+        bindings: &[
+            ExecBinding {
+                action: Action::Scroll {
+                    target: ScrollTarget::ErDiagram,
+                    direction: ScrollDirection::Left,
+                    amount: ScrollAmount::Line,
+                },
+                combos: &[KeyCombo::plain(Key::Char('h')), KeyCombo::plain(Key::Left)],
+            },
+            ExecBinding {
+                action: Action::Scroll {
+                    target: ScrollTarget::ErDiagram,
+                    direction: ScrollDirection::Down,
+                    amount: ScrollAmount::Line,
+                },
+                combos: &[KeyCombo::plain(Key::Char('j')), KeyCombo::plain(Key::Down)],
+            },
+            ExecBinding {
+                action: Action::Scroll {
+                    target: ScrollTarget::ErDiagram,
+                    direction: ScrollDirection::Up,
+                    amount: ScrollAmount::Line,
+                },
+                combos: &[KeyCombo::plain(Key::Char('k')), KeyCombo::plain(Key::Up)],
+            },
+            ExecBinding {
+                action: Action::Scroll {
+                    target: ScrollTarget::ErDiagram,
+                    direction: ScrollDirection::Right,
+                    amount: ScrollAmount::Line,
+                },
+                combos: &[KeyCombo::plain(Key::Char('l')), KeyCombo::plain(Key::Right)],
+            },
+        ],
+    };
+
+    pub const SELECT: ModeRow = ModeRow {
+        key_short: "Tab/⇧Tab",
+        key: "Tab / Shift+Tab",
+        desc_short: "Table",
+        description: "Center on the next / previous table",
+        bindings: &[
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::ErDiagram,
+                    motion: ListMotion::Next,
+                },
+                combos: &[KeyCombo::plain(Key::Tab)],
+            },
+            ExecBinding {
+                action: Action::ListSelect {
+                    target: ListTarget::ErDiagram,
+                    motion: ListMotion::Previous,
+                },
+                combos: &[KeyCombo::plain(Key::BackTab)],
+            },
+        ],
+    };
+
+    pub const FOCUS: ModeRow = ModeRow {
+        key_short: "f",
+        key: "f",
+        desc_short: "Focus",
+        description: "Show only the selected table and its direct FK neighbors",
+        bindings: &[ExecBinding {
+            action: Action::ErViewerToggleFocus,
+            combos: &[KeyCombo::plain(Key::Char('f'))],
+        }],
+    };
+
+    pub const ZOOM: ModeRow = ModeRow {
+        key_short: "+/-",
+        key: "+ / -",
+        desc_short: "Zoom",
+        description: "Boxed tables / compact title-only labels",
+        bindings: &[
+            ExecBinding {
+                action: Action::ErViewerZoomIn,
+                combos: &[
+                    KeyCombo::plain(Key::Char('+')),
+                    KeyCombo::plain(Key::Char('=')),
+                ],
+            },
+            ExecBinding {
+                action: Action::ErViewerZoomOut,
+                combos: &[KeyCombo::plain(Key::Char('-'))],
+            },
+        ],
+    };
+
+    pub const CLOSE: ModeRow = ModeRow {
+        key_short: "Esc",
+        key: "Esc / q",
+        desc_short: "Close",
+        description: "Close the ER diagram",
+        bindings: &[ExecBinding {
+            action: Action::CloseModal(ModalKind::ErDiagram),
+            combos: &[KeyCombo::plain(Key::Esc), KeyCombo::plain(Key::Char('q'))],
+        }],
+    };
+}
+
+pub const ER_DIAGRAM_ROWS: &[ModeRow] = &[
+    er_diagram::PAN,
+    er_diagram::SELECT,
+    er_diagram::FOCUS,
+    er_diagram::ZOOM,
+    er_diagram::CLOSE,
+];
@@ -1,8 +1,10 @@
+use std::cmp::Reverse;
+
 use super::keybindings::{KeyBinding, global};
 use crate::model::shared::engine_feature_profile::EngineFeatureProfile;
 use crate::model::shared::settings::KeymapPreset;
+use crate::model::shared::usage::UsageTracker;
 use crate::policy::FeaturePolicy;
-use crate::update::action::Action;
 
 // Deliberate opt-in list in display order — not derived from GLOBAL_KEYS, so an
 // entry never appears in the palette by accident. A test forces every global
@@ -11,6 +13,7 @@ const PALETTE_COMMANDS: &[KeyBinding] = &[
     global::QUIT,
     global::HELP,
     global::TABLE_PICKER,
+    global::OBJECT_SEARCH,
     global::SETTINGS,
     global::FOCUS,
     global::RELOAD,
@@ -22,12 +25,15 @@ const PALETTE_COMMANDS: &[KeyBinding] = &[
     global::READ_ONLY,
     global::EXIT_READ_ONLY,
     global::QUERY_HISTORY,
+    global::VACUUM_ANALYZE,
+    global::ANALYZE,
 ];
 
 const IDE_PALETTE_COMMANDS: &[KeyBinding] = &[
     global::QUIT,
     global::HELP,
     global::TABLE_PICKER_IDE,
+    global::OBJECT_SEARCH,
     global::SETTINGS,
     global::FOCUS,
     global::RELOAD,
@@ -39,6 +45,8 @@ const IDE_PALETTE_COMMANDS: &[KeyBinding] = &[
     global::READ_ONLY_IDE,
     global::EXIT_READ_ONLY_IDE,
     global::QUERY_HISTORY_IDE,
+    global::VACUUM_ANALYZE,
+    global::ANALYZE,
 ];
 
 fn palette_commands_for(preset: KeymapPreset) -> &'static [KeyBinding] {
@@ -52,22 +60,33 @@ pub fn palette_command_count(
     preset: KeymapPreset,
     engine_feature_profile: &EngineFeatureProfile,
 ) -> usize {
-    palette_commands(preset, engine_feature_profile).count()
+    supported_palette_commands(preset, engine_feature_profile).count()
 }
 
-pub fn palette_action_for_index(
+pub fn palette_command_for_index(
     index: usize,
     preset: KeymapPreset,
     engine_feature_profile: &EngineFeatureProfile,
-) -> Action {
-    palette_commands(preset, engine_feature_profile)
-        .nth(index)
-        .map_or(Action::None, |kb| kb.action.clone())
+    usage: &UsageTracker,
+) -> Option<&'static KeyBinding> {
+    palette_commands(preset, engine_feature_profile, usage).nth(index)
 }
 
+/// Most used entries first when usage tracking is on; the static list order
+/// breaks ties.
 pub fn palette_commands(
     preset: KeymapPreset,
     engine_feature_profile: &EngineFeatureProfile,
+    usage: &UsageTracker,
+) -> impl Iterator<Item = &'static KeyBinding> {
+    let mut commands: Vec<_> = supported_palette_commands(preset, engine_feature_profile).collect();
+    commands.sort_by_key(|kb| Reverse(usage.command_count(kb.description)));
+    commands.into_iter()
+}
+
+fn supported_palette_commands(
+    preset: KeymapPreset,
+    engine_feature_profile: &EngineFeatureProfile,
 ) -> impl Iterator<Item = &'static KeyBinding> {
     let feature_policy = FeaturePolicy::new(engine_feature_profile);
     palette_commands_for(preset)
@@ -82,7 +101,8 @@ fn palette_command_supported(kb: &KeyBinding, feature_policy: &FeaturePolicy) ->
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::update::action::ModalKind;
+    use crate::domain::{UsageKind, UsageStats};
+    use crate::update::action::{Action, ModalKind};
     use crate::update::input::keybindings::{
         GLOBAL_KEYS, IDE_GLOBAL_KEYS, same_payload_free_action,
     };
@@ -160,9 +180,10 @@ mod tests {
     fn palette_commands_contains_no_none_actions() {
         let engine_feature_profile = EngineFeatureProfile::postgres_like();
         for preset in [KeymapPreset::Default, KeymapPreset::Ide] {
-            let none_entries: Vec<_> = palette_commands(preset, &engine_feature_profile)
-                .filter(|kb| matches!(kb.action, Action::None))
-                .collect();
+            let none_entries: Vec<_> =
+                palette_commands(preset, &engine_feature_profile, &UsageTracker::default())
+                    .filter(|kb| matches!(kb.action, Action::None))
+                    .collect();
 
             assert!(
                 none_entries.is_empty(),
@@ -174,9 +195,12 @@ mod tests {
 
     #[test]
     fn sqlite_palette_omits_er_diagram_command() {
-        let commands =
-            palette_commands(KeymapPreset::Default, &EngineFeatureProfile::sqlite_like())
-                .collect::<Vec<_>>();
+        let commands = palette_commands(
+            KeymapPreset::Default,
+            &EngineFeatureProfile::sqlite_like(),
+            &UsageTracker::default(),
+        )
+        .collect::<Vec<_>>();
 
         assert!(
             !commands
@@ -195,6 +219,7 @@ mod tests {
         let commands = palette_commands(
             KeymapPreset::Default,
             &EngineFeatureProfile::postgres_like(),
+            &UsageTracker::default(),
         )
         .collect::<Vec<_>>();
 
@@ -204,4 +229,44 @@ mod tests {
                 .any(|kb| matches!(kb.action, Action::OpenModal(ModalKind::SqliteDiagnostics)))
         );
     }
+
+    #[test]
+    fn tracked_usage_moves_frequent_commands_first() {
+        let mut usage = UsageTracker::default();
+        usage.load(true, UsageStats::default());
+        usage.record_command(global::ANALYZE.description);
+        usage.record_command(global::ANALYZE.description);
+        usage.record_command(global::SQL.description);
+        let profile = EngineFeatureProfile::postgres_like();
+
+        let descriptions: Vec<_> = palette_commands(KeymapPreset::Default, &profile, &usage)
+            .map(|kb| kb.description)
+            .take(3)
+            .collect();
+
+        assert_eq!(
+            descriptions,
+            [
+                global::ANALYZE.description,
+                global::SQL.description,
+                global::QUIT.description
+            ]
+        );
+    }
+
+    #[test]
+    fn disabled_usage_keeps_static_order() {
+        let mut stats = UsageStats::default();
+        stats.record(UsageKind::Command, global::ANALYZE.description);
+        let mut usage = UsageTracker::default();
+        usage.load(false, stats);
+        let profile = EngineFeatureProfile::postgres_like();
+
+        let first = palette_commands(KeymapPreset::Default, &profile, &usage).next();
+
+        assert_eq!(
+            first.map(|kb| kb.description),
+            Some(global::QUIT.description)
+        );
+    }
 }
@@ -34,7 +34,9 @@ pub(in crate::update::input::vim) fn mode_transition(
             match result_ctx.mode {
                 ResultNavMode::Scroll => Action::Escape,
                 ResultNavMode::CellActive => {
-                    if result_ctx.has_pending_draft {
+                    if result_ctx.range.is_some() {
+                        Action::ResultClearRange
+                    } else if result_ctx.has_pending_draft {
                         Action::ResultDiscardCellEdit
                     } else {
                         Action::ResultExitToScroll
@@ -70,7 +72,9 @@ pub(in crate::update::input::vim) fn operator(
         (VimOperator::Yank, BrowseVimContext::Result(result_ctx)) => Some(match result_ctx.mode {
             ResultNavMode::Scroll => Action::None,
             ResultNavMode::CellActive => {
-                if result_ctx.yank_pending {
+                if result_ctx.range.is_some() {
+                    Action::ResultRangeYank
+                } else if result_ctx.yank_pending {
                     Action::ResultRowYank
                 } else {
                     Action::ResultRowYankOperatorPending
@@ -292,6 +296,7 @@ fn result_navigation(navigation: VimNavigation, ctx: ResultVimContext) -> Action
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::shared::ui_state::ResultRange;
     use crate::update::input::vim::{
         SearchContinuation, VimCommand, VimSurfaceContext, action_for_command,
     };
@@ -303,6 +308,7 @@ mod tests {
             has_pending_draft: false,
             yank_pending: false,
             delete_pending: false,
+            range: None,
         }
     }
 
@@ -454,6 +460,38 @@ mod tests {
         assert!(matches!(action, Some(Action::ResultRowYankOperatorPending)));
     }
 
+    #[test]
+    fn result_range_yank_copies_the_range_at_once() {
+        let action = action_for_command(
+            VimCommand::Operator(VimOperator::Yank),
+            browse_result(ResultVimContext {
+                range: Some(ResultRange {
+                    rows: (0, 1),
+                    cols: (0, 0),
+                }),
+                ..result_ctx(ResultNavMode::CellActive)
+            }),
+        );
+
+        assert!(matches!(action, Some(Action::ResultRangeYank)));
+    }
+
+    #[test]
+    fn result_escape_with_range_clears_range_first() {
+        let action = action_for_command(
+            VimCommand::ModeTransition(VimModeTransition::Escape),
+            browse_result(ResultVimContext {
+                range: Some(ResultRange {
+                    rows: (0, 1),
+                    cols: (0, 0),
+                }),
+                ..result_ctx(ResultNavMode::CellActive)
+            }),
+        );
+
+        assert!(matches!(action, Some(Action::ResultClearRange)));
+    }
+
     #[test]
     fn inspector_ddl_yank_resolves_to_ddl_yank() {
         let action = action_for_command(
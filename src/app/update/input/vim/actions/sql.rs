@@ -3,6 +3,7 @@ use crate::update::action::{
 };
 
 use super::scroll;
+use crate::model::sql_editor::vim::SqlVimOperator;
 use crate::update::input::vim::types::{
     SqlModalVimContext, VimCommand, VimModeTransition, VimNavigation, VimOperator,
 };
@@ -23,7 +24,12 @@ pub(in crate::update::input::vim) fn command(
             VimCommand::ModeTransition(VimModeTransition::Insert) => {
                 Some(Action::SqlModalEnterInsert)
             }
-            VimCommand::Operator(VimOperator::Yank) => Some(Action::SqlModalYank),
+            VimCommand::Operator(VimOperator::Yank) => {
+                Some(Action::SqlModalVimOperator(SqlVimOperator::Yank))
+            }
+            VimCommand::Operator(VimOperator::Delete) => {
+                Some(Action::SqlModalVimOperator(SqlVimOperator::Delete))
+            }
             _ => None,
         },
         SqlModalVimContext::QueryEditing => match command {
@@ -108,13 +114,15 @@ mod tests {
         }
     }
 
-    #[test]
-    fn yank_copies_query() {
+    #[rstest]
+    #[case(Key::Char('y'), SqlVimOperator::Yank)]
+    #[case(Key::Char('d'), SqlVimOperator::Delete)]
+    fn operators_start_vim_operator(#[case] key: Key, #[case] expected: SqlVimOperator) {
         let ctx = VimSurfaceContext::SqlModal(SqlModalVimContext::QueryNormal);
 
-        let action = action_for_key(&combo(Key::Char('y')), ctx);
+        let action = action_for_key(&combo(key), ctx);
 
-        assert!(matches!(action, Some(Action::SqlModalYank)));
+        assert!(matches!(action, Some(Action::SqlModalVimOperator(op)) if op == expected));
     }
 
     #[rstest]
@@ -1,7 +1,7 @@
 use crate::model::app_state::AppState;
 use crate::model::shared::focused_pane::FocusedPane;
 use crate::model::shared::inspector_tab::InspectorTab;
-use crate::model::shared::ui_state::ResultNavMode;
+use crate::model::shared::ui_state::{ResultNavMode, ResultRange};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VimCommand {
@@ -81,6 +81,7 @@ pub struct ResultVimContext {
     pub has_pending_draft: bool,
     pub yank_pending: bool,
     pub delete_pending: bool,
+    pub range: Option<ResultRange>,
 }
 
 impl BrowseVimContext {
@@ -103,6 +104,7 @@ impl From<&AppState> for BrowseVimContext {
                 has_pending_draft: state.result_interaction.cell_edit().has_pending_draft(),
                 yank_pending: state.result_interaction.is_yank_operator_pending(),
                 delete_pending: state.result_interaction.is_delete_operator_pending(),
+                range: state.result_interaction.selection().range(),
             });
         }
 
@@ -1,4 +1,5 @@
 pub mod action;
+mod audit;
 pub mod browse;
 pub mod connection;
 pub mod dispatch_result;
@@ -8,10 +9,12 @@ pub mod helpers;
 pub mod input;
 pub mod modal;
 mod query_context;
+mod query_hooks;
 pub mod reducer;
 pub mod sql_editor;
 #[cfg(test)]
 pub(crate) mod test_fixtures;
+mod undo;
 // Facade: re-export sub-reducer entry points for update/reducer.rs dispatch
 pub use browse::metadata::dispatch_metadata;
 pub use browse::navigation::dispatch_navigation;
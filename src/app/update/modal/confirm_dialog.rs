@@ -1,13 +1,24 @@
 use std::time::Instant;
 
 use crate::cmd::effect::Effect;
+use crate::domain::IndexMaintenanceKind;
 use crate::model::app_state::AppState;
-use crate::model::shared::confirm_dialog::ConfirmIntent;
+use crate::model::shared::confirm_dialog::{CONFIRM_INPUT_VISIBLE_WIDTH, ConfirmIntent};
 use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::text_input::{TextInputEditing, TextInputState};
+use crate::policy::write::write_guardrails::WriteOperation;
 use crate::ports::outbound::AccessMode;
-use crate::update::action::{Action, ScrollAmount, ScrollTarget};
+use crate::update::action::{Action, InputTarget, ScrollAmount, ScrollTarget};
+use crate::update::audit::production_audit_effect;
 use crate::update::dispatch_result::DispatchResult;
 
+fn edit_input<R>(state: &mut AppState, edit: impl FnOnce(&mut TextInputState) -> R) -> R {
+    let input = state.confirm_dialog.input_mut();
+    let result = edit(input);
+    input.update_viewport(CONFIRM_INPUT_VISIBLE_WIDTH);
+    result
+}
+
 pub(super) fn reduce_confirm_dialog(
     state: &mut AppState,
     action: &Action,
@@ -28,6 +39,10 @@ pub(super) fn reduce_confirm_dialog(
             DispatchResult::handled()
         }
         Action::ConfirmDialogConfirm => {
+            if !state.confirm_dialog.input_matches() {
+                return DispatchResult::handled();
+            }
+            let typed = state.confirm_dialog.required_input().is_some();
             let intent = state.confirm_dialog.take_intent();
             state.modal.pop_mode();
 
@@ -49,13 +64,31 @@ pub(super) fn reduce_confirm_dialog(
                     blocked: false,
                 }) => {
                     if let Some(dsn) = state.session.dsn().map(String::from) {
+                        let audit = state
+                            .result_interaction
+                            .pending_write_preview()
+                            .filter(|_| typed)
+                            .and_then(|preview| {
+                                let operation = match preview.operation {
+                                    WriteOperation::Update => "UPDATE",
+                                    WriteOperation::Delete => "DELETE",
+                                };
+                                production_audit_effect(
+                                    state,
+                                    operation,
+                                    preview.guardrail.risk_level,
+                                    &sql,
+                                )
+                            });
                         let run_id = state.query.begin_running(now);
-                        DispatchResult::handled_with(vec![Effect::ExecuteWrite {
+                        let mut effects = vec![Effect::ExecuteWrite {
                             dsn,
                             run_id,
                             query: sql,
                             access_mode: AccessMode::from_read_only(state.session.is_read_only()),
-                        }])
+                        }];
+                        effects.extend(audit);
+                        DispatchResult::handled_with(effects)
                     } else {
                         state.result_interaction.clear_write_preview();
                         state.query.clear_delete_refresh_target();
@@ -69,6 +102,24 @@ pub(super) fn reduce_confirm_dialog(
                     state.session.disable_read_only();
                     DispatchResult::handled()
                 }
+                Some(ConfirmIntent::BlockingReindex { index }) => {
+                    DispatchResult::handled_with(vec![Effect::DispatchActions(vec![
+                        Action::StartIndexMaintenance {
+                            kind: IndexMaintenanceKind::Reindex,
+                            index: Some(index),
+                            confirmed: true,
+                        },
+                    ])])
+                }
+                Some(ConfirmIntent::CopyLargeResult {
+                    content,
+                    format,
+                    row_count,
+                }) => DispatchResult::handled_with(vec![Effect::CopyToClipboard {
+                    content,
+                    on_success: Some(Box::new(Action::ResultCopyAllCopied { format, row_count })),
+                    on_failure: None,
+                }]),
                 Some(ConfirmIntent::CsvExportRerunnable {
                     dsn,
                     run_id,
@@ -115,6 +166,48 @@ pub(super) fn reduce_confirm_dialog(
                 None => DispatchResult::handled(),
             }
         }
+        Action::TextInput {
+            target: InputTarget::ConfirmDialog,
+            ch,
+        } => {
+            edit_input(state, |input| input.insert_char(*ch));
+            DispatchResult::handled()
+        }
+        Action::TextBackspace {
+            target: InputTarget::ConfirmDialog,
+        } => {
+            edit_input(state, TextInputState::backspace);
+            DispatchResult::handled()
+        }
+        Action::TextDelete {
+            target: InputTarget::ConfirmDialog,
+        } => {
+            edit_input(state, TextInputState::delete);
+            DispatchResult::handled()
+        }
+        Action::TextKill {
+            target: InputTarget::ConfirmDialog,
+            direction,
+        } => {
+            let killed = edit_input(state, |input| input.kill(*direction));
+            state.record_kill(killed);
+            DispatchResult::handled()
+        }
+        Action::TextYank {
+            target: InputTarget::ConfirmDialog,
+        } => {
+            if let Some(killed) = state.kill_buffer().map(str::to_owned) {
+                edit_input(state, |input| input.yank(&killed));
+            }
+            DispatchResult::handled()
+        }
+        Action::TextMoveCursor {
+            target: InputTarget::ConfirmDialog,
+            direction,
+        } => {
+            edit_input(state, |input| input.move_cursor(*direction));
+            DispatchResult::handled()
+        }
         Action::ConfirmDialogCancel => {
             let intent = state.confirm_dialog.take_intent();
             let canceling_current_csv_export = match &intent {
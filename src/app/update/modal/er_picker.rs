@@ -2,6 +2,7 @@ use std::time::Instant;
 
 use crate::cmd::effect::Effect;
 use crate::model::app_state::AppState;
+use crate::model::er_state::ErOutput;
 use crate::model::shared::input_mode::InputMode;
 use crate::model::shared::text_input::{TextInputEditing, TextInputState};
 use crate::update::action::{Action, InputTarget, ModalKind};
@@ -102,21 +103,28 @@ pub(super) fn reduce_er_picker(
             }
             DispatchResult::handled()
         }
-        Action::ErConfirmSelection => {
-            if state.ui.er_selected_tables().is_empty() {
-                state
-                    .messages
-                    .set_error_at("No tables selected".to_string(), now);
-                return DispatchResult::handled();
-            }
-            state
-                .er_preparation
-                .set_targets(state.ui.er_selected_tables().iter().cloned().collect());
-            state.modal.set_mode(InputMode::Normal);
-            state.ui.er_picker_mut().clear_filter();
-            state.ui.clear_er_selected_tables();
-            DispatchResult::handled_with(vec![Effect::DispatchActions(vec![Action::ErOpenDiagram])])
-        }
+        Action::ErConfirmSelection => confirm_selection(state, ErOutput::Export, now),
+        Action::ErViewSelection => confirm_selection(state, ErOutput::Viewer, now),
         _ => DispatchResult::pass(),
     }
 }
+
+fn confirm_selection(state: &mut AppState, output: ErOutput, now: Instant) -> DispatchResult {
+    if state.ui.er_selected_tables().is_empty() {
+        state
+            .messages
+            .set_error_at("No tables selected".to_string(), now);
+        return DispatchResult::handled();
+    }
+    // A run already in flight keeps the output it was started with.
+    if !state.er_preparation.is_busy() {
+        state.er_preparation.set_output(output);
+    }
+    state
+        .er_preparation
+        .set_targets(state.ui.er_selected_tables().iter().cloned().collect());
+    state.modal.set_mode(InputMode::Normal);
+    state.ui.er_picker_mut().clear_filter();
+    state.ui.clear_er_selected_tables();
+    DispatchResult::handled_with(vec![Effect::DispatchActions(vec![Action::ErOpenDiagram])])
+}
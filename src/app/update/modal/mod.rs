@@ -2,8 +2,11 @@ mod base;
 mod confirm_dialog;
 mod er_picker;
 mod help;
+mod object_search;
 mod query_history;
 mod settings;
+mod snippets;
+mod sql_files;
 mod sqlite_diagnostics;
 
 use std::time::Instant;
@@ -20,6 +23,9 @@ pub fn dispatch_modal(state: &mut AppState, action: &Action, now: Instant) -> Di
         .or_else(|| confirm_dialog::reduce_confirm_dialog(state, action, now))
         .or_else(|| er_picker::reduce_er_picker(state, action, now))
         .or_else(|| query_history::reduce_query_history_picker(state, action, now))
+        .or_else(|| sql_files::reduce_sql_file_browser(state, action, now))
+        .or_else(|| snippets::reduce_snippets(state, action, now))
+        .or_else(|| object_search::reduce_object_search(state, action, now))
 }
 
 #[cfg(test)]
@@ -32,7 +38,7 @@ mod tests {
     use crate::model::shared::confirm_dialog::{ConfirmIntent, CsvExportCacheSnapshot};
     use crate::model::shared::help::HelpMode;
     use crate::model::shared::input_mode::InputMode;
-    use crate::model::shared::settings::KeymapPreset;
+    use crate::model::shared::settings::{KeymapPreset, KeywordCase, PrefetchScope};
     use crate::ports::outbound::AppSettings;
     use crate::update::action::{
         CursorMove, InputTarget, ListMotion, ListTarget, ModalKind, ScrollAmount, ScrollDirection,
@@ -458,6 +464,11 @@ mod tests {
                         theme_id: ThemeId::Light,
                         keymap_preset: KeymapPreset::Ide,
                         er_browser: Some("Google Chrome".to_string()),
+                        keyword_case: None,
+                        locale: None,
+                        usage_stats: false,
+                        update_check: false,
+                        prefetch_scope: PrefetchScope::All,
                     }),
                     Instant::now(),
                 )
@@ -476,6 +487,70 @@ mod tests {
                 );
                 assert!(effects.is_empty());
             }
+
+            #[test]
+            fn keyword_case_applies_immediately_and_persists_saved_settings() {
+                let mut state = create_test_state();
+                state.settings.load_keymap_preset(KeymapPreset::Ide);
+
+                let effects = super::dispatch_modal(
+                    &mut state,
+                    &Action::SetKeywordCase(Some(KeywordCase::Upper)),
+                    Instant::now(),
+                )
+                .into_effects()
+                .expect("reducer should handle action");
+
+                assert_eq!(state.settings.keyword_case(), Some(KeywordCase::Upper));
+                assert!(matches!(
+                    effects.as_slice(),
+                    [Effect::SaveSettings { settings }]
+                        if settings.keyword_case == Some(KeywordCase::Upper)
+                            && settings.keymap_preset == KeymapPreset::Ide
+                ));
+            }
+
+            #[test]
+            fn usage_tracking_starts_recording_and_persists_saved_settings() {
+                let mut state = create_test_state();
+                state.settings.load_keyword_case(Some(KeywordCase::Lower));
+
+                let effects = super::dispatch_modal(
+                    &mut state,
+                    &Action::SetUsageTracking(true),
+                    Instant::now(),
+                )
+                .into_effects()
+                .expect("reducer should handle action");
+
+                assert!(state.usage.is_enabled());
+                assert!(matches!(
+                    effects.as_slice(),
+                    [Effect::SaveSettings { settings }]
+                        if settings.usage_stats
+                            && settings.keyword_case == Some(KeywordCase::Lower)
+                ));
+            }
+
+            #[test]
+            fn enabling_update_check_persists_and_checks_right_away() {
+                let mut state = create_test_state();
+
+                let effects = super::dispatch_modal(
+                    &mut state,
+                    &Action::SetUpdateCheck(true),
+                    Instant::now(),
+                )
+                .into_effects()
+                .expect("reducer should handle action");
+
+                assert!(state.settings.update_check());
+                assert!(matches!(
+                    effects.as_slice(),
+                    [Effect::SaveSettings { settings }, Effect::CheckLatestRelease]
+                        if settings.update_check
+                ));
+            }
         }
 
         #[test]
@@ -659,6 +734,88 @@ mod tests {
                 assert!(matches!(&effects[0], Effect::ExecuteWrite { .. }));
             }
 
+            #[test]
+            fn typed_confirmation_waits_for_matching_input() {
+                let mut state = create_test_state();
+                enter_confirm_dialog(&mut state, InputMode::Normal);
+                test_fixtures::activate_production_connection(&mut state, "postgres://prod");
+                state.confirm_dialog.open(
+                    "",
+                    "",
+                    ConfirmIntent::ExecuteWrite {
+                        sql: "DELETE FROM users WHERE id = 2".to_string(),
+                        blocked: false,
+                    },
+                );
+                state.confirm_dialog.require_input("users");
+
+                let effects = super::dispatch_modal(
+                    &mut state,
+                    &Action::ConfirmDialogConfirm,
+                    Instant::now(),
+                )
+                .unwrap();
+
+                assert!(effects.is_empty());
+                assert_eq!(state.input_mode(), InputMode::ConfirmDialog);
+                assert!(state.confirm_dialog.intent().is_some());
+            }
+
+            #[test]
+            fn typed_confirmation_executes_and_audits_once_matched() {
+                let mut state = create_test_state();
+                enter_confirm_dialog(&mut state, InputMode::Normal);
+                test_fixtures::activate_production_connection(&mut state, "postgres://prod");
+                state.result_interaction.set_write_preview(WritePreview {
+                    operation: WriteOperation::Delete,
+                    sql: "DELETE FROM users WHERE id = 2".to_string(),
+                    target_summary: TargetSummary {
+                        schema: "public".to_string(),
+                        table: "users".to_string(),
+                        key_values: vec![],
+                    },
+                    diff: vec![],
+                    guardrail: GuardrailDecision {
+                        risk_level: RiskLevel::High,
+                        blocked: false,
+                        reason: None,
+                        target_summary: None,
+                    },
+                });
+                state.confirm_dialog.open(
+                    "",
+                    "",
+                    ConfirmIntent::ExecuteWrite {
+                        sql: "DELETE FROM users WHERE id = 2".to_string(),
+                        blocked: false,
+                    },
+                );
+                state.confirm_dialog.require_input("users");
+                for ch in "users".chars() {
+                    super::dispatch_modal(
+                        &mut state,
+                        &Action::TextInput {
+                            target: InputTarget::ConfirmDialog,
+                            ch,
+                        },
+                        Instant::now(),
+                    );
+                }
+
+                let effects = super::dispatch_modal(
+                    &mut state,
+                    &Action::ConfirmDialogConfirm,
+                    Instant::now(),
+                )
+                .unwrap();
+
+                assert!(matches!(
+                    effects.as_slice(),
+                    [Effect::ExecuteWrite { .. }, Effect::AppendAuditLog { event, .. }]
+                        if event.operation == "DELETE" && event.risk == "HIGH"
+                ));
+            }
+
             #[test]
             fn execute_write_no_dsn_sets_error() {
                 let mut state = create_test_state();
@@ -1467,6 +1624,9 @@ mod tests {
                     text: "stale".to_string(),
                     kind: CompletionKind::Keyword,
                     score: 1,
+                    detail: None,
+                    insert_text: None,
+                    preview: Vec::new(),
                 }];
                 state.sql_modal.completion_mut_for_test().selected_index = 3;
                 let test_conn = ConnectionId::from_string("test-conn");
@@ -0,0 +1,257 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::model::browse::object_search::SearchTarget;
+use crate::model::shared::focused_pane::FocusedPane;
+use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::text_input::{TextInputEditing, TextInputState};
+use crate::update::action::{Action, InputTarget, ListMotion, ListTarget, ModalKind};
+use crate::update::dispatch_result::DispatchResult;
+
+pub(super) fn reduce_object_search(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+) -> DispatchResult {
+    match action {
+        Action::OpenModal(ModalKind::ObjectSearch) => {
+            if state.modal.active_mode() == InputMode::ObjectSearch {
+                return DispatchResult::handled();
+            }
+            let Some(metadata) = state.session.metadata().cloned() else {
+                state
+                    .messages
+                    .set_error_at("Metadata not loaded yet".to_string(), now);
+                return DispatchResult::handled();
+            };
+            // Restored connection caches swap the snapshot without a reload.
+            state.object_search.refresh(&metadata);
+            state.object_search.close();
+            state.modal.push_mode(InputMode::ObjectSearch);
+            DispatchResult::handled()
+        }
+        Action::CloseModal(ModalKind::ObjectSearch) => {
+            state.modal.pop_mode();
+            state.object_search.close();
+            DispatchResult::handled()
+        }
+        Action::ObjectSearchConfirm => {
+            let Some(target) = state.object_search.selected_target() else {
+                return DispatchResult::handled();
+            };
+            state.modal.pop_mode();
+            state.object_search.close();
+
+            let row = match &target {
+                SearchTarget::Table { schema, table }
+                | SearchTarget::Column { schema, table, .. } => {
+                    state.explorer_tree.reveal(schema, None);
+                    state.explorer_rows().iter().position(|row| {
+                        row.table()
+                            .is_some_and(|t| &t.schema == schema && &t.name == table)
+                    })
+                }
+                SearchTarget::Object {
+                    schema,
+                    kind,
+                    qualified_name,
+                } => {
+                    state.explorer_tree.reveal(schema, Some(*kind));
+                    state.explorer_rows().iter().position(|row| {
+                        row.object()
+                            .is_some_and(|object| &object.qualified_name() == qualified_name)
+                    })
+                }
+            };
+            let Some(row) = row else {
+                return DispatchResult::handled();
+            };
+            state.ui.set_focused_pane(FocusedPane::Explorer);
+            state.ui.set_explorer_selection(Some(row));
+            state.object_search.set_pending_column(
+                matches!(target, SearchTarget::Column { .. }).then_some(target),
+            );
+            DispatchResult::handled_with(vec![Effect::DispatchActions(vec![
+                Action::ConfirmSelection,
+            ])])
+        }
+        Action::TextInput {
+            target: InputTarget::ObjectSearchFilter,
+            ch,
+        } => {
+            state.object_search.picker_mut().insert_filter_char(*ch);
+            DispatchResult::handled()
+        }
+        Action::TextBackspace {
+            target: InputTarget::ObjectSearchFilter,
+        } => {
+            state.object_search.picker_mut().backspace_filter();
+            DispatchResult::handled()
+        }
+        Action::TextDelete {
+            target: InputTarget::ObjectSearchFilter,
+        } => {
+            state
+                .object_search
+                .picker_mut()
+                .edit_filter(TextInputState::delete);
+            DispatchResult::handled()
+        }
+        Action::TextKill {
+            target: InputTarget::ObjectSearchFilter,
+            direction,
+        } => {
+            let killed = state
+                .object_search
+                .picker_mut()
+                .edit_filter(|input| input.kill(*direction));
+            state.record_kill(killed);
+            DispatchResult::handled()
+        }
+        Action::TextYank {
+            target: InputTarget::ObjectSearchFilter,
+        } => {
+            if let Some(killed) = state.kill_buffer().map(str::to_owned) {
+                state
+                    .object_search
+                    .picker_mut()
+                    .edit_filter(|input| input.yank(&killed));
+            }
+            DispatchResult::handled()
+        }
+        Action::TextMoveCursor {
+            target: InputTarget::ObjectSearchFilter,
+            direction,
+        } => {
+            state
+                .object_search
+                .picker_mut()
+                .move_filter_cursor(*direction);
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::ObjectSearch,
+            motion: ListMotion::Next,
+        } => {
+            state.object_search.select_next();
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::ObjectSearch,
+            motion: ListMotion::Previous,
+        } => {
+            state.object_search.select_previous();
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::{DatabaseMetadata, SchemaObject, SchemaObjectKind, Table, TableSummary};
+    use crate::policy::explorer_tree::ExplorerRow;
+    use crate::test_support;
+    use crate::test_support::column::test_nullable_column;
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        reduce_object_search(state, action, Instant::now())
+            .into_effects()
+            .unwrap()
+    }
+
+    fn connected_state() -> AppState {
+        let mut state = AppState::new("test".to_string());
+        state.session.set_metadata(Some(Arc::new({
+            let mut metadata = DatabaseMetadata::new("app".to_string());
+            metadata.table_summaries = vec![
+                TableSummary::new("audit".to_string(), "events".to_string(), None, false),
+                TableSummary::new("public".to_string(), "orders".to_string(), None, false),
+            ];
+            metadata.objects = vec![SchemaObject {
+                schema: "public".to_string(),
+                name: "order_totals".to_string(),
+                kind: SchemaObjectKind::View,
+                arguments: None,
+                definition: None,
+            }];
+            metadata
+        })));
+        state
+    }
+
+    fn search(state: &mut AppState, query: &str) {
+        dispatch(state, &Action::OpenModal(ModalKind::ObjectSearch));
+        state.object_search.picker_mut().insert_filter_str(query);
+    }
+
+    #[test]
+    fn open_without_metadata_reports_error() {
+        let mut state = AppState::new("test".to_string());
+
+        dispatch(&mut state, &Action::OpenModal(ModalKind::ObjectSearch));
+
+        assert_eq!(state.input_mode(), InputMode::Normal);
+        assert!(state.messages.last_error().is_some());
+    }
+
+    #[test]
+    fn confirming_a_table_selects_its_explorer_row() {
+        let mut state = connected_state();
+        state.explorer_tree.toggle_schema("public");
+        state.ui.set_focused_pane(FocusedPane::Result);
+        search(&mut state, "public.orders");
+
+        let effects = dispatch(&mut state, &Action::ObjectSearchConfirm);
+
+        assert_eq!(state.input_mode(), InputMode::Normal);
+        assert_eq!(state.ui.focused_pane(), FocusedPane::Explorer);
+        assert!(!state.explorer_tree.layout().is_collapsed("public"));
+        let row = state.explorer_selected_row().and_then(ExplorerRow::table);
+        assert_eq!(
+            row.map(TableSummary::qualified_name).as_deref(),
+            Some("public.orders")
+        );
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchActions(actions)] if matches!(actions.as_slice(), [Action::ConfirmSelection])
+        ));
+    }
+
+    #[test]
+    fn confirming_a_view_unfolds_its_section() {
+        let mut state = connected_state();
+        search(&mut state, "order_totals");
+
+        dispatch(&mut state, &Action::ObjectSearchConfirm);
+
+        let object = state.explorer_selected_row().and_then(ExplorerRow::object);
+        assert_eq!(object.map(|o| o.name.as_str()), Some("order_totals"));
+    }
+
+    #[test]
+    fn confirming_a_column_remembers_it_for_the_detail_load() {
+        let mut state = connected_state();
+        let mut placed_at = test_nullable_column("placed_at", "timestamptz", 2);
+        placed_at.comment = Some("checkout time".to_string());
+        state
+            .object_search
+            .refresh(&state.session.metadata().cloned().unwrap());
+        state.object_search.index_table(&Table {
+            columns: vec![test_nullable_column("id", "bigint", 1), placed_at],
+            ..test_support::table::minimal("public", "orders")
+        });
+        search(&mut state, "checkout");
+
+        dispatch(&mut state, &Action::ObjectSearchConfirm);
+
+        assert_eq!(
+            state.object_search.take_pending_column("public", "orders"),
+            Some("placed_at".to_string())
+        );
+    }
+}
@@ -97,6 +97,11 @@ pub(super) fn reduce_settings(
                 theme_id,
                 keymap_preset: state.settings.selected_keymap_preset(),
                 er_browser: state.settings.selected_er_browser(),
+                keyword_case: state.settings.keyword_case(),
+                locale: state.settings.configured_locale(),
+                usage_stats: state.usage.is_enabled(),
+                update_check: state.settings.update_check(),
+                prefetch_scope: state.settings.prefetch_scope(),
             };
             DispatchResult::handled_with(vec![Effect::SaveSettings { settings }])
         }
@@ -105,6 +110,88 @@ pub(super) fn reduce_settings(
             state.modal.set_mode(InputMode::Normal);
             DispatchResult::handled()
         }
+        Action::SetKeywordCase(keyword_case) => {
+            state.settings.load_keyword_case(*keyword_case);
+            let settings = AppSettings {
+                theme_id: state.ui.theme_id(),
+                keymap_preset: state.settings.saved_keymap_preset(),
+                er_browser: state.settings.saved_er_browser().map(str::to_string),
+                keyword_case: *keyword_case,
+                locale: state.settings.configured_locale(),
+                usage_stats: state.usage.is_enabled(),
+                update_check: state.settings.update_check(),
+                prefetch_scope: state.settings.prefetch_scope(),
+            };
+            let msg = keyword_case.map_or_else(
+                || "Keyword case: off".to_string(),
+                |case| format!("Keyword case: {}", case.label()),
+            );
+            state.messages.set_success_at(msg, now);
+            DispatchResult::handled_with(vec![Effect::SaveSettings { settings }])
+        }
+        Action::SetPrefetchScope(scope) => {
+            state.settings.load_prefetch_scope(*scope);
+            // The next SQL modal open plans a run for the new scope.
+            state.sql_modal.invalidate_prefetch();
+            let settings = AppSettings {
+                theme_id: state.ui.theme_id(),
+                keymap_preset: state.settings.saved_keymap_preset(),
+                er_browser: state.settings.saved_er_browser().map(str::to_string),
+                keyword_case: state.settings.keyword_case(),
+                locale: state.settings.configured_locale(),
+                usage_stats: state.usage.is_enabled(),
+                update_check: state.settings.update_check(),
+                prefetch_scope: *scope,
+            };
+            state
+                .messages
+                .set_success_at(format!("Completion prefetch: {}", scope.label()), now);
+            DispatchResult::handled_with(vec![Effect::SaveSettings { settings }])
+        }
+        Action::SetUsageTracking(enabled) => {
+            state.usage.set_enabled(*enabled);
+            let settings = AppSettings {
+                theme_id: state.ui.theme_id(),
+                keymap_preset: state.settings.saved_keymap_preset(),
+                er_browser: state.settings.saved_er_browser().map(str::to_string),
+                keyword_case: state.settings.keyword_case(),
+                locale: state.settings.configured_locale(),
+                usage_stats: *enabled,
+                update_check: state.settings.update_check(),
+                prefetch_scope: state.settings.prefetch_scope(),
+            };
+            let msg = if *enabled {
+                "Usage tracking: on (stored locally per project)"
+            } else {
+                "Usage tracking: off"
+            };
+            state.messages.set_success_at(msg.to_string(), now);
+            DispatchResult::handled_with(vec![Effect::SaveSettings { settings }])
+        }
+        Action::SetUpdateCheck(enabled) => {
+            state.settings.load_update_check(*enabled);
+            let settings = AppSettings {
+                theme_id: state.ui.theme_id(),
+                keymap_preset: state.settings.saved_keymap_preset(),
+                er_browser: state.settings.saved_er_browser().map(str::to_string),
+                keyword_case: state.settings.keyword_case(),
+                locale: state.settings.configured_locale(),
+                usage_stats: state.usage.is_enabled(),
+                update_check: *enabled,
+                prefetch_scope: state.settings.prefetch_scope(),
+            };
+            let msg = if *enabled {
+                "Update check: on (asks GitHub for the latest release at startup)"
+            } else {
+                "Update check: off"
+            };
+            state.messages.set_success_at(msg.to_string(), now);
+            let mut effects = vec![Effect::SaveSettings { settings }];
+            if *enabled {
+                effects.push(Effect::CheckLatestRelease);
+            }
+            DispatchResult::handled_with(effects)
+        }
         Action::SettingsSaved(settings) => {
             state.ui.set_theme(settings.theme_id);
             state.settings.commit_saved(
@@ -112,6 +199,10 @@ pub(super) fn reduce_settings(
                 settings.keymap_preset,
                 settings.er_browser.clone(),
             );
+            state.settings.load_keyword_case(settings.keyword_case);
+            state.usage.set_enabled(settings.usage_stats);
+            state.settings.load_update_check(settings.update_check);
+            state.settings.load_prefetch_scope(settings.prefetch_scope);
             state
                 .messages
                 .set_success_at("Settings saved".to_string(), now);
@@ -0,0 +1,303 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::text_input::{TextInputEditing, TextInputLike, TextInputState};
+use crate::ports::outbound::Snippet;
+use crate::update::action::{Action, InputTarget, ListMotion, ListTarget, ModalKind};
+use crate::update::dispatch_result::DispatchResult;
+
+pub(super) fn reduce_snippets(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+) -> DispatchResult {
+    match action {
+        Action::ReloadSnippets => match state.runtime.project_root().map(ToOwned::to_owned) {
+            Some(root) => DispatchResult::handled_with(vec![Effect::LoadSnippets { root }]),
+            None => DispatchResult::handled(),
+        },
+        Action::OpenModal(ModalKind::SnippetPicker) => {
+            if state.modal.active_mode() == InputMode::SnippetPicker {
+                return DispatchResult::handled();
+            }
+            let Some(root) = state.runtime.project_root().map(ToOwned::to_owned) else {
+                state
+                    .messages
+                    .set_error_at("No project directory for snippets".to_string(), now);
+                return DispatchResult::handled();
+            };
+            state.snippets.begin_load();
+            state.modal.push_mode(InputMode::SnippetPicker);
+            DispatchResult::handled_with(vec![Effect::LoadSnippets { root }])
+        }
+        Action::CloseModal(ModalKind::SnippetPicker) => {
+            state.modal.pop_mode();
+            state.snippets.close();
+            DispatchResult::handled()
+        }
+        Action::SnippetsLoaded(snippets) => {
+            state.snippets.set_loaded(snippets.clone());
+            DispatchResult::handled_with(vec![Effect::SetCompletionSnippets {
+                snippets: snippets.clone(),
+            }])
+        }
+        Action::SnippetsLoadFailed(error) => {
+            state.snippets.set_failed();
+            state.messages.set_error_at(error.to_string(), now);
+            DispatchResult::handled()
+        }
+        Action::SaveSnippet(name) => {
+            let name = name.trim();
+            if name.is_empty() {
+                state
+                    .messages
+                    .set_error_at("Usage: :save <name>".to_string(), now);
+                return DispatchResult::handled();
+            }
+            let sql = state.sql_modal.editor.content().trim().to_string();
+            if sql.is_empty() {
+                state
+                    .messages
+                    .set_error_at("SQL editor is empty; nothing to save".to_string(), now);
+                return DispatchResult::handled();
+            }
+            let Some(root) = state.runtime.project_root().map(ToOwned::to_owned) else {
+                state
+                    .messages
+                    .set_error_at("No project directory for snippets".to_string(), now);
+                return DispatchResult::handled();
+            };
+            DispatchResult::handled_with(vec![Effect::SaveSnippet {
+                root,
+                snippet: Snippet {
+                    name: name.to_string(),
+                    sql,
+                },
+            }])
+        }
+        Action::SnippetSaved { name, snippets } => {
+            state.snippets.set_loaded(snippets.clone());
+            state
+                .messages
+                .set_success_at(format!("Saved snippet: {name}"), now);
+            DispatchResult::handled_with(vec![Effect::SetCompletionSnippets {
+                snippets: snippets.clone(),
+            }])
+        }
+        Action::SnippetSaveFailed(error) => {
+            state.messages.set_error_at(error.to_string(), now);
+            DispatchResult::handled()
+        }
+        Action::SnippetInsert | Action::SnippetRun => {
+            let Some(sql) = state.snippets.selected_snippet().map(|s| s.sql.clone()) else {
+                return DispatchResult::handled();
+            };
+            state.modal.pop_mode();
+            state.snippets.close();
+
+            let mut follow_up = vec![Action::OpenModal(ModalKind::SqlModal)];
+            if matches!(action, Action::SnippetRun) {
+                // Same submit path as `:files` runs, so safety checks still apply.
+                state.sql_modal.editor.set_content(sql);
+                follow_up.push(Action::SqlModalSubmit);
+            } else {
+                state.sql_modal.editor.insert_str(&sql);
+            }
+            DispatchResult::handled_with(vec![Effect::DispatchActions(follow_up)])
+        }
+        Action::TextInput {
+            target: InputTarget::SnippetFilter,
+            ch,
+        } => {
+            state.snippets.picker_mut().insert_filter_char(*ch);
+            DispatchResult::handled()
+        }
+        Action::TextBackspace {
+            target: InputTarget::SnippetFilter,
+        } => {
+            state.snippets.picker_mut().backspace_filter();
+            DispatchResult::handled()
+        }
+        Action::TextDelete {
+            target: InputTarget::SnippetFilter,
+        } => {
+            state
+                .snippets
+                .picker_mut()
+                .edit_filter(TextInputState::delete);
+            DispatchResult::handled()
+        }
+        Action::TextKill {
+            target: InputTarget::SnippetFilter,
+            direction,
+        } => {
+            let killed = state
+                .snippets
+                .picker_mut()
+                .edit_filter(|input| input.kill(*direction));
+            state.record_kill(killed);
+            DispatchResult::handled()
+        }
+        Action::TextYank {
+            target: InputTarget::SnippetFilter,
+        } => {
+            if let Some(killed) = state.kill_buffer().map(str::to_owned) {
+                state
+                    .snippets
+                    .picker_mut()
+                    .edit_filter(|input| input.yank(&killed));
+            }
+            DispatchResult::handled()
+        }
+        Action::TextMoveCursor {
+            target: InputTarget::SnippetFilter,
+            direction,
+        } => {
+            state.snippets.picker_mut().move_filter_cursor(*direction);
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::Snippets,
+            motion: ListMotion::Next,
+        } => {
+            state.snippets.select_next();
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::Snippets,
+            motion: ListMotion::Previous,
+        } => {
+            state.snippets.select_previous();
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        reduce_snippets(state, action, Instant::now())
+            .into_effects()
+            .unwrap()
+    }
+
+    fn snippet(name: &str, sql: &str) -> Snippet {
+        Snippet {
+            name: name.to_string(),
+            sql: sql.to_string(),
+        }
+    }
+
+    fn open_with_snippets(state: &mut AppState, snippets: Vec<Snippet>) {
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+        dispatch(state, &Action::OpenModal(ModalKind::SnippetPicker));
+        dispatch(state, &Action::SnippetsLoaded(snippets));
+    }
+
+    #[test]
+    fn open_loads_snippets_from_project_root() {
+        let mut state = AppState::new("test".to_string());
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+
+        let effects = dispatch(&mut state, &Action::OpenModal(ModalKind::SnippetPicker));
+
+        assert_eq!(state.input_mode(), InputMode::SnippetPicker);
+        assert!(state.snippets.is_loading());
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LoadSnippets { root }] if root == &PathBuf::from("/work/app")
+        ));
+    }
+
+    #[test]
+    fn loaded_snippets_feed_completion() {
+        let mut state = AppState::new("test".to_string());
+
+        let effects = dispatch(
+            &mut state,
+            &Action::SnippetsLoaded(vec![snippet("active", "SELECT 1")]),
+        );
+
+        assert_eq!(state.snippets.snippets().len(), 1);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::SetCompletionSnippets { snippets }] if snippets.len() == 1
+        ));
+    }
+
+    #[test]
+    fn save_stores_editor_content_under_name() {
+        let mut state = AppState::new("test".to_string());
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+        state
+            .sql_modal
+            .editor
+            .set_content("SELECT * FROM users;\n".to_string());
+
+        let effects = dispatch(&mut state, &Action::SaveSnippet(" users ".to_string()));
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::SaveSnippet { snippet: saved, .. }]
+                if saved == &snippet("users", "SELECT * FROM users;")
+        ));
+    }
+
+    #[test]
+    fn save_with_empty_editor_reports_error() {
+        let mut state = AppState::new("test".to_string());
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+
+        let effects = dispatch(&mut state, &Action::SaveSnippet("users".to_string()));
+
+        assert!(effects.is_empty());
+        assert!(state.messages.last_error().is_some());
+    }
+
+    #[test]
+    fn insert_adds_snippet_at_editor_cursor() {
+        let mut state = AppState::new("test".to_string());
+        state.sql_modal.editor.set_content("-- ".to_string());
+        open_with_snippets(&mut state, vec![snippet("one", "SELECT 1")]);
+
+        let effects = dispatch(&mut state, &Action::SnippetInsert);
+
+        assert_eq!(state.sql_modal.editor.content(), "-- SELECT 1");
+        assert_eq!(state.input_mode(), InputMode::Normal);
+        let [Effect::DispatchActions(actions)] = effects.as_slice() else {
+            panic!("expected DispatchActions, got {effects:?}");
+        };
+        assert!(matches!(
+            actions.as_slice(),
+            [Action::OpenModal(ModalKind::SqlModal)]
+        ));
+    }
+
+    #[test]
+    fn run_replaces_editor_and_submits() {
+        let mut state = AppState::new("test".to_string());
+        state.sql_modal.editor.set_content("-- draft".to_string());
+        open_with_snippets(&mut state, vec![snippet("one", "SELECT 1")]);
+
+        let effects = dispatch(&mut state, &Action::SnippetRun);
+
+        assert_eq!(state.sql_modal.editor.content(), "SELECT 1");
+        let [Effect::DispatchActions(actions)] = effects.as_slice() else {
+            panic!("expected DispatchActions, got {effects:?}");
+        };
+        assert!(matches!(
+            actions.as_slice(),
+            [
+                Action::OpenModal(ModalKind::SqlModal),
+                Action::SqlModalSubmit
+            ]
+        ));
+    }
+}
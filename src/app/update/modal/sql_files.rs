@@ -0,0 +1,260 @@
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::text_input::{TextInputEditing, TextInputState};
+use crate::update::action::{Action, InputTarget, ListMotion, ListTarget, ModalKind};
+use crate::update::dispatch_result::DispatchResult;
+
+pub(super) fn reduce_sql_file_browser(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+) -> DispatchResult {
+    match action {
+        Action::OpenModal(ModalKind::SqlFileBrowser) => {
+            if state.modal.active_mode() == InputMode::SqlFileBrowser {
+                return DispatchResult::handled();
+            }
+            let Some(root) = state.runtime.project_root().map(ToOwned::to_owned) else {
+                state
+                    .messages
+                    .set_error_at("No project directory to browse".to_string(), now);
+                return DispatchResult::handled();
+            };
+            state.sql_files.begin_scan();
+            state.modal.push_mode(InputMode::SqlFileBrowser);
+            DispatchResult::handled_with(vec![Effect::LoadSqlFiles { root }])
+        }
+        Action::CloseModal(ModalKind::SqlFileBrowser) => {
+            state.modal.pop_mode();
+            state.sql_files.clear();
+            DispatchResult::handled()
+        }
+        Action::SqlFilesLoaded(files) => {
+            if state.modal.active_mode() == InputMode::SqlFileBrowser {
+                state.sql_files.set_loaded(files.clone());
+            }
+            DispatchResult::handled()
+        }
+        Action::SqlFilesLoadFailed(error) => {
+            if state.modal.active_mode() != InputMode::SqlFileBrowser {
+                return DispatchResult::handled();
+            }
+            state.sql_files.set_failed();
+            state.messages.set_error_at(error.to_string(), now);
+            DispatchResult::handled()
+        }
+        Action::SqlFileOpen | Action::SqlFileRun => {
+            let Some(content) = state.sql_files.selected_file().map(|f| f.content.clone()) else {
+                return DispatchResult::handled();
+            };
+            state.modal.pop_mode();
+            state.sql_files.clear();
+            state.sql_modal.editor.set_content(content);
+
+            // Running goes through the normal submit path so multi-statement,
+            // read-only and confirmation checks still apply.
+            let mut follow_up = vec![Action::OpenModal(ModalKind::SqlModal)];
+            if matches!(action, Action::SqlFileRun) {
+                follow_up.push(Action::SqlModalSubmit);
+            }
+            DispatchResult::handled_with(vec![Effect::DispatchActions(follow_up)])
+        }
+        Action::TextInput {
+            target: InputTarget::SqlFileFilter,
+            ch,
+        } => {
+            state.sql_files.picker_mut().insert_filter_char(*ch);
+            DispatchResult::handled()
+        }
+        Action::TextBackspace {
+            target: InputTarget::SqlFileFilter,
+        } => {
+            state.sql_files.picker_mut().backspace_filter();
+            DispatchResult::handled()
+        }
+        Action::TextDelete {
+            target: InputTarget::SqlFileFilter,
+        } => {
+            state
+                .sql_files
+                .picker_mut()
+                .edit_filter(TextInputState::delete);
+            DispatchResult::handled()
+        }
+        Action::TextKill {
+            target: InputTarget::SqlFileFilter,
+            direction,
+        } => {
+            let killed = state
+                .sql_files
+                .picker_mut()
+                .edit_filter(|input| input.kill(*direction));
+            state.record_kill(killed);
+            DispatchResult::handled()
+        }
+        Action::TextYank {
+            target: InputTarget::SqlFileFilter,
+        } => {
+            if let Some(killed) = state.kill_buffer().map(str::to_owned) {
+                state
+                    .sql_files
+                    .picker_mut()
+                    .edit_filter(|input| input.yank(&killed));
+            }
+            DispatchResult::handled()
+        }
+        Action::TextMoveCursor {
+            target: InputTarget::SqlFileFilter,
+            direction,
+        } => {
+            state.sql_files.picker_mut().move_filter_cursor(*direction);
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::SqlFiles,
+            motion: ListMotion::Next,
+        } => {
+            state.sql_files.select_next();
+            DispatchResult::handled()
+        }
+        Action::ListSelect {
+            target: ListTarget::SqlFiles,
+            motion: ListMotion::Previous,
+        } => {
+            state.sql_files.select_previous();
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::model::shared::text_input::TextInputLike;
+    use crate::ports::outbound::SqlFile;
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        reduce_sql_file_browser(state, action, Instant::now())
+            .into_effects()
+            .unwrap()
+    }
+
+    fn open_with_files(state: &mut AppState, paths: &[&str]) {
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+        dispatch(state, &Action::OpenModal(ModalKind::SqlFileBrowser));
+        let files = paths
+            .iter()
+            .map(|path| SqlFile {
+                path: (*path).to_string(),
+                content: format!("SELECT '{path}';"),
+            })
+            .collect();
+        dispatch(state, &Action::SqlFilesLoaded(files));
+    }
+
+    #[test]
+    fn open_scans_project_root() {
+        let mut state = AppState::new("test".to_string());
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+
+        let effects = dispatch(&mut state, &Action::OpenModal(ModalKind::SqlFileBrowser));
+
+        assert_eq!(state.input_mode(), InputMode::SqlFileBrowser);
+        assert!(state.sql_files.is_loading());
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LoadSqlFiles { root }] if root == &PathBuf::from("/work/app")
+        ));
+    }
+
+    #[test]
+    fn open_without_project_root_reports_error() {
+        let mut state = AppState::new("test".to_string());
+
+        let effects = dispatch(&mut state, &Action::OpenModal(ModalKind::SqlFileBrowser));
+
+        assert!(effects.is_empty());
+        assert_eq!(state.input_mode(), InputMode::Normal);
+        assert!(state.messages.last_error().is_some());
+    }
+
+    #[test]
+    fn open_loads_selected_file_into_sql_modal() {
+        let mut state = AppState::new("test".to_string());
+        open_with_files(&mut state, &["a.sql", "b.sql"]);
+        dispatch(
+            &mut state,
+            &Action::ListSelect {
+                target: ListTarget::SqlFiles,
+                motion: ListMotion::Next,
+            },
+        );
+
+        let effects = dispatch(&mut state, &Action::SqlFileOpen);
+
+        assert_eq!(state.sql_modal.editor.content(), "SELECT 'b.sql';");
+        assert!(state.sql_files.files().is_empty());
+        let [Effect::DispatchActions(actions)] = effects.as_slice() else {
+            panic!("expected DispatchActions, got {effects:?}");
+        };
+        assert!(matches!(
+            actions.as_slice(),
+            [Action::OpenModal(ModalKind::SqlModal)]
+        ));
+    }
+
+    #[test]
+    fn run_submits_after_opening_sql_modal() {
+        let mut state = AppState::new("test".to_string());
+        open_with_files(&mut state, &["a.sql"]);
+
+        let effects = dispatch(&mut state, &Action::SqlFileRun);
+
+        let [Effect::DispatchActions(actions)] = effects.as_slice() else {
+            panic!("expected DispatchActions, got {effects:?}");
+        };
+        assert!(matches!(
+            actions.as_slice(),
+            [
+                Action::OpenModal(ModalKind::SqlModal),
+                Action::SqlModalSubmit
+            ]
+        ));
+    }
+
+    #[test]
+    fn open_with_no_match_keeps_browser() {
+        let mut state = AppState::new("test".to_string());
+        open_with_files(&mut state, &["a.sql"]);
+        state.sql_files.picker_mut().insert_filter_str("zzz");
+
+        let effects = dispatch(&mut state, &Action::SqlFileOpen);
+
+        assert!(effects.is_empty());
+        assert_eq!(state.input_mode(), InputMode::SqlFileBrowser);
+    }
+
+    #[test]
+    fn late_scan_result_after_close_is_ignored() {
+        let mut state = AppState::new("test".to_string());
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+        dispatch(&mut state, &Action::OpenModal(ModalKind::SqlFileBrowser));
+        dispatch(&mut state, &Action::CloseModal(ModalKind::SqlFileBrowser));
+
+        dispatch(
+            &mut state,
+            &Action::SqlFilesLoaded(vec![SqlFile {
+                path: "a.sql".to_string(),
+                content: String::new(),
+            }]),
+        );
+
+        assert!(state.sql_files.files().is_empty());
+    }
+}
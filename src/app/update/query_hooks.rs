@@ -0,0 +1,221 @@
+use crate::cmd::effect::Effect;
+use crate::domain::{CommandTag, QuerySource};
+use crate::model::app_state::AppState;
+use crate::policy::query_hooks::{HookContext, invocations_for};
+use crate::ports::outbound::HookEvent;
+use crate::update::action::Action;
+
+/// Evaluated before the action is reduced: stale runs are still detectable
+/// and the pending write preview has not been cleared yet.
+pub fn query_hook_effect(state: &AppState, action: &Action) -> Option<Effect> {
+    let hooks = state.runtime.query_hooks();
+    if hooks.is_empty() {
+        return None;
+    }
+    let connection = state.session.active_connection_name().unwrap_or_default();
+
+    let invocations = match action {
+        Action::QueryCompleted {
+            dsn,
+            run_id,
+            result,
+            ..
+        } => {
+            if result.source != QuerySource::Adhoc || state.is_stale_query_run(dsn, *run_id) {
+                return None;
+            }
+            let context = HookContext {
+                event: HookEvent::SlowQuery,
+                connection,
+                query: &result.query,
+                duration_ms: Some(result.execution_time_ms),
+                rows: Some(result.row_count()),
+                error: result.error.as_deref(),
+            };
+            if result.is_error() {
+                invocations_for(
+                    hooks,
+                    &HookContext {
+                        event: HookEvent::QueryError,
+                        ..context
+                    },
+                )
+            } else {
+                let mut invocations = invocations_for(hooks, &context);
+                if result
+                    .command_tag
+                    .as_ref()
+                    .is_some_and(CommandTag::needs_refresh)
+                {
+                    invocations.extend(invocations_for(
+                        hooks,
+                        &HookContext {
+                            event: HookEvent::WriteExecuted,
+                            ..context
+                        },
+                    ));
+                }
+                invocations
+            }
+        }
+        Action::QueryFailed {
+            dsn,
+            run_id,
+            error,
+            source: QuerySource::Adhoc,
+            ..
+        } => {
+            if state.is_stale_query_run(dsn, *run_id) {
+                return None;
+            }
+            invocations_for(
+                hooks,
+                &HookContext {
+                    event: HookEvent::QueryError,
+                    connection,
+                    query: &state.sql_modal.submitted_query(),
+                    duration_ms: None,
+                    rows: None,
+                    error: Some(&error.user_message()),
+                },
+            )
+        }
+        Action::ExecuteWriteSucceeded {
+            dsn,
+            run_id,
+            affected_rows,
+        } => {
+            if state.is_stale_query_run(dsn, *run_id) {
+                return None;
+            }
+            invocations_for(
+                hooks,
+                &HookContext {
+                    event: HookEvent::WriteExecuted,
+                    connection,
+                    query: state
+                        .result_interaction
+                        .pending_write_preview()
+                        .map_or("", |preview| preview.sql.as_str()),
+                    duration_ms: None,
+                    rows: Some(*affected_rows),
+                    error: None,
+                },
+            )
+        }
+        _ => return None,
+    };
+
+    (!invocations.is_empty()).then_some(Effect::RunQueryHooks { invocations })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::QueryResult;
+    use crate::ports::outbound::{HookTarget, QueryHook};
+    use crate::update::test_fixtures;
+
+    const DSN: &str = "postgres://localhost/test";
+
+    fn state_with_hooks(events: &[HookEvent]) -> AppState {
+        let mut state = AppState::new("test".to_string());
+        test_fixtures::activate_postgres_connection(&mut state, DSN);
+        state.runtime.set_query_hooks(
+            events
+                .iter()
+                .map(|event| QueryHook {
+                    event: *event,
+                    min_duration_ms: 1000,
+                    target: HookTarget::Webhook("https://example.test/hook".to_string()),
+                    payload_template: None,
+                })
+                .collect(),
+        );
+        state
+    }
+
+    fn completed(state: &mut AppState, result: QueryResult) -> Action {
+        Action::QueryCompleted {
+            dsn: DSN.to_string(),
+            run_id: state.query.begin_running(std::time::Instant::now()),
+            result: Arc::new(result),
+            generation: 0,
+            target_page: None,
+        }
+    }
+
+    fn invocation_count(effect: Option<Effect>) -> usize {
+        match effect {
+            Some(Effect::RunQueryHooks { invocations }) => invocations.len(),
+            None => 0,
+            Some(other) => panic!("unexpected effect {other:?}"),
+        }
+    }
+
+    #[test]
+    fn slow_adhoc_query_fires_slow_query_hook() {
+        let mut state = state_with_hooks(&[HookEvent::SlowQuery]);
+        let action = completed(
+            &mut state,
+            QueryResult::success(
+                "SELECT 1".to_string(),
+                vec![],
+                vec![],
+                1500,
+                QuerySource::Adhoc,
+            ),
+        );
+
+        assert_eq!(invocation_count(query_hook_effect(&state, &action)), 1);
+    }
+
+    #[test]
+    fn preview_queries_never_fire_hooks() {
+        let mut state = state_with_hooks(&[HookEvent::SlowQuery, HookEvent::QueryError]);
+        let action = completed(
+            &mut state,
+            QueryResult::error(
+                "SELECT 1".to_string(),
+                "boom".to_string(),
+                5000,
+                QuerySource::Preview,
+            ),
+        );
+
+        assert_eq!(invocation_count(query_hook_effect(&state, &action)), 0);
+    }
+
+    #[test]
+    fn adhoc_error_fires_query_error_hook_only() {
+        let mut state = state_with_hooks(&[HookEvent::SlowQuery, HookEvent::QueryError]);
+        let action = completed(
+            &mut state,
+            QueryResult::error(
+                "SELECT 1".to_string(),
+                "boom".to_string(),
+                5000,
+                QuerySource::Adhoc,
+            ),
+        );
+
+        assert_eq!(invocation_count(query_hook_effect(&state, &action)), 1);
+    }
+
+    #[test]
+    fn stale_run_fires_nothing() {
+        let mut state = state_with_hooks(&[HookEvent::WriteExecuted]);
+        let run_id = state.query.begin_running(std::time::Instant::now());
+        let _ = state.query.begin_running(std::time::Instant::now());
+
+        let action = Action::ExecuteWriteSucceeded {
+            dsn: DSN.to_string(),
+            run_id,
+            affected_rows: 1,
+        };
+
+        assert_eq!(invocation_count(query_hook_effect(&state, &action)), 0);
+    }
+}
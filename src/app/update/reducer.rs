@@ -17,10 +17,15 @@ use crate::model::app_state::AppState;
 use crate::model::shared::focused_pane::FocusedPane;
 use crate::model::shared::input_mode::InputMode;
 use crate::model::shared::key_sequence::KeySequenceState;
+use crate::model::shared::startup_profile::StartupPhase;
 use crate::policy::FeaturePolicy;
+use crate::policy::explorer_tree::ExplorerRow;
 use crate::services::AppServices;
 use crate::update::action::{Action, TableTarget};
 use crate::update::query_context::termination_effects;
+use crate::update::query_hooks::query_hook_effect;
+use crate::update::sql_editor::sql_edit_kind;
+use crate::update::undo::{capture_undo_snapshot, record_undo_snapshot, undo_last_mutation};
 
 pub fn reduce(
     state: &mut AppState,
@@ -36,7 +41,28 @@ pub fn reduce(
     // Mark dirty for all state-changing actions (except None and Render)
     let should_mark_dirty = !matches!(action, Action::None | Action::Render);
 
-    let effects = reduce_inner(state, action, now, services);
+    let hook_effect = query_hook_effect(state, &action);
+    // Only browse-mode moves count; connection switches restore a cached layout.
+    let layout_before = (state.input_mode() == InputMode::Normal)
+        .then(|| (state.ui.focused_pane(), state.ui.inspector_tab()));
+    let undo_snapshot = capture_undo_snapshot(state, &action);
+    let sql_edit = sql_edit_kind(&action);
+    let mut effects = reduce_inner(state, action, now, services);
+    effects.extend(hook_effect);
+    if let Some(snapshot) = undo_snapshot {
+        record_undo_snapshot(state, snapshot);
+    }
+    state.sql_modal.sync_edit_history(sql_edit);
+    if let Some((pane, tab)) = layout_before {
+        if state.ui.focused_pane() != pane {
+            state.usage.record_pane(state.ui.focused_pane());
+        }
+        if state.ui.inspector_tab() != tab {
+            state.usage.record_tab(state.ui.inspector_tab());
+        }
+    }
+    state.result_interaction.sync_cell_peek(now);
+    note_startup_progress(state, &effects, now);
 
     if should_mark_dirty {
         state.mark_dirty();
@@ -45,6 +71,26 @@ pub fn reduce(
     effects
 }
 
+// Database-bound phases are read off each action's outcome; the main loop
+// marks config load and first render itself.
+fn note_startup_progress(state: &mut AppState, effects: &[Effect], now: Instant) {
+    let connecting = effects
+        .iter()
+        .any(|effect| matches!(effect, Effect::FetchMetadata { .. }));
+    let connected = state.session.connection_state().is_connected();
+    let prefetching = state.sql_modal.is_prefetch_started();
+    let startup = state.runtime.startup_mut();
+    if connecting {
+        startup.mark(StartupPhase::Connect, now);
+    }
+    if connected {
+        startup.mark(StartupPhase::MetadataFetch, now);
+    }
+    if prefetching {
+        startup.mark(StartupPhase::PrefetchStart, now);
+    }
+}
+
 fn reduce_inner(
     state: &mut AppState,
     action: Action,
@@ -84,6 +130,10 @@ fn reduce_inner(
             state.should_quit = true;
             vec![]
         }
+        Action::UndoUiMutation => {
+            undo_last_mutation(state, now);
+            vec![]
+        }
         Action::Resize(w, h) => {
             state.ui.set_terminal_width(w);
             state.ui.set_terminal_height(h);
@@ -116,24 +166,38 @@ fn reduce_inner(
                 if state.ui.focused_pane() != FocusedPane::Explorer {
                     return vec![];
                 }
-                let table = state
-                    .tables()
-                    .get(state.ui.explorer_selected())
-                    .copied()
-                    .cloned();
-                if let Some(table) = table {
-                    return select_table(state, &table);
+                match state.explorer_selected_row() {
+                    Some(ExplorerRow::Schema { .. } | ExplorerRow::Section { .. }) => {
+                        return reduce(state, Action::ExplorerToggleSchema, now, services);
+                    }
+                    Some(ExplorerRow::Table { table, .. }) => {
+                        let table = table.clone();
+                        return select_table(state, &table);
+                    }
+                    Some(ExplorerRow::Object { object, .. }) => {
+                        let object = object.clone();
+                        state.session.inspect_object(&object, &mut state.query);
+                        state.result_interaction.reset_interaction();
+                        state.ui.set_inspector_scroll_offset(0);
+                        return termination_effects(&state.query, vec![]);
+                    }
+                    None => {}
                 }
             } else if state.modal.active_mode() == InputMode::CommandPalette {
-                use crate::update::input::palette::palette_action_for_index;
+                use crate::update::input::palette::palette_command_for_index;
 
-                let cmd_action = palette_action_for_index(
+                let command = palette_command_for_index(
                     state.ui.table_picker().selected(),
                     state.settings.saved_keymap_preset(),
                     state.session.active_engine_feature_profile(),
+                    &state.usage,
                 );
                 state.modal.set_mode(InputMode::Normal);
-                return reduce(state, cmd_action, now, services);
+                let Some(command) = command else {
+                    return vec![];
+                };
+                state.usage.record_command(command.description);
+                return reduce(state, command.action.clone(), now, services);
             }
 
             vec![]
@@ -194,7 +258,7 @@ mod tests {
     use std::sync::Arc;
 
     use super::*;
-    use crate::domain::{ConnectionId, DatabaseType};
+    use crate::domain::{ConnectionId, DatabaseType, UsageStats};
     use crate::ports::outbound::DbOperationError;
     use crate::ports::outbound::connection_store::ConnectionStoreError;
     use crate::update::action::ModalKind;
@@ -1002,6 +1066,9 @@ mod tests {
                 text: text.to_string(),
                 kind: CompletionKind::Table,
                 score: 0,
+                detail: None,
+                insert_text: None,
+                preview: Vec::new(),
             }
         }
 
@@ -1323,7 +1390,13 @@ mod tests {
             ));
             assert_eq!(state.input_mode(), InputMode::ConnectionError);
             assert!(state.connection_error.error_info.is_some());
-            assert!(matches!(effects.as_slice(), [Effect::CancelActiveQuery]));
+            assert!(matches!(
+                effects.as_slice(),
+                [
+                    Effect::CancelActiveQuery,
+                    Effect::LoadMetadataSnapshot { .. }
+                ]
+            ));
         }
 
         #[test]
@@ -1490,7 +1563,7 @@ mod tests {
         use crate::test_support;
 
         use super::*;
-        use crate::domain::{DatabaseMetadata, Table};
+        use crate::domain::{DatabaseMetadata, SchemaObject, SchemaObjectKind, Table};
 
         fn stale_table_detail() -> Table {
             Table {
@@ -1535,6 +1608,85 @@ mod tests {
             assert!(state.session.table_detail().is_none());
         }
 
+        #[test]
+        fn confirm_selection_on_schema_header_folds_schema() {
+            let now = Instant::now();
+            let mut state = create_test_state();
+            state.session.set_metadata(Some(Arc::new({
+                let mut metadata = DatabaseMetadata::new("test".to_string());
+                metadata.table_summaries = vec![
+                    TableSummary::new("audit".to_string(), "events".to_string(), None, false),
+                    TableSummary::new("public".to_string(), "users".to_string(), None, false),
+                ];
+                metadata
+            })));
+            state.modal.set_mode(InputMode::Normal);
+            state.ui.set_focused_pane(FocusedPane::Explorer);
+            state.ui.set_explorer_selection(Some(0));
+
+            let effects = reduce(
+                &mut state,
+                Action::ConfirmSelection,
+                now,
+                &AppServices::stub(),
+            );
+
+            assert!(effects.is_empty());
+            assert!(state.explorer_tree.layout().is_collapsed("audit"));
+            assert!(state.query.pagination.table().is_empty());
+        }
+
+        #[test]
+        fn confirm_selection_on_object_shows_it_in_the_inspector() {
+            let now = Instant::now();
+            let mut state = create_test_state();
+            state.session.set_metadata(Some(Arc::new({
+                let mut metadata = DatabaseMetadata::new("test".to_string());
+                metadata.table_summaries = vec![TableSummary::new(
+                    "public".to_string(),
+                    "users".to_string(),
+                    None,
+                    false,
+                )];
+                metadata.objects = vec![SchemaObject {
+                    schema: "public".to_string(),
+                    name: "active_users".to_string(),
+                    kind: SchemaObjectKind::View,
+                    arguments: None,
+                    definition: Some("CREATE VIEW public.active_users AS\n SELECT 1;".to_string()),
+                }];
+                metadata
+            })));
+            state
+                .session
+                .set_table_detail_raw(Some(stale_table_detail()));
+            state
+                .explorer_tree
+                .toggle_section("public", SchemaObjectKind::View);
+            state.modal.set_mode(InputMode::Normal);
+            state.ui.set_focused_pane(FocusedPane::Explorer);
+            state.ui.set_explorer_selection(Some(2));
+
+            reduce(
+                &mut state,
+                Action::ConfirmSelection,
+                now,
+                &AppServices::stub(),
+            );
+
+            assert!(state.session.table_detail().is_none());
+            assert_eq!(
+                state
+                    .session
+                    .inspected_object()
+                    .map(SchemaObject::qualified_name),
+                Some("public.active_users".to_string())
+            );
+            let view_model = state.inspector_view_model(AppServices::stub().ddl_generator.as_ref());
+            assert_eq!(view_model.object_tab(), Some("Definition"));
+            assert_eq!(view_model.row_count(), 2);
+        }
+
         #[test]
         fn confirm_selection_in_table_picker_mode_clears_stale_table_detail() {
             let now = Instant::now();
@@ -1804,6 +1956,8 @@ mod tests {
     }
 
     mod table_detail_cached {
+        use std::time::Duration;
+
         use crate::test_support;
 
         use super::*;
@@ -1848,6 +2002,65 @@ mod tests {
             assert!(!state.sql_modal.is_table_prefetching("public.users"));
         }
 
+        #[test]
+        fn burst_of_cached_tables_refreshes_open_completion_once() {
+            let mut state = create_test_state();
+            test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/test");
+            state.modal.set_mode(InputMode::SqlModal);
+            state.sql_modal.completion_mut_for_test().visible = true;
+            let run_id = state.sql_modal.begin_prefetch();
+            let start = Instant::now();
+
+            for (offset, table) in [(0, "users"), (40, "orders")] {
+                state
+                    .sql_modal
+                    .start_table_prefetch(format!("public.{table}"));
+                reduce(
+                    &mut state,
+                    Action::TableDetailCached {
+                        dsn: "postgres://localhost/test".to_string(),
+                        run_id,
+                        schema: "public".to_string(),
+                        table: table.to_string(),
+                        detail: make_test_table(),
+                    },
+                    start + Duration::from_millis(offset),
+                    &AppServices::stub(),
+                );
+            }
+
+            assert_eq!(
+                state.sql_modal.completion_debounce(),
+                Some(start + Duration::from_millis(150))
+            );
+        }
+
+        #[test]
+        fn cached_table_without_open_completion_schedules_nothing() {
+            let mut state = create_test_state();
+            test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/test");
+            state.modal.set_mode(InputMode::SqlModal);
+            let run_id = state.sql_modal.begin_prefetch();
+            state
+                .sql_modal
+                .start_table_prefetch("public.users".to_string());
+
+            reduce(
+                &mut state,
+                Action::TableDetailCached {
+                    dsn: "postgres://localhost/test".to_string(),
+                    run_id,
+                    schema: "public".to_string(),
+                    table: "users".to_string(),
+                    detail: make_test_table(),
+                },
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            assert!(state.sql_modal.completion_debounce().is_none());
+        }
+
         #[test]
         fn with_queue_returns_process_effect() {
             let mut state = create_test_state();
@@ -2307,6 +2520,32 @@ mod tests {
             assert!(matches!(effects[0], Effect::FetchMetadata { .. }));
         }
 
+        #[test]
+        fn first_connect_attempt_is_marked_as_startup_phase() {
+            let mut state = create_test_state();
+            test_fixtures::activate_postgres_connection(&mut state, "postgres://localhost/test");
+            state
+                .session
+                .set_connection_state(ConnectionState::NotConnected);
+            state.modal.set_mode(InputMode::Normal);
+            let launched_at = Instant::now();
+            state.runtime.startup_mut().begin(launched_at);
+            let now = launched_at + std::time::Duration::from_millis(120);
+
+            reduce(&mut state, Action::TryConnect, now, &AppServices::stub());
+
+            assert_eq!(
+                state.runtime.startup().elapsed(StartupPhase::Connect),
+                Some(std::time::Duration::from_millis(120))
+            );
+            assert!(
+                !state
+                    .runtime
+                    .startup()
+                    .is_marked(StartupPhase::MetadataFetch)
+            );
+        }
+
         #[test]
         fn try_connect_without_dsn_does_nothing() {
             let mut state = create_test_state();
@@ -2741,7 +2980,7 @@ mod tests {
     mod er_table_picker {
         use super::*;
         use crate::domain::DatabaseMetadata;
-        use crate::model::er_state::ErStatus;
+        use crate::model::er_state::{ErOutput, ErStatus};
 
         fn state_with_metadata() -> AppState {
             let mut state = create_test_state();
@@ -2914,6 +3153,25 @@ mod tests {
             assert!(matches!(effects[0], Effect::DispatchActions(_)));
         }
 
+        #[test]
+        fn view_selection_sends_the_diagram_to_the_terminal_viewer() {
+            let mut state = state_with_metadata();
+            state.modal.set_mode(InputMode::ErTablePicker);
+            state
+                .ui
+                .toggle_er_selected_table("public.users".to_string());
+
+            reduce(
+                &mut state,
+                Action::ErViewSelection,
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            assert_eq!(state.er_preparation.take_output(), ErOutput::Viewer);
+            assert_eq!(state.input_mode(), InputMode::Normal);
+        }
+
         #[test]
         fn confirm_with_no_selection_returns_error() {
             let mut state = state_with_metadata();
@@ -3197,6 +3455,7 @@ mod tests {
             palette_commands(
                 state.settings.saved_keymap_preset(),
                 state.session.active_engine_feature_profile(),
+                &state.usage,
             )
             .enumerate()
             .find(|(_, kb)| target(&kb.action))
@@ -3308,6 +3567,83 @@ mod tests {
                 "palette must be closed after confirm"
             );
         }
+
+        #[test]
+        fn confirm_selection_counts_the_entry_when_tracking_usage() {
+            let mut state = state_in_palette_mode(KeymapPreset::Default);
+            state.usage.load(true, UsageStats::default());
+            let entry_index = palette_index_of(&state, |a| {
+                matches!(a, Action::OpenModal(ModalKind::SqlModal))
+            });
+            state.ui.table_picker_mut().set_selection(entry_index);
+
+            reduce(
+                &mut state,
+                Action::ConfirmSelection,
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            let description = palette_commands(
+                KeymapPreset::Default,
+                state.session.active_engine_feature_profile(),
+                &state.usage,
+            )
+            .find(|kb| matches!(kb.action, Action::OpenModal(ModalKind::SqlModal)))
+            .unwrap()
+            .description;
+            assert_eq!(state.usage.command_count(description), 1);
+        }
+    }
+
+    mod layout_usage {
+        use super::*;
+        use crate::domain::UsageKind;
+        use crate::model::shared::inspector_tab::InspectorTab;
+
+        #[test]
+        fn browse_focus_and_tab_changes_are_counted() {
+            let mut state = create_test_state();
+            state.session.activate_connection_with_dsn(
+                &ConnectionId::new(),
+                "pg",
+                DatabaseType::PostgreSQL,
+                "postgres://localhost/test",
+            );
+            state.usage.load(true, UsageStats::default());
+            state.ui.set_inspector_tab(InspectorTab::Info);
+
+            reduce(
+                &mut state,
+                Action::SetFocusedPane(FocusedPane::Inspector),
+                Instant::now(),
+                &AppServices::stub(),
+            );
+            reduce(
+                &mut state,
+                Action::InspectorNextTab,
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            let stats = state.usage.stats();
+            assert_eq!(stats.count(UsageKind::Pane, "Inspector"), 1);
+            assert_eq!(stats.count(UsageKind::Tab, "Cols"), 1);
+        }
+
+        #[test]
+        fn nothing_is_counted_while_tracking_is_off() {
+            let mut state = create_test_state();
+
+            reduce(
+                &mut state,
+                Action::SetFocusedPane(FocusedPane::Result),
+                Instant::now(),
+                &AppServices::stub(),
+            );
+
+            assert!(state.usage.stats().is_empty());
+        }
     }
 
     mod operator_pending {
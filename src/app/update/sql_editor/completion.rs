@@ -2,7 +2,6 @@ use std::time::Instant;
 
 use crate::cmd::effect::Effect;
 use crate::model::app_state::AppState;
-use crate::model::sql_editor::modal::sql_modal_visible_rows;
 use crate::update::action::Action;
 use crate::update::dispatch_result::DispatchResult;
 
@@ -21,20 +20,33 @@ pub(super) fn reduce_completion(
             state.sql_modal.completion_prev();
             DispatchResult::handled()
         }
+        Action::CompletionTogglePreview => {
+            state.sql_modal.toggle_completion_preview();
+            DispatchResult::handled()
+        }
         Action::CompletionDismiss => {
             state.sql_modal.dismiss_completion();
             DispatchResult::handled()
         }
         // Completion accept
         Action::CompletionAccept => {
-            state
-                .sql_modal
-                .accept_selected_completion(sql_modal_visible_rows(state.ui.terminal_height()));
+            state.sql_modal.accept_selected_completion(
+                state.sql_modal.visible_rows(state.ui.terminal_height()),
+            );
             DispatchResult::handled()
         }
 
         // Completion trigger/update
-        Action::CompletionRequest => DispatchResult::handled_with(vec![Effect::TriggerCompletion]),
+        Action::CompletionRequest => {
+            let mut effects = vec![Effect::TriggerCompletion];
+            // Schemas the buffer now names join the per-schema prefetch.
+            if state.sql_modal.is_schema_prefetch() {
+                effects.push(Effect::DispatchActions(vec![
+                    Action::PrefetchReferencedSchemas,
+                ]));
+            }
+            DispatchResult::handled_with(effects)
+        }
         Action::CompletionUpdated {
             candidates,
             trigger_position,
@@ -4,7 +4,7 @@ use crate::model::app_state::AppState;
 use crate::model::shared::input_mode::InputMode;
 use crate::model::shared::key_sequence::KeySequenceState;
 use crate::model::shared::text_input::TextInputEditing;
-use crate::model::sql_editor::modal::{SqlModalStatus, sql_modal_visible_rows};
+use crate::model::sql_editor::modal::SqlModalStatus;
 use crate::update::action::{Action, CursorMove, InputTarget};
 use crate::update::dispatch_result::DispatchResult;
 
@@ -13,6 +13,40 @@ pub(super) fn reduce_editing(
     action: &Action,
     now: Instant,
 ) -> DispatchResult {
+    if state.modal.active_mode() != InputMode::SqlModal || !can_retype_alias(action) {
+        return reduce_buffer(state, action, now);
+    }
+    let aliases_before = state.sql_modal.editor_table_aliases();
+    let result = reduce_buffer(state, action, now);
+    state.sql_modal.track_alias_edit(&aliases_before);
+    result
+}
+
+fn can_retype_alias(action: &Action) -> bool {
+    matches!(
+        action,
+        Action::Paste(_)
+            | Action::TextInput {
+                target: InputTarget::SqlModal,
+                ..
+            }
+            | Action::TextBackspace {
+                target: InputTarget::SqlModal,
+            }
+            | Action::TextDelete {
+                target: InputTarget::SqlModal,
+            }
+            | Action::TextKill {
+                target: InputTarget::SqlModal,
+                ..
+            }
+            | Action::TextYank {
+                target: InputTarget::SqlModal,
+            }
+    )
+}
+
+fn reduce_buffer(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
     match action {
         // Clipboard paste
         Action::Paste(text) if state.modal.active_mode() == InputMode::SqlModal => {
@@ -24,7 +58,7 @@ pub(super) fn reduce_editing(
             state
                 .sql_modal
                 .editor
-                .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
             state
                 .sql_modal
                 .schedule_completion_after_dismiss(now + Duration::from_millis(100));
@@ -39,10 +73,15 @@ pub(super) fn reduce_editing(
         } => {
             state.sql_modal.enter_editing();
             state.sql_modal.editor.insert_char(*c);
+            if let Some(case) = state.settings.keyword_case()
+                && !(c.is_alphanumeric() || *c == '_')
+            {
+                state.sql_modal.apply_keyword_case(case);
+            }
             state
                 .sql_modal
                 .editor
-                .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
             state
                 .sql_modal
                 .schedule_completion(now + Duration::from_millis(100));
@@ -56,7 +95,7 @@ pub(super) fn reduce_editing(
             state
                 .sql_modal
                 .editor
-                .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
             state
                 .sql_modal
                 .schedule_completion(now + Duration::from_millis(100));
@@ -70,7 +109,7 @@ pub(super) fn reduce_editing(
             state
                 .sql_modal
                 .editor
-                .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
             state
                 .sql_modal
                 .schedule_completion(now + Duration::from_millis(100));
@@ -86,7 +125,7 @@ pub(super) fn reduce_editing(
             state
                 .sql_modal
                 .editor
-                .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
             state
                 .sql_modal
                 .schedule_completion(now + Duration::from_millis(100));
@@ -101,7 +140,7 @@ pub(super) fn reduce_editing(
                 state
                     .sql_modal
                     .editor
-                    .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                    .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
                 state
                     .sql_modal
                     .schedule_completion(now + Duration::from_millis(100));
@@ -111,10 +150,13 @@ pub(super) fn reduce_editing(
         Action::SqlModalNewLine => {
             state.sql_modal.enter_editing();
             state.sql_modal.editor.insert_newline();
+            if let Some(case) = state.settings.keyword_case() {
+                state.sql_modal.apply_keyword_case(case);
+            }
             state
                 .sql_modal
                 .editor
-                .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
             state
                 .sql_modal
                 .schedule_completion(now + Duration::from_millis(100));
@@ -122,11 +164,13 @@ pub(super) fn reduce_editing(
         }
         Action::SqlModalTab => {
             state.sql_modal.enter_editing();
-            state.sql_modal.editor.insert_tab();
+            if !state.sql_modal.jump_to_next_tab_stop() {
+                state.sql_modal.editor.insert_tab();
+            }
             state
                 .sql_modal
                 .editor
-                .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
             state
                 .sql_modal
                 .schedule_completion(now + Duration::from_millis(100));
@@ -142,7 +186,7 @@ pub(super) fn reduce_editing(
                 | CursorMove::ViewportBottom => {
                     state.sql_modal.editor.move_cursor_to_viewport_position(
                         *movement,
-                        sql_modal_visible_rows(state.ui.terminal_height()),
+                        state.sql_modal.visible_rows(state.ui.terminal_height()),
                     );
                 }
                 _ => state.sql_modal.editor.move_cursor(*movement),
@@ -150,10 +194,19 @@ pub(super) fn reduce_editing(
             state
                 .sql_modal
                 .editor
-                .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
             state.ui.set_key_sequence(KeySequenceState::Idle);
             DispatchResult::handled()
         }
+        Action::SqlModalRenameAlias => {
+            if state.sql_modal.apply_alias_rename() > 0 {
+                state
+                    .sql_modal
+                    .editor
+                    .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
+            }
+            DispatchResult::handled()
+        }
         Action::SqlModalClear => {
             state.sql_modal.editor.clear();
             state.sql_modal.reset_completion();
@@ -16,11 +16,20 @@ pub(super) fn start_adhoc_if_connected(
             .finish_adhoc_error("No active connection".to_string());
         return DispatchResult::handled();
     };
+    let query = match state.apply_execution_policy(&query) {
+        Ok(query) => query,
+        Err(violation) => {
+            state.sql_modal.finish_adhoc_error(violation.to_string());
+            return DispatchResult::handled();
+        }
+    };
 
+    let replica_dsn = state.route_adhoc_query(&query);
     let run_id = state.query.begin_running(now);
     state.sql_modal.begin_adhoc_running();
     DispatchResult::handled_with(vec![Effect::ExecuteAdhoc {
         dsn,
+        replica_dsn,
         run_id,
         query,
         access_mode: AccessMode::from_read_only(state.session.is_read_only()),
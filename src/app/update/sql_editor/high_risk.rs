@@ -2,11 +2,12 @@ use std::time::Instant;
 
 use crate::model::app_state::AppState;
 use crate::model::shared::key_sequence::KeySequenceState;
-use crate::model::shared::text_input::{TextInputEditing, TextInputLike, TextInputState};
+use crate::model::shared::text_input::{TextInputEditing, TextInputState};
 use crate::model::sql_editor::modal::{
     HIGH_RISK_INPUT_VISIBLE_WIDTH, SqlModalContext, SqlModalStatus,
 };
 use crate::update::action::{Action, InputTarget};
+use crate::update::audit::production_audit_effect;
 use crate::update::dispatch_result::DispatchResult;
 
 use super::helpers::start_adhoc_if_connected;
@@ -120,8 +121,22 @@ pub(super) fn reduce_high_risk_confirmation(
                 SqlModalStatus::ConfirmingRisk { .. }
             );
             if matched {
-                let query = state.sql_modal.editor.content().trim().to_string();
-                return start_adhoc_if_connected(state, query, now);
+                let audit = match state.sql_modal.status() {
+                    SqlModalStatus::ConfirmingHigh { decision, .. } => Some(*decision),
+                    _ => None,
+                };
+                let query = state.sql_modal.submitted_query();
+                let audit = audit.and_then(|decision| {
+                    production_audit_effect(state, decision.label, decision.risk_level, &query)
+                });
+                let mut effects = start_adhoc_if_connected(state, query, now)
+                    .into_effects()
+                    .unwrap_or_default();
+                // No effects means there was no connection and nothing ran.
+                if !effects.is_empty() {
+                    effects.extend(audit);
+                }
+                return DispatchResult::handled_with(effects);
             }
             DispatchResult::handled()
         }
@@ -0,0 +1,174 @@
+use std::time::Instant;
+
+use crate::model::app_state::AppState;
+use crate::model::sql_editor::edit_history::SqlEditKind;
+use crate::update::action::{Action, InputTarget};
+use crate::update::dispatch_result::DispatchResult;
+
+/// How a change `action` makes to the SQL buffer groups with the edits before it.
+pub fn sql_edit_kind(action: &Action) -> SqlEditKind {
+    match action {
+        Action::TextInput {
+            target: InputTarget::SqlModal,
+            ch,
+        } => SqlEditKind::for_char(*ch),
+        Action::SqlModalNewLine | Action::SqlModalTab => SqlEditKind::InsertSeparator,
+        Action::TextBackspace {
+            target: InputTarget::SqlModal,
+        }
+        | Action::TextDelete {
+            target: InputTarget::SqlModal,
+        } => SqlEditKind::Erase,
+        _ => SqlEditKind::Replace,
+    }
+}
+
+pub(super) fn reduce_history(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+) -> DispatchResult {
+    let restored = match action {
+        Action::SqlModalUndoEdit => state.sql_modal.undo_edit(),
+        Action::SqlModalRedoEdit => state.sql_modal.redo_edit(),
+        _ => return DispatchResult::pass(),
+    };
+    if restored {
+        state
+            .sql_modal
+            .editor
+            .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
+    } else {
+        let message = if matches!(action, Action::SqlModalUndoEdit) {
+            "Already at oldest change"
+        } else {
+            "Already at newest change"
+        };
+        state.messages.set_error_at(message.to_string(), now);
+    }
+    DispatchResult::handled()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::shared::input_mode::InputMode;
+    use crate::model::shared::text_input::TextInputLike;
+    use crate::model::sql_editor::modal::SqlModalStatus;
+    use crate::model::sql_editor::vim::SqlVimOperator;
+    use crate::services::AppServices;
+    use crate::update::action::CursorMove;
+    use crate::update::reducer::reduce;
+
+    fn editing_state() -> AppState {
+        let mut state = AppState::new("test".to_string());
+        state.modal.set_mode(InputMode::SqlModal);
+        state.sql_modal.set_status_for_test(SqlModalStatus::Editing);
+        state
+    }
+
+    fn type_text(state: &mut AppState, text: &str, services: &AppServices) {
+        for ch in text.chars() {
+            let action = Action::TextInput {
+                target: InputTarget::SqlModal,
+                ch,
+            };
+            reduce(state, action, Instant::now(), services);
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_step_through_typed_words() {
+        let services = AppServices::stub();
+        let mut state = editing_state();
+        type_text(&mut state, "SELECT 1", &services);
+
+        reduce(
+            &mut state,
+            Action::SqlModalUndoEdit,
+            Instant::now(),
+            &services,
+        );
+        assert_eq!(state.sql_modal.editor().content(), "SELECT");
+        assert_eq!(state.sql_modal.editor().cursor(), 6);
+
+        reduce(
+            &mut state,
+            Action::SqlModalUndoEdit,
+            Instant::now(),
+            &services,
+        );
+        assert_eq!(state.sql_modal.editor().content(), "");
+
+        reduce(
+            &mut state,
+            Action::SqlModalRedoEdit,
+            Instant::now(),
+            &services,
+        );
+        reduce(
+            &mut state,
+            Action::SqlModalRedoEdit,
+            Instant::now(),
+            &services,
+        );
+        assert_eq!(state.sql_modal.editor().content(), "SELECT 1");
+    }
+
+    #[test]
+    fn vim_delete_is_one_undo_step() {
+        let services = AppServices::stub();
+        let mut state = editing_state();
+        type_text(&mut state, "SELECT id", &services);
+        reduce(
+            &mut state,
+            Action::SqlModalEnterNormal,
+            Instant::now(),
+            &services,
+        );
+        state.sql_modal.editor_mut_for_input().set_cursor(0);
+
+        reduce(
+            &mut state,
+            Action::SqlModalVimOperator(SqlVimOperator::Delete),
+            Instant::now(),
+            &services,
+        );
+        reduce(
+            &mut state,
+            Action::TextMoveCursor {
+                target: InputTarget::SqlModal,
+                direction: CursorMove::WordForward,
+            },
+            Instant::now(),
+            &services,
+        );
+        assert_eq!(state.sql_modal.editor().content(), "id");
+
+        reduce(
+            &mut state,
+            Action::SqlModalUndoEdit,
+            Instant::now(),
+            &services,
+        );
+
+        assert_eq!(state.sql_modal.editor().content(), "SELECT id");
+    }
+
+    #[test]
+    fn undo_past_the_oldest_change_reports_it() {
+        let mut state = editing_state();
+
+        reduce(
+            &mut state,
+            Action::SqlModalUndoEdit,
+            Instant::now(),
+            &AppServices::stub(),
+        );
+
+        assert_eq!(
+            state.messages.last_error(),
+            Some("Already at oldest change")
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+use crate::cmd::effect::Effect;
+use crate::model::app_state::AppState;
+use crate::model::shared::text_input::TextInputLike;
+use crate::model::sql_editor::linked_file::{DiskChange, LinkedSqlFile};
+use crate::update::action::{Action, ModalKind};
+use crate::update::dispatch_result::DispatchResult;
+
+pub(super) fn reduce_linked_file(
+    state: &mut AppState,
+    action: &Action,
+    now: Instant,
+) -> DispatchResult {
+    match action {
+        Action::LinkSqlFile(path) => {
+            let path = resolve_path(state, path);
+            DispatchResult::handled_with(vec![Effect::OpenLinkedSqlFile { path }])
+        }
+        Action::ReloadLinkedSqlFile => {
+            let Some(file) = state.linked_sql_file.file() else {
+                state
+                    .messages
+                    .set_error_at("No linked file — use :edit <file.sql>".to_string(), now);
+                return DispatchResult::handled();
+            };
+            DispatchResult::handled_with(vec![Effect::OpenLinkedSqlFile {
+                path: file.path().to_path_buf(),
+            }])
+        }
+        Action::WriteLinkedSqlFile => {
+            let Some(file) = state.linked_sql_file.file() else {
+                state
+                    .messages
+                    .set_error_at("No linked file — use :w <file.sql>".to_string(), now);
+                return DispatchResult::handled();
+            };
+            DispatchResult::handled_with(vec![Effect::WriteLinkedSqlFile {
+                path: file.path().to_path_buf(),
+                link_id: state.linked_sql_file.link_id(),
+                content: state.sql_modal.editor.content().to_string(),
+            }])
+        }
+        Action::WriteSqlFileAs(path) => {
+            if path.is_empty() {
+                state
+                    .messages
+                    .set_error_at("Usage: :w <file.sql>".to_string(), now);
+                return DispatchResult::handled();
+            }
+            DispatchResult::handled_with(vec![Effect::WriteSqlFileAs {
+                path: resolve_path(state, path),
+                content: state.sql_modal.editor.content().to_string(),
+            }])
+        }
+        // The buffer may have moved on while the write was in flight, so the
+        // link is synced to what reached disk and shows `[+]` for the rest.
+        Action::SqlFileWrittenAs { path, content } => {
+            let link_id = state.linked_sql_file.link(path.clone(), content.clone());
+            let name = linked_file_name(state);
+            state.messages.set_success_at(format!("Wrote {name}"), now);
+            DispatchResult::handled_with(vec![Effect::PollLinkedSqlFile {
+                path: path.clone(),
+                link_id,
+            }])
+        }
+        Action::SqlFileLinked { path, content } => {
+            let link_id = state.linked_sql_file.link(path.clone(), content.clone());
+            state.sql_modal.editor.set_content(content.clone());
+            let name = linked_file_name(state);
+            state.messages.set_success_at(format!("Linked {name}"), now);
+            DispatchResult::handled_with(vec![
+                Effect::DispatchActions(vec![Action::OpenModal(ModalKind::SqlModal)]),
+                Effect::PollLinkedSqlFile {
+                    path: path.clone(),
+                    link_id,
+                },
+            ])
+        }
+        Action::LinkedSqlFilePolled { link_id, content } => {
+            if !state.linked_sql_file.is_current(*link_id) {
+                return DispatchResult::handled();
+            }
+            if let Some(disk) = content {
+                let buffer = state.sql_modal.editor.content().to_string();
+                let change = state.linked_sql_file.apply_disk_content(disk, &buffer);
+                let name = linked_file_name(state);
+                match change {
+                    DiskChange::Unchanged => {}
+                    DiskChange::Reload => {
+                        let cursor = state.sql_modal.editor.cursor();
+                        state
+                            .sql_modal
+                            .editor
+                            .set_content_with_cursor(disk.clone(), cursor);
+                        state
+                            .messages
+                            .set_success_at(format!("Reloaded {name}"), now);
+                    }
+                    DiskChange::Conflict => {
+                        state.messages.set_error_at(
+                            format!("{name} changed on disk — :w overwrites, :e! reloads"),
+                            now,
+                        );
+                    }
+                }
+            }
+            let path = state
+                .linked_sql_file
+                .file()
+                .map(|file| file.path().to_path_buf())
+                .unwrap_or_default();
+            DispatchResult::handled_with(vec![Effect::PollLinkedSqlFile {
+                path,
+                link_id: *link_id,
+            }])
+        }
+        Action::LinkedSqlFileWritten { link_id, content } => {
+            if !state.linked_sql_file.is_current(*link_id) {
+                return DispatchResult::handled();
+            }
+            state.linked_sql_file.mark_written(content.clone());
+            let name = linked_file_name(state);
+            state.messages.set_success_at(format!("Wrote {name}"), now);
+            DispatchResult::handled()
+        }
+        Action::LinkedSqlFileFailed(error) => {
+            state.messages.set_error_at(error.to_string(), now);
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+/// Relative paths are taken from the project root, like the `:files` browser.
+fn resolve_path(state: &AppState, path: &str) -> PathBuf {
+    let path = PathBuf::from(path);
+    match state.runtime.project_root() {
+        Some(root) if path.is_relative() => root.join(path),
+        _ => path,
+    }
+}
+
+fn linked_file_name(state: &AppState) -> String {
+    state
+        .linked_sql_file
+        .file()
+        .map(LinkedSqlFile::file_name)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::shared::input_mode::InputMode;
+
+    fn dispatch(state: &mut AppState, action: &Action) -> Vec<Effect> {
+        reduce_linked_file(state, action, Instant::now())
+            .into_effects()
+            .unwrap()
+    }
+
+    fn linked_state(content: &str) -> (AppState, u64) {
+        let mut state = AppState::new("test".to_string());
+        state.modal.set_mode(InputMode::SqlModal);
+        dispatch(
+            &mut state,
+            &Action::SqlFileLinked {
+                path: PathBuf::from("/work/app/report.sql"),
+                content: content.to_string(),
+            },
+        );
+        let link_id = state.linked_sql_file.link_id();
+        (state, link_id)
+    }
+
+    #[test]
+    fn relative_path_resolves_against_project_root() {
+        let mut state = AppState::new("test".to_string());
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+
+        let effects = dispatch(&mut state, &Action::LinkSqlFile("q/report.sql".to_string()));
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::OpenLinkedSqlFile { path }] if path == &PathBuf::from("/work/app/q/report.sql")
+        ));
+    }
+
+    #[test]
+    fn linking_loads_buffer_and_starts_polling() {
+        let mut state = AppState::new("test".to_string());
+
+        let effects = dispatch(
+            &mut state,
+            &Action::SqlFileLinked {
+                path: PathBuf::from("/work/app/report.sql"),
+                content: "SELECT 1;".to_string(),
+            },
+        );
+
+        assert_eq!(state.sql_modal.editor.content(), "SELECT 1;");
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchActions(_), Effect::PollLinkedSqlFile { .. }]
+        ));
+    }
+
+    #[test]
+    fn external_save_reloads_clean_buffer() {
+        let (mut state, link_id) = linked_state("SELECT 1;");
+
+        let effects = dispatch(
+            &mut state,
+            &Action::LinkedSqlFilePolled {
+                link_id,
+                content: Some("SELECT 2;".to_string()),
+            },
+        );
+
+        assert_eq!(state.sql_modal.editor.content(), "SELECT 2;");
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::PollLinkedSqlFile { .. }]
+        ));
+    }
+
+    #[test]
+    fn external_save_over_local_edits_keeps_buffer_and_warns() {
+        let (mut state, link_id) = linked_state("SELECT 1;");
+        state.sql_modal.editor.set_content("SELECT 42;".to_string());
+
+        dispatch(
+            &mut state,
+            &Action::LinkedSqlFilePolled {
+                link_id,
+                content: Some("SELECT 2;".to_string()),
+            },
+        );
+
+        assert_eq!(state.sql_modal.editor.content(), "SELECT 42;");
+        assert!(state.linked_sql_file.file().unwrap().has_conflict());
+        assert!(state.messages.last_error().is_some());
+    }
+
+    #[test]
+    fn poll_for_previous_link_stops() {
+        let (mut state, link_id) = linked_state("SELECT 1;");
+        dispatch(
+            &mut state,
+            &Action::SqlFileLinked {
+                path: PathBuf::from("/work/app/other.sql"),
+                content: String::new(),
+            },
+        );
+
+        let effects = dispatch(
+            &mut state,
+            &Action::LinkedSqlFilePolled {
+                link_id,
+                content: Some("SELECT 2;".to_string()),
+            },
+        );
+
+        assert!(effects.is_empty());
+        assert_eq!(state.sql_modal.editor.content(), "");
+    }
+
+    #[test]
+    fn write_sends_buffer_to_linked_path() {
+        let (mut state, link_id) = linked_state("SELECT 1;");
+        state.sql_modal.editor.set_content("SELECT 3;".to_string());
+
+        let effects = dispatch(&mut state, &Action::WriteLinkedSqlFile);
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::WriteLinkedSqlFile { path, link_id: id, content }]
+                if path == &PathBuf::from("/work/app/report.sql")
+                    && *id == link_id
+                    && content == "SELECT 3;"
+        ));
+    }
+
+    #[test]
+    fn write_without_link_reports_error() {
+        let mut state = AppState::new("test".to_string());
+
+        let effects = dispatch(&mut state, &Action::WriteLinkedSqlFile);
+
+        assert!(effects.is_empty());
+        assert!(state.messages.last_error().is_some());
+    }
+
+    #[test]
+    fn write_as_sends_buffer_to_resolved_path() {
+        let mut state = AppState::new("test".to_string());
+        state.runtime.set_project_root(PathBuf::from("/work/app"));
+        state.sql_modal.editor.set_content("SELECT 4;".to_string());
+
+        let effects = dispatch(&mut state, &Action::WriteSqlFileAs("q/new.sql".to_string()));
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::WriteSqlFileAs { path, content }]
+                if path == &PathBuf::from("/work/app/q/new.sql") && content == "SELECT 4;"
+        ));
+    }
+
+    #[test]
+    fn written_as_links_the_buffer_to_the_new_file() {
+        let (mut state, previous) = linked_state("SELECT 1;");
+        state.sql_modal.editor.set_content("SELECT 5;".to_string());
+
+        let effects = dispatch(
+            &mut state,
+            &Action::SqlFileWrittenAs {
+                path: PathBuf::from("/work/app/copy.sql"),
+                content: "SELECT 5;".to_string(),
+            },
+        );
+
+        let file = state.linked_sql_file.file().unwrap();
+        assert_eq!(file.path(), PathBuf::from("/work/app/copy.sql"));
+        assert!(!file.is_dirty("SELECT 5;"));
+        assert!(!state.linked_sql_file.is_current(previous));
+        assert_eq!(state.sql_modal.editor.content(), "SELECT 5;");
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::PollLinkedSqlFile { .. }]
+        ));
+    }
+}
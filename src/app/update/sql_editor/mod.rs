@@ -2,8 +2,11 @@ mod completion;
 mod editing;
 mod helpers;
 mod high_risk;
+mod history;
+mod linked_file;
 mod mode;
 mod submit;
+mod vim;
 mod yank;
 
 use std::time::Instant;
@@ -12,13 +15,18 @@ use crate::model::app_state::AppState;
 use crate::update::action::Action;
 use crate::update::dispatch_result::DispatchResult;
 
+pub use history::sql_edit_kind;
+
 pub fn dispatch_sql_modal(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
-    completion::reduce_completion(state, action, now)
+    vim::reduce_vim(state, action, now)
+        .or_else(|| history::reduce_history(state, action, now))
+        .or_else(|| completion::reduce_completion(state, action, now))
         .or_else(|| editing::reduce_editing(state, action, now))
         .or_else(|| mode::reduce_mode(state, action, now))
         .or_else(|| submit::reduce_submit(state, action, now))
         .or_else(|| high_risk::reduce_high_risk_confirmation(state, action, now))
         .or_else(|| yank::reduce_yank(state, action, now))
+        .or_else(|| linked_file::reduce_linked_file(state, action, now))
 }
 
 #[cfg(test)]
@@ -28,11 +36,15 @@ mod tests {
     use crate::model::shared::flash_timer::FlashId;
     use crate::model::shared::input_mode::InputMode;
     use crate::model::shared::text_input::{TextInputLike, TextInputState};
-    use crate::model::sql_editor::modal::{AdhocSuccessSnapshot, SqlModalStatus, SqlModalTab};
+    use crate::model::sql_editor::modal::{
+        AdhocSuccessSnapshot, SqlModalSize, SqlModalStatus, SqlModalTab,
+    };
     use crate::policy::write::sql_risk::AcknowledgeReason;
     use crate::policy::write::write_guardrails::{AdhocRiskDecision, RiskLevel};
+    use crate::ports::outbound::{ExecutionPolicies, ExecutionPolicy};
     use crate::update::action::{CursorMove, InputTarget, ModalKind};
     use crate::update::test_fixtures;
+    use std::collections::HashMap;
     use std::time::Instant;
 
     fn reduce_sql_modal(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
@@ -221,6 +233,24 @@ mod tests {
             assert_eq!(state.sql_modal.editor.cursor_to_position(), (8, 0));
             assert_eq!(state.sql_modal.editor.scroll_row(), 1);
         }
+
+        #[test]
+        fn shrinking_the_modal_keeps_the_cursor_visible() {
+            let mut state = sql_modal_state();
+            state.ui.set_terminal_height(20);
+            state.sql_modal.set_status_for_test(SqlModalStatus::Normal);
+            state
+                .sql_modal
+                .editor
+                .set_content_with_cursor("0\n1\n2\n3\n4\n5\n6\n7\n8".to_string(), 16);
+
+            reduce_sql_modal(&mut state, &Action::SqlModalCycleSize, Instant::now());
+            reduce_sql_modal(&mut state, &Action::SqlModalCycleSize, Instant::now());
+
+            assert_eq!(state.sql_modal.size(), SqlModalSize::Compact);
+            assert_eq!(state.sql_modal.editor.scroll_row(), 6);
+            assert_eq!(state.messages.last_success(), Some("Editor size: compact"));
+        }
     }
 
     mod confirming_high {
@@ -456,6 +486,119 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn production_submit_requires_table_name_for_filtered_delete() {
+            let mut state = sql_modal_state();
+            test_fixtures::activate_production_connection(&mut state, "postgres://prod");
+            state
+                .sql_modal
+                .editor
+                .set_content("DELETE FROM users WHERE id = 1".to_string());
+
+            reduce_sql_modal(&mut state, &Action::SqlModalSubmit, Instant::now());
+
+            assert!(matches!(
+                state.sql_modal.status(),
+                SqlModalStatus::ConfirmingHigh {
+                    decision: AdhocRiskDecision {
+                        risk_level: RiskLevel::High,
+                        label: "DELETE",
+                    },
+                    target_name,
+                    ..
+                } if target_name == "users"
+            ));
+        }
+
+        #[test]
+        fn production_confirm_appends_audit_entry() {
+            let mut state = confirming_high_state("DROP TABLE users", "users");
+            test_fixtures::activate_production_connection(&mut state, "postgres://prod");
+            for c in "users".chars() {
+                reduce_sql_modal(
+                    &mut state,
+                    &Action::TextInput {
+                        target: InputTarget::SqlModalHighRisk,
+                        ch: c,
+                    },
+                    Instant::now(),
+                );
+            }
+
+            let effects =
+                reduce_sql_modal(&mut state, &Action::SqlModalConfirmExecute, Instant::now())
+                    .into_effects()
+                    .unwrap();
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::ExecuteAdhoc { .. }, Effect::AppendAuditLog { event, .. }]
+                    if event.connection == "prod"
+                        && event.operation == "DROP"
+                        && event.risk == "HIGH"
+                        && event.statement == "DROP TABLE users"
+            ));
+        }
+
+        fn with_policy(state: &mut AppState, policy: ExecutionPolicy) {
+            test_fixtures::activate_production_connection(state, "postgres://prod");
+            state
+                .runtime
+                .set_execution_policies(ExecutionPolicies::new(HashMap::from([(
+                    "prod".to_string(),
+                    policy,
+                )])));
+        }
+
+        #[test]
+        fn profile_policy_blocks_writes_before_confirmation() {
+            let mut state = sql_modal_state();
+            with_policy(
+                &mut state,
+                ExecutionPolicy {
+                    select_only: true,
+                    ..ExecutionPolicy::default()
+                },
+            );
+            state
+                .sql_modal
+                .editor
+                .set_content("DELETE FROM users WHERE id = 1".to_string());
+
+            reduce_sql_modal(&mut state, &Action::SqlModalSubmit, Instant::now());
+
+            assert!(matches!(state.sql_modal.status(), SqlModalStatus::Error));
+            assert_eq!(
+                state.sql_modal.last_adhoc_error(),
+                Some("Profile policy allows SELECT only: DELETE is not permitted")
+            );
+        }
+
+        #[test]
+        fn profile_policy_appends_auto_limit_to_select() {
+            let mut state = sql_modal_state();
+            with_policy(
+                &mut state,
+                ExecutionPolicy {
+                    auto_limit: Some(100),
+                    ..ExecutionPolicy::default()
+                },
+            );
+            state
+                .sql_modal
+                .editor
+                .set_content("SELECT * FROM users".to_string());
+
+            let effects = reduce_sql_modal(&mut state, &Action::SqlModalSubmit, Instant::now())
+                .into_effects()
+                .unwrap();
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::ExecuteAdhoc { query, .. }] if query == "SELECT * FROM users\nLIMIT 100"
+            ));
+        }
+
         #[test]
         fn high_risk_confirm_without_dsn_sets_error() {
             let mut state = confirming_high_state("DROP TABLE users", "users");
@@ -822,6 +965,64 @@ mod tests {
         }
     }
 
+    mod pool_mode_guard {
+        use super::*;
+        use crate::domain::DatabaseType;
+        use crate::domain::connection::{ConnectionProfile, PoolMode, SslMode};
+
+        fn pooled_state(pool_mode: PoolMode, sql: &str) -> AppState {
+            let mut state = sql_modal_state();
+            state.sql_modal.editor.set_content(sql.to_string());
+            let profile = ConnectionProfile::new_postgres(
+                "app",
+                "pgbouncer",
+                6432,
+                "app",
+                "user",
+                "",
+                SslMode::Prefer,
+            )
+            .unwrap()
+            .with_pool_mode(Some(pool_mode));
+            state.session.activate_connection_with_dsn(
+                &profile.id,
+                "app",
+                DatabaseType::PostgreSQL,
+                "postgres://pgbouncer:6432/app",
+            );
+            state.set_connections(vec![profile]);
+            state
+        }
+
+        #[test]
+        fn transaction_pooling_blocks_listen() {
+            let mut state = pooled_state(PoolMode::Transaction, "LISTEN orders");
+
+            let effects = reduce_sql_modal(&mut state, &Action::SqlModalSubmit, Instant::now())
+                .into_effects()
+                .expect("reducer should handle action");
+
+            assert!(effects.is_empty());
+            assert_eq!(*state.sql_modal.status(), SqlModalStatus::Error);
+            assert!(
+                state
+                    .sql_modal
+                    .last_adhoc_error()
+                    .is_some_and(|error| error.contains("transaction pooling"))
+            );
+        }
+
+        #[test]
+        fn session_pooling_allows_listen() {
+            let mut state = pooled_state(PoolMode::Session, "LISTEN orders");
+
+            reduce_sql_modal(&mut state, &Action::SqlModalSubmit, Instant::now());
+
+            assert_ne!(*state.sql_modal.status(), SqlModalStatus::Error);
+            assert_eq!(state.sql_modal.last_adhoc_error(), None);
+        }
+    }
+
     mod confirmation_flow {
         use super::*;
 
@@ -881,6 +1082,8 @@ mod tests {
 
     mod normal_insert_mode {
         use super::*;
+        use crate::domain::DatabaseMetadata;
+        use crate::model::shared::settings::PrefetchScope;
 
         #[test]
         fn append_insert_moves_to_line_end_and_transitions_to_editing() {
@@ -1022,6 +1225,31 @@ mod tests {
             assert_eq!(state.sql_modal.active_tab(), SqlModalTab::Sql);
         }
 
+        #[test]
+        fn open_sql_modal_in_schema_scope_prefetches_referenced_schemas() {
+            let mut state = AppState::new("test".to_string());
+            state
+                .session
+                .set_metadata(Some(std::sync::Arc::new(DatabaseMetadata::new(
+                    "test".to_string(),
+                ))));
+            state.settings.load_prefetch_scope(PrefetchScope::Schema);
+
+            let effects = reduce_sql_modal(
+                &mut state,
+                &Action::OpenModal(ModalKind::SqlModal),
+                Instant::now(),
+            )
+            .into_effects()
+            .unwrap();
+
+            assert!(matches!(
+                effects.as_slice(),
+                [Effect::DispatchActions(actions)]
+                    if matches!(actions.as_slice(), [Action::PrefetchReferencedSchemas])
+            ));
+        }
+
         #[test]
         fn ignored_in_normal_mode() {
             let mut state = sql_modal_state();
@@ -1274,4 +1502,389 @@ mod tests {
             }
         }
     }
+
+    mod keyword_case {
+        use super::*;
+        use crate::model::shared::settings::KeywordCase;
+
+        fn type_text(state: &mut AppState, text: &str) {
+            for ch in text.chars() {
+                reduce_sql_modal(
+                    state,
+                    &Action::TextInput {
+                        target: InputTarget::SqlModal,
+                        ch,
+                    },
+                    Instant::now(),
+                );
+            }
+        }
+
+        #[test]
+        fn recases_keywords_at_word_boundary() {
+            let mut state = sql_modal_state();
+            state.settings.load_keyword_case(Some(KeywordCase::Upper));
+
+            type_text(&mut state, "select id from users where");
+
+            assert_eq!(
+                state.sql_modal.editor.content(),
+                "SELECT id FROM users where"
+            );
+            assert_eq!(state.sql_modal.editor.cursor(), 26);
+        }
+
+        #[test]
+        fn leaves_strings_and_identifiers_alone() {
+            let mut state = sql_modal_state();
+            state.settings.load_keyword_case(Some(KeywordCase::Lower));
+
+            type_text(&mut state, "SELECT 'FROM ' AS \"ORDER\" ");
+
+            assert_eq!(
+                state.sql_modal.editor.content(),
+                "select 'FROM ' as \"ORDER\" "
+            );
+        }
+
+        #[test]
+        fn disabled_by_default() {
+            let mut state = sql_modal_state();
+
+            type_text(&mut state, "select 1");
+
+            assert_eq!(state.sql_modal.editor.content(), "select 1");
+        }
+    }
+
+    mod alias_rename {
+        use super::*;
+
+        const QUERY: &str = "SELECT u.id, u.name FROM users u WHERE u.id = 1";
+
+        fn retype_alias(state: &mut AppState, alias: &str) {
+            let alias_end = QUERY.rfind(" WHERE").unwrap();
+            state
+                .sql_modal
+                .editor
+                .set_content_with_cursor(QUERY.to_string(), alias_end);
+            state.sql_modal.set_status_for_test(SqlModalStatus::Editing);
+            reduce_sql_modal(
+                state,
+                &Action::TextBackspace {
+                    target: InputTarget::SqlModal,
+                },
+                Instant::now(),
+            );
+            for ch in alias.chars() {
+                reduce_sql_modal(
+                    state,
+                    &Action::TextInput {
+                        target: InputTarget::SqlModal,
+                        ch,
+                    },
+                    Instant::now(),
+                );
+            }
+            state.sql_modal.refresh_editor_tokens();
+        }
+
+        #[test]
+        fn retyped_alias_offers_rename() {
+            let mut state = sql_modal_state();
+
+            retype_alias(&mut state, "usr");
+
+            let offer = state.sql_modal.alias_rename_offer().unwrap();
+            assert_eq!(offer.from, "u");
+            assert_eq!(offer.to, "usr");
+            assert_eq!(offer.references, 3);
+        }
+
+        #[test]
+        fn accepting_renames_qualified_references() {
+            let mut state = sql_modal_state();
+            retype_alias(&mut state, "usr");
+            let cursor = state.sql_modal.editor.cursor();
+
+            reduce_sql_modal(&mut state, &Action::SqlModalRenameAlias, Instant::now());
+
+            assert_eq!(
+                state.sql_modal.editor.content(),
+                "SELECT usr.id, usr.name FROM users usr WHERE usr.id = 1"
+            );
+            assert_eq!(state.sql_modal.editor.cursor(), cursor + 4);
+            state.sql_modal.refresh_editor_tokens();
+            assert_eq!(state.sql_modal.alias_rename_offer(), None);
+        }
+
+        #[test]
+        fn restoring_the_alias_withdraws_the_offer() {
+            let mut state = sql_modal_state();
+
+            retype_alias(&mut state, "u");
+
+            assert_eq!(state.sql_modal.alias_rename_offer(), None);
+        }
+
+        #[test]
+        fn rename_without_offer_leaves_buffer_unchanged() {
+            let mut state = sql_modal_state();
+            state
+                .sql_modal
+                .editor
+                .set_content_with_cursor(QUERY.to_string(), 0);
+
+            reduce_sql_modal(&mut state, &Action::SqlModalRenameAlias, Instant::now());
+
+            assert_eq!(state.sql_modal.editor.content(), QUERY);
+        }
+    }
+
+    mod vim {
+        use super::*;
+        use crate::model::sql_editor::vim::SqlVimOperator;
+        use crate::services::AppServices;
+        use crate::update::reducer::reduce;
+        use rstest::rstest;
+
+        fn normal_state(content: &str, cursor: usize) -> AppState {
+            let mut state = sql_modal_state();
+            state
+                .sql_modal
+                .editor
+                .set_content_with_cursor(content.to_string(), cursor);
+            state
+        }
+
+        fn run(state: &mut AppState, actions: &[Action]) {
+            for action in actions {
+                reduce_sql_modal(state, action, Instant::now());
+            }
+        }
+
+        fn operator(operator: SqlVimOperator) -> Action {
+            Action::SqlModalVimOperator(operator)
+        }
+
+        fn motion(direction: CursorMove) -> Action {
+            Action::TextMoveCursor {
+                target: InputTarget::SqlModal,
+                direction,
+            }
+        }
+
+        #[rstest]
+        #[case("SELECT 1\nFROM t\nWHERE x", 10, "SELECT 1\nWHERE x", 9)]
+        #[case("SELECT 1\nFROM t", 12, "SELECT 1", 0)]
+        #[case("SELECT 1", 3, "", 0)]
+        fn dd_deletes_the_current_line(
+            #[case] content: &str,
+            #[case] cursor: usize,
+            #[case] expected: &str,
+            #[case] expected_cursor: usize,
+        ) {
+            let mut state = normal_state(content, cursor);
+
+            run(
+                &mut state,
+                &[
+                    operator(SqlVimOperator::Delete),
+                    operator(SqlVimOperator::Delete),
+                ],
+            );
+
+            assert_eq!(state.sql_modal.editor.content(), expected);
+            assert_eq!(state.sql_modal.editor.cursor(), expected_cursor);
+            assert!(!state.sql_modal.vim().is_busy());
+        }
+
+        #[rstest]
+        #[case(CursorMove::WordForward, 0, "WHERE")]
+        #[case(CursorMove::WordEnd, 0, " WHERE")]
+        #[case(CursorMove::LineEnd, 0, "")]
+        #[case(CursorMove::WordForward, 6, "users ")]
+        #[case(CursorMove::WordBackward, 6, "WHERE")]
+        fn delete_with_motion(
+            #[case] direction: CursorMove,
+            #[case] cursor: usize,
+            #[case] expected: &str,
+        ) {
+            let mut state = normal_state("users WHERE", cursor);
+
+            run(
+                &mut state,
+                &[operator(SqlVimOperator::Delete), motion(direction)],
+            );
+
+            assert_eq!(state.sql_modal.editor.content(), expected);
+        }
+
+        #[test]
+        fn dw_on_last_word_keeps_the_newline() {
+            let mut state = normal_state("SELECT id\nFROM t", 7);
+
+            run(
+                &mut state,
+                &[
+                    operator(SqlVimOperator::Delete),
+                    motion(CursorMove::WordForward),
+                ],
+            );
+
+            assert_eq!(state.sql_modal.editor.content(), "SELECT \nFROM t");
+        }
+
+        #[test]
+        fn dj_deletes_both_lines() {
+            let mut state = normal_state("a\nb\nc", 0);
+
+            run(
+                &mut state,
+                &[operator(SqlVimOperator::Delete), motion(CursorMove::Down)],
+            );
+
+            assert_eq!(state.sql_modal.editor.content(), "c");
+            let register = state.sql_modal.vim().register().unwrap();
+            assert_eq!(register.text, "a\nb");
+            assert!(register.linewise);
+        }
+
+        #[test]
+        fn yy_then_p_duplicates_the_line_below() {
+            let mut state = normal_state("SELECT 1\nFROM t", 2);
+
+            run(
+                &mut state,
+                &[
+                    operator(SqlVimOperator::Yank),
+                    operator(SqlVimOperator::Yank),
+                    Action::SqlModalVimPaste { before: false },
+                ],
+            );
+
+            assert_eq!(
+                state.sql_modal.editor.content(),
+                "SELECT 1\nSELECT 1\nFROM t"
+            );
+            assert_eq!(state.sql_modal.editor.cursor_to_position(), (1, 0));
+        }
+
+        #[test]
+        fn linewise_paste_before_goes_above() {
+            let mut state = normal_state("a\nb", 2);
+
+            run(
+                &mut state,
+                &[
+                    operator(SqlVimOperator::Delete),
+                    operator(SqlVimOperator::Delete),
+                    Action::SqlModalVimPaste { before: true },
+                ],
+            );
+
+            assert_eq!(state.sql_modal.editor.content(), "b\na");
+        }
+
+        #[test]
+        fn x_then_p_swaps_chars() {
+            let mut state = normal_state("ab", 0);
+
+            run(
+                &mut state,
+                &[
+                    Action::SqlModalVimDeleteChar,
+                    Action::SqlModalVimPaste { before: false },
+                ],
+            );
+
+            assert_eq!(state.sql_modal.editor.content(), "ba");
+            assert_eq!(state.sql_modal.editor.cursor(), 1);
+        }
+
+        #[test]
+        fn x_at_line_end_does_nothing() {
+            let mut state = normal_state("ab\ncd", 2);
+
+            run(&mut state, &[Action::SqlModalVimDeleteChar]);
+
+            assert_eq!(state.sql_modal.editor.content(), "ab\ncd");
+        }
+
+        #[test]
+        fn yank_with_motion_fills_the_register_without_editing() {
+            let mut state = normal_state("SELECT id", 0);
+
+            run(
+                &mut state,
+                &[operator(SqlVimOperator::Yank), motion(CursorMove::WordEnd)],
+            );
+
+            assert_eq!(state.sql_modal.editor.content(), "SELECT id");
+            let register = state.sql_modal.vim().register().unwrap();
+            assert_eq!(register.text, "SELECT");
+            assert!(!register.linewise);
+        }
+
+        #[test]
+        fn visual_line_delete_removes_selected_rows() {
+            let mut state = normal_state("a\nb\nc\nd", 2);
+
+            run(
+                &mut state,
+                &[
+                    Action::SqlModalVimVisualLine,
+                    motion(CursorMove::Down),
+                    operator(SqlVimOperator::Delete),
+                ],
+            );
+
+            assert_eq!(state.sql_modal.editor.content(), "a\nd");
+            assert!(!state.sql_modal.vim().is_visual_line());
+        }
+
+        #[test]
+        fn visual_line_moves_the_cursor() {
+            let mut state = normal_state("a\nb", 0);
+
+            run(
+                &mut state,
+                &[Action::SqlModalVimVisualLine, motion(CursorMove::Down)],
+            );
+
+            assert_eq!(state.sql_modal.editor.cursor_to_position(), (1, 0));
+            assert_eq!(state.sql_modal.vim().visual_rows(1, 2), Some((0, 1)));
+        }
+
+        #[test]
+        fn entering_insert_drops_pending_operator() {
+            let mut state = normal_state("a", 0);
+
+            run(
+                &mut state,
+                &[
+                    operator(SqlVimOperator::Delete),
+                    Action::SqlModalEnterInsert,
+                ],
+            );
+
+            assert!(!state.sql_modal.vim().is_busy());
+        }
+
+        #[test]
+        fn delete_can_be_undone() {
+            let mut state = normal_state("SELECT 1\nFROM t", 0);
+            let services = AppServices::stub();
+
+            for action in [
+                operator(SqlVimOperator::Delete),
+                operator(SqlVimOperator::Delete),
+                Action::UndoUiMutation,
+            ] {
+                reduce(&mut state, action, Instant::now(), &services);
+            }
+
+            assert_eq!(state.sql_modal.editor.content(), "SELECT 1\nFROM t");
+        }
+    }
 }
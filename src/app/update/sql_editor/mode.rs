@@ -4,11 +4,11 @@ use crate::cmd::effect::Effect;
 use crate::model::app_state::AppState;
 use crate::model::shared::flash_timer::FlashId;
 use crate::model::shared::input_mode::InputMode;
-use crate::model::sql_editor::modal::sql_modal_visible_rows;
 use crate::update::action::{Action, CursorMove, ModalKind};
 use crate::update::dispatch_result::DispatchResult;
+use crate::update::helpers::sql_modal_prefetch_action;
 
-pub(super) fn reduce_mode(state: &mut AppState, action: &Action, _now: Instant) -> DispatchResult {
+pub(super) fn reduce_mode(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
     match action {
         // Modal open/submit
         Action::OpenModal(ModalKind::SqlModal) => {
@@ -17,7 +17,7 @@ pub(super) fn reduce_mode(state: &mut AppState, action: &Action, _now: Instant)
             state.flash_timers.clear(FlashId::SqlModal);
             if !state.sql_modal.is_prefetch_started() && state.session.metadata().is_some() {
                 DispatchResult::handled_with(vec![Effect::DispatchActions(vec![
-                    Action::StartPrefetchAll,
+                    sql_modal_prefetch_action(state),
                 ])])
             } else {
                 DispatchResult::handled()
@@ -28,7 +28,7 @@ pub(super) fn reduce_mode(state: &mut AppState, action: &Action, _now: Instant)
             state
                 .sql_modal
                 .editor
-                .update_scroll(sql_modal_visible_rows(state.ui.terminal_height()));
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
             state.sql_modal.enter_editing();
             DispatchResult::handled()
         }
@@ -40,6 +40,17 @@ pub(super) fn reduce_mode(state: &mut AppState, action: &Action, _now: Instant)
             state.sql_modal.enter_normal();
             DispatchResult::handled()
         }
+        Action::SqlModalCycleSize => {
+            let size = state.sql_modal.cycle_size();
+            state
+                .sql_modal
+                .editor
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
+            state
+                .messages
+                .set_success_at(format!("Editor size: {}", size.label()), now);
+            DispatchResult::handled()
+        }
         _ => DispatchResult::pass(),
     }
 }
@@ -1,9 +1,10 @@
 use std::time::Instant;
 
 use crate::model::app_state::AppState;
-use crate::model::shared::text_input::TextInputLike;
+use crate::policy::sql::session_pooling::session_state_conflict;
 use crate::policy::write::sql_risk::{
-    ConfirmationType, MultiStatementDecision, adhoc_label_for_table_name_confirmation,
+    ConfirmationType, MultiStatementDecision, adhoc_label_for_statement,
+    adhoc_label_for_table_name_confirmation, escalate_for_production,
     evaluate_multi_statement_for_database,
 };
 use crate::policy::write::write_guardrails::AdhocRiskDecision;
@@ -15,7 +16,7 @@ use super::helpers::start_adhoc_if_connected;
 pub(super) fn reduce_submit(state: &mut AppState, action: &Action, now: Instant) -> DispatchResult {
     match action {
         Action::SqlModalSubmit => {
-            let query = state.sql_modal.editor.content().trim().to_string();
+            let query = state.sql_modal.submitted_query();
             if query.is_empty() {
                 return DispatchResult::handled();
             }
@@ -28,13 +29,26 @@ pub(super) fn reduce_submit(state: &mut AppState, action: &Action, now: Instant)
                     state.sql_modal.finish_adhoc_error(reason);
                     DispatchResult::handled()
                 }
-                MultiStatementDecision::Allow { risk, .. } => {
+                MultiStatementDecision::Allow { mut risk, .. } => {
                     if state.session.is_read_only() && !risk.read_only_allowed {
                         state.sql_modal.finish_adhoc_error(
                             "Read-only mode: write operations are disabled".to_string(),
                         );
                         return DispatchResult::handled();
                     }
+                    if let Err(violation) = state.apply_execution_policy(&query) {
+                        state.sql_modal.finish_adhoc_error(violation.to_string());
+                        return DispatchResult::handled();
+                    }
+                    if let Some(mode) = state.active_pool_mode()
+                        && let Some(conflict) = session_state_conflict(&query, mode)
+                    {
+                        state.sql_modal.finish_adhoc_error(conflict.message(mode));
+                        return DispatchResult::handled();
+                    }
+                    if state.active_is_production() {
+                        risk = escalate_for_production(database_type, &query, risk);
+                    }
                     match risk.confirmation {
                         ConfirmationType::Immediate => start_adhoc_if_connected(state, query, now),
                         ConfirmationType::Acknowledge { reason, label } => {
@@ -42,11 +56,13 @@ pub(super) fn reduce_submit(state: &mut AppState, action: &Action, now: Instant)
                             DispatchResult::handled()
                         }
                         ConfirmationType::TableNameInput { target } => {
-                            let label = adhoc_label_for_table_name_confirmation(
-                                database_type,
-                                &query,
-                            )
-                            .expect("TableNameInput confirmation must have a matching statement");
+                            // Production escalation can ask for a name on statements
+                            // that would otherwise run without one.
+                            let label =
+                                adhoc_label_for_table_name_confirmation(database_type, &query)
+                                    .unwrap_or_else(|| {
+                                        adhoc_label_for_statement(database_type, &query)
+                                    });
                             let decision = AdhocRiskDecision {
                                 risk_level: risk.risk_level,
                                 label,
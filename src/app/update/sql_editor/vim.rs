@@ -0,0 +1,216 @@
+use std::time::Instant;
+
+use crate::model::app_state::AppState;
+use crate::model::shared::key_sequence::KeySequenceState;
+use crate::model::shared::text_input::TextInputLike;
+use crate::model::sql_editor::vim::SqlVimOperator;
+use crate::update::action::{Action, CursorMove, InputTarget};
+use crate::update::dispatch_result::DispatchResult;
+
+pub(super) fn reduce_vim(state: &mut AppState, action: &Action, _now: Instant) -> DispatchResult {
+    match action {
+        Action::SqlModalVimOperator(operator) => {
+            let (row, _) = state.sql_modal.editor.cursor_to_position();
+            if let Some((first, last)) = visual_rows(state) {
+                apply_linewise(state, *operator, first, last);
+            } else if state.sql_modal.vim().pending() == Some(*operator) {
+                // `dd` / `yy`
+                apply_linewise(state, *operator, row, row);
+            } else {
+                state.sql_modal.vim_mut().set_pending(*operator);
+                return DispatchResult::handled();
+            }
+            state.sql_modal.vim_mut().cancel();
+            scroll_to_cursor(state);
+            DispatchResult::handled()
+        }
+        Action::TextMoveCursor {
+            target: InputTarget::SqlModal,
+            direction,
+        } if state.sql_modal.vim().pending().is_some() => {
+            let Some(operator) = state.sql_modal.vim().pending() else {
+                return DispatchResult::pass();
+            };
+            state.sql_modal.vim_mut().cancel();
+            state.ui.set_key_sequence(KeySequenceState::Idle);
+            apply_motion(state, operator, *direction);
+            scroll_to_cursor(state);
+            DispatchResult::handled()
+        }
+        Action::SqlModalVimDeleteChar => {
+            if let Some((first, last)) = visual_rows(state) {
+                apply_linewise(state, SqlVimOperator::Delete, first, last);
+                state.sql_modal.vim_mut().cancel();
+            } else {
+                let cursor = state.sql_modal.editor.cursor();
+                let (_, line_end) = current_line_range(state);
+                if cursor < line_end {
+                    apply_charwise(state, SqlVimOperator::Delete, cursor, cursor + 1);
+                }
+            }
+            scroll_to_cursor(state);
+            DispatchResult::handled()
+        }
+        Action::SqlModalVimPaste { before } => {
+            paste(state, *before);
+            scroll_to_cursor(state);
+            DispatchResult::handled()
+        }
+        Action::SqlModalVimVisualLine => {
+            if state.sql_modal.vim().is_visual_line() {
+                state.sql_modal.vim_mut().cancel();
+            } else {
+                let (row, _) = state.sql_modal.editor.cursor_to_position();
+                state.sql_modal.vim_mut().start_visual_line(row);
+            }
+            DispatchResult::handled()
+        }
+        Action::SqlModalVimCancel => {
+            state.sql_modal.vim_mut().cancel();
+            state.ui.set_key_sequence(KeySequenceState::Idle);
+            DispatchResult::handled()
+        }
+        _ => DispatchResult::pass(),
+    }
+}
+
+fn visual_rows(state: &AppState) -> Option<(usize, usize)> {
+    let editor = &state.sql_modal.editor;
+    let (row, _) = editor.cursor_to_position();
+    state.sql_modal.vim().visual_rows(row, editor.line_count())
+}
+
+fn current_line_range(state: &AppState) -> (usize, usize) {
+    let (row, _) = state.sql_modal.editor.cursor_to_position();
+    state.sql_modal.editor.line_range(row, row)
+}
+
+fn apply_motion(state: &mut AppState, operator: SqlVimOperator, direction: CursorMove) {
+    let visible_rows = state.sql_modal.visible_rows(state.ui.terminal_height());
+    let editor = &mut state.sql_modal.editor;
+    let (before_row, _) = editor.cursor_to_position();
+    let before = editor.cursor();
+    match direction {
+        CursorMove::ViewportTop | CursorMove::ViewportMiddle | CursorMove::ViewportBottom => {
+            editor.move_cursor_to_viewport_position(direction, visible_rows);
+        }
+        _ => editor.move_cursor(direction),
+    }
+    let (after_row, _) = editor.cursor_to_position();
+    let after = editor.cursor();
+
+    let linewise = matches!(
+        direction,
+        CursorMove::Up
+            | CursorMove::Down
+            | CursorMove::FirstLine
+            | CursorMove::LastLine
+            | CursorMove::ViewportTop
+            | CursorMove::ViewportMiddle
+            | CursorMove::ViewportBottom
+    );
+    if linewise {
+        editor.set_cursor(before.min(after));
+        apply_linewise(
+            state,
+            operator,
+            before_row.min(after_row),
+            before_row.max(after_row),
+        );
+        return;
+    }
+
+    let start = before.min(after);
+    let mut end = before.max(after);
+    match direction {
+        // `e` includes the char it lands on.
+        CursorMove::WordEnd => end = (end + 1).min(editor.char_count()),
+        // `dw` on the last word of a line stops at the newline.
+        CursorMove::WordForward => {
+            let (_, line_end) = editor.line_range(before_row, before_row);
+            if start < line_end {
+                end = end.min(line_end);
+            }
+        }
+        _ => {}
+    }
+    editor.set_cursor(start);
+    apply_charwise(state, operator, start, end);
+}
+
+fn apply_charwise(state: &mut AppState, operator: SqlVimOperator, start: usize, end: usize) {
+    if start >= end {
+        return;
+    }
+    let editor = &mut state.sql_modal.editor;
+    let text = match operator {
+        SqlVimOperator::Delete => editor.remove_chars(start, end),
+        SqlVimOperator::Yank => {
+            let text = slice_chars(editor.content(), start, end);
+            editor.set_cursor(start);
+            text
+        }
+    };
+    state.sql_modal.vim_mut().set_register(text, false);
+}
+
+fn apply_linewise(state: &mut AppState, operator: SqlVimOperator, first: usize, last: usize) {
+    let editor = &mut state.sql_modal.editor;
+    let (start, end) = editor.line_range(first, last);
+    let text = slice_chars(editor.content(), start, end);
+    match operator {
+        SqlVimOperator::Delete => {
+            // Take one adjoining newline with the lines so no blank line is left behind.
+            if end < editor.char_count() {
+                editor.remove_chars(start, end + 1);
+            } else {
+                editor.remove_chars(start.saturating_sub(1), end);
+            }
+            let row = first.min(editor.line_count() - 1);
+            let (line_start, _) = editor.line_range(row, row);
+            editor.set_cursor(line_start);
+        }
+        SqlVimOperator::Yank => {}
+    }
+    state.sql_modal.vim_mut().set_register(text, true);
+}
+
+fn paste(state: &mut AppState, before: bool) {
+    let Some(register) = state.sql_modal.vim().register().cloned() else {
+        return;
+    };
+    let editor = &mut state.sql_modal.editor;
+    let (row, _) = editor.cursor_to_position();
+    let (line_start, line_end) = editor.line_range(row, row);
+    if register.linewise {
+        let (at, text, cursor) = if before {
+            (line_start, format!("{}\n", register.text), line_start)
+        } else {
+            (line_end, format!("\n{}", register.text), line_end + 1)
+        };
+        editor.set_cursor(at);
+        editor.insert_str(&text);
+        editor.set_cursor(cursor);
+    } else {
+        let at = if before {
+            editor.cursor()
+        } else {
+            (editor.cursor() + 1).min(line_end)
+        };
+        editor.set_cursor(at);
+        editor.insert_str(&register.text);
+        // Leave the cursor on the last pasted char, as vim does.
+        editor.set_cursor(at + register.text.chars().count().saturating_sub(1));
+    }
+}
+
+fn slice_chars(content: &str, start: usize, end: usize) -> String {
+    content.chars().skip(start).take(end - start).collect()
+}
+
+fn scroll_to_cursor(state: &mut AppState) {
+    state
+        .sql_modal
+        .editor
+        .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
+}
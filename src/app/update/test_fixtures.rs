@@ -1,4 +1,5 @@
 use crate::cmd::effect::Effect;
+use crate::domain::connection::{ConnectionProfile, SslMode};
 use crate::domain::{ConnectionId, DatabaseType};
 use crate::model::app_state::AppState;
 
@@ -11,6 +12,25 @@ pub fn activate_postgres_connection(state: &mut AppState, dsn: &str) {
     );
 }
 
+/// Activates a PostgreSQL connection whose profile is tagged production.
+pub fn activate_production_connection(state: &mut AppState, dsn: &str) {
+    let profile = ConnectionProfile::new_postgres(
+        "prod",
+        "db.internal",
+        5432,
+        "app",
+        "app",
+        "",
+        SslMode::Prefer,
+    )
+    .unwrap()
+    .with_production(true);
+    state
+        .session
+        .activate_connection_with_dsn(&profile.id, "prod", DatabaseType::PostgreSQL, dsn);
+    state.set_connections(vec![profile]);
+}
+
 pub fn activate_sqlite_connection(state: &mut AppState, dsn: &str) {
     state.session.activate_connection_with_dsn(
         &ConnectionId::new(),
@@ -33,7 +53,7 @@ pub fn assert_connection_save_fetch_effects(effects: &[Effect], database_type: D
             assert!(matches!(seq[2], Effect::ClearCompletionEngineCache));
             assert!(matches!(seq[3], Effect::FetchMetadata { .. }));
         }
-        DatabaseType::PostgreSQL => {
+        DatabaseType::PostgreSQL | DatabaseType::MySQL => {
             assert_eq!(
                 effects.len(),
                 3,
@@ -0,0 +1,217 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::model::app_state::AppState;
+use crate::model::shared::input_mode::InputMode;
+use crate::model::shared::text_input::TextInputLike;
+use crate::model::sql_editor::vim::SqlVimOperator;
+use crate::model::undo_history::UndoSnapshot;
+use crate::update::action::{Action, InputTarget};
+
+/// Snapshots the sub-state a whitelisted destructive action is about to
+/// overwrite. Evaluated before the action is reduced.
+pub fn capture_undo_snapshot(state: &AppState, action: &Action) -> Option<UndoSnapshot> {
+    let current_result = || {
+        state
+            .query
+            .current_result()
+            .map(Arc::downgrade)
+            .unwrap_or_default()
+    };
+    match action {
+        Action::SqlModalClear
+        | Action::SqlModalRenameAlias
+        | Action::SqlModalVimOperator(SqlVimOperator::Delete)
+        | Action::SqlModalVimDeleteChar
+        | Action::SqlModalVimPaste { .. } => {
+            Some(UndoSnapshot::SqlBuffer(state.sql_modal.editor().clone()))
+        }
+        Action::TextMoveCursor {
+            target: InputTarget::SqlModal,
+            ..
+        } if state.sql_modal.vim().pending() == Some(SqlVimOperator::Delete) => {
+            Some(UndoSnapshot::SqlBuffer(state.sql_modal.editor().clone()))
+        }
+        Action::ClearStagedDeletes => Some(UndoSnapshot::StagedDeletes {
+            result: current_result(),
+            rows: state.result_interaction.staged_delete_rows().clone(),
+        }),
+        Action::ResultDiscardCellEdit => Some(UndoSnapshot::CellEdit {
+            result: current_result(),
+            edit: state.result_interaction.cell_edit().clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// Keeps the snapshot only when the action actually changed what it covers,
+/// so no-op clears and guarded actions leave the history alone.
+pub fn record_undo_snapshot(state: &mut AppState, snapshot: UndoSnapshot) {
+    let changed = match &snapshot {
+        UndoSnapshot::SqlBuffer(editor) => editor.content() != state.sql_modal.editor().content(),
+        UndoSnapshot::StagedDeletes { rows, .. } => {
+            rows != state.result_interaction.staged_delete_rows()
+        }
+        UndoSnapshot::CellEdit { edit, .. } => edit != state.result_interaction.cell_edit(),
+    };
+    if changed {
+        state.undo.push(snapshot);
+    }
+}
+
+pub fn undo_last_mutation(state: &mut AppState, now: Instant) {
+    let Some(snapshot) = state.undo.pop() else {
+        state
+            .messages
+            .set_error_at("Nothing to undo".to_string(), now);
+        return;
+    };
+    let label = snapshot.label();
+    if !snapshot.matches_result(state.query.current_result()) {
+        state
+            .messages
+            .set_error_at(format!("Cannot undo {label}: the result has changed"), now);
+        return;
+    }
+
+    match snapshot {
+        UndoSnapshot::SqlBuffer(editor) => {
+            state.sql_modal.restore_editor(editor);
+            state
+                .sql_modal
+                .editor
+                .update_scroll(state.sql_modal.visible_rows(state.ui.terminal_height()));
+        }
+        UndoSnapshot::StagedDeletes { rows, .. } => {
+            state.result_interaction.restore_staged_deletes(rows);
+        }
+        UndoSnapshot::CellEdit { edit, .. } => {
+            state.result_interaction.restore_cell_edit(edit);
+            if state.input_mode() == InputMode::Normal {
+                state.modal.set_mode(InputMode::CellEdit);
+            }
+        }
+    }
+    state
+        .messages
+        .set_success_at(format!("Restored {label}"), now);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{QueryResult, QuerySource};
+    use crate::services::AppServices;
+    use crate::update::reducer::reduce;
+
+    fn state_with_result() -> AppState {
+        let mut state = AppState::new("test".to_string());
+        state
+            .query
+            .set_current_result(Arc::new(QueryResult::success(
+                "SELECT * FROM users".to_string(),
+                vec!["id".to_string()],
+                vec![vec!["1".to_string()], vec!["2".to_string()]],
+                1,
+                QuerySource::Preview,
+            )));
+        state
+    }
+
+    #[test]
+    fn clear_then_undo_restores_sql_buffer() {
+        let now = Instant::now();
+        let services = AppServices::stub();
+        let mut state = AppState::new("test".to_string());
+        state
+            .sql_modal
+            .editor_mut_for_input()
+            .set_content("SELECT 1".to_string());
+
+        reduce(&mut state, Action::SqlModalClear, now, &services);
+        assert_eq!(state.sql_modal.editor().content(), "");
+
+        reduce(&mut state, Action::UndoUiMutation, now, &services);
+
+        assert_eq!(state.sql_modal.editor().content(), "SELECT 1");
+        assert!(state.undo.is_empty());
+        assert_eq!(state.messages.last_success(), Some("Restored SQL buffer"));
+    }
+
+    #[test]
+    fn clearing_an_empty_buffer_records_nothing() {
+        let mut state = AppState::new("test".to_string());
+
+        reduce(
+            &mut state,
+            Action::SqlModalClear,
+            Instant::now(),
+            &AppServices::stub(),
+        );
+
+        assert!(state.undo.is_empty());
+    }
+
+    #[test]
+    fn undo_restores_cleared_staged_deletes() {
+        let now = Instant::now();
+        let services = AppServices::stub();
+        let mut state = state_with_result();
+        state.result_interaction.stage_row(1);
+
+        reduce(&mut state, Action::ClearStagedDeletes, now, &services);
+        reduce(&mut state, Action::UndoUiMutation, now, &services);
+
+        assert!(state.result_interaction.staged_delete_rows().contains(&1));
+    }
+
+    #[test]
+    fn undo_reopens_discarded_cell_edit() {
+        let now = Instant::now();
+        let services = AppServices::stub();
+        let mut state = state_with_result();
+        state.result_interaction.activate_cell(1, 0);
+        state
+            .result_interaction
+            .begin_cell_edit(1, 0, "draft".to_string());
+        state.modal.set_mode(InputMode::CellEdit);
+
+        reduce(&mut state, Action::ResultDiscardCellEdit, now, &services);
+        assert!(!state.result_interaction.cell_edit().is_active());
+
+        reduce(&mut state, Action::UndoUiMutation, now, &services);
+
+        assert_eq!(state.result_interaction.cell_edit().row(), Some(1));
+        assert_eq!(state.input_mode(), InputMode::CellEdit);
+    }
+
+    #[test]
+    fn staged_deletes_are_not_restored_onto_a_new_result() {
+        let now = Instant::now();
+        let services = AppServices::stub();
+        let mut state = state_with_result();
+        state.result_interaction.stage_row(0);
+        reduce(&mut state, Action::ClearStagedDeletes, now, &services);
+        let replaced = state_with_result();
+        state
+            .query
+            .set_current_result(Arc::clone(replaced.query.current_result().unwrap()));
+
+        reduce(&mut state, Action::UndoUiMutation, now, &services);
+
+        assert!(state.result_interaction.staged_delete_rows().is_empty());
+        assert_eq!(
+            state.messages.last_error(),
+            Some("Cannot undo staged deletes: the result has changed")
+        );
+    }
+
+    #[test]
+    fn undo_with_empty_history_reports_nothing_to_undo() {
+        let mut state = AppState::new("test".to_string());
+
+        undo_last_mutation(&mut state, Instant::now());
+
+        assert_eq!(state.messages.last_error(), Some("Nothing to undo"));
+    }
+}
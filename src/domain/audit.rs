@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use super::query_history::Iso8601Timestamp;
+
+/// A destructive statement the user confirmed on a production profile, or
+/// an unmask of sensitive columns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub connection: String,
+    /// Statement label such as `DELETE` or `DROP`.
+    pub operation: String,
+    /// Guardrail severity after the production escalation.
+    pub risk: String,
+    pub statement: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub recorded_at: Iso8601Timestamp,
+    #[serde(flatten)]
+    pub event: AuditEvent,
+}
+
+impl AuditEntry {
+    pub fn new(recorded_at: String, event: AuditEvent) -> Self {
+        Self {
+            recorded_at: Iso8601Timestamp::new(recorded_at),
+            event,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_serializes_as_one_flat_object() {
+        let entry = AuditEntry::new(
+            "2026-10-16T09:00:00Z".to_string(),
+            AuditEvent {
+                connection: "prod".to_string(),
+                operation: "DELETE".to_string(),
+                risk: "HIGH".to_string(),
+                statement: "DELETE FROM users WHERE id = 1".to_string(),
+            },
+        );
+
+        let json = serde_json::to_string(&entry).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"recorded_at":"2026-10-16T09:00:00Z","connection":"prod","operation":"DELETE","risk":"HIGH","statement":"DELETE FROM users WHERE id = 1"}"#
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+
+use crate::query_result::{QueryResult, QueryValue};
+
+/// Planner statistics for one column, as sampled by ANALYZE into `pg_stats`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnStats {
+    pub column: String,
+    pub null_frac: f64,
+    /// Positive: estimated number of distinct values. Negative: distinct
+    /// values as a fraction of the row count, negated.
+    pub n_distinct: f64,
+}
+
+/// Columns with at most this many distinct values read as categorical.
+const LOW_CARDINALITY_MAX: f64 = 100.0;
+const NULL_FRAC_SHOWN_MIN: f64 = 0.1;
+
+impl ColumnStats {
+    /// Absolute distinct count; `None` when the estimate scales with rows.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "guarded positive and rounded"
+    )]
+    pub fn distinct_count(&self) -> Option<u64> {
+        (self.n_distinct > 0.0).then(|| self.n_distinct.round() as u64)
+    }
+
+    pub fn is_low_cardinality(&self) -> bool {
+        self.n_distinct > 0.0 && self.n_distinct <= LOW_CARDINALITY_MAX
+    }
+
+    /// Short annotation such as "4 distinct, 30% null"; sparse null
+    /// fractions are left out.
+    pub fn summary(&self) -> Option<String> {
+        let distinct = match self.distinct_count() {
+            Some(count) => Some(format!("{count} distinct")),
+            None => ((self.n_distinct + 1.0).abs() < f64::EPSILON).then(|| "unique".to_string()),
+        };
+        let nulls = (self.null_frac >= NULL_FRAC_SHOWN_MIN)
+            .then(|| format!("{:.0}% null", self.null_frac * 100.0));
+        match (distinct, nulls) {
+            (Some(distinct), Some(nulls)) => Some(format!("{distinct}, {nulls}")),
+            (distinct, nulls) => distinct.or(nulls),
+        }
+    }
+}
+
+/// Value distribution of one column, read from `pg_stats` where ANALYZE has
+/// run and from a bounded row sample otherwise.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnDistribution {
+    pub sampled_rows: u64,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    pub avg: Option<String>,
+    pub null_frac: Option<f64>,
+    /// Same encoding as [`ColumnStats::n_distinct`].
+    pub n_distinct: Option<f64>,
+    /// Most common values, most frequent first.
+    pub common_values: Vec<CommonValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommonValue {
+    pub value: String,
+    /// Fraction of all rows holding this value.
+    pub frequency: f64,
+}
+
+impl ColumnDistribution {
+    /// Reads `stat, value, frequency` rows; `common` rows list the most
+    /// common values in order, the rest hold one figure each.
+    pub fn from_result(result: &QueryResult) -> Self {
+        let mut distribution = Self::default();
+        for row in 0..result.data_row_count() {
+            let text = |col| match result.value_at(row, col) {
+                Some(QueryValue::Null) => None,
+                _ => result.display_value_at(row, col),
+            };
+            let (Some(stat), Some(value)) = (text(0), text(1)) else {
+                continue;
+            };
+            match stat.as_str() {
+                "sampled" => distribution.sampled_rows = value.parse().unwrap_or_default(),
+                "min" => distribution.min = Some(value),
+                "max" => distribution.max = Some(value),
+                "avg" => distribution.avg = Some(value),
+                "null_frac" => distribution.null_frac = value.parse().ok(),
+                "n_distinct" => distribution.n_distinct = value.parse().ok(),
+                "common" => {
+                    if let Some(frequency) = text(2).and_then(|f| f.parse().ok()) {
+                        distribution
+                            .common_values
+                            .push(CommonValue { value, frequency });
+                    }
+                }
+                _ => {}
+            }
+        }
+        distribution
+    }
+
+    /// "1234", "unique", or "40% of rows" when the estimate scales with the
+    /// table.
+    pub fn distinct_label(&self) -> Option<String> {
+        let n_distinct = self.n_distinct?;
+        let stats = ColumnStats {
+            column: String::new(),
+            null_frac: 0.0,
+            n_distinct,
+        };
+        Some(match stats.distinct_count() {
+            Some(count) => count.to_string(),
+            None if (n_distinct + 1.0).abs() < f64::EPSILON => "unique".to_string(),
+            None if n_distinct < 0.0 => format!("{:.0}% of rows", -n_distinct * 100.0),
+            None => "0".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_result::QuerySource;
+    use rstest::rstest;
+
+    fn stats(n_distinct: f64, null_frac: f64) -> ColumnStats {
+        ColumnStats {
+            column: "status".to_string(),
+            null_frac,
+            n_distinct,
+        }
+    }
+
+    #[rstest]
+    #[case::small_set(4.0, 0.0, Some("4 distinct"), true)]
+    #[case::large_set(5000.0, 0.0, Some("5000 distinct"), false)]
+    #[case::all_distinct(-1.0, 0.0, Some("unique"), false)]
+    #[case::scales_with_rows(-0.4, 0.0, None, false)]
+    #[case::many_nulls(3.0, 0.3, Some("3 distinct, 30% null"), true)]
+    #[case::nulls_only(-0.4, 0.5, Some("50% null"), false)]
+    #[case::few_nulls(4.0, 0.01, Some("4 distinct"), true)]
+    fn summarizes_distinct_and_null_estimates(
+        #[case] n_distinct: f64,
+        #[case] null_frac: f64,
+        #[case] expected: Option<&str>,
+        #[case] low_cardinality: bool,
+    ) {
+        let stats = stats(n_distinct, null_frac);
+
+        assert_eq!(stats.summary().as_deref(), expected);
+        assert_eq!(stats.is_low_cardinality(), low_cardinality);
+    }
+
+    fn distribution_result(rows: &[(&str, Option<&str>, Option<&str>)]) -> QueryResult {
+        let value = |v: Option<&str>| v.map_or(QueryValue::Null, |v| QueryValue::Text(v.into()));
+        QueryResult::success_with_values(
+            String::new(),
+            vec![
+                "stat".to_string(),
+                "value".to_string(),
+                "frequency".to_string(),
+            ],
+            rows.iter()
+                .map(|(stat, v, f)| vec![QueryValue::Text((*stat).into()), value(*v), value(*f)])
+                .collect(),
+            1,
+            QuerySource::Adhoc,
+        )
+    }
+
+    #[test]
+    fn reads_figures_and_common_values_in_order() {
+        let result = distribution_result(&[
+            ("sampled", Some("500"), None),
+            ("min", Some("1"), None),
+            ("max", Some("99"), None),
+            ("avg", None, None),
+            ("null_frac", Some("0.25"), None),
+            ("n_distinct", Some("-0.5"), None),
+            ("common", Some("active"), Some("0.6")),
+            ("common", Some("banned"), Some("0.1")),
+        ]);
+
+        let distribution = ColumnDistribution::from_result(&result);
+
+        assert_eq!(distribution.sampled_rows, 500);
+        assert_eq!(distribution.min.as_deref(), Some("1"));
+        assert_eq!(distribution.max.as_deref(), Some("99"));
+        assert_eq!(distribution.avg, None);
+        assert_eq!(distribution.null_frac, Some(0.25));
+        assert_eq!(
+            distribution.common_values,
+            vec![
+                CommonValue {
+                    value: "active".to_string(),
+                    frequency: 0.6
+                },
+                CommonValue {
+                    value: "banned".to_string(),
+                    frequency: 0.1
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(Some(42.0), Some("42"))]
+    #[case(Some(-1.0), Some("unique"))]
+    #[case(Some(-0.4), Some("40% of rows"))]
+    #[case(None, None)]
+    fn labels_distinct_estimates(#[case] n_distinct: Option<f64>, #[case] expected: Option<&str>) {
+        let distribution = ColumnDistribution {
+            n_distinct,
+            ..ColumnDistribution::default()
+        };
+
+        assert_eq!(distribution.distinct_label().as_deref(), expected);
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Color a connection profile tints the footer, pane borders and confirm
+/// dialogs with, so dev and prod look different at a glance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProfileAccent {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Magenta,
+}
+
+impl fmt::Display for ProfileAccent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Red => write!(f, "red"),
+            Self::Orange => write!(f, "orange"),
+            Self::Yellow => write!(f, "yellow"),
+            Self::Green => write!(f, "green"),
+            Self::Cyan => write!(f, "cyan"),
+            Self::Blue => write!(f, "blue"),
+            Self::Magenta => write!(f, "magenta"),
+        }
+    }
+}
+
+impl FromStr for ProfileAccent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "red" => Ok(Self::Red),
+            "orange" => Ok(Self::Orange),
+            "yellow" => Ok(Self::Yellow),
+            "green" => Ok(Self::Green),
+            "cyan" => Ok(Self::Cyan),
+            "blue" => Ok(Self::Blue),
+            "magenta" => Ok(Self::Magenta),
+            _ => Err(format!("Unknown accent color: {s}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_matches_parse() {
+        for accent in [
+            ProfileAccent::Red,
+            ProfileAccent::Orange,
+            ProfileAccent::Yellow,
+            ProfileAccent::Green,
+            ProfileAccent::Cyan,
+            ProfileAccent::Blue,
+            ProfileAccent::Magenta,
+        ] {
+            assert_eq!(
+                ProfileAccent::from_str(&accent.to_string()).unwrap(),
+                accent
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_returns_error_for_unknown() {
+        assert!(ProfileAccent::from_str("teal").is_err());
+    }
+}
@@ -32,6 +32,33 @@ impl PostgresConnectionConfig {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MySqlConnectionConfig {
+    pub host: String,
+    pub port: u16,
+    pub database: String,
+    pub username: String,
+    pub password: String,
+}
+
+impl MySqlConnectionConfig {
+    pub fn new(
+        host: impl Into<String>,
+        port: u16,
+        database: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            database: database.into(),
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct SqliteConnectionConfig {
     path: String,
@@ -104,6 +131,7 @@ fn is_sqlite_uri_filename(path: &str) -> bool {
 pub enum ConnectionConfig {
     PostgreSQL(PostgresConnectionConfig),
     SQLite(SqliteConnectionConfig),
+    MySQL(MySqlConnectionConfig),
 }
 
 impl ConnectionConfig {
@@ -111,20 +139,28 @@ impl ConnectionConfig {
         match self {
             Self::PostgreSQL(_) => super::DatabaseType::PostgreSQL,
             Self::SQLite(_) => super::DatabaseType::SQLite,
+            Self::MySQL(_) => super::DatabaseType::MySQL,
         }
     }
 
     pub fn as_postgres(&self) -> Option<&PostgresConnectionConfig> {
         match self {
             Self::PostgreSQL(config) => Some(config),
-            Self::SQLite(_) => None,
+            Self::SQLite(_) | Self::MySQL(_) => None,
         }
     }
 
     pub fn as_sqlite(&self) -> Option<&SqliteConnectionConfig> {
         match self {
             Self::SQLite(config) => Some(config),
-            Self::PostgreSQL(_) => None,
+            Self::PostgreSQL(_) | Self::MySQL(_) => None,
+        }
+    }
+
+    pub fn as_mysql(&self) -> Option<&MySqlConnectionConfig> {
+        match self {
+            Self::MySQL(config) => Some(config),
+            Self::PostgreSQL(_) | Self::SQLite(_) => None,
         }
     }
 }
@@ -1,20 +1,31 @@
+mod accent;
 mod config;
 mod database_type;
+mod dsn;
 mod id;
 mod name;
+mod pool_mode;
+mod pool_settings;
 mod profile;
 mod service_entry;
+mod session_settings;
 mod sqlite_path;
 mod ssl_mode;
 
+pub use accent::ProfileAccent;
 pub use config::{
-    ConnectionConfig, PostgresConnectionConfig, SqliteConnectionConfig, SqliteConnectionConfigError,
+    ConnectionConfig, MySqlConnectionConfig, PostgresConnectionConfig, SqliteConnectionConfig,
+    SqliteConnectionConfigError,
 };
 pub use database_type::DatabaseType;
+pub use dsn::{Dsn, DsnError, DsnStyle};
 pub use id::ConnectionId;
 pub use name::{ConnectionName, ConnectionNameError};
+pub use pool_mode::PoolMode;
+pub use pool_settings::{DEFAULT_POOL_SIZE, PoolSettings};
 pub use profile::{ConnectionProfile, ConnectionProfileError};
 pub use service_entry::ServiceEntry;
+pub use session_settings::{SessionSettingKey, SessionSettings};
 pub use sqlite_path::{
     SqlitePathError, classify_sqlite_metadata_error, classify_sqlite_read_error,
     sqlite_path_from_dsn,
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// pgbouncer `pool_mode` of the proxy in front of a PostgreSQL connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PoolMode {
+    Session,
+    Transaction,
+    Statement,
+}
+
+impl PoolMode {
+    /// False when consecutive transactions may land on different server
+    /// connections, so session state (SET, LISTEN) leaks or gets lost.
+    pub fn keeps_session(self) -> bool {
+        matches!(self, Self::Session)
+    }
+}
+
+impl fmt::Display for PoolMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Session => write!(f, "session"),
+            Self::Transaction => write!(f, "transaction"),
+            Self::Statement => write!(f, "statement"),
+        }
+    }
+}
+
+impl FromStr for PoolMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "session" => Ok(Self::Session),
+            "transaction" => Ok(Self::Transaction),
+            "statement" => Ok(Self::Statement),
+            _ => Err(format!("Unknown pool mode: {s}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_session_pooling_keeps_session_state() {
+        assert!(PoolMode::Session.keeps_session());
+        assert!(!PoolMode::Transaction.keeps_session());
+        assert!(!PoolMode::Statement.keeps_session());
+    }
+
+    #[test]
+    fn display_matches_parse() {
+        for mode in [
+            PoolMode::Session,
+            PoolMode::Transaction,
+            PoolMode::Statement,
+        ] {
+            assert_eq!(PoolMode::from_str(&mode.to_string()).unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn from_str_returns_error_for_unknown() {
+        assert!(PoolMode::from_str("pooled").is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+// Covers the prefetch workers plus an adhoc query without reconnecting.
+pub const DEFAULT_POOL_SIZE: usize = 4;
+
+/// Driver connections sabiql keeps open for each DSN of a profile.
+///
+/// This is sabiql's own pool, not the pgbouncer [`super::PoolMode`] a server
+/// may sit behind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PoolSettings {
+    /// Idle connections kept between queries.
+    pub size: usize,
+    /// Connections opened right after connecting; never more than `size`.
+    pub warmup: usize,
+}
+
+impl Default for PoolSettings {
+    fn default() -> Self {
+        Self {
+            size: DEFAULT_POOL_SIZE,
+            warmup: 0,
+        }
+    }
+}
+
+impl PoolSettings {
+    pub fn new(size: Option<usize>, warmup: Option<usize>) -> Self {
+        let size = size.unwrap_or(DEFAULT_POOL_SIZE);
+        Self {
+            size,
+            warmup: warmup.unwrap_or(0).min(size),
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        assert_eq!(PoolSettings::new(None, None), PoolSettings::default());
+    }
+
+    #[test]
+    fn warmup_is_capped_at_the_pool_size() {
+        let settings = PoolSettings::new(Some(2), Some(5));
+
+        assert_eq!(settings.size, 2);
+        assert_eq!(settings.warmup, 2);
+    }
+}
@@ -1,11 +1,16 @@
 use serde::{Deserialize, Serialize};
 
+use super::accent::ProfileAccent;
 use super::config::{
-    ConnectionConfig, PostgresConnectionConfig, SqliteConnectionConfig, SqliteConnectionConfigError,
+    ConnectionConfig, MySqlConnectionConfig, PostgresConnectionConfig, SqliteConnectionConfig,
+    SqliteConnectionConfigError,
 };
 use super::database_type::DatabaseType;
 use super::id::ConnectionId;
 use super::name::{ConnectionName, ConnectionNameError};
+use super::pool_mode::PoolMode;
+use super::pool_settings::PoolSettings;
+use super::session_settings::SessionSettings;
 use super::sqlite_path::SqlitePathError;
 use super::ssl_mode::SslMode;
 
@@ -25,6 +30,8 @@ pub enum ConnectionProfileError {
     UnsupportedSqliteUriFilename,
     #[error("PostgreSQL connection field `{0}` is required")]
     MissingPostgresField(&'static str),
+    #[error("MySQL connection field `{0}` is required")]
+    MissingMySqlField(&'static str),
     #[error("{0}")]
     SqlitePath(#[from] SqlitePathError),
 }
@@ -49,6 +56,24 @@ pub struct ConnectionProfile {
     pub id: ConnectionId,
     pub name: ConnectionName,
     pub config: ConnectionConfig,
+    /// Optional read replica that serves read-only ad-hoc queries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replica_dsn: Option<String>,
+    /// Set when the connection goes through pgbouncer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_mode: Option<PoolMode>,
+    /// Driver connections kept open for this profile's DSNs.
+    #[serde(default, skip_serializing_if = "PoolSettings::is_default")]
+    pub pool: PoolSettings,
+    /// GUC overrides applied to every session opened for this profile.
+    #[serde(default, skip_serializing_if = "SessionSettings::is_empty")]
+    pub session_settings: SessionSettings,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<ProfileAccent>,
+    /// Marks the profile as production: shows a banner and defaults the
+    /// accent to red.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub production: bool,
 }
 
 impl ConnectionProfile {
@@ -67,6 +92,12 @@ impl ConnectionProfile {
             config: ConnectionConfig::PostgreSQL(PostgresConnectionConfig::new(
                 host, port, database, username, password, ssl_mode,
             )),
+            replica_dsn: None,
+            pool_mode: None,
+            pool: PoolSettings::default(),
+            session_settings: SessionSettings::default(),
+            accent: None,
+            production: false,
         })
     }
 
@@ -78,6 +109,35 @@ impl ConnectionProfile {
             id: ConnectionId::new(),
             name: ConnectionName::new(name)?,
             config: ConnectionConfig::SQLite(SqliteConnectionConfig::new(path)?),
+            replica_dsn: None,
+            pool_mode: None,
+            pool: PoolSettings::default(),
+            session_settings: SessionSettings::default(),
+            accent: None,
+            production: false,
+        })
+    }
+
+    pub fn new_mysql(
+        name: impl Into<String>,
+        host: impl Into<String>,
+        port: u16,
+        database: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Result<Self, ConnectionProfileError> {
+        Ok(Self {
+            id: ConnectionId::new(),
+            name: ConnectionName::new(name)?,
+            config: ConnectionConfig::MySQL(MySqlConnectionConfig::new(
+                host, port, database, username, password,
+            )),
+            replica_dsn: None,
+            pool_mode: None,
+            pool: PoolSettings::default(),
+            session_settings: SessionSettings::default(),
+            accent: None,
+            production: false,
         })
     }
 
@@ -97,6 +157,12 @@ impl ConnectionProfile {
             config: ConnectionConfig::PostgreSQL(PostgresConnectionConfig::new(
                 host, port, database, username, password, ssl_mode,
             )),
+            replica_dsn: None,
+            pool_mode: None,
+            pool: PoolSettings::default(),
+            session_settings: SessionSettings::default(),
+            accent: None,
+            production: false,
         })
     }
 
@@ -109,6 +175,12 @@ impl ConnectionProfile {
             id,
             name: ConnectionName::new(name)?,
             config: ConnectionConfig::SQLite(SqliteConnectionConfig::new(path)?),
+            replica_dsn: None,
+            pool_mode: None,
+            pool: PoolSettings::default(),
+            session_settings: SessionSettings::default(),
+            accent: None,
+            production: false,
         })
     }
 
@@ -121,6 +193,12 @@ impl ConnectionProfile {
             id,
             name: ConnectionName::new(name)?,
             config,
+            replica_dsn: None,
+            pool_mode: None,
+            pool: PoolSettings::default(),
+            session_settings: SessionSettings::default(),
+            accent: None,
+            production: false,
         })
     }
 
@@ -136,9 +214,92 @@ impl ConnectionProfile {
         self.config.as_sqlite()
     }
 
+    pub fn mysql_config(&self) -> Option<&MySqlConnectionConfig> {
+        self.config.as_mysql()
+    }
+
     pub fn display_name(&self) -> &str {
         self.name.as_str()
     }
+
+    #[must_use]
+    pub fn with_replica_dsn(mut self, replica_dsn: Option<String>) -> Self {
+        self.replica_dsn = replica_dsn.filter(|dsn| !dsn.trim().is_empty());
+        self
+    }
+
+    /// Replicas are PostgreSQL-only; other profiles never route elsewhere.
+    pub fn replica_dsn(&self) -> Option<&str> {
+        match self.config {
+            ConnectionConfig::PostgreSQL(_) => self.replica_dsn.as_deref(),
+            ConnectionConfig::SQLite(_) | ConnectionConfig::MySQL(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_pool_mode(mut self, pool_mode: Option<PoolMode>) -> Self {
+        self.pool_mode = pool_mode;
+        self
+    }
+
+    /// Connection poolers only sit in front of PostgreSQL.
+    pub fn pool_mode(&self) -> Option<PoolMode> {
+        match self.config {
+            ConnectionConfig::PostgreSQL(_) => self.pool_mode,
+            ConnectionConfig::SQLite(_) | ConnectionConfig::MySQL(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_pool(mut self, pool: PoolSettings) -> Self {
+        self.pool = pool;
+        self
+    }
+
+    /// Only PostgreSQL queries run over pooled driver connections.
+    pub fn pool(&self) -> Option<PoolSettings> {
+        match self.config {
+            ConnectionConfig::PostgreSQL(_) => Some(self.pool),
+            ConnectionConfig::SQLite(_) | ConnectionConfig::MySQL(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_session_settings(mut self, session_settings: SessionSettings) -> Self {
+        self.session_settings = session_settings;
+        self
+    }
+
+    /// Session settings are PostgreSQL GUCs; SQLite has no server-side
+    /// session and MySQL profiles keep their server defaults.
+    pub fn session_settings(&self) -> Option<&SessionSettings> {
+        match self.config {
+            ConnectionConfig::PostgreSQL(_) => Some(&self.session_settings),
+            ConnectionConfig::SQLite(_) | ConnectionConfig::MySQL(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_accent(mut self, accent: Option<ProfileAccent>) -> Self {
+        self.accent = accent;
+        self
+    }
+
+    #[must_use]
+    pub fn with_production(mut self, production: bool) -> Self {
+        self.production = production;
+        self
+    }
+
+    /// Production profiles without an explicit accent are red.
+    pub fn accent(&self) -> Option<ProfileAccent> {
+        self.accent
+            .or_else(|| self.production.then_some(ProfileAccent::Red))
+    }
+
+    pub fn is_production(&self) -> bool {
+        self.production
+    }
 }
 
 #[cfg(test)]
@@ -250,4 +411,115 @@ mod tests {
             ));
         }
     }
+
+    mod replica_dsn {
+        use super::*;
+
+        #[test]
+        fn postgres_profile_exposes_replica() {
+            let profile =
+                make_test_profile().with_replica_dsn(Some("postgres://replica/testdb".to_string()));
+
+            assert_eq!(profile.replica_dsn(), Some("postgres://replica/testdb"));
+        }
+
+        #[test]
+        fn blank_replica_is_ignored() {
+            let profile = make_test_profile().with_replica_dsn(Some("  ".to_string()));
+
+            assert_eq!(profile.replica_dsn(), None);
+        }
+
+        #[test]
+        fn sqlite_profile_never_routes_to_replica() {
+            let profile = ConnectionProfile::new_sqlite("Local", "/tmp/app.db")
+                .unwrap()
+                .with_replica_dsn(Some("postgres://replica/testdb".to_string()));
+
+            assert_eq!(profile.replica_dsn(), None);
+        }
+    }
+    mod pool_mode {
+        use super::*;
+
+        #[test]
+        fn postgres_profile_exposes_pool_mode() {
+            let profile = make_test_profile().with_pool_mode(Some(PoolMode::Transaction));
+
+            assert_eq!(profile.pool_mode(), Some(PoolMode::Transaction));
+        }
+
+        #[test]
+        fn sqlite_profile_has_no_pool_mode() {
+            let profile = ConnectionProfile::new_sqlite("Local", "/tmp/app.db")
+                .unwrap()
+                .with_pool_mode(Some(PoolMode::Transaction));
+
+            assert_eq!(profile.pool_mode(), None);
+        }
+    }
+
+    mod pool {
+        use super::*;
+
+        #[test]
+        fn postgres_profile_exposes_pool_settings() {
+            let settings = PoolSettings::new(Some(8), Some(2));
+            let profile = make_test_profile().with_pool(settings);
+
+            assert_eq!(profile.pool(), Some(settings));
+        }
+
+        #[test]
+        fn sqlite_profile_is_never_pooled() {
+            let profile = ConnectionProfile::new_sqlite("Local", "/tmp/app.db")
+                .unwrap()
+                .with_pool(PoolSettings::new(Some(8), None));
+
+            assert_eq!(profile.pool(), None);
+        }
+    }
+
+    mod accent {
+        use super::*;
+
+        #[test]
+        fn production_defaults_to_red() {
+            let profile = make_test_profile().with_production(true);
+
+            assert_eq!(profile.accent(), Some(ProfileAccent::Red));
+        }
+
+        #[test]
+        fn explicit_accent_wins_over_production_default() {
+            let profile = make_test_profile()
+                .with_production(true)
+                .with_accent(Some(ProfileAccent::Orange));
+
+            assert_eq!(profile.accent(), Some(ProfileAccent::Orange));
+            assert_eq!(make_test_profile().accent(), None);
+        }
+    }
+
+    mod session_settings {
+        use super::*;
+        use crate::connection::SessionSettingKey;
+
+        #[test]
+        fn sqlite_profile_has_no_session_settings() {
+            let mut settings = SessionSettings::default();
+            settings.set(SessionSettingKey::WorkMem, "64MB");
+            let profile = ConnectionProfile::new_sqlite("Local", "/tmp/app.db")
+                .unwrap()
+                .with_session_settings(settings.clone());
+
+            assert_eq!(profile.session_settings(), None);
+            assert_eq!(
+                make_test_profile()
+                    .with_session_settings(settings.clone())
+                    .session_settings(),
+                Some(&settings)
+            );
+        }
+    }
 }
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+/// GUCs a profile can pin for every session it opens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionSettingKey {
+    ApplicationName,
+    WorkMem,
+    StatementTimeout,
+    SearchPath,
+}
+
+impl SessionSettingKey {
+    pub const ALL: [Self; 4] = [
+        Self::ApplicationName,
+        Self::WorkMem,
+        Self::StatementTimeout,
+        Self::SearchPath,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ApplicationName => "application_name",
+            Self::WorkMem => "work_mem",
+            Self::StatementTimeout => "statement_timeout",
+            Self::SearchPath => "search_path",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_mem: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statement_timeout: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_path: Option<String>,
+}
+
+impl SessionSettings {
+    pub fn is_empty(&self) -> bool {
+        SessionSettingKey::ALL
+            .iter()
+            .all(|key| self.get(*key).is_none())
+    }
+
+    pub fn get(&self, key: SessionSettingKey) -> Option<&str> {
+        match key {
+            SessionSettingKey::ApplicationName => self.application_name.as_deref(),
+            SessionSettingKey::WorkMem => self.work_mem.as_deref(),
+            SessionSettingKey::StatementTimeout => self.statement_timeout.as_deref(),
+            SessionSettingKey::SearchPath => self.search_path.as_deref(),
+        }
+    }
+
+    /// Blank values clear the override so the server default applies.
+    pub fn set(&mut self, key: SessionSettingKey, value: &str) {
+        let value = Some(value.trim())
+            .filter(|v| !v.is_empty())
+            .map(str::to_string);
+        match key {
+            SessionSettingKey::ApplicationName => self.application_name = value,
+            SessionSettingKey::WorkMem => self.work_mem = value,
+            SessionSettingKey::StatementTimeout => self.statement_timeout = value,
+            SessionSettingKey::SearchPath => self.search_path = value,
+        }
+    }
+
+    pub fn entries(&self) -> Vec<(SessionSettingKey, &str)> {
+        SessionSettingKey::ALL
+            .iter()
+            .filter_map(|key| self.get(*key).map(|value| (*key, value)))
+            .collect()
+    }
+
+    /// The libpq `options` value carrying every override except
+    /// `application_name`, which has its own connection parameter.
+    pub fn startup_options(&self) -> Option<String> {
+        let options: Vec<String> = self
+            .entries()
+            .into_iter()
+            .filter(|(key, _)| *key != SessionSettingKey::ApplicationName)
+            .map(|(key, value)| format!("-c {}={}", key.name(), escape_option_value(value)))
+            .collect();
+        (!options.is_empty()).then(|| options.join(" "))
+    }
+}
+
+// libpq splits `options` on whitespace unless it is backslash-escaped.
+fn escape_option_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if ch == '\\' || ch.is_whitespace() {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_trims_and_clears_blank_values() {
+        let mut settings = SessionSettings::default();
+
+        settings.set(SessionSettingKey::WorkMem, "  64MB ");
+        assert_eq!(settings.get(SessionSettingKey::WorkMem), Some("64MB"));
+        assert!(!settings.is_empty());
+
+        settings.set(SessionSettingKey::WorkMem, "   ");
+        assert_eq!(settings.get(SessionSettingKey::WorkMem), None);
+        assert!(settings.is_empty());
+    }
+
+    #[test]
+    fn startup_options_skip_application_name() {
+        let mut settings = SessionSettings::default();
+        settings.set(SessionSettingKey::ApplicationName, "sabiql");
+        assert_eq!(settings.startup_options(), None);
+
+        settings.set(SessionSettingKey::StatementTimeout, "30s");
+        settings.set(SessionSettingKey::WorkMem, "64MB");
+        assert_eq!(
+            settings.startup_options().as_deref(),
+            Some("-c work_mem=64MB -c statement_timeout=30s")
+        );
+    }
+
+    #[test]
+    fn startup_options_escape_whitespace() {
+        let mut settings = SessionSettings::default();
+        settings.set(SessionSettingKey::SearchPath, "app, public");
+
+        assert_eq!(
+            settings.startup_options().as_deref(),
+            Some("-c search_path=app,\\ public")
+        );
+    }
+
+    #[test]
+    fn empty_settings_serialize_to_empty_object() {
+        let json = serde_json::to_string(&SessionSettings::default()).unwrap();
+
+        assert_eq!(json, "{}");
+    }
+}
@@ -19,6 +19,70 @@ pub struct ErTableInfo {
     pub foreign_keys: Vec<ErFkInfo>,
 }
 
+/// Rendering options that keep large diagrams legible.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErDiagramOptions {
+    /// Tables whose foreign key edges are left out, by qualified or bare name.
+    pub hidden_hubs: Vec<String>,
+    pub color_by_schema: bool,
+    /// Past this many tables, nodes shrink to title-only boxes.
+    pub collapse_above: Option<usize>,
+}
+
+impl ErDiagramOptions {
+    /// Parses `hide=users,audit.events color collapse=40`; `None` on an
+    /// unknown or malformed option.
+    pub fn parse(args: &str) -> Option<Self> {
+        let mut options = Self::default();
+        for token in args.split_whitespace() {
+            match token.split_once('=') {
+                Some(("hide", tables)) => options.hidden_hubs.extend(
+                    tables
+                        .split(',')
+                        .filter(|table| !table.is_empty())
+                        .map(str::to_string),
+                ),
+                Some(("collapse", count)) => options.collapse_above = Some(count.parse().ok()?),
+                None if token == "color" => options.color_by_schema = true,
+                _ => return None,
+            }
+        }
+        Some(options)
+    }
+
+    pub fn hides_edges_of(&self, qualified_name: &str) -> bool {
+        let bare = qualified_name
+            .rsplit_once('.')
+            .map_or(qualified_name, |(_, name)| name);
+        self.hidden_hubs
+            .iter()
+            .any(|hub| hub == qualified_name || hub == bare)
+    }
+
+    pub fn collapses(&self, table_count: usize) -> bool {
+        self.collapse_above.is_some_and(|limit| table_count > limit)
+    }
+
+    /// One-line description for the status bar.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.hidden_hubs.is_empty() {
+            parts.push(format!("hiding edges of {}", self.hidden_hubs.join(", ")));
+        }
+        if self.color_by_schema {
+            parts.push("colored by schema".to_string());
+        }
+        if let Some(limit) = self.collapse_above {
+            parts.push(format!("title-only past {limit} tables"));
+        }
+        if parts.is_empty() {
+            "default layout".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
 pub fn er_output_filename(selected: &[String], total: usize) -> String {
     if selected.is_empty() || selected.len() == total {
         "er_full.dot".to_string()
@@ -176,6 +240,39 @@ impl ErTableInfo {
 mod tests {
     use super::*;
 
+    mod diagram_options {
+        use super::*;
+
+        #[test]
+        fn parse_reads_every_option() {
+            let options = ErDiagramOptions::parse("hide=users,audit.events color collapse=40");
+
+            assert_eq!(
+                options,
+                Some(ErDiagramOptions {
+                    hidden_hubs: vec!["users".to_string(), "audit.events".to_string()],
+                    color_by_schema: true,
+                    collapse_above: Some(40),
+                })
+            );
+        }
+
+        #[test]
+        fn parse_rejects_unknown_or_malformed_options() {
+            assert_eq!(ErDiagramOptions::parse("colour"), None);
+            assert_eq!(ErDiagramOptions::parse("collapse=many"), None);
+        }
+
+        #[test]
+        fn hub_matches_bare_and_qualified_names() {
+            let options = ErDiagramOptions::parse("hide=users,audit.events").unwrap();
+
+            assert!(options.hides_edges_of("public.users"));
+            assert!(options.hides_edges_of("audit.events"));
+            assert!(!options.hides_edges_of("public.events"));
+        }
+    }
+
     mod from_table {
         use super::*;
         use crate::{FkAction, ForeignKey, TableKindInfo};
@@ -195,6 +292,9 @@ mod tests {
                 comment: None,
                 source_ddl: None,
                 kind_info: TableKindInfo::default(),
+                hypertable: None,
+                column_stats: Vec::new(),
+                storage: None,
             }
         }
 
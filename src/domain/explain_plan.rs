@@ -95,6 +95,79 @@ impl ExplainPlan {
     }
 }
 
+/// Root-node totals from `EXPLAIN (ANALYZE, BUFFERS)`. Buffer counts are in
+/// blocks; I/O times are only reported with `track_io_timing` on.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceUsage {
+    pub shared_hit: u64,
+    pub shared_read: u64,
+    pub temp_read: u64,
+    pub temp_written: u64,
+    pub io_read_ms: Option<f64>,
+    pub io_write_ms: Option<f64>,
+}
+
+impl ResourceUsage {
+    pub fn io_time_ms(&self) -> Option<f64> {
+        match (self.io_read_ms, self.io_write_ms) {
+            (None, None) => None,
+            (read, write) => Some(read.unwrap_or_default() + write.unwrap_or_default()),
+        }
+    }
+}
+
+fn plan_counters(body: &str) -> impl Iterator<Item = (Option<&str>, &str, &str)> {
+    body.split(',').flat_map(|group| {
+        let mut tokens = group.split_whitespace().peekable();
+        let kind = tokens.next_if(|token| !token.contains('='));
+        tokens.filter_map(move |token| {
+            let (key, value) = token.split_once('=')?;
+            Some((kind, key, value))
+        })
+    })
+}
+
+/// Reads the root node's `Buffers:` and `I/O Timings:` lines, i.e. those
+/// before the first child node and the planning section.
+pub fn parse_resource_usage(text: &str) -> Option<ResourceUsage> {
+    let mut usage = ResourceUsage::default();
+    let mut found = false;
+    for line in text.lines().skip(1) {
+        let line = line.trim();
+        if line.starts_with("->") || line.starts_with("Planning") {
+            break;
+        }
+        if let Some(body) = line.strip_prefix("Buffers:") {
+            found = true;
+            for (kind, key, value) in plan_counters(body) {
+                let Ok(blocks) = value.parse::<u64>() else {
+                    continue;
+                };
+                match (kind, key) {
+                    (Some("shared"), "hit") => usage.shared_hit += blocks,
+                    (Some("shared"), "read") => usage.shared_read += blocks,
+                    (Some("temp"), "read") => usage.temp_read += blocks,
+                    (Some("temp"), "written") => usage.temp_written += blocks,
+                    _ => {}
+                }
+            }
+        } else if let Some(body) = line.strip_prefix("I/O Timings:") {
+            found = true;
+            for (_, key, value) in plan_counters(body) {
+                let Ok(ms) = value.parse::<f64>() else {
+                    continue;
+                };
+                match key {
+                    "read" => *usage.io_read_ms.get_or_insert(0.0) += ms,
+                    "write" => *usage.io_write_ms.get_or_insert(0.0) += ms,
+                    _ => {}
+                }
+            }
+        }
+    }
+    found.then_some(usage)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ComparisonVerdict {
     Improved,
@@ -311,6 +384,50 @@ Execution Time: 0.600 ms";
         }
     }
 
+    mod resource_usage {
+        use super::*;
+
+        #[test]
+        fn root_buffers_and_io_timings_are_read() {
+            let text = "\
+Hash Join  (cost=1.00..2.00 rows=10 width=8) (actual time=0.1..5.2 rows=10 loops=1)
+  Buffers: shared hit=120 read=30 dirtied=2, temp read=64 written=64
+  I/O Timings: shared read=3.250 write=0.500, temp read=1.000
+  ->  Seq Scan on orders  (cost=0.00..1.00 rows=10 width=8)
+        Buffers: shared hit=100
+Planning:
+  Buffers: shared hit=7
+Execution Time: 5.300 ms";
+
+            let usage = parse_resource_usage(text).unwrap();
+
+            assert_eq!(usage.shared_hit, 120);
+            assert_eq!(usage.shared_read, 30);
+            assert_eq!(usage.temp_read, 64);
+            assert_eq!(usage.temp_written, 64);
+            assert_eq!(usage.io_read_ms, Some(4.25));
+            assert_eq!(usage.io_write_ms, Some(0.5));
+        }
+
+        #[test]
+        fn pre_16_io_timings_without_kind_are_read() {
+            let text = "Seq Scan on users  (cost=0.00..1.00 rows=1 width=4)\n  Buffers: shared read=4\n  I/O Timings: read=0.250";
+
+            let usage = parse_resource_usage(text).unwrap();
+
+            assert_eq!(usage.shared_read, 4);
+            assert_eq!(usage.io_time_ms(), Some(0.25));
+        }
+
+        #[test]
+        fn planning_buffers_alone_are_not_the_query_usage() {
+            let text =
+                "Result  (cost=0.00..0.01 rows=1 width=4)\nPlanning:\n  Buffers: shared hit=3";
+
+            assert_eq!(parse_resource_usage(text), None);
+        }
+    }
+
     mod compare {
         use super::*;
 
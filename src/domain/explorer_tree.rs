@@ -0,0 +1,152 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::schema_object::SchemaObjectKind;
+
+/// Per-project Explorer tree layout.
+///
+/// Schemas start expanded and object sections folded, so only the exceptions
+/// are kept, along with the tables starred as favorites and the columns
+/// chosen for a table's preview.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExplorerTreeLayout {
+    #[serde(default)]
+    collapsed_schemas: BTreeSet<String>,
+    #[serde(default)]
+    expanded_sections: BTreeSet<(String, SchemaObjectKind)>,
+    #[serde(default)]
+    favorite_tables: BTreeSet<String>,
+    #[serde(default)]
+    preview_columns: BTreeMap<String, Vec<String>>,
+}
+
+impl ExplorerTreeLayout {
+    pub fn is_collapsed(&self, schema: &str) -> bool {
+        self.collapsed_schemas.contains(schema)
+    }
+
+    pub fn toggle(&mut self, schema: &str) {
+        if !self.collapsed_schemas.remove(schema) {
+            self.collapsed_schemas.insert(schema.to_string());
+        }
+    }
+
+    pub fn is_section_expanded(&self, schema: &str, kind: SchemaObjectKind) -> bool {
+        self.expanded_sections.contains(&(schema.to_string(), kind))
+    }
+
+    pub fn toggle_section(&mut self, schema: &str, kind: SchemaObjectKind) {
+        let key = (schema.to_string(), kind);
+        if !self.expanded_sections.remove(&key) {
+            self.expanded_sections.insert(key);
+        }
+    }
+
+    pub fn is_favorite(&self, qualified_name: &str) -> bool {
+        self.favorite_tables.contains(qualified_name)
+    }
+
+    /// Stars or unstars `schema.table`; returns whether it is now a favorite.
+    pub fn toggle_favorite(&mut self, qualified_name: &str) -> bool {
+        if self.favorite_tables.remove(qualified_name) {
+            false
+        } else {
+            self.favorite_tables.insert(qualified_name.to_string());
+            true
+        }
+    }
+
+    /// Columns the preview of `schema.table` selects; empty means all of them.
+    pub fn preview_columns(&self, qualified_name: &str) -> &[String] {
+        self.preview_columns
+            .get(qualified_name)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// An empty `columns` goes back to selecting every column.
+    pub fn set_preview_columns(&mut self, qualified_name: &str, columns: Vec<String>) {
+        if columns.is_empty() {
+            self.preview_columns.remove(qualified_name);
+        } else {
+            self.preview_columns
+                .insert(qualified_name.to_string(), columns);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_collapses_then_expands() {
+        let mut layout = ExplorerTreeLayout::default();
+
+        layout.toggle("audit");
+        assert!(layout.is_collapsed("audit"));
+        assert!(!layout.is_collapsed("public"));
+
+        layout.toggle("audit");
+        assert!(!layout.is_collapsed("audit"));
+    }
+
+    #[test]
+    fn sections_start_folded_per_schema() {
+        let mut layout = ExplorerTreeLayout::default();
+
+        layout.toggle_section("public", SchemaObjectKind::View);
+
+        assert!(layout.is_section_expanded("public", SchemaObjectKind::View));
+        assert!(!layout.is_section_expanded("audit", SchemaObjectKind::View));
+        assert!(!layout.is_section_expanded("public", SchemaObjectKind::Function));
+    }
+
+    #[test]
+    fn expanded_sections_round_trip_through_json() {
+        let mut layout = ExplorerTreeLayout::default();
+        layout.toggle_section("public", SchemaObjectKind::Function);
+
+        let json = serde_json::to_string(&layout).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<ExplorerTreeLayout>(&json).unwrap(),
+            layout
+        );
+    }
+
+    #[test]
+    fn favorites_toggle_and_round_trip_through_json() {
+        let mut layout = ExplorerTreeLayout::default();
+
+        assert!(layout.toggle_favorite("public.orders"));
+        let json = serde_json::to_string(&layout).unwrap();
+        let restored: ExplorerTreeLayout = serde_json::from_str(&json).unwrap();
+
+        assert!(restored.is_favorite("public.orders"));
+        assert!(!layout.toggle_favorite("public.orders"));
+        assert!(!layout.is_favorite("public.orders"));
+    }
+
+    #[test]
+    fn preview_columns_round_trip_and_clear() {
+        let mut layout = ExplorerTreeLayout::default();
+        let columns = vec!["id".to_string(), "name".to_string()];
+
+        layout.set_preview_columns("public.users", columns.clone());
+        let json = serde_json::to_string(&layout).unwrap();
+        let mut restored: ExplorerTreeLayout = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.preview_columns("public.users"), columns);
+        assert!(restored.preview_columns("public.orders").is_empty());
+        restored.set_preview_columns("public.users", Vec::new());
+        assert_eq!(restored, ExplorerTreeLayout::default());
+    }
+
+    #[test]
+    fn missing_field_deserializes_as_all_expanded() {
+        let layout: ExplorerTreeLayout = serde_json::from_str("{}").unwrap();
+
+        assert_eq!(layout, ExplorerTreeLayout::default());
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// TimescaleDB hypertable details; chunks are the child tables that hold
+/// the data for each time range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hypertable {
+    pub time_column: Option<String>,
+    pub chunk_count: i64,
+    pub compression_enabled: bool,
+    pub compressed_chunk_count: i64,
+    /// `drop_after` interval of the retention policy, when one is scheduled.
+    pub retention: Option<String>,
+}
+
+impl Hypertable {
+    pub fn compression_label(&self) -> String {
+        if self.compression_enabled {
+            format!(
+                "enabled ({}/{} chunks compressed)",
+                self.compressed_chunk_count, self.chunk_count
+            )
+        } else {
+            "disabled".to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypertable(compression_enabled: bool) -> Hypertable {
+        Hypertable {
+            time_column: Some("time".to_string()),
+            chunk_count: 12,
+            compression_enabled,
+            compressed_chunk_count: 9,
+            retention: None,
+        }
+    }
+
+    #[test]
+    fn compression_label_counts_compressed_chunks() {
+        assert_eq!(
+            hypertable(true).compression_label(),
+            "enabled (9/12 chunks compressed)"
+        );
+        assert_eq!(hypertable(false).compression_label(), "disabled");
+    }
+}
@@ -0,0 +1,79 @@
+use crate::query_result::QueryResult;
+
+/// One `pg_stat_user_indexes` row: how often the planner has used an index
+/// since the statistics were last reset, and what it costs on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexUsage {
+    pub schema: String,
+    pub table: String,
+    pub name: String,
+    pub scans: i64,
+    pub size_bytes: i64,
+    pub unique: bool,
+}
+
+impl IndexUsage {
+    /// Reads `schema, table_name, index_name, scans, size_bytes, is_unique`
+    /// rows.
+    pub fn list_from_result(result: &QueryResult) -> Vec<Self> {
+        (0..result.data_row_count())
+            .filter_map(|row| {
+                let text = |col| result.display_value_at(row, col).unwrap_or_default();
+                Some(Self {
+                    schema: text(0),
+                    table: text(1),
+                    name: result.display_value_at(row, 2)?,
+                    scans: text(3).parse().unwrap_or(0),
+                    size_bytes: text(4).parse().unwrap_or(0),
+                    unique: matches!(text(5).as_str(), "t" | "true"),
+                })
+            })
+            .collect()
+    }
+
+    /// Never scanned and not backing a unique constraint, so dropping it
+    /// only costs the planner an option it has not taken.
+    pub fn is_unused(&self) -> bool {
+        self.scans == 0 && !self.unique
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_result::QuerySource;
+
+    #[test]
+    fn rows_become_index_usage() {
+        let result = QueryResult::success(
+            "INDEX USAGE".to_string(),
+            [
+                "schema",
+                "table_name",
+                "index_name",
+                "scans",
+                "size_bytes",
+                "is_unique",
+            ]
+            .map(str::to_string)
+            .to_vec(),
+            vec![
+                ["public", "users", "users_pkey", "0", "16384", "t"]
+                    .map(str::to_string)
+                    .to_vec(),
+                ["public", "users", "users_name_idx", "0", "8192", "f"]
+                    .map(str::to_string)
+                    .to_vec(),
+            ],
+            1,
+            QuerySource::Adhoc,
+        );
+
+        let indexes = IndexUsage::list_from_result(&result);
+
+        assert_eq!(indexes.len(), 2);
+        assert_eq!(indexes[0].size_bytes, 16384);
+        assert!(!indexes[0].is_unused());
+        assert!(indexes[1].is_unused());
+    }
+}
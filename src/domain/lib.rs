@@ -1,43 +1,67 @@
 // Domain models - fields/methods defined to match DB schema
 
+pub mod audit;
 pub mod column;
+pub mod column_stats;
 pub mod command_tag;
 pub mod connection;
 pub mod er;
 pub mod explain_plan;
+pub mod explorer_tree;
 pub mod foreign_key;
+pub mod hypertable;
 pub mod index;
+pub mod index_usage;
+pub mod lock_conflict;
+pub mod maintenance;
 pub mod metadata;
 pub mod query_history;
 pub mod query_result;
 pub mod rls;
 pub mod schema;
+pub mod schema_object;
+pub mod server_setting;
 pub mod sqlite_diagnostics;
 pub mod table;
 pub mod table_kind;
+pub mod table_storage;
 pub mod trigger;
+pub mod usage_stats;
 pub mod write_result;
 
+pub use audit::{AuditEntry, AuditEvent};
 pub use column::{Column, ColumnAttributes};
+pub use column_stats::{ColumnDistribution, ColumnStats, CommonValue};
 pub use command_tag::CommandTag;
+pub use er::ErDiagramOptions;
 #[cfg(test)]
 pub use er::ErFkInfo;
 pub use er::ErTableInfo;
 pub use explain_plan::sqlite_explain_query_plan_text_from_result;
+pub use explorer_tree::ExplorerTreeLayout;
 pub use foreign_key::{FkAction, ForeignKey, UNRESOLVED_FK_COLUMN};
+pub use hypertable::Hypertable;
 pub use index::{Index, IndexAttributes, IndexType};
-pub use metadata::{DatabaseMetadata, MetadataState};
-pub use query_result::{QueryResult, QuerySource, QueryValue};
-pub use rls::{RlsCommand, RlsInfo, RlsPolicy};
+pub use index_usage::IndexUsage;
+pub use lock_conflict::{LockBlocker, LockConflict, LockConflictKind};
+pub use maintenance::{IndexMaintenanceKind, MaintenanceKind};
+pub use metadata::{DatabaseMetadata, MetadataSnapshot, MetadataState};
+pub use query_result::{QueryResult, QuerySource, QueryValue, StatementTiming};
+pub use rls::{PUBLIC_ROLE, RlsCommand, RlsInfo, RlsPolicy};
 pub use schema::Schema;
+pub use schema_object::{SchemaObject, SchemaObjectKind};
+pub use server_setting::ServerSetting;
 pub use sqlite_diagnostics::{DiagnosticField, SqliteDiagnosticsSnapshot};
 pub use table::{Table, TableSignature, TableSummary};
 pub use table_kind::{TableKind, TableKindInfo};
+pub use table_storage::TableStorage;
 pub use trigger::{Trigger, TriggerEvent, TriggerTiming};
+pub use usage_stats::{UsageKind, UsageStats};
 pub use write_result::WriteExecutionResult;
 
 pub use connection::{
-    ConnectionConfig, ConnectionId, ConnectionProfile, ConnectionProfileError, DatabaseType,
-    PostgresConnectionConfig, SqliteConnectionConfig, SqliteConnectionConfigError, SqlitePathError,
-    SslMode, classify_sqlite_metadata_error, classify_sqlite_read_error, sqlite_path_from_dsn,
+    ConnectionConfig, ConnectionId, ConnectionProfile, ConnectionProfileError, DatabaseType, Dsn,
+    DsnError, PoolMode, PoolSettings, PostgresConnectionConfig, ProfileAccent, SessionSettingKey,
+    SessionSettings, SqliteConnectionConfig, SqliteConnectionConfigError, SqlitePathError, SslMode,
+    classify_sqlite_metadata_error, classify_sqlite_read_error, sqlite_path_from_dsn,
 };
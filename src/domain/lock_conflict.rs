@@ -0,0 +1,192 @@
+use crate::query_result::QueryResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockConflictKind {
+    LockTimeout,
+    Deadlock,
+}
+
+impl LockConflictKind {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::LockTimeout => "Lock timeout",
+            Self::Deadlock => "Deadlock",
+        }
+    }
+}
+
+/// What a lock failure's error text reveals about the other side: deadlock
+/// reports name the blocking backends, lock waits sometimes name the relation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockConflict {
+    pub kind: LockConflictKind,
+    pub blocker_pids: Vec<i32>,
+    pub relation: Option<String>,
+}
+
+impl LockConflict {
+    pub fn from_error_details(kind: LockConflictKind, details: &str) -> Self {
+        let mut blocker_pids: Vec<i32> = details
+            .split("blocked by process ")
+            .skip(1)
+            .filter_map(|rest| {
+                let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+                digits.parse().ok()
+            })
+            .collect();
+        blocker_pids.sort_unstable();
+        blocker_pids.dedup();
+
+        let relation = details.split("relation \"").nth(1).and_then(|rest| {
+            rest.split_once('"')
+                .map(|(name, _)| name.to_string())
+                .filter(|name| !name.is_empty())
+        });
+
+        Self {
+            kind,
+            blocker_pids,
+            relation,
+        }
+    }
+}
+
+/// A backend that held a lock the failed statement needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockBlocker {
+    pub pid: i32,
+    pub application_name: String,
+    pub user: String,
+    pub state: String,
+    /// Seconds since the blocker's transaction (or query) started.
+    pub held_secs: Option<u64>,
+    pub query: String,
+}
+
+impl LockBlocker {
+    /// Reads `pid, application_name, usename, state, held_secs, query` rows.
+    pub fn list_from_result(result: &QueryResult) -> Vec<Self> {
+        (0..result.data_row_count())
+            .filter_map(|row| {
+                let text = |col| result.display_value_at(row, col).unwrap_or_default();
+                let pid = text(0).parse().ok()?;
+                Some(Self {
+                    pid,
+                    application_name: text(1),
+                    user: text(2),
+                    state: text(3),
+                    held_secs: text(4).parse().ok(),
+                    query: text(5),
+                })
+            })
+            .collect()
+    }
+
+    pub fn held_display(&self) -> Option<String> {
+        let secs = self.held_secs?;
+        Some(match secs {
+            0..60 => format!("{secs}s"),
+            60..3600 => format!("{}m{:02}s", secs / 60, secs % 60),
+            _ => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+        })
+    }
+
+    /// One-line answer to "who was blocking me": pid, app, user, state,
+    /// how long, and the blocker's current query.
+    pub fn summary(&self) -> String {
+        let mut parts = vec![format!("pid {}", self.pid)];
+        if !self.application_name.is_empty() {
+            parts.push(self.application_name.clone());
+        }
+        if !self.user.is_empty() {
+            parts.push(self.user.clone());
+        }
+        let state = match self.held_display() {
+            Some(held) if self.state.is_empty() => held,
+            Some(held) => format!("{} {held}", self.state),
+            None => self.state.clone(),
+        };
+        if !state.is_empty() {
+            parts.push(state);
+        }
+        let query = self.query.split_whitespace().collect::<Vec<_>>().join(" ");
+        if query.is_empty() {
+            parts.join(" · ")
+        } else {
+            format!("{}: {query}", parts.join(" · "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_result::QuerySource;
+    use rstest::rstest;
+
+    #[test]
+    fn deadlock_details_name_blocking_pids() {
+        let details = "ERROR:  40P01: deadlock detected\n\
+             DETAIL:  Process 4100 waits for ShareLock on transaction 812; blocked by process 4123.\n\
+             Process 4123 waits for ShareLock on transaction 811; blocked by process 4100.\n\
+             CONTEXT:  while updating tuple (0,1) in relation \"accounts\"";
+
+        let conflict = LockConflict::from_error_details(LockConflictKind::Deadlock, details);
+
+        assert_eq!(conflict.blocker_pids, vec![4100, 4123]);
+        assert_eq!(conflict.relation.as_deref(), Some("accounts"));
+    }
+
+    #[test]
+    fn lock_timeout_without_context_has_no_hints() {
+        let conflict = LockConflict::from_error_details(
+            LockConflictKind::LockTimeout,
+            "ERROR:  55P03: canceling statement due to lock timeout",
+        );
+
+        assert!(conflict.blocker_pids.is_empty());
+        assert_eq!(conflict.relation, None);
+    }
+
+    #[test]
+    fn blockers_are_read_from_result_rows() {
+        let result = QueryResult::success(
+            String::new(),
+            vec![],
+            vec![vec![
+                "4123".to_string(),
+                "worker".to_string(),
+                "app".to_string(),
+                "idle in transaction".to_string(),
+                "133".to_string(),
+                "UPDATE accounts\n   SET balance = 0".to_string(),
+            ]],
+            1,
+            QuerySource::Adhoc,
+        );
+
+        let blockers = LockBlocker::list_from_result(&result);
+
+        assert_eq!(
+            blockers[0].summary(),
+            "pid 4123 · worker · app · idle in transaction 2m13s: UPDATE accounts SET balance = 0"
+        );
+    }
+
+    #[rstest]
+    #[case(Some(7), Some("7s"))]
+    #[case(Some(3600 + 120), Some("1h02m"))]
+    #[case(None, None)]
+    fn held_display_picks_largest_unit(#[case] secs: Option<u64>, #[case] expected: Option<&str>) {
+        let blocker = LockBlocker {
+            pid: 1,
+            application_name: String::new(),
+            user: String::new(),
+            state: String::new(),
+            held_secs: secs,
+            query: String::new(),
+        };
+
+        assert_eq!(blocker.held_display().as_deref(), expected);
+    }
+}
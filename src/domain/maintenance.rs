@@ -0,0 +1,39 @@
+/// Table maintenance commands launched from the command palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaintenanceKind {
+    VacuumAnalyze,
+    Analyze,
+}
+
+impl MaintenanceKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::VacuumAnalyze => "VACUUM (ANALYZE)",
+            Self::Analyze => "ANALYZE",
+        }
+    }
+}
+
+/// Index rebuild strategies launched from the Indexes tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexMaintenanceKind {
+    ReindexConcurrently,
+    /// Plain REINDEX blocks writes to the table until it finishes.
+    Reindex,
+    /// CREATE INDEX CONCURRENTLY a copy, then drop the original and swap names.
+    Rebuild,
+}
+
+impl IndexMaintenanceKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ReindexConcurrently => "REINDEX CONCURRENTLY",
+            Self::Reindex => "REINDEX",
+            Self::Rebuild => "Index rebuild",
+        }
+    }
+
+    pub fn is_concurrent(self) -> bool {
+        !matches!(self, Self::Reindex)
+    }
+}
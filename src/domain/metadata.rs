@@ -1,12 +1,18 @@
+use serde::{Deserialize, Serialize};
+
 use super::schema::Schema;
-use super::table::TableSummary;
+use super::schema_object::SchemaObject;
+use super::table::{Table, TableSummary};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatabaseMetadata {
     pub database_name: String,
     pub schemas: Vec<Schema>,
     pub table_summaries: Vec<TableSummary>,
+    /// Views, sequences and functions; only PostgreSQL reports these.
+    #[serde(default)]
+    pub objects: Vec<SchemaObject>,
 }
 
 impl DatabaseMetadata {
@@ -16,6 +22,7 @@ impl DatabaseMetadata {
             database_name,
             schemas: Vec::new(),
             table_summaries: Vec::new(),
+            objects: Vec::new(),
         }
     }
 
@@ -28,6 +35,22 @@ impl DatabaseMetadata {
     }
 }
 
+/// Last metadata and table details seen for a connection, persisted so the
+/// explorer and inspector can be browsed while the database is unreachable.
+#[derive(Debug, Clone)]
+pub struct MetadataSnapshot {
+    pub metadata: DatabaseMetadata,
+    pub tables: Vec<Table>,
+}
+
+impl MetadataSnapshot {
+    pub fn table(&self, schema: &str, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|table| table.schema == schema && table.name == name)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum MetadataState {
     #[default]
@@ -67,4 +90,43 @@ mod tests {
             assert!(meta.tables_by_schema().is_empty());
         }
     }
+
+    mod snapshot {
+        use super::*;
+        use crate::TableKindInfo;
+
+        fn make_table(schema: &str, name: &str) -> Table {
+            Table {
+                schema: schema.to_string(),
+                name: name.to_string(),
+                owner: None,
+                columns: Vec::new(),
+                primary_key: None,
+                foreign_keys: Vec::new(),
+                indexes: Vec::new(),
+                rls: None,
+                triggers: Vec::new(),
+                row_count_estimate: None,
+                comment: None,
+                source_ddl: None,
+                kind_info: TableKindInfo::default(),
+                hypertable: None,
+                column_stats: Vec::new(),
+                storage: None,
+            }
+        }
+
+        #[test]
+        fn table_matches_schema_and_name() {
+            let snapshot = MetadataSnapshot {
+                metadata: DatabaseMetadata::new("testdb".to_string()),
+                tables: vec![make_table("public", "users"), make_table("audit", "users")],
+            };
+
+            let found = snapshot.table("audit", "users").unwrap();
+
+            assert_eq!(found.schema, "audit");
+            assert!(snapshot.table("public", "orders").is_none());
+        }
+    }
 }
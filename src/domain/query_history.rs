@@ -41,6 +41,9 @@ pub struct QueryHistoryEntry {
     pub connection_id: ConnectionId,
     pub result_status: QueryResultStatus,
     pub affected_rows: Option<u64>,
+    /// Absent for failures and for entries written before it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
 }
 
 impl QueryHistoryEntry {
@@ -57,8 +60,15 @@ impl QueryHistoryEntry {
             connection_id,
             result_status,
             affected_rows,
+            duration_ms: None,
         }
     }
+
+    #[must_use]
+    pub fn with_duration_ms(mut self, duration_ms: u64) -> Self {
+        self.duration_ms = Some(duration_ms);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -116,4 +126,33 @@ mod tests {
         assert!(json.contains("\"connection_id\":\"abc-123\""));
         assert!(json.contains("\"result_status\":\"Success\""));
     }
+
+    #[test]
+    fn entries_without_duration_still_parse() {
+        let json = r#"{"query":"SELECT 1","executed_at":"2026-03-13T12:00:00Z","connection_id":"abc-123","result_status":"Success","affected_rows":null}"#;
+
+        let entry: QueryHistoryEntry = serde_json::from_str(json).unwrap();
+
+        assert_eq!(entry.duration_ms, None);
+    }
+
+    #[test]
+    fn duration_round_trips() {
+        let entry = QueryHistoryEntry::new(
+            "SELECT 1".to_string(),
+            "2026-03-13T12:00:00Z".to_string(),
+            ConnectionId::from_string("abc-123"),
+            QueryResultStatus::Success,
+            None,
+        )
+        .with_duration_ms(42);
+
+        let json = serde_json::to_string(&entry).unwrap();
+
+        assert!(json.contains("\"duration_ms\":42"));
+        assert_eq!(
+            serde_json::from_str::<QueryHistoryEntry>(&json).unwrap(),
+            entry
+        );
+    }
 }
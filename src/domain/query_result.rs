@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::time::Duration;
 
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
@@ -222,6 +223,16 @@ pub enum QuerySource {
     Adhoc,
 }
 
+/// One statement of a multi-statement script, as reported by the adapter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementTiming {
+    pub sql: String,
+    pub elapsed: Duration,
+    pub rows: Option<u64>,
+    /// Server command status, e.g. `INSERT 0 3` or `CREATE TABLE`.
+    pub status: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct QueryResult {
     pub query: String,
@@ -234,6 +245,7 @@ pub struct QueryResult {
     values: Vec<Vec<QueryValue>>,
     row_count: usize,
     typed_values: bool,
+    statement_timings: Vec<StatementTiming>,
 }
 
 impl QueryResult {
@@ -261,6 +273,7 @@ impl QueryResult {
             source,
             error: None,
             command_tag: None,
+            statement_timings: Vec::new(),
         }
     }
 
@@ -284,6 +297,7 @@ impl QueryResult {
             source,
             error: None,
             command_tag: None,
+            statement_timings: Vec::new(),
         }
     }
 
@@ -305,6 +319,7 @@ impl QueryResult {
             source,
             error: Some(error),
             command_tag: None,
+            statement_timings: Vec::new(),
         }
     }
 
@@ -314,6 +329,17 @@ impl QueryResult {
         self
     }
 
+    #[must_use]
+    pub fn with_statement_timings(mut self, timings: Vec<StatementTiming>) -> Self {
+        self.statement_timings = timings;
+        self
+    }
+
+    #[must_use]
+    pub fn statement_timings(&self) -> &[StatementTiming] {
+        &self.statement_timings
+    }
+
     #[must_use]
     pub fn with_row_count(mut self, row_count: usize) -> Self {
         self.row_count = row_count;
@@ -357,6 +383,28 @@ impl QueryResult {
         self
     }
 
+    /// Replaces every non-NULL value in `columns` with `mask`.
+    #[must_use]
+    pub fn with_masked_columns(mut self, columns: &[usize], mask: &str) -> Self {
+        for values in &mut self.values {
+            for &col in columns {
+                if let Some(value) = values.get_mut(col)
+                    && *value != QueryValue::Null
+                {
+                    *value = QueryValue::text(mask);
+                }
+            }
+        }
+        for row in &mut self.rows {
+            for &col in columns {
+                if let Some(value) = row.get_mut(col) {
+                    mask.clone_into(value);
+                }
+            }
+        }
+        self
+    }
+
     #[must_use]
     pub fn has_typed_values(&self) -> bool {
         self.typed_values
@@ -577,6 +625,29 @@ mod tests {
             assert_eq!(result.values(), &[vec![QueryValue::text("body")]]);
             assert_eq!(result.display_row_at(0), Some(vec!["body".to_string()]));
         }
+
+        #[test]
+        fn masking_keeps_nulls_and_other_columns() {
+            let result = QueryResult::success_with_values(
+                "SELECT id, token".to_string(),
+                vec!["id".to_string(), "token".to_string()],
+                vec![
+                    vec![QueryValue::text("1"), QueryValue::text("abc")],
+                    vec![QueryValue::text("2"), QueryValue::Null],
+                ],
+                0,
+                QuerySource::Adhoc,
+            )
+            .with_masked_columns(&[1], "***");
+
+            assert_eq!(
+                result.values(),
+                &[
+                    vec![QueryValue::text("1"), QueryValue::text("***")],
+                    vec![QueryValue::text("2"), QueryValue::Null],
+                ]
+            );
+        }
     }
 
     mod row_count_display {
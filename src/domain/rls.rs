@@ -1,13 +1,15 @@
 use std::str::FromStr;
 
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RlsInfo {
     pub enabled: bool,
     pub force: bool,
     pub policies: Vec<RlsPolicy>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RlsPolicy {
     pub name: String,
     pub permissive: bool,
@@ -17,7 +19,7 @@ pub struct RlsPolicy {
     pub with_check: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum RlsCommand {
     #[default]
     All,
@@ -35,8 +37,38 @@ impl RlsInfo {
             (false, _) => "DISABLED",
         }
     }
+
+    /// Every role some policy applies to, sorted and deduplicated.
+    pub fn roles(&self) -> Vec<&str> {
+        let mut roles: Vec<&str> = self
+            .policies
+            .iter()
+            .flat_map(RlsPolicy::role_names)
+            .collect();
+        roles.sort_unstable();
+        roles.dedup();
+        roles
+    }
+}
+
+impl RlsPolicy {
+    /// A policy created without `TO` applies to `public`.
+    pub fn role_names(&self) -> Vec<&str> {
+        if self.roles.is_empty() {
+            vec![PUBLIC_ROLE]
+        } else {
+            self.roles.iter().map(String::as_str).collect()
+        }
+    }
+
+    pub fn applies_to(&self, role: &str) -> bool {
+        self.role_names().contains(&role)
+    }
 }
 
+/// The pseudo-role every database role belongs to.
+pub const PUBLIC_ROLE: &str = "public";
+
 impl std::fmt::Display for RlsCommand {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -110,4 +142,32 @@ mod tests {
             Err(ParseRlsCommandError::Invalid { .. })
         ));
     }
+
+    fn policy(name: &str, roles: &[&str]) -> RlsPolicy {
+        RlsPolicy {
+            name: name.to_string(),
+            permissive: true,
+            roles: roles.iter().map(ToString::to_string).collect(),
+            cmd: RlsCommand::All,
+            qual: None,
+            with_check: None,
+        }
+    }
+
+    #[test]
+    fn roles_are_sorted_and_policies_without_roles_apply_to_public() {
+        let info = RlsInfo {
+            enabled: true,
+            force: false,
+            policies: vec![
+                policy("tenant", &["app_user", "admin"]),
+                policy("audit", &["admin"]),
+                policy("open", &[]),
+            ],
+        };
+
+        assert_eq!(info.roles(), vec!["admin", "app_user", "public"]);
+        assert!(info.policies[2].applies_to(PUBLIC_ROLE));
+        assert!(!info.policies[1].applies_to("app_user"));
+    }
 }
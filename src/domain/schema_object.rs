@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+/// Non-table objects listed under typed sections in the Explorer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum SchemaObjectKind {
+    View,
+    MaterializedView,
+    Sequence,
+    Function,
+}
+
+impl SchemaObjectKind {
+    /// Section order under each schema.
+    pub const ALL: [Self; 4] = [
+        Self::View,
+        Self::MaterializedView,
+        Self::Sequence,
+        Self::Function,
+    ];
+
+    pub fn section_label(self) -> &'static str {
+        match self {
+            Self::View => "Views",
+            Self::MaterializedView => "Materialized Views",
+            Self::Sequence => "Sequences",
+            Self::Function => "Functions",
+        }
+    }
+
+    /// Inspector tab shown for objects of this kind.
+    pub fn inspector_label(self) -> &'static str {
+        match self {
+            Self::Function => "Signature",
+            Self::View | Self::MaterializedView | Self::Sequence => "Definition",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaObject {
+    pub schema: String,
+    pub name: String,
+    pub kind: SchemaObjectKind,
+    /// Identity arguments of a function; tells overloads apart.
+    #[serde(default)]
+    pub arguments: Option<String>,
+    /// View query, sequence options or function signature as SQL.
+    #[serde(default)]
+    pub definition: Option<String>,
+}
+
+impl SchemaObject {
+    /// `name` for relations, `name(args)` for functions.
+    pub fn display_name(&self) -> String {
+        match &self.arguments {
+            Some(arguments) => format!("{}({arguments})", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    pub fn qualified_name(&self) -> String {
+        format!("{}.{}", self.schema, self.display_name())
+    }
+
+    /// Return type from a function's signature definition; procedures have none.
+    pub fn function_result(&self) -> Option<&str> {
+        let signature = self.definition.as_deref()?;
+        let signature = signature
+            .rsplit_once(" LANGUAGE ")
+            .map_or(signature, |(head, _)| head);
+        signature.rsplit_once(" RETURNS ").map(|(_, result)| result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(kind: SchemaObjectKind, arguments: Option<&str>) -> SchemaObject {
+        SchemaObject {
+            schema: "public".to_string(),
+            name: "total".to_string(),
+            kind,
+            arguments: arguments.map(str::to_string),
+            definition: None,
+        }
+    }
+
+    #[test]
+    fn function_names_include_their_arguments() {
+        let function = object(SchemaObjectKind::Function, Some("integer, text"));
+
+        assert_eq!(function.qualified_name(), "public.total(integer, text)");
+    }
+
+    #[test]
+    fn function_result_is_read_from_the_signature() {
+        let mut function = object(SchemaObjectKind::Function, Some("integer"));
+        function.definition = Some(
+            "FUNCTION public.total(n integer) RETURNS TABLE(id integer) LANGUAGE sql".to_string(),
+        );
+        let mut procedure = object(SchemaObjectKind::Function, Some("integer"));
+        procedure.definition =
+            Some("PROCEDURE public.total(n integer) LANGUAGE plpgsql".to_string());
+
+        assert_eq!(function.function_result(), Some("TABLE(id integer)"));
+        assert_eq!(procedure.function_result(), None);
+    }
+
+    #[test]
+    fn relations_use_the_bare_name() {
+        let view = object(SchemaObjectKind::View, None);
+
+        assert_eq!(view.qualified_name(), "public.total");
+    }
+}
@@ -0,0 +1,159 @@
+use crate::query_result::QueryResult;
+
+const MEMORY_UNITS: &[(&str, i64)] = &[
+    ("TB", 1 << 40),
+    ("GB", 1 << 30),
+    ("MB", 1 << 20),
+    ("kB", 1 << 10),
+];
+const TIME_UNITS: &[(&str, i64)] = &[
+    ("d", 86_400_000),
+    ("h", 3_600_000),
+    ("min", 60_000),
+    ("s", 1000),
+];
+
+/// One `pg_settings` row. `reset_val` is what `RESET` returns to for this
+/// session; `boot_val` is the compiled-in default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerSetting {
+    pub name: String,
+    pub setting: String,
+    pub unit: Option<String>,
+    pub reset_val: String,
+    pub boot_val: String,
+    pub source: String,
+    pub category: String,
+    pub short_desc: String,
+    pub pending_restart: bool,
+}
+
+impl ServerSetting {
+    /// Reads `name, setting, unit, reset_val, boot_val, source, category,
+    /// short_desc, pending_restart` rows.
+    pub fn list_from_result(result: &QueryResult) -> Vec<Self> {
+        (0..result.data_row_count())
+            .filter_map(|row| {
+                let text = |col| result.display_value_at(row, col).unwrap_or_default();
+                let name = result.display_value_at(row, 0)?;
+                let unit = text(2);
+                Some(Self {
+                    name,
+                    setting: text(1),
+                    unit: (!unit.is_empty()).then_some(unit),
+                    reset_val: text(3),
+                    boot_val: text(4),
+                    source: text(5),
+                    category: text(6),
+                    short_desc: text(7),
+                    pending_restart: matches!(text(8).as_str(), "t" | "true"),
+                })
+            })
+            .collect()
+    }
+
+    pub fn current_display(&self) -> String {
+        self.humanize(&self.setting)
+    }
+
+    pub fn reset_display(&self) -> String {
+        self.humanize(&self.reset_val)
+    }
+
+    pub fn boot_display(&self) -> String {
+        self.humanize(&self.boot_val)
+    }
+
+    pub fn differs_from_boot(&self) -> bool {
+        self.setting != self.boot_val
+    }
+
+    /// Renders a raw value in the largest unit that divides it exactly, the
+    /// way `SHOW` does: `shared_buffers = 16384` (8kB) becomes `128MB`.
+    /// Negative values are sentinels such as "disabled" and stay as-is.
+    pub fn humanize(&self, raw: &str) -> String {
+        let Some(unit) = self.unit.as_deref() else {
+            return raw.to_string();
+        };
+        let Ok(value) = raw.parse::<i64>() else {
+            return format!("{raw}{unit}");
+        };
+        if value < 0 {
+            return raw.to_string();
+        }
+        let (base, units, smallest) = match unit {
+            "B" => (1, MEMORY_UNITS, "B"),
+            "kB" => (1 << 10, MEMORY_UNITS, "B"),
+            "8kB" => (8 << 10, MEMORY_UNITS, "B"),
+            "16kB" => (16 << 10, MEMORY_UNITS, "B"),
+            "MB" => (1 << 20, MEMORY_UNITS, "B"),
+            "ms" => (1, TIME_UNITS, "ms"),
+            "s" => (1000, TIME_UNITS, "ms"),
+            "min" => (60_000, TIME_UNITS, "ms"),
+            _ => return format!("{raw}{unit}"),
+        };
+        let Some(scaled) = value.checked_mul(base) else {
+            return format!("{raw}{unit}");
+        };
+        if scaled == 0 {
+            return format!("0{smallest}");
+        }
+        units
+            .iter()
+            .find(|(_, size)| scaled % size == 0)
+            .map_or_else(
+                || format!("{scaled}{smallest}"),
+                |(name, size)| format!("{}{name}", scaled / size),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn setting(unit: Option<&str>) -> ServerSetting {
+        ServerSetting {
+            name: "shared_buffers".to_string(),
+            setting: "16384".to_string(),
+            unit: unit.map(str::to_string),
+            reset_val: "16384".to_string(),
+            boot_val: "1024".to_string(),
+            source: "configuration file".to_string(),
+            category: "Resource Usage / Memory".to_string(),
+            short_desc: "Sets the number of shared memory buffers used by the server.".to_string(),
+            pending_restart: false,
+        }
+    }
+
+    #[rstest]
+    #[case(Some("8kB"), "16384", "128MB")]
+    #[case(Some("8kB"), "1", "8kB")]
+    #[case(Some("kB"), "4096", "4MB")]
+    #[case(Some("kB"), "65", "65kB")]
+    #[case(Some("B"), "1000", "1000B")]
+    #[case(Some("ms"), "60000", "1min")]
+    #[case(Some("ms"), "1500", "1500ms")]
+    #[case(Some("s"), "3600", "1h")]
+    #[case(Some("min"), "0", "0ms")]
+    #[case(Some("ms"), "0.5", "0.5ms")]
+    #[case(Some("ms"), "-1", "-1")]
+    #[case(None, "on", "on")]
+    fn humanize_uses_largest_exact_unit(
+        #[case] unit: Option<&str>,
+        #[case] raw: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(setting(unit).humanize(raw), expected);
+    }
+
+    #[test]
+    fn differs_from_boot_compares_raw_values() {
+        let mut value = setting(Some("8kB"));
+        assert!(value.differs_from_boot());
+
+        value.boot_val = value.setting.clone();
+        assert!(!value.differs_from_boot());
+    }
+}
@@ -1,8 +1,13 @@
+use serde::{Deserialize, Serialize};
+
 use super::column::Column;
+use super::column_stats::ColumnStats;
 use super::foreign_key::ForeignKey;
+use super::hypertable::Hypertable;
 use super::index::Index;
 use super::rls::RlsInfo;
 use super::table_kind::TableKindInfo;
+use super::table_storage::TableStorage;
 use super::trigger::Trigger;
 
 fn make_qualified_name(schema: &str, name: &str) -> String {
@@ -17,7 +22,7 @@ fn make_display_name(schema: &str, name: &str, omit_public: bool) -> String {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Table {
     pub schema: String,
     pub name: String,
@@ -32,6 +37,14 @@ pub struct Table {
     pub comment: Option<String>,
     pub source_ddl: Option<String>,
     pub kind_info: TableKindInfo,
+    #[serde(default)]
+    pub hypertable: Option<Hypertable>,
+    /// `pg_stats` rows for analyzed columns; empty when unavailable.
+    #[serde(default)]
+    pub column_stats: Vec<ColumnStats>,
+    /// Sizes and vacuum stats; only PostgreSQL's table detail fills it.
+    #[serde(default)]
+    pub storage: Option<TableStorage>,
 }
 
 impl Table {
@@ -54,7 +67,8 @@ impl Table {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "TableSummaryFields")]
 pub struct TableSummary {
     pub schema: String,
     pub name: String,
@@ -62,9 +76,33 @@ pub struct TableSummary {
     pub has_rls: bool,
     pub kind_info: TableKindInfo,
     // Pre-computed for efficient case-insensitive filtering
+    #[serde(skip_serializing)]
     qualified_name_lower: String,
 }
 
+// Deserialization goes through `new` so the derived lowercase name is rebuilt.
+#[derive(Deserialize)]
+struct TableSummaryFields {
+    schema: String,
+    name: String,
+    row_count_estimate: Option<i64>,
+    has_rls: bool,
+    #[serde(default)]
+    kind_info: TableKindInfo,
+}
+
+impl From<TableSummaryFields> for TableSummary {
+    fn from(fields: TableSummaryFields) -> Self {
+        Self::new(
+            fields.schema,
+            fields.name,
+            fields.row_count_estimate,
+            fields.has_rls,
+        )
+        .with_kind_info(fields.kind_info)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TableSignature {
     pub schema: String,
@@ -134,6 +172,9 @@ mod tests {
             comment: None,
             source_ddl: None,
             kind_info: TableKindInfo::default(),
+            hypertable: None,
+            column_stats: Vec::new(),
+            storage: None,
         }
     }
 
@@ -213,5 +254,15 @@ mod tests {
 
             assert_eq!(summary.qualified_name_lower(), "myschema.mytable");
         }
+
+        #[test]
+        fn deserialize_rebuilds_qualified_name_lower() {
+            let json = serde_json::to_string(&make_summary("MySchema", "MyTable")).unwrap();
+
+            let summary: TableSummary = serde_json::from_str(&json).unwrap();
+
+            assert!(!json.contains("qualified_name_lower"));
+            assert_eq!(summary.qualified_name_lower(), "myschema.mytable");
+        }
     }
 }
@@ -1,12 +1,16 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum TableKind {
     #[default]
     Table,
     Virtual,
     View,
+    /// TimescaleDB hypertable; its chunks are hidden from the table list.
+    Hypertable,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct TableKindInfo {
     pub kind: TableKind,
     pub is_strict: bool,
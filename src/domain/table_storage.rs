@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+const SIZE_UNITS: &[(&str, i64)] = &[
+    ("TB", 1 << 40),
+    ("GB", 1 << 30),
+    ("MB", 1 << 20),
+    ("kB", 1 << 10),
+];
+
+/// On-disk footprint and `pg_stat_user_tables` counters of a table. The
+/// tuple counts and maintenance times are `None` when the statistics
+/// collector has nothing for it yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableStorage {
+    /// Heap, indexes and TOAST together.
+    pub total_bytes: i64,
+    pub table_bytes: i64,
+    pub index_bytes: i64,
+    pub toast_bytes: i64,
+    pub live_tuples: Option<i64>,
+    pub dead_tuples: Option<i64>,
+    pub last_vacuum: Option<String>,
+    pub last_autovacuum: Option<String>,
+    pub last_analyze: Option<String>,
+    pub last_autoanalyze: Option<String>,
+}
+
+impl TableStorage {
+    /// Dead tuples as a share of all tuples, once either count is known.
+    pub fn dead_tuple_ratio(&self) -> Option<f64> {
+        let dead = self.dead_tuples?;
+        let total = dead + self.live_tuples.unwrap_or(0);
+        (total > 0).then(|| dead as f64 / total as f64)
+    }
+}
+
+/// Rounds to the largest unit the size has at least ten of, the way
+/// `pg_size_pretty` does: `8192` stays `8192 bytes`, `16384` is `16 kB`.
+pub fn size_label(bytes: i64) -> String {
+    SIZE_UNITS
+        .iter()
+        .find(|(_, unit)| bytes >= unit * 10)
+        .map_or_else(
+            || format!("{bytes} bytes"),
+            |(name, unit)| format!("{} {name}", (bytes + unit / 2) / unit),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, "0 bytes")]
+    #[case(8192, "8192 bytes")]
+    #[case(16384, "16 kB")]
+    #[case(123_456_789, "118 MB")]
+    #[case(3 << 40, "3072 GB")]
+    #[case(20 << 40, "20 TB")]
+    fn sizes_round_to_the_largest_fitting_unit(#[case] bytes: i64, #[case] expected: &str) {
+        assert_eq!(size_label(bytes), expected);
+    }
+
+    #[test]
+    fn dead_ratio_needs_a_dead_count_and_some_tuples() {
+        let storage = |live, dead| TableStorage {
+            live_tuples: live,
+            dead_tuples: dead,
+            ..TableStorage::default()
+        };
+
+        assert_eq!(storage(Some(75), Some(25)).dead_tuple_ratio(), Some(0.25));
+        assert_eq!(storage(Some(10), None).dead_tuple_ratio(), None);
+        assert_eq!(storage(Some(0), Some(0)).dead_tuple_ratio(), None);
+    }
+}
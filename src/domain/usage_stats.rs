@@ -0,0 +1,116 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageKind {
+    Pane,
+    Tab,
+    Command,
+}
+
+impl UsageKind {
+    pub const ALL: [Self; 3] = [Self::Pane, Self::Tab, Self::Command];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pane => "Pane",
+            Self::Tab => "Tab",
+            Self::Command => "Command",
+        }
+    }
+}
+
+/// Local per-project counters of which panes, inspector tabs, and commands
+/// get used.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageStats {
+    #[serde(default)]
+    panes: BTreeMap<String, u64>,
+    #[serde(default)]
+    tabs: BTreeMap<String, u64>,
+    #[serde(default)]
+    commands: BTreeMap<String, u64>,
+}
+
+impl UsageStats {
+    pub fn record(&mut self, kind: UsageKind, key: &str) {
+        let count = self.counts_mut(kind).entry(key.to_string()).or_default();
+        *count = count.saturating_add(1);
+    }
+
+    pub fn count(&self, kind: UsageKind, key: &str) -> u64 {
+        self.counts(kind).get(key).copied().unwrap_or(0)
+    }
+
+    /// Most used first; ties keep alphabetical order.
+    pub fn ranked(&self, kind: UsageKind) -> Vec<(&str, u64)> {
+        let mut ranked: Vec<_> = self
+            .counts(kind)
+            .iter()
+            .map(|(key, count)| (key.as_str(), *count))
+            .collect();
+        ranked.sort_by_key(|(_, count)| Reverse(*count));
+        ranked
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.panes.is_empty() && self.tabs.is_empty() && self.commands.is_empty()
+    }
+
+    fn counts(&self, kind: UsageKind) -> &BTreeMap<String, u64> {
+        match kind {
+            UsageKind::Pane => &self.panes,
+            UsageKind::Tab => &self.tabs,
+            UsageKind::Command => &self.commands,
+        }
+    }
+
+    fn counts_mut(&mut self, kind: UsageKind) -> &mut BTreeMap<String, u64> {
+        match kind {
+            UsageKind::Pane => &mut self.panes,
+            UsageKind::Tab => &mut self.tabs,
+            UsageKind::Command => &mut self.commands,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_counts_per_kind() {
+        let mut stats = UsageStats::default();
+
+        stats.record(UsageKind::Tab, "Cols");
+        stats.record(UsageKind::Tab, "Cols");
+        stats.record(UsageKind::Command, "Cols");
+
+        assert_eq!(stats.count(UsageKind::Tab, "Cols"), 2);
+        assert_eq!(stats.count(UsageKind::Command, "Cols"), 1);
+        assert_eq!(stats.count(UsageKind::Pane, "Cols"), 0);
+    }
+
+    #[test]
+    fn ranked_orders_by_count_then_name() {
+        let mut stats = UsageStats::default();
+        for key in ["Idx", "DDL", "Cols", "Cols"] {
+            stats.record(UsageKind::Tab, key);
+        }
+
+        assert_eq!(
+            stats.ranked(UsageKind::Tab),
+            [("Cols", 2), ("DDL", 1), ("Idx", 1)]
+        );
+    }
+
+    #[test]
+    fn missing_kinds_deserialize_as_empty() {
+        let stats: UsageStats = serde_json::from_str(r#"{"tabs":{"DDL":3}}"#).unwrap();
+
+        assert_eq!(stats.count(UsageKind::Tab, "DDL"), 3);
+        assert!(stats.ranked(UsageKind::Command).is_empty());
+    }
+}
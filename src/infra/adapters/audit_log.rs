@@ -0,0 +1,110 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::app::ports::outbound::{AuditLogError, AuditLogStore};
+use crate::config::cache::{CacheDirError, get_cache_dir};
+use crate::domain::AuditEntry;
+
+// Sits at the top of the project cache directory, outside every cache
+// category, so `:cache clear` and pruning leave it alone.
+const AUDIT_LOG_FILE: &str = "audit.jsonl";
+
+impl From<CacheDirError> for AuditLogError {
+    fn from(error: CacheDirError) -> Self {
+        match error {
+            CacheDirError::BaseDirUnavailable => Self::MissingCacheDir,
+            CacheDirError::Io(error) => error.into(),
+        }
+    }
+}
+
+fn append_line(path: &Path, line: &str) -> Result<(), AuditLogError> {
+    use std::fs::OpenOptions;
+    use std::io::Write;
+
+    if let Some(dir) = path.parent()
+        && !dir.exists()
+    {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct FileAuditLogStore {
+    base_dir: Option<PathBuf>,
+}
+
+impl FileAuditLogStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[cfg(test)]
+    fn with_base_dir(base_dir: PathBuf) -> Self {
+        Self {
+            base_dir: Some(base_dir),
+        }
+    }
+
+    fn resolve_path(&self, project_name: &str) -> Result<PathBuf, AuditLogError> {
+        let dir = match &self.base_dir {
+            Some(base) => base.clone(),
+            None => get_cache_dir(project_name)?,
+        };
+        Ok(dir.join(AUDIT_LOG_FILE))
+    }
+}
+
+#[async_trait]
+impl AuditLogStore for FileAuditLogStore {
+    async fn append(&self, project_name: &str, entry: &AuditEntry) -> Result<(), AuditLogError> {
+        let path = self.resolve_path(project_name)?;
+        let line = serde_json::to_string(entry)?;
+        tokio::task::spawn_blocking(move || append_line(&path, &line)).await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::AuditEvent;
+
+    fn entry(statement: &str) -> AuditEntry {
+        AuditEntry::new(
+            "2026-10-16T09:00:00Z".to_string(),
+            AuditEvent {
+                connection: "prod".to_string(),
+                operation: "DELETE".to_string(),
+                risk: "HIGH".to_string(),
+                statement: statement.to_string(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn appends_one_json_line_per_entry() {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = FileAuditLogStore::with_base_dir(tmp.path().join("project"));
+
+        store
+            .append("test", &entry("DELETE FROM users WHERE id = 1"))
+            .await
+            .unwrap();
+        store
+            .append("test", &entry("TRUNCATE users"))
+            .await
+            .unwrap();
+
+        let content = std::fs::read_to_string(tmp.path().join("project/audit.jsonl")).unwrap();
+        let entries: Vec<AuditEntry> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].event.statement, "TRUNCATE users");
+    }
+}
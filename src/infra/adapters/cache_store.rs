@@ -0,0 +1,361 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::app::ports::outbound::{
+    CacheCategory, CacheCategoryUsage, CacheCleanup, CachePrunePolicy, CacheStore, CacheStoreError,
+};
+use crate::config::cache::{CacheDirError, get_cache_dir};
+
+impl From<CacheDirError> for CacheStoreError {
+    fn from(error: CacheDirError) -> Self {
+        match error {
+            CacheDirError::BaseDirUnavailable => Self::MissingCacheDir,
+            CacheDirError::Io(error) => error.into(),
+        }
+    }
+}
+
+struct CacheFile {
+    path: PathBuf,
+    category: CacheCategory,
+    bytes: u64,
+    modified: SystemTime,
+}
+
+// Anything not listed here (the session lock, temp files mid-write, files
+// from newer versions) is neither counted nor removed.
+fn categorize(relative: &Path) -> Option<CacheCategory> {
+    let mut components = relative.components();
+    let first = components.next()?.as_os_str().to_str()?;
+    let nested = components.next().is_some();
+    let extension = Path::new(first).extension().and_then(|ext| ext.to_str());
+    match (first, extension) {
+        ("history", _) if nested => Some(CacheCategory::History),
+        ("snapshots", _) if nested => Some(CacheCategory::Snapshots),
+        _ if nested => None,
+        ("usage.json", _) => Some(CacheCategory::Usage),
+        (_, Some("log")) => Some(CacheCategory::Logs),
+        (name, Some("dot" | "svg")) if name.starts_with("er_") => Some(CacheCategory::ErDiagrams),
+        _ => None,
+    }
+}
+
+fn collect_files(root: &Path) -> io::Result<Vec<CacheFile>> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(error),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            let path = entry.path();
+            if metadata.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            let Some(category) = path.strip_prefix(root).ok().and_then(categorize) else {
+                continue;
+            };
+            files.push(CacheFile {
+                path,
+                category,
+                bytes: metadata.len(),
+                modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            });
+        }
+    }
+    Ok(files)
+}
+
+fn remove_files<'a>(files: impl IntoIterator<Item = &'a CacheFile>) -> io::Result<CacheCleanup> {
+    let mut cleanup = CacheCleanup::default();
+    for file in files {
+        match fs::remove_file(&file.path) {
+            Ok(()) => {
+                cleanup.files += 1;
+                cleanup.bytes += file.bytes;
+            }
+            // Another instance or a finished export may have beaten us to it.
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(cleanup)
+}
+
+// Snapshot directories are recreated on save, so empty ones are just noise.
+fn remove_empty_dirs(root: &Path) {
+    for name in ["history", "snapshots"] {
+        remove_empty_below(&root.join(name));
+    }
+}
+
+fn remove_empty_below(dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+            remove_empty_below(&entry.path());
+            let _ = fs::remove_dir(entry.path());
+        }
+    }
+}
+
+fn usage_in(root: &Path) -> io::Result<Vec<CacheCategoryUsage>> {
+    let files = collect_files(root)?;
+    Ok(CacheCategory::ALL
+        .into_iter()
+        .map(|category| {
+            let mut usage = CacheCategoryUsage {
+                category,
+                files: 0,
+                bytes: 0,
+            };
+            for file in files.iter().filter(|file| file.category == category) {
+                usage.files += 1;
+                usage.bytes += file.bytes;
+            }
+            usage
+        })
+        .collect())
+}
+
+fn clear_in(root: &Path, category: Option<CacheCategory>) -> io::Result<CacheCleanup> {
+    let files = collect_files(root)?;
+    let cleanup = remove_files(
+        files
+            .iter()
+            .filter(|file| category.is_none_or(|category| file.category == category)),
+    )?;
+    remove_empty_dirs(root);
+    Ok(cleanup)
+}
+
+fn prune_in(root: &Path, policy: &CachePrunePolicy, now: SystemTime) -> io::Result<CacheCleanup> {
+    let files = collect_files(root)?;
+    let (disposable, kept): (Vec<_>, Vec<_>) = files
+        .into_iter()
+        .partition(|file| file.category.is_disposable());
+    let (expired, mut fresh): (Vec<_>, Vec<_>) = disposable.into_iter().partition(|file| {
+        now.duration_since(file.modified)
+            .is_ok_and(|age| age > policy.max_age)
+    });
+
+    let mut cleanup = remove_files(&expired)?;
+
+    let mut total: u64 = kept.iter().chain(&fresh).map(|file| file.bytes).sum();
+    fresh.sort_by_key(|file| file.modified);
+    let oversized = fresh
+        .iter()
+        .take_while(|file| {
+            let over = total > policy.max_total_bytes;
+            total = total.saturating_sub(file.bytes);
+            over
+        })
+        .collect::<Vec<_>>();
+    let trimmed = remove_files(oversized)?;
+    cleanup.files += trimmed.files;
+    cleanup.bytes += trimmed.bytes;
+
+    if cleanup.files > 0 {
+        remove_empty_dirs(root);
+    }
+    Ok(cleanup)
+}
+
+pub struct FileCacheStore {
+    base_dir: Option<PathBuf>,
+}
+
+impl Default for FileCacheStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileCacheStore {
+    pub fn new() -> Self {
+        Self { base_dir: None }
+    }
+
+    #[cfg(test)]
+    fn with_base_dir(base_dir: PathBuf) -> Self {
+        Self {
+            base_dir: Some(base_dir),
+        }
+    }
+
+    fn resolve_dir(&self, project_name: &str) -> Result<PathBuf, CacheStoreError> {
+        match &self.base_dir {
+            Some(base) => Ok(base.clone()),
+            None => Ok(get_cache_dir(project_name)?),
+        }
+    }
+}
+
+impl CacheStore for FileCacheStore {
+    fn usage(&self, project_name: &str) -> Result<Vec<CacheCategoryUsage>, CacheStoreError> {
+        let dir = self.resolve_dir(project_name)?;
+        Ok(usage_in(&dir)?)
+    }
+
+    fn clear(
+        &self,
+        project_name: &str,
+        category: Option<CacheCategory>,
+    ) -> Result<CacheCleanup, CacheStoreError> {
+        let dir = self.resolve_dir(project_name)?;
+        Ok(clear_in(&dir, category)?)
+    }
+
+    fn prune(
+        &self,
+        project_name: &str,
+        policy: &CachePrunePolicy,
+    ) -> Result<CacheCleanup, CacheStoreError> {
+        let dir = self.resolve_dir(project_name)?;
+        Ok(prune_in(&dir, policy, SystemTime::now())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const DAY: Duration = Duration::from_hours(24);
+
+    fn write(root: &Path, relative: &str, bytes: usize, age: Duration) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, vec![b'x'; bytes]).unwrap();
+        let file = fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() - age).unwrap();
+    }
+
+    fn seeded_cache() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "er_full.dot", 10, Duration::ZERO);
+        write(root, "er_full.svg", 20, Duration::ZERO);
+        write(root, "er_diagram.log", 5, Duration::ZERO);
+        write(root, "history/conn.jsonl", 30, Duration::ZERO);
+        write(root, "snapshots/conn/metadata.json", 40, Duration::ZERO);
+        write(
+            root,
+            "snapshots/conn/tables/public.users.json",
+            8,
+            Duration::ZERO,
+        );
+        write(root, "usage.json", 2, Duration::ZERO);
+        write(root, "session.lock", 4, Duration::ZERO);
+        dir
+    }
+
+    fn bytes_of(usage: &[CacheCategoryUsage], category: CacheCategory) -> (usize, u64) {
+        let entry = usage.iter().find(|u| u.category == category).unwrap();
+        (entry.files, entry.bytes)
+    }
+
+    #[test]
+    fn usage_groups_files_by_category() {
+        let dir = seeded_cache();
+        let store = FileCacheStore::with_base_dir(dir.path().to_path_buf());
+
+        let usage = store.usage("test").unwrap();
+
+        assert_eq!(usage.len(), CacheCategory::ALL.len());
+        assert_eq!(bytes_of(&usage, CacheCategory::ErDiagrams), (2, 30));
+        assert_eq!(bytes_of(&usage, CacheCategory::Logs), (1, 5));
+        assert_eq!(bytes_of(&usage, CacheCategory::History), (1, 30));
+        assert_eq!(bytes_of(&usage, CacheCategory::Snapshots), (2, 48));
+        assert_eq!(bytes_of(&usage, CacheCategory::Usage), (1, 2));
+    }
+
+    #[test]
+    fn clearing_one_category_leaves_the_others() {
+        let dir = seeded_cache();
+        let store = FileCacheStore::with_base_dir(dir.path().to_path_buf());
+
+        let cleanup = store.clear("test", Some(CacheCategory::Snapshots)).unwrap();
+
+        assert_eq!(
+            cleanup,
+            CacheCleanup {
+                files: 2,
+                bytes: 48
+            }
+        );
+        assert!(!dir.path().join("snapshots/conn").exists());
+        assert!(dir.path().join("history/conn.jsonl").exists());
+        assert!(dir.path().join("er_full.dot").exists());
+    }
+
+    #[test]
+    fn clearing_everything_keeps_the_session_lock() {
+        let dir = seeded_cache();
+        let store = FileCacheStore::with_base_dir(dir.path().to_path_buf());
+
+        let cleanup = store.clear("test", None).unwrap();
+
+        assert_eq!(cleanup.files, 7);
+        assert!(dir.path().join("session.lock").exists());
+    }
+
+    #[test]
+    fn prune_drops_expired_disposable_files_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "er_old.svg", 10, 40 * DAY);
+        write(root, "er_new.svg", 10, DAY);
+        write(root, "history/conn.jsonl", 10, 90 * DAY);
+        write(root, "snapshots/conn/metadata.json", 10, 90 * DAY);
+
+        let cleanup = prune_in(root, &CachePrunePolicy::default(), SystemTime::now()).unwrap();
+
+        assert_eq!(
+            cleanup,
+            CacheCleanup {
+                files: 1,
+                bytes: 10
+            }
+        );
+        assert!(!root.join("er_old.svg").exists());
+        assert!(root.join("er_new.svg").exists());
+        assert!(root.join("history/conn.jsonl").exists());
+        assert!(root.join("snapshots/conn/metadata.json").exists());
+    }
+
+    #[test]
+    fn prune_trims_oldest_disposable_files_over_the_size_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "er_a.svg", 40, 3 * DAY);
+        write(root, "er_b.svg", 40, 2 * DAY);
+        write(root, "er_c.svg", 40, DAY);
+        write(root, "usage.json", 40, 5 * DAY);
+        let policy = CachePrunePolicy {
+            max_age: 30 * DAY,
+            max_total_bytes: 100,
+        };
+
+        let cleanup = prune_in(root, &policy, SystemTime::now()).unwrap();
+
+        assert_eq!(
+            cleanup,
+            CacheCleanup {
+                files: 2,
+                bytes: 80
+            }
+        );
+        assert!(root.join("er_c.svg").exists());
+        assert!(root.join("usage.json").exists());
+    }
+}
@@ -1,14 +1,41 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use sabiql_app::domain::QueryValue;
 use sabiql_app::ports::outbound::{CachedResultExporter, DbOperationError};
 use tokio::io::{AsyncWriteExt, BufWriter};
 
 use crate::adapters::csv_export::export_to_downloads;
+use crate::config::cache_cipher::CacheCipher;
 
 const CSV_FLUSH_THRESHOLD: usize = 64 * 1024;
 
-#[derive(Debug, Default, Clone, Copy)]
-pub struct CsvCachedResultExporter;
+#[derive(Default, Clone)]
+pub struct CsvCachedResultExporter {
+    cipher: Option<Arc<CacheCipher>>,
+    key_unavailable: bool,
+}
+
+impl CsvCachedResultExporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seals every export; the file holds one sealed CSV document.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Option<Arc<CacheCipher>>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    /// Spill encryption is on without a key: fail instead of writing plain
+    /// text.
+    #[must_use]
+    pub fn with_key_unavailable(mut self, key_unavailable: bool) -> Self {
+        self.key_unavailable = key_unavailable;
+        self
+    }
+}
 
 #[async_trait]
 impl CachedResultExporter for CsvCachedResultExporter {
@@ -18,10 +45,26 @@ impl CachedResultExporter for CsvCachedResultExporter {
         columns: Vec<String>,
         values: Vec<Vec<QueryValue>>,
     ) -> Result<std::path::PathBuf, DbOperationError> {
-        export_to_downloads(&file_name, |path| {
-            write_cached_result_csv(path, columns, values)
-        })
-        .await
+        if self.key_unavailable {
+            return Err(DbOperationError::QueryFailed(
+                "Spill encryption is on but no key is available".to_string(),
+            ));
+        }
+        match &self.cipher {
+            Some(cipher) => {
+                let cipher = Arc::clone(cipher);
+                export_to_downloads(&file_name, |path| {
+                    write_sealed_cached_result_csv(path, columns, values, cipher)
+                })
+                .await
+            }
+            None => {
+                export_to_downloads(&file_name, |path| {
+                    write_cached_result_csv(path, columns, values)
+                })
+                .await
+            }
+        }
     }
 }
 
@@ -81,6 +124,29 @@ async fn write_cached_result_csv(
     Ok(())
 }
 
+// Sealing needs the whole document, so the plain CSV is built in memory and
+// never touches the disk.
+async fn write_sealed_cached_result_csv(
+    path: std::path::PathBuf,
+    columns: Vec<String>,
+    values: Vec<Vec<QueryValue>>,
+    cipher: Arc<CacheCipher>,
+) -> Result<(), DbOperationError> {
+    let mut csv_writer = csv::WriterBuilder::new().from_writer(Vec::new());
+    csv_writer.write_record(&columns)?;
+    for row in &values {
+        csv_writer.write_record(row.iter().map(cached_csv_cell))?;
+    }
+    let encoded = csv_writer
+        .into_inner()
+        .map_err(|error| DbOperationError::QueryFailed(error.to_string()))?;
+    let plain = String::from_utf8(encoded)
+        .map_err(|error| DbOperationError::QueryFailed(error.to_string()))?;
+    tokio::fs::write(path, cipher.seal(&plain))
+        .await
+        .map_err(|error| DbOperationError::QueryFailed(error.to_string()))
+}
+
 async fn write_csv_record<I>(
     mut csv_writer: csv::Writer<Vec<u8>>,
     file: &mut BufWriter<tokio::fs::File>,
@@ -148,6 +214,7 @@ mod tests {
 
     mod export_cached_result_to_csv {
         use super::*;
+        use crate::config::cache_cipher::unseal;
 
         #[tokio::test]
         async fn writes_columns_and_rows() {
@@ -223,6 +290,40 @@ mod tests {
             );
         }
 
+        #[tokio::test]
+        async fn sealed_export_hides_the_rows() {
+            let dir = tempdir().unwrap();
+            let path = dir.path().join("export.csv");
+            let cipher = Arc::new(CacheCipher::for_spills(&[9; 32]));
+
+            write_sealed_cached_result_csv(
+                path.clone(),
+                vec!["email".to_string()],
+                vec![vec![QueryValue::text("a@b.c")]],
+                Arc::clone(&cipher),
+            )
+            .await
+            .unwrap();
+
+            let stored = std::fs::read_to_string(path).unwrap();
+            assert!(!stored.contains("a@b.c"));
+            assert_eq!(
+                unseal(Some(&cipher), &stored).as_deref(),
+                Some("email\na@b.c\n")
+            );
+        }
+
+        #[tokio::test]
+        async fn missing_key_refuses_to_export() {
+            let exporter = CsvCachedResultExporter::new().with_key_unavailable(true);
+
+            let result = exporter
+                .export_cached_result_to_csv("export.csv".to_string(), vec![], vec![])
+                .await;
+
+            assert!(matches!(result, Err(DbOperationError::QueryFailed(_))));
+        }
+
         #[tokio::test]
         async fn returns_error_when_file_cannot_be_created() {
             let dir = tempdir().unwrap();
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::adapters::app_config_file::get_config_dir;
+use crate::app::ports::outbound::{MaskingRuleError, MaskingRuleReader, MaskingRules};
+
+pub const MASKING_FILE_NAME: &str = "masking.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MaskingFile {
+    #[serde(default)]
+    columns: Vec<String>,
+    #[serde(default)]
+    tables: HashMap<String, Vec<String>>,
+}
+
+fn parse(content: &str) -> Result<MaskingRules, MaskingRuleError> {
+    let file: MaskingFile =
+        toml::from_str(content).map_err(|e| MaskingRuleError::Parse(e.to_string()))?;
+    if let Some(pattern) = file
+        .columns
+        .iter()
+        .find(|pattern| pattern.trim().is_empty())
+    {
+        return Err(MaskingRuleError::Parse(format!(
+            "columns = \"{pattern}\" would match nothing"
+        )));
+    }
+    if let Some(table) = file.tables.keys().find(|table| !table.contains('.')) {
+        return Err(MaskingRuleError::Parse(format!(
+            "tables.\"{table}\" must be written as schema.table"
+        )));
+    }
+    Ok(MaskingRules {
+        column_patterns: file.columns,
+        table_columns: file.tables,
+    })
+}
+
+#[derive(Default)]
+pub struct TomlMaskingRuleReader {
+    path: Option<PathBuf>,
+}
+
+impl TomlMaskingRuleReader {
+    pub fn new() -> Self {
+        Self { path: None }
+    }
+
+    #[cfg(test)]
+    fn with_path(path: PathBuf) -> Self {
+        Self { path: Some(path) }
+    }
+}
+
+impl MaskingRuleReader for TomlMaskingRuleReader {
+    fn read_rules(&self) -> Result<MaskingRules, MaskingRuleError> {
+        let path = match &self.path {
+            Some(path) => path.clone(),
+            None => match get_config_dir() {
+                Ok(dir) => dir.join(MASKING_FILE_NAME),
+                Err(_) => return Ok(MaskingRules::default()),
+            },
+        };
+        if !path.is_file() {
+            return Ok(MaskingRules::default());
+        }
+        let content =
+            std::fs::read_to_string(&path).map_err(|source| MaskingRuleError::ReadAt {
+                path: path.clone(),
+                source: Arc::new(source),
+            })?;
+        parse(&content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn reads_patterns_and_table_lists() {
+        let rules = parse(
+            r#"
+columns = ["*password*", "*ssn*"]
+
+[tables]
+"public.users" = ["email", "phone"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rules,
+            MaskingRules {
+                column_patterns: vec!["*password*".to_string(), "*ssn*".to_string()],
+                table_columns: HashMap::from([(
+                    "public.users".to_string(),
+                    vec!["email".to_string(), "phone".to_string()],
+                )]),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_unqualified_table() {
+        let result = parse("[tables]\nusers = [\"email\"]\n");
+
+        assert!(matches!(result, Err(MaskingRuleError::Parse(msg)) if msg.contains("users")));
+    }
+
+    #[test]
+    fn missing_file_yields_no_rules() {
+        let tmp = TempDir::new().unwrap();
+        let reader = TomlMaskingRuleReader::with_path(tmp.path().join(MASKING_FILE_NAME));
+
+        assert!(reader.read_rules().unwrap().is_empty());
+    }
+}
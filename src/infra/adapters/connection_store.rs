@@ -60,6 +60,12 @@ impl TomlConnectionStore {
             config.theme = existing_config.theme;
             config.keymap_preset = existing_config.keymap_preset;
             config.er_browser = existing_config.er_browser;
+            config.keyword_case = existing_config.keyword_case;
+            config.locale = existing_config.locale;
+            config.usage_stats = existing_config.usage_stats;
+            config.update_check = existing_config.update_check;
+            config.prefetch_scope = existing_config.prefetch_scope;
+            config.cache_encryption = existing_config.cache_encryption;
         }
         let content = toml::to_string_pretty(&config)?;
         let content_with_header = render_config_file(&content);
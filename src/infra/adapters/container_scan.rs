@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use super::connection_import::image_database_config;
+use crate::app::ports::outbound::{
+    ConnectionCandidate, ContainerDatabaseScanner, ContainerScanError, ImportSource,
+};
+
+#[cfg(unix)]
+const REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContainerSummary {
+    id: String,
+    #[serde(default)]
+    names: Vec<String>,
+    #[serde(default)]
+    image: String,
+    #[serde(default)]
+    ports: Vec<PortMapping>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PortMapping {
+    private_port: u16,
+    public_port: Option<u16>,
+    #[serde(default, rename = "Type")]
+    protocol: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContainerInspect {
+    config: ContainerConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContainerConfig {
+    #[serde(default)]
+    env: Option<Vec<String>>,
+}
+
+impl ContainerSummary {
+    fn name(&self) -> String {
+        self.names.first().map_or_else(
+            || self.id.chars().take(12).collect(),
+            |name| name.trim_start_matches('/').to_string(),
+        )
+    }
+
+    fn published_port(&self, container_port: u16) -> Option<u16> {
+        self.ports
+            .iter()
+            .filter(|mapping| mapping.protocol.is_empty() || mapping.protocol == "tcp")
+            .find(|mapping| mapping.private_port == container_port)
+            .and_then(|mapping| mapping.public_port)
+    }
+
+    fn candidate(&self, env: &[String]) -> Option<ConnectionCandidate> {
+        let environment: HashMap<String, String> = env
+            .iter()
+            .filter_map(|entry| entry.split_once('='))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let config =
+            image_database_config(&self.image, &environment, |port| self.published_port(port))?;
+        Some(ConnectionCandidate {
+            name: self.name(),
+            source: ImportSource::Container,
+            config,
+        })
+    }
+}
+
+/// Talks to the Docker Engine API over its Unix socket; Podman serves the
+/// same API on its own socket.
+pub struct DockerSocketScanner {
+    home_dir: Option<PathBuf>,
+    runtime_dir: Option<PathBuf>,
+}
+
+impl Default for DockerSocketScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DockerSocketScanner {
+    pub fn new() -> Self {
+        Self {
+            home_dir: dirs::home_dir(),
+            runtime_dir: std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from),
+        }
+    }
+
+    /// `DOCKER_HOST` wins when it names a Unix socket; otherwise the first
+    /// Docker, Docker Desktop or Podman socket that exists.
+    fn socket_path(&self) -> Option<PathBuf> {
+        if let Some(host) = std::env::var_os("DOCKER_HOST") {
+            return host
+                .to_str()
+                .and_then(|host| host.strip_prefix("unix://"))
+                .map(PathBuf::from);
+        }
+        let mut candidates = vec![PathBuf::from("/var/run/docker.sock")];
+        if let Some(home) = &self.home_dir {
+            candidates.push(home.join(".docker/run/docker.sock"));
+            candidates.push(home.join(".colima/default/docker.sock"));
+        }
+        if let Some(runtime) = &self.runtime_dir {
+            candidates.push(runtime.join("podman/podman.sock"));
+        }
+        candidates.push(PathBuf::from("/run/podman/podman.sock"));
+        candidates.into_iter().find(|path| path.exists())
+    }
+
+    fn scan_socket(
+        &self,
+        socket: &std::path::Path,
+    ) -> Result<Vec<ConnectionCandidate>, ContainerScanError> {
+        let containers: Vec<ContainerSummary> = parse_json(&http_get(socket, "/containers/json")?)?;
+        let mut candidates = Vec::new();
+        for container in containers {
+            // Only database images publishing their port are worth inspecting.
+            if container.candidate(&[]).is_none() {
+                continue;
+            }
+            // A container that vanished since the listing is simply skipped.
+            let env = http_get(socket, &format!("/containers/{}/json", container.id))
+                .and_then(|body| parse_json::<ContainerInspect>(&body))
+                .map(|inspect| inspect.config.env.unwrap_or_default())
+                .unwrap_or_default();
+            candidates.extend(container.candidate(&env));
+        }
+        Ok(candidates)
+    }
+}
+
+impl ContainerDatabaseScanner for DockerSocketScanner {
+    fn scan(&self) -> Result<Vec<ConnectionCandidate>, ContainerScanError> {
+        let socket = self
+            .socket_path()
+            .ok_or(ContainerScanError::SocketNotFound)?;
+        self.scan_socket(&socket)
+    }
+}
+
+fn parse_json<T: for<'de> Deserialize<'de>>(body: &[u8]) -> Result<T, ContainerScanError> {
+    serde_json::from_slice(body).map_err(|e| ContainerScanError::InvalidResponse(e.to_string()))
+}
+
+#[cfg(unix)]
+fn http_get(socket: &std::path::Path, path: &str) -> Result<Vec<u8>, ContainerScanError> {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket)?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+    // HTTP/1.0 makes the engine close the connection after one plain body.
+    write!(stream, "GET {path} HTTP/1.0\r\nHost: docker\r\n\r\n")?;
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+    response_body(response)
+}
+
+#[cfg(not(unix))]
+fn http_get(_socket: &std::path::Path, _path: &str) -> Result<Vec<u8>, ContainerScanError> {
+    Err(ContainerScanError::SocketNotFound)
+}
+
+fn response_body(mut response: Vec<u8>) -> Result<Vec<u8>, ContainerScanError> {
+    let header_end = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(|| ContainerScanError::InvalidResponse("missing HTTP headers".to_string()))?;
+    let status_line = String::from_utf8_lossy(&response[..header_end])
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let status = status_line.split_whitespace().nth(1).unwrap_or_default();
+    if status != "200" {
+        return Err(ContainerScanError::InvalidResponse(status_line));
+    }
+    Ok(response.split_off(header_end + 4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::connection::{
+        ConnectionConfig, MySqlConnectionConfig, PostgresConnectionConfig, SslMode,
+    };
+
+    const LISTING: &str = r#"[
+        {
+            "Id": "a1b2c3d4e5f6a7b8",
+            "Names": ["/shop-db"],
+            "Image": "postgres:16",
+            "Ports": [
+                {"IP": "0.0.0.0", "PrivatePort": 5432, "PublicPort": 55432, "Type": "tcp"},
+                {"IP": "::", "PrivatePort": 5432, "PublicPort": 55432, "Type": "tcp"}
+            ]
+        },
+        {
+            "Id": "ffeeddccbbaa0011",
+            "Names": ["/cache"],
+            "Image": "redis:7",
+            "Ports": [{"PrivatePort": 6379, "PublicPort": 6379, "Type": "tcp"}]
+        },
+        {
+            "Id": "0011223344556677",
+            "Names": ["/internal-pg"],
+            "Image": "postgres:15",
+            "Ports": [{"PrivatePort": 5432, "Type": "tcp"}]
+        }
+    ]"#;
+
+    #[test]
+    fn postgres_container_uses_published_port_and_env() {
+        let containers: Vec<ContainerSummary> = parse_json(LISTING.as_bytes()).unwrap();
+        let env = vec![
+            "POSTGRES_USER=shop".to_string(),
+            "POSTGRES_PASSWORD=s3cret=1".to_string(),
+            "PATH=/usr/bin".to_string(),
+        ];
+
+        let candidate = containers[0].candidate(&env).unwrap();
+
+        assert_eq!(candidate.name, "shop-db");
+        assert_eq!(candidate.source, ImportSource::Container);
+        assert_eq!(
+            candidate.config,
+            ConnectionConfig::PostgreSQL(PostgresConnectionConfig::new(
+                "localhost",
+                55432,
+                "shop",
+                "shop",
+                "s3cret=1",
+                SslMode::Prefer,
+            ))
+        );
+    }
+
+    #[test]
+    fn non_database_and_unpublished_containers_are_skipped() {
+        let containers: Vec<ContainerSummary> = parse_json(LISTING.as_bytes()).unwrap();
+
+        assert!(containers[1].candidate(&[]).is_none());
+        assert!(containers[2].candidate(&[]).is_none());
+    }
+
+    #[test]
+    fn mysql_container_falls_back_to_root_credentials() {
+        let container = ContainerSummary {
+            id: "abc".to_string(),
+            names: vec![],
+            image: "mysql:8".to_string(),
+            ports: vec![PortMapping {
+                private_port: 3306,
+                public_port: Some(33060),
+                protocol: "tcp".to_string(),
+            }],
+        };
+
+        let candidate = container
+            .candidate(&["MYSQL_ROOT_PASSWORD=root".to_string()])
+            .unwrap();
+
+        assert_eq!(candidate.name, "abc");
+        assert_eq!(
+            candidate.config,
+            ConnectionConfig::MySQL(MySqlConnectionConfig::new(
+                "127.0.0.1",
+                33060,
+                "mysql",
+                "root",
+                "root",
+            ))
+        );
+    }
+
+    #[test]
+    fn non_ok_status_is_reported() {
+        let response =
+            b"HTTP/1.0 404 Not Found\r\nContent-Type: text/plain\r\n\r\nno such container";
+
+        let result = response_body(response.to_vec());
+
+        assert!(matches!(
+            result,
+            Err(ContainerScanError::InvalidResponse(line)) if line == "HTTP/1.0 404 Not Found"
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scans_through_a_unix_socket() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::os::unix::net::UnixListener;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let socket = dir.path().join("docker.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+        let server = std::thread::spawn(move || {
+            for _ in 0..2 {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request_line = String::new();
+                BufReader::new(&stream)
+                    .read_line(&mut request_line)
+                    .unwrap();
+                let body = if request_line.starts_with("GET /containers/json ") {
+                    LISTING.to_string()
+                } else {
+                    r#"{"Config": {"Env": ["POSTGRES_DB=orders"]}}"#.to_string()
+                };
+                write!(
+                    stream,
+                    "HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n{body}"
+                )
+                .unwrap();
+            }
+        });
+        let scanner = DockerSocketScanner {
+            home_dir: None,
+            runtime_dir: None,
+        };
+
+        let candidates = scanner.scan_socket(&socket).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].summary(), "postgres@localhost:55432/orders");
+    }
+}
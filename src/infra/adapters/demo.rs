@@ -0,0 +1,647 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use crate::app::ports::outbound::{
+    AccessMode, DbOperationError, MetadataProvider, PreviewOptions, QueryExecutor, TransactionEnd,
+    project_preview_columns,
+};
+use crate::domain::{
+    Column, ColumnAttributes, DatabaseMetadata, FkAction, ForeignKey, Index, IndexAttributes,
+    IndexType, QueryResult, QuerySource, Schema, Table, TableKindInfo, TableSignature,
+    TableSummary, WriteExecutionResult,
+};
+
+const DEMO_DATABASE: &str = "demo";
+const DEMO_SCHEMA: &str = "public";
+const READ_ONLY_MESSAGE: &str = "Demo mode is read-only";
+
+pub(crate) struct SampleTable {
+    pub(crate) table: Table,
+    pub(crate) rows: Vec<Vec<String>>,
+}
+
+/// Serves a bundled sample schema and canned rows so every feature can be
+/// explored without a database. Any DSN is accepted.
+pub struct DemoAdapter {
+    tables: Vec<SampleTable>,
+}
+
+impl Default for DemoAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DemoAdapter {
+    pub fn new() -> Self {
+        Self {
+            tables: vec![users(), products(), orders(), order_items()],
+        }
+    }
+
+    /// Serves `tables` instead of the bundled sample schema.
+    pub(crate) fn with_tables(tables: Vec<SampleTable>) -> Self {
+        Self { tables }
+    }
+
+    fn find(&self, schema: &str, table: &str) -> Result<&SampleTable, DbOperationError> {
+        self.tables
+            .iter()
+            .find(|sample| sample.table.schema == schema && sample.table.name == table)
+            .ok_or_else(|| {
+                DbOperationError::ObjectMissing(format!(
+                    "{schema}.{table} is not part of the sample schema"
+                ))
+            })
+    }
+
+    // Canned results: the first sample table named after FROM answers the query.
+    fn answer(&self, query: &str) -> Result<&SampleTable, DbOperationError> {
+        let lower = query.to_lowercase();
+        if lower.trim_start().starts_with("explain") {
+            return Err(DbOperationError::UnsupportedOperation(
+                "EXPLAIN is not available in demo mode".to_string(),
+            ));
+        }
+        let mut tokens = lower
+            .split(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | '(' | ')'))
+            .filter(|token| !token.is_empty());
+        while let Some(token) = tokens.next() {
+            if token != "from" {
+                continue;
+            }
+            let Some(target) = tokens.next() else {
+                break;
+            };
+            let target = target.replace('"', "");
+            let found = match target.split_once('.') {
+                Some((schema, name)) => self.find(schema, name).ok(),
+                None => self
+                    .tables
+                    .iter()
+                    .find(|sample| sample.table.name == target),
+            };
+            if let Some(sample) = found {
+                return Ok(sample);
+            }
+        }
+        Err(DbOperationError::QueryFailed(
+            "Demo mode only answers SELECTs from the sample tables".to_string(),
+        ))
+    }
+}
+
+fn result(
+    query: &str,
+    sample: &SampleTable,
+    rows: &[Vec<String>],
+    source: QuerySource,
+) -> QueryResult {
+    QueryResult::success(
+        query.to_string(),
+        sample
+            .table
+            .columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect(),
+        rows.to_vec(),
+        0,
+        source,
+    )
+}
+
+#[async_trait]
+impl MetadataProvider for DemoAdapter {
+    async fn fetch_metadata(&self, _dsn: &str) -> Result<DatabaseMetadata, DbOperationError> {
+        let mut metadata = DatabaseMetadata::new(DEMO_DATABASE.to_string());
+        let mut schemas: Vec<&str> = Vec::new();
+        for sample in &self.tables {
+            if !schemas.contains(&sample.table.schema.as_str()) {
+                schemas.push(&sample.table.schema);
+            }
+        }
+        metadata.schemas = schemas.into_iter().map(Schema::new).collect();
+        metadata.table_summaries = self
+            .tables
+            .iter()
+            .map(|sample| {
+                TableSummary::new(
+                    sample.table.schema.clone(),
+                    sample.table.name.clone(),
+                    sample.table.row_count_estimate,
+                    false,
+                )
+            })
+            .collect();
+        Ok(metadata)
+    }
+
+    async fn fetch_table_detail(
+        &self,
+        _dsn: &str,
+        schema: &str,
+        table: &str,
+    ) -> Result<Table, DbOperationError> {
+        self.find(schema, table).map(|sample| sample.table.clone())
+    }
+
+    async fn fetch_table_columns_and_fks(
+        &self,
+        _dsn: &str,
+        schema: &str,
+        table: &str,
+    ) -> Result<Table, DbOperationError> {
+        self.find(schema, table).map(|sample| sample.table.clone())
+    }
+
+    async fn fetch_table_signatures(
+        &self,
+        _dsn: &str,
+    ) -> Result<Vec<TableSignature>, DbOperationError> {
+        Ok(self
+            .tables
+            .iter()
+            .map(|sample| TableSignature {
+                schema: sample.table.schema.clone(),
+                name: sample.table.name.clone(),
+                signature: sample
+                    .table
+                    .columns
+                    .iter()
+                    .map(|column| format!("{}:{}", column.name, column.data_type))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            })
+            .collect())
+    }
+}
+
+// Sample values are text; numbers compare numerically so `o` orders ids and
+// prices the way a database would.
+fn compare_sample_values(a: &str, b: &str) -> std::cmp::Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+#[async_trait]
+impl QueryExecutor for DemoAdapter {
+    async fn execute_preview(
+        &self,
+        _dsn: &str,
+        schema: &str,
+        table: &str,
+        options: PreviewOptions,
+        limit: usize,
+        offset: usize,
+    ) -> Result<QueryResult, DbOperationError> {
+        let PreviewOptions {
+            sort_column,
+            filter,
+            columns,
+        } = options;
+        if filter.is_some() {
+            return Err(DbOperationError::UnsupportedOperation(
+                "Preview filters are not available in demo mode".to_string(),
+            ));
+        }
+        let sample = self.find(schema, table)?;
+        let mut ordered: Vec<_> = sample.rows.iter().collect();
+        let sort_index = sort_column.as_deref().and_then(|sort_column| {
+            sample
+                .table
+                .columns
+                .iter()
+                .position(|column| column.name == sort_column)
+        });
+        if let Some(index) = sort_index {
+            ordered.sort_by(|a, b| compare_sample_values(&a[index], &b[index]));
+        }
+        let names: Vec<String> = sample
+            .table
+            .columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect();
+        let projected = project_preview_columns(&names, &columns);
+        let indexes: Vec<usize> = projected
+            .iter()
+            .filter_map(|name| names.iter().position(|column| column == name))
+            .collect();
+        let rows: Vec<Vec<String>> = ordered
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|row| indexes.iter().map(|&index| row[index].clone()).collect())
+            .collect();
+        let select_list = if columns.is_empty() {
+            "*".to_string()
+        } else {
+            projected.join(", ")
+        };
+        let order_clause = sort_column
+            .as_deref()
+            .map_or_else(String::new, |column| format!(" ORDER BY {column}"));
+        let query = format!(
+            "SELECT {select_list} FROM {schema}.{table}{order_clause} LIMIT {limit} OFFSET {offset}"
+        );
+        Ok(QueryResult::success(
+            query,
+            projected,
+            rows,
+            0,
+            QuerySource::Preview,
+        ))
+    }
+
+    async fn execute_adhoc(
+        &self,
+        _dsn: &str,
+        query: &str,
+        _access_mode: AccessMode,
+    ) -> Result<QueryResult, DbOperationError> {
+        let sample = self.answer(query)?;
+        Ok(result(query, sample, &sample.rows, QuerySource::Adhoc))
+    }
+
+    async fn execute_write(
+        &self,
+        _dsn: &str,
+        _query: &str,
+        _access_mode: AccessMode,
+    ) -> Result<WriteExecutionResult, DbOperationError> {
+        Err(DbOperationError::UnsupportedOperation(
+            READ_ONLY_MESSAGE.to_string(),
+        ))
+    }
+
+    async fn count_query_rows(&self, _dsn: &str, query: &str) -> Result<usize, DbOperationError> {
+        self.answer(query).map(|sample| sample.rows.len())
+    }
+
+    async fn export_to_csv(
+        &self,
+        _dsn: &str,
+        _query: &str,
+        _file_name: &str,
+    ) -> Result<PathBuf, DbOperationError> {
+        Err(DbOperationError::UnsupportedOperation(
+            "CSV export is not available in demo mode".to_string(),
+        ))
+    }
+
+    async fn begin_transaction(
+        &self,
+        _dsn: &str,
+        _access_mode: AccessMode,
+    ) -> Result<(), DbOperationError> {
+        Err(DbOperationError::UnsupportedOperation(
+            "Transactions are not available in demo mode".to_string(),
+        ))
+    }
+
+    async fn end_transaction(
+        &self,
+        _dsn: &str,
+        _end: TransactionEnd,
+    ) -> Result<(), DbOperationError> {
+        Err(DbOperationError::UnsupportedOperation(
+            "Transactions are not available in demo mode".to_string(),
+        ))
+    }
+}
+
+fn column(name: &str, data_type: &str, attributes: ColumnAttributes, position: i32) -> Column {
+    Column {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+        default: None,
+        attributes,
+        comment: None,
+        ordinal_position: position,
+    }
+}
+
+fn id_column() -> Column {
+    Column {
+        default: Some("nextval('id_seq'::regclass)".to_string()),
+        ..column("id", "integer", ColumnAttributes::PRIMARY_KEY, 1)
+    }
+}
+
+fn primary_key_index(table: &str, columns: &[&str]) -> Index {
+    Index {
+        name: format!("{table}_pkey"),
+        columns: columns.iter().map(|column| (*column).to_string()).collect(),
+        attributes: IndexAttributes::from_parts(true, true),
+        index_type: IndexType::BTree,
+        definition: None,
+    }
+}
+
+fn foreign_key(from_table: &str, from_column: &str, to_table: &str) -> ForeignKey {
+    ForeignKey {
+        name: format!("{from_table}_{from_column}_fkey"),
+        from_schema: DEMO_SCHEMA.to_string(),
+        from_table: from_table.to_string(),
+        from_columns: vec![from_column.to_string()],
+        to_schema: DEMO_SCHEMA.to_string(),
+        to_table: to_table.to_string(),
+        to_columns: vec!["id".to_string()],
+        on_delete: FkAction::Cascade,
+        on_update: FkAction::NoAction,
+        reference_resolved: true,
+    }
+}
+
+fn sample_table(
+    name: &str,
+    comment: &str,
+    columns: Vec<Column>,
+    primary_key: &[&str],
+    foreign_keys: Vec<ForeignKey>,
+    rows: Vec<Vec<&'static str>>,
+) -> SampleTable {
+    SampleTable {
+        table: Table {
+            schema: DEMO_SCHEMA.to_string(),
+            name: name.to_string(),
+            owner: Some("demo".to_string()),
+            columns,
+            primary_key: Some(primary_key.iter().map(|key| (*key).to_string()).collect()),
+            foreign_keys,
+            indexes: vec![primary_key_index(name, primary_key)],
+            rls: None,
+            triggers: Vec::new(),
+            row_count_estimate: Some(i64::try_from(rows.len()).unwrap_or(i64::MAX)),
+            comment: Some(comment.to_string()),
+            source_ddl: None,
+            kind_info: TableKindInfo::default(),
+            hypertable: None,
+            column_stats: Vec::new(),
+            storage: None,
+        },
+        rows: rows
+            .into_iter()
+            .map(|row| row.into_iter().map(str::to_string).collect())
+            .collect(),
+    }
+}
+
+fn users() -> SampleTable {
+    sample_table(
+        "users",
+        "Registered customers",
+        vec![
+            id_column(),
+            column(
+                "email",
+                "text",
+                ColumnAttributes::from_parts(false, false, true),
+                2,
+            ),
+            column("name", "text", ColumnAttributes::empty(), 3),
+            column(
+                "created_at",
+                "timestamp with time zone",
+                ColumnAttributes::empty(),
+                4,
+            ),
+        ],
+        &["id"],
+        Vec::new(),
+        vec![
+            vec![
+                "1",
+                "ada@example.com",
+                "Ada Lovelace",
+                "2024-01-03 09:12:00+00",
+            ],
+            vec![
+                "2",
+                "alan@example.com",
+                "Alan Turing",
+                "2024-01-15 14:40:00+00",
+            ],
+            vec![
+                "3",
+                "grace@example.com",
+                "Grace Hopper",
+                "2024-02-02 08:05:00+00",
+            ],
+            vec![
+                "4",
+                "edsger@example.com",
+                "Edsger Dijkstra",
+                "2024-03-21 17:30:00+00",
+            ],
+        ],
+    )
+}
+
+fn products() -> SampleTable {
+    sample_table(
+        "products",
+        "Catalog items",
+        vec![
+            id_column(),
+            column("name", "text", ColumnAttributes::empty(), 2),
+            column("price", "numeric(10,2)", ColumnAttributes::empty(), 3),
+            column("stock", "integer", ColumnAttributes::NULLABLE, 4),
+        ],
+        &["id"],
+        Vec::new(),
+        vec![
+            vec!["1", "Mechanical keyboard", "129.00", "42"],
+            vec!["2", "Trackball", "79.50", "17"],
+            vec!["3", "Monitor arm", "64.99", "5"],
+        ],
+    )
+}
+
+fn orders() -> SampleTable {
+    sample_table(
+        "orders",
+        "Customer orders",
+        vec![
+            id_column(),
+            column("user_id", "integer", ColumnAttributes::empty(), 2),
+            column("status", "text", ColumnAttributes::empty(), 3),
+            column(
+                "placed_at",
+                "timestamp with time zone",
+                ColumnAttributes::empty(),
+                4,
+            ),
+        ],
+        &["id"],
+        vec![foreign_key("orders", "user_id", "users")],
+        vec![
+            vec!["1", "1", "shipped", "2024-02-10 10:00:00+00"],
+            vec!["2", "2", "pending", "2024-03-01 12:30:00+00"],
+            vec!["3", "1", "cancelled", "2024-03-05 16:45:00+00"],
+        ],
+    )
+}
+
+fn order_items() -> SampleTable {
+    sample_table(
+        "order_items",
+        "Line items per order",
+        vec![
+            column("order_id", "integer", ColumnAttributes::PRIMARY_KEY, 1),
+            column("product_id", "integer", ColumnAttributes::PRIMARY_KEY, 2),
+            column("quantity", "integer", ColumnAttributes::empty(), 3),
+        ],
+        &["order_id", "product_id"],
+        vec![
+            foreign_key("order_items", "order_id", "orders"),
+            foreign_key("order_items", "product_id", "products"),
+        ],
+        vec![
+            vec!["1", "1", "1"],
+            vec!["1", "2", "2"],
+            vec!["2", "3", "1"],
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DSN: &str = "demo://sample";
+
+    #[tokio::test]
+    async fn metadata_lists_every_sample_table() {
+        let adapter = DemoAdapter::new();
+
+        let metadata = adapter.fetch_metadata(DSN).await.unwrap();
+
+        let names: Vec<_> = metadata
+            .table_summaries
+            .iter()
+            .map(|summary| summary.name.as_str())
+            .collect();
+        assert_eq!(names, ["users", "products", "orders", "order_items"]);
+    }
+
+    #[test]
+    fn foreign_keys_point_at_sample_tables() {
+        let adapter = DemoAdapter::new();
+
+        for sample in &adapter.tables {
+            for fk in &sample.table.foreign_keys {
+                assert!(adapter.find(&fk.to_schema, &fk.to_table).is_ok());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn preview_orders_by_the_sort_column_numerically() {
+        let adapter = DemoAdapter::new();
+
+        let result = adapter
+            .execute_preview(
+                DSN,
+                "public",
+                "products",
+                PreviewOptions {
+                    sort_column: Some("price".to_string()),
+                    ..PreviewOptions::default()
+                },
+                10,
+                0,
+            )
+            .await
+            .unwrap();
+
+        let prices: Vec<_> = (0..result.row_count())
+            .filter_map(|row| result.display_value_at(row, 2))
+            .collect();
+        assert_eq!(prices, ["64.99", "79.50", "129.00"]);
+        assert!(result.query.contains("ORDER BY price"));
+    }
+
+    #[tokio::test]
+    async fn preview_applies_limit_and_offset() {
+        let adapter = DemoAdapter::new();
+
+        let result = adapter
+            .execute_preview(DSN, "public", "users", PreviewOptions::default(), 2, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(result.row_count(), 2);
+        assert_eq!(
+            result.display_value_at(0, 2).as_deref(),
+            Some("Alan Turing")
+        );
+    }
+
+    #[tokio::test]
+    async fn preview_selects_only_the_projected_columns() {
+        let adapter = DemoAdapter::new();
+        let options = PreviewOptions {
+            columns: vec!["name".to_string(), "id".to_string()],
+            ..PreviewOptions::default()
+        };
+
+        let result = adapter
+            .execute_preview(DSN, "public", "users", options, 1, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(result.columns, ["name", "id"]);
+        assert_eq!(
+            result.display_value_at(0, 0).as_deref(),
+            Some("Alan Turing")
+        );
+        assert!(result.query.starts_with("SELECT name, id FROM"));
+    }
+
+    #[tokio::test]
+    async fn adhoc_select_returns_rows_of_the_named_table() {
+        let adapter = DemoAdapter::new();
+
+        let result = adapter
+            .execute_adhoc(
+                DSN,
+                "SELECT * FROM public.\"orders\" WHERE status = 'shipped'",
+                AccessMode::ReadWrite,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.columns[2], "status");
+        assert_eq!(result.row_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn adhoc_without_sample_table_fails() {
+        let adapter = DemoAdapter::new();
+
+        let result = adapter
+            .execute_adhoc(DSN, "SELECT 1", AccessMode::ReadWrite)
+            .await;
+
+        assert!(matches!(result, Err(DbOperationError::QueryFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn writes_are_rejected() {
+        let adapter = DemoAdapter::new();
+
+        let result = adapter
+            .execute_write(DSN, "DELETE FROM users", AccessMode::ReadWrite)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DbOperationError::UnsupportedOperation(_))
+        ));
+    }
+}
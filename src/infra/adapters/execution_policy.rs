@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::app::ports::outbound::{
+    ExecutionPolicies, ExecutionPolicy, ExecutionPolicyError, ExecutionPolicyReader,
+};
+
+/// Lives in the project root so a team can check its guardrails in.
+pub const POLICY_FILE_NAME: &str = ".sabiql.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyFile {
+    #[serde(default)]
+    profiles: HashMap<String, PolicyEntry>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyEntry {
+    statements: Option<String>,
+    max_rows: Option<usize>,
+    auto_limit: Option<usize>,
+    #[serde(default)]
+    require_where: bool,
+}
+
+fn policy(name: &str, entry: PolicyEntry) -> Result<ExecutionPolicy, ExecutionPolicyError> {
+    let select_only = match entry.statements.as_deref().map(str::trim) {
+        None | Some("all") => false,
+        Some("select") => true,
+        Some(other) => {
+            return Err(ExecutionPolicyError::Parse(format!(
+                "{name}: statements = \"{other}\" (expected select or all)"
+            )));
+        }
+    };
+    for (key, value) in [
+        ("max_rows", entry.max_rows),
+        ("auto_limit", entry.auto_limit),
+    ] {
+        if value == Some(0) {
+            return Err(ExecutionPolicyError::Parse(format!(
+                "{name}: {key} must be at least 1"
+            )));
+        }
+    }
+    if let (Some(auto_limit), Some(max_rows)) = (entry.auto_limit, entry.max_rows)
+        && auto_limit > max_rows
+    {
+        return Err(ExecutionPolicyError::Parse(format!(
+            "{name}: auto_limit ({auto_limit}) exceeds max_rows ({max_rows})"
+        )));
+    }
+    Ok(ExecutionPolicy {
+        select_only,
+        max_rows: entry.max_rows,
+        auto_limit: entry.auto_limit,
+        require_where: entry.require_where,
+    })
+}
+
+fn parse(content: &str) -> Result<ExecutionPolicies, ExecutionPolicyError> {
+    let file: PolicyFile =
+        toml::from_str(content).map_err(|e| ExecutionPolicyError::Parse(e.to_string()))?;
+    file.profiles
+        .into_iter()
+        .map(|(name, entry)| policy(&name, entry).map(|policy| (name, policy)))
+        .collect::<Result<HashMap<_, _>, _>>()
+        .map(ExecutionPolicies::new)
+}
+
+#[derive(Default)]
+pub struct TomlExecutionPolicyReader;
+
+impl TomlExecutionPolicyReader {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ExecutionPolicyReader for TomlExecutionPolicyReader {
+    fn read_policies(
+        &self,
+        project_root: &Path,
+    ) -> Result<ExecutionPolicies, ExecutionPolicyError> {
+        let path = project_root.join(POLICY_FILE_NAME);
+        if !path.is_file() {
+            return Ok(ExecutionPolicies::default());
+        }
+        let content =
+            std::fs::read_to_string(&path).map_err(|source| ExecutionPolicyError::ReadAt {
+                path: path.clone(),
+                source: Arc::new(source),
+            })?;
+        parse(&content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn reads_policies_per_connection() {
+        let policies = parse(
+            r#"
+[profiles.prod]
+statements = "select"
+max_rows = 1000
+auto_limit = 200
+require_where = true
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            policies.for_connection("prod"),
+            Some(&ExecutionPolicy {
+                select_only: true,
+                max_rows: Some(1000),
+                auto_limit: Some(200),
+                require_where: true,
+            })
+        );
+        assert_eq!(policies.for_connection("dev"), None);
+    }
+
+    #[test]
+    fn rejects_unknown_statement_class() {
+        let result = parse("[profiles.prod]\nstatements = \"dml\"\n");
+
+        assert!(matches!(result, Err(ExecutionPolicyError::Parse(msg)) if msg.contains("dml")));
+    }
+
+    #[test]
+    fn rejects_auto_limit_above_max_rows() {
+        let result = parse("[profiles.prod]\nmax_rows = 10\nauto_limit = 50\n");
+
+        assert!(matches!(result, Err(ExecutionPolicyError::Parse(_))));
+    }
+
+    #[test]
+    fn missing_file_yields_no_policies() {
+        let tmp = TempDir::new().unwrap();
+
+        let policies = TomlExecutionPolicyReader::new()
+            .read_policies(tmp.path())
+            .unwrap();
+
+        assert!(policies.is_empty());
+    }
+}
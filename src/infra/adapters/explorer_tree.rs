@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+
+use crate::app::ports::outbound::{ExplorerTreeError, ExplorerTreeStore};
+use crate::config::cache::{CacheDirError, CacheWrites, ReadOnlyCache, get_cache_dir};
+use crate::domain::ExplorerTreeLayout;
+
+const EXPLORER_TREE_FILE_NAME: &str = "explorer.json";
+
+impl From<CacheDirError> for ExplorerTreeError {
+    fn from(error: CacheDirError) -> Self {
+        match error {
+            CacheDirError::BaseDirUnavailable => Self::MissingCacheDir,
+            CacheDirError::Io(error) => error.into(),
+        }
+    }
+}
+
+pub struct FileExplorerTreeStore {
+    base_dir: Option<PathBuf>,
+    writes: CacheWrites,
+}
+
+impl ReadOnlyCache for FileExplorerTreeStore {
+    fn cache_writes_mut(&mut self) -> &mut CacheWrites {
+        &mut self.writes
+    }
+}
+
+impl Default for FileExplorerTreeStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileExplorerTreeStore {
+    pub fn new() -> Self {
+        Self {
+            base_dir: None,
+            writes: CacheWrites::default(),
+        }
+    }
+
+    #[cfg(test)]
+    fn with_base_dir(base_dir: PathBuf) -> Self {
+        Self {
+            base_dir: Some(base_dir),
+            writes: CacheWrites::default(),
+        }
+    }
+
+    fn resolve_path(&self, project_name: &str) -> Result<PathBuf, ExplorerTreeError> {
+        let dir = match &self.base_dir {
+            Some(base) => base.clone(),
+            None => get_cache_dir(project_name)?,
+        };
+        Ok(dir.join(EXPLORER_TREE_FILE_NAME))
+    }
+}
+
+impl ExplorerTreeStore for FileExplorerTreeStore {
+    fn load(&self, project_name: &str) -> Result<ExplorerTreeLayout, ExplorerTreeError> {
+        let path = self.resolve_path(project_name)?;
+        if !path.exists() {
+            return Ok(ExplorerTreeLayout::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    fn save(
+        &self,
+        project_name: &str,
+        layout: &ExplorerTreeLayout,
+    ) -> Result<(), ExplorerTreeError> {
+        if self.writes.dropped() {
+            return Ok(());
+        }
+        let path = self.resolve_path(project_name)?;
+        if let Some(dir) = path.parent()
+            && !dir.exists()
+        {
+            std::fs::create_dir_all(dir)?;
+        }
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_string(layout)?)?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn missing_file_loads_default_layout() {
+        let tmp = TempDir::new().unwrap();
+        let store = FileExplorerTreeStore::with_base_dir(tmp.path().to_path_buf());
+
+        assert_eq!(store.load("test").unwrap(), ExplorerTreeLayout::default());
+    }
+
+    #[test]
+    fn save_and_load_round_trips_collapsed_schemas() {
+        let tmp = TempDir::new().unwrap();
+        let store = FileExplorerTreeStore::with_base_dir(tmp.path().to_path_buf());
+        let mut layout = ExplorerTreeLayout::default();
+        layout.toggle("audit");
+
+        store.save("test", &layout).unwrap();
+
+        assert_eq!(store.load("test").unwrap(), layout);
+        assert!(!tmp.path().join("explorer.json.tmp").exists());
+    }
+
+    #[test]
+    fn read_only_store_drops_writes() {
+        let tmp = TempDir::new().unwrap();
+        let store =
+            FileExplorerTreeStore::with_base_dir(tmp.path().to_path_buf()).with_read_only(true);
+        let mut layout = ExplorerTreeLayout::default();
+        layout.toggle("audit");
+
+        store.save("test", &layout).unwrap();
+
+        assert!(!tmp.path().join(EXPLORER_TREE_FILE_NAME).exists());
+    }
+}
@@ -0,0 +1,387 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::app::ports::outbound::{MetadataSnapshotError, MetadataSnapshotStore};
+use crate::config::cache::{CacheDirError, CacheWrites, ReadOnlyCache, get_cache_dir};
+use crate::config::cache_cipher::{CacheCipher, seal_if, unseal};
+use crate::domain::connection::ConnectionId;
+use crate::domain::{DatabaseMetadata, MetadataSnapshot, Table};
+
+const METADATA_FILE: &str = "metadata.json";
+const TABLES_DIR: &str = "tables";
+
+impl From<CacheDirError> for MetadataSnapshotError {
+    fn from(error: CacheDirError) -> Self {
+        match error {
+            CacheDirError::BaseDirUnavailable => Self::MissingCacheDir,
+            CacheDirError::Io(error) => error.into(),
+        }
+    }
+}
+
+// Write-then-rename so a crash mid-write never leaves a truncated snapshot behind.
+fn write_atomically(path: &Path, contents: &str) -> Result<(), MetadataSnapshotError> {
+    if let Some(dir) = path.parent()
+        && !dir.exists()
+    {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, contents)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+fn table_file_name(schema: &str, name: &str) -> String {
+    format!("{}.json", urlencoding::encode(&format!("{schema}.{name}")))
+}
+
+fn load_snapshot(
+    dir: &Path,
+    cipher: Option<&CacheCipher>,
+) -> Result<Option<MetadataSnapshot>, MetadataSnapshotError> {
+    let metadata_path = dir.join(METADATA_FILE);
+    if !metadata_path.exists() {
+        return Ok(None);
+    }
+    // Sealed under another key: treat as a cold start and fetch afresh.
+    let content = std::fs::read_to_string(&metadata_path)?;
+    let Some(content) = unseal(cipher, &content) else {
+        return Ok(None);
+    };
+    let metadata: DatabaseMetadata = serde_json::from_str(&content)?;
+
+    let mut tables = Vec::new();
+    let tables_dir = dir.join(TABLES_DIR);
+    if tables_dir.is_dir() {
+        for entry in std::fs::read_dir(&tables_dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            // Unreadable or dropped tables are skipped; the rest of the snapshot is still useful.
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let Some(content) = unseal(cipher, &content) else {
+                continue;
+            };
+            let Ok(table) = serde_json::from_str::<Table>(&content) else {
+                continue;
+            };
+            let still_listed = metadata
+                .table_summaries
+                .iter()
+                .any(|summary| summary.schema == table.schema && summary.name == table.name);
+            if still_listed {
+                tables.push(table);
+            }
+        }
+    }
+
+    Ok(Some(MetadataSnapshot { metadata, tables }))
+}
+
+pub struct FileMetadataSnapshotStore {
+    base_dir: Option<PathBuf>,
+    writes: CacheWrites,
+    cipher: Option<Arc<CacheCipher>>,
+}
+
+impl ReadOnlyCache for FileMetadataSnapshotStore {
+    fn cache_writes_mut(&mut self) -> &mut CacheWrites {
+        &mut self.writes
+    }
+}
+
+impl Default for FileMetadataSnapshotStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileMetadataSnapshotStore {
+    pub fn new() -> Self {
+        Self {
+            base_dir: None,
+            writes: CacheWrites::default(),
+            cipher: None,
+        }
+    }
+
+    /// Seals every file written from now on; plain snapshots still load.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Option<Arc<CacheCipher>>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    #[cfg(test)]
+    fn with_base_dir(base_dir: PathBuf) -> Self {
+        Self {
+            base_dir: Some(base_dir),
+            writes: CacheWrites::default(),
+            cipher: None,
+        }
+    }
+
+    fn resolve_snapshot_dir(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+    ) -> Result<PathBuf, MetadataSnapshotError> {
+        let base = match &self.base_dir {
+            Some(base) => base.clone(),
+            None => get_cache_dir(project_name)?,
+        };
+        Ok(base.join("snapshots").join(connection_id.as_str()))
+    }
+
+    async fn save_table(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+        table: &Table,
+        replace_existing: bool,
+    ) -> Result<(), MetadataSnapshotError> {
+        if self.writes.dropped() {
+            return Ok(());
+        }
+        let path = self
+            .resolve_snapshot_dir(project_name, connection_id)?
+            .join(TABLES_DIR)
+            .join(table_file_name(&table.schema, &table.name));
+        let contents = seal_if(self.cipher.as_deref(), serde_json::to_string(table)?);
+
+        tokio::task::spawn_blocking(move || {
+            if !replace_existing && path.exists() {
+                return Ok(());
+            }
+            write_atomically(&path, &contents)
+        })
+        .await?
+    }
+}
+
+#[async_trait]
+impl MetadataSnapshotStore for FileMetadataSnapshotStore {
+    async fn save_metadata(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+        metadata: &DatabaseMetadata,
+    ) -> Result<(), MetadataSnapshotError> {
+        if self.writes.dropped() {
+            return Ok(());
+        }
+        let path = self
+            .resolve_snapshot_dir(project_name, connection_id)?
+            .join(METADATA_FILE);
+        let contents = seal_if(self.cipher.as_deref(), serde_json::to_string(metadata)?);
+
+        tokio::task::spawn_blocking(move || write_atomically(&path, &contents)).await?
+    }
+
+    async fn save_table_detail(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+        table: &Table,
+    ) -> Result<(), MetadataSnapshotError> {
+        self.save_table(project_name, connection_id, table, true)
+            .await
+    }
+
+    async fn save_table_columns(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+        table: &Table,
+    ) -> Result<(), MetadataSnapshotError> {
+        self.save_table(project_name, connection_id, table, false)
+            .await
+    }
+
+    async fn load(
+        &self,
+        project_name: &str,
+        connection_id: &ConnectionId,
+    ) -> Result<Option<MetadataSnapshot>, MetadataSnapshotError> {
+        let dir = self.resolve_snapshot_dir(project_name, connection_id)?;
+        let cipher = self.cipher.clone();
+        tokio::task::spawn_blocking(move || load_snapshot(&dir, cipher.as_deref())).await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::ports::outbound::CacheCategory;
+    use crate::domain::{TableKindInfo, TableSummary};
+    use tempfile::TempDir;
+
+    fn make_metadata(tables: &[(&str, &str)]) -> DatabaseMetadata {
+        let mut metadata = DatabaseMetadata::new("app".to_string());
+        metadata.table_summaries = tables
+            .iter()
+            .map(|(schema, name)| {
+                TableSummary::new((*schema).to_string(), (*name).to_string(), None, false)
+            })
+            .collect();
+        metadata
+    }
+
+    fn make_table(schema: &str, name: &str, source_ddl: Option<&str>) -> Table {
+        Table {
+            schema: schema.to_string(),
+            name: name.to_string(),
+            owner: None,
+            columns: Vec::new(),
+            primary_key: None,
+            foreign_keys: Vec::new(),
+            indexes: Vec::new(),
+            rls: None,
+            triggers: Vec::new(),
+            row_count_estimate: None,
+            comment: None,
+            source_ddl: source_ddl.map(str::to_string),
+            kind_info: TableKindInfo::default(),
+            hypertable: None,
+            column_stats: Vec::new(),
+            storage: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn load_without_metadata_returns_none() {
+        let tmp = TempDir::new().unwrap();
+        let store = FileMetadataSnapshotStore::with_base_dir(tmp.path().to_path_buf());
+        let conn_id = ConnectionId::from_string("test-conn");
+
+        assert!(store.load("test", &conn_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn saved_metadata_and_details_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let store = FileMetadataSnapshotStore::with_base_dir(tmp.path().to_path_buf());
+        let conn_id = ConnectionId::from_string("test-conn");
+
+        store
+            .save_metadata("test", &conn_id, &make_metadata(&[("public", "users")]))
+            .await
+            .unwrap();
+        store
+            .save_table_detail(
+                "test",
+                &conn_id,
+                &make_table("public", "users", Some("CREATE TABLE users ()")),
+            )
+            .await
+            .unwrap();
+
+        let snapshot = store.load("test", &conn_id).await.unwrap().unwrap();
+
+        assert_eq!(snapshot.metadata.table_summaries.len(), 1);
+        assert_eq!(
+            snapshot.metadata.table_summaries[0].qualified_name_lower(),
+            "public.users"
+        );
+        assert_eq!(
+            snapshot.table("public", "users").unwrap().source_ddl(),
+            Some("CREATE TABLE users ()")
+        );
+    }
+
+    #[tokio::test]
+    async fn prefetched_columns_do_not_replace_full_detail() {
+        let tmp = TempDir::new().unwrap();
+        let store = FileMetadataSnapshotStore::with_base_dir(tmp.path().to_path_buf());
+        let conn_id = ConnectionId::from_string("test-conn");
+
+        store
+            .save_metadata("test", &conn_id, &make_metadata(&[("public", "users")]))
+            .await
+            .unwrap();
+        store
+            .save_table_detail(
+                "test",
+                &conn_id,
+                &make_table("public", "users", Some("CREATE TABLE users ()")),
+            )
+            .await
+            .unwrap();
+        store
+            .save_table_columns("test", &conn_id, &make_table("public", "users", None))
+            .await
+            .unwrap();
+
+        let snapshot = store.load("test", &conn_id).await.unwrap().unwrap();
+
+        assert!(
+            snapshot
+                .table("public", "users")
+                .unwrap()
+                .source_ddl()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn tables_missing_from_metadata_are_dropped() {
+        let tmp = TempDir::new().unwrap();
+        let store = FileMetadataSnapshotStore::with_base_dir(tmp.path().to_path_buf());
+        let conn_id = ConnectionId::from_string("test-conn");
+
+        store
+            .save_table_detail("test", &conn_id, &make_table("public", "legacy", None))
+            .await
+            .unwrap();
+        store
+            .save_metadata("test", &conn_id, &make_metadata(&[("public", "users")]))
+            .await
+            .unwrap();
+
+        let snapshot = store.load("test", &conn_id).await.unwrap().unwrap();
+
+        assert!(snapshot.tables.is_empty());
+    }
+
+    #[tokio::test]
+    async fn sealed_snapshot_loads_only_with_its_cipher() {
+        let tmp = TempDir::new().unwrap();
+        let cipher = Arc::new(CacheCipher::new(&[3; 32], CacheCategory::Snapshots));
+        let store = FileMetadataSnapshotStore::with_base_dir(tmp.path().to_path_buf())
+            .with_cipher(Some(cipher));
+        let conn_id = ConnectionId::from_string("test-conn");
+        store
+            .save_metadata("test", &conn_id, &make_metadata(&[("public", "users")]))
+            .await
+            .unwrap();
+        store
+            .save_table_detail(
+                "test",
+                &conn_id,
+                &make_table("public", "users", Some("CREATE TABLE users ()")),
+            )
+            .await
+            .unwrap();
+
+        let snapshot = store.load("test", &conn_id).await.unwrap().unwrap();
+
+        assert_eq!(snapshot.tables.len(), 1);
+        let raw =
+            std::fs::read_to_string(tmp.path().join("snapshots/test-conn").join(METADATA_FILE))
+                .unwrap();
+        assert!(!raw.contains("users"));
+        let without_key = FileMetadataSnapshotStore::with_base_dir(tmp.path().to_path_buf());
+        assert!(without_key.load("test", &conn_id).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn table_file_name_escapes_path_separators() {
+        assert_eq!(table_file_name("public", "a/b"), "public.a%2Fb.json");
+    }
+}
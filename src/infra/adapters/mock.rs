@@ -0,0 +1,406 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::demo::{DemoAdapter, SampleTable};
+use crate::app::ports::outbound::{
+    AccessMode, DbOperationError, MetadataProvider, PreviewOptions, QueryExecutor, TransactionEnd,
+};
+use crate::domain::{
+    Column, ColumnAttributes, DatabaseMetadata, QueryResult, Table, TableKindInfo, TableSignature,
+    WriteExecutionResult,
+};
+
+const INJECTED_FAILURE: &str = "mock: injected failure";
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MockSchemaError {
+    #[error("failed to read mock schema: {0}")]
+    Io(#[source] Arc<std::io::Error>),
+    #[error("invalid mock schema: {0}")]
+    Parse(#[source] Arc<serde_json::Error>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MockOptions {
+    /// Added before every call answers.
+    pub latency: Duration,
+    /// Chance in `0.0..=1.0` that a call fails instead of answering.
+    pub failure_rate: f64,
+    /// Same seed, same sequence of failures.
+    pub seed: u64,
+}
+
+impl Default for MockOptions {
+    fn default() -> Self {
+        Self {
+            latency: Duration::ZERO,
+            failure_rate: 0.0,
+            seed: 0,
+        }
+    }
+}
+
+/// Answers like `DemoAdapter`, but every call waits `latency` and fails at
+/// `failure_rate`, so loading states, error paths, and stale responses can
+/// be exercised without a database.
+pub struct MockAdapter {
+    inner: DemoAdapter,
+    options: MockOptions,
+    calls: AtomicU64,
+}
+
+impl MockAdapter {
+    /// Serves the bundled demo schema.
+    pub fn new(options: MockOptions) -> Self {
+        Self::with_inner(DemoAdapter::new(), options)
+    }
+
+    /// Serves the tables described by a JSON schema file:
+    /// `{"tables": [{"schema": "public", "name": "t", "columns": [{"name": "id", "type": "integer"}], "rows": [["1"]]}]}`
+    pub fn from_schema_file(path: &Path, options: MockOptions) -> Result<Self, MockSchemaError> {
+        let content =
+            std::fs::read_to_string(path).map_err(|e| MockSchemaError::Io(Arc::new(e)))?;
+        let file: MockSchemaFile =
+            serde_json::from_str(&content).map_err(|e| MockSchemaError::Parse(Arc::new(e)))?;
+        let tables = file
+            .tables
+            .into_iter()
+            .map(MockTable::into_sample)
+            .collect();
+        Ok(Self::with_inner(DemoAdapter::with_tables(tables), options))
+    }
+
+    fn with_inner(inner: DemoAdapter, options: MockOptions) -> Self {
+        Self {
+            inner,
+            options,
+            calls: AtomicU64::new(0),
+        }
+    }
+
+    async fn script(
+        &self,
+        failure: fn(String) -> DbOperationError,
+    ) -> Result<(), DbOperationError> {
+        if !self.options.latency.is_zero() {
+            tokio::time::sleep(self.options.latency).await;
+        }
+        let call = self.calls.fetch_add(1, Ordering::Relaxed);
+        if roll(self.options.seed, call) < self.options.failure_rate {
+            return Err(failure(INJECTED_FAILURE.to_string()));
+        }
+        Ok(())
+    }
+}
+
+// SplitMix64 over (seed, call index), mapped to [0, 1).
+fn roll(seed: u64, call: u64) -> f64 {
+    let mut z = seed.wrapping_add(call.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+#[derive(Deserialize)]
+struct MockSchemaFile {
+    tables: Vec<MockTable>,
+}
+
+#[derive(Deserialize)]
+struct MockTable {
+    #[serde(default = "default_schema")]
+    schema: String,
+    name: String,
+    columns: Vec<MockColumn>,
+    #[serde(default)]
+    primary_key: Vec<String>,
+    #[serde(default)]
+    rows: Vec<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct MockColumn {
+    name: String,
+    #[serde(rename = "type", default = "default_type")]
+    data_type: String,
+    #[serde(default)]
+    nullable: bool,
+}
+
+fn default_schema() -> String {
+    "public".to_string()
+}
+
+fn default_type() -> String {
+    "text".to_string()
+}
+
+impl MockTable {
+    fn into_sample(self) -> SampleTable {
+        let columns = self
+            .columns
+            .into_iter()
+            .zip(1..)
+            .map(|(column, position)| Column {
+                attributes: ColumnAttributes::from_parts(
+                    column.nullable,
+                    self.primary_key.contains(&column.name),
+                    false,
+                ),
+                name: column.name,
+                data_type: column.data_type,
+                default: None,
+                comment: None,
+                ordinal_position: position,
+            })
+            .collect();
+        SampleTable {
+            table: Table {
+                schema: self.schema,
+                name: self.name,
+                owner: None,
+                columns,
+                primary_key: (!self.primary_key.is_empty()).then_some(self.primary_key),
+                foreign_keys: Vec::new(),
+                indexes: Vec::new(),
+                rls: None,
+                triggers: Vec::new(),
+                row_count_estimate: Some(i64::try_from(self.rows.len()).unwrap_or(i64::MAX)),
+                comment: None,
+                source_ddl: None,
+                kind_info: TableKindInfo::default(),
+                hypertable: None,
+                column_stats: Vec::new(),
+                storage: None,
+            },
+            rows: self.rows,
+        }
+    }
+}
+
+#[async_trait]
+impl MetadataProvider for MockAdapter {
+    async fn fetch_metadata(&self, dsn: &str) -> Result<DatabaseMetadata, DbOperationError> {
+        self.script(DbOperationError::ConnectionFailed).await?;
+        self.inner.fetch_metadata(dsn).await
+    }
+
+    async fn fetch_table_detail(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+    ) -> Result<Table, DbOperationError> {
+        self.script(DbOperationError::QueryFailed).await?;
+        self.inner.fetch_table_detail(dsn, schema, table).await
+    }
+
+    async fn fetch_table_columns_and_fks(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+    ) -> Result<Table, DbOperationError> {
+        self.script(DbOperationError::QueryFailed).await?;
+        self.inner
+            .fetch_table_columns_and_fks(dsn, schema, table)
+            .await
+    }
+
+    async fn fetch_table_signatures(
+        &self,
+        dsn: &str,
+    ) -> Result<Vec<TableSignature>, DbOperationError> {
+        self.script(DbOperationError::QueryFailed).await?;
+        self.inner.fetch_table_signatures(dsn).await
+    }
+}
+
+#[async_trait]
+impl QueryExecutor for MockAdapter {
+    async fn execute_preview(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+        options: PreviewOptions,
+        limit: usize,
+        offset: usize,
+    ) -> Result<QueryResult, DbOperationError> {
+        self.script(DbOperationError::QueryFailed).await?;
+        self.inner
+            .execute_preview(dsn, schema, table, options, limit, offset)
+            .await
+    }
+
+    async fn execute_adhoc(
+        &self,
+        dsn: &str,
+        query: &str,
+        access_mode: AccessMode,
+    ) -> Result<QueryResult, DbOperationError> {
+        self.script(DbOperationError::QueryFailed).await?;
+        self.inner.execute_adhoc(dsn, query, access_mode).await
+    }
+
+    async fn execute_write(
+        &self,
+        dsn: &str,
+        query: &str,
+        access_mode: AccessMode,
+    ) -> Result<WriteExecutionResult, DbOperationError> {
+        self.script(DbOperationError::QueryFailed).await?;
+        self.inner.execute_write(dsn, query, access_mode).await
+    }
+
+    async fn count_query_rows(&self, dsn: &str, query: &str) -> Result<usize, DbOperationError> {
+        self.script(DbOperationError::QueryFailed).await?;
+        self.inner.count_query_rows(dsn, query).await
+    }
+
+    async fn export_to_csv(
+        &self,
+        dsn: &str,
+        query: &str,
+        file_name: &str,
+    ) -> Result<PathBuf, DbOperationError> {
+        self.inner.export_to_csv(dsn, query, file_name).await
+    }
+
+    async fn begin_transaction(
+        &self,
+        dsn: &str,
+        access_mode: AccessMode,
+    ) -> Result<(), DbOperationError> {
+        self.inner.begin_transaction(dsn, access_mode).await
+    }
+
+    async fn end_transaction(
+        &self,
+        dsn: &str,
+        end: TransactionEnd,
+    ) -> Result<(), DbOperationError> {
+        self.inner.end_transaction(dsn, end).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    const DSN: &str = "mock://scripted";
+
+    fn options(failure_rate: f64) -> MockOptions {
+        MockOptions {
+            failure_rate,
+            ..MockOptions::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn zero_failure_rate_always_answers() {
+        let adapter = MockAdapter::new(options(0.0));
+
+        for _ in 0..20 {
+            assert!(adapter.fetch_metadata(DSN).await.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn full_failure_rate_always_fails() {
+        let adapter = MockAdapter::new(options(1.0));
+
+        let metadata = adapter.fetch_metadata(DSN).await;
+        let preview = adapter
+            .execute_preview(DSN, "public", "users", PreviewOptions::default(), 10, 0)
+            .await;
+
+        assert!(matches!(
+            metadata,
+            Err(DbOperationError::ConnectionFailed(_))
+        ));
+        assert!(matches!(preview, Err(DbOperationError::QueryFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn same_seed_fails_the_same_calls() {
+        let outcomes = |adapter: MockAdapter| async move {
+            let mut outcomes = Vec::new();
+            for _ in 0..32 {
+                outcomes.push(adapter.fetch_metadata(DSN).await.is_ok());
+            }
+            outcomes
+        };
+        let options = MockOptions {
+            failure_rate: 0.5,
+            seed: 7,
+            ..MockOptions::default()
+        };
+
+        let first = outcomes(MockAdapter::new(options)).await;
+        let second = outcomes(MockAdapter::new(options)).await;
+
+        assert_eq!(first, second);
+        assert!(first.contains(&true) && first.contains(&false));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn latency_delays_each_call() {
+        let adapter = MockAdapter::new(MockOptions {
+            latency: Duration::from_millis(250),
+            ..MockOptions::default()
+        });
+        let start = tokio::time::Instant::now();
+
+        adapter.fetch_metadata(DSN).await.unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[tokio::test]
+    async fn schema_file_replaces_the_demo_tables() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"{{"tables": [{{"schema": "app", "name": "events", "primary_key": ["id"],
+                "columns": [{{"name": "id", "type": "bigint"}}, {{"name": "kind"}}],
+                "rows": [["1", "login"], ["2", "logout"]]}}]}}"#
+        )
+        .unwrap();
+
+        let adapter = MockAdapter::from_schema_file(file.path(), MockOptions::default()).unwrap();
+        let metadata = adapter.fetch_metadata(DSN).await.unwrap();
+        let detail = adapter
+            .fetch_table_detail(DSN, "app", "events")
+            .await
+            .unwrap();
+        let result = adapter
+            .execute_adhoc(DSN, "SELECT * FROM app.events", AccessMode::ReadOnly)
+            .await
+            .unwrap();
+
+        assert_eq!(metadata.schemas[0].name, "app");
+        assert_eq!(metadata.table_summaries[0].name, "events");
+        assert_eq!(detail.columns[1].data_type, "text");
+        assert_eq!(detail.primary_key, Some(vec!["id".to_string()]));
+        assert_eq!(result.display_value_at(1, 1).as_deref(), Some("logout"));
+    }
+
+    #[test]
+    fn invalid_schema_file_is_rejected() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, r#"{{"tables": [{{"name": "missing_columns"}}]}}"#).unwrap();
+
+        let result = MockAdapter::from_schema_file(file.path(), MockOptions::default());
+
+        assert!(matches!(result, Err(MockSchemaError::Parse(_))));
+    }
+}
@@ -1,30 +1,65 @@
 mod app_config_file;
 
+pub mod audit_log;
+pub mod cache_store;
 pub mod cached_result_exporter;
 pub mod clipboard;
+pub mod column_masking;
 pub mod config_writer;
+pub mod connection_import;
 pub mod connection_store;
+pub mod container_scan;
 pub(crate) mod csv_export;
+pub mod demo;
 pub mod er_log_writer;
+pub mod execution_policy;
+pub mod explorer_tree;
 pub mod folder_opener;
+pub mod metadata_snapshot;
+pub mod mock;
 pub mod mysql;
+pub mod naming_convention;
 pub mod pg_service;
 pub mod postgres;
 pub mod query_history;
+pub mod query_hook;
 pub mod registry;
+pub mod release_check;
 pub mod settings_store;
+pub mod snippet;
+pub mod sql_file;
 pub mod sqlite;
+pub mod temp_file_writer;
 #[cfg(test)]
 pub(crate) mod test_support;
+pub mod usage_stats;
+pub use audit_log::FileAuditLogStore;
+pub use cache_store::FileCacheStore;
 pub use cached_result_exporter::CsvCachedResultExporter;
 pub use clipboard::ArboardClipboard;
+pub use column_masking::TomlMaskingRuleReader;
 pub use config_writer::FileConfigWriter;
+pub use connection_import::FsConnectionCandidateScanner;
 pub use connection_store::TomlConnectionStore;
+pub use container_scan::DockerSocketScanner;
+pub use demo::DemoAdapter;
 pub use er_log_writer::FsErLogWriter;
+pub use execution_policy::TomlExecutionPolicyReader;
+pub use explorer_tree::FileExplorerTreeStore;
 pub use folder_opener::NativeFolderOpener;
+pub use metadata_snapshot::FileMetadataSnapshotStore;
+pub use mock::{MockAdapter, MockOptions, MockSchemaError};
+pub use mysql::MySqlAdapter;
+pub use naming_convention::TomlNamingConventionReader;
 pub use pg_service::PgServiceFileReader;
 pub use postgres::PostgresAdapter;
 pub use query_history::FileQueryHistoryStore;
+pub use query_hook::{ShellQueryHookRunner, TomlQueryHookReader};
 pub use registry::DbAdapterRegistry;
+pub use release_check::GithubReleaseChecker;
 pub use settings_store::TomlSettingsStore;
+pub use snippet::TomlSnippetStore;
+pub use sql_file::{FsSqlFileScanner, FsSqlFileStore};
 pub use sqlite::{FsSqlitePathValidator, SqliteAdapter};
+pub use temp_file_writer::FsTempFileWriter;
+pub use usage_stats::FileUsageStatsStore;
@@ -0,0 +1,20 @@
+use super::cli::MySqlCli;
+
+#[derive(Debug, Clone)]
+pub struct MySqlAdapter {
+    pub(in crate::adapters::mysql) cli: MySqlCli,
+}
+
+impl MySqlAdapter {
+    pub fn new() -> Self {
+        Self {
+            cli: MySqlCli::new(),
+        }
+    }
+}
+
+impl Default for MySqlAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
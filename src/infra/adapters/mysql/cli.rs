@@ -0,0 +1,184 @@
+use std::process::{ExitStatus, Stdio};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::Command;
+use tokio::time::timeout;
+
+use crate::app::ports::outbound::DbOperationError;
+
+use super::dsn::MySqlDsn;
+use super::error::{classify_cli_spawn_error, classify_query_error};
+
+const CONNECT_TIMEOUT_SECS: u64 = 10;
+
+#[derive(Debug, Clone)]
+pub(in crate::adapters::mysql) struct MySqlCli {
+    timeout_secs: u64,
+}
+
+struct MySqlOutput {
+    status: ExitStatus,
+    stdout: String,
+    stderr: String,
+}
+
+impl MySqlCli {
+    pub(in crate::adapters::mysql) fn new() -> Self {
+        Self { timeout_secs: 30 }
+    }
+
+    /// Runs `sql` in batch mode and returns the tab-separated output with a header row.
+    pub(in crate::adapters::mysql) async fn execute_batch(
+        &self,
+        dsn: &MySqlDsn,
+        sql: &str,
+        read_only: bool,
+    ) -> Result<String, DbOperationError> {
+        self.run(dsn, &["--batch"], sql, read_only).await
+    }
+
+    /// Runs `sql` in batch mode without a header row; metadata queries use this.
+    pub(in crate::adapters::mysql) async fn execute_values(
+        &self,
+        dsn: &MySqlDsn,
+        sql: &str,
+    ) -> Result<String, DbOperationError> {
+        self.run(dsn, &["--batch", "--skip-column-names"], sql, true)
+            .await
+    }
+
+    async fn run(
+        &self,
+        dsn: &MySqlDsn,
+        args: &[&str],
+        sql: &str,
+        read_only: bool,
+    ) -> Result<String, DbOperationError> {
+        let mut cmd = Command::new("mysql");
+        apply_connection_options(&mut cmd, dsn, read_only);
+        for arg in args {
+            cmd.arg(arg);
+        }
+        let output = Self::collect_output(&mut cmd, self.timeout_secs, sql).await?;
+        if !output.status.success() {
+            return Err(classify_query_error(&output.stderr));
+        }
+        Ok(output.stdout)
+    }
+
+    async fn collect_output(
+        cmd: &mut Command,
+        timeout_secs: u64,
+        sql: &str,
+    ) -> Result<MySqlOutput, DbOperationError> {
+        let mut child = cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(classify_cli_spawn_error)?;
+
+        let stdin = child.stdin.take();
+        let mut stdout_handle = child.stdout.take();
+        let mut stderr_handle = child.stderr.take();
+
+        let result = timeout(Duration::from_secs(timeout_secs), async {
+            let (stdin_result, stdout_result, stderr_result) = tokio::join!(
+                write_sql_to_stdin(stdin, sql),
+                async {
+                    let mut buf = Vec::new();
+                    if let Some(ref mut stdout) = stdout_handle {
+                        stdout.read_to_end(&mut buf).await?;
+                    }
+                    Ok::<_, std::io::Error>(String::from_utf8_lossy(&buf).into_owned())
+                },
+                async {
+                    let mut buf = Vec::new();
+                    if let Some(ref mut stderr) = stderr_handle {
+                        stderr.read_to_end(&mut buf).await?;
+                    }
+                    Ok::<_, std::io::Error>(String::from_utf8_lossy(&buf).into_owned())
+                }
+            );
+
+            stdin_result?;
+            let stdout = stdout_result?;
+            let stderr = stderr_result?;
+            let status = child.wait().await?;
+            Ok::<_, std::io::Error>((status, stdout, stderr))
+        })
+        .await
+        .map_err(|error| DbOperationError::Timeout(error.to_string()))?
+        .map_err(|error| DbOperationError::QueryFailed(error.to_string()))?;
+
+        let (status, stdout, stderr) = result;
+        Ok(MySqlOutput {
+            status,
+            stdout,
+            stderr,
+        })
+    }
+}
+
+fn apply_connection_options(cmd: &mut Command, dsn: &MySqlDsn, read_only: bool) {
+    if !dsn.host.is_empty() {
+        cmd.arg(format!("--host={}", dsn.host));
+    }
+    cmd.arg(format!("--port={}", dsn.port));
+    if !dsn.user.is_empty() {
+        cmd.arg(format!("--user={}", dsn.user));
+    }
+    // Keep the password out of the process list.
+    if !dsn.password.is_empty() {
+        cmd.env("MYSQL_PWD", &dsn.password);
+    }
+    cmd.arg(format!("--database={}", dsn.database));
+    cmd.arg("--default-character-set=utf8mb4");
+    cmd.arg(format!("--connect-timeout={CONNECT_TIMEOUT_SECS}"));
+    if read_only {
+        cmd.arg("--init-command=SET SESSION TRANSACTION READ ONLY");
+    }
+}
+
+async fn write_sql_to_stdin(
+    stdin: Option<tokio::process::ChildStdin>,
+    sql: &str,
+) -> Result<(), std::io::Error> {
+    if let Some(mut stdin) = stdin {
+        let execution_sql = terminated_sql(sql);
+        if let Err(error) = stdin.write_all(execution_sql.as_bytes()).await
+            && error.kind() != std::io::ErrorKind::BrokenPipe
+        {
+            return Err(error);
+        }
+        if let Err(error) = stdin.shutdown().await
+            && error.kind() != std::io::ErrorKind::BrokenPipe
+        {
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+// An empty trailing statement makes the client fail with "No query specified".
+fn terminated_sql(sql: &str) -> String {
+    let sql = sql.trim_end();
+    if sql.ends_with(';') {
+        format!("{sql}\n")
+    } else {
+        format!("{sql};\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terminated_sql_does_not_add_empty_statement() {
+        assert_eq!(terminated_sql("SELECT 1;\n"), "SELECT 1;\n");
+        assert_eq!(terminated_sql("SELECT 1"), "SELECT 1;\n");
+    }
+}
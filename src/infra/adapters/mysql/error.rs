@@ -0,0 +1,122 @@
+use crate::app::ports::outbound::{DatabaseCli, DbOperationError};
+
+pub(in crate::adapters::mysql) fn classify_cli_spawn_error(
+    error: std::io::Error,
+) -> DbOperationError {
+    if error.kind() == std::io::ErrorKind::NotFound {
+        DbOperationError::CommandNotFound {
+            command: DatabaseCli::Mysql,
+            details: error.to_string(),
+        }
+    } else {
+        DbOperationError::QueryFailed(error.to_string())
+    }
+}
+
+/// Maps `ERROR <code> (<sqlstate>): <message>` output from the mysql client.
+pub(in crate::adapters::mysql) fn classify_query_error(stderr: &str) -> DbOperationError {
+    let details = stderr
+        .lines()
+        .filter(|line| !is_password_warning(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+    let Some(code) = error_code(&details) else {
+        return DbOperationError::QueryFailed(details);
+    };
+
+    match code {
+        1044 | 1045 | 1049 | 2002 | 2003 | 2005 | 2059 => {
+            DbOperationError::ConnectionFailed(details)
+        }
+        2006 | 2013 => DbOperationError::ConnectionLost(details),
+        1142 | 1143 | 1227 | 1792 => DbOperationError::PermissionDenied(details),
+        1062 | 1586 => DbOperationError::UniqueViolation(details),
+        1216 | 1217 | 1451 | 1452 => DbOperationError::ForeignKeyViolation(details),
+        1205 => DbOperationError::LockTimeout(details),
+        1213 => DbOperationError::Deadlock(details),
+        1054 | 1091 | 1146 | 1305 | 1360 => DbOperationError::ObjectMissing(details),
+        _ => DbOperationError::QueryFailed(details),
+    }
+}
+
+fn error_code(details: &str) -> Option<u16> {
+    let rest = details.strip_prefix("ERROR ")?;
+    let end = rest
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+// The client prints this on every run when a password is supplied via the environment.
+fn is_password_warning(line: &str) -> bool {
+    line.starts_with("mysql: [Warning]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "ERROR 1045 (28000): Access denied for user 'root'@'localhost'",
+        "ConnectionFailed"
+    )]
+    #[case(
+        "ERROR 2003 (HY000): Can't connect to MySQL server on 'db:3306' (111)",
+        "ConnectionFailed"
+    )]
+    #[case(
+        "ERROR 2013 (HY000): Lost connection to MySQL server",
+        "ConnectionLost"
+    )]
+    #[case(
+        "ERROR 1792 (25006): Cannot execute statement in a READ ONLY transaction.",
+        "PermissionDenied"
+    )]
+    #[case(
+        "ERROR 1062 (23000): Duplicate entry '1' for key 'PRIMARY'",
+        "UniqueViolation"
+    )]
+    #[case(
+        "ERROR 1451 (23000): Cannot delete or update a parent row",
+        "ForeignKeyViolation"
+    )]
+    #[case("ERROR 1205 (HY000): Lock wait timeout exceeded", "LockTimeout")]
+    #[case("ERROR 1213 (40001): Deadlock found", "Deadlock")]
+    #[case("ERROR 1146 (42S02): Table 'shop.nope' doesn't exist", "ObjectMissing")]
+    #[case(
+        "ERROR 1064 (42000): You have an error in your SQL syntax",
+        "QueryFailed"
+    )]
+    #[case("unexpected output", "QueryFailed")]
+    fn classifies_by_error_code(#[case] stderr: &str, #[case] expected: &str) {
+        let kind = match classify_query_error(stderr) {
+            DbOperationError::ConnectionFailed(_) => "ConnectionFailed",
+            DbOperationError::ConnectionLost(_) => "ConnectionLost",
+            DbOperationError::PermissionDenied(_) => "PermissionDenied",
+            DbOperationError::UniqueViolation(_) => "UniqueViolation",
+            DbOperationError::ForeignKeyViolation(_) => "ForeignKeyViolation",
+            DbOperationError::LockTimeout(_) => "LockTimeout",
+            DbOperationError::Deadlock(_) => "Deadlock",
+            DbOperationError::ObjectMissing(_) => "ObjectMissing",
+            _ => "QueryFailed",
+        };
+
+        assert_eq!(kind, expected);
+    }
+
+    #[test]
+    fn strips_password_warning_from_details() {
+        let error = classify_query_error(
+            "mysql: [Warning] Using a password on the command line interface can be insecure.\nERROR 1146 (42S02): Table 'shop.nope' doesn't exist",
+        );
+
+        assert!(matches!(
+            error,
+            DbOperationError::ObjectMissing(details) if details.starts_with("ERROR 1146")
+        ));
+    }
+}
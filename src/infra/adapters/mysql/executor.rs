@@ -0,0 +1,238 @@
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use crate::adapters::csv_export::export_to_downloads;
+use crate::app::policy::write::sql_risk::split_statements_for_database;
+use crate::app::ports::outbound::{
+    AccessMode, DbOperationError, PreviewOptions, QueryExecutor, TransactionEnd,
+    preview_order_columns, project_preview_columns,
+};
+use crate::domain::{CommandTag, DatabaseType, QueryResult, QuerySource, WriteExecutionResult};
+
+use super::MySqlAdapter;
+use super::dsn::MySqlDsn;
+use super::parser::{
+    batch_to_query_result, is_row_count_statement, parse_batch_lines, parse_count_result,
+    statement_command_tag, strip_affected_rows_probe,
+};
+use super::sql;
+
+impl MySqlAdapter {
+    /// Returns the visible columns and the primary key columns in key order.
+    async fn preview_columns(
+        &self,
+        dsn: &MySqlDsn,
+        schema: &str,
+        table: &str,
+    ) -> Result<(Vec<String>, Vec<String>), DbOperationError> {
+        let stdout = self
+            .cli
+            .execute_values(dsn, &sql::preview_columns_query(schema, table))
+            .await?;
+        let mut columns = Vec::new();
+        let mut primary_key = Vec::new();
+        for line in parse_batch_lines(&stdout) {
+            let mut fields = line.into_iter();
+            let Some(name) = fields.next().flatten() else {
+                continue;
+            };
+            if let Some(seq) = fields
+                .next()
+                .flatten()
+                .and_then(|seq| seq.parse::<u32>().ok())
+            {
+                primary_key.push((seq, name.clone()));
+            }
+            columns.push(name);
+        }
+        if columns.is_empty() {
+            return Err(DbOperationError::ObjectMissing(format!(
+                "Table {schema}.{table} does not exist"
+            )));
+        }
+        primary_key.sort_by_key(|(seq, _)| *seq);
+        Ok((
+            columns,
+            primary_key.into_iter().map(|(_, name)| name).collect(),
+        ))
+    }
+
+    async fn write_csv(
+        &self,
+        dsn: &MySqlDsn,
+        query: &str,
+        path: &Path,
+    ) -> Result<(), DbOperationError> {
+        let stdout = self.cli.execute_batch(dsn, query, true).await?;
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        for row in parse_batch_lines(&stdout) {
+            writer.write_record(row.into_iter().map(Option::unwrap_or_default))?;
+        }
+        let bytes = writer
+            .into_inner()
+            .map_err(|error| DbOperationError::QueryFailed(error.to_string()))?;
+        tokio::fs::write(path, bytes)
+            .await
+            .map_err(|error| DbOperationError::QueryFailed(error.to_string()))
+    }
+}
+
+#[async_trait]
+impl QueryExecutor for MySqlAdapter {
+    async fn execute_preview(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+        options: PreviewOptions,
+        limit: usize,
+        offset: usize,
+    ) -> Result<QueryResult, DbOperationError> {
+        let dsn = MySqlDsn::parse(dsn)?;
+        let (columns, primary_key) = self.preview_columns(&dsn, schema, table).await?;
+        let order_columns = preview_order_columns(options.sort_column.as_deref(), &primary_key);
+        let projection = if options.columns.is_empty() {
+            Vec::new()
+        } else {
+            project_preview_columns(&columns, &options.columns)
+        };
+        let query = sql::build_preview_query(
+            schema,
+            table,
+            &projection,
+            options.filter.as_deref(),
+            &order_columns,
+            limit,
+            offset,
+        );
+
+        #[expect(
+            clippy::disallowed_methods,
+            reason = "infra measures mysql execution time at the I/O boundary"
+        )]
+        let start = Instant::now();
+        let stdout = self.cli.execute_batch(&dsn, &query, true).await?;
+        let elapsed = start.elapsed().as_millis() as u64;
+
+        Ok(
+            batch_to_query_result(&query, &stdout, QuerySource::Preview, elapsed)
+                .with_columns_if_empty(project_preview_columns(&columns, &projection)),
+        )
+    }
+
+    async fn execute_adhoc(
+        &self,
+        dsn: &str,
+        query: &str,
+        access_mode: AccessMode,
+    ) -> Result<QueryResult, DbOperationError> {
+        let dsn = MySqlDsn::parse(dsn)?;
+        let statements = split_statements_for_database(DatabaseType::MySQL, query);
+        let last_statement = statements.last().map_or(query, String::as_str);
+        let execution_query = if is_row_count_statement(last_statement) {
+            sql::with_affected_rows_probe(query)
+        } else {
+            query.to_string()
+        };
+
+        #[expect(
+            clippy::disallowed_methods,
+            reason = "infra measures mysql execution time at the I/O boundary"
+        )]
+        let start = Instant::now();
+        let stdout = self
+            .cli
+            .execute_batch(&dsn, &execution_query, access_mode.is_read_only())
+            .await?;
+        let elapsed = start.elapsed().as_millis() as u64;
+        let (stdout, affected_rows) = strip_affected_rows_probe(&stdout)?;
+        let tag = statement_command_tag(last_statement, affected_rows);
+
+        if stdout.trim().is_empty() {
+            let tag = tag.unwrap_or(CommandTag::Select(0));
+            return Ok(QueryResult::success(
+                query.to_string(),
+                Vec::new(),
+                Vec::new(),
+                elapsed,
+                QuerySource::Adhoc,
+            )
+            .with_row_count(tag.affected_rows().unwrap_or(0) as usize)
+            .with_command_tag(tag));
+        }
+
+        let result = batch_to_query_result(query, stdout, QuerySource::Adhoc, elapsed);
+        let tag = tag.unwrap_or_else(|| CommandTag::Select(result.row_count() as u64));
+        Ok(result.with_command_tag(tag))
+    }
+
+    async fn execute_write(
+        &self,
+        dsn: &str,
+        query: &str,
+        access_mode: AccessMode,
+    ) -> Result<WriteExecutionResult, DbOperationError> {
+        let dsn = MySqlDsn::parse(dsn)?;
+
+        #[expect(
+            clippy::disallowed_methods,
+            reason = "infra measures mysql execution time at the I/O boundary"
+        )]
+        let start = Instant::now();
+        let stdout = self
+            .cli
+            .execute_batch(
+                &dsn,
+                &sql::with_affected_rows_probe(query),
+                access_mode.is_read_only(),
+            )
+            .await?;
+        let execution_time_ms = start.elapsed().as_millis() as u64;
+        let (_, affected_rows) = strip_affected_rows_probe(&stdout)?;
+        Ok(WriteExecutionResult {
+            affected_rows: affected_rows.unwrap_or(0) as usize,
+            execution_time_ms,
+        })
+    }
+
+    async fn count_query_rows(&self, dsn: &str, query: &str) -> Result<usize, DbOperationError> {
+        let dsn = MySqlDsn::parse(dsn)?;
+        let stdout = self.cli.execute_batch(&dsn, query, true).await?;
+        parse_count_result(&stdout)
+    }
+
+    async fn export_to_csv(
+        &self,
+        dsn: &str,
+        query: &str,
+        file_name: &str,
+    ) -> Result<PathBuf, DbOperationError> {
+        let dsn = MySqlDsn::parse(dsn)?;
+        export_to_downloads(file_name, |path| async move {
+            self.write_csv(&dsn, query, &path).await
+        })
+        .await
+    }
+
+    async fn begin_transaction(
+        &self,
+        _dsn: &str,
+        _access_mode: AccessMode,
+    ) -> Result<(), DbOperationError> {
+        Err(DbOperationError::UnsupportedOperation(
+            "Transactions are only available for PostgreSQL connections".to_string(),
+        ))
+    }
+
+    async fn end_transaction(
+        &self,
+        _dsn: &str,
+        _end: TransactionEnd,
+    ) -> Result<(), DbOperationError> {
+        Err(DbOperationError::UnsupportedOperation(
+            "Transactions are only available for PostgreSQL connections".to_string(),
+        ))
+    }
+}
@@ -0,0 +1,427 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::app::ports::outbound::{DbOperationError, MetadataProvider};
+use crate::domain::{
+    Column, ColumnAttributes, DatabaseMetadata, ForeignKey, Index, IndexAttributes, IndexType,
+    Schema, Table, TableKind, TableKindInfo, TableSignature, TableSummary, Trigger, TriggerEvent,
+    TriggerTiming,
+};
+
+use super::MySqlAdapter;
+use super::dsn::MySqlDsn;
+use super::parser::parse_batch_lines;
+use super::sql;
+
+#[derive(Debug, Deserialize)]
+struct RawTableSummary {
+    name: String,
+    rows: Option<i64>,
+    #[serde(rename = "type")]
+    table_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawTableDetail {
+    table: Option<RawTableInfo>,
+    columns: Vec<RawColumn>,
+    indexes: Vec<RawIndexColumn>,
+    foreign_keys: Vec<RawForeignKeyColumn>,
+    triggers: Vec<RawTrigger>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawTableInfo {
+    rows: Option<i64>,
+    #[serde(rename = "type")]
+    table_type: String,
+    comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawColumn {
+    name: String,
+    #[serde(rename = "type")]
+    data_type: String,
+    default: Option<String>,
+    nullable: String,
+    key: String,
+    extra: String,
+    comment: Option<String>,
+    position: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawIndexColumn {
+    name: String,
+    column: Option<String>,
+    seq: u32,
+    non_unique: i64,
+    #[serde(rename = "type")]
+    index_type: String,
+    collation: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawForeignKeyColumn {
+    name: String,
+    column: String,
+    position: u32,
+    ref_schema: String,
+    ref_table: String,
+    ref_column: String,
+    on_update: String,
+    on_delete: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawTrigger {
+    name: String,
+    timing: String,
+    event: String,
+    statement: String,
+}
+
+impl MySqlAdapter {
+    async fn table_detail(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+        with_source_ddl: bool,
+    ) -> Result<Table, DbOperationError> {
+        let dsn = MySqlDsn::parse(dsn)?;
+        let stdout = self
+            .cli
+            .execute_values(
+                &dsn,
+                &sql::table_detail_query(schema, table, with_source_ddl),
+            )
+            .await?;
+        let mut lines = parse_batch_lines(&stdout).into_iter();
+        let payload = lines
+            .next()
+            .and_then(|line| line.into_iter().next().flatten())
+            .ok_or_else(|| DbOperationError::EmptyResponse("table detail".to_string()))?;
+        let source_ddl = lines
+            .next()
+            .and_then(|line| line.into_iter().nth(1).flatten());
+        let raw: RawTableDetail = serde_json::from_str(&payload)?;
+        table_from_raw(schema, table, raw, source_ddl)
+    }
+}
+
+fn table_kind(table_type: &str) -> TableKind {
+    if table_type.ends_with("VIEW") {
+        TableKind::View
+    } else {
+        TableKind::Table
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty())
+}
+
+fn table_from_raw(
+    schema: &str,
+    name: &str,
+    raw: RawTableDetail,
+    source_ddl: Option<String>,
+) -> Result<Table, DbOperationError> {
+    let info = raw.table.ok_or_else(|| {
+        DbOperationError::ObjectMissing(format!("Table {schema}.{name} does not exist"))
+    })?;
+
+    let mut columns: Vec<Column> = raw.columns.into_iter().map(column_from_raw).collect();
+    columns.sort_by_key(|column| column.ordinal_position);
+
+    let indexes = indexes_from_raw(raw.indexes);
+    let primary_key = indexes
+        .iter()
+        .find(|index| index.is_primary())
+        .map(|index| index.columns.clone());
+
+    Ok(Table {
+        schema: schema.to_string(),
+        name: name.to_string(),
+        owner: None,
+        columns,
+        primary_key,
+        foreign_keys: foreign_keys_from_raw(schema, name, raw.foreign_keys),
+        indexes,
+        rls: None,
+        triggers: raw
+            .triggers
+            .into_iter()
+            .map(trigger_from_raw)
+            .collect::<Result<_, _>>()?,
+        row_count_estimate: info.rows,
+        comment: non_empty(info.comment),
+        source_ddl,
+        kind_info: TableKindInfo {
+            kind: table_kind(&info.table_type),
+            ..TableKindInfo::default()
+        },
+        hypertable: None,
+        column_stats: Vec::new(),
+        storage: None,
+    })
+}
+
+fn column_from_raw(raw: RawColumn) -> Column {
+    let mut attributes =
+        ColumnAttributes::from_parts(raw.nullable == "YES", raw.key == "PRI", raw.key == "UNI");
+    // EXTRA also reports DEFAULT_GENERATED for expression defaults, which stay writable.
+    if raw.extra.contains("VIRTUAL GENERATED") || raw.extra.contains("STORED GENERATED") {
+        attributes = attributes | ColumnAttributes::GENERATED;
+    }
+    Column {
+        name: raw.name,
+        data_type: raw.data_type,
+        default: raw.default,
+        attributes,
+        comment: non_empty(raw.comment),
+        ordinal_position: raw.position,
+    }
+}
+
+fn indexes_from_raw(raw: Vec<RawIndexColumn>) -> Vec<Index> {
+    let mut grouped: BTreeMap<String, Vec<RawIndexColumn>> = BTreeMap::new();
+    for column in raw {
+        grouped.entry(column.name.clone()).or_default().push(column);
+    }
+
+    let mut indexes: Vec<Index> = grouped
+        .into_iter()
+        .map(|(name, mut parts)| {
+            parts.sort_by_key(|part| part.seq);
+            let primary = name == "PRIMARY";
+            let mut attributes = IndexAttributes::from_parts(parts[0].non_unique == 0, primary);
+            if parts.iter().any(|part| part.column.is_none()) {
+                attributes = attributes | IndexAttributes::EXPRESSION;
+            }
+            if parts
+                .iter()
+                .any(|part| part.collation.as_deref() == Some("D"))
+            {
+                attributes = attributes | IndexAttributes::DESCENDING;
+            }
+            let index_type = match parts[0].index_type.parse::<IndexType>() {
+                Ok(index_type) => index_type,
+                Err(never) => match never {},
+            };
+            Index {
+                name,
+                columns: parts.into_iter().filter_map(|part| part.column).collect(),
+                attributes,
+                index_type,
+                definition: None,
+            }
+        })
+        .collect();
+    indexes.sort_by_key(|index| !index.is_primary());
+    indexes
+}
+
+fn foreign_keys_from_raw(
+    schema: &str,
+    table: &str,
+    raw: Vec<RawForeignKeyColumn>,
+) -> Vec<ForeignKey> {
+    let mut grouped: BTreeMap<String, Vec<RawForeignKeyColumn>> = BTreeMap::new();
+    for column in raw {
+        grouped.entry(column.name.clone()).or_default().push(column);
+    }
+
+    grouped
+        .into_iter()
+        .map(|(name, mut parts)| {
+            parts.sort_by_key(|part| part.position);
+            let first = &parts[0];
+            ForeignKey {
+                name,
+                from_schema: schema.to_string(),
+                from_table: table.to_string(),
+                to_schema: first.ref_schema.clone(),
+                to_table: first.ref_table.clone(),
+                on_delete: first.on_delete.parse().unwrap_or_default(),
+                on_update: first.on_update.parse().unwrap_or_default(),
+                from_columns: parts.iter().map(|part| part.column.clone()).collect(),
+                to_columns: parts.into_iter().map(|part| part.ref_column).collect(),
+                reference_resolved: true,
+            }
+        })
+        .collect()
+}
+
+fn trigger_from_raw(raw: RawTrigger) -> Result<Trigger, DbOperationError> {
+    let timing: TriggerTiming = raw
+        .timing
+        .parse()
+        .map_err(|error| DbOperationError::MetadataParseFailed(format!("{error}")))?;
+    let event: TriggerEvent = raw
+        .event
+        .parse()
+        .map_err(|error| DbOperationError::MetadataParseFailed(format!("{error}")))?;
+    Ok(Trigger {
+        name: raw.name,
+        timing,
+        events: vec![event],
+        function_name: raw.statement,
+        security_definer: false,
+    })
+}
+
+#[async_trait]
+impl MetadataProvider for MySqlAdapter {
+    async fn fetch_metadata(&self, dsn: &str) -> Result<DatabaseMetadata, DbOperationError> {
+        let parsed = MySqlDsn::parse(dsn)?;
+        let stdout = self
+            .cli
+            .execute_values(&parsed, sql::table_summaries_query())
+            .await?;
+        let payload = parse_batch_lines(&stdout)
+            .into_iter()
+            .next()
+            .and_then(|line| line.into_iter().next().flatten())
+            .unwrap_or_else(|| "[]".to_string());
+        let mut tables: Vec<RawTableSummary> = serde_json::from_str(&payload)?;
+        tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut metadata = DatabaseMetadata::new(parsed.database.clone());
+        metadata.schemas = vec![Schema::new(parsed.database.clone())];
+        metadata.table_summaries = tables
+            .into_iter()
+            .map(|table| {
+                TableSummary::new(parsed.database.clone(), table.name, table.rows, false)
+                    .with_kind_info(TableKindInfo {
+                        kind: table_kind(&table.table_type),
+                        ..TableKindInfo::default()
+                    })
+            })
+            .collect();
+        Ok(metadata)
+    }
+
+    async fn fetch_table_detail(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+    ) -> Result<Table, DbOperationError> {
+        self.table_detail(dsn, schema, table, true).await
+    }
+
+    async fn fetch_table_columns_and_fks(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+    ) -> Result<Table, DbOperationError> {
+        self.table_detail(dsn, schema, table, false).await
+    }
+
+    async fn fetch_table_signatures(
+        &self,
+        dsn: &str,
+    ) -> Result<Vec<TableSignature>, DbOperationError> {
+        let parsed = MySqlDsn::parse(dsn)?;
+        let stdout = self
+            .cli
+            .execute_values(&parsed, sql::table_signatures_query())
+            .await?;
+        Ok(parse_batch_lines(&stdout)
+            .into_iter()
+            .filter_map(|line| {
+                let mut fields = line.into_iter();
+                let name = fields.next().flatten()?;
+                let signature = fields.next().flatten().unwrap_or_default();
+                Some(TableSignature {
+                    schema: parsed.database.clone(),
+                    name,
+                    signature,
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::FkAction;
+
+    fn raw_detail(json: &str) -> RawTableDetail {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn builds_table_from_information_schema_payload() {
+        let raw = raw_detail(
+            r#"{
+                "table": {"rows": 12, "type": "BASE TABLE", "comment": "Orders"},
+                "columns": [
+                    {"name": "total", "type": "decimal(10,2)", "default": null, "nullable": "YES",
+                     "key": "", "extra": "STORED GENERATED", "comment": "", "position": 3},
+                    {"name": "id", "type": "bigint", "default": null, "nullable": "NO",
+                     "key": "PRI", "extra": "auto_increment", "comment": "", "position": 1},
+                    {"name": "user_id", "type": "bigint", "default": null, "nullable": "NO",
+                     "key": "MUL", "extra": "", "comment": "owner", "position": 2}
+                ],
+                "indexes": [
+                    {"name": "idx_user", "column": "user_id", "seq": 1, "non_unique": 1,
+                     "type": "BTREE", "collation": "D"},
+                    {"name": "PRIMARY", "column": "id", "seq": 1, "non_unique": 0,
+                     "type": "BTREE", "collation": "A"}
+                ],
+                "foreign_keys": [
+                    {"name": "fk_user", "column": "user_id", "position": 1, "ref_schema": "shop",
+                     "ref_table": "users", "ref_column": "id", "on_update": "NO ACTION",
+                     "on_delete": "CASCADE"}
+                ],
+                "triggers": [
+                    {"name": "audit", "timing": "AFTER", "event": "INSERT",
+                     "statement": "INSERT INTO log VALUES (NEW.id)"}
+                ]
+            }"#,
+        );
+
+        let table = table_from_raw("shop", "orders", raw, Some("CREATE TABLE".into())).unwrap();
+
+        let names: Vec<_> = table.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "user_id", "total"]);
+        assert!(table.columns[0].is_primary_key());
+        assert!(table.columns[2].is_generated());
+        assert_eq!(table.columns[1].comment.as_deref(), Some("owner"));
+        assert_eq!(table.primary_key, Some(vec!["id".to_string()]));
+        assert_eq!(table.indexes[0].name, "PRIMARY");
+        assert!(table.indexes[1].has_descending_key());
+        assert_eq!(table.foreign_keys[0].referenced_table(), "shop.users");
+        assert_eq!(table.foreign_keys[0].on_delete, FkAction::Cascade);
+        assert_eq!(table.triggers[0].timing, TriggerTiming::After);
+        assert_eq!(table.row_count_estimate, Some(12));
+        assert_eq!(table.comment.as_deref(), Some("Orders"));
+        assert_eq!(table.source_ddl(), Some("CREATE TABLE"));
+    }
+
+    #[test]
+    fn missing_table_is_reported_as_object_missing() {
+        let raw = raw_detail(
+            r#"{"table": null, "columns": [], "indexes": [], "foreign_keys": [], "triggers": []}"#,
+        );
+
+        let result = table_from_raw("shop", "nope", raw, None);
+
+        assert!(matches!(result, Err(DbOperationError::ObjectMissing(_))));
+    }
+
+    #[test]
+    fn views_are_classified_as_views() {
+        assert_eq!(table_kind("VIEW"), TableKind::View);
+        assert_eq!(table_kind("SYSTEM VIEW"), TableKind::View);
+        assert_eq!(table_kind("BASE TABLE"), TableKind::Table);
+    }
+}
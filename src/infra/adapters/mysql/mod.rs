@@ -0,0 +1,10 @@
+mod adapter;
+mod cli;
+mod dsn;
+mod error;
+mod executor;
+mod metadata;
+mod parser;
+mod sql;
+
+pub use adapter::MySqlAdapter;
@@ -0,0 +1,202 @@
+use crate::app::ports::outbound::DbOperationError;
+use crate::domain::{CommandTag, QueryResult, QuerySource, QueryValue};
+
+/// Column alias of the `ROW_COUNT()` probe appended after DML statements.
+pub(in crate::adapters::mysql) const AFFECTED_ROWS_MARKER: &str = "sabiql_affected_rows";
+
+/// Splits `--batch` output into rows of decoded fields; `NULL` cells become `None`.
+pub(in crate::adapters::mysql) fn parse_batch_lines(stdout: &str) -> Vec<Vec<Option<String>>> {
+    stdout
+        .lines()
+        .map(|line| line.split('\t').map(unescape_field).collect())
+        .collect()
+}
+
+/// The client escapes tab, newline, backslash and NUL inside values and prints
+/// SQL NULL as the bare word `NULL`.
+fn unescape_field(field: &str) -> Option<String> {
+    if field == "NULL" {
+        return None;
+    }
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('0') => out.push('\0'),
+            Some('\\') | None => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+        }
+    }
+    Some(out)
+}
+
+/// Builds a result from `--batch` output whose first line holds the column names.
+/// An empty result set prints nothing, so callers supply fallback columns when known.
+pub(in crate::adapters::mysql) fn batch_to_query_result(
+    query: &str,
+    stdout: &str,
+    source: QuerySource,
+    elapsed: u64,
+) -> QueryResult {
+    let mut lines = parse_batch_lines(stdout).into_iter();
+    let columns = lines
+        .next()
+        .map(|header| header.into_iter().map(Option::unwrap_or_default).collect())
+        .unwrap_or_default();
+    let values = lines
+        .map(|row| {
+            row.into_iter()
+                .map(|cell| cell.map_or(QueryValue::Null, QueryValue::Text))
+                .collect()
+        })
+        .collect();
+    QueryResult::success_with_values(query.to_string(), columns, values, elapsed, source)
+}
+
+/// Splits off the trailing affected-rows probe, returning the remaining output
+/// and the reported count.
+pub(in crate::adapters::mysql) fn strip_affected_rows_probe(
+    stdout: &str,
+) -> Result<(&str, Option<u64>), DbOperationError> {
+    let header = format!("{AFFECTED_ROWS_MARKER}\n");
+    let Some(position) = stdout.rfind(&header) else {
+        return Ok((stdout, None));
+    };
+    if position != 0 && !stdout[..position].ends_with('\n') {
+        return Ok((stdout, None));
+    }
+    let count = stdout[position + header.len()..].trim();
+    let count = count.parse::<i64>().map_err(|_| {
+        DbOperationError::CommandTagParseFailed(format!("Unexpected ROW_COUNT() output: {count}"))
+    })?;
+    Ok((&stdout[..position], Some(u64::try_from(count).unwrap_or(0))))
+}
+
+pub(in crate::adapters::mysql) fn parse_count_result(
+    stdout: &str,
+) -> Result<usize, DbOperationError> {
+    stdout
+        .lines()
+        .nth(1)
+        .and_then(|line| line.trim().parse().ok())
+        .ok_or_else(|| {
+            DbOperationError::QueryFailed(format!("Unexpected count output: {}", stdout.trim()))
+        })
+}
+
+pub(in crate::adapters::mysql) fn first_keyword(statement: &str) -> &str {
+    let trimmed = statement.trim_start_matches(|ch: char| ch.is_whitespace() || ch == '(');
+    let end = trimmed
+        .find(|ch: char| !ch.is_ascii_alphabetic())
+        .unwrap_or(trimmed.len());
+    &trimmed[..end]
+}
+
+pub(in crate::adapters::mysql) fn is_row_count_statement(statement: &str) -> bool {
+    ["INSERT", "UPDATE", "DELETE", "REPLACE"]
+        .iter()
+        .any(|keyword| first_keyword(statement).eq_ignore_ascii_case(keyword))
+}
+
+pub(in crate::adapters::mysql) fn statement_command_tag(
+    statement: &str,
+    affected_rows: Option<u64>,
+) -> Option<CommandTag> {
+    let keyword = first_keyword(statement).to_ascii_uppercase();
+    let object = || {
+        statement
+            .split_whitespace()
+            .nth(1)
+            .unwrap_or_default()
+            .to_ascii_uppercase()
+    };
+    let rows = affected_rows.unwrap_or(0);
+    Some(match keyword.as_str() {
+        "SELECT" | "WITH" | "SHOW" | "DESCRIBE" | "DESC" | "EXPLAIN" => return None,
+        "INSERT" | "REPLACE" => CommandTag::Insert(rows),
+        "UPDATE" => CommandTag::Update(rows),
+        "DELETE" => CommandTag::Delete(rows),
+        "CREATE" => CommandTag::Create(object()),
+        "DROP" => CommandTag::Drop(object()),
+        "ALTER" => CommandTag::Alter(object()),
+        "TRUNCATE" => CommandTag::Truncate,
+        "BEGIN" | "START" => CommandTag::Begin,
+        "COMMIT" => CommandTag::Commit,
+        "ROLLBACK" => CommandTag::Rollback,
+        _ => CommandTag::Other(keyword),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn batch_output_decodes_escapes_and_nulls() {
+        let result = batch_to_query_result(
+            "SELECT",
+            "id\tnote\n1\tline\\nbreak\\ttab\\\\\n2\tNULL\n",
+            QuerySource::Adhoc,
+            3,
+        );
+
+        assert_eq!(result.columns, vec!["id", "note"]);
+        assert_eq!(
+            result.display_row_at(0),
+            Some(vec!["1".to_string(), "line\nbreak\ttab\\".to_string()])
+        );
+        assert_eq!(result.value_at(1, 1), Some(&QueryValue::Null));
+    }
+
+    #[test]
+    fn empty_output_has_no_columns() {
+        let result = batch_to_query_result("SELECT", "", QuerySource::Preview, 0);
+
+        assert!(result.columns.is_empty());
+        assert_eq!(result.row_count(), 0);
+    }
+
+    #[test]
+    fn strips_trailing_affected_rows_probe() {
+        let (rest, count) = strip_affected_rows_probe("id\n1\nsabiql_affected_rows\n3\n").unwrap();
+
+        assert_eq!(rest, "id\n1\n");
+        assert_eq!(count, Some(3));
+    }
+
+    #[test]
+    fn negative_row_count_is_reported_as_zero() {
+        let (_, count) = strip_affected_rows_probe("sabiql_affected_rows\n-1\n").unwrap();
+
+        assert_eq!(count, Some(0));
+    }
+
+    #[test]
+    fn parses_count_from_second_line() {
+        assert_eq!(parse_count_result("COUNT(*)\n42\n").unwrap(), 42);
+        assert!(parse_count_result("COUNT(*)\n").is_err());
+    }
+
+    #[rstest]
+    #[case("SELECT 1", None)]
+    #[case("  insert into t values (1)", Some(CommandTag::Insert(2)))]
+    #[case("REPLACE INTO t VALUES (1)", Some(CommandTag::Insert(2)))]
+    #[case("UPDATE t SET a = 1", Some(CommandTag::Update(2)))]
+    #[case("DELETE FROM t", Some(CommandTag::Delete(2)))]
+    #[case("create table t (id int)", Some(CommandTag::Create("TABLE".to_string())))]
+    #[case("START TRANSACTION", Some(CommandTag::Begin))]
+    #[case("SET @a = 1", Some(CommandTag::Other("SET".to_string())))]
+    fn derives_command_tag(#[case] statement: &str, #[case] expected: Option<CommandTag>) {
+        assert_eq!(statement_command_tag(statement, Some(2)), expected);
+    }
+}
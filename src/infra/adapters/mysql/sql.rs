@@ -0,0 +1,610 @@
+use std::fmt::Write as _;
+
+use crate::app::ports::outbound::{
+    DdlGenerator, NamingConventions, SqlDialect, preview_where_clause,
+};
+use crate::domain::{
+    Column, DatabaseType, ForeignKey, Index, IndexMaintenanceKind, LockConflict, MaintenanceKind,
+    QueryValue, Table,
+};
+
+use super::MySqlAdapter;
+use super::parser::AFFECTED_ROWS_MARKER;
+
+pub(in crate::adapters::mysql) fn quote_ident(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
+
+/// Escapes for the default `sql_mode`, where backslash starts an escape sequence.
+pub(in crate::adapters::mysql) fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+fn sql_literal(value: &QueryValue) -> String {
+    match value {
+        QueryValue::Null => "NULL".to_string(),
+        QueryValue::Text(value) => quote_literal(value),
+        QueryValue::SqlLiteral(value) => value.clone(),
+        QueryValue::Blob(bytes) => {
+            let mut hex = String::with_capacity(bytes.len() * 2);
+            for byte in bytes {
+                let _ = write!(hex, "{byte:02X}");
+            }
+            format!("X'{hex}'")
+        }
+    }
+}
+
+fn equality_predicate(column: &str, value: &QueryValue) -> String {
+    let column = quote_ident(column);
+    match value {
+        QueryValue::Null => format!("{column} IS NULL"),
+        _ => format!("{column} = {}", sql_literal(value)),
+    }
+}
+
+fn row_predicate(pk_pairs: &[(String, QueryValue)]) -> String {
+    pk_pairs
+        .iter()
+        .map(|(col, val)| equality_predicate(col, val))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+fn rows_predicate(pk_pairs_per_row: &[Vec<(String, QueryValue)>]) -> String {
+    let predicates = pk_pairs_per_row
+        .iter()
+        .map(|pairs| row_predicate(pairs))
+        .collect::<Vec<_>>();
+    if predicates.len() == 1 {
+        predicates[0].clone()
+    } else {
+        predicates
+            .into_iter()
+            .map(|predicate| format!("({predicate})"))
+            .collect::<Vec<_>>()
+            .join(" OR ")
+    }
+}
+
+fn qualified_table(schema: &str, table: &str) -> String {
+    format!("{}.{}", quote_ident(schema), quote_ident(table))
+}
+
+pub(in crate::adapters::mysql) fn table_summaries_query() -> &'static str {
+    "SELECT COALESCE(JSON_ARRAYAGG(JSON_OBJECT(\
+     'name', TABLE_NAME, 'rows', TABLE_ROWS, 'type', TABLE_TYPE)), JSON_ARRAY()) \
+     FROM information_schema.TABLES WHERE TABLE_SCHEMA = DATABASE()"
+}
+
+/// One JSON document with the table's columns, indexes, foreign keys and triggers,
+/// followed by `SHOW CREATE TABLE` when `with_source_ddl` is set.
+pub(in crate::adapters::mysql) fn table_detail_query(
+    schema: &str,
+    table: &str,
+    with_source_ddl: bool,
+) -> String {
+    let schema_literal = quote_literal(schema);
+    let table_literal = quote_literal(table);
+    let filter = format!("TABLE_SCHEMA = {schema_literal} AND TABLE_NAME = {table_literal}");
+    let mut sql = format!(
+        "SELECT JSON_OBJECT(\n\
+         'table', (SELECT JSON_OBJECT('rows', TABLE_ROWS, 'type', TABLE_TYPE, 'comment', TABLE_COMMENT) \
+         FROM information_schema.TABLES WHERE {filter}),\n\
+         'columns', (SELECT COALESCE(JSON_ARRAYAGG(JSON_OBJECT(\
+         'name', COLUMN_NAME, 'type', COLUMN_TYPE, 'default', COLUMN_DEFAULT, \
+         'nullable', IS_NULLABLE, 'key', COLUMN_KEY, 'extra', EXTRA, \
+         'comment', COLUMN_COMMENT, 'position', ORDINAL_POSITION)), JSON_ARRAY()) \
+         FROM information_schema.COLUMNS WHERE {filter}),\n\
+         'indexes', (SELECT COALESCE(JSON_ARRAYAGG(JSON_OBJECT(\
+         'name', INDEX_NAME, 'column', COLUMN_NAME, 'seq', SEQ_IN_INDEX, \
+         'non_unique', NON_UNIQUE, 'type', INDEX_TYPE, 'collation', COLLATION)), JSON_ARRAY()) \
+         FROM information_schema.STATISTICS WHERE {filter}),\n\
+         'foreign_keys', (SELECT COALESCE(JSON_ARRAYAGG(JSON_OBJECT(\
+         'name', k.CONSTRAINT_NAME, 'column', k.COLUMN_NAME, 'position', k.ORDINAL_POSITION, \
+         'ref_schema', k.REFERENCED_TABLE_SCHEMA, 'ref_table', k.REFERENCED_TABLE_NAME, \
+         'ref_column', k.REFERENCED_COLUMN_NAME, \
+         'on_update', r.UPDATE_RULE, 'on_delete', r.DELETE_RULE)), JSON_ARRAY()) \
+         FROM information_schema.KEY_COLUMN_USAGE k \
+         JOIN information_schema.REFERENTIAL_CONSTRAINTS r \
+         ON r.CONSTRAINT_SCHEMA = k.CONSTRAINT_SCHEMA AND r.CONSTRAINT_NAME = k.CONSTRAINT_NAME \
+         AND r.TABLE_NAME = k.TABLE_NAME \
+         WHERE k.TABLE_SCHEMA = {schema_literal} AND k.TABLE_NAME = {table_literal} \
+         AND k.REFERENCED_TABLE_NAME IS NOT NULL),\n\
+         'triggers', (SELECT COALESCE(JSON_ARRAYAGG(JSON_OBJECT(\
+         'name', TRIGGER_NAME, 'timing', ACTION_TIMING, 'event', EVENT_MANIPULATION, \
+         'statement', ACTION_STATEMENT)), JSON_ARRAY()) \
+         FROM information_schema.TRIGGERS \
+         WHERE EVENT_OBJECT_SCHEMA = {schema_literal} AND EVENT_OBJECT_TABLE = {table_literal})\n\
+         );"
+    );
+    if with_source_ddl {
+        let _ = write!(
+            sql,
+            "\nSHOW CREATE TABLE {};",
+            qualified_table(schema, table)
+        );
+    }
+    sql
+}
+
+pub(in crate::adapters::mysql) fn table_signatures_query() -> &'static str {
+    "SET SESSION group_concat_max_len = 1048576;\n\
+     SELECT TABLE_NAME, MD5(GROUP_CONCAT(CONCAT_WS(':', COLUMN_NAME, COLUMN_TYPE, IS_NULLABLE, \
+     COALESCE(COLUMN_DEFAULT, ''), COLUMN_KEY, EXTRA) ORDER BY ORDINAL_POSITION SEPARATOR ',')) \
+     FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = DATABASE() \
+     GROUP BY TABLE_NAME ORDER BY TABLE_NAME;"
+}
+
+/// Column names in ordinal order plus each column's position in the primary key, if any.
+pub(in crate::adapters::mysql) fn preview_columns_query(schema: &str, table: &str) -> String {
+    format!(
+        "SELECT c.COLUMN_NAME, s.SEQ_IN_INDEX FROM information_schema.COLUMNS c \
+         LEFT JOIN information_schema.STATISTICS s ON s.TABLE_SCHEMA = c.TABLE_SCHEMA \
+         AND s.TABLE_NAME = c.TABLE_NAME AND s.COLUMN_NAME = c.COLUMN_NAME \
+         AND s.INDEX_NAME = 'PRIMARY' \
+         WHERE c.TABLE_SCHEMA = {} AND c.TABLE_NAME = {} ORDER BY c.ORDINAL_POSITION;",
+        quote_literal(schema),
+        quote_literal(table)
+    )
+}
+
+pub(in crate::adapters::mysql) fn build_preview_query(
+    schema: &str,
+    table: &str,
+    columns: &[String],
+    filter: Option<&str>,
+    order_columns: &[String],
+    limit: usize,
+    offset: usize,
+) -> String {
+    let order_clause = if order_columns.is_empty() {
+        String::new()
+    } else {
+        let columns = order_columns
+            .iter()
+            .map(|column| quote_ident(column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(" ORDER BY {columns}")
+    };
+    let select_list = if columns.is_empty() {
+        "*".to_string()
+    } else {
+        columns
+            .iter()
+            .map(|column| quote_ident(column))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "SELECT {select_list} FROM {}{}{order_clause} LIMIT {limit} OFFSET {offset};",
+        qualified_table(schema, table),
+        preview_where_clause(filter)
+    )
+}
+
+/// Appends a `ROW_COUNT()` probe so DML reports affected rows in batch mode.
+pub(in crate::adapters::mysql) fn with_affected_rows_probe(query: &str) -> String {
+    format!(
+        "{};\nSELECT ROW_COUNT() AS {AFFECTED_ROWS_MARKER};",
+        query.trim_end().trim_end_matches(';')
+    )
+}
+
+impl DdlGenerator for MySqlAdapter {
+    fn generate_ddl(&self, _database_type: DatabaseType, table: &Table) -> String {
+        if let Some(source_ddl) = table.source_ddl() {
+            let mut ddl = format!("{};", source_ddl.trim_end().trim_end_matches(';'));
+            for trigger in &table.triggers {
+                let events = trigger
+                    .events
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" OR ");
+                let _ = write!(
+                    ddl,
+                    "\n\nCREATE TRIGGER {} {} {events} ON {} FOR EACH ROW\n{};",
+                    quote_ident(&trigger.name),
+                    trigger.timing,
+                    quote_ident(&table.name),
+                    trigger.function_name.trim().trim_end_matches(';')
+                );
+            }
+            return ddl;
+        }
+
+        let mut ddl = format!(
+            "CREATE TABLE {} (\n",
+            qualified_table(&table.schema, &table.name)
+        );
+        for (i, col) in table.columns.iter().enumerate() {
+            let nullable = if col.is_nullable() { "" } else { " NOT NULL" };
+            let default = col
+                .default
+                .as_ref()
+                .map(|d| format!(" DEFAULT {d}"))
+                .unwrap_or_default();
+            let _ = write!(
+                ddl,
+                "  {} {}{}{}",
+                quote_ident(&col.name),
+                col.data_type,
+                nullable,
+                default
+            );
+            if i < table.columns.len() - 1 || table.has_primary_key() {
+                ddl.push(',');
+            }
+            ddl.push('\n');
+        }
+        if let Some(pk) = table.primary_key.as_ref().filter(|pk| !pk.is_empty()) {
+            let columns = pk
+                .iter()
+                .map(|column| quote_ident(column))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let _ = writeln!(ddl, "  PRIMARY KEY ({columns})");
+        }
+        ddl.push_str(");");
+        ddl
+    }
+}
+
+impl SqlDialect for MySqlAdapter {
+    fn build_explain_sql(&self, _database_type: DatabaseType, query: &str) -> Option<String> {
+        Some(format!("EXPLAIN {query}"))
+    }
+
+    fn build_explain_analyze_sql(
+        &self,
+        _database_type: DatabaseType,
+        _query: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_replication_overview_sql(
+        &self,
+        _database_type: DatabaseType,
+        _table: Option<&str>,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_sample_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        limit: usize,
+        _row_estimate: Option<i64>,
+    ) -> String {
+        format!(
+            "SELECT * FROM {} ORDER BY RAND() LIMIT {limit};",
+            qualified_table(schema, table)
+        )
+    }
+
+    fn build_row_count_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+    ) -> String {
+        format!(
+            "SELECT COUNT(*) AS row_count FROM {};",
+            qualified_table(schema, table)
+        )
+    }
+
+    fn build_rls_test_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        _table: &str,
+        _role: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_maintenance_sql(
+        &self,
+        _database_type: DatabaseType,
+        _kind: MaintenanceKind,
+        _schema: &str,
+        _table: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_maintenance_progress_sql(
+        &self,
+        _database_type: DatabaseType,
+        _kind: MaintenanceKind,
+        _schema: &str,
+        _table: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_index_maintenance_sql(
+        &self,
+        _database_type: DatabaseType,
+        _kind: IndexMaintenanceKind,
+        _schema: &str,
+        _index: &Index,
+    ) -> Option<Vec<String>> {
+        None
+    }
+
+    fn build_index_maintenance_progress_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        _table: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_autovacuum_report_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        _table: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_table_sizes_sql(
+        &self,
+        _database_type: DatabaseType,
+        _tables: &[(String, String)],
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_server_settings_sql(&self, _database_type: DatabaseType) -> Option<String> {
+        None
+    }
+
+    fn build_index_usage_sql(&self, _database_type: DatabaseType) -> Option<String> {
+        None
+    }
+
+    fn build_fk_orphan_check_sql(
+        &self,
+        _database_type: DatabaseType,
+        _foreign_keys: &[ForeignKey],
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_fk_suggestion_sql(&self, _database_type: DatabaseType) -> Option<String> {
+        None
+    }
+
+    fn build_naming_audit_sql(
+        &self,
+        _database_type: DatabaseType,
+        _conventions: &NamingConventions,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_lock_blockers_sql(
+        &self,
+        _database_type: DatabaseType,
+        _conflict: &LockConflict,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_result_types_sql(&self, _database_type: DatabaseType, _query: &str) -> Option<String> {
+        None
+    }
+
+    fn build_column_distribution_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        _table: &str,
+        _column: &Column,
+        _row_estimate: Option<i64>,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_update_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        column: &str,
+        new_value: &QueryValue,
+        pk_pairs: &[(String, QueryValue)],
+    ) -> String {
+        format!(
+            "UPDATE {}\nSET {} = {}\nWHERE {};",
+            qualified_table(schema, table),
+            quote_ident(column),
+            sql_literal(new_value),
+            row_predicate(pk_pairs)
+        )
+    }
+
+    fn build_bulk_delete_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        pk_pairs_per_row: &[Vec<(String, QueryValue)>],
+    ) -> String {
+        assert!(
+            !pk_pairs_per_row.is_empty(),
+            "pk_pairs_per_row must not be empty"
+        );
+
+        format!(
+            "DELETE FROM {}\nWHERE {};",
+            qualified_table(schema, table),
+            rows_predicate(pk_pairs_per_row)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::test_support;
+    use crate::domain::ColumnAttributes;
+
+    #[test]
+    fn update_sql_uses_backtick_identifiers_and_escaped_literals() {
+        let sql = MySqlAdapter::new().build_update_sql(
+            DatabaseType::MySQL,
+            "shop",
+            "users",
+            "na`me",
+            &QueryValue::text("O'Brien \\ co"),
+            &[("id".into(), QueryValue::text("1"))],
+        );
+
+        assert_eq!(
+            sql,
+            "UPDATE `shop`.`users`\nSET `na``me` = 'O''Brien \\\\ co'\nWHERE `id` = '1';"
+        );
+    }
+
+    #[test]
+    fn bulk_delete_sql_joins_rows_with_or() {
+        let sql = MySqlAdapter::new().build_bulk_delete_sql(
+            DatabaseType::MySQL,
+            "shop",
+            "orders",
+            &[
+                vec![("id".into(), QueryValue::text("1"))],
+                vec![("id".into(), QueryValue::Null)],
+            ],
+        );
+
+        assert_eq!(
+            sql,
+            "DELETE FROM `shop`.`orders`\nWHERE (`id` = '1') OR (`id` IS NULL);"
+        );
+    }
+
+    #[test]
+    fn blob_values_use_hex_literals() {
+        assert_eq!(sql_literal(&QueryValue::Blob(vec![0xab, 0x01])), "X'AB01'");
+    }
+
+    #[test]
+    fn preview_orders_by_primary_key() {
+        assert_eq!(
+            build_preview_query("shop", "users", &[], None, &["id".to_string()], 50, 100),
+            "SELECT * FROM `shop`.`users` ORDER BY `id` LIMIT 50 OFFSET 100;"
+        );
+        assert_eq!(
+            build_preview_query("shop", "log", &[], None, &[], 10, 0),
+            "SELECT * FROM `shop`.`log` LIMIT 10 OFFSET 0;"
+        );
+        assert_eq!(
+            build_preview_query("shop", "log", &[], Some("level = 'error'"), &[], 10, 0),
+            "SELECT * FROM `shop`.`log` WHERE (level = 'error') LIMIT 10 OFFSET 0;"
+        );
+        assert_eq!(
+            build_preview_query(
+                "shop",
+                "log",
+                &["id".to_string(), "level".to_string()],
+                None,
+                &[],
+                10,
+                0
+            ),
+            "SELECT `id`, `level` FROM `shop`.`log` LIMIT 10 OFFSET 0;"
+        );
+    }
+
+    #[test]
+    fn sample_shuffles_the_whole_table() {
+        let sql = MySqlAdapter::new().build_sample_sql(
+            DatabaseType::MySQL,
+            "shop",
+            "users",
+            25,
+            Some(1_000_000),
+        );
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM `shop`.`users` ORDER BY RAND() LIMIT 25;"
+        );
+    }
+
+    #[test]
+    fn row_count_is_qualified() {
+        let sql = MySqlAdapter::new().build_row_count_sql(DatabaseType::MySQL, "shop", "users");
+
+        assert_eq!(sql, "SELECT COUNT(*) AS row_count FROM `shop`.`users`;");
+    }
+
+    #[test]
+    fn affected_rows_probe_replaces_trailing_semicolon() {
+        assert_eq!(
+            with_affected_rows_probe("DELETE FROM t;\n"),
+            "DELETE FROM t;\nSELECT ROW_COUNT() AS sabiql_affected_rows;"
+        );
+    }
+
+    #[test]
+    fn explain_is_query_plan_only() {
+        let adapter = MySqlAdapter::new();
+
+        assert_eq!(
+            adapter.build_explain_sql(DatabaseType::MySQL, "SELECT 1"),
+            Some("EXPLAIN SELECT 1".to_string())
+        );
+        assert_eq!(
+            adapter.build_explain_analyze_sql(DatabaseType::MySQL, "SELECT 1"),
+            None
+        );
+    }
+
+    #[test]
+    fn ddl_prefers_show_create_table_output() {
+        let table = Table {
+            source_ddl: Some("CREATE TABLE `users` (\n  `id` int NOT NULL\n)".to_string()),
+            ..test_support::minimal_table("shop", "users")
+        };
+
+        let ddl = MySqlAdapter::new().generate_ddl(DatabaseType::MySQL, &table);
+
+        assert_eq!(ddl, "CREATE TABLE `users` (\n  `id` int NOT NULL\n);");
+    }
+
+    #[test]
+    fn ddl_falls_back_to_columns_and_primary_key() {
+        let table = Table {
+            columns: vec![Column {
+                name: "id".to_string(),
+                data_type: "int".to_string(),
+                default: None,
+                attributes: ColumnAttributes::from_parts(false, true, false),
+                comment: None,
+                ordinal_position: 1,
+            }],
+            primary_key: Some(vec!["id".to_string()]),
+            ..test_support::minimal_table("shop", "users")
+        };
+
+        let ddl = MySqlAdapter::new().generate_ddl(DatabaseType::MySQL, &table);
+
+        assert_eq!(
+            ddl,
+            "CREATE TABLE `shop`.`users` (\n  `id` int NOT NULL,\n  PRIMARY KEY (`id`)\n);"
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::adapters::app_config_file::get_config_dir;
+use crate::app::ports::outbound::{
+    NAME_TEMPLATE_COLUMNS, NAME_TEMPLATE_TABLE, NamingConventionError, NamingConventionReader,
+    NamingConventions,
+};
+
+pub const NAMING_FILE_NAME: &str = "naming.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NamingFile {
+    snake_case: Option<bool>,
+    reserved_words: Option<bool>,
+    primary_key: Option<String>,
+    foreign_key: Option<String>,
+    index: Option<String>,
+}
+
+/// An empty template turns the rule off; a missing one keeps the default.
+fn template(
+    key: &str,
+    value: Option<String>,
+    default: Option<String>,
+) -> Result<Option<String>, NamingConventionError> {
+    let Some(value) = value else {
+        return Ok(default);
+    };
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let stripped = value
+        .replace(NAME_TEMPLATE_TABLE, "")
+        .replace(NAME_TEMPLATE_COLUMNS, "");
+    if stripped.contains(['{', '}']) {
+        return Err(NamingConventionError::Parse(format!(
+            "{key} = \"{value}\" only accepts {NAME_TEMPLATE_TABLE} and {NAME_TEMPLATE_COLUMNS}"
+        )));
+    }
+    Ok(Some(value))
+}
+
+fn parse(content: &str) -> Result<NamingConventions, NamingConventionError> {
+    let file: NamingFile =
+        toml::from_str(content).map_err(|e| NamingConventionError::Parse(e.to_string()))?;
+    let defaults = NamingConventions::default();
+    Ok(NamingConventions {
+        snake_case: file.snake_case.unwrap_or(defaults.snake_case),
+        reserved_words: file.reserved_words.unwrap_or(defaults.reserved_words),
+        primary_key: template("primary_key", file.primary_key, defaults.primary_key)?,
+        foreign_key: template("foreign_key", file.foreign_key, defaults.foreign_key)?,
+        index: template("index", file.index, defaults.index)?,
+    })
+}
+
+#[derive(Default)]
+pub struct TomlNamingConventionReader {
+    path: Option<PathBuf>,
+}
+
+impl TomlNamingConventionReader {
+    pub fn new() -> Self {
+        Self { path: None }
+    }
+
+    #[cfg(test)]
+    fn with_path(path: PathBuf) -> Self {
+        Self { path: Some(path) }
+    }
+}
+
+impl NamingConventionReader for TomlNamingConventionReader {
+    fn read_conventions(&self) -> Result<NamingConventions, NamingConventionError> {
+        let path = match &self.path {
+            Some(path) => path.clone(),
+            None => match get_config_dir() {
+                Ok(dir) => dir.join(NAMING_FILE_NAME),
+                Err(_) => return Ok(NamingConventions::default()),
+            },
+        };
+        if !path.is_file() {
+            return Ok(NamingConventions::default());
+        }
+        let content =
+            std::fs::read_to_string(&path).map_err(|source| NamingConventionError::ReadAt {
+                path: path.clone(),
+                source: Arc::new(source),
+            })?;
+        parse(&content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn overrides_templates_and_disables_empty_ones() {
+        let conventions = parse(
+            r#"
+reserved_words = false
+foreign_key = "fk_{table}_{columns}"
+index = ""
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            conventions,
+            NamingConventions {
+                snake_case: true,
+                reserved_words: false,
+                primary_key: Some("{table}_pkey".to_string()),
+                foreign_key: Some("fk_{table}_{columns}".to_string()),
+                index: None,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_placeholder() {
+        let result = parse("index = \"{schema}_{table}_idx\"\n");
+
+        assert!(
+            matches!(result, Err(NamingConventionError::Parse(msg)) if msg.contains("{schema}"))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_key() {
+        let result = parse("camel_case = true\n");
+
+        assert!(matches!(result, Err(NamingConventionError::Parse(_))));
+    }
+
+    #[test]
+    fn missing_file_yields_defaults() {
+        let tmp = TempDir::new().unwrap();
+        let reader = TomlNamingConventionReader::with_path(tmp.path().join(NAMING_FILE_NAME));
+
+        assert_eq!(
+            reader.read_conventions().unwrap(),
+            NamingConventions::default()
+        );
+    }
+}
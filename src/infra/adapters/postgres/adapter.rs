@@ -1,14 +1,31 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use super::native::NativePool;
+
 pub struct PostgresAdapter {
     pub(super) timeout_secs: u64,
+    // DSNs whose server (typically pgbouncer) refused the PGOPTIONS startup
+    // parameter; read-only is then enforced per transaction instead.
+    pub(super) startup_options_rejected: Mutex<HashSet<String>>,
+    // Whether each DSN has the timescaledb extension; checked once per DSN.
+    pub(super) timescaledb_installed: Mutex<HashMap<String, bool>>,
+    // Driver connections reused across queries; psql serves what it cannot.
+    pub(super) native: NativePool,
 }
 
 impl PostgresAdapter {
     pub fn new() -> Self {
-        Self { timeout_secs: 30 }
+        Self::with_timeout(30)
     }
 
     pub fn with_timeout(timeout_secs: u64) -> Self {
-        Self { timeout_secs }
+        Self {
+            timeout_secs,
+            startup_options_rejected: Mutex::new(HashSet::new()),
+            timescaledb_installed: Mutex::new(HashMap::new()),
+            native: NativePool::default(),
+        }
     }
 }
 
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::app::ports::outbound::{ConnectionPool, PoolStats};
+use crate::domain::PoolSettings;
+
+use super::PostgresAdapter;
+
+#[async_trait]
+impl ConnectionPool for PostgresAdapter {
+    async fn warm_up(&self, dsn: &str, settings: PoolSettings) {
+        self.native.warm_up(dsn, settings).await;
+    }
+
+    fn stats(&self, dsn: &str) -> Option<PoolStats> {
+        self.native.stats(dsn)
+    }
+
+    async fn drain(&self) {
+        self.native.drain().await;
+    }
+}
@@ -1,25 +1,19 @@
 use crate::app::ports::outbound::DsnBuilder;
-use crate::domain::connection::ConnectionProfile;
+use crate::domain::connection::{ConnectionProfile, Dsn, DsnStyle, SessionSettingKey};
 
 use super::PostgresAdapter;
 
 impl PostgresAdapter {
     pub fn extract_database_name(dsn: &str) -> String {
-        if let Some(name) = dsn.strip_prefix("service=") {
-            return name.to_string();
+        let Ok(parsed) = Dsn::parse(dsn) else {
+            return "unknown".to_string();
+        };
+        match (parsed.database(), parsed.style()) {
+            // Hand-written conninfo strings sometimes carry URL-encoded names.
+            (Some(name), DsnStyle::Conninfo) => decode_database_name(name),
+            (Some(name), DsnStyle::Url { .. }) => name.to_string(),
+            (None, _) => parsed.param("service").unwrap_or("unknown").to_string(),
         }
-        if let Some(db) = find_conninfo_value(dsn, "dbname") {
-            return decode_database_name(&db);
-        }
-        if let Some(db) = dsn
-            .rsplit('/')
-            .next()
-            .map(|s| s.split('?').next().unwrap_or(s))
-            .filter(|s| !s.is_empty() && !s.contains('='))
-        {
-            return decode_database_name(db);
-        }
-        "unknown".to_string()
     }
 }
 
@@ -32,83 +26,24 @@ impl DsnBuilder for PostgresAdapter {
         let config = profile
             .postgres_config()
             .expect("PostgresAdapter requires a PostgreSQL profile");
-        let mut parts = Vec::new();
-        push_conninfo_part(&mut parts, "host", config.host.trim());
-        push_conninfo_part(&mut parts, "port", &config.port.to_string());
-        push_conninfo_part(&mut parts, "dbname", config.database.trim());
-        push_conninfo_part(&mut parts, "user", config.username.trim());
-        push_conninfo_part(&mut parts, "password", config.password.as_str());
-        push_conninfo_part(&mut parts, "sslmode", &config.ssl_mode.to_string());
-        parts.join(" ")
-    }
-}
-
-fn push_conninfo_part(parts: &mut Vec<String>, key: &str, value: &str) {
-    if !value.is_empty() {
-        parts.push(format!("{key}={}", quote_conninfo_value(value)));
-    }
-}
-
-fn quote_conninfo_value(value: &str) -> String {
-    let escaped = value.replace('\\', "\\\\").replace('\'', "\\'");
-    format!("'{escaped}'")
-}
-
-fn find_conninfo_value(dsn: &str, key: &str) -> Option<String> {
-    let bytes = dsn.as_bytes();
-    let mut i = 0;
-    while i < bytes.len() {
-        while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
-            i += 1;
+        let mut dsn = Dsn::conninfo()
+            .with_host(config.host.trim())
+            .with_port(config.port)
+            .with_database(config.database.trim())
+            .with_user(config.username.trim())
+            .with_password(config.password.as_str())
+            .with_param("sslmode", config.ssl_mode.to_string());
+        let settings = &profile.session_settings;
+        if let Some(name) = settings.get(SessionSettingKey::ApplicationName) {
+            dsn = dsn.with_param("application_name", name);
         }
-        let key_start = i;
-        while bytes
-            .get(i)
-            .is_some_and(|byte| byte.is_ascii_alphanumeric() || *byte == b'_')
+        // pgbouncer rejects the `options` startup parameter outright.
+        if profile.pool_mode().is_none()
+            && let Some(options) = settings.startup_options()
         {
-            i += 1;
+            dsn = dsn.with_param("options", options);
         }
-        let candidate = &dsn[key_start..i];
-        if bytes.get(i) != Some(&b'=') {
-            while bytes.get(i).is_some_and(|byte| !byte.is_ascii_whitespace()) {
-                i += 1;
-            }
-            continue;
-        }
-        i += 1;
-        let (value, next) = parse_conninfo_value(dsn, i);
-        if candidate.eq_ignore_ascii_case(key) {
-            return Some(value);
-        }
-        i = next;
-    }
-    None
-}
-
-fn parse_conninfo_value(dsn: &str, start: usize) -> (String, usize) {
-    let bytes = dsn.as_bytes();
-    if bytes.get(start) == Some(&b'\'') {
-        let mut value = String::new();
-        let mut i = start + 1;
-        while i < bytes.len() {
-            if bytes[i] == b'\\' && bytes.get(i + 1).is_some() {
-                let next = dsn[i + 1..].chars().next().unwrap();
-                value.push(next);
-                i += 1 + next.len_utf8();
-            } else if bytes[i] == b'\'' {
-                return (value, i + 1);
-            } else {
-                let ch = dsn[i..].chars().next().unwrap();
-                value.push(ch);
-                i += ch.len_utf8();
-            }
-        }
-        (value, i)
-    } else {
-        let end = dsn[start..]
-            .find(char::is_whitespace)
-            .map_or(dsn.len(), |offset| start + offset);
-        (dsn[start..end].to_string(), end)
+        dsn.build()
     }
 }
 
@@ -199,6 +134,49 @@ mod tests {
         }
     }
 
+    mod session_settings {
+        use super::*;
+        use crate::domain::connection::{PoolMode, SessionSettings};
+
+        fn profile_with_overrides() -> ConnectionProfile {
+            let mut settings = SessionSettings::default();
+            settings.set(SessionSettingKey::ApplicationName, "sabiql");
+            settings.set(SessionSettingKey::SearchPath, "app, public");
+            ConnectionProfile::new_postgres("Test", "", 5432, "mydb", "", "", SslMode::Prefer)
+                .unwrap()
+                .with_session_settings(settings)
+        }
+
+        #[test]
+        fn appends_application_name_and_options() {
+            let adapter = PostgresAdapter::new();
+
+            let dsn = adapter.build_dsn(&profile_with_overrides());
+
+            assert_eq!(
+                dsn,
+                "port='5432' dbname='mydb' sslmode='prefer' application_name='sabiql' options='-c search_path=app,\\\\ public'"
+            );
+            assert_eq!(
+                Dsn::parse(&dsn).unwrap().param("options"),
+                Some("-c search_path=app,\\ public")
+            );
+        }
+
+        #[test]
+        fn pooled_profiles_keep_only_application_name() {
+            let adapter = PostgresAdapter::new();
+            let profile = profile_with_overrides().with_pool_mode(Some(PoolMode::Transaction));
+
+            let dsn = adapter.build_dsn(&profile);
+
+            assert_eq!(
+                dsn,
+                "port='5432' dbname='mydb' sslmode='prefer' application_name='sabiql'"
+            );
+        }
+    }
+
     mod extract_database_name {
         use super::*;
         use rstest::rstest;
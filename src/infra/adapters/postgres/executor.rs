@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 
 use crate::adapters::csv_export::export_to_downloads;
-use crate::app::ports::outbound::{AccessMode, DbOperationError, QueryExecutor};
+use crate::app::ports::outbound::{
+    AccessMode, DbOperationError, PreviewOptions, QueryExecutor, TransactionEnd,
+    preview_order_columns,
+};
 use crate::domain::{QueryResult, QuerySource, WriteExecutionResult};
 
 use super::PostgresAdapter;
@@ -13,17 +16,54 @@ impl QueryExecutor for PostgresAdapter {
         dsn: &str,
         schema: &str,
         table: &str,
+        options: PreviewOptions,
         limit: usize,
         offset: usize,
     ) -> Result<QueryResult, DbOperationError> {
+        let PreviewOptions {
+            sort_column,
+            filter,
+            columns,
+        } = options;
         // Editing a cell re-fetches the same page; stable ordering prevents the
         // edited row from shifting position after the refresh.
         // On failure, falls back to unordered preview (rows may shift after edits).
-        let order_columns = self
+        let key_columns = self
             .fetch_preview_order_columns(dsn, schema, table)
             .await
             .unwrap_or_default();
-        let query = Self::build_preview_query(schema, table, &order_columns, limit, offset);
+        let order_columns = preview_order_columns(sort_column.as_deref(), &key_columns);
+        // A chosen sort column wins over the newest-first hypertable order.
+        let time_column = match &sort_column {
+            Some(_) => None,
+            None => self
+                .fetch_hypertable(dsn, schema, table)
+                .await
+                .ok()
+                .flatten()
+                .and_then(|hypertable| hypertable.time_column),
+        };
+        let query = match time_column {
+            Some(time_column) => Self::build_hypertable_preview_query(
+                schema,
+                table,
+                &columns,
+                filter.as_deref(),
+                &time_column,
+                &order_columns,
+                limit,
+                offset,
+            ),
+            None => Self::build_preview_query(
+                schema,
+                table,
+                &columns,
+                filter.as_deref(),
+                &order_columns,
+                limit,
+                offset,
+            ),
+        };
         self.execute_query_raw(dsn, &query, QuerySource::Preview, true)
             .await
     }
@@ -63,4 +103,21 @@ impl QueryExecutor for PostgresAdapter {
         })
         .await
     }
+
+    async fn begin_transaction(
+        &self,
+        dsn: &str,
+        access_mode: AccessMode,
+    ) -> Result<(), DbOperationError> {
+        self.begin_native_transaction(dsn, access_mode.is_read_only())
+            .await
+    }
+
+    async fn end_transaction(
+        &self,
+        dsn: &str,
+        end: TransactionEnd,
+    ) -> Result<(), DbOperationError> {
+        self.end_native_transaction(dsn, end).await
+    }
 }
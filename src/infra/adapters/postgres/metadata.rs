@@ -26,11 +26,18 @@ impl MetadataProvider for PostgresAdapter {
 
         let schemas = Self::parse_schemas(&schemas_json)?;
         let tables = Self::parse_tables(&tables_json)?;
+        // Like hypertables, other objects are supplementary: catalogs older
+        // than PostgreSQL 11 lack `prokind`, and tables stay browsable.
+        let objects = match self.execute_query(dsn, Self::schema_objects_query()).await {
+            Ok(json) => Self::parse_schema_objects(&json).unwrap_or_default(),
+            Err(_) => Vec::new(),
+        };
 
         let db_name = Self::extract_database_name(dsn);
         let mut metadata = DatabaseMetadata::new(db_name);
         metadata.schemas = schemas;
         metadata.table_summaries = tables;
+        metadata.objects = objects;
 
         Ok(metadata)
     }
@@ -64,6 +71,13 @@ impl MetadataProvider for PostgresAdapter {
         let (columns, indexes, foreign_keys, rls, triggers, table_info) =
             Self::parse_table_detail_combined(&json)?;
         let primary_key = extract_primary_key(&columns);
+        // Hypertable details are supplementary; the rest of the Inspector
+        // stays usable when TimescaleDB's views are unreadable.
+        let hypertable = self
+            .fetch_hypertable(dsn, schema, table)
+            .await
+            .ok()
+            .flatten();
 
         Ok(Table {
             schema: schema.to_string(),
@@ -79,6 +93,9 @@ impl MetadataProvider for PostgresAdapter {
             comment: table_info.comment,
             source_ddl: None,
             kind_info: TableKindInfo::default(),
+            hypertable,
+            column_stats: Vec::new(),
+            storage: table_info.storage,
         })
     }
 
@@ -90,7 +107,8 @@ impl MetadataProvider for PostgresAdapter {
     ) -> Result<Table, DbOperationError> {
         let query = Self::table_columns_and_fks_query(schema, table);
         let json = self.execute_query(dsn, &query).await?;
-        let (columns, foreign_keys) = Self::parse_table_columns_and_fks(&json)?;
+        let (columns, foreign_keys, indexes, column_stats) =
+            Self::parse_table_columns_and_fks(&json)?;
         let primary_key = extract_primary_key(&columns);
 
         Ok(Table {
@@ -100,13 +118,16 @@ impl MetadataProvider for PostgresAdapter {
             columns,
             primary_key,
             foreign_keys,
-            indexes: Vec::new(),
+            indexes,
             rls: None,
             triggers: Vec::new(),
             row_count_estimate: None,
             comment: None,
             source_ddl: None,
             kind_info: TableKindInfo::default(),
+            hypertable: None,
+            column_stats,
+            storage: None,
         })
     }
 }
@@ -1,7 +1,9 @@
 mod adapter;
+mod connection_pool;
 mod dsn;
 mod executor;
 mod metadata;
+mod native;
 mod psql;
 mod sql;
 
@@ -0,0 +1,129 @@
+// The simple-query protocol only surfaces each CommandComplete's row count,
+// so the tag psql would have printed is rebuilt from the statement text and
+// fed to the same tag parser the psql path uses.
+
+const CREATE_MODIFIERS: &[&str] = &[
+    "OR",
+    "REPLACE",
+    "UNIQUE",
+    "TEMP",
+    "TEMPORARY",
+    "UNLOGGED",
+    "GLOBAL",
+    "LOCAL",
+    "RECURSIVE",
+    "TRUSTED",
+    "PROCEDURAL",
+];
+
+const TWO_WORD_OBJECTS: &[&str] = &["MATERIALIZED", "FOREIGN", "EVENT", "ACCESS", "TEXT"];
+
+pub(super) fn statement_tag(statement: &str, rows: u64) -> String {
+    let words = keywords(statement);
+    let Some(keyword) = words.first().map(String::as_str) else {
+        return String::new();
+    };
+    match keyword {
+        "INSERT" => format!("INSERT 0 {rows}"),
+        "SELECT" | "UPDATE" | "DELETE" | "MERGE" | "COPY" | "FETCH" | "MOVE" => {
+            format!("{keyword} {rows}")
+        }
+        "WITH" => match words
+            .iter()
+            .rev()
+            .find(|word| matches!(word.as_str(), "INSERT" | "UPDATE" | "DELETE" | "MERGE"))
+        {
+            Some(dml) if dml == "INSERT" => format!("INSERT 0 {rows}"),
+            Some(dml) => format!("{dml} {rows}"),
+            None => format!("SELECT {rows}"),
+        },
+        "CREATE" | "DROP" | "ALTER" => {
+            let mut object = words[1..]
+                .iter()
+                .skip_while(|word| CREATE_MODIFIERS.contains(&word.as_str()));
+            match object.next() {
+                Some(first) if TWO_WORD_OBJECTS.contains(&first.as_str()) => match object.next() {
+                    Some(second) => format!("{keyword} {first} {second}"),
+                    None => format!("{keyword} {first}"),
+                },
+                Some(kind) => format!("{keyword} {kind}"),
+                None => keyword.to_string(),
+            }
+        }
+        "END" => "COMMIT".to_string(),
+        "ABORT" => "ROLLBACK".to_string(),
+        "START" => "START TRANSACTION".to_string(),
+        "TRUNCATE" => "TRUNCATE TABLE".to_string(),
+        _ => keyword.to_string(),
+    }
+}
+
+fn keywords(statement: &str) -> Vec<String> {
+    strip_leading_comments(statement)
+        .split(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_uppercase)
+        .collect()
+}
+
+fn strip_leading_comments(statement: &str) -> &str {
+    let mut rest = statement.trim_start();
+    loop {
+        if let Some(line_comment) = rest.strip_prefix("--") {
+            rest = line_comment
+                .split_once('\n')
+                .map_or("", |(_, after)| after)
+                .trim_start();
+        } else if let Some(block_comment) = rest.strip_prefix("/*") {
+            rest = block_comment
+                .split_once("*/")
+                .map_or("", |(_, after)| after)
+                .trim_start();
+        } else {
+            return rest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("INSERT INTO t VALUES (1)", 3, "INSERT 0 3")]
+    #[case("update t set a = 1", 2, "UPDATE 2")]
+    #[case("-- cleanup\nDELETE FROM t", 5, "DELETE 5")]
+    #[case("/* seed */ SELECT * INTO t2 FROM t", 4, "SELECT 4")]
+    #[case("CREATE TABLE t(id int)", 0, "CREATE TABLE")]
+    #[case("create or replace view v as select 1", 0, "CREATE VIEW")]
+    #[case("CREATE UNIQUE INDEX i ON t(id)", 0, "CREATE INDEX")]
+    #[case("CREATE TEMP TABLE t (id int)", 0, "CREATE TABLE")]
+    #[case(
+        "CREATE MATERIALIZED VIEW mv AS SELECT 1",
+        0,
+        "CREATE MATERIALIZED VIEW"
+    )]
+    #[case("DROP TABLE IF EXISTS t", 0, "DROP TABLE")]
+    #[case(
+        "WITH d AS (DELETE FROM a RETURNING id) INSERT INTO b SELECT id FROM d",
+        2,
+        "INSERT 0 2"
+    )]
+    #[case("WITH s AS (SELECT 1) UPDATE t SET a = 1", 1, "UPDATE 1")]
+    #[case("TRUNCATE t", 0, "TRUNCATE TABLE")]
+    #[case("begin", 0, "BEGIN")]
+    #[case("END", 0, "COMMIT")]
+    #[case("ABORT", 0, "ROLLBACK")]
+    #[case("START TRANSACTION READ ONLY", 0, "START TRANSACTION")]
+    #[case("SAVEPOINT s1", 0, "SAVEPOINT")]
+    #[case("SET search_path = app", 0, "SET")]
+    #[case("-- only a comment", 0, "")]
+    fn rebuilds_psql_command_tag(
+        #[case] statement: &str,
+        #[case] rows: u64,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(statement_tag(statement, rows), expected);
+    }
+}
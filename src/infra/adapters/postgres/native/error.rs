@@ -0,0 +1,45 @@
+use std::error::Error as _;
+
+use tokio_postgres::error::DbError;
+
+use crate::app::ports::outbound::DbOperationError;
+
+use super::super::psql::error::classify_query_error;
+
+/// Server errors are rendered the way `psql -v VERBOSITY=verbose` prints
+/// them, so both execution paths share one classifier and one message shape.
+pub(super) fn classify_driver_error(error: &tokio_postgres::Error) -> DbOperationError {
+    if let Some(db_error) = error.as_db_error() {
+        return classify_query_error(&verbose_message(db_error));
+    }
+    if error.is_closed() || is_io_failure(error) {
+        DbOperationError::ConnectionLost(error.to_string())
+    } else {
+        DbOperationError::QueryFailed(error.to_string())
+    }
+}
+
+/// The socket failed rather than the server answering; worth a retry later.
+pub(super) fn is_io_failure(error: &tokio_postgres::Error) -> bool {
+    error
+        .source()
+        .is_some_and(<dyn std::error::Error>::is::<std::io::Error>)
+}
+
+fn verbose_message(error: &DbError) -> String {
+    let mut message = format!(
+        "{}:  {}: {}",
+        error.severity(),
+        error.code().code(),
+        error.message()
+    );
+    if let Some(detail) = error.detail() {
+        message.push_str("\nDETAIL:  ");
+        message.push_str(detail);
+    }
+    if let Some(hint) = error.hint() {
+        message.push_str("\nHINT:  ");
+        message.push_str(hint);
+    }
+    message
+}
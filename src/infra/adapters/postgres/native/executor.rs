@@ -0,0 +1,517 @@
+use std::time::{Duration, Instant};
+
+use tokio::time::timeout;
+use tokio_postgres::{Client, NoTls, SimpleQueryMessage};
+
+use crate::app::ports::outbound::{DbOperationError, TransactionEnd};
+use crate::domain::{QueryResult, QuerySource, QueryValue, StatementTiming, WriteExecutionResult};
+
+use super::super::PostgresAdapter;
+use super::super::psql::parser::split_sql_statements;
+use super::command_tag::statement_tag;
+use super::error::classify_driver_error;
+use super::pool::PooledClient;
+
+struct StatementOutput {
+    sql: String,
+    columns: Option<Vec<String>>,
+    rows: Vec<Vec<Option<String>>>,
+    affected: u64,
+    elapsed: Duration,
+}
+
+impl StatementOutput {
+    fn tag(&self) -> String {
+        if self.columns.is_some() {
+            format!("SELECT {}", self.rows.len())
+        } else {
+            statement_tag(&self.sql, self.affected)
+        }
+    }
+
+    fn timing(&self) -> StatementTiming {
+        let tag = self.tag();
+        let (rows, status) = if tag.is_empty() {
+            (None, "OK".to_string())
+        } else {
+            let rows = PostgresAdapter::parse_command_tag(&tag)
+                .ok()
+                .and_then(|tag| tag.affected_rows());
+            (rows, tag)
+        };
+        StatementTiming {
+            sql: self.sql.clone(),
+            elapsed: self.elapsed,
+            rows,
+            status,
+        }
+    }
+}
+
+/// How a script leaves its pooled session behind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SessionUse {
+    // Our own catalog queries; nothing to undo.
+    Internal,
+    // User SQL may SET, PREPARE or open a transaction; psql got a fresh
+    // session per call, so the pooled one is reset before reuse.
+    User,
+}
+
+// Mirrors psql's `--single-transaction` for scripts and the read-only
+// transaction fallback, while timing each statement on its own.
+async fn run_script(
+    client: &Client,
+    query: &str,
+    read_only: bool,
+) -> Result<Vec<StatementOutput>, tokio_postgres::Error> {
+    let parts = script_parts(query);
+    let wrap = read_only || parts.len() > 1;
+    if wrap {
+        client
+            .simple_query(if read_only {
+                "BEGIN READ ONLY"
+            } else {
+                "BEGIN"
+            })
+            .await?;
+    }
+    let outputs = run_statements(client, &parts).await?;
+    if wrap {
+        client.simple_query("COMMIT").await?;
+    }
+    Ok(outputs)
+}
+
+fn script_parts(query: &str) -> Vec<&str> {
+    let statements = split_sql_statements(query);
+    if statements.len() > 1 {
+        statements
+    } else {
+        vec![query]
+    }
+}
+
+async fn run_statements(
+    client: &Client,
+    parts: &[&str],
+) -> Result<Vec<StatementOutput>, tokio_postgres::Error> {
+    let mut outputs = Vec::with_capacity(parts.len());
+    for sql in parts {
+        #[expect(
+            clippy::disallowed_methods,
+            reason = "infra measures statement execution time at the I/O boundary"
+        )]
+        let start = Instant::now();
+        let messages = client.simple_query(sql).await?;
+        outputs.push(collect_output(sql, messages, start.elapsed()));
+    }
+    Ok(outputs)
+}
+
+fn collect_output(
+    sql: &str,
+    messages: Vec<SimpleQueryMessage>,
+    elapsed: Duration,
+) -> StatementOutput {
+    let mut output = StatementOutput {
+        sql: sql.to_string(),
+        columns: None,
+        rows: Vec::new(),
+        affected: 0,
+        elapsed,
+    };
+    for message in messages {
+        match message {
+            SimpleQueryMessage::RowDescription(columns) => {
+                output.columns = Some(columns.iter().map(|c| c.name().to_string()).collect());
+                output.rows.clear();
+            }
+            SimpleQueryMessage::Row(row) => {
+                output.rows.push(
+                    (0..row.len())
+                        .map(|i| row.get(i).map(str::to_string))
+                        .collect(),
+                );
+            }
+            SimpleQueryMessage::CommandComplete(rows) => output.affected = rows,
+            _ => {}
+        }
+    }
+    output
+}
+
+fn query_result(
+    query: &str,
+    outputs: Vec<StatementOutput>,
+    elapsed: u64,
+    source: QuerySource,
+) -> QueryResult {
+    let timings = if outputs.len() > 1 {
+        outputs.iter().map(StatementOutput::timing).collect()
+    } else {
+        Vec::new()
+    };
+    let tags = outputs
+        .iter()
+        .map(StatementOutput::tag)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if let Some(output) = outputs.into_iter().rev().find(|o| o.columns.is_some()) {
+        let values = output
+            .rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|cell| cell.map_or(QueryValue::Null, QueryValue::Text))
+                    .collect()
+            })
+            .collect();
+        return QueryResult::success_with_values(
+            query.to_string(),
+            output.columns.unwrap_or_default(),
+            values,
+            elapsed,
+            source,
+        )
+        .with_statement_timings(timings);
+    }
+
+    if let Some(tag) = PostgresAdapter::parse_aggregate_command_tag(tags.trim(), query) {
+        return PostgresAdapter::command_tag_result(query, tag, elapsed, source)
+            .with_statement_timings(timings);
+    }
+
+    QueryResult::success(query.to_string(), Vec::new(), Vec::new(), elapsed, source)
+        .with_statement_timings(timings)
+}
+
+// Same shape as `psql -t -A`: one line per row, `|` between fields, NULL as
+// an empty field.
+fn unaligned_rows(outputs: &[StatementOutput]) -> String {
+    let mut text = String::new();
+    for row in outputs.iter().flat_map(|output| &output.rows) {
+        let fields: Vec<&str> = row
+            .iter()
+            .map(|cell| cell.as_deref().unwrap_or(""))
+            .collect();
+        text.push_str(&fields.join("|"));
+        text.push('\n');
+    }
+    text
+}
+
+impl PostgresAdapter {
+    /// Runs `query` over a pooled driver connection. `None` means the DSN
+    /// is served by psql instead.
+    async fn run_native(
+        &self,
+        dsn: &str,
+        query: &str,
+        read_only: bool,
+        session: SessionUse,
+    ) -> Option<Result<Vec<StatementOutput>, DbOperationError>> {
+        if session == SessionUse::User
+            && let Some(client) = self.native.transaction(dsn)
+        {
+            return Some(self.run_in_transaction(dsn, &client, query).await);
+        }
+        let limit = Duration::from_secs(self.timeout_secs);
+        let pooled = timeout(limit, self.native.checkout(dsn)).await.ok()??;
+
+        let outcome = timeout(limit, run_script(pooled.client(), query, read_only)).await;
+        Some(match outcome {
+            Ok(Ok(outputs)) => {
+                if session == SessionUse::User {
+                    Self::reset_session(pooled).await;
+                }
+                Ok(outputs)
+            }
+            Ok(Err(error)) => {
+                let failure = classify_driver_error(&error);
+                Self::recover_session(pooled, error.as_db_error().is_some()).await;
+                Err(failure)
+            }
+            Err(elapsed) => {
+                let token = pooled.client().cancel_token();
+                tokio::spawn(async move {
+                    let _ = token.cancel_query(NoTls).await;
+                });
+                pooled.discard();
+                Err(DbOperationError::Timeout(elapsed.to_string()))
+            }
+        })
+    }
+
+    // No BEGIN/COMMIT around the script: the open transaction is the unit.
+    // A failed statement leaves it aborted until `:rollback`, as in psql.
+    async fn run_in_transaction(
+        &self,
+        dsn: &str,
+        client: &Client,
+        query: &str,
+    ) -> Result<Vec<StatementOutput>, DbOperationError> {
+        if client.is_closed() {
+            self.native.take_transaction(dsn);
+            return Err(DbOperationError::ConnectionLost(
+                "The transaction's connection closed; the server rolled it back".to_string(),
+            ));
+        }
+        let limit = Duration::from_secs(self.timeout_secs);
+        match timeout(limit, run_statements(client, &script_parts(query))).await {
+            Ok(outcome) => outcome.map_err(|error| classify_driver_error(&error)),
+            Err(elapsed) => {
+                let token = client.cancel_token();
+                tokio::spawn(async move {
+                    let _ = token.cancel_query(NoTls).await;
+                });
+                Err(DbOperationError::Timeout(elapsed.to_string()))
+            }
+        }
+    }
+
+    /// Holds a driver connection for `dsn` with a transaction open on it.
+    pub(in crate::adapters::postgres) async fn begin_native_transaction(
+        &self,
+        dsn: &str,
+        read_only: bool,
+    ) -> Result<(), DbOperationError> {
+        if self.native.transaction(dsn).is_some() {
+            return Err(DbOperationError::QueryFailed(
+                "A transaction is already open on this connection".to_string(),
+            ));
+        }
+        let limit = Duration::from_secs(self.timeout_secs);
+        let Some(pooled) = timeout(limit, self.native.checkout(dsn))
+            .await
+            .ok()
+            .flatten()
+        else {
+            return Err(DbOperationError::UnsupportedOperation(
+                "Transactions need a direct driver connection (localhost, a unix socket or \
+                 sslmode=disable); psql serves this connection one statement at a time"
+                    .to_string(),
+            ));
+        };
+        let begin = if read_only {
+            "BEGIN READ ONLY"
+        } else {
+            "BEGIN"
+        };
+        match timeout(limit, pooled.client().simple_query(begin)).await {
+            Ok(Ok(_)) => {
+                self.native.hold_transaction(dsn, pooled.detach());
+                Ok(())
+            }
+            Ok(Err(error)) => {
+                pooled.discard();
+                Err(classify_driver_error(&error))
+            }
+            Err(elapsed) => {
+                pooled.discard();
+                Err(DbOperationError::Timeout(elapsed.to_string()))
+            }
+        }
+    }
+
+    /// Finishes the held transaction; its connection closes either way.
+    pub(in crate::adapters::postgres) async fn end_native_transaction(
+        &self,
+        dsn: &str,
+        end: TransactionEnd,
+    ) -> Result<(), DbOperationError> {
+        let Some(client) = self.native.take_transaction(dsn) else {
+            return Err(DbOperationError::QueryFailed(
+                "No transaction is open on this connection".to_string(),
+            ));
+        };
+        let limit = Duration::from_secs(self.timeout_secs);
+        let finish = async {
+            // COMMIT of an aborted transaction silently rolls back; probing
+            // first turns that into an error the user sees.
+            if end == TransactionEnd::Commit
+                && let Err(error) = client.simple_query("SELECT 1").await
+            {
+                client.simple_query("ROLLBACK").await?;
+                return Err(error);
+            }
+            client.simple_query(end.statement()).await.map(drop)
+        };
+        match timeout(limit, finish).await {
+            Ok(outcome) => outcome.map_err(|error| classify_driver_error(&error)),
+            Err(elapsed) => Err(DbOperationError::Timeout(elapsed.to_string())),
+        }
+    }
+
+    // DISCARD ALL refuses to run inside a transaction block, which is
+    // exactly the session that must not be reused.
+    async fn reset_session(pooled: PooledClient<'_>) {
+        if pooled.client().simple_query("DISCARD ALL").await.is_err() {
+            pooled.discard();
+        }
+    }
+
+    async fn recover_session(pooled: PooledClient<'_>, server_error: bool) {
+        if !server_error
+            || pooled.client().simple_query("ROLLBACK").await.is_err()
+            || pooled.client().simple_query("DISCARD ALL").await.is_err()
+        {
+            pooled.discard();
+        }
+    }
+
+    pub(in crate::adapters::postgres) async fn native_query_text(
+        &self,
+        dsn: &str,
+        query: &str,
+    ) -> Option<Result<String, DbOperationError>> {
+        let outputs = self
+            .run_native(dsn, query, false, SessionUse::Internal)
+            .await?;
+        Some(outputs.map(|outputs| unaligned_rows(&outputs)))
+    }
+
+    pub(in crate::adapters::postgres) async fn native_query_result(
+        &self,
+        dsn: &str,
+        query: &str,
+        source: QuerySource,
+        read_only: bool,
+    ) -> Option<Result<QueryResult, DbOperationError>> {
+        #[expect(
+            clippy::disallowed_methods,
+            reason = "infra measures query execution time at the I/O boundary"
+        )]
+        let start = Instant::now();
+        let outputs = self
+            .run_native(dsn, query, read_only, SessionUse::User)
+            .await?;
+        let elapsed = start.elapsed().as_millis() as u64;
+        Some(outputs.map(|outputs| query_result(query, outputs, elapsed, source)))
+    }
+
+    pub(in crate::adapters::postgres) async fn native_write(
+        &self,
+        dsn: &str,
+        query: &str,
+        read_only: bool,
+    ) -> Option<Result<WriteExecutionResult, DbOperationError>> {
+        #[expect(
+            clippy::disallowed_methods,
+            reason = "infra measures query execution time at the I/O boundary"
+        )]
+        let start = Instant::now();
+        let outputs = self
+            .run_native(dsn, query, read_only, SessionUse::User)
+            .await?;
+        let elapsed = start.elapsed().as_millis() as u64;
+        Some(outputs.and_then(|outputs| {
+            let tags = outputs
+                .iter()
+                .map(StatementOutput::tag)
+                .collect::<Vec<_>>()
+                .join("\n");
+            let affected_rows = Self::parse_affected_rows_with_source(&tags)
+                .map_err(|error| DbOperationError::CommandTagParseFailed(error.to_string()))?;
+            Ok(WriteExecutionResult {
+                affected_rows,
+                execution_time_ms: elapsed,
+            })
+        }))
+    }
+
+    pub(in crate::adapters::postgres) async fn native_count(
+        &self,
+        dsn: &str,
+        query: &str,
+        read_only: bool,
+    ) -> Option<Result<usize, DbOperationError>> {
+        let outputs = self
+            .run_native(dsn, query, read_only, SessionUse::Internal)
+            .await?;
+        Some(outputs.and_then(|outputs| {
+            unaligned_rows(&outputs)
+                .trim()
+                .parse::<usize>()
+                .map_err(|e| {
+                    DbOperationError::QueryFailed(format!("Failed to parse COUNT result: {e}"))
+                })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::CommandTag;
+
+    fn output(sql: &str, columns: Option<&[&str]>, rows: &[&[Option<&str>]]) -> StatementOutput {
+        StatementOutput {
+            sql: sql.to_string(),
+            columns: columns.map(|c| c.iter().map(ToString::to_string).collect()),
+            rows: rows
+                .iter()
+                .map(|row| row.iter().map(|cell| cell.map(str::to_string)).collect())
+                .collect(),
+            affected: rows.len() as u64,
+            elapsed: Duration::from_millis(2),
+        }
+    }
+
+    #[test]
+    fn last_result_set_wins_and_keeps_nulls() {
+        let outputs = vec![
+            output("SELECT 1 AS a", Some(&["a"]), &[&[Some("1")]]),
+            output(
+                "SELECT id, note FROM t",
+                Some(&["id", "note"]),
+                &[&[Some("1"), None]],
+            ),
+        ];
+
+        let result = query_result("q", outputs, 5, QuerySource::Adhoc);
+
+        assert_eq!(result.columns, vec!["id", "note"]);
+        assert_eq!(result.value_at(0, 1), Some(&QueryValue::Null));
+        assert_eq!(result.statement_timings().len(), 2);
+        assert_eq!(result.statement_timings()[1].status, "SELECT 1");
+    }
+
+    #[test]
+    fn tag_only_script_reports_aggregate_tag() {
+        let query = "CREATE TABLE t(id int); INSERT INTO t VALUES (1)";
+        let mut insert = output("INSERT INTO t VALUES (1)", None, &[]);
+        insert.affected = 1;
+        let outputs = vec![output("CREATE TABLE t(id int)", None, &[]), insert];
+
+        let result = query_result(query, outputs, 5, QuerySource::Adhoc);
+
+        assert!(result.columns.is_empty());
+        assert!(result.command_tag.is_some());
+        assert_eq!(result.statement_timings()[0].status, "CREATE TABLE");
+        assert_eq!(result.statement_timings()[1].rows, Some(1));
+    }
+
+    #[test]
+    fn single_statement_has_no_timings() {
+        let mut update = output("UPDATE t SET a = 1", None, &[]);
+        update.affected = 4;
+
+        let result = query_result("UPDATE t SET a = 1", vec![update], 5, QuerySource::Adhoc);
+
+        assert_eq!(result.command_tag, Some(CommandTag::Update(4)));
+        assert!(result.statement_timings().is_empty());
+    }
+
+    #[test]
+    fn unaligned_rows_match_psql_tuples_only_output() {
+        let outputs = vec![output(
+            "SELECT",
+            Some(&["a", "b"]),
+            &[&[Some("1"), None], &[Some("x"), Some("y")]],
+        )];
+
+        assert_eq!(unaligned_rows(&outputs), "1|\nx|y\n");
+    }
+}
@@ -0,0 +1,6 @@
+mod command_tag;
+mod error;
+mod executor;
+mod pool;
+
+pub(in crate::adapters::postgres) use pool::NativePool;
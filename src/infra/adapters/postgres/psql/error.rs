@@ -28,7 +28,10 @@ pub(in crate::adapters::postgres) fn classify_query_error(stderr: &str) -> DbOpe
 
 fn classify_by_sqlstate(sqlstate: &str, details: &str) -> DbOperationError {
     match sqlstate {
-        "08003" | "08006" | "08P01" => DbOperationError::ConnectionLost(details.to_string()),
+        // 57P01/57P02: the server is shutting down under the session
+        "08003" | "08006" | "08P01" | "57P01" | "57P02" => {
+            DbOperationError::ConnectionLost(details.to_string())
+        }
         "08000" | "08001" | "08004" | "08007" => {
             DbOperationError::ConnectionFailed(details.to_string())
         }
@@ -37,6 +40,7 @@ fn classify_by_sqlstate(sqlstate: &str, details: &str) -> DbOperationError {
         "23503" => DbOperationError::ForeignKeyViolation(details.to_string()),
         "23505" => DbOperationError::UniqueViolation(details.to_string()),
         "55P03" => DbOperationError::LockTimeout(details.to_string()),
+        "40P01" => DbOperationError::Deadlock(details.to_string()),
         "57014" => classify_query_canceled(details),
         "42P01" | "42703" => DbOperationError::ObjectMissing(details.to_string()),
         code if code.starts_with("08") => {
@@ -87,6 +91,10 @@ fn classify_by_stderr(details: &str) -> DbOperationError {
         return DbOperationError::LockTimeout(details.to_string());
     }
 
+    if lower.contains("deadlock detected") {
+        return DbOperationError::Deadlock(details.to_string());
+    }
+
     if lower.contains("canceling statement due to statement timeout")
         || lower.contains("statement timeout")
         || lower.contains("query timed out")
@@ -231,6 +239,7 @@ mod tests {
             "UniqueViolation"
         )]
         #[case("ERROR:  55P03: lock not available", "LockTimeout")]
+        #[case("ERROR:  40P01: deadlock detected", "Deadlock")]
         #[case(
             "ERROR:  57014: canceling statement due to statement timeout",
             "Timeout"
@@ -242,6 +251,10 @@ mod tests {
         #[case("ERROR:  42P01: relation \"users\" does not exist", "ObjectMissing")]
         #[case("ERROR:  08006: connection to server was lost", "ConnectionLost")]
         #[case("ERROR:  08006: could not receive data from server", "ConnectionLost")]
+        #[case(
+            "FATAL:  57P01: terminating connection due to administrator command",
+            "ConnectionLost"
+        )]
         #[case("ERROR:  08001: could not connect to server", "ConnectionFailed")]
         fn classifies_sqlstate_first(#[case] input: &str, #[case] expected: &str) {
             let error = classify_query_error(input);
@@ -250,6 +263,7 @@ mod tests {
                 DbOperationError::ForeignKeyViolation(_) => "ForeignKeyViolation",
                 DbOperationError::UniqueViolation(_) => "UniqueViolation",
                 DbOperationError::LockTimeout(_) => "LockTimeout",
+                DbOperationError::Deadlock(_) => "Deadlock",
                 DbOperationError::Timeout(_) => "Timeout",
                 DbOperationError::ObjectMissing(_) => "ObjectMissing",
                 DbOperationError::ConnectionLost(_) => "ConnectionLost",
@@ -269,6 +283,7 @@ mod tests {
         #[case("ERROR: relation \"users\" does not exist", "ObjectMissing")]
         #[case("server closed the connection unexpectedly", "ConnectionLost")]
         #[case("ERROR: canceling statement due to statement timeout", "Timeout")]
+        #[case("ERROR: deadlock detected", "Deadlock")]
         #[case(r#"FATAL: role "alice" does not exist"#, "ConnectionFailed")]
         #[case(r#"ERROR: role "alice" does not exist"#, "QueryFailed")]
         fn falls_back_to_stderr_matching(#[case] input: &str, #[case] expected: &str) {
@@ -279,6 +294,7 @@ mod tests {
                 DbOperationError::ObjectMissing(_) => "ObjectMissing",
                 DbOperationError::ConnectionLost(_) => "ConnectionLost",
                 DbOperationError::Timeout(_) => "Timeout",
+                DbOperationError::Deadlock(_) => "Deadlock",
                 DbOperationError::ConnectionFailed(_) => "ConnectionFailed",
                 DbOperationError::QueryFailed(_) => "QueryFailed",
                 _ => "Other",
@@ -6,7 +6,9 @@ use tokio::process::Command;
 use tokio::time::timeout;
 
 use crate::app::ports::outbound::DbOperationError;
-use crate::domain::{CommandTag, QueryResult, QuerySource, WriteExecutionResult};
+use crate::domain::{
+    CommandTag, Dsn, Hypertable, QueryResult, QuerySource, StatementTiming, WriteExecutionResult,
+};
 
 use super::super::PostgresAdapter;
 use super::error::{classify_cli_spawn_error, classify_query_error};
@@ -34,6 +36,8 @@ fn segmented_query_args(statements: &[&str], marker: &str) -> Vec<String> {
     let echo = format!("\\echo {marker}");
     let mut args = Vec::with_capacity(statements.len() * 4 + 1);
     args.push("--single-transaction".to_string());
+    args.push("-c".to_string());
+    args.push("\\timing on".to_string());
     for stmt in statements {
         args.push("-c".to_string());
         args.push(echo.clone());
@@ -62,6 +66,70 @@ fn split_marker_segments<'a>(stdout: &'a str, marker: &str) -> Vec<&'a str> {
     segments
 }
 
+// `\timing` ends each statement's output with `Time: 1.234 ms`, plus a
+// ` (00:01.235)` suffix past one second.
+fn take_statement_time(segment: &str) -> (&str, Option<Duration>) {
+    let body = segment.trim_end_matches(['\n', '\r']);
+    let (rest, last) = body.rsplit_once('\n').unwrap_or(("", body));
+    let elapsed = last
+        .trim_end_matches('\r')
+        .strip_prefix("Time: ")
+        .and_then(|time| time.split_once(" ms"))
+        .and_then(|(millis, _)| millis.parse::<f64>().ok())
+        .and_then(|millis| Duration::try_from_secs_f64(millis / 1000.0).ok());
+    match elapsed {
+        Some(elapsed) => (rest.trim_end_matches(['\n', '\r']), Some(elapsed)),
+        None => (segment, None),
+    }
+}
+
+// Timings are all-or-nothing: a segment without a `Time:` line means the
+// split cannot be trusted to line up with the statements.
+fn statement_timings(
+    statements: &[&str],
+    segments: &[&str],
+    elapsed: &[Option<Duration>],
+) -> Vec<StatementTiming> {
+    let Some(elapsed) = elapsed.iter().copied().collect::<Option<Vec<_>>>() else {
+        return Vec::new();
+    };
+    statements
+        .iter()
+        .zip(segments)
+        .zip(elapsed)
+        .map(|((sql, segment), elapsed)| {
+            let (rows, status) = if segment.trim().is_empty() {
+                (None, "OK".to_string())
+            } else if PostgresAdapter::is_command_tags_only(segment) {
+                let status = segment
+                    .lines()
+                    .rev()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .unwrap_or_default()
+                    .to_string();
+                let rows = PostgresAdapter::parse_command_tag(segment)
+                    .ok()
+                    .and_then(|tag| tag.affected_rows());
+                (rows, status)
+            } else {
+                let rows = csv::ReaderBuilder::new()
+                    .has_headers(true)
+                    .from_reader(segment.as_bytes())
+                    .records()
+                    .count() as u64;
+                (Some(rows), format!("SELECT {rows}"))
+            };
+            StatementTiming {
+                sql: (*sql).to_string(),
+                elapsed,
+                rows,
+                status,
+            }
+        })
+        .collect()
+}
+
 fn select_result_segment<'a>(segments: &[&'a str]) -> Option<&'a str> {
     segments
         .iter()
@@ -76,6 +144,28 @@ struct PsqlOutput {
     stderr: String,
 }
 
+// pgbouncer answers this to startup parameters outside its
+// `ignore_startup_parameters` list, including the PGOPTIONS `options` packet.
+const UNSUPPORTED_STARTUP_PARAMETER: &str = "unsupported startup parameter";
+
+// QUIET keeps the SET command tag out of the output the parsers read.
+const TRANSACTION_READ_ONLY_ARGS: &[&str] = &[
+    "--single-transaction",
+    "-c",
+    "\\set QUIET on",
+    "-c",
+    "SET TRANSACTION READ ONLY",
+    "-c",
+    "\\set QUIET off",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReadOnlyEnforcement {
+    None,
+    StartupOption,
+    Transaction,
+}
+
 impl PostgresAdapter {
     const PGOPTIONS_READ_ONLY: &str = "-c default_transaction_read_only=on";
 
@@ -96,9 +186,40 @@ impl PostgresAdapter {
         extra_args: &[&str],
         query_args: &[&str],
         read_only: bool,
+    ) -> Result<PsqlOutput, DbOperationError> {
+        let enforcement = self.read_only_enforcement(dsn, read_only);
+        let output = self
+            .spawn_psql(dsn, extra_args, query_args, enforcement)
+            .await?;
+        if enforcement == ReadOnlyEnforcement::StartupOption
+            && !output.status.success()
+            && output.stderr.contains(UNSUPPORTED_STARTUP_PARAMETER)
+        {
+            self.startup_options_rejected
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .insert(dsn.to_string());
+            return self
+                .spawn_psql(
+                    dsn,
+                    extra_args,
+                    query_args,
+                    ReadOnlyEnforcement::Transaction,
+                )
+                .await;
+        }
+        Ok(output)
+    }
+
+    async fn spawn_psql(
+        &self,
+        dsn: &str,
+        extra_args: &[&str],
+        query_args: &[&str],
+        enforcement: ReadOnlyEnforcement,
     ) -> Result<PsqlOutput, DbOperationError> {
         let mut cmd = Command::new("psql");
-        if read_only {
+        if enforcement == ReadOnlyEnforcement::StartupOption {
             Self::apply_read_only_pgoptions(&mut cmd);
         }
         Self::apply_psql_base_args(&mut cmd, dsn);
@@ -106,6 +227,9 @@ impl PostgresAdapter {
         for arg in extra_args {
             cmd.arg(arg);
         }
+        if enforcement == ReadOnlyEnforcement::Transaction {
+            cmd.args(TRANSACTION_READ_ONLY_ARGS);
+        }
         for arg in query_args {
             cmd.arg(arg);
         }
@@ -113,6 +237,23 @@ impl PostgresAdapter {
         Self::collect_output(&mut cmd, self.timeout_secs).await
     }
 
+    fn read_only_enforcement(&self, dsn: &str, read_only: bool) -> ReadOnlyEnforcement {
+        if !read_only {
+            return ReadOnlyEnforcement::None;
+        }
+        let rejected = self
+            .startup_options_rejected
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .contains(dsn);
+        // libpq ignores PGOPTIONS once the DSN carries its own `options`.
+        if rejected || Dsn::parse(dsn).is_ok_and(|dsn| dsn.param("options").is_some()) {
+            ReadOnlyEnforcement::Transaction
+        } else {
+            ReadOnlyEnforcement::StartupOption
+        }
+    }
+
     fn apply_read_only_pgoptions(cmd: &mut Command) {
         let merged = match std::env::var("PGOPTIONS") {
             Ok(existing) => format!("{} {}", Self::PGOPTIONS_READ_ONLY, existing),
@@ -187,6 +328,9 @@ impl PostgresAdapter {
         dsn: &str,
         query: &str,
     ) -> Result<String, DbOperationError> {
+        if let Some(result) = self.native_query_text(dsn, query).await {
+            return result;
+        }
         let output = self.run_psql(dsn, &["-t", "-A"], query, false).await?;
 
         if !output.status.success() {
@@ -203,6 +347,12 @@ impl PostgresAdapter {
         source: QuerySource,
         read_only: bool,
     ) -> Result<QueryResult, DbOperationError> {
+        if let Some(result) = self
+            .native_query_result(dsn, query, source, read_only)
+            .await
+        {
+            return result;
+        }
         let statements = split_sql_statements(query);
         if statements.len() <= 1 {
             return self
@@ -275,13 +425,17 @@ impl PostgresAdapter {
             .run_psql_args(dsn, &["--csv"], &arg_refs, read_only)
             .await?;
 
-        let elapsed = start.elapsed().as_millis() as u64;
+        let total_elapsed = start.elapsed().as_millis() as u64;
 
         if !output.status.success() {
             return Err(Self::classify_psql_error(&output.stderr));
         }
 
-        let segments = split_marker_segments(&output.stdout, &marker);
+        let (segments, elapsed): (Vec<&str>, Vec<Option<Duration>>) =
+            split_marker_segments(&output.stdout, &marker)
+                .into_iter()
+                .map(take_statement_time)
+                .unzip();
         // A mismatch implies a marker collision in data; guessing would
         // reintroduce silent result-set misattribution.
         if segments.len() != statements.len() {
@@ -292,25 +446,30 @@ impl PostgresAdapter {
             )));
         }
 
+        let timings = statement_timings(statements, &segments, &elapsed);
+
         if let Some(csv_block) = select_result_segment(&segments) {
-            return Self::csv_result(query, csv_block, elapsed, source);
+            return Self::csv_result(query, csv_block, total_elapsed, source)
+                .map(|result| result.with_statement_timings(timings));
         }
 
         let tags = segments.join("\n");
         if let Some(tag) = Self::parse_aggregate_command_tag(tags.trim(), query) {
-            return Ok(Self::command_tag_result(query, tag, elapsed, source));
+            return Ok(Self::command_tag_result(query, tag, total_elapsed, source)
+                .with_statement_timings(timings));
         }
 
         Ok(QueryResult::success(
             query.to_string(),
             Vec::new(),
             Vec::new(),
-            elapsed,
+            total_elapsed,
             source,
-        ))
+        )
+        .with_statement_timings(timings))
     }
 
-    fn command_tag_result(
+    pub(in crate::adapters::postgres) fn command_tag_result(
         query: &str,
         tag: CommandTag,
         elapsed: u64,
@@ -356,6 +515,9 @@ impl PostgresAdapter {
         query: &str,
         read_only: bool,
     ) -> Result<WriteExecutionResult, DbOperationError> {
+        if let Some(result) = self.native_write(dsn, query, read_only).await {
+            return result;
+        }
         #[expect(
             clippy::disallowed_methods,
             reason = "infra measures psql execution time at the I/O boundary"
@@ -388,6 +550,9 @@ impl PostgresAdapter {
         query: &str,
         read_only: bool,
     ) -> Result<usize, DbOperationError> {
+        if let Some(result) = self.native_count(dsn, query, read_only).await {
+            return result;
+        }
         let output = self.run_psql(dsn, &["-t", "-A"], query, read_only).await?;
         if !output.status.success() {
             return Err(Self::classify_psql_error(&output.stderr));
@@ -404,12 +569,19 @@ impl PostgresAdapter {
         path: &std::path::Path,
         read_only: bool,
     ) -> Result<(), DbOperationError> {
+        // Streams straight to the file, so there is no retry; an earlier
+        // rejection on this DSN (from the preview, say) picks the fallback.
+        let enforcement = self.read_only_enforcement(dsn, read_only);
         let mut cmd = Command::new("psql");
-        if read_only {
+        if enforcement == ReadOnlyEnforcement::StartupOption {
             Self::apply_read_only_pgoptions(&mut cmd);
         }
         Self::apply_psql_base_args(&mut cmd, dsn);
-        cmd.arg("--csv").arg("-c").arg(query);
+        cmd.arg("--csv");
+        if enforcement == ReadOnlyEnforcement::Transaction {
+            cmd.args(TRANSACTION_READ_ONLY_ARGS);
+        }
+        cmd.arg("-c").arg(query);
 
         let mut child = cmd
             .stdout(Stdio::piped())
@@ -485,7 +657,46 @@ impl PostgresAdapter {
         serde_json::from_str(trimmed).map_err(Into::into)
     }
 
-    fn parse_affected_rows_with_source(stdout: &str) -> Result<usize, ParseCommandTagError> {
+    async fn has_timescaledb(&self, dsn: &str) -> Result<bool, DbOperationError> {
+        let cached = self
+            .timescaledb_installed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(dsn)
+            .copied();
+        if let Some(installed) = cached {
+            return Ok(installed);
+        }
+        let raw = self
+            .execute_query(dsn, Self::timescaledb_installed_query())
+            .await?;
+        let installed = raw.trim() == "t";
+        self.timescaledb_installed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(dsn.to_string(), installed);
+        Ok(installed)
+    }
+
+    /// `None` for ordinary tables and for databases without TimescaleDB,
+    /// which cost a single extension check per DSN.
+    pub(in crate::adapters::postgres) async fn fetch_hypertable(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+    ) -> Result<Option<Hypertable>, DbOperationError> {
+        if !self.has_timescaledb(dsn).await? {
+            return Ok(None);
+        }
+        let query = Self::hypertable_query(schema, table);
+        let json = self.execute_query(dsn, &query).await?;
+        Self::parse_hypertable(&json)
+    }
+
+    pub(in crate::adapters::postgres) fn parse_affected_rows_with_source(
+        stdout: &str,
+    ) -> Result<usize, ParseCommandTagError> {
         let tag = Self::parse_command_tag(stdout)?;
         tag.affected_rows()
             .map(|n| n as usize)
@@ -517,6 +728,51 @@ mod tests {
         }
     }
 
+    mod read_only_enforcement {
+        use super::super::ReadOnlyEnforcement;
+        use super::PostgresAdapter;
+
+        #[test]
+        fn uses_startup_option_until_rejected() {
+            let adapter = PostgresAdapter::new();
+            let dsn = "postgres://pgbouncer:6432/app";
+
+            assert_eq!(
+                adapter.read_only_enforcement(dsn, true),
+                ReadOnlyEnforcement::StartupOption
+            );
+
+            adapter
+                .startup_options_rejected
+                .lock()
+                .unwrap()
+                .insert(dsn.to_string());
+
+            assert_eq!(
+                adapter.read_only_enforcement(dsn, true),
+                ReadOnlyEnforcement::Transaction
+            );
+            assert_eq!(
+                adapter.read_only_enforcement(dsn, false),
+                ReadOnlyEnforcement::None
+            );
+            assert_eq!(
+                adapter.read_only_enforcement("postgres://db/app", true),
+                ReadOnlyEnforcement::StartupOption
+            );
+        }
+
+        #[test]
+        fn dsn_options_fall_back_to_transaction() {
+            let adapter = PostgresAdapter::new();
+
+            assert_eq!(
+                adapter.read_only_enforcement("host='db' options='-c statement_timeout=30s'", true),
+                ReadOnlyEnforcement::Transaction
+            );
+        }
+    }
+
     mod segmented_query_args {
         use super::super::segmented_query_args;
 
@@ -529,6 +785,8 @@ mod tests {
                 vec![
                     "--single-transaction",
                     "-c",
+                    "\\timing on",
+                    "-c",
                     "\\echo M",
                     "-c",
                     "SELECT 1",
@@ -583,6 +841,81 @@ mod tests {
         }
     }
 
+    mod take_statement_time {
+        use std::time::Duration;
+
+        use super::super::take_statement_time;
+
+        #[test]
+        fn strips_trailing_time_line() {
+            let (body, elapsed) = take_statement_time("INSERT 0 3\nTime: 12.500 ms");
+
+            assert_eq!(body, "INSERT 0 3");
+            assert_eq!(elapsed, Some(Duration::from_micros(12_500)));
+        }
+
+        #[test]
+        fn reads_millis_before_long_duration_suffix() {
+            let (body, elapsed) = take_statement_time("id\n1\r\nTime: 1500.000 ms (00:01.500)\r\n");
+
+            assert_eq!(body, "id\n1");
+            assert_eq!(elapsed, Some(Duration::from_millis(1500)));
+        }
+
+        #[test]
+        fn segment_without_time_is_unchanged() {
+            assert_eq!(take_statement_time("id\n1"), ("id\n1", None));
+        }
+    }
+
+    mod statement_timings {
+        use std::time::Duration;
+
+        use super::super::statement_timings;
+
+        #[test]
+        fn reports_rows_and_status_per_statement() {
+            let timings = statement_timings(
+                &[
+                    "INSERT INTO t VALUES (1)",
+                    "SELECT id FROM t",
+                    "CREATE INDEX i ON t (id)",
+                ],
+                &["INSERT 0 1", "id\n1\n2", "CREATE INDEX"],
+                &[
+                    Some(Duration::from_millis(3)),
+                    Some(Duration::from_millis(1)),
+                    Some(Duration::from_millis(40)),
+                ],
+            );
+
+            let summary: Vec<_> = timings
+                .iter()
+                .map(|t| (t.rows, t.status.as_str(), t.elapsed))
+                .collect();
+            assert_eq!(
+                summary,
+                vec![
+                    (Some(1), "INSERT 0 1", Duration::from_millis(3)),
+                    (Some(2), "SELECT 2", Duration::from_millis(1)),
+                    (None, "CREATE INDEX", Duration::from_millis(40)),
+                ]
+            );
+            assert_eq!(timings[1].sql, "SELECT id FROM t");
+        }
+
+        #[test]
+        fn missing_time_drops_all_timings() {
+            let timings = statement_timings(
+                &["SELECT 1", "SELECT 2"],
+                &["a\n1", "a\n2"],
+                &[Some(Duration::from_millis(1)), None],
+            );
+
+            assert!(timings.is_empty());
+        }
+    }
+
     mod select_result_segment {
         use super::super::select_result_segment;
 
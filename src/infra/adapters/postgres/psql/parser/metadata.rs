@@ -1,11 +1,16 @@
 use crate::app::ports::outbound::DbOperationError;
 use crate::domain::{
-    Column, ColumnAttributes, FkAction, ForeignKey, Index, IndexAttributes, IndexType, RlsCommand,
-    RlsInfo, RlsPolicy, Schema, TableSignature, TableSummary, Trigger, TriggerEvent, TriggerTiming,
+    Column, ColumnAttributes, ColumnStats, FkAction, ForeignKey, Hypertable, Index,
+    IndexAttributes, IndexType, RlsCommand, RlsInfo, RlsPolicy, Schema, SchemaObject,
+    SchemaObjectKind, TableKind, TableKindInfo, TableSignature, TableStorage, TableSummary,
+    Trigger, TriggerEvent, TriggerTiming,
 };
 
 use super::super::super::PostgresAdapter;
 
+pub(in crate::adapters::postgres) type TableCompletionDetail =
+    (Vec<Column>, Vec<ForeignKey>, Vec<Index>, Vec<ColumnStats>);
+
 pub(in crate::adapters::postgres) type TableDetailCombined = (
     Vec<Column>,
     Vec<Index>,
@@ -46,6 +51,7 @@ pub(in crate::adapters::postgres) struct TableInfo {
     pub owner: Option<String>,
     pub comment: Option<String>,
     pub row_count_estimate: Option<i64>,
+    pub storage: Option<TableStorage>,
 }
 
 impl PostgresAdapter {
@@ -57,6 +63,7 @@ impl PostgresAdapter {
                 owner: None,
                 comment: None,
                 row_count_estimate: None,
+                storage: None,
             });
         };
 
@@ -65,6 +72,8 @@ impl PostgresAdapter {
             owner: Option<String>,
             comment: Option<String>,
             row_count_estimate: Option<i64>,
+            #[serde(default)]
+            storage: Option<TableStorage>,
         }
 
         let raw: RawTableInfo = serde_json::from_str(trimmed)?;
@@ -76,6 +85,7 @@ impl PostgresAdapter {
             owner: raw.owner,
             comment: raw.comment,
             row_count_estimate: row_count,
+            storage: raw.storage,
         })
     }
 
@@ -92,16 +102,99 @@ impl PostgresAdapter {
             name: String,
             row_count_estimate: Option<i64>,
             has_rls: bool,
+            #[serde(default)]
+            is_hypertable: bool,
         }
 
         let raw: Vec<RawTable> = serde_json::from_str(trimmed)?;
 
         Ok(raw
             .into_iter()
-            .map(|t| TableSummary::new(t.schema, t.name, t.row_count_estimate, t.has_rls))
+            .map(|t| {
+                let summary = TableSummary::new(t.schema, t.name, t.row_count_estimate, t.has_rls);
+                if t.is_hypertable {
+                    summary.with_kind_info(TableKindInfo {
+                        kind: TableKind::Hypertable,
+                        ..TableKindInfo::default()
+                    })
+                } else {
+                    summary
+                }
+            })
             .collect())
     }
 
+    pub(in crate::adapters::postgres) fn parse_schema_objects(
+        json: &str,
+    ) -> Result<Vec<SchemaObject>, DbOperationError> {
+        let Some(trimmed) = non_empty_json(json) else {
+            return Ok(Vec::new());
+        };
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        enum RawKind {
+            View,
+            MaterializedView,
+            Sequence,
+            Function,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct RawObject {
+            schema: String,
+            name: String,
+            kind: RawKind,
+            arguments: Option<String>,
+            definition: Option<String>,
+        }
+
+        let raw: Vec<RawObject> = serde_json::from_str(trimmed)?;
+
+        Ok(raw
+            .into_iter()
+            .map(|o| SchemaObject {
+                schema: o.schema,
+                name: o.name,
+                kind: match o.kind {
+                    RawKind::View => SchemaObjectKind::View,
+                    RawKind::MaterializedView => SchemaObjectKind::MaterializedView,
+                    RawKind::Sequence => SchemaObjectKind::Sequence,
+                    RawKind::Function => SchemaObjectKind::Function,
+                },
+                arguments: o.arguments,
+                definition: o.definition,
+            })
+            .collect())
+    }
+
+    pub(in crate::adapters::postgres) fn parse_hypertable(
+        json: &str,
+    ) -> Result<Option<Hypertable>, DbOperationError> {
+        let Some(trimmed) = non_empty_json(json) else {
+            return Ok(None);
+        };
+
+        #[derive(serde::Deserialize)]
+        struct RawHypertable {
+            time_column: Option<String>,
+            chunk_count: Option<i64>,
+            compression_enabled: Option<bool>,
+            compressed_chunk_count: i64,
+            retention: Option<String>,
+        }
+
+        let raw: RawHypertable = serde_json::from_str(trimmed)?;
+
+        Ok(Some(Hypertable {
+            time_column: raw.time_column,
+            chunk_count: raw.chunk_count.unwrap_or(0),
+            compression_enabled: raw.compression_enabled.unwrap_or(false),
+            compressed_chunk_count: raw.compressed_chunk_count,
+            retention: raw.retention,
+        }))
+    }
+
     pub(in crate::adapters::postgres) fn parse_table_signatures(
         json: &str,
     ) -> Result<Vec<TableSignature>, DbOperationError> {
@@ -394,7 +487,7 @@ impl PostgresAdapter {
 
     pub(in crate::adapters::postgres) fn parse_table_columns_and_fks(
         json: &str,
-    ) -> Result<(Vec<Column>, Vec<ForeignKey>), DbOperationError> {
+    ) -> Result<TableCompletionDetail, DbOperationError> {
         let Some(trimmed) = non_empty_json(json) else {
             return Err(DbOperationError::EmptyResponse(
                 "table_columns_and_fks".to_string(),
@@ -406,14 +499,27 @@ impl PostgresAdapter {
         struct LightDetail {
             columns: serde_json::Value,
             foreign_keys: serde_json::Value,
+            indexes: serde_json::Value,
+            column_stats: serde_json::Value,
         }
 
         let light: LightDetail = serde_json::from_str(trimmed)?;
 
         let columns = Self::parse_columns(&light.columns.to_string())?;
         let foreign_keys = Self::parse_foreign_keys(&light.foreign_keys.to_string())?;
+        let indexes = Self::parse_indexes(&light.indexes.to_string())?;
+        let column_stats = Self::parse_column_stats(&light.column_stats.to_string())?;
 
-        Ok((columns, foreign_keys))
+        Ok((columns, foreign_keys, indexes, column_stats))
+    }
+
+    pub(in crate::adapters::postgres) fn parse_column_stats(
+        json: &str,
+    ) -> Result<Vec<ColumnStats>, DbOperationError> {
+        let Some(trimmed) = non_empty_json(json) else {
+            return Ok(Vec::new());
+        };
+        serde_json::from_str(trimmed).map_err(Into::into)
     }
 }
 
@@ -422,6 +528,52 @@ mod tests {
     use crate::adapters::postgres::PostgresAdapter;
     use crate::app::ports::outbound::DbOperationError;
 
+    mod schema_object_parsing {
+        use super::*;
+        use crate::domain::SchemaObjectKind;
+
+        #[test]
+        fn null_aggregate_returns_no_objects() {
+            let result = PostgresAdapter::parse_schema_objects("null").unwrap();
+            assert!(result.is_empty());
+        }
+
+        #[test]
+        fn kinds_and_function_arguments_are_kept() {
+            let json = r#"[
+                {"schema": "public", "name": "active_users", "kind": "view",
+                 "arguments": null, "definition": "CREATE VIEW public.active_users AS\n SELECT 1;"},
+                {"schema": "public", "name": "daily_totals", "kind": "materialized_view",
+                 "arguments": null, "definition": null},
+                {"schema": "public", "name": "add", "kind": "function",
+                 "arguments": "a integer, b integer",
+                 "definition": "FUNCTION public.add(a integer, b integer) RETURNS integer LANGUAGE sql"}
+            ]"#;
+
+            let objects = PostgresAdapter::parse_schema_objects(json).unwrap();
+
+            assert_eq!(
+                objects.iter().map(|o| o.kind).collect::<Vec<_>>(),
+                vec![
+                    SchemaObjectKind::View,
+                    SchemaObjectKind::MaterializedView,
+                    SchemaObjectKind::Function,
+                ]
+            );
+            assert_eq!(
+                objects[2].qualified_name(),
+                "public.add(a integer, b integer)"
+            );
+        }
+
+        #[test]
+        fn unknown_kind_is_rejected() {
+            let json = r#"[{"schema": "public", "name": "t", "kind": "table"}]"#;
+            let result = PostgresAdapter::parse_schema_objects(json);
+            assert!(matches!(result, Err(DbOperationError::InvalidJson(_))));
+        }
+    }
+
     mod table_signature_parsing {
         use super::*;
         use rstest::rstest;
@@ -681,6 +833,43 @@ mod tests {
         }
     }
 
+    mod hypertable_parsing {
+        use super::*;
+        use crate::domain::{Hypertable, TableKind};
+
+        #[test]
+        fn non_hypertable_returns_none() {
+            assert_eq!(PostgresAdapter::parse_hypertable("").unwrap(), None);
+        }
+
+        #[test]
+        fn parses_chunks_compression_and_retention() {
+            let json = r#"{"time_column": "time", "chunk_count": 12, "compression_enabled": true, "compressed_chunk_count": 9, "retention": "90 days"}"#;
+
+            let hypertable = PostgresAdapter::parse_hypertable(json).unwrap();
+
+            assert_eq!(
+                hypertable,
+                Some(Hypertable {
+                    time_column: Some("time".to_string()),
+                    chunk_count: 12,
+                    compression_enabled: true,
+                    compressed_chunk_count: 9,
+                    retention: Some("90 days".to_string()),
+                })
+            );
+        }
+
+        #[test]
+        fn table_list_marks_hypertables() {
+            let json = r#"[{"schema": "public", "name": "metrics", "row_count_estimate": 10, "has_rls": false, "is_hypertable": true}]"#;
+
+            let tables = PostgresAdapter::parse_tables(json).unwrap();
+
+            assert_eq!(tables[0].kind_info.kind, TableKind::Hypertable);
+        }
+    }
+
     mod table_info_parsing {
         use super::*;
         use rstest::rstest;
@@ -736,6 +925,28 @@ mod tests {
             assert_eq!(info.row_count_estimate, Some(0));
         }
 
+        #[test]
+        fn storage_object_parses_sizes_and_stats() {
+            let json = r#"{"owner": null, "comment": null, "row_count_estimate": 10, "storage": {
+                "total_bytes": 65536, "table_bytes": 40960, "index_bytes": 16384, "toast_bytes": 8192,
+                "live_tuples": 10, "dead_tuples": 2, "last_vacuum": null,
+                "last_autovacuum": "2026-01-02 03:04:05", "last_analyze": null, "last_autoanalyze": null
+            }}"#;
+
+            let storage = PostgresAdapter::parse_table_info(json)
+                .unwrap()
+                .storage
+                .unwrap();
+
+            assert_eq!(storage.total_bytes, 65536);
+            assert_eq!(storage.dead_tuples, Some(2));
+            assert_eq!(
+                storage.last_autovacuum.as_deref(),
+                Some("2026-01-02 03:04:05")
+            );
+            assert!(storage.last_vacuum.is_none());
+        }
+
         #[test]
         fn malformed_json_returns_invalid_json_error() {
             let result = PostgresAdapter::parse_table_info("{not valid json}");
@@ -1239,9 +1450,12 @@ mod tests {
 
     mod table_columns_and_fks_parsing {
         use super::*;
+        use crate::domain::ColumnStats;
 
-        fn build_light_json(columns: &str, fks: &str) -> String {
-            format!(r#"{{"columns": {columns}, "foreign_keys": {fks}}}"#)
+        fn build_light_json(columns: &str, fks: &str, stats: &str) -> String {
+            format!(
+                r#"{{"columns": {columns}, "foreign_keys": {fks}, "indexes": null, "column_stats": {stats}}}"#
+            )
         }
 
         #[test]
@@ -1249,24 +1463,37 @@ mod tests {
             let json = build_light_json(
                 r#"[{"name":"id","data_type":"integer","nullable":false,"default":null,"is_primary_key":true,"is_unique":false,"comment":null,"ordinal_position":1}]"#,
                 r#"[{"name":"fk_1","from_schema":"public","from_table":"orders","from_columns":["user_id"],"to_schema":"public","to_table":"users","to_columns":["id"],"on_delete":"c","on_update":"a"}]"#,
+                r#"[{"column":"status","null_frac":0.25,"n_distinct":4}]"#,
             );
 
-            let (columns, fks) = PostgresAdapter::parse_table_columns_and_fks(&json).unwrap();
+            let (columns, fks, _, stats) =
+                PostgresAdapter::parse_table_columns_and_fks(&json).unwrap();
 
             assert_eq!(columns.len(), 1);
             assert_eq!(columns[0].name, "id");
             assert_eq!(fks.len(), 1);
             assert_eq!(fks[0].name, "fk_1");
+            assert_eq!(
+                stats,
+                vec![ColumnStats {
+                    column: "status".to_string(),
+                    null_frac: 0.25,
+                    n_distinct: 4.0,
+                }]
+            );
         }
 
         #[test]
         fn null_sub_values_parse_to_empty() {
-            let json = build_light_json("null", "null");
+            let json = build_light_json("null", "null", "null");
 
-            let (columns, fks) = PostgresAdapter::parse_table_columns_and_fks(&json).unwrap();
+            let (columns, fks, indexes, stats) =
+                PostgresAdapter::parse_table_columns_and_fks(&json).unwrap();
 
             assert!(columns.is_empty());
             assert!(fks.is_empty());
+            assert!(indexes.is_empty());
+            assert!(stats.is_empty());
         }
 
         #[test]
@@ -1278,7 +1505,7 @@ mod tests {
 
         #[test]
         fn unknown_key_returns_error() {
-            let json = r#"{"columns": null, "foreign_keys": null, "extra": null}"#;
+            let json = r#"{"columns": null, "foreign_keys": null, "indexes": null, "column_stats": null, "extra": null}"#;
             let result = PostgresAdapter::parse_table_columns_and_fks(json);
             assert!(matches!(result, Err(DbOperationError::InvalidJson(_))));
         }
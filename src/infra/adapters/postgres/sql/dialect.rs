@@ -1,7 +1,10 @@
 use std::fmt::Write as _;
 
-use crate::app::ports::outbound::SqlDialect;
-use crate::domain::{DatabaseType, QueryValue};
+use crate::app::ports::outbound::{NamingConventions, SqlDialect};
+use crate::domain::{
+    Column, DatabaseType, ForeignKey, Index, IndexMaintenanceKind, LockConflict, MaintenanceKind,
+    QueryValue,
+};
 
 use super::super::PostgresAdapter;
 use super::{quote_ident, quote_literal};
@@ -63,7 +66,158 @@ impl SqlDialect for PostgresAdapter {
         _database_type: DatabaseType,
         query: &str,
     ) -> Option<String> {
-        Some(format!("EXPLAIN ANALYZE {query}"))
+        Some(format!("EXPLAIN (ANALYZE, BUFFERS) {query}"))
+    }
+
+    fn build_replication_overview_sql(
+        &self,
+        _database_type: DatabaseType,
+        table: Option<&str>,
+    ) -> Option<String> {
+        Some(Self::replication_overview_query(table))
+    }
+
+    fn build_sample_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        limit: usize,
+        row_estimate: Option<i64>,
+    ) -> String {
+        Self::sample_query(schema, table, limit, row_estimate)
+    }
+
+    fn build_row_count_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+    ) -> String {
+        Self::row_count_query(schema, table)
+    }
+
+    fn build_rls_test_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        role: &str,
+    ) -> Option<String> {
+        Some(Self::rls_test_query(schema, table, role))
+    }
+
+    fn build_maintenance_sql(
+        &self,
+        _database_type: DatabaseType,
+        kind: MaintenanceKind,
+        schema: &str,
+        table: &str,
+    ) -> Option<String> {
+        Some(Self::maintenance_query(kind, schema, table))
+    }
+
+    fn build_maintenance_progress_sql(
+        &self,
+        _database_type: DatabaseType,
+        kind: MaintenanceKind,
+        schema: &str,
+        table: &str,
+    ) -> Option<String> {
+        Some(Self::maintenance_progress_query(kind, schema, table))
+    }
+
+    fn build_index_maintenance_sql(
+        &self,
+        _database_type: DatabaseType,
+        kind: IndexMaintenanceKind,
+        schema: &str,
+        index: &Index,
+    ) -> Option<Vec<String>> {
+        Self::index_maintenance_queries(kind, schema, index)
+    }
+
+    fn build_index_maintenance_progress_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+    ) -> Option<String> {
+        Some(Self::index_maintenance_progress_query(schema, table))
+    }
+
+    fn build_autovacuum_report_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+    ) -> Option<String> {
+        Some(Self::autovacuum_report_query(schema, table))
+    }
+
+    fn build_table_sizes_sql(
+        &self,
+        _database_type: DatabaseType,
+        tables: &[(String, String)],
+    ) -> Option<String> {
+        (!tables.is_empty()).then(|| Self::table_sizes_query(tables))
+    }
+
+    fn build_server_settings_sql(&self, _database_type: DatabaseType) -> Option<String> {
+        Some(Self::server_settings_query().to_string())
+    }
+
+    fn build_index_usage_sql(&self, _database_type: DatabaseType) -> Option<String> {
+        Some(Self::index_usage_query().to_string())
+    }
+
+    fn build_fk_orphan_check_sql(
+        &self,
+        _database_type: DatabaseType,
+        foreign_keys: &[ForeignKey],
+    ) -> Option<String> {
+        Some(Self::fk_orphan_check_query(foreign_keys))
+    }
+
+    fn build_fk_suggestion_sql(&self, _database_type: DatabaseType) -> Option<String> {
+        Some(Self::fk_suggestion_query())
+    }
+
+    fn build_naming_audit_sql(
+        &self,
+        _database_type: DatabaseType,
+        conventions: &NamingConventions,
+    ) -> Option<String> {
+        Some(Self::naming_audit_query(conventions))
+    }
+
+    fn build_lock_blockers_sql(
+        &self,
+        _database_type: DatabaseType,
+        conflict: &LockConflict,
+    ) -> Option<String> {
+        Some(Self::lock_blockers_query(conflict))
+    }
+
+    fn build_result_types_sql(&self, _database_type: DatabaseType, query: &str) -> Option<String> {
+        Self::result_types_query(query)
+    }
+
+    fn build_column_distribution_sql(
+        &self,
+        _database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        column: &Column,
+        row_estimate: Option<i64>,
+    ) -> Option<String> {
+        Some(Self::column_distribution_query(
+            schema,
+            table,
+            &column.name,
+            &column.data_type,
+            row_estimate,
+        ))
     }
 
     fn build_update_sql(
@@ -179,12 +333,12 @@ mod tests {
         }
 
         #[test]
-        fn explain_analyze_sql_uses_postgres_prefix() {
+        fn explain_analyze_sql_reports_buffers() {
             let adapter = PostgresAdapter::new();
 
             assert_eq!(
                 adapter.build_explain_analyze_sql(DatabaseType::PostgreSQL, "SELECT 1"),
-                Some("EXPLAIN ANALYZE SELECT 1".to_string())
+                Some("EXPLAIN (ANALYZE, BUFFERS) SELECT 1".to_string())
             );
         }
     }
@@ -1,5 +1,33 @@
 use super::super::PostgresAdapter;
 use super::{quote_ident, quote_literal};
+use crate::adapters::postgres::psql::parser::split_sql_statements;
+use crate::app::ports::outbound::{
+    NAME_TEMPLATE_COLUMNS, NAME_TEMPLATE_TABLE, NamingConventions, preview_where_clause,
+};
+use crate::domain::{ForeignKey, Index, IndexMaintenanceKind, LockConflict, MaintenanceKind};
+
+const FK_SUGGESTION_SAMPLE_ROWS: usize = 1000;
+const RESULT_TYPES_STATEMENT: &str = "sabiql_result_types";
+/// Below this many estimated rows a full `ORDER BY random()` scan is cheap.
+const TABLESAMPLE_MIN_ROWS: i64 = 10_000;
+/// Block sampling returns uneven counts, so read a few times what is needed.
+const TABLESAMPLE_OVERSAMPLE: f64 = 4.0;
+/// Rows read when a column has no `pg_stats` entry to describe it.
+const DISTRIBUTION_SAMPLE_ROWS: usize = 10_000;
+const DISTRIBUTION_COMMON_VALUES: usize = 10;
+const AVERAGED_TYPES: &[&str] = &[
+    "smallint",
+    "integer",
+    "bigint",
+    "numeric",
+    "decimal",
+    "real",
+    "double precision",
+    "interval",
+];
+const ORDERED_TYPE_PREFIXES: &[&str] = &[
+    "text", "char", "varchar", "char", "citext", "date", "time", "uuid", "inet",
+];
 
 impl PostgresAdapter {
     pub(in crate::adapters::postgres) fn tables_query() -> &'static str {
@@ -10,11 +38,20 @@ impl PostgresAdapter {
                 n.nspname as schema,
                 c.relname as name,
                 c.reltuples::bigint as row_count_estimate,
-                c.relrowsecurity as has_rls
+                c.relrowsecurity as has_rls,
+                EXISTS (
+                    SELECT 1
+                    FROM pg_inherits i
+                    JOIN pg_class ch ON ch.oid = i.inhrelid
+                    JOIN pg_namespace chn ON chn.oid = ch.relnamespace
+                    WHERE i.inhparent = c.oid
+                      AND chn.nspname = '_timescaledb_internal'
+                ) as is_hypertable
             FROM pg_class c
             JOIN pg_namespace n ON n.oid = c.relnamespace
             WHERE c.relkind = 'r'
               AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+              AND n.nspname NOT LIKE '\_timescaledb\_%'
               AND (
                   has_table_privilege(c.oid, 'SELECT')
                   OR has_table_privilege(c.oid, 'INSERT')
@@ -65,6 +102,7 @@ impl PostgresAdapter {
             JOIN pg_namespace n ON n.oid = c.relnamespace
             WHERE c.relkind = 'r'
               AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+              AND n.nspname NOT LIKE '\_timescaledb\_%'
               AND (
                   has_table_privilege(c.oid, 'SELECT')
                   OR has_table_privilege(c.oid, 'INSERT')
@@ -79,6 +117,84 @@ impl PostgresAdapter {
         "
     }
 
+    /// Views, materialized views, standalone sequences and functions.
+    /// Extension members and sequences owned by a column are left out.
+    pub(in crate::adapters::postgres) fn schema_objects_query() -> &'static str {
+        r"
+        SELECT json_agg(row_to_json(o))
+        FROM (
+            SELECT
+                n.nspname AS schema,
+                c.relname AS name,
+                CASE c.relkind
+                    WHEN 'v' THEN 'view'
+                    WHEN 'm' THEN 'materialized_view'
+                    ELSE 'sequence'
+                END AS kind,
+                NULL::text AS arguments,
+                CASE c.relkind
+                    WHEN 'S' THEN (
+                        SELECT format(
+                            'CREATE SEQUENCE %I.%I AS %s START %s INCREMENT %s MINVALUE %s MAXVALUE %s%s;',
+                            n.nspname, c.relname, format_type(s.seqtypid, NULL),
+                            s.seqstart, s.seqincrement, s.seqmin, s.seqmax,
+                            CASE WHEN s.seqcycle THEN ' CYCLE' ELSE '' END
+                        )
+                        FROM pg_sequence s
+                        WHERE s.seqrelid = c.oid
+                    )
+                    ELSE format(
+                        'CREATE %sVIEW %I.%I AS' || E'
+' || '%s',
+                        CASE c.relkind WHEN 'm' THEN 'MATERIALIZED ' ELSE '' END,
+                        n.nspname, c.relname, pg_get_viewdef(c.oid, true)
+                    )
+                END AS definition
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relkind IN ('v', 'm', 'S')
+              AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+              AND n.nspname NOT LIKE '\_timescaledb\_%'
+              AND has_table_privilege(c.oid, 'SELECT')
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM pg_depend d
+                  WHERE d.classid = 'pg_class'::regclass
+                    AND d.objid = c.oid
+                    AND d.deptype IN ('e', 'a', 'i')
+              )
+            UNION ALL
+            SELECT
+                n.nspname AS schema,
+                p.proname AS name,
+                'function' AS kind,
+                pg_get_function_identity_arguments(p.oid) AS arguments,
+                format(
+                    '%s %I.%I(%s)%s LANGUAGE %s',
+                    CASE p.prokind WHEN 'p' THEN 'PROCEDURE' ELSE 'FUNCTION' END,
+                    n.nspname, p.proname, pg_get_function_arguments(p.oid),
+                    COALESCE(' RETURNS ' || pg_get_function_result(p.oid), ''),
+                    l.lanname
+                ) AS definition
+            FROM pg_proc p
+            JOIN pg_namespace n ON n.oid = p.pronamespace
+            JOIN pg_language l ON l.oid = p.prolang
+            WHERE p.prokind IN ('f', 'p')
+              AND n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+              AND n.nspname NOT LIKE '\_timescaledb\_%'
+              AND has_function_privilege(p.oid, 'EXECUTE')
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM pg_depend d
+                  WHERE d.classid = 'pg_proc'::regclass
+                    AND d.objid = p.oid
+                    AND d.deptype = 'e'
+              )
+            ORDER BY 1, 3, 2, 4
+        ) o
+        "
+    }
+
     pub(in crate::adapters::postgres) fn schemas_query() -> &'static str {
         r"
         SELECT json_agg(row_to_json(s))
@@ -88,6 +204,7 @@ impl PostgresAdapter {
             WHERE nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
               AND nspname NOT LIKE 'pg_temp_%'
               AND nspname NOT LIKE 'pg_toast_temp_%'
+              AND nspname NOT LIKE '\_timescaledb\_%'
             ORDER BY nspname
         ) s
         "
@@ -161,6 +278,8 @@ impl PostgresAdapter {
     pub(in crate::adapters::postgres) fn build_preview_query(
         schema: &str,
         table: &str,
+        columns: &[String],
+        filter: Option<&str>,
         order_columns: &[String],
         limit: usize,
         offset: usize,
@@ -177,9 +296,11 @@ impl PostgresAdapter {
         };
 
         format!(
-            "SELECT * FROM {}.{}{} LIMIT {} OFFSET {}",
+            "SELECT {} FROM {}.{}{}{} LIMIT {} OFFSET {}",
+            preview_select_list(columns),
             quote_ident(schema),
             quote_ident(table),
+            preview_where_clause(filter),
             order_clause,
             limit,
             offset
@@ -215,6 +336,28 @@ impl PostgresAdapter {
         )
     }
 
+    /// Empty until the table has been analyzed. Partitioned parents only
+    /// carry inherited statistics, so those win when both exist.
+    pub(in crate::adapters::postgres) fn column_stats_query(schema: &str, table: &str) -> String {
+        format!(
+            r#"
+            SELECT json_agg(row_to_json(s))
+            FROM (
+                SELECT DISTINCT ON (st.attname)
+                    st.attname as "column",
+                    st.null_frac,
+                    st.n_distinct
+                FROM pg_stats st
+                WHERE st.schemaname = {}
+                  AND st.tablename = {}
+                ORDER BY st.attname, st.inherited DESC
+            ) s
+            "#,
+            quote_literal(schema),
+            quote_literal(table)
+        )
+    }
+
     pub(in crate::adapters::postgres) fn foreign_keys_query(schema: &str, table: &str) -> String {
         format!(
             r"
@@ -315,7 +458,10 @@ impl PostgresAdapter {
         )
     }
 
+    // Views and foreign tables have no storage of their own, so `storage`
+    // is only built for relations with a heap.
     pub(in crate::adapters::postgres) fn table_info_query(schema: &str, table: &str) -> String {
+        let timestamp = |column: &str| format!("to_char({column}, 'YYYY-MM-DD HH24:MI:SS')");
         format!(
             r"
             SELECT row_to_json(t)
@@ -323,11 +469,73 @@ impl PostgresAdapter {
                 SELECT
                     pg_get_userbyid(c.relowner) AS owner,
                     obj_description(c.oid) AS comment,
-                    c.reltuples::bigint AS row_count_estimate
+                    c.reltuples::bigint AS row_count_estimate,
+                    CASE WHEN c.relkind IN ('r', 'm', 'p') THEN json_build_object(
+                        'total_bytes', pg_total_relation_size(c.oid),
+                        'table_bytes', pg_relation_size(c.oid),
+                        'index_bytes', pg_indexes_size(c.oid),
+                        'toast_bytes', CASE WHEN c.reltoastrelid = 0 THEN 0
+                            ELSE pg_total_relation_size(c.reltoastrelid) END,
+                        'live_tuples', st.n_live_tup,
+                        'dead_tuples', st.n_dead_tup,
+                        'last_vacuum', {last_vacuum},
+                        'last_autovacuum', {last_autovacuum},
+                        'last_analyze', {last_analyze},
+                        'last_autoanalyze', {last_autoanalyze}
+                    ) END AS storage
                 FROM pg_class c
                 JOIN pg_namespace n ON n.oid = c.relnamespace
-                WHERE n.nspname = {}
-                  AND c.relname = {}
+                LEFT JOIN pg_stat_user_tables st ON st.relid = c.oid
+                WHERE n.nspname = {schema}
+                  AND c.relname = {table}
+            ) t
+            ",
+            last_vacuum = timestamp("st.last_vacuum"),
+            last_autovacuum = timestamp("st.last_autovacuum"),
+            last_analyze = timestamp("st.last_analyze"),
+            last_autoanalyze = timestamp("st.last_autoanalyze"),
+            schema = quote_literal(schema),
+            table = quote_literal(table)
+        )
+    }
+
+    pub(in crate::adapters::postgres) fn timescaledb_installed_query() -> &'static str {
+        "SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'timescaledb')"
+    }
+
+    // The timescaledb_information views only exist once the extension is
+    // installed, so this must stay out of the combined detail query.
+    pub(in crate::adapters::postgres) fn hypertable_query(schema: &str, table: &str) -> String {
+        format!(
+            r"
+            SELECT row_to_json(t)
+            FROM (
+                SELECT
+                    d.column_name AS time_column,
+                    h.num_chunks AS chunk_count,
+                    h.compression_enabled,
+                    (
+                        SELECT count(*)
+                        FROM timescaledb_information.chunks ch
+                        WHERE ch.hypertable_schema = h.hypertable_schema
+                          AND ch.hypertable_name = h.hypertable_name
+                          AND ch.is_compressed
+                    ) AS compressed_chunk_count,
+                    (
+                        SELECT j.config ->> 'drop_after'
+                        FROM timescaledb_information.jobs j
+                        WHERE j.hypertable_schema = h.hypertable_schema
+                          AND j.hypertable_name = h.hypertable_name
+                          AND j.proc_name = 'policy_retention'
+                        LIMIT 1
+                    ) AS retention
+                FROM timescaledb_information.hypertables h
+                LEFT JOIN timescaledb_information.dimensions d
+                  ON d.hypertable_schema = h.hypertable_schema
+                 AND d.hypertable_name = h.hypertable_name
+                 AND d.dimension_number = 1
+                WHERE h.hypertable_schema = {}
+                  AND h.hypertable_name = {}
             ) t
             ",
             quote_literal(schema),
@@ -335,6 +543,765 @@ impl PostgresAdapter {
         )
     }
 
+    /// Newest rows first: TimescaleDB's ordered append then only reads the
+    /// latest chunks instead of scanning every one.
+    pub(in crate::adapters::postgres) fn build_hypertable_preview_query(
+        schema: &str,
+        table: &str,
+        columns: &[String],
+        filter: Option<&str>,
+        time_column: &str,
+        order_columns: &[String],
+        limit: usize,
+        offset: usize,
+    ) -> String {
+        let mut order = vec![format!("{} DESC", quote_ident(time_column))];
+        order.extend(
+            order_columns
+                .iter()
+                .filter(|col| col.as_str() != time_column)
+                .map(|col| quote_ident(col)),
+        );
+
+        format!(
+            "SELECT {} FROM {}.{}{} ORDER BY {} LIMIT {} OFFSET {}",
+            preview_select_list(columns),
+            quote_ident(schema),
+            quote_ident(table),
+            preview_where_clause(filter),
+            order.join(", "),
+            limit,
+            offset
+        )
+    }
+
+    /// `TABLESAMPLE SYSTEM` only reads a fraction of the pages on large
+    /// tables; the trailing `ORDER BY random()` shuffles what it returns.
+    pub(in crate::adapters::postgres) fn sample_query(
+        schema: &str,
+        table: &str,
+        limit: usize,
+        row_estimate: Option<i64>,
+    ) -> String {
+        let sample = match row_estimate {
+            Some(rows) if rows >= TABLESAMPLE_MIN_ROWS => {
+                #[allow(
+                    clippy::cast_precision_loss,
+                    reason = "a sampling percentage tolerates rounding"
+                )]
+                let percent = (limit as f64 * TABLESAMPLE_OVERSAMPLE * 100.0 / rows as f64)
+                    .clamp(0.0001, 100.0);
+                format!(" TABLESAMPLE SYSTEM ({percent:.4})")
+            }
+            _ => String::new(),
+        };
+
+        format!(
+            "SELECT * FROM {}.{}{} ORDER BY random() LIMIT {}",
+            quote_ident(schema),
+            quote_ident(table),
+            sample,
+            limit
+        )
+    }
+
+    pub(in crate::adapters::postgres) fn row_count_query(schema: &str, table: &str) -> String {
+        format!(
+            "SELECT count(*) AS row_count FROM {}.{}",
+            quote_ident(schema),
+            quote_ident(table)
+        )
+    }
+
+    /// Scripts run as one transaction, so `SET LOCAL` resets when it ends.
+    pub(in crate::adapters::postgres) fn rls_test_query(
+        schema: &str,
+        table: &str,
+        role: &str,
+    ) -> String {
+        format!(
+            "-- Rows visible to {role} through row-level security\n\
+             SET LOCAL ROLE {};\n\
+             SELECT * FROM {}.{} LIMIT 100;",
+            quote_ident(role),
+            quote_ident(schema),
+            quote_ident(table)
+        )
+    }
+
+    /// Publications, subscriptions and logical slots in one result grid.
+    /// A table filter keeps slot rows so lag stays visible next to the answer.
+    pub(in crate::adapters::postgres) fn replication_overview_query(table: Option<&str>) -> String {
+        let filter = table.map_or_else(String::new, |table| {
+            let table = quote_literal(table);
+            format!(
+                "WHERE r.kind = 'slot' OR r.\"table\" = {table} OR split_part(r.\"table\", '.', 2) = {table}\n"
+            )
+        });
+        format!(
+            r#"
+            SELECT r.kind, r.name, r."table", r.status, r.detail
+            FROM (
+                SELECT
+                    'publication' AS kind,
+                    p.pubname::text AS name,
+                    pt.schemaname || '.' || pt.tablename AS "table",
+                    CASE WHEN p.puballtables THEN 'all tables' ELSE 'listed tables' END AS status,
+                    concat_ws(', ',
+                        CASE WHEN p.pubinsert THEN 'insert' END,
+                        CASE WHEN p.pubupdate THEN 'update' END,
+                        CASE WHEN p.pubdelete THEN 'delete' END
+                    ) AS detail
+                FROM pg_publication p
+                LEFT JOIN pg_publication_tables pt ON pt.pubname = p.pubname
+                UNION ALL
+                SELECT
+                    'subscription',
+                    s.subname::text,
+                    n.nspname || '.' || c.relname,
+                    CASE sr.srsubstate
+                        WHEN 'i' THEN 'initializing'
+                        WHEN 'd' THEN 'copying data'
+                        WHEN 'f' THEN 'finished copy'
+                        WHEN 's' THEN 'synchronized'
+                        WHEN 'r' THEN 'ready'
+                        ELSE CASE WHEN s.subenabled THEN 'enabled' ELSE 'disabled' END
+                    END,
+                    'from ' || array_to_string(s.subpublications, ', ')
+                FROM pg_subscription s
+                LEFT JOIN pg_subscription_rel sr ON sr.srsubid = s.oid
+                LEFT JOIN pg_class c ON c.oid = sr.srrelid
+                LEFT JOIN pg_namespace n ON n.oid = c.relnamespace
+                WHERE s.subdbid = (SELECT oid FROM pg_database WHERE datname = current_database())
+                UNION ALL
+                SELECT
+                    'slot',
+                    rs.slot_name::text,
+                    NULL,
+                    CASE WHEN rs.active THEN 'active' ELSE 'inactive' END,
+                    'lag ' || pg_size_pretty(pg_wal_lsn_diff(
+                        CASE WHEN pg_is_in_recovery() THEN pg_last_wal_replay_lsn()
+                             ELSE pg_current_wal_lsn() END,
+                        rs.confirmed_flush_lsn
+                    ))
+                FROM pg_replication_slots rs
+                WHERE rs.slot_type = 'logical' AND rs.database = current_database()
+            ) r
+            {filter}ORDER BY r.kind, r.name, r."table"
+            "#
+        )
+    }
+
+    pub(in crate::adapters::postgres) fn maintenance_query(
+        kind: MaintenanceKind,
+        schema: &str,
+        table: &str,
+    ) -> String {
+        let target = format!("{}.{}", quote_ident(schema), quote_ident(table));
+        match kind {
+            MaintenanceKind::VacuumAnalyze => format!("VACUUM (ANALYZE) {target}"),
+            MaintenanceKind::Analyze => format!("ANALYZE {target}"),
+        }
+    }
+
+    /// One `phase, done, total` row while the command runs, none otherwise.
+    /// VACUUM (ANALYZE) only reports its vacuum part: pg_stat_progress_analyze
+    /// needs PostgreSQL 13, and the vacuum pass is the long one.
+    pub(in crate::adapters::postgres) fn maintenance_progress_query(
+        kind: MaintenanceKind,
+        schema: &str,
+        table: &str,
+    ) -> String {
+        let relid = format!(
+            "to_regclass({})",
+            quote_literal(&format!("{}.{}", quote_ident(schema), quote_ident(table)))
+        );
+        let (view, done, total) = match kind {
+            MaintenanceKind::VacuumAnalyze => (
+                "pg_stat_progress_vacuum",
+                "heap_blks_scanned",
+                "heap_blks_total",
+            ),
+            MaintenanceKind::Analyze => (
+                "pg_stat_progress_analyze",
+                "sample_blks_scanned",
+                "sample_blks_total",
+            ),
+        };
+        format!(
+            "SELECT phase, {done} AS done, {total} AS total FROM {view} \
+             WHERE datname = current_database() AND relid = {relid}"
+        )
+    }
+
+    /// Statements to run one at a time: CONCURRENTLY refuses to run inside a
+    /// transaction block. `None` when the index cannot be rebuilt by swapping.
+    pub(in crate::adapters::postgres) fn index_maintenance_queries(
+        kind: IndexMaintenanceKind,
+        schema: &str,
+        index: &Index,
+    ) -> Option<Vec<String>> {
+        let target = format!("{}.{}", quote_ident(schema), quote_ident(&index.name));
+        match kind {
+            IndexMaintenanceKind::ReindexConcurrently => {
+                Some(vec![format!("REINDEX INDEX CONCURRENTLY {target}")])
+            }
+            IndexMaintenanceKind::Reindex => Some(vec![format!("REINDEX INDEX {target}")]),
+            IndexMaintenanceKind::Rebuild => {
+                // Constraint-backed indexes cannot be dropped on their own.
+                if index.is_primary() {
+                    return None;
+                }
+                let swap_name = format!("{}_rebuild", index.name);
+                let create = concurrent_index_copy(index.definition.as_deref()?, &swap_name)?;
+                Some(vec![
+                    create,
+                    format!("DROP INDEX CONCURRENTLY {target}"),
+                    format!(
+                        "ALTER INDEX {}.{} RENAME TO {}",
+                        quote_ident(schema),
+                        quote_ident(&swap_name),
+                        quote_ident(&index.name)
+                    ),
+                ])
+            }
+        }
+    }
+
+    /// Same `phase, done, total` shape as the table maintenance progress query.
+    pub(in crate::adapters::postgres) fn index_maintenance_progress_query(
+        schema: &str,
+        table: &str,
+    ) -> String {
+        let relid = format!(
+            "to_regclass({})",
+            quote_literal(&format!("{}.{}", quote_ident(schema), quote_ident(table)))
+        );
+        format!(
+            "SELECT phase, blocks_done AS done, blocks_total AS total \
+             FROM pg_stat_progress_create_index \
+             WHERE datname = current_database() AND relid = {relid}"
+        )
+    }
+
+    /// `item, value, source, suggestion` rows: dead tuple ratio, the effective
+    /// autovacuum settings (`table` when overridden via reloptions), last runs,
+    /// and an ALTER TABLE hint where a scale factor is too coarse for the size
+    /// or autovacuum is falling behind.
+    pub(in crate::adapters::postgres) fn autovacuum_report_query(
+        schema: &str,
+        table: &str,
+    ) -> String {
+        let target = format!("{}.{}", quote_ident(schema), quote_ident(table));
+        let relid = format!("to_regclass({})", quote_literal(&target));
+        let alter = quote_literal(&format!("ALTER TABLE {target} "));
+        let reloption = |name: &str| {
+            format!(
+                "(SELECT split_part(o, '=', 2) FROM unnest(c.reloptions) o WHERE o LIKE '{name}=%')"
+            )
+        };
+        let source =
+            |column: &str| format!("CASE WHEN {column} IS NULL THEN 'global' ELSE 'table' END");
+        let last_run =
+            |column: &str| format!("coalesce(to_char({column}, 'YYYY-MM-DD HH24:MI:SS'), 'never')");
+        format!(
+            r"
+        WITH t AS (
+            SELECT
+                coalesce(st.n_live_tup, 0) AS live,
+                coalesce(st.n_dead_tup, 0) AS dead,
+                coalesce(st.n_tup_upd + st.n_tup_del, 0) AS churn,
+                st.last_autovacuum, st.last_vacuum, st.last_autoanalyze, st.last_analyze,
+                {enabled_opt} AS enabled_opt,
+                {vac_sf_opt} AS vac_sf_opt,
+                {vac_th_opt} AS vac_th_opt,
+                {an_sf_opt} AS an_sf_opt,
+                {an_th_opt} AS an_th_opt
+            FROM pg_class c
+            LEFT JOIN pg_stat_user_tables st ON st.relid = c.oid
+            WHERE c.oid = {relid}
+        ),
+        e AS (
+            SELECT t.*,
+                coalesce(enabled_opt, current_setting('autovacuum'))::bool AS enabled,
+                coalesce(vac_sf_opt, current_setting('autovacuum_vacuum_scale_factor'))::float8 AS vac_sf,
+                coalesce(vac_th_opt, current_setting('autovacuum_vacuum_threshold'))::float8 AS vac_th,
+                coalesce(an_sf_opt, current_setting('autovacuum_analyze_scale_factor'))::float8 AS an_sf,
+                coalesce(an_th_opt, current_setting('autovacuum_analyze_threshold'))::float8 AS an_th
+            FROM t
+        )
+        SELECT r.item, r.value, r.source, r.suggestion
+        FROM e CROSS JOIN LATERAL (VALUES
+            (1, 'dead tuples',
+                format('%s dead / %s live (%s%%)', dead, live,
+                    coalesce(round(100.0 * dead / nullif(live + dead, 0), 1), 0)),
+                'stats', NULL),
+            (2, 'updates + deletes', churn::text, 'stats', NULL),
+            (3, 'vacuum triggers at', format('%s dead rows', round(vac_th + vac_sf * live)),
+                'derived', NULL),
+            (4, 'autovacuum_enabled', enabled::text, {enabled_src},
+                CASE WHEN NOT enabled AND dead > vac_th + vac_sf * live
+                    THEN {alter} || 'RESET (autovacuum_enabled)' END),
+            (5, 'autovacuum_vacuum_scale_factor', vac_sf::text, {vac_sf_src},
+                CASE
+                    WHEN live >= 1000000 AND vac_sf > 0.05
+                        THEN {alter} || 'SET (autovacuum_vacuum_scale_factor = 0.01)'
+                    WHEN dead > 2 * (vac_th + vac_sf * live) AND vac_sf > 0.01
+                        THEN {alter} || format('SET (autovacuum_vacuum_scale_factor = %s)',
+                            round((vac_sf / 2)::numeric, 3))
+                END),
+            (6, 'autovacuum_vacuum_threshold', vac_th::text, {vac_th_src}, NULL),
+            (7, 'autovacuum_analyze_scale_factor', an_sf::text, {an_sf_src},
+                CASE WHEN live >= 1000000 AND an_sf > 0.05
+                    THEN {alter} || 'SET (autovacuum_analyze_scale_factor = 0.02)' END),
+            (8, 'autovacuum_analyze_threshold', an_th::text, {an_th_src}, NULL),
+            (9, 'last autovacuum', {last_autovacuum}, 'stats', NULL),
+            (10, 'last vacuum', {last_vacuum}, 'stats', NULL),
+            (11, 'last autoanalyze', {last_autoanalyze}, 'stats', NULL),
+            (12, 'last analyze', {last_analyze}, 'stats', NULL)
+        ) AS r(ord, item, value, source, suggestion)
+        ORDER BY r.ord
+        ",
+            enabled_opt = reloption("autovacuum_enabled"),
+            vac_sf_opt = reloption("autovacuum_vacuum_scale_factor"),
+            vac_th_opt = reloption("autovacuum_vacuum_threshold"),
+            an_sf_opt = reloption("autovacuum_analyze_scale_factor"),
+            an_th_opt = reloption("autovacuum_analyze_threshold"),
+            enabled_src = source("enabled_opt"),
+            vac_sf_src = source("vac_sf_opt"),
+            vac_th_src = source("vac_th_opt"),
+            an_sf_src = source("an_sf_opt"),
+            an_th_src = source("an_th_opt"),
+            last_autovacuum = last_run("last_autovacuum"),
+            last_vacuum = last_run("last_vacuum"),
+            last_autoanalyze = last_run("last_autoanalyze"),
+            last_analyze = last_run("last_analyze"),
+        )
+    }
+
+    /// Tables dropped since the metadata load resolve to NULL and are skipped.
+    pub(in crate::adapters::postgres) fn table_sizes_query(tables: &[(String, String)]) -> String {
+        let targets = tables
+            .iter()
+            .map(|(schema, table)| {
+                let target = format!("{}.{}", quote_ident(schema), quote_ident(table));
+                format!(
+                    "({}, to_regclass({}))",
+                    quote_literal(&format!("{schema}.{table}")),
+                    quote_literal(&target)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "SELECT t.table_name, \
+             pg_size_pretty(pg_total_relation_size(t.oid)) AS total_size, \
+             pg_size_pretty(pg_relation_size(t.oid)) AS table_size, \
+             pg_size_pretty(pg_indexes_size(t.oid)) AS index_size \
+             FROM (VALUES {targets}) AS t(table_name, oid) \
+             WHERE t.oid IS NOT NULL \
+             ORDER BY pg_total_relation_size(t.oid) DESC"
+        )
+    }
+
+    pub(in crate::adapters::postgres) fn server_settings_query() -> &'static str {
+        "SELECT name, setting, coalesce(unit, '') AS unit, \
+         coalesce(reset_val, '') AS reset_val, coalesce(boot_val, '') AS boot_val, \
+         source, category, short_desc, pending_restart \
+         FROM pg_settings ORDER BY name"
+    }
+
+    pub(in crate::adapters::postgres) fn index_usage_query() -> &'static str {
+        "SELECT s.schemaname AS schema, s.relname AS table_name, \
+         s.indexrelname AS index_name, s.idx_scan AS scans, \
+         pg_relation_size(s.indexrelid) AS size_bytes, i.indisunique AS is_unique \
+         FROM pg_stat_user_indexes s \
+         JOIN pg_index i ON i.indexrelid = s.indexrelid \
+         ORDER BY s.schemaname, s.relname, s.indexrelname"
+    }
+
+    /// Deadlock reports name the blockers; for lock timeouts the best
+    /// evidence left is who still holds locks (on the named relation, if any)
+    /// inside an open transaction.
+    pub(in crate::adapters::postgres) fn lock_blockers_query(conflict: &LockConflict) -> String {
+        let filter = if conflict.blocker_pids.is_empty() {
+            let relation = conflict
+                .relation
+                .as_deref()
+                .map_or_else(String::new, |name| {
+                    format!(
+                        " AND l.locktype = 'relation' AND l.relation = to_regclass({})",
+                        quote_literal(name)
+                    )
+                });
+            format!(
+                "a.backend_type = 'client backend' AND a.xact_start IS NOT NULL \
+                 AND EXISTS (SELECT 1 FROM pg_locks l WHERE l.pid = a.pid AND l.granted{relation})"
+            )
+        } else {
+            let pids = conflict
+                .blocker_pids
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("a.pid IN ({pids})")
+        };
+        format!(
+            "SELECT a.pid, coalesce(a.application_name, '') AS application_name, \
+             coalesce(a.usename::text, '') AS usename, coalesce(a.state, '') AS state, \
+             floor(extract(epoch FROM now() - coalesce(a.xact_start, a.query_start)))::bigint \
+             AS held_secs, coalesce(a.query, '') AS query \
+             FROM pg_stat_activity a \
+             WHERE a.pid <> pg_backend_pid() AND {filter} \
+             ORDER BY held_secs DESC NULLS LAST LIMIT 5"
+        )
+    }
+
+    /// Prepares (never executes) the statement and reads the planner's
+    /// result types back; needs PostgreSQL 14+ for `result_types`. Scripts
+    /// have no single result shape, so only lone statements are described.
+    pub(in crate::adapters::postgres) fn result_types_query(query: &str) -> Option<String> {
+        let [statement] = split_sql_statements(query)[..] else {
+            return None;
+        };
+        // Each statement ends on its own line so a trailing `--` comment in
+        // the user's SQL cannot swallow the separator.
+        Some(format!(
+            "PREPARE {RESULT_TYPES_STATEMENT} AS {statement}\n;\n\
+             SELECT t.data_type::text AS data_type \
+             FROM pg_prepared_statements p, \
+             unnest(p.result_types) WITH ORDINALITY AS t(data_type, position) \
+             WHERE p.name = '{RESULT_TYPES_STATEMENT}' ORDER BY t.position\n;\n\
+             DEALLOCATE {RESULT_TYPES_STATEMENT}"
+        ))
+    }
+
+    /// Yields `stat, value, frequency` rows. `pg_stats` answers the null
+    /// fraction, distinct estimate and most common values when ANALYZE has
+    /// run; a bounded sample fills in the rest. Only types with a natural
+    /// order get min/max, and only numbers and intervals get an average.
+    pub(in crate::adapters::postgres) fn column_distribution_query(
+        schema: &str,
+        table: &str,
+        column: &str,
+        data_type: &str,
+        row_estimate: Option<i64>,
+    ) -> String {
+        let base_type = data_type
+            .split('(')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        let averaged = AVERAGED_TYPES.contains(&base_type.as_str());
+        let ordered = averaged
+            || ORDERED_TYPE_PREFIXES
+                .iter()
+                .any(|prefix| base_type.starts_with(prefix));
+        let sample = match row_estimate {
+            Some(rows) if rows >= TABLESAMPLE_MIN_ROWS => {
+                #[allow(
+                    clippy::cast_precision_loss,
+                    reason = "a sampling percentage tolerates rounding"
+                )]
+                let percent = (DISTRIBUTION_SAMPLE_ROWS as f64 * TABLESAMPLE_OVERSAMPLE * 100.0
+                    / rows as f64)
+                    .clamp(0.0001, 100.0);
+                format!(" TABLESAMPLE SYSTEM ({percent:.4})")
+            }
+            _ => String::new(),
+        };
+
+        let mut figures = vec![
+            "SELECT 'sampled' AS stat, count(*)::text AS value, NULL::float8 AS frequency \
+             FROM sample"
+                .to_string(),
+        ];
+        if ordered {
+            figures.push("SELECT 'min', min(v)::text, NULL FROM sample".to_string());
+            figures.push("SELECT 'max', max(v)::text, NULL FROM sample".to_string());
+        }
+        if averaged {
+            figures.push("SELECT 'avg', avg(v)::text, NULL FROM sample".to_string());
+        }
+        figures.push(
+            "SELECT 'null_frac', coalesce((SELECT null_frac::numeric FROM st), \
+             (SELECT avg((v IS NULL)::int) FROM sample))::text, NULL"
+                .to_string(),
+        );
+        figures.push(
+            "SELECT 'n_distinct', coalesce((SELECT n_distinct::numeric FROM st), \
+             (SELECT count(DISTINCT v::text) FROM sample))::text, NULL"
+                .to_string(),
+        );
+        figures.push(format!(
+            "(SELECT 'common', m.val, m.freq::float8 \
+             FROM st, unnest(st.vals, st.freqs) AS m(val, freq) \
+             ORDER BY m.freq DESC LIMIT {DISTRIBUTION_COMMON_VALUES})"
+        ));
+        figures.push(format!(
+            "(SELECT 'common', v::text, count(*)::float8 / (SELECT count(*) FROM sample) \
+             FROM sample \
+             WHERE v IS NOT NULL AND NOT EXISTS (SELECT 1 FROM st WHERE st.vals IS NOT NULL) \
+             GROUP BY v::text ORDER BY count(*) DESC LIMIT {DISTRIBUTION_COMMON_VALUES})"
+        ));
+
+        format!(
+            "WITH sample AS (\n\
+             SELECT {column} AS v FROM {schema_ident}.{table_ident}{sample} LIMIT {DISTRIBUTION_SAMPLE_ROWS}\n\
+             ), st AS (\n\
+             SELECT null_frac, n_distinct, most_common_vals::text::text[] AS vals, \
+             most_common_freqs AS freqs FROM pg_stats \
+             WHERE schemaname = {schema_literal} AND tablename = {table_literal} \
+             AND attname = {column_literal} ORDER BY inherited DESC LIMIT 1\n\
+             )\n\
+             {figures}",
+            column = quote_ident(column),
+            schema_ident = quote_ident(schema),
+            table_ident = quote_ident(table),
+            schema_literal = quote_literal(schema),
+            table_literal = quote_literal(table),
+            column_literal = quote_literal(column),
+            figures = figures.join("\nUNION ALL "),
+        )
+    }
+
+    /// MATCH SIMPLE semantics: rows with any NULL referencing column are
+    /// never orphans.
+    pub(in crate::adapters::postgres) fn fk_orphan_check_query(
+        foreign_keys: &[ForeignKey],
+    ) -> String {
+        foreign_keys
+            .iter()
+            .map(|fk| {
+                let not_null = fk
+                    .from_columns
+                    .iter()
+                    .map(|column| format!("c.{} IS NOT NULL", quote_ident(column)))
+                    .collect::<Vec<_>>()
+                    .join(" AND ");
+                let matches = fk
+                    .from_columns
+                    .iter()
+                    .zip(&fk.to_columns)
+                    .map(|(from, to)| format!("p.{} = c.{}", quote_ident(to), quote_ident(from)))
+                    .collect::<Vec<_>>()
+                    .join(" AND ");
+                let references = format!("{}({})", fk.referenced_table(), fk.to_columns.join(", "));
+                let child = format!(
+                    "{}.{}",
+                    quote_ident(&fk.from_schema),
+                    quote_ident(&fk.from_table)
+                );
+                let parent = format!(
+                    "{}.{}",
+                    quote_ident(&fk.to_schema),
+                    quote_ident(&fk.to_table)
+                );
+                format!(
+                    "SELECT {name} AS constraint_name, {references} AS \"references\", \
+                     count(*) AS orphan_rows, \
+                     CASE WHEN count(*) = 0 THEN 'ok' \
+                     ELSE 'would block ADD/VALIDATE CONSTRAINT' END AS status \
+                     FROM {child} c \
+                     WHERE {not_null} AND NOT EXISTS (SELECT 1 FROM {parent} p WHERE {matches})",
+                    name = quote_literal(&fk.name),
+                    references = quote_literal(&references),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\nUNION ALL\n")
+    }
+
+    /// `*_id` columns whose prefix names a table (plain or plural) with a
+    /// single-column primary key of the same type, and that no FK covers yet.
+    /// Each candidate is checked against its first `FK_SUGGESTION_SAMPLE_ROWS`
+    /// non-null values; `query_to_xml` runs the per-pair anti-join without
+    /// leaving the read-only statement. Orphans in the sample get `NOT VALID`.
+    pub(in crate::adapters::postgres) fn fk_suggestion_query() -> String {
+        format!(
+            r#"
+        WITH pk AS (
+            SELECT c.oid AS relid, n.nspname AS schema_name, c.relname AS table_name,
+                a.attname AS column_name, a.atttypid AS type_oid
+            FROM pg_constraint con
+            JOIN pg_class c ON c.oid = con.conrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            JOIN pg_attribute a ON a.attrelid = con.conrelid AND a.attnum = con.conkey[1]
+            WHERE con.contype = 'p' AND cardinality(con.conkey) = 1
+              AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+              AND n.nspname NOT LIKE 'pg\_toast%'
+        ),
+        candidate AS (
+            SELECT DISTINCT ON (n.nspname, c.relname, a.attname)
+                n.nspname AS schema_name, c.relname AS table_name, a.attname AS column_name,
+                pk.schema_name AS ref_schema, pk.table_name AS ref_table,
+                pk.column_name AS ref_column
+            FROM pg_attribute a
+            JOIN pg_class c ON c.oid = a.attrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            CROSS JOIN LATERAL (SELECT lower(left(a.attname, -3)) AS prefix) p
+            JOIN pk ON pk.type_oid = a.atttypid
+                AND lower(pk.table_name) IN (
+                    p.prefix, p.prefix || 's', p.prefix || 'es',
+                    regexp_replace(p.prefix, 'y$', 'ies')
+                )
+                AND NOT (pk.relid = c.oid AND pk.column_name = a.attname)
+            WHERE c.relkind IN ('r', 'p') AND NOT c.relispartition
+              AND a.attnum > 0 AND NOT a.attisdropped
+              AND a.attname ILIKE '%\_id'
+              AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+              AND n.nspname NOT LIKE 'pg\_toast%'
+              AND NOT EXISTS (
+                  SELECT 1 FROM pg_constraint f
+                  WHERE f.conrelid = c.oid AND f.contype = 'f' AND a.attnum = ANY (f.conkey)
+              )
+            ORDER BY n.nspname, c.relname, a.attname, pk.schema_name = n.nspname DESC,
+                pk.schema_name
+        ),
+        checked AS (
+            SELECT candidate.*,
+                (xpath('/row/sampled/text()', x))[1]::text::bigint AS sampled_rows,
+                (xpath('/row/orphans/text()', x))[1]::text::bigint AS orphan_rows
+            FROM candidate
+            CROSS JOIN LATERAL query_to_xml(format(
+                'SELECT count(*) AS sampled, count(*) FILTER (WHERE NOT EXISTS '
+                '(SELECT 1 FROM %I.%I p WHERE p.%I = s.%I)) AS orphans '
+                'FROM (SELECT %I FROM %I.%I WHERE %I IS NOT NULL LIMIT {FK_SUGGESTION_SAMPLE_ROWS}) s',
+                ref_schema, ref_table, ref_column, column_name,
+                column_name, schema_name, table_name, column_name
+            ), false, true, '') AS x
+        )
+        SELECT
+            format('%I.%I', schema_name, table_name) AS table_name,
+            column_name,
+            format('%I.%I(%I)', ref_schema, ref_table, ref_column) AS "references",
+            sampled_rows,
+            orphan_rows,
+            format('ALTER TABLE %I.%I ADD CONSTRAINT %I FOREIGN KEY (%I) REFERENCES %I.%I (%I)%s;',
+                schema_name, table_name, table_name || '_' || column_name || '_fkey',
+                column_name, ref_schema, ref_table, ref_column,
+                CASE WHEN orphan_rows > 0 THEN ' NOT VALID' ELSE '' END) AS statement
+        FROM checked
+        ORDER BY schema_name, table_name, column_name
+        "#
+        )
+    }
+
+    /// Reserved words are errors (every query must quote them), non
+    /// snake_case names warnings, and PK/FK/index names that drift from the
+    /// configured templates info. Indexes backing a constraint are judged by
+    /// the constraint's name instead.
+    pub(in crate::adapters::postgres) fn naming_audit_query(
+        conventions: &NamingConventions,
+    ) -> String {
+        let mut findings = Vec::new();
+        if conventions.reserved_words {
+            findings.push(
+                "SELECT 1, 'error', kind, schema_name, table_name, name, 'reserved word', \
+                 'rename; it must be quoted in every query' \
+                 FROM names WHERE kind IN ('table', 'column') \
+                 AND lower(name) IN (SELECT word FROM pg_get_keywords() WHERE catcode = 'R')"
+                    .to_string(),
+            );
+        }
+        if conventions.snake_case {
+            findings.push(
+                "SELECT 2, 'warning', kind, schema_name, table_name, name, 'snake_case', \
+                 trim(both '_' from lower(regexp_replace(\
+                 regexp_replace(name, '([a-z0-9])([A-Z])', '\\1_\\2', 'g'), \
+                 '[^A-Za-z0-9_]+', '_', 'g'))) \
+                 FROM names WHERE name !~ '^[a-z_][a-z0-9_]*$'"
+                    .to_string(),
+            );
+        }
+        let templates = [
+            (
+                "primary key",
+                "keys WHERE contype = 'p'",
+                &conventions.primary_key,
+            ),
+            (
+                "foreign key",
+                "keys WHERE contype = 'f'",
+                &conventions.foreign_key,
+            ),
+            ("index", "plain_indexes", &conventions.index),
+        ];
+        for (kind, source, template) in templates {
+            let Some(template) = template else {
+                continue;
+            };
+            findings.push(format!(
+                "SELECT 3, 'info', '{kind}', schema_name, table_name, name, {rule}, expected \
+                 FROM (SELECT *, left({expected}, 63) AS expected FROM {source}) t \
+                 WHERE name <> expected",
+                rule = quote_literal(&format!("{kind} name {template}")),
+                expected = name_template_sql(template),
+            ));
+        }
+
+        format!(
+            r"
+        WITH tables AS (
+            SELECT c.oid, n.nspname AS schema_name, c.relname AS table_name
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relkind IN ('r', 'p') AND NOT c.relispartition
+              AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+              AND n.nspname NOT LIKE 'pg\_toast%'
+        ),
+        names AS (
+            SELECT 'table' AS kind, schema_name, table_name, table_name AS name FROM tables
+            UNION ALL
+            SELECT 'column', t.schema_name, t.table_name, a.attname
+            FROM tables t
+            JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum > 0 AND NOT a.attisdropped
+            UNION ALL
+            SELECT 'index', t.schema_name, t.table_name, i.relname
+            FROM tables t
+            JOIN pg_index x ON x.indrelid = t.oid
+            JOIN pg_class i ON i.oid = x.indexrelid
+            UNION ALL
+            SELECT 'constraint', t.schema_name, t.table_name, con.conname
+            FROM tables t
+            JOIN pg_constraint con ON con.conrelid = t.oid
+            WHERE NOT EXISTS (SELECT 1 FROM pg_class i WHERE i.oid = con.conindid AND i.relname = con.conname)
+        ),
+        keys AS (
+            SELECT t.schema_name, t.table_name, con.contype, con.conname AS name,
+                (SELECT string_agg(a.attname, '_' ORDER BY k.ord)
+                 FROM unnest(con.conkey) WITH ORDINALITY AS k(attnum, ord)
+                 JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum) AS columns
+            FROM tables t
+            JOIN pg_constraint con ON con.conrelid = t.oid
+            WHERE con.contype IN ('p', 'f')
+        ),
+        plain_indexes AS (
+            SELECT t.schema_name, t.table_name, i.relname AS name,
+                (SELECT string_agg(a.attname, '_' ORDER BY k.ord)
+                 FROM unnest(x.indkey::int2[]) WITH ORDINALITY AS k(attnum, ord)
+                 JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+                 WHERE k.ord <= x.indnkeyatts) AS columns
+            FROM tables t
+            JOIN pg_index x ON x.indrelid = t.oid
+            JOIN pg_class i ON i.oid = x.indexrelid
+            WHERE 0 <> ALL (x.indkey::int2[])
+              AND NOT EXISTS (SELECT 1 FROM pg_constraint con WHERE con.conindid = x.indexrelid)
+        ),
+        findings (rank, severity, kind, schema_name, table_name, name, rule, suggestion) AS (
+            {findings}
+        )
+        SELECT severity, kind, format('%I.%I', schema_name, table_name) AS table_name, name,
+            rule, suggestion
+        FROM findings
+        ORDER BY rank, schema_name, table_name, kind, name
+        ",
+            findings = findings.join("\n            UNION ALL\n            "),
+        )
+    }
+
     pub(in crate::adapters::postgres) fn table_columns_and_fks_query(
         schema: &str,
         table: &str,
@@ -343,11 +1310,15 @@ impl PostgresAdapter {
             r"
             SELECT json_build_object(
                 'columns', ({columns}),
-                'foreign_keys', ({fks})
+                'foreign_keys', ({fks}),
+                'indexes', ({indexes}),
+                'column_stats', ({stats})
             )
             ",
             columns = Self::columns_query(schema, table).trim(),
             fks = Self::foreign_keys_query(schema, table).trim(),
+            indexes = Self::indexes_query(schema, table).trim(),
+            stats = Self::column_stats_query(schema, table).trim(),
         )
     }
 
@@ -373,9 +1344,424 @@ impl PostgresAdapter {
     }
 }
 
+// `*`, or the preview's chosen columns.
+fn preview_select_list(columns: &[String]) -> String {
+    if columns.is_empty() {
+        return "*".to_string();
+    }
+    columns
+        .iter()
+        .map(|column| quote_ident(column))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Turns a naming template into a `concat()` over the audit CTE's
+// `table_name` and `columns`.
+fn name_template_sql(template: &str) -> String {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut rest = template;
+    while let Some(ch) = rest.chars().next() {
+        let placeholder = [
+            (NAME_TEMPLATE_TABLE, "table_name"),
+            (NAME_TEMPLATE_COLUMNS, "columns"),
+        ]
+        .into_iter()
+        .find(|(placeholder, _)| rest.starts_with(placeholder));
+        if let Some((placeholder, column)) = placeholder {
+            if !literal.is_empty() {
+                parts.push(quote_literal(&std::mem::take(&mut literal)));
+            }
+            parts.push(column.to_string());
+            rest = &rest[placeholder.len()..];
+        } else {
+            literal.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+    }
+    if !literal.is_empty() {
+        parts.push(quote_literal(&literal));
+    }
+    format!("concat({})", parts.join(", "))
+}
+
+// Rewrites a pg_get_indexdef() definition into a concurrent build under a new
+// name. Partitioned parents (ON ONLY) cannot be built concurrently.
+fn concurrent_index_copy(definition: &str, new_name: &str) -> Option<String> {
+    let (prefix, rest) = ["CREATE UNIQUE INDEX ", "CREATE INDEX "]
+        .into_iter()
+        .find_map(|prefix| Some((prefix, definition.strip_prefix(prefix)?)))?;
+    let (_, on_clause) = rest.split_once(" ON ")?;
+    if on_clause.starts_with("ONLY ") {
+        return None;
+    }
+    Some(format!(
+        "{prefix}CONCURRENTLY {} ON {on_clause}",
+        quote_ident(new_name)
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::adapters::postgres::PostgresAdapter;
+    use crate::app::ports::outbound::NamingConventions;
+    use crate::domain::{
+        FkAction, ForeignKey, Index, IndexAttributes, IndexMaintenanceKind, IndexType,
+        MaintenanceKind,
+    };
+
+    #[test]
+    fn replication_overview_lists_publications_subscriptions_and_slots() {
+        let sql = PostgresAdapter::replication_overview_query(None);
+
+        assert!(sql.contains("FROM pg_publication p"));
+        assert!(sql.contains("FROM pg_subscription s"));
+        assert!(sql.contains("pg_wal_lsn_diff"));
+        assert!(!sql.contains("WHERE r.kind"));
+    }
+
+    #[test]
+    fn replication_overview_filters_by_table_but_keeps_slots() {
+        let sql = PostgresAdapter::replication_overview_query(Some("public.o'rders"));
+
+        assert!(sql.contains(
+            "WHERE r.kind = 'slot' OR r.\"table\" = 'public.o''rders' OR split_part(r.\"table\", '.', 2) = 'public.o''rders'"
+        ));
+    }
+
+    #[test]
+    fn maintenance_query_quotes_target() {
+        assert_eq!(
+            PostgresAdapter::maintenance_query(MaintenanceKind::VacuumAnalyze, "public", "Orders"),
+            "VACUUM (ANALYZE) \"public\".\"Orders\""
+        );
+        assert_eq!(
+            PostgresAdapter::maintenance_query(MaintenanceKind::Analyze, "public", "orders"),
+            "ANALYZE \"public\".\"orders\""
+        );
+    }
+
+    #[test]
+    fn maintenance_progress_query_reads_the_matching_view() {
+        let vacuum = PostgresAdapter::maintenance_progress_query(
+            MaintenanceKind::VacuumAnalyze,
+            "public",
+            "o'rders",
+        );
+        let analyze =
+            PostgresAdapter::maintenance_progress_query(MaintenanceKind::Analyze, "public", "t");
+
+        assert!(vacuum.contains("FROM pg_stat_progress_vacuum"));
+        assert!(vacuum.contains("relid = to_regclass('\"public\".\"o''rders\"')"));
+        assert!(analyze.contains("sample_blks_scanned AS done"));
+        assert!(analyze.contains("FROM pg_stat_progress_analyze"));
+    }
+
+    fn index(name: &str, attributes: IndexAttributes, definition: Option<&str>) -> Index {
+        Index {
+            name: name.to_string(),
+            columns: vec!["email".to_string()],
+            attributes,
+            index_type: IndexType::BTree,
+            definition: definition.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn reindex_targets_the_quoted_index() {
+        let idx = index("users_Email_idx", IndexAttributes::empty(), None);
+
+        assert_eq!(
+            PostgresAdapter::index_maintenance_queries(
+                IndexMaintenanceKind::ReindexConcurrently,
+                "public",
+                &idx
+            ),
+            Some(vec![
+                "REINDEX INDEX CONCURRENTLY \"public\".\"users_Email_idx\"".to_string()
+            ])
+        );
+        assert_eq!(
+            PostgresAdapter::index_maintenance_queries(
+                IndexMaintenanceKind::Reindex,
+                "public",
+                &idx
+            ),
+            Some(vec![
+                "REINDEX INDEX \"public\".\"users_Email_idx\"".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn rebuild_builds_a_copy_then_swaps_names() {
+        let idx = index(
+            "users_email_idx",
+            IndexAttributes::UNIQUE,
+            Some("CREATE UNIQUE INDEX users_email_idx ON public.users USING btree (email)"),
+        );
+
+        let statements = PostgresAdapter::index_maintenance_queries(
+            IndexMaintenanceKind::Rebuild,
+            "public",
+            &idx,
+        )
+        .unwrap();
+
+        assert_eq!(
+            statements,
+            vec![
+                "CREATE UNIQUE INDEX CONCURRENTLY \"users_email_idx_rebuild\" ON public.users USING btree (email)",
+                "DROP INDEX CONCURRENTLY \"public\".\"users_email_idx\"",
+                "ALTER INDEX \"public\".\"users_email_idx_rebuild\" RENAME TO \"users_email_idx\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn rebuild_is_unavailable_for_primary_keys_and_partitioned_parents() {
+        let pkey = index(
+            "users_pkey",
+            IndexAttributes::UNIQUE | IndexAttributes::PRIMARY,
+            Some("CREATE UNIQUE INDEX users_pkey ON public.users USING btree (id)"),
+        );
+        let parent = index(
+            "events_at_idx",
+            IndexAttributes::empty(),
+            Some("CREATE INDEX events_at_idx ON ONLY public.events USING btree (at)"),
+        );
+        let unknown = index("legacy_idx", IndexAttributes::empty(), None);
+
+        for idx in [pkey, parent, unknown] {
+            assert_eq!(
+                PostgresAdapter::index_maintenance_queries(
+                    IndexMaintenanceKind::Rebuild,
+                    "public",
+                    &idx
+                ),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn index_progress_reads_create_index_view_for_the_table() {
+        let sql = PostgresAdapter::index_maintenance_progress_query("public", "users");
+
+        assert!(sql.contains("blocks_done AS done"));
+        assert!(sql.contains("FROM pg_stat_progress_create_index"));
+        assert!(sql.contains("relid = to_regclass('\"public\".\"users\"')"));
+    }
+
+    #[test]
+    fn autovacuum_report_reads_stats_and_table_overrides() {
+        let sql = PostgresAdapter::autovacuum_report_query("public", "o'rders");
+
+        assert!(sql.contains("WHERE c.oid = to_regclass('\"public\".\"o''rders\"')"));
+        assert!(
+            sql.contains("unnest(c.reloptions) o WHERE o LIKE 'autovacuum_vacuum_scale_factor=%'")
+        );
+        assert!(sql.contains("current_setting('autovacuum_analyze_threshold')"));
+        assert!(sql.contains(
+            "'ALTER TABLE \"public\".\"o''rders\" ' || 'SET (autovacuum_vacuum_scale_factor = 0.01)'"
+        ));
+    }
+
+    #[test]
+    fn table_sizes_resolve_each_target_and_skip_dropped_tables() {
+        let sql = PostgresAdapter::table_sizes_query(&[
+            ("public".to_string(), "users".to_string()),
+            ("audit".to_string(), "o'rders".to_string()),
+        ]);
+
+        assert!(sql.contains("('public.users', to_regclass('\"public\".\"users\"'))"));
+        assert!(sql.contains("('audit.o''rders', to_regclass('\"audit\".\"o''rders\"'))"));
+        assert!(sql.contains("WHERE t.oid IS NOT NULL"));
+        assert!(sql.contains("ORDER BY pg_total_relation_size(t.oid) DESC"));
+    }
+
+    #[test]
+    fn fk_orphan_check_counts_children_without_parent() {
+        let fk = ForeignKey {
+            name: "orders_user_fk".to_string(),
+            from_schema: "public".to_string(),
+            from_table: "orders".to_string(),
+            from_columns: vec!["tenant_id".to_string(), "user_id".to_string()],
+            to_schema: "auth".to_string(),
+            to_table: "users".to_string(),
+            to_columns: vec!["tenant_id".to_string(), "id".to_string()],
+            on_delete: FkAction::Cascade,
+            on_update: FkAction::NoAction,
+            reference_resolved: true,
+        };
+        let other = ForeignKey {
+            name: "orders_product_fk".to_string(),
+            from_columns: vec!["product_id".to_string()],
+            to_table: "products".to_string(),
+            to_columns: vec!["id".to_string()],
+            ..fk.clone()
+        };
+
+        let sql = PostgresAdapter::fk_orphan_check_query(&[fk, other]);
+
+        assert!(sql.contains("SELECT 'orders_user_fk' AS constraint_name"));
+        assert!(sql.contains("'auth.users(tenant_id, id)' AS \"references\""));
+        assert!(sql.contains("FROM \"public\".\"orders\" c"));
+        assert!(sql.contains("c.\"tenant_id\" IS NOT NULL AND c.\"user_id\" IS NOT NULL"));
+        assert!(sql.contains(
+            "NOT EXISTS (SELECT 1 FROM \"auth\".\"users\" p WHERE p.\"tenant_id\" = c.\"tenant_id\" AND p.\"id\" = c.\"user_id\")"
+        ));
+        assert_eq!(sql.matches("UNION ALL").count(), 1);
+    }
+
+    #[test]
+    fn fk_suggestion_samples_candidates_without_constraints() {
+        let sql = PostgresAdapter::fk_suggestion_query();
+
+        assert!(sql.contains("a.attname ILIKE '%\\_id'"));
+        assert!(sql.contains("WHERE f.conrelid = c.oid AND f.contype = 'f'"));
+        assert!(sql.contains("WHERE %I IS NOT NULL LIMIT 1000) s"));
+        assert!(sql.contains("CASE WHEN orphan_rows > 0 THEN ' NOT VALID' ELSE '' END"));
+    }
+
+    #[test]
+    fn naming_audit_expands_templates_and_skips_disabled_rules() {
+        let conventions = NamingConventions {
+            reserved_words: false,
+            foreign_key: Some("fk_{table}_{columns}".to_string()),
+            index: None,
+            ..NamingConventions::default()
+        };
+
+        let sql = PostgresAdapter::naming_audit_query(&conventions);
+
+        assert!(!sql.contains("pg_get_keywords()"));
+        assert!(sql.contains("name !~ '^[a-z_][a-z0-9_]*$'"));
+        assert!(sql.contains("left(concat(table_name, '_pkey'), 63) AS expected"));
+        assert!(sql.contains("left(concat('fk_', table_name, '_', columns), 63) AS expected"));
+        assert!(!sql.contains("FROM plain_indexes) t"));
+        assert!(sql.contains("ORDER BY rank, schema_name, table_name, kind, name"));
+    }
+
+    #[test]
+    fn server_settings_query_reads_pg_settings_by_name() {
+        let sql = PostgresAdapter::server_settings_query();
+
+        assert!(sql.contains("reset_val"));
+        assert!(sql.contains("boot_val"));
+        assert!(sql.contains("pending_restart"));
+        assert!(sql.ends_with("FROM pg_settings ORDER BY name"));
+    }
+
+    #[test]
+    fn index_usage_query_reads_scans_size_and_uniqueness() {
+        let sql = PostgresAdapter::index_usage_query();
+
+        assert!(sql.contains("FROM pg_stat_user_indexes s"));
+        assert!(sql.contains("pg_relation_size(s.indexrelid) AS size_bytes"));
+        assert!(sql.contains("i.indisunique AS is_unique"));
+    }
+
+    mod column_distribution_query {
+        use super::*;
+
+        #[test]
+        fn numbers_get_min_max_and_average() {
+            let sql = PostgresAdapter::column_distribution_query(
+                "public",
+                "orders",
+                "total",
+                "numeric(10,2)",
+                Some(100),
+            );
+
+            assert!(sql.contains("SELECT \"total\" AS v FROM \"public\".\"orders\" LIMIT 10000"));
+            assert!(sql.contains("'min'"));
+            assert!(sql.contains("'avg'"));
+            assert!(sql.contains("attname = 'total'"));
+        }
+
+        #[test]
+        fn unordered_types_skip_min_max_and_large_tables_are_sampled() {
+            let sql = PostgresAdapter::column_distribution_query(
+                "public",
+                "orders",
+                "payload",
+                "jsonb",
+                Some(10_000_000),
+            );
+
+            assert!(sql.contains("TABLESAMPLE SYSTEM (0.4000)"));
+            assert!(!sql.contains("'min'"));
+            assert!(!sql.contains("'avg'"));
+            assert!(sql.contains("'common'"));
+        }
+    }
+
+    mod result_types_query {
+        use super::*;
+
+        #[test]
+        fn single_statement_is_prepared_then_deallocated() {
+            let sql = PostgresAdapter::result_types_query("SELECT 1 AS n -- note").unwrap();
+
+            assert!(sql.starts_with("PREPARE sabiql_result_types AS SELECT 1 AS n -- note\n;"));
+            assert!(sql.contains("unnest(p.result_types) WITH ORDINALITY"));
+            assert!(sql.ends_with("DEALLOCATE sabiql_result_types"));
+        }
+
+        #[test]
+        fn scripts_are_not_described() {
+            assert!(PostgresAdapter::result_types_query("SELECT 1; SELECT 2").is_none());
+        }
+    }
+
+    mod lock_blockers_query {
+        use super::*;
+        use crate::domain::{LockConflict, LockConflictKind};
+
+        fn conflict(pids: Vec<i32>, relation: Option<&str>) -> LockConflict {
+            LockConflict {
+                kind: LockConflictKind::LockTimeout,
+                blocker_pids: pids,
+                relation: relation.map(str::to_string),
+            }
+        }
+
+        #[test]
+        fn known_pids_are_looked_up_directly() {
+            let sql = PostgresAdapter::lock_blockers_query(&conflict(vec![41, 42], None));
+
+            assert!(sql.contains("a.pid IN (41, 42)"));
+            assert!(!sql.contains("pg_locks"));
+        }
+
+        #[test]
+        fn named_relation_narrows_lock_holders() {
+            let sql = PostgresAdapter::lock_blockers_query(&conflict(vec![], Some("o'rders")));
+
+            assert!(sql.contains("l.relation = to_regclass('o''rders')"));
+            assert!(sql.contains("a.xact_start IS NOT NULL"));
+        }
+
+        #[test]
+        fn unknown_relation_lists_all_lock_holders() {
+            let sql = PostgresAdapter::lock_blockers_query(&conflict(vec![], None));
+
+            assert!(sql.contains("l.pid = a.pid AND l.granted)"));
+            assert!(sql.ends_with("ORDER BY held_secs DESC NULLS LAST LIMIT 5"));
+        }
+    }
+
+    #[test]
+    fn schema_objects_query_skips_extension_members_and_owned_sequences() {
+        let sql = PostgresAdapter::schema_objects_query();
+
+        assert!(sql.contains("c.relkind IN ('v', 'm', 'S')"));
+        assert!(sql.contains("d.deptype IN ('e', 'a', 'i')"));
+        assert!(sql.contains("pg_get_function_identity_arguments(p.oid)"));
+    }
 
     #[test]
     fn effective_user_query_selects_current_user() {
@@ -387,12 +1773,15 @@ mod tests {
 
     mod preview_query {
         use super::*;
+        use rstest::rstest;
 
         #[test]
         fn with_primary_key_columns_returns_ordered_preview_query() {
             let sql = PostgresAdapter::build_preview_query(
                 "public",
                 "users",
+                &[],
+                None,
                 &["id".to_string(), "tenant_id".to_string()],
                 100,
                 200,
@@ -404,13 +1793,110 @@ mod tests {
             );
         }
 
+        #[test]
+        fn filter_lands_before_the_order_clause() {
+            let sql = PostgresAdapter::build_preview_query(
+                "public",
+                "users",
+                &[],
+                Some("status = 'active' OR id < 10"),
+                &["id".to_string()],
+                100,
+                0,
+            );
+
+            assert_eq!(
+                sql,
+                "SELECT * FROM \"public\".\"users\" WHERE (status = 'active' OR id < 10) ORDER BY \"id\" LIMIT 100 OFFSET 0"
+            );
+        }
+
+        #[test]
+        fn chosen_columns_replace_the_star() {
+            let sql = PostgresAdapter::build_preview_query(
+                "public",
+                "users",
+                &["id".to_string(), "display\"name".to_string()],
+                None,
+                &["id".to_string()],
+                100,
+                0,
+            );
+
+            assert_eq!(
+                sql,
+                "SELECT \"id\", \"display\"\"name\" FROM \"public\".\"users\" ORDER BY \"id\" LIMIT 100 OFFSET 0"
+            );
+        }
+
         #[test]
         fn without_primary_key_columns_returns_unordered_preview_query() {
-            let sql = PostgresAdapter::build_preview_query("public", "users", &[], 100, 0);
+            let sql =
+                PostgresAdapter::build_preview_query("public", "users", &[], None, &[], 100, 0);
 
             assert_eq!(sql, "SELECT * FROM \"public\".\"users\" LIMIT 100 OFFSET 0");
         }
 
+        #[test]
+        fn hypertable_preview_reads_newest_rows_first() {
+            let sql = PostgresAdapter::build_hypertable_preview_query(
+                "public",
+                "metrics",
+                &[],
+                None,
+                "time",
+                &["time".to_string(), "device_id".to_string()],
+                100,
+                0,
+            );
+
+            assert_eq!(
+                sql,
+                "SELECT * FROM \"public\".\"metrics\" ORDER BY \"time\" DESC, \"device_id\" LIMIT 100 OFFSET 0"
+            );
+        }
+
+        #[rstest]
+        #[case(None, "SELECT * FROM \"public\".\"users\" ORDER BY random() LIMIT 100")]
+        #[case(
+            Some(500),
+            "SELECT * FROM \"public\".\"users\" ORDER BY random() LIMIT 100"
+        )]
+        #[case(
+            Some(1_000_000),
+            "SELECT * FROM \"public\".\"users\" TABLESAMPLE SYSTEM (0.0400) ORDER BY random() LIMIT 100"
+        )]
+        #[case(
+            Some(10_000),
+            "SELECT * FROM \"public\".\"users\" TABLESAMPLE SYSTEM (4.0000) ORDER BY random() LIMIT 100"
+        )]
+        fn sample_query_uses_tablesample_only_for_large_tables(
+            #[case] row_estimate: Option<i64>,
+            #[case] expected: &str,
+        ) {
+            let sql = PostgresAdapter::sample_query("public", "users", 100, row_estimate);
+
+            assert_eq!(sql, expected);
+        }
+
+        #[test]
+        fn row_count_query_quotes_the_table() {
+            assert_eq!(
+                PostgresAdapter::row_count_query("public", "Order Items"),
+                "SELECT count(*) AS row_count FROM \"public\".\"Order Items\""
+            );
+        }
+
+        #[test]
+        fn rls_test_query_switches_role_for_the_transaction() {
+            assert_eq!(
+                PostgresAdapter::rls_test_query("public", "orders", "app user"),
+                "-- Rows visible to app user through row-level security\n\
+                 SET LOCAL ROLE \"app user\";\n\
+                 SELECT * FROM \"public\".\"orders\" LIMIT 100;"
+            );
+        }
+
         #[test]
         fn primary_key_query_returns_json_aggregate_sql() {
             let sql = PostgresAdapter::preview_pk_columns_query("public", "users");
@@ -448,6 +1934,7 @@ mod tests {
             for fragment in [
                 "c.relkind = 'r'",
                 "n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast')",
+                r"n.nspname NOT LIKE '\_timescaledb\_%'",
                 "has_table_privilege(c.oid, 'SELECT')",
                 "has_table_privilege(c.oid, 'INSERT')",
                 "has_table_privilege(c.oid, 'TRIGGER')",
@@ -477,7 +1964,8 @@ mod tests {
 
         #[test]
         fn schema_name_with_double_quote_is_escaped() {
-            let sql = PostgresAdapter::build_preview_query("my\"schema", "users", &[], 100, 0);
+            let sql =
+                PostgresAdapter::build_preview_query("my\"schema", "users", &[], None, &[], 100, 0);
 
             assert_eq!(
                 sql,
@@ -487,7 +1975,8 @@ mod tests {
 
         #[test]
         fn table_name_with_double_quote_is_escaped() {
-            let sql = PostgresAdapter::build_preview_query("public", "my\"table", &[], 100, 0);
+            let sql =
+                PostgresAdapter::build_preview_query("public", "my\"table", &[], None, &[], 100, 0);
 
             assert_eq!(
                 sql,
@@ -500,6 +1989,8 @@ mod tests {
             let sql = PostgresAdapter::build_preview_query(
                 "public",
                 "users",
+                &[],
+                None,
                 &["my\"col".to_string()],
                 100,
                 0,
@@ -539,19 +2030,36 @@ mod tests {
             assert!(sql.contains("'my_schema'"));
             assert!(sql.contains("'my_table'"));
         }
+
+        #[test]
+        fn table_info_gathers_sizes_and_vacuum_stats() {
+            let sql = PostgresAdapter::table_detail_query("public", "users");
+
+            for expr in [
+                "pg_total_relation_size(c.oid)",
+                "pg_indexes_size(c.oid)",
+                "pg_total_relation_size(c.reltoastrelid)",
+                "LEFT JOIN pg_stat_user_tables st",
+                "to_char(st.last_autovacuum",
+            ] {
+                assert!(sql.contains(expr), "Missing: {expr}");
+            }
+        }
     }
 
     mod table_columns_and_fks_query {
         use super::*;
 
         #[test]
-        fn wraps_columns_and_fks_only_in_json_build_object() {
+        fn wraps_completion_fields_only_in_json_build_object() {
             let sql = PostgresAdapter::table_columns_and_fks_query("public", "users");
 
             assert!(sql.contains("json_build_object("));
             assert!(sql.contains("'columns'"));
             assert!(sql.contains("'foreign_keys'"));
-            assert!(!sql.contains("'indexes'"));
+            assert!(sql.contains("'indexes'"));
+            assert!(sql.contains("'column_stats'"));
+            assert!(sql.contains("FROM pg_stats"));
             assert!(!sql.contains("'rls'"));
             assert!(!sql.contains("'triggers'"));
             assert!(!sql.contains("'table_info'"));
@@ -584,6 +2092,14 @@ mod tests {
             "foreign_keys_query",
             PostgresAdapter::foreign_keys_query(HOSTILE, "t")
         )]
+        #[case(
+            "column_stats_query",
+            PostgresAdapter::column_stats_query(HOSTILE, "t")
+        )]
+        #[case(
+            "column_distribution_query",
+            PostgresAdapter::column_distribution_query("public", "t", HOSTILE, "text", None)
+        )]
         #[case("rls_query", PostgresAdapter::rls_query(HOSTILE, "t"))]
         #[case("triggers_query", PostgresAdapter::triggers_query(HOSTILE, "t"))]
         #[case(
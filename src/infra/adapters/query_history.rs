@@ -1,9 +1,11 @@
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use async_trait::async_trait;
 
 use crate::app::ports::outbound::{QueryHistoryError, QueryHistoryStore};
-use crate::config::cache::{CacheDirError, get_cache_dir};
+use crate::config::cache::{CacheDirError, CacheWrites, ReadOnlyCache, get_cache_dir};
+use crate::config::cache_cipher::{CacheCipher, seal_if, unseal};
 use crate::domain::connection::ConnectionId;
 use crate::domain::query_history::QueryHistoryEntry;
 
@@ -45,6 +47,14 @@ fn trim_if_exceeded(path: &Path, max: usize) -> Result<(), QueryHistoryError> {
 
 pub struct FileQueryHistoryStore {
     base_dir: Option<PathBuf>,
+    writes: CacheWrites,
+    cipher: Option<Arc<CacheCipher>>,
+}
+
+impl ReadOnlyCache for FileQueryHistoryStore {
+    fn cache_writes_mut(&mut self) -> &mut CacheWrites {
+        &mut self.writes
+    }
 }
 
 impl Default for FileQueryHistoryStore {
@@ -55,13 +65,26 @@ impl Default for FileQueryHistoryStore {
 
 impl FileQueryHistoryStore {
     pub fn new() -> Self {
-        Self { base_dir: None }
+        Self {
+            base_dir: None,
+            writes: CacheWrites::default(),
+            cipher: None,
+        }
+    }
+
+    /// Seals each appended entry; plain entries already on disk still load.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Option<Arc<CacheCipher>>) -> Self {
+        self.cipher = cipher;
+        self
     }
 
     #[cfg(test)]
     fn with_base_dir(base_dir: PathBuf) -> Self {
         Self {
             base_dir: Some(base_dir),
+            writes: CacheWrites::default(),
+            cipher: None,
         }
     }
 
@@ -83,9 +106,12 @@ impl QueryHistoryStore for FileQueryHistoryStore {
         connection_id: &ConnectionId,
         entry: &QueryHistoryEntry,
     ) -> Result<(), QueryHistoryError> {
+        if self.writes.dropped() {
+            return Ok(());
+        }
         let history_dir = self.resolve_history_dir(project_name)?;
         let path = history_dir.join(format!("{connection_id}.jsonl"));
-        let line = serde_json::to_string(entry)?;
+        let line = seal_if(self.cipher.as_deref(), serde_json::to_string(entry)?);
 
         tokio::task::spawn_blocking(move || {
             append_entry(&path, &history_dir, &line)?;
@@ -103,6 +129,7 @@ impl QueryHistoryStore for FileQueryHistoryStore {
     ) -> Result<Vec<QueryHistoryEntry>, QueryHistoryError> {
         let history_dir = self.resolve_history_dir(project_name)?;
         let path = history_dir.join(format!("{connection_id}.jsonl"));
+        let cipher = self.cipher.clone();
 
         tokio::task::spawn_blocking(move || {
             if !path.exists() {
@@ -113,7 +140,11 @@ impl QueryHistoryStore for FileQueryHistoryStore {
             let entries: Vec<QueryHistoryEntry> = content
                 .lines()
                 .filter(|line| !line.trim().is_empty())
-                .filter_map(|line| serde_json::from_str(line).ok())
+                // Entries sealed under another key are as unreadable as corrupt ones.
+                .filter_map(|line| {
+                    let line = unseal(cipher.as_deref(), line)?;
+                    serde_json::from_str(&line).ok()
+                })
                 .collect();
 
             Ok(entries)
@@ -125,6 +156,7 @@ impl QueryHistoryStore for FileQueryHistoryStore {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::app::ports::outbound::CacheCategory;
     use crate::domain::query_history::QueryResultStatus;
     use tempfile::TempDir;
 
@@ -179,6 +211,28 @@ mod tests {
         assert!(content.contains("SELECT 1"));
     }
 
+    #[tokio::test]
+    async fn read_only_store_loads_but_skips_appends() {
+        let tmp = TempDir::new().unwrap();
+        let conn_id = ConnectionId::from_string("test-conn");
+        let owner = FileQueryHistoryStore::with_base_dir(tmp.path().to_path_buf());
+        owner
+            .append("test", &conn_id, &make_entry("SELECT 1"))
+            .await
+            .unwrap();
+        let shared =
+            FileQueryHistoryStore::with_base_dir(tmp.path().to_path_buf()).with_read_only(true);
+
+        shared
+            .append("test", &conn_id, &make_entry("SELECT 2"))
+            .await
+            .unwrap();
+
+        let entries = shared.load("test", &conn_id).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].query, "SELECT 1");
+    }
+
     #[tokio::test]
     async fn load_returns_entries_in_order() {
         let tmp = TempDir::new().unwrap();
@@ -395,4 +449,36 @@ mod tests {
         assert_eq!(entries[1].result_status, QueryResultStatus::Success);
         assert_eq!(entries[1].affected_rows, Some(5));
     }
+
+    #[tokio::test]
+    async fn cipher_seals_appends_and_still_loads_plain_entries() {
+        let tmp = TempDir::new().unwrap();
+        let conn_id = ConnectionId::from_string("test-conn");
+        FileQueryHistoryStore::with_base_dir(tmp.path().to_path_buf())
+            .append("test", &conn_id, &make_entry("SELECT 'plain'"))
+            .await
+            .unwrap();
+        let cipher = Arc::new(CacheCipher::new(&[9; 32], CacheCategory::History));
+        let store = FileQueryHistoryStore::with_base_dir(tmp.path().to_path_buf())
+            .with_cipher(Some(cipher));
+
+        store
+            .append("test", &conn_id, &make_entry("SELECT 'sealed'"))
+            .await
+            .unwrap();
+
+        let path = tmp.path().join("history").join(format!("{conn_id}.jsonl"));
+        let raw = std::fs::read_to_string(path).unwrap();
+        assert!(!raw.contains("'sealed'"));
+        let queries: Vec<String> = store
+            .load("test", &conn_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.query)
+            .collect();
+        assert_eq!(queries, vec!["SELECT 'plain'", "SELECT 'sealed'"]);
+        let without_key = FileQueryHistoryStore::with_base_dir(tmp.path().to_path_buf());
+        assert_eq!(without_key.load("test", &conn_id).await.unwrap().len(), 1);
+    }
 }
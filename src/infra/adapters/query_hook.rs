@@ -0,0 +1,279 @@
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::adapters::app_config_file::get_config_dir;
+use crate::app::ports::outbound::{
+    HookEvent, HookInvocation, HookTarget, QueryHook, QueryHookError, QueryHookReader,
+    QueryHookRunner,
+};
+
+pub const HOOKS_FILE_NAME: &str = "hooks.toml";
+
+// A hung webhook or command must not linger until quit.
+const HOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Deserialize)]
+struct HooksFile {
+    #[serde(default)]
+    hook: Vec<HookEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct HookEntry {
+    event: String,
+    #[serde(default)]
+    min_seconds: u64,
+    command: Option<String>,
+    webhook: Option<String>,
+    payload: Option<String>,
+}
+
+impl TryFrom<HookEntry> for QueryHook {
+    type Error = QueryHookError;
+
+    fn try_from(entry: HookEntry) -> Result<Self, Self::Error> {
+        let event = HookEvent::from_config_value(&entry.event).ok_or_else(|| {
+            QueryHookError::Parse(format!(
+                "unknown event \"{}\" (expected slow_query, query_error or write_executed)",
+                entry.event
+            ))
+        })?;
+        let target = match (entry.command, entry.webhook) {
+            (Some(command), None) => HookTarget::Command(command),
+            (None, Some(url)) => HookTarget::Webhook(url),
+            _ => {
+                return Err(QueryHookError::Parse(format!(
+                    "{} hook needs exactly one of `command` or `webhook`",
+                    entry.event
+                )));
+            }
+        };
+        Ok(Self {
+            event,
+            min_duration_ms: entry.min_seconds.saturating_mul(1000),
+            target,
+            payload_template: entry.payload,
+        })
+    }
+}
+
+fn parse(content: &str) -> Result<Vec<QueryHook>, QueryHookError> {
+    let file: HooksFile =
+        toml::from_str(content).map_err(|e| QueryHookError::Parse(e.to_string()))?;
+    file.hook.into_iter().map(QueryHook::try_from).collect()
+}
+
+#[derive(Default)]
+pub struct TomlQueryHookReader {
+    path: Option<PathBuf>,
+}
+
+impl TomlQueryHookReader {
+    pub fn new() -> Self {
+        Self { path: None }
+    }
+
+    #[cfg(test)]
+    fn with_path(path: PathBuf) -> Self {
+        Self { path: Some(path) }
+    }
+}
+
+impl QueryHookReader for TomlQueryHookReader {
+    fn read_hooks(&self) -> Result<Vec<QueryHook>, QueryHookError> {
+        let path = match &self.path {
+            Some(path) => path.clone(),
+            None => match get_config_dir() {
+                Ok(dir) => dir.join(HOOKS_FILE_NAME),
+                Err(_) => return Ok(Vec::new()),
+            },
+        };
+        if !path.is_file() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&path).map_err(|source| QueryHookError::ReadAt {
+            path: path.clone(),
+            source: Arc::new(source),
+        })?;
+        parse(&content)
+    }
+}
+
+/// Runs commands through the platform shell and posts webhooks with `curl`,
+/// keeping the adapter as driver-less as the database adapters.
+pub struct ShellQueryHookRunner;
+
+impl ShellQueryHookRunner {
+    fn command_for(target: &HookTarget) -> Command {
+        match target {
+            HookTarget::Command(script) => shell_command(script),
+            HookTarget::Webhook(url) => {
+                let mut cmd = Command::new("curl");
+                cmd.args(["-sS", "-f", "-X", "POST"])
+                    .args(["-H", "Content-Type: application/json"])
+                    .args(["--data-binary", "@-"])
+                    .arg(url);
+                cmd
+            }
+        }
+    }
+}
+
+#[cfg(not(windows))]
+fn shell_command(script: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(script);
+    cmd
+}
+
+#[cfg(windows)]
+fn shell_command(script: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C").arg(script);
+    cmd
+}
+
+#[async_trait]
+impl QueryHookRunner for ShellQueryHookRunner {
+    async fn run(&self, invocation: &HookInvocation) -> Result<(), QueryHookError> {
+        let mut cmd = Self::command_for(&invocation.target);
+        let mut child = cmd
+            .envs(invocation.env.iter().map(|(k, v)| (k, v)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| QueryHookError::Execution(e.to_string()))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // A command that ignores stdin may exit before reading it.
+            let _ = stdin.write_all(invocation.payload.as_bytes()).await;
+        }
+
+        let output = tokio::time::timeout(HOOK_TIMEOUT, child.wait_with_output())
+            .await
+            .map_err(|_| QueryHookError::Execution("timed out".to_string()))?
+            .map_err(|e| QueryHookError::Execution(e.to_string()))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let detail = stderr.trim();
+            Err(QueryHookError::Execution(if detail.is_empty() {
+                output.status.to_string()
+            } else {
+                detail.to_string()
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_command_and_webhook_hooks() {
+        let hooks = parse(
+            r#"
+[[hook]]
+event = "slow_query"
+min_seconds = 30
+webhook = "https://hooks.example.test/abc"
+payload = '{"text": "{{query}}"}'
+
+[[hook]]
+event = "query_error"
+command = "notify-send sabiql"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            hooks,
+            vec![
+                QueryHook {
+                    event: HookEvent::SlowQuery,
+                    min_duration_ms: 30_000,
+                    target: HookTarget::Webhook("https://hooks.example.test/abc".to_string()),
+                    payload_template: Some(r#"{"text": "{{query}}"}"#.to_string()),
+                },
+                QueryHook {
+                    event: HookEvent::QueryError,
+                    min_duration_ms: 0,
+                    target: HookTarget::Command("notify-send sabiql".to_string()),
+                    payload_template: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_event() {
+        let result = parse("[[hook]]\nevent = \"finished\"\ncommand = \"true\"\n");
+
+        assert!(matches!(result, Err(QueryHookError::Parse(msg)) if msg.contains("finished")));
+    }
+
+    #[test]
+    fn rejects_hook_with_both_targets() {
+        let result = parse(
+            "[[hook]]\nevent = \"query_error\"\ncommand = \"true\"\nwebhook = \"https://x.test\"\n",
+        );
+
+        assert!(matches!(result, Err(QueryHookError::Parse(_))));
+    }
+
+    #[test]
+    fn missing_file_yields_no_hooks() {
+        let tmp = TempDir::new().unwrap();
+        let reader = TomlQueryHookReader::with_path(tmp.path().join(HOOKS_FILE_NAME));
+
+        assert!(reader.read_hooks().unwrap().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn command_receives_payload_and_env() {
+        let tmp = TempDir::new().unwrap();
+        let out = tmp.path().join("out.txt");
+        let invocation = HookInvocation {
+            target: HookTarget::Command(format!(
+                "cat > '{}'; printf '%s' \"$SABIQL_EVENT\" >> '{}'",
+                out.display(),
+                out.display()
+            )),
+            payload: "payload|".to_string(),
+            env: vec![("SABIQL_EVENT".to_string(), "query_error".to_string())],
+        };
+
+        ShellQueryHookRunner.run(&invocation).await.unwrap();
+
+        assert_eq!(std::fs::read_to_string(out).unwrap(), "payload|query_error");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn failing_command_reports_stderr() {
+        let invocation = HookInvocation {
+            target: HookTarget::Command("echo nope >&2; exit 3".to_string()),
+            payload: String::new(),
+            env: vec![],
+        };
+
+        let error = ShellQueryHookRunner.run(&invocation).await.unwrap_err();
+
+        assert!(matches!(error, QueryHookError::Execution(msg) if msg == "nope"));
+    }
+}
@@ -2,22 +2,26 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::app::ports::outbound::{
-    AccessMode, DbOperationError, DdlGenerator, DsnBuilder, MetadataProvider, QueryExecutor,
-    SqlDialect, SqliteDiagnosticsProvider,
+    AccessMode, ConnectionPool, DbOperationError, DdlGenerator, DsnBuilder, MetadataProvider,
+    NamingConventions, PoolStats, PreviewOptions, QueryExecutor, SqlDialect,
+    SqliteDiagnosticsProvider, TransactionEnd,
 };
-use crate::domain::connection::{ConnectionProfile, DatabaseType};
+use crate::domain::connection::{ConnectionProfile, DatabaseType, Dsn};
 use crate::domain::{
-    DatabaseMetadata, DiagnosticField, QueryResult, QueryValue, SqliteDiagnosticsSnapshot, Table,
-    TableSignature, WriteExecutionResult,
+    Column, DatabaseMetadata, DiagnosticField, ForeignKey, Index, IndexMaintenanceKind,
+    LockConflict, MaintenanceKind, PoolSettings, QueryResult, QueryValue,
+    SqliteDiagnosticsSnapshot, Table, TableSignature, WriteExecutionResult,
 };
 use async_trait::async_trait;
 
+use super::mysql::MySqlAdapter;
 use super::postgres::PostgresAdapter;
 use super::sqlite::SqliteAdapter;
 
 pub struct DbAdapterRegistry {
     postgres: Arc<PostgresAdapter>,
     sqlite: Arc<SqliteAdapter>,
+    mysql: Arc<MySqlAdapter>,
 }
 
 impl DbAdapterRegistry {
@@ -25,43 +29,31 @@ impl DbAdapterRegistry {
         Self {
             postgres,
             sqlite: Arc::new(SqliteAdapter::new()),
+            mysql: Arc::new(MySqlAdapter::new()),
         }
     }
 
     fn db_type_from_dsn(dsn: &str) -> Result<DatabaseType, DbOperationError> {
-        if dsn.starts_with("sqlite://") {
-            return Ok(DatabaseType::SQLite);
-        }
-        if dsn.starts_with("postgres://") || is_postgres_conninfo_dsn(dsn) {
-            return Ok(DatabaseType::PostgreSQL);
-        }
-        Err(DbOperationError::ConnectionFailed(format!(
-            "Unsupported database DSN scheme: {dsn}"
-        )))
+        let parsed = Dsn::parse(dsn)
+            .map_err(|error| DbOperationError::ConnectionFailed(error.to_string()))?;
+        parsed.database_type().ok_or_else(|| {
+            DbOperationError::ConnectionFailed(format!("Unsupported database DSN scheme: {parsed}"))
+        })
     }
 }
 
-fn is_postgres_conninfo_dsn(dsn: &str) -> bool {
-    let Some((key, _)) = dsn.trim_start().split_once('=') else {
-        return false;
-    };
-    matches!(
-        key.trim(),
-        "host" | "hostaddr" | "port" | "dbname" | "user" | "password" | "sslmode" | "service"
-    )
-}
-
 impl DsnBuilder for DbAdapterRegistry {
     fn build_dsn(&self, profile: &ConnectionProfile) -> String {
         match profile.database_type() {
             DatabaseType::PostgreSQL => self.postgres.build_dsn(profile),
             DatabaseType::SQLite => {
-                let path = &profile
+                let path = profile
                     .sqlite_config()
                     .expect("SQLite profile requires SQLite config")
                     .path();
-                format!("sqlite://{path}")
+                Dsn::url("sqlite").with_database(path).build()
             }
+            DatabaseType::MySQL => self.mysql.build_dsn(profile),
         }
     }
 }
@@ -72,6 +64,7 @@ impl MetadataProvider for DbAdapterRegistry {
         match Self::db_type_from_dsn(dsn)? {
             DatabaseType::PostgreSQL => self.postgres.fetch_metadata(dsn).await,
             DatabaseType::SQLite => self.sqlite.fetch_metadata(dsn).await,
+            DatabaseType::MySQL => self.mysql.fetch_metadata(dsn).await,
         }
     }
 
@@ -84,6 +77,7 @@ impl MetadataProvider for DbAdapterRegistry {
         match Self::db_type_from_dsn(dsn)? {
             DatabaseType::PostgreSQL => self.postgres.fetch_table_detail(dsn, schema, table).await,
             DatabaseType::SQLite => self.sqlite.fetch_table_detail(dsn, schema, table).await,
+            DatabaseType::MySQL => self.mysql.fetch_table_detail(dsn, schema, table).await,
         }
     }
 
@@ -104,6 +98,11 @@ impl MetadataProvider for DbAdapterRegistry {
                     .fetch_table_columns_and_fks(dsn, schema, table)
                     .await
             }
+            DatabaseType::MySQL => {
+                self.mysql
+                    .fetch_table_columns_and_fks(dsn, schema, table)
+                    .await
+            }
         }
     }
 
@@ -114,6 +113,7 @@ impl MetadataProvider for DbAdapterRegistry {
         match Self::db_type_from_dsn(dsn)? {
             DatabaseType::PostgreSQL => self.postgres.fetch_table_signatures(dsn).await,
             DatabaseType::SQLite => self.sqlite.fetch_table_signatures(dsn).await,
+            DatabaseType::MySQL => self.mysql.fetch_table_signatures(dsn).await,
         }
     }
 }
@@ -125,13 +125,14 @@ impl QueryExecutor for DbAdapterRegistry {
         dsn: &str,
         schema: &str,
         table: &str,
+        options: PreviewOptions,
         limit: usize,
         offset: usize,
     ) -> Result<QueryResult, DbOperationError> {
         match Self::db_type_from_dsn(dsn)? {
             DatabaseType::PostgreSQL => {
                 self.postgres
-                    .execute_preview(dsn, schema, table, limit, offset)
+                    .execute_preview(dsn, schema, table, options, limit, offset)
                     .await
             }
             DatabaseType::SQLite => {
@@ -140,6 +141,19 @@ impl QueryExecutor for DbAdapterRegistry {
                     dsn,
                     schema,
                     table,
+                    options,
+                    limit,
+                    offset,
+                )
+                .await
+            }
+            DatabaseType::MySQL => {
+                QueryExecutor::execute_preview(
+                    self.mysql.as_ref(),
+                    dsn,
+                    schema,
+                    table,
+                    options,
                     limit,
                     offset,
                 )
@@ -159,6 +173,9 @@ impl QueryExecutor for DbAdapterRegistry {
             DatabaseType::SQLite => {
                 QueryExecutor::execute_adhoc(self.sqlite.as_ref(), dsn, query, access_mode).await
             }
+            DatabaseType::MySQL => {
+                QueryExecutor::execute_adhoc(self.mysql.as_ref(), dsn, query, access_mode).await
+            }
         }
     }
 
@@ -173,6 +190,9 @@ impl QueryExecutor for DbAdapterRegistry {
             DatabaseType::SQLite => {
                 QueryExecutor::execute_write(self.sqlite.as_ref(), dsn, query, access_mode).await
             }
+            DatabaseType::MySQL => {
+                QueryExecutor::execute_write(self.mysql.as_ref(), dsn, query, access_mode).await
+            }
         }
     }
 
@@ -182,6 +202,9 @@ impl QueryExecutor for DbAdapterRegistry {
             DatabaseType::SQLite => {
                 QueryExecutor::count_query_rows(self.sqlite.as_ref(), dsn, query).await
             }
+            DatabaseType::MySQL => {
+                QueryExecutor::count_query_rows(self.mysql.as_ref(), dsn, query).await
+            }
         }
     }
 
@@ -196,8 +219,64 @@ impl QueryExecutor for DbAdapterRegistry {
             DatabaseType::SQLite => {
                 QueryExecutor::export_to_csv(self.sqlite.as_ref(), dsn, query, file_name).await
             }
+            DatabaseType::MySQL => {
+                QueryExecutor::export_to_csv(self.mysql.as_ref(), dsn, query, file_name).await
+            }
+        }
+    }
+
+    async fn begin_transaction(
+        &self,
+        dsn: &str,
+        access_mode: AccessMode,
+    ) -> Result<(), DbOperationError> {
+        match Self::db_type_from_dsn(dsn)? {
+            DatabaseType::PostgreSQL => self.postgres.begin_transaction(dsn, access_mode).await,
+            DatabaseType::SQLite => {
+                QueryExecutor::begin_transaction(self.sqlite.as_ref(), dsn, access_mode).await
+            }
+            DatabaseType::MySQL => {
+                QueryExecutor::begin_transaction(self.mysql.as_ref(), dsn, access_mode).await
+            }
         }
     }
+
+    async fn end_transaction(
+        &self,
+        dsn: &str,
+        end: TransactionEnd,
+    ) -> Result<(), DbOperationError> {
+        match Self::db_type_from_dsn(dsn)? {
+            DatabaseType::PostgreSQL => self.postgres.end_transaction(dsn, end).await,
+            DatabaseType::SQLite => {
+                QueryExecutor::end_transaction(self.sqlite.as_ref(), dsn, end).await
+            }
+            DatabaseType::MySQL => {
+                QueryExecutor::end_transaction(self.mysql.as_ref(), dsn, end).await
+            }
+        }
+    }
+}
+
+// SQLite and MySQL start a CLI process per query, so only PostgreSQL pools.
+#[async_trait]
+impl ConnectionPool for DbAdapterRegistry {
+    async fn warm_up(&self, dsn: &str, settings: PoolSettings) {
+        if matches!(Self::db_type_from_dsn(dsn), Ok(DatabaseType::PostgreSQL)) {
+            self.postgres.warm_up(dsn, settings).await;
+        }
+    }
+
+    fn stats(&self, dsn: &str) -> Option<PoolStats> {
+        match Self::db_type_from_dsn(dsn).ok()? {
+            DatabaseType::PostgreSQL => self.postgres.stats(dsn),
+            DatabaseType::SQLite | DatabaseType::MySQL => None,
+        }
+    }
+
+    async fn drain(&self) {
+        self.postgres.drain().await;
+    }
 }
 
 impl DdlGenerator for DbAdapterRegistry {
@@ -205,6 +284,7 @@ impl DdlGenerator for DbAdapterRegistry {
         match database_type {
             DatabaseType::PostgreSQL => self.postgres.generate_ddl(database_type, table),
             DatabaseType::SQLite => self.sqlite.generate_ddl(database_type, table),
+            DatabaseType::MySQL => self.mysql.generate_ddl(database_type, table),
         }
     }
 }
@@ -214,6 +294,7 @@ impl SqlDialect for DbAdapterRegistry {
         match database_type {
             DatabaseType::PostgreSQL => self.postgres.build_explain_sql(database_type, query),
             DatabaseType::SQLite => self.sqlite.build_explain_sql(database_type, query),
+            DatabaseType::MySQL => self.mysql.build_explain_sql(database_type, query),
         }
     }
 
@@ -227,6 +308,330 @@ impl SqlDialect for DbAdapterRegistry {
                 .postgres
                 .build_explain_analyze_sql(database_type, query),
             DatabaseType::SQLite => self.sqlite.build_explain_analyze_sql(database_type, query),
+            DatabaseType::MySQL => self.mysql.build_explain_analyze_sql(database_type, query),
+        }
+    }
+
+    fn build_replication_overview_sql(
+        &self,
+        database_type: DatabaseType,
+        table: Option<&str>,
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self
+                .postgres
+                .build_replication_overview_sql(database_type, table),
+            DatabaseType::SQLite => self
+                .sqlite
+                .build_replication_overview_sql(database_type, table),
+            DatabaseType::MySQL => self
+                .mysql
+                .build_replication_overview_sql(database_type, table),
+        }
+    }
+
+    fn build_sample_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        limit: usize,
+        row_estimate: Option<i64>,
+    ) -> String {
+        match database_type {
+            DatabaseType::PostgreSQL => {
+                self.postgres
+                    .build_sample_sql(database_type, schema, table, limit, row_estimate)
+            }
+            DatabaseType::SQLite => {
+                self.sqlite
+                    .build_sample_sql(database_type, schema, table, limit, row_estimate)
+            }
+            DatabaseType::MySQL => {
+                self.mysql
+                    .build_sample_sql(database_type, schema, table, limit, row_estimate)
+            }
+        }
+    }
+
+    fn build_row_count_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+    ) -> String {
+        match database_type {
+            DatabaseType::PostgreSQL => {
+                self.postgres
+                    .build_row_count_sql(database_type, schema, table)
+            }
+            DatabaseType::SQLite => self
+                .sqlite
+                .build_row_count_sql(database_type, schema, table),
+            DatabaseType::MySQL => self.mysql.build_row_count_sql(database_type, schema, table),
+        }
+    }
+
+    fn build_rls_test_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        role: &str,
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => {
+                self.postgres
+                    .build_rls_test_sql(database_type, schema, table, role)
+            }
+            DatabaseType::SQLite => {
+                self.sqlite
+                    .build_rls_test_sql(database_type, schema, table, role)
+            }
+            DatabaseType::MySQL => {
+                self.mysql
+                    .build_rls_test_sql(database_type, schema, table, role)
+            }
+        }
+    }
+
+    fn build_maintenance_sql(
+        &self,
+        database_type: DatabaseType,
+        kind: MaintenanceKind,
+        schema: &str,
+        table: &str,
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => {
+                self.postgres
+                    .build_maintenance_sql(database_type, kind, schema, table)
+            }
+            DatabaseType::SQLite => {
+                self.sqlite
+                    .build_maintenance_sql(database_type, kind, schema, table)
+            }
+            DatabaseType::MySQL => {
+                self.mysql
+                    .build_maintenance_sql(database_type, kind, schema, table)
+            }
+        }
+    }
+
+    fn build_maintenance_progress_sql(
+        &self,
+        database_type: DatabaseType,
+        kind: MaintenanceKind,
+        schema: &str,
+        table: &str,
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => {
+                self.postgres
+                    .build_maintenance_progress_sql(database_type, kind, schema, table)
+            }
+            DatabaseType::SQLite => {
+                self.sqlite
+                    .build_maintenance_progress_sql(database_type, kind, schema, table)
+            }
+            DatabaseType::MySQL => {
+                self.mysql
+                    .build_maintenance_progress_sql(database_type, kind, schema, table)
+            }
+        }
+    }
+
+    fn build_index_maintenance_sql(
+        &self,
+        database_type: DatabaseType,
+        kind: IndexMaintenanceKind,
+        schema: &str,
+        index: &Index,
+    ) -> Option<Vec<String>> {
+        match database_type {
+            DatabaseType::PostgreSQL => {
+                self.postgres
+                    .build_index_maintenance_sql(database_type, kind, schema, index)
+            }
+            DatabaseType::SQLite => {
+                self.sqlite
+                    .build_index_maintenance_sql(database_type, kind, schema, index)
+            }
+            DatabaseType::MySQL => {
+                self.mysql
+                    .build_index_maintenance_sql(database_type, kind, schema, index)
+            }
+        }
+    }
+
+    fn build_index_maintenance_progress_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => {
+                self.postgres
+                    .build_index_maintenance_progress_sql(database_type, schema, table)
+            }
+            DatabaseType::SQLite => {
+                self.sqlite
+                    .build_index_maintenance_progress_sql(database_type, schema, table)
+            }
+            DatabaseType::MySQL => {
+                self.mysql
+                    .build_index_maintenance_progress_sql(database_type, schema, table)
+            }
+        }
+    }
+
+    fn build_autovacuum_report_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => {
+                self.postgres
+                    .build_autovacuum_report_sql(database_type, schema, table)
+            }
+            DatabaseType::SQLite => {
+                self.sqlite
+                    .build_autovacuum_report_sql(database_type, schema, table)
+            }
+            DatabaseType::MySQL => {
+                self.mysql
+                    .build_autovacuum_report_sql(database_type, schema, table)
+            }
+        }
+    }
+
+    fn build_table_sizes_sql(
+        &self,
+        database_type: DatabaseType,
+        tables: &[(String, String)],
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self.postgres.build_table_sizes_sql(database_type, tables),
+            DatabaseType::SQLite => self.sqlite.build_table_sizes_sql(database_type, tables),
+            DatabaseType::MySQL => self.mysql.build_table_sizes_sql(database_type, tables),
+        }
+    }
+
+    fn build_server_settings_sql(&self, database_type: DatabaseType) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self.postgres.build_server_settings_sql(database_type),
+            DatabaseType::SQLite => self.sqlite.build_server_settings_sql(database_type),
+            DatabaseType::MySQL => self.mysql.build_server_settings_sql(database_type),
+        }
+    }
+
+    fn build_index_usage_sql(&self, database_type: DatabaseType) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self.postgres.build_index_usage_sql(database_type),
+            DatabaseType::SQLite => self.sqlite.build_index_usage_sql(database_type),
+            DatabaseType::MySQL => self.mysql.build_index_usage_sql(database_type),
+        }
+    }
+
+    fn build_fk_orphan_check_sql(
+        &self,
+        database_type: DatabaseType,
+        foreign_keys: &[ForeignKey],
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self
+                .postgres
+                .build_fk_orphan_check_sql(database_type, foreign_keys),
+            DatabaseType::SQLite => self
+                .sqlite
+                .build_fk_orphan_check_sql(database_type, foreign_keys),
+            DatabaseType::MySQL => self
+                .mysql
+                .build_fk_orphan_check_sql(database_type, foreign_keys),
+        }
+    }
+
+    fn build_fk_suggestion_sql(&self, database_type: DatabaseType) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self.postgres.build_fk_suggestion_sql(database_type),
+            DatabaseType::SQLite => self.sqlite.build_fk_suggestion_sql(database_type),
+            DatabaseType::MySQL => self.mysql.build_fk_suggestion_sql(database_type),
+        }
+    }
+
+    fn build_naming_audit_sql(
+        &self,
+        database_type: DatabaseType,
+        conventions: &NamingConventions,
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self
+                .postgres
+                .build_naming_audit_sql(database_type, conventions),
+            DatabaseType::SQLite => self
+                .sqlite
+                .build_naming_audit_sql(database_type, conventions),
+            DatabaseType::MySQL => self
+                .mysql
+                .build_naming_audit_sql(database_type, conventions),
+        }
+    }
+
+    fn build_lock_blockers_sql(
+        &self,
+        database_type: DatabaseType,
+        conflict: &LockConflict,
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self
+                .postgres
+                .build_lock_blockers_sql(database_type, conflict),
+            DatabaseType::SQLite => self.sqlite.build_lock_blockers_sql(database_type, conflict),
+            DatabaseType::MySQL => self.mysql.build_lock_blockers_sql(database_type, conflict),
+        }
+    }
+
+    fn build_result_types_sql(&self, database_type: DatabaseType, query: &str) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self.postgres.build_result_types_sql(database_type, query),
+            DatabaseType::SQLite => self.sqlite.build_result_types_sql(database_type, query),
+            DatabaseType::MySQL => self.mysql.build_result_types_sql(database_type, query),
+        }
+    }
+
+    fn build_column_distribution_sql(
+        &self,
+        database_type: DatabaseType,
+        schema: &str,
+        table: &str,
+        column: &Column,
+        row_estimate: Option<i64>,
+    ) -> Option<String> {
+        match database_type {
+            DatabaseType::PostgreSQL => self.postgres.build_column_distribution_sql(
+                database_type,
+                schema,
+                table,
+                column,
+                row_estimate,
+            ),
+            DatabaseType::SQLite => self.sqlite.build_column_distribution_sql(
+                database_type,
+                schema,
+                table,
+                column,
+                row_estimate,
+            ),
+            DatabaseType::MySQL => self.mysql.build_column_distribution_sql(
+                database_type,
+                schema,
+                table,
+                column,
+                row_estimate,
+            ),
         }
     }
 
@@ -256,6 +661,14 @@ impl SqlDialect for DbAdapterRegistry {
                 new_value,
                 pk_pairs,
             ),
+            DatabaseType::MySQL => self.mysql.build_update_sql(
+                database_type,
+                schema,
+                table,
+                column,
+                new_value,
+                pk_pairs,
+            ),
         }
     }
 
@@ -275,6 +688,10 @@ impl SqlDialect for DbAdapterRegistry {
                 self.sqlite
                     .build_bulk_delete_sql(database_type, schema, table, pk_pairs_per_row)
             }
+            DatabaseType::MySQL => {
+                self.mysql
+                    .build_bulk_delete_sql(database_type, schema, table, pk_pairs_per_row)
+            }
         }
     }
 }
@@ -284,8 +701,8 @@ mod tests {
     use crate::adapters::test_support;
 
     use super::*;
+    use crate::domain::ColumnAttributes;
     use crate::domain::connection::SslMode;
-    use crate::domain::{Column, ColumnAttributes};
 
     fn make_table() -> Table {
         Table {
@@ -333,6 +750,28 @@ mod tests {
         assert_eq!(dsn, "sqlite:///tmp/app.db");
     }
 
+    #[test]
+    fn builds_mysql_dsn_from_mysql_profile() {
+        let registry = DbAdapterRegistry::new(Arc::new(PostgresAdapter::new()));
+        let profile =
+            ConnectionProfile::new_mysql("Shop", "localhost", 3306, "shop", "root", "").unwrap();
+
+        let dsn = registry.build_dsn(&profile);
+
+        assert_eq!(dsn, "mysql://root@localhost:3306/shop");
+    }
+
+    #[test]
+    fn mysql_sql_generation_uses_backtick_identifiers() {
+        let registry = DbAdapterRegistry::new(Arc::new(PostgresAdapter::new()));
+        let rows = vec![vec![("id".to_string(), QueryValue::text("1"))]];
+
+        let delete_sql =
+            registry.build_bulk_delete_sql(DatabaseType::MySQL, "shop", "users", &rows);
+
+        assert_eq!(delete_sql, "DELETE FROM `shop`.`users`\nWHERE `id` = '1';");
+    }
+
     #[test]
     fn postgres_sql_generation_keeps_schema_qualified_sql() {
         let registry = DbAdapterRegistry::new(Arc::new(PostgresAdapter::new()));
@@ -397,7 +836,7 @@ mod tests {
         );
         assert_eq!(
             registry.build_explain_analyze_sql(DatabaseType::PostgreSQL, "SELECT 1"),
-            Some("EXPLAIN ANALYZE SELECT 1".to_string())
+            Some("EXPLAIN (ANALYZE, BUFFERS) SELECT 1".to_string())
         );
     }
 
@@ -423,11 +862,19 @@ mod tests {
     async fn unknown_dsn_scheme_is_rejected() {
         let registry = DbAdapterRegistry::new(Arc::new(PostgresAdapter::new()));
 
-        let result = registry.fetch_metadata("mysql://localhost/db").await;
+        let result = registry.fetch_metadata("oracle://localhost/db").await;
 
         assert!(matches!(result, Err(DbOperationError::ConnectionFailed(_))));
     }
 
+    #[test]
+    fn mysql_dsn_scheme_is_recognized() {
+        assert!(matches!(
+            DbAdapterRegistry::db_type_from_dsn("mysql://root@localhost:3306/shop"),
+            Ok(DatabaseType::MySQL)
+        ));
+    }
+
     #[tokio::test]
     async fn sqlite_metadata_is_dispatched_to_sqlite_adapter() {
         let (_dir, dsn) =
@@ -503,9 +950,11 @@ impl SqliteDiagnosticsProvider for DbAdapterRegistry {
         dsn: &str,
     ) -> Result<SqliteDiagnosticsSnapshot, DbOperationError> {
         match Self::db_type_from_dsn(dsn)? {
-            DatabaseType::PostgreSQL => Err(DbOperationError::ConnectionFailed(
-                "SQLite diagnostics are unavailable for non-SQLite connections".to_string(),
-            )),
+            DatabaseType::PostgreSQL | DatabaseType::MySQL => {
+                Err(DbOperationError::ConnectionFailed(
+                    "SQLite diagnostics are unavailable for non-SQLite connections".to_string(),
+                ))
+            }
             DatabaseType::SQLite => self.sqlite.fetch_diagnostics_core(dsn).await,
         }
     }
@@ -513,7 +962,7 @@ impl SqliteDiagnosticsProvider for DbAdapterRegistry {
     async fn fetch_quick_check(&self, dsn: &str) -> DiagnosticField {
         match Self::db_type_from_dsn(dsn) {
             Ok(DatabaseType::SQLite) => self.sqlite.fetch_quick_check(dsn).await,
-            Ok(DatabaseType::PostgreSQL) | Err(_) => DiagnosticField::err(
+            Ok(DatabaseType::PostgreSQL | DatabaseType::MySQL) | Err(_) => DiagnosticField::err(
                 "SQLite diagnostics are unavailable for non-SQLite connections",
             ),
         }
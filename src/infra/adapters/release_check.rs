@@ -0,0 +1,88 @@
+use std::process::Stdio;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::process::Command;
+
+use crate::app::ports::outbound::{ReleaseCheckError, ReleaseChecker, ReleaseInfo};
+
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/riii111/sabiql/releases/latest";
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    html_url: String,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+fn parse(body: &[u8]) -> Result<ReleaseInfo, ReleaseCheckError> {
+    let release: GithubRelease =
+        serde_json::from_slice(body).map_err(|e| ReleaseCheckError::Parse(e.to_string()))?;
+    Ok(ReleaseInfo {
+        version: release.tag_name.trim_start_matches('v').to_string(),
+        url: release.html_url,
+        notes: release.body.unwrap_or_default().replace("\r\n", "\n"),
+    })
+}
+
+/// Asks the GitHub releases API through `curl`, like the webhook hooks, so
+/// the binary carries no HTTP client of its own.
+pub struct GithubReleaseChecker;
+
+#[async_trait]
+impl ReleaseChecker for GithubReleaseChecker {
+    async fn latest_release(&self) -> Result<ReleaseInfo, ReleaseCheckError> {
+        let child = Command::new("curl")
+            .args(["-sS", "-f", "-L"])
+            .args(["-H", "Accept: application/vnd.github+json"])
+            .args(["-A", concat!("sabiql/", env!("CARGO_PKG_VERSION"))])
+            .arg(LATEST_RELEASE_URL)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| ReleaseCheckError::Request(e.to_string()))?;
+
+        let output = tokio::time::timeout(CHECK_TIMEOUT, child.wait_with_output())
+            .await
+            .map_err(|_| ReleaseCheckError::Request("timed out".to_string()))?
+            .map_err(|e| ReleaseCheckError::Request(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ReleaseCheckError::Request(stderr.trim().to_string()));
+        }
+        parse(&output.stdout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_response_strips_the_tag_prefix() {
+        let body = br#"{
+            "tag_name": "v1.16.0",
+            "html_url": "https://github.com/riii111/sabiql/releases/tag/v1.16.0",
+            "body": "Features\r\n- Column masking",
+            "draft": false
+        }"#;
+
+        let release = parse(body).unwrap();
+
+        assert_eq!(release.version, "1.16.0");
+        assert_eq!(release.notes, "Features\n- Column masking");
+    }
+
+    #[test]
+    fn missing_tag_is_a_parse_error() {
+        let result = parse(br#"{"message": "Not Found"}"#);
+
+        assert!(matches!(result, Err(ReleaseCheckError::Parse(_))));
+    }
+}
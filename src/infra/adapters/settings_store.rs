@@ -4,11 +4,13 @@ use std::path::PathBuf;
 use super::app_config_file::{
     self, config_file_path, get_config_dir as app_config_dir, render_config_file, write_config_file,
 };
-use crate::app::model::shared::settings::KeymapPreset;
+use crate::app::i18n::Locale;
+use crate::app::model::shared::settings::{KeymapPreset, KeywordCase, PrefetchScope};
 use crate::app::model::shared::theme_id::ThemeId;
 use crate::app::ports::outbound::{AppSettings, SettingsStore, SettingsStoreError};
 use crate::config::connection_config::{
-    CURRENT_VERSION, ConfigVersionCheck, ConnectionConfigFile, is_supported_config_version,
+    CURRENT_VERSION, CacheEncryptionConfig, ConfigVersionCheck, ConnectionConfigFile,
+    is_supported_config_version,
 };
 
 #[cfg(test)]
@@ -32,6 +34,15 @@ impl TomlSettingsStore {
         config_file_path(&self.config_dir)
     }
 
+    /// Read once at startup to build the cache stores; the settings modal
+    /// never edits it, and saves keep whatever the file says.
+    pub fn load_cache_encryption(&self) -> Result<CacheEncryptionConfig, SettingsStoreError> {
+        Ok(self
+            .load_config_file_lenient()?
+            .and_then(|config| config.cache_encryption)
+            .unwrap_or_default())
+    }
+
     fn load_config_file_lenient(&self) -> Result<Option<ConnectionConfigFile>, SettingsStoreError> {
         let path = self.config_file_path();
         if !path.exists() {
@@ -90,6 +101,12 @@ impl SettingsStore for TomlSettingsStore {
                 theme: None,
                 keymap_preset: None,
                 er_browser: None,
+                keyword_case: None,
+                locale: None,
+                usage_stats: None,
+                update_check: None,
+                prefetch_scope: None,
+                cache_encryption: None,
                 connections: vec![],
             });
         set_app_settings(&mut config, settings);
@@ -118,6 +135,18 @@ fn app_settings(config: ConnectionConfigFile) -> AppSettings {
             .and_then(KeymapPreset::from_config_value)
             .unwrap_or(KeymapPreset::Default),
         er_browser: config.er_browser,
+        keyword_case: config
+            .keyword_case
+            .as_deref()
+            .and_then(KeywordCase::from_config_value),
+        locale: config.locale.as_deref().and_then(Locale::from_config_value),
+        usage_stats: config.usage_stats.unwrap_or(false),
+        update_check: config.update_check.unwrap_or(false),
+        prefetch_scope: config
+            .prefetch_scope
+            .as_deref()
+            .and_then(PrefetchScope::from_config_value)
+            .unwrap_or_default(),
     }
 }
 
@@ -125,6 +154,16 @@ fn set_app_settings(config: &mut ConnectionConfigFile, settings: AppSettings) {
     config.theme = Some(settings.theme_id.config_value().to_string());
     config.keymap_preset = Some(settings.keymap_preset.config_value().to_string());
     config.er_browser = settings.er_browser;
+    config.keyword_case = settings
+        .keyword_case
+        .map(|case| case.config_value().to_string());
+    config.locale = settings
+        .locale
+        .map(|locale| locale.config_value().to_string());
+    config.usage_stats = settings.usage_stats.then_some(true);
+    config.update_check = settings.update_check.then_some(true);
+    config.prefetch_scope = (settings.prefetch_scope != PrefetchScope::All)
+        .then(|| settings.prefetch_scope.config_value().to_string());
 }
 
 #[cfg(test)]
@@ -153,6 +192,11 @@ mod tests {
                 theme_id: ThemeId::Light,
                 keymap_preset: KeymapPreset::Ide,
                 er_browser: Some("Google Chrome".to_string()),
+                keyword_case: Some(KeywordCase::Lower),
+                locale: Some(Locale::Japanese),
+                usage_stats: true,
+                update_check: true,
+                prefetch_scope: PrefetchScope::Schema,
             })
             .unwrap();
 
@@ -160,6 +204,11 @@ mod tests {
         assert_eq!(settings.theme_id, ThemeId::Light);
         assert_eq!(settings.keymap_preset, KeymapPreset::Ide);
         assert_eq!(settings.er_browser.as_deref(), Some("Google Chrome"));
+        assert_eq!(settings.keyword_case, Some(KeywordCase::Lower));
+        assert_eq!(settings.locale, Some(Locale::Japanese));
+        assert!(settings.usage_stats);
+        assert!(settings.update_check);
+        assert_eq!(settings.prefetch_scope, PrefetchScope::Schema);
     }
 
     #[test]
@@ -188,6 +237,11 @@ ssl_mode = "prefer"
                 theme_id: ThemeId::Light,
                 keymap_preset: KeymapPreset::Ide,
                 er_browser: Some("Firefox".to_string()),
+                keyword_case: None,
+                locale: None,
+                usage_stats: false,
+                update_check: false,
+                prefetch_scope: PrefetchScope::All,
             })
             .unwrap();
 
@@ -199,6 +253,34 @@ ssl_mode = "prefer"
         assert!(content.contains("name = \"Test\""));
     }
 
+    #[test]
+    fn save_keeps_cache_encryption_flags() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(CONFIG_FILE_NAME),
+            "version = 3
+connections = []
+
+[cache_encryption]
+history = true
+spill = true
+",
+        )
+        .unwrap();
+        let store = TomlSettingsStore::with_config_dir(temp_dir.path().to_path_buf());
+
+        store.save(AppSettings::default()).unwrap();
+
+        assert_eq!(
+            store.load_cache_encryption().unwrap(),
+            CacheEncryptionConfig {
+                history: true,
+                snapshots: false,
+                spill: true,
+            }
+        );
+    }
+
     #[test]
     fn invalid_toml_falls_back_to_default() {
         let temp_dir = TempDir::new().unwrap();
@@ -270,6 +352,11 @@ ssl_mode = "prefer"
             theme_id: ThemeId::Light,
             keymap_preset: KeymapPreset::Default,
             er_browser: None,
+            keyword_case: None,
+            locale: None,
+            usage_stats: false,
+            update_check: false,
+            prefetch_scope: PrefetchScope::All,
         });
 
         assert!(matches!(
@@ -0,0 +1,161 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::ports::outbound::{Snippet, SnippetError, SnippetStore};
+
+/// Lives in the project so a team can commit its snippets with the code.
+pub const SNIPPETS_FILE: &str = ".sabiql/snippets.toml";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SnippetsFile {
+    #[serde(default)]
+    snippet: Vec<SnippetEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SnippetEntry {
+    name: String,
+    sql: String,
+}
+
+pub struct TomlSnippetStore;
+
+impl TomlSnippetStore {
+    fn read(path: &Path) -> Result<Vec<Snippet>, SnippetError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => {
+                return Err(SnippetError::ReadAt {
+                    path: path.to_path_buf(),
+                    source: Arc::new(source),
+                });
+            }
+        };
+        let file: SnippetsFile = toml::from_str(&content).map_err(|e| SnippetError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        let mut snippets: Vec<Snippet> = file
+            .snippet
+            .into_iter()
+            .map(|entry| Snippet {
+                name: entry.name,
+                sql: entry.sql,
+            })
+            .collect();
+        snippets.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(snippets)
+    }
+}
+
+impl SnippetStore for TomlSnippetStore {
+    fn load(&self, root: &Path) -> Result<Vec<Snippet>, SnippetError> {
+        Self::read(&root.join(SNIPPETS_FILE))
+    }
+
+    fn save(&self, root: &Path, snippet: &Snippet) -> Result<Vec<Snippet>, SnippetError> {
+        let path = root.join(SNIPPETS_FILE);
+        let mut snippets = Self::read(&path)?;
+        snippets.retain(|existing| existing.name != snippet.name);
+        snippets.push(snippet.clone());
+        snippets.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let file = SnippetsFile {
+            snippet: snippets
+                .iter()
+                .map(|s| SnippetEntry {
+                    name: s.name.clone(),
+                    sql: s.sql.clone(),
+                })
+                .collect(),
+        };
+        let content = toml::to_string_pretty(&file).map_err(|e| SnippetError::Parse {
+            path: path.clone(),
+            message: e.to_string(),
+        })?;
+        let write_error = |source| SnippetError::WriteAt {
+            path: path.clone(),
+            source: Arc::new(source),
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(write_error)?;
+        }
+        let staging = PathBuf::from(format!("{}.tmp", path.display()));
+        std::fs::write(&staging, content).map_err(write_error)?;
+        std::fs::rename(&staging, &path).map_err(|source| {
+            let _ = std::fs::remove_file(&staging);
+            write_error(source)
+        })?;
+        Ok(snippets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippet(name: &str, sql: &str) -> Snippet {
+        Snippet {
+            name: name.to_string(),
+            sql: sql.to_string(),
+        }
+    }
+
+    #[test]
+    fn missing_file_loads_no_snippets() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        assert!(TomlSnippetStore.load(temp_dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn save_round_trips_sorted_by_name() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+
+        TomlSnippetStore
+            .save(
+                root,
+                &snippet("stuck jobs", "SELECT *\nFROM jobs\nWHERE state = 'stuck';"),
+            )
+            .unwrap();
+        let saved = TomlSnippetStore
+            .save(root, &snippet("active users", "SELECT 1;"))
+            .unwrap();
+
+        assert_eq!(saved, TomlSnippetStore.load(root).unwrap());
+        assert_eq!(saved[0].name, "active users");
+        assert_eq!(saved[1].sql, "SELECT *\nFROM jobs\nWHERE state = 'stuck';");
+    }
+
+    #[test]
+    fn saving_an_existing_name_replaces_it() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        TomlSnippetStore
+            .save(root, &snippet("count", "SELECT 1;"))
+            .unwrap();
+
+        let saved = TomlSnippetStore
+            .save(root, &snippet("count", "SELECT 2;"))
+            .unwrap();
+
+        assert_eq!(saved, vec![snippet("count", "SELECT 2;")]);
+    }
+
+    #[test]
+    fn malformed_file_is_a_parse_error() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(SNIPPETS_FILE);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "[[snippet]]\nname = 1\n").unwrap();
+
+        let result = TomlSnippetStore.load(temp_dir.path());
+
+        assert!(matches!(result, Err(SnippetError::Parse { .. })));
+    }
+}
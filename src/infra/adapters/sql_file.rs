@@ -0,0 +1,184 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::app::ports::outbound::{SqlFile, SqlFileError, SqlFileScanner, SqlFileStore};
+
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules", "vendor"];
+const MAX_FILES: usize = 2000;
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+
+pub struct FsSqlFileScanner;
+
+impl SqlFileScanner for FsSqlFileScanner {
+    fn scan(&self, root: &Path) -> Result<Vec<SqlFile>, SqlFileError> {
+        let mut files = Vec::new();
+        walk(root, root, &mut files)?;
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(files)
+    }
+}
+
+fn walk(root: &Path, dir: &Path, files: &mut Vec<SqlFile>) -> Result<(), SqlFileError> {
+    let scan_error = |source| SqlFileError::ScanAt {
+        path: dir.to_path_buf(),
+        source: Arc::new(source),
+    };
+    let mut entries = std::fs::read_dir(dir)
+        .map_err(scan_error)?
+        .filter_map(Result::ok)
+        .collect::<Vec<_>>();
+    entries.sort_by_key(std::fs::DirEntry::file_name);
+
+    for entry in entries {
+        if files.len() >= MAX_FILES {
+            return Ok(());
+        }
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') {
+            continue;
+        }
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        let path = entry.path();
+        if file_type.is_dir() {
+            if !SKIPPED_DIRS.contains(&name.as_ref()) {
+                walk(root, &path, files)?;
+            }
+            continue;
+        }
+        let is_sql = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("sql"));
+        let fits = entry
+            .metadata()
+            .is_ok_and(|meta| meta.len() <= MAX_FILE_BYTES);
+        if !file_type.is_file() || !is_sql || !fits {
+            continue;
+        }
+        let Ok(bytes) = std::fs::read(&path) else {
+            continue;
+        };
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        files.push(SqlFile {
+            path: relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            content: String::from_utf8_lossy(&bytes).into_owned(),
+        });
+    }
+    Ok(())
+}
+
+pub struct FsSqlFileStore;
+
+impl SqlFileStore for FsSqlFileStore {
+    fn read(&self, path: &Path) -> Result<String, SqlFileError> {
+        let bytes = std::fs::read(path).map_err(|source| SqlFileError::ReadAt {
+            path: path.to_path_buf(),
+            source: Arc::new(source),
+        })?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    // Written beside the target and renamed over it so an editor watching the
+    // same file never sees it half-written.
+    fn write(&self, path: &Path, content: &str) -> Result<(), SqlFileError> {
+        let write_error = |source| SqlFileError::WriteAt {
+            path: path.to_path_buf(),
+            source: Arc::new(source),
+        };
+        let mut staging = path.as_os_str().to_owned();
+        staging.push(".sabiql-tmp");
+        let staging = PathBuf::from(staging);
+        std::fs::write(&staging, content).map_err(write_error)?;
+        std::fs::rename(&staging, path).map_err(|source| {
+            let _ = std::fs::remove_file(&staging);
+            write_error(source)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, relative: &str, content: &str) {
+        let path = root.join(relative);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn lists_sql_files_sorted_with_relative_paths() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        write(root, "migrations/002_orders.sql", "CREATE TABLE orders ();");
+        write(root, "migrations/001_users.SQL", "CREATE TABLE users ();");
+        write(root, "seed.sql", "INSERT INTO users VALUES (1);");
+        write(root, "README.md", "# not sql");
+
+        let files = FsSqlFileScanner.scan(root).unwrap();
+
+        let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "migrations/001_users.SQL",
+                "migrations/002_orders.sql",
+                "seed.sql"
+            ]
+        );
+        assert_eq!(files[2].content, "INSERT INTO users VALUES (1);");
+    }
+
+    #[test]
+    fn skips_hidden_and_build_directories() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        write(root, ".git/hooks/x.sql", "SELECT 1;");
+        write(root, "target/debug/y.sql", "SELECT 1;");
+        write(root, "node_modules/pkg/z.sql", "SELECT 1;");
+        write(root, "db/schema.sql", "SELECT 1;");
+
+        let files = FsSqlFileScanner.scan(root).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "db/schema.sql");
+    }
+
+    #[test]
+    fn missing_root_is_an_error() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let result = FsSqlFileScanner.scan(&temp_dir.path().join("missing"));
+
+        assert!(matches!(result, Err(SqlFileError::ScanAt { .. })));
+    }
+
+    #[test]
+    fn store_round_trips_content() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("report.sql");
+        std::fs::write(&path, "SELECT 1;").unwrap();
+
+        assert_eq!(FsSqlFileStore.read(&path).unwrap(), "SELECT 1;");
+        FsSqlFileStore.write(&path, "SELECT 2;\n").unwrap();
+
+        assert_eq!(FsSqlFileStore.read(&path).unwrap(), "SELECT 2;\n");
+        let leftovers = std::fs::read_dir(temp_dir.path()).unwrap().count();
+        assert_eq!(leftovers, 1);
+    }
+
+    #[test]
+    fn store_read_of_missing_file_is_an_error() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let result = FsSqlFileStore.read(&temp_dir.path().join("missing.sql"));
+
+        assert!(matches!(result, Err(SqlFileError::ReadAt { .. })));
+    }
+}
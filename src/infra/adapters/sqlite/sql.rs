@@ -2,9 +2,13 @@ use std::fmt::Write as _;
 
 use crate::app::policy::sql::sqlite_explain::build_sqlite_explain_query_plan_sql;
 use crate::app::ports::outbound::{
-    DbOperationError, DdlGenerator, SQLITE_TABLE_LIST_REQUIRED_MARKER, SqlDialect,
+    DbOperationError, DdlGenerator, NamingConventions, SQLITE_TABLE_LIST_REQUIRED_MARKER,
+    SqlDialect, preview_where_clause,
+};
+use crate::domain::{
+    Column, DatabaseType, ForeignKey, Index, IndexMaintenanceKind, LockConflict, MaintenanceKind,
+    QueryValue, Table, Trigger,
 };
-use crate::domain::{DatabaseType, QueryValue, Table, Trigger};
 
 use super::SqliteAdapter;
 
@@ -155,6 +159,7 @@ pub(super) fn encode_preview_column_expr(column: &str) -> String {
 pub(super) fn build_preview_query(
     table: &str,
     columns: &[String],
+    filter: Option<&str>,
     order_columns: &[String],
     rowid_order_alias: Option<&str>,
     limit: usize,
@@ -169,22 +174,22 @@ pub(super) fn build_preview_query(
             .collect::<Vec<_>>()
             .join(", ")
     };
-    let order_clause = if order_columns.is_empty() {
-        rowid_order_alias.map_or_else(String::new, |alias| {
-            format!(" ORDER BY {}", quote_ident(alias))
-        })
+    let order_terms = order_columns
+        .iter()
+        .map(String::as_str)
+        .chain(rowid_order_alias)
+        .map(quote_ident)
+        .collect::<Vec<_>>();
+    let order_clause = if order_terms.is_empty() {
+        String::new()
     } else {
-        let cols = order_columns
-            .iter()
-            .map(|col| quote_ident(col))
-            .collect::<Vec<_>>()
-            .join(", ");
-        format!(" ORDER BY {cols}")
+        format!(" ORDER BY {}", order_terms.join(", "))
     };
 
     format!(
-        "SELECT {visible_select_list} FROM {}{} LIMIT {} OFFSET {}",
+        "SELECT {visible_select_list} FROM {}{}{} LIMIT {} OFFSET {}",
         quote_ident(table),
+        preview_where_clause(filter),
         order_clause,
         limit,
         offset
@@ -431,6 +436,42 @@ fn append_trigger_ddls(ddl: &mut String, triggers: &[Trigger]) {
     }
 }
 
+// MATCH SIMPLE: a row with any NULL child column is never an orphan.
+fn fk_orphan_check_sql(foreign_keys: &[ForeignKey]) -> String {
+    foreign_keys
+        .iter()
+        .map(|fk| {
+            let not_null = fk
+                .from_columns
+                .iter()
+                .map(|column| format!("c.{} IS NOT NULL", quote_ident(column)))
+                .collect::<Vec<_>>()
+                .join(" AND ");
+            let matches = fk
+                .from_columns
+                .iter()
+                .zip(&fk.to_columns)
+                .map(|(from, to)| format!("p.{} = c.{}", quote_ident(to), quote_ident(from)))
+                .collect::<Vec<_>>()
+                .join(" AND ");
+            let references = format!("{}({})", fk.to_table, fk.to_columns.join(", "));
+            format!(
+                "SELECT {name} AS constraint_name, {references} AS \"references\", \
+                 count(*) AS orphan_rows, \
+                 CASE WHEN count(*) = 0 THEN 'ok' \
+                 ELSE 'would block ADD/VALIDATE CONSTRAINT' END AS status \
+                 FROM {child} c \
+                 WHERE {not_null} AND NOT EXISTS (SELECT 1 FROM {parent} p WHERE {matches})",
+                name = quote_literal(&fk.name),
+                references = quote_literal(&references),
+                child = quote_ident(&fk.from_table),
+                parent = quote_ident(&fk.to_table),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\nUNION ALL\n")
+}
+
 impl DdlGenerator for SqliteAdapter {
     fn generate_ddl(&self, _database_type: DatabaseType, table: &Table) -> String {
         if let Some(source_ddl) = table.source_ddl() {
@@ -491,6 +532,154 @@ impl SqlDialect for SqliteAdapter {
         None
     }
 
+    fn build_replication_overview_sql(
+        &self,
+        _database_type: DatabaseType,
+        _table: Option<&str>,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_sample_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        table: &str,
+        limit: usize,
+        _row_estimate: Option<i64>,
+    ) -> String {
+        format!(
+            "SELECT * FROM {} ORDER BY random() LIMIT {limit}",
+            quote_ident(table)
+        )
+    }
+
+    fn build_row_count_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        table: &str,
+    ) -> String {
+        format!("SELECT count(*) AS row_count FROM {}", quote_ident(table))
+    }
+
+    fn build_rls_test_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        _table: &str,
+        _role: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_maintenance_sql(
+        &self,
+        _database_type: DatabaseType,
+        _kind: MaintenanceKind,
+        _schema: &str,
+        _table: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_maintenance_progress_sql(
+        &self,
+        _database_type: DatabaseType,
+        _kind: MaintenanceKind,
+        _schema: &str,
+        _table: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_index_maintenance_sql(
+        &self,
+        _database_type: DatabaseType,
+        _kind: IndexMaintenanceKind,
+        _schema: &str,
+        _index: &Index,
+    ) -> Option<Vec<String>> {
+        None
+    }
+
+    fn build_index_maintenance_progress_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        _table: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_autovacuum_report_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        _table: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_table_sizes_sql(
+        &self,
+        _database_type: DatabaseType,
+        _tables: &[(String, String)],
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_server_settings_sql(&self, _database_type: DatabaseType) -> Option<String> {
+        None
+    }
+
+    fn build_index_usage_sql(&self, _database_type: DatabaseType) -> Option<String> {
+        None
+    }
+
+    fn build_fk_orphan_check_sql(
+        &self,
+        _database_type: DatabaseType,
+        foreign_keys: &[ForeignKey],
+    ) -> Option<String> {
+        Some(fk_orphan_check_sql(foreign_keys))
+    }
+
+    fn build_fk_suggestion_sql(&self, _database_type: DatabaseType) -> Option<String> {
+        None
+    }
+
+    fn build_naming_audit_sql(
+        &self,
+        _database_type: DatabaseType,
+        _conventions: &NamingConventions,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_lock_blockers_sql(
+        &self,
+        _database_type: DatabaseType,
+        _conflict: &LockConflict,
+    ) -> Option<String> {
+        None
+    }
+
+    fn build_result_types_sql(&self, _database_type: DatabaseType, _query: &str) -> Option<String> {
+        None
+    }
+
+    fn build_column_distribution_sql(
+        &self,
+        _database_type: DatabaseType,
+        _schema: &str,
+        _table: &str,
+        _column: &Column,
+        _row_estimate: Option<i64>,
+    ) -> Option<String> {
+        None
+    }
+
     fn build_update_sql(
         &self,
         _database_type: DatabaseType,
@@ -542,7 +731,7 @@ mod tests {
     use crate::adapters::test_support;
 
     use super::*;
-    use crate::domain::{Column, ColumnAttributes, Trigger, TriggerEvent, TriggerTiming};
+    use crate::domain::{ColumnAttributes, Trigger, TriggerEvent, TriggerTiming};
 
     fn make_column(name: &str, data_type: &str, nullable: bool) -> Column {
         Column {
@@ -731,6 +920,7 @@ mod tests {
                 build_preview_query(
                     "users",
                     &["id".to_string(), "name".to_string()],
+                    None,
                     &["id".to_string()],
                     None,
                     10,
@@ -746,10 +936,31 @@ mod tests {
             );
         }
 
+        #[test]
+        fn sample_orders_by_random() {
+            let sql = SqliteAdapter::new().build_sample_sql(
+                DatabaseType::SQLite,
+                "main",
+                "users",
+                100,
+                None,
+            );
+
+            assert_eq!(sql, r#"SELECT * FROM "users" ORDER BY random() LIMIT 100"#);
+        }
+
+        #[test]
+        fn row_count_ignores_the_schema() {
+            let sql =
+                SqliteAdapter::new().build_row_count_sql(DatabaseType::SQLite, "main", "users");
+
+            assert_eq!(sql, r#"SELECT count(*) AS row_count FROM "users""#);
+        }
+
         #[test]
         fn falls_back_to_star_without_columns() {
             assert_eq!(
-                build_preview_query("users", &[], &["id".to_string()], None, 10, 20),
+                build_preview_query("users", &[], None, &["id".to_string()], None, 10, 20),
                 r#"SELECT * FROM "users" ORDER BY "id" LIMIT 10 OFFSET 20"#
             );
         }
@@ -757,7 +968,15 @@ mod tests {
         #[test]
         fn primary_keyless_table_orders_by_rowid_without_selecting_it() {
             assert_eq!(
-                build_preview_query("logs", &["message".to_string()], &[], Some("rowid"), 10, 0),
+                build_preview_query(
+                    "logs",
+                    &["message".to_string()],
+                    None,
+                    &[],
+                    Some("rowid"),
+                    10,
+                    0
+                ),
                 concat!(
                     r#"SELECT CASE WHEN typeof("message") = 'text' "#,
                     r#"THEN char(1) || 'SABIQL_HEX:' || hex("message") ELSE "message" END AS "message" "#,
@@ -765,6 +984,38 @@ mod tests {
                 )
             );
         }
+
+        #[test]
+        fn sort_column_keeps_rowid_as_tiebreaker() {
+            assert_eq!(
+                build_preview_query(
+                    "logs",
+                    &[],
+                    None,
+                    &["level".to_string()],
+                    Some("rowid"),
+                    10,
+                    0
+                ),
+                r#"SELECT * FROM "logs" ORDER BY "level", "rowid" LIMIT 10 OFFSET 0"#
+            );
+        }
+
+        #[test]
+        fn filter_applies_before_ordering() {
+            assert_eq!(
+                build_preview_query(
+                    "logs",
+                    &[],
+                    Some("level = 'error'"),
+                    &[],
+                    Some("rowid"),
+                    10,
+                    0
+                ),
+                r#"SELECT * FROM "logs" WHERE (level = 'error') ORDER BY "rowid" LIMIT 10 OFFSET 0"#
+            );
+        }
     }
 
     mod text_literal_encoding {
@@ -934,6 +1185,43 @@ mod tests {
         }
     }
 
+    mod fk_orphan_check_sql {
+        use super::*;
+        use crate::domain::FkAction;
+
+        #[test]
+        fn counts_children_without_parent_row() {
+            let adapter = SqliteAdapter::new();
+            let fk = ForeignKey {
+                name: "fk_orders_0".to_string(),
+                from_schema: "main".to_string(),
+                from_table: "orders".to_string(),
+                from_columns: vec!["user_id".to_string()],
+                to_schema: "main".to_string(),
+                to_table: "users".to_string(),
+                to_columns: vec!["id".to_string()],
+                on_delete: FkAction::SetNull,
+                on_update: FkAction::NoAction,
+                reference_resolved: true,
+            };
+
+            let sql = adapter
+                .build_fk_orphan_check_sql(DatabaseType::SQLite, &[fk])
+                .unwrap();
+
+            assert_eq!(
+                sql,
+                "SELECT 'fk_orders_0' AS constraint_name, 'users(id)' AS \"references\", \
+                 count(*) AS orphan_rows, \
+                 CASE WHEN count(*) = 0 THEN 'ok' \
+                 ELSE 'would block ADD/VALIDATE CONSTRAINT' END AS status \
+                 FROM \"orders\" c \
+                 WHERE c.\"user_id\" IS NOT NULL \
+                 AND NOT EXISTS (SELECT 1 FROM \"users\" p WHERE p.\"id\" = c.\"user_id\")"
+            );
+        }
+    }
+
     mod bulk_delete_sql {
         use super::*;
 
@@ -12,7 +12,9 @@ use async_trait::async_trait;
 use crate::adapters::csv_export::export_to_downloads;
 use crate::app::policy::sql::sqlite_explain::is_sqlite_explain_query_plan_sql;
 use crate::app::ports::outbound::{
-    AccessMode, DatabaseCli, DbOperationError, QueryExecutor, SQLITE_SAFE_MODE_REQUIRED_MARKER,
+    AccessMode, DatabaseCli, DbOperationError, PreviewOptions, QueryExecutor,
+    SQLITE_SAFE_MODE_REQUIRED_MARKER, TransactionEnd, preview_order_columns,
+    project_preview_columns,
 };
 use crate::domain::{
     CommandTag, QueryResult, QuerySource, TableKind, TableKindInfo, WriteExecutionResult,
@@ -600,17 +602,24 @@ impl QueryExecutor for SqliteAdapter {
         dsn: &str,
         schema: &str,
         table: &str,
+        options: PreviewOptions,
         limit: usize,
         offset: usize,
     ) -> Result<QueryResult, DbOperationError> {
         Self::validate_main_schema(schema)?;
         let path = Self::path_from_dsn(dsn)?;
-        let (columns, order_columns, kind_info) = self.preview_metadata(path, table).await?;
-        let rowid_order_alias =
-            Self::preview_rowid_order_alias(&columns, &order_columns, &kind_info);
+        let (columns, key_columns, kind_info) = self.preview_metadata(path, table).await?;
+        // Without a primary key the rowid still breaks ties behind a sort column.
+        let rowid_order_alias = Self::preview_rowid_order_alias(&columns, &key_columns, &kind_info);
+        let order_columns = preview_order_columns(options.sort_column.as_deref(), &key_columns);
+        let mut visible_columns = project_preview_columns(&columns, &options.columns);
+        if visible_columns.is_empty() {
+            visible_columns = columns;
+        }
         let query = sql::build_preview_query(
             table,
-            &columns,
+            &visible_columns,
+            options.filter.as_deref(),
             &order_columns,
             rowid_order_alias,
             limit,
@@ -619,7 +628,7 @@ impl QueryExecutor for SqliteAdapter {
         let result = self
             .execute_quoted_query(path, &query, QuerySource::Preview, true)
             .await?;
-        Ok(result.with_columns_if_empty(columns))
+        Ok(result.with_columns_if_empty(visible_columns))
     }
 
     async fn execute_adhoc(
@@ -732,6 +741,26 @@ impl QueryExecutor for SqliteAdapter {
         })
         .await
     }
+
+    async fn begin_transaction(
+        &self,
+        _dsn: &str,
+        _access_mode: AccessMode,
+    ) -> Result<(), DbOperationError> {
+        Err(DbOperationError::UnsupportedOperation(
+            "Transactions are only available for PostgreSQL connections".to_string(),
+        ))
+    }
+
+    async fn end_transaction(
+        &self,
+        _dsn: &str,
+        _end: TransactionEnd,
+    ) -> Result<(), DbOperationError> {
+        Err(DbOperationError::UnsupportedOperation(
+            "Transactions are only available for PostgreSQL connections".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -811,7 +840,7 @@ mod tests {
             let (adapter, process_counter) = SqliteAdapter::with_process_counter(&dsn);
 
             adapter
-                .execute_preview(&dsn, "main", "users", 10, 0)
+                .execute_preview(&dsn, "main", "users", PreviewOptions::default(), 10, 0)
                 .await
                 .unwrap();
 
@@ -829,7 +858,7 @@ mod tests {
             let adapter = SqliteAdapter::new();
 
             let result = adapter
-                .execute_preview(&dsn, "main", "users", 1, 1)
+                .execute_preview(&dsn, "main", "users", PreviewOptions::default(), 1, 1)
                 .await
                 .unwrap();
 
@@ -841,6 +870,32 @@ mod tests {
             );
         }
 
+        #[tokio::test]
+        async fn selects_only_the_projected_columns() {
+            let (_dir, dsn) = test_support::make_sqlite_db(
+                r"
+            CREATE TABLE users(id INTEGER PRIMARY KEY, name TEXT, avatar BLOB);
+            INSERT INTO users(id, name, avatar) VALUES (1, 'a', x'00');
+            ",
+            );
+            let adapter = SqliteAdapter::new();
+            let options = PreviewOptions {
+                columns: vec!["name".to_string(), "dropped".to_string(), "id".to_string()],
+                ..PreviewOptions::default()
+            };
+
+            let result = adapter
+                .execute_preview(&dsn, "main", "users", options, 10, 0)
+                .await
+                .unwrap();
+
+            assert_eq!(result.columns, vec!["name", "id"]);
+            assert_eq!(
+                display_row(&result, 0),
+                vec!["a".to_string(), "1".to_string()]
+            );
+        }
+
         #[tokio::test]
         async fn primary_keyless_preview_exposes_only_user_columns() {
             let (_dir, dsn) = test_support::make_sqlite_db(
@@ -852,7 +907,7 @@ mod tests {
             let adapter = SqliteAdapter::new();
 
             let result = adapter
-                .execute_preview(&dsn, "main", "logs", 10, 0)
+                .execute_preview(&dsn, "main", "logs", PreviewOptions::default(), 10, 0)
                 .await
                 .unwrap();
 
@@ -869,7 +924,9 @@ mod tests {
                 test_support::make_sqlite_db("CREATE TABLE users(id INTEGER PRIMARY KEY);");
             let adapter = SqliteAdapter::new();
 
-            let result = adapter.execute_preview(&dsn, "other", "users", 10, 0).await;
+            let result = adapter
+                .execute_preview(&dsn, "other", "users", PreviewOptions::default(), 10, 0)
+                .await;
 
             assert!(matches!(result, Err(DbOperationError::ObjectMissing(_))));
         }
@@ -885,7 +942,7 @@ mod tests {
             let adapter = SqliteAdapter::new();
 
             let preview = adapter
-                .execute_preview(&dsn, "main", "users", 10, 0)
+                .execute_preview(&dsn, "main", "users", PreviewOptions::default(), 10, 0)
                 .await
                 .unwrap();
 
@@ -911,7 +968,7 @@ mod tests {
             assert_eq!(write.affected_rows, 1);
 
             let remaining = adapter
-                .execute_preview(&dsn, "main", "users", 10, 0)
+                .execute_preview(&dsn, "main", "users", PreviewOptions::default(), 10, 0)
                 .await
                 .unwrap();
             assert_eq!(remaining.row_count(), 1);
@@ -932,7 +989,7 @@ mod tests {
             let adapter = SqliteAdapter::new();
 
             let result = adapter
-                .execute_preview(&dsn, "main", "notes_fts", 10, 0)
+                .execute_preview(&dsn, "main", "notes_fts", PreviewOptions::default(), 10, 0)
                 .await
                 .unwrap();
 
@@ -950,7 +1007,7 @@ mod tests {
             let adapter = SqliteAdapter::new();
 
             let result = adapter
-                .execute_preview(&dsn, "main", "users", 10, 0)
+                .execute_preview(&dsn, "main", "users", PreviewOptions::default(), 10, 0)
                 .await
                 .unwrap();
 
@@ -971,7 +1028,7 @@ mod tests {
             let adapter = SqliteAdapter::new();
 
             let result = adapter
-                .execute_preview(&dsn, "main", "users", 10, 0)
+                .execute_preview(&dsn, "main", "users", PreviewOptions::default(), 10, 0)
                 .await
                 .unwrap();
 
@@ -996,7 +1053,7 @@ mod tests {
             let adapter = SqliteAdapter::new();
 
             let result = adapter
-                .execute_preview(&dsn, "main", "users", 10, 0)
+                .execute_preview(&dsn, "main", "users", PreviewOptions::default(), 10, 0)
                 .await
                 .unwrap();
 
@@ -1024,7 +1081,7 @@ mod tests {
             let adapter = SqliteAdapter::new();
 
             let result = adapter
-                .execute_preview(&dsn, "main", "users", 10, 0)
+                .execute_preview(&dsn, "main", "users", PreviewOptions::default(), 10, 0)
                 .await
                 .unwrap();
 
@@ -2991,7 +3048,7 @@ world'), (2, 'done');
                     .unwrap();
                 let metadata = adapter.fetch_metadata(&dsn).await.unwrap();
                 let preview = adapter
-                    .execute_preview(&dsn, "main", "users", 10, 0)
+                    .execute_preview(&dsn, "main", "users", PreviewOptions::default(), 10, 0)
                     .await
                     .unwrap();
                 let diagnostics = adapter.fetch_diagnostics_core(&dsn).await.unwrap();
@@ -534,6 +534,9 @@ impl SqliteAdapter {
                 None
             },
             kind_info,
+            hypertable: None,
+            column_stats: Vec::new(),
+            storage: None,
         })
     }
 
@@ -0,0 +1,151 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::app::ports::outbound::{TempFileError, TempFileWriter};
+use crate::config::cache::get_spill_dir;
+use crate::config::cache_cipher::{CacheCipher, seal_if};
+
+#[derive(Default)]
+pub struct FsTempFileWriter {
+    base_dir: Option<PathBuf>,
+    cipher: Option<Arc<CacheCipher>>,
+    key_unavailable: bool,
+}
+
+impl FsTempFileWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seals every spilled file.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Option<Arc<CacheCipher>>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    /// Spill encryption is on without a key: fail instead of writing plain
+    /// text.
+    #[must_use]
+    pub fn with_key_unavailable(mut self, key_unavailable: bool) -> Self {
+        self.key_unavailable = key_unavailable;
+        self
+    }
+
+    #[cfg(test)]
+    fn with_base_dir(base_dir: PathBuf) -> Self {
+        Self {
+            base_dir: Some(base_dir),
+            ..Self::default()
+        }
+    }
+}
+
+impl TempFileWriter for FsTempFileWriter {
+    fn write_temp_file(&self, file_name: &str, content: &str) -> Result<PathBuf, TempFileError> {
+        if self.key_unavailable {
+            return Err(TempFileError::KeyUnavailable);
+        }
+        let dir = match &self.base_dir {
+            Some(base) => base.clone(),
+            None => get_spill_dir().map_err(std::io::Error::other)?,
+        };
+        let content = seal_if(self.cipher.as_deref(), content.to_string());
+        write_temp_file_in(&dir, file_name, &content)
+    }
+}
+
+// The random name and owner-only mode keep other local users from guessing
+// or reading the spilled result.
+fn write_temp_file_in(
+    dir: &Path,
+    file_name: &str,
+    content: &str,
+) -> Result<PathBuf, TempFileError> {
+    std::fs::create_dir_all(dir)?;
+    let suffix = format!("_{file_name}");
+    let mut builder = tempfile::Builder::new();
+    builder.suffix(&suffix);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(std::fs::Permissions::from_mode(0o600));
+    }
+    let mut file = builder.tempfile_in(dir)?;
+    file.write_all(content.as_bytes())?;
+    let (_, path) = file.keep().map_err(|e| e.error)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::cache_cipher::unseal;
+
+    #[test]
+    fn writes_content_under_unique_name() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let path = write_temp_file_in(temp_dir.path(), "result.csv", "id\n1\n").unwrap();
+        let other = write_temp_file_in(temp_dir.path(), "result.csv", "id\n2\n").unwrap();
+
+        assert!(path.starts_with(temp_dir.path()));
+        assert!(
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with("_result.csv"))
+        );
+        assert_ne!(path, other);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "id\n1\n");
+    }
+
+    #[test]
+    fn creates_missing_directory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let nested = temp_dir.path().join("nested");
+
+        let path = write_temp_file_in(&nested, "result.md", "| a |").unwrap();
+
+        assert!(path.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn spilled_file_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let path = write_temp_file_in(temp_dir.path(), "result.csv", "secret").unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn cipher_seals_spilled_content() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cipher = Arc::new(CacheCipher::for_spills(&[9; 32]));
+        let writer = FsTempFileWriter::with_base_dir(temp_dir.path().to_path_buf())
+            .with_cipher(Some(Arc::clone(&cipher)));
+
+        let path = writer.write_temp_file("result.csv", "secret").unwrap();
+
+        let stored = std::fs::read_to_string(&path).unwrap();
+        assert!(!stored.contains("secret"));
+        assert_eq!(unseal(Some(&cipher), &stored).as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn missing_key_refuses_to_spill() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let writer = FsTempFileWriter::with_base_dir(temp_dir.path().to_path_buf())
+            .with_key_unavailable(true);
+
+        let result = writer.write_temp_file("result.csv", "secret");
+
+        assert!(matches!(result, Err(TempFileError::KeyUnavailable)));
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+}
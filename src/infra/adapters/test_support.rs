@@ -18,6 +18,9 @@ pub(super) fn minimal_table(schema: impl Into<String>, name: impl Into<String>)
         comment: None,
         source_ddl: None,
         kind_info: TableKindInfo::default(),
+        hypertable: None,
+        column_stats: Vec::new(),
+        storage: None,
     }
 }
 
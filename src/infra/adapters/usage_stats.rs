@@ -0,0 +1,126 @@
+use std::path::PathBuf;
+
+use crate::app::ports::outbound::{UsageStatsError, UsageStatsStore};
+use crate::config::cache::{CacheDirError, CacheWrites, ReadOnlyCache, get_cache_dir};
+use crate::domain::UsageStats;
+
+const USAGE_FILE_NAME: &str = "usage.json";
+
+impl From<CacheDirError> for UsageStatsError {
+    fn from(error: CacheDirError) -> Self {
+        match error {
+            CacheDirError::BaseDirUnavailable => Self::MissingCacheDir,
+            CacheDirError::Io(error) => error.into(),
+        }
+    }
+}
+
+pub struct FileUsageStatsStore {
+    base_dir: Option<PathBuf>,
+    writes: CacheWrites,
+}
+
+impl ReadOnlyCache for FileUsageStatsStore {
+    fn cache_writes_mut(&mut self) -> &mut CacheWrites {
+        &mut self.writes
+    }
+}
+
+impl Default for FileUsageStatsStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileUsageStatsStore {
+    pub fn new() -> Self {
+        Self {
+            base_dir: None,
+            writes: CacheWrites::default(),
+        }
+    }
+
+    #[cfg(test)]
+    fn with_base_dir(base_dir: PathBuf) -> Self {
+        Self {
+            base_dir: Some(base_dir),
+            writes: CacheWrites::default(),
+        }
+    }
+
+    fn resolve_path(&self, project_name: &str) -> Result<PathBuf, UsageStatsError> {
+        let dir = match &self.base_dir {
+            Some(base) => base.clone(),
+            None => get_cache_dir(project_name)?,
+        };
+        Ok(dir.join(USAGE_FILE_NAME))
+    }
+}
+
+impl UsageStatsStore for FileUsageStatsStore {
+    fn load(&self, project_name: &str) -> Result<UsageStats, UsageStatsError> {
+        let path = self.resolve_path(project_name)?;
+        if !path.exists() {
+            return Ok(UsageStats::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    fn save(&self, project_name: &str, stats: &UsageStats) -> Result<(), UsageStatsError> {
+        if self.writes.dropped() {
+            return Ok(());
+        }
+        let path = self.resolve_path(project_name)?;
+        if let Some(dir) = path.parent()
+            && !dir.exists()
+        {
+            std::fs::create_dir_all(dir)?;
+        }
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_string(stats)?)?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::UsageKind;
+    use tempfile::TempDir;
+
+    #[test]
+    fn missing_file_loads_empty_stats() {
+        let tmp = TempDir::new().unwrap();
+        let store = FileUsageStatsStore::with_base_dir(tmp.path().to_path_buf());
+
+        assert!(store.load("test").unwrap().is_empty());
+    }
+
+    #[test]
+    fn save_and_load_round_trips_counts() {
+        let tmp = TempDir::new().unwrap();
+        let store = FileUsageStatsStore::with_base_dir(tmp.path().to_path_buf());
+        let mut stats = UsageStats::default();
+        stats.record(UsageKind::Pane, "Result");
+        stats.record(UsageKind::Command, ":sql");
+
+        store.save("test", &stats).unwrap();
+
+        assert_eq!(store.load("test").unwrap(), stats);
+        assert!(!tmp.path().join("usage.json.tmp").exists());
+    }
+
+    #[test]
+    fn corrupt_file_returns_error() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join(USAGE_FILE_NAME), "{not json").unwrap();
+        let store = FileUsageStatsStore::with_base_dir(tmp.path().to_path_buf());
+
+        assert!(matches!(
+            store.load("test"),
+            Err(UsageStatsError::Serialization(_))
+        ));
+    }
+}
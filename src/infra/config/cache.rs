@@ -26,3 +26,61 @@ pub fn get_cache_dir(project_name: &str) -> Result<PathBuf, CacheDirError> {
 
     Ok(cache_dir)
 }
+
+/// Whether a cache store may write to disk; see [`ReadOnlyCache`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CacheWrites {
+    dropped: bool,
+}
+
+impl CacheWrites {
+    /// Writes should return `Ok(())` without touching disk.
+    pub fn dropped(self) -> bool {
+        self.dropped
+    }
+}
+
+/// A file-backed cache store that can be opened read-only.
+///
+/// While another instance owns the project's caches (or the key sealing them
+/// is unavailable), the store keeps loading what is on disk but drops every
+/// write, so two instances never interleave writes to the same files.
+pub trait ReadOnlyCache: Sized {
+    fn cache_writes_mut(&mut self) -> &mut CacheWrites;
+
+    #[must_use]
+    fn with_read_only(mut self, read_only: bool) -> Self {
+        self.cache_writes_mut().dropped = read_only;
+        self
+    }
+}
+
+/// Checks for a project's cache directory without creating it.
+pub fn cache_dir_exists(project_name: &str) -> bool {
+    dirs::cache_dir().is_some_and(|base| base.join("sabiql").join(project_name).is_dir())
+}
+
+/// Spill files hold copied result data, so they live under the user's cache
+/// directory instead of the shared system temp directory.
+pub fn get_spill_dir() -> Result<PathBuf, CacheDirError> {
+    let cache_base = dirs::cache_dir().ok_or(CacheDirError::BaseDirUnavailable)?;
+    let spill_dir = cache_base.join("sabiql").join("spill");
+
+    if !spill_dir.exists() {
+        fs::create_dir_all(&spill_dir)?;
+    }
+
+    Ok(spill_dir)
+}
+
+/// Crash reports are not tied to a project: a panic can happen before one is resolved.
+pub fn get_crash_report_dir() -> Result<PathBuf, CacheDirError> {
+    let cache_base = dirs::cache_dir().ok_or(CacheDirError::BaseDirUnavailable)?;
+    let crash_dir = cache_base.join("sabiql").join("crash-reports");
+
+    if !crash_dir.exists() {
+        fs::create_dir_all(&crash_dir)?;
+    }
+
+    Ok(crash_dir)
+}
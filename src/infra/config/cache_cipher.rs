@@ -0,0 +1,181 @@
+use std::borrow::Cow;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+use crate::app::ports::outbound::CacheCategory;
+
+const KEYCHAIN_SERVICE: &str = "sabiql";
+const KEYCHAIN_USER: &str = "cache-key";
+const SECRET_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const SPILL_KEY_NAME: &str = "spill";
+
+/// Marks a sealed value; anything without it is read as plain text, so
+/// files written before encryption was turned on stay readable.
+const SEALED_PREFIX: &str = "sabiql-enc1:";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CacheKeyError {
+    #[error("OS keychain is unavailable: {0}")]
+    Keychain(#[from] keyring::Error),
+    #[error("cache key in the OS keychain is malformed")]
+    MalformedKey,
+}
+
+/// Reads the cache master secret from the OS keychain, creating it on first
+/// use. It is shared by every project; categories derive their own keys.
+pub fn load_or_create_cache_secret() -> Result<Vec<u8>, CacheKeyError> {
+    let entry = keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_USER)?;
+    match entry.get_secret() {
+        Ok(secret) if secret.len() == SECRET_LEN => Ok(secret),
+        Ok(_) => Err(CacheKeyError::MalformedKey),
+        Err(keyring::Error::NoEntry) => {
+            let secret = Aes256Gcm::generate_key(OsRng).to_vec();
+            entry.set_secret(&secret)?;
+            Ok(secret)
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// AES-256-GCM over one cache category's files.
+pub struct CacheCipher {
+    cipher: Aes256Gcm,
+}
+
+impl CacheCipher {
+    pub fn new(secret: &[u8], category: CacheCategory) -> Self {
+        Self::derive(secret, category.name())
+    }
+
+    /// Spilled clipboard copies and cached-result exports live outside the
+    /// project cache, so they get a key of their own.
+    pub fn for_spills(secret: &[u8]) -> Self {
+        Self::derive(secret, SPILL_KEY_NAME)
+    }
+
+    fn derive(secret: &[u8], name: &str) -> Self {
+        let mut key = Key::<Aes256Gcm>::default();
+        let info = format!("sabiql cache {name}");
+        Hkdf::<Sha256>::new(None, secret)
+            .expand(info.as_bytes(), &mut key)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        Self {
+            cipher: Aes256Gcm::new(&key),
+        }
+    }
+
+    /// Single-line output, so sealed history entries stay one per line.
+    pub fn seal(&self, plaintext: &str) -> String {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .expect("AES-GCM encryption of an in-memory buffer cannot fail");
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        format!("{SEALED_PREFIX}{}", BASE64.encode(sealed))
+    }
+
+    fn open(&self, sealed: &str) -> Option<String> {
+        let bytes = BASE64.decode(sealed.trim_end()).ok()?;
+        if bytes.len() < NONCE_LEN {
+            return None;
+        }
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .ok()?;
+        String::from_utf8(plaintext).ok()
+    }
+}
+
+/// Plain text passes through; sealed text needs the matching cipher and
+/// comes back `None` without it or when it was tampered with.
+pub fn unseal<'a>(cipher: Option<&CacheCipher>, stored: &'a str) -> Option<Cow<'a, str>> {
+    match stored.strip_prefix(SEALED_PREFIX) {
+        Some(sealed) => cipher?.open(sealed).map(Cow::Owned),
+        None => Some(Cow::Borrowed(stored)),
+    }
+}
+
+/// Seals when a cipher is configured, otherwise stores as-is.
+pub fn seal_if(cipher: Option<&CacheCipher>, plaintext: String) -> String {
+    match cipher {
+        Some(cipher) => cipher.seal(&plaintext),
+        None => plaintext,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; SECRET_LEN] = [7; SECRET_LEN];
+
+    #[test]
+    fn sealed_text_round_trips_without_leaking_plaintext() {
+        let cipher = CacheCipher::new(&SECRET, CacheCategory::History);
+
+        let sealed = cipher.seal("SELECT * FROM users WHERE email = 'a@b.c'");
+
+        assert!(sealed.starts_with(SEALED_PREFIX));
+        assert!(!sealed.contains("users"));
+        assert!(!sealed.contains('\n'));
+        assert_eq!(
+            unseal(Some(&cipher), &sealed).as_deref(),
+            Some("SELECT * FROM users WHERE email = 'a@b.c'")
+        );
+    }
+
+    #[test]
+    fn plain_text_passes_through_with_or_without_cipher() {
+        let cipher = CacheCipher::new(&SECRET, CacheCategory::History);
+
+        assert_eq!(
+            unseal(Some(&cipher), "{\"a\":1}").as_deref(),
+            Some("{\"a\":1}")
+        );
+        assert_eq!(unseal(None, "{\"a\":1}").as_deref(), Some("{\"a\":1}"));
+    }
+
+    #[test]
+    fn sealed_text_needs_the_same_category_key() {
+        let history = CacheCipher::new(&SECRET, CacheCategory::History);
+        let snapshots = CacheCipher::new(&SECRET, CacheCategory::Snapshots);
+
+        let sealed = history.seal("secret");
+
+        assert!(unseal(Some(&snapshots), &sealed).is_none());
+        assert!(unseal(None, &sealed).is_none());
+    }
+
+    #[test]
+    fn spill_key_differs_from_every_category_key() {
+        let spills = CacheCipher::for_spills(&SECRET);
+
+        let sealed = spills.seal("id\n1\n");
+
+        for category in CacheCategory::ALL {
+            let other = CacheCipher::new(&SECRET, category);
+            assert!(unseal(Some(&other), &sealed).is_none());
+        }
+        assert_eq!(unseal(Some(&spills), &sealed).as_deref(), Some("id\n1\n"));
+    }
+
+    #[test]
+    fn tampered_text_is_rejected() {
+        let cipher = CacheCipher::new(&SECRET, CacheCategory::History);
+        let mut sealed = cipher.seal("secret");
+        let last = sealed.pop().unwrap();
+        sealed.push(if last == 'A' { 'B' } else { 'A' });
+
+        assert!(unseal(Some(&cipher), &sealed).is_none());
+    }
+}
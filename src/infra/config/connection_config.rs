@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::domain::connection::{
     ConnectionConfig, ConnectionId, ConnectionName, ConnectionProfile, ConnectionProfileError,
-    DatabaseType, PostgresConnectionConfig, SqliteConnectionConfig, SslMode,
+    DEFAULT_POOL_SIZE, DatabaseType, MySqlConnectionConfig, PoolMode, PoolSettings,
+    PostgresConnectionConfig, ProfileAccent, SessionSettings, SqliteConnectionConfig, SslMode,
 };
 
 pub const CURRENT_VERSION: u32 = 3;
@@ -27,9 +28,40 @@ pub struct ConnectionConfigFile {
     pub keymap_preset: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub er_browser: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword_case: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_stats: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_check: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefetch_scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_encryption: Option<CacheEncryptionConfig>,
     pub connections: Vec<ConnectionConfigEntry>,
 }
 
+/// `[cache_encryption]`: cache categories sealed at rest with a key kept in
+/// the OS keychain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEncryptionConfig {
+    #[serde(default)]
+    pub history: bool,
+    #[serde(default)]
+    pub snapshots: bool,
+    /// Large `:copy` results spilled to a file and cached-result CSV exports.
+    #[serde(default)]
+    pub spill: bool,
+}
+
+impl CacheEncryptionConfig {
+    pub fn any(self) -> bool {
+        self.history || self.snapshots || self.spill
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConnectionConfigEntry {
     pub id: String,
@@ -50,6 +82,20 @@ pub struct ConnectionConfigEntry {
     pub ssl_mode: Option<SslMode>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replica_dsn: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_mode: Option<PoolMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_warmup: Option<usize>,
+    #[serde(default, skip_serializing_if = "SessionSettings::is_empty")]
+    pub session_settings: SessionSettings,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<ProfileAccent>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub production: bool,
 }
 
 impl From<&[ConnectionProfile]> for ConnectionConfigFile {
@@ -59,6 +105,12 @@ impl From<&[ConnectionProfile]> for ConnectionConfigFile {
             theme: None,
             keymap_preset: None,
             er_browser: None,
+            keyword_case: None,
+            locale: None,
+            usage_stats: None,
+            update_check: None,
+            prefetch_scope: None,
+            cache_encryption: None,
             connections: profiles.iter().map(ConnectionConfigEntry::from).collect(),
         }
     }
@@ -89,6 +141,13 @@ impl From<&ConnectionProfile> for ConnectionConfigEntry {
             password: None,
             ssl_mode: None,
             path: None,
+            replica_dsn: profile.replica_dsn.clone(),
+            pool_mode: profile.pool_mode,
+            pool_size: (profile.pool.size != DEFAULT_POOL_SIZE).then_some(profile.pool.size),
+            pool_warmup: (profile.pool.warmup > 0).then_some(profile.pool.warmup),
+            session_settings: profile.session_settings.clone(),
+            accent: profile.accent,
+            production: profile.production,
         };
         match &profile.config {
             ConnectionConfig::PostgreSQL(config) => {
@@ -102,6 +161,13 @@ impl From<&ConnectionProfile> for ConnectionConfigEntry {
             ConnectionConfig::SQLite(config) => {
                 entry.path = Some(config.path().to_string());
             }
+            ConnectionConfig::MySQL(config) => {
+                entry.host = Some(config.host.clone());
+                entry.port = Some(config.port);
+                entry.database = Some(config.database.clone());
+                entry.username = Some(config.username.clone());
+                entry.password = Some(config.password.clone());
+            }
         }
         entry
     }
@@ -113,15 +179,15 @@ impl TryFrom<&ConnectionConfigEntry> for ConnectionProfile {
     fn try_from(entry: &ConnectionConfigEntry) -> Result<Self, Self::Error> {
         let id = ConnectionId::from_string(&entry.id);
         let name = ConnectionName::new(&entry.name)?;
-        match entry.db_type {
+        let profile = match entry.db_type {
             DatabaseType::PostgreSQL => Self::with_id_and_config(
                 id,
                 name.as_str().to_string(),
                 ConnectionConfig::PostgreSQL(PostgresConnectionConfig::new(
-                    optional_postgres_field(entry.host.as_ref()),
+                    optional_field(entry.host.as_ref()),
                     entry.port.unwrap_or(5432),
                     required_postgres_field(entry.database.as_ref(), "database")?,
-                    optional_postgres_field(entry.username.as_ref()),
+                    optional_field(entry.username.as_ref()),
                     match &entry.password {
                         Some(password) => password.clone(),
                         None => String::new(),
@@ -136,7 +202,25 @@ impl TryFrom<&ConnectionConfigEntry> for ConnectionProfile {
                     entry.path.as_ref(),
                 )?)?),
             ),
-        }
+            DatabaseType::MySQL => Self::with_id_and_config(
+                id,
+                name.as_str().to_string(),
+                ConnectionConfig::MySQL(MySqlConnectionConfig::new(
+                    optional_field(entry.host.as_ref()),
+                    entry.port.unwrap_or(3306),
+                    required_mysql_field(entry.database.as_ref(), "database")?,
+                    optional_field(entry.username.as_ref()),
+                    optional_field(entry.password.as_ref()),
+                )),
+            ),
+        }?;
+        Ok(profile
+            .with_replica_dsn(entry.replica_dsn.clone())
+            .with_pool_mode(entry.pool_mode)
+            .with_pool(PoolSettings::new(entry.pool_size, entry.pool_warmup))
+            .with_session_settings(entry.session_settings.clone())
+            .with_accent(entry.accent)
+            .with_production(entry.production))
     }
 }
 
@@ -157,13 +241,25 @@ fn required_postgres_field(
     Ok(value.clone())
 }
 
-fn optional_postgres_field(value: Option<&String>) -> String {
+fn required_mysql_field(
+    value: Option<&String>,
+    field: &'static str,
+) -> Result<String, ConnectionProfileError> {
+    let value = value.ok_or(ConnectionProfileError::MissingMySqlField(field))?;
+    if value.trim().is_empty() {
+        return Err(ConnectionProfileError::MissingMySqlField(field));
+    }
+    Ok(value.clone())
+}
+
+fn optional_field(value: Option<&String>) -> String {
     value.cloned().unwrap_or_default()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::connection::SessionSettingKey;
 
     #[test]
     fn supported_versions_are_accepted() {
@@ -191,6 +287,13 @@ mod tests {
             password: None,
             ssl_mode: Some(SslMode::Prefer),
             path: None,
+            replica_dsn: None,
+            pool_mode: None,
+            pool_size: None,
+            pool_warmup: None,
+            session_settings: SessionSettings::default(),
+            accent: None,
+            production: false,
         }
     }
 
@@ -206,6 +309,13 @@ mod tests {
             password: None,
             ssl_mode: None,
             path: path.map(str::to_string),
+            replica_dsn: None,
+            pool_mode: None,
+            pool_size: None,
+            pool_warmup: None,
+            session_settings: SessionSettings::default(),
+            accent: None,
+            production: false,
         }
     }
 
@@ -222,6 +332,85 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn replica_dsn_round_trips_through_entry() {
+        let mut entry = postgres_entry();
+        entry.replica_dsn = Some("postgres://replica/app".to_string());
+
+        let profile = ConnectionProfile::try_from(&entry).unwrap();
+        let saved = ConnectionConfigEntry::from(&profile);
+
+        assert_eq!(profile.replica_dsn(), Some("postgres://replica/app"));
+        assert_eq!(saved.replica_dsn.as_deref(), Some("postgres://replica/app"));
+    }
+
+    #[test]
+    fn pool_settings_round_trip_through_entry() {
+        let mut entry = postgres_entry();
+        entry.pool_size = Some(8);
+        entry.pool_warmup = Some(2);
+
+        let profile = ConnectionProfile::try_from(&entry).unwrap();
+        let saved = ConnectionConfigEntry::from(&profile);
+
+        assert_eq!(profile.pool(), Some(PoolSettings::new(Some(8), Some(2))));
+        assert_eq!((saved.pool_size, saved.pool_warmup), (Some(8), Some(2)));
+    }
+
+    #[test]
+    fn default_pool_settings_stay_out_of_the_entry() {
+        let profile = ConnectionProfile::try_from(&postgres_entry()).unwrap();
+        let saved = ConnectionConfigEntry::from(&profile);
+
+        assert_eq!((saved.pool_size, saved.pool_warmup), (None, None));
+    }
+
+    #[test]
+    fn pool_mode_round_trips_through_entry() {
+        let mut entry = postgres_entry();
+        entry.pool_mode = Some(PoolMode::Transaction);
+
+        let profile = ConnectionProfile::try_from(&entry).unwrap();
+        let saved = ConnectionConfigEntry::from(&profile);
+
+        assert_eq!(profile.pool_mode(), Some(PoolMode::Transaction));
+        assert_eq!(saved.pool_mode, Some(PoolMode::Transaction));
+    }
+
+    #[test]
+    fn session_settings_round_trip_through_entry() {
+        let mut entry = postgres_entry();
+        entry
+            .session_settings
+            .set(SessionSettingKey::ApplicationName, "sabiql");
+
+        let profile = ConnectionProfile::try_from(&entry).unwrap();
+        let saved = ConnectionConfigEntry::from(&profile);
+
+        assert_eq!(
+            profile
+                .session_settings()
+                .and_then(|s| s.get(SessionSettingKey::ApplicationName)),
+            Some("sabiql")
+        );
+        assert_eq!(saved.session_settings, entry.session_settings);
+    }
+
+    #[test]
+    fn accent_and_production_round_trip_through_entry() {
+        let mut entry = postgres_entry();
+        entry.accent = Some(ProfileAccent::Magenta);
+        entry.production = true;
+
+        let profile = ConnectionProfile::try_from(&entry).unwrap();
+        let saved = ConnectionConfigEntry::from(&profile);
+
+        assert_eq!(profile.accent(), Some(ProfileAccent::Magenta));
+        assert!(profile.is_production());
+        assert_eq!(saved.accent, Some(ProfileAccent::Magenta));
+        assert!(saved.production);
+    }
+
     #[test]
     fn v2_entry_defaults_to_postgres() {
         let entry: ConnectionConfigEntry = serde_json::from_str(
@@ -243,6 +432,43 @@ mod tests {
         assert_eq!(profile.database_type(), DatabaseType::PostgreSQL);
     }
 
+    #[test]
+    fn mysql_entry_defaults_port_and_round_trips() {
+        let entry: ConnectionConfigEntry = serde_json::from_str(
+            r#"{
+                "id": "mysql-id",
+                "name": "Shop",
+                "db_type": "mysql",
+                "host": "localhost",
+                "database": "shop",
+                "username": "root"
+            }"#,
+        )
+        .unwrap();
+
+        let profile = ConnectionProfile::try_from(&entry).unwrap();
+        let saved = ConnectionConfigEntry::from(&profile);
+
+        assert_eq!(profile.database_type(), DatabaseType::MySQL);
+        assert_eq!(saved.port, Some(3306));
+        assert_eq!(saved.database.as_deref(), Some("shop"));
+        assert_eq!(saved.ssl_mode, None);
+    }
+
+    #[test]
+    fn mysql_entry_rejects_missing_database() {
+        let mut entry = postgres_entry();
+        entry.db_type = DatabaseType::MySQL;
+        entry.database = Some("  ".to_string());
+
+        let result = ConnectionProfile::try_from(&entry);
+
+        assert!(matches!(
+            result,
+            Err(ConnectionProfileError::MissingMySqlField("database"))
+        ));
+    }
+
     #[test]
     fn sqlite_entry_rejects_missing_path() {
         let entry = sqlite_entry(None);
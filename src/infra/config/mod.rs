@@ -1,3 +1,5 @@
 pub mod cache;
+pub mod cache_cipher;
 pub mod connection_config;
 pub mod project_root;
+pub mod session_lock;
@@ -1,15 +1,65 @@
 use std::env;
-use std::io;
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 
-pub fn find_project_root() -> io::Result<PathBuf> {
-    let cwd = env::current_dir()?;
+use sha2::{Digest, Sha256};
 
-    if let Some(root) = find_dir_upward(&cwd, ".git") {
-        return Ok(root);
+use super::cache::cache_dir_exists;
+
+/// Cache namespace when not even the working directory can be resolved.
+pub const GLOBAL_PROJECT_NAME: &str = "global";
+
+/// Where sabiql was launched from and the name its caches are kept under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectContext {
+    /// The enclosing git checkout; `None` outside one, like psql run from `$HOME`.
+    pub root: Option<PathBuf>,
+    /// Where `.sabiql.toml` is read from: the checkout root, else the working directory.
+    pub config_dir: Option<PathBuf>,
+    pub name: String,
+}
+
+impl ProjectContext {
+    pub fn detect() -> Self {
+        let cwd = env::current_dir().ok();
+        let root = cwd.as_deref().and_then(|cwd| find_dir_upward(cwd, ".git"));
+        Self::resolve(root, cwd.as_deref(), cache_dir_exists)
     }
 
-    Ok(cwd)
+    fn resolve(
+        root: Option<PathBuf>,
+        cwd: Option<&Path>,
+        has_cache: impl Fn(&str) -> bool,
+    ) -> Self {
+        let name = match (&root, cwd) {
+            (Some(root), _) => get_project_name(root),
+            (None, Some(cwd)) => cwd_namespace(cwd, has_cache),
+            (None, None) => GLOBAL_PROJECT_NAME.to_string(),
+        };
+        let config_dir = root.clone().or_else(|| cwd.map(Path::to_path_buf));
+        Self {
+            root,
+            config_dir,
+            name,
+        }
+    }
+}
+
+/// Scratch directories often share a basename, so the path digest keeps their
+/// caches apart. Directories that already have caches under the bare basename
+/// keep using them, so history and snapshots from older versions stay put.
+fn cwd_namespace(cwd: &Path, has_cache: impl Fn(&str) -> bool) -> String {
+    let legacy = get_project_name(cwd);
+    if has_cache(&legacy) {
+        return legacy;
+    }
+    let digest = Sha256::digest(cwd.as_os_str().as_encoded_bytes());
+    let mut name = legacy;
+    name.push('-');
+    for byte in &digest[..4] {
+        let _ = write!(name, "{byte:02x}");
+    }
+    name
 }
 
 fn find_dir_upward(start: &Path, dirname: &str) -> Option<PathBuf> {
@@ -76,6 +126,63 @@ mod tests {
         }
     }
 
+    mod project_context_tests {
+        use super::*;
+
+        fn no_cache(_: &str) -> bool {
+            false
+        }
+
+        #[test]
+        fn git_checkout_is_named_after_its_root() {
+            let context = ProjectContext::resolve(
+                Some(PathBuf::from("/work/app")),
+                Some(Path::new("/work/app/src")),
+                no_cache,
+            );
+
+            assert_eq!(context.root, Some(PathBuf::from("/work/app")));
+            assert_eq!(context.config_dir, Some(PathBuf::from("/work/app")));
+            assert_eq!(context.name, "app");
+        }
+
+        #[test]
+        fn directory_outside_a_checkout_gets_a_path_keyed_name() {
+            let scratch = ProjectContext::resolve(None, Some(Path::new("/tmp/scratch")), no_cache);
+            let other =
+                ProjectContext::resolve(None, Some(Path::new("/home/me/scratch")), no_cache);
+
+            assert_eq!(scratch.root, None);
+            assert_eq!(scratch.config_dir, Some(PathBuf::from("/tmp/scratch")));
+            assert!(scratch.name.starts_with("scratch-"));
+            assert_eq!(scratch.name.len(), "scratch-".len() + 8);
+            assert_ne!(scratch.name, other.name);
+            assert_eq!(
+                scratch,
+                ProjectContext::resolve(None, Some(Path::new("/tmp/scratch")), no_cache)
+            );
+        }
+
+        #[test]
+        fn existing_basename_cache_keeps_its_name() {
+            let context =
+                ProjectContext::resolve(None, Some(Path::new("/home/me/scratch")), |name| {
+                    name == "scratch"
+                });
+
+            assert_eq!(context.name, "scratch");
+        }
+
+        #[test]
+        fn unknown_working_directory_uses_the_global_namespace() {
+            let context = ProjectContext::resolve(None, None, no_cache);
+
+            assert_eq!(context.root, None);
+            assert_eq!(context.config_dir, None);
+            assert_eq!(context.name, GLOBAL_PROJECT_NAME);
+        }
+    }
+
     mod get_project_name_tests {
         use super::*;
 
@@ -0,0 +1,190 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::cache::{CacheDirError, get_cache_dir};
+
+const LOCK_FILE_NAME: &str = "session.lock";
+
+/// Who owns a project's cache directory (history, snapshots, usage stats).
+#[derive(Debug)]
+pub enum SessionLock {
+    /// This process writes the caches; the lock file is removed on drop.
+    Owned(OwnedLock),
+    /// Another live instance owns them; this one should leave them alone.
+    Shared { owner_pid: u32, lock_path: PathBuf },
+}
+
+impl SessionLock {
+    pub fn owner_pid(&self) -> Option<u32> {
+        match self {
+            Self::Owned(_) => None,
+            Self::Shared { owner_pid, .. } => Some(*owner_pid),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct OwnedLock {
+    path: PathBuf,
+    pid: u32,
+}
+
+impl Drop for OwnedLock {
+    fn drop(&mut self) {
+        // A lock taken over after being judged stale is no longer ours.
+        if read_owner_pid(&self.path) == Some(self.pid) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+pub fn acquire_session_lock(project_name: &str) -> Result<SessionLock, CacheDirError> {
+    let dir = get_cache_dir(project_name)?;
+    Ok(acquire_in(&dir, std::process::id(), process_is_alive)?)
+}
+
+fn acquire_in(dir: &Path, pid: u32, is_alive: impl Fn(u32) -> bool) -> io::Result<SessionLock> {
+    let path = dir.join(LOCK_FILE_NAME);
+    // One retry: the first attempt may only clear a stale lock.
+    for _ in 0..2 {
+        if create_with_pid(dir, &path, pid)? {
+            return Ok(SessionLock::Owned(OwnedLock { path, pid }));
+        }
+        match read_owner_pid(&path) {
+            Some(owner_pid) if owner_pid != pid && is_alive(owner_pid) => {
+                return Ok(SessionLock::Shared {
+                    owner_pid,
+                    lock_path: path,
+                });
+            }
+            _ => match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(error) => return Err(error),
+            },
+        }
+    }
+    Err(io::Error::other(format!(
+        "could not take {}: another instance keeps replacing it",
+        path.display()
+    )))
+}
+
+// Linking a fully written temp file publishes the PID atomically, so a
+// racing instance never reads a half-written lock.
+fn create_with_pid(dir: &Path, path: &Path, pid: u32) -> io::Result<bool> {
+    let tmp_path = dir.join(format!("{LOCK_FILE_NAME}.{pid}.tmp"));
+    fs::write(&tmp_path, pid.to_string())?;
+    let linked = fs::hard_link(&tmp_path, path);
+    let _ = fs::remove_file(&tmp_path);
+    match linked {
+        Ok(()) => Ok(true),
+        Err(error) if error.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+fn read_owner_pid(path: &Path) -> Option<u32> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+// Without a way to ask, assume the owner is alive: a needlessly read-only
+// session is better than two writers.
+#[cfg(unix)]
+fn process_is_alive(pid: u32) -> bool {
+    use rustix::io::Errno;
+    use rustix::process::{Pid, test_kill_process};
+
+    let Some(pid) = i32::try_from(pid).ok().and_then(Pid::from_raw) else {
+        return false;
+    };
+    // EPERM means the process exists but belongs to someone else.
+    !matches!(test_kill_process(pid), Err(Errno::SRCH))
+}
+
+#[cfg(windows)]
+fn process_is_alive(pid: u32) -> bool {
+    std::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {pid}"), "/NH", "/FO", "CSV"])
+        .output()
+        .ok()
+        .is_none_or(|output| {
+            String::from_utf8_lossy(&output.stdout).contains(&format!("\"{pid}\""))
+        })
+}
+
+#[cfg(not(any(unix, windows)))]
+fn process_is_alive(_pid: u32) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_instance_owns_the_lock_until_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let lock = acquire_in(dir.path(), 100, |_| true).unwrap();
+
+        assert!(matches!(lock, SessionLock::Owned(_)));
+        assert_eq!(read_owner_pid(&dir.path().join(LOCK_FILE_NAME)), Some(100));
+        drop(lock);
+        assert!(!dir.path().join(LOCK_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn second_instance_shares_while_owner_is_alive() {
+        let dir = tempfile::tempdir().unwrap();
+        let _owner = acquire_in(dir.path(), 100, |_| true).unwrap();
+
+        let second = acquire_in(dir.path(), 200, |pid| pid == 100).unwrap();
+
+        assert_eq!(second.owner_pid(), Some(100));
+        drop(second);
+        assert!(dir.path().join(LOCK_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn stale_lock_from_dead_process_is_taken_over() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(LOCK_FILE_NAME), "100").unwrap();
+
+        let lock = acquire_in(dir.path(), 200, |_| false).unwrap();
+
+        assert!(matches!(lock, SessionLock::Owned(_)));
+        assert_eq!(read_owner_pid(&dir.path().join(LOCK_FILE_NAME)), Some(200));
+    }
+
+    #[test]
+    fn unreadable_lock_is_treated_as_stale() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(LOCK_FILE_NAME), "garbage").unwrap();
+
+        let lock = acquire_in(dir.path(), 200, |_| true).unwrap();
+
+        assert!(matches!(lock, SessionLock::Owned(_)));
+    }
+
+    #[test]
+    fn dropping_a_taken_over_lock_leaves_the_new_owner_alone() {
+        let dir = tempfile::tempdir().unwrap();
+        let stale = acquire_in(dir.path(), 100, |_| true).unwrap();
+        let _owner = acquire_in(dir.path(), 200, |_| false).unwrap();
+
+        drop(stale);
+
+        assert_eq!(read_owner_pid(&dir.path().join(LOCK_FILE_NAME)), Some(200));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn liveness_probe_sees_running_and_missing_pids() {
+        assert!(process_is_alive(std::process::id()));
+        // Above every kernel's pid_max, so nothing can own it.
+        assert!(!process_is_alive(i32::MAX.cast_unsigned()));
+        assert!(!process_is_alive(0));
+    }
+}
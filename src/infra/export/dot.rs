@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use crate::app::ports::outbound::{ErDiagramExporter, ErExportResult};
-use crate::domain::ErTableInfo;
+use crate::domain::{ErDiagramOptions, ErTableInfo};
 use crate::export::graphviz::{GraphvizError, GraphvizRunner, ViewerError, ViewerLauncher};
 
 pub struct SystemGraphvizRunner;
@@ -255,6 +255,18 @@ impl<G: GraphvizRunner, V: ViewerLauncher> DotExporter<G, V> {
     }
 }
 
+// Pastel fills that keep black labels readable; schemas beyond the list reuse them.
+const SCHEMA_COLORS: &[&str] = &[
+    "lightblue",
+    "palegreen",
+    "lightgoldenrod1",
+    "lightpink",
+    "plum1",
+    "lightsalmon",
+    "lightcyan",
+    "wheat",
+];
+
 impl<G, V> DotExporter<G, V> {
     fn escape_dot_string(s: &str) -> String {
         s.replace('\\', "\\\\")
@@ -262,7 +274,7 @@ impl<G, V> DotExporter<G, V> {
             .replace('\n', "\\n")
     }
 
-    pub fn generate_full_dot(tables: &[ErTableInfo]) -> String {
+    pub fn generate_full_dot(tables: &[ErTableInfo], options: &ErDiagramOptions) -> String {
         let mut dot = String::new();
         dot.push_str("digraph full_er {\n");
         dot.push_str("    rankdir=LR;\n");
@@ -273,15 +285,33 @@ impl<G, V> DotExporter<G, V> {
         let mut sorted_tables: Vec<_> = tables.iter().collect();
         sorted_tables.sort_by(|a, b| a.qualified_name.cmp(&b.qualified_name));
 
+        let mut schemas: Vec<&str> = sorted_tables.iter().map(|t| t.schema.as_str()).collect();
+        schemas.sort_unstable();
+        schemas.dedup();
+        let collapsed = options.collapses(sorted_tables.len());
+
         for table in &sorted_tables {
             let full_name = Self::escape_dot_string(&table.qualified_name);
             let table_name = Self::escape_dot_string(&table.name);
             let schema_name = Self::escape_dot_string(&table.schema);
-
-            let _ = writeln!(
-                dot,
-                "    \"{full_name}\" [label=\"{table_name}\\n({schema_name})\" style=filled fillcolor=lightblue];"
-            );
+            let fill = if options.color_by_schema {
+                let index = schemas.binary_search(&table.schema.as_str()).unwrap_or(0);
+                SCHEMA_COLORS[index % SCHEMA_COLORS.len()]
+            } else {
+                "lightblue"
+            };
+
+            if collapsed {
+                let _ = writeln!(
+                    dot,
+                    "    \"{full_name}\" [label=\"{table_name}\" tooltip=\"{full_name}\" fontsize=9 height=0.2 style=filled fillcolor={fill}];"
+                );
+            } else {
+                let _ = writeln!(
+                    dot,
+                    "    \"{full_name}\" [label=\"{table_name}\\n({schema_name})\" style=filled fillcolor={fill}];"
+                );
+            }
         }
 
         dot.push('\n');
@@ -289,13 +319,20 @@ impl<G, V> DotExporter<G, V> {
         let mut edges: Vec<_> = sorted_tables
             .iter()
             .flat_map(|table| {
-                table.foreign_keys.iter().map(|fk| {
-                    (
-                        fk.from_qualified.clone(),
-                        fk.to_qualified.clone(),
-                        fk.name.clone(),
-                    )
-                })
+                table
+                    .foreign_keys
+                    .iter()
+                    .filter(|fk| {
+                        !options.hides_edges_of(&fk.from_qualified)
+                            && !options.hides_edges_of(&fk.to_qualified)
+                    })
+                    .map(|fk| {
+                        (
+                            fk.from_qualified.clone(),
+                            fk.to_qualified.clone(),
+                            fk.name.clone(),
+                        )
+                    })
             })
             .collect();
         edges.sort();
@@ -363,11 +400,12 @@ impl<G: GraphvizRunner + 'static, V: ViewerLauncher + 'static> ErDiagramExporter
     fn generate_and_export(
         &self,
         tables: &[ErTableInfo],
+        options: &ErDiagramOptions,
         filename: &str,
         cache_dir: &Path,
         browser: Option<&str>,
     ) -> ErExportResult<PathBuf> {
-        let dot_content = Self::generate_full_dot(tables);
+        let dot_content = Self::generate_full_dot(tables, options);
         self.export(&dot_content, filename, cache_dir, browser)
     }
 }
@@ -407,6 +445,7 @@ mod tests {
 
             let dot = DotExporter::<SystemGraphvizRunner, SystemViewerLauncher>::generate_full_dot(
                 &tables,
+                &ErDiagramOptions::default(),
             );
 
             assert!(dot.contains("\"public.users\""));
@@ -419,6 +458,7 @@ mod tests {
 
             let dot = DotExporter::<SystemGraphvizRunner, SystemViewerLauncher>::generate_full_dot(
                 &tables,
+                &ErDiagramOptions::default(),
             );
 
             assert!(dot.contains("\"public.orders\" -> \"public.users\""));
@@ -444,12 +484,62 @@ mod tests {
 
             let dot = DotExporter::<SystemGraphvizRunner, SystemViewerLauncher>::generate_full_dot(
                 &tables,
+                &ErDiagramOptions::default(),
             );
 
             let first_pos = dot.find("\"a.first\"").unwrap();
             let last_pos = dot.find("\"z.last\"").unwrap();
             assert!(first_pos < last_pos);
         }
+
+        #[test]
+        fn hidden_hub_drops_its_edges_but_keeps_the_node() {
+            let tables = make_test_tables();
+            let options = ErDiagramOptions {
+                hidden_hubs: vec!["users".to_string()],
+                ..ErDiagramOptions::default()
+            };
+
+            let dot = DotExporter::<SystemGraphvizRunner, SystemViewerLauncher>::generate_full_dot(
+                &tables, &options,
+            );
+
+            assert!(dot.contains("\"public.users\" [label="));
+            assert!(!dot.contains("->"));
+        }
+
+        #[test]
+        fn schema_coloring_gives_each_schema_its_own_fill() {
+            let mut tables = make_test_tables();
+            tables[0].schema = "auth".to_string();
+            let options = ErDiagramOptions {
+                color_by_schema: true,
+                ..ErDiagramOptions::default()
+            };
+
+            let dot = DotExporter::<SystemGraphvizRunner, SystemViewerLauncher>::generate_full_dot(
+                &tables, &options,
+            );
+
+            assert!(dot.contains("(auth)\" style=filled fillcolor=lightblue"));
+            assert!(dot.contains("(public)\" style=filled fillcolor=palegreen"));
+        }
+
+        #[test]
+        fn collapse_threshold_switches_to_title_only_nodes() {
+            let tables = make_test_tables();
+            let options = ErDiagramOptions {
+                collapse_above: Some(1),
+                ..ErDiagramOptions::default()
+            };
+
+            let dot = DotExporter::<SystemGraphvizRunner, SystemViewerLauncher>::generate_full_dot(
+                &tables, &options,
+            );
+
+            assert!(dot.contains("\"public.users\" [label=\"users\" tooltip=\"public.users\""));
+            assert!(!dot.contains("(public)"));
+        }
     }
 
     mod export {
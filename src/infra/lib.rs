@@ -9,6 +9,7 @@
 pub mod adapters;
 pub mod config;
 pub mod export;
+pub mod metrics;
 
 pub use sabiql_app as app;
 pub use sabiql_domain as domain;
@@ -0,0 +1,267 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use super::registry::{MetricsRegistry, Outcome};
+use crate::app::ports::outbound::{
+    AccessMode, DbOperationError, MetadataProvider, PreviewOptions, QueryExecutor, TransactionEnd,
+};
+use crate::domain::{DatabaseMetadata, QueryResult, Table, TableSignature, WriteExecutionResult};
+
+async fn timed<T>(
+    metrics: &MetricsRegistry,
+    operation: &'static str,
+    call: impl Future<Output = Result<T, DbOperationError>>,
+) -> Result<T, DbOperationError> {
+    #[expect(
+        clippy::disallowed_methods,
+        reason = "infra measures adapter latency at the I/O boundary"
+    )]
+    let start = Instant::now();
+    let result = call.await;
+    let outcome = if result.is_ok() {
+        Outcome::Ok
+    } else {
+        Outcome::Error
+    };
+    metrics.observe(operation, outcome, start.elapsed());
+    result
+}
+
+/// Records latency and outcome for every call it forwards.
+pub struct MeteredQueryExecutor {
+    inner: Arc<dyn QueryExecutor>,
+    metrics: Arc<MetricsRegistry>,
+}
+
+impl MeteredQueryExecutor {
+    pub fn new(inner: Arc<dyn QueryExecutor>, metrics: Arc<MetricsRegistry>) -> Self {
+        Self { inner, metrics }
+    }
+}
+
+#[async_trait]
+impl QueryExecutor for MeteredQueryExecutor {
+    async fn execute_preview(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+        options: PreviewOptions,
+        limit: usize,
+        offset: usize,
+    ) -> Result<QueryResult, DbOperationError> {
+        timed(
+            &self.metrics,
+            "preview",
+            self.inner
+                .execute_preview(dsn, schema, table, options, limit, offset),
+        )
+        .await
+    }
+
+    async fn execute_adhoc(
+        &self,
+        dsn: &str,
+        query: &str,
+        access_mode: AccessMode,
+    ) -> Result<QueryResult, DbOperationError> {
+        timed(
+            &self.metrics,
+            "adhoc",
+            self.inner.execute_adhoc(dsn, query, access_mode),
+        )
+        .await
+    }
+
+    async fn execute_write(
+        &self,
+        dsn: &str,
+        query: &str,
+        access_mode: AccessMode,
+    ) -> Result<WriteExecutionResult, DbOperationError> {
+        timed(
+            &self.metrics,
+            "write",
+            self.inner.execute_write(dsn, query, access_mode),
+        )
+        .await
+    }
+
+    async fn count_query_rows(&self, dsn: &str, query: &str) -> Result<usize, DbOperationError> {
+        timed(
+            &self.metrics,
+            "count_rows",
+            self.inner.count_query_rows(dsn, query),
+        )
+        .await
+    }
+
+    async fn export_to_csv(
+        &self,
+        dsn: &str,
+        query: &str,
+        file_name: &str,
+    ) -> Result<PathBuf, DbOperationError> {
+        timed(
+            &self.metrics,
+            "export_csv",
+            self.inner.export_to_csv(dsn, query, file_name),
+        )
+        .await
+    }
+
+    async fn begin_transaction(
+        &self,
+        dsn: &str,
+        access_mode: AccessMode,
+    ) -> Result<(), DbOperationError> {
+        timed(
+            &self.metrics,
+            "begin_transaction",
+            self.inner.begin_transaction(dsn, access_mode),
+        )
+        .await
+    }
+
+    async fn end_transaction(
+        &self,
+        dsn: &str,
+        end: TransactionEnd,
+    ) -> Result<(), DbOperationError> {
+        timed(
+            &self.metrics,
+            "end_transaction",
+            self.inner.end_transaction(dsn, end),
+        )
+        .await
+    }
+}
+
+/// Records latency and outcome for every call it forwards; completion
+/// prefetch shows up as `prefetch`.
+pub struct MeteredMetadataProvider {
+    inner: Arc<dyn MetadataProvider>,
+    metrics: Arc<MetricsRegistry>,
+}
+
+impl MeteredMetadataProvider {
+    pub fn new(inner: Arc<dyn MetadataProvider>, metrics: Arc<MetricsRegistry>) -> Self {
+        Self { inner, metrics }
+    }
+}
+
+#[async_trait]
+impl MetadataProvider for MeteredMetadataProvider {
+    async fn fetch_metadata(&self, dsn: &str) -> Result<DatabaseMetadata, DbOperationError> {
+        timed(&self.metrics, "metadata", self.inner.fetch_metadata(dsn)).await
+    }
+
+    async fn fetch_effective_user(&self, dsn: &str) -> Result<Option<String>, DbOperationError> {
+        timed(
+            &self.metrics,
+            "effective_user",
+            self.inner.fetch_effective_user(dsn),
+        )
+        .await
+    }
+
+    async fn fetch_table_detail(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+    ) -> Result<Table, DbOperationError> {
+        timed(
+            &self.metrics,
+            "table_detail",
+            self.inner.fetch_table_detail(dsn, schema, table),
+        )
+        .await
+    }
+
+    async fn fetch_table_columns_and_fks(
+        &self,
+        dsn: &str,
+        schema: &str,
+        table: &str,
+    ) -> Result<Table, DbOperationError> {
+        timed(
+            &self.metrics,
+            "prefetch",
+            self.inner.fetch_table_columns_and_fks(dsn, schema, table),
+        )
+        .await
+    }
+
+    async fn fetch_table_signatures(
+        &self,
+        dsn: &str,
+    ) -> Result<Vec<TableSignature>, DbOperationError> {
+        timed(
+            &self.metrics,
+            "table_signatures",
+            self.inner.fetch_table_signatures(dsn),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::DemoAdapter;
+
+    #[tokio::test]
+    async fn forwards_results_and_records_outcomes() {
+        let metrics = Arc::new(MetricsRegistry::default());
+        let executor =
+            MeteredQueryExecutor::new(Arc::new(DemoAdapter::new()), Arc::clone(&metrics));
+
+        let ok = executor
+            .execute_preview(
+                "demo://sample",
+                "public",
+                "users",
+                PreviewOptions::default(),
+                10,
+                0,
+            )
+            .await;
+        let err = executor
+            .export_to_csv("demo://sample", "SELECT 1", "out.csv")
+            .await;
+
+        assert!(ok.is_ok());
+        assert!(err.is_err());
+        let text = metrics.render();
+        assert!(
+            text.contains("sabiql_db_operations_total{operation=\"preview\",outcome=\"ok\"} 1")
+        );
+        assert!(
+            text.contains(
+                "sabiql_db_operations_total{operation=\"export_csv\",outcome=\"error\"} 1"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn prefetch_is_reported_separately_from_table_detail() {
+        let metrics = Arc::new(MetricsRegistry::default());
+        let provider =
+            MeteredMetadataProvider::new(Arc::new(DemoAdapter::new()), Arc::clone(&metrics));
+
+        provider
+            .fetch_table_columns_and_fks("demo://sample", "public", "users")
+            .await
+            .unwrap();
+
+        let text = metrics.render();
+        assert!(
+            text.contains("sabiql_db_operation_duration_seconds_count{operation=\"prefetch\"} 1")
+        );
+        assert!(!text.contains("operation=\"table_detail\""));
+    }
+}
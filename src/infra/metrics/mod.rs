@@ -0,0 +1,7 @@
+//! Opt-in Prometheus-style metrics, written to a textfile-collector file.
+
+mod metered;
+mod registry;
+
+pub use metered::{MeteredMetadataProvider, MeteredQueryExecutor};
+pub use registry::{MetricsRegistry, Outcome};
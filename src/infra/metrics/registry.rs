@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+// Upper bounds in seconds; psql/sqlite3 start-up alone is a few milliseconds.
+const DURATION_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outcome {
+    Ok,
+    Error,
+}
+
+impl Outcome {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Histogram {
+    buckets: [u64; DURATION_BUCKETS.len()],
+    count: u64,
+    sum_seconds: f64,
+}
+
+impl Histogram {
+    fn observe(&mut self, elapsed: Duration) {
+        let seconds = elapsed.as_secs_f64();
+        for (bucket, bound) in self.buckets.iter_mut().zip(DURATION_BUCKETS) {
+            if seconds <= bound {
+                *bucket += 1;
+            }
+        }
+        self.count += 1;
+        self.sum_seconds += seconds;
+    }
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    operations: BTreeMap<(&'static str, Outcome), u64>,
+    durations: BTreeMap<&'static str, Histogram>,
+    caches: BTreeMap<&'static str, (u64, u64)>,
+}
+
+/// Counters and histograms rendered in the Prometheus text exposition format.
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    inner: Mutex<Inner>,
+}
+
+impl MetricsRegistry {
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    pub fn observe(&self, operation: &'static str, outcome: Outcome, elapsed: Duration) {
+        let mut inner = self.lock();
+        *inner.operations.entry((operation, outcome)).or_default() += 1;
+        inner
+            .durations
+            .entry(operation)
+            .or_default()
+            .observe(elapsed);
+    }
+
+    /// Caches keep their own cumulative counters; the latest totals replace the previous ones.
+    pub fn set_cache_lookups(&self, cache: &'static str, hits: u64, misses: u64) {
+        self.lock().caches.insert(cache, (hits, misses));
+    }
+
+    pub fn render(&self) -> String {
+        let inner = self.lock();
+        let mut out = String::new();
+
+        out.push_str(
+            "# HELP sabiql_db_operations_total Database operations by kind and outcome.\n",
+        );
+        out.push_str("# TYPE sabiql_db_operations_total counter\n");
+        for ((operation, outcome), count) in &inner.operations {
+            let _ = writeln!(
+                out,
+                "sabiql_db_operations_total{{operation=\"{operation}\",outcome=\"{}\"}} {count}",
+                outcome.label()
+            );
+        }
+
+        out.push_str(
+            "# HELP sabiql_db_operation_duration_seconds Database operation latency, including CLI start-up.\n",
+        );
+        out.push_str("# TYPE sabiql_db_operation_duration_seconds histogram\n");
+        for (operation, histogram) in &inner.durations {
+            for (bound, count) in DURATION_BUCKETS.iter().zip(histogram.buckets) {
+                let _ = writeln!(
+                    out,
+                    "sabiql_db_operation_duration_seconds_bucket{{operation=\"{operation}\",le=\"{bound}\"}} {count}"
+                );
+            }
+            let _ = writeln!(
+                out,
+                "sabiql_db_operation_duration_seconds_bucket{{operation=\"{operation}\",le=\"+Inf\"}} {}",
+                histogram.count
+            );
+            let _ = writeln!(
+                out,
+                "sabiql_db_operation_duration_seconds_sum{{operation=\"{operation}\"}} {}",
+                histogram.sum_seconds
+            );
+            let _ = writeln!(
+                out,
+                "sabiql_db_operation_duration_seconds_count{{operation=\"{operation}\"}} {}",
+                histogram.count
+            );
+        }
+
+        out.push_str("# HELP sabiql_cache_lookups_total Cache lookups by cache and result.\n");
+        out.push_str("# TYPE sabiql_cache_lookups_total counter\n");
+        for (cache, (hits, misses)) in &inner.caches {
+            let _ = writeln!(
+                out,
+                "sabiql_cache_lookups_total{{cache=\"{cache}\",result=\"hit\"}} {hits}"
+            );
+            let _ = writeln!(
+                out,
+                "sabiql_cache_lookups_total{{cache=\"{cache}\",result=\"miss\"}} {misses}"
+            );
+        }
+
+        out
+    }
+
+    /// Write-then-rename so scrapers never read a half-written file.
+    pub fn write_to(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent()
+            && !dir.as_os_str().is_empty()
+            && !dir.exists()
+        {
+            std::fs::create_dir_all(dir)?;
+        }
+        let tmp_path = path.with_extension("prom.tmp");
+        std::fs::write(&tmp_path, self.render())?;
+        std::fs::rename(&tmp_path, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn histogram_buckets_are_cumulative() {
+        let registry = MetricsRegistry::default();
+        registry.observe("adhoc", Outcome::Ok, Duration::from_millis(30));
+        registry.observe("adhoc", Outcome::Error, Duration::from_secs(20));
+
+        let text = registry.render();
+
+        assert!(text.contains(
+            "sabiql_db_operation_duration_seconds_bucket{operation=\"adhoc\",le=\"0.025\"} 0"
+        ));
+        assert!(text.contains(
+            "sabiql_db_operation_duration_seconds_bucket{operation=\"adhoc\",le=\"0.05\"} 1"
+        ));
+        assert!(text.contains(
+            "sabiql_db_operation_duration_seconds_bucket{operation=\"adhoc\",le=\"10\"} 1"
+        ));
+        assert!(text.contains(
+            "sabiql_db_operation_duration_seconds_bucket{operation=\"adhoc\",le=\"+Inf\"} 2"
+        ));
+        assert!(text.contains("sabiql_db_operation_duration_seconds_count{operation=\"adhoc\"} 2"));
+    }
+
+    #[test]
+    fn counters_split_by_outcome() {
+        let registry = MetricsRegistry::default();
+        registry.observe("preview", Outcome::Ok, Duration::ZERO);
+        registry.observe("preview", Outcome::Ok, Duration::ZERO);
+        registry.observe("preview", Outcome::Error, Duration::ZERO);
+
+        let text = registry.render();
+
+        assert!(
+            text.contains("sabiql_db_operations_total{operation=\"preview\",outcome=\"ok\"} 2")
+        );
+        assert!(
+            text.contains("sabiql_db_operations_total{operation=\"preview\",outcome=\"error\"} 1")
+        );
+    }
+
+    #[test]
+    fn cache_lookups_replace_previous_totals() {
+        let registry = MetricsRegistry::default();
+        registry.set_cache_lookups("metadata", 1, 1);
+        registry.set_cache_lookups("metadata", 4, 2);
+
+        let text = registry.render();
+
+        assert!(text.contains("sabiql_cache_lookups_total{cache=\"metadata\",result=\"hit\"} 4"));
+        assert!(text.contains("sabiql_cache_lookups_total{cache=\"metadata\",result=\"miss\"} 2"));
+    }
+
+    #[test]
+    fn write_to_creates_parent_dirs() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("textfile").join("sabiql.prom");
+        let registry = MetricsRegistry::default();
+        registry.observe("metadata", Outcome::Ok, Duration::from_millis(5));
+
+        registry.write_to(&path).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), registry.render());
+    }
+}
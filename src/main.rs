@@ -4,6 +4,8 @@
 )]
 
 use std::cell::RefCell;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -21,30 +23,50 @@ mod tests;
 #[path = "tests/render_snapshots/mod.rs"]
 mod render_snapshots;
 
+use sabiql_app::cmd::action_lanes::ActionLanes;
 use sabiql_app::cmd::cache::TtlCache;
 use sabiql_app::cmd::cli_sqlite::{activate_cli_sqlite_connection, resolve_cli_sqlite_target};
 use sabiql_app::cmd::completion_engine::CompletionEngine;
+use sabiql_app::cmd::demo::{activate_demo_connection, activate_mock_connection};
 use sabiql_app::cmd::effect::Effect;
+use sabiql_app::cmd::key_repeat::KeyRepeatFilter;
 use sabiql_app::cmd::render_schedule::next_animation_deadline;
 use sabiql_app::cmd::runner::{
     ConnectionDeps, EffectRunner, ErDeps, QueryDeps, SettingsDeps, UtilityDeps,
 };
+use sabiql_app::i18n::Locale;
 use sabiql_app::model::app_state::AppState;
 use sabiql_app::model::shared::input_mode::InputMode;
+use sabiql_app::model::shared::startup_profile::StartupPhase;
+use sabiql_app::ports::inbound::InputEvent;
 use sabiql_app::ports::outbound::{
-    ConnectionStore, ConnectionStoreError, PgServiceEntryReader, ServiceFileError, SettingsStore,
+    CacheCategory, CachePrunePolicy, CacheStore, ConnectionPool, ConnectionStore,
+    ConnectionStoreError, ExecutionPolicies, ExecutionPolicyReader, ExplorerTreeStore,
+    MaskingRuleReader, MetadataProvider, NamingConventionReader, PgServiceEntryReader,
+    QueryExecutor, QueryHookReader, Renderer, ServiceFileError, SettingsStore, UsageStatsStore,
 };
 use sabiql_app::services::AppServices;
 use sabiql_app::update::action::Action;
 use sabiql_app::update::input::handle_event;
 use sabiql_app::update::reducer::reduce;
+use sabiql_domain::DatabaseMetadata;
 use sabiql_infra::adapters::{
-    ArboardClipboard, CsvCachedResultExporter, DbAdapterRegistry, FileConfigWriter,
-    FileQueryHistoryStore, FsErLogWriter, FsSqlitePathValidator, NativeFolderOpener,
-    PgServiceFileReader, PostgresAdapter, TomlConnectionStore, TomlSettingsStore,
+    ArboardClipboard, CsvCachedResultExporter, DbAdapterRegistry, DemoAdapter, DockerSocketScanner,
+    FileAuditLogStore, FileCacheStore, FileConfigWriter, FileExplorerTreeStore,
+    FileMetadataSnapshotStore, FileQueryHistoryStore, FileUsageStatsStore,
+    FsConnectionCandidateScanner, FsErLogWriter, FsSqlFileScanner, FsSqlFileStore,
+    FsSqlitePathValidator, FsTempFileWriter, GithubReleaseChecker, MockAdapter, MockOptions,
+    NativeFolderOpener, PgServiceFileReader, PostgresAdapter, ShellQueryHookRunner,
+    TomlConnectionStore, TomlExecutionPolicyReader, TomlMaskingRuleReader,
+    TomlNamingConventionReader, TomlQueryHookReader, TomlSettingsStore, TomlSnippetStore,
 };
-use sabiql_infra::config::project_root::{find_project_root, get_project_name};
+use sabiql_infra::config::cache::ReadOnlyCache;
+use sabiql_infra::config::cache_cipher::{CacheCipher, CacheKeyError, load_or_create_cache_secret};
+use sabiql_infra::config::connection_config::CacheEncryptionConfig;
+use sabiql_infra::config::project_root::ProjectContext;
+use sabiql_infra::config::session_lock::{SessionLock, acquire_session_lock};
 use sabiql_infra::export::DotExporter;
+use sabiql_infra::metrics::{MeteredMetadataProvider, MeteredQueryExecutor, MetricsRegistry};
 use sabiql_ui::adapters::TuiAdapter;
 use sabiql_ui::tui::TuiRunner;
 
@@ -54,6 +76,40 @@ struct Args {
     /// SQLite database file path or sqlite:// DSN
     database: Option<String>,
 
+    /// Explore a bundled sample schema without a database
+    #[arg(long, conflicts_with = "database")]
+    demo: bool,
+
+    /// Serve a scripted mock database instead of connecting to one
+    #[arg(long, conflicts_with_all = ["database", "demo"])]
+    mock: bool,
+
+    /// Delay every mock database call by this many milliseconds
+    #[arg(long, value_name = "MS", requires = "mock", default_value_t = 0)]
+    mock_latency_ms: u64,
+
+    /// Fraction of mock database calls that fail, from 0.0 to 1.0
+    #[arg(
+        long,
+        value_name = "RATE",
+        requires = "mock",
+        default_value_t = 0.0,
+        value_parser = parse_failure_rate
+    )]
+    mock_failure_rate: f64,
+
+    /// Seed deciding which mock calls fail; reuse it to replay a run
+    #[arg(long, value_name = "SEED", requires = "mock", default_value_t = 0)]
+    mock_seed: u64,
+
+    /// JSON file describing the mock tables (defaults to the demo schema)
+    #[arg(long, value_name = "PATH", requires = "mock")]
+    mock_schema: Option<PathBuf>,
+
+    /// Periodically write Prometheus-style metrics to this file
+    #[arg(long, value_name = "PATH")]
+    metrics_file: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Option<Command>,
 }
@@ -75,6 +131,7 @@ enum Command {
     reason = "CLI error output before TUI initialization"
 )]
 async fn main() -> Result<()> {
+    let launched_at = Instant::now();
     dotenvy::dotenv().ok();
     panic_hooks::install_hooks()?;
 
@@ -99,8 +156,43 @@ async fn main() -> Result<()> {
         None => None,
     };
 
-    let project_root = find_project_root()?;
-    let project_name = get_project_name(&project_root);
+    let mock_adapter = if args.mock {
+        let options = MockOptions {
+            latency: Duration::from_millis(args.mock_latency_ms),
+            failure_rate: args.mock_failure_rate,
+            seed: args.mock_seed,
+        };
+        let adapter = match args.mock_schema.as_deref() {
+            Some(path) => MockAdapter::from_schema_file(path, options)
+                .map_err(|error| color_eyre::eyre::eyre!(error.to_string()))?,
+            None => MockAdapter::new(options),
+        };
+        Some(Arc::new(adapter))
+    } else {
+        None
+    };
+
+    // Demo, mock, and CLI targets open straight into the browser instead of the connection selector.
+    let has_cli_target = cli_sqlite.is_some() || args.demo || mock_adapter.is_some();
+
+    let ProjectContext {
+        root: project_root,
+        config_dir,
+        name: project_name,
+    } = ProjectContext::detect();
+    // Held until exit; without a cache dir there is nothing to protect.
+    let session_lock = acquire_session_lock(&project_name).ok();
+    let cache_owner_pid = session_lock.as_ref().and_then(SessionLock::owner_pid);
+    let read_only_caches = cache_owner_pid.is_some();
+    let cache_store = Arc::new(FileCacheStore::new());
+    if !read_only_caches {
+        // Housekeeping only: a failed prune is retried next launch.
+        let cache_store = Arc::clone(&cache_store);
+        let project_name = project_name.clone();
+        tokio::task::spawn_blocking(move || {
+            let _ = cache_store.prune(&project_name, &CachePrunePolicy::default());
+        });
+    }
 
     let (action_tx, mut action_rx) = mpsc::channel::<Action>(256);
 
@@ -111,25 +203,98 @@ async fn main() -> Result<()> {
     let connection_store = TomlConnectionStore::new()?;
     let settings_store = TomlSettingsStore::new()?;
     let app_settings = settings_store.load().unwrap_or_default();
+    let cache_ciphers =
+        CacheCiphers::resolve(settings_store.load_cache_encryption().unwrap_or_default());
     let connection_store = Arc::new(connection_store);
     let settings_store = Arc::new(settings_store);
 
+    let mut config_errors = Vec::new();
+    let query_hooks =
+        or_default_config(TomlQueryHookReader::new().read_hooks(), &mut config_errors);
+    let naming_conventions = or_default_config(
+        TomlNamingConventionReader::new().read_conventions(),
+        &mut config_errors,
+    );
+    let execution_policies = match &config_dir {
+        Some(dir) => or_default_config(
+            TomlExecutionPolicyReader::new().read_policies(dir),
+            &mut config_errors,
+        ),
+        None => ExecutionPolicies::default(),
+    };
+    let masking_rules = or_default_config(
+        TomlMaskingRuleReader::new().read_rules(),
+        &mut config_errors,
+    );
+
     let pg_service_entry_reader: Arc<dyn PgServiceEntryReader> =
         Arc::new(PgServiceFileReader::new());
 
+    let connection_pool: Option<Arc<dyn ConnectionPool>> =
+        (mock_adapter.is_none() && !args.demo).then(|| Arc::clone(&adapter_registry) as _);
+    let (metadata_provider, query_executor): (Arc<dyn MetadataProvider>, Arc<dyn QueryExecutor>) =
+        if let Some(mock_adapter) = &mock_adapter {
+            (Arc::clone(mock_adapter) as _, Arc::clone(mock_adapter) as _)
+        } else if args.demo {
+            let demo_adapter = Arc::new(DemoAdapter::new());
+            (Arc::clone(&demo_adapter) as _, demo_adapter)
+        } else {
+            (
+                Arc::clone(&adapter_registry) as _,
+                Arc::clone(&adapter_registry) as _,
+            )
+        };
+
+    let metrics = args
+        .metrics_file
+        .map(|path| (path, Arc::new(MetricsRegistry::default())));
+    let (metadata_provider, query_executor): (Arc<dyn MetadataProvider>, Arc<dyn QueryExecutor>) =
+        match &metrics {
+            Some((_, registry)) => (
+                Arc::new(MeteredMetadataProvider::new(
+                    metadata_provider,
+                    Arc::clone(registry),
+                )),
+                Arc::new(MeteredQueryExecutor::new(
+                    query_executor,
+                    Arc::clone(registry),
+                )),
+            ),
+            None => (metadata_provider, query_executor),
+        };
+
     let effect_runner = EffectRunner::new(
-        Arc::clone(&adapter_registry) as _,
+        metadata_provider,
         ConnectionDeps {
             dsn_builder: Arc::clone(&adapter_registry) as _,
             connection_store: Arc::clone(&connection_store) as _,
             pg_service_entry_reader: Some(Arc::clone(&pg_service_entry_reader)),
+            connection_candidate_scanner: Arc::new(FsConnectionCandidateScanner::new(Arc::clone(
+                &pg_service_entry_reader,
+            ))),
+            container_database_scanner: Arc::new(DockerSocketScanner::new()),
             sqlite_path_validator: Arc::new(FsSqlitePathValidator),
+            metadata_snapshot_store: Arc::new(
+                FileMetadataSnapshotStore::new()
+                    .with_read_only(read_only_caches || cache_ciphers.snapshots_blocked)
+                    .with_cipher(cache_ciphers.snapshots.clone()),
+            ),
         },
         QueryDeps {
-            query_executor: Arc::clone(&adapter_registry) as _,
-            query_history_store: Arc::new(FileQueryHistoryStore::new()),
+            query_executor,
+            connection_pool,
+            query_history_store: Arc::new(
+                FileQueryHistoryStore::new()
+                    .with_read_only(read_only_caches || cache_ciphers.history_blocked)
+                    .with_cipher(cache_ciphers.history.clone()),
+            ),
+            audit_log_store: Arc::new(FileAuditLogStore::new()),
             sqlite_diagnostics: Arc::clone(&adapter_registry) as _,
-            cached_result_exporter: Arc::new(CsvCachedResultExporter),
+            cached_result_exporter: Arc::new(
+                CsvCachedResultExporter::new()
+                    .with_key_unavailable(cache_ciphers.spill_blocked)
+                    .with_cipher(cache_ciphers.spill.clone()),
+            ),
         },
         ErDeps {
             er_exporter: Arc::new(DotExporter::new()),
@@ -139,6 +304,17 @@ async fn main() -> Result<()> {
         UtilityDeps {
             clipboard: Arc::new(ArboardClipboard),
             folder_opener: Arc::new(NativeFolderOpener),
+            temp_file_writer: Arc::new(
+                FsTempFileWriter::new()
+                    .with_key_unavailable(cache_ciphers.spill_blocked)
+                    .with_cipher(cache_ciphers.spill.clone()),
+            ),
+            query_hook_runner: Arc::new(ShellQueryHookRunner),
+            sql_file_scanner: Arc::new(FsSqlFileScanner),
+            sql_file_store: Arc::new(FsSqlFileStore),
+            snippet_store: Arc::new(TomlSnippetStore),
+            cache_store: Arc::clone(&cache_store) as _,
+            release_checker: Arc::new(GithubReleaseChecker),
         },
         SettingsDeps {
             settings_store: Arc::clone(&settings_store) as _,
@@ -154,19 +330,78 @@ async fn main() -> Result<()> {
     };
 
     let mut state = AppState::new(project_name);
+    state.runtime.startup_mut().begin(launched_at);
+    if let Some(root) = project_root {
+        state.runtime.set_project_root(root);
+    }
     state.ui.set_theme(app_settings.theme_id);
     state
         .settings
         .load_keymap_preset(app_settings.keymap_preset);
     state.settings.load_er_browser(app_settings.er_browser);
+    state.settings.load_keyword_case(app_settings.keyword_case);
+    state
+        .settings
+        .load_locale(app_settings.locale, environment_locale());
+    state.settings.load_update_check(app_settings.update_check);
+    state
+        .settings
+        .load_prefetch_scope(app_settings.prefetch_scope);
+    let usage_store = FileUsageStatsStore::new().with_read_only(read_only_caches);
+    // A corrupt usage file only costs the counts, never startup.
+    state.usage.load(
+        app_settings.usage_stats,
+        usage_store
+            .load(state.runtime.project_name())
+            .unwrap_or_default(),
+    );
+    if let Some(tab) = state.usage.favorite_tab() {
+        state.ui.set_inspector_tab(tab);
+    }
+    let explorer_tree_store = FileExplorerTreeStore::new().with_read_only(read_only_caches);
+    state.explorer_tree.load(
+        explorer_tree_store
+            .load(state.runtime.project_name())
+            .unwrap_or_default(),
+    );
+    state.runtime.set_query_hooks(query_hooks);
+    state.runtime.set_cache_owner_pid(cache_owner_pid);
+    if let Some(SessionLock::Shared {
+        owner_pid,
+        lock_path,
+    }) = &session_lock
+    {
+        state.messages.set_error_at(
+            format!(
+                "Another sabiql (PID {owner_pid}) has this project open: history, snapshots and usage stats are read-only here. Remove {} if that process is gone.",
+                lock_path.display()
+            ),
+            Instant::now(),
+        );
+    }
+    if let Some(error) = &cache_ciphers.key_error {
+        state.messages.set_error_at(
+            format!("Cache encryption is on but no key is available ({error}): encrypted caches are not written this session."),
+            Instant::now(),
+        );
+    }
+    if !config_errors.is_empty() {
+        state.messages.set_error_at(
+            format!("{} (using defaults)", config_errors.join("; ")),
+            Instant::now(),
+        );
+    }
+    state.runtime.set_naming_conventions(naming_conventions);
+    state.runtime.set_execution_policies(execution_policies);
+    state.runtime.set_masking_rules(masking_rules);
 
     match connection_store.load_all() {
         Ok(profiles) if profiles.is_empty() => {
             load_service_entries(&mut state, pg_service_entry_reader.as_ref());
-            if cli_sqlite.is_none() && state.service_entries().is_empty() {
+            if !has_cli_target && state.service_entries().is_empty() {
                 state.connection_setup.set_first_run(true);
                 state.modal.set_mode(InputMode::ConnectionSetup);
-            } else if cli_sqlite.is_none() {
+            } else if !has_cli_target {
                 state.modal.set_mode(InputMode::ConnectionSelector);
                 state.ui.set_connection_list_selection(Some(0));
             }
@@ -180,12 +415,12 @@ async fn main() -> Result<()> {
             state.set_connections(profiles);
             load_service_entries(&mut state, pg_service_entry_reader.as_ref());
 
-            if cli_sqlite.is_none() {
+            if !has_cli_target {
                 state.modal.set_mode(InputMode::ConnectionSelector);
                 state.ui.set_connection_list_selection(Some(0));
             }
         }
-        Err(ConnectionStoreError::VersionMismatch { found, expected }) if cli_sqlite.is_none() => {
+        Err(ConnectionStoreError::VersionMismatch { found, expected }) if !has_cli_target => {
             eprintln!(
                 "Error: Configuration file version mismatch (found v{}, expected v{}).\n\
                  Please delete {} and reconfigure.",
@@ -195,7 +430,7 @@ async fn main() -> Result<()> {
             );
             std::process::exit(1);
         }
-        Err(_) if cli_sqlite.is_none() => {
+        Err(_) if !has_cli_target => {
             state.connection_setup.set_first_run(true);
             state.modal.set_mode(InputMode::ConnectionSetup);
         }
@@ -206,6 +441,17 @@ async fn main() -> Result<()> {
         activate_cli_sqlite_connection(&mut state, target, &FsSqlitePathValidator)
             .map_err(|error| color_eyre::eyre::eyre!(error.to_string()))?;
     }
+    if args.demo {
+        activate_demo_connection(&mut state);
+    }
+    if args.mock {
+        activate_mock_connection(&mut state);
+    }
+
+    state
+        .runtime
+        .startup_mut()
+        .mark(StartupPhase::ConfigLoad, Instant::now());
 
     let mut tui = TuiRunner::new()?;
     tui.enter()?;
@@ -222,36 +468,66 @@ async fn main() -> Result<()> {
         services,
     };
 
+    // Draw before anything touches the database; the explorer hydrates when
+    // metadata arrives on the action channel.
+    runtime.process_action(Action::Render).await?;
+    runtime
+        .state
+        .runtime
+        .startup_mut()
+        .mark(StartupPhase::FirstRender, Instant::now());
+
+    runtime.process_action(Action::ReloadSnippets).await?;
+    runtime.process_action(Action::CheckForUpdate).await?;
+
     if runtime.state.session.dsn().is_some() && runtime.state.input_mode() == InputMode::Normal {
         runtime.process_action(Action::TryConnect).await?;
     }
 
     let cache_cleanup_interval = Duration::from_secs(150);
     let mut last_cache_cleanup = Instant::now();
+    let mut last_metrics_write = Instant::now();
+    let mut last_input = Instant::now();
+
+    let mut lanes = ActionLanes::default();
 
     loop {
-        let now = Instant::now();
-        let deadline = next_animation_deadline(&runtime.state, now);
+        while !lanes.is_full() {
+            let Ok(action) = action_rx.try_recv() else {
+                break;
+            };
+            lanes.push(action);
+        }
 
-        tokio::select! {
-            Some(event) = runtime.tui.next_event() => {
-                let action = handle_event(event, &runtime.state);
-                if !action.is_none() {
-                    runtime.process_terminal_event_burst(action).await?;
+        if lanes.is_empty() {
+            let now = Instant::now();
+            let deadline = next_animation_deadline(&runtime.state, now, last_input);
+
+            // Biased so terminal input always wins over channel traffic.
+            tokio::select! {
+                biased;
+                Some(event) = runtime.tui.next_event() => {
+                    last_input = Instant::now();
+                    runtime.process_input_event(event).await?;
                 }
-            }
-            Some(action) = action_rx.recv() => {
-                runtime.process_action(action).await?;
-            }
-            // Animation deadline reached (spinner, cursor blink, message timeout)
-            () = async {
-                match deadline {
-                    Some(d) => sleep_until(d.into()).await,
-                    None => std::future::pending::<()>().await,
+                Some(action) = action_rx.recv() => {
+                    lanes.push(action);
+                }
+                // Animation deadline reached (spinner, cursor blink, message timeout)
+                () = async {
+                    match deadline {
+                        Some(d) => sleep_until(d.into()).await,
+                        None => std::future::pending::<()>().await,
+                    }
+                } => {
+                    runtime.process_action(Action::Render).await?;
                 }
-            } => {
-                runtime.process_action(Action::Render).await?;
             }
+        } else if let Some(event) = runtime.tui.try_next_event() {
+            last_input = Instant::now();
+            runtime.process_input_event(event).await?;
+        } else if let Some(action) = lanes.pop() {
+            runtime.process_action(action).await?;
         }
 
         if let Some(debounce_until) = runtime.state.sql_modal.completion_debounce()
@@ -266,17 +542,56 @@ async fn main() -> Result<()> {
             last_cache_cleanup = Instant::now();
         }
 
+        if let Some((path, registry)) = &metrics
+            && last_metrics_write.elapsed() >= METRICS_WRITE_INTERVAL
+        {
+            write_metrics(path, registry, &metadata_cache);
+            last_metrics_write = Instant::now();
+        }
+
         if runtime.state.should_quit {
             break;
         }
     }
 
+    runtime.shutdown().await?;
+    if let Some((path, registry)) = &metrics {
+        write_metrics(path, registry, &metadata_cache);
+    }
+    if runtime.state.usage.is_dirty() {
+        let _ = usage_store.save(
+            runtime.state.runtime.project_name(),
+            runtime.state.usage.stats(),
+        );
+    }
+    if runtime.state.explorer_tree.is_dirty() {
+        let _ = explorer_tree_store.save(
+            runtime.state.runtime.project_name(),
+            runtime.state.explorer_tree.layout(),
+        );
+    }
     runtime.tui.exit()?;
+    drop(session_lock);
     Ok(())
 }
 
+const METRICS_WRITE_INTERVAL: Duration = Duration::from_secs(15);
+
+// Metrics are diagnostic; a failed write must never interrupt the session.
+fn write_metrics(
+    path: &Path,
+    registry: &MetricsRegistry,
+    metadata_cache: &TtlCache<String, Arc<DatabaseMetadata>>,
+) {
+    let stats = metadata_cache.stats();
+    registry.set_cache_lookups("metadata", stats.hits, stats.misses);
+    let _ = registry.write_to(path);
+}
+
 const MAX_DEPTH: usize = 16;
 const MAX_DRAIN: usize = 32;
+// Quick exits stay silent; anything slower tells the user why the TUI is still up.
+const SHUTDOWN_NOTICE_DELAY: Duration = Duration::from_millis(300);
 
 struct Runtime {
     state: AppState,
@@ -287,12 +602,27 @@ struct Runtime {
 }
 
 impl Runtime {
+    async fn shutdown(&mut self) -> Result<()> {
+        let shutdown = self.effect_runner.shutdown();
+        tokio::pin!(shutdown);
+        if tokio::time::timeout(SHUTDOWN_NOTICE_DELAY, &mut shutdown)
+            .await
+            .is_err()
+        {
+            self.state.runtime.begin_finishing_up();
+            TuiAdapter::new(&mut self.tui).draw(&self.state, &self.services, Instant::now())?;
+            shutdown.await;
+        }
+        Ok(())
+    }
+
     async fn process_action(&mut self, action: Action) -> Result<()> {
         let now = Instant::now();
         let is_animation_tick = matches!(action, Action::Render);
         if is_animation_tick {
             self.state.clear_expired_timers(now);
         }
+        panic_hooks::record_action(&action);
         let mut effects = reduce(&mut self.state, action, now, &self.services);
         if self.state.render_dirty {
             if !is_animation_tick {
@@ -328,6 +658,7 @@ impl Runtime {
             let mut next = Vec::new();
             for action in pending {
                 let now = Instant::now();
+                panic_hooks::record_action(&action);
                 let mut effects = reduce(&mut self.state, action, now, &self.services);
                 if self.state.render_dirty {
                     self.state.clear_expired_timers(now);
@@ -351,12 +682,27 @@ impl Runtime {
         Ok(())
     }
 
-    async fn process_terminal_event_burst(&mut self, first_action: Action) -> Result<()> {
-        if !first_action.is_scroll() {
+    async fn process_input_event(&mut self, event: InputEvent) -> Result<()> {
+        let action = handle_event(event.clone(), &self.state);
+        if action.is_none() {
+            return Ok(());
+        }
+        self.process_terminal_event_burst(&event, action).await
+    }
+
+    async fn process_terminal_event_burst(
+        &mut self,
+        first_event: &InputEvent,
+        first_action: Action,
+    ) -> Result<()> {
+        if !first_action.is_navigation() {
             return self.process_action(first_action).await;
         }
+        let mut repeats = KeyRepeatFilter::default();
+        repeats.admit(first_event, &first_action);
 
         let now = Instant::now();
+        panic_hooks::record_action(&first_action);
         let mut effects = reduce(&mut self.state, first_action, now, &self.services);
         if !effects.is_empty() {
             if self.state.render_dirty {
@@ -366,21 +712,22 @@ impl Runtime {
             return self.flush_effects(effects).await;
         }
 
-        // Effect-free scroll reduces only mutate state; defer the render to the
-        // end of the burst so N input events produce one draw, not N.
+        // Effect-free navigation reduces only mutate state; defer the render to
+        // the end of the burst so N input events produce one draw, not N.
         let mut drained = 0;
         while drained < MAX_DRAIN {
             let Some(event) = self.tui.try_next_event() else {
                 break;
             };
             drained += 1;
-            let action = handle_event(event, &self.state);
-            if action.is_none() {
+            let action = handle_event(event.clone(), &self.state);
+            if action.is_none() || !repeats.admit(&event, &action) {
                 continue;
             }
 
-            if action.is_scroll() {
+            if action.is_navigation() {
                 let now = Instant::now();
+                panic_hooks::record_action(&action);
                 let mut effects = reduce(&mut self.state, action, now, &self.services);
                 if !effects.is_empty() {
                     if self.state.render_dirty {
@@ -439,6 +786,84 @@ fn dispatch_overflow_fallback(
     state.messages.set_error_at(message, now);
 }
 
+// POSIX precedence: the first non-empty of LC_ALL, LC_MESSAGES, LANG decides.
+fn parse_failure_rate(value: &str) -> Result<f64, String> {
+    let rate: f64 = value
+        .parse()
+        .map_err(|_| format!("'{value}' is not a number"))?;
+    if (0.0..=1.0).contains(&rate) {
+        Ok(rate)
+    } else {
+        Err(format!("{rate} is outside 0.0-1.0"))
+    }
+}
+
+/// Per-category ciphers from `[cache_encryption]`. Without a keychain key the
+/// flagged stores stop writing rather than fall back to plaintext.
+struct CacheCiphers {
+    history: Option<Arc<CacheCipher>>,
+    snapshots: Option<Arc<CacheCipher>>,
+    spill: Option<Arc<CacheCipher>>,
+    history_blocked: bool,
+    snapshots_blocked: bool,
+    spill_blocked: bool,
+    key_error: Option<CacheKeyError>,
+}
+
+impl CacheCiphers {
+    fn resolve(config: CacheEncryptionConfig) -> Self {
+        let secret = if config.any() {
+            Some(load_or_create_cache_secret())
+        } else {
+            None
+        };
+        let (secret, key_error) = match secret {
+            Some(Ok(secret)) => (Some(secret), None),
+            Some(Err(error)) => (None, Some(error)),
+            None => (None, None),
+        };
+        let cipher = |enabled: bool, category: CacheCategory| {
+            secret
+                .as_deref()
+                .filter(|_| enabled)
+                .map(|secret| Arc::new(CacheCipher::new(secret, category)))
+        };
+        Self {
+            history: cipher(config.history, CacheCategory::History),
+            snapshots: cipher(config.snapshots, CacheCategory::Snapshots),
+            spill: secret
+                .as_deref()
+                .filter(|_| config.spill)
+                .map(|secret| Arc::new(CacheCipher::for_spills(secret))),
+            history_blocked: config.history && key_error.is_some(),
+            snapshots_blocked: config.snapshots && key_error.is_some(),
+            spill_blocked: config.spill && key_error.is_some(),
+            key_error,
+        }
+    }
+}
+
+fn environment_locale() -> Option<Locale> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .find_map(|key| std::env::var(key).ok().filter(|value| !value.is_empty()))
+        .and_then(|value| Locale::from_env_value(&value))
+}
+
+// A broken optional config file costs its feature, not startup: the error is
+// printed and collected for the status line, and the defaults apply.
+#[allow(
+    clippy::print_stderr,
+    reason = "CLI error output before TUI initialization"
+)]
+fn or_default_config<T: Default, E: Display>(result: Result<T, E>, errors: &mut Vec<String>) -> T {
+    result.unwrap_or_else(|error| {
+        eprintln!("Warning: {error}");
+        errors.push(error.to_string());
+        T::default()
+    })
+}
+
 fn load_service_entries(state: &mut AppState, reader: &dyn PgServiceEntryReader) {
     match reader.read_services() {
         Ok((services, path)) if !services.is_empty() => {
@@ -1,12 +1,22 @@
-use std::io::stdout;
+use std::backtrace::Backtrace;
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::fs;
 use std::panic;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use color_eyre::eyre::Result;
-use crossterm::{
-    cursor::SetCursorStyle,
-    execute,
-    terminal::{LeaveAlternateScreen, disable_raw_mode},
-};
+use sabiql_app::update::action::Action;
+use sabiql_infra::config::cache::{CacheDirError, get_crash_report_dir};
+use sabiql_ui::tui::TuiRunner;
+
+const RECENT_ACTION_LIMIT: usize = 50;
+// Result payloads can be huge; the report only needs enough to recognize the action.
+const ACTION_SUMMARY_LIMIT: usize = 200;
+
+static RECENT_ACTIONS: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
 
 #[allow(
     clippy::print_stderr,
@@ -18,18 +28,110 @@ pub fn install_hooks() -> Result<()> {
     eyre_hook.install()?;
 
     panic::set_hook(Box::new(move |panic_info| {
-        let _ = restore_terminal();
+        let _ = TuiRunner::restore_terminal();
         eprintln!("{}", panic_hook.panic_report(panic_info));
+
+        let unix_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let report = crash_report(
+            &panic_info.to_string(),
+            &Backtrace::force_capture(),
+            &recent_actions(),
+            unix_secs,
+        );
+        match write_crash_report(&report, unix_secs) {
+            Ok(path) => eprintln!("Crash report written to {}", path.display()),
+            Err(e) => eprintln!("Failed to write crash report: {e}"),
+        }
     }));
 
     Ok(())
 }
 
-fn restore_terminal() -> Result<()> {
-    if crossterm::terminal::is_raw_mode_enabled()? {
-        let _ = execute!(stdout(), SetCursorStyle::DefaultUserShape);
-        execute!(stdout(), LeaveAlternateScreen)?;
-        disable_raw_mode()?;
+/// Keeps the last few reduced actions for the crash report. Animation ticks
+/// are skipped so they do not push out the actions that led to the panic.
+pub fn record_action(action: &Action) {
+    if matches!(action, Action::Render) {
+        return;
+    }
+    let summary = summarize_action(action);
+    let mut recent = RECENT_ACTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if recent.len() == RECENT_ACTION_LIMIT {
+        recent.pop_front();
+    }
+    recent.push_back(summary);
+}
+
+fn recent_actions() -> Vec<String> {
+    // try_lock: the panic may have fired on a thread that holds the lock.
+    RECENT_ACTIONS
+        .try_lock()
+        .map(|recent| recent.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+pub fn summarize_action(action: &Action) -> String {
+    let mut out = BoundedWriter {
+        buf: String::new(),
+        limit: ACTION_SUMMARY_LIMIT,
+    };
+    if write!(out, "{action:?}").is_err() {
+        out.buf.push('…');
+    }
+    out.buf
+}
+
+pub fn crash_report(
+    panic_message: &str,
+    backtrace: &Backtrace,
+    actions: &[String],
+    unix_secs: u64,
+) -> String {
+    let mut report = String::new();
+    let _ = writeln!(report, "sabiql {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(
+        report,
+        "os: {} ({})",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    let _ = writeln!(report, "time: {unix_secs} (unix)");
+    let _ = writeln!(report, "\n## Panic\n{panic_message}");
+    let _ = writeln!(report, "\n## Last {} actions (oldest first)", actions.len());
+    for action in actions {
+        let _ = writeln!(report, "{action}");
+    }
+    let _ = writeln!(report, "\n## Backtrace\n{backtrace}");
+    report
+}
+
+fn write_crash_report(report: &str, unix_secs: u64) -> Result<PathBuf, CacheDirError> {
+    let path = get_crash_report_dir()?.join(format!("crash-{unix_secs}.log"));
+    fs::write(&path, report)?;
+    Ok(path)
+}
+
+// Stops `Debug` formatting once the limit is hit instead of rendering the whole payload.
+struct BoundedWriter {
+    buf: String,
+    limit: usize,
+}
+
+impl fmt::Write for BoundedWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let remaining = self.limit.saturating_sub(self.buf.len());
+        if s.len() <= remaining {
+            self.buf.push_str(s);
+            return Ok(());
+        }
+        let cut = (0..=remaining)
+            .rev()
+            .find(|&i| s.is_char_boundary(i))
+            .unwrap_or(0);
+        self.buf.push_str(&s[..cut]);
+        Err(fmt::Error)
     }
-    Ok(())
 }
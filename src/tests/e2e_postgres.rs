@@ -0,0 +1,172 @@
+//! Action-driven scenarios against PostgreSQL (Tier 2).
+//!
+//! Each test feeds actions through the reducer and effect runner wired to the
+//! real adapters, then checks both `AppState` and the rendered screen.
+//! Same requirements and run instructions as `adapter_postgres`; select these
+//! with `-E 'test(tests::e2e_postgres)'`.
+
+use std::time::Duration;
+
+use sabiql_app::model::app_state::AppState;
+use sabiql_app::update::action::Action;
+use sabiql_domain::{ConnectionId, DatabaseType};
+
+use crate::tests::harness::app::AppDriver;
+use crate::tests::harness::postgres::{PostgresTestDb, with_postgres_test_db};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+async fn connected_driver(db: &PostgresTestDb) -> AppDriver {
+    let mut state = AppState::new("sabiql_e2e".to_string());
+    state.session.activate_connection_with_dsn(
+        &ConnectionId::new(),
+        "fixture",
+        DatabaseType::PostgreSQL,
+        db.dsn(),
+    );
+    let mut app = AppDriver::new(state);
+
+    app.dispatch(Action::TryConnect).await;
+    app.run_until(TIMEOUT, |state| {
+        state.session.connection_state().is_connected()
+    })
+    .await;
+    app
+}
+
+async fn open_table(app: &mut AppDriver, schema: &str, table: &str) -> Result<(), String> {
+    let index = app
+        .state
+        .tables()
+        .iter()
+        .position(|summary| summary.schema == schema && summary.name == table)
+        .ok_or_else(|| format!("'{schema}.{table}' missing from the explorer"))?;
+    app.state.ui.set_explorer_selection(Some(index));
+
+    app.dispatch(Action::ConfirmSelection).await;
+    app.run_until(TIMEOUT, |state| {
+        state
+            .session
+            .table_detail()
+            .is_some_and(|detail| detail.name == table)
+            && !state.query.is_running()
+            && state.query.visible_result().is_some()
+    })
+    .await;
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL, tracked: #133"]
+async fn selecting_a_table_loads_detail_and_previews_rows() {
+    with_postgres_test_db(|db| {
+        Box::pin(async move {
+            let mut app = Box::pin(connected_driver(db)).await;
+            open_table(&mut app, db.schema(), db.table()).await?;
+
+            let preview = app.state.query.visible_result().unwrap();
+            if preview.is_error() || preview.data_row_count() != 2 {
+                return Err(format!("expected two preview rows, got {preview:?}"));
+            }
+            let screen = app.render().await;
+            for expected in [db.table(), "Ada", "Grace"] {
+                if !screen.contains(expected) {
+                    return Err(format!("'{expected}' not on screen:\n{screen}"));
+                }
+            }
+            Ok(())
+        })
+    })
+    .await;
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL, tracked: #133"]
+async fn child_table_detail_includes_foreign_key_and_index() {
+    with_postgres_test_db(|db| {
+        Box::pin(async move {
+            let mut app = Box::pin(connected_driver(db)).await;
+            open_table(&mut app, db.schema(), db.child_table()).await?;
+
+            let detail = app.state.session.table_detail().unwrap();
+            if !detail
+                .foreign_keys
+                .iter()
+                .any(|fk| fk.to_table == db.table() && fk.from_columns == ["owner_id"])
+            {
+                return Err(format!("expected owner FK, got {:?}", detail.foreign_keys));
+            }
+            if !detail
+                .indexes
+                .iter()
+                .any(|index| index.name == "fixture_pets_species_idx")
+            {
+                return Err(format!("expected species index, got {:?}", detail.indexes));
+            }
+            Ok(())
+        })
+    })
+    .await;
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL, tracked: #133"]
+async fn adhoc_query_result_replaces_the_grid() {
+    with_postgres_test_db(|db| {
+        Box::pin(async move {
+            let mut app = Box::pin(connected_driver(db)).await;
+            let sql = format!(
+                r#"SELECT species FROM "{}"."{}" ORDER BY id"#,
+                db.schema(),
+                db.child_table()
+            );
+
+            app.dispatch(Action::ExecuteAdhoc(sql.clone())).await;
+            app.run_until(TIMEOUT, |state| {
+                state
+                    .query
+                    .visible_result()
+                    .is_some_and(|result| result.query == sql)
+            })
+            .await;
+
+            let result = app.state.query.visible_result().unwrap();
+            if result.columns != ["species"] || result.data_row_count() != 2 {
+                return Err(format!("unexpected adhoc result: {result:?}"));
+            }
+            let screen = app.render().await;
+            if !screen.contains("moth") {
+                return Err(format!("adhoc rows not on screen:\n{screen}"));
+            }
+            Ok(())
+        })
+    })
+    .await;
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL, tracked: #133"]
+async fn failing_adhoc_query_surfaces_the_server_error() {
+    with_postgres_test_db(|db| {
+        Box::pin(async move {
+            let mut app = Box::pin(connected_driver(db)).await;
+
+            app.dispatch(Action::ExecuteAdhoc(
+                "SELECT * FROM sabiql_missing_table".to_string(),
+            ))
+            .await;
+            app.run_until(TIMEOUT, |state| state.messages.last_error().is_some())
+                .await;
+
+            let error = app.state.messages.last_error().unwrap();
+            if !error.contains("sabiql_missing_table") {
+                return Err(format!("expected the missing relation in '{error}'"));
+            }
+            if app.state.query.is_running() {
+                return Err("query should be idle after a failure".to_string());
+            }
+            Ok(())
+        })
+    })
+    .await;
+}
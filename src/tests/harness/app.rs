@@ -0,0 +1,317 @@
+//! Drives `AppState` the way the main loop does — reduce, run effects, feed
+//! dispatched and background actions back in — against real adapters, and
+//! renders into a `TestBackend` instead of a terminal.
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use tempfile::TempDir;
+use tokio::sync::mpsc;
+
+use sabiql_app::cmd::action_lanes::ActionLanes;
+use sabiql_app::cmd::cache::TtlCache;
+use sabiql_app::cmd::completion_engine::CompletionEngine;
+use sabiql_app::cmd::effect::Effect;
+use sabiql_app::cmd::runner::{
+    ConnectionDeps, EffectRunner, ErDeps, QueryDeps, SettingsDeps, UtilityDeps,
+};
+use sabiql_app::model::app_state::AppState;
+use sabiql_app::ports::outbound::{
+    AuditLogError, AuditLogStore, ConnectionCandidate, ConnectionCandidateScanner,
+    ContainerDatabaseScanner, ContainerScanError, MetadataSnapshotError, MetadataSnapshotStore,
+    QueryHistoryError, QueryHistoryStore, RenderOutput, RenderResult, Renderer,
+};
+use sabiql_app::services::AppServices;
+use sabiql_app::update::action::Action;
+use sabiql_app::update::reducer::reduce;
+use sabiql_domain::connection::ConnectionId;
+use sabiql_domain::query_history::QueryHistoryEntry;
+use sabiql_domain::{AuditEntry, DatabaseMetadata, MetadataSnapshot, Table};
+use sabiql_infra::adapters::{
+    ArboardClipboard, CsvCachedResultExporter, DbAdapterRegistry, FileCacheStore, FileConfigWriter,
+    FsErLogWriter, FsSqlFileScanner, FsSqlFileStore, FsSqlitePathValidator, FsTempFileWriter,
+    GithubReleaseChecker, NativeFolderOpener, PostgresAdapter, ShellQueryHookRunner,
+    TomlConnectionStore, TomlSettingsStore, TomlSnippetStore,
+};
+use sabiql_infra::export::DotExporter;
+use sabiql_ui::shell::layout::MainLayout;
+
+use super::{TEST_HEIGHT, TEST_WIDTH, buffer_to_string};
+
+const MAX_DEPTH: usize = 8;
+
+struct TestRenderer {
+    terminal: Terminal<TestBackend>,
+}
+
+impl TestRenderer {
+    fn new() -> Self {
+        Self {
+            terminal: Terminal::new(TestBackend::new(TEST_WIDTH, TEST_HEIGHT)).unwrap(),
+        }
+    }
+
+    fn screen(&self) -> String {
+        buffer_to_string(self.terminal.backend().buffer())
+    }
+}
+
+impl Renderer for TestRenderer {
+    fn draw(
+        &mut self,
+        state: &AppState,
+        services: &AppServices,
+        now: Instant,
+    ) -> RenderResult<RenderOutput> {
+        let mut output = RenderOutput::default();
+        let Ok(_) = self.terminal.draw(|frame| {
+            output = MainLayout::render(frame, state, None, services, now);
+        });
+        Ok(output)
+    }
+}
+
+/// Keeps the user's cache directory out of test runs.
+struct NullSnapshotStore;
+
+#[async_trait]
+impl MetadataSnapshotStore for NullSnapshotStore {
+    async fn save_metadata(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+        _metadata: &DatabaseMetadata,
+    ) -> Result<(), MetadataSnapshotError> {
+        Ok(())
+    }
+
+    async fn save_table_detail(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+        _table: &Table,
+    ) -> Result<(), MetadataSnapshotError> {
+        Ok(())
+    }
+
+    async fn save_table_columns(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+        _table: &Table,
+    ) -> Result<(), MetadataSnapshotError> {
+        Ok(())
+    }
+
+    async fn load(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+    ) -> Result<Option<MetadataSnapshot>, MetadataSnapshotError> {
+        Ok(None)
+    }
+}
+
+struct NullCandidateScanner;
+
+impl ConnectionCandidateScanner for NullCandidateScanner {
+    fn scan(&self, _project_root: Option<PathBuf>) -> Vec<ConnectionCandidate> {
+        Vec::new()
+    }
+}
+
+struct NullContainerScanner;
+
+impl ContainerDatabaseScanner for NullContainerScanner {
+    fn scan(&self) -> Result<Vec<ConnectionCandidate>, ContainerScanError> {
+        Ok(Vec::new())
+    }
+}
+
+struct NullAuditLogStore;
+
+#[async_trait]
+impl AuditLogStore for NullAuditLogStore {
+    async fn append(&self, _project_name: &str, _entry: &AuditEntry) -> Result<(), AuditLogError> {
+        Ok(())
+    }
+}
+
+struct NullHistoryStore;
+
+#[async_trait]
+impl QueryHistoryStore for NullHistoryStore {
+    async fn append(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+        _entry: &QueryHistoryEntry,
+    ) -> Result<(), QueryHistoryError> {
+        Ok(())
+    }
+
+    async fn load(
+        &self,
+        _project_name: &str,
+        _connection_id: &ConnectionId,
+    ) -> Result<Vec<QueryHistoryEntry>, QueryHistoryError> {
+        Ok(Vec::new())
+    }
+}
+
+pub struct AppDriver {
+    pub state: AppState,
+    renderer: TestRenderer,
+    runner: EffectRunner,
+    services: AppServices,
+    completion_engine: RefCell<CompletionEngine>,
+    action_rx: mpsc::Receiver<Action>,
+    lanes: ActionLanes,
+    _config_dir: TempDir,
+}
+
+impl AppDriver {
+    /// Wired like `main`, with config written to a temp dir and the
+    /// snapshot/history caches discarded.
+    pub fn new(state: AppState) -> Self {
+        let config_dir = tempfile::tempdir().unwrap();
+        let registry = Arc::new(DbAdapterRegistry::new(Arc::new(PostgresAdapter::new())));
+        let (action_tx, action_rx) = mpsc::channel(256);
+
+        let runner = EffectRunner::new(
+            Arc::clone(&registry) as _,
+            ConnectionDeps {
+                dsn_builder: Arc::clone(&registry) as _,
+                connection_store: Arc::new(TomlConnectionStore::with_config_dir(
+                    config_dir.path().to_path_buf(),
+                )),
+                pg_service_entry_reader: None,
+                connection_candidate_scanner: Arc::new(NullCandidateScanner),
+                container_database_scanner: Arc::new(NullContainerScanner),
+                sqlite_path_validator: Arc::new(FsSqlitePathValidator),
+                metadata_snapshot_store: Arc::new(NullSnapshotStore),
+            },
+            QueryDeps {
+                query_executor: Arc::clone(&registry) as _,
+                connection_pool: Some(Arc::clone(&registry) as _),
+                query_history_store: Arc::new(NullHistoryStore),
+                audit_log_store: Arc::new(NullAuditLogStore),
+                sqlite_diagnostics: Arc::clone(&registry) as _,
+                cached_result_exporter: Arc::new(CsvCachedResultExporter::new()),
+            },
+            ErDeps {
+                er_exporter: Arc::new(DotExporter::new()),
+                config_writer: Arc::new(FileConfigWriter::new()),
+                er_log_writer: Arc::new(FsErLogWriter),
+            },
+            UtilityDeps {
+                clipboard: Arc::new(ArboardClipboard),
+                folder_opener: Arc::new(NativeFolderOpener),
+                temp_file_writer: Arc::new(FsTempFileWriter::new()),
+                query_hook_runner: Arc::new(ShellQueryHookRunner),
+                sql_file_scanner: Arc::new(FsSqlFileScanner),
+                sql_file_store: Arc::new(FsSqlFileStore),
+                snippet_store: Arc::new(TomlSnippetStore),
+                cache_store: Arc::new(FileCacheStore::new()),
+                release_checker: Arc::new(GithubReleaseChecker),
+            },
+            SettingsDeps {
+                settings_store: Arc::new(TomlSettingsStore::with_config_dir(
+                    config_dir.path().to_path_buf(),
+                )),
+            },
+            TtlCache::new(300),
+            action_tx,
+        );
+        let services = AppServices {
+            ddl_generator: Arc::clone(&registry) as _,
+            sql_dialect: Arc::clone(&registry) as _,
+            dsn_builder: registry as _,
+        };
+
+        Self {
+            state,
+            renderer: TestRenderer::new(),
+            runner,
+            services,
+            completion_engine: RefCell::new(CompletionEngine::new()),
+            action_rx,
+            lanes: ActionLanes::default(),
+            _config_dir: config_dir,
+        }
+    }
+
+    pub async fn dispatch(&mut self, action: Action) {
+        let mut pending = vec![action];
+        let mut depth = 0;
+        while !pending.is_empty() && depth < MAX_DEPTH {
+            depth += 1;
+            let mut next = Vec::new();
+            for action in pending {
+                let mut effects = reduce(&mut self.state, action, Instant::now(), &self.services);
+                if self.state.render_dirty {
+                    effects.push(Effect::Render);
+                }
+                next.extend(
+                    self.runner
+                        .run(
+                            effects,
+                            &mut self.renderer,
+                            &mut self.state,
+                            &self.completion_engine,
+                            &self.services,
+                        )
+                        .await
+                        .unwrap(),
+                );
+                self.state.clear_dirty();
+            }
+            pending = next;
+        }
+    }
+
+    /// Feeds background actions (query results, metadata loads) back into the
+    /// app until `done` holds; panics with the current screen on timeout.
+    pub async fn run_until(&mut self, timeout: Duration, done: impl Fn(&AppState) -> bool) {
+        let deadline = tokio::time::Instant::now() + timeout;
+        while !done(&self.state) {
+            while let Ok(action) = self.action_rx.try_recv() {
+                self.lanes.push(action);
+            }
+            if let Some(action) = self.lanes.pop() {
+                self.dispatch(action).await;
+                continue;
+            }
+            match tokio::time::timeout_at(deadline, self.action_rx.recv()).await {
+                Ok(Some(action)) => self.lanes.push(action),
+                Ok(None) | Err(_) => panic!(
+                    "condition not reached within {timeout:?}; screen:\n{}",
+                    self.screen()
+                ),
+            }
+        }
+    }
+
+    pub async fn render(&mut self) -> String {
+        self.runner
+            .run(
+                vec![Effect::Render],
+                &mut self.renderer,
+                &mut self.state,
+                &self.completion_engine,
+                &self.services,
+            )
+            .await
+            .unwrap();
+        self.screen()
+    }
+
+    pub fn screen(&self) -> String {
+        self.renderer.screen()
+    }
+}
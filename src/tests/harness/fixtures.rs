@@ -19,6 +19,9 @@ pub fn minimal_table(schema: &str, name: &str) -> Table {
         comment: None,
         source_ddl: None,
         kind_info: TableKindInfo::default(),
+        hypertable: None,
+        column_stats: Vec::new(),
+        storage: None,
     }
 }
 
@@ -1,3 +1,4 @@
+pub mod app;
 pub mod fixtures;
 pub mod postgres;
 
@@ -112,6 +113,8 @@ pub fn render_and_get_buffer_at_with_theme_and_services(
     theme: &ThemePalette,
     services: &AppServices,
 ) -> Buffer {
+    // Mirrors the runner's render effect.
+    state.sql_modal.refresh_editor_tokens();
     terminal
         .draw(|frame| {
             let output = MainLayout::render_with_theme(
@@ -1,4 +1,5 @@
 mod adapter_postgres;
+mod e2e_postgres;
 pub mod harness;
 
 use clap::Parser;
@@ -28,6 +29,61 @@ fn database_positional_is_recognized() {
     assert!(args.command.is_none());
 }
 
+#[test]
+fn demo_flag_is_recognized() {
+    let args = Args::parse_from(["sabiql", "--demo"]);
+    assert!(args.demo);
+    assert!(args.database.is_none());
+}
+
+#[test]
+fn demo_flag_conflicts_with_database() {
+    assert!(Args::try_parse_from(["sabiql", "--demo", "/tmp/app.db"]).is_err());
+}
+
+#[test]
+fn mock_flag_accepts_latency_failure_rate_and_seed() {
+    let args = Args::parse_from([
+        "sabiql",
+        "--mock",
+        "--mock-latency-ms",
+        "300",
+        "--mock-failure-rate",
+        "0.25",
+        "--mock-seed",
+        "9",
+    ]);
+
+    assert!(args.mock);
+    assert_eq!(args.mock_latency_ms, 300);
+    assert!((args.mock_failure_rate - 0.25).abs() < f64::EPSILON);
+    assert_eq!(args.mock_seed, 9);
+    assert!(args.mock_schema.is_none());
+}
+
+#[test]
+fn mock_flag_rejects_invalid_combinations() {
+    for argv in [
+        &["sabiql", "--mock", "--demo"][..],
+        &["sabiql", "--mock", "/tmp/app.db"],
+        &["sabiql", "--mock-latency-ms", "100"],
+        &["sabiql", "--mock", "--mock-failure-rate", "1.5"],
+    ] {
+        assert!(Args::try_parse_from(argv).is_err(), "{argv:?}");
+    }
+}
+
+#[test]
+fn metrics_file_is_opt_in() {
+    assert!(Args::parse_from(["sabiql"]).metrics_file.is_none());
+
+    let args = Args::parse_from(["sabiql", "--metrics-file", "/tmp/sabiql.prom"]);
+    assert_eq!(
+        args.metrics_file.as_deref(),
+        Some(std::path::Path::new("/tmp/sabiql.prom"))
+    );
+}
+
 mod cli_sqlite_startup {
     use std::fs;
     use std::path::Path;
@@ -36,7 +92,7 @@ mod cli_sqlite_startup {
         activate_cli_sqlite_connection, connection_id_for_path, resolve_cli_sqlite_target,
     };
     use sabiql_app::model::app_state::AppState;
-    use sabiql_app::ports::outbound::{AccessMode, QueryExecutor};
+    use sabiql_app::ports::outbound::{AccessMode, PreviewOptions, QueryExecutor};
     use sabiql_infra::adapters::{FsSqlitePathValidator, SqliteAdapter};
     use tempfile::tempdir;
 
@@ -163,7 +219,14 @@ mod cli_sqlite_startup {
         symlink(&database_b, &alias).unwrap();
 
         let preview = adapter
-            .execute_preview(&symlinked.1, "main", "items", 10, 0)
+            .execute_preview(
+                &symlinked.1,
+                "main",
+                "items",
+                PreviewOptions::default(),
+                10,
+                0,
+            )
             .await
             .unwrap();
         assert_eq!(preview.display_value_at(0, 1).as_deref(), Some("A"));
@@ -179,7 +242,14 @@ mod cli_sqlite_startup {
         assert_eq!(write.affected_rows, 1);
 
         let updated_a = adapter
-            .execute_preview(&symlinked.1, "main", "items", 10, 0)
+            .execute_preview(
+                &symlinked.1,
+                "main",
+                "items",
+                PreviewOptions::default(),
+                10,
+                0,
+            )
             .await
             .unwrap();
         assert_eq!(
@@ -189,7 +259,14 @@ mod cli_sqlite_startup {
 
         let database_b_dsn = format!("sqlite://{}", database_b.display());
         let unchanged_b = adapter
-            .execute_preview(&database_b_dsn, "main", "items", 10, 0)
+            .execute_preview(
+                &database_b_dsn,
+                "main",
+                "items",
+                PreviewOptions::default(),
+                10,
+                0,
+            )
             .await
             .unwrap();
         assert_eq!(unchanged_b.display_value_at(0, 1).as_deref(), Some("B"));
@@ -250,3 +327,37 @@ mod dispatch_overflow_fallback {
         assert!(error.contains("2 actions dropped"), "got: {error}");
     }
 }
+
+mod crash_report {
+    use std::backtrace::Backtrace;
+
+    use sabiql_app::update::action::Action;
+
+    use crate::panic_hooks::{crash_report, summarize_action};
+
+    #[test]
+    fn report_lists_version_panic_and_actions_in_order() {
+        let actions = vec!["Quit".to_string(), "Resize(80, 24)".to_string()];
+
+        let report = crash_report("boom", &Backtrace::disabled(), &actions, 42);
+
+        assert!(report.starts_with(&format!("sabiql {}\n", env!("CARGO_PKG_VERSION"))));
+        assert!(report.contains("## Panic\nboom"));
+        assert!(report.contains("## Last 2 actions (oldest first)\nQuit\nResize(80, 24)\n"));
+        assert!(report.contains("## Backtrace"));
+    }
+
+    #[test]
+    fn action_summary_truncates_large_payloads() {
+        let summary = summarize_action(&Action::ExecuteAdhoc("x".repeat(1_000)));
+
+        assert!(summary.starts_with("ExecuteAdhoc(\"xxx"));
+        assert!(summary.ends_with('…'));
+        assert!(summary.chars().count() <= 201);
+    }
+
+    #[test]
+    fn action_summary_keeps_small_actions_intact() {
+        assert_eq!(summarize_action(&Action::Resize(80, 24)), "Resize(80, 24)");
+    }
+}
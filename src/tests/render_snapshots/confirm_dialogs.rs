@@ -149,6 +149,39 @@ fn confirm_dialog_delete_preview_low_risk() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn confirm_dialog_delete_preview_production_typed() {
+    let mut state = connected_state();
+    let mut terminal = create_test_terminal();
+
+    let sql = "DELETE FROM \"public\".\"users\"\nWHERE \"id\" = '3';".to_string();
+    state.result_interaction.set_write_preview(WritePreview {
+        operation: WriteOperation::Delete,
+        sql: sql.clone(),
+        target_summary: TargetSummary {
+            schema: "public".to_string(),
+            table: "users".to_string(),
+            key_values: vec![("id".to_string(), QueryValue::text("3"))],
+        },
+        diff: vec![],
+        guardrail: GuardrailDecision {
+            risk_level: RiskLevel::High,
+            blocked: false,
+            reason: Some("Production connection".to_string()),
+            target_summary: None,
+        },
+    });
+    open_write_confirm(&mut state, "Confirm DELETE: 1 row from users", &sql);
+    state.confirm_dialog.require_input("users");
+    for ch in "use".chars() {
+        state.confirm_dialog.input_mut().insert_char(ch);
+    }
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn confirm_dialog_update_preview_long_jsonb() {
     let mut state = connected_state();
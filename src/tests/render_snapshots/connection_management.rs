@@ -1,7 +1,11 @@
 use super::*;
+use sabiql_app::model::connection::import::CandidateDiscovery;
 use sabiql_app::model::shared::confirm_dialog::ConfirmIntent;
+use sabiql_app::ports::outbound::{ConnectionCandidate, ImportSource};
 use sabiql_domain::connection::ServiceEntry;
-use sabiql_domain::connection::{ConnectionId, ConnectionProfile, SslMode};
+use sabiql_domain::connection::{
+    ConnectionConfig, ConnectionId, ConnectionProfile, PostgresConnectionConfig, SslMode,
+};
 
 fn three_connections() -> (ConnectionId, Vec<ConnectionProfile>) {
     let active_id = ConnectionId::new();
@@ -220,3 +224,75 @@ fn confirm_dialog_delete_inactive_connection() {
 
     insta::assert_snapshot!(output);
 }
+
+#[test]
+fn connection_import_picker_with_candidates() {
+    let mut state = create_test_state();
+    let mut terminal = create_test_terminal();
+
+    state.connection_import.set_loaded(vec![
+        ConnectionCandidate {
+            name: "compose-db".to_string(),
+            source: ImportSource::DockerCompose,
+            config: ConnectionConfig::PostgreSQL(PostgresConnectionConfig::new(
+                "localhost",
+                5433,
+                "app",
+                "postgres",
+                "secret",
+                SslMode::Prefer,
+            )),
+        },
+        ConnectionCandidate {
+            name: "orders".to_string(),
+            source: ImportSource::PgPass,
+            config: ConnectionConfig::PostgreSQL(PostgresConnectionConfig::new(
+                "db.internal",
+                5432,
+                "orders",
+                "app",
+                "",
+                SslMode::Prefer,
+            )),
+        },
+    ]);
+    state.connection_import.select_next();
+    state.modal.set_mode(InputMode::ConnectionImport);
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn connection_import_picker_while_scanning() {
+    let mut state = create_test_state();
+    let mut terminal = create_test_terminal();
+
+    state
+        .connection_import
+        .begin_scan(CandidateDiscovery::ProjectFiles);
+    state.modal.set_mode(InputMode::ConnectionImport);
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn container_discovery_picker_with_socket_error() {
+    let mut state = create_test_state();
+    let mut terminal = create_test_terminal();
+
+    state
+        .connection_import
+        .begin_scan(CandidateDiscovery::Containers);
+    state.connection_import.set_failed(
+        "No Docker or Podman socket found (set DOCKER_HOST to point at one)".to_string(),
+    );
+    state.modal.set_mode(InputMode::ConnectionImport);
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
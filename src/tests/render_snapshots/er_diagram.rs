@@ -1,5 +1,9 @@
 use super::*;
 use harness::explorer_selected_state;
+use sabiql_app::model::app_state::AppState;
+use sabiql_app::model::er_viewer::ErViewerState;
+use sabiql_domain::ErTableInfo;
+use sabiql_domain::er::ErFkInfo;
 
 #[test]
 fn er_waiting_progress() {
@@ -89,3 +93,67 @@ fn er_table_picker_all_selected() {
 
     insta::assert_snapshot!(output);
 }
+
+fn er_viewer_state() -> AppState {
+    fn table(name: &str, references: &[&str]) -> ErTableInfo {
+        ErTableInfo {
+            qualified_name: format!("public.{name}"),
+            name: name.to_string(),
+            schema: "public".to_string(),
+            foreign_keys: references
+                .iter()
+                .map(|parent| ErFkInfo {
+                    name: format!("{name}_{parent}_fkey"),
+                    from_qualified: format!("public.{name}"),
+                    to_qualified: format!("public.{parent}"),
+                })
+                .collect(),
+        }
+    }
+
+    let mut state = connected_state();
+    state.er_viewer = ErViewerState::open(
+        vec![
+            table("users", &[]),
+            table("posts", &["users"]),
+            table("comments", &["posts", "users"]),
+            table("tags", &[]),
+        ],
+        &["public.posts".to_string()],
+    );
+    state.modal.set_mode(InputMode::ErDiagram);
+    state
+}
+
+#[test]
+fn er_viewer_boxes_and_edges() {
+    let mut state = er_viewer_state();
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn er_viewer_focus_mode_hides_unrelated_tables() {
+    let mut state = er_viewer_state();
+    state.er_viewer.toggle_focus();
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    assert!(!output.contains("tags"));
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn er_viewer_compact_zoom() {
+    let mut state = er_viewer_state();
+    state.er_viewer.zoom_out();
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
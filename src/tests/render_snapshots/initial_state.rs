@@ -82,3 +82,20 @@ fn header_shows_read_only_badge_at_narrow_width() {
 
     insta::assert_snapshot!(output);
 }
+
+#[test]
+fn header_shows_shared_cache_badge_for_second_instance() {
+    let mut state = connected_state();
+    state.session.activate_connection_with_dsn(
+        &ConnectionId::new(),
+        "test",
+        DatabaseType::PostgreSQL,
+        "postgresql://localhost/test",
+    );
+    state.runtime.set_cache_owner_pid(Some(4242));
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
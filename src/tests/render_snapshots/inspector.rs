@@ -1,7 +1,10 @@
 use super::*;
 use harness::table_detail_loaded_state;
 use sabiql_app::model::shared::inspector_tab::InspectorTab;
-use sabiql_domain::{ConnectionId, TableKind, TableKindInfo};
+use sabiql_domain::{
+    ColumnDistribution, CommonValue, ConnectionId, RlsCommand, RlsInfo, RlsPolicy, TableKind,
+    TableKindInfo, TableStorage,
+};
 
 #[test]
 fn inspector_columns_narrow_pane_keeps_horizontal_scroll() {
@@ -296,6 +299,76 @@ fn inspector_triggers_tab_with_data() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn inspector_stats_tab_shows_common_value_bars() {
+    let mut state = table_detail_loaded_state();
+    let mut terminal = create_test_terminal();
+
+    state.ui.set_inspector_tab(InspectorTab::Stats);
+    state.ui.set_focused_pane(FocusedPane::Inspector);
+    state.ui.set_inspector_column_cursor(1);
+    let (table, column) = {
+        let table = state.session.table_detail().unwrap();
+        (table.qualified_name(), table.columns[1].name.clone())
+    };
+    let run_id = state.column_distribution.begin_fetch(&table, &column);
+    state.column_distribution.set_loaded(
+        run_id,
+        ColumnDistribution {
+            sampled_rows: 1200,
+            min: Some("Ada".to_string()),
+            max: Some("Zoe".to_string()),
+            avg: None,
+            null_frac: Some(0.05),
+            n_distinct: Some(340.0),
+            common_values: vec![
+                CommonValue {
+                    value: "Alice".to_string(),
+                    frequency: 0.12,
+                },
+                CommonValue {
+                    value: "Bob".to_string(),
+                    frequency: 0.06,
+                },
+                CommonValue {
+                    value: "Carol".to_string(),
+                    frequency: 0.03,
+                },
+            ],
+        },
+    );
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn inspector_storage_tab_shows_sizes_and_vacuum_stats() {
+    let mut state = harness::explorer_selected_state();
+    let mut terminal = create_test_terminal();
+
+    let mut table = fixtures::sample_table_detail();
+    table.storage = Some(TableStorage {
+        total_bytes: 24 << 20,
+        table_bytes: 18 << 20,
+        index_bytes: 6 << 20,
+        toast_bytes: 8192,
+        live_tuples: Some(98_500),
+        dead_tuples: Some(1500),
+        last_vacuum: None,
+        last_autovacuum: Some("2026-03-01 04:12:09".to_string()),
+        last_analyze: Some("2026-03-02 10:00:00".to_string()),
+        last_autoanalyze: Some("2026-03-01 04:12:11".to_string()),
+    });
+    let _ = state.session.set_table_detail(table, 0);
+    state.ui.set_inspector_tab(InspectorTab::Storage);
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn inspector_triggers_tab_empty() {
     let mut state = harness::explorer_selected_state();
@@ -458,3 +531,46 @@ fn inspector_info_tab_for_sqlite_shows_view_kind() {
 
     insta::assert_snapshot!(output);
 }
+
+#[test]
+fn inspector_rls_tab_groups_formatted_policies_by_role() {
+    let mut state = harness::explorer_selected_state();
+    let mut terminal = create_test_terminal();
+
+    let mut table = fixtures::sample_table_detail();
+    table.rls = Some(RlsInfo {
+        enabled: true,
+        force: true,
+        policies: vec![
+            RlsPolicy {
+                name: "tenant_isolation".to_string(),
+                permissive: true,
+                roles: vec!["app_user".to_string(), "reporting".to_string()],
+                cmd: RlsCommand::All,
+                qual: Some(
+                    "((tenant_id = (current_setting('app.tenant'::text))::integer) AND (deleted_at IS NULL))"
+                        .to_string(),
+                ),
+                with_check: Some("(tenant_id = (current_setting('app.tenant'::text))::integer)".to_string()),
+            },
+            RlsPolicy {
+                name: "reporting_read".to_string(),
+                permissive: true,
+                roles: vec!["reporting".to_string()],
+                cmd: RlsCommand::Select,
+                qual: Some("true".to_string()),
+                with_check: None,
+            },
+        ],
+    });
+    let _ = state.session.set_table_detail(table, 0);
+    state.ui.set_inspector_tab(InspectorTab::Rls);
+    state.ui.set_focused_pane(FocusedPane::Inspector);
+    state
+        .ui
+        .set_inspector_rls_role(Some("app_user".to_string()));
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
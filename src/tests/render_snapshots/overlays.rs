@@ -43,51 +43,81 @@ fn sql_modal_completion_popup_with_scroll() {
             text: "users".into(),
             kind: CompletionKind::Table,
             score: 100,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "posts".into(),
             kind: CompletionKind::Table,
             score: 90,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "comments".into(),
             kind: CompletionKind::Table,
             score: 80,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "id".into(),
             kind: CompletionKind::Column,
             score: 70,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "name".into(),
             kind: CompletionKind::Column,
             score: 60,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "email".into(),
             kind: CompletionKind::Column,
             score: 50,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "created_at".into(),
             kind: CompletionKind::Column,
             score: 40,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "updated_at".into(),
             kind: CompletionKind::Column,
             score: 30,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "COUNT".into(),
             kind: CompletionKind::Keyword,
             score: 20,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "DISTINCT".into(),
             kind: CompletionKind::Keyword,
             score: 10,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
     ];
     state
@@ -102,6 +132,51 @@ fn sql_modal_completion_popup_with_scroll() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn sql_modal_completion_preview_beside_popup() {
+    let mut state = connected_state();
+    let mut terminal = create_test_terminal();
+
+    state.modal.set_mode(InputMode::SqlModal);
+    state
+        .sql_modal
+        .editor_mut_for_input()
+        .set_content("SELECT * FROM users WHERE ".to_string());
+    state.sql_modal.enter_editing();
+
+    let candidates = vec![
+        CompletionCandidate {
+            text: "email".into(),
+            kind: CompletionKind::Column,
+            score: 100,
+            detail: Some("unique".into()),
+            insert_text: None,
+            preview: vec![
+                "public.users.email".into(),
+                "varchar(255) NOT NULL".into(),
+                "unique".into(),
+                "Login address".into(),
+            ],
+        },
+        CompletionCandidate {
+            text: "id".into(),
+            kind: CompletionKind::Column,
+            score: 90,
+            detail: None,
+            insert_text: None,
+            preview: vec!["public.users.id".into(), "integer NOT NULL".into()],
+        },
+    ];
+    state
+        .sql_modal
+        .apply_completion_update(&candidates, 26, true);
+    state.sql_modal.toggle_completion_preview();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn sql_modal_unknown_risk_acknowledge() {
     let mut state = connected_state();
@@ -238,6 +313,41 @@ fn sql_modal_cursor_at_tail() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn sql_modal_fullscreen() {
+    let mut state = connected_state();
+    let mut terminal = create_test_terminal();
+
+    state.modal.set_mode(InputMode::SqlModal);
+    state
+        .sql_modal
+        .editor_mut_for_input()
+        .set_content("SELECT 1".to_string());
+    state.sql_modal.cycle_size();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn sql_modal_compact_leaves_result_pane_visible() {
+    let mut state = connected_state();
+    let mut terminal = create_test_terminal();
+
+    state.modal.set_mode(InputMode::SqlModal);
+    state
+        .sql_modal
+        .editor_mut_for_input()
+        .set_content("SELECT 1".to_string());
+    state.sql_modal.cycle_size();
+    state.sql_modal.cycle_size();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn sql_modal_ide_editing() {
     let mut state = create_test_state();
@@ -614,14 +724,16 @@ fn query_history_picker_with_entries() {
             ConnectionId::from_string("test-conn"),
             QueryResultStatus::Success,
             None,
-        ),
+        )
+        .with_duration_ms(12),
         QueryHistoryEntry::new(
             "INSERT INTO orders (user_id, total) VALUES (1, 100)".to_string(),
             "2026-03-13T11:00:00Z".to_string(),
             ConnectionId::from_string("test-conn"),
             QueryResultStatus::Success,
             Some(1),
-        ),
+        )
+        .with_duration_ms(23_400),
         QueryHistoryEntry::new(
             "SELECT count(*) FROM users".to_string(),
             "2026-03-13T12:00:00Z".to_string(),
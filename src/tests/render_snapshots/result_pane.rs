@@ -327,6 +327,85 @@ fn result_pane_first_cell_active_mode() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn result_pane_masks_configured_columns() {
+    let mut state = table_detail_loaded_state();
+    let mut terminal = create_test_terminal();
+
+    let original = Arc::new(fixtures::sample_query_result());
+    state.query.set_current_result(Arc::new(
+        fixtures::sample_query_result().with_masked_columns(&[2], "••••••"),
+    ));
+    state
+        .query
+        .hold_unmasked(original, vec!["email".to_string()]);
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn result_pane_footer_shows_explain_analyze_resource_usage() {
+    let mut state = table_detail_loaded_state();
+    let mut terminal = create_test_terminal();
+
+    let plan = [
+        "Sort  (cost=10.00..12.00 rows=100 width=8) (actual time=4.1..4.9 rows=100 loops=1)",
+        "  Buffers: shared hit=120 read=30, temp read=64 written=64",
+        "  I/O Timings: shared read=3.300",
+        "  ->  Seq Scan on users  (cost=0.00..8.00 rows=100 width=8)",
+        "Execution Time: 5.300 ms",
+    ];
+    state
+        .query
+        .set_current_result(Arc::new(QueryResult::success(
+            "EXPLAIN (ANALYZE, BUFFERS) SELECT * FROM users ORDER BY name".to_string(),
+            vec!["QUERY PLAN".to_string()],
+            plan.iter().map(|line| vec![(*line).to_string()]).collect(),
+            6,
+            QuerySource::Adhoc,
+        )));
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn result_pane_title_shows_preview_page_window() {
+    let mut state = table_detail_loaded_state();
+    let mut terminal = create_test_terminal();
+
+    with_current_result(&mut state);
+    state
+        .query
+        .pagination
+        .reset_for_table_with_estimate("public", "users", Some(12_345));
+    state.query.pagination.set_current_page(1);
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn result_pane_title_shows_preview_filter() {
+    let mut state = table_detail_loaded_state();
+    let mut terminal = create_test_terminal();
+
+    with_current_result(&mut state);
+    state.query.pagination.reset_for_table("public", "users");
+    state
+        .query
+        .pagination
+        .set_filter(Some("email LIKE '%@example.com'".to_string()));
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn result_pane_cell_active_mode() {
     let mut state = table_detail_loaded_state();
@@ -699,3 +778,60 @@ fn result_pane_row_detail_mode() {
 
     insta::assert_snapshot!(output);
 }
+
+fn long_value_state() -> AppState {
+    let mut state = table_detail_loaded_state();
+    let long_value = format!("{}tailmarker", "lorem ipsum ".repeat(30));
+    state
+        .query
+        .set_current_result(Arc::new(QueryResult::success(
+            "SELECT id, body FROM notes".to_string(),
+            vec!["id".to_string(), "body".to_string()],
+            vec![vec!["1".to_string(), long_value]],
+            1,
+            QuerySource::Adhoc,
+        )));
+    state.ui.set_focused_pane(FocusedPane::Result);
+    state.result_interaction.activate_cell(0, 1);
+    state
+}
+
+fn buffer_text(buffer: &ratatui::buffer::Buffer) -> String {
+    buffer
+        .content()
+        .iter()
+        .map(ratatui::buffer::Cell::symbol)
+        .collect()
+}
+
+#[test]
+fn truncated_cell_peek_appears_after_dwell() {
+    let mut state = long_value_state();
+    let now = test_instant();
+    state.result_interaction.sync_cell_peek(now);
+    let mut terminal = create_test_terminal();
+
+    let before = render_and_get_buffer_at(&mut terminal, &mut state, now);
+    assert!(!buffer_text(&before).contains("tailmarker"));
+    assert!(state.ui.result_active_cell_truncated());
+
+    let after = render_and_get_buffer_at(
+        &mut terminal,
+        &mut state,
+        now + std::time::Duration::from_millis(600),
+    );
+    assert!(buffer_text(&after).contains("tailmarker"));
+}
+
+#[test]
+fn pinned_cell_peek_shows_immediately() {
+    let mut state = long_value_state();
+    let now = test_instant();
+    state.result_interaction.sync_cell_peek(now);
+    state.result_interaction.pin_cell_peek();
+    let mut terminal = create_test_terminal();
+
+    let buffer = render_and_get_buffer_at(&mut terminal, &mut state, now);
+
+    assert!(buffer_text(&buffer).contains("tailmarker"));
+}
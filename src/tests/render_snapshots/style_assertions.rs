@@ -186,6 +186,24 @@ fn staged_delete_row_uses_dark_red_bg() {
     );
 }
 
+#[test]
+fn marked_row_keeps_marked_bg_in_scroll_mode() {
+    let mut state = table_detail_loaded_state();
+    let mut terminal = create_test_terminal();
+
+    with_current_result(&mut state);
+    state.ui.set_focused_pane(FocusedPane::Result);
+    state.result_interaction.activate_cell(1, 0);
+    state.result_interaction.toggle_row_mark(1);
+    state.result_interaction.exit_cell_to_scroll();
+
+    let buffer = render_and_get_buffer(&mut terminal, &mut state);
+
+    assert!(has_cell(&buffer, |cell| {
+        cell.bg == DEFAULT_THEME.component.table.marked_row_bg
+    }));
+}
+
 #[test]
 fn scrim_applies_dim_modifier() {
     let mut state = connected_state();
@@ -494,6 +512,35 @@ fn sql_modal_keyword_and_number_use_syntax_colors() {
     assert!(number_cell.is_some(), "Expected a yellow SQL number cell");
 }
 
+#[test]
+fn sql_modal_visual_line_highlights_selected_rows() {
+    let mut state = create_test_state();
+    let mut terminal = create_test_terminal();
+
+    state.modal.set_mode(InputMode::SqlModal);
+    state
+        .sql_modal
+        .editor_mut_for_input()
+        .set_content_with_cursor("SELECT 1\nFROM t\nWHERE x".to_string(), 0);
+    state.sql_modal.enter_normal();
+    state.sql_modal.vim_mut().start_visual_line(1);
+
+    let buffer = render_and_get_buffer(&mut terminal, &mut state);
+
+    let selected = |symbol: &'static str| {
+        has_cell(&buffer, move |cell| {
+            cell.symbol() == symbol
+                && cell.bg == DEFAULT_THEME.component.editor.completion_selected_bg
+        })
+    };
+    assert!(selected("L"), "Expected the cursor row to be selected");
+    assert!(selected("F"), "Expected the anchor row to be selected");
+    assert!(
+        !selected("W"),
+        "Expected rows outside the selection to stay plain"
+    );
+}
+
 #[test]
 fn sql_modal_string_comment_and_operator_use_syntax_colors() {
     let mut state = create_test_state();
@@ -1080,6 +1127,105 @@ fn test_contrast_theme_applies_result_pane_table_colors() {
     );
 }
 
+#[test]
+fn result_cell_range_is_highlighted_around_the_active_cell() {
+    let mut state = table_detail_loaded_state();
+    let now = test_instant();
+    let mut terminal = create_test_terminal();
+
+    with_current_result(&mut state);
+    state.ui.set_focused_pane(FocusedPane::Result);
+    state.result_interaction.activate_cell(0, 0);
+    state.result_interaction.start_range();
+    state.result_interaction.move_row(1);
+    state.result_interaction.move_cell(1);
+
+    let buffer =
+        render_and_get_buffer_at_with_theme(&mut terminal, &mut state, now, &TEST_CONTRAST_THEME);
+    let has_range_bg = has_cell(&buffer, |cell| {
+        cell.bg == TEST_CONTRAST_THEME.component.table.result_range_bg
+    });
+    let has_active_cell_bg = has_cell(&buffer, |cell| {
+        cell.bg == TEST_CONTRAST_THEME.component.table.result_cell_active_bg
+    });
+
+    assert!(has_range_bg, "Expected range cells to use result_range_bg");
+    assert!(
+        has_active_cell_bg,
+        "Expected the active cell to keep its own highlight inside a range"
+    );
+}
+
+#[test]
+fn pinned_inspector_column_is_traced_into_the_preview() {
+    let mut state = table_detail_loaded_state();
+    let now = test_instant();
+    let mut terminal = create_test_terminal();
+
+    with_current_result(&mut state);
+    state
+        .ui
+        .set_inspector_pinned_column(Some("email".to_string()));
+
+    let buffer =
+        render_and_get_buffer_at_with_theme(&mut terminal, &mut state, now, &TEST_CONTRAST_THEME);
+    let pinned_fg = TEST_CONTRAST_THEME.component.table.pinned_column_fg;
+    let has_pinned_cell = has_cell(&buffer, |cell| cell.symbol() == "@" && cell.fg == pinned_fg);
+    let has_unpinned_cell = has_cell(&buffer, |cell| cell.symbol() == "B" && cell.fg == pinned_fg);
+
+    assert!(
+        has_pinned_cell,
+        "Expected the pinned column's values to use pinned_column_fg"
+    );
+    assert!(
+        !has_unpinned_cell,
+        "Expected other columns to keep their colors"
+    );
+}
+
+#[test]
+fn rerun_highlights_changed_cells_and_strikes_old_values() {
+    let mut state = table_detail_loaded_state();
+    let now = test_instant();
+    let mut terminal = create_test_terminal();
+
+    let previous = fixtures::sample_query_result();
+    let mut rows: Vec<Vec<String>> = (0..previous.data_row_count())
+        .map(|row| previous.display_row_at(row).unwrap())
+        .collect();
+    rows[1][1] = "Quinn".to_string();
+    let current = QueryResult::success(
+        previous.query.clone(),
+        previous.columns.clone(),
+        rows,
+        15,
+        previous.source,
+    );
+    state.query.set_rerun_result(Arc::new(previous));
+    state.query.set_rerun_result(Arc::new(current));
+    state.query.toggle_diff_old_values();
+
+    let buffer =
+        render_and_get_buffer_at_with_theme(&mut terminal, &mut state, now, &TEST_CONTRAST_THEME);
+    let changed_fg = TEST_CONTRAST_THEME.component.table.changed_cell_fg;
+
+    assert!(
+        has_cell(&buffer, |cell| cell.symbol() == "Q"
+            && cell.fg == changed_fg),
+        "Expected the changed cell to use changed_cell_fg"
+    );
+    assert!(
+        !has_cell(&buffer, |cell| cell.symbol() == "A"
+            && cell.fg == changed_fg),
+        "Expected unchanged cells to keep their colors"
+    );
+    assert!(
+        has_cell(&buffer, |cell| cell.symbol() == "B"
+            && cell.modifier.contains(Modifier::CROSSED_OUT)),
+        "Expected the old value to be struck through"
+    );
+}
+
 #[test]
 fn sql_completion_popup_uses_injected_theme_styles() {
     let mut state = connected_state();
@@ -1111,11 +1257,17 @@ fn sql_completion_popup_uses_injected_theme_styles() {
             text: "users".into(),
             kind: CompletionKind::Table,
             score: 100,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
         CompletionCandidate {
             text: "posts".into(),
             kind: CompletionKind::Table,
             score: 90,
+            detail: None,
+            insert_text: None,
+            preview: Vec::new(),
         },
     ];
     state
@@ -1223,3 +1375,38 @@ fn light_theme_applies_shell_and_sql_colors() {
     assert!(has_light_keyword, "Expected SQL keyword to use light theme");
     assert!(has_light_number, "Expected SQL number to use light theme");
 }
+
+#[test]
+fn sql_modal_underlines_lint_warning_tokens() {
+    let mut state = create_test_state();
+    let mut terminal = create_test_terminal();
+
+    state.modal.set_mode(InputMode::SqlModal);
+    state
+        .sql_modal
+        .editor_mut_for_input()
+        .set_content("SELECT * FROM users".to_string());
+    state.sql_modal.enter_editing();
+
+    let buffer = render_and_get_buffer(&mut terminal, &mut state);
+
+    let star_underlined = has_cell(&buffer, |cell| {
+        cell.symbol() == "*"
+            && cell.modifier.contains(Modifier::UNDERLINED)
+            && cell.underline_color == DEFAULT_THEME.semantic.status.warning
+    });
+    let keyword_underlined = has_cell(&buffer, |cell| {
+        cell.symbol() == "S"
+            && cell.fg == DEFAULT_THEME.component.syntax.sql_keyword
+            && cell.modifier.contains(Modifier::UNDERLINED)
+    });
+
+    assert!(
+        star_underlined,
+        "Expected the SELECT * wildcard to be underlined"
+    );
+    assert!(
+        !keyword_underlined,
+        "Expected unflagged tokens to stay plain"
+    );
+}
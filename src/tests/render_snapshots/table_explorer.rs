@@ -1,8 +1,10 @@
 use super::*;
 use harness::{explorer_selected_state, table_detail_loaded_state, with_current_result};
+use sabiql_app::model::app_state::AppState;
 use sabiql_app::model::shared::ui_state::FocusMode;
 use sabiql_domain::{
-    ConnectionId, DatabaseMetadata, Schema, TableKind, TableKindInfo, TableSummary,
+    ConnectionId, DatabaseMetadata, Schema, SchemaObject, SchemaObjectKind, TableKind,
+    TableKindInfo, TableSummary,
 };
 
 #[test]
@@ -121,3 +123,165 @@ fn sqlite_explorer_shows_table_kind_suffixes() {
 
     insta::assert_snapshot!(output);
 }
+
+#[test]
+fn explorer_title_counts_marked_tables() {
+    let mut state = explorer_selected_state();
+    let names: Vec<String> = state
+        .tables()
+        .into_iter()
+        .take(2)
+        .map(TableSummary::qualified_name)
+        .collect();
+    for name in names {
+        state.ui.toggle_explorer_marked_table(name);
+    }
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+fn multi_schema_state() -> AppState {
+    let mut state = create_test_state();
+    let metadata = {
+        let mut metadata = DatabaseMetadata::new("app".to_string());
+        metadata.schemas = vec![
+            Schema::new("audit"),
+            Schema::new("billing"),
+            Schema::new("public"),
+        ];
+        metadata.table_summaries = vec![
+            TableSummary::new("audit".to_string(), "events".to_string(), None, false),
+            TableSummary::new("billing".to_string(), "invoices".to_string(), None, false),
+            TableSummary::new("billing".to_string(), "payments".to_string(), None, false),
+            TableSummary::new("public".to_string(), "orders".to_string(), None, false),
+            TableSummary::new(
+                "public".to_string(),
+                "active_users".to_string(),
+                None,
+                false,
+            )
+            .with_kind_info(fixtures::view_kind_info()),
+        ];
+        metadata
+    };
+    state.session.mark_connected(Arc::new(metadata));
+    state
+}
+
+#[test]
+fn explorer_groups_tables_under_schemas() {
+    let mut state = multi_schema_state();
+    state.explorer_tree.toggle_schema("billing");
+    state.ui.set_explorer_selection(Some(2));
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn explorer_schema_filter_shows_flat_list() {
+    let mut state = multi_schema_state();
+    state
+        .explorer_tree
+        .set_schema_filter(Some("billing".to_string()));
+    state.ui.set_explorer_selection(Some(0));
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn explorer_lists_objects_under_sections_and_inspects_a_view() {
+    let mut state = create_test_state();
+    let view = SchemaObject {
+        schema: "public".to_string(),
+        name: "active_users".to_string(),
+        kind: SchemaObjectKind::View,
+        arguments: None,
+        definition: Some(
+            "CREATE VIEW public.active_users AS\n SELECT users.id,\n    users.email\n   FROM users\n  WHERE users.active;"
+                .to_string(),
+        ),
+    };
+    let metadata = {
+        let mut metadata = DatabaseMetadata::new("app".to_string());
+        metadata.schemas = vec![Schema::new("public")];
+        metadata.table_summaries = vec![TableSummary::new(
+            "public".to_string(),
+            "users".to_string(),
+            None,
+            false,
+        )];
+        metadata.objects = vec![
+            view.clone(),
+            SchemaObject {
+                schema: "public".to_string(),
+                name: "users_id_seq".to_string(),
+                kind: SchemaObjectKind::Sequence,
+                arguments: None,
+                definition: None,
+            },
+            SchemaObject {
+                schema: "public".to_string(),
+                name: "full_name".to_string(),
+                kind: SchemaObjectKind::Function,
+                arguments: Some("first text, last text".to_string()),
+                definition: None,
+            },
+        ];
+        metadata
+    };
+    state.session.mark_connected(Arc::new(metadata));
+    state
+        .explorer_tree
+        .toggle_section("public", SchemaObjectKind::View);
+    state
+        .explorer_tree
+        .toggle_section("public", SchemaObjectKind::Function);
+    state.session.inspect_object(&view, &mut state.query);
+    state.ui.set_explorer_selection(Some(2));
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn object_search_lists_tables_and_columns_with_comments() {
+    let mut state = table_detail_loaded_state();
+    let metadata = state.session.metadata().cloned().unwrap();
+    state.object_search.refresh(&metadata);
+    state
+        .object_search
+        .index_table(state.session.table_detail().unwrap());
+    state.object_search.picker_mut().insert_filter_str("users");
+    state.modal.set_mode(InputMode::ObjectSearch);
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn quick_actions_menu_on_selected_table() {
+    let mut state = explorer_selected_state();
+    let table = state.explorer_selected_table().cloned().unwrap();
+    state.explorer_tree.toggle_favorite(&table.qualified_name());
+    state.quick_actions.open(table.schema, table.name);
+    state.quick_actions.select_next();
+    state.modal.set_mode(InputMode::QuickActions);
+    let mut terminal = create_test_terminal();
+
+    let output = render_to_string(&mut terminal, &mut state);
+
+    insta::assert_snapshot!(output);
+}
@@ -23,20 +23,23 @@ pub fn translate(event: KeyEvent) -> KeyCombo {
         KeyCode::Null => Key::Null,
         _ => Key::Other,
     };
+    let ctrl = event.modifiers.contains(KeyModifiers::CONTROL);
+    // Legacy terminals send Ctrl+_ as 0x1F, which crossterm reports as Ctrl+7.
+    let key = match key {
+        Key::Char('7') if ctrl => Key::Char('_'),
+        other => other,
+    };
 
     // Normalize: uppercase Key::Char already encodes shift, so drop the shift
     // flag to prevent double-encoding (e.g. Kitty sends 'G' + SHIFT).
     let raw_shift = event.modifiers.contains(KeyModifiers::SHIFT);
     let shift = match key {
-        Key::Char(c) if c.is_ascii_uppercase() => false,
+        Key::Char(c) if c.is_ascii_uppercase() || c == '_' => false,
         _ => raw_shift,
     };
 
     let mut modifiers = Modifiers::empty();
-    modifiers.set(
-        Modifiers::CTRL,
-        event.modifiers.contains(KeyModifiers::CONTROL),
-    );
+    modifiers.set(Modifiers::CTRL, ctrl);
     modifiers.set(Modifiers::ALT, event.modifiers.contains(KeyModifiers::ALT));
     modifiers.set(Modifiers::SHIFT, shift);
 
@@ -184,6 +187,23 @@ mod tests {
         assert_eq!(combo, KeyCombo::ctrl_shift(Key::Char('d')));
     }
 
+    #[test]
+    fn legacy_ctrl_seven_translates_to_ctrl_underscore() {
+        let combo = translate(KeyEvent::new(KeyCode::Char('7'), KeyModifiers::CONTROL));
+
+        assert_eq!(combo, KeyCombo::ctrl(Key::Char('_')));
+    }
+
+    #[test]
+    fn ctrl_shift_underscore_drops_implied_shift() {
+        let combo = translate(KeyEvent::new(
+            KeyCode::Char('_'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        ));
+
+        assert_eq!(combo, KeyCombo::ctrl(Key::Char('_')));
+    }
+
     mod sqlite_diagnostics_binding {
         use super::*;
         use crate::app::model::app_state::AppState;
@@ -5,6 +5,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Paragraph, Wrap};
 use unicode_casefold::UnicodeCaseFold;
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::shared::detail_view::DetailDisplayMode;
 use crate::app::model::shared::flash_timer::FlashId;
@@ -32,7 +33,8 @@ impl CellDetail {
         }
 
         let title = format!(
-            " Cell Detail \u{2500}\u{2500} {}",
+            " {} \u{2500}\u{2500} {}",
+            Message::CellDetailTitle.text(state.settings.locale()),
             state.cell_detail.column_name()
         );
         let hints = vec![("y", "Copy"), ("/", "Search"), ("Esc", "Close")];
@@ -4,12 +4,15 @@ use ratatui::style::{Modifier, Style};
 use ratatui::widgets::{List, ListItem, ListState};
 use unicode_width::UnicodeWidthChar;
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::shared::focused_pane::FocusedPane;
 use crate::app::model::shared::ui_state::{
     explorer_content_width_from_inner_width, scroll_max_offset, text_display_width,
 };
-use crate::app::policy::table_kind::{explorer_table_label, max_explorer_table_label_width};
+use crate::app::policy::explorer_tree::{
+    ExplorerRow, explorer_row_label, max_explorer_row_label_width,
+};
 use crate::domain::MetadataState;
 use crate::theme::ThemePalette;
 
@@ -20,7 +23,22 @@ pub struct Explorer;
 impl Explorer {
     pub fn render(frame: &mut Frame, area: Rect, state: &AppState, theme: &ThemePalette) {
         let is_focused = state.ui.focused_pane() == FocusedPane::Explorer;
-        let block = panel_block(" [1] Explorer ", is_focused, theme);
+        let marked = state.ui.explorer_marked_tables().len();
+        let schema = state
+            .explorer_tree
+            .schema_filter()
+            .map(|schema| format!("[{schema}] "))
+            .unwrap_or_default();
+        let marks = if marked == 0 {
+            String::new()
+        } else {
+            format!("({marked} marked) ")
+        };
+        let title = format!(
+            " [1] {} {schema}{marks}",
+            Message::ExplorerTitle.text(state.settings.locale())
+        );
+        let block = panel_block(&title, is_focused, theme);
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
@@ -39,25 +57,51 @@ impl Explorer {
     ) {
         let content_width = explorer_content_width_from_inner_width(area.width);
 
-        let table_names: Vec<String> = if has_cached_data {
-            state
-                .tables()
-                .into_iter()
-                .map(explorer_table_label)
-                .collect()
+        let marked = state.ui.explorer_marked_tables();
+        let layout = state.explorer_tree.layout();
+        let rows = if has_cached_data {
+            state.explorer_rows()
         } else {
             Vec::new()
         };
-        let max_name_width = max_explorer_table_label_width(state.tables());
+        let max_name_width = max_explorer_row_label_width(&rows);
         let max_offset = scroll_max_offset(max_name_width, content_width);
         let h_offset = state.ui.explorer_horizontal_offset().min(max_offset);
 
-        let items: Vec<ListItem> = if has_cached_data {
-            table_names
-                .iter()
-                .map(|name| {
-                    let displayed = truncate_with_offset(name, h_offset, content_width);
-                    ListItem::new(displayed)
+        let items: Vec<ListItem> = if has_cached_data && rows.is_empty() {
+            let filter = state.explorer_tree.schema_filter().unwrap_or_default();
+            vec![ListItem::new(format!(" No tables in schema {filter}"))]
+        } else if has_cached_data {
+            rows.iter()
+                .map(|row| {
+                    let mut label = explorer_row_label(*row);
+                    if let ExplorerRow::Table { table, .. } = row
+                        && layout.is_favorite(&table.qualified_name())
+                    {
+                        label.push_str(" \u{2605}");
+                    }
+                    let displayed = truncate_with_offset(&label, h_offset, content_width);
+                    match row {
+                        ExplorerRow::Schema { .. } | ExplorerRow::Section { .. } => {
+                            ListItem::new(displayed).style(
+                                Style::default()
+                                    .fg(theme.semantic.text.secondary)
+                                    .add_modifier(Modifier::BOLD),
+                            )
+                        }
+                        ExplorerRow::Table { table, .. }
+                            if marked.contains(&table.qualified_name()) =>
+                        {
+                            ListItem::new(displayed).style(
+                                Style::default()
+                                    .fg(theme.semantic.surface.focus_border)
+                                    .add_modifier(Modifier::UNDERLINED),
+                            )
+                        }
+                        ExplorerRow::Table { .. } | ExplorerRow::Object { .. } => {
+                            ListItem::new(displayed)
+                        }
+                    }
                 })
                 .collect()
         } else {
@@ -101,7 +145,7 @@ impl Explorer {
 
         // Render scrollbars
         if has_cached_data {
-            let total_items = state.tables().len();
+            let total_items = rows.len();
             let viewport_size = area.height.saturating_sub(1) as usize; // Reserve for horizontal scrollbar
 
             if total_items > viewport_size {
@@ -6,26 +6,35 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Cell, Paragraph, Row, Table as RatatuiTable, Wrap};
 
+use crate::app::i18n::{Locale, Message};
 use crate::app::model::app_state::AppState;
 use crate::app::model::browse::inspector_view_model::{
-    InspectorColumnRow, InspectorEmptyState, InspectorForeignKeyRow, InspectorIndexRow,
-    InspectorInfoRow, InspectorRlsRow, InspectorSection, InspectorTriggerRow, InspectorViewModel,
+    HypertableField, InspectorColumnRow, InspectorEmptyState, InspectorForeignKeyRow,
+    InspectorIndexRow, InspectorInfoRow, InspectorRlsRow, InspectorSection, InspectorStatsRow,
+    InspectorStorageRow, InspectorTriggerRow, InspectorViewModel,
 };
 use crate::app::model::shared::engine_feature_profile::InspectorInfoField;
 use crate::app::model::shared::flash_timer::{FlashId, FlashTimerStore};
 use crate::app::model::shared::focused_pane::FocusedPane;
 use crate::app::model::shared::inspector_tab::InspectorTab;
+use crate::app::model::shared::ui_state::UiState;
 use crate::app::model::shared::viewport::{
     ColumnWidthConfig, MAX_COL_WIDTH, SelectionContext, ViewportPlan, select_viewport_columns,
     widths_fingerprint,
 };
 use crate::app::services::AppServices;
+use crate::domain::FkAction;
 use crate::primitives::atoms::{apply_yank_flash, panel_block};
 use crate::primitives::utils::text_utils::{
     MIN_COL_WIDTH, PADDING, calculate_header_min_widths, truncate_to_width,
 };
 use crate::theme::ThemePalette;
 
+const STATS_LABEL_WIDTH: usize = 10;
+const STATS_VALUE_MAX_WIDTH: usize = 24;
+const STATS_PERCENT_WIDTH: usize = 6;
+const STORAGE_LABEL_WIDTH: usize = 13;
+
 pub struct Inspector;
 
 impl Inspector {
@@ -42,7 +51,11 @@ impl Inspector {
             Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(area);
         let view_model = state.inspector_view_model(services.ddl_generator.as_ref());
 
-        Self::render_tab_bar(frame, tab_area, view_model.active_tab(), state, theme);
+        if let Some(label) = view_model.object_tab() {
+            Self::render_object_tab(frame, tab_area, label, theme);
+        } else {
+            Self::render_tab_bar(frame, tab_area, view_model.active_tab(), state, theme);
+        }
         Self::render_content(
             frame,
             content_area,
@@ -81,7 +94,10 @@ impl Inspector {
                 if i > 0 {
                     spans.push(Span::raw(" "));
                 }
-                spans.push(Span::styled(format!("[{}]", tab.display_name()), style));
+                spans.push(Span::styled(
+                    format!("[{}]", tab_label(*tab, state.settings.locale())),
+                    style,
+                ));
                 spans
             })
             .collect();
@@ -91,6 +107,16 @@ impl Inspector {
         frame.render_widget(paragraph, area);
     }
 
+    fn render_object_tab(frame: &mut Frame, area: Rect, label: &str, theme: &ThemePalette) {
+        let style = Style::default()
+            .fg(theme.component.navigation.tab_active)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(format!("[{label}]"), style))),
+            area,
+        );
+    }
+
     fn render_content(
         frame: &mut Frame,
         area: Rect,
@@ -100,7 +126,14 @@ impl Inspector {
         now: Instant,
         theme: &ThemePalette,
     ) -> ViewportPlan {
-        let block = panel_block(" [2] Inspector ", is_focused, theme);
+        let block = panel_block(
+            &format!(
+                " [2] {} ",
+                Message::InspectorTitle.text(state.settings.locale())
+            ),
+            is_focused,
+            theme,
+        );
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
@@ -142,11 +175,38 @@ impl Inspector {
                 inner,
                 rows,
                 *show_read_only,
-                state.ui.inspector_scroll_offset(),
-                state.ui.inspector_horizontal_offset(),
-                state.ui.inspector_viewport_plan(),
+                is_focused
+                    .then(|| {
+                        view_model.column_cursor(
+                            state.ui.inspector_column_cursor(),
+                            state.ui.inspector_scroll_offset(),
+                            state.ui.inspector_pane_height(),
+                        )
+                    })
+                    .flatten(),
+                &state.ui,
                 theme,
             ),
+            Some(InspectorSection::Stats { rows }) => {
+                Self::render_stats(
+                    frame,
+                    inner,
+                    rows,
+                    state.ui.inspector_scroll_offset(),
+                    theme,
+                );
+                ViewportPlan::default()
+            }
+            Some(InspectorSection::Storage { rows }) => {
+                Self::render_storage(
+                    frame,
+                    inner,
+                    rows,
+                    state.ui.inspector_scroll_offset(),
+                    theme,
+                );
+                ViewportPlan::default()
+            }
             Some(InspectorSection::Indexes {
                 rows,
                 show_type,
@@ -222,6 +282,14 @@ impl Inspector {
                 InspectorInfoRow::Field { field, value } => {
                     Self::render_info_field(*field, value.as_deref(), theme)
                 }
+                InspectorInfoRow::HypertableSpacer => Line::from(""),
+                InspectorInfoRow::HypertableHeading => Line::from(Span::styled(
+                    "Hypertable:",
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                InspectorInfoRow::Hypertable { field, value } => {
+                    Self::render_hypertable_field(*field, value.as_deref(), theme)
+                }
             })
             .collect();
 
@@ -252,7 +320,31 @@ impl Inspector {
             InspectorInfoField::TableKind => "Kind:    ",
             InspectorInfoField::TableFlags => "Flags:   ",
         };
-        let value = value.map_or_else(
+        Line::from(vec![
+            Self::info_label(label),
+            Self::info_value(value, theme),
+        ])
+    }
+
+    fn render_hypertable_field<'a>(
+        field: HypertableField,
+        value: Option<&'a str>,
+        theme: &ThemePalette,
+    ) -> Line<'a> {
+        let label = match field {
+            HypertableField::TimeColumn => "  Time column: ",
+            HypertableField::Chunks => "  Chunks:      ",
+            HypertableField::Compression => "  Compression: ",
+            HypertableField::Retention => "  Retention:   ",
+        };
+        Line::from(vec![
+            Self::info_label(label),
+            Self::info_value(value, theme),
+        ])
+    }
+
+    fn info_value<'a>(value: Option<&'a str>, theme: &ThemePalette) -> Span<'a> {
+        value.map_or_else(
             || {
                 Span::styled(
                     "(none)",
@@ -260,8 +352,7 @@ impl Inspector {
                 )
             },
             Span::raw,
-        );
-        Line::from(vec![Self::info_label(label), value])
+        )
     }
 
     fn info_label(label: &'static str) -> Span<'static> {
@@ -273,11 +364,13 @@ impl Inspector {
         area: Rect,
         rows: &[InspectorColumnRow],
         show_read_only: bool,
-        scroll_offset: usize,
-        horizontal_offset: usize,
-        stored_plan: &ViewportPlan,
+        cursor: Option<usize>,
+        ui: &UiState,
         theme: &ThemePalette,
     ) -> ViewportPlan {
+        let scroll_offset = ui.inspector_scroll_offset();
+        let horizontal_offset = ui.inspector_horizontal_offset();
+        let stored_plan = ui.inspector_viewport_plan();
         let available_width = area.width.saturating_sub(2);
         let mut headers = vec!["Name", "Type", "Null", "PK"];
         if show_read_only {
@@ -356,7 +449,9 @@ impl Inspector {
             .take(data_rows_visible)
             .map(|(row_idx, row)| {
                 let cells = column_row_cells(row, show_read_only);
-                let base_style = if (row_idx - clamped_scroll_offset) % 2 == 1 {
+                let base_style = if cursor == Some(row_idx) {
+                    Style::default().bg(theme.component.table.result_row_active_bg)
+                } else if (row_idx - clamped_scroll_offset) % 2 == 1 {
                     Style::default().bg(theme.component.table.striped_row_bg)
                 } else {
                     Style::default()
@@ -369,7 +464,9 @@ impl Inspector {
 
                         let read_only_col_idx = show_read_only.then_some(4);
                         let comment_col_idx = if show_read_only { 6 } else { 5 };
-                        let cell_style = if col_idx == 3 && !text.is_empty() {
+                        let cell_style = if col_idx == 0 && row.pinned {
+                            pinned_style(theme)
+                        } else if col_idx == 3 && !text.is_empty() {
                             Style::default().fg(theme.semantic.text.accent)
                         } else if read_only_col_idx == Some(col_idx) && !text.is_empty() {
                             Style::default().fg(theme.semantic.status.warning)
@@ -467,9 +564,15 @@ impl Inspector {
             scroll_offset,
             theme,
             |idx| {
-                index_row_cells(&rows[idx], show_type, has_details)
+                let row = &rows[idx];
+                let cell_style = if row.pinned {
+                    pinned_style(theme)
+                } else {
+                    Style::default()
+                };
+                index_row_cells(row, show_type, has_details)
                     .into_iter()
-                    .map(Cell::from)
+                    .map(|text| Cell::from(text).style(cell_style))
                     .collect()
             },
         );
@@ -482,7 +585,7 @@ impl Inspector {
         scroll_offset: usize,
         theme: &ThemePalette,
     ) {
-        let headers = ["Name", "Columns", "References"];
+        let headers = ["Name", "Columns", "References", "On Delete", "On Update"];
         // Width sampling sees only the first 50 rows, so row_fn rebuilds text
         // per visible row instead of indexing into the sample
         let data_rows: Vec<Vec<String>> = rows.iter().take(50).map(foreign_key_row_cells).collect();
@@ -502,10 +605,22 @@ impl Inspector {
             scroll_offset,
             theme,
             |idx| {
-                foreign_key_row_cells(&rows[idx])
+                let row = &rows[idx];
+                let cell_style = if row.pinned {
+                    pinned_style(theme)
+                } else {
+                    Style::default()
+                };
+                let mut cells: Vec<Cell> = foreign_key_row_cells(row)
                     .into_iter()
-                    .map(Cell::from)
-                    .collect()
+                    .map(|text| Cell::from(text).style(cell_style))
+                    .collect();
+                // Actions that touch referencing rows stand out from the
+                // NO ACTION default
+                for (cell, action) in cells[3..].iter_mut().zip([&row.on_delete, &row.on_update]) {
+                    *cell = cell.clone().style(fk_action_style(action, theme));
+                }
+                cells
             },
         );
     }
@@ -539,28 +654,55 @@ impl Inspector {
                     ]));
                 }
                 InspectorRlsRow::RlsSpacer => lines.push(Line::from("")),
-                InspectorRlsRow::RlsPoliciesHeading => lines.push(Line::from(Span::styled(
-                    "Policies:",
-                    Style::default().add_modifier(Modifier::BOLD),
+                InspectorRlsRow::RlsPoliciesHeading { role_filter } => {
+                    let heading = role_filter.as_ref().map_or_else(
+                        || "Policies by role:".to_string(),
+                        |role| format!("Policies for role {role}:"),
+                    );
+                    lines.push(Line::from(Span::styled(
+                        heading,
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )));
+                }
+                InspectorRlsRow::RlsRole { role } => lines.push(Line::from(Span::styled(
+                    format!("  {role}"),
+                    Style::default()
+                        .fg(theme.semantic.text.accent)
+                        .add_modifier(Modifier::BOLD),
                 ))),
                 InspectorRlsRow::RlsPolicy {
                     name,
                     command,
                     permissive,
-                } => lines.push(Line::from(format!(
-                    "  {} ({}) - {}",
-                    name,
-                    command,
-                    if *permissive {
-                        "PERMISSIVE"
+                    pinned,
+                } => lines.push(pin_line(
+                    format!(
+                        "    {} ({}) - {}",
+                        name,
+                        command,
+                        if *permissive {
+                            "PERMISSIVE"
+                        } else {
+                            "RESTRICTIVE"
+                        }
+                    ),
+                    *pinned,
+                    theme,
+                )),
+                InspectorRlsRow::RlsPolicyExpression {
+                    clause,
+                    text,
+                    continuation,
+                    pinned,
+                } => {
+                    // Continuation lines line up under the first line's text.
+                    let label = if *continuation {
+                        " ".repeat(clause.len() + 2)
                     } else {
-                        "RESTRICTIVE"
-                    }
-                ))),
-                InspectorRlsRow::RlsPolicyQual(qual) => lines.push(Line::from(format!(
-                    "    USING: {}",
-                    truncate_to_width(qual, 50)
-                ))),
+                        format!("{clause}: ")
+                    };
+                    lines.push(pin_line(format!("      {label}{text}"), *pinned, theme));
+                }
             }
         }
 
@@ -591,6 +733,121 @@ impl Inspector {
         );
     }
 
+    fn render_stats(
+        frame: &mut Frame,
+        area: Rect,
+        rows: &[InspectorStatsRow],
+        scroll_offset: usize,
+        theme: &ThemePalette,
+    ) {
+        use unicode_width::UnicodeWidthStr;
+
+        // Values take up to a third of the width; bars fill what is left
+        // after the percentage.
+        let value_width = (area.width as usize / 3).clamp(4, STATS_VALUE_MAX_WIDTH);
+        let bar_width = (area.width as usize).saturating_sub(value_width + STATS_PERCENT_WIDTH + 3);
+        let lines: Vec<Line> = rows
+            .iter()
+            .map(|row| match row {
+                InspectorStatsRow::Column {
+                    name,
+                    data_type,
+                    position,
+                    total,
+                } => Line::from(vec![
+                    Span::styled(
+                        name.clone(),
+                        Style::default()
+                            .fg(theme.semantic.text.accent)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(" {data_type}  ({position}/{total})"),
+                        Style::default().fg(theme.semantic.text.secondary),
+                    ),
+                ]),
+                InspectorStatsRow::Note(text) => Line::from(Span::styled(
+                    *text,
+                    Style::default().fg(theme.semantic.text.placeholder),
+                )),
+                InspectorStatsRow::Field { label, value } => Line::from(vec![
+                    Self::info_label(label),
+                    Span::raw(" ".repeat(STATS_LABEL_WIDTH.saturating_sub(label.len()))),
+                    Span::raw(value.clone()),
+                ]),
+                InspectorStatsRow::Spacer => Line::from(""),
+                InspectorStatsRow::CommonValuesHeading => Line::from(Span::styled(
+                    "Most common values:",
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                InspectorStatsRow::CommonValue {
+                    value,
+                    percent,
+                    bar_permille,
+                } => {
+                    let filled = (bar_width * usize::from(*bar_permille)).div_ceil(1000);
+                    let value = truncate_to_width(value, value_width);
+                    let padding = value_width.saturating_sub(value.width());
+                    Line::from(vec![
+                        Span::raw(format!("  {value}{}", " ".repeat(padding))),
+                        Span::raw(format!(" {percent:>STATS_PERCENT_WIDTH$} ")),
+                        Span::styled(
+                            "█".repeat(filled),
+                            Style::default().fg(theme.semantic.text.accent),
+                        ),
+                    ])
+                }
+            })
+            .collect();
+
+        let total_lines = lines.len();
+        let visible_lines = area.height as usize;
+
+        use crate::primitives::atoms::scroll_indicator::clamp_scroll_offset;
+        let clamped_scroll_offset = clamp_scroll_offset(scroll_offset, visible_lines, total_lines);
+
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(theme.semantic.text.primary))
+            .scroll((clamped_scroll_offset as u16, 0));
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_storage(
+        frame: &mut Frame,
+        area: Rect,
+        rows: &[InspectorStorageRow],
+        scroll_offset: usize,
+        theme: &ThemePalette,
+    ) {
+        let lines: Vec<Line> = rows
+            .iter()
+            .map(|row| match row {
+                InspectorStorageRow::Heading(text) => Line::from(Span::styled(
+                    format!("{text}:"),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                InspectorStorageRow::Field { label, value } => Line::from(vec![
+                    Span::raw("  "),
+                    Self::info_label(label),
+                    Span::raw(" ".repeat(STORAGE_LABEL_WIDTH.saturating_sub(label.len()))),
+                    Span::raw(value.clone()),
+                ]),
+                InspectorStorageRow::Spacer => Line::from(""),
+            })
+            .collect();
+
+        let total_lines = lines.len();
+        let visible_lines = area.height as usize;
+
+        use crate::primitives::atoms::scroll_indicator::clamp_scroll_offset;
+        let clamped_scroll_offset = clamp_scroll_offset(scroll_offset, visible_lines, total_lines);
+
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(theme.semantic.text.primary))
+            .scroll((clamped_scroll_offset as u16, 0));
+        frame.render_widget(paragraph, area);
+    }
+
     fn render_triggers(
         frame: &mut Frame,
         area: Rect,
@@ -675,6 +932,20 @@ impl Inspector {
     }
 }
 
+fn pinned_style(theme: &ThemePalette) -> Style {
+    Style::default()
+        .fg(theme.component.table.pinned_column_fg)
+        .add_modifier(Modifier::BOLD)
+}
+
+fn pin_line(text: String, pinned: bool, theme: &ThemePalette) -> Line<'static> {
+    if pinned {
+        Line::from(text).style(pinned_style(theme))
+    } else {
+        Line::from(text)
+    }
+}
+
 fn column_row_cells(row: &InspectorColumnRow, show_read_only: bool) -> Vec<String> {
     let mut cells = vec![
         row.name.clone(),
@@ -708,9 +979,22 @@ fn foreign_key_row_cells(row: &InspectorForeignKeyRow) -> Vec<String> {
         row.name.clone(),
         row.columns.clone(),
         row.references.clone(),
+        row.on_delete.to_string(),
+        row.on_update.to_string(),
     ]
 }
 
+fn fk_action_style(action: &FkAction, theme: &ThemePalette) -> Style {
+    match action {
+        FkAction::Cascade => Style::default().fg(theme.semantic.status.warning),
+        FkAction::SetNull | FkAction::SetDefault => {
+            Style::default().fg(theme.semantic.status.pending)
+        }
+        FkAction::Restrict => Style::default().fg(theme.semantic.text.primary),
+        FkAction::NoAction => Style::default().fg(theme.semantic.text.dim),
+    }
+}
+
 fn trigger_row_cells(row: &InspectorTriggerRow) -> Vec<String> {
     vec![
         row.name.clone(),
@@ -749,3 +1033,19 @@ fn calculate_column_widths(headers: &[&str], rows: &[Vec<String>]) -> Vec<u16> {
         })
         .collect()
 }
+
+// Abbreviations such as FK and DDL read the same in every locale.
+fn tab_label(tab: InspectorTab, locale: Locale) -> &'static str {
+    let message = match tab {
+        InspectorTab::Info => Message::InfoTab,
+        InspectorTab::Columns => Message::ColumnsTab,
+        InspectorTab::Stats => Message::StatsTab,
+        InspectorTab::Storage => Message::StorageTab,
+        InspectorTab::Indexes => Message::IndexesTab,
+        InspectorTab::Triggers => Message::TriggersTab,
+        InspectorTab::ForeignKeys | InspectorTab::Rls | InspectorTab::Ddl => {
+            return tab.display_name();
+        }
+    };
+    message.text(locale)
+}
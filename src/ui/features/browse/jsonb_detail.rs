@@ -4,6 +4,7 @@ use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::browse::jsonb_detail::JsonbDetailMode;
 use crate::app::model::shared::flash_timer::FlashId;
@@ -36,12 +37,14 @@ impl JsonbDetail {
         let is_editing = matches!(state.jsonb_detail.mode(), JsonbDetailMode::Editing);
         let title = if is_editing {
             format!(
-                " JSONB Edit \u{2500}\u{2500} {} (jsonb) ",
+                " {} \u{2500}\u{2500} {} (jsonb) ",
+                Message::JsonbEditTitle.text(state.settings.locale()),
                 state.jsonb_detail.column_name()
             )
         } else {
             format!(
-                " JSONB Detail \u{2500}\u{2500} {}",
+                " {} \u{2500}\u{2500} {}",
+                Message::JsonbDetailTitle.text(state.settings.locale()),
                 state.jsonb_detail.column_name()
             )
         };
@@ -0,0 +1,139 @@
+use std::time::Instant;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph};
+
+use crate::app::model::app_state::AppState;
+use crate::app::model::browse::maintenance::{MaintenanceJob, MaintenanceProgress};
+use crate::app::policy::duration_format::humanize_secs;
+use crate::primitives::atoms::{panel_block, spinner_char};
+use crate::theme::ThemePalette;
+
+const PANEL_WIDTH: u16 = 48;
+const PANEL_HEIGHT: u16 = 4;
+const BAR_WIDTH: u64 = 20;
+
+/// Small corner panel shown while a maintenance command runs; it sits under
+/// modals so browsing continues unobstructed.
+pub struct MaintenancePanel;
+
+impl MaintenancePanel {
+    pub fn render(
+        frame: &mut Frame,
+        main_area: Rect,
+        state: &AppState,
+        now: Instant,
+        theme: &ThemePalette,
+    ) {
+        let Some(job) = state.maintenance.job() else {
+            return;
+        };
+        let area = panel_area(main_area);
+        if area.width < 10 || area.height < PANEL_HEIGHT {
+            return;
+        }
+
+        let lines = panel_lines(job, now, theme);
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines).block(panel_block(&format!(" {} ", job.label), false, theme)),
+            area,
+        );
+    }
+}
+
+fn panel_area(main_area: Rect) -> Rect {
+    let [column] =
+        main_area.layout(&Layout::horizontal([Constraint::Length(PANEL_WIDTH)]).flex(Flex::End));
+    let [area] =
+        column.layout(&Layout::vertical([Constraint::Length(PANEL_HEIGHT)]).flex(Flex::End));
+    area
+}
+
+fn panel_lines(job: &MaintenanceJob, now: Instant, theme: &ThemePalette) -> Vec<Line<'static>> {
+    let elapsed = now.saturating_duration_since(job.started_at);
+    let header = Line::from(vec![
+        Span::styled(
+            format!("{} ", spinner_char(elapsed.as_millis())),
+            Style::default().fg(theme.semantic.status.pending),
+        ),
+        Span::styled(
+            job.target.clone(),
+            Style::default().fg(theme.semantic.text.primary),
+        ),
+        Span::styled(
+            format!("  {}", humanize_secs(elapsed.as_secs_f64())),
+            Style::default().fg(theme.semantic.text.muted),
+        ),
+    ]);
+    let detail = match &job.progress {
+        Some(progress) => progress_line(progress, theme),
+        None => Line::from(Span::styled(
+            "running…",
+            Style::default().fg(theme.semantic.text.muted),
+        )),
+    };
+    vec![header, detail]
+}
+
+fn progress_line(progress: &MaintenanceProgress, theme: &ThemePalette) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        progress.phase.clone(),
+        Style::default().fg(theme.semantic.text.secondary),
+    )];
+    if let Some(percent) = progress.percent() {
+        let filled = (percent * BAR_WIDTH / 100) as usize;
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            "█".repeat(filled),
+            Style::default().fg(theme.semantic.text.accent),
+        ));
+        spans.push(Span::styled(
+            "░".repeat(BAR_WIDTH as usize - filled),
+            Style::default().fg(theme.semantic.text.dim),
+        ));
+        spans.push(Span::raw(format!(" {percent}%")));
+    }
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::DEFAULT_THEME;
+
+    #[test]
+    fn panel_hugs_bottom_right_corner() {
+        let area = panel_area(Rect::new(0, 1, 120, 40));
+
+        assert_eq!(area, Rect::new(72, 37, 48, 4));
+    }
+
+    #[test]
+    fn panel_shrinks_to_a_small_main_area() {
+        let area = panel_area(Rect::new(0, 1, 30, 3));
+
+        assert_eq!(area, Rect::new(0, 1, 30, 3));
+    }
+
+    #[test]
+    fn progress_bar_fills_by_percent() {
+        let line = progress_line(
+            &MaintenanceProgress {
+                phase: "scanning heap".to_string(),
+                done: 1,
+                total: 2,
+            },
+            &DEFAULT_THEME,
+        );
+
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(
+            text,
+            format!("scanning heap {}{} 50%", "█".repeat(10), "░".repeat(10))
+        );
+    }
+}
@@ -3,5 +3,6 @@ mod detail_view;
 pub mod explorer;
 pub mod inspector;
 pub mod jsonb_detail;
+pub mod maintenance_panel;
 pub mod result;
 pub mod row_detail;
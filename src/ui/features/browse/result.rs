@@ -2,27 +2,37 @@ use std::collections::BTreeSet;
 use std::time::Instant;
 
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Rect};
+use ratatui::layout::{Constraint, Offset, Rect, Size};
 use ratatui::style::{Modifier, Style};
-use ratatui::text::Line;
-use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, Wrap};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap};
+use unicode_width::UnicodeWidthStr;
 
 use crate::primitives::atoms::{panel_block_highlight, text_cursor_spans};
 
+use crate::app::i18n::{Locale, Message};
 use crate::app::model::app_state::AppState;
+use crate::app::model::browse::result_column_format::ColumnFormat;
+use crate::app::model::browse::result_diff::ResultDiff;
 use crate::app::model::shared::focused_pane::FocusedPane;
 use crate::app::model::shared::input_mode::InputMode;
+use crate::app::model::shared::render_output::ResultLayout;
 use crate::app::model::shared::ui_state::{RESULT_INNER_OVERHEAD, ResultSelection, YankFlash};
 use crate::app::model::shared::viewport::{
     ColumnWidthConfig, ColumnWidthsCache, MAX_COL_WIDTH, SelectionContext, ViewportPlan,
     select_viewport_columns, widths_fingerprint,
 };
+use crate::app::policy::sql::resource_usage::{resource_usage_summary, result_resource_usage};
 use crate::domain::{QueryResult, QuerySource};
 use crate::primitives::utils::text_utils::{
     MIN_COL_WIDTH, PADDING, calculate_header_min_widths, truncate_to_width,
 };
 use crate::theme::ThemePalette;
 
+const PEEK_MAX_WIDTH: u16 = 60;
+const PEEK_MAX_LINES: u16 = 8;
+const TITLE_FILTER_MAX_WIDTH: usize = 40;
+
 pub struct ResultPane;
 
 struct EditingCellView<'a> {
@@ -42,7 +52,16 @@ struct ResultTableParams<'a> {
     selection: &'a ResultSelection,
     editing_cell: Option<EditingCellView<'a>>,
     staged_delete_rows: &'a BTreeSet<usize>,
+    marked_rows: &'a BTreeSet<usize>,
     yank_flash: Option<YankFlash>,
+    column_formats: Vec<Option<ColumnFormat>>,
+    // `Some` while the type row is toggled on; blank entries are unknown.
+    column_types: Option<Vec<String>>,
+    // Inspector pin, only traced through the selected table's preview.
+    pinned_column: Option<usize>,
+    diff: Option<&'a ResultDiff>,
+    show_old_values: bool,
+    peek_visible: bool,
     now: Instant,
 }
 
@@ -53,7 +72,7 @@ impl ResultPane {
         state: &AppState,
         now: Instant,
         theme: &ThemePalette,
-    ) -> (ViewportPlan, ColumnWidthsCache) {
+    ) -> ResultLayout {
         let is_focused = state.ui.focused_pane() == FocusedPane::Result;
         let should_highlight = state
             .query
@@ -61,11 +80,43 @@ impl ResultPane {
             .is_some_and(|t| now < t);
 
         let result = state.query.visible_result();
-        let title = Self::build_title(result);
+        let filter = state
+            .query
+            .pagination
+            .filter()
+            .filter(|_| state.query.can_paginate_visible_result());
+        let page_window = result
+            .filter(|_| state.query.can_paginate_visible_result())
+            .map(|r| {
+                state
+                    .query
+                    .pagination
+                    .row_window_display(r.data_row_count())
+            });
+        let title = Self::build_title(
+            result,
+            state.query.result_diff(),
+            state.query.masked_columns().len(),
+            page_window,
+            filter,
+            state.settings.locale(),
+        );
 
-        let block = panel_block_highlight(&title, is_focused, should_highlight, theme);
+        let mut block = panel_block_highlight(&title, is_focused, should_highlight, theme);
+        if let Some(usage) = result.and_then(result_resource_usage) {
+            block = block.title_bottom(
+                Line::from(Span::styled(
+                    format!(" {} ", resource_usage_summary(&usage)),
+                    Style::default().fg(theme.semantic.text.muted),
+                ))
+                .right_aligned(),
+            );
+        }
 
-        let default_result = || (ViewportPlan::default(), ColumnWidthsCache::default());
+        let default_result = || ResultLayout {
+            pane_height: area.height,
+            ..ResultLayout::default()
+        };
 
         if let Some(result) = result {
             if result.is_error() {
@@ -97,7 +148,25 @@ impl ResultPane {
                         selection: state.result_interaction.selection(),
                         editing_cell,
                         staged_delete_rows: state.result_interaction.staged_delete_rows(),
+                        marked_rows: state.result_interaction.marked_rows(),
                         yank_flash: state.result_interaction.yank_flash(),
+                        column_formats: state.query.column_formats().for_columns(&result.columns),
+                        column_types: state
+                            .ui
+                            .shows_result_types()
+                            .then(|| state.result_column_types()),
+                        pinned_column: (result.source == QuerySource::Preview)
+                            .then(|| state.ui.inspector_pinned_column())
+                            .flatten()
+                            .and_then(|pinned| {
+                                result.columns.iter().position(|column| column == pinned)
+                            }),
+                        diff: state.query.result_diff(),
+                        show_old_values: state.query.shows_diff_old_values(),
+                        peek_visible: is_focused
+                            && state.input_mode() == InputMode::Normal
+                            && !cell_edit.is_active()
+                            && state.result_interaction.cell_peek().is_visible(now),
                         now,
                     },
                     theme,
@@ -109,23 +178,56 @@ impl ResultPane {
         }
     }
 
-    fn build_title(result: Option<&QueryResult>) -> String {
+    fn build_title(
+        result: Option<&QueryResult>,
+        diff: Option<&ResultDiff>,
+        masked_columns: usize,
+        page_window: Option<String>,
+        filter: Option<&str>,
+        locale: Locale,
+    ) -> String {
         match result {
-            None => " [3] Result ".to_string(),
+            None => format!(" [3] {} ", Message::ResultTitle.text(locale)),
             Some(r) => {
                 let name = match r.source {
-                    QuerySource::Preview => "Result",
-                    QuerySource::Adhoc => "Result Query",
-                };
+                    QuerySource::Preview => Message::ResultTitle,
+                    QuerySource::Adhoc => Message::ResultQueryTitle,
+                }
+                .text(locale);
 
                 if r.is_error() {
                     format!(" [3] {name} ERROR ")
                 } else {
+                    let changed = diff
+                        .filter(|diff| !diff.is_empty())
+                        .map(|diff| {
+                            format!(
+                                ", {} changed",
+                                diff.changed_cell_count() + diff.added_row_count()
+                            )
+                        })
+                        .unwrap_or_default();
+                    let masked = if masked_columns == 0 {
+                        String::new()
+                    } else {
+                        format!(", {masked_columns} masked")
+                    };
+                    let filter = filter
+                        .map(|filter| {
+                            format!(
+                                " WHERE {}",
+                                truncate_to_width(filter, TITLE_FILTER_MAX_WIDTH)
+                            )
+                        })
+                        .unwrap_or_default();
                     format!(
-                        " [3] {} ({}, {}ms) ",
+                        " [3] {}{} ({}, {}ms{}{}) ",
                         name,
-                        r.row_count_display(),
+                        filter,
+                        page_window.unwrap_or_else(|| r.row_count_display()),
                         r.execution_time_ms,
+                        changed,
+                        masked,
                     )
                 }
             }
@@ -172,7 +274,7 @@ impl ResultPane {
         block: Block,
         params: ResultTableParams,
         theme: &ThemePalette,
-    ) -> (ViewportPlan, ColumnWidthsCache) {
+    ) -> ResultLayout {
         let ResultTableParams {
             scroll_offset,
             horizontal_offset,
@@ -182,14 +284,25 @@ impl ResultPane {
             selection,
             editing_cell,
             staged_delete_rows,
+            marked_rows,
             yank_flash,
+            column_formats,
+            column_types,
+            pinned_column,
+            diff,
+            show_old_values,
+            peek_visible,
             now,
         } = params;
+        let old_values = diff.filter(|_| show_old_values);
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
         if result.columns.is_empty() {
-            return (ViewportPlan::default(), ColumnWidthsCache::default());
+            return ResultLayout {
+                pane_height: area.height,
+                ..ResultLayout::default()
+            };
         }
 
         let cached = stored_cache.is_valid(result_generation);
@@ -201,7 +314,7 @@ impl ResultPane {
                 &stored_cache.header_min_widths[..],
             )
         } else {
-            fresh_ideal = calculate_result_ideal_widths(result);
+            fresh_ideal = calculate_result_ideal_widths(result, &column_formats, old_values);
             fresh_min = calculate_header_min_widths(&result.columns);
             (&fresh_ideal[..], &fresh_min[..])
         };
@@ -238,7 +351,12 @@ impl ResultPane {
         let (viewport_indices, viewport_widths) = select_viewport_columns(&config, &ctx);
 
         if viewport_indices.is_empty() {
-            return (plan, widths_cache);
+            return ResultLayout {
+                viewport_plan: plan,
+                widths_cache,
+                pane_height: area.height,
+                active_cell_truncated: false,
+            };
         }
 
         let widths: Vec<Constraint> = viewport_widths
@@ -246,22 +364,58 @@ impl ResultPane {
             .map(|&w| Constraint::Length(w))
             .collect();
 
-        let header = Row::new(viewport_indices.iter().map(|&idx| {
-            let col_name = result.columns.get(idx).map_or("", String::as_str);
-            Cell::from(col_name.to_string())
-        }))
-        .style(
-            Style::default()
-                .add_modifier(Modifier::UNDERLINED)
-                .add_modifier(Modifier::BOLD)
-                .fg(theme.semantic.text.primary),
-        )
-        .height(1);
+        let header_style = Style::default()
+            .add_modifier(Modifier::UNDERLINED)
+            .add_modifier(Modifier::BOLD)
+            .fg(theme.semantic.text.primary);
+        let pinned_style = Style::default()
+            .fg(theme.component.table.pinned_column_fg)
+            .add_modifier(Modifier::BOLD);
+        let header_style_at = |idx: usize| {
+            if pinned_column == Some(idx) {
+                header_style.patch(pinned_style)
+            } else {
+                header_style
+            }
+        };
+        let header = if let Some(types) = &column_types {
+            Row::new(viewport_indices.iter().zip(viewport_widths.iter()).map(
+                |(&idx, &col_width)| {
+                    let col_name = result.columns.get(idx).map_or("", String::as_str);
+                    let data_type = types.get(idx).map_or("", String::as_str);
+                    Cell::from(Text::from(vec![
+                        Line::styled(col_name.to_string(), header_style_at(idx)),
+                        Line::styled(
+                            truncate_to_width(data_type, col_width as usize),
+                            Style::default().fg(theme.semantic.text.dim),
+                        ),
+                    ]))
+                },
+            ))
+            .height(2)
+        } else {
+            Row::new(viewport_indices.iter().map(|&idx| {
+                let col_name = result.columns.get(idx).map_or("", String::as_str);
+                let cell = Cell::from(col_name.to_string());
+                if pinned_column == Some(idx) {
+                    cell.style(pinned_style)
+                } else {
+                    cell
+                }
+            }))
+            .style(header_style)
+            .height(1)
+        };
+        let type_row = u16::from(column_types.is_some());
 
-        let data_rows_visible = inner.height.saturating_sub(RESULT_INNER_OVERHEAD) as usize;
+        let data_rows_visible = inner
+            .height
+            .saturating_sub(RESULT_INNER_OVERHEAD + type_row)
+            as usize;
         let scroll_viewport_size = data_rows_visible;
         let active_row = selection.row();
         let active_cell = selection.cell();
+        let range = selection.range();
 
         let yank_flash_active = yank_flash.is_some_and(|f| now < f.until);
 
@@ -279,6 +433,8 @@ impl ResultPane {
                     Some(theme.component.feedback.yank_flash_bg)
                 } else if is_staged_for_delete {
                     Some(theme.component.table.staged_delete_bg)
+                } else if marked_rows.contains(&abs_row_idx) {
+                    Some(theme.component.table.marked_row_bg)
                 } else if is_active_row {
                     Some(theme.component.table.result_row_active_bg)
                 } else if (abs_row_idx - scroll_offset) % 2 == 1 {
@@ -319,10 +475,28 @@ impl ResultPane {
                                 );
                             }
                         } else {
-                            let display = result
-                                .display_value_at_width(abs_row_idx, orig_idx, col_width as usize)
-                                .unwrap_or_default();
-                            cell = Cell::from(display);
+                            let display =
+                                formatted_value_at(result, &column_formats, abs_row_idx, orig_idx)
+                                    .map(|formatted| truncate_cell(&formatted, col_width as usize))
+                                    .or_else(|| {
+                                        result.display_value_at_width(
+                                            abs_row_idx,
+                                            orig_idx,
+                                            col_width as usize,
+                                        )
+                                    })
+                                    .unwrap_or_default();
+                            cell = match old_values
+                                .and_then(|diff| diff.old_value(abs_row_idx, orig_idx))
+                            {
+                                Some(old) => Cell::from(old_value_line(
+                                    old,
+                                    &display,
+                                    col_width as usize,
+                                    theme,
+                                )),
+                                None => Cell::from(display),
+                            };
                         }
                         if !is_editing_cell {
                             if is_row_flash || flash_scope == Some(Some(orig_idx)) {
@@ -340,6 +514,20 @@ impl ResultPane {
                                     Style::default()
                                         .bg(theme.component.table.result_cell_active_bg),
                                 );
+                            } else if range.is_some_and(|r| r.contains(abs_row_idx, orig_idx)) {
+                                cell = cell.style(
+                                    Style::default().bg(theme.component.table.result_range_bg),
+                                );
+                            } else if diff.is_some_and(|d| d.is_changed(abs_row_idx, orig_idx)) {
+                                cell = cell.style(
+                                    Style::default()
+                                        .fg(theme.component.table.changed_cell_fg)
+                                        .add_modifier(Modifier::BOLD),
+                                );
+                            } else if pinned_column == Some(orig_idx) {
+                                cell = cell.style(
+                                    Style::default().fg(theme.component.table.pinned_column_fg),
+                                );
                             }
                         }
                         cell
@@ -360,6 +548,32 @@ impl ResultPane {
 
         frame.render_widget(table, inner);
 
+        // The full value of the active cell when its column cuts it short
+        let peek = active_row
+            .zip(active_cell)
+            .filter(|(row, _)| (scroll_offset..scroll_offset + data_rows_visible).contains(row))
+            .and_then(|(row, col)| {
+                let pos = viewport_indices.iter().position(|&idx| idx == col)?;
+                let value = formatted_value_at(result, &column_formats, row, col)
+                    .or_else(|| result.display_value_at(row, col))?;
+                let col_width = viewport_widths[pos] as usize;
+                if !value.contains('\n') && value.width() <= col_width {
+                    return None;
+                }
+                let x_offset: u16 = viewport_widths[..pos].iter().map(|w| w + 1).sum();
+                let anchor = Rect {
+                    x: inner.x + x_offset,
+                    y: inner.y + 1 + type_row + (row - scroll_offset) as u16,
+                    width: viewport_widths[pos],
+                    height: 1,
+                };
+                Some((anchor, value))
+            });
+        let active_cell_truncated = peek.is_some();
+        if peek_visible && let Some((anchor, value)) = peek {
+            render_cell_peek(frame, inner, anchor, &value, theme);
+        }
+
         // Scroll indicators (pass inner area, not outer with border)
         let total_rows = result.data_row_count();
         let total_cols = result.column_count();
@@ -392,14 +606,64 @@ impl ResultPane {
             theme,
         );
 
-        (plan, widths_cache)
+        ResultLayout {
+            viewport_plan: plan,
+            widths_cache,
+            pane_height: area.height,
+            active_cell_truncated,
+        }
     }
 }
 
+fn render_cell_peek(
+    frame: &mut Frame,
+    bounds: Rect,
+    anchor: Rect,
+    value: &str,
+    theme: &ThemePalette,
+) {
+    let text_width = value.lines().map(UnicodeWidthStr::width).max().unwrap_or(0);
+    let width = (text_width as u16 + 2)
+        .min(PEEK_MAX_WIDTH)
+        .min(bounds.width);
+    let inner_width = width.saturating_sub(2).max(1) as usize;
+    let wrapped_lines: usize = value
+        .lines()
+        .map(|line| line.width().div_ceil(inner_width).max(1))
+        .sum();
+    let height = (wrapped_lines as u16).min(PEEK_MAX_LINES) + 2;
+
+    // Prefer just below the cell, flipping above when the pane runs out;
+    // clamped so it never leaves the result pane.
+    let size = Size::new(width, height);
+    let below = anchor.offset(Offset { x: 0, y: 1 }).resize(size);
+    let popup = if below.bottom() <= bounds.bottom() {
+        below
+    } else {
+        anchor
+            .offset(Offset {
+                x: 0,
+                y: -i32::from(height),
+            })
+            .resize(size)
+    }
+    .clamp(bounds);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(theme.modal_border_style());
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(value.to_string())
+            .style(Style::default().fg(theme.semantic.text.primary))
+            .wrap(Wrap { trim: false })
+            .block(block),
+        popup,
+    );
+}
+
 #[cfg(test)]
 pub(crate) fn calculate_ideal_widths(headers: &[String], rows: &[Vec<String>]) -> Vec<u16> {
-    use unicode_width::UnicodeWidthStr;
-
     calculate_ideal_widths_with(headers, rows.len(), |row_idx, col_idx| {
         rows.get(row_idx)
             .and_then(|row| row.get(col_idx))
@@ -407,21 +671,42 @@ pub(crate) fn calculate_ideal_widths(headers: &[String], rows: &[Vec<String>]) -
     })
 }
 
-fn calculate_result_ideal_widths(result: &QueryResult) -> Vec<u16> {
+fn calculate_result_ideal_widths(
+    result: &QueryResult,
+    column_formats: &[Option<ColumnFormat>],
+    old_values: Option<&ResultDiff>,
+) -> Vec<u16> {
     calculate_ideal_widths_with(
         &result.columns,
         result.data_row_count(),
-        |row_idx, col_idx| result.display_width_at(row_idx, col_idx),
+        |row_idx, col_idx| {
+            let width = formatted_value_at(result, column_formats, row_idx, col_idx)
+                .map(|formatted| UnicodeWidthStr::width(formatted.as_str()))
+                .or_else(|| result.display_width_at(row_idx, col_idx))?;
+            let old_width = old_values
+                .and_then(|diff| diff.old_value(row_idx, col_idx))
+                .map_or(0, |old| UnicodeWidthStr::width(old) + 1);
+            Some(width + old_width)
+        },
     )
 }
 
+fn formatted_value_at(
+    result: &QueryResult,
+    column_formats: &[Option<ColumnFormat>],
+    row_idx: usize,
+    col_idx: usize,
+) -> Option<String> {
+    let format = column_formats.get(col_idx).copied().flatten()?;
+    let raw = result.display_value_ref_at(row_idx, col_idx)?;
+    format.apply(&raw)
+}
+
 fn calculate_ideal_widths_with(
     headers: &[String],
     row_count: usize,
     mut cell_width: impl FnMut(usize, usize) -> Option<usize>,
 ) -> Vec<u16> {
-    use unicode_width::UnicodeWidthStr;
-
     const SAMPLE_ROWS: usize = 50;
 
     headers
@@ -474,6 +759,22 @@ fn cell_edit_line_with_cursor(
     ))
 }
 
+/// The previous run's value struck through, then as much of the new one as fits.
+fn old_value_line(old: &str, new: &str, max_width: usize, theme: &ThemePalette) -> Line<'static> {
+    let old = truncate_cell(old, max_width);
+    let remaining = max_width.saturating_sub(old.width() + 1);
+    Line::from(vec![
+        Span::styled(
+            old,
+            Style::default()
+                .fg(theme.semantic.text.dim)
+                .add_modifier(Modifier::CROSSED_OUT),
+        ),
+        Span::raw(" "),
+        Span::raw(truncate_to_width(new, remaining)),
+    ])
+}
+
 fn truncate_cell(s: &str, max_width: usize) -> String {
     let first_line = s.lines().next().unwrap_or(s);
     truncate_to_width(first_line, max_width)
@@ -603,7 +904,7 @@ mod tests {
                 QuerySource::Preview,
             );
 
-            assert_eq!(calculate_result_ideal_widths(&result), vec![7]);
+            assert_eq!(calculate_result_ideal_widths(&result, &[], None), vec![7]);
             assert_eq!(result.display_value_ref_at(0, 0).as_deref(), Some("hello"));
             assert_eq!(result.display_row_at(0), Some(vec!["hello".to_string()]));
         }
@@ -648,8 +949,6 @@ mod tests {
         #[case] max: usize,
         #[case] expected: &str,
     ) {
-        use unicode_width::UnicodeWidthStr;
-
         let result = truncate_cell(input, max);
 
         assert_eq!(result, expected);
@@ -4,6 +4,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::shared::flash_timer::FlashId;
 use crate::app::model::shared::render_output::RowDetailLayout;
@@ -29,14 +30,17 @@ impl RowDetail {
             return None;
         }
 
-        let title = " Row Detail ";
+        let title = format!(
+            " {} ",
+            Message::RowDetailTitle.text(state.settings.locale())
+        );
         let hints = ROW_DETAIL_FOOTER_ROWS.iter().map(ModeRow::as_hint);
 
         let (_area, inner) = render_modal(
             frame,
             Constraint::Percentage(80),
             Constraint::Percentage(70),
-            title,
+            &title,
             FooterHintBar::new(hints),
             theme,
         );
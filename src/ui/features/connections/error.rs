@@ -5,6 +5,7 @@ use ratatui::widgets::{Paragraph, Wrap};
 
 use crate::theme::ThemePalette;
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::connection::error_state::ConnectionErrorState;
 use crate::primitives::atoms::key_chip;
@@ -54,7 +55,10 @@ impl ConnectionError {
             frame,
             Constraint::Percentage(70),
             height,
-            " Connection Error ",
+            &format!(
+                " {} ",
+                Message::ConnectionErrorTitle.text(state.settings.locale())
+            ),
             FooterHintBar::new([("Esc", "Close")]),
             theme,
         );
@@ -0,0 +1,108 @@
+use ratatui::Frame;
+use ratatui::layout::Constraint;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, Paragraph};
+
+use crate::app::i18n::Message;
+use crate::app::model::app_state::AppState;
+use crate::app::model::connection::import::CandidateDiscovery;
+use crate::app::ports::outbound::ConnectionCandidate;
+use crate::app::update::input::keybindings::connection_import;
+use crate::primitives::molecules::{FooterHintBar, render_modal};
+use crate::primitives::utils::text_utils::truncate_to_width_with;
+use crate::theme::ThemePalette;
+
+const NAME_COL_PERCENT: usize = 30;
+const SOURCE_COL_WIDTH: usize = 16;
+
+pub struct ConnectionImportPicker;
+
+impl ConnectionImportPicker {
+    pub fn render(frame: &mut Frame, state: &AppState, theme: &ThemePalette) {
+        let import = &state.connection_import;
+        let title = match import.discovery() {
+            CandidateDiscovery::ProjectFiles => Message::ImportConnectionTitle,
+            CandidateDiscovery::Containers => Message::ContainerDatabasesTitle,
+        };
+        let (_, inner) = render_modal(
+            frame,
+            Constraint::Percentage(70),
+            Constraint::Percentage(60),
+            &format!(" {} ", title.text(state.settings.locale())),
+            FooterHintBar::new(vec![
+                connection_import::IMPORT.as_hint(),
+                connection_import::EDIT.as_hint(),
+                connection_import::CLOSE.as_hint(),
+            ]),
+            theme,
+        );
+
+        if import.candidates().is_empty() {
+            let msg = if import.is_loading() {
+                "Scanning..."
+            } else if let Some(error) = import.error() {
+                error
+            } else {
+                match import.discovery() {
+                    CandidateDiscovery::ProjectFiles => {
+                        "Nothing found in pg_service.conf, .pgpass, .env or compose files"
+                    }
+                    CandidateDiscovery::Containers => {
+                        "No running containers publish a PostgreSQL or MySQL port"
+                    }
+                }
+            };
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    format!("  {msg}"),
+                    Style::default().fg(theme.semantic.text.secondary),
+                ))),
+                inner,
+            );
+            return;
+        }
+
+        // highlight_symbol "▸ " takes 2 columns
+        let content_width = (inner.width as usize).saturating_sub(2);
+        let items: Vec<ListItem> = import
+            .candidates()
+            .iter()
+            .map(|candidate| build_item(candidate, content_width, theme))
+            .collect();
+        let list = List::new(items)
+            .highlight_style(theme.picker_selected_style())
+            .highlight_symbol("\u{25b8} ");
+        let mut list_state = ListState::default().with_selected(Some(import.selected()));
+        frame.render_stateful_widget(list, inner, &mut list_state);
+    }
+}
+
+fn build_item(
+    candidate: &ConnectionCandidate,
+    content_width: usize,
+    theme: &ThemePalette,
+) -> ListItem<'static> {
+    let name_width = content_width * NAME_COL_PERCENT / 100;
+    let summary_width = content_width.saturating_sub(name_width + SOURCE_COL_WIDTH);
+    let name = truncate_to_width_with(&candidate.name, name_width.saturating_sub(1), "\u{2026}");
+    let summary = truncate_to_width_with(
+        &candidate.summary(),
+        summary_width.saturating_sub(1),
+        "\u{2026}",
+    );
+    ListItem::new(Line::from(vec![
+        Span::styled(
+            format!("{name:<name_width$}"),
+            Style::default().fg(theme.semantic.text.primary),
+        ),
+        Span::styled(
+            format!("{summary:<summary_width$}"),
+            Style::default().fg(theme.semantic.text.secondary),
+        ),
+        Span::styled(
+            candidate.source.label().to_string(),
+            Style::default().fg(theme.semantic.text.muted),
+        ),
+    ]))
+}
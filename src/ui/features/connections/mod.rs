@@ -1,3 +1,4 @@
 pub mod error;
+pub mod import;
 pub mod selector;
 pub mod setup;
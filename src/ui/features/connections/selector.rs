@@ -4,6 +4,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{List, ListItem, ListState};
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::connection::list::{self, ConnectionListItem};
 use crate::app::update::input::keybindings::connection_selector;
@@ -31,7 +32,10 @@ impl ConnectionSelector {
             frame,
             Constraint::Percentage(60),
             Constraint::Percentage(60),
-            " Select Connection ",
+            &format!(
+                " {} ",
+                Message::SelectConnectionTitle.text(state.settings.locale())
+            ),
             FooterHintBar::new(hint),
             theme,
         );
@@ -1,7 +1,9 @@
+use ratatui::layout::Offset;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use unicode_width::UnicodeWidthStr;
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::connection::setup::{
     CONNECTION_INPUT_VISIBLE_WIDTH, CONNECTION_INPUT_WIDTH, ConnectionField, ConnectionSetupState,
@@ -11,7 +13,7 @@ use crate::app::services::AppServices;
 use crate::app::update::input::keybindings::{connection_setup, connection_setup_save};
 #[cfg(test)]
 use crate::domain::connection::ConnectionConfig;
-use crate::domain::connection::{ConnectionId, ConnectionProfile, DatabaseType, SslMode};
+use crate::domain::connection::{ConnectionId, ConnectionProfile, DatabaseType, Dsn, SslMode};
 use crate::primitives::atoms::text_cursor_spans;
 use crate::primitives::molecules::{FooterHintBar, render_modal};
 use crate::primitives::utils::text_utils::{take_within_width, truncate_to_width_with};
@@ -64,10 +66,11 @@ impl ConnectionSetup {
             visible_fields.len() as u16 + preview_lines.len() as u16 + MODAL_VERTICAL_CHROME;
 
         let (title, submit_desc) = if form_state.is_edit_mode() {
-            (" Edit Connection ", "Save")
+            (Message::EditConnectionTitle, "Save")
         } else {
-            (" New Connection ", "Connect")
+            (Message::NewConnectionTitle, "Connect")
         };
+        let title = format!(" {} ", title.text(state.settings.locale()));
         let submit_hints = Self::submit_hints(state, form_state, submit_desc);
         let mut footer_hints = vec![connection_setup::TAB_NAV.as_hint()];
         footer_hints.extend(submit_hints);
@@ -78,7 +81,7 @@ impl ConnectionSetup {
             frame,
             Constraint::Length(modal_width),
             Constraint::Length(modal_height),
-            title,
+            &title,
             footer,
             theme,
         );
@@ -384,12 +387,11 @@ impl ConnectionSetup {
         ])
         .split(field_area);
 
-        let dropdown_area = Rect {
-            x: chunks[1].x,
-            y: chunks[1].y + 1,
-            width: INPUT_WIDTH,
-            height: items.len() as u16 + 2,
-        };
+        // Clamped so the list stays on screen when the field sits near the bottom edge.
+        let dropdown_area = chunks[1]
+            .offset(Offset { x: 0, y: 1 })
+            .resize(Size::new(INPUT_WIDTH, items.len() as u16 + 2))
+            .clamp(frame.area());
 
         frame.render_widget(Clear, dropdown_area);
 
@@ -401,14 +403,7 @@ impl ConnectionSetup {
 
         let inner = dropdown_area.inner(Margin::new(1, 1));
 
-        for (i, item) in items.enumerate() {
-            let item_area = Rect {
-                x: inner.x,
-                y: inner.y + i as u16,
-                width: inner.width,
-                height: 1,
-            };
-
+        for ((i, item), item_area) in items.enumerate().zip(inner.rows()) {
             let is_selected = i == selected_index;
             let item_style = if is_selected {
                 theme.picker_selected_style()
@@ -487,7 +482,8 @@ fn preview_text(form_state: &ConnectionSetupState, services: &AppServices) -> Op
     }
 
     let profile = preview_profile(form_state);
-    Some(mask_password(&services.dsn_builder.build_dsn(&profile)))
+    let dsn = services.dsn_builder.build_dsn(&profile);
+    Some(Dsn::parse(&dsn).map_or_else(|_| mask_password(&dsn), |dsn| dsn.redacted()))
 }
 
 fn preview_lines(dsn: &str, width: usize, max_lines: usize) -> Vec<String> {
@@ -2,16 +2,17 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Paragraph, Wrap};
 
 use crate::app::model::app_state::AppState;
-use crate::app::model::shared::confirm_dialog::ConfirmIntent;
+use crate::app::model::shared::confirm_dialog::{CONFIRM_INPUT_VISIBLE_WIDTH, ConfirmIntent};
 use crate::app::model::shared::render_output::ConfirmPreviewLayout;
+use crate::app::model::shared::text_input::TextInputState;
 use crate::app::policy::json::json_diff::JsonDiffLine;
 use crate::app::policy::write::write_guardrails::{RiskLevel, WriteOperation};
 use crate::app::policy::write::write_update::escape_preview_value;
 use crate::domain::QueryValue;
-use crate::primitives::atoms::highlight_sql;
+use crate::primitives::atoms::{highlight_sql, text_cursor_spans};
 use crate::primitives::molecules::{FooterHintBar, render_modal, render_modal_with_border_color};
 use crate::primitives::utils::text_utils::wrapped_line_count;
-use crate::theme::ThemePalette;
+use crate::theme::{ThemePalette, accent_color};
 
 pub struct ConfirmDialog;
 
@@ -30,7 +31,9 @@ impl ConfirmDialog {
 
     fn intent_border_color(intent: Option<&ConfirmIntent>, theme: &ThemePalette) -> Option<Color> {
         match intent {
-            Some(ConfirmIntent::DisableReadOnly) => Some(theme.semantic.status.warning),
+            Some(ConfirmIntent::DisableReadOnly | ConfirmIntent::BlockingReindex { .. }) => {
+                Some(theme.semantic.status.warning)
+            }
             Some(ConfirmIntent::DeleteConnection(_)) => Some(theme.semantic.status.error),
             _ => None,
         }
@@ -64,27 +67,28 @@ impl ConfirmDialog {
         let modal_height = (message_height + 2).clamp(6, max_modal_height);
 
         let title = format!(" {} ", dialog.title());
-        let (_, modal_inner) =
-            if let Some(color) = Self::intent_border_color(dialog.intent(), theme) {
-                render_modal_with_border_color(
-                    frame,
-                    Constraint::Length(modal_width),
-                    Constraint::Length(modal_height),
-                    &title,
-                    hint,
-                    color,
-                    theme,
-                )
-            } else {
-                render_modal(
-                    frame,
-                    Constraint::Length(modal_width),
-                    Constraint::Length(modal_height),
-                    &title,
-                    hint,
-                    theme,
-                )
-            };
+        let border_color = Self::intent_border_color(dialog.intent(), theme)
+            .or_else(|| state.active_accent().map(accent_color));
+        let (_, modal_inner) = if let Some(color) = border_color {
+            render_modal_with_border_color(
+                frame,
+                Constraint::Length(modal_width),
+                Constraint::Length(modal_height),
+                &title,
+                hint,
+                color,
+                theme,
+            )
+        } else {
+            render_modal(
+                frame,
+                Constraint::Length(modal_width),
+                Constraint::Length(modal_height),
+                &title,
+                hint,
+                theme,
+            )
+        };
 
         let inner = modal_inner.inner(Margin::new(1, 0));
         let message_para = Paragraph::new(dialog.message().to_owned())
@@ -117,7 +121,14 @@ impl ConfirmDialog {
 
         let risk_label = match preview.guardrail.risk_level {
             RiskLevel::Low => "✓ LOW RISK".to_string(),
-            RiskLevel::Medium => "⚠ MEDIUM RISK: Multiple rows may be affected".to_string(),
+            RiskLevel::Medium => format!(
+                "⚠ MEDIUM RISK: {}",
+                preview
+                    .guardrail
+                    .reason
+                    .as_deref()
+                    .unwrap_or("Multiple rows may be affected")
+            ),
             RiskLevel::High => format!(
                 "⚠ HIGH RISK: {}",
                 preview
@@ -188,6 +199,15 @@ impl ConfirmDialog {
 
         content_lines.push(Line::from(""));
 
+        if let Some(expected) = state.confirm_dialog.required_input() {
+            content_lines.push(Self::typed_confirmation_line(
+                expected,
+                state.confirm_dialog.input(),
+                theme,
+            ));
+            content_lines.push(Line::from(""));
+        }
+
         let full_area = frame.area();
         let terminal_cap = full_area.width.saturating_sub(2).max(20);
         let max_modal_width = (full_area.width * 70 / 100).max(44).min(terminal_cap);
@@ -259,6 +279,31 @@ impl ConfirmDialog {
         }
     }
 
+    fn typed_confirmation_line(
+        expected: &str,
+        input: &TextInputState,
+        theme: &ThemePalette,
+    ) -> Line<'static> {
+        let mut spans = vec![Span::styled(
+            format!("Type \"{expected}\" to confirm: > "),
+            Style::default().fg(theme.semantic.text.secondary),
+        )];
+        spans.extend(text_cursor_spans(
+            input.content(),
+            input.cursor(),
+            input.viewport_offset(),
+            CONFIRM_INPUT_VISIBLE_WIDTH,
+            theme,
+        ));
+        if input.content() == expected {
+            spans.push(Span::styled(
+                " \u{2713}",
+                Style::default().fg(theme.semantic.status.success),
+            ));
+        }
+        Line::from(spans)
+    }
+
     fn render_key_value_lines(
         key_values: &[(String, QueryValue)],
         theme: &ThemePalette,
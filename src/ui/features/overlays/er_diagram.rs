@@ -0,0 +1,380 @@
+use ratatui::Frame;
+use ratatui::layout::Constraint;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::i18n::Message;
+use crate::app::model::app_state::AppState;
+use crate::app::model::er_viewer::{ErViewerState, ErViewerZoom};
+use crate::primitives::molecules::{FooterHintBar, render_modal};
+use crate::theme::ThemePalette;
+
+const DETAILED_COLUMN_GAP: usize = 6;
+const COMPACT_COLUMN_GAP: usize = 4;
+const DETAILED_BOX_HEIGHT: usize = 4;
+
+const NORTH: u8 = 0b0001;
+const EAST: u8 = 0b0010;
+const SOUTH: u8 = 0b0100;
+const WEST: u8 = 0b1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ink {
+    Blank,
+    Edge,
+    Border,
+    Selected,
+    Name,
+    Detail,
+}
+
+struct NodeBox {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    lines: Vec<String>,
+}
+
+impl NodeBox {
+    /// Row where edges attach: the name line.
+    fn anchor_row(&self) -> usize {
+        if self.height > 1 { self.y + 1 } else { self.y }
+    }
+
+    fn right(&self) -> usize {
+        self.x + self.width
+    }
+}
+
+/// The whole diagram rasterized to cells; the viewport is a window onto it.
+struct Canvas {
+    width: usize,
+    height: usize,
+    edges: Vec<u8>,
+    cells: Vec<(char, Ink)>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            edges: vec![0; width * height],
+            cells: vec![(' ', Ink::Blank); width * height],
+        }
+    }
+
+    fn connect(&mut self, x: usize, y: usize, bits: u8) {
+        if x < self.width && y < self.height {
+            self.edges[y * self.width + x] |= bits;
+        }
+    }
+
+    fn hline(&mut self, y: usize, from: usize, to: usize) {
+        let (start, end) = (from.min(to), from.max(to));
+        for x in start..=end {
+            let mut bits = 0;
+            if x > start {
+                bits |= WEST;
+            }
+            if x < end {
+                bits |= EAST;
+            }
+            self.connect(x, y, bits);
+        }
+    }
+
+    fn vline(&mut self, x: usize, from: usize, to: usize) {
+        let (start, end) = (from.min(to), from.max(to));
+        for y in start..=end {
+            let mut bits = 0;
+            if y > start {
+                bits |= NORTH;
+            }
+            if y < end {
+                bits |= SOUTH;
+            }
+            self.connect(x, y, bits);
+        }
+    }
+
+    fn put(&mut self, x: usize, y: usize, ch: char, ink: Ink) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = (ch, ink);
+        }
+    }
+
+    fn put_str(&mut self, x: usize, y: usize, text: &str, ink: Ink) {
+        for (offset, ch) in text.chars().enumerate() {
+            self.put(x + offset, y, ch, ink);
+        }
+    }
+
+    fn ink_edges(&mut self) {
+        for (cell, bits) in self.cells.iter_mut().zip(&self.edges) {
+            if let Some(ch) = edge_char(*bits) {
+                *cell = (ch, Ink::Edge);
+            }
+        }
+    }
+
+    fn get(&self, x: i64, y: i64) -> (char, Ink) {
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) if x < self.width && y < self.height => self.cells[y * self.width + x],
+            _ => (' ', Ink::Blank),
+        }
+    }
+}
+
+fn edge_char(bits: u8) -> Option<char> {
+    let ch = match bits {
+        0 => return None,
+        b if b == NORTH | SOUTH || b == NORTH || b == SOUTH => '│',
+        b if b == EAST | SOUTH => '┌',
+        b if b == WEST | SOUTH => '┐',
+        b if b == NORTH | EAST => '└',
+        b if b == NORTH | WEST => '┘',
+        b if b == NORTH | SOUTH | EAST => '├',
+        b if b == NORTH | SOUTH | WEST => '┤',
+        b if b == EAST | WEST | SOUTH => '┬',
+        b if b == EAST | WEST | NORTH => '┴',
+        b if b == NORTH | EAST | SOUTH | WEST => '┼',
+        _ => '─',
+    };
+    Some(ch)
+}
+
+fn layout_boxes(viewer: &ErViewerState) -> Vec<Option<NodeBox>> {
+    let zoom = viewer.zoom();
+    let placements = viewer.placements();
+    let labels: Vec<Vec<String>> = viewer
+        .tables()
+        .iter()
+        .map(|table| match zoom {
+            ErViewerZoom::Detailed => vec![
+                table.name.clone(),
+                format!("{} · {} FK", table.schema, table.foreign_keys.len()),
+            ],
+            ErViewerZoom::Compact => vec![format!("[{}]", table.name)],
+        })
+        .collect();
+
+    let column_count = placements.iter().map(|p| p.column + 1).max().unwrap_or(0);
+    let mut column_widths = vec![0; column_count];
+    for placement in &placements {
+        let label_width = labels[placement.table]
+            .iter()
+            .map(|line| line.width())
+            .max()
+            .unwrap_or(0);
+        let box_width = match zoom {
+            ErViewerZoom::Detailed => label_width + 4,
+            ErViewerZoom::Compact => label_width,
+        };
+        column_widths[placement.column] = column_widths[placement.column].max(box_width);
+    }
+    let gap = match zoom {
+        ErViewerZoom::Detailed => DETAILED_COLUMN_GAP,
+        ErViewerZoom::Compact => COMPACT_COLUMN_GAP,
+    };
+    let column_x: Vec<usize> = column_widths
+        .iter()
+        .scan(0, |x, width| {
+            let start = *x;
+            *x += width + gap;
+            Some(start)
+        })
+        .collect();
+    let height = match zoom {
+        ErViewerZoom::Detailed => DETAILED_BOX_HEIGHT,
+        ErViewerZoom::Compact => 1,
+    };
+
+    let mut boxes: Vec<Option<NodeBox>> = (0..viewer.tables().len()).map(|_| None).collect();
+    for placement in placements {
+        boxes[placement.table] = Some(NodeBox {
+            x: column_x[placement.column],
+            y: placement.row * (height + 1),
+            width: column_widths[placement.column],
+            height,
+            lines: labels[placement.table].clone(),
+        });
+    }
+    boxes
+}
+
+fn rasterize(viewer: &ErViewerState, boxes: &[Option<NodeBox>]) -> Canvas {
+    let placed = || boxes.iter().flatten();
+    // Edges between boxes of one column loop around their right side.
+    let width = placed().map(NodeBox::right).max().unwrap_or(0) + 3;
+    let height = placed().map(|b| b.y + b.height).max().unwrap_or(0);
+    let mut canvas = Canvas::new(width, height);
+
+    let mut arrows = Vec::new();
+    for edge in viewer.edges() {
+        let (Some(parent), Some(child)) = (&boxes[edge.parent], &boxes[edge.child]) else {
+            continue;
+        };
+        let (parent_y, child_y) = (parent.anchor_row(), child.anchor_row());
+        if parent.right() < child.x {
+            let bend = child.x - 2;
+            canvas.hline(parent_y, parent.right(), bend);
+            canvas.vline(bend, parent_y, child_y);
+            canvas.hline(child_y, bend, child.x - 1);
+            arrows.push((parent.right(), parent_y, '◀'));
+        } else if child.right() < parent.x {
+            let bend = parent.x - 2;
+            canvas.hline(child_y, child.right(), bend);
+            canvas.vline(bend, child_y, parent_y);
+            canvas.hline(parent_y, bend, parent.x - 1);
+            arrows.push((parent.x - 1, parent_y, '▶'));
+        } else {
+            let bend = parent.right().max(child.right()) + 1;
+            canvas.hline(parent_y, parent.right(), bend);
+            canvas.vline(bend, parent_y, child_y);
+            canvas.hline(child_y, child.right(), bend);
+            arrows.push((parent.right(), parent_y, '◀'));
+        }
+    }
+    canvas.ink_edges();
+    for (x, y, arrow) in arrows {
+        canvas.put(x, y, arrow, Ink::Edge);
+    }
+
+    for (index, node) in boxes.iter().enumerate() {
+        let Some(node) = node else { continue };
+        let selected = index == viewer.selected();
+        if node.height == 1 {
+            let ink = if selected { Ink::Selected } else { Ink::Name };
+            canvas.put_str(node.x, node.y, &node.lines[0], ink);
+            continue;
+        }
+        draw_box(&mut canvas, node, selected);
+    }
+    canvas
+}
+
+fn draw_box(canvas: &mut Canvas, node: &NodeBox, selected: bool) {
+    let (
+        [
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            vertical,
+        ],
+        ink,
+    ) = if selected {
+        (['╔', '╗', '╚', '╝', '═', '║'], Ink::Selected)
+    } else {
+        (['┌', '┐', '└', '┘', '─', '│'], Ink::Border)
+    };
+    let (left, right) = (node.x, node.right() - 1);
+    let (top, bottom) = (node.y, node.y + node.height - 1);
+    for x in left + 1..right {
+        canvas.put(x, top, horizontal, ink);
+        canvas.put(x, bottom, horizontal, ink);
+    }
+    for y in top + 1..bottom {
+        canvas.put(left, y, vertical, ink);
+        canvas.put(right, y, vertical, ink);
+        for x in left + 1..right {
+            canvas.put(x, y, ' ', Ink::Blank);
+        }
+    }
+    canvas.put(left, top, top_left, ink);
+    canvas.put(right, top, top_right, ink);
+    canvas.put(left, bottom, bottom_left, ink);
+    canvas.put(right, bottom, bottom_right, ink);
+    for (offset, (line, line_ink)) in node.lines.iter().zip([Ink::Name, Ink::Detail]).enumerate() {
+        canvas.put_str(left + 2, top + 1 + offset, line, line_ink);
+    }
+}
+
+fn ink_style(ink: Ink, theme: &ThemePalette) -> Style {
+    match ink {
+        Ink::Blank => Style::default(),
+        Ink::Edge => Style::default().fg(theme.semantic.text.muted),
+        Ink::Border => Style::default().fg(theme.semantic.surface.unfocus_border),
+        Ink::Selected => Style::default()
+            .fg(theme.semantic.surface.focus_border)
+            .add_modifier(Modifier::BOLD),
+        Ink::Name => Style::default()
+            .fg(theme.semantic.text.primary)
+            .add_modifier(Modifier::BOLD),
+        Ink::Detail => Style::default().fg(theme.semantic.text.secondary),
+    }
+}
+
+pub struct ErDiagramViewer;
+
+impl ErDiagramViewer {
+    pub fn render(frame: &mut Frame, state: &AppState, theme: &ThemePalette) {
+        let viewer = &state.er_viewer;
+        let mut title = format!(
+            " {} · {} tables",
+            Message::ErDiagramTitle.text(state.settings.locale()),
+            viewer.tables().len()
+        );
+        if let Some(table) = viewer.selected_table() {
+            title.push_str(" · ");
+            title.push_str(&table.qualified_name);
+        }
+        if viewer.is_focus_mode() {
+            title.push_str(" (focus)");
+        }
+        title.push(' ');
+        let (_, inner) = render_modal(
+            frame,
+            Constraint::Percentage(90),
+            Constraint::Percentage(85),
+            &title,
+            FooterHintBar::new([
+                ("hjkl", "Pan"),
+                ("Tab", "Table"),
+                ("f", "Focus"),
+                ("+/-", "Zoom"),
+                ("Esc", "Close"),
+            ]),
+            theme,
+        );
+
+        let boxes = layout_boxes(viewer);
+        let canvas = rasterize(viewer, &boxes);
+        let (center_x, center_y) = boxes
+            .get(viewer.selected())
+            .and_then(Option::as_ref)
+            .map_or((0, 0), |node| {
+                (node.x + node.width / 2, node.y + node.height / 2)
+            });
+        let (pan_x, pan_y) = viewer.pan();
+        let origin_x = center_x as i64 - i64::from(inner.width / 2) + i64::from(pan_x);
+        let origin_y = center_y as i64 - i64::from(inner.height / 2) + i64::from(pan_y);
+
+        let lines: Vec<Line> = (0..i64::from(inner.height))
+            .map(|row| {
+                let mut spans: Vec<Span> = Vec::new();
+                let mut run = String::new();
+                let mut run_ink = Ink::Blank;
+                for col in 0..i64::from(inner.width) {
+                    let (ch, ink) = canvas.get(origin_x + col, origin_y + row);
+                    if ink != run_ink && !run.is_empty() {
+                        spans.push(Span::styled(
+                            std::mem::take(&mut run),
+                            ink_style(run_ink, theme),
+                        ));
+                    }
+                    run_ink = ink;
+                    run.push(ch);
+                }
+                spans.push(Span::styled(run, ink_style(run_ink, theme)));
+                Line::from(spans)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
@@ -8,6 +8,7 @@ use unicode_width::UnicodeWidthStr;
 use crate::theme::ThemePalette;
 
 use crate::app::catalog::{HelpDocument, HelpRow};
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::shared::help::HelpMode;
 use crate::app::model::shared::ui_state::{
@@ -42,7 +43,7 @@ impl HelpOverlay {
             frame,
             Constraint::Percentage(HELP_MODAL_WIDTH_PERCENT),
             Constraint::Percentage(HELP_MODAL_HEIGHT_PERCENT),
-            " Help ",
+            &format!(" {} ", Message::HelpTitle.text(state.settings.locale())),
             footer,
             theme,
         );
@@ -1,4 +1,6 @@
 pub mod confirm_dialog;
+pub mod er_diagram;
 pub mod help;
+pub mod session_settings;
 pub mod settings;
 pub mod sqlite_diagnostics;
@@ -0,0 +1,105 @@
+use ratatui::Frame;
+use ratatui::layout::Constraint;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::app::i18n::Message;
+use crate::app::model::app_state::AppState;
+use crate::app::model::connection::session_settings::SESSION_SETTINGS_INPUT_VISIBLE_WIDTH;
+use crate::domain::connection::SessionSettingKey;
+use crate::primitives::atoms::text_cursor_spans;
+use crate::primitives::molecules::{FooterHintBar, render_modal};
+use crate::theme::ThemePalette;
+
+const LABEL_WIDTH: usize = 20;
+const MODAL_WIDTH: u16 = 72;
+const MODAL_HEIGHT: u16 = 11;
+
+fn placeholder(key: SessionSettingKey) -> &'static str {
+    match key {
+        SessionSettingKey::ApplicationName => "sabiql",
+        SessionSettingKey::WorkMem => "64MB",
+        SessionSettingKey::StatementTimeout => "30s",
+        SessionSettingKey::SearchPath => "app, public",
+    }
+}
+
+pub struct SessionSettingsOverlay;
+
+impl SessionSettingsOverlay {
+    pub fn render(frame: &mut Frame, state: &AppState, theme: &ThemePalette) {
+        let (_, inner) = render_modal(
+            frame,
+            Constraint::Length(MODAL_WIDTH),
+            Constraint::Length(MODAL_HEIGHT),
+            &format!(
+                " {} ",
+                Message::SessionSettingsTitle.text(state.settings.locale())
+            ),
+            FooterHintBar::new([("Tab", "Field"), ("Enter", "Apply"), ("Esc", "Discard")]),
+            theme,
+        );
+
+        let form = &state.session_settings_form;
+        let mut lines: Vec<Line> = SessionSettingKey::ALL
+            .into_iter()
+            .map(|key| {
+                let focused = key == form.focused_key();
+                let input = form.input(key);
+                let label_style = if focused {
+                    Style::default().fg(theme.semantic.text.secondary).bold()
+                } else {
+                    Style::default().fg(theme.semantic.text.secondary)
+                };
+                let mut spans = vec![Span::styled(
+                    format!("{:<LABEL_WIDTH$}", key.name()),
+                    label_style,
+                )];
+                if focused {
+                    spans.extend(text_cursor_spans(
+                        input.content(),
+                        input.cursor(),
+                        input.viewport_offset(),
+                        SESSION_SETTINGS_INPUT_VISIBLE_WIDTH,
+                        theme,
+                    ));
+                } else if input.content().is_empty() {
+                    spans.push(Span::styled(
+                        placeholder(key),
+                        Style::default().fg(theme.semantic.text.placeholder),
+                    ));
+                } else {
+                    let visible: String = input
+                        .content()
+                        .chars()
+                        .take(SESSION_SETTINGS_INPUT_VISIBLE_WIDTH)
+                        .collect();
+                    spans.push(Span::styled(
+                        visible,
+                        Style::default().fg(theme.semantic.text.primary),
+                    ));
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        let note_style = Style::default().fg(theme.semantic.text.dim);
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            "Blank values fall back to the server default.",
+            note_style,
+        ));
+        if state
+            .active_profile()
+            .is_some_and(|profile| profile.pool_mode().is_some())
+        {
+            lines.push(Line::styled(
+                "Pooled profile: only application_name is sent.",
+                note_style,
+            ));
+        }
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
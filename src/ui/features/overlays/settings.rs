@@ -4,6 +4,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::shared::settings::{ErBrowserChoice, KeymapPreset, SettingsSection};
 use crate::app::model::shared::theme_id::ThemeId;
@@ -23,7 +24,7 @@ impl SettingsOverlay {
             frame,
             Constraint::Percentage(60),
             Constraint::Percentage(48),
-            " Settings ",
+            &format!(" {} ", Message::SettingsTitle.text(state.settings.locale())),
             settings_modal_hint_bar(state),
             theme,
         );
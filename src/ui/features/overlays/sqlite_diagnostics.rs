@@ -4,6 +4,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Paragraph, Wrap};
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::sqlite::diagnostics::{DiagnosticFieldKind, display_rows};
 use crate::domain::SqliteDiagnosticsSnapshot;
@@ -33,7 +34,10 @@ impl SqliteDiagnosticsOverlay {
             frame,
             Constraint::Percentage(70),
             Constraint::Percentage(60),
-            " SQLite Diagnostics ",
+            &format!(
+                " {} ",
+                Message::SqliteDiagnosticsTitle.text(state.settings.locale())
+            ),
             hint_bar,
             theme,
         );
@@ -3,6 +3,7 @@ use ratatui::layout::Constraint;
 use ratatui::style::Style;
 use ratatui::widgets::{List, ListItem};
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::update::input::palette::palette_commands;
 use crate::theme::ThemePalette;
@@ -17,7 +18,10 @@ impl CommandPalette {
             frame,
             Constraint::Percentage(50),
             Constraint::Percentage(50),
-            " Command Palette ",
+            &format!(
+                " {} ",
+                Message::CommandPaletteTitle.text(state.settings.locale())
+            ),
             FooterHintBar::new([("Enter", "Select"), ("Esc", "Close")]),
             theme,
         );
@@ -25,6 +29,7 @@ impl CommandPalette {
         let items: Vec<ListItem> = palette_commands(
             state.settings.saved_keymap_preset(),
             state.session.active_engine_feature_profile(),
+            &state.usage,
         )
         .enumerate()
         .map(|(i, kb)| {
@@ -4,6 +4,7 @@ use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{List, ListItem, ListState, Paragraph};
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::shared::render_output::PickerLayout;
 use crate::app::update::input::keybindings;
@@ -59,7 +60,10 @@ impl ErTablePicker {
             frame,
             Constraint::Percentage(60),
             Constraint::Percentage(70),
-            " ER Diagram ",
+            &format!(
+                " {} ",
+                Message::ErDiagramTitle.text(state.settings.locale())
+            ),
             FooterHintBar::with_prefix(
                 format!("{selected_count}/{total_count} selected"),
                 [
@@ -1,4 +1,9 @@
 pub mod command_palette;
 pub mod er_table_picker;
+pub mod object_search;
 pub mod query_history_picker;
+pub mod quick_actions;
+pub mod server_settings;
+pub mod snippets;
+pub mod sql_files;
 pub mod table_picker;
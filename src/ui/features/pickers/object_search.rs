@@ -0,0 +1,129 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, Paragraph};
+
+use crate::app::i18n::Message;
+use crate::app::model::app_state::AppState;
+use crate::app::model::browse::object_search::SearchHit;
+use crate::app::model::shared::render_output::PickerLayout;
+use crate::primitives::molecules::{FooterHintBar, render_filter_input_line, render_modal};
+use crate::primitives::utils::text_utils::truncate_to_width_with;
+use crate::theme::ThemePalette;
+
+const KIND_WIDTH: usize = 9;
+
+pub struct ObjectSearchPicker;
+
+impl ObjectSearchPicker {
+    pub fn render(frame: &mut Frame, state: &AppState, theme: &ThemePalette) -> PickerLayout {
+        let search = &state.object_search;
+        let hits = search.filtered();
+        let selected_idx = search.clamped_selected();
+
+        let prefix = format!(
+            "{} of {} indexed │ type to search",
+            hits.len(),
+            search.entry_count()
+        );
+        let (_, inner) = render_modal(
+            frame,
+            Constraint::Percentage(70),
+            Constraint::Percentage(70),
+            &format!(
+                " {} ",
+                Message::ObjectSearchTitle.text(state.settings.locale())
+            ),
+            FooterHintBar::with_prefix(prefix, [("Enter", "Jump"), ("Esc", "Close")]),
+            theme,
+        );
+
+        let [filter_area, list_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(inner);
+
+        let visible_width = render_filter_input_line(
+            frame,
+            filter_area,
+            search.picker().filter_input(),
+            Some("tables, columns, views, comments"),
+            theme,
+        );
+        let layout = PickerLayout {
+            pane_height: list_area.height,
+            filter_visible_width: visible_width,
+        };
+
+        if hits.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    "  No matches",
+                    Style::default().fg(theme.semantic.text.secondary),
+                ))),
+                list_area,
+            );
+            return layout;
+        }
+
+        let width = (list_area.width as usize).saturating_sub(2);
+        let items: Vec<ListItem> = hits
+            .iter()
+            .enumerate()
+            .map(|(i, hit)| build_list_item(hit, i == selected_idx, width, theme))
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(theme.picker_selected_style())
+            .highlight_symbol("\u{25b8} ");
+        let mut list_state = ListState::default()
+            .with_selected(Some(selected_idx))
+            .with_offset(search.picker().scroll_offset());
+        frame.render_stateful_widget(list, list_area, &mut list_state);
+        layout
+    }
+}
+
+fn build_list_item(
+    hit: &SearchHit<'_>,
+    selected: bool,
+    width: usize,
+    theme: &ThemePalette,
+) -> ListItem<'static> {
+    let base = Style::default().fg(if selected {
+        theme.semantic.text.primary
+    } else {
+        theme.semantic.text.secondary
+    });
+    let muted = Style::default().fg(theme.semantic.text.muted);
+    let label = truncate_to_width_with(
+        &hit.entry.label,
+        width.saturating_sub(KIND_WIDTH),
+        "\u{2026}",
+    );
+
+    let mut spans = vec![Span::styled(
+        format!("{:<KIND_WIDTH$}", hit.entry.kind.label()),
+        muted,
+    )];
+    spans.extend(label.chars().enumerate().map(|(ci, ch)| {
+        if hit.match_indices.contains(&(ci as u32)) {
+            Span::styled(
+                ch.to_string(),
+                Style::default()
+                    .fg(theme.semantic.text.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::styled(ch.to_string(), base)
+        }
+    }));
+    let used = KIND_WIDTH + label.chars().count();
+    if let Some(comment) = &hit.entry.comment
+        && used + 4 < width
+    {
+        let comment = truncate_to_width_with(comment, width - used - 3, "\u{2026}");
+        spans.push(Span::styled(format!(" \u{2014} {comment}"), muted));
+    }
+
+    ListItem::new(Line::from(spans))
+}
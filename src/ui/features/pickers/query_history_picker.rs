@@ -5,6 +5,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
 use unicode_width::UnicodeWidthStr;
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::shared::render_output::PickerLayout;
 use crate::app::model::sql_editor::query_history::GroupedEntry;
@@ -15,6 +16,7 @@ use crate::theme::{StatusTone, ThemePalette};
 
 const TIMESTAMP_WIDTH: usize = 18;
 const STATUS_WIDTH: usize = 2;
+const DURATION_WIDTH: usize = 9;
 const LIST_MIN_HEIGHT: u16 = 5;
 const LIST_MAX_HEIGHT: u16 = 10;
 const PREVIEW_MIN_HEIGHT: u16 = 6;
@@ -42,6 +44,15 @@ fn format_short_timestamp(iso: impl AsRef<str>) -> String {
     format!("{month_name} {day} {time} UTC")
 }
 
+// Matches the Result pane title for short runs; long ones read easier in seconds.
+fn format_duration(ms: u64) -> String {
+    if ms < 10_000 {
+        format!("{ms}ms")
+    } else {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    }
+}
+
 fn status_span(status: QueryResultStatus, theme: &ThemePalette) -> Span<'static> {
     let tone = status_tone(status);
     match status {
@@ -72,6 +83,7 @@ struct PreviewData<'a> {
     query: &'a str,
     result_status: QueryResultStatus,
     affected_rows: Option<u64>,
+    duration_ms: Option<u64>,
     executed_at: &'a Iso8601Timestamp,
 }
 
@@ -108,7 +120,10 @@ impl QueryHistoryPicker {
             frame,
             Constraint::Percentage(70),
             Constraint::Max(desired_height),
-            " Query History ",
+            &format!(
+                " {} ",
+                Message::QueryHistoryTitle.text(state.settings.locale())
+            ),
             FooterHintBar::with_prefix(
                 format!("{grouped_count} entries │ type to filter"),
                 [("Enter", "Select")],
@@ -164,12 +179,14 @@ impl QueryHistoryPicker {
         }
 
         let available_width = list_area.width as usize;
-        let query_max = available_width.saturating_sub(STATUS_WIDTH + TIMESTAMP_WIDTH + 4);
+        let query_max =
+            available_width.saturating_sub(STATUS_WIDTH + DURATION_WIDTH + TIMESTAMP_WIDTH + 4);
 
         let preview_data = grouped.get(selected_idx).map(|ge| PreviewData {
             query: ge.entry.query.as_str(),
             result_status: ge.entry.result_status,
             affected_rows: ge.entry.affected_rows,
+            duration_ms: ge.entry.duration_ms,
             executed_at: &ge.entry.executed_at,
         });
 
@@ -263,6 +280,15 @@ fn build_list_item(
     }
 
     spans.push(Span::raw(" ".repeat(pad)));
+    let duration = ge
+        .entry
+        .duration_ms
+        .map(format_duration)
+        .unwrap_or_default();
+    spans.push(Span::styled(
+        format!("{duration:>DURATION_WIDTH$}"),
+        Style::default().fg(theme.semantic.text.secondary),
+    ));
     spans.push(Span::styled(
         format!("  {ts_short}"),
         Style::default().fg(theme.semantic.text.dim),
@@ -305,6 +331,12 @@ fn render_preview(
             Style::default().fg(theme.semantic.text.secondary),
         ));
     }
+    if let Some(duration_ms) = pd.duration_ms {
+        meta_spans.push(Span::styled(
+            format!("  \u{2502} {}", format_duration(duration_ms)),
+            Style::default().fg(theme.semantic.text.secondary),
+        ));
+    }
     meta_spans.push(Span::styled(
         format!("  \u{2502} {}", format_short_timestamp(pd.executed_at)),
         Style::default().fg(theme.semantic.text.dim),
@@ -0,0 +1,67 @@
+use ratatui::Frame;
+use ratatui::layout::Constraint;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState};
+
+use crate::app::i18n::Message;
+use crate::app::model::app_state::AppState;
+use crate::app::model::browse::quick_actions::QuickAction;
+use crate::app::update::input::keybindings::{ModeRow, QUICK_ACTIONS_ROWS};
+use crate::primitives::molecules::{FooterHintBar, render_modal};
+use crate::theme::ThemePalette;
+
+const MENU_WIDTH: u16 = 56;
+
+pub struct QuickActionsMenu;
+
+impl QuickActionsMenu {
+    pub fn render(frame: &mut Frame, state: &AppState, theme: &ThemePalette) {
+        let Some((schema, table)) = state.quick_actions.target() else {
+            return;
+        };
+        let favorite = state
+            .explorer_tree
+            .layout()
+            .is_favorite(&format!("{schema}.{table}"));
+
+        let (_, inner) = render_modal(
+            frame,
+            Constraint::Length(MENU_WIDTH),
+            Constraint::Length(QuickAction::ALL.len() as u16 + 2),
+            &format!(
+                " {}: {schema}.{table} ",
+                Message::QuickActionsTitle.text(state.settings.locale())
+            ),
+            FooterHintBar::new(QUICK_ACTIONS_ROWS.iter().map(ModeRow::as_hint)),
+            theme,
+        );
+
+        let items: Vec<ListItem> = QuickAction::ALL
+            .iter()
+            .map(|&action| {
+                let label = action.label(favorite);
+                match state.quick_action_unavailable_reason(action) {
+                    Some(reason) => ListItem::new(Line::from(vec![
+                        Span::styled(label, Style::default().fg(theme.semantic.text.muted)),
+                        Span::styled(
+                            format!(" ({reason})"),
+                            Style::default().fg(theme.semantic.text.muted),
+                        ),
+                    ])),
+                    None => ListItem::new(Line::from(Span::styled(
+                        label,
+                        Style::default().fg(theme.semantic.text.primary),
+                    ))),
+                }
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(theme.picker_selected_style())
+            .highlight_symbol("\u{25b8} ");
+        let mut list_state =
+            ListState::default().with_selected(Some(state.quick_actions.selected()));
+        frame.render_stateful_widget(list, inner, &mut list_state);
+    }
+}
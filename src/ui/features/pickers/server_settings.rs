@@ -0,0 +1,246 @@
+use std::fmt::Write as _;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::i18n::Message;
+use crate::app::model::app_state::AppState;
+use crate::app::model::postgres::server_settings::FilteredSetting;
+use crate::app::model::shared::render_output::PickerLayout;
+use crate::domain::ServerSetting;
+use crate::primitives::molecules::{FooterHintBar, render_filter_input_line, render_modal};
+use crate::primitives::utils::text_utils::truncate_to_width_with;
+use crate::theme::{StatusTone, ThemePalette};
+
+const NAME_WIDTH: usize = 36;
+const DETAIL_HEIGHT: u16 = 8;
+const RESTART_BADGE: &str = " \u{27f3} restart";
+const SESSION_BADGE: &str = " \u{25c6} session";
+
+pub struct ServerSettingsOverlay;
+
+impl ServerSettingsOverlay {
+    pub fn render(frame: &mut Frame, state: &AppState, theme: &ThemePalette) -> PickerLayout {
+        let settings = &state.server_settings;
+        let filtered = settings.filtered();
+        let selected_idx = settings.clamped_selected();
+
+        let session_overrides: Vec<&str> = state
+            .active_session_settings()
+            .map(|s| s.entries().into_iter().map(|(key, _)| key.name()).collect())
+            .unwrap_or_default();
+
+        let mut prefix = format!("{} settings", filtered.len());
+        let pending = settings.pending_restart_count();
+        if pending > 0 {
+            let _ = write!(prefix, " │ {pending} pending restart");
+        }
+        if !session_overrides.is_empty() {
+            let _ = write!(prefix, " │ {} session overrides", session_overrides.len());
+        }
+        prefix.push_str(" │ type to filter");
+
+        let (_, inner) = render_modal(
+            frame,
+            Constraint::Percentage(80),
+            Constraint::Percentage(80),
+            &format!(
+                " {} ",
+                Message::ServerSettingsTitle.text(state.settings.locale())
+            ),
+            FooterHintBar::with_prefix(prefix, [("Esc", "Close")]),
+            theme,
+        );
+
+        let [filter_area, list_area, detail_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(DETAIL_HEIGHT),
+        ])
+        .areas(inner);
+
+        let visible_width = render_filter_input_line(
+            frame,
+            filter_area,
+            settings.picker().filter_input(),
+            Some("type to filter"),
+            theme,
+        );
+        let layout = PickerLayout {
+            pane_height: list_area.height,
+            filter_visible_width: visible_width,
+        };
+
+        if filtered.is_empty() {
+            let msg = if settings.is_loading() {
+                "Loading..."
+            } else if settings.entries().is_empty() {
+                "No settings"
+            } else {
+                "No matches"
+            };
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    format!("  {msg}"),
+                    Style::default().fg(theme.semantic.text.secondary),
+                ))),
+                list_area,
+            );
+            render_detail(frame, detail_area, None, theme);
+            return layout;
+        }
+
+        let value_max = (list_area.width as usize).saturating_sub(NAME_WIDTH + 4);
+        let items: Vec<ListItem> = filtered
+            .iter()
+            .enumerate()
+            .map(|(i, fs)| {
+                let overridden = session_overrides.contains(&fs.setting.name.as_str());
+                build_list_item(fs, i == selected_idx, overridden, value_max, theme)
+            })
+            .collect();
+        render_detail(
+            frame,
+            detail_area,
+            filtered.get(selected_idx).map(|fs| fs.setting),
+            theme,
+        );
+
+        let list = List::new(items)
+            .highlight_style(theme.picker_selected_style())
+            .highlight_symbol("\u{25b8} ");
+        let mut list_state = ListState::default()
+            .with_selected(Some(selected_idx))
+            .with_offset(settings.picker().scroll_offset());
+        frame.render_stateful_widget(list, list_area, &mut list_state);
+        layout
+    }
+}
+
+fn build_list_item(
+    fs: &FilteredSetting<'_>,
+    selected: bool,
+    overridden: bool,
+    value_max: usize,
+    theme: &ThemePalette,
+) -> ListItem<'static> {
+    let setting = fs.setting;
+    let base = Style::default().fg(if selected {
+        theme.semantic.text.primary
+    } else {
+        theme.semantic.text.secondary
+    });
+    let name = truncate_to_width_with(&setting.name, NAME_WIDTH, "\u{2026}");
+
+    let mut spans: Vec<Span> = name
+        .chars()
+        .enumerate()
+        .map(|(ci, ch)| {
+            if fs.match_indices.contains(&(ci as u32)) {
+                Span::styled(
+                    ch.to_string(),
+                    Style::default()
+                        .fg(theme.semantic.text.accent)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled(ch.to_string(), base)
+            }
+        })
+        .collect();
+    spans.push(Span::raw(" ".repeat(
+        NAME_WIDTH.saturating_sub(UnicodeWidthStr::width(name.as_str())) + 2,
+    )));
+
+    // Values changed from the compiled default stand out from the long tail.
+    let value_style = if setting.differs_from_boot() {
+        base.add_modifier(Modifier::BOLD)
+    } else {
+        base
+    };
+    spans.push(Span::styled(
+        truncate_to_width_with(&setting.current_display(), value_max, "\u{2026}"),
+        value_style,
+    ));
+    if setting.pending_restart {
+        spans.push(Span::styled(
+            RESTART_BADGE,
+            theme.status_style(StatusTone::Warning),
+        ));
+    }
+    // Pinned by the profile, so the value shown is this session's, not the server's.
+    if overridden {
+        spans.push(Span::styled(
+            SESSION_BADGE,
+            Style::default().fg(theme.semantic.text.accent),
+        ));
+    }
+
+    ListItem::new(Line::from(spans))
+}
+
+fn render_detail(
+    frame: &mut Frame,
+    area: Rect,
+    setting: Option<&ServerSetting>,
+    theme: &ThemePalette,
+) {
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(theme.modal_border_style())
+        .title(Span::styled(
+            " Detail ",
+            Style::default().fg(theme.component.modal.title),
+        ));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(setting) = setting else {
+        frame.render_widget(
+            Paragraph::new(Line::styled(
+                "No selection",
+                Style::default().fg(theme.semantic.text.muted),
+            )),
+            inner,
+        );
+        return;
+    };
+
+    let label = |text: &'static str| {
+        Span::styled(
+            format!("{text:<10}"),
+            Style::default().fg(theme.semantic.text.dim),
+        )
+    };
+    let value = |text: String| Span::styled(text, Style::default().fg(theme.semantic.text.primary));
+
+    let mut current = vec![label("Current"), value(setting.current_display())];
+    if setting.pending_restart {
+        current.push(Span::styled(
+            "  (changed in config; takes effect after restart)",
+            theme.status_style(StatusTone::Warning),
+        ));
+    }
+    let lines = vec![
+        Line::from(current),
+        Line::from(vec![label("Reset"), value(setting.reset_display())]),
+        Line::from(vec![label("Boot"), value(setting.boot_display())]),
+        Line::from(vec![
+            label("Source"),
+            value(setting.source.clone()),
+            Span::styled(
+                format!("  │ {}", setting.category),
+                Style::default().fg(theme.semantic.text.dim),
+            ),
+        ]),
+        Line::styled(
+            setting.short_desc.clone(),
+            Style::default().fg(theme.semantic.text.secondary),
+        ),
+    ];
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
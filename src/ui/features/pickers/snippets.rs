@@ -0,0 +1,169 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+
+use crate::app::i18n::Message;
+use crate::app::model::app_state::AppState;
+use crate::app::model::shared::render_output::PickerLayout;
+use crate::app::model::sql_editor::snippets::FilteredSnippet;
+use crate::app::ports::outbound::Snippet;
+use crate::primitives::atoms::highlight_sql;
+use crate::primitives::molecules::{FooterHintBar, render_filter_input_line, render_modal};
+use crate::primitives::utils::text_utils::truncate_to_width_with;
+use crate::theme::ThemePalette;
+
+pub struct SnippetPicker;
+
+impl SnippetPicker {
+    pub fn render(frame: &mut Frame, state: &AppState, theme: &ThemePalette) -> PickerLayout {
+        let browser = &state.snippets;
+        let filtered = browser.filtered();
+        let selected_idx = browser.clamped_selected();
+
+        let prefix = format!("{} snippets │ type to filter", filtered.len());
+        let (_, inner) = render_modal(
+            frame,
+            Constraint::Percentage(85),
+            Constraint::Percentage(80),
+            &format!(" {} ", Message::SnippetsTitle.text(state.settings.locale())),
+            FooterHintBar::with_prefix(
+                prefix,
+                [("Enter", "Insert"), ("^R", "Run"), ("Esc", "Close")],
+            ),
+            theme,
+        );
+
+        let [filter_area, body] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(inner);
+        let [list_area, preview_area] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(body);
+
+        let visible_width = render_filter_input_line(
+            frame,
+            filter_area,
+            browser.picker().filter_input(),
+            Some("type to filter"),
+            theme,
+        );
+        let layout = PickerLayout {
+            pane_height: list_area.height,
+            filter_visible_width: visible_width,
+        };
+
+        if filtered.is_empty() {
+            let msg = if browser.is_loading() {
+                "Loading..."
+            } else if browser.snippets().is_empty() {
+                "No snippets yet (:save <name> in the SQL editor)"
+            } else {
+                "No matches"
+            };
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    format!("  {msg}"),
+                    Style::default().fg(theme.semantic.text.secondary),
+                ))),
+                list_area,
+            );
+            render_preview(frame, preview_area, None, theme);
+            return layout;
+        }
+
+        let name_max = (list_area.width as usize).saturating_sub(2);
+        let items: Vec<ListItem> = filtered
+            .iter()
+            .enumerate()
+            .map(|(i, fs)| build_list_item(fs, i == selected_idx, name_max, theme))
+            .collect();
+        render_preview(
+            frame,
+            preview_area,
+            filtered.get(selected_idx).map(|fs| fs.snippet),
+            theme,
+        );
+
+        let list = List::new(items)
+            .highlight_style(theme.picker_selected_style())
+            .highlight_symbol("\u{25b8} ");
+        let mut list_state = ListState::default()
+            .with_selected(Some(selected_idx))
+            .with_offset(browser.picker().scroll_offset());
+        frame.render_stateful_widget(list, list_area, &mut list_state);
+        layout
+    }
+}
+
+fn build_list_item(
+    fs: &FilteredSnippet<'_>,
+    selected: bool,
+    name_max: usize,
+    theme: &ThemePalette,
+) -> ListItem<'static> {
+    let base = Style::default().fg(if selected {
+        theme.semantic.text.primary
+    } else {
+        theme.semantic.text.secondary
+    });
+    let name = truncate_to_width_with(&fs.snippet.name, name_max, "\u{2026}");
+
+    let spans: Vec<Span> = name
+        .chars()
+        .enumerate()
+        .map(|(ci, ch)| {
+            if fs.match_indices.contains(&(ci as u32)) {
+                Span::styled(
+                    ch.to_string(),
+                    Style::default()
+                        .fg(theme.semantic.text.accent)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled(ch.to_string(), base)
+            }
+        })
+        .collect();
+
+    ListItem::new(Line::from(spans))
+}
+
+fn render_preview(frame: &mut Frame, area: Rect, snippet: Option<&Snippet>, theme: &ThemePalette) {
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(theme.modal_border_style())
+        .title(Span::styled(
+            " Preview ",
+            Style::default().fg(theme.component.modal.title),
+        ));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(snippet) = snippet else {
+        frame.render_widget(
+            Paragraph::new(Line::styled(
+                " No selection",
+                Style::default().fg(theme.semantic.text.muted),
+            )),
+            inner,
+        );
+        return;
+    };
+
+    let visible: String = snippet
+        .sql
+        .lines()
+        .take(inner.height as usize)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let lines: Vec<Line> = highlight_sql(&visible, theme)
+        .into_iter()
+        .map(|line| {
+            let mut spans = vec![Span::raw(" ")];
+            spans.extend(line.spans);
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
@@ -3,6 +3,7 @@ use ratatui::layout::{Constraint, Layout};
 use ratatui::style::Style;
 use ratatui::widgets::{List, ListItem, ListState};
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::app::model::shared::render_output::PickerLayout;
 use crate::primitives::molecules::{FooterHintBar, render_filter_input_line, render_modal};
@@ -17,7 +18,10 @@ impl TablePicker {
             frame,
             Constraint::Percentage(60),
             Constraint::Percentage(70),
-            " Table Picker ",
+            &format!(
+                " {} ",
+                Message::TablePickerTitle.text(state.settings.locale())
+            ),
             FooterHintBar::with_prefix(format!("{filtered_count} tables"), [("Enter", "Select")]),
             theme,
         );
@@ -1,12 +1,19 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::style::Style;
-use ratatui::widgets::{Block, Borders, Clear, List, ListItem};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
 
 use crate::app::model::app_state::AppState;
-use crate::app::model::sql_editor::completion::CompletionKind;
+use crate::app::model::sql_editor::completion::{CompletionCandidate, CompletionKind};
 use crate::theme::ThemePalette;
 
+const MAX_ITEMS: usize = 8;
+const POPUP_WIDTH: u16 = 45;
+const PREVIEW_WIDTH: u16 = 40;
+/// Preview lines beyond the list height still get room up to this many.
+const PREVIEW_MAX_LINES: usize = 12;
+
 pub(super) fn render_completion_popup(
     frame: &mut Frame,
     modal_area: Rect,
@@ -18,10 +25,8 @@ pub(super) fn render_completion_popup(
     let scroll_row = state.sql_modal.editor().scroll_row();
 
     let completion = state.sql_modal.completion();
-    let max_items = 8;
-    let visible_count = completion.candidates.len().min(max_items);
-    let popup_height = (visible_count as u16) + 2;
-    let popup_width = 45u16.min(modal_area.width);
+    let wanted_height = (completion.candidates.len().min(MAX_ITEMS) as u16) + 2;
+    let popup_width = POPUP_WIDTH.min(modal_area.width);
 
     let popup_x = if modal_area.width < popup_width {
         modal_area.x
@@ -31,11 +36,12 @@ pub(super) fn render_completion_popup(
     let visible_row = cursor_row.saturating_sub(scroll_row);
     let cursor_screen_y = editor_area.y + visible_row as u16;
 
-    let popup_y = if cursor_screen_y + 1 + popup_height > modal_area.bottom() {
-        cursor_screen_y.saturating_sub(popup_height)
-    } else {
-        cursor_screen_y + 1
+    let Some((popup_y, popup_height)) =
+        vertical_placement(cursor_screen_y, modal_area, wanted_height)
+    else {
+        return;
     };
+    let max_items = usize::from(popup_height - 2);
 
     let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
 
@@ -74,20 +80,18 @@ pub(super) fn render_completion_popup(
         .map(|(i, candidate)| {
             let is_selected = i == selected;
 
-            let kind_label = match candidate.kind {
-                CompletionKind::Keyword => "keyword",
-                CompletionKind::Table => "table",
-                CompletionKind::Column => "column",
-            };
-
             let padding = max_text_width.saturating_sub(candidate.text.len()) + 2;
-            let text = format!(
+            let mut text = format!(
                 " {}{:padding$}{}",
                 candidate.text,
                 "",
-                kind_label,
+                kind_label(candidate.kind),
                 padding = padding
             );
+            if let Some(detail) = &candidate.detail {
+                text.push_str(" · ");
+                text.push_str(detail);
+            }
 
             let style = if is_selected {
                 theme.picker_selected_style()
@@ -107,4 +111,121 @@ pub(super) fn render_completion_popup(
     );
 
     frame.render_widget(list, popup_area);
+
+    if completion.preview_visible
+        && let Some(candidate) = completion.candidates.get(selected)
+    {
+        render_preview(
+            frame,
+            modal_area,
+            popup_area,
+            cursor_screen_y,
+            candidate,
+            theme,
+        );
+    }
+}
+
+fn kind_label(kind: CompletionKind) -> &'static str {
+    match kind {
+        CompletionKind::Keyword => "keyword",
+        CompletionKind::Table => "table",
+        CompletionKind::Column => "column",
+        CompletionKind::Function => "function",
+        CompletionKind::Snippet => "snippet",
+        CompletionKind::Template => "template",
+    }
+}
+
+/// Puts the popup below the cursor line when it fits there, otherwise
+/// above it, otherwise on whichever side has more rows, shortened to fit.
+/// `None` when neither side has room for a single item.
+fn vertical_placement(cursor_y: u16, area: Rect, wanted_height: u16) -> Option<(u16, u16)> {
+    let below = area.bottom().saturating_sub(cursor_y + 1);
+    let above = cursor_y.saturating_sub(area.y);
+    if wanted_height <= below {
+        Some((cursor_y + 1, wanted_height))
+    } else if wanted_height <= above {
+        Some((cursor_y - wanted_height, wanted_height))
+    } else if below >= above {
+        (below >= 3).then_some((cursor_y + 1, below))
+    } else {
+        (above >= 3).then_some((area.y, above))
+    }
+}
+
+/// Beside the popup on whichever side has room, on the same side of the
+/// cursor line as the popup so the edited line stays uncovered.
+fn render_preview(
+    frame: &mut Frame,
+    modal_area: Rect,
+    popup_area: Rect,
+    cursor_y: u16,
+    candidate: &CompletionCandidate,
+    theme: &ThemePalette,
+) {
+    if candidate.preview.is_empty() {
+        return;
+    }
+    let width = PREVIEW_WIDTH;
+    let x = if popup_area.right() + width <= modal_area.right() {
+        popup_area.right()
+    } else if popup_area.x >= modal_area.x + width {
+        popup_area.x - width
+    } else {
+        return;
+    };
+
+    let wanted_height =
+        (candidate.preview.len().min(PREVIEW_MAX_LINES) as u16 + 2).max(popup_area.height);
+    let (y, height) = if popup_area.y > cursor_y {
+        let room = modal_area.bottom().saturating_sub(popup_area.y);
+        (popup_area.y, wanted_height.min(room))
+    } else {
+        let room = popup_area.bottom().saturating_sub(modal_area.y);
+        let height = wanted_height.min(room);
+        (popup_area.bottom() - height, height)
+    };
+    let area = Rect::new(x, y, width, height);
+
+    let lines: Vec<Line> = candidate
+        .preview
+        .iter()
+        .map(|line| Line::raw(line.as_str()))
+        .collect();
+    let preview = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .style(Style::default().fg(theme.semantic.text.secondary))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", kind_label(candidate.kind)))
+                .border_style(theme.modal_border_style()),
+        );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(preview, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::below(24, 5, 10, Some((6, 10)))]
+    #[case::above_when_below_is_short(24, 20, 10, Some((10, 10)))]
+    #[case::shrunk_below(24, 11, 20, Some((12, 12)))]
+    #[case::shrunk_above(24, 13, 20, Some((0, 13)))]
+    #[case::no_room(2, 0, 10, None)]
+    fn places_popup_around_the_cursor_line(
+        #[case] area_height: u16,
+        #[case] cursor_y: u16,
+        #[case] wanted_height: u16,
+        #[case] expected: Option<(u16, u16)>,
+    ) {
+        let area = Rect::new(0, 0, 80, area_height);
+
+        assert_eq!(vertical_placement(cursor_y, area, wanted_height), expected);
+    }
 }
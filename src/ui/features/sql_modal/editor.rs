@@ -12,7 +12,8 @@ use crate::app::model::shared::text_input::TextInputLike;
 use crate::app::model::sql_editor::modal::SqlModalStatus;
 use crate::primitives::atoms::{
     CursorKind, ModalTextSurface, apply_yank_flash, build_modal_text_surface_lines,
-    highlight_sql_spans, render_modal_text_surface,
+    highlight_sql_spans_with_warnings, highlight_sql_tokens_with_warnings,
+    render_modal_text_surface,
 };
 use crate::theme::ThemePalette;
 
@@ -74,9 +75,31 @@ pub(super) fn render_editor(
         base_style: Style::default(),
         current_line_style: Style::default().bg(theme.component.editor.current_line_bg),
     };
-    let line_spans = highlight_sql_spans(content, theme);
+    let warning_spans: Vec<_> = state
+        .sql_modal
+        .editor_lint_warnings()
+        .unwrap_or_default()
+        .iter()
+        .map(|w| w.span.clone())
+        .collect();
+    let line_spans = match state.sql_modal.editor_tokens() {
+        Some(tokens) => highlight_sql_tokens_with_warnings(content, tokens, &warning_spans, theme),
+        None => highlight_sql_spans_with_warnings(content, &warning_spans, theme),
+    };
     let mut lines = build_modal_text_surface_lines(surface, line_spans, theme);
 
+    if is_normal
+        && let Some((first, last)) = state
+            .sql_modal
+            .vim()
+            .visual_rows(cursor_row, state.sql_modal.editor().line_count())
+    {
+        let selection_style = Style::default().bg(theme.component.editor.completion_selected_bg);
+        for line in lines.iter_mut().take(last + 1).skip(first) {
+            *line = std::mem::take(line).style(selection_style);
+        }
+    }
+
     let flash_active = state.flash_timers.is_active(FlashId::SqlModal, now);
     apply_yank_flash(&mut lines, flash_active, theme);
 
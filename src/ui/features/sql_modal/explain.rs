@@ -10,6 +10,7 @@ use crate::app::model::app_state::AppState;
 use crate::app::model::shared::flash_timer::FlashId;
 use crate::app::model::shared::text_input::TextInputState;
 use crate::app::model::sql_editor::modal::{HIGH_RISK_INPUT_VISIBLE_WIDTH, SqlModalStatus};
+use crate::app::policy::sql::resource_usage::resource_usage_summary;
 use crate::app::policy::sql::sqlite_explain::SQLITE_EXPLAIN_QUERY_PLAN_PREFIX;
 use crate::app::policy::write::sql_risk::AcknowledgeReason;
 use crate::app::policy::{FeaturePolicy, FeatureRequirement};
@@ -89,10 +90,15 @@ pub fn render(
             )
         };
         let time_secs = state.explain.execution_time_ms() as f64 / 1000.0;
+        let usage = state
+            .explain
+            .resource_usage()
+            .map(|usage| format!(" \u{00b7} {}", resource_usage_summary(usage)))
+            .unwrap_or_default();
         let header = Line::from(vec![
             Span::styled(format!("{label} "), label_style),
             Span::styled(
-                format!("({time_secs:.2}s)"),
+                format!("({time_secs:.2}s){usage}"),
                 Style::default().fg(theme.semantic.text.muted),
             ),
         ]);
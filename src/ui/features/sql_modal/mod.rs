@@ -5,22 +5,24 @@ mod plan_highlight;
 use std::time::Instant;
 
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::symbols::border;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
+use crate::app::i18n::{Locale, Message};
 use crate::app::model::app_state::AppState;
 use crate::app::model::shared::engine_feature_profile::EngineFeatureProfile;
 use crate::app::model::shared::settings::KeymapPreset;
-use crate::app::model::sql_editor::modal::{SQL_MODAL_HEIGHT_PERCENT, SqlModalStatus, SqlModalTab};
+use crate::app::model::shared::text_input::TextInputLike;
+use crate::app::model::sql_editor::modal::{SqlModalSize, SqlModalStatus, SqlModalTab};
 use crate::app::policy::write::sql_risk::AcknowledgeReason;
 use crate::app::policy::{FeaturePolicy, FeatureRequirement};
 use crate::app::update::input::keybindings::{
     sql_modal, sql_modal_compare, sql_modal_normal, sql_modal_plan, sql_modal_plan_explain,
 };
-use crate::primitives::molecules::overlay::{centered_rect, render_scrim};
+use crate::primitives::molecules::overlay::render_scrim;
 use crate::primitives::molecules::{FooterHintBar, render_modal_with_border_color};
 use crate::theme::ThemePalette;
 
@@ -45,6 +47,7 @@ impl SqlModal {
         let feature_policy = FeaturePolicy::new(engine_feature_profile);
         let active_tab =
             engine_feature_profile.normalize_sql_modal_tab(state.sql_modal.active_tab());
+        let size = state.sql_modal.size();
 
         let (area, inner) = if is_confirming {
             match state.sql_modal.status() {
@@ -65,8 +68,8 @@ impl SqlModal {
                     };
                     render_modal_with_border_color(
                         frame,
-                        Constraint::Percentage(80),
-                        Constraint::Percentage(SQL_MODAL_HEIGHT_PERCENT),
+                        Constraint::Percentage(size.width_percent()),
+                        Constraint::Percentage(size.height_percent()),
                         &title,
                         footer,
                         theme.semantic.status.error,
@@ -90,8 +93,8 @@ impl SqlModal {
                     };
                     render_modal_with_border_color(
                         frame,
-                        Constraint::Percentage(80),
-                        Constraint::Percentage(SQL_MODAL_HEIGHT_PERCENT),
+                        Constraint::Percentage(size.width_percent()),
+                        Constraint::Percentage(size.height_percent()),
                         title,
                         FooterHintBar::new([("Enter", "Execute"), ("Esc", "Back")]),
                         border_color,
@@ -130,7 +133,17 @@ impl SqlModal {
                     )
                 }
             };
-            Self::render_modal_with_tabs(frame, active_tab, hint, engine_feature_profile, theme)
+            let linked_file = Self::linked_file_span(state, theme);
+            Self::render_modal_with_tabs(
+                frame,
+                size,
+                active_tab,
+                hint,
+                linked_file,
+                engine_feature_profile,
+                state.settings.locale(),
+                theme,
+            )
         };
 
         // Add 1-char horizontal padding for breathing room inside the modal
@@ -188,20 +201,35 @@ impl SqlModal {
 
     fn render_modal_with_tabs(
         frame: &mut Frame,
+        size: SqlModalSize,
         active_tab: SqlModalTab,
         hint: FooterHintBar,
+        linked_file: Option<Span<'static>>,
         engine_feature_profile: &EngineFeatureProfile,
+        locale: Locale,
         theme: &ThemePalette,
     ) -> (Rect, Rect) {
-        let area = centered_rect(
-            frame.area(),
-            Constraint::Percentage(80),
-            Constraint::Percentage(SQL_MODAL_HEIGHT_PERCENT),
-        );
-        render_scrim(frame, theme);
+        let width = Constraint::Percentage(size.width_percent());
+        let height = Constraint::Percentage(size.height_percent());
+        let area = if size == SqlModalSize::Compact {
+            // Docked at the top and undimmed so the result pane below stays readable.
+            let [top] = Layout::vertical([height])
+                .flex(Flex::Start)
+                .areas(frame.area());
+            top.centered_horizontally(width)
+        } else {
+            render_scrim(frame, theme);
+            frame.area().centered(width, height)
+        };
         frame.render_widget(Clear, area);
 
-        let title = Self::build_title_with_tabs(active_tab, engine_feature_profile, theme);
+        let title = Self::build_title_with_tabs(
+            active_tab,
+            linked_file,
+            engine_feature_profile,
+            locale,
+            theme,
+        );
         let block = Block::default()
             .title(title)
             .title_bottom(hint.line(theme))
@@ -215,9 +243,28 @@ impl SqlModal {
         (area, inner)
     }
 
+    // `name.sql [+]` while the buffer differs from disk; a conflict replaces the
+    // marker with a warning until `:w` or `:e!` resolves it.
+    fn linked_file_span(state: &AppState, theme: &ThemePalette) -> Option<Span<'static>> {
+        let file = state.linked_sql_file.file()?;
+        let name = file.file_name();
+        Some(if file.has_conflict() {
+            Span::styled(
+                format!("{name} \u{26a0} changed on disk "),
+                Style::default().fg(theme.semantic.status.warning),
+            )
+        } else if file.is_dirty(state.sql_modal.editor().content()) {
+            Span::styled(format!("{name} [+] "), theme.modal_title_style())
+        } else {
+            Span::styled(format!("{name} "), theme.modal_title_style())
+        })
+    }
+
     fn build_title_with_tabs(
         active_tab: SqlModalTab,
+        linked_file: Option<Span<'static>>,
         engine_feature_profile: &EngineFeatureProfile,
+        locale: Locale,
         theme: &ThemePalette,
     ) -> Line<'static> {
         let title_style = theme.modal_title_style();
@@ -235,14 +282,25 @@ impl SqlModal {
         };
         let supported_tabs = engine_feature_profile.supported_sql_modal_tabs();
 
+        let mut spans = vec![Span::styled(
+            format!(" {} ", Message::SqlEditorTitle.text(locale)),
+            title_style,
+        )];
+        if let Some(linked_file) = linked_file {
+            spans.push(Span::styled(
+                "\u{2500}\u{2500} ",
+                theme.modal_border_style(),
+            ));
+            spans.push(linked_file);
+        }
         if supported_tabs.len() == 1 {
-            return Line::from(vec![Span::styled(" SQL Editor ", title_style)]);
+            return Line::from(spans);
         }
 
-        let mut spans = vec![
-            Span::styled(" SQL Editor ", title_style),
-            Span::styled("\u{2500}\u{2500} ", theme.modal_border_style()),
-        ];
+        spans.push(Span::styled(
+            "\u{2500}\u{2500} ",
+            theme.modal_border_style(),
+        ));
         for tab in supported_tabs {
             let label = match tab {
                 SqlModalTab::Sql => "[SQL]",
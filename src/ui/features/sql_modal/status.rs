@@ -5,10 +5,13 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::app::model::app_state::AppState;
-use crate::app::model::shared::text_input::TextInputState;
-use crate::app::model::sql_editor::modal::{HIGH_RISK_INPUT_VISIBLE_WIDTH, SqlModalStatus};
+use crate::app::model::shared::text_input::{TextInputLike, TextInputState};
+use crate::app::model::sql_editor::modal::{
+    HIGH_RISK_INPUT_VISIBLE_WIDTH, SqlModalStatus, SqlModalTab,
+};
 use crate::app::policy::write::sql_risk::AcknowledgeReason;
 use crate::app::policy::write::write_guardrails::AdhocRiskDecision;
+use crate::app::update::input::keybindings::sql_modal;
 use crate::primitives::atoms::{spinner_char, text_cursor_spans};
 use crate::primitives::utils::text_utils::truncate_to_width_with;
 use crate::theme::ThemePalette;
@@ -29,32 +32,58 @@ pub(super) fn render_status(frame: &mut Frame, area: Rect, state: &AppState, the
         return;
     }
 
+    let normal_badge = if state.sql_modal.vim().is_visual_line() {
+        "[V-LINE]"
+    } else {
+        "[NORMAL]"
+    };
     let (badge_text, badge_style, status_text, status_style) = match state.sql_modal.status() {
         SqlModalStatus::Normal => {
             if let Some(msg) = state.messages.last_success() {
                 (
-                    "[NORMAL]",
+                    normal_badge,
                     Style::default().fg(theme.semantic.text.dim),
                     format!("\u{2713} {msg}"),
                     Style::default().fg(theme.semantic.status.success),
                 )
+            } else if let Some(lint) = lint_status_message(state) {
+                (
+                    normal_badge,
+                    Style::default().fg(theme.semantic.text.dim),
+                    lint,
+                    Style::default().fg(theme.semantic.status.warning),
+                )
             } else {
                 (
-                    "[NORMAL]",
+                    normal_badge,
                     Style::default().fg(theme.semantic.text.dim),
                     "Ready".to_string(),
                     Style::default().fg(theme.semantic.text.dim),
                 )
             }
         }
-        SqlModalStatus::Editing => (
-            "[INSERT]",
-            Style::default()
-                .fg(theme.semantic.text.accent)
-                .add_modifier(Modifier::BOLD),
-            "Ready".to_string(),
-            Style::default().fg(theme.semantic.text.dim),
-        ),
+        SqlModalStatus::Editing => {
+            let (status, status_style) = alias_rename_prompt(state)
+                .map(|prompt| (prompt, Style::default().fg(theme.semantic.text.accent)))
+                .or_else(|| {
+                    lint_status_message(state)
+                        .map(|lint| (lint, Style::default().fg(theme.semantic.status.warning)))
+                })
+                .unwrap_or_else(|| {
+                    (
+                        "Ready".to_string(),
+                        Style::default().fg(theme.semantic.text.dim),
+                    )
+                });
+            (
+                "[INSERT]",
+                Style::default()
+                    .fg(theme.semantic.text.accent)
+                    .add_modifier(Modifier::BOLD),
+                status,
+                status_style,
+            )
+        }
         SqlModalStatus::Running => {
             let elapsed = state
                 .query
@@ -74,7 +103,7 @@ pub(super) fn render_status(frame: &mut Frame, area: Rect, state: &AppState, the
         SqlModalStatus::Success => {
             let msg = success_status_message(state);
             (
-                "[NORMAL]",
+                normal_badge,
                 Style::default().fg(theme.semantic.status.success),
                 msg,
                 Style::default()
@@ -85,7 +114,7 @@ pub(super) fn render_status(frame: &mut Frame, area: Rect, state: &AppState, the
         SqlModalStatus::Error => {
             let msg = error_status_message(state);
             (
-                "[NORMAL]",
+                normal_badge,
                 Style::default().fg(theme.semantic.status.error),
                 msg,
                 Style::default()
@@ -134,6 +163,51 @@ pub(super) fn render_status(frame: &mut Frame, area: Rect, state: &AppState, the
     );
 }
 
+fn alias_rename_prompt(state: &AppState) -> Option<String> {
+    let offer = state.sql_modal.alias_rename_offer()?;
+    let noun = if offer.references == 1 { "ref" } else { "refs" };
+    Some(format!(
+        "{}: rename {}. \u{2192} {}. ({} {noun})",
+        sql_modal::RENAME_ALIAS.key_short,
+        offer.from,
+        offer.to,
+        offer.references,
+    ))
+}
+
+// Explains the warning on the cursor line; elsewhere only the count is shown.
+fn lint_status_message(state: &AppState) -> Option<String> {
+    if state.sql_modal.active_tab() != SqlModalTab::Sql {
+        return None;
+    }
+    let content = state.sql_modal.editor().content();
+    let warnings = state.sql_modal.editor_lint_warnings()?;
+    if warnings.is_empty() {
+        return None;
+    }
+
+    let (cursor_row, _) = state.sql_modal.editor().cursor_to_position();
+    let line_start: usize = content
+        .split('\n')
+        .take(cursor_row)
+        .map(|line| line.chars().count() + 1)
+        .sum();
+    let line_end = line_start
+        + content
+            .split('\n')
+            .nth(cursor_row)
+            .map_or(0, |line| line.chars().count());
+
+    let on_cursor_line = warnings
+        .iter()
+        .find(|w| w.span.start <= line_end && line_start < w.span.end);
+    Some(match on_cursor_line {
+        Some(warning) => format!("\u{26a0} {}", warning.rule.message()),
+        None if warnings.len() == 1 => "\u{26a0} 1 lint warning".to_string(),
+        None => format!("\u{26a0} {} lint warnings", warnings.len()),
+    })
+}
+
 fn render_confirming_high_status(
     frame: &mut Frame,
     area: Rect,
@@ -244,6 +318,9 @@ fn success_status_message(state: &AppState) -> String {
 }
 
 fn error_status_message(state: &AppState) -> String {
+    if let Some(summary) = state.sql_modal.lock_blocker_summary() {
+        return format!("\u{2717} {summary}");
+    }
     state
         .sql_modal
         .last_adhoc_error()
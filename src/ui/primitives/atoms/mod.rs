@@ -10,7 +10,10 @@ mod yank_flash;
 pub use key_chip::{key_chip, key_text};
 pub use panel_border::{panel_block, panel_block_highlight};
 pub use spinner::spinner_char;
-pub use sql_highlight::{highlight_sql, highlight_sql_spans};
+pub use sql_highlight::{
+    highlight_sql, highlight_sql_spans, highlight_sql_spans_with_warnings,
+    highlight_sql_tokens_with_warnings,
+};
 pub use text_cursor::{
     CursorKind, ModalTextSurface, build_modal_text_surface_lines, cursor_style_for,
     insert_cursor_span, insert_cursor_span_with_kind, render_modal_text_surface,
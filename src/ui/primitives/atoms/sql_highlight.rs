@@ -1,7 +1,9 @@
+use std::ops::Range;
+
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 
-use crate::app::policy::sql::lexer::{SqlLexer, TokenKind};
+use crate::app::policy::sql::lexer::{SqlLexer, Token, TokenKind};
 use crate::theme::ThemePalette;
 
 pub fn highlight_sql(text: &str, theme: &ThemePalette) -> Vec<Line<'static>> {
@@ -12,16 +14,46 @@ pub fn highlight_sql(text: &str, theme: &ThemePalette) -> Vec<Line<'static>> {
 }
 
 pub fn highlight_sql_spans(text: &str, theme: &ThemePalette) -> Vec<Vec<Span<'static>>> {
+    highlight_sql_spans_with_warnings(text, &[], theme)
+}
+
+/// Like [`highlight_sql_spans`], underlining tokens that overlap a lint warning char range.
+pub fn highlight_sql_spans_with_warnings(
+    text: &str,
+    warnings: &[Range<usize>],
+    theme: &ThemePalette,
+) -> Vec<Vec<Span<'static>>> {
+    if text.is_empty() {
+        return vec![];
+    }
+
+    let tokens = SqlLexer::new().tokenize(text, text.chars().count());
+    highlight_sql_tokens_with_warnings(text, &tokens, warnings, theme)
+}
+
+/// Like [`highlight_sql_spans_with_warnings`] for tokens already lexed from `text`.
+pub fn highlight_sql_tokens_with_warnings(
+    text: &str,
+    tokens: &[Token],
+    warnings: &[Range<usize>],
+    theme: &ThemePalette,
+) -> Vec<Vec<Span<'static>>> {
     if text.is_empty() {
         return vec![];
     }
 
-    let lexer = SqlLexer::new();
-    let tokens = lexer.tokenize(text, text.chars().count());
     let mut lines: Vec<Vec<Span<'static>>> = vec![Vec::new()];
 
     for token in tokens {
-        let style = token_style(&token.kind, theme);
+        let mut style = token_style(&token.kind, theme);
+        if warnings
+            .iter()
+            .any(|span| span.start < token.end && token.start < span.end)
+        {
+            style = style
+                .underline_color(theme.semantic.status.warning)
+                .add_modifier(Modifier::UNDERLINED);
+        }
         let mut segment = String::new();
 
         for ch in token.text.chars() {
@@ -4,7 +4,7 @@ use ratatui::style::Color;
 use ratatui::widgets::Clear;
 
 use crate::primitives::molecules::FooterHintBar;
-use crate::primitives::molecules::overlay::{centered_rect, modal_block, render_scrim};
+use crate::primitives::molecules::overlay::{modal_block, render_scrim};
 use crate::theme::ThemePalette;
 
 pub fn render_modal(
@@ -35,7 +35,7 @@ pub fn render_modal_with_border_color(
     border_color: Color,
     theme: &ThemePalette,
 ) -> (Rect, Rect) {
-    let area = centered_rect(frame.area(), width, height);
+    let area = frame.area().centered(width, height);
 
     render_scrim(frame, theme);
     frame.render_widget(Clear, area);
@@ -1,5 +1,4 @@
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::symbols::border;
 use ratatui::text::Line;
@@ -7,12 +6,6 @@ use ratatui::widgets::{Block, Borders};
 
 use crate::theme::ThemePalette;
 
-pub fn centered_rect(area: Rect, width: Constraint, height: Constraint) -> Rect {
-    let [area] = Layout::horizontal([width]).flex(Flex::Center).areas(area);
-    let [area] = Layout::vertical([height]).flex(Flex::Center).areas(area);
-    area
-}
-
 // Uses DIM + dark foreground to suppress background borders
 // that would otherwise appear adjacent to modal borders.
 pub fn render_scrim(frame: &mut Frame, theme: &ThemePalette) {
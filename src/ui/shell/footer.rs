@@ -1,6 +1,6 @@
 use ratatui::Frame;
-use ratatui::layout::Rect;
-use ratatui::style::Style;
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
@@ -13,21 +13,24 @@ use crate::app::model::shared::help::HelpMode;
 use crate::app::model::shared::input_mode::InputMode;
 use crate::app::model::shared::ui_state::ResultNavMode;
 use crate::app::model::sql_editor::modal::SqlModalStatus;
+use crate::app::policy::sql::replica_routing::QueryEndpoint;
 use crate::app::policy::{FeaturePolicy, FeatureRequirement};
 use crate::app::update::input::keybindings::{
-    ModeRow, ROW_DETAIL_FOOTER_ROWS, cell_detail, cell_detail_search, cell_edit, command_palette,
+    CONNECTION_IMPORT_ROWS, ER_DIAGRAM_ROWS, ModeRow, QUICK_ACTIONS_ROWS, ROW_DETAIL_FOOTER_ROWS,
+    cell_detail, cell_detail_search, cell_edit, command_palette,
     command_palette as command_palette_key, connection_error, connection_selector,
     connection_setup, connection_setup_save, csv_export, er_picker, er_picker_select_all,
-    exit_read_only, footer_nav, global, help, inspector_ddl, jsonb_detail, jsonb_edit,
-    jsonb_search, overlay, query_history, query_history_picker, read_only, result_active, settings,
-    sql_modal, sql_modal_confirming, sqlite_diagnostics, table_picker,
-    table_picker as table_picker_key,
+    exit_read_only, footer_nav, global, help, inspector_columns, inspector_ddl, inspector_rls,
+    inspector_stats, jsonb_detail, jsonb_edit, jsonb_search, object_search, overlay, query_history,
+    query_history_picker, read_only, result_active, server_settings, session_settings, settings,
+    snippet_picker, sql_file_browser, sql_modal, sql_modal_confirming, sqlite_diagnostics,
+    table_picker, table_picker as table_picker_key,
 };
 use crate::features::settings::hints::settings_hints;
 use crate::primitives::atoms::key_text;
 use crate::primitives::atoms::spinner_char;
 use crate::primitives::atoms::status_message::{MessageType, StatusMessage};
-use crate::theme::ThemePalette;
+use crate::theme::{ThemePalette, accent_color};
 
 pub struct Footer;
 
@@ -40,18 +43,110 @@ impl Footer {
         theme: &ThemePalette,
     ) {
         let base_style = Style::default().fg(theme.semantic.text.primary);
-        if state.er_preparation.status() == ErStatus::Waiting {
-            let line = Self::build_er_waiting_line(state, time_ms, theme);
-            frame.render_widget(Paragraph::new(line).style(base_style), area);
+        let line = if state.runtime.is_finishing_up() {
+            Line::from(Span::styled(
+                "Finishing up…",
+                Style::default().fg(theme.semantic.text.accent),
+            ))
+        } else if state.er_preparation.status() == ErStatus::Waiting {
+            Self::build_er_waiting_line(state, time_ms, theme)
         } else if let Some(error) = state.messages.last_error() {
-            let line = StatusMessage::render_line(error, MessageType::Error, theme);
-            frame.render_widget(Paragraph::new(line).style(base_style), area);
+            StatusMessage::render_line(error, MessageType::Error, theme)
         } else {
             // Show hints with optional inline success message
             let hints = Self::get_context_hints(state);
-            let line =
-                Self::build_hint_line_with_success(&hints, state.messages.last_success(), theme);
-            frame.render_widget(Paragraph::new(line).style(base_style), area);
+            Self::build_hint_line_with_success(&hints, state.messages.last_success(), theme)
+        };
+        let mut spans = Self::profile_marker(state);
+        spans.extend(line.spans);
+
+        let chips = Self::status_chips(
+            state.session.in_transaction(),
+            state.runtime.queued_effects(),
+            state.session.last_query_endpoint(),
+            theme,
+        );
+        // One cell of air around each chip; the hints give way first on narrow terminals.
+        let chips_width = chips
+            .iter()
+            .map(|chip| chip.width() as u16 + 1)
+            .sum::<u16>()
+            + u16::from(!chips.is_empty());
+        let [line_area, chips_area] = area.layout(&Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(chips_width),
+        ]));
+        frame.render_widget(
+            Paragraph::new(Line::from(spans)).style(base_style),
+            line_area,
+        );
+        if !chips.is_empty() {
+            let chip_areas = chips_area.layout_vec(
+                &Layout::horizontal(
+                    chips
+                        .iter()
+                        .map(|chip| Constraint::Length(chip.width() as u16)),
+                )
+                .flex(Flex::SpaceEvenly),
+            );
+            for (chip, chip_area) in chips.into_iter().zip(chip_areas) {
+                frame.render_widget(chip, chip_area);
+            }
+        }
+    }
+
+    /// Session state that stays visible whatever the footer is showing:
+    /// an open transaction, queued background tasks and the last query endpoint.
+    fn status_chips(
+        in_transaction: bool,
+        queued: usize,
+        endpoint: Option<QueryEndpoint>,
+        theme: &ThemePalette,
+    ) -> Vec<Span<'static>> {
+        let mut chips = Vec::new();
+        if in_transaction {
+            chips.push(Span::styled(
+                "● TX",
+                Style::default()
+                    .fg(theme.semantic.status.warning)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if queued > 0 {
+            chips.push(Span::styled(
+                format!("⧗ {queued} queued"),
+                Style::default().fg(theme.semantic.status.warning),
+            ));
+        }
+        if let Some(endpoint) = endpoint {
+            chips.push(Span::styled(
+                format!("[{}]", endpoint.label()),
+                Style::default().fg(theme.semantic.text.accent),
+            ));
+        }
+        chips
+    }
+
+    /// Leading banner in the active profile's accent: a PRODUCTION chip for
+    /// production profiles, a colored bar for other accented ones.
+    fn profile_marker(state: &AppState) -> Vec<Span<'static>> {
+        let Some(accent) = state.active_accent() else {
+            return Vec::new();
+        };
+        let color = accent_color(accent);
+        if state.active_is_production() {
+            vec![
+                Span::styled(
+                    " PRODUCTION ",
+                    Style::default()
+                        .bg(color)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("  "),
+            ]
+        } else {
+            vec![Span::styled("▌ ", Style::default().fg(color))]
         }
     }
 
@@ -101,6 +196,13 @@ impl Footer {
                             result_active::DRAFT_DISCARD.as_hint(),
                             global::QUIT.as_hint(),
                         ]
+                    } else if state.result_interaction.selection().range().is_some() {
+                        vec![
+                            result_active::RANGE_YANK.as_hint(),
+                            global::HELP.as_hint(),
+                            result_active::RANGE_CLEAR.as_hint(),
+                            global::QUIT.as_hint(),
+                        ]
                     } else if state.result_interaction.staged_delete_rows().is_empty() {
                         let mut hints = vec![result_active::DETAIL.as_hint()];
                         if can_edit_selected_cell {
@@ -183,6 +285,16 @@ impl Footer {
                         if active_inspector_tab == InspectorTab::Ddl {
                             list.push(inspector_ddl::YANK.as_hint());
                         }
+                        if active_inspector_tab == InspectorTab::Columns {
+                            list.push(inspector_columns::PIN.as_hint());
+                        }
+                        if active_inspector_tab == InspectorTab::Stats {
+                            list.push(inspector_stats::COLUMN_NAV.as_hint());
+                        }
+                        if active_inspector_tab == InspectorTab::Rls {
+                            list.push(inspector_rls::ROLE_FILTER.as_hint());
+                            list.push(inspector_rls::TEST_AS_ROLE.as_hint());
+                        }
                     }
                     // Navigation
                     if state.ui.focused_pane() == FocusedPane::Result {
@@ -293,13 +405,17 @@ impl Footer {
                 } else {
                     connection_error::RETRY.as_hint()
                 };
-                vec![
-                    first,
+                let mut hints = vec![first];
+                if state.session.has_offline_snapshot() {
+                    hints.push(connection_error::OFFLINE.as_hint());
+                }
+                hints.extend([
                     connection_error::SWITCH.as_hint(),
                     connection_error::DETAILS.as_hint(),
                     connection_error::COPY.as_hint(),
                     connection_error::ESC_CLOSE.as_hint(),
-                ]
+                ]);
+                hints
             }
             InputMode::SqliteDiagnostics => {
                 let feature_policy =
@@ -326,6 +442,7 @@ impl Footer {
                 if feature_policy.is_enabled(FeatureRequirement::ErDiagram) {
                     hints.extend([
                         er_picker::ENTER_GENERATE.as_hint(),
+                        er_picker::VIEW.as_hint(),
                         er_picker::SELECT.as_hint(),
                         er_picker_select_all(state.settings.saved_keymap_preset()).as_hint(),
                         er_picker::TYPE_FILTER.as_hint(),
@@ -339,6 +456,33 @@ impl Footer {
                 query_history_picker::TYPE_FILTER.as_hint(),
                 query_history_picker::ESC_CLOSE.as_hint(),
             ],
+            InputMode::ServerSettings => vec![
+                server_settings::NAVIGATE.as_hint(),
+                server_settings::TYPE_FILTER.as_hint(),
+                server_settings::ESC_CLOSE.as_hint(),
+            ],
+            InputMode::SessionSettings => vec![
+                session_settings::SAVE.as_hint(),
+                session_settings::FIELD.as_hint(),
+                session_settings::ESC_CLOSE.as_hint(),
+            ],
+            InputMode::SqlFileBrowser => vec![
+                sql_file_browser::ENTER_OPEN.as_hint(),
+                sql_file_browser::RUN.as_hint(),
+                sql_file_browser::TYPE_FILTER.as_hint(),
+                sql_file_browser::ESC_CLOSE.as_hint(),
+            ],
+            InputMode::SnippetPicker => vec![
+                snippet_picker::ENTER_INSERT.as_hint(),
+                snippet_picker::RUN.as_hint(),
+                snippet_picker::TYPE_FILTER.as_hint(),
+                snippet_picker::ESC_CLOSE.as_hint(),
+            ],
+            InputMode::ObjectSearch => vec![
+                object_search::ENTER_JUMP.as_hint(),
+                object_search::TYPE_FILTER.as_hint(),
+                object_search::ESC_CLOSE.as_hint(),
+            ],
             InputMode::JsonbDetail => {
                 let feature_policy =
                     FeaturePolicy::new(state.session.active_engine_feature_profile());
@@ -395,6 +539,12 @@ impl Footer {
                 .iter()
                 .map(ModeRow::as_hint)
                 .collect(),
+            InputMode::ErDiagram => ER_DIAGRAM_ROWS.iter().map(ModeRow::as_hint).collect(),
+            InputMode::ConnectionImport => CONNECTION_IMPORT_ROWS
+                .iter()
+                .map(ModeRow::as_hint)
+                .collect(),
+            InputMode::QuickActions => QUICK_ACTIONS_ROWS.iter().map(ModeRow::as_hint).collect(),
             InputMode::ConnectionSelector => {
                 use connection_selector as cs;
                 let is_service_selected = connection_list::is_service_selected(
@@ -441,7 +591,9 @@ impl Footer {
 #[cfg(test)]
 mod tests {
     use super::Footer;
-    use crate::app::domain::{ConnectionId, DatabaseType};
+    use crate::app::domain::{
+        ConnectionId, ConnectionProfile, DatabaseType, ProfileAccent, SslMode,
+    };
     use crate::app::model::app_state::AppState;
     use crate::app::model::connection::setup::ConnectionField;
     use crate::app::model::shared::focused_pane::FocusedPane;
@@ -449,9 +601,11 @@ mod tests {
     use crate::app::model::shared::settings::KeymapPreset;
     use crate::app::model::shared::ui_state::FocusMode;
     use crate::app::model::sql_editor::modal::SqlModalStatus;
+    use crate::app::policy::sql::replica_routing::QueryEndpoint;
     use crate::app::update::input::keybindings::{
         connection_setup, global, help, jsonb_detail, jsonb_edit, result_active, row_detail,
     };
+    use crate::theme::{DEFAULT_THEME, accent_color};
     use rstest::rstest;
 
     fn inspector_state() -> AppState {
@@ -576,6 +730,19 @@ mod tests {
         assert!(hints.contains(&result_active::ROW_DETAIL.as_hint()));
     }
 
+    #[test]
+    fn range_footer_offers_copy_and_clear() {
+        let mut state = result_focused_state();
+        state.result_interaction.activate_cell(0, 0);
+        state.result_interaction.start_range();
+
+        let hints = Footer::get_context_hints(&state);
+
+        assert_eq!(hints[0], result_active::RANGE_YANK.as_hint());
+        assert!(hints.contains(&result_active::RANGE_CLEAR.as_hint()));
+        assert!(!hints.contains(&result_active::ROW_DETAIL.as_hint()));
+    }
+
     #[test]
     fn row_detail_footer_omits_navigation_hints() {
         let mut state = AppState::new("test".to_string());
@@ -675,4 +842,71 @@ mod tests {
         assert!(hints.contains(&connection_setup::SAVE.as_hint()));
         assert!(!hints.contains(&("Enter", "Connect")));
     }
+
+    #[test]
+    fn hint_line_leads_with_success_message() {
+        let line = Footer::build_hint_line_with_success(
+            &[global::HELP.as_hint()],
+            Some("Done"),
+            &DEFAULT_THEME,
+        );
+
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.starts_with("✓ Done  "));
+    }
+
+    #[rstest]
+    #[case(false, 0, None, &[])]
+    #[case(false, 0, Some(QueryEndpoint::Replica), &["[replica]"])]
+    #[case(false, 3, Some(QueryEndpoint::Primary), &["⧗ 3 queued", "[primary]"])]
+    #[case(true, 0, Some(QueryEndpoint::Primary), &["● TX", "[primary]"])]
+    fn status_chips_in_display_order(
+        #[case] in_transaction: bool,
+        #[case] queued: usize,
+        #[case] endpoint: Option<QueryEndpoint>,
+        #[case] expected: &[&str],
+    ) {
+        let chips = Footer::status_chips(in_transaction, queued, endpoint, &DEFAULT_THEME);
+
+        let texts: Vec<&str> = chips.iter().map(|chip| chip.content.as_ref()).collect();
+        assert_eq!(texts, expected);
+    }
+
+    fn state_with_profile(profile: ConnectionProfile) -> AppState {
+        let mut state = AppState::new("test".to_string());
+        state.session.activate_connection_with_dsn(
+            &profile.id,
+            "app",
+            DatabaseType::PostgreSQL,
+            "postgres://prod/app",
+        );
+        state.set_connections(vec![profile]);
+        state
+    }
+
+    fn profile() -> ConnectionProfile {
+        ConnectionProfile::new_postgres("app", "prod", 5432, "app", "user", "", SslMode::Prefer)
+            .unwrap()
+    }
+
+    #[test]
+    fn production_profile_leads_with_banner() {
+        let state = state_with_profile(profile().with_production(true));
+
+        let spans = Footer::profile_marker(&state);
+
+        assert_eq!(spans[0].content, " PRODUCTION ");
+        assert_eq!(spans[0].style.bg, Some(accent_color(ProfileAccent::Red)));
+    }
+
+    #[test]
+    fn accented_profile_shows_colored_bar_without_banner() {
+        let state = state_with_profile(profile().with_accent(Some(ProfileAccent::Green)));
+
+        let spans = Footer::profile_marker(&state);
+
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].style.fg, Some(accent_color(ProfileAccent::Green)));
+        assert!(Footer::profile_marker(&state_with_profile(profile())).is_empty());
+    }
 }
@@ -5,6 +5,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use unicode_width::UnicodeWidthStr;
 
+use crate::app::i18n::Message;
 use crate::app::model::app_state::AppState;
 use crate::domain::MetadataState;
 use crate::primitives::utils::text_utils::truncate_to_width_with;
@@ -23,16 +24,19 @@ impl Header {
         let sep_style = Style::default().fg(theme.semantic.text.muted);
         let item_style = Style::default().fg(theme.semantic.text.secondary);
 
-        let (status_text, status_color) = if state.session.dsn().is_none() {
-            ("no dsn", theme.semantic.status.error)
+        let (status, status_color) = if state.session.dsn().is_none() {
+            (Message::StatusNoDsn, theme.semantic.status.error)
+        } else if state.session.is_offline() {
+            (Message::StatusOffline, theme.semantic.status.warning)
         } else {
             match &state.session.metadata_state() {
-                MetadataState::Loaded => ("connected", theme.semantic.status.success),
-                MetadataState::Loading => ("loading...", theme.semantic.status.warning),
-                MetadataState::Error(_) => ("error", theme.semantic.status.error),
-                MetadataState::NotLoaded => ("not loaded", theme.semantic.text.muted),
+                MetadataState::Loaded => (Message::StatusConnected, theme.semantic.status.success),
+                MetadataState::Loading => (Message::StatusLoading, theme.semantic.status.warning),
+                MetadataState::Error(_) => (Message::StatusError, theme.semantic.status.error),
+                MetadataState::NotLoaded => (Message::StatusNotLoaded, theme.semantic.text.muted),
             }
         };
+        let status_text = status.text(state.settings.locale());
 
         let left_items = vec![
             HeaderItem::new(state.runtime.project_name(), item_style, 2),
@@ -52,11 +56,27 @@ impl Header {
                 1,
             ));
         }
+        if let Some(pool_mode) = state.active_pool_mode() {
+            right_items.push(HeaderItem::new(
+                &format!("pgbouncer: {pool_mode}"),
+                item_style,
+                1,
+            ));
+        }
         right_items.push(HeaderItem::new(
             state.session.active_connection_name().unwrap_or("-"),
             item_style,
             0,
         ));
+        if state.runtime.cache_owner_pid().is_some() {
+            right_items.push(HeaderItem::new(
+                "SHARED CACHE",
+                Style::default()
+                    .fg(theme.semantic.status.warning)
+                    .add_modifier(Modifier::BOLD),
+                2,
+            ));
+        }
         if state.session.is_read_only() {
             right_items.push(HeaderItem::new(
                 "READ-ONLY",
@@ -7,7 +7,7 @@ use crate::app::model::app_state::AppState;
 use crate::app::model::shared::input_mode::InputMode;
 use crate::app::model::shared::render_output::{
     BrowseLayout, ConfirmPreviewLayout, DetailLayout, ExplorerLayout, InputLayout, InspectorLayout,
-    OverlayLayout, PickerLayouts, ResultLayout,
+    OverlayLayout, PickerLayouts,
 };
 use crate::app::model::shared::ui_state::explorer_content_width_from_pane_width;
 use crate::app::ports::outbound::{CellDetailViewport, RenderOutput};
@@ -16,18 +16,27 @@ use crate::features::browse::cell_detail::{CellDetail, CellDetailRenderMetrics};
 use crate::features::browse::explorer::Explorer;
 use crate::features::browse::inspector::Inspector;
 use crate::features::browse::jsonb_detail::JsonbDetail;
+use crate::features::browse::maintenance_panel::MaintenancePanel;
 use crate::features::browse::result::ResultPane;
 use crate::features::browse::row_detail::RowDetail;
 use crate::features::connections::error::ConnectionError;
+use crate::features::connections::import::ConnectionImportPicker;
 use crate::features::connections::selector::ConnectionSelector;
 use crate::features::connections::setup::ConnectionSetup;
 use crate::features::overlays::confirm_dialog::ConfirmDialog;
+use crate::features::overlays::er_diagram::ErDiagramViewer;
 use crate::features::overlays::help::HelpOverlay;
+use crate::features::overlays::session_settings::SessionSettingsOverlay;
 use crate::features::overlays::settings::SettingsOverlay;
 use crate::features::overlays::sqlite_diagnostics::SqliteDiagnosticsOverlay;
 use crate::features::pickers::command_palette::CommandPalette;
 use crate::features::pickers::er_table_picker::ErTablePicker;
+use crate::features::pickers::object_search::ObjectSearchPicker;
 use crate::features::pickers::query_history_picker::QueryHistoryPicker;
+use crate::features::pickers::quick_actions::QuickActionsMenu;
+use crate::features::pickers::server_settings::ServerSettingsOverlay;
+use crate::features::pickers::snippets::SnippetPicker;
+use crate::features::pickers::sql_files::SqlFileBrowser;
 use crate::features::pickers::table_picker::TablePicker;
 use crate::features::sql_modal::SqlModal;
 use crate::shell::command_line::CommandLine;
@@ -45,14 +54,18 @@ impl MainLayout {
         services: &AppServices,
         now: Instant,
     ) -> RenderOutput {
-        Self::render_impl(
-            frame,
-            state,
-            time_ms,
-            services,
-            now,
-            palette_for(state.ui.theme_id()),
-        )
+        let palette = palette_for(state.ui.theme_id());
+        match state.active_accent() {
+            Some(accent) => Self::render_impl(
+                frame,
+                state,
+                time_ms,
+                services,
+                now,
+                &palette.with_profile_accent(accent),
+            ),
+            None => Self::render_impl(frame, state, time_ms, services, now, palette),
+        }
     }
 
     // `render_with_theme` exists only as a test seam for injected palettes.
@@ -79,19 +92,19 @@ impl MainLayout {
     ) -> RenderOutput {
         let area = frame.area();
 
-        let [header_area, main_area, footer_area, cmdline_area] = Layout::vertical([
+        let [header_area, main_area, footer_area, cmdline_area] = area.layout(&Layout::vertical([
             Constraint::Length(1),
             Constraint::Min(10),
             Constraint::Length(1),
             Constraint::Length(1),
-        ])
-        .areas(area);
+        ]));
 
         Header::render(frame, header_area, state, theme);
         let browse = Self::render_browse_mode(frame, main_area, state, services, now, theme);
+        MaintenancePanel::render(frame, main_area, state, now, theme);
 
         Footer::render(frame, footer_area, state, time_ms, theme);
-        let command_line_visible_width = CommandLine::render(frame, cmdline_area, state, theme);
+        let mut command_line_visible_width = CommandLine::render(frame, cmdline_area, state, theme);
         let connection_list_pane_height = match state.input_mode() {
             InputMode::ConnectionSelector => Some(ConnectionSelector::render(frame, state, theme)),
             _ => None,
@@ -112,13 +125,44 @@ impl MainLayout {
             _ => None,
         };
 
+        let server_settings = match state.input_mode() {
+            InputMode::ServerSettings => Some(ServerSettingsOverlay::render(frame, state, theme)),
+            _ => None,
+        };
+
+        let sql_files = match state.input_mode() {
+            InputMode::SqlFileBrowser => Some(SqlFileBrowser::render(frame, state, theme)),
+            _ => None,
+        };
+
+        let snippets = match state.input_mode() {
+            InputMode::SnippetPicker => Some(SnippetPicker::render(frame, state, theme)),
+            _ => None,
+        };
+
+        let object_search = match state.input_mode() {
+            InputMode::ObjectSearch => Some(ObjectSearchPicker::render(frame, state, theme)),
+            _ => None,
+        };
+
         let confirm_preview = match state.input_mode() {
             InputMode::ConfirmDialog => ConfirmDialog::render(frame, state, theme),
             _ => ConfirmPreviewLayout::default(),
         };
 
-        let explain_compare_viewport_height = if matches!(state.input_mode(), InputMode::SqlModal) {
-            SqlModal::render(frame, state, now, theme)
+        // `:` from the SQL modal keeps the editor on screen behind the command line.
+        let sql_modal_visible = match state.input_mode() {
+            InputMode::SqlModal => true,
+            InputMode::CommandLine => state.modal.return_destination() == InputMode::SqlModal,
+            _ => false,
+        };
+        let explain_compare_viewport_height = if sql_modal_visible {
+            let viewport_height = SqlModal::render(frame, state, now, theme);
+            if state.input_mode() == InputMode::CommandLine {
+                // Redrawn so the modal's scrim does not dim what is being typed.
+                command_line_visible_width = CommandLine::render(frame, cmdline_area, state, theme);
+            }
+            viewport_height
         } else {
             None
         };
@@ -166,6 +210,10 @@ impl MainLayout {
             InputMode::Help => HelpOverlay::render(frame, state, theme),
             InputMode::ConnectionSetup => ConnectionSetup::render(frame, state, services, theme),
             InputMode::ConnectionError => ConnectionError::render(frame, state, now, theme),
+            InputMode::SessionSettings => SessionSettingsOverlay::render(frame, state, theme),
+            InputMode::ErDiagram => ErDiagramViewer::render(frame, state, theme),
+            InputMode::ConnectionImport => ConnectionImportPicker::render(frame, state, theme),
+            InputMode::QuickActions => QuickActionsMenu::render(frame, state, theme),
             _ => {}
         }
 
@@ -179,6 +227,10 @@ impl MainLayout {
                 table: table_picker,
                 er: er_picker,
                 query_history: query_history_picker,
+                server_settings,
+                sql_files,
+                snippets,
+                object_search,
             },
             details: DetailLayout {
                 jsonb: jsonb_detail,
@@ -203,32 +255,27 @@ impl MainLayout {
         theme: &ThemePalette,
     ) -> BrowseLayout {
         if state.ui.is_focus_mode() {
-            let (result_plan, result_widths_cache) =
-                ResultPane::render(frame, main_area, state, now, theme);
             BrowseLayout {
                 explorer: ExplorerLayout::default(),
                 inspector: InspectorLayout::default(),
-                result: ResultLayout {
-                    viewport_plan: result_plan,
-                    widths_cache: result_widths_cache,
-                    pane_height: main_area.height,
-                },
+                result: ResultPane::render(frame, main_area, state, now, theme),
             }
         } else {
-            let [left_area, right_area] =
-                Layout::horizontal([Constraint::Percentage(25), Constraint::Percentage(75)])
-                    .areas(main_area);
+            let [left_area, right_area] = main_area.layout(&Layout::horizontal([
+                Constraint::Percentage(25),
+                Constraint::Percentage(75),
+            ]));
 
             Explorer::render(frame, left_area, state, theme);
 
-            let [inspector_area, result_area] =
-                Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
-                    .areas(right_area);
+            let [inspector_area, result_area] = right_area.layout(&Layout::vertical([
+                Constraint::Percentage(50),
+                Constraint::Percentage(50),
+            ]));
 
             let inspector_plan =
                 Inspector::render(frame, inspector_area, state, services, now, theme);
-            let (result_plan, result_widths_cache) =
-                ResultPane::render(frame, result_area, state, now, theme);
+            let result = ResultPane::render(frame, result_area, state, now, theme);
 
             BrowseLayout {
                 explorer: ExplorerLayout {
@@ -239,11 +286,7 @@ impl MainLayout {
                     viewport_plan: inspector_plan,
                     pane_height: inspector_area.height,
                 },
-                result: ResultLayout {
-                    viewport_plan: result_plan,
-                    widths_cache: result_widths_cache,
-                    pane_height: result_area.height,
-                },
+                result,
             }
         }
     }
@@ -2,6 +2,7 @@ use ratatui::style::{Color, Modifier, Style};
 
 use crate::app::model::shared::theme_id::ThemeId;
 use crate::app::policy::write::write_guardrails::RiskLevel;
+use crate::domain::ProfileAccent;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StatusTone {
@@ -97,9 +98,13 @@ pub struct EditorTokens {
 pub struct TableTokens {
     pub result_row_active_bg: Color,
     pub result_cell_active_bg: Color,
+    pub result_range_bg: Color,
+    pub pinned_column_fg: Color,
+    pub changed_cell_fg: Color,
     pub cell_edit_fg: Color,
     pub staged_delete_bg: Color,
     pub staged_delete_fg: Color,
+    pub marked_row_bg: Color,
     pub striped_row_bg: Color,
 }
 
@@ -190,6 +195,29 @@ impl ThemePalette {
     pub fn insert_cursor_style(&self) -> Style {
         Style::default().fg(self.semantic.cursor.fg)
     }
+
+    /// Recolors focused pane borders and highlighted modal borders with the
+    /// active connection's accent.
+    #[must_use]
+    pub fn with_profile_accent(mut self, accent: ProfileAccent) -> Self {
+        let color = accent_color(accent);
+        self.semantic.surface.focus_border = color;
+        self.semantic.surface.highlight_border = color;
+        self.component.modal.border_highlight = color;
+        self
+    }
+}
+
+pub fn accent_color(accent: ProfileAccent) -> Color {
+    match accent {
+        ProfileAccent::Red => Color::Rgb(0xe0, 0x5a, 0x50),
+        ProfileAccent::Orange => Color::Rgb(0xe8, 0x8a, 0x3c),
+        ProfileAccent::Yellow => Color::Rgb(0xd8, 0xc0, 0x48),
+        ProfileAccent::Green => Color::Rgb(0x6c, 0xb8, 0x6a),
+        ProfileAccent::Cyan => Color::Rgb(0x4c, 0xb8, 0xc8),
+        ProfileAccent::Blue => Color::Rgb(0x5c, 0x8c, 0xe0),
+        ProfileAccent::Magenta => Color::Rgb(0xc0, 0x6c, 0xc8),
+    }
 }
 
 pub const DEFAULT_THEME: ThemePalette = ThemePalette {
@@ -244,9 +272,13 @@ pub const DEFAULT_THEME: ThemePalette = ThemePalette {
         table: TableTokens {
             result_row_active_bg: Color::Rgb(0x2e, 0x2e, 0x44),
             result_cell_active_bg: Color::Rgb(0x3a, 0x3a, 0x5a),
+            result_range_bg: Color::Rgb(0x33, 0x33, 0x50),
+            pinned_column_fg: Color::Rgb(0x89, 0xdc, 0xeb),
+            changed_cell_fg: Color::Rgb(0xf9, 0xc8, 0x6b),
             cell_edit_fg: Color::Rgb(0xa8, 0xb8, 0xb5),
             staged_delete_bg: Color::Rgb(0x3d, 0x22, 0x22),
             staged_delete_fg: Color::Rgb(0xee, 0x77, 0x77),
+            marked_row_bg: Color::Rgb(0x24, 0x33, 0x2c),
             striped_row_bg: Color::Rgb(0x1e, 0x1e, 0x23),
         },
         feedback: FeedbackTokens {
@@ -317,9 +349,13 @@ pub const LIGHT_THEME: ThemePalette = ThemePalette {
         table: TableTokens {
             result_row_active_bg: Color::Rgb(0xe3, 0xed, 0xeb),
             result_cell_active_bg: Color::Rgb(0xd0, 0xe2, 0xdf),
+            result_range_bg: Color::Rgb(0xe0, 0xec, 0xea),
+            pinned_column_fg: Color::Rgb(0x1f, 0x6f, 0xb2),
+            changed_cell_fg: Color::Rgb(0xa8, 0x5d, 0x00),
             cell_edit_fg: Color::Rgb(0x4a, 0x78, 0x72),
             staged_delete_bg: Color::Rgb(0xf3, 0xdd, 0xdb),
             staged_delete_fg: Color::Rgb(0xb3, 0x26, 0x1e),
+            marked_row_bg: Color::Rgb(0xe4, 0xf1, 0xd9),
             striped_row_bg: Color::Rgb(0xf1, 0xf5, 0xf4),
         },
         feedback: FeedbackTokens {
@@ -392,9 +428,13 @@ pub const TEST_CONTRAST_THEME: ThemePalette = ThemePalette {
         table: TableTokens {
             result_row_active_bg: Color::Rgb(0x2b, 0x32, 0x54),
             result_cell_active_bg: Color::Rgb(0x3a, 0x44, 0x6e),
+            result_range_bg: Color::Rgb(0x30, 0x38, 0x5c),
+            pinned_column_fg: Color::Rgb(0x7f, 0xdb, 0xca),
+            changed_cell_fg: Color::Rgb(0xff, 0x9f, 0x1c),
             cell_edit_fg: Color::Rgb(0xff, 0xe0, 0x66),
             staged_delete_bg: Color::Rgb(0x4a, 0x1f, 0x1f),
             staged_delete_fg: Color::Rgb(0xff, 0x7a, 0x59),
+            marked_row_bg: Color::Rgb(0x1f, 0x3d, 0x2e),
             striped_row_bg: Color::Rgb(0x1d, 0x21, 0x2b),
         },
         feedback: FeedbackTokens {
@@ -482,6 +522,20 @@ mod tests {
         assert_eq!(style.fg, Some(DEFAULT_THEME.component.modal.border));
     }
 
+    #[test]
+    fn profile_accent_recolors_focused_borders_only() {
+        let theme = DEFAULT_THEME.with_profile_accent(ProfileAccent::Red);
+
+        assert_eq!(
+            theme.panel_border_style(true, false).fg,
+            Some(accent_color(ProfileAccent::Red))
+        );
+        assert_eq!(
+            theme.panel_border_style(false, false).fg,
+            Some(DEFAULT_THEME.semantic.surface.unfocus_border)
+        );
+    }
+
     #[test]
     fn block_cursor_style_uses_semantic_cursor_colors() {
         let style = DEFAULT_THEME.block_cursor_style();
@@ -58,15 +58,23 @@ impl TuiRunner {
 
     pub fn exit(&mut self) -> Result<()> {
         self.stop_event_loop();
+        Self::restore_terminal()
+    }
+
+    /// Undoes everything `enter` set up. Needs no runner instance so the panic
+    /// hook can call it while the runner is mid-draw on another frame.
+    pub fn restore_terminal() -> Result<()> {
         if crossterm::terminal::is_raw_mode_enabled()? {
             let _ = execute!(stdout(), SetCursorStyle::DefaultUserShape);
-            execute!(
+            // Leave raw mode even if the screen reset fails, or the shell stays unusable.
+            let screen = execute!(
                 stdout(),
                 LeaveAlternateScreen,
                 DisableMouseCapture,
                 DisableBracketedPaste
-            )?;
+            );
             disable_raw_mode()?;
+            screen?;
         }
         Ok(())
     }